@@ -0,0 +1,181 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Counts the allocations made while building the message batches on the append path,
+//! comparing the fresh buffer allocated for each batch with the buffers reused from the pool.
+//!
+//! Run with `cargo bench -p bench --bench batch_allocations`.
+
+use bytes::{Bytes, BytesMut};
+use iggy::models::messages::MessageState;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::sizeable::Sizeable;
+use server::configs::system::BufferPoolConfig;
+use server::streaming::batching::batch_accumulator::BatchAccumulator;
+use server::streaming::models::messages::RetainedMessage;
+use server::streaming::utils::buffer_pool::BufferPool;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u64 = 1_000;
+const SHAPES: [(u64, usize); 3] = [(1_000, 100), (1_000, 1_024), (100, 16_384)];
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size as u64, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+struct Measurement {
+    allocations: u64,
+    allocated_bytes: u64,
+    elapsed: Duration,
+}
+
+fn main() {
+    println!(
+        "{:<20} {:>10} {:>10} {:>16} {:>20} {:>12}",
+        "scenario", "messages", "payload", "allocs/batch", "allocated B/batch", "MB/s"
+    );
+    for (messages_count, payload_size) in SHAPES {
+        let messages = build_messages(messages_count, payload_size);
+        let batch_size = messages
+            .iter()
+            .map(|message| message.get_size_bytes())
+            .sum::<IggyByteSize>();
+
+        let fresh = measure(|| {
+            let bytes = build_with_fresh_buffer(&messages, batch_size);
+            black_box(bytes);
+        });
+        report(
+            "fresh buffer",
+            messages_count,
+            payload_size,
+            batch_size,
+            &fresh,
+        );
+
+        let buffer_pool = BufferPool::new(&BufferPoolConfig::default());
+        let mut accumulator = BatchAccumulator::new(0, messages.len());
+        let pooled = measure(|| {
+            accumulator.append(batch_size, &messages);
+            let batch = accumulator.materialize_batch_and_update_state(&buffer_pool);
+            // Mimics the log writer, which returns the buffer to the pool once the batch has been written.
+            buffer_pool.reclaim(black_box(batch.bytes));
+        });
+        report(
+            "pooled buffer",
+            messages_count,
+            payload_size,
+            batch_size,
+            &pooled,
+        );
+    }
+}
+
+/// Builds the batch the way it was done before the pool, with a new buffer for each batch.
+fn build_with_fresh_buffer(messages: &[Arc<RetainedMessage>], batch_size: IggyByteSize) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(batch_size.as_bytes_u64() as usize);
+    for message in messages {
+        message.extend(&mut bytes);
+    }
+    bytes.freeze()
+}
+
+fn build_messages(count: u64, payload_size: usize) -> Vec<Arc<RetainedMessage>> {
+    (0..count)
+        .map(|offset| {
+            Arc::new(RetainedMessage {
+                id: offset as u128,
+                offset,
+                timestamp: offset,
+                checksum: 0,
+                message_state: MessageState::Available,
+                headers: None,
+                payload: Bytes::from(vec![offset as u8; payload_size]),
+            })
+        })
+        .collect()
+}
+
+fn measure(mut build_batch: impl FnMut()) -> Measurement {
+    // Warms up the pool and the accumulator, so only the steady state is measured.
+    build_batch();
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let started_at = Instant::now();
+    for _ in 0..ITERATIONS {
+        build_batch();
+    }
+    Measurement {
+        elapsed: started_at.elapsed(),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes,
+    }
+}
+
+fn report(
+    scenario: &str,
+    messages_count: u64,
+    payload_size: usize,
+    batch_size: IggyByteSize,
+    measurement: &Measurement,
+) {
+    let throughput = (batch_size.as_bytes_u64() * ITERATIONS) as f64
+        / measurement.elapsed.as_secs_f64()
+        / 1_000_000.0;
+    println!(
+        "{:<20} {:>10} {:>10} {:>16.2} {:>20.0} {:>12.2}",
+        scenario,
+        messages_count,
+        payload_size,
+        measurement.allocations as f64 / ITERATIONS as f64,
+        measurement.allocated_bytes as f64 / ITERATIONS as f64,
+        throughput,
+    );
+}
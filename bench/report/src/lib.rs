@@ -103,9 +103,13 @@ pub fn create_latency_chart(
 ) -> Chart {
     let title = report.title(ChartKind::Latency);
 
-    let mut chart = IggyChart::new(&title, &report.subtext(), dark, strip_title_and_subtext)
-        .with_time_x_axis()
-        .with_y_axis("Latency [ms]");
+    let chart =
+        IggyChart::new(&title, &report.subtext(), dark, strip_title_and_subtext).with_time_x_axis();
+    // Server CPU usage goes on the right axis, to correlate the latency spikes with the server saturation
+    let mut chart = match report.server_usage {
+        Some(_) => chart.with_dual_y_axis("Latency [ms]", "Server CPU [%]"),
+        None => chart.with_y_axis("Latency [ms]"),
+    };
 
     // Add individual metrics series
     for metrics in &report.individual_metrics {
@@ -139,5 +143,16 @@ pub fn create_latency_chart(
         );
     }
 
+    if let Some(server_usage) = &report.server_usage {
+        chart = chart.add_dual_time_line_series(
+            "Server CPU [%]",
+            server_usage.cpu_usage_ts.as_charming_points(),
+            None,
+            1.0,
+            1,
+            2.0,
+        );
+    }
+
     chart.inner
 }
@@ -23,6 +23,7 @@ use tracing::info;
 use crate::{
     benchmark_kind::BenchmarkKind, group_metrics::BenchmarkGroupMetrics,
     group_metrics_kind::GroupMetricsKind, report::BenchmarkReport,
+    server_usage::BenchmarkServerUsage,
 };
 
 impl BenchmarkReport {
@@ -80,6 +81,33 @@ impl BenchmarkReport {
         self.group_metrics
             .iter()
             .for_each(|s| info!("{}\n", s.formatted_string()));
+
+        if let Some(server_usage) = &self.server_usage {
+            info!("{}\n", server_usage.formatted_string());
+        }
+    }
+}
+
+impl BenchmarkServerUsage {
+    pub fn formatted_string(&self) -> ColoredString {
+        let cache_hit_ratio = self
+            .summary
+            .cache_hit_ratio
+            .map(|ratio| format!("{:.2}%", ratio * 100.0))
+            .unwrap_or_else(|| "n/a".to_owned());
+
+        format!(
+            "Server Resources: average CPU usage: {:.2}%, max CPU usage: {:.2}%, \
+            average memory usage: {:.2} MB, max memory usage: {:.2} MB, \
+            cache hit ratio: {}, samples: {}",
+            self.summary.average_cpu_usage,
+            self.summary.max_cpu_usage,
+            self.summary.average_memory_usage_mb,
+            self.summary.max_memory_usage_mb,
+            cache_hit_ratio,
+            self.summary.samples
+        )
+        .color(Color::Yellow)
     }
 }
 
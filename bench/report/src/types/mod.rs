@@ -27,5 +27,6 @@ pub mod individual_metrics_summary;
 pub mod params;
 pub mod report;
 pub mod server_stats;
+pub mod server_usage;
 pub mod time_series;
 pub mod transport;
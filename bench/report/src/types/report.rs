@@ -17,6 +17,7 @@
  */
 
 use super::server_stats::BenchmarkServerStats;
+use super::server_usage::BenchmarkServerUsage;
 use crate::group_metrics::BenchmarkGroupMetrics;
 use crate::individual_metrics::BenchmarkIndividualMetrics;
 use crate::types::hardware::BenchmarkHardware;
@@ -36,6 +37,10 @@ pub struct BenchmarkReport {
     /// Benchmark server statistics
     pub server_stats: BenchmarkServerStats,
 
+    /// Server resource usage during the benchmark, present when the server stats were scraped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server_usage: Option<BenchmarkServerUsage>,
+
     /// Benchmark hardware
     pub hardware: BenchmarkHardware,
 
@@ -0,0 +1,49 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::time_series::TimeSeries;
+use serde::{Deserialize, Serialize};
+
+/// Server resource usage scraped periodically from the server stats during the benchmark.
+/// The time series share the time axis with the client metrics, so the latency can be correlated with the server saturation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct BenchmarkServerUsage {
+    pub summary: BenchmarkServerUsageSummary,
+    /// CPU usage of the server process in percents
+    pub cpu_usage_ts: TimeSeries,
+    /// Memory usage of the server process in megabytes
+    pub memory_usage_mb_ts: TimeSeries,
+    /// Cache hit ratio of all partitions, calculated for each scrape interval
+    pub cache_hit_ratio_ts: TimeSeries,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct BenchmarkServerUsageSummary {
+    /// Number of the server stats samples
+    pub samples: u32,
+    /// Average CPU usage of the server process in percents
+    pub average_cpu_usage: f64,
+    /// Maximum CPU usage of the server process in percents
+    pub max_cpu_usage: f64,
+    /// Average memory usage of the server process in megabytes
+    pub average_memory_usage_mb: f64,
+    /// Maximum memory usage of the server process in megabytes
+    pub max_memory_usage_mb: f64,
+    /// Cache hit ratio of all partitions during the benchmark, `None` if the cache wasn't accessed
+    pub cache_hit_ratio: Option<f64>,
+}
@@ -46,6 +46,9 @@ pub enum TimeSeriesKind {
     ThroughputMB,
     ThroughputMsg,
     Latency,
+    ServerCpuUsage,
+    ServerMemoryUsageMB,
+    ServerCacheHitRatio,
 }
 
 impl TimeSeries {
@@ -24,15 +24,15 @@ use human_repr::HumanCount;
 use iggy::client::MessageClient;
 use iggy::clients::client::IggyClient;
 use iggy::error::IggyError;
-use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::messages::send_messages::Partitioning;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::sizeable::Sizeable;
 use iggy_bench_report::actor_kind::ActorKind;
 use iggy_bench_report::benchmark_kind::BenchmarkKind;
 use iggy_bench_report::individual_metrics::BenchmarkIndividualMetrics;
+use iggy_test_data::generator::{MessagesGenerator, MessagesGeneratorConfig, PayloadSchema};
 use integration::test_server::{login_root, ClientFactory};
-use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::Instant;
@@ -104,15 +104,16 @@ impl Producer {
             "Producer #{} → preparing the test messages...",
             self.producer_id
         );
-        let payload = Self::create_payload(message_size);
+        let mut messages_generator = MessagesGenerator::with_config(
+            MessagesGeneratorConfig::default()
+                .with_schema(PayloadSchema::Alphabet { size: message_size }),
+        );
         let mut batch_user_data_bytes = 0;
         let mut batch_total_bytes = 0;
-        let mut messages = Vec::with_capacity(messages_per_batch as usize);
-        for _ in 0..messages_per_batch {
-            let message = Message::from_str(&payload).unwrap();
+        let mut messages = messages_generator.generate_messages(messages_per_batch)?;
+        for message in &messages {
             batch_user_data_bytes += message.length as u64;
             batch_total_bytes += message.get_size_bytes().as_bytes_u64();
-            messages.push(message);
         }
         let batch_user_data_bytes = batch_user_data_bytes;
         let batch_total_bytes = batch_total_bytes;
@@ -203,16 +204,6 @@ impl Producer {
         Ok(metrics)
     }
 
-    fn create_payload(size: u32) -> String {
-        let mut payload = String::with_capacity(size as usize);
-        for i in 0..size {
-            let char = (i % 26 + 97) as u8 as char;
-            payload.push(char);
-        }
-
-        payload
-    }
-
     fn log_statistics(
         producer_id: u32,
         total_messages: u64,
@@ -27,15 +27,15 @@ use iggy::clients::client::IggyClient;
 use iggy::consumer::Consumer as IggyConsumer;
 use iggy::error::IggyError;
 use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::messages::send_messages::Partitioning;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::sizeable::Sizeable;
 use iggy_bench_report::actor_kind::ActorKind;
 use iggy_bench_report::benchmark_kind::BenchmarkKind;
 use iggy_bench_report::individual_metrics::BenchmarkIndividualMetrics;
+use iggy_test_data::generator::{MessagesGenerator, MessagesGeneratorConfig, PayloadSchema};
 use integration::test_server::{login_root, ClientFactory};
-use std::str::FromStr;
 use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
@@ -117,15 +117,16 @@ impl ProducingConsumer {
             "ProducingConsumer #{} → preparing test messages...",
             self.actor_id
         );
-        let payload = self.create_payload(message_size);
+        let mut messages_generator = MessagesGenerator::with_config(
+            MessagesGeneratorConfig::default()
+                .with_schema(PayloadSchema::Alphabet { size: message_size }),
+        );
         let mut batch_user_data_bytes = 0;
         let mut batch_total_bytes = 0;
-        let mut messages = Vec::with_capacity(messages_per_batch as usize);
-        for _ in 0..messages_per_batch {
-            let message = Message::from_str(&payload).unwrap();
+        let mut messages = messages_generator.generate_messages(messages_per_batch)?;
+        for message in &messages {
             batch_user_data_bytes += message.length as u64;
             batch_total_bytes += message.get_size_bytes().as_bytes_u64();
-            messages.push(message);
         }
 
         let stream_id = self.stream_id.try_into()?;
@@ -345,10 +346,6 @@ impl ProducingConsumer {
         Ok(metrics)
     }
 
-    fn create_payload(&self, size: u32) -> String {
-        "a".repeat(size as usize)
-    }
-
     fn log_statistics(
         actor_id: u32,
         total_messages: u64,
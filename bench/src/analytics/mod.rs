@@ -19,4 +19,5 @@
 pub mod metrics;
 pub mod record;
 pub mod report_builder;
+pub mod server_usage;
 pub mod time_series;
@@ -33,6 +33,7 @@ use iggy_bench_report::{
     params::BenchmarkParams,
     report::BenchmarkReport,
     server_stats::{BenchmarkCacheMetrics, BenchmarkCacheMetricsKey, BenchmarkServerStats},
+    server_usage::BenchmarkServerUsage,
 };
 
 pub struct BenchmarkReportBuilder;
@@ -42,6 +43,7 @@ impl BenchmarkReportBuilder {
         hardware: BenchmarkHardware,
         mut params: BenchmarkParams,
         mut individual_metrics: Vec<BenchmarkIndividualMetrics>,
+        server_usage: Option<BenchmarkServerUsage>,
         moving_average_window: u32,
     ) -> BenchmarkReport {
         let uuid = uuid::Uuid::new_v4();
@@ -129,6 +131,7 @@ impl BenchmarkReportBuilder {
         BenchmarkReport {
             uuid,
             server_stats: stats_to_benchmark_server_stats(server_stats),
+            server_usage,
             timestamp,
             hardware,
             params,
@@ -0,0 +1,163 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::connect_root_client;
+use iggy::client::SystemClient;
+use iggy::error::IggyError;
+use iggy::models::stats::Stats;
+use iggy::utils::duration::IggyDuration;
+use iggy_bench_report::server_usage::{BenchmarkServerUsage, BenchmarkServerUsageSummary};
+use iggy_bench_report::time_series::{TimePoint, TimeSeries, TimeSeriesKind};
+use iggy_bench_report::transport::BenchmarkTransport;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tracing::{error, warn};
+
+/// Periodically scrapes the server stats while the benchmark is running.
+pub struct ServerUsageSampler {
+    stop_sender: oneshot::Sender<()>,
+    handle: JoinHandle<Vec<ServerUsageSample>>,
+}
+
+struct ServerUsageSample {
+    time_s: f64,
+    cpu_usage: f64,
+    memory_usage_mb: f64,
+    cache_hits: u64,
+    cache_misses: u64,
+}
+
+impl ServerUsageSample {
+    fn new(time_s: f64, stats: &Stats) -> Self {
+        let (cache_hits, cache_misses) = stats
+            .cache_metrics
+            .values()
+            .fold((0, 0), |(hits, misses), metrics| {
+                (hits + metrics.hits, misses + metrics.misses)
+            });
+        Self {
+            time_s,
+            cpu_usage: stats.cpu_usage as f64,
+            memory_usage_mb: stats.memory_usage.as_bytes_u64() as f64 / 1_000_000.0,
+            cache_hits,
+            cache_misses,
+        }
+    }
+}
+
+impl ServerUsageSampler {
+    pub async fn start(
+        transport: &BenchmarkTransport,
+        server_address: &str,
+        interval: IggyDuration,
+    ) -> Result<Self, IggyError> {
+        let client = connect_root_client(transport, server_address).await?;
+        let (stop_sender, mut stop_receiver) = oneshot::channel();
+        let handle = tokio::spawn(async move {
+            let start = Instant::now();
+            let mut ticker = tokio::time::interval(interval.get_duration());
+            let mut samples = Vec::new();
+            loop {
+                tokio::select! {
+                    _ = &mut stop_receiver => break,
+                    _ = ticker.tick() => match client.get_stats().await {
+                        Ok(stats) => samples.push(ServerUsageSample::new(start.elapsed().as_secs_f64(), &stats)),
+                        Err(error) => warn!("Failed to scrape the server stats: {error}"),
+                    }
+                }
+            }
+            samples
+        });
+
+        Ok(Self {
+            stop_sender,
+            handle,
+        })
+    }
+
+    /// Stops the sampling and aggregates the samples, returns `None` if no sample was scraped.
+    pub async fn stop(self) -> Option<BenchmarkServerUsage> {
+        let _ = self.stop_sender.send(());
+        match self.handle.await {
+            Ok(samples) => server_usage_from_samples(&samples),
+            Err(error) => {
+                error!("Server stats sampler failed: {error}");
+                None
+            }
+        }
+    }
+}
+
+fn server_usage_from_samples(samples: &[ServerUsageSample]) -> Option<BenchmarkServerUsage> {
+    let (first, last) = (samples.first()?, samples.last()?);
+    let count = samples.len() as f64;
+
+    let summary = BenchmarkServerUsageSummary {
+        samples: samples.len() as u32,
+        average_cpu_usage: samples.iter().map(|s| s.cpu_usage).sum::<f64>() / count,
+        max_cpu_usage: samples.iter().map(|s| s.cpu_usage).fold(0.0, f64::max),
+        average_memory_usage_mb: samples.iter().map(|s| s.memory_usage_mb).sum::<f64>() / count,
+        max_memory_usage_mb: samples
+            .iter()
+            .map(|s| s.memory_usage_mb)
+            .fold(0.0, f64::max),
+        cache_hit_ratio: cache_hit_ratio(first, last),
+    };
+
+    // The cache metrics are cumulative, so the ratio of each interval is taken from the difference of the adjacent samples.
+    let cache_hit_ratio_points = samples
+        .windows(2)
+        .filter_map(|pair| {
+            cache_hit_ratio(&pair[0], &pair[1]).map(|ratio| TimePoint::new(pair[1].time_s, ratio))
+        })
+        .collect();
+
+    Some(BenchmarkServerUsage {
+        summary,
+        cpu_usage_ts: time_series(samples, TimeSeriesKind::ServerCpuUsage, |s| s.cpu_usage),
+        memory_usage_mb_ts: time_series(samples, TimeSeriesKind::ServerMemoryUsageMB, |s| {
+            s.memory_usage_mb
+        }),
+        cache_hit_ratio_ts: TimeSeries {
+            points: cache_hit_ratio_points,
+            kind: TimeSeriesKind::ServerCacheHitRatio,
+        },
+    })
+}
+
+fn time_series(
+    samples: &[ServerUsageSample],
+    kind: TimeSeriesKind,
+    value: impl Fn(&ServerUsageSample) -> f64,
+) -> TimeSeries {
+    TimeSeries {
+        points: samples
+            .iter()
+            .map(|sample| TimePoint::new(sample.time_s, value(sample)))
+            .collect(),
+        kind,
+    }
+}
+
+fn cache_hit_ratio(from: &ServerUsageSample, to: &ServerUsageSample) -> Option<f64> {
+    let hits = to.cache_hits.saturating_sub(from.cache_hits);
+    let misses = to.cache_misses.saturating_sub(from.cache_misses);
+    let total = hits + misses;
+    (total > 0).then(|| hits as f64 / total as f64)
+}
@@ -74,6 +74,11 @@ pub struct IggyBenchArgs {
     #[arg(long, short = 't', default_value_t = IggyDuration::from_str(DEFAULT_SAMPLING_TIME).unwrap(), value_parser = IggyDuration::from_str)]
     pub sampling_time: IggyDuration,
 
+    /// Optional interval of scraping the server stats (CPU, memory, cache hit ratio) during the benchmark, e.g. "1s".
+    /// The server resource usage is included in the report and the latency chart.
+    #[arg(long, value_parser = IggyDuration::from_str, verbatim_doc_comment)]
+    pub server_stats_interval: Option<IggyDuration>,
+
     /// Window size for moving average calculations in time series data
     #[arg(long, short = 'W', default_value_t = DEFAULT_MOVING_AVERAGE_WINDOW)]
     pub moving_average_window: u32,
@@ -155,6 +160,18 @@ impl IggyBenchArgs {
                 .exit();
         }
 
+        if self
+            .server_stats_interval
+            .is_some_and(|interval| interval.is_zero())
+        {
+            IggyBenchArgs::command()
+                .error(
+                    ErrorKind::ValueValidation,
+                    "--server-stats-interval must be greater than zero",
+                )
+                .exit();
+        }
+
         self.benchmark_kind.inner().validate()
     }
 
@@ -202,6 +219,10 @@ impl IggyBenchArgs {
         self.sampling_time
     }
 
+    pub fn server_stats_interval(&self) -> Option<IggyDuration> {
+        self.server_stats_interval
+    }
+
     pub fn moving_average_window(&self) -> u32 {
         self.moving_average_window
     }
@@ -306,7 +327,7 @@ impl IggyBenchArgs {
             BenchmarkKindCommand::EndToEndProducingConsumerGroup(_) => {
                 "end_to_end_producing_consumer_group"
             }
-            BenchmarkKindCommand::Examples => unreachable!(),
+            BenchmarkKindCommand::Scenario(_) | BenchmarkKindCommand::Examples => unreachable!(),
         };
 
         let transport = match self.transport_command() {
@@ -328,7 +349,7 @@ impl IggyBenchArgs {
             }
             BenchmarkKindCommand::EndToEndProducingConsumer(_) => self.producers(),
             BenchmarkKindCommand::EndToEndProducingConsumerGroup(_) => self.producers(),
-            BenchmarkKindCommand::Examples => unreachable!(),
+            BenchmarkKindCommand::Scenario(_) | BenchmarkKindCommand::Examples => unreachable!(),
         };
 
         let mut parts = vec![
@@ -381,7 +402,7 @@ impl IggyBenchArgs {
                     self.consumers()
                 )
             }
-            BenchmarkKindCommand::Examples => unreachable!(),
+            BenchmarkKindCommand::Scenario(_) | BenchmarkKindCommand::Examples => unreachable!(),
         };
 
         let mut name = format!(
@@ -447,6 +468,13 @@ fn recreate_bench_command(args: &IggyBenchArgs) -> String {
         parts.push(format!("--warmup-time \'{}\'", args.warmup_time()));
     }
 
+    if let Some(server_stats_interval) = args.server_stats_interval() {
+        parts.push(format!(
+            "--server-stats-interval \'{}\'",
+            server_stats_interval
+        ));
+    }
+
     let kind_str = match args.benchmark_kind.as_simple_kind() {
         BenchmarkKind::PinnedProducer => "pinned-producer",
         BenchmarkKind::PinnedConsumer => "pinned-consumer",
@@ -56,6 +56,7 @@ const EXAMPLES: &str = r#"EXAMPLES:
     --warmup-time (-w): Warmup duration [default: 0s]
     --sampling-time (-t): Metrics sampling interval [default: 10ms]
     --moving-average-window (-W): Window size for moving average [default: 20]
+    --server-stats-interval: Optional interval of scraping server CPU, memory and cache hit ratio (e.g., "1s")
     --cleanup: Remove server data after benchmark
     --verbose (-v): Show server output (only applicable for local server)
 
@@ -121,7 +122,18 @@ const EXAMPLES: &str = r#"EXAMPLES:
     --gitref-date      : Git reference date (merge/commit date)
     --open-charts      : Auto-open result charts in browser
 
-7) Help and Documentation:
+7) Scenario Benchmarking:
+
+    The multi-phase workload can be defined in the TOML or YAML scenario file,
+    with the phases run one after another:
+
+    $ cargo r -r --bin iggy-bench -- scenario --file bench/scenarios/mixed_workload.toml
+
+    Each phase sets its benchmark kind, actors, rate limit, topics and messages,
+    while the scenario sets the transport, server address and output options.
+    Global flags given on the command line (e.g. --verbose, --cleanup) apply to all phases.
+
+8) Help and Documentation:
 
     For more details on available options:
 
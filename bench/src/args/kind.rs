@@ -22,6 +22,7 @@ use super::kinds::balanced::producer_and_consumer_group::BalancedProducerAndCons
 use super::kinds::end_to_end::producing_consumer::EndToEndProducingConsumerArgs;
 use super::kinds::end_to_end::producing_consumer_group::EndToEndProducingConsumerGroupArgs;
 use super::props::BenchmarkKindProps;
+use super::scenario::ScenarioArgs;
 use super::transport::BenchmarkTransportCommand;
 use crate::args::kinds::balanced::consumer_group::BalancedConsumerGroupArgs;
 use crate::args::kinds::pinned::consumer::PinnedConsumerArgs;
@@ -93,6 +94,14 @@ pub enum BenchmarkKindCommand {
     )]
     EndToEndProducingConsumerGroup(EndToEndProducingConsumerGroupArgs),
 
+    #[command(
+        about = "Run the benchmarks defined in the TOML or YAML scenario file",
+        long_about = "Run the phases of the scenario file one after another, each being a benchmark with its own actors, rates, topics and messages",
+        visible_alias = "sc",
+        verbatim_doc_comment
+    )]
+    Scenario(ScenarioArgs),
+
     #[command(about = "Print examples", visible_alias = "e", verbatim_doc_comment)]
     Examples,
 }
@@ -116,6 +125,9 @@ impl BenchmarkKindCommand {
             BenchmarkKindCommand::EndToEndProducingConsumerGroup(_) => {
                 BenchmarkKind::EndToEndProducingConsumerGroup
             }
+            BenchmarkKindCommand::Scenario(_) => {
+                unreachable!("Scenario is run as the benchmarks of its phases")
+            }
             BenchmarkKindCommand::Examples => {
                 print_examples();
                 std::process::exit(0);
@@ -163,6 +175,9 @@ impl BenchmarkKindProps for BenchmarkKindCommand {
             BenchmarkKindCommand::BalancedProducerAndConsumerGroup(args) => args,
             BenchmarkKindCommand::EndToEndProducingConsumer(args) => args,
             BenchmarkKindCommand::EndToEndProducingConsumerGroup(args) => args,
+            BenchmarkKindCommand::Scenario(_) => {
+                unreachable!("Scenario is run as the benchmarks of its phases")
+            }
             BenchmarkKindCommand::Examples => {
                 print_examples();
                 std::process::exit(0);
@@ -20,6 +20,7 @@ pub mod common;
 pub mod defaults;
 pub mod kind;
 pub mod kinds;
+pub mod scenario;
 
 mod examples;
 mod output;
@@ -0,0 +1,216 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::common::IggyBenchArgs;
+use super::kind::BenchmarkKindCommand;
+use clap::Parser;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// Runs the benchmarks defined in the scenario file
+#[derive(Parser, Debug, Clone)]
+pub struct ScenarioArgs {
+    /// Path to the scenario file in TOML (.toml) or YAML (.yaml, .yml) format
+    #[arg(long, short = 'f')]
+    pub file: PathBuf,
+}
+
+/// The complete workload definition, consisting of the phases executed one after another.
+/// Each phase is the single benchmark, described with the same options as the CLI subcommands.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BenchmarkScenario {
+    pub name: String,
+    #[serde(default = "default_transport")]
+    pub transport: String,
+    pub server_address: Option<String>,
+    pub output_dir: Option<String>,
+    pub identifier: Option<String>,
+    pub gitref: Option<String>,
+    pub gitref_date: Option<String>,
+    pub phases: Vec<ScenarioPhase>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioPhase {
+    pub name: String,
+    /// Benchmark kind, the same as the CLI subcommand, e.g. "pinned-producer"
+    pub kind: String,
+    pub warmup_time: Option<String>,
+    pub sampling_time: Option<String>,
+    pub rate_limit: Option<String>,
+    #[serde(default)]
+    pub actors: ScenarioActors,
+    #[serde(default)]
+    pub topics: ScenarioTopics,
+    #[serde(default)]
+    pub messages: ScenarioMessages,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioActors {
+    pub producers: Option<u32>,
+    pub consumers: Option<u32>,
+    pub consumer_groups: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioTopics {
+    pub start_stream_id: Option<u32>,
+    pub streams: Option<u32>,
+    pub partitions: Option<u32>,
+    pub max_topic_size: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioMessages {
+    pub size: Option<u32>,
+    pub per_batch: Option<u32>,
+    pub batches: Option<u32>,
+}
+
+fn default_transport() -> String {
+    "tcp".to_owned()
+}
+
+impl BenchmarkScenario {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| format!("Cannot read scenario file {}: {error}", path.display()))?;
+        let scenario: BenchmarkScenario = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => toml::from_str(&content).map_err(|error| error.to_string()),
+            Some("yaml") | Some("yml") => {
+                serde_yaml::from_str(&content).map_err(|error| error.to_string())
+            }
+            _ => Err("expected .toml, .yaml or .yml extension".to_owned()),
+        }
+        .map_err(|error| format!("Invalid scenario file {}: {error}", path.display()))?;
+
+        if scenario.phases.is_empty() {
+            return Err(format!(
+                "Scenario '{}' doesn't define any phases.",
+                scenario.name
+            ));
+        }
+
+        Ok(scenario)
+    }
+
+    /// Translates the phases into the benchmark arguments, applying the global options given on the command line.
+    pub fn phases_args(&self, args: &IggyBenchArgs) -> Result<Vec<IggyBenchArgs>, String> {
+        self.phases
+            .iter()
+            .map(|phase| {
+                let argv = self.phase_argv(phase, args);
+                let phase_args = IggyBenchArgs::try_parse_from(&argv).map_err(|error| {
+                    format!(
+                        "Invalid phase '{}' of scenario '{}': {error}",
+                        phase.name, self.name
+                    )
+                })?;
+                if matches!(
+                    phase_args.benchmark_kind,
+                    BenchmarkKindCommand::Scenario(_) | BenchmarkKindCommand::Examples
+                ) {
+                    return Err(format!(
+                        "Invalid phase '{}' of scenario '{}': '{}' is not a benchmark kind",
+                        phase.name, self.name, phase.kind
+                    ));
+                }
+                Ok(phase_args)
+            })
+            .collect()
+    }
+
+    fn phase_argv(&self, phase: &ScenarioPhase, args: &IggyBenchArgs) -> Vec<String> {
+        let mut argv = vec!["iggy-bench".to_owned()];
+        push_arg(&mut argv, "message-size", phase.messages.size);
+        push_arg(&mut argv, "messages-per-batch", phase.messages.per_batch);
+        push_arg(&mut argv, "message-batches", phase.messages.batches);
+        push_arg(&mut argv, "start-stream-id", phase.topics.start_stream_id);
+        push_arg(&mut argv, "rate-limit", phase.rate_limit.as_ref());
+        push_arg(&mut argv, "warmup-time", phase.warmup_time.as_ref());
+        push_arg(&mut argv, "sampling-time", phase.sampling_time.as_ref());
+        push_arg(
+            &mut argv,
+            "moving-average-window",
+            Some(args.moving_average_window),
+        );
+        push_arg(
+            &mut argv,
+            "server-stats-interval",
+            args.server_stats_interval,
+        );
+        push_arg(
+            &mut argv,
+            "server-executable-path",
+            args.server_executable_path.as_ref(),
+        );
+        push_flag(&mut argv, "verbose", args.verbose);
+        push_flag(&mut argv, "cleanup", args.cleanup);
+        push_flag(&mut argv, "skip-server-start", args.skip_server_start);
+
+        argv.push(phase.kind.clone());
+        push_arg(&mut argv, "streams", phase.topics.streams);
+        push_arg(&mut argv, "partitions", phase.topics.partitions);
+        push_arg(
+            &mut argv,
+            "max-topic-size",
+            phase.topics.max_topic_size.as_ref(),
+        );
+        push_arg(&mut argv, "producers", phase.actors.producers);
+        push_arg(&mut argv, "consumers", phase.actors.consumers);
+        push_arg(&mut argv, "consumer-groups", phase.actors.consumer_groups);
+
+        argv.push(self.transport.clone());
+        push_arg(&mut argv, "server-address", self.server_address.as_ref());
+
+        if self.output_dir.is_some() {
+            argv.push("output".to_owned());
+            push_arg(&mut argv, "output-dir", self.output_dir.as_ref());
+            push_arg(&mut argv, "identifier", self.identifier.as_ref());
+            // The remark tells apart the results of the phases.
+            push_arg(
+                &mut argv,
+                "remark",
+                Some(format!("{}-{}", self.name, phase.name)),
+            );
+            push_arg(&mut argv, "gitref", self.gitref.as_ref());
+            push_arg(&mut argv, "gitref-date", self.gitref_date.as_ref());
+        }
+
+        argv
+    }
+}
+
+fn push_arg<T: ToString>(argv: &mut Vec<String>, name: &str, value: Option<T>) {
+    if let Some(value) = value {
+        argv.push(format!("--{name}"));
+        argv.push(value.to_string());
+    }
+}
+
+fn push_flag(argv: &mut Vec<String>, name: &str, enabled: bool) {
+    if enabled {
+        argv.push(format!("--{name}"));
+    }
+}
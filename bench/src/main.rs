@@ -25,12 +25,13 @@ mod rate_limiter;
 mod runner;
 mod utils;
 
-use crate::{args::common::IggyBenchArgs, runner::BenchmarkRunner};
+use crate::args::{common::IggyBenchArgs, kind::BenchmarkKindCommand, scenario::BenchmarkScenario};
+use crate::runner::BenchmarkRunner;
 use clap::Parser;
 use figlet_rs::FIGfont;
 use iggy::error::IggyError;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tracing::{error, info};
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 use utils::cpu_name::append_cpu_name_lowercase;
@@ -42,29 +43,55 @@ async fn main() -> Result<(), IggyError> {
     println!("{}", figure.unwrap());
 
     let args = IggyBenchArgs::parse();
-    args.validate();
-
-    // Store output_dir before moving args
-    let output_dir = args.output_dir();
-    let benchmark_dir = output_dir.as_ref().map(|dir| {
-        let dir_path = Path::new(dir);
-        if !dir_path.exists() {
-            fs::create_dir_all(dir_path).unwrap();
+    let (log_file_name, phases) = match &args.benchmark_kind {
+        BenchmarkKindCommand::Scenario(scenario_args) => {
+            let phases = BenchmarkScenario::load(&scenario_args.file).and_then(|scenario| {
+                let phases = scenario.phases_args(&args)?;
+                Ok((format!("{}.log", scenario.name), phases))
+            });
+            match phases {
+                Ok(phases) => phases,
+                Err(error) => {
+                    eprintln!("{error}");
+                    std::process::exit(1);
+                }
+            }
         }
-        let mut dir_name = args.generate_dir_name();
-        append_cpu_name_lowercase(&mut dir_name);
-        dir_path.join(dir_name)
-    });
+        _ => ("bench.log".to_owned(), vec![args]),
+    };
+    for phase_args in &phases {
+        phase_args.validate();
+    }
+
+    // Store benchmark directories before moving args
+    let benchmark_dirs = phases
+        .iter()
+        .map(|phase_args| {
+            phase_args.output_dir().map(|dir| {
+                let dir_path = Path::new(&dir);
+                if !dir_path.exists() {
+                    fs::create_dir_all(dir_path).unwrap();
+                }
+                let mut dir_name = phase_args.generate_dir_name();
+                append_cpu_name_lowercase(&mut dir_name);
+                dir_path.join(dir_name)
+            })
+        })
+        .collect::<Vec<_>>();
 
     // Configure logging
     let env_filter = EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO"));
     let stdout_layer = fmt::layer().with_ansi(true);
 
-    // If output directory is specified, also log to file
-    if let Some(ref benchmark_dir) = benchmark_dir {
+    // If output directory is specified, also log to file, the scenario logs to the one shared by all phases
+    let log_dir = match benchmark_dirs.as_slice() {
+        [Some(benchmark_dir)] => Some(benchmark_dir.clone()),
+        _ => phases[0].output_dir().map(PathBuf::from),
+    };
+    if let Some(ref log_dir) = log_dir {
         // Create output directory if it doesn't exist
-        fs::create_dir_all(benchmark_dir).unwrap();
-        let file_appender = tracing_appender::rolling::never(benchmark_dir, "bench.log");
+        fs::create_dir_all(log_dir).unwrap();
+        let file_appender = tracing_appender::rolling::never(log_dir, log_file_name);
         let file_layer = fmt::layer().with_ansi(false).with_writer(file_appender);
 
         tracing_subscriber::registry()
@@ -79,27 +106,38 @@ async fn main() -> Result<(), IggyError> {
             .init();
     }
 
-    let mut benchmark_runner = BenchmarkRunner::new(args);
+    let phases_count = phases.len();
+    for (index, (args, benchmark_dir)) in phases.into_iter().zip(benchmark_dirs).enumerate() {
+        let mut benchmark_runner = BenchmarkRunner::new(args);
 
-    info!("Starting the benchmarks...");
-    let ctrl_c = tokio::signal::ctrl_c();
-    let benchmark_future = benchmark_runner.run();
+        if phases_count > 1 {
+            info!(
+                "Starting the benchmarks of phase {}/{phases_count}...",
+                index + 1
+            );
+        } else {
+            info!("Starting the benchmarks...");
+        }
+        let ctrl_c = tokio::signal::ctrl_c();
+        let benchmark_future = benchmark_runner.run();
 
-    tokio::select! {
-        _ = ctrl_c => {
-            info!("Received Ctrl-C, exiting...");
-            // Clean up unfinished benchmark directory on manual interruption
-            if let Some(ref benchmark_dir) = benchmark_dir {
-                info!("Cleaning up unfinished benchmark directory...");
-                if let Err(e) = std::fs::remove_dir_all(benchmark_dir) {
-                    error!("Failed to clean up benchmark directory: {}", e);
+        tokio::select! {
+            _ = ctrl_c => {
+                info!("Received Ctrl-C, exiting...");
+                // Clean up unfinished benchmark directory on manual interruption
+                if let Some(ref benchmark_dir) = benchmark_dir {
+                    info!("Cleaning up unfinished benchmark directory...");
+                    if let Err(e) = std::fs::remove_dir_all(benchmark_dir) {
+                        error!("Failed to clean up benchmark directory: {}", e);
+                    }
                 }
+                return Ok(());
             }
-        }
-        result = benchmark_future => {
-            if let Err(e) = result {
-                error!("Benchmark failed with error: {:?}", e);
-                return Err(e);
+            result = benchmark_future => {
+                if let Err(e) = result {
+                    error!("Benchmark failed with error: {:?}", e);
+                    return Err(e);
+                }
             }
         }
     }
@@ -17,6 +17,7 @@
  */
 
 use crate::analytics::report_builder::BenchmarkReportBuilder;
+use crate::analytics::server_usage::ServerUsageSampler;
 use crate::args::common::IggyBenchArgs;
 use crate::benchmarks::benchmark::Benchmarkable;
 use crate::plot::{plot_chart, ChartType};
@@ -54,6 +55,17 @@ impl BenchmarkRunner {
         let server_addr = args.server_address();
         info!("Starting to benchmark: {transport} with server: {server_addr}",);
 
+        let server_usage_sampler = match args.server_stats_interval() {
+            Some(interval) => {
+                let params = BenchmarkParams::from(&args);
+                ServerUsageSampler::start(&params.transport, &params.server_address, interval)
+                    .await
+                    .inspect_err(|e| error!("Failed to start scraping server stats: {e}"))
+                    .ok()
+            }
+            None => None,
+        };
+
         let mut benchmark: Box<dyn Benchmarkable> = args.into();
         let mut join_handles = benchmark.run().await?;
 
@@ -68,6 +80,10 @@ impl BenchmarkRunner {
         }
 
         info!("All actors joined!");
+        let server_usage = match server_usage_sampler {
+            Some(sampler) => sampler.stop().await,
+            None => None,
+        };
         let hardware =
             BenchmarkHardware::get_system_info_with_identifier(benchmark.args().identifier());
         let params = BenchmarkParams::from(benchmark.args());
@@ -78,6 +94,7 @@ impl BenchmarkRunner {
             hardware,
             params,
             individual_metrics,
+            server_usage,
             benchmark.args().moving_average_window(),
         )
         .await;
@@ -18,7 +18,7 @@
 
 use iggy::{
     client::{Client, SystemClient, UserClient},
-    clients::builder::IggyClientBuilder,
+    clients::{builder::IggyClientBuilder, client::IggyClient},
     error::IggyError,
     models::stats::Stats,
     snapshot::{SnapshotCompression, SystemSnapshotType},
@@ -32,10 +32,11 @@ pub mod client_factory;
 pub mod cpu_name;
 pub mod server_starter;
 
-pub async fn get_server_stats(
+/// Creates the client connected to the server and logged in as the root user.
+pub async fn connect_root_client(
     transport: &BenchmarkTransport,
     server_address: &str,
-) -> Result<Stats, IggyError> {
+) -> Result<IggyClient, IggyError> {
     let client = IggyClientBuilder::new();
 
     let client = match transport {
@@ -58,6 +59,15 @@ pub async fn get_server_stats(
         .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
         .await?;
 
+    Ok(client)
+}
+
+pub async fn get_server_stats(
+    transport: &BenchmarkTransport,
+    server_address: &str,
+) -> Result<Stats, IggyError> {
+    let client = connect_root_client(transport, server_address).await?;
+
     client.get_stats().await
 }
 
@@ -66,27 +76,7 @@ pub async fn collect_server_logs_and_save_to_file(
     server_address: &str,
     output_dir: &Path,
 ) -> Result<(), IggyError> {
-    let client = IggyClientBuilder::new();
-
-    let client = match transport {
-        BenchmarkTransport::Tcp => client
-            .with_tcp()
-            .with_server_address(server_address.to_string())
-            .build()?,
-        BenchmarkTransport::Http => client
-            .with_http()
-            .with_api_url(format!("http://{}", server_address))
-            .build()?,
-        BenchmarkTransport::Quic => client
-            .with_quic()
-            .with_server_address(server_address.to_string())
-            .build()?,
-    };
-
-    client.connect().await?;
-    client
-        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
-        .await?;
+    let client = connect_root_client(transport, server_address).await?;
 
     let snapshot = client
         .snapshot(
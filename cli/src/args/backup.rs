@@ -0,0 +1,104 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use clap::{Args, Subcommand, ValueEnum};
+use iggy::identifier::Identifier;
+use iggy::models::backup::BackupDestination;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum BackupAction {
+    /// Create consistent point-in-time backup of server metadata and selected streams
+    ///
+    /// Closed segments are hard-linked or copied, open segments are flushed and copied
+    /// up to their size at the time of taking the backup. Backup is written either to
+    /// the local archive directory or to the S3-compatible object storage configured
+    /// on the server, and can be restored with the `--restore` server argument.
+    ///
+    /// Stream IDs can be specified as stream names or IDs, all streams are archived
+    /// if none is specified. Incremental backup, taken on top of the base backup,
+    /// archives only the segments which are not archived by the base backup yet.
+    ///
+    /// Examples
+    ///  iggy backup create nightly
+    ///  iggy backup create before-upgrade 1 orders
+    ///  iggy backup create offsite prod --destination s3
+    ///  iggy backup create nightly-2 --base nightly
+    #[clap(verbatim_doc_comment, visible_alias = "c")]
+    Create(BackupCreateArgs),
+    /// Restore streams from backup into running server
+    ///
+    /// Streams are restored with their original IDs, names, topics, partitions,
+    /// consumer groups, message offsets and consumer offsets. None of the restored
+    /// streams can exist on the server. Backup is looked up in the local archive
+    /// directory first, and downloaded from the S3-compatible object storage if
+    /// not found there.
+    ///
+    /// Stream IDs can be specified as stream names or IDs, all archived streams
+    /// are restored if none is specified.
+    ///
+    /// Examples
+    ///  iggy backup restore nightly
+    ///  iggy backup restore nightly-2 1 orders
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Restore(BackupRestoreArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct BackupCreateArgs {
+    /// Name of the backup
+    ///
+    /// Name can contain alphanumeric characters, dots, dashes and underscores
+    pub(crate) name: String,
+    /// Stream IDs to archive, all streams are archived if none is specified
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) streams: Vec<Identifier>,
+    /// Destination of the backup
+    #[arg(short, long, value_enum, default_value_t = BackupDestinationArg::default())]
+    pub(crate) destination: BackupDestinationArg,
+    /// Name of the previous backup to take the incremental backup on top of
+    #[arg(short, long)]
+    pub(crate) base: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct BackupRestoreArgs {
+    /// Name of the backup to restore
+    pub(crate) name: String,
+    /// Stream IDs to restore, all archived streams are restored if none is specified
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) streams: Vec<Identifier>,
+}
+
+#[derive(Debug, Clone, Default, ValueEnum, PartialEq)]
+pub(crate) enum BackupDestinationArg {
+    #[default]
+    Local,
+    S3,
+}
+
+impl From<BackupDestinationArg> for BackupDestination {
+    fn from(value: BackupDestinationArg) -> Self {
+        match value {
+            BackupDestinationArg::Local => BackupDestination::Local,
+            BackupDestinationArg::S3 => BackupDestination::S3,
+        }
+    }
+}
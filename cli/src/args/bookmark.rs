@@ -0,0 +1,147 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::common::ListMode;
+use clap::{Args, Subcommand};
+use iggy::identifier::Identifier;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum BookmarkAction {
+    /// List all bookmarks for given stream ID and topic ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy bookmark list 1 1
+    ///  iggy bookmark list stream 2 --list-mode table
+    ///  iggy bookmark list 3 topic -l table
+    ///  iggy bookmark list stream topic -l table
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    List(BookmarkListArgs),
+    /// Create the bookmark or move the existing one to a given partition and offset
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Offset points to the next message to be polled from the bookmark
+    ///
+    /// Examples
+    ///  iggy bookmark set 1 1 audit 1 0
+    ///  iggy bookmark set stream topic audit 2 100
+    #[clap(verbatim_doc_comment, visible_alias = "s")]
+    Set(BookmarkSetArgs),
+    /// Delete the bookmark with given name for given stream ID and topic ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy bookmark delete 1 1 audit
+    ///  iggy bookmark delete stream topic audit
+    #[clap(verbatim_doc_comment, visible_alias = "d")]
+    Delete(BookmarkDeleteArgs),
+    /// Poll messages starting at the bookmark and move it past the last polled message
+    ///
+    /// Consumer offsets are not affected, thus the bookmark works as an independent cursor.
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy bookmark poll 1 1 audit
+    ///  iggy bookmark poll stream topic audit --message-count 10
+    ///  iggy bookmark poll stream topic audit --peek
+    #[clap(verbatim_doc_comment, visible_alias = "p")]
+    Poll(BookmarkPollArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct BookmarkListArgs {
+    /// Stream ID to list bookmarks
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to list bookmarks
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct BookmarkSetArgs {
+    /// Stream ID for which the bookmark is set
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID for which the bookmark is set
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Name of the bookmark
+    pub(crate) name: String,
+    /// Partition ID on which the bookmark is set
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+    /// Offset of the next message to be polled from the bookmark
+    pub(crate) offset: u64,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct BookmarkDeleteArgs {
+    /// Stream ID for which the bookmark is deleted
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID for which the bookmark is deleted
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Name of the bookmark to delete
+    pub(crate) name: String,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct BookmarkPollArgs {
+    /// Stream ID from which messages are polled
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID from which messages are polled
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Name of the bookmark to poll from
+    pub(crate) name: String,
+    /// Number of messages to poll
+    #[clap(verbatim_doc_comment, short, long, default_value_t = 1)]
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) message_count: u32,
+    /// Poll the messages without moving the bookmark
+    #[clap(short, long, default_value_t = false)]
+    pub(crate) peek: bool,
+}
@@ -17,11 +17,16 @@
  */
 
 use clap::ValueEnum;
+use iggy::cli::bookmark::get_bookmarks::GetBookmarksOutput;
 use iggy::cli::client::get_clients::GetClientsOutput;
 use iggy::cli::consumer_group::get_consumer_groups::GetConsumerGroupsOutput;
 use iggy::cli::context::get_contexts::GetContextsOutput;
 use iggy::cli::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokensOutput;
+use iggy::cli::quotas::get_quotas::GetQuotasOutput;
+use iggy::cli::schemas::get_schemas::GetSchemasOutput;
 use iggy::cli::streams::get_streams::GetStreamsOutput;
+use iggy::cli::system::audit::GetAuditLogOutput;
+use iggy::cli::system::config::GetConfigOutput;
 use iggy::cli::system::stats::GetStatsOutput;
 use iggy::cli::topics::get_topics::GetTopicsOutput;
 use iggy::cli::users::get_users::GetUsersOutput;
@@ -32,6 +37,42 @@ pub(crate) enum ListMode {
     List,
 }
 
+impl From<ListMode> for GetBookmarksOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetBookmarksOutput::Table,
+            ListMode::List => GetBookmarksOutput::List,
+        }
+    }
+}
+
+impl From<ListMode> for GetQuotasOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetQuotasOutput::Table,
+            ListMode::List => GetQuotasOutput::List,
+        }
+    }
+}
+
+impl From<ListMode> for GetAuditLogOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetAuditLogOutput::Table,
+            ListMode::List => GetAuditLogOutput::List,
+        }
+    }
+}
+
+impl From<ListMode> for GetConfigOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetConfigOutput::Table,
+            ListMode::List => GetConfigOutput::List,
+        }
+    }
+}
+
 impl From<ListMode> for GetStreamsOutput {
     fn from(mode: ListMode) -> Self {
         match mode {
@@ -86,6 +127,15 @@ impl From<ListMode> for GetConsumerGroupsOutput {
     }
 }
 
+impl From<ListMode> for GetSchemasOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetSchemasOutput::Table,
+            ListMode::List => GetSchemasOutput::List,
+        }
+    }
+}
+
 impl From<ListMode> for GetContextsOutput {
     fn from(mode: ListMode) -> Self {
         match mode {
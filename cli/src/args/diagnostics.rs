@@ -0,0 +1,47 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use clap::{Args, Subcommand};
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum DiagnosticsAction {
+    /// Collect redacted support bundle
+    ///
+    /// Command collects the server version, statistics, effective configuration,
+    /// enabled features, topology summary and recent server logs into a single
+    /// zip archive, which can be attached to the bug report. Secrets found in the
+    /// configuration and logs are masked. Parts which cannot be collected
+    /// (e.g. due to missing permissions) are listed in the errors.txt entry.
+    ///
+    /// Examples
+    ///  iggy diagnostics collect
+    ///  iggy diagnostics collect --out-dir /tmp --log-lines 5000
+    #[clap(verbatim_doc_comment, visible_alias = "c")]
+    Collect(DiagnosticsCollectArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct DiagnosticsCollectArgs {
+    /// Output directory for the support bundle
+    #[arg(short, long)]
+    pub(crate) out_dir: Option<String>,
+
+    /// Number of the most recent server log lines to include
+    #[arg(short, long)]
+    pub(crate) log_lines: Option<usize>,
+}
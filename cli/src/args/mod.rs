@@ -25,19 +25,25 @@ use figlet_rs::FIGfont;
 
 use iggy::args::{Args as IggyArgs, ArgsOptional as IggyArgsOptional};
 use iggy::cli::context::common::ContextConfig;
+use iggy::utils::duration::IggyDuration;
 use segment::SegmentAction;
 use system::SnapshotArgs;
 
 use crate::args::{
+    backup::BackupAction,
+    bookmark::BookmarkAction,
     client::ClientAction,
     consumer_group::ConsumerGroupAction,
     consumer_offset::ConsumerOffsetAction,
     context::ContextAction,
+    diagnostics::DiagnosticsAction,
     message::MessageAction,
     partition::PartitionAction,
     personal_access_token::PersonalAccessTokenAction,
+    quota::QuotaAction,
+    schema::SchemaAction,
     stream::StreamAction,
-    system::{PingArgs, StatsArgs},
+    system::{AuditArgs, ConfigArgs, PingArgs, StatsArgs},
     topic::TopicAction,
 };
 
@@ -46,15 +52,20 @@ use crate::args::system::LoginArgs;
 
 use self::user::UserAction;
 
+pub(crate) mod backup;
+pub(crate) mod bookmark;
 pub(crate) mod client;
 pub(crate) mod common;
 pub(crate) mod consumer_group;
 pub(crate) mod consumer_offset;
 pub(crate) mod context;
+pub(crate) mod diagnostics;
 pub(crate) mod message;
 pub(crate) mod partition;
 pub(crate) mod permissions;
 pub(crate) mod personal_access_token;
+pub(crate) mod quota;
+pub(crate) mod schema;
 pub(crate) mod segment;
 pub(crate) mod stream;
 pub(crate) mod system;
@@ -128,6 +139,44 @@ pub(crate) struct CliOptions {
     #[clap(verbatim_doc_comment)]
     #[clap(long = "generate", value_enum)]
     pub(crate) generator: Option<Shell>,
+
+    /// JSON-RPC mode for the tooling integration
+    ///
+    /// Option makes the tool read JSON-RPC 2.0 requests from standard input, one per line,
+    /// execute them using a single connection to the server, and write the responses
+    /// to standard output, one per line. Method is a command with its subcommands joined
+    /// by a dot and params are its remaining arguments. Response result contains
+    /// the executed command and its output lines.
+    /// Option cannot be combined with commands and quiet mode.
+    ///
+    /// Example:
+    ///  {"jsonrpc": "2.0", "id": 1, "method": "topic.list", "params": ["stream"]}
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, conflicts_with = "quiet")]
+    pub(crate) json_rpc: bool,
+
+    /// Time to live of the cached topology listings
+    ///
+    /// Option makes the tool cache the streams, topics and consumer groups returned
+    /// by the list and get commands in the local file (separately for each server address)
+    /// and reuse them until they expire, so the commands respond instantly even against
+    /// the servers with tens of thousands of entities. Cache is cleared by the commands
+    /// changing the streams, topics, partitions or consumer groups.
+    /// Cache is disabled by default.
+    ///
+    /// Example:
+    ///  iggy --cache-ttl 5m topic list stream
+    #[clap(verbatim_doc_comment)]
+    #[clap(long)]
+    pub(crate) cache_ttl: Option<IggyDuration>,
+
+    /// Refresh the cached topology listings
+    ///
+    /// Option makes the tool fetch the listings from the server and store them
+    /// in the cache, even if the cached ones haven't expired yet.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, requires = "cache_ttl")]
+    pub(crate) refresh: bool,
 }
 
 #[derive(Debug, Clone, Subcommand)]
@@ -161,9 +210,28 @@ pub(crate) enum Command {
     /// Server OS name, version, etc. are also collected.
     #[clap(verbatim_doc_comment)]
     Stats(StatsArgs),
+    /// get effective iggy server configuration
+    ///
+    /// Collect all the configuration values the server is running with, along with
+    /// their sources (default, file, environment or runtime). Secrets are masked.
+    #[clap(verbatim_doc_comment)]
+    Config(ConfigArgs),
+    /// get audit log of administrative actions
+    ///
+    /// Collect the most recent administrative actions (e.g. creating or deleting streams,
+    /// topics and users, permission changes, purges) recorded by the server, along with
+    /// the user, IP address, timestamp and outcome of each of them.
+    #[clap(verbatim_doc_comment)]
+    Audit(AuditArgs),
     /// collect iggy server troubleshooting data
     #[clap(verbatim_doc_comment)]
     Snapshot(SnapshotArgs),
+    /// backup operations
+    #[command(subcommand)]
+    Backup(BackupAction),
+    /// diagnostics operations
+    #[command(subcommand, visible_alias = "diag")]
+    Diagnostics(DiagnosticsAction),
     /// personal access token operations
     #[command(subcommand)]
     Pat(PersonalAccessTokenAction),
@@ -179,9 +247,18 @@ pub(crate) enum Command {
     /// consumer offset operations
     #[command(subcommand, visible_alias = "o")]
     ConsumerOffset(ConsumerOffsetAction),
+    /// bookmark operations
+    #[command(subcommand, visible_alias = "b")]
+    Bookmark(BookmarkAction),
     /// message operations
     #[command(subcommand, visible_alias = "m")]
     Message(MessageAction),
+    /// schema registry operations
+    #[command(subcommand, visible_alias = "sc")]
+    Schema(SchemaAction),
+    /// throughput quota operations
+    #[command(subcommand, visible_alias = "q")]
+    Quota(QuotaAction),
     /// context operations
     #[command(subcommand, visible_alias = "ctx")]
     Context(ContextAction),
@@ -254,6 +331,9 @@ impl IggyMergedConsoleArgs {
             #[cfg(feature = "login-session")]
             token_name: args.cli.token_name.or(context.token_name),
             generator: args.cli.generator,
+            json_rpc: args.cli.json_rpc,
+            cache_ttl: args.cli.cache_ttl,
+            refresh: args.cli.refresh,
         };
 
         Self {
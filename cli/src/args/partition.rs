@@ -18,6 +18,7 @@
 
 use clap::{Args, Subcommand};
 use iggy::identifier::Identifier;
+use iggy::partitions::key_routing_policy::KeyRoutingPolicy;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum PartitionAction {
@@ -32,6 +33,7 @@ pub(crate) enum PartitionAction {
     ///  iggy partition create prod 2 2
     ///  iggy partition create test sensor 2
     ///  iggy partition create 1 sensor 16
+    ///  iggy partition create --key-routing-policy preserve_existing prod sensor 4
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(PartitionCreateArgs),
     /// Delete partitions for the specified topic ID
@@ -47,6 +49,47 @@ pub(crate) enum PartitionAction {
     ///  iggy partition delete 1 sensor 16
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(PartitionDeleteArgs),
+    /// Get details of a single partition with given ID (or name)
+    /// for the specified topic ID and stream ID.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Partition ID can be specified as a partition name or ID
+    ///
+    /// Examples
+    ///  iggy partition get 1 1 1
+    ///  iggy partition get prod sensor 2
+    ///  iggy partition get prod sensor orders-eu
+    #[clap(verbatim_doc_comment, visible_alias = "g")]
+    Get(PartitionGetArgs),
+    /// Update name of a partition with given ID (or name)
+    /// for the specified topic ID and stream ID.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Partition ID can be specified as a partition name or ID
+    /// When name is not provided, the current partition name is removed
+    ///
+    /// Examples
+    ///  iggy partition update 1 1 1 orders-eu
+    ///  iggy partition update prod sensor orders-eu orders-us
+    ///  iggy partition update prod sensor orders-us
+    #[clap(verbatim_doc_comment, visible_alias = "u")]
+    Update(PartitionUpdateArgs),
+    /// Move data of a partition with given ID (or name)
+    /// to another data directory configured on the server.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Partition ID can be specified as a partition name or ID
+    /// The data is copied in the background, and the partition
+    /// is switched to the new directory once it's copied
+    ///
+    /// Examples
+    ///  iggy partition move 1 1 1 /mnt/disk2/iggy
+    ///  iggy partition move prod sensor orders-eu /mnt/disk2/iggy
+    #[clap(verbatim_doc_comment, visible_alias = "m")]
+    Move(PartitionMoveArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -64,6 +107,12 @@ pub(crate) struct PartitionCreateArgs {
     /// Partitions count to be created
     #[arg(value_parser = clap::value_parser!(u32).range(1..100_001))]
     pub(crate) partitions_count: u32,
+    /// Policy for the messages keys already routed to the existing partitions
+    ///
+    /// "rehash" re-hashes all the keys across the new number of partitions (existing keys might be moved),
+    /// "preserve_existing" keeps the existing keys in their partitions and routes only the new keys to all the partitions.
+    #[arg(short, long, default_value = "rehash", value_parser = clap::value_parser!(KeyRoutingPolicy), verbatim_doc_comment)]
+    pub(crate) key_routing_policy: KeyRoutingPolicy,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -82,3 +131,65 @@ pub(crate) struct PartitionDeleteArgs {
     #[arg(value_parser = clap::value_parser!(u32).range(1..100_001))]
     pub(crate) partitions_count: u32,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionGetArgs {
+    /// Stream ID to get partition
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to get partition
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID to get
+    ///
+    /// Partition ID can be specified as a partition name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) partition_id: Identifier,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionUpdateArgs {
+    /// Stream ID to update partition
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to update partition
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID to update
+    ///
+    /// Partition ID can be specified as a partition name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) partition_id: Identifier,
+    /// New partition name, unique within the topic
+    pub(crate) name: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionMoveArgs {
+    /// Stream ID to move partition
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to move partition
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID to move
+    ///
+    /// Partition ID can be specified as a partition name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) partition_id: Identifier,
+    /// Target data directory, either the system path
+    /// or one of the data directories configured on the server
+    pub(crate) directory: String,
+}
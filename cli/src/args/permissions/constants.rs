@@ -20,6 +20,8 @@ pub(crate) const MANAGE_SERVERS_SHORT: &str = "m_srv";
 pub(crate) const MANAGE_SERVERS_LONG: &str = "manage_servers";
 pub(crate) const READ_SERVERS_SHORT: &str = "r_srv";
 pub(crate) const READ_SERVERS_LONG: &str = "read_servers";
+pub(crate) const MANAGE_CONFIG_SHORT: &str = "m_cfg";
+pub(crate) const MANAGE_CONFIG_LONG: &str = "manage_config";
 pub(crate) const MANAGE_USERS_SHORT: &str = "m_usr";
 pub(crate) const MANAGE_USERS_LONG: &str = "manage_users";
 pub(crate) const READ_USERS_SHORT: &str = "r_usr";
@@ -44,3 +46,9 @@ pub(crate) const MANAGE_TOPIC_SHORT: &str = "m_top";
 pub(crate) const MANAGE_TOPIC_LONG: &str = "manage_topic";
 pub(crate) const READ_TOPIC_SHORT: &str = "r_top";
 pub(crate) const READ_TOPIC_LONG: &str = "read_topic";
+pub(crate) const ADMIN_SHORT: &str = "a";
+pub(crate) const ADMIN_LONG: &str = "admin";
+pub(crate) const READ_SHORT: &str = "r";
+pub(crate) const READ_LONG: &str = "read";
+pub(crate) const WRITE_SHORT: &str = "w";
+pub(crate) const WRITE_LONG: &str = "write";
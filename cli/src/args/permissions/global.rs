@@ -17,11 +17,11 @@
  */
 
 use super::constants::{
-    MANAGE_SERVERS_LONG, MANAGE_SERVERS_SHORT, MANAGE_STREAMS_LONG, MANAGE_STREAMS_SHORT,
-    MANAGE_TOPICS_LONG, MANAGE_TOPICS_SHORT, MANAGE_USERS_LONG, MANAGE_USERS_SHORT,
-    POLL_MESSAGES_LONG, POLL_MESSAGES_SHORT, READ_SERVERS_LONG, READ_SERVERS_SHORT,
-    READ_STREAMS_LONG, READ_STREAMS_SHORT, READ_TOPICS_LONG, READ_TOPICS_SHORT, READ_USERS_LONG,
-    READ_USERS_SHORT, SEND_MESSAGES_LONG, SEND_MESSAGES_SHORT,
+    MANAGE_CONFIG_LONG, MANAGE_CONFIG_SHORT, MANAGE_SERVERS_LONG, MANAGE_SERVERS_SHORT,
+    MANAGE_STREAMS_LONG, MANAGE_STREAMS_SHORT, MANAGE_TOPICS_LONG, MANAGE_TOPICS_SHORT,
+    MANAGE_USERS_LONG, MANAGE_USERS_SHORT, POLL_MESSAGES_LONG, POLL_MESSAGES_SHORT,
+    READ_SERVERS_LONG, READ_SERVERS_SHORT, READ_STREAMS_LONG, READ_STREAMS_SHORT, READ_TOPICS_LONG,
+    READ_TOPICS_SHORT, READ_USERS_LONG, READ_USERS_SHORT, SEND_MESSAGES_LONG, SEND_MESSAGES_SHORT,
 };
 use iggy::models::permissions::GlobalPermissions;
 use std::str::FromStr;
@@ -30,6 +30,7 @@ use std::str::FromStr;
 pub(super) enum GlobalPermission {
     ManageServers,
     ReadServers,
+    ManageConfig,
     ManageUsers,
     ReadUsers,
     ManageStreams,
@@ -50,6 +51,7 @@ impl FromStr for GlobalPermission {
         match s {
             MANAGE_SERVERS_SHORT | MANAGE_SERVERS_LONG => Ok(GlobalPermission::ManageServers),
             READ_SERVERS_SHORT | READ_SERVERS_LONG => Ok(GlobalPermission::ReadServers),
+            MANAGE_CONFIG_SHORT | MANAGE_CONFIG_LONG => Ok(GlobalPermission::ManageConfig),
             MANAGE_USERS_SHORT | MANAGE_USERS_LONG => Ok(GlobalPermission::ManageUsers),
             READ_USERS_SHORT | READ_USERS_LONG => Ok(GlobalPermission::ReadUsers),
             MANAGE_STREAMS_SHORT | MANAGE_STREAMS_LONG => Ok(GlobalPermission::ManageStreams),
@@ -92,6 +94,7 @@ impl GlobalPermissionsArg {
         match permission {
             GlobalPermission::ManageServers => self.permissions.manage_servers = true,
             GlobalPermission::ReadServers => self.permissions.read_servers = true,
+            GlobalPermission::ManageConfig => self.permissions.manage_config = true,
             GlobalPermission::ManageUsers => self.permissions.manage_users = true,
             GlobalPermission::ReadUsers => self.permissions.read_users = true,
             GlobalPermission::ManageStreams => self.permissions.manage_streams = true,
@@ -149,6 +152,10 @@ mod tests {
             GlobalPermission::from_str("read_servers").unwrap(),
             GlobalPermission::ReadServers
         );
+        assert_eq!(
+            GlobalPermission::from_str("manage_config").unwrap(),
+            GlobalPermission::ManageConfig
+        );
         assert_eq!(
             GlobalPermission::from_str("manage_users").unwrap(),
             GlobalPermission::ManageUsers
@@ -193,6 +200,10 @@ mod tests {
             GlobalPermission::from_str("r_srv").unwrap(),
             GlobalPermission::ReadServers
         );
+        assert_eq!(
+            GlobalPermission::from_str("m_cfg").unwrap(),
+            GlobalPermission::ManageConfig
+        );
         assert_eq!(
             GlobalPermission::from_str("m_usr").unwrap(),
             GlobalPermission::ManageUsers
@@ -262,12 +273,13 @@ mod tests {
     #[test]
     fn should_deserialize_permissions() {
         assert_eq!(
-            GlobalPermissionsArg::from_str("manage_servers,read_servers,manage_users,read_users,manage_streams,read_streams,manage_topics,read_topics,poll_messages,send_messages")
+            GlobalPermissionsArg::from_str("manage_servers,read_servers,manage_config,manage_users,read_users,manage_streams,read_streams,manage_topics,read_topics,poll_messages,send_messages")
                 .unwrap(),
             GlobalPermissionsArg {
                 permissions: GlobalPermissions {
                     manage_servers: true,
                     read_servers: true,
+                    manage_config: true,
                     manage_users: true,
                     read_users: true,
                     manage_streams: true,
@@ -285,6 +297,7 @@ mod tests {
                 permissions: GlobalPermissions {
                     manage_servers: false,
                     read_servers: false,
+                    manage_config: false,
                     manage_users: false,
                     read_users: false,
                     manage_streams: false,
@@ -302,6 +315,7 @@ mod tests {
                 permissions: GlobalPermissions {
                     manage_servers: true,
                     read_servers: false,
+                    manage_config: false,
                     manage_users: false,
                     read_users: false,
                     manage_streams: false,
@@ -319,13 +333,14 @@ mod tests {
     fn should_deserialize_short_permissions() {
         assert_eq!(
             GlobalPermissionsArg::from_str(
-                "m_srv,r_srv,m_usr,r_usr,m_str,r_str,m_top,r_top,p_msg,s_msg"
+                "m_srv,r_srv,m_cfg,m_usr,r_usr,m_str,r_str,m_top,r_top,p_msg,s_msg"
             )
             .unwrap(),
             GlobalPermissionsArg {
                 permissions: GlobalPermissions {
                     manage_servers: true,
                     read_servers: true,
+                    manage_config: true,
                     manage_users: true,
                     read_users: true,
                     manage_streams: true,
@@ -343,6 +358,7 @@ mod tests {
                 permissions: GlobalPermissions {
                     manage_servers: false,
                     read_servers: false,
+                    manage_config: false,
                     manage_users: false,
                     read_users: false,
                     manage_streams: false,
@@ -360,6 +376,7 @@ mod tests {
                 permissions: GlobalPermissions {
                     manage_servers: true,
                     read_servers: false,
+                    manage_config: false,
                     manage_users: false,
                     read_users: false,
                     manage_streams: false,
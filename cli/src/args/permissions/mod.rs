@@ -16,7 +16,9 @@
  * under the License.
  */
 
-use self::{global::GlobalPermissionsArg, stream::StreamPermissionsArg};
+use self::{
+    global::GlobalPermissionsArg, pattern::PatternPermissionsArg, stream::StreamPermissionsArg,
+};
 use ahash::AHashMap;
 use clap::ValueEnum;
 use iggy::models::{
@@ -26,22 +28,26 @@ use iggy::models::{
 
 pub(crate) mod constants;
 pub(crate) mod global;
+pub(crate) mod pattern;
 pub(crate) mod stream;
 pub(crate) mod topic;
 
 pub(crate) struct PermissionsArgs {
     global: Option<GlobalPermissionsArg>,
     stream: Vec<StreamPermissionsArg>,
+    pattern: Vec<PatternPermissionsArg>,
 }
 
 impl PermissionsArgs {
     pub(crate) fn new(
         global: Option<GlobalPermissionsArg>,
         stream: Option<Vec<StreamPermissionsArg>>,
+        pattern: Option<Vec<PatternPermissionsArg>>,
     ) -> Self {
         Self {
             global,
             stream: stream.unwrap_or_default(),
+            pattern: pattern.unwrap_or_default(),
         }
     }
 }
@@ -54,19 +60,36 @@ impl From<PermissionsArgs> for Option<Permissions> {
             .map(|s| (s.stream_id, s.into()))
             .collect::<AHashMap<u32, StreamPermissions>>();
 
-        match (value.global, stream_permissions.is_empty()) {
-            (Some(global), true) => Some(Permissions {
+        let pattern_permissions = match value.pattern.is_empty() {
+            true => None,
+            false => Some(value.pattern.into_iter().map(|p| p.into()).collect()),
+        };
+
+        match (
+            value.global,
+            stream_permissions.is_empty(),
+            pattern_permissions,
+        ) {
+            (Some(global), true, patterns) => Some(Permissions {
                 global: global.into(),
                 streams: None,
+                patterns,
             }),
-            (Some(global), false) => Some(Permissions {
+            (Some(global), false, patterns) => Some(Permissions {
                 global: global.into(),
                 streams: Some(stream_permissions),
+                patterns,
+            }),
+            (None, true, None) => None,
+            (None, true, patterns) => Some(Permissions {
+                global: Default::default(),
+                streams: None,
+                patterns,
             }),
-            (None, true) => None,
-            (None, false) => Some(Permissions {
+            (None, false, patterns) => Some(Permissions {
                 global: Default::default(),
                 streams: Some(stream_permissions),
+                patterns,
             }),
         }
     }
@@ -97,10 +120,11 @@ impl From<UserStatusArg> for UserStatus {
 mod tests {
     use super::*;
     use crate::args::permissions::global::GlobalPermission;
+    use iggy::models::permissions::PatternPermissions;
 
     #[test]
     fn should_convert_empty_permissions_args() {
-        let permissions: Option<Permissions> = Option::from(PermissionsArgs::new(None, None));
+        let permissions: Option<Permissions> = Option::from(PermissionsArgs::new(None, None, None));
         assert_eq!(permissions, None);
     }
 
@@ -108,7 +132,7 @@ mod tests {
     fn should_convert_only_global_permissions_args() {
         let global = GlobalPermissionsArg::new(vec![GlobalPermission::ManageServers]);
         let permissions_args: Option<Permissions> =
-            Option::from(PermissionsArgs::new(Some(global), None));
+            Option::from(PermissionsArgs::new(Some(global), None, None));
 
         let mut permissions = Permissions::default();
         permissions.global.manage_servers = true;
@@ -119,7 +143,7 @@ mod tests {
     fn should_convert_only_stream_permissions_args() {
         let stream = StreamPermissionsArg::new(1, vec![], vec![]);
         let permissions_args: Option<Permissions> =
-            Option::from(PermissionsArgs::new(None, Some(vec![stream])));
+            Option::from(PermissionsArgs::new(None, Some(vec![stream]), None));
 
         let permissions = Permissions {
             streams: Some(AHashMap::from([(1, StreamPermissions::default())])),
@@ -133,7 +157,7 @@ mod tests {
         let global = GlobalPermissionsArg::new(vec![GlobalPermission::ManageTopics]);
         let stream = StreamPermissionsArg::new(1, vec![], vec![]);
         let permissions_args: Option<Permissions> =
-            Option::from(PermissionsArgs::new(Some(global), Some(vec![stream])));
+            Option::from(PermissionsArgs::new(Some(global), Some(vec![stream]), None));
 
         let mut permissions = Permissions {
             streams: Some(AHashMap::from([(1, StreamPermissions::default())])),
@@ -143,6 +167,19 @@ mod tests {
         assert_eq!(permissions_args, Some(permissions));
     }
 
+    #[test]
+    fn should_convert_only_pattern_permissions_args() {
+        let pattern = "orders.*:r,w".parse::<PatternPermissionsArg>().unwrap();
+        let permissions_args: Option<Permissions> =
+            Option::from(PermissionsArgs::new(None, None, Some(vec![pattern])));
+
+        let permissions = Permissions {
+            patterns: Some(vec![PatternPermissions::new("orders.*", false, true, true)]),
+            ..Default::default()
+        };
+        assert_eq!(permissions_args, Some(permissions));
+    }
+
     #[test]
     fn should_deserialize_user_status() {
         assert_eq!(
@@ -0,0 +1,217 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use super::constants::{ADMIN_LONG, ADMIN_SHORT, READ_LONG, READ_SHORT, WRITE_LONG, WRITE_SHORT};
+use iggy::models::permissions::PatternPermissions;
+use iggy::validatable::Validatable;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq)]
+enum PatternPermission {
+    Admin,
+    Read,
+    Write,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct PatternPermissionError(String);
+
+impl FromStr for PatternPermission {
+    type Err = PatternPermissionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            ADMIN_SHORT | ADMIN_LONG => Ok(PatternPermission::Admin),
+            READ_SHORT | READ_LONG => Ok(PatternPermission::Read),
+            WRITE_SHORT | WRITE_LONG => Ok(PatternPermission::Write),
+            "" => Err(PatternPermissionError("[empty]".to_owned())),
+            _ => Err(PatternPermissionError(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct PatternPermissionsArg {
+    pub(crate) permissions: PatternPermissions,
+}
+
+impl From<PatternPermissionsArg> for PatternPermissions {
+    fn from(cmd: PatternPermissionsArg) -> Self {
+        cmd.permissions
+    }
+}
+
+impl PatternPermissionsArg {
+    fn new(pattern: &str, pattern_permissions: Vec<PatternPermission>) -> Self {
+        let mut result = Self {
+            permissions: PatternPermissions {
+                pattern: pattern.to_owned(),
+                ..Default::default()
+            },
+        };
+
+        for permission in pattern_permissions {
+            result.set_permission(permission);
+        }
+
+        result
+    }
+
+    fn set_permission(&mut self, permission: PatternPermission) {
+        match permission {
+            PatternPermission::Admin => self.permissions.admin = true,
+            PatternPermission::Read => self.permissions.read = true,
+            PatternPermission::Write => self.permissions.write = true,
+        }
+    }
+}
+
+impl FromStr for PatternPermissionsArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pattern, permissions_str) = match s.rsplit_once(':') {
+            Some((pattern, permissions_str)) => (pattern, Some(permissions_str)),
+            None => (s, None),
+        };
+
+        let permissions: Vec<PatternPermission> = match permissions_str {
+            Some(permissions_str) => {
+                let (values, errors): (Vec<_>, Vec<_>) = permissions_str
+                    .split(',')
+                    .map(|s| s.parse::<PatternPermission>())
+                    .partition(Result::is_ok);
+
+                if !errors.is_empty() {
+                    let errors = errors
+                        .into_iter()
+                        .map(|e| format!("\"{}\"", e.err().unwrap().0))
+                        .collect::<Vec<String>>();
+
+                    return Err(format!(
+                        "Unknown permission{} {} for pattern: {}",
+                        match errors.len() {
+                            1 => "",
+                            _ => "s",
+                        },
+                        errors.join(", "),
+                        pattern
+                    ));
+                }
+
+                values.into_iter().map(|p| p.unwrap()).collect()
+            }
+            None => vec![],
+        };
+
+        let result = PatternPermissionsArg::new(pattern, permissions);
+        result
+            .permissions
+            .validate()
+            .map_err(|_| format!("Invalid pattern: \"{}\"", pattern))?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_deserialize_single_permission() {
+        assert_eq!(
+            PatternPermission::from_str("admin").unwrap(),
+            PatternPermission::Admin
+        );
+        assert_eq!(
+            PatternPermission::from_str("read").unwrap(),
+            PatternPermission::Read
+        );
+        assert_eq!(
+            PatternPermission::from_str("write").unwrap(),
+            PatternPermission::Write
+        );
+    }
+
+    #[test]
+    fn should_deserialize_single_short_permission() {
+        assert_eq!(
+            PatternPermission::from_str("a").unwrap(),
+            PatternPermission::Admin
+        );
+        assert_eq!(
+            PatternPermission::from_str("r").unwrap(),
+            PatternPermission::Read
+        );
+        assert_eq!(
+            PatternPermission::from_str("w").unwrap(),
+            PatternPermission::Write
+        );
+    }
+
+    #[test]
+    fn should_not_deserialize_single_permission() {
+        let wrong_permission = PatternPermission::from_str("manage");
+        assert!(wrong_permission.is_err());
+        assert_eq!(
+            wrong_permission.unwrap_err(),
+            PatternPermissionError("manage".to_owned())
+        );
+        let empty_permission = PatternPermission::from_str("");
+        assert!(empty_permission.is_err());
+        assert_eq!(
+            empty_permission.unwrap_err(),
+            PatternPermissionError("[empty]".to_owned())
+        );
+    }
+
+    #[test]
+    fn should_deserialize_permissions() {
+        assert_eq!(
+            PatternPermissionsArg::from_str("orders.*:admin,read,write").unwrap(),
+            PatternPermissionsArg {
+                permissions: PatternPermissions::new("orders.*", true, true, true),
+            }
+        );
+        assert_eq!(
+            PatternPermissionsArg::from_str("*.audit:r").unwrap(),
+            PatternPermissionsArg {
+                permissions: PatternPermissions::new("*.audit", false, true, false),
+            }
+        );
+        assert_eq!(
+            PatternPermissionsArg::from_str("orders").unwrap(),
+            PatternPermissionsArg {
+                permissions: PatternPermissions::new("orders", false, false, false),
+            }
+        );
+    }
+
+    #[test]
+    fn should_not_deserialize_permissions() {
+        let wrong_permissions = PatternPermissionsArg::from_str("orders.*:r,manage,x");
+        assert!(wrong_permissions.is_err());
+        assert_eq!(
+            wrong_permissions.unwrap_err(),
+            "Unknown permissions \"manage\", \"x\" for pattern: orders.*"
+        );
+        let empty_pattern = PatternPermissionsArg::from_str(":r");
+        assert!(empty_pattern.is_err());
+        assert_eq!(empty_pattern.unwrap_err(), "Invalid pattern: \"\"");
+    }
+}
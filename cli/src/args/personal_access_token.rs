@@ -32,6 +32,8 @@ pub(crate) enum PersonalAccessTokenAction {
     ///  iggy pat create name
     ///  iggy pat create client 1day
     ///  iggy pat create sensor 3weeks
+    ///  iggy pat create dashboard --read-only --streams 1,2
+    ///  iggy pat create ci --allowed-addresses 10.0.0.0/8
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(PersonalAccessTokenCreateArgs),
     /// Delete personal access token
@@ -68,6 +70,23 @@ pub(crate) struct PersonalAccessTokenCreateArgs {
     /// This option can only be used for creating tokens which does not have expiry time set.
     #[clap(short, long, default_value_t = false, group = "store")]
     pub(crate) store_token: bool,
+    /// Allow only reading with the personal access token
+    ///
+    /// The token can be used only to get the resources, poll the messages
+    /// and store the consumer offsets, e.g. when it's embedded in a dashboard.
+    #[clap(short, long, default_value_t = false)]
+    pub(crate) read_only: bool,
+    /// Comma separated list of stream IDs the personal access token is limited to
+    ///
+    /// The actions not related to any of these streams are not allowed with the token.
+    #[clap(long, value_delimiter = ',')]
+    pub(crate) streams: Option<Vec<u32>>,
+    /// Comma separated list of addresses the personal access token can be used from
+    ///
+    /// The addresses can be provided in the CIDR notation, e.g. 10.0.0.0/8,192.168.1.10.
+    /// Logging in with the token from any other address is denied.
+    #[clap(long, value_delimiter = ',')]
+    pub(crate) allowed_addresses: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -0,0 +1,127 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::common::ListMode;
+use clap::{Args, Subcommand};
+use iggy::identifier::Identifier;
+use iggy::models::quota::QuotaLimits;
+use iggy::utils::byte_size::IggyByteSize;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum QuotaAction {
+    /// List throughput quotas of all users and topics along with their usage
+    ///
+    /// Examples
+    ///  iggy quota list
+    ///  iggy quota list --list-mode list
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    List(QuotaListArgs),
+    /// Set throughput quota of the user
+    ///
+    /// User ID can be specified as a username or ID
+    /// Skipped limits are unlimited, skipping all of them restores the default quota
+    ///
+    /// Examples
+    ///  iggy quota user 2 --produce-bytes 10MB --produce-messages 1000
+    ///  iggy quota user producer --consume-bytes 50MB
+    ///  iggy quota user producer
+    #[clap(verbatim_doc_comment, visible_alias = "u")]
+    User(QuotaUserArgs),
+    /// Set throughput quota of the topic
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Skipped limits are unlimited, skipping all of them restores the default quota
+    ///
+    /// Examples
+    ///  iggy quota topic 1 1 --produce-bytes 100MB
+    ///  iggy quota topic stream topic --consume-messages 10000
+    ///  iggy quota topic stream topic
+    #[clap(verbatim_doc_comment, visible_alias = "t")]
+    Topic(QuotaTopicArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct QuotaListArgs {
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct QuotaUserArgs {
+    /// User ID to set quota
+    ///
+    /// User ID can be specified as a username or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) user_id: Identifier,
+    #[clap(flatten)]
+    pub(crate) limits: QuotaLimitsArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct QuotaTopicArgs {
+    /// Stream ID to set quota
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to set quota
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    #[clap(flatten)]
+    pub(crate) limits: QuotaLimitsArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct QuotaLimitsArgs {
+    /// Max size of the produced messages per second in human-readable format like "10MB"
+    #[arg(long)]
+    pub(crate) produce_bytes: Option<IggyByteSize>,
+    /// Max count of the produced messages per second
+    #[arg(long)]
+    pub(crate) produce_messages: Option<u64>,
+    /// Max size of the consumed messages per second in human-readable format like "10MB"
+    #[arg(long)]
+    pub(crate) consume_bytes: Option<IggyByteSize>,
+    /// Max count of the consumed messages per second
+    #[arg(long)]
+    pub(crate) consume_messages: Option<u64>,
+}
+
+impl QuotaLimitsArgs {
+    /// Returns the limits to set, or `None` to restore the default ones if no limit is provided.
+    pub(crate) fn limits(&self) -> Option<QuotaLimits> {
+        if self.produce_bytes.is_none()
+            && self.produce_messages.is_none()
+            && self.consume_bytes.is_none()
+            && self.consume_messages.is_none()
+        {
+            return None;
+        }
+
+        Some(QuotaLimits {
+            produce_bytes_per_second: self.produce_bytes.unwrap_or_default(),
+            produce_messages_per_second: self.produce_messages.unwrap_or_default(),
+            consume_bytes_per_second: self.consume_bytes.unwrap_or_default(),
+            consume_messages_per_second: self.consume_messages.unwrap_or_default(),
+        })
+    }
+}
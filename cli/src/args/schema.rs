@@ -0,0 +1,117 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::common::ListMode;
+use clap::{Args, Subcommand};
+use iggy::models::topic_schema::SchemaKind;
+use iggy::schemas::schema_compatibility::SchemaCompatibility;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum SchemaAction {
+    /// Register new schema version for given subject
+    ///
+    /// Definition is read from the given file, it must be a JSON Schema document
+    /// for "json" kind or a serialized protobuf FileDescriptorSet for "protobuf" kind.
+    /// If the same definition is already the latest version of the subject,
+    /// the existing schema is returned instead of creating a new version.
+    ///
+    /// Examples:
+    ///  iggy schema register orders order.schema.json
+    ///  iggy schema register -k protobuf -m shop.Order orders order.desc
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Register(SchemaRegisterArgs),
+    /// Get details of a single schema with given ID
+    ///
+    /// Examples:
+    ///  iggy schema get 1
+    #[clap(verbatim_doc_comment, visible_alias = "g")]
+    Get(SchemaGetArgs),
+    /// Get details of a schema for given subject and version
+    ///
+    /// If version is not provided then the latest version of the subject is returned
+    ///
+    /// Examples:
+    ///  iggy schema version orders
+    ///  iggy schema version orders 2
+    #[clap(verbatim_doc_comment, visible_alias = "v")]
+    Version(SchemaVersionArgs),
+    /// List all schema versions for given subject
+    ///
+    /// Examples:
+    ///  iggy schema list orders
+    ///  iggy schema list orders --list-mode table
+    ///  iggy schema list orders -l list
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    List(SchemaListArgs),
+    /// Update compatibility level for given subject
+    ///
+    /// Compatibility level can be one of: none, backward, forward, full
+    ///
+    /// Examples:
+    ///  iggy schema compatibility orders full
+    ///  iggy schema compatibility orders none
+    #[clap(verbatim_doc_comment, visible_alias = "c")]
+    Compatibility(SchemaCompatibilityArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaRegisterArgs {
+    /// Subject to register schema for
+    pub(crate) subject: String,
+    /// Path to the file with schema definition
+    pub(crate) definition_file: PathBuf,
+    /// Schema kind (json or protobuf)
+    #[clap(short, long, default_value = "json", value_parser = clap::value_parser!(SchemaKind))]
+    pub(crate) kind: SchemaKind,
+    /// Fully qualified name of the protobuf message, required only for protobuf kind
+    #[clap(short, long)]
+    pub(crate) message_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaGetArgs {
+    /// Schema ID to get
+    pub(crate) schema_id: u32,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaVersionArgs {
+    /// Subject to get schema for
+    pub(crate) subject: String,
+    /// Schema version to get, latest version if not provided
+    pub(crate) version: Option<u32>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaListArgs {
+    /// Subject to list schema versions
+    pub(crate) subject: String,
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SchemaCompatibilityArgs {
+    /// Subject to update compatibility for
+    pub(crate) subject: String,
+    /// Compatibility level (none, backward, forward or full)
+    #[arg(value_parser = clap::value_parser!(SchemaCompatibility))]
+    pub(crate) compatibility: SchemaCompatibility,
+}
@@ -34,6 +34,20 @@ pub(crate) enum SegmentAction {
     ///  iggy segment delete 1 sensor 2 16
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(SegmentDeleteArgs),
+    /// Verify checksums of all batches stored in segments for the specified topic ID,
+    /// stream ID and partition ID.
+    ///
+    /// Corrupted batches are quarantined and skipped when reading messages.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy segment verify 1 1 1
+    ///  iggy segment verify prod 2 2
+    ///  iggy segment verify test sensor 2
+    #[clap(verbatim_doc_comment, visible_alias = "v")]
+    Verify(SegmentVerifyArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -55,3 +69,19 @@ pub(crate) struct SegmentDeleteArgs {
     #[arg(value_parser = clap::value_parser!(u32).range(1..100_001))]
     pub(crate) segments_count: u32,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SegmentVerifyArgs {
+    /// Stream ID to verify segments
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to verify segments
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID to verify segments
+    pub(crate) partition_id: u32,
+}
@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use crate::args::common::ListModeExt;
+use crate::args::common::{ListMode, ListModeExt};
 use clap::Args;
 use iggy::cli::utils::login_session_expiry::LoginSessionExpiry;
 use iggy::snapshot::{SnapshotCompression, SystemSnapshotType};
@@ -46,6 +46,32 @@ pub(crate) struct StatsArgs {
     pub(crate) output: ListModeExt,
 }
 
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ConfigArgs {
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct AuditArgs {
+    /// Show only the actions invoked by the user with the given ID
+    #[arg(short, long)]
+    pub(crate) user_id: Option<u32>,
+
+    /// Show only the actions with the given name, e.g. stream.create
+    #[arg(short, long)]
+    pub(crate) action: Option<String>,
+
+    /// Maximum number of the most recent entries to show
+    #[arg(short = 'n', long, default_value_t = 100)]
+    pub(crate) limit: u32,
+
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
 #[derive(Debug, Clone, Args)]
 pub(crate) struct SnapshotArgs {
     /// Specify snapshot compression method.
@@ -20,6 +20,13 @@ use crate::args::common::ListMode;
 use clap::{Args, Subcommand};
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
+use iggy::models::fsync_policy::FsyncPolicy;
+use iggy::models::queue_policy::QueuePolicy;
+use iggy::models::retention_policy::{RetentionMode, RetentionPolicy};
+use iggy::models::segment_rollover_policy::SegmentRolloverPolicy;
+use iggy::models::tiering_policy::TieringPolicy;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
 
@@ -41,12 +48,15 @@ pub(crate) enum TopicAction {
     ///
     /// Stream ID can be specified as a stream name or ID
     /// Topic ID can be specified as a topic name or ID
+    /// If deferred, the topic is removed once the consumer groups
+    /// have consumed all of its messages or the grace period has elapsed
     ///
     /// Examples
     ///  iggy topic delete 1 1
     ///  iggy topic delete prod 2
     ///  iggy topic delete test debugs
     ///  iggy topic delete 2 debugs
+    ///  iggy topic delete --deferred prod 2
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(TopicDeleteArgs),
     /// Update topic name, compression algorithm and message expiry time for given topic ID in given stream ID
@@ -96,6 +106,20 @@ pub(crate) enum TopicAction {
     ///  iggy topic purge 2 debugs
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Purge(TopicPurgeArgs),
+    /// Set or clear temporary throughput cap for topic with given ID in given stream ID
+    ///
+    /// Messages appended above the max throughput (per second) are rejected
+    /// Skipping max throughput clears the current throttle
+    /// Throttle is not persisted and is cleared on the server restart
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy topic throttle 1 1 10MB
+    ///  iggy topic throttle prod orders 1MB --duration 15min
+    ///  iggy topic throttle prod orders
+    #[clap(verbatim_doc_comment, visible_alias = "t")]
+    Throttle(TopicThrottleArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -129,6 +153,50 @@ pub(crate) struct TopicCreateArgs {
     /// "server_default" or skipping parameter makes CLI to use server default (from current server config) expiry time
     #[arg(default_value = "server_default", value_parser = clap::value_parser!(IggyExpiry), verbatim_doc_comment)]
     pub(crate) message_expiry: Vec<IggyExpiry>,
+    /// Retention mode deciding when the oldest segments are deleted: "time", "size" or "time_and_size"
+    ///
+    /// "size" deletes the oldest segments once the topic exceeds its max size or a partition exceeds the max partition size,
+    /// "time_and_size" applies both the message expiry and the size limits.
+    /// Skipping parameter keeps the time-based retention (message expiry) only.
+    #[arg(long, value_parser = clap::value_parser!(RetentionMode), verbatim_doc_comment)]
+    pub(crate) retention_mode: Option<RetentionMode>,
+    /// Max partition size in human-readable format like "1GB", applicable to the size-based retention only
+    #[arg(long)]
+    pub(crate) max_partition_size: Option<IggyByteSize>,
+    /// URL of the webhook called with the segment metadata before the segment is deleted by the retention
+    ///
+    /// The deletion is deferred until the webhook responds with the success status.
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) retention_hook: Option<String>,
+    /// Minimum age in human-readable format like "7days" after which the closed segments are offloaded to the tiered storage
+    ///
+    /// Skipping both tiering parameters keeps all the segments locally.
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) tiering_min_segment_age: Option<IggyDuration>,
+    /// Max size of the segments kept locally by a partition in human-readable format like "10GB", the oldest closed segments are offloaded first
+    #[arg(long)]
+    pub(crate) tiering_max_local_partition_size: Option<IggyByteSize>,
+    /// Number of the persisted messages after which the topic partitions are synced to the disk
+    ///
+    /// Skipping all the fsync parameters makes the topic follow the server configuration.
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) fsync_every_n_messages: Option<u32>,
+    /// Interval in human-readable format like "1s" after which the topic partitions are synced to the disk
+    #[arg(long)]
+    pub(crate) fsync_interval: Option<IggyDuration>,
+    /// Never sync the topic partitions explicitly, relying on the OS flush instead
+    #[arg(long, conflicts_with_all = ["fsync_every_n_messages", "fsync_interval"])]
+    pub(crate) fsync_never: bool,
+    /// Max age of the segments in human-readable format like "6h", after which they're closed even if not full
+    ///
+    /// Skipping the parameter makes the segments close only once full.
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) segment_max_age: Option<IggyDuration>,
+    /// Visibility timeout in human-readable format like "30s", switching the topic to the queue mode with the individually acknowledged messages
+    ///
+    /// Skipping the parameter makes the topic consumed by the offsets.
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) queue_visibility_timeout: Option<IggyDuration>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -143,6 +211,9 @@ pub(crate) struct TopicDeleteArgs {
     /// Topic ID can be specified as a topic name or ID
     #[arg(value_parser = clap::value_parser!(Identifier))]
     pub(crate) topic_id: Identifier,
+    /// Remove the topic once the consumer groups have consumed all of its messages
+    #[clap(short, long, default_value_t = false)]
+    pub(crate) deferred: bool,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -176,6 +247,46 @@ pub(crate) struct TopicUpdateArgs {
     /// "server_default" or skipping parameter makes CLI to use server default (from current server config) expiry time
     #[arg(default_value = "server_default", value_parser = clap::value_parser!(IggyExpiry), verbatim_doc_comment)]
     pub(crate) message_expiry: Vec<IggyExpiry>,
+    /// New retention mode deciding when the oldest segments are deleted: "time", "size" or "time_and_size"
+    ///
+    /// Skipping parameter keeps the current retention policy.
+    #[arg(long, value_parser = clap::value_parser!(RetentionMode), verbatim_doc_comment)]
+    pub(crate) retention_mode: Option<RetentionMode>,
+    /// New max partition size in human-readable format like "1GB", applicable to the size-based retention only
+    #[arg(long)]
+    pub(crate) max_partition_size: Option<IggyByteSize>,
+    /// New URL of the webhook called with the segment metadata before the segment is deleted by the retention
+    #[arg(long)]
+    pub(crate) retention_hook: Option<String>,
+    /// New minimum age in human-readable format like "7days" after which the closed segments are offloaded to the tiered storage
+    ///
+    /// Skipping both tiering parameters keeps the current tiering policy.
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) tiering_min_segment_age: Option<IggyDuration>,
+    /// New max size of the segments kept locally by a partition in human-readable format like "10GB"
+    #[arg(long)]
+    pub(crate) tiering_max_local_partition_size: Option<IggyByteSize>,
+    /// New number of the persisted messages after which the topic partitions are synced to the disk
+    ///
+    /// Skipping all the fsync parameters keeps the current fsync policy.
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) fsync_every_n_messages: Option<u32>,
+    /// New interval in human-readable format like "1s" after which the topic partitions are synced to the disk
+    #[arg(long)]
+    pub(crate) fsync_interval: Option<IggyDuration>,
+    /// Never sync the topic partitions explicitly, relying on the OS flush instead
+    #[arg(long, conflicts_with_all = ["fsync_every_n_messages", "fsync_interval"])]
+    pub(crate) fsync_never: bool,
+    /// New max age of the segments in human-readable format like "6h", after which they're closed even if not full
+    ///
+    /// Skipping the parameter keeps the current segment rollover policy.
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) segment_max_age: Option<IggyDuration>,
+    /// New visibility timeout in human-readable format like "30s", switching the topic to the queue mode with the individually acknowledged messages
+    ///
+    /// Skipping the parameter keeps the current queue policy.
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) queue_visibility_timeout: Option<IggyDuration>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -218,3 +329,89 @@ pub(crate) struct TopicPurgeArgs {
     #[arg(value_parser = clap::value_parser!(Identifier))]
     pub(crate) topic_id: Identifier,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicThrottleArgs {
+    /// Stream ID to throttle topic
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to throttle
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Max throughput per second in human-readable format like "10MB"
+    ///
+    /// Skipping parameter clears the current throttle
+    #[arg(verbatim_doc_comment)]
+    pub(crate) max_throughput: Option<IggyByteSize>,
+    /// Duration in human-readable format like "15min" after which the throttle is lifted
+    ///
+    /// Skipping parameter keeps the throttle until it's cleared
+    #[arg(short, long, verbatim_doc_comment)]
+    pub(crate) duration: Option<IggyDuration>,
+}
+
+/// Builds the retention policy from the optional mode, max partition size and pre-deletion hook,
+/// the size-based mode is used if only the size was provided, and the time-based one if only the hook was provided.
+pub(crate) fn retention_policy(
+    retention_mode: Option<RetentionMode>,
+    max_partition_size: Option<IggyByteSize>,
+    pre_deletion_hook: Option<String>,
+) -> Option<RetentionPolicy> {
+    match (retention_mode, max_partition_size, pre_deletion_hook) {
+        (None, None, None) => None,
+        (mode, max_partition_size, pre_deletion_hook) => Some(RetentionPolicy {
+            mode: mode.unwrap_or(match max_partition_size {
+                Some(_) => RetentionMode::Size,
+                None => RetentionMode::Time,
+            }),
+            max_partition_size,
+            pre_deletion_hook,
+        }),
+    }
+}
+
+/// Builds the tiering policy from the optional thresholds, if any of them was provided.
+pub(crate) fn tiering_policy(
+    min_segment_age: Option<IggyDuration>,
+    max_local_partition_size: Option<IggyByteSize>,
+) -> Option<TieringPolicy> {
+    match (min_segment_age, max_local_partition_size) {
+        (None, None) => None,
+        (min_segment_age, max_local_partition_size) => Some(TieringPolicy {
+            min_segment_age,
+            max_local_partition_size,
+        }),
+    }
+}
+
+/// Builds the fsync policy from the optional thresholds, if any of them was provided or the explicit sync was disabled.
+pub(crate) fn fsync_policy(
+    every_n_messages: Option<u32>,
+    interval: Option<IggyDuration>,
+    never: bool,
+) -> Option<FsyncPolicy> {
+    match (every_n_messages, interval, never) {
+        (None, None, false) => None,
+        (_, _, true) => Some(FsyncPolicy::never()),
+        (every_n_messages, interval, false) => Some(FsyncPolicy {
+            every_n_messages,
+            interval,
+        }),
+    }
+}
+
+/// Builds the segment rollover policy from the optional max age of the segments.
+pub(crate) fn segment_rollover_policy(
+    max_age: Option<IggyDuration>,
+) -> Option<SegmentRolloverPolicy> {
+    max_age.map(SegmentRolloverPolicy::max_age)
+}
+
+/// Builds the queue policy from the optional visibility timeout of the polled messages.
+pub(crate) fn queue_policy(visibility_timeout: Option<IggyDuration>) -> Option<QueuePolicy> {
+    visibility_timeout.map(QueuePolicy::visibility_timeout)
+}
@@ -17,6 +17,7 @@
  */
 
 use crate::args::common::ListMode;
+use crate::args::permissions::pattern::PatternPermissionsArg;
 use crate::args::permissions::stream::StreamPermissionsArg;
 use crate::args::permissions::UserStatusArg;
 use clap::{Args, Subcommand};
@@ -128,9 +129,10 @@ pub(crate) struct UserCreateArgs {
     /// there's long variant (same as in SDK) and short variant.
     ///
     /// Available permissions (long and short versions):  manage_servers / m_srv,
-    /// read_servers / r_srv, manage_users / m_usr, read_users / r_usr,
-    /// manage_streams / m_str, read_streams / r_str, manage_topics / m_top,
-    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg
+    /// read_servers / r_srv, manage_config / m_cfg, manage_users / m_usr,
+    /// read_users / r_usr, manage_streams / m_str, read_streams / r_str,
+    /// manage_topics / m_top, read_topics / r_top, poll_messages / p_msg,
+    /// send_messages / s_msg
     ///
     /// Examples:
     ///  iggy user create guest guess --global-permissions p_msg,s_msg
@@ -171,6 +173,25 @@ pub(crate) struct UserCreateArgs {
     #[clap(short, long, verbatim_doc_comment)]
     #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
     pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
+    /// Set pattern permissions for created user
+    ///
+    /// Pattern permissions are applied to all streams and topics whose names match
+    /// the pattern, including the ones created later. Pattern has STREAM_NAME.TOPIC_NAME
+    /// form, where asterisk (*) matches any sequence of characters, pattern without
+    /// topic part matches all topics of the stream. Pattern is followed by colon (:)
+    /// and list of permissions separated by comma (,). If multiple patterns match the
+    /// same topic, the most specific one (with most characters other than *) is used.
+    ///
+    /// Available pattern permissions: admin / a, read / r, write / w.
+    ///
+    /// Permissions format: PATTERN[:PATTERN_PERMISSIONS]
+    ///
+    /// Examples:
+    ///  iggy user create reader r3ad3r -P orders.*:read
+    ///  iggy user create writer wr1t3r --pattern-permissions *.events:w -P orders.audit:r,w
+    #[clap(short = 'P', long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(PatternPermissionsArg))]
+    pub(crate) pattern_permissions: Option<Vec<PatternPermissionsArg>>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -259,9 +280,10 @@ pub(crate) struct UserPermissionsArgs {
     /// there's long variant (same as in SDK) and short variant.
     ///
     /// Available permissions (long and short versions):  manage_servers / m_srv,
-    /// read_servers / r_srv, manage_users / m_usr, read_users / r_usr,
-    /// manage_streams / m_str, read_streams / r_str, manage_topics / m_top,
-    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg
+    /// read_servers / r_srv, manage_config / m_cfg, manage_users / m_usr,
+    /// read_users / r_usr, manage_streams / m_str, read_streams / r_str,
+    /// manage_topics / m_top, read_topics / r_top, poll_messages / p_msg,
+    /// send_messages / s_msg
     ///
     /// Examples:
     ///  iggy user create guest guess --global-permissions p_msg,s_msg
@@ -302,4 +324,23 @@ pub(crate) struct UserPermissionsArgs {
     #[clap(short, long, verbatim_doc_comment)]
     #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
     pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
+    /// Set pattern permissions for created user
+    ///
+    /// Pattern permissions are applied to all streams and topics whose names match
+    /// the pattern, including the ones created later. Pattern has STREAM_NAME.TOPIC_NAME
+    /// form, where asterisk (*) matches any sequence of characters, pattern without
+    /// topic part matches all topics of the stream. Pattern is followed by colon (:)
+    /// and list of permissions separated by comma (,). If multiple patterns match the
+    /// same topic, the most specific one (with most characters other than *) is used.
+    ///
+    /// Available pattern permissions: admin / a, read / r, write / w.
+    ///
+    /// Permissions format: PATTERN[:PATTERN_PERMISSIONS]
+    ///
+    /// Examples:
+    ///  iggy user create reader r3ad3r -P orders.*:read
+    ///  iggy user create writer wr1t3r --pattern-permissions *.events:w -P orders.audit:r,w
+    #[clap(short = 'P', long, verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(PatternPermissionsArg))]
+    pub(crate) pattern_permissions: Option<Vec<PatternPermissionsArg>>,
 }
@@ -0,0 +1,293 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::{CliOptions, Command};
+use crate::credentials::IggyCredentials;
+use crate::error::IggyCmdError;
+use crate::{changes_topology, create_client, create_topology_cache, get_command};
+use anyhow::Context;
+use clap::error::ErrorKind;
+use clap::Parser;
+use iggy::args::Args;
+use iggy::clients::client::IggyClient;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use tokio::io::{stdin, AsyncBufReadExt, BufReader};
+use tracing_subscriber::fmt::MakeWriter;
+
+const JSON_RPC_VERSION: &str = "2.0";
+const PARSE_ERROR: i32 = -32700;
+const INVALID_REQUEST: i32 = -32600;
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+const COMMAND_ERROR: i32 = -32000;
+
+/// JSON-RPC 2.0 request, where the method is the command with its subcommands joined by a dot
+/// (e.g. `topic.list`) and the params are its remaining command line arguments.
+#[derive(Debug, Deserialize)]
+struct Request {
+    jsonrpc: String,
+    #[serde(default)]
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Response {
+    jsonrpc: &'static str,
+    id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<CommandResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<ResponseError>,
+}
+
+#[derive(Debug, Serialize)]
+struct CommandResult {
+    command: String,
+    output: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ResponseError {
+    code: i32,
+    message: String,
+}
+
+impl ResponseError {
+    fn new(code: i32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl Response {
+    fn new(id: Value, result: Result<CommandResult, ResponseError>) -> Self {
+        let (result, error) = match result {
+            Ok(result) => (Some(result), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            jsonrpc: JSON_RPC_VERSION,
+            id,
+            result,
+            error,
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+#[command(no_binary_name = true)]
+struct MethodArgs {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+/// The output printed by the commands, captured to be returned in the results rather than written to stdout.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CapturedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedOutput {
+    fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+
+    fn take_lines(&self) -> Vec<String> {
+        let bytes = std::mem::take(&mut *self.0.lock().unwrap());
+        String::from_utf8_lossy(&bytes)
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+}
+
+impl Write for CapturedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for CapturedOutput {
+    type Writer = CapturedOutput;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+/// Executes the JSON-RPC requests read from stdin line by line, using the single connection to the server,
+/// and writes the responses to stdout, one per line. The notifications (requests without ID) are not responded to.
+pub(crate) async fn serve(
+    cli_options: &CliOptions,
+    iggy_args: &Args,
+    output: CapturedOutput,
+) -> Result<(), IggyCmdError> {
+    let mut credentials = IggyCredentials::new(cli_options, iggy_args, true)?;
+    let client = create_client(iggy_args, true).await?;
+    credentials.set_iggy_client(&client);
+    credentials.login_user().await?;
+
+    let mut lines = BufReader::new(stdin()).lines();
+    while let Some(line) = lines
+        .next_line()
+        .await
+        .context("Problem reading request from standard input")?
+    {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(response) =
+            handle_request(&line, &client, cli_options, iggy_args, &output).await
+        {
+            let response =
+                serde_json::to_string(&response).context("Problem serializing response")?;
+            println!("{response}");
+        }
+    }
+
+    credentials.logout_user().await?;
+
+    Ok(())
+}
+
+async fn handle_request(
+    line: &str,
+    client: &IggyClient,
+    cli_options: &CliOptions,
+    iggy_args: &Args,
+    output: &CapturedOutput,
+) -> Option<Response> {
+    let request = match serde_json::from_str::<Request>(line) {
+        Ok(request) => request,
+        Err(error) => {
+            let code = match error.is_syntax() || error.is_eof() {
+                true => PARSE_ERROR,
+                false => INVALID_REQUEST,
+            };
+            let error = ResponseError::new(code, error.to_string());
+            return Some(Response::new(Value::Null, Err(error)));
+        }
+    };
+
+    let id = request.id.clone();
+    let result = execute(request, client, cli_options, iggy_args, output).await;
+    id.map(|id| Response::new(id, result))
+}
+
+async fn execute(
+    request: Request,
+    client: &IggyClient,
+    cli_options: &CliOptions,
+    iggy_args: &Args,
+    output: &CapturedOutput,
+) -> Result<CommandResult, ResponseError> {
+    if request.jsonrpc != JSON_RPC_VERSION {
+        return Err(ResponseError::new(
+            INVALID_REQUEST,
+            format!("Unsupported JSON-RPC version: {}", request.jsonrpc),
+        ));
+    }
+
+    let command = parse_command(&request.method, request.params)?;
+    let invalidate_topology_cache = changes_topology(&command);
+    let mut command = get_command(command, cli_options, iggy_args);
+    let explanation = command.explain();
+    output.clear();
+    match command.execute_cmd(client).await {
+        Ok(()) => {
+            if invalidate_topology_cache {
+                if let Some(topology_cache) = create_topology_cache(cli_options, iggy_args) {
+                    topology_cache
+                        .invalidate()
+                        .await
+                        .map_err(|error| ResponseError::new(COMMAND_ERROR, format!("{error:#}")))?;
+                }
+            }
+            Ok(CommandResult {
+                command: explanation,
+                output: output.take_lines(),
+            })
+        }
+        Err(error) => {
+            output.clear();
+            Err(ResponseError::new(COMMAND_ERROR, format!("{error:#}")))
+        }
+    }
+}
+
+fn parse_command(method: &str, params: Vec<String>) -> Result<Command, ResponseError> {
+    let args = method.split('.').map(String::from).chain(params);
+    MethodArgs::try_parse_from(args)
+        .map(|args| args.command)
+        .map_err(|error| {
+            let code = match error.kind() {
+                ErrorKind::InvalidSubcommand
+                | ErrorKind::MissingSubcommand
+                | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand => METHOD_NOT_FOUND,
+                _ => INVALID_PARAMS,
+            };
+            ResponseError::new(code, error.render().to_string().trim_end())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::args::stream::StreamAction;
+
+    #[test]
+    fn should_parse_method_with_params_into_command() {
+        let command = parse_command("stream.create", vec!["test".into()]).unwrap();
+        assert!(matches!(
+            command,
+            Command::Stream(StreamAction::Create(args)) if args.name == "test"
+        ));
+    }
+
+    #[test]
+    fn should_fail_to_parse_unknown_method() {
+        let error = parse_command("stream.unknown", vec![]).unwrap_err();
+        assert_eq!(error.code, METHOD_NOT_FOUND);
+    }
+
+    #[test]
+    fn should_fail_to_parse_method_with_invalid_params() {
+        let error = parse_command("stream.get", vec![]).unwrap_err();
+        assert_eq!(error.code, INVALID_PARAMS);
+    }
+
+    #[test]
+    fn should_take_captured_output_lines() {
+        let mut output = CapturedOutput::default();
+        output.write_all(b"first\nsecond\n").unwrap();
+        assert_eq!(output.take_lines(), vec!["first", "second"]);
+        assert!(output.take_lines().is_empty());
+    }
+}
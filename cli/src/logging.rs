@@ -21,7 +21,7 @@ use std::path::PathBuf;
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{
     filter::{self, LevelFilter},
-    fmt::{self},
+    fmt::{self, MakeWriter},
     layer::{Layer, SubscriberExt},
 };
 
@@ -39,10 +39,24 @@ impl Logging {
     }
 
     pub(crate) fn init(&mut self, quiet: bool, debug: &Option<PathBuf>) -> &mut Self {
+        let (stdout_writer, stdout_guard) = tracing_appender::non_blocking(std::io::stdout());
+        self.stdout_guard = Some(stdout_guard);
+        self.init_with_writer(stdout_writer, quiet, debug)
+    }
+
+    /// Initializes logging with the printed output written to the given writer instead of stdout.
+    pub(crate) fn init_with_writer<W>(
+        &mut self,
+        stdout_writer: W,
+        quiet: bool,
+        debug: &Option<PathBuf>,
+    ) -> &mut Self
+    where
+        W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+    {
         let mut layers = vec![];
 
         let stdout_filter = filter::filter_fn(|metadata| metadata.target().contains(PRINT_TARGET));
-        let (stdout_writer, stdout_guard) = tracing_appender::non_blocking(std::io::stdout());
 
         let stdout_layer = fmt::Layer::default()
             .without_time()
@@ -56,8 +70,6 @@ impl Logging {
             })
             .boxed();
 
-        self.stdout_guard = Some(stdout_guard);
-
         layers.push(stdout_layer.with_filter(stdout_filter).boxed());
 
         if let Some(file_path) = debug {
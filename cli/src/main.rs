@@ -19,18 +19,32 @@
 mod args;
 mod credentials;
 mod error;
+mod json_rpc;
 mod logging;
 
 use crate::args::{
-    client::ClientAction, consumer_group::ConsumerGroupAction,
-    consumer_offset::ConsumerOffsetAction, permissions::PermissionsArgs,
-    personal_access_token::PersonalAccessTokenAction, stream::StreamAction, topic::TopicAction,
+    bookmark::BookmarkAction,
+    client::ClientAction,
+    consumer_group::ConsumerGroupAction,
+    consumer_offset::ConsumerOffsetAction,
+    permissions::PermissionsArgs,
+    personal_access_token::PersonalAccessTokenAction,
+    quota::QuotaAction,
+    schema::SchemaAction,
+    stream::StreamAction,
+    topic::{
+        fsync_policy, queue_policy, retention_policy, segment_rollover_policy, tiering_policy,
+        TopicAction,
+    },
     Command, IggyConsoleArgs,
 };
 use crate::credentials::IggyCredentials;
 use crate::error::IggyCmdError;
+use crate::json_rpc::CapturedOutput;
 use crate::logging::Logging;
+use args::backup::BackupAction;
 use args::context::ContextAction;
+use args::diagnostics::DiagnosticsAction;
 use args::message::MessageAction;
 use args::partition::PartitionAction;
 use args::segment::SegmentAction;
@@ -41,8 +55,16 @@ use iggy::args::Args;
 use iggy::cli::context::common::ContextManager;
 use iggy::cli::context::use_context::UseContextCmd;
 use iggy::cli::segments::delete_segments::DeleteSegmentsCmd;
+use iggy::cli::segments::verify_segments::VerifySegmentsCmd;
+use iggy::cli::system::backup::{CreateBackupCmd, RestoreBackupCmd};
+use iggy::cli::system::diagnostics::CollectDiagnosticsCmd;
 use iggy::cli::system::snapshot::GetSnapshotCmd;
+use iggy::cli::utils::topology_cache::TopologyCache;
 use iggy::cli::{
+    bookmark::{
+        delete_bookmark::DeleteBookmarkCmd, get_bookmarks::GetBookmarksCmd,
+        poll_bookmark::PollBookmarkCmd, set_bookmark::SetBookmarkCmd,
+    },
     client::{get_client::GetClientCmd, get_clients::GetClientsCmd},
     consumer_group::{
         create_consumer_group::CreateConsumerGroupCmd,
@@ -54,23 +76,37 @@ use iggy::cli::{
     },
     context::get_contexts::GetContextsCmd,
     message::{
-        flush_messages::FlushMessagesCmd, poll_messages::PollMessagesCmd,
-        send_messages::SendMessagesCmd,
+        flush_messages::FlushMessagesCmd, mirror_messages::MirrorMessagesCmd,
+        poll_messages::PollMessagesCmd, send_messages::SendMessagesCmd,
+    },
+    partitions::{
+        create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd,
+        get_partition::GetPartitionCmd, move_partition::MovePartitionCmd,
+        update_partition::UpdatePartitionCmd,
     },
-    partitions::{create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd},
     personal_access_tokens::{
         create_personal_access_token::CreatePersonalAccessTokenCmd,
         delete_personal_access_tokens::DeletePersonalAccessTokenCmd,
         get_personal_access_tokens::GetPersonalAccessTokensCmd,
     },
+    quotas::{get_quotas::GetQuotasCmd, set_quota::SetQuotaCmd},
+    schemas::{
+        get_schema::GetSchemaCmd, get_schemas::GetSchemasCmd,
+        get_subject_schema::GetSubjectSchemaCmd, register_schema::RegisterSchemaCmd,
+        update_schema_compatibility::UpdateSchemaCompatibilityCmd,
+    },
     streams::{
         create_stream::CreateStreamCmd, delete_stream::DeleteStreamCmd, get_stream::GetStreamCmd,
         get_streams::GetStreamsCmd, purge_stream::PurgeStreamCmd, update_stream::UpdateStreamCmd,
     },
-    system::{me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
+    system::{
+        audit::GetAuditLogCmd, config::GetConfigCmd, me::GetMeCmd, ping::PingCmd,
+        stats::GetStatsCmd,
+    },
     topics::{
         create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd, get_topic::GetTopicCmd,
-        get_topics::GetTopicsCmd, purge_topic::PurgeTopicCmd, update_topic::UpdateTopicCmd,
+        get_topics::GetTopicsCmd, purge_topic::PurgeTopicCmd,
+        set_topic_throttle::SetTopicThrottleCmd, update_topic::UpdateTopicCmd,
     },
     users::{
         change_password::ChangePasswordCmd,
@@ -85,6 +121,8 @@ use iggy::cli::{
 use iggy::cli_command::{CliCommand, PRINT_TARGET};
 use iggy::client_provider::{self, ClientProviderConfig};
 use iggy::clients::client::IggyClient;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
+use iggy::models::topic_options::TopicOptions;
 use iggy::utils::crypto::{Aes256GcmEncryptor, EncryptorKind};
 use iggy::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 use std::sync::Arc;
@@ -104,6 +142,7 @@ fn get_command(
     cli_options: &CliOptions,
     iggy_args: &Args,
 ) -> Box<dyn CliCommand> {
+    let topology_cache = create_topology_cache(cli_options, iggy_args);
     #[warn(clippy::let_and_return)]
     match command {
         Command::Stream(command) => match command {
@@ -115,8 +154,12 @@ fn get_command(
                 args.stream_id.clone(),
                 args.name.clone(),
             )),
-            StreamAction::Get(args) => Box::new(GetStreamCmd::new(args.stream_id.clone())),
-            StreamAction::List(args) => Box::new(GetStreamsCmd::new(args.list_mode.into())),
+            StreamAction::Get(args) => {
+                Box::new(GetStreamCmd::new(args.stream_id.clone()).with_cache(topology_cache))
+            }
+            StreamAction::List(args) => {
+                Box::new(GetStreamsCmd::new(args.list_mode.into()).with_cache(topology_cache))
+            }
             StreamAction::Purge(args) => Box::new(PurgeStreamCmd::new(args.stream_id.clone())),
         },
         Command::Topic(command) => match command {
@@ -129,10 +172,30 @@ fn get_command(
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                TopicOptions {
+                    retention_policy: retention_policy(
+                        args.retention_mode,
+                        args.max_partition_size,
+                        args.retention_hook.clone(),
+                    ),
+                    tiering_policy: tiering_policy(
+                        args.tiering_min_segment_age,
+                        args.tiering_max_local_partition_size,
+                    ),
+                    fsync_policy: fsync_policy(
+                        args.fsync_every_n_messages,
+                        args.fsync_interval,
+                        args.fsync_never,
+                    ),
+                    segment_rollover_policy: segment_rollover_policy(args.segment_max_age),
+                    queue_policy: queue_policy(args.queue_visibility_timeout),
+                    ..Default::default()
+                },
             )),
             TopicAction::Delete(args) => Box::new(DeleteTopicCmd::new(
                 args.stream_id.clone(),
                 args.topic_id.clone(),
+                args.deferred,
             )),
             TopicAction::Update(args) => Box::new(UpdateTopicCmd::new(
                 args.stream_id.clone(),
@@ -142,18 +205,43 @@ fn get_command(
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                TopicOptions {
+                    retention_policy: retention_policy(
+                        args.retention_mode,
+                        args.max_partition_size,
+                        args.retention_hook.clone(),
+                    ),
+                    tiering_policy: tiering_policy(
+                        args.tiering_min_segment_age,
+                        args.tiering_max_local_partition_size,
+                    ),
+                    fsync_policy: fsync_policy(
+                        args.fsync_every_n_messages,
+                        args.fsync_interval,
+                        args.fsync_never,
+                    ),
+                    segment_rollover_policy: segment_rollover_policy(args.segment_max_age),
+                    queue_policy: queue_policy(args.queue_visibility_timeout),
+                    ..Default::default()
+                },
             )),
-            TopicAction::Get(args) => Box::new(GetTopicCmd::new(
+            TopicAction::Get(args) => Box::new(
+                GetTopicCmd::new(args.stream_id.clone(), args.topic_id.clone())
+                    .with_cache(topology_cache),
+            ),
+            TopicAction::List(args) => Box::new(
+                GetTopicsCmd::new(args.stream_id.clone(), args.list_mode.into())
+                    .with_cache(topology_cache),
+            ),
+            TopicAction::Purge(args) => Box::new(PurgeTopicCmd::new(
                 args.stream_id.clone(),
                 args.topic_id.clone(),
             )),
-            TopicAction::List(args) => Box::new(GetTopicsCmd::new(
-                args.stream_id.clone(),
-                args.list_mode.into(),
-            )),
-            TopicAction::Purge(args) => Box::new(PurgeTopicCmd::new(
+            TopicAction::Throttle(args) => Box::new(SetTopicThrottleCmd::new(
                 args.stream_id.clone(),
                 args.topic_id.clone(),
+                args.max_throughput,
+                args.duration,
             )),
         },
         Command::Partition(command) => match command {
@@ -161,12 +249,30 @@ fn get_command(
                 args.stream_id.clone(),
                 args.topic_id.clone(),
                 args.partitions_count,
+                args.key_routing_policy,
             )),
             PartitionAction::Delete(args) => Box::new(DeletePartitionsCmd::new(
                 args.stream_id.clone(),
                 args.topic_id.clone(),
                 args.partitions_count,
             )),
+            PartitionAction::Get(args) => Box::new(GetPartitionCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partition_id.clone(),
+            )),
+            PartitionAction::Update(args) => Box::new(UpdatePartitionCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partition_id.clone(),
+                args.name.clone(),
+            )),
+            PartitionAction::Move(args) => Box::new(MovePartitionCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partition_id.clone(),
+                args.directory.clone(),
+            )),
         },
         Command::Segment(command) => match command {
             SegmentAction::Delete(args) => Box::new(DeleteSegmentsCmd::new(
@@ -175,20 +281,53 @@ fn get_command(
                 args.partition_id,
                 args.segments_count,
             )),
+            SegmentAction::Verify(args) => Box::new(VerifySegmentsCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partition_id,
+            )),
         },
         Command::Ping(args) => Box::new(PingCmd::new(args.count)),
         Command::Me => Box::new(GetMeCmd::new()),
         Command::Stats(args) => Box::new(GetStatsCmd::new(cli_options.quiet, args.output.into())),
+        Command::Config(args) => Box::new(GetConfigCmd::new(args.list_mode.into())),
+        Command::Audit(args) => Box::new(GetAuditLogCmd::new(
+            args.user_id,
+            args.action,
+            args.limit,
+            args.list_mode.into(),
+        )),
         Command::Snapshot(args) => Box::new(GetSnapshotCmd::new(
             args.compression,
             args.snapshot_types,
             args.out_dir,
         )),
+        Command::Backup(command) => match command {
+            BackupAction::Create(args) => Box::new(CreateBackupCmd::new(
+                args.name,
+                args.streams,
+                args.destination.into(),
+                args.base,
+            )),
+            BackupAction::Restore(args) => Box::new(RestoreBackupCmd::new(args.name, args.streams)),
+        },
+        Command::Diagnostics(command) => match command {
+            DiagnosticsAction::Collect(args) => {
+                Box::new(CollectDiagnosticsCmd::new(args.out_dir, args.log_lines))
+            }
+        },
         Command::Pat(command) => match command {
             PersonalAccessTokenAction::Create(pat_create_args) => {
                 Box::new(CreatePersonalAccessTokenCmd::new(
                     pat_create_args.name.clone(),
                     PersonalAccessTokenExpiry::new(pat_create_args.expiry.clone()),
+                    (pat_create_args.read_only || pat_create_args.streams.is_some()).then(|| {
+                        PersonalAccessTokenScope {
+                            read_only: pat_create_args.read_only,
+                            streams: pat_create_args.streams.clone(),
+                        }
+                    }),
+                    pat_create_args.allowed_addresses.clone(),
                     cli_options.quiet,
                     pat_create_args.store_token,
                     iggy_args.get_server_address().unwrap(),
@@ -212,6 +351,7 @@ fn get_command(
                 PermissionsArgs::new(
                     create_args.global_permissions.clone(),
                     create_args.stream_permissions.clone(),
+                    create_args.pattern_permissions.clone(),
                 )
                 .into(),
             )),
@@ -238,6 +378,7 @@ fn get_command(
                 PermissionsArgs::new(
                     permissions_args.global_permissions.clone(),
                     permissions_args.stream_permissions.clone(),
+                    permissions_args.pattern_permissions.clone(),
                 )
                 .into(),
             )),
@@ -260,16 +401,22 @@ fn get_command(
                 delete_args.topic_id.clone(),
                 delete_args.group_id.clone(),
             )),
-            ConsumerGroupAction::Get(get_args) => Box::new(GetConsumerGroupCmd::new(
-                get_args.stream_id.clone(),
-                get_args.topic_id.clone(),
-                get_args.group_id.clone(),
-            )),
-            ConsumerGroupAction::List(list_args) => Box::new(GetConsumerGroupsCmd::new(
-                list_args.stream_id.clone(),
-                list_args.topic_id.clone(),
-                list_args.list_mode.into(),
-            )),
+            ConsumerGroupAction::Get(get_args) => Box::new(
+                GetConsumerGroupCmd::new(
+                    get_args.stream_id.clone(),
+                    get_args.topic_id.clone(),
+                    get_args.group_id.clone(),
+                )
+                .with_cache(topology_cache),
+            ),
+            ConsumerGroupAction::List(list_args) => Box::new(
+                GetConsumerGroupsCmd::new(
+                    list_args.stream_id.clone(),
+                    list_args.topic_id.clone(),
+                    list_args.list_mode.into(),
+                )
+                .with_cache(topology_cache),
+            ),
         },
         Command::Message(command) => match command {
             MessageAction::Send(send_args) => Box::new(SendMessagesCmd::new(
@@ -280,13 +427,14 @@ fn get_command(
                 send_args.messages.clone(),
                 send_args.headers.clone(),
                 send_args.input_file.clone(),
+                send_args.confirmation,
             )),
             MessageAction::Poll(poll_args) => Box::new(PollMessagesCmd::new(
                 poll_args.stream_id.clone(),
                 poll_args.topic_id.clone(),
                 poll_args.partition_id,
                 poll_args.message_count,
-                poll_args.auto_commit,
+                poll_args.auto_commit_mode(),
                 poll_args.offset,
                 poll_args.first,
                 poll_args.last,
@@ -301,6 +449,39 @@ fn get_command(
                 flush_args.partition_id,
                 flush_args.fsync,
             )),
+            MessageAction::Mirror(mirror_args) => Box::new(MirrorMessagesCmd::new(
+                mirror_args.stream_id.clone(),
+                mirror_args.topic_ids.clone(),
+                mirror_args.target.clone(),
+                mirror_args.target_stream.clone(),
+                mirror_args.consumer.clone(),
+                mirror_args.message_count,
+                mirror_args.confirmation,
+                mirror_args.follow,
+            )),
+        },
+        Command::Schema(command) => match command {
+            SchemaAction::Register(register_args) => Box::new(RegisterSchemaCmd::new(
+                register_args.subject.clone(),
+                register_args.kind,
+                register_args.message_type.clone(),
+                register_args.definition_file.clone(),
+            )),
+            SchemaAction::Get(get_args) => Box::new(GetSchemaCmd::new(get_args.schema_id)),
+            SchemaAction::Version(version_args) => Box::new(GetSubjectSchemaCmd::new(
+                version_args.subject.clone(),
+                version_args.version,
+            )),
+            SchemaAction::List(list_args) => Box::new(GetSchemasCmd::new(
+                list_args.subject.clone(),
+                list_args.list_mode.into(),
+            )),
+            SchemaAction::Compatibility(compatibility_args) => {
+                Box::new(UpdateSchemaCompatibilityCmd::new(
+                    compatibility_args.subject.clone(),
+                    compatibility_args.compatibility,
+                ))
+            }
         },
         Command::ConsumerOffset(command) => match command {
             ConsumerOffsetAction::Get(get_args) => Box::new(GetConsumerOffsetCmd::new(
@@ -317,6 +498,44 @@ fn get_command(
                 set_args.offset,
             )),
         },
+        Command::Quota(command) => match command {
+            QuotaAction::List(list_args) => Box::new(GetQuotasCmd::new(list_args.list_mode.into())),
+            QuotaAction::User(user_args) => Box::new(SetQuotaCmd::user(
+                user_args.user_id.clone(),
+                user_args.limits.limits(),
+            )),
+            QuotaAction::Topic(topic_args) => Box::new(SetQuotaCmd::topic(
+                topic_args.stream_id.clone(),
+                topic_args.topic_id.clone(),
+                topic_args.limits.limits(),
+            )),
+        },
+        Command::Bookmark(command) => match command {
+            BookmarkAction::List(list_args) => Box::new(GetBookmarksCmd::new(
+                list_args.stream_id.clone(),
+                list_args.topic_id.clone(),
+                list_args.list_mode.into(),
+            )),
+            BookmarkAction::Set(set_args) => Box::new(SetBookmarkCmd::new(
+                set_args.stream_id.clone(),
+                set_args.topic_id.clone(),
+                set_args.name.clone(),
+                set_args.partition_id,
+                set_args.offset,
+            )),
+            BookmarkAction::Delete(delete_args) => Box::new(DeleteBookmarkCmd::new(
+                delete_args.stream_id.clone(),
+                delete_args.topic_id.clone(),
+                delete_args.name.clone(),
+            )),
+            BookmarkAction::Poll(poll_args) => Box::new(PollBookmarkCmd::new(
+                poll_args.stream_id.clone(),
+                poll_args.topic_id.clone(),
+                poll_args.name.clone(),
+                poll_args.message_count,
+                poll_args.peek,
+            )),
+        },
         Command::Context(command) => match command {
             ContextAction::List(list_args) => {
                 Box::new(GetContextsCmd::new(list_args.list_mode.into()))
@@ -335,6 +554,49 @@ fn get_command(
     }
 }
 
+/// Creates the cache of the topology listings of the server, disabled unless the cache TTL is provided.
+fn create_topology_cache(cli_options: &CliOptions, iggy_args: &Args) -> Option<TopologyCache> {
+    TopologyCache::new(
+        &iggy_args.get_server_address()?,
+        cli_options.cache_ttl.unwrap_or_default(),
+        cli_options.refresh,
+    )
+}
+
+/// Checks whether the command changes the streams, topics, partitions or consumer groups, so the cached listings are outdated.
+fn changes_topology(command: &Command) -> bool {
+    match command {
+        Command::Stream(action) => !matches!(action, StreamAction::Get(_) | StreamAction::List(_)),
+        Command::Topic(action) => !matches!(action, TopicAction::Get(_) | TopicAction::List(_)),
+        Command::Partition(action) => !matches!(action, PartitionAction::Get(_)),
+        Command::ConsumerGroup(action) => !matches!(
+            action,
+            ConsumerGroupAction::Get(_) | ConsumerGroupAction::List(_)
+        ),
+        _ => false,
+    }
+}
+
+async fn create_client(
+    iggy_args: &Args,
+    connection_required: bool,
+) -> Result<IggyClient, IggyCmdError> {
+    let encryptor = match iggy_args.encryption_key.is_empty() {
+        true => None,
+        false => Some(Arc::new(EncryptorKind::Aes256Gcm(
+            Aes256GcmEncryptor::from_base64_key(&iggy_args.encryption_key).unwrap(),
+        ))),
+    };
+    let client_provider_config = Arc::new(ClientProviderConfig::from_args_set_autologin(
+        iggy_args.clone(),
+        false,
+    )?);
+
+    let client =
+        client_provider::get_raw_client(client_provider_config, connection_required).await?;
+    Ok(IggyClient::create(client, None, encryptor))
+}
+
 #[tokio::main]
 async fn main() -> Result<(), IggyCmdError> {
     let args = IggyConsoleArgs::parse();
@@ -344,15 +606,20 @@ async fn main() -> Result<(), IggyCmdError> {
         return Ok(());
     }
 
-    if args.command.is_none() {
+    if args.command.is_none() && !args.cli.json_rpc {
         IggyConsoleArgs::print_overview();
         return Ok(());
     }
 
+    // In the JSON-RPC mode the output of the commands is returned in the responses instead of being printed
+    let output = CapturedOutput::default();
     let mut logging = Logging::new();
-    logging.init(args.cli.quiet, &args.cli.debug);
+    match args.cli.json_rpc {
+        true => logging.init_with_writer(output.clone(), false, &args.cli.debug),
+        false => logging.init(args.cli.quiet, &args.cli.debug),
+    };
 
-    let command = args.command.clone().unwrap();
+    let command = args.command.clone();
 
     let mut context_manager = ContextManager::default();
     let active_context = context_manager.get_active_context().await?;
@@ -361,27 +628,20 @@ async fn main() -> Result<(), IggyCmdError> {
     let iggy_args = merged_args.iggy;
     let cli_options = merged_args.cli;
 
+    if cli_options.json_rpc {
+        return json_rpc::serve(&cli_options, &iggy_args, output).await;
+    }
+
+    let command = command.unwrap();
+    let invalidate_topology_cache = changes_topology(&command);
+
     // Get command based on command line arguments
     let mut command = get_command(command, &cli_options, &iggy_args);
 
     // Create credentials based on command line arguments and command
     let mut credentials = IggyCredentials::new(&cli_options, &iggy_args, command.login_required())?;
 
-    let encryptor = match iggy_args.encryption_key.is_empty() {
-        true => None,
-        false => Some(Arc::new(EncryptorKind::Aes256Gcm(
-            Aes256GcmEncryptor::from_base64_key(&iggy_args.encryption_key).unwrap(),
-        ))),
-    };
-    let client_provider_config = Arc::new(ClientProviderConfig::from_args_set_autologin(
-        iggy_args.clone(),
-        false,
-    )?);
-
-    let client =
-        client_provider::get_raw_client(client_provider_config, command.connection_required())
-            .await?;
-    let client = IggyClient::create(client, None, encryptor);
+    let client = create_client(&iggy_args, command.connection_required()).await?;
 
     credentials.set_iggy_client(&client);
     credentials.login_user().await?;
@@ -393,6 +653,12 @@ async fn main() -> Result<(), IggyCmdError> {
     }
     command.execute_cmd(&client).await?;
 
+    if invalidate_topology_cache {
+        if let Some(topology_cache) = create_topology_cache(&cli_options, &iggy_args) {
+            topology_cache.invalidate().await?;
+        }
+    }
+
     credentials.logout_user().await?;
 
     Ok(())
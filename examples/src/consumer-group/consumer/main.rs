@@ -0,0 +1,253 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use clap::Parser;
+use futures_util::future::join_all;
+use futures_util::StreamExt;
+use iggy::client::{Client, ConsumerGroupClient};
+use iggy::client_provider;
+use iggy::client_provider::ClientProviderConfig;
+use iggy::clients::client::IggyClient;
+use iggy::clients::consumer::{AssignmentChange, AutoCommit, IggyConsumer, ReceivedMessage};
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::utils::duration::IggyDuration;
+use iggy_examples::shared::args::Args;
+use iggy_test_data::messages::Envelope;
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+use tracing::{error, info, warn};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry};
+
+const CONSUMER_GROUP: &str = "consumer-group-example";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    Registry::default()
+        .with(tracing_subscriber::fmt::layer())
+        .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+        .init();
+    let members_count = env::var("MEMBERS_COUNT")
+        .unwrap_or_else(|_| 3.to_string())
+        .parse::<u32>()
+        .expect("Invalid members count");
+
+    // Starting the members one by one makes the rebalances visible, as each new member takes over some partitions.
+    let members_start_delay = env::var("MEMBERS_START_DELAY")
+        .unwrap_or_else(|_| "3s".to_string())
+        .parse::<IggyDuration>()
+        .expect("Invalid members start delay");
+
+    info!(
+        "Consumer group example has started, members: {members_count}, selected transport: {}",
+        args.transport
+    );
+    let client_provider_config = Arc::new(ClientProviderConfig::from_args(args.to_sdk_args())?);
+    let (shutdown_sender, shutdown_receiver) = watch::channel(false);
+    let mut tasks = Vec::new();
+    for member_id in 1..=members_count {
+        let client = client_provider::get_raw_client(client_provider_config.clone(), false).await?;
+        let client = IggyClient::builder().with_client(client).build()?;
+        client.connect().await?;
+        tasks.push(start_member(
+            member_id,
+            client,
+            &args,
+            shutdown_receiver.clone(),
+        )?);
+        if member_id < members_count {
+            sleep(members_start_delay.get_duration()).await;
+        }
+    }
+
+    let mut members = join_all(tasks);
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => {
+            info!("Received CTRL-C, shutting down all the consumer group members...");
+            let _ = shutdown_sender.send(true);
+            members.await;
+        }
+        _ = &mut members => {
+            info!("All the consumer group members have left the group.");
+        }
+    }
+
+    info!("Consumer group example has finished.");
+    Ok(())
+}
+
+fn start_member(
+    member_id: u32,
+    client: IggyClient,
+    args: &Args,
+    shutdown: watch::Receiver<bool>,
+) -> Result<JoinHandle<()>, Box<dyn Error>> {
+    // The assignment changes are detected in the background, so they're passed to the member loop,
+    // where the offsets for the revoked partitions can be stored before another member takes them over.
+    let (changes_sender, changes_receiver) = mpsc::unbounded_channel();
+    let consumer = client
+        .consumer_group(CONSUMER_GROUP, &args.stream_id, &args.topic_id)?
+        // The offsets are stored explicitly on rebalance and shutdown, so that no processed message is consumed twice.
+        .auto_commit(AutoCommit::Disabled)
+        .create_consumer_group_if_not_exists()
+        .auto_join_consumer_group()
+        // Once the offset has been stored, the next member assigned to the partition resumes right after it.
+        .polling_strategy(PollingStrategy::next())
+        .poll_interval(IggyDuration::from_str(&args.interval)?)
+        .batch_size(args.messages_per_batch)
+        .on_assignment_changed(move |change| {
+            let _ = changes_sender.send(change);
+        })
+        .build();
+
+    let member = Member {
+        id: member_id,
+        client,
+        consumer,
+        processed_offsets: HashMap::new(),
+        messages_limit: args.message_batches_limit * args.messages_per_batch as u64,
+    };
+    Ok(tokio::spawn(async move {
+        if let Err(error) = member.run(changes_receiver, shutdown).await {
+            error!("Consumer group member: {member_id} has failed: {error}");
+        }
+    }))
+}
+
+struct Member {
+    id: u32,
+    client: IggyClient,
+    consumer: IggyConsumer,
+    /// The offsets of the last processed messages, which haven't been stored on the server yet, by partition ID.
+    processed_offsets: HashMap<u32, u64>,
+    messages_limit: u64,
+}
+
+impl Member {
+    async fn run(
+        mut self,
+        mut changes: mpsc::UnboundedReceiver<AssignmentChange>,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), Box<dyn Error>> {
+        self.consumer.init().await?;
+        info!("Consumer group member: {} has joined the group.", self.id);
+        let mut consumed_messages = 0;
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    info!("Consumer group member: {} is shutting down.", self.id);
+                    break;
+                }
+                Some(change) = changes.recv() => {
+                    self.handle_assignment_change(change).await;
+                }
+                message = self.consumer.next() => {
+                    match message {
+                        Some(Ok(message)) => {
+                            self.handle_message(&message);
+                            consumed_messages += 1;
+                            if self.messages_limit > 0 && consumed_messages == self.messages_limit {
+                                // Leaving the group earlier than the others triggers another rebalance.
+                                info!("Consumer group member: {} has consumed {consumed_messages} messages, leaving the group.", self.id);
+                                break;
+                            }
+                        }
+                        Some(Err(error)) => {
+                            error!("Error while handling message by member: {}: {error}", self.id);
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        self.shutdown().await
+    }
+
+    fn handle_message(&mut self, message: &ReceivedMessage) {
+        let offset = message.message.offset;
+        let partition_id = message.partition_id;
+        match std::str::from_utf8(&message.message.payload)
+            .map_err(|error| error.to_string())
+            .and_then(|json| {
+                serde_json::from_str::<Envelope>(json).map_err(|error| error.to_string())
+            }) {
+            Ok(envelope) => info!(
+                "Member: {} handled message type: {} at offset: {offset} in partition ID: {partition_id}",
+                self.id, envelope.message_type
+            ),
+            Err(error) => warn!(
+                "Member: {} received invalid message at offset: {offset} in partition ID: {partition_id}: {error}",
+                self.id
+            ),
+        }
+        self.processed_offsets.insert(partition_id, offset);
+    }
+
+    async fn handle_assignment_change(&mut self, change: AssignmentChange) {
+        info!(
+            "Consumer group member: {} partitions have changed in generation: {}, assigned: {:?}, revoked: {:?}, current: {:?}",
+            self.id, change.generation, change.assigned, change.revoked, change.partitions
+        );
+        for partition_id in change.revoked {
+            if let Some(offset) = self.processed_offsets.remove(&partition_id) {
+                self.store_offset(partition_id, offset).await;
+            }
+        }
+    }
+
+    async fn shutdown(mut self) -> Result<(), Box<dyn Error>> {
+        // Storing the offsets before leaving the group allows the remaining (or restarted) members to resume where this one stopped.
+        for (partition_id, offset) in std::mem::take(&mut self.processed_offsets) {
+            self.store_offset(partition_id, offset).await;
+        }
+
+        self.client
+            .leave_consumer_group(
+                self.consumer.stream(),
+                self.consumer.topic(),
+                &Identifier::named(CONSUMER_GROUP)?,
+            )
+            .await?;
+        self.client.shutdown().await?;
+        info!("Consumer group member: {} has left the group.", self.id);
+        Ok(())
+    }
+
+    async fn store_offset(&self, partition_id: u32, offset: u64) {
+        match self.consumer.store_offset(offset, Some(partition_id)).await {
+            Ok(()) => info!(
+                "Consumer group member: {} stored offset: {offset} for partition ID: {partition_id}",
+                self.id
+            ),
+            Err(error) => error!(
+                "Consumer group member: {} failed to store offset: {offset} for partition ID: {partition_id}: {error}",
+                self.id
+            ),
+        }
+    }
+}
@@ -24,8 +24,8 @@ use iggy::client_provider::ClientProviderConfig;
 use iggy::clients::client::IggyClient;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy_examples::shared::args::Args;
-use iggy_examples::shared::messages_generator::MessagesGenerator;
 use iggy_examples::shared::system;
+use iggy_test_data::generator::MessagesGenerator;
 use std::error::Error;
 use std::str::FromStr;
 use std::sync::Arc;
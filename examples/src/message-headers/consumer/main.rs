@@ -25,8 +25,8 @@ use iggy::clients::client::IggyClient;
 use iggy::models::header::HeaderKey;
 use iggy::models::messages::PolledMessage;
 use iggy_examples::shared::args::Args;
-use iggy_examples::shared::messages::*;
 use iggy_examples::shared::system;
+use iggy_test_data::messages::*;
 use std::error::Error;
 use std::sync::Arc;
 use tracing::{info, warn};
@@ -26,8 +26,8 @@ use iggy::clients::client::IggyClient;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy_examples::shared::args::Args;
-use iggy_examples::shared::messages_generator::MessagesGenerator;
 use iggy_examples::shared::system;
+use iggy_test_data::generator::MessagesGenerator;
 use std::collections::HashMap;
 use std::error::Error;
 use std::str::FromStr;
@@ -27,7 +27,7 @@ use iggy::consumer::ConsumerKind;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::utils::duration::IggyDuration;
 use iggy_examples::shared::args::Args;
-use iggy_examples::shared::messages::{
+use iggy_test_data::messages::{
     Envelope, OrderConfirmed, OrderCreated, OrderRejected, ORDER_CONFIRMED_TYPE,
     ORDER_CREATED_TYPE, ORDER_REJECTED_TYPE,
 };
@@ -27,7 +27,7 @@ use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
 use iggy_examples::shared::args::Args;
-use iggy_examples::shared::messages_generator::MessagesGenerator;
+use iggy_test_data::generator::MessagesGenerator;
 use std::error::Error;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -0,0 +1,155 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use clap::Parser;
+use futures_util::future::join_all;
+use iggy::client::Client;
+use iggy::client_provider;
+use iggy::client_provider::ClientProviderConfig;
+use iggy::clients::client::IggyClient;
+use iggy::clients::request_reply::IggyRequester;
+use iggy::error::IggyError;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::Partitioning;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use iggy_examples::shared::args::Args;
+use iggy_examples::shared::calculator::{
+    CalculationReply, CalculationRequest, Operation, REPLIES_TOPIC, REQUESTS_TOPIC,
+};
+use std::error::Error;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{info, warn};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry};
+
+const REQUEST_TIMEOUT: &str = "2s";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    Registry::default()
+        .with(tracing_subscriber::fmt::layer())
+        .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+        .init();
+    info!(
+        "Request-reply requester has started, selected transport: {}",
+        args.transport
+    );
+    let client_provider_config = Arc::new(ClientProviderConfig::from_args(args.to_sdk_args())?);
+    let client = client_provider::get_raw_client(client_provider_config, false).await?;
+    let client = IggyClient::builder().with_client(client).build()?;
+    client.connect().await?;
+
+    // The requester owns the requests topic, while the replies topic is created by the responder.
+    let mut producer = client
+        .producer(&args.stream_id, REQUESTS_TOPIC)?
+        .without_send_interval()
+        .partitioning(Partitioning::balanced())
+        .create_topic_if_not_exists(
+            1,
+            None,
+            IggyExpiry::ServerDefault,
+            MaxTopicSize::ServerDefault,
+        )
+        .build();
+    producer.init().await?;
+
+    let mut consumer = client
+        .consumer("request-reply-requester", &args.stream_id, REPLIES_TOPIC, 1)?
+        .polling_strategy(PollingStrategy::next())
+        .poll_interval(IggyDuration::from_str(&args.interval)?)
+        .init_retries(30, IggyDuration::from_str("1s")?)
+        .build();
+    consumer.init().await?;
+
+    let requester =
+        IggyRequester::new(producer, consumer, IggyDuration::from_str(REQUEST_TIMEOUT)?);
+    info!(
+        "Requests will be sent to stream: {}, topic: {} and the replies polled from topic: {} with timeout {REQUEST_TIMEOUT}.",
+        args.stream_id, REQUESTS_TOPIC, REPLIES_TOPIC
+    );
+
+    let mut sent_batches = 0;
+    loop {
+        if args.message_batches_limit > 0 && sent_batches == args.message_batches_limit {
+            info!("Sent {sent_batches} batches of requests, exiting.");
+            return Ok(());
+        }
+
+        // All the requests in the batch are outstanding at the same time, each of them completes independently.
+        let requests = create_requests(sent_batches, args.messages_per_batch);
+        let replies = join_all(
+            requests
+                .iter()
+                .map(|request| send_request(&requester, request)),
+        )
+        .await;
+        for (request, reply) in requests.iter().zip(replies) {
+            match reply {
+                Ok(reply) => info!("Received reply: {reply:?} for request: {request:?}"),
+                Err(IggyError::RequestTimedOut(correlation_id)) => {
+                    warn!(
+                        "Request: {request:?} with correlation ID: {correlation_id} has timed out."
+                    )
+                }
+                Err(error) => return Err(error.into()),
+            }
+        }
+        sent_batches += 1;
+        info!(
+            "Completed batch {sent_batches} of {} requests, outstanding requests: {}.",
+            requests.len(),
+            requester.outstanding_requests()
+        );
+    }
+}
+
+// Each batch contains the supported operations and a single unsupported one, which is never replied to.
+fn create_requests(batch: u64, requests_count: u32) -> Vec<CalculationRequest> {
+    let mut requests = (0..requests_count as i64)
+        .map(|i| CalculationRequest {
+            operation: if i % 2 == 0 {
+                Operation::Add
+            } else {
+                Operation::Multiply
+            },
+            left: batch as i64 + i,
+            right: i + 1,
+        })
+        .collect::<Vec<_>>();
+    requests.push(CalculationRequest {
+        operation: Operation::Divide,
+        left: batch as i64,
+        right: 1,
+    });
+    requests
+}
+
+async fn send_request(
+    requester: &IggyRequester,
+    request: &CalculationRequest,
+) -> Result<CalculationReply, IggyError> {
+    let payload = serde_json::to_vec(request).map_err(|_| IggyError::CannotSerializeResource)?;
+    let reply = requester.request(Bytes::from(payload)).await?;
+    serde_json::from_slice(&reply.message.payload).map_err(|_| IggyError::CannotDeserializeResource)
+}
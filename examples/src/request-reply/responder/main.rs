@@ -0,0 +1,137 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use clap::Parser;
+use futures_util::StreamExt;
+use iggy::client::Client;
+use iggy::client_provider;
+use iggy::client_provider::ClientProviderConfig;
+use iggy::clients::client::IggyClient;
+use iggy::clients::consumer::{AutoCommit, AutoCommitWhen, ReceivedMessage};
+use iggy::clients::request_reply::create_reply;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use iggy_examples::shared::args::Args;
+use iggy_examples::shared::calculator::{
+    CalculationReply, CalculationRequest, Operation, REPLIES_TOPIC, REQUESTS_TOPIC,
+};
+use std::error::Error;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{error, info, warn};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    Registry::default()
+        .with(tracing_subscriber::fmt::layer())
+        .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+        .init();
+    info!(
+        "Request-reply responder has started, selected transport: {}",
+        args.transport
+    );
+    let client_provider_config = Arc::new(ClientProviderConfig::from_args(args.to_sdk_args())?);
+    let client = client_provider::get_raw_client(client_provider_config, false).await?;
+    let client = IggyClient::builder().with_client(client).build()?;
+    client.connect().await?;
+
+    // The stream and the requests topic are created by the requester, so the consumer waits for them first.
+    let mut consumer = client
+        .consumer(
+            "request-reply-responder",
+            &args.stream_id,
+            REQUESTS_TOPIC,
+            1,
+        )?
+        .auto_commit(AutoCommit::When(AutoCommitWhen::PollingMessages))
+        .polling_strategy(PollingStrategy::next())
+        .poll_interval(IggyDuration::from_str(&args.interval)?)
+        .init_retries(30, IggyDuration::from_str("1s")?)
+        .build();
+    consumer.init().await?;
+
+    let mut producer = client
+        .producer(&args.stream_id, REPLIES_TOPIC)?
+        .without_send_interval()
+        .partitioning(Partitioning::balanced())
+        .create_topic_if_not_exists(
+            1,
+            None,
+            IggyExpiry::ServerDefault,
+            MaxTopicSize::ServerDefault,
+        )
+        .build();
+    producer.init().await?;
+
+    info!(
+        "Requests will be polled from stream: {}, topic: {} and the replies sent to topic: {}.",
+        args.stream_id, REQUESTS_TOPIC, REPLIES_TOPIC
+    );
+
+    // Every batch sent by the requester contains one additional, unsupported request.
+    let requests_limit = args.message_batches_limit * (args.messages_per_batch as u64 + 1);
+    let mut handled_requests = 0;
+    while let Some(request) = consumer.next().await {
+        let request = match request {
+            Ok(request) => request,
+            Err(error) => {
+                error!("Error while polling requests: {error}");
+                continue;
+            }
+        };
+
+        if let Some(reply) = handle_request(&request)? {
+            producer.send_one(reply).await?;
+        }
+
+        handled_requests += 1;
+        if requests_limit > 0 && handled_requests == requests_limit {
+            info!("Handled {handled_requests} requests, exiting.");
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_request(request: &ReceivedMessage) -> Result<Option<Message>, Box<dyn Error>> {
+    let correlation_id = request.correlation_id()?.unwrap_or_default();
+    let calculation = serde_json::from_slice::<CalculationRequest>(&request.message.payload)?;
+    let result = match calculation.operation {
+        Operation::Add => calculation.left + calculation.right,
+        Operation::Multiply => calculation.left * calculation.right,
+        Operation::Divide => {
+            warn!("Unsupported operation in request: {calculation:?} with correlation ID: {correlation_id}, no reply will be sent.");
+            return Ok(None);
+        }
+    };
+
+    info!(
+        "Handled request: {calculation:?} with correlation ID: {correlation_id}, result: {result}"
+    );
+    let reply = serde_json::to_vec(&CalculationReply { result })?;
+    Ok(Some(create_reply(request, Bytes::from(reply))?))
+}
@@ -238,6 +238,8 @@ impl Args {
             tcp_tls_domain: self.tcp_tls_domain.clone(),
             tcp_tls_ca_file: None,
             tcp_nodelay: self.tcp_nodelay,
+            tcp_frame_checksums: false,
+            tcp_zero_copy_polling: false,
             quic_client_address: self.quic_client_address.clone(),
             quic_server_address: self.quic_server_address.clone(),
             quic_server_name: self.quic_server_name.clone(),
@@ -0,0 +1,43 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use serde::{Deserialize, Serialize};
+
+pub const REQUESTS_TOPIC: &str = "requests";
+pub const REPLIES_TOPIC: &str = "replies";
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    Add,
+    Multiply,
+    // The responder doesn't support this operation and never replies, so the request times out.
+    Divide,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CalculationRequest {
+    pub operation: Operation,
+    pub left: i64,
+    pub right: i64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CalculationReply {
+    pub result: i64,
+}
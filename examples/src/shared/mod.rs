@@ -17,8 +17,7 @@
  */
 
 pub mod args;
+pub mod calculator;
 pub mod client;
-pub mod messages;
-pub mod messages_generator;
 pub mod stream;
 pub mod system;
@@ -474,6 +474,7 @@ pub async fn create_user(client: &IggyClient, username: &str) {
                 global: GlobalPermissions {
                     manage_servers: true,
                     read_servers: true,
+                    manage_config: true,
                     manage_users: true,
                     read_users: true,
                     manage_streams: true,
@@ -484,6 +485,7 @@ pub async fn create_user(client: &IggyClient, username: &str) {
                     send_messages: true,
                 },
                 streams: None,
+                patterns: None,
             }),
         )
         .await
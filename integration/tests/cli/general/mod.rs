@@ -17,6 +17,7 @@
  */
 
 mod test_help_command;
+mod test_json_rpc_mode;
 mod test_missing_credentials;
 mod test_overview_command;
 mod test_quiet_mode;
@@ -40,13 +40,20 @@ Commands:
   ping             ping iggy server
   me               get current client info
   stats            get iggy server statistics
+  config           get effective iggy server configuration
+  audit            get audit log of administrative actions
   snapshot         collect iggy server troubleshooting data
+  backup           backup operations
+  diagnostics      diagnostics operations [aliases: diag]
   pat              personal access token operations
   user             user operations [aliases: u]
   client           client operations [aliases: c]
   consumer-group   consumer group operations [aliases: g]
   consumer-offset  consumer offset operations [aliases: o]
+  bookmark         bookmark operations [aliases: b]
   message          message operations [aliases: m]
+  schema           schema registry operations [aliases: sc]
+  quota            throughput quota operations [aliases: q]
   context          context operations [aliases: ctx]
   login            login to Iggy server [aliases: li]
   logout           logout from Iggy server [aliases: lo]
@@ -216,6 +223,38 @@ Options:
 {CLAP_INDENT}
           [possible values: bash, elvish, fish, powershell, zsh]
 
+      --json-rpc
+          JSON-RPC mode for the tooling integration
+{CLAP_INDENT}
+          Option makes the tool read JSON-RPC 2.0 requests from standard input, one per line,
+          execute them using a single connection to the server, and write the responses
+          to standard output, one per line. Method is a command with its subcommands joined
+          by a dot and params are its remaining arguments. Response result contains
+          the executed command and its output lines.
+          Option cannot be combined with commands and quiet mode.
+{CLAP_INDENT}
+          Example:
+           {{"jsonrpc": "2.0", "id": 1, "method": "topic.list", "params": ["stream"]}}
+
+      --cache-ttl <CACHE_TTL>
+          Time to live of the cached topology listings
+{CLAP_INDENT}
+          Option makes the tool cache the streams, topics and consumer groups returned
+          by the list and get commands in the local file (separately for each server address)
+          and reuse them until they expire, so the commands respond instantly even against
+          the servers with tens of thousands of entities. Cache is cleared by the commands
+          changing the streams, topics, partitions or consumer groups.
+          Cache is disabled by default.
+{CLAP_INDENT}
+          Example:
+           iggy --cache-ttl 5m topic list stream
+
+      --refresh
+          Refresh the cached topology listings
+{CLAP_INDENT}
+          Option makes the tool fetch the listings from the server and store them
+          in the cache, even if the cached ones haven't expired yet.
+
   -h, --help
           Print help (see a summary with '-h')
 
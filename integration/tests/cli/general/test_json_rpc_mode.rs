@@ -0,0 +1,86 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::{Client, StreamClient};
+use iggy::identifier::Identifier;
+use predicates::str::contains;
+use serial_test::parallel;
+
+struct TestJsonRpcModeCmd {
+    stream_name: String,
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestJsonRpcModeCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .opt("--json-rpc")
+            .with_env_credentials()
+    }
+
+    fn provide_stdin_input(&self) -> Option<Vec<String>> {
+        Some(vec![
+            format!(
+                r#"{{"jsonrpc": "2.0", "id": 1, "method": "stream.create", "params": ["{}"]}}"#,
+                self.stream_name
+            ),
+            r#"{"jsonrpc": "2.0", "id": 2, "method": "stream.unknown"}"#.to_string(),
+            r#"{"jsonrpc": "2.0", "id": 3, "method": "stream.get", "params": ["missing"]}"#
+                .to_string(),
+            r#"{"jsonrpc": "2.0", "method": "ping"}"#.to_string(),
+            "not a json".to_string(),
+        ])
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(contains(format!(
+                r#"{{"jsonrpc":"2.0","id":1,"result":{{"command":"create stream with name: {} and ID auto incremented","output":["Stream with name: {} and ID auto incremented created"]}}}}"#,
+                self.stream_name, self.stream_name
+            )))
+            .stdout(contains(r#"{"jsonrpc":"2.0","id":2,"error":{"code":-32601"#))
+            .stdout(contains(r#"{"jsonrpc":"2.0","id":3,"error":{"code":-32000"#))
+            .stdout(contains(r#"{"jsonrpc":"2.0","id":null,"error":{"code":-32700"#));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream_id = Identifier::from_str_value(&self.stream_name).unwrap();
+        let stream = client.get_stream(&stream_id).await;
+        assert!(stream.unwrap().is_some());
+        client.delete_stream(&stream_id).await.unwrap();
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_execute_commands_read_from_stdin() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestJsonRpcModeCmd {
+            stream_name: String::from("json-rpc"),
+        })
+        .await;
+}
@@ -51,13 +51,20 @@ Commands:
   ping             ping iggy server
   me               get current client info
   stats            get iggy server statistics
+  config           get effective iggy server configuration
+  audit            get audit log of administrative actions
   snapshot         collect iggy server troubleshooting data
+  backup           backup operations
+  diagnostics      diagnostics operations [aliases: diag]
   pat              personal access token operations
   user             user operations [aliases: u]
   client           client operations [aliases: c]
   consumer-group   consumer group operations [aliases: g]
   consumer-offset  consumer offset operations [aliases: o]
+  bookmark         bookmark operations [aliases: b]
   message          message operations [aliases: m]
+  schema           schema registry operations [aliases: sc]
+  quota            throughput quota operations [aliases: q]
   context          context operations [aliases: ctx]
   login            login to Iggy server [aliases: li]
   logout           logout from Iggy server [aliases: lo]
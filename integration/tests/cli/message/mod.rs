@@ -18,6 +18,7 @@
 
 mod test_message_flush_command;
 mod test_message_help_command;
+mod test_message_mirror_command;
 mod test_message_poll_command;
 mod test_message_poll_to_file_command;
 mod test_message_reply_via_file;
@@ -33,10 +33,11 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} message <COMMAND>
 
 Commands:
-  send   Send messages to given topic ID and given stream ID [aliases: s]
-  poll   Poll messages from given topic ID and given stream ID [aliases: p]
-  flush  Flush messages from given topic ID and given stream ID [aliases: f]
-  help   Print this message or the help of the given subcommand(s)
+  send    Send messages to given topic ID and given stream ID [aliases: s]
+  poll    Poll messages from given topic ID and given stream ID [aliases: p]
+  flush   Flush messages from given topic ID and given stream ID [aliases: f]
+  mirror  Mirror messages from given stream ID to the target server [aliases: m]
+  help    Print this message or the help of the given subcommand(s)
 
 Options:
   -h, --help  Print help
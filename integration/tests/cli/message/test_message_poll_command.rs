@@ -366,6 +366,7 @@ Examples:
  iggy message poll --offset 0 stream 2 1
  iggy message poll --offset 0 1 topic 1
  iggy message poll --offset 0 stream topic 1
+ iggy message poll --next --commit-mode after_poll stream topic 1
 
 {USAGE_PREFIX} message poll [OPTIONS] <--offset <OFFSET>|--first|--last|--next> <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
 
@@ -395,6 +396,13 @@ Options:
           Flag indicates whether to commit offset on the server automatically
           after polling the messages.
 
+      --commit-mode <COMMIT_MODE>
+          Auto commit mode
+{CLAP_INDENT}
+          Mode in which the offset is committed on the server automatically,
+          one of: manual, before_delivery, after_poll or interval:<DURATION>,
+          e.g. interval:5s. Cannot be combined with the auto commit flag.
+
   -o, --offset <OFFSET>
           Polling strategy - offset to start polling messages from
 {CLAP_INDENT}
@@ -464,6 +472,7 @@ Arguments:
 Options:
   -m, --message-count <MESSAGE_COUNT>  Number of messages to poll [default: 1]
   -a, --auto-commit                    Auto commit offset
+      --commit-mode <COMMIT_MODE>      Auto commit mode
   -o, --offset <OFFSET>                Polling strategy - offset to start polling messages from
   -f, --first                          Polling strategy - start polling from the first message in the partition
   -l, --last                           Polling strategy - start polling from the last message in the partition
@@ -449,6 +449,15 @@ Options:
           will be read from the file and sent as is. Option cannot be used
           with the messages option (messages given as command line arguments).
 
+      --confirmation <CONFIRMATION>
+          When the server confirms the sent messages
+{CLAP_INDENT}
+          Confirmation level can be one of the following: no_wait (once appended,
+          without waiting for the disk write), received (once buffered by the server)
+          or persisted (once written to disk and synced).
+{CLAP_INDENT}
+          [default: received]
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -480,6 +489,7 @@ Options:
   -m, --message-key <MESSAGE_KEY>    Messages key which will be used to partition the messages
   -H, --headers <HEADERS>            Comma separated list of key:kind:value, sent as header with the message
       --input-file <INPUT_FILE>      Input file with messages to be sent
+      --confirmation <CONFIRMATION>  When the server confirms the sent messages [default: received]
   -h, --help                         Print help (see more with '--help')
 "#,
             ),
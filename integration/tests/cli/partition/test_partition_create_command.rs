@@ -249,8 +249,9 @@ Examples
  iggy partition create prod 2 2
  iggy partition create test sensor 2
  iggy partition create 1 sensor 16
+ iggy partition create --key-routing-policy preserve_existing prod sensor 4
 
-{USAGE_PREFIX} partition create <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT>
+{USAGE_PREFIX} partition create [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT>
 
 Arguments:
   <STREAM_ID>
@@ -267,6 +268,14 @@ Arguments:
           Partitions count to be created
 
 Options:
+  -k, --key-routing-policy <KEY_ROUTING_POLICY>
+          Policy for the messages keys already routed to the existing partitions
+{CLAP_INDENT}
+          "rehash" re-hashes all the keys across the new number of partitions (existing keys might be moved),
+          "preserve_existing" keeps the existing keys in their partitions and routes only the new keys to all the partitions.
+{CLAP_INDENT}
+          [default: rehash]
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -287,7 +296,7 @@ pub async fn should_short_help_match() {
                 r#"Create partitions for the specified topic ID
 and stream ID based on the given count.
 
-{USAGE_PREFIX} partition create <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT>
+{USAGE_PREFIX} partition create [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT>
 
 Arguments:
   <STREAM_ID>         Stream ID to create partitions
@@ -295,7 +304,10 @@ Arguments:
   <PARTITIONS_COUNT>  Partitions count to be created
 
 Options:
-  -h, --help  Print help (see more with '--help')
+  -k, --key-routing-policy <KEY_ROUTING_POLICY>
+          Policy for the messages keys already routed to the existing partitions [default: rehash]
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
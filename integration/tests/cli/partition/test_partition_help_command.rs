@@ -37,6 +37,12 @@ Commands:
           and stream ID based on the given count. [aliases: c]
   delete  Delete partitions for the specified topic ID
           and stream ID based on the given count. [aliases: d]
+  get     Get details of a single partition with given ID (or name)
+          for the specified topic ID and stream ID. [aliases: g]
+  update  Update name of a partition with given ID (or name)
+          for the specified topic ID and stream ID. [aliases: u]
+  move    Move data of a partition with given ID (or name)
+          to another data directory configured on the server. [aliases: m]
   help    Print this message or the help of the given subcommand(s)
 
 Options:
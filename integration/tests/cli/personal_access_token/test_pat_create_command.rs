@@ -135,6 +135,8 @@ Examples
  iggy pat create name
  iggy pat create client 1day
  iggy pat create sensor 3weeks
+ iggy pat create dashboard --read-only --streams 1,2
+ iggy pat create ci --allowed-addresses 10.0.0.0/8
 
 {USAGE_PREFIX} pat create [OPTIONS] <NAME> [EXPIRY]...
 
@@ -153,6 +155,21 @@ Options:
 {CLAP_INDENT}
           Generated token is stored in a platform-specific secure storage without revealing its content to the user. It can be used to authenticate on iggy server using associated name and -n/--token-name command line option instead of -u/--username and -p/--password or -t/--token. In quiet mode only the token name is printed. This option can only be used for creating tokens which does not have expiry time set.
 
+  -r, --read-only
+          Allow only reading with the personal access token
+{CLAP_INDENT}
+          The token can be used only to get the resources, poll the messages and store the consumer offsets, e.g. when it's embedded in a dashboard.
+
+      --streams <STREAMS>
+          Comma separated list of stream IDs the personal access token is limited to
+{CLAP_INDENT}
+          The actions not related to any of these streams are not allowed with the token.
+
+      --allowed-addresses <ALLOWED_ADDRESSES>
+          Comma separated list of addresses the personal access token can be used from
+{CLAP_INDENT}
+          The addresses can be provided in the CIDR notation, e.g. 10.0.0.0/8,192.168.1.10. Logging in with the token from any other address is denied.
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -179,8 +196,11 @@ Arguments:
   [EXPIRY]...  Personal access token expiry time in human-readable format
 
 Options:
-  -s, --store-token  Store token in an underlying platform-specific secure store
-  -h, --help         Print help (see more with '--help')
+  -s, --store-token                            Store token in an underlying platform-specific secure store
+  -r, --read-only                              Allow only reading with the personal access token
+      --streams <STREAMS>                      Comma separated list of stream IDs the personal access token is limited to
+      --allowed-addresses <ALLOWED_ADDRESSES>  Comma separated list of addresses the personal access token can be used from
+  -h, --help                                   Print help (see more with '--help')
 "#,
             ),
         ))
@@ -16,10 +16,15 @@
  * under the License.
  */
 
+mod test_audit_command;
+mod test_backup_create_command;
+mod test_backup_restore_command;
 // Disable tests due to missing keyring on macOS until #794 is implemented and skip for musl targets
 // due to missing keyring support while running tests under cross
 #[cfg(not(any(target_os = "macos", target_env = "musl")))]
 mod test_cli_session_scenario;
+mod test_config_command;
+mod test_diagnostics_collect_command;
 #[cfg(not(any(target_os = "macos", target_env = "musl")))]
 mod test_login_cmd;
 mod test_login_command;
@@ -0,0 +1,118 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestAuditCmd {
+    stream_id: u32,
+    stream_name: String,
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestAuditCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, Some(self.stream_id))
+            .await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("audit")
+            .arg("--action")
+            .arg("stream.create")
+            .arg("--list-mode")
+            .arg("list")
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(starts_with("Executing get audit log in list mode\n"))
+            .stdout(contains("|stream.create|"))
+            .stdout(contains(self.stream_name.as_str()))
+            .stdout(contains("|success|"));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestAuditCmd {
+            stream_id: 1,
+            stream_name: String::from("audited"),
+        })
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["audit", "--help"],
+            format!(
+                r#"get audit log of administrative actions
+
+Collect the most recent administrative actions (e.g. creating or deleting streams,
+topics and users, permission changes, purges) recorded by the server, along with
+the user, IP address, timestamp and outcome of each of them.
+
+{USAGE_PREFIX} audit [OPTIONS]
+
+Options:
+  -u, --user-id <USER_ID>
+          Show only the actions invoked by the user with the given ID
+
+  -a, --action <ACTION>
+          Show only the actions with the given name, e.g. stream.create
+
+  -n, --limit <LIMIT>
+          Maximum number of the most recent entries to show
+{CLAP_INDENT}
+          [default: 100]
+
+  -l, --list-mode <LIST_MODE>
+          List mode (table or list)
+{CLAP_INDENT}
+          [default: table]
+          [possible values: table, list]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,144 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestBackupCreateCmd {
+    stream_id: u32,
+    stream_name: String,
+    backup_name: String,
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestBackupCreateCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, Some(self.stream_id))
+            .await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("backup")
+            .arg("create")
+            .arg(self.backup_name.clone())
+            .arg(self.stream_id.to_string())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing create backup with name: {} of streams with IDs: {} in destination: local\n",
+            self.backup_name, self.stream_id
+        );
+
+        command_state
+            .success()
+            .stdout(starts_with(message))
+            .stdout(contains(format!(
+                "Backup with name: {} of streams with IDs: {} created in destination: local",
+                self.backup_name, self.stream_id
+            )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestBackupCreateCmd {
+            stream_id: 1,
+            stream_name: String::from("archived"),
+            backup_name: String::from("nightly"),
+        })
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["backup", "create", "--help"],
+            format!(
+                r#"Create consistent point-in-time backup of server metadata and selected streams
+
+Closed segments are hard-linked or copied, open segments are flushed and copied
+up to their size at the time of taking the backup. Backup is written either to
+the local archive directory or to the S3-compatible object storage configured
+on the server, and can be restored with the `--restore` server argument.
+
+Stream IDs can be specified as stream names or IDs, all streams are archived
+if none is specified. Incremental backup, taken on top of the base backup,
+archives only the segments which are not archived by the base backup yet.
+
+Examples
+ iggy backup create nightly
+ iggy backup create before-upgrade 1 orders
+ iggy backup create offsite prod --destination s3
+ iggy backup create nightly-2 --base nightly
+
+{USAGE_PREFIX} backup create [OPTIONS] <NAME> [STREAMS]...
+
+Arguments:
+  <NAME>
+          Name of the backup
+{CLAP_INDENT}
+          Name can contain alphanumeric characters, dots, dashes and underscores
+
+  [STREAMS]...
+          Stream IDs to archive, all streams are archived if none is specified
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+Options:
+  -d, --destination <DESTINATION>
+          Destination of the backup
+{CLAP_INDENT}
+          [default: local]
+          [possible values: local, s3]
+
+  -b, --base <BASE>
+          Name of the previous backup to take the incremental backup on top of
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,149 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::identifier::Identifier;
+use iggy::models::backup::BackupDestination;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestBackupRestoreCmd {
+    stream_id: u32,
+    stream_name: String,
+    backup_name: String,
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestBackupRestoreCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, Some(self.stream_id))
+            .await;
+        assert!(stream.is_ok());
+        let stream_id = Identifier::numeric(self.stream_id).unwrap();
+        let backup = client
+            .create_backup(
+                &self.backup_name,
+                &[stream_id.clone()],
+                BackupDestination::Local,
+                None,
+            )
+            .await;
+        assert!(backup.is_ok());
+        let stream = client.delete_stream(&stream_id).await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("backup")
+            .arg("restore")
+            .arg(self.backup_name.clone())
+            .arg(self.stream_id.to_string())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing restore streams with IDs: {} from backup with name: {}\n",
+            self.stream_id, self.backup_name
+        );
+
+        command_state
+            .success()
+            .stdout(starts_with(message))
+            .stdout(contains(format!(
+                "Restored 1 stream(s) from backup with name: {}",
+                self.backup_name
+            )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream_id = Identifier::numeric(self.stream_id).unwrap();
+        let stream = client.get_stream(&stream_id).await;
+        assert!(stream.is_ok());
+        let stream = stream.unwrap().expect("Restored stream should exist");
+        assert_eq!(stream.name, self.stream_name);
+
+        let stream = client.delete_stream(&stream_id).await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestBackupRestoreCmd {
+            stream_id: 2,
+            stream_name: String::from("restored"),
+            backup_name: String::from("before-restore"),
+        })
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["backup", "restore", "--help"],
+            format!(
+                r#"Restore streams from backup into running server
+
+Streams are restored with their original IDs, names, topics, partitions,
+consumer groups, message offsets and consumer offsets. None of the restored
+streams can exist on the server. Backup is looked up in the local archive
+directory first, and downloaded from the S3-compatible object storage if
+not found there.
+
+Stream IDs can be specified as stream names or IDs, all archived streams
+are restored if none is specified.
+
+Examples
+ iggy backup restore nightly
+ iggy backup restore nightly-2 1 orders
+
+{USAGE_PREFIX} backup restore <NAME> [STREAMS]...
+
+Arguments:
+  <NAME>
+          Name of the backup to restore
+
+  [STREAMS]...
+          Stream IDs to restore, all archived streams are restored if none is specified
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,130 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use predicates::str::starts_with;
+use serial_test::parallel;
+use std::{
+    fs::{self, File},
+    io::Read,
+};
+use tempfile::tempdir;
+use zip::ZipArchive;
+
+struct TestDiagnosticsCollectCmd {
+    temp_out_dir: String,
+}
+
+impl TestDiagnosticsCollectCmd {
+    fn new(temp_out_dir: String) -> Self {
+        TestDiagnosticsCollectCmd { temp_out_dir }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestDiagnosticsCollectCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("diagnostics")
+            .arg("collect")
+            .arg("--out-dir")
+            .arg(self.temp_out_dir.as_str())
+            .arg("--log-lines")
+            .arg("100")
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(starts_with("Executing collect diagnostics command\n"));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    let temp_out_dir = tempdir().unwrap();
+    iggy_cmd_test
+        .execute_test(TestDiagnosticsCollectCmd::new(
+            temp_out_dir.path().to_str().unwrap().to_string(),
+        ))
+        .await;
+
+    let bundle_file = fs::read_dir(&temp_out_dir)
+        .unwrap()
+        .filter_map(Result::ok)
+        .find(|entry| {
+            let file_name = entry.file_name();
+            file_name.to_string_lossy().starts_with("diagnostics")
+        })
+        .unwrap();
+
+    let file = File::open(bundle_file.path()).unwrap();
+    let mut archive = ZipArchive::new(file).unwrap();
+    assert!(archive.by_name("errors.txt").is_err());
+
+    let read_entry = |archive: &mut ZipArchive<File>, name: &str| {
+        let mut entry = archive.by_name(name).unwrap();
+        let mut contents = String::new();
+        entry.read_to_string(&mut contents).unwrap();
+        contents
+    };
+
+    assert!(read_entry(&mut archive, "version.json").contains("server_version"));
+    assert!(read_entry(&mut archive, "stats.json").contains("streams_count"));
+    assert!(read_entry(&mut archive, "config.json").contains("system.segment.size"));
+    assert!(read_entry(&mut archive, "features.json").contains("http"));
+    assert!(read_entry(&mut archive, "topology.json").contains("streams"));
+    let logs = read_entry(&mut archive, "logs.txt");
+    assert!(logs.contains("INFO ThreadId"));
+    assert!(logs.lines().count() <= 100);
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["diagnostics", "collect", "-h"],
+            format!(
+                r#"Collect redacted support bundle
+
+{USAGE_PREFIX} diagnostics collect [OPTIONS]
+
+Options:
+  -o, --out-dir <OUT_DIR>      Output directory for the support bundle
+  -l, --log-lines <LOG_LINES>  Number of the most recent server log lines to include
+  -h, --help                   Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
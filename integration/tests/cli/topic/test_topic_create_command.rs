@@ -322,6 +322,50 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+      --retention-mode <RETENTION_MODE>
+          Retention mode deciding when the oldest segments are deleted: "time", "size" or "time_and_size"
+{CLAP_INDENT}
+          "size" deletes the oldest segments once the topic exceeds its max size or a partition exceeds the max partition size,
+          "time_and_size" applies both the message expiry and the size limits.
+          Skipping parameter keeps the time-based retention (message expiry) only.
+
+      --max-partition-size <MAX_PARTITION_SIZE>
+          Max partition size in human-readable format like "1GB", applicable to the size-based retention only
+
+      --retention-hook <RETENTION_HOOK>
+          URL of the webhook called with the segment metadata before the segment is deleted by the retention
+{CLAP_INDENT}
+          The deletion is deferred until the webhook responds with the success status.
+
+      --tiering-min-segment-age <TIERING_MIN_SEGMENT_AGE>
+          Minimum age in human-readable format like "7days" after which the closed segments are offloaded to the tiered storage
+{CLAP_INDENT}
+          Skipping both tiering parameters keeps all the segments locally.
+
+      --tiering-max-local-partition-size <TIERING_MAX_LOCAL_PARTITION_SIZE>
+          Max size of the segments kept locally by a partition in human-readable format like "10GB", the oldest closed segments are offloaded first
+
+      --fsync-every-n-messages <FSYNC_EVERY_N_MESSAGES>
+          Number of the persisted messages after which the topic partitions are synced to the disk
+{CLAP_INDENT}
+          Skipping all the fsync parameters makes the topic follow the server configuration.
+
+      --fsync-interval <FSYNC_INTERVAL>
+          Interval in human-readable format like "1s" after which the topic partitions are synced to the disk
+
+      --fsync-never
+          Never sync the topic partitions explicitly, relying on the OS flush instead
+
+      --segment-max-age <SEGMENT_MAX_AGE>
+          Max age of the segments in human-readable format like "6h", after which they're closed even if not full
+{CLAP_INDENT}
+          Skipping the parameter makes the segments close only once full.
+
+      --queue-visibility-timeout <QUEUE_VISIBILITY_TIMEOUT>
+          Visibility timeout in human-readable format like "30s", switching the topic to the queue mode with the individually acknowledged messages
+{CLAP_INDENT}
+          Skipping the parameter makes the topic consumed by the offsets.
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -356,6 +400,26 @@ Options:
           Max topic size in human-readable format like "unlimited" or "15GB" [default: server_default]
   -r, --replication-factor <REPLICATION_FACTOR>
           Replication factor for the topic [default: 1]
+      --retention-mode <RETENTION_MODE>
+          Retention mode deciding when the oldest segments are deleted: "time", "size" or "time_and_size"
+      --max-partition-size <MAX_PARTITION_SIZE>
+          Max partition size in human-readable format like "1GB", applicable to the size-based retention only
+      --retention-hook <RETENTION_HOOK>
+          URL of the webhook called with the segment metadata before the segment is deleted by the retention
+      --tiering-min-segment-age <TIERING_MIN_SEGMENT_AGE>
+          Minimum age in human-readable format like "7days" after which the closed segments are offloaded to the tiered storage
+      --tiering-max-local-partition-size <TIERING_MAX_LOCAL_PARTITION_SIZE>
+          Max size of the segments kept locally by a partition in human-readable format like "10GB", the oldest closed segments are offloaded first
+      --fsync-every-n-messages <FSYNC_EVERY_N_MESSAGES>
+          Number of the persisted messages after which the topic partitions are synced to the disk
+      --fsync-interval <FSYNC_INTERVAL>
+          Interval in human-readable format like "1s" after which the topic partitions are synced to the disk
+      --fsync-never
+          Never sync the topic partitions explicitly, relying on the OS flush instead
+      --segment-max-age <SEGMENT_MAX_AGE>
+          Max age of the segments in human-readable format like "6h", after which they're closed even if not full
+      --queue-visibility-timeout <QUEUE_VISIBILITY_TIMEOUT>
+          Visibility timeout in human-readable format like "30s", switching the topic to the queue mode with the individually acknowledged messages
   -h, --help
           Print help (see more with '--help')
 "#,
@@ -191,14 +191,17 @@ pub async fn should_help_match() {
 
 Stream ID can be specified as a stream name or ID
 Topic ID can be specified as a topic name or ID
+If deferred, the topic is removed once the consumer groups
+have consumed all of its messages or the grace period has elapsed
 
 Examples
  iggy topic delete 1 1
  iggy topic delete prod 2
  iggy topic delete test debugs
  iggy topic delete 2 debugs
+ iggy topic delete --deferred prod 2
 
-{USAGE_PREFIX} topic delete <STREAM_ID> <TOPIC_ID>
+{USAGE_PREFIX} topic delete [OPTIONS] <STREAM_ID> <TOPIC_ID>
 
 Arguments:
   <STREAM_ID>
@@ -212,6 +215,9 @@ Arguments:
           Topic ID can be specified as a topic name or ID
 
 Options:
+  -d, --deferred
+          Remove the topic once the consumer groups have consumed all of its messages
+
   -h, --help
           Print help (see a summary with '-h')
 ",
@@ -231,14 +237,15 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Delete topic with given ID in given stream ID
 
-{USAGE_PREFIX} topic delete <STREAM_ID> <TOPIC_ID>
+{USAGE_PREFIX} topic delete [OPTIONS] <STREAM_ID> <TOPIC_ID>
 
 Arguments:
   <STREAM_ID>  Stream ID to delete topic
   <TOPIC_ID>   Topic ID to delete
 
 Options:
-  -h, --help  Print help (see more with '--help')
+  -d, --deferred  Remove the topic once the consumer groups have consumed all of its messages
+  -h, --help      Print help (see more with '--help')
 "#,
             ),
         ))
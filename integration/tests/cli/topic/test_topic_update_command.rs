@@ -426,6 +426,46 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+      --retention-mode <RETENTION_MODE>
+          New retention mode deciding when the oldest segments are deleted: "time", "size" or "time_and_size"
+{CLAP_INDENT}
+          Skipping parameter keeps the current retention policy.
+
+      --max-partition-size <MAX_PARTITION_SIZE>
+          New max partition size in human-readable format like "1GB", applicable to the size-based retention only
+
+      --retention-hook <RETENTION_HOOK>
+          New URL of the webhook called with the segment metadata before the segment is deleted by the retention
+
+      --tiering-min-segment-age <TIERING_MIN_SEGMENT_AGE>
+          New minimum age in human-readable format like "7days" after which the closed segments are offloaded to the tiered storage
+{CLAP_INDENT}
+          Skipping both tiering parameters keeps the current tiering policy.
+
+      --tiering-max-local-partition-size <TIERING_MAX_LOCAL_PARTITION_SIZE>
+          New max size of the segments kept locally by a partition in human-readable format like "10GB"
+
+      --fsync-every-n-messages <FSYNC_EVERY_N_MESSAGES>
+          New number of the persisted messages after which the topic partitions are synced to the disk
+{CLAP_INDENT}
+          Skipping all the fsync parameters keeps the current fsync policy.
+
+      --fsync-interval <FSYNC_INTERVAL>
+          New interval in human-readable format like "1s" after which the topic partitions are synced to the disk
+
+      --fsync-never
+          Never sync the topic partitions explicitly, relying on the OS flush instead
+
+      --segment-max-age <SEGMENT_MAX_AGE>
+          New max age of the segments in human-readable format like "6h", after which they're closed even if not full
+{CLAP_INDENT}
+          Skipping the parameter keeps the current segment rollover policy.
+
+      --queue-visibility-timeout <QUEUE_VISIBILITY_TIMEOUT>
+          New visibility timeout in human-readable format like "30s", switching the topic to the queue mode with the individually acknowledged messages
+{CLAP_INDENT}
+          Skipping the parameter keeps the current queue policy.
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -459,6 +499,26 @@ Options:
           New max topic size in human-readable format like "unlimited" or "15GB" [default: server_default]
   -r, --replication-factor <REPLICATION_FACTOR>
           New replication factor for the topic [default: 1]
+      --retention-mode <RETENTION_MODE>
+          New retention mode deciding when the oldest segments are deleted: "time", "size" or "time_and_size"
+      --max-partition-size <MAX_PARTITION_SIZE>
+          New max partition size in human-readable format like "1GB", applicable to the size-based retention only
+      --retention-hook <RETENTION_HOOK>
+          New URL of the webhook called with the segment metadata before the segment is deleted by the retention
+      --tiering-min-segment-age <TIERING_MIN_SEGMENT_AGE>
+          New minimum age in human-readable format like "7days" after which the closed segments are offloaded to the tiered storage
+      --tiering-max-local-partition-size <TIERING_MAX_LOCAL_PARTITION_SIZE>
+          New max size of the segments kept locally by a partition in human-readable format like "10GB"
+      --fsync-every-n-messages <FSYNC_EVERY_N_MESSAGES>
+          New number of the persisted messages after which the topic partitions are synced to the disk
+      --fsync-interval <FSYNC_INTERVAL>
+          New interval in human-readable format like "1s" after which the topic partitions are synced to the disk
+      --fsync-never
+          Never sync the topic partitions explicitly, relying on the OS flush instead
+      --segment-max-age <SEGMENT_MAX_AGE>
+          New max age of the segments in human-readable format like "6h", after which they're closed even if not full
+      --queue-visibility-timeout <QUEUE_VISIBILITY_TIMEOUT>
+          New visibility timeout in human-readable format like "30s", switching the topic to the queue mode with the individually acknowledged messages
   -h, --help
           Print help (see more with '--help')
 "#,
@@ -22,6 +22,7 @@ use iggy::models::permissions::Permissions;
 pub(crate) struct PermissionsTestArgs {
     pub(crate) global_permissions: Option<String>,
     pub(crate) stream_permissions: Vec<String>,
+    pub(crate) pattern_permissions: Vec<String>,
     pub(crate) expected_permissions: Option<Permissions>,
 }
 
@@ -34,10 +35,16 @@ impl PermissionsTestArgs {
         Self {
             global_permissions,
             stream_permissions,
+            pattern_permissions: vec![],
             expected_permissions,
         }
     }
 
+    pub(crate) fn with_pattern_permissions(mut self, pattern_permissions: Vec<String>) -> Self {
+        self.pattern_permissions = pattern_permissions;
+        self
+    }
+
     pub(crate) fn as_arg(&self) -> Vec<String> {
         let mut args = vec![];
         if let Some(global_permissions) = &self.global_permissions {
@@ -51,6 +58,12 @@ impl PermissionsTestArgs {
                 .flat_map(|i| vec![String::from("--stream-permissions"), i.clone()])
                 .collect::<Vec<String>>(),
         );
+        args.extend(
+            self.pattern_permissions
+                .iter()
+                .flat_map(|i| vec![String::from("--pattern-permissions"), i.clone()])
+                .collect::<Vec<String>>(),
+        );
         args
     }
 }
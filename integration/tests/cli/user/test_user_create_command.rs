@@ -169,6 +169,7 @@ pub async fn should_be_successful() {
                     global: GlobalPermissions {
                         manage_servers: false,
                         read_servers: true,
+                        manage_config: false,
                         manage_users: false,
                         read_users: true,
                         manage_streams: false,
@@ -179,6 +180,7 @@ pub async fn should_be_successful() {
                         send_messages: false,
                     },
                     streams: None,
+                    patterns: None,
                 }),
             ),
         ))
@@ -194,6 +196,7 @@ pub async fn should_be_successful() {
                 Some(Permissions {
                     global: GlobalPermissions::default(),
                     streams: Some(AHashMap::from([(3u32, StreamPermissions::default())])),
+                    patterns: None,
                 }),
             ),
         ))
@@ -223,6 +226,7 @@ pub async fn should_be_successful() {
                             ..Default::default()
                         },
                     )])),
+                    patterns: None,
                 }),
             ),
         ))
@@ -239,6 +243,7 @@ pub async fn should_be_successful() {
                     global: GlobalPermissions {
                         manage_servers: true,
                         read_servers: true,
+                        manage_config: false,
                         manage_users: false,
                         read_users: false,
                         manage_streams: true,
@@ -263,6 +268,7 @@ pub async fn should_be_successful() {
                             ..Default::default()
                         },
                     )])),
+                    patterns: None,
                 }),
             ),
         ))
@@ -315,9 +321,10 @@ Options:
           there's long variant (same as in SDK) and short variant.
 {CLAP_INDENT}
           Available permissions (long and short versions):  manage_servers / m_srv,
-          read_servers / r_srv, manage_users / m_usr, read_users / r_usr,
-          manage_streams / m_str, read_streams / r_str, manage_topics / m_top,
-          read_topics / r_top, poll_messages / p_msg, send_messages / s_msg
+          read_servers / r_srv, manage_config / m_cfg, manage_users / m_usr,
+          read_users / r_usr, manage_streams / m_str, read_streams / r_str,
+          manage_topics / m_top, read_topics / r_top, poll_messages / p_msg,
+          send_messages / s_msg
 {CLAP_INDENT}
           Examples:
            iggy user create guest guess --global-permissions p_msg,s_msg
@@ -355,6 +362,24 @@ Options:
            iggy user create sender s3n43r -s 3#1:s_msg#2:s_msg
            iggy user create user1 test12 -s 4:manage_stream,r_top#1:s_msg,p_msg#2:manage_topic
 
+  -P, --pattern-permissions <PATTERN_PERMISSIONS>
+          Set pattern permissions for created user
+{CLAP_INDENT}
+          Pattern permissions are applied to all streams and topics whose names match
+          the pattern, including the ones created later. Pattern has STREAM_NAME.TOPIC_NAME
+          form, where asterisk (*) matches any sequence of characters, pattern without
+          topic part matches all topics of the stream. Pattern is followed by colon (:)
+          and list of permissions separated by comma (,). If multiple patterns match the
+          same topic, the most specific one (with most characters other than *) is used.
+{CLAP_INDENT}
+          Available pattern permissions: admin / a, read / r, write / w.
+{CLAP_INDENT}
+          Permissions format: PATTERN[:PATTERN_PERMISSIONS]
+{CLAP_INDENT}
+          Examples:
+           iggy user create reader r3ad3r -P orders.*:read
+           iggy user create writer wr1t3r --pattern-permissions *.events:w -P orders.audit:r,w
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -387,6 +412,8 @@ Options:
           Set global permissions for created user
   -s, --stream-permissions <STREAM_PERMISSIONS>
           Set stream permissions for created user
+  -P, --pattern-permissions <PATTERN_PERMISSIONS>
+          Set pattern permissions for created user
   -h, --help
           Print help (see more with '--help')
 "#,
@@ -26,7 +26,9 @@ use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
 use iggy::models::permissions::Permissions;
-use iggy::models::permissions::{GlobalPermissions, StreamPermissions, TopicPermissions};
+use iggy::models::permissions::{
+    GlobalPermissions, PatternPermissions, StreamPermissions, TopicPermissions,
+};
 use iggy::models::user_info::UserId;
 use iggy::models::user_status::UserStatus;
 use predicates::str::diff;
@@ -135,6 +137,7 @@ pub async fn should_be_successful() {
                     global: GlobalPermissions {
                         manage_servers: false,
                         read_servers: true,
+                        manage_config: false,
                         manage_users: false,
                         read_users: true,
                         manage_streams: false,
@@ -145,6 +148,7 @@ pub async fn should_be_successful() {
                         send_messages: true,
                     },
                     streams: None,
+                    patterns: None,
                 }),
             ),
             TestUserId::Named,
@@ -159,6 +163,7 @@ pub async fn should_be_successful() {
                 Some(Permissions {
                     global: GlobalPermissions::default(),
                     streams: Some(AHashMap::from([(3u32, StreamPermissions::default())])),
+                    patterns: None,
                 }),
             ),
             TestUserId::Numeric,
@@ -187,6 +192,7 @@ pub async fn should_be_successful() {
                             ..Default::default()
                         },
                     )])),
+                    patterns: None,
                 }),
             ),
             TestUserId::Named,
@@ -202,6 +208,7 @@ pub async fn should_be_successful() {
                     global: GlobalPermissions {
                         manage_servers: true,
                         read_servers: true,
+                        manage_config: false,
                         manage_users: false,
                         read_users: false,
                         manage_streams: true,
@@ -226,11 +233,33 @@ pub async fn should_be_successful() {
                             ..Default::default()
                         },
                     )])),
+                    patterns: None,
                 }),
             ),
             TestUserId::Named,
         ))
         .await;
+    iggy_cmd_test
+        .execute_test(TestUserPermissionsCmd::new(
+            String::from("orders"),
+            PermissionsTestArgs::new(
+                None,
+                vec![],
+                Some(Permissions {
+                    patterns: Some(vec![
+                        PatternPermissions::new("orders.*", false, true, false),
+                        PatternPermissions::new("orders.payments", true, false, true),
+                    ]),
+                    ..Default::default()
+                }),
+            )
+            .with_pattern_permissions(vec![
+                String::from("orders.*:r"),
+                String::from("orders.payments:admin,write"),
+            ]),
+            TestUserId::Numeric,
+        ))
+        .await;
 }
 
 #[tokio::test]
@@ -279,9 +308,10 @@ Options:
           there's long variant (same as in SDK) and short variant.
 {CLAP_INDENT}
           Available permissions (long and short versions):  manage_servers / m_srv,
-          read_servers / r_srv, manage_users / m_usr, read_users / r_usr,
-          manage_streams / m_str, read_streams / r_str, manage_topics / m_top,
-          read_topics / r_top, poll_messages / p_msg, send_messages / s_msg
+          read_servers / r_srv, manage_config / m_cfg, manage_users / m_usr,
+          read_users / r_usr, manage_streams / m_str, read_streams / r_str,
+          manage_topics / m_top, read_topics / r_top, poll_messages / p_msg,
+          send_messages / s_msg
 {CLAP_INDENT}
           Examples:
            iggy user create guest guess --global-permissions p_msg,s_msg
@@ -319,6 +349,24 @@ Options:
            iggy user create sender s3n43r -s 3#1:s_msg#2:s_msg
            iggy user create user1 test12 -s 4:manage_stream,r_top#1:s_msg,p_msg#2:manage_topic
 
+  -P, --pattern-permissions <PATTERN_PERMISSIONS>
+          Set pattern permissions for created user
+{CLAP_INDENT}
+          Pattern permissions are applied to all streams and topics whose names match
+          the pattern, including the ones created later. Pattern has STREAM_NAME.TOPIC_NAME
+          form, where asterisk (*) matches any sequence of characters, pattern without
+          topic part matches all topics of the stream. Pattern is followed by colon (:)
+          and list of permissions separated by comma (,). If multiple patterns match the
+          same topic, the most specific one (with most characters other than *) is used.
+{CLAP_INDENT}
+          Available pattern permissions: admin / a, read / r, write / w.
+{CLAP_INDENT}
+          Permissions format: PATTERN[:PATTERN_PERMISSIONS]
+{CLAP_INDENT}
+          Examples:
+           iggy user create reader r3ad3r -P orders.*:read
+           iggy user create writer wr1t3r --pattern-permissions *.events:w -P orders.audit:r,w
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -351,6 +399,8 @@ Options:
           Set global permissions for created user
   -s, --stream-permissions <STREAM_PERMISSIONS>
           Set stream permissions for created user
+  -P, --pattern-permissions <PATTERN_PERMISSIONS>
+          Set pattern permissions for created user
   -h, --help
           Print help (see more with '--help')
 "#,
@@ -20,6 +20,7 @@ mod test_basic;
 mod test_getting_started;
 mod test_message_envelope;
 mod test_message_headers;
+mod test_request_reply;
 
 use assert_cmd::Command;
 use iggy::client::{Client, StreamClient, SystemClient, TopicClient, UserClient};
@@ -88,6 +89,9 @@ pub(crate) trait IggyExampleTestCase {
             assert!(stdout.contains(format!("has connected to server: {}", &server_addr).as_str()));
         }
     }
+    fn executables(&self) -> (&'static str, &'static str) {
+        ("producer", "consumer")
+    }
     fn protocol(&self, server: &TestServer) -> Vec<String> {
         vec![
             "--tcp-server-address".into(),
@@ -150,7 +154,7 @@ impl<'a> IggyExampleTest<'a> {
             "Server is not running, make sure it has been started with IggyExampleTest::setup()"
         );
         let (producer_stdout, consumer_stdout) = self
-            .spawn_executables(test_case.protocol(&self.server))
+            .spawn_executables(test_case.executables(), test_case.protocol(&self.server))
             .await;
 
         test_case.verify_server_output(&producer_stdout, &consumer_stdout, &self.server);
@@ -160,11 +164,15 @@ impl<'a> IggyExampleTest<'a> {
 }
 
 impl IggyExampleTest<'_> {
-    async fn spawn_executables(&mut self, tcp_server_address: Vec<String>) -> (String, String) {
-        let mut producer_cmd = Command::cargo_bin(format!("examples/{}-producer", self.module))
-            .unwrap_or_else(|_| panic!("Failed to find {}-producer", self.module));
-        let mut consumer_cmd = Command::cargo_bin(format!("examples/{}-consumer", self.module))
-            .unwrap_or_else(|_| panic!("Failed to find {}-consumer", self.module));
+    async fn spawn_executables(
+        &mut self,
+        (producer, consumer): (&str, &str),
+        tcp_server_address: Vec<String>,
+    ) -> (String, String) {
+        let mut producer_cmd = Command::cargo_bin(format!("examples/{}-{producer}", self.module))
+            .unwrap_or_else(|_| panic!("Failed to find {}-{producer}", self.module));
+        let mut consumer_cmd = Command::cargo_bin(format!("examples/{}-{consumer}", self.module))
+            .unwrap_or_else(|_| panic!("Failed to find {}-{consumer}", self.module));
 
         let mut args: Vec<String> = tcp_server_address.clone();
         args.push("--message-batches-limit".into());
@@ -0,0 +1,87 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::verify_stdout_contains_expected_logs;
+use crate::examples::{IggyExampleTest, IggyExampleTestCase};
+use regex::Regex;
+use serial_test::parallel;
+
+struct TestRequestReply<'a> {
+    expected_requester_output: Vec<&'a str>,
+    expected_responder_output: Vec<&'a str>,
+}
+
+impl IggyExampleTestCase for TestRequestReply<'_> {
+    fn executables(&self) -> (&'static str, &'static str) {
+        ("requester", "responder")
+    }
+
+    fn verify_log_output(&self, requester_stdout: &str, responder_stdout: &str) {
+        verify_stdout_contains_expected_logs(
+            requester_stdout,
+            responder_stdout,
+            &self.expected_requester_output,
+            &self.expected_responder_output,
+        );
+    }
+
+    fn verify_message_output(&self, requester_stdout: &str, responder_stdout: &str) {
+        let re =
+            Regex::new(r"Handled request: .*? with correlation ID: \d+, result: (-?\d+)").unwrap();
+        let results = re
+            .captures_iter(responder_stdout)
+            .map(|cap| cap[1].to_string())
+            .collect::<Vec<_>>();
+        assert!(!results.is_empty(), "Responder did not handle any request");
+
+        for result in results {
+            let expected_reply = format!("Received reply: CalculationReply {{ result: {result} }}");
+            assert!(
+                requester_stdout.contains(&expected_reply),
+                "Requester output does not contain expected reply: '{}'",
+                expected_reply
+            );
+        }
+    }
+}
+
+#[tokio::test]
+#[parallel]
+async fn should_successfully_execute() {
+    let mut iggy_example_test = IggyExampleTest::new("request-reply");
+    iggy_example_test.setup(false).await;
+
+    iggy_example_test
+        .execute_test(TestRequestReply {
+            expected_requester_output: vec![
+                "Request-reply requester has started, selected transport: tcp",
+                "Requests will be sent to stream: example-stream, topic: requests and the replies polled from topic: replies with timeout 2s.",
+                "Received reply: CalculationReply { result: 1 } for request: CalculationRequest { operation: Add, left: 0, right: 1 }",
+                "Request: CalculationRequest { operation: Divide, left: 0, right: 1 } with correlation ID:",
+                "Completed batch 1 of 2 requests, outstanding requests: 0.",
+                "Sent 1 batches of requests, exiting.",
+            ],
+            expected_responder_output: vec![
+                "Request-reply responder has started, selected transport: tcp",
+                "Requests will be polled from stream: example-stream, topic: requests and the replies sent to topic: replies.",
+                "Unsupported operation in request: CalculationRequest { operation: Divide, left: 0, right: 1 }",
+                "Handled 2 requests, exiting.",
+            ],
+        })
+        .await;
+}
@@ -17,9 +17,16 @@
  */
 
 use crate::server::scenarios::{
-    create_message_payload, stream_size_validation_scenario, system_scenario, user_scenario,
+    binary_messages_scenario, bookmark_scenario, confirmation_level_scenario,
+    create_message_payload, effective_config_scenario, ndjson_messages_scenario, openapi_scenario,
+    sse_messages_scenario, stream_size_validation_scenario, system_scenario, user_scenario,
+    websocket_scenario,
+};
+use iggy::http::client::HttpClient;
+use integration::{
+    http_client::HttpClientFactory,
+    test_server::{IpAddrKind, TestServer},
 };
-use integration::{http_client::HttpClientFactory, test_server::TestServer};
 use serial_test::parallel;
 
 #[tokio::test]
@@ -71,3 +78,93 @@ async fn user_scenario_should_be_valid() {
     let client_factory = HttpClientFactory { server_addr };
     user_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn ndjson_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client = HttpClient::new(&format!("http://{server_addr}")).unwrap();
+    ndjson_messages_scenario::run(&client).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn binary_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client = HttpClient::new(&format!("http://{server_addr}")).unwrap();
+    binary_messages_scenario::run(&client).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn sse_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client = HttpClient::new(&format!("http://{server_addr}")).unwrap();
+    sse_messages_scenario::run(&client).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn confirmation_level_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(
+        Some(confirmation_level_scenario::server_envs()),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    confirmation_level_scenario::run(&client_factory, test_server.get_local_data_path()).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn bookmark_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    bookmark_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn effective_config_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    effective_config_scenario::run(&client_factory, test_server.get_local_data_path()).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn websocket_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(
+        Some(websocket_scenario::server_envs()),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client = HttpClient::new(&format!("http://{server_addr}")).unwrap();
+    websocket_scenario::run(&client, &server_addr).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn openapi_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client = HttpClient::new(&format!("http://{server_addr}")).unwrap();
+    openapi_scenario::run(&client).await;
+}
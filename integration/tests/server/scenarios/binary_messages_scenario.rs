@@ -0,0 +1,157 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient, UserClient};
+use iggy::confirmation::ConfirmationLevel;
+use iggy::consumer::Consumer;
+use iggy::http::client::HttpClient;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::messages::AutoCommitMode;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::collections::HashMap;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 100;
+
+pub async fn run(client: &HttpClient) {
+    client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &STREAM_ID.try_into().unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            Default::default(),
+            None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+
+    // 1. Send the messages with the raw binary payloads and headers
+    let messages = (1..=MESSAGES_COUNT)
+        .map(|id| {
+            Message::new(
+                Some(id as u128),
+                create_message_payload(id),
+                Some(create_message_headers()),
+            )
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages_binary(
+            &STREAM_ID.try_into().unwrap(),
+            &TOPIC_ID.try_into().unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &messages,
+            ConfirmationLevel::Wait,
+        )
+        .await
+        .unwrap();
+
+    // 2. Poll the messages back in the binary format and validate them
+    let polled_messages = client
+        .poll_messages_binary(
+            &STREAM_ID.try_into().unwrap(),
+            &TOPIC_ID.try_into().unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            AutoCommitMode::Manual,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.partition_id, PARTITION_ID);
+    assert_eq!(polled_messages.current_offset, (MESSAGES_COUNT - 1) as u64);
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    for (index, message) in polled_messages.messages.iter().enumerate() {
+        let id = index as u32 + 1;
+        assert_eq!(message.offset, index as u64);
+        assert_eq!(message.id, id as u128);
+        assert_eq!(message.payload, create_message_payload(id));
+        assert_eq!(message.headers, Some(create_message_headers()));
+    }
+
+    // 3. The same messages are returned as JSON without the Accept header
+    let json_polled_messages = client
+        .poll_messages(
+            &STREAM_ID.try_into().unwrap(),
+            &TOPIC_ID.try_into().unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        json_polled_messages.messages.len(),
+        polled_messages.messages.len()
+    );
+    for (json_message, message) in json_polled_messages
+        .messages
+        .iter()
+        .zip(polled_messages.messages.iter())
+    {
+        assert_eq!(json_message.offset, message.offset);
+        assert_eq!(json_message.checksum, message.checksum);
+        assert_eq!(json_message.payload, message.payload);
+    }
+
+    client
+        .delete_stream(&STREAM_ID.try_into().unwrap())
+        .await
+        .unwrap();
+}
+
+fn create_message_payload(id: u32) -> Bytes {
+    // Non-UTF-8 bytes, which would otherwise have to be Base64 encoded.
+    Bytes::from_iter((0..=255u8).chain(id.to_le_bytes()))
+}
+
+fn create_message_headers() -> HashMap<HeaderKey, HeaderValue> {
+    let mut headers = HashMap::new();
+    headers.insert(
+        HeaderKey::new("key_1").unwrap(),
+        HeaderValue::from_str("Value 1").unwrap(),
+    );
+    headers.insert(
+        HeaderKey::new("key-2").unwrap(),
+        HeaderValue::from_uint64(123456).unwrap(),
+    );
+    headers
+}
@@ -0,0 +1,190 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::client::{
+    BookmarkClient, ConsumerOffsetClient, MessageClient, StreamClient, TopicClient,
+};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+const MESSAGES_COUNT: u32 = 10;
+const AUDIT_BOOKMARK: &str = "audit";
+const REPLAY_BOOKMARK: &str = "replay";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. Create two bookmarks at different positions of the same partition
+    client
+        .store_bookmark(&stream_id, &topic_id, AUDIT_BOOKMARK, PARTITION_ID, 0)
+        .await
+        .unwrap();
+    client
+        .store_bookmark(&stream_id, &topic_id, REPLAY_BOOKMARK, PARTITION_ID, 5)
+        .await
+        .unwrap();
+    let bookmarks = client.get_bookmarks(&stream_id, &topic_id).await.unwrap();
+    assert_eq!(bookmarks.len(), 2);
+    assert_eq!(bookmarks[0].name, AUDIT_BOOKMARK);
+    assert_eq!(bookmarks[0].offset, 0);
+    assert_eq!(bookmarks[1].name, REPLAY_BOOKMARK);
+    assert_eq!(bookmarks[1].offset, 5);
+
+    // 2. Poll from the bookmark and move it past the last polled message
+    let bookmark = client
+        .get_bookmark(&stream_id, &topic_id, AUDIT_BOOKMARK)
+        .await
+        .unwrap()
+        .expect("Bookmark should exist");
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(bookmark.partition_id),
+            &Consumer::default(),
+            &PollingStrategy::offset(bookmark.offset),
+            3,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 3);
+    assert_eq!(polled_messages.messages[0].offset, 0);
+    let next_offset = polled_messages.messages.last().unwrap().offset + 1;
+    client
+        .store_bookmark(
+            &stream_id,
+            &topic_id,
+            AUDIT_BOOKMARK,
+            bookmark.partition_id,
+            next_offset,
+        )
+        .await
+        .unwrap();
+    let moved_bookmark = client
+        .get_bookmark(&stream_id, &topic_id, AUDIT_BOOKMARK)
+        .await
+        .unwrap()
+        .expect("Bookmark should exist");
+    assert_eq!(moved_bookmark.offset, 3);
+    assert_eq!(moved_bookmark.created_at, bookmark.created_at);
+    assert!(moved_bookmark.updated_at.as_micros() >= bookmark.updated_at.as_micros());
+
+    // 3. The other bookmark and the consumer offsets are not affected
+    let replay_bookmark = client
+        .get_bookmark(&stream_id, &topic_id, REPLAY_BOOKMARK)
+        .await
+        .unwrap()
+        .expect("Bookmark should exist");
+    assert_eq!(replay_bookmark.offset, 5);
+    let consumer_offset = client
+        .get_consumer_offset(
+            &Consumer::default(),
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+        )
+        .await
+        .unwrap();
+    assert!(consumer_offset.is_none());
+
+    // 4. The bookmark cannot point beyond the offset following the last message
+    let result = client
+        .store_bookmark(
+            &stream_id,
+            &topic_id,
+            AUDIT_BOOKMARK,
+            PARTITION_ID,
+            MESSAGES_COUNT as u64 + 1,
+        )
+        .await;
+    assert!(result.is_err());
+
+    // 5. Delete the bookmark
+    client
+        .delete_bookmark(&stream_id, &topic_id, REPLAY_BOOKMARK)
+        .await
+        .unwrap();
+    let bookmark = client
+        .get_bookmark(&stream_id, &topic_id, REPLAY_BOOKMARK)
+        .await
+        .unwrap();
+    assert!(bookmark.is_none());
+    let result = client
+        .delete_bookmark(&stream_id, &topic_id, REPLAY_BOOKMARK)
+        .await;
+    assert!(result.is_err());
+    let bookmarks = client.get_bookmarks(&stream_id, &topic_id).await.unwrap();
+    assert_eq!(bookmarks.len(), 1);
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+
+    // 3. Send the messages to the single partition
+    let mut messages = (1..=MESSAGES_COUNT)
+        .map(|id| Message::new(None, Bytes::from(format!("message {id}")), None))
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
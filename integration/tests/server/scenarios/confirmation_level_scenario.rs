@@ -0,0 +1,136 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, SystemClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::confirmation::ConfirmationLevel;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+
+const MESSAGES_COUNT: u32 = 10;
+
+/// Returns the server environment variables, which disable persisting the buffered messages other than on demand,
+/// and syncing the partitions after every write, so that the explicit syncs are counted in the stats.
+pub fn server_envs() -> HashMap<String, String> {
+    HashMap::from([
+        (
+            "IGGY_SYSTEM_PARTITION_MESSAGES_REQUIRED_TO_SAVE".to_owned(),
+            "1000000".to_owned(),
+        ),
+        (
+            "IGGY_SYSTEM_PARTITION_ENFORCE_FSYNC".to_owned(),
+            "false".to_owned(),
+        ),
+        ("IGGY_MESSAGE_SAVER_ENABLED".to_owned(), "false".to_owned()),
+    ])
+}
+
+/// Expects the server to be started with the `server_envs`, so that only the `persisted` confirmation level writes the messages to disk and syncs them.
+pub async fn run(client_factory: &dyn ClientFactory, data_path: &str) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let segment_log_path = format!(
+        "{data_path}/streams/{STREAM_ID}/topics/{TOPIC_ID}/partitions/{PARTITION_ID}/{:0>20}.log",
+        0
+    );
+
+    let fsyncs_count = get_fsyncs_count(&client).await;
+
+    // 1. Send messages confirmed once received, which are kept in the buffer
+    send_messages(&client, ConfirmationLevel::Received).await;
+    assert_eq!(get_file_size(&segment_log_path), 0);
+    assert_eq!(get_fsyncs_count(&client).await, fsyncs_count);
+
+    // 2. Send messages confirmed once persisted, which writes the whole buffer to disk and syncs it before the response
+    send_messages(&client, ConfirmationLevel::Persisted).await;
+    let persisted_size = get_file_size(&segment_log_path);
+    assert!(persisted_size > 0);
+    assert_eq!(get_fsyncs_count(&client).await, fsyncs_count + 1);
+
+    // 3. Send messages without waiting, which are kept in the buffer as well
+    send_messages(&client, ConfirmationLevel::NoWait).await;
+    assert_eq!(get_file_size(&segment_log_path), persisted_size);
+    assert_eq!(get_fsyncs_count(&client).await, fsyncs_count + 1);
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn send_messages(client: &IggyClient, confirmation: ConfirmationLevel) {
+    let mut messages = (1..=MESSAGES_COUNT)
+        .map(|id| {
+            Message::new(
+                None,
+                Bytes::from(format!("message {id} confirmed as {confirmation}")),
+                None,
+            )
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages_with_confirmation(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+            confirmation,
+        )
+        .await
+        .unwrap();
+}
+
+async fn get_fsyncs_count(client: &IggyClient) -> u64 {
+    client.get_stats().await.unwrap().fsyncs_count
+}
+
+fn get_file_size(path: &str) -> u64 {
+    std::fs::metadata(path).unwrap().len()
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,64 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::create_client;
+use iggy::client::{SystemClient, UserClient};
+use iggy::models::config_value::ConfigSource;
+use integration::test_server::{login_root, ClientFactory};
+use serde_json::Value;
+
+/// Expects the server to be started with the system path set by the environment variable, as the test server does.
+pub async fn run(client_factory: &dyn ClientFactory, system_path: &str) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+
+    let config = client.get_config().await.unwrap();
+    let get_value = |key: &str| {
+        config
+            .iter()
+            .find(|value| value.key == key)
+            .unwrap_or_else(|| panic!("Missing config value: {key}"))
+    };
+
+    // 1. The value set by the environment variable is reported with its source
+    let system_path_value = get_value("system.path");
+    assert_eq!(
+        system_path_value.value,
+        Value::String(system_path.to_owned())
+    );
+    assert_eq!(system_path_value.source, ConfigSource::Environment);
+
+    // 2. The value which has not been overridden is reported as the default one
+    assert_eq!(
+        get_value("system.segment.size").source,
+        ConfigSource::Default
+    );
+
+    // 3. The secrets are masked
+    assert_eq!(
+        get_value("http.jwt.encoding_secret").value,
+        Value::String("******".to_owned())
+    );
+    assert!(config
+        .iter()
+        .all(|value| !value.value.to_string().contains("top_secret")));
+
+    // 4. The configuration is available only to the authenticated users
+    client.logout_user().await.unwrap();
+    assert!(client.get_config().await.is_err());
+}
@@ -23,15 +23,23 @@ use iggy::identifier::Identifier;
 use iggy::models::consumer_group::ConsumerGroupDetails;
 use integration::test_server::{delete_user, ClientFactory};
 
+pub mod binary_messages_scenario;
+pub mod bookmark_scenario;
+pub mod confirmation_level_scenario;
 pub mod consumer_group_join_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
 pub mod create_message_payload;
+pub mod effective_config_scenario;
 pub mod message_headers_scenario;
 pub mod message_size_scenario;
+pub mod ndjson_messages_scenario;
+pub mod openapi_scenario;
+pub mod sse_messages_scenario;
 pub mod stream_size_validation_scenario;
 pub mod system_scenario;
 pub mod user_scenario;
+pub mod websocket_scenario;
 
 const STREAM_ID: u32 = 1;
 const TOPIC_ID: u32 = 1;
@@ -0,0 +1,131 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use futures::{stream, StreamExt};
+use iggy::client::{MessageClient, StreamClient, TopicClient, UserClient};
+use iggy::consumer::Consumer;
+use iggy::http::client::HttpClient;
+use iggy::http::HttpTransport;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use serde_json::Value;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 2500;
+
+pub async fn run(client: &HttpClient) {
+    client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &STREAM_ID.try_into().unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            Default::default(),
+            None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+
+    // 1. Stream more messages than fit in a single appended batch
+    let messages = stream::iter(1..=MESSAGES_COUNT)
+        .map(|id| Message::new(Some(id as u128), Bytes::from(format!("message {id}")), None));
+    client
+        .send_messages_stream(
+            &STREAM_ID.try_into().unwrap(),
+            &TOPIC_ID.try_into().unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            messages,
+        )
+        .await
+        .unwrap();
+
+    // 2. Poll the messages back and validate their order
+    let polled_messages = client
+        .poll_messages(
+            &STREAM_ID.try_into().unwrap(),
+            &TOPIC_ID.try_into().unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT * 2,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    for (index, message) in polled_messages.messages.iter().enumerate() {
+        assert_eq!(message.offset, index as u64);
+        assert_eq!(
+            message.payload,
+            Bytes::from(format!("message {}", index + 1))
+        );
+    }
+
+    // 3. Poll the messages back as NDJSON streamed in multiple chunks
+    let response = client
+        .get_with_query(
+            &format!("streams/{STREAM_ID}/topics/{TOPIC_ID}/messages"),
+            &[
+                ("partition_id", PARTITION_ID.to_string()),
+                ("kind", "offset".to_string()),
+                ("value", "0".to_string()),
+                ("count", (MESSAGES_COUNT * 2).to_string()),
+                ("payload_encoding", "utf8".to_string()),
+                ("format", "ndjson".to_string()),
+            ],
+        )
+        .await
+        .unwrap();
+    let body = response.text().await.unwrap();
+    let mut lines = body.lines();
+    let header = serde_json::from_str::<Value>(lines.next().unwrap()).unwrap();
+    assert_eq!(header["partition_id"], PARTITION_ID);
+    assert_eq!(header["current_offset"], (MESSAGES_COUNT - 1) as u64);
+    let messages = lines
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(messages.len() as u32, MESSAGES_COUNT);
+    for (index, message) in messages.iter().enumerate() {
+        assert_eq!(message["offset"], index as u64);
+        assert_eq!(message["payload"], format!("message {}", index + 1));
+    }
+
+    client
+        .delete_stream(&STREAM_ID.try_into().unwrap())
+        .await
+        .unwrap();
+}
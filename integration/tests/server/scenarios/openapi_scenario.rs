@@ -0,0 +1,79 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::http::client::HttpClient;
+use iggy::http::HttpTransport;
+use serde_json::Value;
+
+const EXPECTED_PATHS: &[&str] = &[
+    "/streams",
+    "/streams/{stream_id}",
+    "/streams/{stream_id}/topics",
+    "/streams/{stream_id}/topics/{topic_id}",
+    "/streams/{stream_id}/topics/{topic_id}/consumer-groups",
+    "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}",
+    "/streams/{stream_id}/topics/{topic_id}/messages",
+    "/users",
+    "/users/{user_id}",
+    "/users/login",
+];
+
+const EXPECTED_SCHEMAS: &[&str] = &[
+    "StreamDetails",
+    "CreateTopic",
+    "TopicDetails",
+    "ConsumerGroupDetails",
+    "SendMessages",
+    "EncodedPolledMessages",
+    "CreateUser",
+    "IdentityInfo",
+    "ErrorResponse",
+];
+
+pub async fn run(client: &HttpClient) {
+    // 1. The specification is available without authentication
+    let response = client.get("openapi.json").await.unwrap();
+    let openapi = response.json::<Value>().await.unwrap();
+    assert!(openapi["openapi"].as_str().unwrap().starts_with("3."));
+    assert_eq!(openapi["info"]["title"], "Iggy HTTP API");
+
+    // 2. All the resources are covered, along with their schemas
+    let paths = openapi["paths"].as_object().unwrap();
+    for path in EXPECTED_PATHS {
+        assert!(paths.contains_key(*path), "missing path: {path}");
+    }
+    let schemas = openapi["components"]["schemas"].as_object().unwrap();
+    for schema in EXPECTED_SCHEMAS {
+        assert!(schemas.contains_key(*schema), "missing schema: {schema}");
+    }
+    assert_eq!(
+        openapi["components"]["securitySchemes"]["bearer_auth"]["scheme"],
+        "bearer"
+    );
+    assert!(paths["/streams"]["get"].is_object());
+    assert!(paths["/streams"]["post"]["requestBody"].is_object());
+    assert_eq!(
+        paths["/users/login"]["post"]["security"][0],
+        Value::Object(Default::default())
+    );
+
+    // 3. The Swagger UI loads the specification
+    let response = client.get("swagger-ui").await.unwrap();
+    let html = response.text().await.unwrap();
+    assert!(html.contains("/openapi.json"));
+}
@@ -0,0 +1,142 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient, UserClient};
+use iggy::consumer::Consumer;
+use iggy::http::client::HttpClient;
+use iggy::http::HttpTransport;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::time::Duration;
+use tokio::time::timeout;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const CONSUMER_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 3;
+
+pub async fn run(client: &HttpClient) {
+    client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &STREAM_ID.try_into().unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            Default::default(),
+            None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+
+    // 1. Send the messages before subscribing
+    send_messages(client, 1..=MESSAGES_COUNT).await;
+
+    // 2. Subscribe to the messages with the auto-commit before delivery
+    let mut response = client
+        .get_with_query(
+            &format!("streams/{STREAM_ID}/topics/{TOPIC_ID}/messages/sse"),
+            &[
+                ("id", CONSUMER_ID.to_string()),
+                ("partition_id", PARTITION_ID.to_string()),
+                ("kind", "first".to_string()),
+                ("count", "10".to_string()),
+                ("auto_commit", "before_delivery".to_string()),
+                ("payload_encoding", "utf8".to_string()),
+                ("poll_interval", "10ms".to_string()),
+            ],
+        )
+        .await
+        .unwrap();
+
+    // 3. Receive the already sent messages, then the ones sent while subscribed
+    let mut events = String::new();
+    let mut received_count = 0;
+    while received_count < 2 * MESSAGES_COUNT {
+        let chunk = timeout(Duration::from_secs(5), response.chunk())
+            .await
+            .expect("Timed out waiting for the server-sent events.")
+            .unwrap()
+            .expect("The server-sent events stream ended unexpectedly.");
+        events.push_str(std::str::from_utf8(&chunk).unwrap());
+        received_count = events.matches("event: message").count() as u32;
+        if received_count == MESSAGES_COUNT {
+            send_messages(client, MESSAGES_COUNT + 1..=2 * MESSAGES_COUNT).await;
+        }
+    }
+    drop(response);
+
+    // 4. Validate the order of the received messages
+    for id in 1..=2 * MESSAGES_COUNT {
+        let offset = id - 1;
+        assert!(events.contains(&format!("id: {offset}")));
+        assert!(events.contains(&format!("\"payload\":\"message {id}\"")));
+    }
+
+    // 5. Ensure that the offset of the last received message has been committed
+    let consumer_offset = client
+        .get_consumer_offset(
+            &Consumer::new(CONSUMER_ID.try_into().unwrap()),
+            &STREAM_ID.try_into().unwrap(),
+            &TOPIC_ID.try_into().unwrap(),
+            Some(PARTITION_ID),
+        )
+        .await
+        .unwrap()
+        .expect("The consumer offset should be stored.");
+    assert_eq!(
+        consumer_offset.stored_offset,
+        (2 * MESSAGES_COUNT - 1) as u64
+    );
+
+    client
+        .delete_stream(&STREAM_ID.try_into().unwrap())
+        .await
+        .unwrap();
+}
+
+async fn send_messages(client: &HttpClient, ids: std::ops::RangeInclusive<u32>) {
+    let mut messages = ids
+        .map(|id| Message::new(Some(id as u128), Bytes::from(format!("message {id}")), None))
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &STREAM_ID.try_into().unwrap(),
+            &TOPIC_ID.try_into().unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
@@ -36,6 +36,7 @@ use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::messages::PolledMessage;
+use iggy::partitions::key_routing_policy::KeyRoutingPolicy;
 use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
@@ -572,6 +573,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             &Identifier::numeric(STREAM_ID).unwrap(),
             &Identifier::numeric(TOPIC_ID).unwrap(),
             PARTITIONS_COUNT,
+            KeyRoutingPolicy::PreserveExisting,
         )
         .await
         .unwrap();
@@ -608,7 +610,34 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
 
-    // 38. Update the existing topic and ensure it's updated
+    // 38. Name the first partition and get its details by the name
+    let partition_name = "partition-1";
+    client
+        .update_partition(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Identifier::numeric(PARTITION_ID).unwrap(),
+            Some(partition_name),
+        )
+        .await
+        .unwrap();
+
+    let partition = client
+        .get_partition(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Identifier::named(partition_name).unwrap(),
+        )
+        .await
+        .unwrap()
+        .expect("Failed to get partition");
+
+    assert_eq!(partition.id, PARTITION_ID);
+    assert_eq!(partition.name.as_deref(), Some(partition_name));
+    assert_eq!(partition.segments_count, partition.segments.len() as u32);
+    assert!(partition.segments_count > 0);
+
+    // 39. Update the existing topic and ensure it's updated
     let updated_topic_name = format!("{}-updated", TOPIC_NAME);
     let updated_message_expiry = 1000;
     let message_expiry_duration = updated_message_expiry.into();
@@ -649,7 +678,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(updated_topic.max_topic_size, updated_max_topic_size);
     assert_eq!(updated_topic.replication_factor, updated_replication_factor);
 
-    // 39. Purge the existing topic and ensure it has no messages
+    // 40. Purge the existing topic and ensure it has no messages
     client
         .purge_topic(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -673,7 +702,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(polled_messages.current_offset, 0);
     assert!(polled_messages.messages.is_empty());
 
-    // 40. Update the existing stream and ensure it's updated
+    // 41. Update the existing stream and ensure it's updated
     let updated_stream_name = format!("{}-updated", STREAM_NAME);
 
     client
@@ -692,7 +721,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(updated_stream.name, updated_stream_name);
 
-    // 41. Purge the existing stream and ensure it has no messages
+    // 42. Purge the existing stream and ensure it has no messages
     let mut messages = create_messages();
     client
         .send_messages(
@@ -724,7 +753,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(polled_messages.current_offset, 0);
     assert!(polled_messages.messages.is_empty());
 
-    // 42. Delete the existing topic and ensure it doesn't exist anymore
+    // 43. Delete the existing topic and ensure it doesn't exist anymore
     client
         .delete_topic(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -738,7 +767,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .unwrap();
     assert!(topics.is_empty());
 
-    // 43. Create the stream with automatically generated ID on the server
+    // 44. Create the stream with automatically generated ID on the server
     let stream_name = format!("{}-auto", STREAM_NAME);
     let stream_id = STREAM_ID + 1;
     client.create_stream(&stream_name, None).await.unwrap();
@@ -752,7 +781,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(stream.id, stream_id);
     assert_eq!(stream.name, stream_name);
 
-    // 44. Create the topic with automatically generated ID on the server
+    // 45. Create the topic with automatically generated ID on the server
     let topic_name = format!("{}-auto", TOPIC_NAME);
     let topic_id = 1;
     client
@@ -781,7 +810,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic.id, topic_id);
     assert_eq!(topic.name, topic_name);
 
-    // 45. Delete the existing streams and ensure there's no streams left
+    // 46. Delete the existing streams and ensure there's no streams left
     let streams = client.get_streams().await.unwrap();
     assert_eq!(streams.len(), 2);
 
@@ -795,7 +824,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let streams = client.get_streams().await.unwrap();
     assert!(streams.is_empty());
 
-    // 46. Get clients and ensure that there's 0 (HTTP) or 1 (TCP, QUIC) client
+    // 47. Get clients and ensure that there's 0 (HTTP) or 1 (TCP, QUIC) client
     let clients = client.get_clients().await.unwrap();
 
     assert!(clients.len() <= 1);
@@ -75,6 +75,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
                 global: GlobalPermissions {
                     manage_servers: false,
                     read_servers: true,
+                    manage_config: false,
                     manage_users: false,
                     read_users: true,
                     manage_streams: false,
@@ -85,6 +86,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
                     send_messages: true,
                 },
                 streams: None,
+                patterns: None,
             }),
         )
         .await
@@ -223,6 +225,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
                 global: GlobalPermissions {
                     manage_servers: false,
                     read_servers: true,
+                    manage_config: false,
                     manage_users: false,
                     read_users: true,
                     manage_streams: false,
@@ -233,6 +236,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
                     send_messages: true,
                 },
                 streams: None,
+                patterns: None,
             }),
         )
         .await
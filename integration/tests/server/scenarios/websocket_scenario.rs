@@ -0,0 +1,272 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use futures::{SinkExt, StreamExt};
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::client::{StreamClient, TopicClient, UserClient};
+use iggy::consumer::Consumer;
+use iggy::http::client::HttpClient;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::AutoCommitMode;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_tungstenite::tungstenite::Message as WebSocketMessage;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const CONSUMER_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 3;
+const MAX_REQUESTS_PER_SECOND: u32 = 10;
+const REQUESTS_LIMIT_EXCEEDED_CODE: u64 = 307;
+
+type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+pub fn server_envs() -> HashMap<String, String> {
+    HashMap::from([(
+        "IGGY_HTTP_WEBSOCKET_MAX_REQUESTS_PER_SECOND".to_owned(),
+        MAX_REQUESTS_PER_SECOND.to_string(),
+    )])
+}
+
+pub async fn run(client: &HttpClient, server_addr: &str) {
+    let identity = client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    let access_token = identity.access_token.unwrap().token;
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &STREAM_ID.try_into().unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            Default::default(),
+            None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+
+    // 1. Connecting without the access token should fail
+    assert!(connect_async(format!("ws://{server_addr}/ws"))
+        .await
+        .is_err());
+
+    // 2. Connect with the access token passed as the query parameter
+    let (mut socket, _) =
+        connect_async(format!("ws://{server_addr}/ws?access_token={access_token}"))
+            .await
+            .unwrap();
+
+    // 3. Send the messages as the JSON request
+    let request = json!({
+        "id": 1,
+        "command": "send",
+        "stream_id": STREAM_ID.to_string(),
+        "topic_id": TOPIC_ID.to_string(),
+        "body": serde_json::to_value(create_send_messages(1..=MESSAGES_COUNT)).unwrap(),
+    });
+    send_request(&mut socket, &request).await;
+    let response = receive_response(&mut socket, 1).await;
+    assert_eq!(response["status"], "ok");
+
+    // 4. Send the messages as the binary request
+    let mut command = create_send_messages(MESSAGES_COUNT + 1..=2 * MESSAGES_COUNT);
+    command.stream_id = STREAM_ID.try_into().unwrap();
+    command.topic_id = TOPIC_ID.try_into().unwrap();
+    let mut frame = 2u32.to_le_bytes().to_vec();
+    frame.extend_from_slice(&command.to_bytes());
+    socket
+        .send(WebSocketMessage::Binary(frame.into()))
+        .await
+        .unwrap();
+    let response = receive_response(&mut socket, 2).await;
+    assert_eq!(response["status"], "ok");
+
+    // 5. Poll all the sent messages
+    let request = json!({
+        "id": 3,
+        "command": "poll",
+        "stream_id": STREAM_ID.to_string(),
+        "topic_id": TOPIC_ID.to_string(),
+        "body": serde_json::to_value(create_poll_messages(0)).unwrap(),
+        "payload_encoding": "utf8",
+    });
+    send_request(&mut socket, &request).await;
+    let response = receive_response(&mut socket, 3).await;
+    assert_eq!(response["status"], "ok");
+    let messages = response["messages"]["messages"].as_array().unwrap();
+    assert_eq!(messages.len() as u32, 2 * MESSAGES_COUNT);
+    for (index, message) in messages.iter().enumerate() {
+        assert_eq!(message["offset"], index as u64);
+        assert_eq!(message["payload"], format!("message {}", index + 1));
+    }
+
+    // 6. Subscribe to the messages after the already polled ones, and receive the new messages
+    let request = json!({
+        "id": 4,
+        "command": "subscribe",
+        "stream_id": STREAM_ID.to_string(),
+        "topic_id": TOPIC_ID.to_string(),
+        "body": serde_json::to_value(create_poll_messages(2 * MESSAGES_COUNT as u64)).unwrap(),
+        "payload_encoding": "utf8",
+        "poll_interval": "10ms",
+    });
+    send_request(&mut socket, &request).await;
+    let response = receive_response(&mut socket, 4).await;
+    assert_eq!(response["status"], "ok");
+
+    let request = json!({
+        "id": 5,
+        "command": "send",
+        "stream_id": STREAM_ID.to_string(),
+        "topic_id": TOPIC_ID.to_string(),
+        "body": serde_json::to_value(create_send_messages(
+            2 * MESSAGES_COUNT + 1..=3 * MESSAGES_COUNT
+        ))
+        .unwrap(),
+    });
+    send_request(&mut socket, &request).await;
+    let mut received_count = 0;
+    let mut sent = false;
+    while received_count < MESSAGES_COUNT || !sent {
+        let response = receive_frame(&mut socket).await;
+        if response["status"] != "messages" {
+            assert_eq!(response["id"], 5);
+            assert_eq!(response["status"], "ok");
+            sent = true;
+            continue;
+        }
+
+        assert_eq!(response["id"], 4);
+        for message in response["messages"]["messages"].as_array().unwrap() {
+            received_count += 1;
+            let offset = 2 * MESSAGES_COUNT + received_count - 1;
+            assert_eq!(message["offset"], offset as u64);
+            assert_eq!(message["payload"], format!("message {}", offset + 1));
+        }
+    }
+
+    // 7. Unsubscribe, and ensure that the subscription no longer exists
+    let request = json!({"id": 6, "command": "unsubscribe", "subscription_id": 4});
+    send_request(&mut socket, &request).await;
+    let response = receive_response(&mut socket, 6).await;
+    assert_eq!(response["status"], "ok");
+    send_request(&mut socket, &request).await;
+    let response = receive_response(&mut socket, 6).await;
+    assert_eq!(response["status"], "error");
+
+    // 8. Invalid request should fail
+    send_request(&mut socket, &json!({"id": 7, "command": "unknown"})).await;
+    let response = receive_response(&mut socket, 0).await;
+    assert_eq!(response["status"], "error");
+
+    // 9. Requests over the limit should be rejected
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    let requests_count = 2 * MAX_REQUESTS_PER_SECOND;
+    for id in 0..requests_count {
+        let request = json!({"id": 100 + id, "command": "unsubscribe", "subscription_id": 4});
+        send_request(&mut socket, &request).await;
+    }
+    let mut rejected_count = 0;
+    for id in 0..requests_count {
+        let response = receive_response(&mut socket, 100 + id).await;
+        if response["code"] == REQUESTS_LIMIT_EXCEEDED_CODE {
+            rejected_count += 1;
+        }
+    }
+    assert!(rejected_count > 0);
+
+    socket.close(None).await.unwrap();
+    client
+        .delete_stream(&STREAM_ID.try_into().unwrap())
+        .await
+        .unwrap();
+}
+
+fn create_send_messages(ids: std::ops::RangeInclusive<u32>) -> SendMessages {
+    let messages = ids
+        .map(|id| Message::new(Some(id as u128), Bytes::from(format!("message {id}")), None))
+        .collect::<Vec<_>>();
+    SendMessages {
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        messages,
+        ..Default::default()
+    }
+}
+
+fn create_poll_messages(offset: u64) -> PollMessages {
+    PollMessages {
+        consumer: Consumer::new(Identifier::numeric(CONSUMER_ID).unwrap()),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(offset),
+        count: 100,
+        auto_commit: AutoCommitMode::Manual,
+        ..Default::default()
+    }
+}
+
+async fn send_request(socket: &mut WebSocket, request: &Value) {
+    socket
+        .send(WebSocketMessage::Text(request.to_string().into()))
+        .await
+        .unwrap();
+}
+
+/// Receives the response to the request with the given ID, skipping the subscriptions messages.
+async fn receive_response(socket: &mut WebSocket, id: u32) -> Value {
+    loop {
+        let response = receive_frame(socket).await;
+        if response["status"] != "messages" {
+            assert_eq!(response["id"], id);
+            return response;
+        }
+    }
+}
+
+async fn receive_frame(socket: &mut WebSocket) -> Value {
+    loop {
+        let frame = timeout(Duration::from_secs(5), socket.next())
+            .await
+            .expect("Timed out waiting for the WebSocket response.")
+            .expect("The WebSocket connection has been closed unexpectedly.")
+            .unwrap();
+        if let WebSocketMessage::Text(text) = frame {
+            return serde_json::from_str(&text).unwrap();
+        }
+    }
+}
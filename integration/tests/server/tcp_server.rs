@@ -17,13 +17,20 @@
  */
 
 use crate::server::scenarios::{
-    consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
+    bookmark_scenario, confirmation_level_scenario, consumer_group_join_scenario,
+    consumer_group_with_multiple_clients_polling_messages_scenario,
     consumer_group_with_single_client_polling_messages_scenario, create_message_payload,
-    message_headers_scenario, message_size_scenario, stream_size_validation_scenario,
-    system_scenario, user_scenario,
+    effective_config_scenario, message_headers_scenario, message_size_scenario,
+    stream_size_validation_scenario, system_scenario, user_scenario,
+};
+use iggy::utils::duration::IggyDuration;
+use integration::{
+    tcp_client::TcpClientFactory,
+    test_server::{IpAddrKind, TestServer},
 };
-use integration::{tcp_client::TcpClientFactory, test_server::TestServer};
 use serial_test::parallel;
+use server::configs::server::ProxyConfig;
+use server::proxy::proxy_server;
 
 #[tokio::test]
 #[parallel]
@@ -141,3 +148,67 @@ async fn message_size_scenario_should_be_valid() {
     };
     message_size_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn confirmation_level_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(
+        Some(confirmation_level_scenario::server_envs()),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    confirmation_level_scenario::run(&client_factory, test_server.get_local_data_path()).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn bookmark_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    bookmark_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn effective_config_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    effective_config_scenario::run(&client_factory, test_server.get_local_data_path()).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn system_scenario_should_be_valid_when_forwarded_through_proxy() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let proxy_addr = proxy_server::start(ProxyConfig {
+        address: "127.0.0.1:0".to_string(),
+        upstream_address: test_server.get_raw_tcp_addr().unwrap(),
+        latency: IggyDuration::from(1000),
+        jitter: IggyDuration::from(1000),
+        reset_probability: 0.0,
+    })
+    .await;
+    let client_factory = TcpClientFactory {
+        server_addr: proxy_addr.to_string(),
+        ..Default::default()
+    };
+    system_scenario::run(&client_factory).await;
+}
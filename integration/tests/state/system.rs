@@ -17,6 +17,7 @@
  */
 
 use crate::state::StateSetup;
+use iggy::consumer_groups::assignment_strategy::AssignmentStrategy;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
@@ -74,6 +75,15 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic1".to_string(),
         replication_factor: None,
+        schema: None,
+        dead_letter_policy: None,
+        sampling_policy: None,
+        compaction_policy: None,
+        retention_policy: None,
+        tiering_policy: None,
+        fsync_policy: None,
+        segment_rollover_policy: None,
+        queue_policy: None,
     };
 
     let create_topic1_clone = CreateTopic {
@@ -85,6 +95,15 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic1".to_string(),
         replication_factor: None,
+        schema: None,
+        dead_letter_policy: None,
+        sampling_policy: None,
+        compaction_policy: None,
+        retention_policy: None,
+        tiering_policy: None,
+        fsync_policy: None,
+        segment_rollover_policy: None,
+        queue_policy: None,
     };
 
     let stream2_id = 2;
@@ -103,6 +122,15 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic2".to_string(),
         replication_factor: None,
+        schema: None,
+        dead_letter_policy: None,
+        sampling_policy: None,
+        compaction_policy: None,
+        retention_policy: None,
+        tiering_policy: None,
+        fsync_policy: None,
+        segment_rollover_policy: None,
+        queue_policy: None,
     };
 
     let create_partitions = CreatePartitions {
@@ -119,6 +147,8 @@ async fn should_be_initialized_based_on_state_entries() {
         command: CreatePersonalAccessToken {
             name: "test".to_string(),
             expiry: IggyExpiry::NeverExpire,
+            scope: None,
+            allowed_addresses: None,
         },
         hash: "hash".to_string(),
     };
@@ -127,6 +157,8 @@ async fn should_be_initialized_based_on_state_entries() {
         command: CreatePersonalAccessToken {
             name: "test".to_string(),
             expiry: IggyExpiry::NeverExpire,
+            scope: None,
+            allowed_addresses: None,
         },
         hash: "hash".to_string(),
     };
@@ -137,6 +169,7 @@ async fn should_be_initialized_based_on_state_entries() {
         topic_id: topic1_id.try_into().unwrap(),
         group_id: Some(group_id),
         name: "test".to_string(),
+        assignment_strategy: AssignmentStrategy::Sticky,
     };
 
     let create_consumer_group_clone = CreateConsumerGroup {
@@ -144,6 +177,7 @@ async fn should_be_initialized_based_on_state_entries() {
         topic_id: topic1_id.try_into().unwrap(),
         group_id: Some(group_id),
         name: "test".to_string(),
+        assignment_strategy: AssignmentStrategy::Sticky,
     };
 
     state
@@ -272,4 +306,8 @@ async fn should_be_initialized_based_on_state_entries() {
         create_consumer_group_clone.group_id.unwrap()
     );
     assert_eq!(consumer_group.name, create_consumer_group_clone.name);
+    assert_eq!(
+        consumer_group.assignment_strategy,
+        create_consumer_group_clone.assignment_strategy
+    );
 }
@@ -106,6 +106,7 @@ async fn test_get_messages_by_offset(
         cache: CacheConfig {
             enabled: msg_cache_enabled,
             size: msg_cache_size,
+            ..Default::default()
         },
         partition: PartitionConfig {
             messages_required_to_save,
@@ -186,7 +187,7 @@ async fn test_get_messages_by_offset(
             partition.partition_id,
         );
         partition
-            .append_messages(batch_info, batch.clone(), None)
+            .append_messages(batch_info, batch.clone(), None, IggyTimestamp::now())
             .await
             .unwrap();
 
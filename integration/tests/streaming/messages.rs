@@ -138,12 +138,17 @@ async fn should_persist_messages_and_then_load_them_by_timestamp() {
         partition.partition_id,
     );
     partition
-        .append_messages(appendable_batch_info, messages, None)
+        .append_messages(appendable_batch_info, messages, None, IggyTimestamp::now())
         .await
         .unwrap();
     let test_timestamp = IggyTimestamp::now();
     partition
-        .append_messages(appendable_batch_info_two, messages_two, None)
+        .append_messages(
+            appendable_batch_info_two,
+            messages_two,
+            None,
+            IggyTimestamp::now(),
+        )
         .await
         .unwrap();
 
@@ -273,7 +278,7 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         partition.partition_id,
     );
     partition
-        .append_messages(appendable_batch_info, messages, None)
+        .append_messages(appendable_batch_info, messages, None, IggyTimestamp::now())
         .await
         .unwrap();
     assert_eq!(
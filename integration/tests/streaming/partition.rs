@@ -208,7 +208,7 @@ async fn should_purge_existing_partition_on_disk() {
             partition.partition_id,
         );
         partition
-            .append_messages(appendable_batch_info, messages, None)
+            .append_messages(appendable_batch_info, messages, None, IggyTimestamp::now())
             .await
             .unwrap();
         let loaded_messages = partition.get_messages_by_offset(0, 100).await.unwrap();
@@ -222,6 +222,89 @@ async fn should_purge_existing_partition_on_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_rebuild_truncated_index_when_loading_partition_from_disk() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 1;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        IggyTimestamp::now(),
+    )
+    .await;
+    partition.persist().await.unwrap();
+    let messages = create_messages();
+    let messages_count = messages.len();
+    let appendable_batch_info = AppendableBatchInfo::new(
+        messages
+            .iter()
+            .map(|msg| msg.get_size_bytes())
+            .sum::<IggyByteSize>(),
+        partition.partition_id,
+    );
+    partition
+        .append_messages(appendable_batch_info, messages, None, IggyTimestamp::now())
+        .await
+        .unwrap();
+    partition
+        .flush_unsaved_buffer(true, IggyTimestamp::now())
+        .await
+        .unwrap();
+
+    let index_path = partition.get_segments()[0].index_path.clone();
+    let index_size = fs::metadata(&index_path).await.unwrap().len();
+    assert!(index_size > 0);
+    let index = std::fs::OpenOptions::new()
+        .write(true)
+        .open(&index_path)
+        .unwrap();
+    index.set_len(index_size - INDEX_SIZE / 2).unwrap();
+
+    let now = IggyTimestamp::now();
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        setup.config.clone(),
+        setup.storage.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        now,
+    )
+    .await;
+    let partition_state = PartitionState {
+        id: partition_id,
+        created_at: now,
+    };
+    loaded_partition.load(partition_state).await.unwrap();
+
+    assert_eq!(fs::metadata(&index_path).await.unwrap().len(), index_size);
+    assert_eq!(loaded_partition.current_offset, partition.current_offset);
+    let loaded_messages = loaded_partition
+        .get_messages_by_offset(0, 100)
+        .await
+        .unwrap();
+    assert_eq!(loaded_messages.len(), messages_count);
+}
+
 async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
     assert!(fs::metadata(&partition_path).await.is_ok());
 
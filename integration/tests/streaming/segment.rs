@@ -24,7 +24,9 @@ use iggy::confirmation::Confirmation;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::compaction_policy::CompactionKey;
 use iggy::models::messages::{MessageState, PolledMessage};
+use iggy::models::topic_options::TopicOptions;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
@@ -36,6 +38,7 @@ use server::streaming::models::messages::RetainedMessage;
 use server::streaming::segments::*;
 use server::streaming::session::Session;
 use server::streaming::systems::system::System;
+use std::collections::HashMap;
 use std::fs::DirEntry;
 use std::net::{Ipv4Addr, SocketAddr};
 use std::sync::atomic::AtomicU64;
@@ -58,6 +61,8 @@ async fn should_persist_segment() {
             partition_id,
             start_offset,
             setup.config.clone(),
+            setup.storage.buffer_pool.clone(),
+            setup.storage.segment_encryption.clone(),
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -95,6 +100,8 @@ async fn should_load_existing_segment_from_disk() {
             partition_id,
             start_offset,
             setup.config.clone(),
+            setup.storage.buffer_pool.clone(),
+            setup.storage.segment_encryption.clone(),
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -121,6 +128,8 @@ async fn should_load_existing_segment_from_disk() {
             partition_id,
             start_offset,
             setup.config.clone(),
+            setup.storage.buffer_pool.clone(),
+            setup.storage.segment_encryption.clone(),
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -157,6 +166,8 @@ async fn should_persist_and_load_segment_with_messages() {
         partition_id,
         start_offset,
         setup.config.clone(),
+        setup.storage.buffer_pool.clone(),
+        setup.storage.segment_encryption.clone(),
         IggyExpiry::NeverExpire,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -207,6 +218,8 @@ async fn should_persist_and_load_segment_with_messages() {
         partition_id,
         start_offset,
         setup.config.clone(),
+        setup.storage.buffer_pool.clone(),
+        setup.storage.segment_encryption.clone(),
         IggyExpiry::NeverExpire,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -243,6 +256,8 @@ async fn should_persist_and_load_segment_with_messages_with_nowait_confirmation(
         partition_id,
         start_offset,
         setup.config.clone(),
+        setup.storage.buffer_pool.clone(),
+        setup.storage.segment_encryption.clone(),
         IggyExpiry::NeverExpire,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -297,6 +312,8 @@ async fn should_persist_and_load_segment_with_messages_with_nowait_confirmation(
         partition_id,
         start_offset,
         setup.config.clone(),
+        setup.storage.buffer_pool.clone(),
+        setup.storage.segment_encryption.clone(),
         IggyExpiry::NeverExpire,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -328,6 +345,8 @@ async fn given_all_expired_messages_segment_should_be_expired() {
         partition_id,
         start_offset,
         setup.config.clone(),
+        setup.storage.buffer_pool.clone(),
+        setup.storage.segment_encryption.clone(),
         message_expiry,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -395,6 +414,8 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
         partition_id,
         start_offset,
         setup.config.clone(),
+        setup.storage.buffer_pool.clone(),
+        setup.storage.segment_encryption.clone(),
         message_expiry,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -507,6 +528,7 @@ async fn should_delete_persisted_segments() -> Result<(), Box<dyn std::error::Er
             CompressionAlgorithm::default(),
             MaxTopicSize::default(),
             None,
+            TopicOptions::default(),
         )
         .await?;
 
@@ -601,6 +623,211 @@ async fn should_delete_persisted_segments() -> Result<(), Box<dyn std::error::Er
     Ok(())
 }
 
+#[tokio::test]
+async fn should_compact_closed_segment_retaining_latest_message_per_key() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.buffer_pool.clone(),
+        setup.storage.segment_encryption.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let messages_count = 10;
+    let mut messages = Vec::new();
+    let mut batch_size = IggyByteSize::default();
+    for i in 0..messages_count {
+        let message = create_message(i, "test", IggyTimestamp::now());
+        let retained_message = Arc::new(RetainedMessage {
+            id: (i % 3) as u128,
+            offset: message.offset,
+            timestamp: message.timestamp,
+            checksum: message.checksum,
+            message_state: message.state,
+            headers: None,
+            payload: message.payload.clone(),
+        });
+        batch_size += retained_message.get_size_bytes();
+        messages.push(retained_message);
+    }
+
+    segment
+        .append_batch(batch_size, messages_count as u32, &messages)
+        .await
+        .unwrap();
+    segment.persist_messages(None).await.unwrap();
+    segment.is_closed = true;
+    let size_before_compaction = segment.size_bytes;
+
+    let key = CompactionKey::MessageId;
+    let mut latest_offsets = HashMap::new();
+    for message in segment.get_all_messages().await.unwrap() {
+        latest_offsets.insert(get_compaction_key(&message, &key).unwrap(), message.offset);
+    }
+    let compacted_segment = segment
+        .compact(|message| {
+            latest_offsets.get(&get_compaction_key(message, &key).unwrap()) == Some(&message.offset)
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(compacted_segment.removed_messages_count, 7);
+    assert!(!compacted_segment.is_empty);
+    assert!(segment.size_bytes < size_before_compaction);
+    let offsets = segment
+        .get_all_messages()
+        .await
+        .unwrap()
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, vec![7, 8, 9]);
+
+    let mut loaded_segment = Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.buffer_pool.clone(),
+        setup.storage.segment_encryption.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    );
+    loaded_segment.load_from_disk().await.unwrap();
+    let messages = loaded_segment
+        .get_messages_by_offset(0, messages_count as u32)
+        .await
+        .unwrap();
+    assert_eq!(
+        messages
+            .iter()
+            .map(|message| (message.offset, message.id))
+            .collect::<Vec<_>>(),
+        vec![(7, 1), (8, 2), (9, 0)]
+    );
+}
+
+#[tokio::test]
+async fn should_quarantine_corrupted_batch_and_read_subsequent_batches() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.buffer_pool.clone(),
+        setup.storage.segment_encryption.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let batches_count = 2;
+    let messages_count_per_batch = 5;
+    for batch in 0..batches_count {
+        let mut messages = Vec::new();
+        let mut batch_size = IggyByteSize::default();
+        for i in 0..messages_count_per_batch {
+            let offset = batch * messages_count_per_batch + i;
+            let message = create_message(offset, "test", IggyTimestamp::now());
+            let retained_message = Arc::new(RetainedMessage {
+                id: message.id,
+                offset: message.offset,
+                timestamp: message.timestamp,
+                checksum: message.checksum,
+                message_state: message.state,
+                headers: message.headers.map(|headers| headers.to_bytes()),
+                payload: message.payload.clone(),
+            });
+            batch_size += retained_message.get_size_bytes();
+            messages.push(retained_message);
+        }
+
+        segment
+            .append_batch(batch_size, messages_count_per_batch as u32, &messages)
+            .await
+            .unwrap();
+        segment.persist_messages(None).await.unwrap();
+    }
+
+    // Flip the last byte of the first batch payload.
+    let mut log = std::fs::read(&segment.log_path).unwrap();
+    let first_batch_length = u32::from_le_bytes(log[8..12].try_into().unwrap()) as usize;
+    log[28 + first_batch_length - 1] ^= 0xFF;
+    std::fs::write(&segment.log_path, log).unwrap();
+
+    let mut loaded_segment = Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.buffer_pool.clone(),
+        setup.storage.segment_encryption.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    );
+    loaded_segment.load_from_disk().await.unwrap();
+    let verification = loaded_segment.verify().await.unwrap();
+    assert_eq!(verification.batches_count, batches_count as u32);
+    assert_eq!(verification.corrupted_batches.len(), 1);
+    assert_eq!(verification.corrupted_batches[0].base_offset, 0);
+    assert_eq!(verification.corrupted_batches[0].position, 0);
+    let quarantine_path = format!("{}.{QUARANTINE_EXTENSION}", loaded_segment.log_path);
+    assert!(fs::metadata(&quarantine_path).await.is_ok());
+
+    let messages = loaded_segment
+        .get_messages_by_offset(0, (batches_count * messages_count_per_batch) as u32)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), messages_count_per_batch as usize);
+    assert!(messages
+        .iter()
+        .all(|message| message.offset >= messages_count_per_batch));
+}
+
 async fn assert_persisted_segment(partition_path: &str, start_offset: u64) {
     let segment_path = format!("{}/{:0>20}", partition_path, start_offset);
     let log_path = format!("{}.{}", segment_path, LOG_EXTENSION);
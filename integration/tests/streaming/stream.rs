@@ -22,6 +22,7 @@ use ahash::AHashMap;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::Partitioning;
+use iggy::models::topic_options::TopicOptions;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
@@ -30,6 +31,7 @@ use iggy::utils::topic_size::MaxTopicSize;
 use server::state::system::StreamState;
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::streams::stream::Stream;
+use server::streaming::transactions::transaction_coordinator::TransactionCoordinator;
 use tokio::fs;
 
 #[tokio::test]
@@ -137,6 +139,7 @@ async fn should_purge_existing_stream_on_disk() {
                 Default::default(),
                 MaxTopicSize::ServerDefault,
                 1,
+                TopicOptions::default(),
             )
             .await
             .unwrap();
@@ -151,7 +154,13 @@ async fn should_purge_existing_stream_on_disk() {
             .map(|msg| msg.get_size_bytes())
             .sum::<IggyByteSize>();
         topic
-            .append_messages(batch_size, Partitioning::partition_id(1), messages, None)
+            .append_messages(
+                batch_size,
+                Partitioning::partition_id(1),
+                messages,
+                None,
+                IggyTimestamp::now(),
+            )
             .await
             .unwrap();
         let loaded_messages = topic
@@ -160,6 +169,8 @@ async fn should_purge_existing_stream_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                IggyTimestamp::now(),
+                &TransactionCoordinator::default(),
             )
             .await
             .unwrap();
@@ -173,6 +184,8 @@ async fn should_purge_existing_stream_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                IggyTimestamp::now(),
+                &TransactionCoordinator::default(),
             )
             .await
             .unwrap();
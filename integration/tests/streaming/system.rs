@@ -17,11 +17,17 @@
  */
 
 use crate::streaming::common::test_setup::TestSetup;
+use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::timestamp::IggyTimestamp;
 use server::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
 use server::streaming::session::Session;
 use server::streaming::systems::system::System;
+use server::streaming::utils::clock::ManualClock;
 use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
 use tokio::fs;
 
 #[tokio::test]
@@ -120,6 +126,79 @@ async fn should_delete_persisted_stream() {
     assert!(fs::metadata(stream_path).await.is_err());
 }
 
+#[tokio::test]
+async fn should_expire_personal_access_token_when_clock_is_advanced() {
+    let setup = TestSetup::init().await;
+    let clock = Arc::new(ManualClock::new(IggyTimestamp::from(1_000_000)));
+    let mut system = System::new(
+        setup.config.clone(),
+        DataMaintenanceConfig::default(),
+        PersonalAccessTokenConfig::default(),
+    )
+    .with_clock(clock.clone());
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    let token = system
+        .create_personal_access_token(
+            &session,
+            "test",
+            IggyExpiry::ExpireDuration(IggyDuration::ONE_SECOND),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let ip_address = session.ip_address.ip();
+    clock.advance(IggyDuration::from(999_999));
+    assert!(system
+        .login_with_personal_access_token(&token, &ip_address, None)
+        .await
+        .is_ok());
+
+    clock.advance(IggyDuration::from(1));
+    assert!(matches!(
+        system
+            .login_with_personal_access_token(&token, &ip_address, None)
+            .await,
+        Err(IggyError::PersonalAccessTokenExpired(_, _))
+    ));
+}
+
+#[tokio::test]
+async fn should_allow_personal_access_token_login_only_from_allowed_addresses() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        DataMaintenanceConfig::default(),
+        PersonalAccessTokenConfig::default(),
+    );
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    let token = system
+        .create_personal_access_token(
+            &session,
+            "test",
+            IggyExpiry::NeverExpire,
+            None,
+            Some(vec!["10.0.0.0/8".to_string()]),
+        )
+        .await
+        .unwrap();
+
+    let (_, personal_access_token) = system
+        .login_with_personal_access_token(&token, &"10.1.2.3".parse().unwrap(), None)
+        .await
+        .unwrap();
+    assert!(personal_access_token.last_used_at().is_some());
+    assert!(matches!(
+        system
+            .login_with_personal_access_token(&token, &session.ip_address.ip(), None)
+            .await,
+        Err(IggyError::ClientAccessDenied)
+    ));
+}
+
 async fn assert_persisted_stream(streams_path: &str, stream_id: u32) {
     let streams_metadata = fs::metadata(streams_path).await.unwrap();
     assert!(streams_metadata.is_dir());
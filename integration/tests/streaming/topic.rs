@@ -30,6 +30,7 @@ use iggy::utils::topic_size::MaxTopicSize;
 use server::state::system::{PartitionState, TopicState};
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::topics::topic::Topic;
+use server::streaming::transactions::transaction_coordinator::TransactionCoordinator;
 use std::default::Default;
 use std::sync::atomic::{AtomicU32, AtomicU64};
 use std::sync::Arc;
@@ -134,6 +135,7 @@ async fn should_load_existing_topic_from_disk() {
             message_expiry: IggyExpiry::NeverExpire,
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
+            options: Default::default(),
             created_at: Default::default(),
         };
         loaded_topic.load(topic_state).await.unwrap();
@@ -231,7 +233,13 @@ async fn should_purge_existing_topic_on_disk() {
             .map(|msg| msg.get_size_bytes())
             .sum::<IggyByteSize>();
         topic
-            .append_messages(batch_size, Partitioning::partition_id(1), messages, None)
+            .append_messages(
+                batch_size,
+                Partitioning::partition_id(1),
+                messages,
+                None,
+                IggyTimestamp::now(),
+            )
             .await
             .unwrap();
         let loaded_messages = topic
@@ -240,6 +248,8 @@ async fn should_purge_existing_topic_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                IggyTimestamp::now(),
+                &TransactionCoordinator::default(),
             )
             .await
             .unwrap();
@@ -252,6 +262,8 @@ async fn should_purge_existing_topic_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                IggyTimestamp::now(),
+                &TransactionCoordinator::default(),
             )
             .await
             .unwrap();
@@ -24,11 +24,13 @@ use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
+use iggy::utils::timestamp::IggyTimestamp;
 use iggy::utils::topic_size::MaxTopicSize;
 use server::configs::resource_quota::MemoryResourceQuota;
-use server::configs::system::{CacheConfig, SystemConfig};
+use server::configs::system::{CacheConfig, PollingConfig, SystemConfig};
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::topics::topic::Topic;
+use server::streaming::transactions::transaction_coordinator::TransactionCoordinator;
 use server::streaming::utils::hash;
 use std::collections::HashMap;
 use std::str::from_utf8;
@@ -53,6 +55,7 @@ async fn given_enabled_cache_with_enough_capacity_all_messages_should_be_polled(
         CacheConfig {
             enabled: true,
             size: MemoryResourceQuota::Bytes(IggyByteSize::from(100_000_000)),
+            ..Default::default()
         },
         true,
     )
@@ -65,6 +68,7 @@ async fn given_enabled_cache_without_enough_capacity_all_messages_should_be_poll
         CacheConfig {
             enabled: true,
             size: MemoryResourceQuota::Bytes(IggyByteSize::from(100_000)),
+            ..Default::default()
         },
         true,
     )
@@ -102,7 +106,13 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
         .map(|m| m.get_size_bytes())
         .sum::<IggyByteSize>();
     topic
-        .append_messages(batch_size, partitioning, messages, None)
+        .append_messages(
+            batch_size,
+            partitioning,
+            messages,
+            None,
+            IggyTimestamp::now(),
+        )
         .await
         .unwrap();
 
@@ -113,6 +123,8 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
             partition_id,
             PollingStrategy::offset(0),
             messages_count,
+            IggyTimestamp::now(),
+            &TransactionCoordinator::default(),
         )
         .await
         .unwrap();
@@ -132,6 +144,118 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
     }
 }
 
+#[tokio::test]
+async fn given_max_response_size_polled_messages_should_be_truncated_with_next_offset() {
+    let messages_count = 100;
+    let payload_size_bytes = 1000;
+    let config = SystemConfig {
+        polling: PollingConfig {
+            max_response_size: IggyByteSize::from(10_000),
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let topic = init_topic(&setup, 1).await;
+    let partition_id = 1;
+    let partitioning = Partitioning::partition_id(partition_id);
+    let messages = (0..messages_count)
+        .map(|id| get_message(id as u128, &create_payload(payload_size_bytes)))
+        .collect::<Vec<_>>();
+    let batch_size = messages
+        .iter()
+        .map(|m| m.get_size_bytes())
+        .sum::<IggyByteSize>();
+    topic
+        .append_messages(
+            batch_size,
+            partitioning,
+            messages,
+            None,
+            IggyTimestamp::now(),
+        )
+        .await
+        .unwrap();
+
+    let consumer = PollingConsumer::Consumer(1, partition_id);
+    let mut offset = 0;
+    let mut polled_messages_count = 0;
+    loop {
+        let polled_messages = topic
+            .get_messages(
+                consumer,
+                partition_id,
+                PollingStrategy::offset(offset),
+                messages_count,
+                IggyTimestamp::now(),
+                &TransactionCoordinator::default(),
+            )
+            .await
+            .unwrap();
+        assert!(!polled_messages.messages.is_empty());
+        assert_eq!(polled_messages.messages[0].offset, offset);
+        polled_messages_count += polled_messages.messages.len() as u32;
+        let Some(next_offset) = polled_messages.next_offset else {
+            break;
+        };
+
+        assert!(polled_messages.messages.len() < messages_count as usize);
+        assert_eq!(
+            next_offset,
+            polled_messages.messages.last().unwrap().offset + 1
+        );
+        offset = next_offset;
+    }
+
+    assert_eq!(polled_messages_count, messages_count);
+}
+
+#[tokio::test]
+async fn given_message_larger_than_max_response_size_it_should_still_be_polled() {
+    let config = SystemConfig {
+        polling: PollingConfig {
+            max_response_size: IggyByteSize::from(100),
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let topic = init_topic(&setup, 1).await;
+    let partition_id = 1;
+    let partitioning = Partitioning::partition_id(partition_id);
+    let messages = (0..2)
+        .map(|id| get_message(id as u128, &create_payload(1000)))
+        .collect::<Vec<_>>();
+    let batch_size = messages
+        .iter()
+        .map(|m| m.get_size_bytes())
+        .sum::<IggyByteSize>();
+    topic
+        .append_messages(
+            batch_size,
+            partitioning,
+            messages,
+            None,
+            IggyTimestamp::now(),
+        )
+        .await
+        .unwrap();
+
+    let consumer = PollingConsumer::Consumer(1, partition_id);
+    let polled_messages = topic
+        .get_messages(
+            consumer,
+            partition_id,
+            PollingStrategy::offset(0),
+            2,
+            IggyTimestamp::now(),
+            &TransactionCoordinator::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert_eq!(polled_messages.messages[0].offset, 0);
+    assert_eq!(polled_messages.next_offset, Some(1));
+}
+
 #[tokio::test]
 async fn given_key_none_messages_should_be_appended_to_the_next_partition_using_round_robin() {
     let setup = TestSetup::init().await;
@@ -148,6 +272,7 @@ async fn given_key_none_messages_should_be_appended_to_the_next_partition_using_
                 partitioning.clone(),
                 vec![get_message(i as u128, &payload)],
                 None,
+                IggyTimestamp::now(),
             )
             .await
             .unwrap();
@@ -174,6 +299,7 @@ async fn given_key_partition_id_messages_should_be_appended_to_the_chosen_partit
                 partitioning.clone(),
                 vec![get_message(i as u128, &payload)],
                 None,
+                IggyTimestamp::now(),
             )
             .await
             .unwrap();
@@ -204,6 +330,7 @@ async fn given_key_messages_key_messages_should_be_appended_to_the_calculated_pa
                 partitioning,
                 vec![get_message(entity_id as u128, &payload)],
                 None,
+                IggyTimestamp::now(),
             )
             .await
             .unwrap();
@@ -236,7 +363,14 @@ fn get_payload(id: u32) -> String {
 async fn assert_messages(topic: &Topic, partition_id: u32, expected_messages: u32) {
     let consumer = PollingConsumer::Consumer(0, partition_id);
     let polled_messages = topic
-        .get_messages(consumer, partition_id, PollingStrategy::offset(0), 1000)
+        .get_messages(
+            consumer,
+            partition_id,
+            PollingStrategy::offset(0),
+            1000,
+            IggyTimestamp::now(),
+            &TransactionCoordinator::default(),
+        )
         .await
         .unwrap();
     assert_eq!(polled_messages.messages.len() as u32, expected_messages);
@@ -251,6 +251,12 @@ pub struct Args {
     /// Disable nodelay for the TCP transport
     pub tcp_nodelay: bool,
 
+    /// Flag to negotiate the frame checksums for the TCP transport
+    pub tcp_frame_checksums: bool,
+
+    /// Flag to negotiate the zero-copy polling for the TCP transport
+    pub tcp_zero_copy_polling: bool,
+
     /// The optional client address for the QUIC transport
     pub quic_client_address: String,
 
@@ -342,6 +348,8 @@ impl Default for Args {
             tcp_tls_domain: "localhost".to_string(),
             tcp_tls_ca_file: None,
             tcp_nodelay: false,
+            tcp_frame_checksums: false,
+            tcp_zero_copy_polling: false,
             quic_client_address: "127.0.0.1:0".to_string(),
             quic_server_address: "127.0.0.1:8080".to_string(),
             quic_server_name: "localhost".to_string(),
@@ -0,0 +1,103 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::bookmarks::delete_bookmark::DeleteBookmark;
+use crate::bookmarks::get_bookmark::GetBookmark;
+use crate::bookmarks::get_bookmarks::GetBookmarks;
+use crate::bookmarks::store_bookmark::StoreBookmark;
+use crate::client::BookmarkClient;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::bookmark::Bookmark;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> BookmarkClient for B {
+    async fn get_bookmark(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<Option<Bookmark>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetBookmark {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                name: name.to_string(),
+            })
+            .await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_bookmark(response).map(Some)
+    }
+
+    async fn get_bookmarks(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Bookmark>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetBookmarks {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+            })
+            .await?;
+        mapper::map_bookmarks(response)
+    }
+
+    async fn store_bookmark(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&StoreBookmark {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            name: name.to_string(),
+            partition_id,
+            offset,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_bookmark(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DeleteBookmark {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            name: name.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+}
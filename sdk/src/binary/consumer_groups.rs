@@ -19,10 +19,14 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::ConsumerGroupClient;
+use crate::consumer_groups::assignment_strategy::AssignmentStrategy;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
-use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_group::{
+    GetConsumerGroup, CONSUMER_GROUP_DETAILS_VERSION,
+};
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::error::IggyError;
@@ -43,6 +47,7 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
                 stream_id: stream_id.clone(),
                 topic_id: topic_id.clone(),
                 group_id: group_id.clone(),
+                details_version: CONSUMER_GROUP_DETAILS_VERSION,
             })
             .await?;
         if response.is_empty() {
@@ -73,6 +78,24 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
         topic_id: &Identifier,
         name: &str,
         group_id: Option<u32>,
+    ) -> Result<ConsumerGroupDetails, IggyError> {
+        self.create_consumer_group_with_strategy(
+            stream_id,
+            topic_id,
+            name,
+            group_id,
+            AssignmentStrategy::default(),
+        )
+        .await
+    }
+
+    async fn create_consumer_group_with_strategy(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+        assignment_strategy: AssignmentStrategy,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -81,9 +104,13 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
                 topic_id: topic_id.clone(),
                 name: name.to_string(),
                 group_id,
+                assignment_strategy,
             })
             .await?;
-        mapper::map_consumer_group(response)
+        // The created group is returned in the legacy layout, so the strategy is the one which has been requested.
+        let mut consumer_group = mapper::map_consumer_group(response)?;
+        consumer_group.assignment_strategy = assignment_strategy;
+        Ok(consumer_group)
     }
 
     async fn delete_consumer_group(
@@ -113,6 +140,25 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
             stream_id: stream_id.clone(),
             topic_id: topic_id.clone(),
             group_id: group_id.clone(),
+            assignment_strategy: None,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn join_consumer_group_with_strategy(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        assignment_strategy: AssignmentStrategy,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&JoinConsumerGroup {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            group_id: group_id.clone(),
+            assignment_strategy: Some(assignment_strategy),
         })
         .await?;
         Ok(())
@@ -133,4 +179,20 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
         .await?;
         Ok(())
     }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&HeartbeatConsumerGroup {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            group_id: group_id.clone(),
+        })
+        .await?;
+        Ok(())
+    }
 }
@@ -18,34 +18,64 @@
 
 use crate::bytes_serializable::BytesSerializable;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::consumer::ConsumerKind;
+use crate::consumer_groups::assignment_strategy::AssignmentStrategy;
 use crate::error::IggyError;
+use crate::models::audit_entry::{AuditEntry, AuditOutcome};
+use crate::models::backup::{BackupDestination, BackupInfo};
+use crate::models::bookmark::Bookmark;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use crate::models::cluster::{ClusterMetadata, ClusterNode, ClusterPartition, ClusterTopic};
+use crate::models::config_value::{ConfigSource, ConfigValue};
+use crate::models::consumer_group::{
+    ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember, ConsumerGroupRebalance,
+};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
-use crate::models::messages::{MessageState, PolledMessage, PolledMessages};
-use crate::models::partition::Partition;
+use crate::models::messages::{
+    MessageState, PolledMessage, PolledMessageView, PolledMessages, PolledMessagesMetadata,
+};
+use crate::models::partition::{
+    Partition, PartitionConsumerOffset, PartitionDetails, PartitionSegment,
+};
 use crate::models::permissions::Permissions;
-use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::personal_access_token::{
+    read_allowed_addresses, PersonalAccessTokenInfo, PersonalAccessTokenScope,
+    RawPersonalAccessToken,
+};
+use crate::models::quota::{Quota, QuotaLimits, QuotaPrincipal};
+use crate::models::schema::Schema;
+use crate::models::segments_verification::{CorruptedBatchInfo, SegmentsVerification};
 use crate::models::stats::{CacheMetrics, CacheMetricsKey, Stats};
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::topic_options::read_optional_topic_options;
+use crate::models::topic_schema::TopicSchema;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
+use crate::system::enable_zero_copy_polling::{MESSAGES_FRAMING, STORED_BATCHES_FRAMING};
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::checksum::ChecksumHasher;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::topic_size::MaxTopicSize;
-use bytes::Bytes;
+use bytes::{Buf, Bytes};
 use std::collections::HashMap;
 use std::str::from_utf8;
 
 const EMPTY_MESSAGES: Vec<PolledMessage> = vec![];
+const POLLED_MESSAGES_HEADER_LENGTH: usize = 24;
+const STORED_BATCH_HEADER_LENGTH: usize = 28;
+const STORED_BATCH_CHECKSUM_POSITION: usize = 24;
+const STORED_MESSAGE_HEADER_LENGTH: usize = 45;
 const EMPTY_TOPICS: Vec<Topic> = vec![];
 const EMPTY_STREAMS: Vec<Stream> = vec![];
 const EMPTY_CLIENTS: Vec<ClientInfo> = vec![];
 const EMPTY_USERS: Vec<UserInfo> = vec![];
 const EMPTY_PERSONAL_ACCESS_TOKENS: Vec<PersonalAccessTokenInfo> = vec![];
 const EMPTY_CONSUMER_GROUPS: Vec<ConsumerGroup> = vec![];
+const EMPTY_SCHEMAS: Vec<Schema> = vec![];
+const EMPTY_QUOTAS: Vec<Quota> = vec![];
+const EMPTY_BOOKMARKS: Vec<Bookmark> = vec![];
 
 pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
     let process_id = u32::from_le_bytes(
@@ -352,6 +382,28 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
         }
     }
 
+    // disk_usage (if it exists)
+    let mut disk_usage = 0.into();
+    if current_position + 8 <= payload.len() {
+        disk_usage = u64::from_le_bytes(
+            payload[current_position..current_position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        )
+        .into();
+        current_position += 8;
+    }
+
+    // fsyncs_count (if it exists)
+    let mut fsyncs_count = 0;
+    if current_position + 8 <= payload.len() {
+        fsyncs_count = u64::from_le_bytes(
+            payload[current_position..current_position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+    }
+
     Ok(Stats {
         process_id,
         cpu_usage,
@@ -378,6 +430,8 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
         iggy_server_version,
         iggy_server_semver,
         cache_metrics,
+        disk_usage,
+        fsyncs_count,
     })
 }
 
@@ -404,6 +458,316 @@ pub fn map_consumer_offset(payload: Bytes) -> Result<ConsumerOffsetInfo, IggyErr
     })
 }
 
+pub fn map_bookmarks(payload: Bytes) -> Result<Vec<Bookmark>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_BOOKMARKS);
+    }
+
+    let mut bookmarks = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (bookmark, read_bytes) = map_to_bookmark(payload.clone(), position)?;
+        bookmarks.push(bookmark);
+        position += read_bytes;
+    }
+    bookmarks.sort_by(|x, y| x.name.cmp(&y.name));
+    Ok(bookmarks)
+}
+
+pub fn map_bookmark(payload: Bytes) -> Result<Bookmark, IggyError> {
+    let (bookmark, _) = map_to_bookmark(payload, 0)?;
+    Ok(bookmark)
+}
+
+fn map_to_bookmark(payload: Bytes, position: usize) -> Result<(Bookmark, usize), IggyError> {
+    let partition_id = u32::from_le_bytes(
+        payload[position..position + 4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let offset = u64::from_le_bytes(
+        payload[position + 4..position + 12]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let created_at = u64::from_le_bytes(
+        payload[position + 12..position + 20]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let updated_at = u64::from_le_bytes(
+        payload[position + 20..position + 28]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let name_length = payload[position + 28] as usize;
+    let name = from_utf8(&payload[position + 29..position + 29 + name_length])
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+    let read_bytes = 29 + name_length;
+    Ok((
+        Bookmark {
+            name,
+            partition_id,
+            offset,
+            created_at,
+            updated_at,
+        },
+        read_bytes,
+    ))
+}
+
+pub fn map_audit_entries(payload: Bytes) -> Result<Vec<AuditEntry>, IggyError> {
+    let mut entries = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let id = read_u64(&payload, position)?;
+        let timestamp = read_u64(&payload, position + 8)?;
+        let user_id = read_u32(&payload, position + 16)?;
+        let outcome = payload
+            .get(position + 20)
+            .ok_or(IggyError::InvalidNumberEncoding)
+            .and_then(|code| AuditOutcome::from_code(*code))?;
+        position += 21;
+        let (address, read_bytes) = read_audit_string(&payload, position, 1)?;
+        position += read_bytes;
+        let (transport, read_bytes) = read_audit_string(&payload, position, 1)?;
+        position += read_bytes;
+        let (action, read_bytes) = read_audit_string(&payload, position, 1)?;
+        position += read_bytes;
+        let (details, read_bytes) = read_audit_string(&payload, position, 4)?;
+        position += read_bytes;
+        let (error, read_bytes) = read_audit_string(&payload, position, 4)?;
+        position += read_bytes;
+        entries.push(AuditEntry {
+            id,
+            timestamp: timestamp.into(),
+            user_id,
+            address,
+            transport,
+            action,
+            details,
+            outcome,
+            error: (!error.is_empty()).then_some(error),
+        });
+    }
+    Ok(entries)
+}
+
+pub fn map_backup(payload: Bytes) -> Result<BackupInfo, IggyError> {
+    let destination = payload
+        .first()
+        .ok_or(IggyError::InvalidNumberEncoding)
+        .and_then(|code| BackupDestination::from_code(*code))?;
+    let created_at = read_u64(&payload, 1)?;
+    let streams_count = read_u32(&payload, 9)?;
+    let files_count = read_u32(&payload, 13)?;
+    let size = read_u64(&payload, 17)?;
+    let mut position = 25;
+    let (name, read_bytes) = read_audit_string(&payload, position, 1)?;
+    position += read_bytes;
+    let (base, read_bytes) = read_audit_string(&payload, position, 1)?;
+    position += read_bytes;
+    let (location, _) = read_audit_string(&payload, position, 4)?;
+    Ok(BackupInfo {
+        name,
+        destination,
+        base: (!base.is_empty()).then_some(base),
+        location,
+        created_at: created_at.into(),
+        streams_count,
+        files_count,
+        size: size.into(),
+    })
+}
+
+pub fn map_cluster_metadata(payload: Bytes) -> Result<ClusterMetadata, IggyError> {
+    let enabled = *payload.first().ok_or(IggyError::InvalidNumberEncoding)? == 1;
+    let node_id = read_u32(&payload, 1)?;
+    let leader_id = read_u32(&payload, 5)?;
+    let nodes_count = read_u32(&payload, 9)?;
+    let mut position = 13;
+    let mut nodes = Vec::with_capacity(nodes_count as usize);
+    for _ in 0..nodes_count {
+        let id = read_u32(&payload, position)?;
+        let alive = *payload
+            .get(position + 4)
+            .ok_or(IggyError::InvalidNumberEncoding)?
+            == 1;
+        position += 5;
+        let (address, read_bytes) = read_audit_string(&payload, position, 1)?;
+        position += read_bytes;
+        nodes.push(ClusterNode { id, address, alive });
+    }
+
+    let mut topics = Vec::new();
+    while position < payload.len() {
+        let stream_id = read_u32(&payload, position)?;
+        position += 4;
+        let (stream_name, read_bytes) = read_audit_string(&payload, position, 1)?;
+        position += read_bytes;
+        let topic_id = read_u32(&payload, position)?;
+        position += 4;
+        let (topic_name, read_bytes) = read_audit_string(&payload, position, 1)?;
+        position += read_bytes;
+        let partitions_count = read_u32(&payload, position)?;
+        position += 4;
+        let mut partitions = Vec::with_capacity(partitions_count as usize);
+        for _ in 0..partitions_count {
+            let id = read_u32(&payload, position)?;
+            let leader_id = read_u32(&payload, position + 4)?;
+            let replicas_count = *payload
+                .get(position + 8)
+                .ok_or(IggyError::InvalidNumberEncoding)?;
+            position += 9;
+            let mut replicas = Vec::with_capacity(replicas_count as usize);
+            for _ in 0..replicas_count {
+                replicas.push(read_u32(&payload, position)?);
+                position += 4;
+            }
+            partitions.push(ClusterPartition {
+                id,
+                leader_id: (leader_id > 0).then_some(leader_id),
+                replicas,
+            });
+        }
+        topics.push(ClusterTopic {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partitions,
+        });
+    }
+
+    Ok(ClusterMetadata {
+        enabled,
+        node_id,
+        leader_id: (leader_id > 0).then_some(leader_id),
+        nodes,
+        topics,
+    })
+}
+
+fn read_u64(payload: &[u8], position: usize) -> Result<u64, IggyError> {
+    payload
+        .get(position..position + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(IggyError::InvalidNumberEncoding)
+}
+
+fn read_u32(payload: &[u8], position: usize) -> Result<u32, IggyError> {
+    payload
+        .get(position..position + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u32::from_le_bytes)
+        .ok_or(IggyError::InvalidNumberEncoding)
+}
+
+/// Reads the string prefixed with its length encoded on the `length_size` bytes (either 1 or 4),
+/// returns the string and the total number of the read bytes.
+fn read_audit_string(
+    payload: &[u8],
+    position: usize,
+    length_size: usize,
+) -> Result<(String, usize), IggyError> {
+    let length = if length_size == 1 {
+        *payload
+            .get(position)
+            .ok_or(IggyError::InvalidNumberEncoding)? as usize
+    } else {
+        read_u32(payload, position)? as usize
+    };
+    let start = position + length_size;
+    let value = payload
+        .get(start..start + length)
+        .ok_or(IggyError::InvalidCommand)?;
+    let value = from_utf8(value)
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+    Ok((value, length_size + length))
+}
+
+pub fn map_config_values(payload: Bytes) -> Result<Vec<ConfigValue>, IggyError> {
+    let mut values = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let source = ConfigSource::from_code(payload[position])?;
+        let key_length = u32::from_le_bytes(
+            payload[position + 1..position + 5]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 5;
+        let key = from_utf8(&payload[position..position + key_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        position += key_length;
+        let value_length = u32::from_le_bytes(
+            payload[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 4;
+        let value = serde_json::from_slice(&payload[position..position + value_length])
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        position += value_length;
+        values.push(ConfigValue { key, value, source });
+    }
+    Ok(values)
+}
+
+pub fn map_segments_verification(payload: Bytes) -> Result<SegmentsVerification, IggyError> {
+    let segments_count = u32::from_le_bytes(
+        payload[..4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let batches_count = u32::from_le_bytes(
+        payload[4..8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let corrupted_batches_count = u32::from_le_bytes(
+        payload[8..12]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let mut corrupted_batches = Vec::with_capacity(corrupted_batches_count as usize);
+    let mut position = 12;
+    for _ in 0..corrupted_batches_count {
+        let mut fields = [0u64; 4];
+        for field in fields.iter_mut() {
+            *field = u64::from_le_bytes(
+                payload
+                    .get(position..position + 8)
+                    .ok_or(IggyError::InvalidFormat)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            position += 8;
+        }
+        corrupted_batches.push(CorruptedBatchInfo {
+            segment_start_offset: fields[0],
+            base_offset: fields[1],
+            position: fields[2],
+            length: fields[3],
+        });
+    }
+
+    Ok(SegmentsVerification {
+        segments_count,
+        batches_count,
+        corrupted_batches,
+    })
+}
+
 pub fn map_user(payload: Bytes) -> Result<UserInfoDetails, IggyError> {
     let (user, position) = map_to_user_info(payload.clone(), 0)?;
     let has_permissions = payload[position];
@@ -477,6 +841,17 @@ pub fn map_identity_info(payload: Bytes) -> Result<IdentityInfo, IggyError> {
     })
 }
 
+pub fn map_transaction_id(payload: Bytes) -> Result<u64, IggyError> {
+    let transaction_id = u64::from_le_bytes(
+        payload
+            .get(..8)
+            .ok_or(IggyError::InvalidNumberEncoding)?
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    Ok(transaction_id)
+}
+
 pub fn map_raw_pat(payload: Bytes) -> Result<RawPersonalAccessToken, IggyError> {
     let token_length = payload[0];
     let token = from_utf8(&payload[1..1 + token_length as usize])
@@ -551,10 +926,79 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
             messages: EMPTY_MESSAGES,
             partition_id: 0,
             current_offset: 0,
+            next_offset: None,
         });
     }
 
-    let length = payload.len();
+    let mut messages = Vec::new();
+    let metadata = visit_polled_messages(&payload, |message| {
+        messages.push(map_polled_message(&payload, message)?);
+        Ok(())
+    })?;
+    messages.sort_by_key(|message| message.offset);
+    Ok(PolledMessages {
+        partition_id: metadata.partition_id,
+        current_offset: metadata.current_offset,
+        messages,
+        next_offset: metadata.next_offset,
+    })
+}
+
+pub fn map_zero_copy_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError> {
+    if payload.is_empty() {
+        return map_polled_messages(payload);
+    }
+
+    match payload[0] {
+        MESSAGES_FRAMING => map_polled_messages(payload.slice(1..)),
+        STORED_BATCHES_FRAMING => map_stored_batches(payload.slice(1..)),
+        _ => Err(IggyError::InvalidFormat),
+    }
+}
+
+fn map_stored_batches(payload: Bytes) -> Result<PolledMessages, IggyError> {
+    let metadata = read_polled_messages_metadata(&payload)?;
+    let mut messages = Vec::with_capacity(metadata.messages_count as usize);
+    visit_stored_batches(&payload, |message| {
+        messages.push(map_polled_message(&payload, message)?);
+        Ok(())
+    })?;
+    messages.sort_by_key(|message| message.offset);
+    Ok(PolledMessages {
+        partition_id: metadata.partition_id,
+        current_offset: metadata.current_offset,
+        messages,
+        next_offset: metadata.next_offset,
+    })
+}
+
+/// Maps the borrowed message to the owned one, with the headers and payload referencing the receive buffer instead of being copied.
+fn map_polled_message(
+    buffer: &Bytes,
+    message: PolledMessageView<'_>,
+) -> Result<PolledMessage, IggyError> {
+    let headers = if message.headers.is_empty() {
+        None
+    } else {
+        Some(HashMap::from_bytes(buffer.slice_ref(message.headers))?)
+    };
+    Ok(PolledMessage {
+        offset: message.offset,
+        timestamp: message.timestamp,
+        state: message.state,
+        checksum: message.checksum,
+        id: message.id,
+        headers,
+        length: IggyByteSize::from(message.payload.len() as u64),
+        payload: buffer.slice_ref(message.payload),
+    })
+}
+
+fn read_polled_messages_metadata(payload: &[u8]) -> Result<PolledMessagesMetadata, IggyError> {
+    if payload.len() < POLLED_MESSAGES_HEADER_LENGTH {
+        return Err(IggyError::InvalidFormat);
+    }
+
     let partition_id = u32::from_le_bytes(
         payload[..4]
             .try_into()
@@ -565,14 +1009,62 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    // Currently ignored
-    let _messages_count = u32::from_le_bytes(
+    let messages_count = u32::from_le_bytes(
         payload[12..16]
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    let mut position = 16;
-    let mut messages = Vec::new();
+    let next_offset = u64::from_le_bytes(
+        payload[16..24]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let next_offset = if next_offset > 0 {
+        Some(next_offset)
+    } else {
+        None
+    };
+    Ok(PolledMessagesMetadata {
+        partition_id,
+        current_offset,
+        messages_count,
+        next_offset,
+    })
+}
+
+/// Invokes the visitor with each message of the zero-copy poll response, borrowed from the payload, in the order they were sent by the server.
+pub fn visit_zero_copy_polled_messages<'a>(
+    payload: &'a [u8],
+    visitor: impl FnMut(PolledMessageView<'a>) -> Result<(), IggyError>,
+) -> Result<PolledMessagesMetadata, IggyError> {
+    if payload.is_empty() {
+        return visit_polled_messages(payload, visitor);
+    }
+
+    match payload[0] {
+        MESSAGES_FRAMING => visit_polled_messages(&payload[1..], visitor),
+        STORED_BATCHES_FRAMING => visit_stored_batches(&payload[1..], visitor),
+        _ => Err(IggyError::InvalidFormat),
+    }
+}
+
+/// Invokes the visitor with each message of the poll response, borrowed from the payload, in the order they were sent by the server.
+pub fn visit_polled_messages<'a>(
+    payload: &'a [u8],
+    mut visitor: impl FnMut(PolledMessageView<'a>) -> Result<(), IggyError>,
+) -> Result<PolledMessagesMetadata, IggyError> {
+    if payload.is_empty() {
+        return Ok(PolledMessagesMetadata {
+            partition_id: 0,
+            current_offset: 0,
+            messages_count: 0,
+            next_offset: None,
+        });
+    }
+
+    let metadata = read_polled_messages_metadata(payload)?;
+    let length = payload.len();
+    let mut position = POLLED_MESSAGES_HEADER_LENGTH;
     while position < length {
         let offset = u64::from_le_bytes(
             payload[position..position + 8]
@@ -600,13 +1092,7 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
-        let headers = if headers_length > 0 {
-            let headers_payload =
-                payload.slice(position + 41..position + 41 + headers_length as usize);
-            Some(HashMap::from_bytes(headers_payload)?)
-        } else {
-            None
-        };
+        let headers = &payload[position + 41..position + 41 + headers_length as usize];
         position += headers_length as usize;
         let message_length = u32::from_le_bytes(
             payload[position + 41..position + 45]
@@ -618,31 +1104,120 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
             break;
         }
 
-        let payload = payload[payload_range].to_vec();
-        let total_size = 45 + message_length as usize;
-        position += total_size;
-        messages.push(PolledMessage {
+        visitor(PolledMessageView {
             offset,
-            timestamp,
             state,
-            checksum,
+            timestamp,
             id,
+            checksum,
             headers,
-            length: IggyByteSize::from(message_length as u64),
-            payload: Bytes::from(payload),
-        });
-
+            payload: &payload[payload_range],
+        })?;
+        position += 45 + message_length as usize;
         if position + 45 >= length {
             break;
         }
     }
 
-    messages.sort_by(|x, y| x.offset.cmp(&y.offset));
-    Ok(PolledMessages {
-        partition_id,
-        current_offset,
-        messages,
-    })
+    Ok(metadata)
+}
+
+/// Invokes the visitor with each message of the stored batches, borrowed from the payload, skipping the corrupted batches.
+pub fn visit_stored_batches<'a>(
+    payload: &'a [u8],
+    mut visitor: impl FnMut(PolledMessageView<'a>) -> Result<(), IggyError>,
+) -> Result<PolledMessagesMetadata, IggyError> {
+    let metadata = read_polled_messages_metadata(payload)?;
+    let length = payload.len();
+
+    // Each stored batch consists of the header (base offset, length, last offset delta, max timestamp and checksum) and the stored messages,
+    // which are prefixed with their length, and have the payload length implied by it.
+    // The batches having the checksum not matching their content are corrupted and skipped.
+    let mut position = POLLED_MESSAGES_HEADER_LENGTH;
+    while position + STORED_BATCH_HEADER_LENGTH <= length {
+        let batch_length = u32::from_le_bytes(
+            payload[position + 8..position + 12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let batch_start = position;
+        position += STORED_BATCH_HEADER_LENGTH;
+        let batch_end = position + batch_length as usize;
+        if batch_end > length {
+            return Err(IggyError::InvalidFormat);
+        }
+
+        let checksum = u32::from_le_bytes(
+            payload[batch_start + STORED_BATCH_CHECKSUM_POSITION..position]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let mut hasher = ChecksumHasher::new();
+        hasher.update(&payload[batch_start..batch_start + STORED_BATCH_CHECKSUM_POSITION]);
+        hasher.update(&payload[position..batch_end]);
+        if hasher.checksum() != checksum {
+            position = batch_end;
+            continue;
+        }
+
+        while position < batch_end {
+            let message_length = u32::from_le_bytes(
+                payload[position..position + 4]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            let message_end = position + 4 + message_length as usize;
+            if message_end > batch_end
+                || (message_length as usize) < STORED_MESSAGE_HEADER_LENGTH - 4
+            {
+                return Err(IggyError::InvalidFormat);
+            }
+
+            let offset = u64::from_le_bytes(
+                payload[position + 4..position + 12]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            let state = MessageState::from_code(payload[position + 12])?;
+            let timestamp = u64::from_le_bytes(
+                payload[position + 13..position + 21]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            let id = u128::from_le_bytes(
+                payload[position + 21..position + 37]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            let checksum = u32::from_le_bytes(
+                payload[position + 37..position + 41]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            let headers_length = u32::from_le_bytes(
+                payload[position + 41..position + 45]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            let payload_start = position + STORED_MESSAGE_HEADER_LENGTH + headers_length as usize;
+            if payload_start > message_end {
+                return Err(IggyError::InvalidFormat);
+            }
+
+            visitor(PolledMessageView {
+                offset,
+                state,
+                timestamp,
+                id,
+                checksum,
+                headers: &payload[position + STORED_MESSAGE_HEADER_LENGTH..payload_start],
+                payload: &payload[payload_start..message_end],
+            })?;
+            position = message_end;
+        }
+    }
+
+    Ok(metadata)
 }
 
 pub fn map_streams(payload: Bytes) -> Result<Vec<Stream>, IggyError> {
@@ -750,6 +1325,16 @@ pub fn map_topics(payload: Bytes) -> Result<Vec<Topic>, IggyError> {
 
 pub fn map_topic(payload: Bytes) -> Result<TopicDetails, IggyError> {
     let (topic, mut position) = map_to_topic(payload.clone(), 0)?;
+    let (options, read_bytes) = read_optional_topic_options(&payload, position)?;
+    position += read_bytes;
+    let disk_usage = u64::from_le_bytes(
+        payload
+            .get(position..position + 8)
+            .ok_or(IggyError::InvalidNumberEncoding)?
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    position += 8;
     let mut partitions = Vec::new();
     let length = payload.len();
     while position < length {
@@ -772,6 +1357,8 @@ pub fn map_topic(payload: Bytes) -> Result<TopicDetails, IggyError> {
         #[allow(clippy::cast_possible_truncation)]
         partitions_count: partitions.len() as u32,
         partitions,
+        options,
+        disk_usage: disk_usage.into(),
     };
     Ok(topic)
 }
@@ -841,6 +1428,94 @@ fn map_to_topic(payload: Bytes, position: usize) -> Result<(Topic, usize), IggyE
     ))
 }
 
+pub fn map_partition(payload: Bytes) -> Result<PartitionDetails, IggyError> {
+    let (partition, mut position) = map_to_partition(payload.clone(), 0)?;
+    let name_length = *payload.get(position).ok_or(IggyError::InvalidCommand)? as usize;
+    position += 1;
+    let name = match name_length {
+        0 => None,
+        _ => Some(
+            from_utf8(&payload[position..position + name_length])
+                .map_err(|_| IggyError::InvalidUtf8)?
+                .to_string(),
+        ),
+    };
+    position += name_length;
+    let segments_count = u32::from_le_bytes(
+        payload[position..position + 4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    position += 4;
+    let mut segments = Vec::with_capacity(segments_count as usize);
+    for _ in 0..segments_count {
+        let start_offset = u64::from_le_bytes(
+            payload[position..position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let current_offset = u64::from_le_bytes(
+            payload[position + 8..position + 16]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let size = u64::from_le_bytes(
+            payload[position + 16..position + 24]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        )
+        .into();
+        let messages_count = u64::from_le_bytes(
+            payload[position + 24..position + 32]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let is_closed = payload[position + 32] == 1;
+        segments.push(PartitionSegment {
+            start_offset,
+            current_offset,
+            size,
+            messages_count,
+            is_closed,
+        });
+        position += 33;
+    }
+
+    let mut consumer_offsets = Vec::new();
+    let length = payload.len();
+    while position < length {
+        let kind = ConsumerKind::from_code(payload[position])?;
+        let consumer_id = u32::from_le_bytes(
+            payload[position + 1..position + 5]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let offset = u64::from_le_bytes(
+            payload[position + 5..position + 13]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        consumer_offsets.push(PartitionConsumerOffset {
+            kind,
+            consumer_id,
+            offset,
+        });
+        position += 13;
+    }
+
+    Ok(PartitionDetails {
+        id: partition.id,
+        name,
+        created_at: partition.created_at,
+        segments_count: partition.segments_count,
+        current_offset: partition.current_offset,
+        size: partition.size,
+        messages_count: partition.messages_count,
+        segments,
+        consumer_offsets,
+    })
+}
+
 fn map_to_partition(payload: Bytes, position: usize) -> Result<(Partition, usize), IggyError> {
     let id = u32::from_le_bytes(
         payload[position..position + 4]
@@ -908,23 +1583,203 @@ pub fn map_consumer_groups(payload: Bytes) -> Result<Vec<ConsumerGroup>, IggyErr
 pub fn map_consumer_group(payload: Bytes) -> Result<ConsumerGroupDetails, IggyError> {
     let (consumer_group, mut position) = map_to_consumer_group(payload.clone(), 0)?;
     let mut members = Vec::new();
-    let length = payload.len();
-    while position < length {
+    for _ in 0..consumer_group.members_count {
         let (member, read_bytes) = map_to_consumer_group_member(payload.clone(), position)?;
         members.push(member);
         position += read_bytes;
     }
     members.sort_by(|x, y| x.id.cmp(&y.id));
-    let consumer_group_details = ConsumerGroupDetails {
+    let mut consumer_group_details = ConsumerGroupDetails {
         id: consumer_group.id,
         name: consumer_group.name,
         partitions_count: consumer_group.partitions_count,
         members_count: consumer_group.members_count,
+        generation: 0,
+        assignment_strategy: AssignmentStrategy::default(),
+        topic_deleting: false,
         members,
     };
+    // The details following the members are missing in the legacy layout.
+    if payload.len() >= position + 6 {
+        consumer_group_details.generation = u32::from_le_bytes(
+            payload[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        consumer_group_details.assignment_strategy =
+            AssignmentStrategy::from_code(payload[position + 4])?;
+        consumer_group_details.topic_deleting = payload[position + 5] == 1;
+    }
     Ok(consumer_group_details)
 }
 
+pub fn map_consumer_group_rebalance(payload: Bytes) -> Result<ConsumerGroupRebalance, IggyError> {
+    let mut position = 0;
+    let read_u32 = |position: &mut usize| -> Result<u32, IggyError> {
+        let value = u32::from_le_bytes(
+            payload
+                .get(*position..*position + 4)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        *position += 4;
+        Ok(value)
+    };
+    let stream_id = read_u32(&mut position)?;
+    let topic_id = read_u32(&mut position)?;
+    let group_id = read_u32(&mut position)?;
+    let generation = read_u32(&mut position)?;
+    let partitions_count = read_u32(&mut position)?;
+    let mut partitions = Vec::with_capacity(partitions_count as usize);
+    for _ in 0..partitions_count {
+        partitions.push(read_u32(&mut position)?);
+    }
+    let mut names = Vec::with_capacity(3);
+    for _ in 0..3 {
+        let length = *payload.get(position).ok_or(IggyError::InvalidCommand)? as usize;
+        let name = from_utf8(
+            payload
+                .get(position + 1..position + 1 + length)
+                .ok_or(IggyError::InvalidCommand)?,
+        )
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+        names.push(name);
+        position += 1 + length;
+    }
+    let group_name = names.pop().unwrap_or_default();
+    let topic_name = names.pop().unwrap_or_default();
+    let stream_name = names.pop().unwrap_or_default();
+    partitions.sort_unstable();
+    Ok(ConsumerGroupRebalance {
+        stream_id,
+        stream_name,
+        topic_id,
+        topic_name,
+        group_id,
+        group_name,
+        generation,
+        partitions,
+    })
+}
+
+pub fn map_schemas(payload: Bytes) -> Result<Vec<Schema>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_SCHEMAS);
+    }
+
+    let mut schemas = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (schema, read_bytes) = map_to_schema(payload.clone(), position)?;
+        schemas.push(schema);
+        position += read_bytes;
+    }
+    schemas.sort_by_key(|schema| schema.version);
+    Ok(schemas)
+}
+
+pub fn map_schema(payload: Bytes) -> Result<Schema, IggyError> {
+    let (schema, _) = map_to_schema(payload, 0)?;
+    Ok(schema)
+}
+
+fn map_to_schema(payload: Bytes, position: usize) -> Result<(Schema, usize), IggyError> {
+    let id = u32::from_le_bytes(
+        payload[position..position + 4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let version = u32::from_le_bytes(
+        payload[position + 4..position + 8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let created_at = u64::from_le_bytes(
+        payload[position + 8..position + 16]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let subject_length = payload[position + 16] as usize;
+    let subject = from_utf8(&payload[position + 17..position + 17 + subject_length])
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+    let mut read_bytes = 17 + subject_length;
+    let schema_length = u32::from_le_bytes(
+        payload[position + read_bytes..position + read_bytes + 4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ) as usize;
+    read_bytes += 4;
+    let schema = TopicSchema::from_bytes(
+        payload.slice(position + read_bytes..position + read_bytes + schema_length),
+    )?;
+    read_bytes += schema_length;
+    Ok((
+        Schema {
+            id,
+            subject,
+            version,
+            created_at,
+            schema,
+        },
+        read_bytes,
+    ))
+}
+
+pub fn map_quotas(payload: Bytes) -> Result<Vec<Quota>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_QUOTAS);
+    }
+
+    let mut quotas = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let principal_length = match payload[position] {
+            1 => 5,
+            _ => 9,
+        };
+        if length < position + principal_length + 81 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let principal =
+            QuotaPrincipal::from_bytes(payload.slice(position..position + principal_length))?;
+        position += principal_length;
+        let limits = QuotaLimits::from_bytes(payload.slice(position..position + 32))?;
+        position += 32;
+        let is_default = payload[position] == 1;
+        position += 1;
+        let mut counters = [0u64; 6];
+        for counter in counters.iter_mut() {
+            *counter = u64::from_le_bytes(
+                payload[position..position + 8]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            position += 8;
+        }
+        let [produced_bytes, produced_messages, consumed_bytes, consumed_messages, delayed_requests, rejected_requests] =
+            counters;
+        quotas.push(Quota {
+            principal,
+            limits,
+            is_default,
+            produced_bytes,
+            produced_messages,
+            consumed_bytes,
+            consumed_messages,
+            delayed_requests,
+            rejected_requests,
+        });
+    }
+    Ok(quotas)
+}
+
 fn map_to_consumer_group(
     payload: Bytes,
     position: usize,
@@ -1100,6 +1955,317 @@ fn map_to_pat_info(
         0 => None,
         value => Some(value.into()),
     };
-    let read_bytes = 1 + name_length as usize + 8;
-    Ok((PersonalAccessTokenInfo { name, expiry_at }, read_bytes))
+    let mut bytes = payload.slice(position + 8..);
+    let remaining = bytes.len();
+    let scope = match bytes.get_u8() {
+        1 => {
+            let scope_length = bytes.get_u32_le() as usize;
+            Some(PersonalAccessTokenScope::from_bytes(
+                bytes.split_to(scope_length),
+            )?)
+        }
+        _ => None,
+    };
+    let allowed_addresses = read_allowed_addresses(&mut bytes)?;
+    let last_used_at = match bytes.get_u64_le() {
+        0 => None,
+        value => Some(value.into()),
+    };
+    let read_bytes = 1 + name_length as usize + 8 + remaining - bytes.len();
+    Ok((
+        PersonalAccessTokenInfo {
+            name,
+            expiry_at,
+            scope,
+            allowed_addresses,
+            last_used_at,
+        },
+        read_bytes,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::header::{HeaderKey, HeaderValue};
+    use crate::utils::timestamp::IggyTimestamp;
+    use bytes::{BufMut, BytesMut};
+    use std::str::FromStr;
+
+    #[test]
+    fn polled_messages_should_reference_receive_buffer() {
+        let buffer = polled_messages_payload();
+
+        let polled_messages = map_polled_messages(buffer.clone()).unwrap();
+
+        assert_eq!(polled_messages.partition_id, 1);
+        assert_eq!(polled_messages.current_offset, 5);
+        assert_eq!(polled_messages.next_offset, None);
+        assert_eq!(polled_messages.messages.len(), 2);
+        let buffer_range = buffer.as_ptr_range();
+        for message in &polled_messages.messages {
+            assert!(buffer_range.contains(&message.payload.as_ptr()));
+        }
+        let message = &polled_messages.messages[0];
+        assert_eq!(message.offset, 4);
+        assert_eq!(message.payload, Bytes::from("first message"));
+        assert_eq!(message.headers, Some(headers()));
+        assert_eq!(polled_messages.messages[1].headers, None);
+    }
+
+    #[test]
+    fn polled_messages_should_be_sorted_by_offset() {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(1);
+        bytes.put_u64_le(5);
+        bytes.put_u32_le(2);
+        bytes.put_u64_le(0);
+        for offset in [5, 4] {
+            PolledMessage::create(
+                offset,
+                MessageState::Available,
+                IggyTimestamp::now(),
+                offset as u128,
+                Bytes::from("message"),
+                0,
+                None,
+            )
+            .extend(&mut bytes);
+        }
+
+        let polled_messages = map_polled_messages(bytes.freeze()).unwrap();
+
+        let offsets = polled_messages
+            .messages
+            .iter()
+            .map(|message| message.offset)
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, vec![4, 5]);
+    }
+
+    #[test]
+    fn polled_messages_should_be_visited_in_place() {
+        let buffer = polled_messages_payload();
+        let mut offsets = Vec::new();
+        let mut payloads = Vec::new();
+
+        let metadata = visit_polled_messages(&buffer, |message| {
+            offsets.push(message.offset);
+            payloads.push(message.payload);
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(metadata.partition_id, 1);
+        assert_eq!(metadata.current_offset, 5);
+        assert_eq!(metadata.messages_count, 2);
+        assert_eq!(metadata.next_offset, None);
+        assert_eq!(offsets, vec![4, 5]);
+        assert_eq!(
+            payloads,
+            vec![&b"first message"[..], &b"second message"[..]]
+        );
+    }
+
+    #[test]
+    fn visiting_polled_messages_should_stop_on_visitor_error() {
+        let buffer = polled_messages_payload();
+        let mut visited = 0;
+
+        let result = visit_polled_messages(&buffer, |message| {
+            visited += 1;
+            assert_eq!(message.decode_headers().unwrap(), Some(headers()));
+            Err(IggyError::InvalidFormat)
+        });
+
+        assert!(result.is_err());
+        assert_eq!(visited, 1);
+    }
+
+    fn polled_messages_payload() -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(1);
+        bytes.put_u64_le(5);
+        bytes.put_u32_le(2);
+        bytes.put_u64_le(0);
+        PolledMessage::create(
+            4,
+            MessageState::Available,
+            IggyTimestamp::now(),
+            1,
+            Bytes::from("first message"),
+            0,
+            Some(headers()),
+        )
+        .extend(&mut bytes);
+        PolledMessage::create(
+            5,
+            MessageState::Available,
+            IggyTimestamp::now(),
+            2,
+            Bytes::from("second message"),
+            0,
+            None,
+        )
+        .extend(&mut bytes);
+        bytes.freeze()
+    }
+
+    fn headers() -> HashMap<HeaderKey, HeaderValue> {
+        HashMap::from([(
+            HeaderKey::from_str("key").unwrap(),
+            HeaderValue::from_str("value").unwrap(),
+        )])
+    }
+
+    #[test]
+    fn consumer_group_should_be_mapped_from_legacy_layout() {
+        let payload = consumer_group_payload();
+        let consumer_group = map_consumer_group(payload.freeze()).unwrap();
+        assert_eq!(consumer_group.id, 1);
+        assert_eq!(consumer_group.name, "group");
+        assert_eq!(consumer_group.members.len(), 2);
+        assert_eq!(consumer_group.members[0].partitions, vec![1, 2]);
+        assert_eq!(consumer_group.members[1].partitions, vec![3]);
+        assert_eq!(consumer_group.generation, 0);
+        assert_eq!(
+            consumer_group.assignment_strategy,
+            AssignmentStrategy::default()
+        );
+        assert!(!consumer_group.topic_deleting);
+    }
+
+    #[test]
+    fn consumer_group_should_be_mapped_with_details_following_members() {
+        let mut payload = consumer_group_payload();
+        payload.put_u32_le(7);
+        payload.put_u8(AssignmentStrategy::Range.as_code());
+        payload.put_u8(1);
+        let consumer_group = map_consumer_group(payload.freeze()).unwrap();
+        assert_eq!(consumer_group.members.len(), 2);
+        assert_eq!(consumer_group.members[1].partitions, vec![3]);
+        assert_eq!(consumer_group.generation, 7);
+        assert_eq!(
+            consumer_group.assignment_strategy,
+            AssignmentStrategy::Range
+        );
+        assert!(consumer_group.topic_deleting);
+    }
+
+    #[test]
+    fn consumer_group_rebalance_should_be_mapped() {
+        let mut payload = BytesMut::new();
+        payload.put_u32_le(1);
+        payload.put_u32_le(2);
+        payload.put_u32_le(3);
+        payload.put_u32_le(4);
+        payload.put_u32_le(2);
+        payload.put_u32_le(5);
+        payload.put_u32_le(1);
+        for name in ["stream", "topic", "group"] {
+            payload.put_u8(name.len() as u8);
+            payload.put_slice(name.as_bytes());
+        }
+
+        let rebalance = map_consumer_group_rebalance(payload.freeze()).unwrap();
+        assert_eq!(
+            rebalance,
+            ConsumerGroupRebalance {
+                stream_id: 1,
+                stream_name: "stream".to_string(),
+                topic_id: 2,
+                topic_name: "topic".to_string(),
+                group_id: 3,
+                group_name: "group".to_string(),
+                generation: 4,
+                partitions: vec![1, 5],
+            }
+        );
+    }
+
+    #[test]
+    fn truncated_consumer_group_rebalance_should_not_be_mapped() {
+        let mut payload = BytesMut::new();
+        payload.put_u32_le(1);
+        payload.put_u32_le(2);
+        assert!(map_consumer_group_rebalance(payload.freeze()).is_err());
+    }
+
+    fn consumer_group_payload() -> BytesMut {
+        let mut payload = BytesMut::new();
+        payload.put_u32_le(1);
+        payload.put_u32_le(3);
+        payload.put_u32_le(2);
+        payload.put_u8(5);
+        payload.put_slice(b"group");
+        for (member_id, partitions) in [(1u32, vec![1u32, 2]), (2, vec![3])] {
+            payload.put_u32_le(member_id);
+            payload.put_u32_le(partitions.len() as u32);
+            for partition_id in partitions {
+                payload.put_u32_le(partition_id);
+            }
+        }
+        payload
+    }
+
+    #[test]
+    fn audit_entries_should_be_mapped() {
+        let mut bytes = BytesMut::new();
+        bytes.put_u64_le(1);
+        bytes.put_u64_le(1_000);
+        bytes.put_u32_le(2);
+        bytes.put_u8(AuditOutcome::Failure.as_code());
+        for value in ["127.0.0.1:1234", "tcp", "stream.delete"] {
+            bytes.put_u8(value.len() as u8);
+            bytes.put_slice(value.as_bytes());
+        }
+        for value in ["1", "unauthorized"] {
+            bytes.put_u32_le(value.len() as u32);
+            bytes.put_slice(value.as_bytes());
+        }
+
+        let entries = map_audit_entries(bytes.freeze()).unwrap();
+
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.id, 1);
+        assert_eq!(entry.timestamp, IggyTimestamp::from(1_000));
+        assert_eq!(entry.user_id, 2);
+        assert_eq!(entry.address, "127.0.0.1:1234");
+        assert_eq!(entry.transport, "tcp");
+        assert_eq!(entry.action, "stream.delete");
+        assert_eq!(entry.details, "1");
+        assert_eq!(entry.outcome, AuditOutcome::Failure);
+        assert_eq!(entry.error.as_deref(), Some("unauthorized"));
+    }
+
+    #[test]
+    fn backup_should_be_mapped() {
+        let name = "nightly";
+        let base = "weekly";
+        let location = "s3://iggy/backups/nightly";
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(BackupDestination::S3.as_code());
+        bytes.put_u64_le(1_000);
+        bytes.put_u32_le(2);
+        bytes.put_u32_le(10);
+        bytes.put_u64_le(4_096);
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+        bytes.put_u8(base.len() as u8);
+        bytes.put_slice(base.as_bytes());
+        bytes.put_u32_le(location.len() as u32);
+        bytes.put_slice(location.as_bytes());
+
+        let backup = map_backup(bytes.freeze()).unwrap();
+
+        assert_eq!(backup.name, name);
+        assert_eq!(backup.destination, BackupDestination::S3);
+        assert_eq!(backup.base.as_deref(), Some(base));
+        assert_eq!(backup.location, location);
+        assert_eq!(backup.created_at, IggyTimestamp::from(1_000));
+        assert_eq!(backup.streams_count, 2);
+        assert_eq!(backup.files_count, 10);
+        assert_eq!(backup.size, IggyByteSize::from(4_096));
+    }
 }
@@ -20,14 +20,87 @@ use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::MessageClient;
 use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
+use crate::confirmation::ConfirmationLevel;
 use crate::consumer::Consumer;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::messages::abort_transaction::AbortTransaction;
+use crate::messages::ack_messages::AckMessages;
+use crate::messages::begin_transaction::BeginTransaction;
+use crate::messages::commit_transaction::CommitTransaction;
 use crate::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
+use crate::messages::nack_messages::NackMessages;
 use crate::messages::poll_messages::PollingStrategy;
+use crate::messages::reject_messages::RejectMessages;
 use crate::messages::send_messages::{Message, Partitioning};
-use crate::messages::{poll_messages, send_messages};
-use crate::models::messages::PolledMessages;
+use crate::messages::send_offsets_to_transaction::SendOffsetsToTransaction;
+use crate::messages::{poll_messages, send_messages, AutoCommitMode};
+use crate::models::messages::{PolledMessageView, PolledMessages, PolledMessagesMetadata};
+use crate::utils::duration::IggyDuration;
+
+/// The visitor invoked with each polled message borrowed from the network receive buffer.
+pub type PolledMessageVisitor<'v> =
+    dyn FnMut(PolledMessageView<'_>) -> Result<(), IggyError> + Send + 'v;
+
+/// This trait defines the method to poll the messages without allocating them, for the binary clients.
+#[async_trait::async_trait]
+pub trait VisitMessagesClient {
+    /// Poll given amount of messages using the specified consumer and strategy from the specified stream and topic by unique IDs or names,
+    /// and invoke the visitor with each of them borrowed from the network receive buffer, so that they can be parsed in place without any copies.
+    /// The messages are visited in the order they were sent by the server, and the first error returned by the visitor stops the visiting.
+    ///
+    /// Returns the metadata of the polled messages, i.e. the partition, its current offset and the offset to continue polling from.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn visit_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: AutoCommitMode,
+        visitor: &mut PolledMessageVisitor<'_>,
+    ) -> Result<PolledMessagesMetadata, IggyError>;
+}
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> VisitMessagesClient for B {
+    async fn visit_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: AutoCommitMode,
+        visitor: &mut PolledMessageVisitor<'_>,
+    ) -> Result<PolledMessagesMetadata, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_raw_with_response(
+                POLL_MESSAGES_CODE,
+                poll_messages::as_bytes(
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                    consumer,
+                    strategy,
+                    count,
+                    auto_commit,
+                ),
+            )
+            .await?;
+        if self.is_zero_copy_polling_enabled() {
+            return mapper::visit_zero_copy_polled_messages(&response, &mut *visitor);
+        }
+
+        mapper::visit_polled_messages(&response, &mut *visitor)
+    }
+}
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> MessageClient for B {
@@ -40,6 +113,28 @@ impl<B: BinaryClient> MessageClient for B {
         strategy: &PollingStrategy,
         count: u32,
         auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        self.poll_messages_with_commit_mode(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            AutoCommitMode::from(auto_commit),
+        )
+        .await
+    }
+
+    async fn poll_messages_with_commit_mode(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: AutoCommitMode,
     ) -> Result<PolledMessages, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -56,6 +151,10 @@ impl<B: BinaryClient> MessageClient for B {
                 ),
             )
             .await?;
+        if self.is_zero_copy_polling_enabled() {
+            return mapper::map_zero_copy_polled_messages(response);
+        }
+
         mapper::map_polled_messages(response)
     }
 
@@ -65,11 +164,29 @@ impl<B: BinaryClient> MessageClient for B {
         topic_id: &Identifier,
         partitioning: &Partitioning,
         messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        self.send_messages_with_confirmation(
+            stream_id,
+            topic_id,
+            partitioning,
+            messages,
+            ConfirmationLevel::default(),
+        )
+        .await
+    }
+
+    async fn send_messages_with_confirmation(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+        confirmation: ConfirmationLevel,
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_raw_with_response(
             SEND_MESSAGES_CODE,
-            send_messages::as_bytes(stream_id, topic_id, partitioning, messages),
+            send_messages::as_bytes(stream_id, topic_id, partitioning, messages, confirmation),
         )
         .await?;
         Ok(())
@@ -92,4 +209,108 @@ impl<B: BinaryClient> MessageClient for B {
         .await?;
         Ok(())
     }
+
+    async fn reject_messages(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&RejectMessages {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            offsets: offsets.to_vec(),
+            reason: reason.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn nack_messages(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        redelivery_delay: Option<IggyDuration>,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&NackMessages {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            offsets: offsets.to_vec(),
+            redelivery_delay,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn ack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&AckMessages {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            offsets: offsets.to_vec(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn begin_transaction(&self) -> Result<u64, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&BeginTransaction {}).await?;
+        mapper::map_transaction_id(response)
+    }
+
+    async fn commit_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&CommitTransaction { transaction_id })
+            .await?;
+        Ok(())
+    }
+
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&AbortTransaction { transaction_id })
+            .await?;
+        Ok(())
+    }
+
+    async fn send_offsets_to_transaction(
+        &self,
+        transaction_id: u64,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&SendOffsetsToTransaction {
+            transaction_id,
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            offset,
+        })
+        .await?;
+        Ok(())
+    }
 }
@@ -27,10 +27,12 @@ use derive_more::Display;
 #[allow(deprecated)]
 pub mod binary_client;
 #[allow(deprecated)]
+pub mod bookmarks;
+#[allow(deprecated)]
 pub mod consumer_groups;
 #[allow(deprecated)]
 pub mod consumer_offsets;
-mod mapper;
+pub(crate) mod mapper;
 #[allow(deprecated)]
 pub mod messages;
 #[allow(deprecated)]
@@ -38,6 +40,10 @@ pub mod partitions;
 #[allow(deprecated)]
 pub mod personal_access_tokens;
 #[allow(deprecated)]
+pub mod quotas;
+#[allow(deprecated)]
+pub mod schemas;
+#[allow(deprecated)]
 pub mod segments;
 #[allow(deprecated)]
 pub mod streams;
@@ -82,6 +88,10 @@ pub trait BinaryTransport {
     async fn send_with_response<T: Command>(&self, command: &T) -> Result<Bytes, IggyError>;
     async fn send_raw_with_response(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError>;
     fn get_heartbeat_interval(&self) -> IggyDuration;
+    /// Checks whether the zero-copy polling was negotiated with the server, changing the framing of the poll responses.
+    fn is_zero_copy_polling_enabled(&self) -> bool {
+        false
+    }
 }
 
 async fn fail_if_not_authenticated<T: BinaryTransport>(transport: &T) -> Result<(), IggyError> {
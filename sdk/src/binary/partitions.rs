@@ -18,26 +18,54 @@
 
 #[allow(deprecated)]
 use crate::binary::binary_client::BinaryClient;
-use crate::binary::fail_if_not_authenticated;
+use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::PartitionClient;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::partition::PartitionDetails;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_partition::GetPartition;
+use crate::partitions::key_routing_policy::KeyRoutingPolicy;
+use crate::partitions::move_partition::MovePartition;
+use crate::partitions::update_partition::UpdatePartition;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> PartitionClient for B {
+    async fn get_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+    ) -> Result<Option<PartitionDetails>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetPartition {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id: partition_id.clone(),
+            })
+            .await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_partition(response).map(Some)
+    }
+
     async fn create_partitions(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitions_count: u32,
+        key_routing_policy: KeyRoutingPolicy,
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(&CreatePartitions {
             stream_id: stream_id.clone(),
             topic_id: topic_id.clone(),
             partitions_count,
+            key_routing_policy,
         })
         .await?;
         Ok(())
@@ -58,4 +86,40 @@ impl<B: BinaryClient> PartitionClient for B {
         .await?;
         Ok(())
     }
+
+    async fn update_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+        name: Option<&str>,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdatePartition {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id: partition_id.clone(),
+            name: name.map(|name| name.to_string()),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn move_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+        directory: &str,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&MovePartition {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id: partition_id.clone(),
+            directory: directory.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
 }
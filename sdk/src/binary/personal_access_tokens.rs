@@ -19,9 +19,12 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper, ClientState};
 use crate::client::PersonalAccessTokenClient;
+use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
 use crate::models::identity_info::IdentityInfo;
-use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::personal_access_token::{
+    PersonalAccessTokenInfo, PersonalAccessTokenScope, RawPersonalAccessToken,
+};
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
@@ -40,12 +43,25 @@ impl<B: BinaryClient> PersonalAccessTokenClient for B {
         &self,
         name: &str,
         expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        self.create_personal_access_token_with_restrictions(name, expiry, None, None)
+            .await
+    }
+
+    async fn create_personal_access_token_with_restrictions(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+        scope: Option<PersonalAccessTokenScope>,
+        allowed_addresses: Option<Vec<String>>,
     ) -> Result<RawPersonalAccessToken, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
             .send_with_response(&CreatePersonalAccessToken {
                 name: name.to_string(),
                 expiry,
+                scope,
+                allowed_addresses,
             })
             .await?;
         mapper::map_raw_pat(response)
@@ -70,6 +86,7 @@ impl<B: BinaryClient> PersonalAccessTokenClient for B {
             })
             .await?;
         self.set_state(ClientState::Authenticated).await;
+        self.publish_event(DiagnosticEvent::SignedIn).await;
         mapper::map_identity_info(response)
     }
 }
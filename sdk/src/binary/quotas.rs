@@ -0,0 +1,67 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::client::QuotaClient;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::quota::{Quota, QuotaLimits};
+use crate::quotas::get_quotas::GetQuotas;
+use crate::quotas::set_quota::SetQuota;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> QuotaClient for B {
+    async fn get_quotas(&self) -> Result<Vec<Quota>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetQuotas {}).await?;
+        mapper::map_quotas(response)
+    }
+
+    async fn set_user_quota(
+        &self,
+        user_id: &Identifier,
+        limits: Option<QuotaLimits>,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&SetQuota {
+            user_id: Some(user_id.clone()),
+            limits,
+            ..SetQuota::default()
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn set_topic_quota(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        limits: Option<QuotaLimits>,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&SetQuota {
+            stream_id: Some(stream_id.clone()),
+            topic_id: Some(topic_id.clone()),
+            limits,
+            ..SetQuota::default()
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::client::SchemaClient;
+use crate::error::IggyError;
+use crate::models::schema::Schema;
+use crate::models::topic_schema::TopicSchema;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::get_subject_schema::GetSubjectSchema;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::schema_compatibility::SchemaCompatibility;
+use crate::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> SchemaClient for B {
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetSchema { schema_id }).await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_schema(response).map(Some)
+    }
+
+    async fn get_schemas(&self, subject: &str) -> Result<Vec<Schema>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetSchemas {
+                subject: subject.to_string(),
+            })
+            .await?;
+        mapper::map_schemas(response)
+    }
+
+    async fn get_subject_schema(
+        &self,
+        subject: &str,
+        version: Option<u32>,
+    ) -> Result<Option<Schema>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetSubjectSchema {
+                subject: subject.to_string(),
+                version,
+            })
+            .await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_schema(response).map(Some)
+    }
+
+    async fn register_schema(
+        &self,
+        subject: &str,
+        schema: TopicSchema,
+    ) -> Result<Schema, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&RegisterSchema {
+                subject: subject.to_string(),
+                schema,
+            })
+            .await?;
+        mapper::map_schema(response)
+    }
+
+    async fn update_schema_compatibility(
+        &self,
+        subject: &str,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateSchemaCompatibility {
+            subject: subject.to_string(),
+            compatibility,
+        })
+        .await?;
+        Ok(())
+    }
+}
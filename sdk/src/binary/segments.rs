@@ -17,11 +17,13 @@
  */
 #[allow(deprecated)]
 use crate::binary::binary_client::BinaryClient;
-use crate::binary::fail_if_not_authenticated;
+use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::SegmentClient;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::segments_verification::SegmentsVerification;
 use crate::segments::delete_segments::DeleteSegments;
+use crate::segments::verify_segments::VerifySegments;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> SegmentClient for B {
@@ -42,4 +44,21 @@ impl<B: BinaryClient> SegmentClient for B {
         .await?;
         Ok(())
     }
+
+    async fn verify_segments(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<SegmentsVerification, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&VerifySegments {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+            })
+            .await?;
+        mapper::map_segments_verification(response)
+    }
 }
@@ -20,16 +20,27 @@ use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::SystemClient;
 use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::audit_entry::AuditEntry;
+use crate::models::backup::{BackupDestination, BackupInfo};
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::cluster::ClusterMetadata;
+use crate::models::config_value::ConfigValue;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
+use crate::models::user_info::UserId;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use crate::system::create_backup::CreateBackup;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
+use crate::system::get_cluster_metadata::GetClusterMetadata;
+use crate::system::get_config::GetConfig;
 use crate::system::get_me::GetMe;
 use crate::system::get_snapshot::GetSnapshot;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::restore_backup::RestoreBackup;
 use crate::utils::duration::IggyDuration;
 
 #[async_trait::async_trait]
@@ -61,6 +72,35 @@ impl<B: BinaryClient> SystemClient for B {
         mapper::map_clients(response)
     }
 
+    async fn get_config(&self) -> Result<Vec<ConfigValue>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetConfig {}).await?;
+        mapper::map_config_values(response)
+    }
+
+    async fn get_audit_log(
+        &self,
+        user_id: Option<UserId>,
+        action: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<AuditEntry>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetAuditLog {
+                user_id,
+                action: action.map(|action| action.to_string()),
+                limit,
+            })
+            .await?;
+        mapper::map_audit_entries(response)
+    }
+
+    async fn get_cluster_metadata(&self) -> Result<ClusterMetadata, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetClusterMetadata {}).await?;
+        mapper::map_cluster_metadata(response)
+    }
+
     async fn ping(&self) -> Result<(), IggyError> {
         self.send_with_response(&Ping {}).await?;
         Ok(())
@@ -85,4 +125,38 @@ impl<B: BinaryClient> SystemClient for B {
         let snapshot = Snapshot::new(response.to_vec());
         Ok(snapshot)
     }
+
+    async fn create_backup(
+        &self,
+        name: &str,
+        streams: &[Identifier],
+        destination: BackupDestination,
+        base: Option<&str>,
+    ) -> Result<BackupInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&CreateBackup {
+                name: name.to_string(),
+                streams: streams.to_vec(),
+                destination,
+                base: base.map(|base| base.to_string()),
+            })
+            .await?;
+        mapper::map_backup(response)
+    }
+
+    async fn restore_backup(
+        &self,
+        name: &str,
+        streams: &[Identifier],
+    ) -> Result<BackupInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&RestoreBackup {
+                name: name.to_string(),
+                streams: streams.to_vec(),
+            })
+            .await?;
+        mapper::map_backup(response)
+    }
 }
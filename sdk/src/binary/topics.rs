@@ -23,12 +23,16 @@ use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::topic_options::TopicOptions;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::set_topic_throttle::SetTopicThrottle;
 use crate::topics::update_topic::UpdateTopic;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::topic_size::MaxTopicSize;
 
@@ -73,6 +77,32 @@ impl<B: BinaryClient> TopicClient for B {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+    ) -> Result<TopicDetails, IggyError> {
+        self.create_topic_with_options(
+            stream_id,
+            name,
+            partitions_count,
+            compression_algorithm,
+            replication_factor,
+            topic_id,
+            message_expiry,
+            max_topic_size,
+            TopicOptions::default(),
+        )
+        .await
+    }
+
+    async fn create_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: TopicOptions,
     ) -> Result<TopicDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -85,6 +115,7 @@ impl<B: BinaryClient> TopicClient for B {
                 topic_id,
                 message_expiry,
                 max_topic_size,
+                options,
             })
             .await?;
         mapper::map_topic(response)
@@ -109,6 +140,33 @@ impl<B: BinaryClient> TopicClient for B {
             replication_factor,
             message_expiry,
             max_topic_size,
+            options: None,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn update_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: TopicOptions,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateTopic {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            name: name.to_string(),
+            compression_algorithm,
+            replication_factor,
+            message_expiry,
+            max_topic_size,
+            options: Some(options),
         })
         .await?;
         Ok(())
@@ -123,6 +181,22 @@ impl<B: BinaryClient> TopicClient for B {
         self.send_with_response(&DeleteTopic {
             stream_id: stream_id.clone(),
             topic_id: topic_id.clone(),
+            deferred: false,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_topic_deferred(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DeleteTopic {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            deferred: true,
         })
         .await?;
         Ok(())
@@ -141,4 +215,22 @@ impl<B: BinaryClient> TopicClient for B {
         .await?;
         Ok(())
     }
+
+    async fn set_topic_throttle(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        max_throughput: Option<IggyByteSize>,
+        duration: Option<IggyDuration>,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&SetTopicThrottle {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            max_throughput,
+            duration,
+        })
+        .await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,223 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bookmarks::is_valid_name;
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, STORE_BOOKMARK_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `StoreBookmark` command creates the bookmark in the topic, or moves the existing one to another position.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `name` - unique bookmark name within the topic, max length is 255 characters.
+/// - `partition_id` - partition ID on which the bookmark is stored.
+/// - `offset` - offset of the next message to be polled from the bookmark, up to the offset following the last message.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct StoreBookmark {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique bookmark name within the topic, max length is 255 characters.
+    pub name: String,
+    /// Partition ID on which the bookmark is stored.
+    pub partition_id: u32,
+    /// Offset of the next message to be polled from the bookmark.
+    pub offset: u64,
+}
+
+impl Default for StoreBookmark {
+    fn default() -> Self {
+        StoreBookmark {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            name: "bookmark".to_string(),
+            partition_id: 1,
+            offset: 0,
+        }
+    }
+}
+
+impl Command for StoreBookmark {
+    fn code(&self) -> u32 {
+        STORE_BOOKMARK_CODE
+    }
+}
+
+impl Validatable<IggyError> for StoreBookmark {
+    fn validate(&self) -> Result<(), IggyError> {
+        if !is_valid_name(&self.name) {
+            return Err(IggyError::InvalidBookmarkName);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for StoreBookmark {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            13 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u64_le(self.offset);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<StoreBookmark, IggyError> {
+        if bytes.len() < 20 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let name_length = bytes[position] as usize;
+        if bytes.len() != position + 13 + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[position + 1..position + 1 + name_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        position += 1 + name_length;
+        let partition_id = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let offset = u64::from_le_bytes(
+            bytes[position + 4..position + 12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let command = StoreBookmark {
+            stream_id,
+            topic_id,
+            name,
+            partition_id,
+            offset,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for StoreBookmark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.name, self.partition_id, self.offset
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = StoreBookmark {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            name: "audit".to_string(),
+            partition_id: 3,
+            offset: 4,
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let name_length = bytes[position] as usize;
+        let name = from_utf8(&bytes[position + 1..position + 1 + name_length]).unwrap();
+        position += 1 + name_length;
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(name, command.name);
+        assert_eq!(partition_id, command.partition_id);
+        assert_eq!(offset, command.offset);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let name = "audit";
+        let partition_id = 3u32;
+        let offset = 4u64;
+
+        let stream_id_bytes = stream_id.to_bytes();
+        let topic_id_bytes = topic_id.to_bytes();
+        let mut bytes =
+            BytesMut::with_capacity(13 + stream_id_bytes.len() + topic_id_bytes.len() + name.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+        bytes.put_u32_le(partition_id);
+        bytes.put_u64_le(offset);
+
+        let command = StoreBookmark::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.name, name);
+        assert_eq!(command.partition_id, partition_id);
+        assert_eq!(command.offset, offset);
+    }
+
+    #[test]
+    fn empty_name_should_be_invalid() {
+        let command = StoreBookmark {
+            name: String::new(),
+            ..StoreBookmark::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
@@ -0,0 +1,114 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bookmarks::get_bookmarks::GetBookmarks;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use std::fmt::{self, Display, Formatter};
+use tracing::{event, Level};
+
+pub enum GetBookmarksOutput {
+    Table,
+    List,
+}
+
+impl Display for GetBookmarksOutput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GetBookmarksOutput::Table => write!(f, "table"),
+            GetBookmarksOutput::List => write!(f, "list"),
+        }?;
+
+        Ok(())
+    }
+}
+
+pub struct GetBookmarksCmd {
+    get_bookmarks: GetBookmarks,
+    output: GetBookmarksOutput,
+}
+
+impl GetBookmarksCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, output: GetBookmarksOutput) -> Self {
+        Self {
+            get_bookmarks: GetBookmarks {
+                stream_id,
+                topic_id,
+            },
+            output,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetBookmarksCmd {
+    fn explain(&self) -> String {
+        format!(
+            "list bookmarks for stream with ID: {} and topic with ID: {} in {} mode",
+            self.get_bookmarks.stream_id, self.get_bookmarks.topic_id, self.output
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let bookmarks = client
+            .get_bookmarks(&self.get_bookmarks.stream_id, &self.get_bookmarks.topic_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting bookmarks for stream with ID: {} and topic with ID: {}",
+                    self.get_bookmarks.stream_id, self.get_bookmarks.topic_id
+                )
+            })?;
+
+        match self.output {
+            GetBookmarksOutput::Table => {
+                let mut table = Table::new();
+                table.set_header(vec!["Name", "Partition ID", "Offset", "Created", "Updated"]);
+                bookmarks.iter().for_each(|bookmark| {
+                    table.add_row(vec![
+                        bookmark.name.clone(),
+                        format!("{}", bookmark.partition_id),
+                        format!("{}", bookmark.offset),
+                        bookmark.created_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+                        bookmark.updated_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetBookmarksOutput::List => {
+                bookmarks.iter().for_each(|bookmark| {
+                    event!(target: PRINT_TARGET, Level::INFO,
+                        "{}|{}|{}|{}|{}",
+                        bookmark.name,
+                        bookmark.partition_id,
+                        bookmark.offset,
+                        bookmark.created_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+                        bookmark.updated_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
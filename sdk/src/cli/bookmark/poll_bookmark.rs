@@ -0,0 +1,147 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::PollingStrategy;
+use crate::utils::timestamp::IggyTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+/// Polls the messages starting at the bookmark and, unless it's a peek, moves the bookmark past the last polled message.
+/// The consumer offsets are left untouched, so the bookmark works as an independent cursor over the topic.
+pub struct PollBookmarkCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    name: String,
+    message_count: u32,
+    peek: bool,
+}
+
+impl PollBookmarkCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        name: String,
+        message_count: u32,
+        peek: bool,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            name,
+            message_count,
+            peek,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for PollBookmarkCmd {
+    fn explain(&self) -> String {
+        format!(
+            "poll messages from bookmark: {} for topic with ID: {} and stream with ID: {}",
+            self.name, self.topic_id, self.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let bookmark = client
+            .get_bookmark(&self.stream_id, &self.topic_id, &self.name)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting bookmark: {} for topic with ID: {} and stream with ID: {}",
+                    self.name, self.topic_id, self.stream_id
+                )
+            })?;
+        let Some(bookmark) = bookmark else {
+            event!(target: PRINT_TARGET, Level::INFO, "Bookmark: {} for topic with ID: {} and stream with ID: {} was not found", self.name, self.topic_id, self.stream_id);
+            return Ok(());
+        };
+
+        let messages = client
+            .poll_messages(
+                &self.stream_id,
+                &self.topic_id,
+                Some(bookmark.partition_id),
+                &Consumer::default(),
+                &PollingStrategy::offset(bookmark.offset),
+                self.message_count,
+                false,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem polling messages from bookmark: {} for topic with ID: {} and stream with ID: {}",
+                    self.name, self.topic_id, self.stream_id
+                )
+            })?;
+
+        let mut table = Table::new();
+        table.set_header(vec!["Offset", "Timestamp", "ID", "Length", "Payload"]);
+        for message in messages.messages.iter() {
+            table.add_row(vec![
+                format!("{}", message.offset),
+                IggyTimestamp::from(message.timestamp).to_local_string("%Y-%m-%d %H:%M:%S%.6f"),
+                format!("{}", message.id),
+                format!("{}", message.payload.len()),
+                String::from_utf8_lossy(&message.payload).to_string(),
+            ]);
+        }
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        let Some(last_message) = messages.messages.last() else {
+            return Ok(());
+        };
+
+        if self.peek {
+            return Ok(());
+        }
+
+        let next_offset = last_message.offset + 1;
+        client
+            .store_bookmark(
+                &self.stream_id,
+                &self.topic_id,
+                &self.name,
+                bookmark.partition_id,
+                next_offset,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem moving bookmark: {} for topic with ID: {} and stream with ID: {} to offset: {next_offset}",
+                    self.name, self.topic_id, self.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Bookmark: {} for topic with ID: {} and stream with ID: {} moved to offset: {next_offset}",
+            self.name,
+            self.topic_id,
+            self.stream_id,
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bookmarks::store_bookmark::StoreBookmark;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct SetBookmarkCmd {
+    set_bookmark: StoreBookmark,
+}
+
+impl SetBookmarkCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        name: String,
+        partition_id: u32,
+        offset: u64,
+    ) -> Self {
+        Self {
+            set_bookmark: StoreBookmark {
+                stream_id,
+                topic_id,
+                name,
+                partition_id,
+                offset,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for SetBookmarkCmd {
+    fn explain(&self) -> String {
+        format!(
+            "set bookmark: {} for stream with ID: {} and topic with ID: {} to partition with ID: {} and offset: {}",
+            self.set_bookmark.name,
+            self.set_bookmark.stream_id,
+            self.set_bookmark.topic_id,
+            self.set_bookmark.partition_id,
+            self.set_bookmark.offset,
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .store_bookmark(&self.set_bookmark.stream_id, &self.set_bookmark.topic_id, &self.set_bookmark.name, self.set_bookmark.partition_id, self.set_bookmark.offset)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem setting bookmark: {} for stream with ID: {} and topic with ID: {} and partition with ID: {}",
+                    self.set_bookmark.name, self.set_bookmark.stream_id, self.set_bookmark.topic_id, self.set_bookmark.partition_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Bookmark: {} for stream with ID: {} and topic with ID: {} set to partition with ID: {} and offset: {}",
+            self.set_bookmark.name,
+            self.set_bookmark.stream_id,
+            self.set_bookmark.topic_id,
+            self.set_bookmark.partition_id,
+            self.set_bookmark.offset,
+        );
+
+        Ok(())
+    }
+}
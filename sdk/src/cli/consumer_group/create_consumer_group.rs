@@ -41,6 +41,7 @@ impl CreateConsumerGroupCmd {
                 topic_id,
                 name,
                 group_id,
+                assignment_strategy: Default::default(),
             },
         }
     }
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::cli::utils::topology_cache::{fetch_cached, TopologyCache};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
@@ -27,6 +28,7 @@ use tracing::{event, Level};
 
 pub struct GetConsumerGroupCmd {
     get_consumer_group: GetConsumerGroup,
+    cache: Option<TopologyCache>,
 }
 
 impl GetConsumerGroupCmd {
@@ -36,9 +38,16 @@ impl GetConsumerGroupCmd {
                 stream_id,
                 topic_id,
                 group_id: consumer_group_id,
+                ..Default::default()
             },
+            cache: None,
         }
     }
+
+    pub fn with_cache(mut self, cache: Option<TopologyCache>) -> Self {
+        self.cache = cache;
+        self
+    }
 }
 
 #[async_trait]
@@ -53,15 +62,24 @@ impl CliCommand for GetConsumerGroupCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        let consumer_group = client
-            .get_consumer_group(&self.get_consumer_group.stream_id, &self.get_consumer_group.topic_id, &self.get_consumer_group.group_id)
-            .await
-            .with_context(|| {
-                format!(
-                    "Problem getting consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
-                    self.get_consumer_group.group_id, self.get_consumer_group.topic_id, self.get_consumer_group.stream_id
-                )
-            })?;
+        let key = format!(
+            "consumer_group/{}/{}/{}",
+            self.get_consumer_group.stream_id,
+            self.get_consumer_group.topic_id,
+            self.get_consumer_group.group_id
+        );
+        let consumer_group = fetch_cached(self.cache.as_ref(), &key, async {
+            client
+                .get_consumer_group(&self.get_consumer_group.stream_id, &self.get_consumer_group.topic_id, &self.get_consumer_group.group_id)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem getting consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
+                        self.get_consumer_group.group_id, self.get_consumer_group.topic_id, self.get_consumer_group.stream_id
+                    )
+                })
+        })
+        .await?;
 
         if consumer_group.is_none() {
             event!(target: PRINT_TARGET, Level::INFO, "Consumer group with ID: {} was not found", self.get_consumer_group.group_id);
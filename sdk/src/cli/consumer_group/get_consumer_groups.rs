@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::cli::utils::topology_cache::{fetch_cached, TopologyCache};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
@@ -45,6 +46,7 @@ impl Display for GetConsumerGroupsOutput {
 pub struct GetConsumerGroupsCmd {
     get_consumer_groups: GetConsumerGroups,
     output: GetConsumerGroupsOutput,
+    cache: Option<TopologyCache>,
 }
 
 impl GetConsumerGroupsCmd {
@@ -59,8 +61,14 @@ impl GetConsumerGroupsCmd {
                 topic_id,
             },
             output,
+            cache: None,
         }
     }
+
+    pub fn with_cache(mut self, cache: Option<TopologyCache>) -> Self {
+        self.cache = cache;
+        self
+    }
 }
 
 #[async_trait]
@@ -73,18 +81,25 @@ impl CliCommand for GetConsumerGroupsCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        let consumer_groups = client
-            .get_consumer_groups(
-                &self.get_consumer_groups.stream_id,
-                &self.get_consumer_groups.topic_id,
-            )
-            .await
-            .with_context(|| {
-                format!(
-                    "Problem getting consumer groups for stream with ID: {} and topic with ID: {}",
-                    self.get_consumer_groups.stream_id, self.get_consumer_groups.topic_id
+        let key = format!(
+            "consumer_groups/{}/{}",
+            self.get_consumer_groups.stream_id, self.get_consumer_groups.topic_id
+        );
+        let consumer_groups = fetch_cached(self.cache.as_ref(), &key, async {
+            client
+                .get_consumer_groups(
+                    &self.get_consumer_groups.stream_id,
+                    &self.get_consumer_groups.topic_id,
                 )
-            })?;
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem getting consumer groups for stream with ID: {} and topic with ID: {}",
+                        self.get_consumer_groups.stream_id, self.get_consumer_groups.topic_id
+                    )
+                })
+        })
+        .await?;
 
         match self.output {
             GetConsumerGroupsOutput::Table => {
@@ -0,0 +1,341 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::clients::client::IggyClient;
+use crate::confirmation::ConfirmationLevel;
+use crate::consumer::Consumer;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::PollingStrategy;
+use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::topic::TopicDetails;
+use crate::models::topic_options::TopicOptions;
+use crate::partitions::key_routing_policy::KeyRoutingPolicy;
+use crate::utils::duration::IggyDuration;
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use tokio::time::sleep;
+use tracing::{event, Level};
+
+/// Replicates the messages of the selected topics from the connected (source) server to the target server.
+///
+/// Messages keep their IDs, headers and partitions, so the keys stay routed to the same partitions.
+/// Progress is checkpointed by storing the offsets of the mirroring consumer on the source server
+/// once the messages are confirmed by the target server, so the interrupted mirroring resumes
+/// where it stopped.
+pub struct MirrorMessagesCmd {
+    stream_id: Identifier,
+    topic_ids: Vec<Identifier>,
+    target: String,
+    target_stream: Option<String>,
+    consumer: Consumer,
+    messages_count: u32,
+    confirmation: ConfirmationLevel,
+    follow: Option<IggyDuration>,
+}
+
+impl MirrorMessagesCmd {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        stream_id: Identifier,
+        topic_ids: Vec<Identifier>,
+        target: String,
+        target_stream: Option<String>,
+        consumer: Identifier,
+        messages_count: u32,
+        confirmation: ConfirmationLevel,
+        follow: Option<IggyDuration>,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_ids,
+            target,
+            target_stream,
+            consumer: Consumer::new(consumer),
+            messages_count,
+            confirmation,
+            follow,
+        }
+    }
+
+    fn describe_topics(&self) -> String {
+        match self.topic_ids.is_empty() {
+            true => "all topics".to_string(),
+            false => format!(
+                "topics with IDs: {}",
+                self.topic_ids
+                    .iter()
+                    .map(|topic_id| topic_id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    async fn prepare_target_topic(
+        &self,
+        target: &dyn Client,
+        target_stream_id: &Identifier,
+        topic: &TopicDetails,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        let target_topic_id = Identifier::named(&topic.name)?;
+        let target_topic = target
+            .get_topic(target_stream_id, &target_topic_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic with ID: {target_topic_id} and stream with ID: {target_stream_id} from target server"
+                )
+            })?;
+
+        match target_topic {
+            None => {
+                target
+                    .create_topic_with_options(
+                        target_stream_id,
+                        &topic.name,
+                        topic.partitions_count,
+                        topic.compression_algorithm,
+                        Some(topic.replication_factor),
+                        None,
+                        topic.message_expiry,
+                        topic.max_topic_size,
+                        TopicOptions {
+                            // Dead letter topic might not exist on the target server (yet).
+                            dead_letter_policy: None,
+                            ..topic.options.clone()
+                        },
+                    )
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Problem creating topic with name: {} and stream with ID: {target_stream_id} on target server",
+                            topic.name
+                        )
+                    })?;
+                event!(target: PRINT_TARGET, Level::INFO, "Created topic with name: {} in stream with ID: {target_stream_id} on target server", topic.name);
+            }
+            Some(target_topic) if target_topic.partitions_count < topic.partitions_count => {
+                target
+                    .create_partitions(
+                        target_stream_id,
+                        &target_topic_id,
+                        topic.partitions_count - target_topic.partitions_count,
+                        KeyRoutingPolicy::PreserveExisting,
+                    )
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Problem creating partitions for topic with ID: {target_topic_id} and stream with ID: {target_stream_id} on target server"
+                        )
+                    })?;
+                event!(target: PRINT_TARGET, Level::INFO, "Created {} partition(s) for topic with ID: {target_topic_id} in stream with ID: {target_stream_id} on target server",
+                    topic.partitions_count - target_topic.partitions_count);
+            }
+            Some(_) => {}
+        }
+
+        Ok(())
+    }
+
+    async fn mirror_partition(
+        &self,
+        source: &dyn Client,
+        target: &dyn Client,
+        target_stream_id: &Identifier,
+        topic: &TopicDetails,
+        partition_id: u32,
+    ) -> anyhow::Result<u64, anyhow::Error> {
+        let topic_id = Identifier::numeric(topic.id)?;
+        let target_topic_id = Identifier::named(&topic.name)?;
+        let partitioning = Partitioning::partition_id(partition_id);
+        let mut mirrored_count = 0;
+
+        loop {
+            let polled_messages = source
+                .poll_messages(
+                    &self.stream_id,
+                    &topic_id,
+                    Some(partition_id),
+                    &self.consumer,
+                    &PollingStrategy::next(),
+                    self.messages_count,
+                    false,
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem polling messages from topic with ID: {topic_id} and stream with ID: {} (partition with ID: {partition_id})",
+                        self.stream_id
+                    )
+                })?;
+
+            let Some(last_offset) = polled_messages.messages.last().map(|m| m.offset) else {
+                return Ok(mirrored_count);
+            };
+
+            let mut messages = polled_messages
+                .messages
+                .into_iter()
+                .map(|m| Message::new(Some(m.id), m.payload, m.headers))
+                .collect::<Vec<_>>();
+
+            target
+                .send_messages_with_confirmation(
+                    target_stream_id,
+                    &target_topic_id,
+                    &partitioning,
+                    &mut messages,
+                    self.confirmation,
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem sending messages to topic with ID: {target_topic_id} and stream with ID: {target_stream_id} (partition with ID: {partition_id}) on target server"
+                    )
+                })?;
+
+            // Checkpoint is stored only once the target server has confirmed the messages.
+            source
+                .store_consumer_offset(
+                    &self.consumer,
+                    &self.stream_id,
+                    &topic_id,
+                    Some(partition_id),
+                    last_offset,
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem storing offset: {last_offset} for topic with ID: {topic_id} and stream with ID: {} (partition with ID: {partition_id})",
+                        self.stream_id
+                    )
+                })?;
+
+            mirrored_count += messages.len() as u64;
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for MirrorMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "mirror messages from {} of stream with ID: {} to target server",
+            self.describe_topics(),
+            self.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let target = IggyClient::from_connection_string(&self.target)
+            .with_context(|| "Problem parsing target server connection string".to_owned())?;
+        let target: &dyn Client = &target;
+        target
+            .connect()
+            .await
+            .with_context(|| "Problem connecting to target server".to_owned())?;
+
+        let stream = client
+            .get_stream(&self.stream_id)
+            .await
+            .with_context(|| format!("Problem getting stream with ID: {}", self.stream_id))?
+            .ok_or_else(|| anyhow!("Stream with ID: {} was not found", self.stream_id))?;
+
+        let target_stream_name = self.target_stream.clone().unwrap_or(stream.name);
+        let target_stream_id = Identifier::named(&target_stream_name)?;
+        let target_stream = target
+            .get_stream(&target_stream_id)
+            .await
+            .with_context(|| {
+                format!("Problem getting stream with ID: {target_stream_id} from target server")
+            })?;
+        if target_stream.is_none() {
+            target
+                .create_stream(&target_stream_name, None)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem creating stream with name: {target_stream_name} on target server"
+                    )
+                })?;
+            event!(target: PRINT_TARGET, Level::INFO, "Created stream with name: {target_stream_name} on target server");
+        }
+
+        let topic_ids = match self.topic_ids.is_empty() {
+            true => stream
+                .topics
+                .iter()
+                .map(|topic| Identifier::numeric(topic.id))
+                .collect::<Result<Vec<_>, _>>()?,
+            false => self.topic_ids.clone(),
+        };
+
+        let mut topics = Vec::with_capacity(topic_ids.len());
+        for topic_id in topic_ids {
+            let topic = client
+                .get_topic(&self.stream_id, &topic_id)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem getting topic with ID: {topic_id} and stream with ID: {}",
+                        self.stream_id
+                    )
+                })?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Topic with ID: {topic_id} and stream with ID: {} was not found",
+                        self.stream_id
+                    )
+                })?;
+            self.prepare_target_topic(target, &target_stream_id, &topic)
+                .await?;
+            topics.push(topic);
+        }
+
+        loop {
+            let mut mirrored_count = 0;
+            for topic in &topics {
+                for partition in &topic.partitions {
+                    mirrored_count += self
+                        .mirror_partition(client, target, &target_stream_id, topic, partition.id)
+                        .await?;
+                }
+            }
+
+            let Some(interval) = self.follow else {
+                event!(target: PRINT_TARGET, Level::INFO, "Mirrored {mirrored_count} message(s) from {} of stream with ID: {} to stream with name: {target_stream_name} on target server",
+                    self.describe_topics(), self.stream_id);
+                break;
+            };
+
+            if mirrored_count > 0 {
+                event!(target: PRINT_TARGET, Level::INFO, "Mirrored {mirrored_count} message(s) to stream with name: {target_stream_name} on target server");
+            } else {
+                sleep(interval.get_duration()).await;
+            }
+        }
+
+        target
+            .shutdown()
+            .await
+            .with_context(|| "Problem disconnecting from target server".to_owned())?;
+
+        Ok(())
+    }
+}
@@ -17,5 +17,7 @@
  */
 
 pub mod flush_messages;
+#[cfg(feature = "tcp")]
+pub mod mirror_messages;
 pub mod poll_messages;
 pub mod send_messages;
@@ -23,6 +23,7 @@ use crate::consumer::Consumer;
 use crate::identifier::Identifier;
 use crate::messages::poll_messages::{PollMessages, PollingStrategy};
 use crate::messages::send_messages::Message;
+use crate::messages::AutoCommitMode;
 use crate::models::header::{HeaderKey, HeaderKind};
 use crate::models::messages::PolledMessages;
 use crate::utils::sizeable::Sizeable;
@@ -49,7 +50,7 @@ impl PollMessagesCmd {
         topic_id: Identifier,
         partition_id: u32,
         message_count: u32,
-        auto_commit: bool,
+        auto_commit: AutoCommitMode,
         offset: Option<u64>,
         first: bool,
         last: bool,
@@ -169,7 +170,7 @@ impl CliCommand for PollMessagesCmd {
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         let start = std::time::Instant::now();
         let messages = client
-            .poll_messages(
+            .poll_messages_with_commit_mode(
                 &self.poll_messages.stream_id,
                 &self.poll_messages.topic_id,
                 self.poll_messages.partition_id,
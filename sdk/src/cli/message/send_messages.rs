@@ -19,6 +19,7 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
+use crate::confirmation::ConfirmationLevel;
 use crate::identifier::Identifier;
 use crate::messages::send_messages::{Message, Partitioning};
 use crate::models::header::{HeaderKey, HeaderValue};
@@ -38,6 +39,7 @@ pub struct SendMessagesCmd {
     messages: Option<Vec<String>>,
     headers: Vec<(HeaderKey, HeaderValue)>,
     input_file: Option<String>,
+    confirmation: ConfirmationLevel,
 }
 
 impl SendMessagesCmd {
@@ -49,6 +51,7 @@ impl SendMessagesCmd {
         messages: Option<Vec<String>>,
         headers: Vec<(HeaderKey, HeaderValue)>,
         input_file: Option<String>,
+        confirmation: ConfirmationLevel,
     ) -> Self {
         let partitioning = match (partition_id, message_key) {
             (Some(_), Some(_)) => unreachable!(),
@@ -69,6 +72,7 @@ impl SendMessagesCmd {
             messages,
             headers,
             input_file,
+            confirmation,
         }
     }
 
@@ -157,11 +161,12 @@ impl CliCommand for SendMessagesCmd {
         };
 
         client
-            .send_messages(
+            .send_messages_with_confirmation(
                 &self.stream_id,
                 &self.topic_id,
                 &self.partitioning,
                 &mut messages,
+                self.confirmation,
             )
             .await
             .with_context(|| {
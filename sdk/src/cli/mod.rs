@@ -16,6 +16,7 @@
  * under the License.
  */
 
+pub mod bookmark;
 pub mod client;
 pub mod consumer_group;
 pub mod consumer_offset;
@@ -23,6 +24,8 @@ pub mod context;
 pub mod message;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod quotas;
+pub mod schemas;
 pub mod segments;
 pub mod streams;
 pub mod system;
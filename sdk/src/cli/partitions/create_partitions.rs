@@ -20,6 +20,7 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
 use crate::partitions::create_partitions::CreatePartitions;
+use crate::partitions::key_routing_policy::KeyRoutingPolicy;
 use anyhow::Context;
 use async_trait::async_trait;
 use tracing::{event, Level};
@@ -29,12 +30,18 @@ pub struct CreatePartitionsCmd {
 }
 
 impl CreatePartitionsCmd {
-    pub fn new(stream_id: Identifier, topic_id: Identifier, partitions_count: u32) -> Self {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partitions_count: u32,
+        key_routing_policy: KeyRoutingPolicy,
+    ) -> Self {
         Self {
             create_partition: CreatePartitions {
                 stream_id,
                 topic_id,
                 partitions_count,
+                key_routing_policy,
             },
         }
     }
@@ -67,6 +74,7 @@ impl CliCommand for CreatePartitionsCmd {
                 &self.create_partition.stream_id,
                 &self.create_partition.topic_id,
                 self.create_partition.partitions_count,
+                self.create_partition.key_routing_policy,
             )
             .await
             .with_context(|| {
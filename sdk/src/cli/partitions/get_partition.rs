@@ -0,0 +1,144 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::partitions::get_partition::GetPartition;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::{presets::ASCII_NO_BORDERS, Table};
+use tracing::{event, Level};
+
+pub struct GetPartitionCmd {
+    get_partition: GetPartition,
+}
+
+impl GetPartitionCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, partition_id: Identifier) -> Self {
+        Self {
+            get_partition: GetPartition {
+                stream_id,
+                topic_id,
+                partition_id,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetPartitionCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get partition with ID: {} for topic with ID: {} and stream with ID: {}",
+            self.get_partition.partition_id,
+            self.get_partition.topic_id,
+            self.get_partition.stream_id,
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let partition = client
+            .get_partition(&self.get_partition.stream_id, &self.get_partition.topic_id, &self.get_partition.partition_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting partition with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.get_partition.partition_id, self.get_partition.topic_id, self.get_partition.stream_id
+                )
+            })?;
+
+        if partition.is_none() {
+            event!(target: PRINT_TARGET, Level::INFO, "Partition with ID: {} was not found", self.get_partition.partition_id);
+            return Ok(());
+        }
+
+        let partition = partition.unwrap();
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec!["Partition id", format!("{}", partition.id).as_str()]);
+        table.add_row(vec![
+            "Partition name",
+            partition.name.as_deref().unwrap_or("-"),
+        ]);
+        table.add_row(vec![
+            "Created",
+            partition
+                .created_at
+                .to_utc_string("%Y-%m-%d %H:%M:%S")
+                .as_str(),
+        ]);
+        table.add_row(vec![
+            "Partition size",
+            format!("{}", partition.size).as_str(),
+        ]);
+        table.add_row(vec![
+            "Current offset",
+            format!("{}", partition.current_offset).as_str(),
+        ]);
+        table.add_row(vec![
+            "Messages count",
+            format!("{}", partition.messages_count).as_str(),
+        ]);
+        table.add_row(vec![
+            "Segments count",
+            format!("{}", partition.segments_count).as_str(),
+        ]);
+
+        if !partition.segments.is_empty() {
+            let mut segments_table = Table::new();
+            segments_table.load_preset(ASCII_NO_BORDERS);
+            segments_table.set_header(vec![
+                "Start offset",
+                "Current offset",
+                "Size",
+                "Messages count",
+                "Closed",
+            ]);
+            for segment in partition.segments {
+                segments_table.add_row(vec![
+                    format!("{}", segment.start_offset).as_str(),
+                    format!("{}", segment.current_offset).as_str(),
+                    format!("{}", segment.size).as_str(),
+                    format!("{}", segment.messages_count).as_str(),
+                    format!("{}", segment.is_closed).as_str(),
+                ]);
+            }
+            table.add_row(vec!["Segments", segments_table.to_string().as_str()]);
+        }
+
+        if !partition.consumer_offsets.is_empty() {
+            let mut offsets_table = Table::new();
+            offsets_table.load_preset(ASCII_NO_BORDERS);
+            offsets_table.set_header(vec!["Kind", "Consumer id", "Offset"]);
+            for consumer_offset in partition.consumer_offsets {
+                offsets_table.add_row(vec![
+                    format!("{}", consumer_offset.kind).as_str(),
+                    format!("{}", consumer_offset.consumer_id).as_str(),
+                    format!("{}", consumer_offset.offset).as_str(),
+                ]);
+            }
+            table.add_row(vec!["Consumer offsets", offsets_table.to_string().as_str()]);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO,"{table}");
+
+        Ok(())
+    }
+}
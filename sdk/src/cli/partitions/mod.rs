@@ -18,3 +18,6 @@
 
 pub mod create_partitions;
 pub mod delete_partitions;
+pub mod get_partition;
+pub mod move_partition;
+pub mod update_partition;
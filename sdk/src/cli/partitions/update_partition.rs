@@ -0,0 +1,89 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::partitions::update_partition::UpdatePartition;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct UpdatePartitionCmd {
+    update_partition: UpdatePartition,
+}
+
+impl UpdatePartitionCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: Identifier,
+        name: Option<String>,
+    ) -> Self {
+        Self {
+            update_partition: UpdatePartition {
+                stream_id,
+                topic_id,
+                partition_id,
+                name,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UpdatePartitionCmd {
+    fn explain(&self) -> String {
+        format!(
+            "update partition with ID: {} for topic with ID: {} and stream with ID: {}, name: {}",
+            self.update_partition.partition_id,
+            self.update_partition.topic_id,
+            self.update_partition.stream_id,
+            self.update_partition.name.as_deref().unwrap_or("-"),
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .update_partition(
+                &self.update_partition.stream_id,
+                &self.update_partition.topic_id,
+                &self.update_partition.partition_id,
+                self.update_partition.name.as_deref(),
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating partition with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.update_partition.partition_id,
+                    self.update_partition.topic_id,
+                    self.update_partition.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Partition with ID: {} for topic with ID: {} and stream with ID: {} updated, name: {}",
+            self.update_partition.partition_id,
+            self.update_partition.topic_id,
+            self.update_partition.stream_id,
+            self.update_partition.name.as_deref().unwrap_or("-"),
+        );
+
+        Ok(())
+    }
+}
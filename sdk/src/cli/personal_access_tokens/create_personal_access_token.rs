@@ -18,6 +18,7 @@
 
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
+use crate::models::personal_access_token::PersonalAccessTokenScope;
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 use anyhow::Context;
@@ -37,6 +38,8 @@ impl CreatePersonalAccessTokenCmd {
     pub fn new(
         name: String,
         pat_expiry: Option<PersonalAccessTokenExpiry>,
+        scope: Option<PersonalAccessTokenScope>,
+        allowed_addresses: Option<Vec<String>>,
         quiet_mode: bool,
         store_token: bool,
         server_address: String,
@@ -48,6 +51,8 @@ impl CreatePersonalAccessTokenCmd {
                     None => PersonalAccessTokenExpiry::NeverExpire,
                     Some(value) => *value,
                 },
+                scope,
+                allowed_addresses,
             },
             token_expiry: pat_expiry,
             quiet_mode,
@@ -72,7 +77,12 @@ impl CliCommand for CreatePersonalAccessTokenCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         let token = client
-            .create_personal_access_token(&self.create_token.name, self.create_token.expiry)
+            .create_personal_access_token_with_restrictions(
+                &self.create_token.name,
+                self.create_token.expiry,
+                self.create_token.scope.clone(),
+                self.create_token.allowed_addresses.clone(),
+            )
             .await
             .with_context(|| {
                 format!(
@@ -18,6 +18,7 @@
 
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
+use crate::models::personal_access_token::PersonalAccessTokenInfo;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -63,7 +64,13 @@ impl CliCommand for GetPersonalAccessTokensCmd {
             GetPersonalAccessTokensOutput::Table => {
                 let mut table = Table::new();
 
-                table.set_header(vec!["Name", "Token Expiry Time"]);
+                table.set_header(vec![
+                    "Name",
+                    "Token Expiry Time",
+                    "Scope",
+                    "Allowed Addresses",
+                    "Last Used",
+                ]);
 
                 tokens.iter().for_each(|token| {
                     table.add_row(vec![
@@ -72,6 +79,9 @@ impl CliCommand for GetPersonalAccessTokensCmd {
                             None => String::from("unlimited"),
                             Some(value) => value.to_local_string("%Y-%m-%d %H:%M:%S"),
                         },
+                        format_scope(token),
+                        format_allowed_addresses(token),
+                        format_last_used_at(token),
                     ]);
                 });
 
@@ -80,12 +90,15 @@ impl CliCommand for GetPersonalAccessTokensCmd {
             GetPersonalAccessTokensOutput::List => {
                 tokens.iter().for_each(|token| {
                     event!(target: PRINT_TARGET, Level::INFO,
-                        "{}|{}",
+                        "{}|{}|{}|{}|{}",
                         token.name,
                         match token.expiry_at {
                             None => String::from("unlimited"),
                             Some(value) => value.to_local_string("%Y-%m-%d %H:%M:%S"),
                         },
+                        format_scope(token),
+                        format_allowed_addresses(token),
+                        format_last_used_at(token),
                     );
                 });
             }
@@ -94,3 +107,24 @@ impl CliCommand for GetPersonalAccessTokensCmd {
         Ok(())
     }
 }
+
+fn format_scope(token: &PersonalAccessTokenInfo) -> String {
+    match &token.scope {
+        None => String::from("unrestricted"),
+        Some(scope) => scope.to_string(),
+    }
+}
+
+fn format_allowed_addresses(token: &PersonalAccessTokenInfo) -> String {
+    match &token.allowed_addresses {
+        None => String::from("any"),
+        Some(addresses) => addresses.join(", "),
+    }
+}
+
+fn format_last_used_at(token: &PersonalAccessTokenInfo) -> String {
+    match token.last_used_at {
+        None => String::from("never"),
+        Some(value) => value.to_local_string("%Y-%m-%d %H:%M:%S"),
+    }
+}
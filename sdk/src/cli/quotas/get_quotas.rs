@@ -0,0 +1,128 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::models::quota::{Quota, QuotaPrincipal};
+use crate::utils::byte_size::IggyByteSize;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use std::fmt::{self, Display, Formatter};
+use tracing::{event, Level};
+
+pub enum GetQuotasOutput {
+    Table,
+    List,
+}
+
+impl Display for GetQuotasOutput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GetQuotasOutput::Table => write!(f, "table"),
+            GetQuotasOutput::List => write!(f, "list"),
+        }?;
+
+        Ok(())
+    }
+}
+
+pub struct GetQuotasCmd {
+    output: GetQuotasOutput,
+}
+
+impl GetQuotasCmd {
+    pub fn new(output: GetQuotasOutput) -> Self {
+        Self { output }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetQuotasCmd {
+    fn explain(&self) -> String {
+        format!("list quotas in {} mode", self.output)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let quotas = client
+            .get_quotas()
+            .await
+            .with_context(|| "Problem getting list of quotas".to_string())?;
+
+        match self.output {
+            GetQuotasOutput::Table => {
+                let mut table = Table::new();
+                table.set_header(vec![
+                    "Principal",
+                    "Limits",
+                    "Default",
+                    "Produced",
+                    "Consumed",
+                    "Delayed",
+                    "Rejected",
+                ]);
+                quotas.iter().for_each(|quota| {
+                    table.add_row(vec![
+                        format_principal(quota),
+                        format!("{}", quota.limits),
+                        format!("{}", quota.is_default),
+                        format_usage(quota.produced_bytes, quota.produced_messages),
+                        format_usage(quota.consumed_bytes, quota.consumed_messages),
+                        format!("{}", quota.delayed_requests),
+                        format!("{}", quota.rejected_requests),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetQuotasOutput::List => {
+                quotas.iter().for_each(|quota| {
+                    event!(target: PRINT_TARGET, Level::INFO,
+                        "{}|{}|{}|{}|{}|{}|{}",
+                        format_principal(quota),
+                        quota.limits,
+                        quota.is_default,
+                        format_usage(quota.produced_bytes, quota.produced_messages),
+                        format_usage(quota.consumed_bytes, quota.consumed_messages),
+                        quota.delayed_requests,
+                        quota.rejected_requests,
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn format_principal(quota: &Quota) -> String {
+    match quota.principal {
+        QuotaPrincipal::User { user_id } => format!("user {user_id}"),
+        QuotaPrincipal::Topic {
+            stream_id,
+            topic_id,
+        } => format!("topic {stream_id}/{topic_id}"),
+    }
+}
+
+fn format_usage(bytes: u64, messages: u64) -> String {
+    format!(
+        "{} ({messages} messages)",
+        IggyByteSize::from(bytes).as_human_string()
+    )
+}
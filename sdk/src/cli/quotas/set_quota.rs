@@ -0,0 +1,110 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::quota::QuotaLimits;
+use crate::quotas::set_quota::SetQuota;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct SetQuotaCmd {
+    set_quota: SetQuota,
+}
+
+impl SetQuotaCmd {
+    pub fn user(user_id: Identifier, limits: Option<QuotaLimits>) -> Self {
+        Self {
+            set_quota: SetQuota {
+                user_id: Some(user_id),
+                limits,
+                ..SetQuota::default()
+            },
+        }
+    }
+
+    pub fn topic(stream_id: Identifier, topic_id: Identifier, limits: Option<QuotaLimits>) -> Self {
+        Self {
+            set_quota: SetQuota {
+                stream_id: Some(stream_id),
+                topic_id: Some(topic_id),
+                limits,
+                ..SetQuota::default()
+            },
+        }
+    }
+
+    fn principal(&self) -> String {
+        match (
+            &self.set_quota.user_id,
+            &self.set_quota.stream_id,
+            &self.set_quota.topic_id,
+        ) {
+            (Some(user_id), _, _) => format!("user with ID: {user_id}"),
+            (_, Some(stream_id), Some(topic_id)) => {
+                format!("topic with ID: {topic_id} in stream with ID: {stream_id}")
+            }
+            _ => "unknown principal".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for SetQuotaCmd {
+    fn explain(&self) -> String {
+        match self.set_quota.limits {
+            Some(limits) => format!("set quota of {} to {limits}", self.principal()),
+            None => format!("restore default quota of {}", self.principal()),
+        }
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        match (
+            &self.set_quota.user_id,
+            &self.set_quota.stream_id,
+            &self.set_quota.topic_id,
+        ) {
+            (Some(user_id), _, _) => client.set_user_quota(user_id, self.set_quota.limits).await,
+            (_, Some(stream_id), Some(topic_id)) => {
+                client
+                    .set_topic_quota(stream_id, topic_id, self.set_quota.limits)
+                    .await
+            }
+            _ => Err(IggyError::InvalidQuota(
+                "either user ID or stream and topic IDs must be provided".to_string(),
+            )),
+        }
+        .with_context(|| format!("Problem setting quota of {}", self.principal()))?;
+
+        match self.set_quota.limits {
+            Some(limits) => {
+                event!(target: PRINT_TARGET, Level::INFO,
+                    "Quota of {} set to {limits}", self.principal());
+            }
+            None => {
+                event!(target: PRINT_TARGET, Level::INFO,
+                    "Quota of {} restored to default", self.principal());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::schemas::schema_table;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::schemas::get_schema::GetSchema;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct GetSchemaCmd {
+    get_schema: GetSchema,
+}
+
+impl GetSchemaCmd {
+    pub fn new(schema_id: u32) -> Self {
+        Self {
+            get_schema: GetSchema { schema_id },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetSchemaCmd {
+    fn explain(&self) -> String {
+        format!("get schema with ID: {}", self.get_schema.schema_id)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let schema = client
+            .get_schema(self.get_schema.schema_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting schema with ID: {}",
+                    self.get_schema.schema_id
+                )
+            })?;
+
+        let Some(schema) = schema else {
+            event!(target: PRINT_TARGET, Level::INFO, "Schema with ID: {} was not found", self.get_schema.schema_id);
+            return Ok(());
+        };
+
+        let table = schema_table(&schema);
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::schemas::schema_table;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::schemas::get_subject_schema::GetSubjectSchema;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct GetSubjectSchemaCmd {
+    get_subject_schema: GetSubjectSchema,
+}
+
+impl GetSubjectSchemaCmd {
+    pub fn new(subject: String, version: Option<u32>) -> Self {
+        Self {
+            get_subject_schema: GetSubjectSchema { subject, version },
+        }
+    }
+
+    fn get_version_info(&self) -> String {
+        match self.get_subject_schema.version {
+            Some(version) => format!("version: {version}"),
+            None => "latest version".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetSubjectSchemaCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get schema with {} for subject: {}",
+            self.get_version_info(),
+            self.get_subject_schema.subject
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let schema = client
+            .get_subject_schema(
+                &self.get_subject_schema.subject,
+                self.get_subject_schema.version,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting schema with {} for subject: {}",
+                    self.get_version_info(),
+                    self.get_subject_schema.subject
+                )
+            })?;
+
+        let Some(schema) = schema else {
+            event!(target: PRINT_TARGET, Level::INFO, "Schema with {} for subject: {} was not found", self.get_version_info(), self.get_subject_schema.subject);
+            return Ok(());
+        };
+
+        let table = schema_table(&schema);
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+pub mod get_schema;
+pub mod get_schemas;
+pub mod get_subject_schema;
+pub mod register_schema;
+pub mod update_schema_compatibility;
+
+use crate::models::schema::Schema;
+use crate::models::topic_schema::SchemaKind;
+use comfy_table::Table;
+
+pub(crate) fn schema_table(schema: &Schema) -> Table {
+    let mut table = Table::new();
+
+    table.set_header(vec!["Property", "Value"]);
+    table.add_row(vec!["Schema id", format!("{}", schema.id).as_str()]);
+    table.add_row(vec!["Subject", schema.subject.as_str()]);
+    table.add_row(vec!["Version", format!("{}", schema.version).as_str()]);
+    table.add_row(vec![
+        "Created",
+        schema
+            .created_at
+            .to_local_string("%Y-%m-%d %H:%M:%S")
+            .as_str(),
+    ]);
+    table.add_row(vec!["Kind", format!("{}", schema.schema.kind).as_str()]);
+    if let Some(message_type) = &schema.schema.message_type {
+        table.add_row(vec!["Message type", message_type.as_str()]);
+    }
+    match schema.schema.kind {
+        SchemaKind::Json => {
+            table.add_row(vec![
+                "Definition",
+                String::from_utf8_lossy(&schema.schema.definition).as_ref(),
+            ]);
+        }
+        SchemaKind::Protobuf => {
+            table.add_row(vec![
+                "Definition",
+                format!("{} bytes", schema.schema.definition.len()).as_str(),
+            ]);
+        }
+    }
+
+    table
+}
@@ -0,0 +1,90 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::models::topic_schema::{SchemaKind, TopicSchema};
+use anyhow::Context;
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::path::PathBuf;
+use tracing::{event, Level};
+
+pub struct RegisterSchemaCmd {
+    subject: String,
+    kind: SchemaKind,
+    message_type: Option<String>,
+    definition_file: PathBuf,
+}
+
+impl RegisterSchemaCmd {
+    pub fn new(
+        subject: String,
+        kind: SchemaKind,
+        message_type: Option<String>,
+        definition_file: PathBuf,
+    ) -> Self {
+        Self {
+            subject,
+            kind,
+            message_type,
+            definition_file,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RegisterSchemaCmd {
+    fn explain(&self) -> String {
+        format!(
+            "register {} schema from file: {} for subject: {}",
+            self.kind,
+            self.definition_file.display(),
+            self.subject
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let definition = tokio::fs::read(&self.definition_file)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem reading schema definition from file: {}",
+                    self.definition_file.display()
+                )
+            })?;
+
+        let schema = TopicSchema {
+            kind: self.kind,
+            message_type: self.message_type.clone(),
+            definition: Bytes::from(definition),
+        };
+
+        let schema = client
+            .register_schema(&self.subject, schema)
+            .await
+            .with_context(|| format!("Problem registering schema for subject: {}", self.subject))?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Schema with ID: {} and version: {} registered for subject: {}",
+            schema.id, schema.version, schema.subject
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::schemas::schema_compatibility::SchemaCompatibility;
+use crate::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct UpdateSchemaCompatibilityCmd {
+    update_schema_compatibility: UpdateSchemaCompatibility,
+}
+
+impl UpdateSchemaCompatibilityCmd {
+    pub fn new(subject: String, compatibility: SchemaCompatibility) -> Self {
+        Self {
+            update_schema_compatibility: UpdateSchemaCompatibility {
+                subject,
+                compatibility,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UpdateSchemaCompatibilityCmd {
+    fn explain(&self) -> String {
+        format!(
+            "update compatibility for subject: {} to: {}",
+            self.update_schema_compatibility.subject,
+            self.update_schema_compatibility.compatibility
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .update_schema_compatibility(
+                &self.update_schema_compatibility.subject,
+                self.update_schema_compatibility.compatibility,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating compatibility for subject: {} to: {}",
+                    self.update_schema_compatibility.subject,
+                    self.update_schema_compatibility.compatibility
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Compatibility for subject: {} updated to: {}",
+            self.update_schema_compatibility.subject,
+            self.update_schema_compatibility.compatibility
+        );
+
+        Ok(())
+    }
+}
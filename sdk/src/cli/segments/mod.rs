@@ -1 +1,2 @@
 pub mod delete_segments;
+pub mod verify_segments;
@@ -0,0 +1,98 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::segments::verify_segments::VerifySegments;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct VerifySegmentsCmd {
+    verify_segments: VerifySegments,
+}
+
+impl VerifySegmentsCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, partition_id: u32) -> Self {
+        Self {
+            verify_segments: VerifySegments {
+                stream_id,
+                topic_id,
+                partition_id,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for VerifySegmentsCmd {
+    fn explain(&self) -> String {
+        format!(
+            "verify segments for topic with ID: {}, stream with ID: {} and partition with ID: {}",
+            self.verify_segments.topic_id,
+            self.verify_segments.stream_id,
+            self.verify_segments.partition_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let verification = client
+            .verify_segments(
+                &self.verify_segments.stream_id,
+                &self.verify_segments.topic_id,
+                self.verify_segments.partition_id,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem verifying segments for topic with ID: {}, stream with ID: {} and partition with ID: {}",
+                    self.verify_segments.topic_id,
+                    self.verify_segments.stream_id,
+                    self.verify_segments.partition_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Verified {} segments with {} batches for topic with ID: {}, stream with ID: {} and partition with ID: {}, found {} corrupted batches",
+            verification.segments_count,
+            verification.batches_count,
+            self.verify_segments.topic_id,
+            self.verify_segments.stream_id,
+            self.verify_segments.partition_id,
+            verification.corrupted_batches.len()
+        );
+
+        if !verification.corrupted_batches.is_empty() {
+            let mut table = Table::new();
+            table.set_header(vec!["Segment", "Base offset", "Position", "Length"]);
+            for batch in verification.corrupted_batches {
+                table.add_row(vec![
+                    format!("{}", batch.segment_start_offset),
+                    format!("{}", batch.base_offset),
+                    format!("{}", batch.position),
+                    format!("{}", batch.length),
+                ]);
+            }
+            event!(target: PRINT_TARGET, Level::INFO, "{table}");
+        }
+
+        Ok(())
+    }
+}
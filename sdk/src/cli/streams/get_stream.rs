@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::cli::utils::topology_cache::{fetch_cached, TopologyCache};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
@@ -27,14 +28,21 @@ use tracing::{event, Level};
 
 pub struct GetStreamCmd {
     get_stream: GetStream,
+    cache: Option<TopologyCache>,
 }
 
 impl GetStreamCmd {
     pub fn new(stream_id: Identifier) -> Self {
         Self {
             get_stream: GetStream { stream_id },
+            cache: None,
         }
     }
+
+    pub fn with_cache(mut self, cache: Option<TopologyCache>) -> Self {
+        self.cache = cache;
+        self
+    }
 }
 
 #[async_trait]
@@ -44,15 +52,19 @@ impl CliCommand for GetStreamCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        let stream = client
-            .get_stream(&self.get_stream.stream_id)
-            .await
-            .with_context(|| {
-                format!(
-                    "Problem getting stream with ID: {}",
-                    self.get_stream.stream_id
-                )
-            })?;
+        let key = format!("stream/{}", self.get_stream.stream_id);
+        let stream = fetch_cached(self.cache.as_ref(), &key, async {
+            client
+                .get_stream(&self.get_stream.stream_id)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem getting stream with ID: {}",
+                        self.get_stream.stream_id
+                    )
+                })
+        })
+        .await?;
 
         if stream.is_none() {
             event!(target: PRINT_TARGET, Level::INFO, "Stream with ID: {} was not found", self.get_stream.stream_id);
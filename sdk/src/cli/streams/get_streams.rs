@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::cli::utils::topology_cache::{fetch_cached, TopologyCache};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::streams::get_streams::GetStreams;
@@ -32,6 +33,7 @@ pub enum GetStreamsOutput {
 pub struct GetStreamsCmd {
     _get_streams: GetStreams,
     output: GetStreamsOutput,
+    cache: Option<TopologyCache>,
 }
 
 impl GetStreamsCmd {
@@ -39,8 +41,14 @@ impl GetStreamsCmd {
         GetStreamsCmd {
             _get_streams: GetStreams {},
             output,
+            cache: None,
         }
     }
+
+    pub fn with_cache(mut self, cache: Option<TopologyCache>) -> Self {
+        self.cache = cache;
+        self
+    }
 }
 
 impl Default for GetStreamsCmd {
@@ -48,6 +56,7 @@ impl Default for GetStreamsCmd {
         GetStreamsCmd {
             _get_streams: GetStreams {},
             output: GetStreamsOutput::Table,
+            cache: None,
         }
     }
 }
@@ -63,10 +72,13 @@ impl CliCommand for GetStreamsCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        let streams = client
-            .get_streams()
-            .await
-            .with_context(|| String::from("Problem getting list of streams"))?;
+        let streams = fetch_cached(self.cache.as_ref(), "streams", async {
+            client
+                .get_streams()
+                .await
+                .with_context(|| String::from("Problem getting list of streams"))
+        })
+        .await?;
 
         if streams.is_empty() {
             event!(target: PRINT_TARGET, Level::INFO, "No streams found!");
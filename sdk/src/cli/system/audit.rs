@@ -0,0 +1,138 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::models::user_info::UserId;
+use crate::system::get_audit_log::GetAuditLog;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use std::fmt::{self, Display, Formatter};
+use tracing::{event, Level};
+
+pub enum GetAuditLogOutput {
+    Table,
+    List,
+}
+
+impl Display for GetAuditLogOutput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GetAuditLogOutput::Table => write!(f, "table"),
+            GetAuditLogOutput::List => write!(f, "list"),
+        }?;
+
+        Ok(())
+    }
+}
+
+pub struct GetAuditLogCmd {
+    get_audit_log: GetAuditLog,
+    output: GetAuditLogOutput,
+}
+
+impl GetAuditLogCmd {
+    pub fn new(
+        user_id: Option<UserId>,
+        action: Option<String>,
+        limit: u32,
+        output: GetAuditLogOutput,
+    ) -> Self {
+        Self {
+            get_audit_log: GetAuditLog {
+                user_id,
+                action,
+                limit,
+            },
+            output,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetAuditLogCmd {
+    fn explain(&self) -> String {
+        format!("get audit log in {} mode", self.output)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let entries = client
+            .get_audit_log(
+                self.get_audit_log.user_id,
+                self.get_audit_log.action.as_deref(),
+                self.get_audit_log.limit,
+            )
+            .await
+            .with_context(|| "Problem getting audit log".to_owned())?;
+
+        if entries.is_empty() {
+            event!(target: PRINT_TARGET, Level::INFO, "No audit log entries found!");
+            return Ok(());
+        }
+
+        match self.output {
+            GetAuditLogOutput::Table => {
+                let mut table = Table::new();
+                table.set_header(vec![
+                    "ID",
+                    "Timestamp",
+                    "User ID",
+                    "Address",
+                    "Transport",
+                    "Action",
+                    "Details",
+                    "Outcome",
+                    "Error",
+                ]);
+                entries.iter().for_each(|entry| {
+                    table.add_row(vec![
+                        format!("{}", entry.id),
+                        entry.timestamp.to_local_string("%Y-%m-%d %H:%M:%S"),
+                        format!("{}", entry.user_id),
+                        entry.address.clone(),
+                        entry.transport.clone(),
+                        entry.action.clone(),
+                        entry.details.clone(),
+                        entry.outcome.to_string(),
+                        entry.error.clone().unwrap_or_default(),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetAuditLogOutput::List => {
+                entries.iter().for_each(|entry| {
+                    event!(target: PRINT_TARGET, Level::INFO,
+                        "{}|{}|{}|{}|{}|{}|{}|{}|{}",
+                        entry.id,
+                        entry.timestamp.to_local_string("%Y-%m-%d %H:%M:%S"),
+                        entry.user_id,
+                        entry.address,
+                        entry.transport,
+                        entry.action,
+                        entry.details,
+                        entry.outcome,
+                        entry.error.as_deref().unwrap_or_default(),
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::models::backup::BackupDestination;
+use crate::models::backup::BackupInfo;
+use crate::system::create_backup::CreateBackup;
+use crate::system::restore_backup::RestoreBackup;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct CreateBackupCmd {
+    create_backup: CreateBackup,
+}
+
+impl CreateBackupCmd {
+    pub fn new(
+        name: String,
+        streams: Vec<Identifier>,
+        destination: BackupDestination,
+        base: Option<String>,
+    ) -> Self {
+        Self {
+            create_backup: CreateBackup {
+                name,
+                streams,
+                destination,
+                base,
+            },
+        }
+    }
+
+    fn kind(&self) -> String {
+        match &self.create_backup.base {
+            Some(base) => format!("incremental backup (based on: {base})"),
+            None => "backup".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for CreateBackupCmd {
+    fn explain(&self) -> String {
+        format!(
+            "create {} with name: {} of {} in destination: {}",
+            self.kind(),
+            self.create_backup.name,
+            describe_streams(&self.create_backup.streams),
+            self.create_backup.destination
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let backup = client
+            .create_backup(
+                &self.create_backup.name,
+                &self.create_backup.streams,
+                self.create_backup.destination,
+                self.create_backup.base.as_deref(),
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem creating {} with name: {} of {} in destination: {}",
+                    self.kind(),
+                    self.create_backup.name,
+                    describe_streams(&self.create_backup.streams),
+                    self.create_backup.destination
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Backup with name: {} of {} created in destination: {}",
+            backup.name,
+            describe_streams(&self.create_backup.streams),
+            backup.destination
+        );
+        print_backup(&backup);
+
+        Ok(())
+    }
+}
+
+pub struct RestoreBackupCmd {
+    restore_backup: RestoreBackup,
+}
+
+impl RestoreBackupCmd {
+    pub fn new(name: String, streams: Vec<Identifier>) -> Self {
+        Self {
+            restore_backup: RestoreBackup { name, streams },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RestoreBackupCmd {
+    fn explain(&self) -> String {
+        format!(
+            "restore {} from backup with name: {}",
+            describe_streams(&self.restore_backup.streams),
+            self.restore_backup.name
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let backup = client
+            .restore_backup(&self.restore_backup.name, &self.restore_backup.streams)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem restoring {} from backup with name: {}",
+                    describe_streams(&self.restore_backup.streams),
+                    self.restore_backup.name
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Restored {} stream(s) from backup with name: {}",
+            backup.streams_count,
+            backup.name
+        );
+        print_backup(&backup);
+
+        Ok(())
+    }
+}
+
+fn describe_streams(streams: &[Identifier]) -> String {
+    if streams.is_empty() {
+        return "all streams".to_string();
+    }
+
+    let streams = streams
+        .iter()
+        .map(|stream_id| stream_id.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("streams with IDs: {streams}")
+}
+
+fn print_backup(backup: &BackupInfo) {
+    let mut table = Table::new();
+    table.set_header(vec!["Property", "Value"]);
+    table.add_row(vec!["Name", backup.name.as_str()]);
+    table.add_row(vec!["Base", backup.base.as_deref().unwrap_or("-")]);
+    table.add_row(vec!["Location", backup.location.as_str()]);
+    table.add_row(vec![
+        "Created".to_string(),
+        backup.created_at.to_local_string("%Y-%m-%d %H:%M:%S"),
+    ]);
+    table.add_row(vec![
+        "Streams".to_string(),
+        format!("{}", backup.streams_count),
+    ]);
+    table.add_row(vec!["Files".to_string(), format!("{}", backup.files_count)]);
+    table.add_row(vec!["Size".to_string(), backup.size.as_human_string()]);
+    event!(target: PRINT_TARGET, Level::INFO, "{table}");
+}
@@ -0,0 +1,98 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::system::get_config::GetConfig;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use std::fmt::{self, Display, Formatter};
+use tracing::{event, Level};
+
+pub enum GetConfigOutput {
+    Table,
+    List,
+}
+
+impl Display for GetConfigOutput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GetConfigOutput::Table => write!(f, "table"),
+            GetConfigOutput::List => write!(f, "list"),
+        }?;
+
+        Ok(())
+    }
+}
+
+pub struct GetConfigCmd {
+    _get_config: GetConfig,
+    output: GetConfigOutput,
+}
+
+impl GetConfigCmd {
+    pub fn new(output: GetConfigOutput) -> Self {
+        Self {
+            _get_config: GetConfig {},
+            output,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetConfigCmd {
+    fn explain(&self) -> String {
+        format!("get server config in {} mode", self.output)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let values = client
+            .get_config()
+            .await
+            .with_context(|| "Problem getting server config".to_owned())?;
+
+        match self.output {
+            GetConfigOutput::Table => {
+                let mut table = Table::new();
+                table.set_header(vec!["Key", "Value", "Source"]);
+                values.iter().for_each(|value| {
+                    table.add_row(vec![
+                        value.key.clone(),
+                        value.value.to_string(),
+                        value.source.to_string(),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetConfigOutput::List => {
+                values.iter().for_each(|value| {
+                    event!(target: PRINT_TARGET, Level::INFO,
+                        "{}|{}|{}",
+                        value.key,
+                        value.value,
+                        value.source,
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,367 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::models::config_value::ConfigValue;
+use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use serde_json::{json, Value};
+use std::io::{Cursor, Read, Write};
+use std::path::Path;
+use tokio::io::AsyncWriteExt;
+use tracing::{event, Level};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+const REDACTED: &str = "***";
+const SENSITIVE_KEYWORDS: [&str; 4] = ["password", "secret", "token", "bearer"];
+const SERVER_LOGS_ENTRY: &str = "server_logs.txt";
+pub const DEFAULT_LOG_LINES: usize = 1000;
+
+/// Collects the redacted support bundle, i.e. the single zip archive with the server version,
+/// statistics, effective configuration, enabled features, topology summary and recent logs,
+/// so that the bug reports contain the actionable context without gathering it manually.
+/// The parts which cannot be collected (e.g. due to missing permissions) are listed in `errors.txt`.
+pub struct CollectDiagnosticsCmd {
+    out_dir: String,
+    log_lines: usize,
+}
+
+impl CollectDiagnosticsCmd {
+    pub fn new(out_dir: Option<String>, log_lines: Option<usize>) -> Self {
+        Self {
+            out_dir: out_dir.unwrap_or_else(|| ".".to_string()),
+            log_lines: log_lines.unwrap_or(DEFAULT_LOG_LINES),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for CollectDiagnosticsCmd {
+    fn explain(&self) -> String {
+        "collect diagnostics command".to_owned()
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let mut entries = Vec::new();
+        let mut errors = Vec::new();
+
+        match client.get_stats().await {
+            Ok(stats) => {
+                let version = json!({
+                    "sdk_version": env!("CARGO_PKG_VERSION"),
+                    "server_version": stats.iggy_server_version,
+                    "server_semver": stats.iggy_server_semver,
+                    "os_name": stats.os_name,
+                    "os_version": stats.os_version,
+                    "kernel_version": stats.kernel_version,
+                });
+                entries.push(("version.json", to_json(&version)?));
+                entries.push(("stats.json", to_json(&stats)?));
+            }
+            Err(error) => errors.push(format!("stats: {error}")),
+        }
+
+        match client.get_config().await {
+            Ok(config) => {
+                let config = redact_config(config);
+                entries.push(("features.json", to_json(&get_features(&config))?));
+                entries.push(("config.json", to_json(&config)?));
+            }
+            Err(error) => errors.push(format!("config: {error}")),
+        }
+
+        match get_topology(client).await {
+            Ok(topology) => entries.push(("topology.json", to_json(&topology)?)),
+            Err(error) => errors.push(format!("topology: {error}")),
+        }
+
+        match get_recent_logs(client, self.log_lines).await {
+            Ok(logs) => entries.push(("logs.txt", redact(&logs))),
+            Err(error) => errors.push(format!("logs: {error}")),
+        }
+
+        if !errors.is_empty() {
+            entries.push(("errors.txt", errors.join("\n")));
+        }
+
+        let mut zip_writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        for (name, content) in &entries {
+            zip_writer
+                .start_file(*name, options)
+                .with_context(|| format!("Failed to add {name} to diagnostics bundle"))?;
+            zip_writer
+                .write_all(content.as_bytes())
+                .with_context(|| format!("Failed to write {name} to diagnostics bundle"))?;
+        }
+        let bundle = zip_writer
+            .finish()
+            .with_context(|| "Failed to complete diagnostics bundle".to_owned())?
+            .into_inner();
+
+        let file_path = Path::new(&self.out_dir).join(format!(
+            "diagnostics_{}.zip",
+            chrono::Local::now().format("%Y%m%d_%H%M%S")
+        ));
+        let mut file = tokio::fs::File::create(&file_path)
+            .await
+            .with_context(|| format!("Failed to create file at {:?}", file_path))?;
+        file.write_all(&bundle)
+            .await
+            .with_context(|| "Failed to write diagnostics bundle to file".to_owned())?;
+
+        let mut table = Table::new();
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec!["File Path", file_path.to_string_lossy().as_ref()]);
+        table.add_row(vec!["File Size (bytes)", &bundle.len().to_string()]);
+        table.add_row(vec![
+            "Entries",
+            &entries
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", "),
+        ]);
+        if !errors.is_empty() {
+            table.add_row(vec!["Errors", &errors.join("\n")]);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
+
+fn to_json<T: serde::Serialize + ?Sized>(value: &T) -> anyhow::Result<String> {
+    serde_json::to_string_pretty(value)
+        .with_context(|| "Failed to serialize diagnostics data".to_owned())
+}
+
+async fn get_topology(client: &dyn Client) -> anyhow::Result<Value> {
+    let mut streams = Vec::new();
+    for stream in client.get_streams().await? {
+        let topics = client
+            .get_topics(&Identifier::numeric(stream.id)?)
+            .await?
+            .into_iter()
+            .map(|topic| {
+                json!({
+                    "id": topic.id,
+                    "name": topic.name,
+                    "partitions_count": topic.partitions_count,
+                    "replication_factor": topic.replication_factor,
+                    "messages_count": topic.messages_count,
+                    "size": topic.size.to_string(),
+                    "message_expiry": topic.message_expiry.to_string(),
+                    "max_topic_size": topic.max_topic_size.to_string(),
+                })
+            })
+            .collect::<Vec<_>>();
+        streams.push(json!({
+            "id": stream.id,
+            "name": stream.name,
+            "messages_count": stream.messages_count,
+            "size": stream.size.to_string(),
+            "topics": topics,
+        }));
+    }
+
+    Ok(json!({ "streams": streams }))
+}
+
+async fn get_recent_logs(client: &dyn Client, log_lines: usize) -> anyhow::Result<String> {
+    let snapshot = client
+        .snapshot(
+            SnapshotCompression::Deflated,
+            vec![SystemSnapshotType::ServerLogs],
+        )
+        .await?;
+    let mut archive = ZipArchive::new(Cursor::new(snapshot.0))?;
+    let mut logs = String::new();
+    archive
+        .by_name(SERVER_LOGS_ENTRY)?
+        .read_to_string(&mut logs)?;
+
+    Ok(tail(&logs, log_lines))
+}
+
+/// Returns the last `lines` lines of the text.
+fn tail(text: &str, lines: usize) -> String {
+    let all_lines = text.lines().collect::<Vec<_>>();
+    all_lines[all_lines.len().saturating_sub(lines)..].join("\n")
+}
+
+/// Returns the flags of the server features, i.e. the configuration values with the key ending with `enabled`.
+fn get_features(config: &[ConfigValue]) -> Value {
+    Value::Object(
+        config
+            .iter()
+            .filter(|value| value.key.ends_with(".enabled"))
+            .map(|value| {
+                let feature = value.key.trim_end_matches(".enabled").to_owned();
+                (feature, value.value.clone())
+            })
+            .collect(),
+    )
+}
+
+/// Masks the string values of the configuration keys which look like the secrets,
+/// in addition to the secrets already masked by the server.
+fn redact_config(mut config: Vec<ConfigValue>) -> Vec<ConfigValue> {
+    for value in config.iter_mut() {
+        let name = value.key.rsplit('.').next().unwrap_or_default();
+        if value.value.is_string()
+            && SENSITIVE_KEYWORDS
+                .iter()
+                .any(|keyword| name.contains(keyword))
+        {
+            value.value = Value::String(REDACTED.to_owned());
+        }
+    }
+    config
+}
+
+/// Masks the values following the keywords which look like the secrets (e.g. `password=...`, `"token": "..."` or `Bearer ...`),
+/// in each line of the text.
+fn redact(text: &str) -> String {
+    text.lines().map(redact_line).collect::<Vec<_>>().join("\n")
+}
+
+fn redact_line(line: &str) -> String {
+    let lowercase = line.to_ascii_lowercase();
+    let bytes = line.as_bytes();
+    let length = bytes.len();
+    let mut redacted = String::with_capacity(length);
+    let mut copied = 0;
+    let mut search = 0;
+    while let Some((start, keyword)) = SENSITIVE_KEYWORDS
+        .iter()
+        .filter_map(|keyword| {
+            lowercase[search..]
+                .find(keyword)
+                .map(|index| (search + index, *keyword))
+        })
+        .min_by_key(|(start, _)| *start)
+    {
+        let mut cursor = start + keyword.len();
+        search = cursor;
+        while cursor < length && (bytes[cursor].is_ascii_alphanumeric() || bytes[cursor] == b'_') {
+            cursor += 1;
+        }
+        while cursor < length && matches!(bytes[cursor], b'"' | b'\'' | b' ') {
+            cursor += 1;
+        }
+        if cursor < length && matches!(bytes[cursor], b'=' | b':') {
+            cursor += 1;
+        } else if keyword != "bearer" || cursor == start + keyword.len() {
+            continue;
+        }
+
+        while cursor < length && matches!(bytes[cursor], b'"' | b'\'' | b' ') {
+            cursor += 1;
+        }
+        let value_start = cursor;
+        while cursor < length
+            && !matches!(
+                bytes[cursor],
+                b' ' | b',' | b'"' | b'\'' | b'}' | b';' | b'&'
+            )
+        {
+            cursor += 1;
+        }
+        if cursor > value_start {
+            redacted.push_str(&line[copied..value_start]);
+            redacted.push_str(REDACTED);
+            copied = cursor;
+        }
+        search = cursor;
+    }
+    redacted.push_str(&line[copied..]);
+    redacted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::config_value::ConfigSource;
+
+    #[test]
+    fn should_redact_secrets_in_logs() {
+        let logs = "user: admin, password=secret123 logged in\n\
+                    {\"token\": \"abc.def\", \"count\": 1}\n\
+                    Authorization: Bearer xyz789\n\
+                    Created 5 streams";
+
+        let redacted = redact(logs);
+
+        assert_eq!(
+            redacted,
+            "user: admin, password=*** logged in\n\
+             {\"token\": \"***\", \"count\": 1}\n\
+             Authorization: Bearer ***\n\
+             Created 5 streams"
+        );
+    }
+
+    #[test]
+    fn should_redact_secrets_in_config() {
+        let config = vec![
+            config_value("http.jwt.encoding_secret", json!("secret")),
+            config_value("system.segment.size", json!("1 GB")),
+            config_value("personal_access_token.max_tokens_per_user", json!(100)),
+        ];
+
+        let config = redact_config(config);
+
+        assert_eq!(config[0].value, json!(REDACTED));
+        assert_eq!(config[1].value, json!("1 GB"));
+        assert_eq!(config[2].value, json!(100));
+    }
+
+    #[test]
+    fn should_collect_features_from_config() {
+        let config = vec![
+            config_value("http.enabled", json!(true)),
+            config_value("quic.enabled", json!(false)),
+            config_value("system.segment.size", json!("1 GB")),
+        ];
+
+        let features = get_features(&config);
+
+        assert_eq!(features, json!({ "http": true, "quic": false }));
+    }
+
+    #[test]
+    fn should_keep_last_log_lines() {
+        assert_eq!(tail("first\nsecond\nthird", 2), "second\nthird");
+        assert_eq!(tail("first", 2), "first");
+    }
+
+    fn config_value(key: &str, value: Value) -> ConfigValue {
+        ConfigValue {
+            key: key.to_owned(),
+            value,
+            source: ConfigSource::Default,
+        }
+    }
+}
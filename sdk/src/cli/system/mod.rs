@@ -16,6 +16,10 @@
  * under the License.
  */
 
+pub mod audit;
+pub mod backup;
+pub mod config;
+pub mod diagnostics;
 pub mod login;
 pub mod logout;
 pub mod me;
@@ -130,6 +130,14 @@ impl CliCommand for GetStatsCmd {
                         .to_string()
                         .as_str(),
                 ]);
+                table.add_row(vec![
+                    "Disk Usage Bytes",
+                    stats.disk_usage.as_bytes_u64().to_string().as_str(),
+                ]);
+                table.add_row(vec![
+                    "Fsyncs Count",
+                    format!("{}", stats.fsyncs_count).as_str(),
+                ]);
                 table.add_row(vec![
                     "Streams Count",
                     format!("{}", stats.streams_count).as_str(),
@@ -197,6 +205,11 @@ impl CliCommand for GetStatsCmd {
                     "Messages Size Bytes|{}",
                     stats.messages_size_bytes.as_bytes_u64()
                 ));
+                list.push(format!(
+                    "Disk Usage Bytes|{}",
+                    stats.disk_usage.as_bytes_u64()
+                ));
+                list.push(format!("Fsyncs Count|{}", stats.fsyncs_count));
                 list.push(format!("Streams Count|{}", stats.streams_count));
                 list.push(format!("Topics Count|{}", stats.topics_count));
                 list.push(format!("Partitions Count|{}", stats.partitions_count));
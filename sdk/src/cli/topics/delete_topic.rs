@@ -29,11 +29,12 @@ pub struct DeleteTopicCmd {
 }
 
 impl DeleteTopicCmd {
-    pub fn new(stream_id: Identifier, topic_id: Identifier) -> Self {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, deferred: bool) -> Self {
         Self {
             delete_topic: DeleteTopic {
                 stream_id,
                 topic_id,
+                deferred,
             },
         }
     }
@@ -49,15 +50,21 @@ impl CliCommand for DeleteTopicCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        client
-            .delete_topic(&self.delete_topic.stream_id, &self.delete_topic.topic_id)
-            .await
-            .with_context(|| {
-                format!(
-                    "Problem deleting topic with ID: {} in stream {}",
-                    self.delete_topic.topic_id, self.delete_topic.stream_id
-                )
-            })?;
+        let result = if self.delete_topic.deferred {
+            client
+                .delete_topic_deferred(&self.delete_topic.stream_id, &self.delete_topic.topic_id)
+                .await
+        } else {
+            client
+                .delete_topic(&self.delete_topic.stream_id, &self.delete_topic.topic_id)
+                .await
+        };
+        result.with_context(|| {
+            format!(
+                "Problem deleting topic with ID: {} in stream {}",
+                self.delete_topic.topic_id, self.delete_topic.stream_id
+            )
+        })?;
 
         event!(target: PRINT_TARGET, Level::INFO,
             "Topic with ID: {} in stream with ID: {} deleted",
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::cli::utils::topology_cache::{fetch_cached, TopologyCache};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
@@ -28,6 +29,7 @@ use tracing::{event, Level};
 
 pub struct GetTopicCmd {
     get_topic: GetTopic,
+    cache: Option<TopologyCache>,
 }
 
 impl GetTopicCmd {
@@ -37,8 +39,14 @@ impl GetTopicCmd {
                 stream_id,
                 topic_id,
             },
+            cache: None,
         }
     }
+
+    pub fn with_cache(mut self, cache: Option<TopologyCache>) -> Self {
+        self.cache = cache;
+        self
+    }
 }
 
 #[async_trait]
@@ -51,15 +59,22 @@ impl CliCommand for GetTopicCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        let topic = client
-            .get_topic(&self.get_topic.stream_id, &self.get_topic.topic_id)
-            .await
-            .with_context(|| {
-                format!(
-                    "Problem getting topic with ID: {} in stream {}",
-                    self.get_topic.topic_id, self.get_topic.stream_id
-                )
-            })?;
+        let key = format!(
+            "topic/{}/{}",
+            self.get_topic.stream_id, self.get_topic.topic_id
+        );
+        let topic = fetch_cached(self.cache.as_ref(), &key, async {
+            client
+                .get_topic(&self.get_topic.stream_id, &self.get_topic.topic_id)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem getting topic with ID: {} in stream {}",
+                        self.get_topic.topic_id, self.get_topic.stream_id
+                    )
+                })
+        })
+        .await?;
 
         if topic.is_none() {
             event!(target: PRINT_TARGET, Level::INFO, "Topic with ID: {} in stream {} was not found", self.get_topic.topic_id, self.get_topic.stream_id);
@@ -77,6 +92,10 @@ impl CliCommand for GetTopicCmd {
         ]);
         table.add_row(vec!["Topic name", topic.name.as_str()]);
         table.add_row(vec!["Topic size", format!("{}", topic.size).as_str()]);
+        table.add_row(vec![
+            "Topic disk usage",
+            format!("{}", topic.disk_usage).as_str(),
+        ]);
         table.add_row(vec![
             "Compression",
             topic.compression_algorithm.to_string().as_str(),
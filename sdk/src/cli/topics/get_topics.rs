@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::cli::utils::topology_cache::{fetch_cached, TopologyCache};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
@@ -46,6 +47,7 @@ impl Display for GetTopicsOutput {
 pub struct GetTopicsCmd {
     get_topics: GetTopics,
     output: GetTopicsOutput,
+    cache: Option<TopologyCache>,
 }
 
 impl GetTopicsCmd {
@@ -53,8 +55,14 @@ impl GetTopicsCmd {
         Self {
             get_topics: GetTopics { stream_id },
             output,
+            cache: None,
         }
     }
+
+    pub fn with_cache(mut self, cache: Option<TopologyCache>) -> Self {
+        self.cache = cache;
+        self
+    }
 }
 
 #[async_trait]
@@ -67,15 +75,19 @@ impl CliCommand for GetTopicsCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        let topics = client
-            .get_topics(&self.get_topics.stream_id)
-            .await
-            .with_context(|| {
-                format!(
-                    "Problem getting topics from stream {}",
-                    self.get_topics.stream_id
-                )
-            })?;
+        let key = format!("topics/{}", self.get_topics.stream_id);
+        let topics = fetch_cached(self.cache.as_ref(), &key, async {
+            client
+                .get_topics(&self.get_topics.stream_id)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem getting topics from stream {}",
+                        self.get_topics.stream_id
+                    )
+                })
+        })
+        .await?;
 
         match self.output {
             GetTopicsOutput::Table => {
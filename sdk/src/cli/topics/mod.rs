@@ -21,4 +21,5 @@ pub mod delete_topic;
 pub mod get_topic;
 pub mod get_topics;
 pub mod purge_topic;
+pub mod set_topic_throttle;
 pub mod update_topic;
@@ -0,0 +1,103 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::topics::set_topic_throttle::SetTopicThrottle;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct SetTopicThrottleCmd {
+    set_topic_throttle: SetTopicThrottle,
+}
+
+impl SetTopicThrottleCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        max_throughput: Option<IggyByteSize>,
+        duration: Option<IggyDuration>,
+    ) -> Self {
+        Self {
+            set_topic_throttle: SetTopicThrottle {
+                stream_id,
+                topic_id,
+                max_throughput,
+                duration,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for SetTopicThrottleCmd {
+    fn explain(&self) -> String {
+        match self.set_topic_throttle.max_throughput {
+            Some(max_throughput) => format!(
+                "throttle topic with ID: {} in stream with ID: {} to max throughput: {}/s",
+                self.set_topic_throttle.topic_id,
+                self.set_topic_throttle.stream_id,
+                max_throughput.as_human_string(),
+            ),
+            None => format!(
+                "clear throttle of topic with ID: {} in stream with ID: {}",
+                self.set_topic_throttle.topic_id, self.set_topic_throttle.stream_id
+            ),
+        }
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .set_topic_throttle(
+                &self.set_topic_throttle.stream_id,
+                &self.set_topic_throttle.topic_id,
+                self.set_topic_throttle.max_throughput,
+                self.set_topic_throttle.duration,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem setting throttle of topic with ID: {} in stream {}",
+                    self.set_topic_throttle.topic_id, self.set_topic_throttle.stream_id
+                )
+            })?;
+
+        match self.set_topic_throttle.max_throughput {
+            Some(max_throughput) => {
+                event!(target: PRINT_TARGET, Level::INFO,
+                    "Topic with ID: {} in stream with ID: {} throttled to max throughput: {}/s for: {}",
+                    self.set_topic_throttle.topic_id,
+                    self.set_topic_throttle.stream_id,
+                    max_throughput.as_human_string(),
+                    self.set_topic_throttle.duration
+                        .map_or("unlimited".to_string(), |duration| duration.as_human_time_string()));
+            }
+            None => {
+                event!(target: PRINT_TARGET, Level::INFO,
+                    "Throttle of topic with ID: {} in stream with ID: {} cleared",
+                    self.set_topic_throttle.topic_id, self.set_topic_throttle.stream_id);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -20,6 +20,7 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::identifier::Identifier;
+use crate::models::topic_options::TopicOptions;
 use crate::topics::update_topic::UpdateTopic;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::topic_size::MaxTopicSize;
@@ -33,9 +34,11 @@ pub struct UpdateTopicCmd {
     message_expiry: IggyExpiry,
     max_topic_size: MaxTopicSize,
     replication_factor: u8,
+    options: TopicOptions,
 }
 
 impl UpdateTopicCmd {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         stream_id: Identifier,
         topic_id: Identifier,
@@ -44,6 +47,7 @@ impl UpdateTopicCmd {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        options: TopicOptions,
     ) -> Self {
         Self {
             update_topic: UpdateTopic {
@@ -54,10 +58,12 @@ impl UpdateTopicCmd {
                 message_expiry,
                 max_topic_size,
                 replication_factor: Some(replication_factor),
+                options: None,
             },
             message_expiry,
             max_topic_size,
             replication_factor,
+            options,
         }
     }
 }
@@ -69,9 +75,60 @@ impl CliCommand for UpdateTopicCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        client
-            .update_topic(&self.update_topic.stream_id, &self.update_topic.topic_id, &self.update_topic.name, self.update_topic.compression_algorithm, self.replication_factor.into(), self.message_expiry, self.max_topic_size)
-            .await
+        let result = if self.options.is_empty() {
+            client
+                .update_topic(
+                    &self.update_topic.stream_id,
+                    &self.update_topic.topic_id,
+                    &self.update_topic.name,
+                    self.update_topic.compression_algorithm,
+                    self.replication_factor.into(),
+                    self.message_expiry,
+                    self.max_topic_size,
+                )
+                .await
+        } else {
+            // The update replaces the topic schema, dead letter, sampling and compaction policies, so the current ones are kept as they're not configurable here.
+            // The same applies to the retention, tiering, fsync, segment rollover and queue policies, unless the new ones were provided.
+            let topic = client
+                .get_topic(&self.update_topic.stream_id, &self.update_topic.topic_id)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem getting topic with ID: {} in stream with ID: {}",
+                        self.update_topic.topic_id, self.update_topic.stream_id
+                    )
+                })?;
+            let mut options = self.options.clone();
+            if let Some(topic) = topic {
+                let current = topic.options;
+                options.schema = current.schema;
+                options.dead_letter_policy = current.dead_letter_policy;
+                options.sampling_policy = current.sampling_policy;
+                options.compaction_policy = current.compaction_policy;
+                options.retention_policy = options.retention_policy.or(current.retention_policy);
+                options.tiering_policy = options.tiering_policy.or(current.tiering_policy);
+                options.fsync_policy = options.fsync_policy.or(current.fsync_policy);
+                options.segment_rollover_policy = options
+                    .segment_rollover_policy
+                    .or(current.segment_rollover_policy);
+                options.queue_policy = options.queue_policy.or(current.queue_policy);
+            }
+
+            client
+                .update_topic_with_options(
+                    &self.update_topic.stream_id,
+                    &self.update_topic.topic_id,
+                    &self.update_topic.name,
+                    self.update_topic.compression_algorithm,
+                    self.replication_factor.into(),
+                    self.message_expiry,
+                    self.max_topic_size,
+                    options,
+                )
+                .await
+        };
+        result
             .with_context(|| {
                 format!(
                     "Problem updating topic (ID: {}, name: {}, message expiry: {}) in stream with ID: {}",
@@ -19,7 +19,9 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
-use crate::models::permissions::{GlobalPermissions, StreamPermissions, TopicPermissions};
+use crate::models::permissions::{
+    GlobalPermissions, PatternPermissions, StreamPermissions, TopicPermissions,
+};
 use crate::users::get_user::GetUser;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -41,6 +43,10 @@ impl From<GlobalPermissions> for Table {
             "Read Servers",
             value.read_servers.to_string().as_str(),
         ]);
+        table.add_row(vec![
+            "Manage Config",
+            value.manage_config.to_string().as_str(),
+        ]);
         table.add_row(vec![
             "Manage Users",
             value.manage_users.to_string().as_str(),
@@ -135,6 +141,20 @@ impl From<&StreamPermissions> for Table {
     }
 }
 
+impl From<&PatternPermissions> for Table {
+    fn from(value: &PatternPermissions) -> Self {
+        let mut table = Self::new();
+
+        table.load_preset(ASCII_NO_BORDERS);
+        table.set_header(vec!["Permission", "Value"]);
+        table.add_row(vec!["Admin", value.admin.to_string().as_str()]);
+        table.add_row(vec!["Read", value.read.to_string().as_str()]);
+        table.add_row(vec!["Write", value.write.to_string().as_str()]);
+
+        table
+    }
+}
+
 pub struct GetUserCmd {
     get_user: GetUser,
 }
@@ -196,6 +216,16 @@ impl CliCommand for GetUserCmd {
                     ]);
                 });
             }
+
+            if let Some(patterns) = permissions.patterns {
+                patterns.iter().for_each(|pattern_permissions| {
+                    let pattern_table: Table = pattern_permissions.into();
+                    table.add_row(vec![
+                        format!("Pattern: {}", pattern_permissions.pattern).as_str(),
+                        format!("{}", pattern_table).as_str(),
+                    ]);
+                });
+            }
         };
 
         event!(target: PRINT_TARGET, Level::INFO, "{table}");
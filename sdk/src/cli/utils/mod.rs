@@ -17,3 +17,4 @@
  */
 
 pub mod login_session_expiry;
+pub mod topology_cache;
@@ -0,0 +1,208 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::cli::context::common::iggy_home;
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::path::PathBuf;
+use tracing::debug;
+
+static TOPOLOGY_CACHE_DIR_NAME: &str = "topology_cache";
+
+/// The local cache of the streams, topics and consumer groups listings of a single server, kept in the Iggy home directory,
+/// so the list and get commands don't have to fetch the whole topology of a large cluster on every call.
+///
+/// The entries are reused until the TTL expires, unless the refresh is requested, in which case they're fetched and stored again.
+/// The zero TTL disables the cache, but it can still be invalidated.
+#[derive(Debug, Clone)]
+pub struct TopologyCache {
+    path: PathBuf,
+    ttl: IggyDuration,
+    refresh: bool,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TopologyCacheFile {
+    entries: BTreeMap<String, TopologyCacheEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TopologyCacheEntry {
+    fetched_at: u64,
+    value: serde_json::Value,
+}
+
+impl TopologyCacheEntry {
+    fn is_fresh(&self, ttl: IggyDuration, now: IggyTimestamp) -> bool {
+        now.as_micros().saturating_sub(self.fetched_at) < ttl.as_micros()
+    }
+}
+
+impl TopologyCache {
+    /// Creates the cache of the given server, or returns `None` if the Iggy home directory is unknown.
+    pub fn new(server_address: &str, ttl: IggyDuration, refresh: bool) -> Option<Self> {
+        let path = iggy_home()?
+            .join(TOPOLOGY_CACHE_DIR_NAME)
+            .join(cache_file_name(server_address));
+        Some(Self { path, ttl, refresh })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.ttl.is_zero()
+    }
+
+    /// Returns the cached value of the key if it hasn't expired, otherwise fetches it and stores in the cache.
+    /// The cache is best effort, so the failures to read or write the cache file only fall back to fetching the value.
+    pub async fn get_or_fetch<T, F>(&self, key: &str, fetch: F) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: Future<Output = Result<T>>,
+    {
+        let mut cache = self.read().await;
+        let now = IggyTimestamp::now();
+        if !self.refresh {
+            let cached_value = cache
+                .entries
+                .get(key)
+                .filter(|entry| entry.is_fresh(self.ttl, now))
+                .and_then(|entry| serde_json::from_value(entry.value.clone()).ok());
+            if let Some(value) = cached_value {
+                debug!("Using cached topology entry: {key}");
+                return Ok(value);
+            }
+        }
+
+        let value = fetch.await?;
+        cache
+            .entries
+            .retain(|_, entry| entry.is_fresh(self.ttl, now));
+        cache.entries.insert(
+            key.to_string(),
+            TopologyCacheEntry {
+                fetched_at: now.as_micros(),
+                value: serde_json::to_value(&value)
+                    .context("failed serializing topology cache entry")?,
+            },
+        );
+        if let Err(error) = self.write(&cache).await {
+            debug!("Failed to write topology cache: {error:#}");
+        }
+        Ok(value)
+    }
+
+    /// Removes all the cached entries, e.g. after the topology has been changed.
+    pub async fn invalidate(&self) -> Result<()> {
+        match tokio::fs::remove_file(&self.path).await {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error).context(
+                format!("failed removing topology cache {}", self.path.display()),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    async fn read(&self) -> TopologyCacheFile {
+        let Ok(contents) = tokio::fs::read(&self.path).await else {
+            return TopologyCacheFile::default();
+        };
+
+        serde_json::from_slice(&contents).unwrap_or_else(|error| {
+            debug!(
+                "Ignoring invalid topology cache {}: {error}",
+                self.path.display()
+            );
+            TopologyCacheFile::default()
+        })
+    }
+
+    async fn write(&self, cache: &TopologyCacheFile) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .context(format!("failed creating directory {}", dir.display()))?;
+        }
+
+        let contents = serde_json::to_vec(cache).context("failed serializing topology cache")?;
+        tokio::fs::write(&self.path, contents)
+            .await
+            .context(format!(
+                "failed writing topology cache {}",
+                self.path.display()
+            ))
+    }
+}
+
+/// Fetches the value through the cache if it's enabled, or directly from the server otherwise.
+pub async fn fetch_cached<T, F>(cache: Option<&TopologyCache>, key: &str, fetch: F) -> Result<T>
+where
+    T: Serialize + DeserializeOwned,
+    F: Future<Output = Result<T>>,
+{
+    match cache.filter(|cache| cache.is_enabled()) {
+        Some(cache) => cache.get_or_fetch(key, fetch).await,
+        None => fetch.await,
+    }
+}
+
+fn cache_file_name(server_address: &str) -> String {
+    let name: String = server_address
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("{name}.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_create_cache_file_name_from_server_address() {
+        assert_eq!(cache_file_name("127.0.0.1:8090"), "127_0_0_1_8090.json");
+        assert_eq!(
+            cache_file_name("https://iggy.local:3000"),
+            "https___iggy_local_3000.json"
+        );
+    }
+
+    #[test]
+    fn should_expire_entry_after_ttl() {
+        let entry = TopologyCacheEntry {
+            fetched_at: 1_000_000,
+            value: serde_json::Value::Null,
+        };
+        let ttl = IggyDuration::new_from_secs(10);
+
+        assert!(entry.is_fresh(ttl, IggyTimestamp::from(10_999_999)));
+        assert!(!entry.is_fresh(ttl, IggyTimestamp::from(11_000_000)));
+    }
+
+    #[test]
+    fn should_disable_cache_with_zero_ttl() {
+        let cache = TopologyCache {
+            path: PathBuf::from("127_0_0_1_8090.json"),
+            ttl: IggyDuration::default(),
+            refresh: false,
+        };
+
+        assert!(!cache.is_enabled());
+    }
+}
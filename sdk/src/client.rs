@@ -17,27 +17,47 @@
  */
 
 use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::confirmation::ConfirmationLevel;
 use crate::consumer::Consumer;
+use crate::consumer_groups::assignment_strategy::AssignmentStrategy;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::messages::poll_messages::PollingStrategy;
 use crate::messages::send_messages::{Message, Partitioning};
+use crate::messages::AutoCommitMode;
+use crate::models::audit_entry::AuditEntry;
+use crate::models::backup::{BackupDestination, BackupInfo};
+use crate::models::bookmark::Bookmark;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::cluster::ClusterMetadata;
+use crate::models::config_value::ConfigValue;
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupRebalance};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::PolledMessages;
+use crate::models::partition::PartitionDetails;
 use crate::models::permissions::Permissions;
-use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::personal_access_token::{
+    PersonalAccessTokenInfo, PersonalAccessTokenScope, RawPersonalAccessToken,
+};
+use crate::models::quota::{Quota, QuotaLimits};
+use crate::models::schema::Schema;
+use crate::models::segments_verification::SegmentsVerification;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
-use crate::models::user_info::{UserInfo, UserInfoDetails};
+use crate::models::topic_options::TopicOptions;
+use crate::models::topic_schema::TopicSchema;
+use crate::models::user_info::{UserId, UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
+use crate::partitions::key_routing_policy::KeyRoutingPolicy;
+use crate::schemas::schema_compatibility::SchemaCompatibility;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+#[cfg(feature = "tcp")]
 use crate::tcp::config::{TcpClientConfig, TcpClientReconnectionConfig};
+use crate::utils::byte_size::IggyByteSize;
 use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
@@ -45,10 +65,13 @@ use crate::utils::topic_size::MaxTopicSize;
 use async_broadcast::Receiver;
 use async_trait::async_trait;
 use std::fmt::Debug;
+#[cfg(feature = "tcp")]
 use std::str::FromStr;
 
+#[cfg(feature = "tcp")]
 const CONNECTION_STRING_PREFIX: &str = "iggy://";
 
+#[cfg(feature = "tcp")]
 #[derive(Debug)]
 pub(crate) struct ConnectionString {
     server_address: String,
@@ -82,7 +105,10 @@ pub trait Client:
     + SegmentClient
     + MessageClient
     + ConsumerOffsetClient
+    + BookmarkClient
     + ConsumerGroupClient
+    + SchemaClient
+    + QuotaClient
     + Sync
     + Send
     + Debug
@@ -99,6 +125,14 @@ pub trait Client:
 
     /// Subscribe to diagnostic events.
     async fn subscribe_events(&self) -> Receiver<DiagnosticEvent>;
+
+    /// Subscribe to the rebalances of the consumer groups the client is a member of, pushed by the server.
+    /// Only the TCP client without TLS supports it, for the other ones `FeatureUnavailable` error is returned.
+    async fn subscribe_consumer_group_rebalances(
+        &self,
+    ) -> Result<Receiver<ConsumerGroupRebalance>, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
 }
 
 /// This trait defines the methods to interact with the system module.
@@ -120,6 +154,27 @@ pub trait SystemClient {
     ///
     /// Authentication is required, and the permission to read the server info.
     async fn get_clients(&self) -> Result<Vec<ClientInfo>, IggyError>;
+    /// Get the effective server configuration, with the source of each value (default, file, environment or runtime).
+    /// The secrets are masked.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    async fn get_config(&self) -> Result<Vec<ConfigValue>, IggyError>;
+    /// Get the most recent entries of the audit log of the administrative actions, from the newest to the oldest.
+    /// Optionally filtered by the identifier of the user who invoked the action and the name of the action, e.g. `stream.create`.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn get_audit_log(
+        &self,
+        user_id: Option<UserId>,
+        action: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<AuditEntry>, IggyError>;
+    /// Get the metadata of the cluster - its nodes, and the leaders and replicas of the partitions,
+    /// used to route the appended and polled messages to the partition leaders.
+    /// If the cluster mode is disabled on the server, the metadata is empty.
+    ///
+    /// Authentication is required.
+    async fn get_cluster_metadata(&self) -> Result<ClusterMetadata, IggyError>;
     /// Ping the server to check if it's alive.
     async fn ping(&self) -> Result<(), IggyError>;
     async fn heartbeat_interval(&self) -> IggyDuration;
@@ -131,6 +186,28 @@ pub trait SystemClient {
         compression: SnapshotCompression,
         snapshot_types: Vec<SystemSnapshotType>,
     ) -> Result<Snapshot, IggyError>;
+    /// Take a consistent point-in-time backup of the server metadata and the selected streams (all of them, if empty),
+    /// written to the local archive directory or the S3-compatible object storage configured on the server.
+    /// If the base backup is provided, the incremental backup archives only the segments which are not archived by the base one yet.
+    /// The backup can be restored with the `--restore` server argument, or imported with the `restore_backup` method.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn create_backup(
+        &self,
+        name: &str,
+        streams: &[Identifier],
+        destination: BackupDestination,
+        base: Option<&str>,
+    ) -> Result<BackupInfo, IggyError>;
+    /// Import the selected streams (all the archived ones, if empty) from the backup into the running server,
+    /// preserving their IDs, message offsets and consumer offsets. None of the streams can already exist on the server.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn restore_backup(
+        &self,
+        name: &str,
+        streams: &[Identifier],
+    ) -> Result<BackupInfo, IggyError>;
 }
 
 /// This trait defines the methods to interact with the user module.
@@ -201,6 +278,14 @@ pub trait PersonalAccessTokenClient {
         name: &str,
         expiry: PersonalAccessTokenExpiry,
     ) -> Result<RawPersonalAccessToken, IggyError>;
+    /// Create a new personal access token for the currently authenticated user, limited to the provided scope and usable only from the provided addresses (or CIDR ranges).
+    async fn create_personal_access_token_with_restrictions(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+        scope: Option<PersonalAccessTokenScope>,
+        allowed_addresses: Option<Vec<String>>,
+    ) -> Result<RawPersonalAccessToken, IggyError>;
     /// Delete a personal access token of the currently authenticated user by unique token name.
     async fn delete_personal_access_token(&self, name: &str) -> Result<(), IggyError>;
     /// Login the user with the provided personal access token.
@@ -274,9 +359,26 @@ pub trait TopicClient {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
     ) -> Result<TopicDetails, IggyError>;
-    /// Update a topic by unique ID or name.
+    /// Create a new topic with the given options (schema and policies), see `TopicOptions`.
     ///
     /// Authentication is required, and the permission to manage the topics.
+    #[allow(clippy::too_many_arguments)]
+    async fn create_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: TopicOptions,
+    ) -> Result<TopicDetails, IggyError>;
+    /// Update a topic by unique ID or name, keeping its current options (schema and policies).
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    #[allow(clippy::too_many_arguments)]
     async fn update_topic(
         &self,
         stream_id: &Identifier,
@@ -287,6 +389,21 @@ pub trait TopicClient {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
     ) -> Result<(), IggyError>;
+    /// Update a topic by unique ID or name, replacing its options (schema and policies), the unset ones are removed.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    #[allow(clippy::too_many_arguments)]
+    async fn update_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: TopicOptions,
+    ) -> Result<(), IggyError>;
     /// Delete a topic by unique ID or name.
     ///
     /// Authentication is required, and the permission to manage the topics.
@@ -295,6 +412,15 @@ pub trait TopicClient {
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<(), IggyError>;
+    /// Delete a topic by unique ID or name, once the consumer groups have consumed all of its messages,
+    /// or the deletion grace period configured on the server has elapsed. Meanwhile, the topic rejects the new messages.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn delete_topic_deferred(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError>;
     /// Purge a topic by unique ID or name.
     ///
     /// Authentication is required, and the permission to manage the topics.
@@ -303,14 +429,37 @@ pub trait TopicClient {
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<(), IggyError>;
+    /// Set or clear the temporary throughput cap of a topic by unique ID or name, e.g. to stop a runaway producer.
+    ///
+    /// The messages appended above the max throughput (per second) are rejected, and the throttle is lifted after the optional duration.
+    /// When the max throughput is not provided, the current throttle is cleared. The throttle is not persisted, so it's cleared on the server restart.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn set_topic_throttle(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        max_throughput: Option<IggyByteSize>,
+        duration: Option<IggyDuration>,
+    ) -> Result<(), IggyError>;
 }
 
 /// This trait defines the methods to interact with the partition module.
 #[async_trait]
 pub trait PartitionClient {
+    /// Get the details of a specific partition by unique ID or name, including its segments and the stored consumer offsets.
+    ///
+    /// Authentication is required, and the permission to read the topics.
+    async fn get_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+    ) -> Result<Option<PartitionDetails>, IggyError>;
     /// Create new N partitions for a topic by unique ID or name.
     ///
     /// For example, given a topic with 3 partitions, if you create 2 partitions, the topic will have 5 partitions (from 1 to 5).
+    /// The key routing policy decides whether the messages keys already routed to the existing partitions are re-hashed or preserved.
     ///
     /// Authentication is required, and the permission to manage the partitions.
     async fn create_partitions(
@@ -318,6 +467,7 @@ pub trait PartitionClient {
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitions_count: u32,
+        key_routing_policy: KeyRoutingPolicy,
     ) -> Result<(), IggyError>;
     /// Delete last N partitions for a topic by unique ID or name.
     ///
@@ -330,6 +480,31 @@ pub trait PartitionClient {
         topic_id: &Identifier,
         partitions_count: u32,
     ) -> Result<(), IggyError>;
+    /// Update a partition by unique ID or name.
+    ///
+    /// The name (label) must be unique within the topic, and when it's not provided, the current name is removed.
+    ///
+    /// Authentication is required, and the permission to manage the partitions.
+    async fn update_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+        name: Option<&str>,
+    ) -> Result<(), IggyError>;
+    /// Move the data of a partition by unique ID or name to a different data directory, e.g. another disk.
+    ///
+    /// The directory must be either the system path or one of the data directories configured on the server.
+    /// The segments are copied in the background, and the partition is switched to the new location once all of them are copied.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn move_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+        directory: &str,
+    ) -> Result<(), IggyError>;
 }
 
 /// This trait defines the methods to interact with the partition module.
@@ -347,6 +522,17 @@ pub trait SegmentClient {
         partition_id: u32,
         segments_count: u32,
     ) -> Result<(), IggyError>;
+    /// Verify the checksums of all the batches stored in the segments of a partition by unique ID.
+    ///
+    /// The corrupted batches are quarantined, and skipped when reading the messages.
+    ///
+    /// Authentication is required, and the permission to manage the segments.
+    async fn verify_segments(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<SegmentsVerification, IggyError>;
 }
 
 /// This trait defines the methods to interact with the messaging module.
@@ -366,6 +552,21 @@ pub trait MessageClient {
         count: u32,
         auto_commit: bool,
     ) -> Result<PolledMessages, IggyError>;
+    /// Poll given amount of messages using the specified consumer and strategy from the specified stream and topic by unique IDs or names.
+    /// The `auto_commit` mode specifies when the server stores the offset of the polled messages on behalf of the consumer.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn poll_messages_with_commit_mode(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: AutoCommitMode,
+    ) -> Result<PolledMessages, IggyError>;
     /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names.
     ///
     /// Authentication is required, and the permission to send the messages.
@@ -376,6 +577,18 @@ pub trait MessageClient {
         partitioning: &Partitioning,
         messages: &mut [Message],
     ) -> Result<(), IggyError>;
+    /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names,
+    /// which are confirmed by the server once they reach the given confirmation level (`received` for `send_messages`).
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn send_messages_with_confirmation(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+        confirmation: ConfirmationLevel,
+    ) -> Result<(), IggyError>;
     /// Force flush of the `unsaved_messages` buffer to disk, optionally fsyncing the data.
     #[allow(clippy::too_many_arguments)]
     async fn flush_unsaved_buffer(
@@ -385,6 +598,72 @@ pub trait MessageClient {
         partition_id: u32,
         fsync: bool,
     ) -> Result<(), IggyError>;
+    /// Reject (negatively acknowledge) the messages with the given offsets for the specified consumer, stream and topic by unique IDs or names.
+    /// Once the delivery attempts of the message exceed the limit configured in the dead letter policy of the topic, the message is moved to the dead letter topic.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn reject_messages(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        reason: &str,
+    ) -> Result<(), IggyError>;
+    /// Negatively acknowledge the messages with the given offsets for the specified consumer, stream and topic by unique IDs or names.
+    /// The messages are redelivered by the next polling using `Next` strategy, once the optional redelivery delay has passed.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn nack_messages(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        redelivery_delay: Option<IggyDuration>,
+    ) -> Result<(), IggyError>;
+    /// Acknowledge the messages with the given offsets polled from the partition of the topic in the queue mode, for the specified stream and topic by unique IDs or names.
+    /// The acknowledged messages are never redelivered, while the other ones are redelivered once their visibility timeout elapses.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn ack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offsets: &[u64],
+    ) -> Result<(), IggyError>;
+    /// Begin the transaction, within which the messages can be sent to multiple streams, topics and partitions, returning its unique ID.
+    /// The messages sent with the `iggy-transaction-id` header set to this ID become visible to the consumers only once the transaction is committed.
+    /// The transaction is bound to the current client, and it's aborted if the client disconnects before committing it.
+    ///
+    /// Authentication is required.
+    async fn begin_transaction(&self) -> Result<u64, IggyError>;
+    /// Commit the transaction by unique ID, making all the messages sent within it visible to the consumers at once.
+    ///
+    /// Authentication is required.
+    async fn commit_transaction(&self, transaction_id: u64) -> Result<(), IggyError>;
+    /// Abort the transaction by unique ID, so that none of the messages sent within it is ever visible to the consumers.
+    ///
+    /// Authentication is required.
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError>;
+    /// Send the consumer offset for a specific consumer or consumer group for the given stream and topic by unique IDs or names to the transaction by unique ID.
+    /// The offset is stored only once the transaction is committed, together with the messages sent within it, and it's discarded if the transaction is aborted.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn send_offsets_to_transaction(
+        &self,
+        transaction_id: u64,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offset: u64,
+    ) -> Result<(), IggyError>;
 }
 
 /// This trait defines the methods to interact with the consumer offset module.
@@ -423,6 +702,49 @@ pub trait ConsumerOffsetClient {
     ) -> Result<(), IggyError>;
 }
 
+/// This trait defines the methods to interact with the bookmark module.
+#[async_trait]
+pub trait BookmarkClient {
+    /// Get the bookmark by its name for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn get_bookmark(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<Option<Bookmark>, IggyError>;
+    /// Get all the bookmarks for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn get_bookmarks(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Bookmark>, IggyError>;
+    /// Create the bookmark with the unique name for the given stream and topic by unique IDs or names,
+    /// or move the existing one to the given partition and offset of the next message to be polled.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn store_bookmark(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<(), IggyError>;
+    /// Delete the bookmark by its name for the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn delete_bookmark(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<(), IggyError>;
+}
+
 /// This trait defines the methods to interact with the consumer group module.
 #[async_trait]
 pub trait ConsumerGroupClient {
@@ -453,6 +775,17 @@ pub trait ConsumerGroupClient {
         name: &str,
         group_id: Option<u32>,
     ) -> Result<ConsumerGroupDetails, IggyError>;
+    /// Create a new consumer group for the given stream and topic by unique IDs or names, using the provided partitions assignment strategy.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    async fn create_consumer_group_with_strategy(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+        assignment_strategy: AssignmentStrategy,
+    ) -> Result<ConsumerGroupDetails, IggyError>;
     /// Delete a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
     ///
     /// Authentication is required, and the permission to manage the streams or topics.
@@ -471,6 +804,17 @@ pub trait ConsumerGroupClient {
         topic_id: &Identifier,
         group_id: &Identifier,
     ) -> Result<(), IggyError>;
+    /// Join a consumer group by unique ID or name for the given stream and topic by unique IDs or names, requesting the provided partitions assignment strategy.
+    /// The strategy must match the one chosen when creating the group, otherwise the member can't join it.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    async fn join_consumer_group_with_strategy(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        assignment_strategy: AssignmentStrategy,
+    ) -> Result<(), IggyError>;
     /// Leave a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
     ///
     /// Authentication is required, and the permission to read the streams or topics.
@@ -480,8 +824,86 @@ pub trait ConsumerGroupClient {
         topic_id: &Identifier,
         group_id: &Identifier,
     ) -> Result<(), IggyError>;
+    /// Send a heartbeat to a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
+    /// The member is evicted from the group if no heartbeat (or poll) is received within the session timeout configured on the server.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    async fn heartbeat_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError>;
 }
 
+/// This trait defines the methods to interact with the schema registry module.
+#[async_trait]
+pub trait SchemaClient {
+    /// Get the registered schema by its globally unique ID, e.g. the one attached to the message headers.
+    ///
+    /// Authentication is required, and the permission to read the streams.
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError>;
+    /// Get all the registered versions of the schema for the given subject.
+    ///
+    /// Authentication is required, and the permission to read the streams.
+    async fn get_schemas(&self, subject: &str) -> Result<Vec<Schema>, IggyError>;
+    /// Get the specific version of the schema for the given subject, or the latest one if no version is provided.
+    ///
+    /// Authentication is required, and the permission to read the streams.
+    async fn get_subject_schema(
+        &self,
+        subject: &str,
+        version: Option<u32>,
+    ) -> Result<Option<Schema>, IggyError>;
+    /// Register a new version of the schema for the given subject.
+    /// The schema must be compatible with the latest version according to the subject compatibility level,
+    /// and if it's identical to the latest version, the existing schema is returned.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    async fn register_schema(
+        &self,
+        subject: &str,
+        schema: TopicSchema,
+    ) -> Result<Schema, IggyError>;
+    /// Update the compatibility level enforced when registering the new schema versions for the given subject.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    async fn update_schema_compatibility(
+        &self,
+        subject: &str,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError>;
+}
+
+/// This trait defines the methods to interact with the throughput quotas module.
+#[async_trait]
+pub trait QuotaClient {
+    /// Get the throughput quotas of the users and the topics which have been set or used since the server start, along with their usage.
+    ///
+    /// Authentication is required, and the permission to read the servers.
+    async fn get_quotas(&self) -> Result<Vec<Quota>, IggyError>;
+    /// Set the throughput quota of the user by unique ID or name, overriding the default one from the server configuration.
+    /// When the limits are not provided, the default quota is restored. The quota is not persisted, so it's restored on the server restart.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn set_user_quota(
+        &self,
+        user_id: &Identifier,
+        limits: Option<QuotaLimits>,
+    ) -> Result<(), IggyError>;
+    /// Set the throughput quota of the topic by unique ID or name, overriding the default one from the server configuration.
+    /// When the limits are not provided, the default quota is restored. The quota is not persisted, so it's restored on the server restart.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn set_topic_quota(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        limits: Option<QuotaLimits>,
+    ) -> Result<(), IggyError>;
+}
+
+#[cfg(feature = "tcp")]
 impl FromStr for ConnectionString {
     type Err = IggyError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -489,6 +911,7 @@ impl FromStr for ConnectionString {
     }
 }
 
+#[cfg(feature = "tcp")]
 impl ConnectionString {
     pub fn new(connection_string: &str) -> Result<Self, IggyError> {
         if connection_string.is_empty() {
@@ -567,6 +990,8 @@ impl ConnectionString {
         let mut reestablish_after = "5s".to_owned();
         let mut heartbeat_interval = "5s".to_owned();
         let mut nodelay = false;
+        let mut frame_checksums = false;
+        let mut zero_copy_polling = false;
 
         for option in options {
             let option_parts = option.split('=').collect::<Vec<&str>>();
@@ -598,6 +1023,12 @@ impl ConnectionString {
                 "nodelay" => {
                     nodelay = option_parts[1] == "true";
                 }
+                "frame_checksums" => {
+                    frame_checksums = option_parts[1] == "true";
+                }
+                "zero_copy_polling" => {
+                    zero_copy_polling = option_parts[1] == "true";
+                }
                 _ => {
                     return Err(IggyError::InvalidConnectionString);
                 }
@@ -625,10 +1056,13 @@ impl ConnectionString {
                     .map_err(|_| IggyError::InvalidConnectionString)?,
             },
             nodelay,
+            frame_checksums,
+            zero_copy_polling,
         })
     }
 }
 
+#[cfg(feature = "tcp")]
 #[derive(Debug)]
 struct ConnectionStringOptions {
     tls_enabled: bool,
@@ -637,8 +1071,11 @@ struct ConnectionStringOptions {
     reconnection: TcpClientReconnectionConfig,
     heartbeat_interval: IggyDuration,
     nodelay: bool,
+    frame_checksums: bool,
+    zero_copy_polling: bool,
 }
 
+#[cfg(feature = "tcp")]
 impl Default for ConnectionStringOptions {
     fn default() -> Self {
         ConnectionStringOptions {
@@ -648,10 +1085,13 @@ impl Default for ConnectionStringOptions {
             reconnection: Default::default(),
             heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
             nodelay: false,
+            frame_checksums: false,
+            zero_copy_polling: false,
         }
     }
 }
 
+#[cfg(feature = "tcp")]
 impl From<ConnectionString> for TcpClientConfig {
     fn from(connection_string: ConnectionString) -> Self {
         TcpClientConfig {
@@ -663,11 +1103,13 @@ impl From<ConnectionString> for TcpClientConfig {
             reconnection: connection_string.options.reconnection,
             heartbeat_interval: connection_string.options.heartbeat_interval,
             nodelay: connection_string.options.nodelay,
+            frame_checksums: connection_string.options.frame_checksums,
+            zero_copy_polling: connection_string.options.zero_copy_polling,
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "tcp"))]
 mod tests {
     use super::*;
 
@@ -728,6 +1170,8 @@ mod tests {
             IggyDuration::from_str("1s").unwrap()
         );
         assert!(!connection_string.options.nodelay);
+        assert!(!connection_string.options.frame_checksums);
+        assert!(!connection_string.options.zero_copy_polling);
     }
 
     #[test]
@@ -743,7 +1187,9 @@ mod tests {
         let reestablish_after = "10s";
         let heartbeat_interval = "3s";
         let nodelay = true;
-        let value = format!("{CONNECTION_STRING_PREFIX}{username}:{password}@{server_address}?tls={tls}&tls_domain={tls_domain}&tls_ca_file={tls_ca_file}&reconnection_retries={reconnection_retries}&reconnection_interval={reconnection_interval}&reestablish_after={reestablish_after}&heartbeat_interval={heartbeat_interval}&nodelay={nodelay}");
+        let frame_checksums = true;
+        let zero_copy_polling = true;
+        let value = format!("{CONNECTION_STRING_PREFIX}{username}:{password}@{server_address}?tls={tls}&tls_domain={tls_domain}&tls_ca_file={tls_ca_file}&reconnection_retries={reconnection_retries}&reconnection_interval={reconnection_interval}&reestablish_after={reestablish_after}&heartbeat_interval={heartbeat_interval}&nodelay={nodelay}&frame_checksums={frame_checksums}&zero_copy_polling={zero_copy_polling}");
         let connection_string = ConnectionString::new(&value);
         assert!(connection_string.is_ok());
         let connection_string = connection_string.unwrap();
@@ -780,5 +1226,10 @@ mod tests {
             IggyDuration::from_str(heartbeat_interval).unwrap()
         );
         assert_eq!(connection_string.options.nodelay, nodelay);
+        assert_eq!(connection_string.options.frame_checksums, frame_checksums);
+        assert_eq!(
+            connection_string.options.zero_copy_polling,
+            zero_copy_polling
+        );
     }
 }
@@ -16,21 +16,35 @@
  * under the License.
  */
 
-use crate::client::{AutoLogin, Client, Credentials};
+use crate::client::Client;
+#[cfg(any(feature = "tcp", feature = "quic"))]
+use crate::client::{AutoLogin, Credentials};
 use crate::client_error::ClientError;
 #[allow(deprecated)]
 use crate::clients::client::IggyClient;
+use crate::error::IggyError;
+#[cfg(feature = "http")]
 use crate::http::client::HttpClient;
+#[cfg(feature = "http")]
 use crate::http::config::HttpClientConfig;
+#[cfg(feature = "quic")]
 use crate::quic::client::QuicClient;
+#[cfg(feature = "quic")]
 use crate::quic::config::{QuicClientConfig, QuicClientReconnectionConfig};
+#[cfg(feature = "tcp")]
 use crate::tcp::client::TcpClient;
+#[cfg(feature = "tcp")]
 use crate::tcp::config::{TcpClientConfig, TcpClientReconnectionConfig};
+#[cfg(any(feature = "tcp", feature = "quic"))]
 use crate::utils::duration::IggyDuration;
+#[cfg(any(feature = "tcp", feature = "quic"))]
 use std::str::FromStr;
 use std::sync::Arc;
+use tracing::warn;
 
+#[cfg(feature = "quic")]
 const QUIC_TRANSPORT: &str = "quic";
+#[cfg(feature = "http")]
 const HTTP_TRANSPORT: &str = "http";
 const TCP_TRANSPORT: &str = "tcp";
 
@@ -40,25 +54,43 @@ const TCP_TRANSPORT: &str = "tcp";
 /// - `http`: the optional configuration for the HTTP transport.
 /// - `quic`: the optional configuration for the QUIC transport.
 /// - `tcp`: the optional configuration for the TCP transport.
+/// - `endpoints`: the addresses of the other servers (e.g. the cluster nodes) tried in order, if the configured one is unavailable.
+/// - `cluster_routing`: whether to route the sent and polled messages to the leaders of the partitions (TCP transport only).
+///
+/// The transport specific fields are only available when the matching cargo feature is enabled.
 #[derive(Debug)]
 pub struct ClientProviderConfig {
     /// The transport to use. Valid values are `quic`, `http` and `tcp`.
     pub transport: String,
     /// The optional configuration for the HTTP transport.
+    #[cfg(feature = "http")]
     pub http: Option<Arc<HttpClientConfig>>,
     /// The optional configuration for the QUIC transport.
+    #[cfg(feature = "quic")]
     pub quic: Option<Arc<QuicClientConfig>>,
     /// The optional configuration for the TCP transport.
+    #[cfg(feature = "tcp")]
     pub tcp: Option<Arc<TcpClientConfig>>,
+    /// The addresses of the other servers (e.g. the cluster nodes) tried in order, if the configured one is unavailable.
+    /// These are the server addresses for TCP and QUIC, or the API URLs for HTTP.
+    pub endpoints: Vec<String>,
+    /// Whether to route the sent and polled messages to the leaders of the partitions, when the server runs in the cluster mode.
+    /// Only the TCP transport is supported, as the nodes advertise their TCP server addresses.
+    pub cluster_routing: bool,
 }
 
 impl Default for ClientProviderConfig {
     fn default() -> ClientProviderConfig {
         ClientProviderConfig {
             transport: TCP_TRANSPORT.to_string(),
+            #[cfg(feature = "http")]
             http: Some(Arc::new(HttpClientConfig::default())),
+            #[cfg(feature = "quic")]
             quic: Some(Arc::new(QuicClientConfig::default())),
+            #[cfg(feature = "tcp")]
             tcp: Some(Arc::new(TcpClientConfig::default())),
+            endpoints: Vec::new(),
+            cluster_routing: false,
         }
     }
 }
@@ -71,6 +103,10 @@ impl ClientProviderConfig {
 
     /// Create a new `ClientProviderConfig` from the provided `Args` with possibility to enable or disable
     /// auto login option for TCP or QUIC protocols.
+    #[cfg_attr(
+        not(any(feature = "tcp", feature = "quic")),
+        allow(unused_variables, unused_mut)
+    )]
     pub fn from_args_set_autologin(
         args: crate::args::Args,
         auto_login: bool,
@@ -78,11 +114,17 @@ impl ClientProviderConfig {
         let transport = args.transport;
         let mut config = Self {
             transport,
+            #[cfg(feature = "http")]
             http: None,
+            #[cfg(feature = "quic")]
             quic: None,
+            #[cfg(feature = "tcp")]
             tcp: None,
+            endpoints: Vec::new(),
+            cluster_routing: false,
         };
         match config.transport.as_str() {
+            #[cfg(feature = "quic")]
             QUIC_TRANSPORT => {
                 config.quic = Some(Arc::new(QuicClientConfig {
                     client_address: args.quic_client_address,
@@ -118,12 +160,14 @@ impl ClientProviderConfig {
                     validate_certificate: args.quic_validate_certificate,
                 }));
             }
+            #[cfg(feature = "http")]
             HTTP_TRANSPORT => {
                 config.http = Some(Arc::new(HttpClientConfig {
                     api_url: args.http_api_url,
                     retries: args.http_retries,
                 }));
             }
+            #[cfg(feature = "tcp")]
             TCP_TRANSPORT => {
                 config.tcp = Some(Arc::new(TcpClientConfig {
                     server_address: args.tcp_server_address,
@@ -131,6 +175,8 @@ impl ClientProviderConfig {
                     tls_domain: args.tcp_tls_domain,
                     tls_ca_file: args.tcp_tls_ca_file,
                     nodelay: args.tcp_nodelay,
+                    frame_checksums: args.tcp_frame_checksums,
+                    zero_copy_polling: args.tcp_zero_copy_polling,
                     heartbeat_interval: IggyDuration::from_str(&args.tcp_heartbeat_interval)
                         .unwrap(),
                     reconnection: TcpClientReconnectionConfig {
@@ -165,9 +211,27 @@ pub async fn get_default_client_() -> Result<IggyClient, ClientError> {
 }
 
 /// Create a `IggyClient` for the specific transport based on the provided configuration.
+/// If the cluster routing is enabled, the clients for the other cluster nodes are created using the same configuration.
 pub async fn get_client(config: Arc<ClientProviderConfig>) -> Result<IggyClient, ClientError> {
-    let client = get_raw_connected_client(config).await?;
-    Ok(IggyClient::builder().with_client(client).build()?)
+    let client = get_raw_connected_client(config.clone()).await?;
+    let mut builder = IggyClient::builder().with_client(client);
+    if config.cluster_routing {
+        if config.transport == TCP_TRANSPORT {
+            let node_config = config.clone();
+            builder = builder.with_cluster_routing(Arc::new(move |address| {
+                create_raw_client(&node_config, Some(address)).map_err(|error| match error {
+                    ClientError::SdkError(error) => error,
+                    _ => IggyError::InvalidConfiguration,
+                })
+            }));
+        } else {
+            warn!(
+                "Cluster routing is supported only by the TCP transport, not: {}.",
+                config.transport
+            );
+        }
+    }
+    Ok(builder.build()?)
 }
 
 /// Create a `Client` for the specific transport based on the provided configuration.
@@ -178,33 +242,61 @@ pub async fn get_raw_connected_client(
 }
 
 /// Create a `Client` for the specific transport based on the provided configuration.
+/// When establishing the connection fails, the configured endpoints are tried in order, and the last error is returned.
+/// Returns `ClientError::InvalidTransport` if the transport is unknown or its feature is disabled.
 pub async fn get_raw_client(
     config: Arc<ClientProviderConfig>,
     establish_connection: bool,
 ) -> Result<Box<dyn Client>, ClientError> {
-    let transport = config.transport.clone();
-    match transport.as_str() {
+    let client = create_raw_client(&config, None)?;
+    if !establish_connection {
+        return Ok(client);
+    }
+
+    let mut result = client.connect().await.map(|_| client);
+    for endpoint in &config.endpoints {
+        let Err(error) = &result else {
+            break;
+        };
+
+        warn!("Unable to connect to the server, trying the next endpoint: {endpoint}. {error}");
+        let client = create_raw_client(&config, Some(endpoint))?;
+        result = client.connect().await.map(|_| client);
+    }
+    Ok(result?)
+}
+
+/// Create a not connected `Client` for the specific transport, optionally for the other server address than the configured one.
+#[cfg_attr(not(any(feature = "tcp", feature = "quic")), allow(unused_variables))]
+fn create_raw_client(
+    config: &ClientProviderConfig,
+    address: Option<&str>,
+) -> Result<Box<dyn Client>, ClientError> {
+    match config.transport.as_str() {
+        #[cfg(feature = "quic")]
         QUIC_TRANSPORT => {
-            let quic_config = config.quic.as_ref().unwrap();
-            let client = QuicClient::create(quic_config.clone())?;
-            if establish_connection {
-                Client::connect(&client).await?
-            };
-            Ok(Box::new(client))
+            let mut quic_config = config.quic.as_ref().unwrap().as_ref().clone();
+            if let Some(address) = address {
+                quic_config.server_address = address.to_owned();
+            }
+            Ok(Box::new(QuicClient::create(Arc::new(quic_config))?))
         }
+        #[cfg(feature = "http")]
         HTTP_TRANSPORT => {
-            let http_config = config.http.as_ref().unwrap();
-            let client = HttpClient::create(http_config.clone())?;
-            Ok(Box::new(client))
+            let mut http_config = config.http.as_ref().unwrap().as_ref().clone();
+            if let Some(address) = address {
+                http_config.api_url = address.to_owned();
+            }
+            Ok(Box::new(HttpClient::create(Arc::new(http_config))?))
         }
+        #[cfg(feature = "tcp")]
         TCP_TRANSPORT => {
-            let tcp_config = config.tcp.as_ref().unwrap();
-            let client = TcpClient::create(tcp_config.clone())?;
-            if establish_connection {
-                Client::connect(&client).await?
-            };
-            Ok(Box::new(client))
+            let mut tcp_config = config.tcp.as_ref().unwrap().as_ref().clone();
+            if let Some(address) = address {
+                tcp_config.server_address = address.to_owned();
+            }
+            Ok(Box::new(TcpClient::create(Arc::new(tcp_config))?))
         }
-        _ => Err(ClientError::InvalidTransport(transport)),
+        _ => Err(ClientError::InvalidTransport(config.transport.clone())),
     }
 }
@@ -16,17 +16,27 @@
  * under the License.
  */
 
-use crate::client::{AutoLogin, Client};
+#[cfg(any(feature = "tcp", feature = "quic"))]
+use crate::client::AutoLogin;
+use crate::client::Client;
 use crate::clients::client::IggyClient;
+use crate::clients::cluster_router::{ClusterRouter, NodeClientFactory};
 use crate::error::IggyError;
+#[cfg(feature = "http")]
 use crate::http::client::HttpClient;
+#[cfg(feature = "http")]
 use crate::http::config::HttpClientConfigBuilder;
 use crate::partitioner::Partitioner;
+#[cfg(feature = "quic")]
 use crate::quic::client::QuicClient;
+#[cfg(feature = "quic")]
 use crate::quic::config::QuicClientConfigBuilder;
+#[cfg(feature = "tcp")]
 use crate::tcp::client::TcpClient;
+#[cfg(feature = "tcp")]
 use crate::tcp::config::TcpClientConfigBuilder;
 use crate::utils::crypto::EncryptorKind;
+#[cfg(any(feature = "tcp", feature = "quic"))]
 use crate::utils::duration::IggyDuration;
 use std::sync::Arc;
 use tracing::error;
@@ -37,6 +47,7 @@ pub struct IggyClientBuilder {
     client: Option<Box<dyn Client>>,
     partitioner: Option<Arc<dyn Partitioner>>,
     encryptor: Option<Arc<EncryptorKind>>,
+    cluster_router: Option<Arc<ClusterRouter>>,
 }
 
 impl IggyClientBuilder {
@@ -46,6 +57,7 @@ impl IggyClientBuilder {
         IggyClientBuilder::default()
     }
 
+    #[cfg(feature = "tcp")]
     pub fn from_connection_string(connection_string: &str) -> Result<Self, IggyError> {
         let mut builder = Self::new();
         builder.client = Some(Box::new(TcpClient::from_connection_string(
@@ -72,9 +84,17 @@ impl IggyClientBuilder {
         self
     }
 
+    /// Route the sent and polled messages to the leaders of the partitions, when the server runs in the cluster mode.
+    /// The factory creates the clients for the other nodes of the cluster, using the addresses from the cluster metadata.
+    pub fn with_cluster_routing(mut self, factory: NodeClientFactory) -> Self {
+        self.cluster_router = Some(Arc::new(ClusterRouter::new(factory)));
+        self
+    }
+
     /// This method provides fluent API for the TCP client configuration.
     /// It returns the `TcpClientBuilder` instance, which allows to configure the TCP client with custom settings or using defaults.
     /// This should be called after the non-protocol specific methods, such as `with_partitioner`, `with_encryptor` or `with_message_handler`.
+    #[cfg(feature = "tcp")]
     pub fn with_tcp(self) -> TcpClientBuilder {
         TcpClientBuilder {
             config: TcpClientConfigBuilder::default(),
@@ -85,6 +105,7 @@ impl IggyClientBuilder {
     /// This method provides fluent API for the QUIC client configuration.
     /// It returns the `QuicClientBuilder` instance, which allows to configure the QUIC client with custom settings or using defaults.
     /// This should be called after the non-protocol specific methods, such as `with_partitioner`, `with_encryptor` or `with_message_handler`.
+    #[cfg(feature = "quic")]
     pub fn with_quic(self) -> QuicClientBuilder {
         QuicClientBuilder {
             config: QuicClientConfigBuilder::default(),
@@ -95,6 +116,7 @@ impl IggyClientBuilder {
     /// This method provides fluent API for the HTTP client configuration.
     /// It returns the `HttpClientBuilder` instance, which allows to configure the HTTP client with custom settings or using defaults.
     /// This should be called after the non-protocol specific methods, such as `with_partitioner`, `with_encryptor` or `with_message_handler`.
+    #[cfg(feature = "http")]
     pub fn with_http(self) -> HttpClientBuilder {
         HttpClientBuilder {
             config: HttpClientConfigBuilder::default(),
@@ -112,16 +134,22 @@ impl IggyClientBuilder {
             return Err(IggyError::InvalidConfiguration);
         };
 
-        Ok(IggyClient::create(client, self.partitioner, self.encryptor))
+        let client = IggyClient::create(client, self.partitioner, self.encryptor);
+        match self.cluster_router {
+            Some(router) => Ok(client.with_cluster_router(router)),
+            None => Ok(client),
+        }
     }
 }
 
+#[cfg(feature = "tcp")]
 #[derive(Debug, Default)]
 pub struct TcpClientBuilder {
     config: TcpClientConfigBuilder,
     parent_builder: IggyClientBuilder,
 }
 
+#[cfg(feature = "tcp")]
 impl TcpClientBuilder {
     /// Sets the server address for the TCP client.
     pub fn with_server_address(mut self, server_address: String) -> Self {
@@ -175,6 +203,18 @@ impl TcpClientBuilder {
         self
     }
 
+    /// Enables the negotiation of the frame checksums with the server.
+    pub fn with_frame_checksums(mut self) -> Self {
+        self.config = self.config.with_frame_checksums();
+        self
+    }
+
+    /// Enables the negotiation of the zero-copy polling with the server.
+    pub fn with_zero_copy_polling(mut self) -> Self {
+        self.config = self.config.with_zero_copy_polling();
+        self
+    }
+
     /// Builds the parent `IggyClient` with TCP configuration.
     pub fn build(self) -> Result<IggyClient, IggyError> {
         let client = TcpClient::create(Arc::new(self.config.build()))?;
@@ -183,12 +223,14 @@ impl TcpClientBuilder {
     }
 }
 
+#[cfg(feature = "quic")]
 #[derive(Debug, Default)]
 pub struct QuicClientBuilder {
     config: QuicClientConfigBuilder,
     parent_builder: IggyClientBuilder,
 }
 
+#[cfg(feature = "quic")]
 impl QuicClientBuilder {
     /// Sets the server address for the QUIC client.
     pub fn with_server_address(mut self, server_address: String) -> Self {
@@ -232,12 +274,14 @@ impl QuicClientBuilder {
     }
 }
 
+#[cfg(feature = "http")]
 #[derive(Debug, Default)]
 pub struct HttpClientBuilder {
     config: HttpClientConfigBuilder,
     parent_builder: IggyClientBuilder,
 }
 
+#[cfg(feature = "http")]
 impl HttpClientBuilder {
     /// Sets the server address for the HTTP client.
     pub fn with_api_url(mut self, api_url: String) -> Self {
@@ -17,14 +17,19 @@
  */
 
 use crate::client::{
-    Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, SegmentClient, StreamClient, SystemClient, TopicClient, UserClient,
+    BookmarkClient, Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient,
+    PartitionClient, PersonalAccessTokenClient, QuotaClient, SchemaClient, SegmentClient,
+    StreamClient, SystemClient, TopicClient, UserClient,
 };
 use crate::clients::builder::IggyClientBuilder;
+use crate::clients::cluster_router::{ClusterRouter, MAX_CLUSTER_REDIRECTS};
 use crate::clients::consumer::IggyConsumerBuilder;
+use crate::clients::multi_topic_consumer::IggyMultiTopicConsumerBuilder;
 use crate::clients::producer::IggyProducerBuilder;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::confirmation::ConfirmationLevel;
 use crate::consumer::Consumer;
+use crate::consumer_groups::assignment_strategy::AssignmentStrategy;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
@@ -32,21 +37,38 @@ use crate::locking::IggySharedMut;
 use crate::locking::IggySharedMutFn;
 use crate::messages::poll_messages::PollingStrategy;
 use crate::messages::send_messages::{Message, Partitioning};
+use crate::messages::AutoCommitMode;
+use crate::models::audit_entry::AuditEntry;
+use crate::models::backup::{BackupDestination, BackupInfo};
+use crate::models::bookmark::Bookmark;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::cluster::ClusterMetadata;
+use crate::models::config_value::ConfigValue;
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupRebalance};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::PolledMessages;
+use crate::models::partition::PartitionDetails;
 use crate::models::permissions::Permissions;
-use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::personal_access_token::{
+    PersonalAccessTokenInfo, PersonalAccessTokenScope, RawPersonalAccessToken,
+};
+use crate::models::quota::{Quota, QuotaLimits};
+use crate::models::schema::Schema;
+use crate::models::segments_verification::SegmentsVerification;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
-use crate::models::user_info::{UserInfo, UserInfoDetails};
+use crate::models::topic_options::TopicOptions;
+use crate::models::topic_schema::TopicSchema;
+use crate::models::user_info::{UserId, UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
 use crate::partitioner::Partitioner;
+use crate::partitions::key_routing_policy::KeyRoutingPolicy;
+use crate::schemas::schema_compatibility::SchemaCompatibility;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+#[cfg(feature = "tcp")]
 use crate::tcp::client::TcpClient;
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::crypto::EncryptorKind;
@@ -58,6 +80,7 @@ use async_broadcast::Receiver;
 use async_dropper::AsyncDrop;
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures_util::StreamExt;
 use std::fmt::Debug;
 use std::sync::Arc;
 use tokio::spawn;
@@ -74,8 +97,10 @@ pub struct IggyClient {
     client: IggySharedMut<Box<dyn Client>>,
     partitioner: Option<Arc<dyn Partitioner>>,
     encryptor: Option<Arc<EncryptorKind>>,
+    cluster: Option<Arc<ClusterRouter>>,
 }
 
+#[cfg(feature = "tcp")]
 impl Default for IggyClient {
     fn default() -> Self {
         IggyClient::new(Box::<TcpClient>::default())
@@ -89,6 +114,7 @@ impl IggyClient {
     }
 
     /// Creates a new `IggyClientBuilder`.
+    #[cfg(feature = "tcp")]
     pub fn builder_from_connection_string(
         connection_string: &str,
     ) -> Result<IggyClientBuilder, IggyError> {
@@ -102,9 +128,11 @@ impl IggyClient {
             client,
             partitioner: None,
             encryptor: None,
+            cluster: None,
         }
     }
 
+    #[cfg(feature = "tcp")]
     pub fn from_connection_string(connection_string: &str) -> Result<Self, IggyError> {
         let client = Box::new(TcpClient::from_connection_string(connection_string)?);
         Ok(IggyClient::new(client))
@@ -128,6 +156,16 @@ impl IggyClient {
             client,
             partitioner,
             encryptor,
+            cluster: None,
+        }
+    }
+
+    /// Routes the sent and polled messages to the leaders of the partitions, when the server runs in the cluster mode.
+    pub(crate) fn with_cluster_router(self, router: Arc<ClusterRouter>) -> Self {
+        info!("Cluster-aware routing is enabled.");
+        IggyClient {
+            cluster: Some(router),
+            ..self
         }
     }
 
@@ -136,6 +174,28 @@ impl IggyClient {
         self.client.clone()
     }
 
+    /// Invokes the provided callback for every connection lifecycle event (connected, disconnected, reconnecting, signed in or out, shutdown) published by the underlying client.
+    /// The callback is invoked in the background task, which stops once the client has been shut down.
+    ///
+    /// Might be useful to update the application health checks or pause the producers, without relying on the returned errors.
+    pub async fn on_connection_event<F>(&self, callback: F)
+    where
+        F: Fn(DiagnosticEvent) + Send + Sync + 'static,
+    {
+        let mut receiver = self.subscribe_events().await;
+        spawn(async move {
+            while let Some(event) = receiver.next().await {
+                callback(event);
+                if event == DiagnosticEvent::Shutdown {
+                    debug!(
+                        "The client has been shut down - stopping the connection events handler."
+                    );
+                    return;
+                }
+            }
+        });
+    }
+
     /// Returns the builder for the standalone consumer.
     pub fn consumer(
         &self,
@@ -175,6 +235,26 @@ impl IggyClient {
         ))
     }
 
+    /// Returns the builder for the consumer merging the messages of many topics into a single stream,
+    /// with each topic consumed by the same consumer group. More sources can be added using `source()`.
+    pub fn multi_topic_consumer_group(
+        &self,
+        name: &str,
+        topics: &[(&str, &str)],
+    ) -> Result<IggyMultiTopicConsumerBuilder, IggyError> {
+        let consumers = topics
+            .iter()
+            .map(|(stream, topic)| self.consumer_group(name, stream, topic))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(IggyMultiTopicConsumerBuilder::new(consumers))
+    }
+
+    /// Returns the builder for the consumer merging the messages of many topics into a single stream,
+    /// with the sources added using `source()`.
+    pub fn multi_topic_consumer(&self) -> IggyMultiTopicConsumerBuilder {
+        IggyMultiTopicConsumerBuilder::new(Vec::new())
+    }
+
     /// Returns the builder for the producer.
     pub fn producer(&self, stream: &str, topic: &str) -> Result<IggyProducerBuilder, IggyError> {
         Ok(IggyProducerBuilder::new(
@@ -229,6 +309,16 @@ impl Client for IggyClient {
     async fn subscribe_events(&self) -> Receiver<DiagnosticEvent> {
         self.client.read().await.subscribe_events().await
     }
+
+    async fn subscribe_consumer_group_rebalances(
+        &self,
+    ) -> Result<Receiver<ConsumerGroupRebalance>, IggyError> {
+        self.client
+            .read()
+            .await
+            .subscribe_consumer_group_rebalances()
+            .await
+    }
 }
 
 #[async_trait]
@@ -328,6 +418,20 @@ impl PersonalAccessTokenClient for IggyClient {
             .await
     }
 
+    async fn create_personal_access_token_with_restrictions(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+        scope: Option<PersonalAccessTokenScope>,
+        allowed_addresses: Option<Vec<String>>,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_personal_access_token_with_restrictions(name, expiry, scope, allowed_addresses)
+            .await
+    }
+
     async fn delete_personal_access_token(&self, name: &str) -> Result<(), IggyError> {
         self.client
             .read()
@@ -366,6 +470,27 @@ impl SystemClient for IggyClient {
         self.client.read().await.get_clients().await
     }
 
+    async fn get_config(&self) -> Result<Vec<ConfigValue>, IggyError> {
+        self.client.read().await.get_config().await
+    }
+
+    async fn get_audit_log(
+        &self,
+        user_id: Option<UserId>,
+        action: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<AuditEntry>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_audit_log(user_id, action, limit)
+            .await
+    }
+
+    async fn get_cluster_metadata(&self) -> Result<ClusterMetadata, IggyError> {
+        self.client.read().await.get_cluster_metadata().await
+    }
+
     async fn ping(&self) -> Result<(), IggyError> {
         self.client.read().await.ping().await
     }
@@ -385,6 +510,28 @@ impl SystemClient for IggyClient {
             .snapshot(compression, snapshot_types)
             .await
     }
+
+    async fn create_backup(
+        &self,
+        name: &str,
+        streams: &[Identifier],
+        destination: BackupDestination,
+        base: Option<&str>,
+    ) -> Result<BackupInfo, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_backup(name, streams, destination, base)
+            .await
+    }
+
+    async fn restore_backup(
+        &self,
+        name: &str,
+        streams: &[Identifier],
+    ) -> Result<BackupInfo, IggyError> {
+        self.client.read().await.restore_backup(name, streams).await
+    }
 }
 
 #[async_trait]
@@ -471,6 +618,35 @@ impl TopicClient for IggyClient {
             .await
     }
 
+    async fn create_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: TopicOptions,
+    ) -> Result<TopicDetails, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_topic_with_options(
+                stream_id,
+                name,
+                partitions_count,
+                compression_algorithm,
+                replication_factor,
+                topic_id,
+                message_expiry,
+                max_topic_size,
+                options,
+            )
+            .await
+    }
+
     async fn update_topic(
         &self,
         stream_id: &Identifier,
@@ -496,6 +672,33 @@ impl TopicClient for IggyClient {
             .await
     }
 
+    async fn update_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: TopicOptions,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_topic_with_options(
+                stream_id,
+                topic_id,
+                name,
+                compression_algorithm,
+                replication_factor,
+                message_expiry,
+                max_topic_size,
+                options,
+            )
+            .await
+    }
+
     async fn delete_topic(
         &self,
         stream_id: &Identifier,
@@ -508,6 +711,18 @@ impl TopicClient for IggyClient {
             .await
     }
 
+    async fn delete_topic_deferred(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .delete_topic_deferred(stream_id, topic_id)
+            .await
+    }
+
     async fn purge_topic(
         &self,
         stream_id: &Identifier,
@@ -519,20 +734,48 @@ impl TopicClient for IggyClient {
             .purge_topic(stream_id, topic_id)
             .await
     }
+
+    async fn set_topic_throttle(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        max_throughput: Option<IggyByteSize>,
+        duration: Option<IggyDuration>,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .set_topic_throttle(stream_id, topic_id, max_throughput, duration)
+            .await
+    }
 }
 
 #[async_trait]
 impl PartitionClient for IggyClient {
+    async fn get_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+    ) -> Result<Option<PartitionDetails>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_partition(stream_id, topic_id, partition_id)
+            .await
+    }
+
     async fn create_partitions(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitions_count: u32,
+        key_routing_policy: KeyRoutingPolicy,
     ) -> Result<(), IggyError> {
         self.client
             .read()
             .await
-            .create_partitions(stream_id, topic_id, partitions_count)
+            .create_partitions(stream_id, topic_id, partitions_count, key_routing_policy)
             .await
     }
 
@@ -548,6 +791,34 @@ impl PartitionClient for IggyClient {
             .delete_partitions(stream_id, topic_id, partitions_count)
             .await
     }
+
+    async fn update_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+        name: Option<&str>,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_partition(stream_id, topic_id, partition_id, name)
+            .await
+    }
+
+    async fn move_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+        directory: &str,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .move_partition(stream_id, topic_id, partition_id, directory)
+            .await
+    }
 }
 
 #[async_trait]
@@ -565,6 +836,19 @@ impl SegmentClient for IggyClient {
             .delete_segments(stream_id, topic_id, partition_id, segments_count)
             .await
     }
+
+    async fn verify_segments(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<SegmentsVerification, IggyError> {
+        self.client
+            .read()
+            .await
+            .verify_segments(stream_id, topic_id, partition_id)
+            .await
+    }
 }
 
 #[async_trait]
@@ -578,25 +862,95 @@ impl MessageClient for IggyClient {
         strategy: &PollingStrategy,
         count: u32,
         auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        self.poll_messages_with_commit_mode(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            AutoCommitMode::from(auto_commit),
+        )
+        .await
+    }
+
+    async fn poll_messages_with_commit_mode(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: AutoCommitMode,
     ) -> Result<PolledMessages, IggyError> {
         if count == 0 {
             return Err(IggyError::InvalidMessagesCount);
         }
 
-        let mut polled_messages = self
-            .client
-            .read()
-            .await
-            .poll_messages(
-                stream_id,
-                topic_id,
-                partition_id,
-                consumer,
-                strategy,
-                count,
-                auto_commit,
-            )
-            .await?;
+        let mut polled_messages = match &self.cluster {
+            Some(cluster) => {
+                let mut node = cluster
+                    .get_partition_client(&self.client, stream_id, topic_id, partition_id)
+                    .await;
+                let mut redirects = 0;
+                loop {
+                    let result = match &node {
+                        Some(client) => {
+                            client
+                                .poll_messages_with_commit_mode(
+                                    stream_id,
+                                    topic_id,
+                                    partition_id,
+                                    consumer,
+                                    strategy,
+                                    count,
+                                    auto_commit,
+                                )
+                                .await
+                        }
+                        None => {
+                            self.client
+                                .read()
+                                .await
+                                .poll_messages_with_commit_mode(
+                                    stream_id,
+                                    topic_id,
+                                    partition_id,
+                                    consumer,
+                                    strategy,
+                                    count,
+                                    auto_commit,
+                                )
+                                .await
+                        }
+                    };
+                    match result {
+                        Err(error) if redirects < MAX_CLUSTER_REDIRECTS => {
+                            node = cluster.redirect(&self.client, error).await?;
+                            redirects += 1;
+                        }
+                        result => break result?,
+                    }
+                }
+            }
+            None => {
+                self.client
+                    .read()
+                    .await
+                    .poll_messages_with_commit_mode(
+                        stream_id,
+                        topic_id,
+                        partition_id,
+                        consumer,
+                        strategy,
+                        count,
+                        auto_commit,
+                    )
+                    .await?
+            }
+        };
 
         if let Some(ref encryptor) = self.encryptor {
             for message in &mut polled_messages.messages {
@@ -615,6 +969,24 @@ impl MessageClient for IggyClient {
         topic_id: &Identifier,
         partitioning: &Partitioning,
         messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        self.send_messages_with_confirmation(
+            stream_id,
+            topic_id,
+            partitioning,
+            messages,
+            ConfirmationLevel::default(),
+        )
+        .await
+    }
+
+    async fn send_messages_with_confirmation(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+        confirmation: ConfirmationLevel,
     ) -> Result<(), IggyError> {
         if messages.is_empty() {
             return Err(IggyError::InvalidMessagesCount);
@@ -627,24 +999,173 @@ impl MessageClient for IggyClient {
             }
         }
 
+        let Some(cluster) = &self.cluster else {
+            return self
+                .client
+                .read()
+                .await
+                .send_messages_with_confirmation(
+                    stream_id,
+                    topic_id,
+                    partitioning,
+                    messages,
+                    confirmation,
+                )
+                .await;
+        };
+
+        let partitioning = cluster
+            .resolve_partitioning(&self.client, stream_id, topic_id, partitioning)
+            .await;
+        let partition_id = ClusterRouter::get_partition_id(&partitioning);
+        let mut node = cluster
+            .get_partition_client(&self.client, stream_id, topic_id, partition_id)
+            .await;
+        let mut redirects = 0;
+        loop {
+            let result = match &node {
+                Some(client) => {
+                    client
+                        .send_messages_with_confirmation(
+                            stream_id,
+                            topic_id,
+                            &partitioning,
+                            messages,
+                            confirmation,
+                        )
+                        .await
+                }
+                None => {
+                    self.client
+                        .read()
+                        .await
+                        .send_messages_with_confirmation(
+                            stream_id,
+                            topic_id,
+                            &partitioning,
+                            messages,
+                            confirmation,
+                        )
+                        .await
+                }
+            };
+            match result {
+                Err(error) if redirects < MAX_CLUSTER_REDIRECTS => {
+                    node = cluster.redirect(&self.client, error).await?;
+                    redirects += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn flush_unsaved_buffer(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        fsync: bool,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .flush_unsaved_buffer(stream_id, topic_id, partition_id, fsync)
+            .await
+    }
+
+    async fn reject_messages(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        reason: &str,
+    ) -> Result<(), IggyError> {
         self.client
             .read()
             .await
-            .send_messages(stream_id, topic_id, partitioning, messages)
+            .reject_messages(consumer, stream_id, topic_id, partition_id, offsets, reason)
             .await
     }
 
-    async fn flush_unsaved_buffer(
+    async fn nack_messages(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        redelivery_delay: Option<IggyDuration>,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .nack_messages(
+                consumer,
+                stream_id,
+                topic_id,
+                partition_id,
+                offsets,
+                redelivery_delay,
+            )
+            .await
+    }
+
+    async fn ack_messages(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partition_id: u32,
-        fsync: bool,
+        offsets: &[u64],
     ) -> Result<(), IggyError> {
         self.client
             .read()
             .await
-            .flush_unsaved_buffer(stream_id, topic_id, partition_id, fsync)
+            .ack_messages(stream_id, topic_id, partition_id, offsets)
+            .await
+    }
+
+    async fn begin_transaction(&self) -> Result<u64, IggyError> {
+        self.client.read().await.begin_transaction().await
+    }
+
+    async fn commit_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .commit_transaction(transaction_id)
+            .await
+    }
+
+    async fn abort_transaction(&self, transaction_id: u64) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .abort_transaction(transaction_id)
+            .await
+    }
+
+    async fn send_offsets_to_transaction(
+        &self,
+        transaction_id: u64,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .send_offsets_to_transaction(
+                transaction_id,
+                consumer,
+                stream_id,
+                topic_id,
+                partition_id,
+                offset,
+            )
             .await
     }
 }
@@ -695,6 +1216,62 @@ impl ConsumerOffsetClient for IggyClient {
     }
 }
 
+#[async_trait]
+impl BookmarkClient for IggyClient {
+    async fn get_bookmark(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<Option<Bookmark>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_bookmark(stream_id, topic_id, name)
+            .await
+    }
+
+    async fn get_bookmarks(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Bookmark>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_bookmarks(stream_id, topic_id)
+            .await
+    }
+
+    async fn store_bookmark(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .store_bookmark(stream_id, topic_id, name, partition_id, offset)
+            .await
+    }
+
+    async fn delete_bookmark(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .delete_bookmark(stream_id, topic_id, name)
+            .await
+    }
+}
+
 #[async_trait]
 impl ConsumerGroupClient for IggyClient {
     async fn get_consumer_group(
@@ -736,6 +1313,27 @@ impl ConsumerGroupClient for IggyClient {
             .await
     }
 
+    async fn create_consumer_group_with_strategy(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+        assignment_strategy: AssignmentStrategy,
+    ) -> Result<ConsumerGroupDetails, IggyError> {
+        self.client
+            .read()
+            .await
+            .create_consumer_group_with_strategy(
+                stream_id,
+                topic_id,
+                name,
+                group_id,
+                assignment_strategy,
+            )
+            .await
+    }
+
     async fn delete_consumer_group(
         &self,
         stream_id: &Identifier,
@@ -762,6 +1360,20 @@ impl ConsumerGroupClient for IggyClient {
             .await
     }
 
+    async fn join_consumer_group_with_strategy(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+        assignment_strategy: AssignmentStrategy,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .join_consumer_group_with_strategy(stream_id, topic_id, group_id, assignment_strategy)
+            .await
+    }
+
     async fn leave_consumer_group(
         &self,
         stream_id: &Identifier,
@@ -774,6 +1386,98 @@ impl ConsumerGroupClient for IggyClient {
             .leave_consumer_group(stream_id, topic_id, group_id)
             .await
     }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .heartbeat_consumer_group(stream_id, topic_id, group_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl SchemaClient for IggyClient {
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        self.client.read().await.get_schema(schema_id).await
+    }
+
+    async fn get_schemas(&self, subject: &str) -> Result<Vec<Schema>, IggyError> {
+        self.client.read().await.get_schemas(subject).await
+    }
+
+    async fn get_subject_schema(
+        &self,
+        subject: &str,
+        version: Option<u32>,
+    ) -> Result<Option<Schema>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_subject_schema(subject, version)
+            .await
+    }
+
+    async fn register_schema(
+        &self,
+        subject: &str,
+        schema: TopicSchema,
+    ) -> Result<Schema, IggyError> {
+        self.client
+            .read()
+            .await
+            .register_schema(subject, schema)
+            .await
+    }
+
+    async fn update_schema_compatibility(
+        &self,
+        subject: &str,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_schema_compatibility(subject, compatibility)
+            .await
+    }
+}
+
+#[async_trait]
+impl QuotaClient for IggyClient {
+    async fn get_quotas(&self) -> Result<Vec<Quota>, IggyError> {
+        self.client.read().await.get_quotas().await
+    }
+
+    async fn set_user_quota(
+        &self,
+        user_id: &Identifier,
+        limits: Option<QuotaLimits>,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .set_user_quota(user_id, limits)
+            .await
+    }
+
+    async fn set_topic_quota(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        limits: Option<QuotaLimits>,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .set_topic_quota(stream_id, topic_id, limits)
+            .await
+    }
 }
 
 #[async_trait]
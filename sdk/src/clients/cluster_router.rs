@@ -0,0 +1,197 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::Client;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::locking::{IggySharedMut, IggySharedMutFn};
+use crate::messages::send_messages::{Partitioning, PartitioningKind};
+use crate::models::cluster::ClusterMetadata;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
+use tracing::{info, warn};
+
+/// The maximum number of times the request is redirected to the other node, after being rejected by the previous one.
+pub(crate) const MAX_CLUSTER_REDIRECTS: u32 = 3;
+
+/// Creates the not connected client for the cluster node with the given address, using the same transport and credentials.
+pub type NodeClientFactory = Arc<dyn Fn(&str) -> Result<Box<dyn Client>, IggyError> + Send + Sync>;
+
+/// Keeps the map of the cluster nodes and the leaders of the partitions, fetched from the server,
+/// and the connections to the nodes other than the one the client has been connected to.
+/// The metadata is fetched on the first use, and refreshed whenever the node rejects the request as not being the leader.
+pub struct ClusterRouter {
+    factory: NodeClientFactory,
+    metadata: RwLock<Option<ClusterMetadata>>,
+    clients: Mutex<HashMap<u32, Arc<Box<dyn Client>>>>,
+    next_partition: AtomicUsize,
+}
+
+impl Debug for ClusterRouter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClusterRouter").finish_non_exhaustive()
+    }
+}
+
+impl ClusterRouter {
+    pub fn new(factory: NodeClientFactory) -> Self {
+        Self {
+            factory,
+            metadata: RwLock::new(None),
+            clients: Mutex::new(HashMap::new()),
+            next_partition: AtomicUsize::new(0),
+        }
+    }
+
+    /// Fetches the cluster metadata from the node the client has been connected to.
+    pub async fn refresh(&self, client: &IggySharedMut<Box<dyn Client>>) {
+        match client.read().await.get_cluster_metadata().await {
+            Ok(metadata) => {
+                if !metadata.enabled {
+                    info!("Cluster mode is disabled on the server, requests will not be routed.");
+                }
+                *self.metadata.write().await = Some(metadata);
+            }
+            Err(error) => warn!("Unable to fetch the cluster metadata. {error}"),
+        }
+    }
+
+    /// Picks the partition for the balanced partitioning on the client side, so the messages can be sent to its leader.
+    /// The other kinds of partitioning are returned as they are.
+    pub(crate) async fn resolve_partitioning(
+        &self,
+        client: &IggySharedMut<Box<dyn Client>>,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+    ) -> Partitioning {
+        if partitioning.kind != PartitioningKind::Balanced {
+            return partitioning.clone();
+        }
+
+        self.ensure_metadata(client).await;
+        let metadata = self.metadata.read().await;
+        let Some(topic) = metadata
+            .as_ref()
+            .filter(|metadata| metadata.enabled)
+            .and_then(|metadata| metadata.get_topic(stream_id, topic_id))
+            .filter(|topic| !topic.partitions.is_empty())
+        else {
+            return partitioning.clone();
+        };
+
+        let index = self.next_partition.fetch_add(1, Ordering::Relaxed) % topic.partitions.len();
+        Partitioning::partition_id(topic.partitions[index].id)
+    }
+
+    /// Returns the client connected to the leader of the partition, or `None` if the default client should be used,
+    /// e.g. the leader is not known, or it's the node the client has been connected to.
+    pub(crate) async fn get_partition_client(
+        &self,
+        client: &IggySharedMut<Box<dyn Client>>,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Option<Arc<Box<dyn Client>>> {
+        let partition_id = partition_id?;
+        self.ensure_metadata(client).await;
+        let leader_id = self.metadata.read().await.as_ref()?.get_partition_leader(
+            stream_id,
+            topic_id,
+            partition_id,
+        )?;
+        self.get_node_client(leader_id).await
+    }
+
+    /// Refreshes the metadata and returns the client connected to the leader pointed by the error,
+    /// or the error itself if the request cannot be redirected.
+    pub(crate) async fn redirect(
+        &self,
+        client: &IggySharedMut<Box<dyn Client>>,
+        error: IggyError,
+    ) -> Result<Option<Arc<Box<dyn Client>>>, IggyError> {
+        let leader_id = match error {
+            IggyError::NotPartitionLeader(_, _, _, leader_id)
+            | IggyError::NotClusterLeader(leader_id)
+                if leader_id > 0 =>
+            {
+                leader_id
+            }
+            _ => return Err(error),
+        };
+
+        info!("Request has been rejected by the node which is not the leader, redirecting it to the node with ID: {leader_id}.");
+        self.refresh(client).await;
+        Ok(self.get_node_client(leader_id).await)
+    }
+
+    /// Returns the ID of the partition the messages are sent to, if it's explicitly provided.
+    pub(crate) fn get_partition_id(partitioning: &Partitioning) -> Option<u32> {
+        if partitioning.kind != PartitioningKind::PartitionId {
+            return None;
+        }
+
+        partitioning
+            .value
+            .get(..4)
+            .and_then(|value| value.try_into().ok())
+            .map(u32::from_le_bytes)
+    }
+
+    async fn ensure_metadata(&self, client: &IggySharedMut<Box<dyn Client>>) {
+        if self.metadata.read().await.is_none() {
+            self.refresh(client).await;
+        }
+    }
+
+    async fn get_node_client(&self, node_id: u32) -> Option<Arc<Box<dyn Client>>> {
+        let address = {
+            let metadata = self.metadata.read().await;
+            let metadata = metadata.as_ref()?;
+            if !metadata.enabled || metadata.node_id == node_id {
+                return None;
+            }
+            metadata.get_node(node_id)?.address.clone()
+        };
+
+        let mut clients = self.clients.lock().await;
+        if let Some(client) = clients.get(&node_id) {
+            return Some(client.clone());
+        }
+
+        let client = match (self.factory)(&address) {
+            Ok(client) => client,
+            Err(error) => {
+                warn!("Unable to create the client for the cluster node with ID: {node_id}, address: {address}. {error}");
+                return None;
+            }
+        };
+        if let Err(error) = client.connect().await {
+            warn!("Unable to connect to the cluster node with ID: {node_id}, address: {address}. {error}");
+            return None;
+        }
+
+        info!("Connected to the cluster node with ID: {node_id}, address: {address}.");
+        let client = Arc::new(client);
+        clients.insert(node_id, client.clone());
+        Some(client)
+    }
+}
@@ -17,12 +17,18 @@
  */
 
 use crate::client::Client;
+use crate::clients::consumer_deduplicator::{DeduplicationWindow, MessageDeduplicator};
+use crate::compression::codec::{self, CodecRegistry};
 use crate::consumer::{Consumer, ConsumerKind};
+use crate::consumer_groups::assignment_strategy::AssignmentStrategy;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
+use crate::headers::well_known;
 use crate::identifier::{IdKind, Identifier};
 use crate::locking::{IggySharedMut, IggySharedMutFn};
 use crate::messages::poll_messages::{PollingKind, PollingStrategy};
+use crate::messages::send_messages::Message;
+use crate::messages::AutoCommitMode;
 use crate::models::messages::{PolledMessage, PolledMessages};
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::crypto::EncryptorKind;
@@ -39,6 +45,7 @@ use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64};
 use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::time::Duration;
+use tokio::sync::watch;
 use tokio::time;
 use tokio::time::sleep;
 use tracing::{error, info, trace, warn};
@@ -67,6 +74,8 @@ pub enum AutoCommit {
     ///
     /// **This will only work with the `IggyConsumerMessageExt` trait when using `consume_messages()`.**
     After(AutoCommitAfter),
+    /// The auto-commit is performed by the server when polling the messages, depending on the mode.
+    Server(AutoCommitMode),
 }
 
 /// The auto-commit mode for storing the offset on the server.
@@ -95,6 +104,66 @@ pub enum AutoCommitAfter {
     ConsumingEveryNthMessage(u32),
 }
 
+/// The change of the partitions assigned to the consumer group member, detected after the rebalance on the server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssignmentChange {
+    /// The assignment generation of the consumer group after the rebalance.
+    pub generation: u32,
+    /// All the partitions currently assigned to the member.
+    pub partitions: Vec<u32>,
+    /// The partitions which have been newly assigned to the member.
+    pub assigned: Vec<u32>,
+    /// The partitions which have been revoked from the member.
+    pub revoked: Vec<u32>,
+}
+
+impl AssignmentChange {
+    fn new(generation: u32, previous: &[u32], current: &[u32]) -> Self {
+        Self {
+            generation,
+            partitions: current.to_vec(),
+            assigned: current
+                .iter()
+                .filter(|partition_id| !previous.contains(partition_id))
+                .copied()
+                .collect(),
+            revoked: previous
+                .iter()
+                .filter(|partition_id| !current.contains(partition_id))
+                .copied()
+                .collect(),
+        }
+    }
+
+    /// Returns `true` if any partition has been assigned or revoked.
+    pub fn has_changes(&self) -> bool {
+        !self.assigned.is_empty() || !self.revoked.is_empty()
+    }
+}
+
+/// The callback invoked when the partitions assigned to the consumer group member have changed.
+#[derive(Clone)]
+pub struct AssignmentChangedCallback(Arc<dyn Fn(AssignmentChange) + Send + Sync>);
+
+impl AssignmentChangedCallback {
+    pub fn new<F>(callback: F) -> Self
+    where
+        F: Fn(AssignmentChange) + Send + Sync + 'static,
+    {
+        Self(Arc::new(callback))
+    }
+
+    fn invoke(&self, change: AssignmentChange) {
+        (self.0)(change)
+    }
+}
+
+impl std::fmt::Debug for AssignmentChangedCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AssignmentChangedCallback")
+    }
+}
+
 unsafe impl Send for IggyConsumer {}
 unsafe impl Sync for IggyConsumer {}
 
@@ -114,6 +183,7 @@ pub struct IggyConsumer {
     batch_size: u32,
     auto_commit: AutoCommit,
     auto_commit_after_polling: bool,
+    polling_auto_commit: AutoCommitMode,
     auto_join_consumer_group: bool,
     create_consumer_group_if_not_exists: bool,
     last_stored_offsets: Arc<DashMap<u32, AtomicU64>>,
@@ -122,6 +192,7 @@ pub struct IggyConsumer {
     poll_future: Option<PollMessagesFuture>,
     buffered_messages: VecDeque<PolledMessage>,
     encryptor: Option<Arc<EncryptorKind>>,
+    codecs: Option<Arc<CodecRegistry>>,
     store_offset_sender: flume::Sender<(u32, u64)>,
     store_offset_after_each_message: bool,
     store_offset_after_all_messages: bool,
@@ -132,6 +203,12 @@ pub struct IggyConsumer {
     init_retries: Option<u32>,
     init_retry_interval: IggyDuration,
     allow_replay: bool,
+    on_assignment_changed: Option<AssignmentChangedCallback>,
+    assignment_strategy: Option<AssignmentStrategy>,
+    heartbeat_interval: Option<IggyDuration>,
+    deduplicator: Option<MessageDeduplicator>,
+    // Dropped along with the consumer, which stops the background tasks watching the consumer group.
+    background_tasks: watch::Sender<()>,
 }
 
 impl IggyConsumer {
@@ -150,12 +227,25 @@ impl IggyConsumer {
         auto_join_consumer_group: bool,
         create_consumer_group_if_not_exists: bool,
         encryptor: Option<Arc<EncryptorKind>>,
+        codecs: Option<Arc<CodecRegistry>>,
         reconnection_retry_interval: IggyDuration,
         init_retries: Option<u32>,
         init_retry_interval: IggyDuration,
         allow_replay: bool,
+        on_assignment_changed: Option<AssignmentChangedCallback>,
+        assignment_strategy: Option<AssignmentStrategy>,
+        heartbeat_interval: Option<IggyDuration>,
+        deduplication_window: Option<DeduplicationWindow>,
     ) -> Self {
         let (store_offset_sender, _) = flume::unbounded();
+        let polling_auto_commit = match auto_commit {
+            AutoCommit::When(AutoCommitWhen::PollingMessages)
+            | AutoCommit::IntervalOrWhen(_, AutoCommitWhen::PollingMessages) => {
+                AutoCommitMode::BeforeDelivery
+            }
+            AutoCommit::Server(mode) => mode,
+            _ => AutoCommitMode::Manual,
+        };
         Self {
             initialized: false,
             is_consumer_group: consumer.kind == ConsumerKind::ConsumerGroup,
@@ -175,15 +265,13 @@ impl IggyConsumer {
             poll_future: None,
             batch_size,
             auto_commit,
-            auto_commit_after_polling: matches!(
-                auto_commit,
-                AutoCommit::When(AutoCommitWhen::PollingMessages)
-                    | AutoCommit::IntervalOrWhen(_, AutoCommitWhen::PollingMessages)
-            ),
+            auto_commit_after_polling: polling_auto_commit == AutoCommitMode::BeforeDelivery,
+            polling_auto_commit,
             auto_join_consumer_group,
             create_consumer_group_if_not_exists,
             buffered_messages: VecDeque::new(),
             encryptor,
+            codecs,
             store_offset_sender,
             store_offset_after_each_message: matches!(
                 auto_commit,
@@ -208,6 +296,11 @@ impl IggyConsumer {
             init_retries,
             init_retry_interval,
             allow_replay,
+            on_assignment_changed,
+            assignment_strategy,
+            heartbeat_interval,
+            deduplicator: deduplication_window.map(MessageDeduplicator::new),
+            background_tasks: watch::channel(()).0,
         }
     }
 
@@ -230,11 +323,20 @@ impl IggyConsumer {
         &self.stream_id
     }
 
+    /// Returns `true` if the consumer is a part of the consumer group.
+    pub fn is_consumer_group(&self) -> bool {
+        self.is_consumer_group
+    }
+
     /// Returns the current partition ID of the consumer.
     pub fn partition_id(&self) -> u32 {
         self.current_partition_id.load(ORDERING)
     }
 
+    pub(crate) fn consumer(&self) -> &Consumer {
+        &self.consumer
+    }
+
     /// Stores the consumer offset on the server either for the current partition or the provided partition ID.
     pub async fn store_offset(
         &self,
@@ -272,6 +374,53 @@ impl IggyConsumer {
             .await
     }
 
+    /// Rejects the received message, so that it's moved to the dead letter topic once its delivery attempts exceed the limit configured for the topic.
+    pub async fn reject(&self, message: &ReceivedMessage, reason: &str) -> Result<(), IggyError> {
+        let client = self.client.read().await;
+        client
+            .reject_messages(
+                &self.consumer,
+                &self.stream_id,
+                &self.topic_id,
+                Some(message.partition_id),
+                &[message.message.offset],
+                reason,
+            )
+            .await
+    }
+
+    /// Negatively acknowledges the received message, so that it's redelivered by the next polling once the optional delay has passed.
+    pub async fn nack(
+        &self,
+        message: &ReceivedMessage,
+        redelivery_delay: Option<IggyDuration>,
+    ) -> Result<(), IggyError> {
+        let client = self.client.read().await;
+        client
+            .nack_messages(
+                &self.consumer,
+                &self.stream_id,
+                &self.topic_id,
+                Some(message.partition_id),
+                &[message.message.offset],
+                redelivery_delay,
+            )
+            .await
+    }
+
+    /// Acknowledges the received message polled from the topic in the queue mode, so that it's never redelivered.
+    pub async fn ack(&self, message: &ReceivedMessage) -> Result<(), IggyError> {
+        let client = self.client.read().await;
+        client
+            .ack_messages(
+                &self.stream_id,
+                &self.topic_id,
+                message.partition_id,
+                &[message.message.offset],
+            )
+            .await
+    }
+
     /// Initializes the consumer by subscribing to diagnostic events, initializing the consumer group if needed, storing the offsets in the background etc.
     ///
     /// Note: This method must be called before polling messages.
@@ -348,6 +497,8 @@ impl IggyConsumer {
 
         self.subscribe_events().await;
         self.init_consumer_group().await?;
+        self.watch_assignment_changes().await;
+        self.send_heartbeats_in_background();
 
         match self.auto_commit {
             AutoCommit::Interval(interval) => self.store_offsets_in_background(interval),
@@ -484,10 +635,162 @@ impl IggyConsumer {
             self.consumer.clone(),
             &self.consumer_name,
             self.joined_consumer_group.clone(),
+            self.assignment_strategy,
         )
         .await
     }
 
+    async fn watch_assignment_changes(&self) {
+        if !self.is_consumer_group {
+            return;
+        }
+
+        let Some(callback) = self.on_assignment_changed.clone() else {
+            return;
+        };
+
+        let stream_id = self.stream_id.clone();
+        let topic_id = self.topic_id.clone();
+        let group_id = self.consumer.id.clone();
+        let subscription = self
+            .client
+            .read()
+            .await
+            .subscribe_consumer_group_rebalances()
+            .await;
+        let mut rebalances = match subscription {
+            Ok(rebalances) => rebalances,
+            Err(error) => {
+                warn!("Couldn't subscribe to the rebalances of consumer group: {group_id}, topic: {topic_id}, stream: {stream_id}, the assignment changes won't be reported. {error}");
+                return;
+            }
+        };
+
+        let mut background_tasks = self.background_tasks.subscribe();
+        tokio::spawn(async move {
+            let mut generation = None;
+            let mut partitions = Vec::new();
+            loop {
+                let rebalance = tokio::select! {
+                    _ = background_tasks.changed() => {
+                        trace!("Consumer has been dropped, stopping the assignment changes watcher.");
+                        break;
+                    }
+                    rebalance = rebalances.next() => rebalance,
+                };
+                let Some(rebalance) = rebalance else {
+                    trace!("Client has been dropped, stopping the assignment changes watcher.");
+                    break;
+                };
+
+                if !Self::is_identified_by(&stream_id, rebalance.stream_id, &rebalance.stream_name)
+                    || !Self::is_identified_by(&topic_id, rebalance.topic_id, &rebalance.topic_name)
+                    || !Self::is_identified_by(&group_id, rebalance.group_id, &rebalance.group_name)
+                {
+                    continue;
+                }
+
+                // The current assignment is pushed again e.g. after reconnecting, so it's reported only once per generation.
+                if generation == Some(rebalance.generation) {
+                    continue;
+                }
+
+                let change =
+                    AssignmentChange::new(rebalance.generation, &partitions, &rebalance.partitions);
+                generation = Some(rebalance.generation);
+                partitions = rebalance.partitions;
+                if change.has_changes() {
+                    info!("Partitions assignment has changed for consumer group: {group_id}, topic: {topic_id}, stream: {stream_id}, generation: {}, assigned: {:?}, revoked: {:?}", change.generation, change.assigned, change.revoked);
+                    callback.invoke(change);
+                }
+            }
+        });
+    }
+
+    fn is_identified_by(identifier: &Identifier, id: u32, name: &str) -> bool {
+        match identifier.kind {
+            IdKind::Numeric => identifier.get_u32_value().is_ok_and(|value| value == id),
+            IdKind::String => identifier
+                .get_cow_str_value()
+                .is_ok_and(|value| value == name),
+        }
+    }
+
+    fn send_heartbeats_in_background(&self) {
+        if !self.is_consumer_group || !self.auto_join_consumer_group {
+            return;
+        }
+
+        let Some(interval) = self.heartbeat_interval else {
+            trace!(
+                "Heartbeats are disabled for consumer group: {}",
+                self.consumer_name
+            );
+            return;
+        };
+
+        let client = self.client.clone();
+        let create_consumer_group_if_not_exists = self.create_consumer_group_if_not_exists;
+        let stream_id = self.stream_id.clone();
+        let topic_id = self.topic_id.clone();
+        let consumer = self.consumer.clone();
+        let consumer_name = self.consumer_name.clone();
+        let can_poll = self.can_poll.clone();
+        let joined_consumer_group = self.joined_consumer_group.clone();
+        let assignment_strategy = self.assignment_strategy;
+        let mut background_tasks = self.background_tasks.subscribe();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = background_tasks.changed() => {
+                        trace!("Consumer has been dropped, stopping the heartbeats.");
+                        break;
+                    }
+                    _ = sleep(interval.get_duration()) => {}
+                }
+                if !can_poll.load(ORDERING) || !joined_consumer_group.load(ORDERING) {
+                    continue;
+                }
+
+                let result = client
+                    .read()
+                    .await
+                    .heartbeat_consumer_group(&stream_id, &topic_id, &consumer.id)
+                    .await;
+                match result {
+                    Ok(()) => {
+                        trace!("Sent heartbeat to consumer group: {consumer_name} for stream: {stream_id}, topic: {topic_id}");
+                    }
+                    Err(IggyError::ClientShutdown) => {
+                        trace!("Client has been shutdown, stopping the heartbeats.");
+                        break;
+                    }
+                    Err(IggyError::ConsumerGroupMemberNotFound(_, _, _)) => {
+                        warn!("Consumer has been evicted from consumer group: {consumer_name} for stream: {stream_id}, topic: {topic_id}, rejoining...");
+                        joined_consumer_group.store(false, ORDERING);
+                        if let Err(error) = Self::initialize_consumer_group(
+                            client.clone(),
+                            create_consumer_group_if_not_exists,
+                            stream_id.clone(),
+                            topic_id.clone(),
+                            consumer.clone(),
+                            &consumer_name,
+                            joined_consumer_group.clone(),
+                            assignment_strategy,
+                        )
+                        .await
+                        {
+                            error!("Failed to rejoin consumer group: {consumer_name} for stream: {stream_id}, topic: {topic_id}. {error}");
+                        }
+                    }
+                    Err(error) => {
+                        warn!("Failed to send heartbeat to consumer group: {consumer_name} for stream: {stream_id}, topic: {topic_id}. {error}");
+                    }
+                }
+            }
+        });
+    }
+
     async fn subscribe_events(&self) {
         trace!("Subscribing to diagnostic events");
         let mut receiver;
@@ -506,6 +809,7 @@ impl IggyConsumer {
         let consumer_name = self.consumer_name.clone();
         let can_poll = self.can_poll.clone();
         let joined_consumer_group = self.joined_consumer_group.clone();
+        let assignment_strategy = self.assignment_strategy;
         let mut reconnected = false;
         let mut disconnected = false;
 
@@ -535,6 +839,10 @@ impl IggyConsumer {
                         can_poll.store(false, ORDERING);
                         warn!("Disconnected from the server");
                     }
+                    DiagnosticEvent::Reconnecting => {
+                        can_poll.store(false, ORDERING);
+                        trace!("Reconnecting to the server");
+                    }
                     DiagnosticEvent::SignedIn => {
                         if !is_consumer_group {
                             can_poll.store(true, ORDERING);
@@ -566,6 +874,7 @@ impl IggyConsumer {
                             consumer.clone(),
                             &consumer_name,
                             joined_consumer_group.clone(),
+                            assignment_strategy,
                         )
                         .await
                         {
@@ -595,7 +904,12 @@ impl IggyConsumer {
         let client = self.client.clone();
         let count = self.batch_size;
         let auto_commit_after_polling = self.auto_commit_after_polling;
-        let auto_commit_enabled = self.auto_commit != AutoCommit::Disabled;
+        let polling_auto_commit = self.polling_auto_commit;
+        // The offsets committed by the server in the other modes aren't tracked by the consumer.
+        let auto_commit_enabled = !matches!(
+            self.auto_commit,
+            AutoCommit::Disabled | AutoCommit::Server(_)
+        ) || auto_commit_after_polling;
         let interval = self.poll_interval_micros;
         let last_polled_at = self.last_polled_at.clone();
         let can_poll = self.can_poll.clone();
@@ -619,14 +933,14 @@ impl IggyConsumer {
             let polled_messages = client
                 .read()
                 .await
-                .poll_messages(
+                .poll_messages_with_commit_mode(
                     &stream_id,
                     &topic_id,
                     partition_id,
                     &consumer,
                     &polling_strategy,
                     count,
-                    auto_commit_after_polling,
+                    polling_auto_commit,
                 )
                 .await;
 
@@ -656,6 +970,7 @@ impl IggyConsumer {
                             messages: EMPTY_MESSAGES,
                             current_offset: polled_messages.current_offset,
                             partition_id,
+                            next_offset: polled_messages.next_offset,
                         });
                     }
                 }
@@ -711,6 +1026,7 @@ impl IggyConsumer {
                         messages: EMPTY_MESSAGES,
                         current_offset: polled_messages.current_offset,
                         partition_id,
+                        next_offset: None,
                     });
                 }
 
@@ -747,6 +1063,20 @@ impl IggyConsumer {
         sleep(Duration::from_micros(remaining)).await;
     }
 
+    fn decompress_message(
+        codecs: &CodecRegistry,
+        message: &mut PolledMessage,
+    ) -> Result<(), IggyError> {
+        let Some(codec_id) = codec::get_codec_id(&message.headers)? else {
+            return Ok(());
+        };
+
+        let codec = codecs.get(codec_id)?;
+        message.payload = Bytes::from(codec.decompress(&message.payload)?);
+        message.length = IggyByteSize::from(message.payload.len() as u64);
+        Ok(())
+    }
+
     async fn initialize_consumer_group(
         client: IggySharedMut<Box<dyn Client>>,
         create_consumer_group_if_not_exists: bool,
@@ -755,6 +1085,7 @@ impl IggyConsumer {
         consumer: Arc<Consumer>,
         consumer_name: &str,
         joined_consumer_group: Arc<AtomicBool>,
+        assignment_strategy: Option<AssignmentStrategy>,
     ) -> Result<(), IggyError> {
         if joined_consumer_group.load(ORDERING) {
             return Ok(());
@@ -783,15 +1114,35 @@ impl IggyConsumer {
 
             info!("Creating consumer group: {consumer_group_id} for topic: {topic_id}, stream: {stream_id}");
             client
-                .create_consumer_group(&stream_id, &topic_id, &name, id)
+                .create_consumer_group_with_strategy(
+                    &stream_id,
+                    &topic_id,
+                    &name,
+                    id,
+                    assignment_strategy.unwrap_or_default(),
+                )
                 .await?;
         }
 
         info!("Joining consumer group: {consumer_group_id} for topic: {topic_id}, stream: {stream_id}",);
-        if let Err(error) = client
-            .join_consumer_group(&stream_id, &topic_id, &consumer_group_id)
-            .await
-        {
+        let joined = match assignment_strategy {
+            Some(assignment_strategy) => {
+                client
+                    .join_consumer_group_with_strategy(
+                        &stream_id,
+                        &topic_id,
+                        &consumer_group_id,
+                        assignment_strategy,
+                    )
+                    .await
+            }
+            None => {
+                client
+                    .join_consumer_group(&stream_id, &topic_id, &consumer_group_id)
+                    .await
+            }
+        };
+        if let Err(error) = joined {
             joined_consumer_group.store(false, ORDERING);
             error!("Failed to join consumer group: {consumer_group_id} for topic: {topic_id}, stream: {stream_id}: {error}");
             return Err(error);
@@ -819,6 +1170,90 @@ impl ReceivedMessage {
             partition_id,
         }
     }
+
+    /// Returns the ID of the registered schema the message was produced with, if any.
+    /// The matching schema version can be fetched using `SchemaClient::get_schema()`.
+    pub fn schema_id(&self) -> Result<Option<u32>, IggyError> {
+        well_known::get_schema_id(&self.message.headers)
+    }
+
+    /// Returns the correlation ID attached to the message by the `IggyRequester`, if any.
+    pub fn correlation_id(&self) -> Result<Option<u128>, IggyError> {
+        well_known::get_correlation_id(&self.message.headers)
+    }
+
+    /// Returns the format of the payload, if any.
+    pub fn content_type(&self) -> Result<Option<&str>, IggyError> {
+        well_known::get_content_type(&self.message.headers)
+    }
+
+    /// Returns the W3C trace context the message was produced within, if any.
+    pub fn traceparent(&self) -> Result<Option<&str>, IggyError> {
+        well_known::get_traceparent(&self.message.headers)
+    }
+
+    /// Returns how many times the message has already been retried.
+    pub fn retry_count(&self) -> Result<u32, IggyError> {
+        well_known::get_retry_count(&self.message.headers)
+    }
+
+    /// Creates the copy of the message to be sent again, e.g. to the retry topic, with the incremented retry count.
+    /// All the other headers, including the correlation ID and the trace context, are carried over.
+    pub fn to_retry(&self) -> Result<Message, IggyError> {
+        let mut headers = self.message.headers.clone();
+        well_known::increment_retry_count(&mut headers)?;
+        Ok(Message::new(None, self.message.payload.clone(), headers))
+    }
+}
+
+impl IggyConsumer {
+    /// Removes the messages which have already been delivered within the deduplication window.
+    /// If all of them are removed, the consumer moves past them, as if they were consumed.
+    fn skip_duplicated_messages(&mut self, polled_messages: &mut PolledMessages) {
+        let Some(deduplicator) = self.deduplicator.as_mut() else {
+            return;
+        };
+
+        let Some(last_offset) = polled_messages
+            .messages
+            .last()
+            .map(|message| message.offset)
+        else {
+            return;
+        };
+
+        let partition_id = polled_messages.partition_id;
+        let now = IggyTimestamp::now();
+        polled_messages.messages.retain(|message| {
+            let unique = deduplicator.try_insert(message.id, now);
+            if !unique {
+                trace!("Skipping the duplicated message with ID: {} at offset: {}, partition ID: {partition_id}", message.id, message.offset);
+            }
+            unique
+        });
+        deduplicator.persist(now);
+        if !polled_messages.messages.is_empty() {
+            return;
+        }
+
+        if self.polling_strategy.kind == PollingKind::Offset {
+            self.polling_strategy = PollingStrategy::offset(last_offset + 1);
+        }
+
+        if let Some(last_consumed_offset_entry) = self.last_consumed_offsets.get(&partition_id) {
+            last_consumed_offset_entry.store(last_offset, ORDERING);
+        } else {
+            self.last_consumed_offsets
+                .insert(partition_id, AtomicU64::new(last_offset));
+        }
+
+        if self.store_after_every_nth_message > 0
+            || self.store_offset_after_each_message
+            || self.store_offset_after_all_messages
+        {
+            self.send_store_offset(partition_id, last_offset);
+        }
+    }
 }
 
 impl Stream for IggyConsumer {
@@ -879,6 +1314,10 @@ impl Stream for IggyConsumer {
                 Poll::Ready(Ok(mut polled_messages)) => {
                     let partition_id = polled_messages.partition_id;
                     self.current_partition_id.store(partition_id, ORDERING);
+                    if self.deduplicator.is_some() {
+                        self.skip_duplicated_messages(&mut polled_messages);
+                    }
+
                     if polled_messages.messages.is_empty() {
                         self.poll_future = Some(Box::pin(self.create_poll_messages_future()));
                     } else {
@@ -898,6 +1337,16 @@ impl Stream for IggyConsumer {
                             }
                         }
 
+                        if let Some(ref codecs) = self.codecs {
+                            for message in &mut polled_messages.messages {
+                                if let Err(error) = Self::decompress_message(codecs, message) {
+                                    self.poll_future = None;
+                                    error!("Failed to decompress the message payload at offset: {}, partition ID: {}", message.offset, partition_id);
+                                    return Poll::Ready(Some(Err(error)));
+                                }
+                            }
+                        }
+
                         if let Some(current_offset_entry) = self.current_offsets.get(&partition_id)
                         {
                             current_offset_entry.store(polled_messages.current_offset, ORDERING);
@@ -968,10 +1417,15 @@ pub struct IggyConsumerBuilder {
     auto_join_consumer_group: bool,
     create_consumer_group_if_not_exists: bool,
     encryptor: Option<Arc<EncryptorKind>>,
+    codecs: Option<Arc<CodecRegistry>>,
     polling_retry_interval: IggyDuration,
     init_retries: Option<u32>,
     init_retry_interval: IggyDuration,
     allow_replay: bool,
+    on_assignment_changed: Option<AssignmentChangedCallback>,
+    assignment_strategy: Option<AssignmentStrategy>,
+    heartbeat_interval: Option<IggyDuration>,
+    deduplication_window: Option<DeduplicationWindow>,
 }
 
 impl IggyConsumerBuilder {
@@ -1002,11 +1456,16 @@ impl IggyConsumerBuilder {
             auto_join_consumer_group: true,
             create_consumer_group_if_not_exists: true,
             encryptor,
+            codecs: None,
             polling_interval,
             polling_retry_interval: IggyDuration::ONE_SECOND,
             init_retries: None,
             init_retry_interval: IggyDuration::ONE_SECOND,
             allow_replay: false,
+            on_assignment_changed: None,
+            assignment_strategy: None,
+            heartbeat_interval: Some(IggyDuration::new_from_secs(5)),
+            deduplication_window: None,
         }
     }
 
@@ -1117,6 +1576,23 @@ impl IggyConsumerBuilder {
         }
     }
 
+    /// Sets the codecs for decompressing the messages' payloads (after they are decrypted).
+    /// The codec is picked by the ID attached to the message by the producer, the messages without the codec ID are left as they are.
+    pub fn codecs(self, codecs: CodecRegistry) -> Self {
+        Self {
+            codecs: Some(Arc::new(codecs)),
+            ..self
+        }
+    }
+
+    /// Clears the codecs for decompressing the messages' payloads.
+    pub fn without_codecs(self) -> Self {
+        Self {
+            codecs: None,
+            ..self
+        }
+    }
+
     /// Sets the polling retry interval in case of server disconnection.
     pub fn polling_retry_interval(self, interval: IggyDuration) -> Self {
         Self {
@@ -1144,6 +1620,62 @@ impl IggyConsumerBuilder {
         }
     }
 
+    /// Sets the callback invoked when the partitions assigned to this consumer group member have changed due to the rebalance on the server.
+    /// The rebalances are pushed by the server, which is supported only by the TCP client without TLS.
+    pub fn on_assignment_changed<F>(self, callback: F) -> Self
+    where
+        F: Fn(AssignmentChange) + Send + Sync + 'static,
+    {
+        Self {
+            on_assignment_changed: Some(AssignmentChangedCallback::new(callback)),
+            ..self
+        }
+    }
+
+    /// Sets the strategy used by the server to assign the partitions to the consumer group members.
+    /// It's used when creating the consumer group, and requested when joining it - the consumer can't join the existing group using a different strategy.
+    /// By default, the strategy of the existing consumer group is used.
+    pub fn assignment_strategy(self, assignment_strategy: AssignmentStrategy) -> Self {
+        Self {
+            assignment_strategy: Some(assignment_strategy),
+            ..self
+        }
+    }
+
+    /// Sets the interval of sending the heartbeats to the consumer group, 5 seconds by default.
+    /// It should be lower than the session timeout configured on the server, otherwise the member might be evicted from the group when it's not polling the messages.
+    pub fn heartbeat_interval(self, interval: IggyDuration) -> Self {
+        Self {
+            heartbeat_interval: Some(interval),
+            ..self
+        }
+    }
+
+    /// Disables sending the heartbeats to the consumer group, the membership is then kept alive only by polling the messages.
+    pub fn without_heartbeat(self) -> Self {
+        Self {
+            heartbeat_interval: None,
+            ..self
+        }
+    }
+
+    /// Enables filtering out the messages whose IDs have already been delivered within the given window,
+    /// so that the messages received again e.g. after reconnecting don't reach the application twice.
+    pub fn deduplicate(self, window: DeduplicationWindow) -> Self {
+        Self {
+            deduplication_window: Some(window),
+            ..self
+        }
+    }
+
+    /// Disables filtering out the already delivered messages.
+    pub fn without_deduplication(self) -> Self {
+        Self {
+            deduplication_window: None,
+            ..self
+        }
+    }
+
     /// Builds the consumer.
     ///
     /// Note: After building the consumer, `init()` must be invoked before producing messages.
@@ -1162,10 +1694,15 @@ impl IggyConsumerBuilder {
             self.auto_join_consumer_group,
             self.create_consumer_group_if_not_exists,
             self.encryptor,
+            self.codecs,
             self.polling_retry_interval,
             self.init_retries,
             self.init_retry_interval,
             self.allow_replay,
+            self.on_assignment_changed,
+            self.assignment_strategy,
+            self.heartbeat_interval,
+            self.deduplication_window,
         )
     }
 }
@@ -0,0 +1,348 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::clients::consumer::{IggyConsumer, ReceivedMessage};
+use crate::error::IggyError;
+use futures::Stream;
+use futures_util::StreamExt;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc};
+use tokio::task::JoinHandle;
+use tracing::{error, trace};
+
+const BROADCAST_BLOCK_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Determines what happens when the channel buffer is full, because the workers don't keep up with the consumer.
+/// It has the following variants:
+/// - `Block`: the consumer stops polling the messages until there's room in the buffer.
+/// - `DropOldest`: the oldest buffered message is dropped to make room for the new one.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LagPolicy {
+    /// The consumer stops polling the messages until there's room in the buffer.
+    #[default]
+    Block,
+    /// The oldest buffered message is dropped to make room for the new one.
+    DropOldest,
+}
+
+/// The configuration of the bridge forwarding the messages from the consumer to the channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelConfig {
+    /// The maximum number of the messages buffered for the workers.
+    pub buffer: usize,
+    /// The policy applied when the buffer is full.
+    pub lag_policy: LagPolicy,
+}
+
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        Self {
+            buffer: 1000,
+            lag_policy: LagPolicy::Block,
+        }
+    }
+}
+
+impl ChannelConfig {
+    /// Creates the configuration buffering up to the given number of the messages.
+    pub fn new(buffer: usize) -> Self {
+        Self {
+            buffer: buffer.max(1),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the policy applied when the buffer is full.
+    pub fn lag_policy(self, lag_policy: LagPolicy) -> Self {
+        Self { lag_policy, ..self }
+    }
+}
+
+/// The handle of the background task forwarding the messages from the consumer to the channel.
+/// The task stops once the consumer stream ends, or all the receivers are dropped.
+#[derive(Debug)]
+pub struct ChannelBridge {
+    dropped_messages: Arc<AtomicU64>,
+    task: JoinHandle<()>,
+}
+
+impl ChannelBridge {
+    /// Returns the number of the messages dropped due to the `DropOldest` lag policy.
+    pub fn dropped_messages(&self) -> u64 {
+        self.dropped_messages.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the forwarding task has stopped.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Stops forwarding the messages, the already buffered ones are still delivered to the receivers.
+    pub fn abort(&self) {
+        self.task.abort();
+    }
+}
+
+impl IggyConsumer {
+    /// Converts the consumer into the `tokio::mpsc` channel, so the consumed messages can be shared by many in-process workers
+    /// e.g. by wrapping the receiver in a mutex. Returns the receiver and the handle of the forwarding task.
+    pub fn into_mpsc(
+        self,
+        config: ChannelConfig,
+    ) -> (mpsc::Receiver<ReceivedMessage>, ChannelBridge) {
+        forward_to_mpsc(self, config)
+    }
+
+    /// Converts the consumer into the `tokio::broadcast` channel, so each subscribed worker receives every consumed message.
+    /// Returns the sender to subscribe the workers with, and the handle of the forwarding task.
+    /// With the `Block` lag policy, the consumer waits until there's at least one subscriber and room in the buffer,
+    /// with the `DropOldest` one, the lagging subscribers skip the oldest messages, as the broadcast channel does.
+    pub fn into_broadcast(
+        self,
+        config: ChannelConfig,
+    ) -> (broadcast::Sender<Arc<ReceivedMessage>>, ChannelBridge) {
+        forward_to_broadcast(self, config)
+    }
+}
+
+fn forward_to_mpsc<S>(
+    mut stream: S,
+    config: ChannelConfig,
+) -> (mpsc::Receiver<ReceivedMessage>, ChannelBridge)
+where
+    S: Stream<Item = Result<ReceivedMessage, IggyError>> + Unpin + Send + 'static,
+{
+    // The channel holds only a single message, the rest is buffered by the task, so the oldest one can be dropped.
+    let (sender, receiver) = mpsc::channel(1);
+    let dropped_messages = Arc::new(AtomicU64::new(0));
+    let dropped = dropped_messages.clone();
+    let task = tokio::spawn(async move {
+        let mut pending = VecDeque::with_capacity(config.buffer);
+        loop {
+            let can_poll =
+                config.lag_policy == LagPolicy::DropOldest || pending.len() < config.buffer;
+            tokio::select! {
+                biased;
+                permit = sender.reserve(), if !pending.is_empty() => {
+                    let Ok(permit) = permit else {
+                        trace!("All the receivers of the consumer channel were dropped.");
+                        return;
+                    };
+                    if let Some(message) = pending.pop_front() {
+                        permit.send(message);
+                    }
+                }
+                message = stream.next(), if can_poll => {
+                    let Some(message) = message else {
+                        break;
+                    };
+                    match message {
+                        Ok(message) => {
+                            if push_pending(&mut pending, message, config.buffer) {
+                                dropped.fetch_add(1, Ordering::Relaxed);
+                            }
+                        }
+                        Err(error) => error!("Failed to consume the message for the channel. {error}"),
+                    }
+                }
+                _ = sender.closed() => {
+                    trace!("All the receivers of the consumer channel were dropped.");
+                    return;
+                }
+            }
+        }
+
+        for message in pending {
+            if sender.send(message).await.is_err() {
+                return;
+            }
+        }
+    });
+
+    (
+        receiver,
+        ChannelBridge {
+            dropped_messages,
+            task,
+        },
+    )
+}
+
+fn forward_to_broadcast<S>(
+    mut stream: S,
+    config: ChannelConfig,
+) -> (broadcast::Sender<Arc<ReceivedMessage>>, ChannelBridge)
+where
+    S: Stream<Item = Result<ReceivedMessage, IggyError>> + Unpin + Send + 'static,
+{
+    let (sender, _) = broadcast::channel(config.buffer);
+    let dropped_messages = Arc::new(AtomicU64::new(0));
+    let dropped = dropped_messages.clone();
+    let task_sender = sender.clone();
+    let task = tokio::spawn(async move {
+        while let Some(message) = stream.next().await {
+            let message = match message {
+                Ok(message) => Arc::new(message),
+                Err(error) => {
+                    error!("Failed to consume the message for the channel. {error}");
+                    continue;
+                }
+            };
+
+            if config.lag_policy == LagPolicy::Block {
+                // The broadcast channel never applies the backpressure, so wait for the subscribers to catch up.
+                while task_sender.receiver_count() == 0 || task_sender.len() >= config.buffer {
+                    tokio::time::sleep(BROADCAST_BLOCK_INTERVAL).await;
+                }
+            } else if task_sender.len() >= config.buffer {
+                dropped.fetch_add(1, Ordering::Relaxed);
+            }
+
+            if task_sender.send(message).is_err() {
+                dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    });
+
+    (
+        sender,
+        ChannelBridge {
+            dropped_messages,
+            task,
+        },
+    )
+}
+
+/// Buffers the message, dropping the oldest one if the buffer is full. Returns `true` if the message was dropped.
+fn push_pending(
+    pending: &mut VecDeque<ReceivedMessage>,
+    message: ReceivedMessage,
+    buffer: usize,
+) -> bool {
+    let dropped = pending.len() >= buffer && pending.pop_front().is_some();
+    pending.push_back(message);
+    dropped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::messages::{MessageState, PolledMessage};
+    use crate::utils::timestamp::IggyTimestamp;
+    use bytes::Bytes;
+    use futures::stream;
+
+    fn received_message(offset: u64) -> Result<ReceivedMessage, IggyError> {
+        let message = PolledMessage::create(
+            offset,
+            MessageState::Available,
+            IggyTimestamp::now(),
+            offset as u128,
+            Bytes::from("message"),
+            0,
+            None,
+        );
+        Ok(ReceivedMessage::new(message, offset, 1))
+    }
+
+    fn messages(count: u64) -> impl Stream<Item = Result<ReceivedMessage, IggyError>> {
+        stream::iter((0..count).map(received_message))
+    }
+
+    #[tokio::test]
+    async fn mpsc_channel_should_deliver_all_messages_when_blocking() {
+        let (mut receiver, bridge) = forward_to_mpsc(messages(10), ChannelConfig::new(2));
+
+        let mut offsets = Vec::new();
+        while let Some(message) = receiver.recv().await {
+            offsets.push(message.message.offset);
+        }
+
+        assert_eq!(offsets, (0..10).collect::<Vec<_>>());
+        assert_eq!(bridge.dropped_messages(), 0);
+    }
+
+    #[tokio::test]
+    async fn mpsc_channel_should_drop_oldest_messages_when_lagging() {
+        let config = ChannelConfig::new(2).lag_policy(LagPolicy::DropOldest);
+        let (mut receiver, bridge) = forward_to_mpsc(messages(5), config);
+        while !bridge.is_finished() && bridge.dropped_messages() < 2 {
+            tokio::task::yield_now().await;
+        }
+
+        let mut offsets = Vec::new();
+        while let Some(message) = receiver.recv().await {
+            offsets.push(message.message.offset);
+        }
+
+        assert_eq!(offsets, vec![0, 3, 4]);
+        assert_eq!(bridge.dropped_messages(), 2);
+    }
+
+    #[tokio::test]
+    async fn broadcast_channel_should_deliver_all_messages_to_each_subscriber_when_blocking() {
+        let (sender, bridge) = forward_to_broadcast(messages(10), ChannelConfig::new(4));
+        let mut first = sender.subscribe();
+        let mut second = sender.subscribe();
+        drop(sender);
+
+        let (first, second) = tokio::join!(
+            async move {
+                let mut offsets = Vec::new();
+                while let Ok(message) = first.recv().await {
+                    offsets.push(message.message.offset);
+                }
+                offsets
+            },
+            async move {
+                let mut offsets = Vec::new();
+                while let Ok(message) = second.recv().await {
+                    offsets.push(message.message.offset);
+                }
+                offsets
+            }
+        );
+
+        assert_eq!(first, (0..10).collect::<Vec<_>>());
+        assert_eq!(second, first);
+        assert_eq!(bridge.dropped_messages(), 0);
+    }
+
+    #[test]
+    fn pending_buffer_should_drop_oldest_message_when_full() {
+        let mut pending = VecDeque::new();
+        for offset in 0..3 {
+            assert!(!push_pending(
+                &mut pending,
+                received_message(offset).unwrap(),
+                3
+            ));
+        }
+
+        assert!(push_pending(&mut pending, received_message(3).unwrap(), 3));
+
+        let offsets = pending
+            .iter()
+            .map(|message| message.message.offset)
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, vec![1, 2, 3]);
+    }
+}
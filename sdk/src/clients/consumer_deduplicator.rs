@@ -0,0 +1,281 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
+use ahash::AHashSet;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use tracing::{trace, warn};
+
+const ENTRY_SIZE: usize = 16 + 8;
+const PERSIST_INTERVAL: IggyDuration = IggyDuration::ONE_SECOND;
+
+/// The sliding window of the message IDs already delivered by the consumer, used to filter out the duplicates
+/// that might be received again e.g. after reconnecting, when the offset hasn't been stored on the server yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeduplicationWindow {
+    /// The maximum number of the message IDs kept in the window.
+    pub max_entries: usize,
+    /// The optional time after which the message ID is evicted from the window.
+    pub ttl: Option<IggyDuration>,
+    /// The optional path of the file in which the window is persisted, so that it survives the consumer restarts.
+    pub path: Option<PathBuf>,
+}
+
+impl Default for DeduplicationWindow {
+    fn default() -> Self {
+        Self {
+            max_entries: 10_000,
+            ttl: None,
+            path: None,
+        }
+    }
+}
+
+impl DeduplicationWindow {
+    /// Creates the window keeping up to the given number of the message IDs.
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the time after which the message ID is evicted from the window.
+    pub fn ttl(self, ttl: IggyDuration) -> Self {
+        Self {
+            ttl: Some(ttl),
+            ..self
+        }
+    }
+
+    /// Persists the window in the given file, which is loaded when the consumer is built.
+    pub fn persist_to(self, path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Some(path.into()),
+            ..self
+        }
+    }
+}
+
+/// Filters out the messages whose IDs have already been delivered within the window.
+/// The persisted window is saved at most once per second while consuming, and when the deduplicator is dropped.
+#[derive(Debug)]
+pub(crate) struct MessageDeduplicator {
+    window: DeduplicationWindow,
+    ids: AHashSet<u128>,
+    entries: VecDeque<(u128, u64)>,
+    changed: bool,
+    persisted_at: u64,
+}
+
+impl MessageDeduplicator {
+    pub fn new(window: DeduplicationWindow) -> Self {
+        let mut deduplicator = Self {
+            window,
+            ids: AHashSet::new(),
+            entries: VecDeque::new(),
+            changed: false,
+            persisted_at: 0,
+        };
+
+        if let Some(path) = &deduplicator.window.path {
+            if path.exists() {
+                match Self::load(path) {
+                    Ok(entries) => {
+                        trace!(
+                            "Loaded {} message IDs of the deduplication window from: {path:?}",
+                            entries.len()
+                        );
+                        for (id, inserted_at) in entries {
+                            deduplicator.push(id, inserted_at);
+                        }
+                    }
+                    Err(error) => {
+                        warn!("Failed to load the deduplication window from: {path:?}, starting with the empty one. {error}");
+                    }
+                }
+            }
+        }
+
+        deduplicator
+    }
+
+    /// Inserts the message ID into the window, returns `false` if it has already been delivered.
+    pub fn try_insert(&mut self, id: u128, now: IggyTimestamp) -> bool {
+        self.evict_expired(now);
+        if self.ids.contains(&id) {
+            return false;
+        }
+
+        self.push(id, now.as_micros());
+        self.changed = true;
+        true
+    }
+
+    /// Saves the window if it's persisted and has changed since it was last saved more than a second ago.
+    pub fn persist(&mut self, now: IggyTimestamp) {
+        if self.window.path.is_none() || !self.changed {
+            return;
+        }
+
+        if now.as_micros() < self.persisted_at + PERSIST_INTERVAL.as_micros() {
+            return;
+        }
+
+        self.persisted_at = now.as_micros();
+        if let Err(error) = self.save() {
+            warn!("Failed to persist the deduplication window. {error}");
+        }
+    }
+
+    fn push(&mut self, id: u128, inserted_at: u64) {
+        if !self.ids.insert(id) {
+            return;
+        }
+
+        self.entries.push_back((id, inserted_at));
+        while self.entries.len() > self.window.max_entries {
+            if let Some((evicted_id, _)) = self.entries.pop_front() {
+                self.ids.remove(&evicted_id);
+            }
+        }
+    }
+
+    fn evict_expired(&mut self, now: IggyTimestamp) {
+        let Some(ttl) = self.window.ttl else {
+            return;
+        };
+
+        while let Some((id, inserted_at)) = self.entries.front().copied() {
+            if inserted_at + ttl.as_micros() > now.as_micros() {
+                break;
+            }
+
+            self.entries.pop_front();
+            self.ids.remove(&id);
+            self.changed = true;
+        }
+    }
+
+    fn save(&mut self) -> Result<(), IggyError> {
+        let Some(path) = &self.window.path else {
+            return Ok(());
+        };
+
+        let mut bytes = Vec::with_capacity(self.entries.len() * ENTRY_SIZE);
+        for (id, inserted_at) in &self.entries {
+            bytes.extend_from_slice(&id.to_le_bytes());
+            bytes.extend_from_slice(&inserted_at.to_le_bytes());
+        }
+
+        // Write to the temporary file first, so that the window isn't corrupted if the process crashes while saving it.
+        let temp_path = path.with_extension("tmp");
+        std::fs::write(&temp_path, bytes).map_err(|_| IggyError::CannotWriteToFile)?;
+        std::fs::rename(&temp_path, path).map_err(|_| IggyError::CannotOverwriteFile)?;
+        self.changed = false;
+        Ok(())
+    }
+
+    fn load(path: &PathBuf) -> Result<Vec<(u128, u64)>, IggyError> {
+        let bytes = std::fs::read(path).map_err(|_| IggyError::CannotReadFile)?;
+        if bytes.len() % ENTRY_SIZE != 0 {
+            return Err(IggyError::CannotReadFile);
+        }
+
+        let entries = bytes
+            .chunks_exact(ENTRY_SIZE)
+            .map(|entry| {
+                let id = u128::from_le_bytes(entry[..16].try_into().unwrap());
+                let inserted_at = u64::from_le_bytes(entry[16..].try_into().unwrap());
+                (id, inserted_at)
+            })
+            .collect();
+        Ok(entries)
+    }
+}
+
+impl Drop for MessageDeduplicator {
+    fn drop(&mut self) {
+        if self.window.path.is_none() || !self.changed {
+            return;
+        }
+
+        if let Err(error) = self.save() {
+            warn!("Failed to persist the deduplication window. {error}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deduplicator_should_insert_only_unique_identifiers() {
+        let mut deduplicator = MessageDeduplicator::new(DeduplicationWindow::new(100));
+        let now = IggyTimestamp::now();
+        for id in 0..100 {
+            assert!(deduplicator.try_insert(id, now));
+            assert!(!deduplicator.try_insert(id, now));
+        }
+    }
+
+    #[test]
+    fn deduplicator_should_evict_the_oldest_identifiers_when_window_is_full() {
+        let mut deduplicator = MessageDeduplicator::new(DeduplicationWindow::new(2));
+        let now = IggyTimestamp::now();
+        assert!(deduplicator.try_insert(1, now));
+        assert!(deduplicator.try_insert(2, now));
+        assert!(deduplicator.try_insert(3, now));
+        assert!(deduplicator.try_insert(1, now));
+        assert!(!deduplicator.try_insert(3, now));
+    }
+
+    #[test]
+    fn deduplicator_should_evict_identifiers_after_given_time_to_live() {
+        let ttl = IggyDuration::ONE_SECOND;
+        let mut deduplicator = MessageDeduplicator::new(DeduplicationWindow::new(100).ttl(ttl));
+        let now = IggyTimestamp::from(1_000_000);
+        assert!(deduplicator.try_insert(1, now));
+        assert!(!deduplicator.try_insert(1, IggyTimestamp::from(1_500_000)));
+        assert!(deduplicator.try_insert(1, IggyTimestamp::from(2_000_000)));
+    }
+
+    #[test]
+    fn persisted_window_should_be_loaded_by_the_new_deduplicator() {
+        let path = std::env::temp_dir().join(format!(
+            "iggy_deduplication_window_{}.bin",
+            uuid::Uuid::now_v7()
+        ));
+        let window = DeduplicationWindow::new(100).persist_to(&path);
+        let mut deduplicator = MessageDeduplicator::new(window.clone());
+        assert!(deduplicator.try_insert(1, IggyTimestamp::now()));
+        assert!(deduplicator.try_insert(2, IggyTimestamp::now()));
+        drop(deduplicator);
+
+        let mut deduplicator = MessageDeduplicator::new(window);
+        assert!(!deduplicator.try_insert(1, IggyTimestamp::now()));
+        assert!(!deduplicator.try_insert(2, IggyTimestamp::now()));
+        assert!(deduplicator.try_insert(3, IggyTimestamp::now()));
+        drop(deduplicator);
+        std::fs::remove_file(path).unwrap();
+    }
+}
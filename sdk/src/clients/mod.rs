@@ -18,5 +18,10 @@
 
 pub mod builder;
 pub mod client;
+pub mod cluster_router;
 pub mod consumer;
+pub mod consumer_channel;
+pub mod consumer_deduplicator;
+pub mod multi_topic_consumer;
 pub mod producer;
+pub mod request_reply;
@@ -0,0 +1,284 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::clients::consumer::{IggyConsumer, IggyConsumerBuilder, ReceivedMessage};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::duration::IggyDuration;
+use futures::stream::SelectAll;
+use futures::Stream;
+use futures_util::StreamExt;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// The source of the message consumed by the multi-topic consumer,
+/// i.e. the stream and topic, and the consumer (or consumer group) the message was polled by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageSource {
+    /// The name of the consumer or consumer group.
+    pub consumer_name: String,
+    /// Whether the messages are consumed as a part of the consumer group.
+    pub is_consumer_group: bool,
+    /// The stream ID of the source.
+    pub stream: Identifier,
+    /// The topic ID of the source.
+    pub topic: Identifier,
+}
+
+/// The message consumed by the multi-topic consumer, along with its source.
+pub struct SourcedMessage {
+    pub source: Arc<MessageSource>,
+    pub message: ReceivedMessage,
+}
+
+/// The consumer merging the messages of many topics (each consumed by its own consumer or consumer group) into a single stream,
+/// so that a service aggregating the topics doesn't have to poll each of them separately.
+///
+/// The sources are polled concurrently, thus the messages are ordered only within a single partition of a single topic.
+/// The stream ends once all the underlying consumers have stopped.
+pub struct IggyMultiTopicConsumer {
+    initialized: bool,
+    consumers: SelectAll<Sourced<IggyConsumer>>,
+}
+
+impl IggyMultiTopicConsumer {
+    /// Creates the multi-topic consumer from the already built consumers, each of them must be for a distinct source.
+    pub fn new(consumers: Vec<IggyConsumer>) -> Self {
+        let consumers = consumers.into_iter().map(|consumer| {
+            let source = Arc::new(MessageSource {
+                consumer_name: consumer.name().to_owned(),
+                is_consumer_group: consumer.is_consumer_group(),
+                stream: consumer.stream().clone(),
+                topic: consumer.topic().clone(),
+            });
+            (source, consumer)
+        });
+        Self {
+            initialized: false,
+            consumers: merge_sources(consumers),
+        }
+    }
+
+    /// Returns the sources of the messages which are still being consumed.
+    pub fn sources(&self) -> Vec<Arc<MessageSource>> {
+        self.consumers
+            .iter()
+            .map(|consumer| consumer.source.clone())
+            .collect()
+    }
+
+    /// Initializes all the underlying consumers, see `IggyConsumer::init()`.
+    ///
+    /// Note: This method must be called before polling messages.
+    pub async fn init(&mut self) -> Result<(), IggyError> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        if self.consumers.is_empty() {
+            return Err(IggyError::InvalidConfiguration);
+        }
+
+        for consumer in self.consumers.iter_mut() {
+            consumer.stream.init().await?;
+        }
+
+        self.initialized = true;
+        Ok(())
+    }
+
+    /// Returns the underlying consumer of the given source.
+    pub fn consumer(&self, source: &MessageSource) -> Result<&IggyConsumer, IggyError> {
+        self.consumers
+            .iter()
+            .find(|consumer| consumer.source.as_ref() == source)
+            .map(|consumer| &consumer.stream)
+            .ok_or(IggyError::InvalidConfiguration)
+    }
+
+    /// Stores the consumer offset of the given source on the server, either for its current partition or the provided partition ID.
+    pub async fn store_offset(
+        &self,
+        source: &MessageSource,
+        offset: u64,
+        partition_id: Option<u32>,
+    ) -> Result<(), IggyError> {
+        self.consumer(source)?
+            .store_offset(offset, partition_id)
+            .await
+    }
+
+    /// Rejects the received message using the consumer of its source, see `IggyConsumer::reject()`.
+    pub async fn reject(&self, message: &SourcedMessage, reason: &str) -> Result<(), IggyError> {
+        self.consumer(&message.source)?
+            .reject(&message.message, reason)
+            .await
+    }
+
+    /// Negatively acknowledges the received message using the consumer of its source, see `IggyConsumer::nack()`.
+    pub async fn nack(
+        &self,
+        message: &SourcedMessage,
+        redelivery_delay: Option<IggyDuration>,
+    ) -> Result<(), IggyError> {
+        self.consumer(&message.source)?
+            .nack(&message.message, redelivery_delay)
+            .await
+    }
+}
+
+impl Stream for IggyMultiTopicConsumer {
+    type Item = Result<SourcedMessage, IggyError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.consumers.poll_next_unpin(cx)
+    }
+}
+
+/// The builder of the multi-topic consumer, holding the builder of the consumer for each source.
+pub struct IggyMultiTopicConsumerBuilder {
+    consumers: Vec<IggyConsumerBuilder>,
+}
+
+impl IggyMultiTopicConsumerBuilder {
+    pub(crate) fn new(consumers: Vec<IggyConsumerBuilder>) -> Self {
+        Self { consumers }
+    }
+
+    /// Adds the source consumed by the given consumer or consumer group.
+    pub fn source(mut self, consumer: IggyConsumerBuilder) -> Self {
+        self.consumers.push(consumer);
+        self
+    }
+
+    /// Applies the same configuration (e.g. the batch size or the auto-commit) to the consumers of all the sources added so far.
+    pub fn configure<F>(self, configure: F) -> Self
+    where
+        F: Fn(IggyConsumerBuilder) -> IggyConsumerBuilder,
+    {
+        Self {
+            consumers: self.consumers.into_iter().map(configure).collect(),
+        }
+    }
+
+    /// Builds the multi-topic consumer.
+    ///
+    /// Note: After building the consumer, `init()` must be invoked before consuming messages.
+    pub fn build(self) -> IggyMultiTopicConsumer {
+        IggyMultiTopicConsumer::new(
+            self.consumers
+                .into_iter()
+                .map(IggyConsumerBuilder::build)
+                .collect(),
+        )
+    }
+}
+
+/// The stream of the messages tagged with their source.
+struct Sourced<S> {
+    source: Arc<MessageSource>,
+    stream: S,
+}
+
+impl<S> Stream for Sourced<S>
+where
+    S: Stream<Item = Result<ReceivedMessage, IggyError>> + Unpin,
+{
+    type Item = Result<SourcedMessage, IggyError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let source = self.source.clone();
+        self.stream.poll_next_unpin(cx).map(|message| {
+            message.map(|message| message.map(|message| SourcedMessage { source, message }))
+        })
+    }
+}
+
+fn merge_sources<S, I>(sources: I) -> SelectAll<Sourced<S>>
+where
+    S: Stream<Item = Result<ReceivedMessage, IggyError>> + Unpin,
+    I: IntoIterator<Item = (Arc<MessageSource>, S)>,
+{
+    futures::stream::select_all(
+        sources
+            .into_iter()
+            .map(|(source, stream)| Sourced { source, stream }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::messages::{MessageState, PolledMessage};
+    use crate::utils::timestamp::IggyTimestamp;
+    use bytes::Bytes;
+    use futures::stream;
+
+    fn source(topic: u32) -> Arc<MessageSource> {
+        Arc::new(MessageSource {
+            consumer_name: "aggregator".to_owned(),
+            is_consumer_group: true,
+            stream: Identifier::numeric(1).unwrap(),
+            topic: Identifier::numeric(topic).unwrap(),
+        })
+    }
+
+    fn messages(count: u64) -> impl Stream<Item = Result<ReceivedMessage, IggyError>> + Unpin {
+        stream::iter((0..count).map(|offset| {
+            let message = PolledMessage::create(
+                offset,
+                MessageState::Available,
+                IggyTimestamp::now(),
+                offset as u128,
+                Bytes::from("message"),
+                0,
+                None,
+            );
+            Ok(ReceivedMessage::new(message, offset, 1))
+        }))
+    }
+
+    #[tokio::test]
+    async fn merged_sources_should_deliver_all_messages_tagged_with_their_source() {
+        let merged = merge_sources(vec![(source(1), messages(3)), (source(2), messages(5))]);
+
+        let messages = merged
+            .map(|message| message.unwrap())
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(messages.len(), 8);
+        for topic in [1, 2] {
+            let offsets = messages
+                .iter()
+                .filter(|message| message.source.topic == Identifier::numeric(topic).unwrap())
+                .map(|message| message.message.message.offset)
+                .collect::<Vec<_>>();
+            let expected_count = if topic == 1 { 3 } else { 5 };
+            assert_eq!(offsets, (0..expected_count).collect::<Vec<_>>());
+        }
+    }
+
+    #[tokio::test]
+    async fn merged_sources_should_end_once_all_sources_have_ended() {
+        let mut merged = merge_sources(vec![(source(1), messages(0)), (source(2), messages(1))]);
+
+        assert!(merged.next().await.is_some());
+        assert!(merged.next().await.is_none());
+    }
+}
@@ -17,12 +17,17 @@
  */
 
 use crate::client::Client;
+use crate::clients::consumer::IggyConsumer;
+use crate::compression::codec::{self, Codec};
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
+use crate::headers::trace_context::TraceContextProvider;
+use crate::headers::well_known;
 use crate::identifier::{IdKind, Identifier};
 use crate::locking::{IggySharedMut, IggySharedMutFn};
-use crate::messages::send_messages::{Message, Partitioning};
+use crate::messages::send_messages::{Message, Partitioning, PartitioningKind};
+use crate::models::transaction;
 use crate::partitioner::Partitioner;
 use crate::utils::crypto::EncryptorKind;
 use crate::utils::duration::IggyDuration;
@@ -43,6 +48,30 @@ const MAX_BATCH_SIZE: usize = 1000000;
 unsafe impl Send for IggyProducer {}
 unsafe impl Sync for IggyProducer {}
 
+/// The report of delivering a batch of messages to the server, published for every batch sent by the producer if the delivery reports are enabled.
+#[derive(Debug)]
+pub struct DeliveryReport {
+    /// The stream to which the messages were sent.
+    pub stream: Arc<Identifier>,
+    /// The topic to which the messages were sent.
+    pub topic: Arc<Identifier>,
+    /// The partition ID, available only if it was provided by the client - otherwise, the partition is selected by the server.
+    pub partition_id: Option<u32>,
+    /// The IDs of the messages in the batch.
+    pub messages_ids: Vec<u128>,
+    /// The timestamp when the batch was sent.
+    pub sent_at: IggyTimestamp,
+    /// The error, if the batch could not be delivered (including the retries).
+    pub error: Option<IggyError>,
+}
+
+impl DeliveryReport {
+    /// Returns `true` if the batch has been delivered to the server.
+    pub fn is_delivered(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
 pub struct IggyProducer {
     initialized: bool,
     can_send: Arc<AtomicBool>,
@@ -54,7 +83,11 @@ pub struct IggyProducer {
     batch_size: Option<usize>,
     partitioning: Option<Arc<Partitioning>>,
     encryptor: Option<Arc<EncryptorKind>>,
+    codec: Option<Arc<dyn Codec>>,
+    schema_id: Option<u32>,
+    content_type: Option<String>,
     partitioner: Option<Arc<dyn Partitioner>>,
+    trace_context_provider: Option<Arc<dyn TraceContextProvider>>,
     send_interval_micros: u64,
     create_stream_if_not_exists: bool,
     create_topic_if_not_exists: bool,
@@ -67,6 +100,10 @@ pub struct IggyProducer {
     last_sent_at: Arc<AtomicU64>,
     send_retries_count: Option<u32>,
     send_retries_interval: Option<IggyDuration>,
+    delivery_reports: Option<(
+        flume::Sender<DeliveryReport>,
+        flume::Receiver<DeliveryReport>,
+    )>,
 }
 
 impl IggyProducer {
@@ -80,7 +117,11 @@ impl IggyProducer {
         batch_size: Option<usize>,
         partitioning: Option<Partitioning>,
         encryptor: Option<Arc<EncryptorKind>>,
+        codec: Option<Arc<dyn Codec>>,
+        schema_id: Option<u32>,
+        content_type: Option<String>,
         partitioner: Option<Arc<dyn Partitioner>>,
+        trace_context_provider: Option<Arc<dyn TraceContextProvider>>,
         interval: Option<IggyDuration>,
         create_stream_if_not_exists: bool,
         create_topic_if_not_exists: bool,
@@ -90,6 +131,7 @@ impl IggyProducer {
         topic_max_size: MaxTopicSize,
         send_retries_count: Option<u32>,
         send_retries_interval: Option<IggyDuration>,
+        delivery_reports: bool,
     ) -> Self {
         Self {
             initialized: false,
@@ -102,7 +144,11 @@ impl IggyProducer {
             batch_size,
             partitioning: partitioning.map(Arc::new),
             encryptor,
+            codec,
+            schema_id,
+            content_type,
             partitioner,
+            trace_context_provider,
             send_interval_micros: interval.map_or(0, |i| i.as_micros()),
             create_stream_if_not_exists,
             create_topic_if_not_exists,
@@ -115,6 +161,7 @@ impl IggyProducer {
             last_sent_at: Arc::new(AtomicU64::new(0)),
             send_retries_count,
             send_retries_interval,
+            delivery_reports: delivery_reports.then(flume::unbounded),
         }
     }
 
@@ -126,6 +173,14 @@ impl IggyProducer {
         &self.topic_id
     }
 
+    /// Returns the receiver of the delivery reports, available only if the delivery reports have been enabled when building the producer.
+    /// The report is published for every sent batch of messages, regardless of the result.
+    pub fn delivery_reports(&self) -> Option<flume::Receiver<DeliveryReport>> {
+        self.delivery_reports
+            .as_ref()
+            .map(|(_, receiver)| receiver.clone())
+    }
+
     /// Initializes the producer by subscribing to diagnostic events, creating the stream and topic if they do not exist etc.
     ///
     /// Note: This method must be invoked before producing messages.
@@ -179,7 +234,7 @@ impl IggyProducer {
                     &self.stream_id,
                     &self.topic_name,
                     self.topic_partitions_count,
-                    CompressionAlgorithm::None,
+                    self.get_compression_algorithm(),
                     self.topic_replication_factor,
                     id,
                     self.topic_message_expiry,
@@ -219,6 +274,10 @@ impl IggyProducer {
                         can_send.store(false, ORDERING);
                         warn!("Disconnected from the server");
                     }
+                    DiagnosticEvent::Reconnecting => {
+                        can_send.store(false, ORDERING);
+                        trace!("Reconnecting to the server");
+                    }
                     DiagnosticEvent::SignedIn => {
                         can_send.store(true, ORDERING);
                     }
@@ -251,6 +310,18 @@ impl IggyProducer {
         .await
     }
 
+    /// Begins the transaction, within which the messages can be sent to multiple streams, topics and partitions,
+    /// and become visible to the consumers atomically, once the transaction is committed.
+    /// The messages sent within the transaction are never buffered, regardless of the configured batch size and send interval.
+    pub async fn begin_transaction(&self) -> Result<IggyProducerTransaction<'_>, IggyError> {
+        let transaction_id = self.client.read().await.begin_transaction().await?;
+        trace!("Began transaction with ID: {transaction_id}.");
+        Ok(IggyProducerTransaction {
+            producer: self,
+            transaction_id,
+        })
+    }
+
     pub async fn send_one(&self, message: Message) -> Result<(), IggyError> {
         self.send(vec![message]).await
     }
@@ -309,6 +380,8 @@ impl IggyProducer {
         mut messages: Vec<Message>,
         partitioning: Option<Arc<Partitioning>>,
     ) -> Result<(), IggyError> {
+        self.attach_headers(&mut messages)?;
+        self.compress_messages(&mut messages)?;
         self.encrypt_messages(&mut messages)?;
         let partitioning = self.get_partitioning(&stream, &topic, &messages, partitioning)?;
         let batch_size = self.batch_size.unwrap_or(MAX_BATCH_SIZE);
@@ -328,9 +401,7 @@ impl IggyProducer {
             trace!(
                 "Sending {messages_count} messages ({current_batch}/{batches_count} batch(es))..."
             );
-            self.last_sent_at
-                .store(IggyTimestamp::now().into(), ORDERING);
-            self.try_send_messages(&self.stream_id, &self.topic_id, &partitioning, batch)
+            self.send_batch(&self.stream_id, &self.topic_id, &partitioning, batch)
                 .await?;
             trace!("Sent {messages_count} messages ({current_batch}/{batches_count} batch(es)).");
             current_batch += 1;
@@ -346,26 +417,65 @@ impl IggyProducer {
         partitioning: Option<Arc<Partitioning>>,
     ) -> Result<(), IggyError> {
         trace!("No batch size specified, sending messages immediately.");
+        self.attach_headers(&mut messages)?;
+        self.compress_messages(&mut messages)?;
         self.encrypt_messages(&mut messages)?;
         let partitioning = self.get_partitioning(stream, topic, &messages, partitioning)?;
         let batch_size = self.batch_size.unwrap_or(MAX_BATCH_SIZE);
         if messages.len() <= batch_size {
-            self.last_sent_at
-                .store(IggyTimestamp::now().into(), ORDERING);
-            self.try_send_messages(stream, topic, &partitioning, &mut messages)
-                .await?;
-            return Ok(());
+            return self
+                .send_batch(stream, topic, &partitioning, &mut messages)
+                .await;
         }
 
         for batch in messages.chunks_mut(batch_size) {
-            self.last_sent_at
-                .store(IggyTimestamp::now().into(), ORDERING);
-            self.try_send_messages(stream, topic, &partitioning, batch)
-                .await?;
+            self.send_batch(stream, topic, &partitioning, batch).await?;
         }
         Ok(())
     }
 
+    async fn send_batch(
+        &self,
+        stream: &Identifier,
+        topic: &Identifier,
+        partitioning: &Arc<Partitioning>,
+        messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        let sent_at = IggyTimestamp::now();
+        self.last_sent_at.store(sent_at.into(), ORDERING);
+        let result = self
+            .try_send_messages(stream, topic, partitioning, messages)
+            .await;
+        let Some((sender, _)) = &self.delivery_reports else {
+            return result;
+        };
+
+        let partition_id = match partitioning.kind {
+            PartitioningKind::PartitionId => partitioning
+                .value
+                .as_slice()
+                .try_into()
+                .ok()
+                .map(u32::from_le_bytes),
+            _ => None,
+        };
+        let report = DeliveryReport {
+            stream: Arc::new(stream.clone()),
+            topic: Arc::new(topic.clone()),
+            partition_id,
+            messages_ids: messages.iter().map(|message| message.id).collect(),
+            sent_at,
+            error: result
+                .as_ref()
+                .err()
+                .map(|error| IggyError::from_code(error.as_code())),
+        };
+        if let Err(error) = sender.send(report) {
+            warn!("Failed to publish the delivery report: {error}");
+        }
+        result
+    }
+
     async fn wait_before_sending(interval: u64, last_sent_at: u64) {
         if interval == 0 {
             return;
@@ -383,6 +493,60 @@ impl IggyProducer {
         sleep(Duration::from_micros(remaining)).await;
     }
 
+    fn get_compression_algorithm(&self) -> CompressionAlgorithm {
+        self.codec
+            .as_ref()
+            .map_or(CompressionAlgorithm::None, |codec| {
+                CompressionAlgorithm::from_code(codec.id()).unwrap_or_default()
+            })
+    }
+
+    /// Attaches the well known headers configured for the producer, before the payload is compressed or encrypted.
+    fn attach_headers(&self, messages: &mut [Message]) -> Result<(), IggyError> {
+        for message in messages.iter_mut() {
+            if let Some(schema_id) = self.schema_id {
+                well_known::set_schema_id(&mut message.headers, schema_id)?;
+            }
+            if let Some(content_type) = &self.content_type {
+                well_known::set_content_type(&mut message.headers, content_type)?;
+            }
+        }
+        self.attach_traceparent(messages)
+    }
+
+    /// Attaches the `traceparent` of the current span, unless the message already carries its own trace context.
+    fn attach_traceparent(&self, messages: &mut [Message]) -> Result<(), IggyError> {
+        let Some(traceparent) = self
+            .trace_context_provider
+            .as_ref()
+            .and_then(|provider| provider.traceparent())
+        else {
+            return Ok(());
+        };
+
+        for message in messages {
+            if well_known::get_traceparent(&message.headers)?.is_some() {
+                continue;
+            }
+            if let Err(error) = well_known::set_traceparent(&mut message.headers, &traceparent) {
+                warn!("Invalid traceparent: {traceparent} returned by the trace context provider, skipping. {error}");
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+
+    fn compress_messages(&self, messages: &mut [Message]) -> Result<(), IggyError> {
+        if let Some(codec) = &self.codec {
+            for message in messages {
+                message.payload = Bytes::from(codec.compress(&message.payload)?);
+                message.length = message.payload.len() as u32;
+                codec::set_codec_id(&mut message.headers, codec.id())?;
+            }
+        }
+        Ok(())
+    }
+
     fn encrypt_messages(&self, messages: &mut [Message]) -> Result<(), IggyError> {
         if let Some(encryptor) = &self.encryptor {
             for message in messages {
@@ -534,6 +698,107 @@ impl IggyProducer {
     }
 }
 
+/// The transaction begun by the producer. The messages sent within it become visible to the consumers only once it's committed.
+/// The transaction is aborted by the server if the client disconnects before committing it.
+pub struct IggyProducerTransaction<'a> {
+    producer: &'a IggyProducer,
+    transaction_id: u64,
+}
+
+impl IggyProducerTransaction<'_> {
+    /// Returns the unique ID of the transaction.
+    pub fn id(&self) -> u64 {
+        self.transaction_id
+    }
+
+    /// Sends the messages within the transaction to the stream and topic of the producer.
+    pub async fn send(&self, messages: Vec<Message>) -> Result<(), IggyError> {
+        let producer = self.producer;
+        self.send_to(
+            producer.stream_id.clone(),
+            producer.topic_id.clone(),
+            messages,
+            None,
+        )
+        .await
+    }
+
+    /// Sends the messages within the transaction to the given stream and topic, using the optional partitioning.
+    pub async fn send_to(
+        &self,
+        stream: Arc<Identifier>,
+        topic: Arc<Identifier>,
+        mut messages: Vec<Message>,
+        partitioning: Option<Arc<Partitioning>>,
+    ) -> Result<(), IggyError> {
+        if messages.is_empty() {
+            trace!("No messages to send.");
+            return Ok(());
+        }
+
+        for message in messages.iter_mut() {
+            transaction::set_transaction_id(&mut message.headers, self.transaction_id)?;
+        }
+        self.producer
+            .send_immediately(&stream, &topic, messages, partitioning)
+            .await
+    }
+
+    /// Sends the offset of the consumer either for its current partition or the provided partition ID to the transaction.
+    /// The offset is stored only once the transaction is committed, so that consuming the input messages
+    /// and producing the output messages happen atomically. The consumer shouldn't auto commit the offsets on its own.
+    pub async fn send_offsets(
+        &self,
+        consumer: &IggyConsumer,
+        offset: u64,
+        partition_id: Option<u32>,
+    ) -> Result<(), IggyError> {
+        let partition_id = partition_id.unwrap_or_else(|| consumer.partition_id());
+        self.producer
+            .client
+            .read()
+            .await
+            .send_offsets_to_transaction(
+                self.transaction_id,
+                consumer.consumer(),
+                consumer.stream(),
+                consumer.topic(),
+                Some(partition_id),
+                offset,
+            )
+            .await?;
+        trace!(
+            "Sent offset: {offset} for partition: {partition_id} to transaction with ID: {}.",
+            self.transaction_id
+        );
+        Ok(())
+    }
+
+    /// Commits the transaction, making all the messages sent within it visible to the consumers at once.
+    pub async fn commit(self) -> Result<(), IggyError> {
+        self.producer
+            .client
+            .read()
+            .await
+            .commit_transaction(self.transaction_id)
+            .await?;
+        trace!("Committed transaction with ID: {}.", self.transaction_id);
+        Ok(())
+    }
+
+    /// Aborts the transaction, so that none of the messages sent within it is ever visible to the consumers.
+    pub async fn abort(self) -> Result<(), IggyError> {
+        self.producer
+            .client
+            .read()
+            .await
+            .abort_transaction(self.transaction_id)
+            .await?;
+        trace!("Aborted transaction with ID: {}.", self.transaction_id);
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct IggyProducerBuilder {
     client: IggySharedMut<Box<dyn Client>>,
@@ -544,7 +809,11 @@ pub struct IggyProducerBuilder {
     batch_size: Option<usize>,
     partitioning: Option<Partitioning>,
     encryptor: Option<Arc<EncryptorKind>>,
+    codec: Option<Arc<dyn Codec>>,
+    schema_id: Option<u32>,
+    content_type: Option<String>,
     partitioner: Option<Arc<dyn Partitioner>>,
+    trace_context_provider: Option<Arc<dyn TraceContextProvider>>,
     send_interval: Option<IggyDuration>,
     create_stream_if_not_exists: bool,
     create_topic_if_not_exists: bool,
@@ -554,6 +823,7 @@ pub struct IggyProducerBuilder {
     send_retries_interval: Option<IggyDuration>,
     topic_message_expiry: IggyExpiry,
     topic_max_size: MaxTopicSize,
+    delivery_reports: bool,
 }
 
 impl IggyProducerBuilder {
@@ -576,7 +846,11 @@ impl IggyProducerBuilder {
             batch_size: Some(1000),
             partitioning: None,
             encryptor,
+            codec: None,
+            schema_id: None,
+            content_type: None,
             partitioner,
+            trace_context_provider: None,
             send_interval: Some(IggyDuration::from(1000)),
             create_stream_if_not_exists: true,
             create_topic_if_not_exists: true,
@@ -586,6 +860,7 @@ impl IggyProducerBuilder {
             topic_max_size: MaxTopicSize::ServerDefault,
             send_retries_count: Some(3),
             send_retries_interval: Some(IggyDuration::ONE_SECOND),
+            delivery_reports: false,
         }
     }
 
@@ -651,6 +926,56 @@ impl IggyProducerBuilder {
         }
     }
 
+    /// Sets the codec for compressing the messages' payloads (before they are encrypted).
+    /// The codec ID is attached to each message, and used as the compression algorithm when the topic is created.
+    pub fn codec(self, codec: Arc<dyn Codec>) -> Self {
+        Self {
+            codec: Some(codec),
+            ..self
+        }
+    }
+
+    /// Clears the codec for compressing the messages' payloads.
+    pub fn without_codec(self) -> Self {
+        Self {
+            codec: None,
+            ..self
+        }
+    }
+
+    /// Sets the ID of the schema registered in the schema registry, which is attached to each message,
+    /// so that the consumers can resolve the matching schema version.
+    pub fn schema_id(self, schema_id: u32) -> Self {
+        Self {
+            schema_id: Some(schema_id),
+            ..self
+        }
+    }
+
+    /// Clears the ID of the registered schema attached to each message.
+    pub fn without_schema_id(self) -> Self {
+        Self {
+            schema_id: None,
+            ..self
+        }
+    }
+
+    /// Sets the format of the payload, e.g. `application/json`, which is attached to each message.
+    pub fn content_type(self, content_type: &str) -> Self {
+        Self {
+            content_type: Some(content_type.to_owned()),
+            ..self
+        }
+    }
+
+    /// Clears the format of the payload attached to each message.
+    pub fn without_content_type(self) -> Self {
+        Self {
+            content_type: None,
+            ..self
+        }
+    }
+
     /// Sets the partitioning strategy for messages.
     pub fn partitioning(self, partitioning: Partitioning) -> Self {
         Self {
@@ -683,6 +1008,22 @@ impl IggyProducerBuilder {
         }
     }
 
+    /// Sets the trace context provider used to attach the `traceparent` header to the produced messages.
+    pub fn trace_context_provider(self, provider: Arc<dyn TraceContextProvider>) -> Self {
+        Self {
+            trace_context_provider: Some(provider),
+            ..self
+        }
+    }
+
+    /// Clears the trace context provider.
+    pub fn without_trace_context_provider(self) -> Self {
+        Self {
+            trace_context_provider: None,
+            ..self
+        }
+    }
+
     /// Creates the stream if it does not exist - requires user to have the necessary permissions.
     pub fn create_stream_if_not_exists(self) -> Self {
         Self {
@@ -736,6 +1077,23 @@ impl IggyProducerBuilder {
         }
     }
 
+    /// Enables the delivery reports, which can be received via `delivery_reports()` on the producer, disabled by default.
+    /// Make sure to consume the reports, as they are buffered until received.
+    pub fn with_delivery_reports(self) -> Self {
+        Self {
+            delivery_reports: true,
+            ..self
+        }
+    }
+
+    /// Disables the delivery reports.
+    pub fn without_delivery_reports(self) -> Self {
+        Self {
+            delivery_reports: false,
+            ..self
+        }
+    }
+
     /// Builds the producer.
     ///
     /// Note: After building the producer, `init()` must be invoked before producing messages.
@@ -749,7 +1107,11 @@ impl IggyProducerBuilder {
             self.batch_size,
             self.partitioning,
             self.encryptor,
+            self.codec,
+            self.schema_id,
+            self.content_type,
             self.partitioner,
+            self.trace_context_provider,
             self.send_interval,
             self.create_stream_if_not_exists,
             self.create_topic_if_not_exists,
@@ -759,6 +1121,7 @@ impl IggyProducerBuilder {
             self.topic_max_size,
             self.send_retries_count,
             self.send_retries_interval,
+            self.delivery_reports,
         )
     }
 }
@@ -0,0 +1,233 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::clients::consumer::{IggyConsumer, ReceivedMessage};
+use crate::clients::producer::IggyProducer;
+use crate::error::IggyError;
+use crate::headers::well_known;
+use crate::messages::send_messages::Message;
+use crate::utils::duration::IggyDuration;
+use bytes::Bytes;
+use dashmap::DashMap;
+use futures_util::StreamExt;
+use std::sync::Arc;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tracing::{error, info, trace, warn};
+use uuid::Uuid;
+
+pub use well_known::{get_correlation_id, set_correlation_id};
+
+/// The header key under which the correlation ID of the request is attached to both the request and the reply.
+pub const CORRELATION_ID_HEADER_KEY: &str = well_known::CORRELATION_ID;
+
+type PendingRequests = DashMap<u128, oneshot::Sender<ReceivedMessage>>;
+
+/// Creates the reply to the received request, carrying over its correlation ID, so that the requester can match it.
+///
+/// Returns `IggyError::MissingCorrelationId` if the request was not sent by the `IggyRequester`.
+pub fn create_reply(request: &ReceivedMessage, payload: Bytes) -> Result<Message, IggyError> {
+    let Some(correlation_id) = get_correlation_id(&request.message.headers)? else {
+        return Err(IggyError::MissingCorrelationId);
+    };
+
+    let mut headers = None;
+    set_correlation_id(&mut headers, correlation_id)?;
+    Ok(Message::new(None, payload, headers))
+}
+
+/// `IggyRequester` implements the request-reply pattern on top of two topics.
+///
+/// Each request is sent by the producer with the unique correlation ID attached as a header,
+/// while the replies are polled in the background by the consumer and matched with the outstanding requests by their correlation ID.
+/// Many requests can be outstanding at the same time, and each of them completes either with the reply or with `IggyError::RequestTimedOut`.
+///
+/// The responder is expected to send the replies created with `create_reply()` to the topic the requester consumer is polling from.
+pub struct IggyRequester {
+    producer: IggyProducer,
+    pending: Arc<PendingRequests>,
+    timeout: IggyDuration,
+    listener: JoinHandle<()>,
+}
+
+impl IggyRequester {
+    /// Creates the requester from the initialized producer of the requests and the initialized consumer of the replies.
+    /// The replies which do not match any outstanding request (e.g. received after the timeout) are discarded.
+    pub fn new(producer: IggyProducer, consumer: IggyConsumer, timeout: IggyDuration) -> Self {
+        let pending = Arc::new(PendingRequests::new());
+        let listener = tokio::spawn(Self::handle_replies(consumer, pending.clone()));
+        Self {
+            producer,
+            pending,
+            timeout,
+            listener,
+        }
+    }
+
+    /// Returns the number of requests which are still waiting for the reply.
+    pub fn outstanding_requests(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Sends the request and waits for the matching reply, up to the configured timeout.
+    pub async fn request(&self, payload: Bytes) -> Result<ReceivedMessage, IggyError> {
+        self.request_with_timeout(payload, self.timeout).await
+    }
+
+    /// Sends the request and waits for the matching reply, up to the given timeout.
+    pub async fn request_with_timeout(
+        &self,
+        payload: Bytes,
+        timeout: IggyDuration,
+    ) -> Result<ReceivedMessage, IggyError> {
+        let correlation_id = Uuid::now_v7().to_u128_le();
+        let mut headers = None;
+        set_correlation_id(&mut headers, correlation_id)?;
+        let message = Message::new(None, payload, headers);
+
+        let (sender, receiver) = oneshot::channel();
+        self.pending.insert(correlation_id, sender);
+        if let Err(error) = self.producer.send_one(message).await {
+            self.pending.remove(&correlation_id);
+            return Err(error);
+        }
+
+        trace!("Sent request with correlation ID: {correlation_id}, waiting for the reply...");
+        match tokio::time::timeout(timeout.get_duration(), receiver).await {
+            Ok(Ok(reply)) => Ok(reply),
+            Ok(Err(_)) => Err(IggyError::ClientShutdown),
+            Err(_) => {
+                self.pending.remove(&correlation_id);
+                warn!(
+                    "Request with correlation ID: {correlation_id} has timed out after: {timeout}."
+                );
+                Err(IggyError::RequestTimedOut(correlation_id))
+            }
+        }
+    }
+
+    /// Stops polling the replies, the outstanding requests complete with `IggyError::ClientShutdown`.
+    pub fn shutdown(&self) {
+        self.listener.abort();
+        self.pending.clear();
+    }
+
+    async fn handle_replies(mut consumer: IggyConsumer, pending: Arc<PendingRequests>) {
+        info!(
+            "Polling replies by consumer: {} from stream: {}, topic: {}...",
+            consumer.name(),
+            consumer.stream(),
+            consumer.topic()
+        );
+        while let Some(reply) = consumer.next().await {
+            let reply = match reply {
+                Ok(reply) => reply,
+                Err(IggyError::ClientShutdown) => break,
+                Err(error) => {
+                    error!("Error while polling replies: {error}");
+                    continue;
+                }
+            };
+
+            let correlation_id = match get_correlation_id(&reply.message.headers) {
+                Ok(Some(correlation_id)) => correlation_id,
+                Ok(None) => {
+                    warn!(
+                        "Received reply without correlation ID at offset: {}, skipping it.",
+                        reply.message.offset
+                    );
+                    continue;
+                }
+                Err(error) => {
+                    error!(
+                        "Invalid correlation ID of the reply at offset: {}, error: {error}",
+                        reply.message.offset
+                    );
+                    continue;
+                }
+            };
+
+            let Some((_, sender)) = pending.remove(&correlation_id) else {
+                trace!("Received reply with correlation ID: {correlation_id} for no outstanding request, skipping it.");
+                continue;
+            };
+
+            if sender.send(reply).is_err() {
+                trace!("Request with correlation ID: {correlation_id} is no longer awaiting the reply.");
+            }
+        }
+    }
+}
+
+impl Drop for IggyRequester {
+    fn drop(&mut self) {
+        self.listener.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::header::{HeaderKey, HeaderValue};
+    use crate::models::messages::{MessageState, PolledMessage};
+    use crate::utils::timestamp::IggyTimestamp;
+    use std::collections::HashMap;
+
+    #[test]
+    fn correlation_id_should_be_set_and_read_from_headers() {
+        let mut headers = None;
+        assert_eq!(get_correlation_id(&headers).unwrap(), None);
+
+        set_correlation_id(&mut headers, 42).unwrap();
+        assert_eq!(get_correlation_id(&headers).unwrap(), Some(42));
+    }
+
+    #[test]
+    fn reply_should_carry_over_correlation_id_of_request() {
+        let mut headers = None;
+        set_correlation_id(&mut headers, 42).unwrap();
+        let request = create_request(headers);
+
+        let reply = create_reply(&request, Bytes::from("reply")).unwrap();
+
+        assert_eq!(reply.payload, Bytes::from("reply"));
+        assert_eq!(get_correlation_id(&reply.headers).unwrap(), Some(42));
+    }
+
+    #[test]
+    fn reply_should_not_be_created_for_request_without_correlation_id() {
+        let request = create_request(None);
+
+        let result = create_reply(&request, Bytes::from("reply"));
+
+        assert!(matches!(result, Err(IggyError::MissingCorrelationId)));
+    }
+
+    fn create_request(headers: Option<HashMap<HeaderKey, HeaderValue>>) -> ReceivedMessage {
+        let message = PolledMessage::create(
+            0,
+            MessageState::Available,
+            IggyTimestamp::zero(),
+            1,
+            Bytes::from("request"),
+            0,
+            headers,
+        );
+        ReceivedMessage::new(message, 0, 1)
+    }
+}
@@ -27,10 +27,26 @@ pub trait Command: BytesSerializable + Validatable<IggyError> + Send + Sync + Di
 
 pub const PING: &str = "ping";
 pub const PING_CODE: u32 = 1;
+pub const ENABLE_FRAME_CHECKSUMS: &str = "frame_checksums.enable";
+pub const ENABLE_FRAME_CHECKSUMS_CODE: u32 = 2;
+pub const ENABLE_ZERO_COPY_POLLING: &str = "zero_copy_polling.enable";
+pub const ENABLE_ZERO_COPY_POLLING_CODE: u32 = 3;
+pub const ENABLE_CONSUMER_GROUP_EVENTS: &str = "consumer_group_events.enable";
+pub const ENABLE_CONSUMER_GROUP_EVENTS_CODE: u32 = 4;
 pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
 pub const GET_SNAPSHOT_FILE: &str = "snapshot";
 pub const GET_SNAPSHOT_FILE_CODE: u32 = 11;
+pub const GET_CONFIG: &str = "config";
+pub const GET_CONFIG_CODE: u32 = 12;
+pub const GET_AUDIT_LOG: &str = "audit_log";
+pub const GET_AUDIT_LOG_CODE: u32 = 13;
+pub const CREATE_BACKUP: &str = "backup.create";
+pub const CREATE_BACKUP_CODE: u32 = 14;
+pub const RESTORE_BACKUP: &str = "backup.restore";
+pub const RESTORE_BACKUP_CODE: u32 = 15;
+pub const GET_CLUSTER_METADATA: &str = "cluster.metadata";
+pub const GET_CLUSTER_METADATA_CODE: u32 = 16;
 pub const GET_ME: &str = "me";
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT: &str = "client.get";
@@ -69,12 +85,34 @@ pub const SEND_MESSAGES: &str = "message.send";
 pub const SEND_MESSAGES_CODE: u32 = 101;
 pub const FLUSH_UNSAVED_BUFFER: &str = "message.flush_unsaved_buffer";
 pub const FLUSH_UNSAVED_BUFFER_CODE: u32 = 102;
+pub const REJECT_MESSAGES: &str = "message.reject";
+pub const REJECT_MESSAGES_CODE: u32 = 103;
+pub const NACK_MESSAGES: &str = "message.nack";
+pub const NACK_MESSAGES_CODE: u32 = 104;
+pub const BEGIN_TRANSACTION: &str = "transaction.begin";
+pub const BEGIN_TRANSACTION_CODE: u32 = 105;
+pub const COMMIT_TRANSACTION: &str = "transaction.commit";
+pub const COMMIT_TRANSACTION_CODE: u32 = 106;
+pub const ABORT_TRANSACTION: &str = "transaction.abort";
+pub const ABORT_TRANSACTION_CODE: u32 = 107;
+pub const SEND_OFFSETS_TO_TRANSACTION: &str = "transaction.send_offsets";
+pub const SEND_OFFSETS_TO_TRANSACTION_CODE: u32 = 108;
+pub const ACK_MESSAGES: &str = "message.ack";
+pub const ACK_MESSAGES_CODE: u32 = 109;
 pub const GET_CONSUMER_OFFSET: &str = "consumer_offset.get";
 pub const GET_CONSUMER_OFFSET_CODE: u32 = 120;
 pub const STORE_CONSUMER_OFFSET: &str = "consumer_offset.store";
 pub const STORE_CONSUMER_OFFSET_CODE: u32 = 121;
 pub const DELETE_CONSUMER_OFFSET: &str = "consumer_offset.delete";
 pub const DELETE_CONSUMER_OFFSET_CODE: u32 = 122;
+pub const GET_BOOKMARK: &str = "bookmark.get";
+pub const GET_BOOKMARK_CODE: u32 = 130;
+pub const GET_BOOKMARKS: &str = "bookmark.list";
+pub const GET_BOOKMARKS_CODE: u32 = 131;
+pub const STORE_BOOKMARK: &str = "bookmark.store";
+pub const STORE_BOOKMARK_CODE: u32 = 132;
+pub const DELETE_BOOKMARK: &str = "bookmark.delete";
+pub const DELETE_BOOKMARK_CODE: u32 = 133;
 pub const GET_STREAM: &str = "stream.get";
 pub const GET_STREAM_CODE: u32 = 200;
 pub const GET_STREAMS: &str = "stream.list";
@@ -99,12 +137,22 @@ pub const UPDATE_TOPIC: &str = "topic.update";
 pub const UPDATE_TOPIC_CODE: u32 = 304;
 pub const PURGE_TOPIC: &str = "topic.purge";
 pub const PURGE_TOPIC_CODE: u32 = 305;
+pub const SET_TOPIC_THROTTLE: &str = "topic.throttle";
+pub const SET_TOPIC_THROTTLE_CODE: u32 = 306;
+pub const GET_PARTITION: &str = "partition.get";
+pub const GET_PARTITION_CODE: u32 = 400;
 pub const CREATE_PARTITIONS: &str = "partition.create";
 pub const CREATE_PARTITIONS_CODE: u32 = 402;
 pub const DELETE_PARTITIONS: &str = "partition.delete";
 pub const DELETE_PARTITIONS_CODE: u32 = 403;
+pub const UPDATE_PARTITION: &str = "partition.update";
+pub const UPDATE_PARTITION_CODE: u32 = 404;
+pub const MOVE_PARTITION: &str = "partition.move";
+pub const MOVE_PARTITION_CODE: u32 = 405;
 pub const DELETE_SEGMENTS: &str = "segment.delete";
 pub const DELETE_SEGMENTS_CODE: u32 = 503;
+pub const VERIFY_SEGMENTS: &str = "segment.verify";
+pub const VERIFY_SEGMENTS_CODE: u32 = 504;
 pub const GET_CONSUMER_GROUP: &str = "consumer_group.get";
 pub const GET_CONSUMER_GROUP_CODE: u32 = 600;
 pub const GET_CONSUMER_GROUPS: &str = "consumer_group.list";
@@ -117,11 +165,35 @@ pub const JOIN_CONSUMER_GROUP: &str = "consumer_group.join";
 pub const JOIN_CONSUMER_GROUP_CODE: u32 = 604;
 pub const LEAVE_CONSUMER_GROUP: &str = "consumer_group.leave";
 pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
+pub const HEARTBEAT_CONSUMER_GROUP: &str = "consumer_group.heartbeat";
+pub const HEARTBEAT_CONSUMER_GROUP_CODE: u32 = 606;
+pub const GET_SCHEMA: &str = "schema.get";
+pub const GET_SCHEMA_CODE: u32 = 700;
+pub const GET_SCHEMAS: &str = "schema.list";
+pub const GET_SCHEMAS_CODE: u32 = 701;
+pub const REGISTER_SCHEMA: &str = "schema.register";
+pub const REGISTER_SCHEMA_CODE: u32 = 702;
+pub const UPDATE_SCHEMA_COMPATIBILITY: &str = "schema.update_compatibility";
+pub const UPDATE_SCHEMA_COMPATIBILITY_CODE: u32 = 704;
+pub const GET_SUBJECT_SCHEMA: &str = "schema.get_subject";
+pub const GET_SUBJECT_SCHEMA_CODE: u32 = 705;
+pub const GET_QUOTAS: &str = "quota.list";
+pub const GET_QUOTAS_CODE: u32 = 800;
+pub const SET_QUOTA: &str = "quota.set";
+pub const SET_QUOTA_CODE: u32 = 801;
 
 pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
     match code {
         PING_CODE => Ok(PING),
+        ENABLE_FRAME_CHECKSUMS_CODE => Ok(ENABLE_FRAME_CHECKSUMS),
+        ENABLE_ZERO_COPY_POLLING_CODE => Ok(ENABLE_ZERO_COPY_POLLING),
+        ENABLE_CONSUMER_GROUP_EVENTS_CODE => Ok(ENABLE_CONSUMER_GROUP_EVENTS),
         GET_STATS_CODE => Ok(GET_STATS),
+        GET_CONFIG_CODE => Ok(GET_CONFIG),
+        GET_AUDIT_LOG_CODE => Ok(GET_AUDIT_LOG),
+        CREATE_BACKUP_CODE => Ok(CREATE_BACKUP),
+        RESTORE_BACKUP_CODE => Ok(RESTORE_BACKUP),
+        GET_CLUSTER_METADATA_CODE => Ok(GET_CLUSTER_METADATA),
         GET_ME_CODE => Ok(GET_ME),
         GET_CLIENT_CODE => Ok(GET_CLIENT),
         GET_CLIENTS_CODE => Ok(GET_CLIENTS),
@@ -141,8 +213,19 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         SEND_MESSAGES_CODE => Ok(SEND_MESSAGES),
         POLL_MESSAGES_CODE => Ok(POLL_MESSAGES),
         FLUSH_UNSAVED_BUFFER_CODE => Ok(FLUSH_UNSAVED_BUFFER),
+        REJECT_MESSAGES_CODE => Ok(REJECT_MESSAGES),
+        NACK_MESSAGES_CODE => Ok(NACK_MESSAGES),
+        BEGIN_TRANSACTION_CODE => Ok(BEGIN_TRANSACTION),
+        COMMIT_TRANSACTION_CODE => Ok(COMMIT_TRANSACTION),
+        ABORT_TRANSACTION_CODE => Ok(ABORT_TRANSACTION),
+        SEND_OFFSETS_TO_TRANSACTION_CODE => Ok(SEND_OFFSETS_TO_TRANSACTION),
+        ACK_MESSAGES_CODE => Ok(ACK_MESSAGES),
         STORE_CONSUMER_OFFSET_CODE => Ok(STORE_CONSUMER_OFFSET),
         GET_CONSUMER_OFFSET_CODE => Ok(GET_CONSUMER_OFFSET),
+        GET_BOOKMARK_CODE => Ok(GET_BOOKMARK),
+        GET_BOOKMARKS_CODE => Ok(GET_BOOKMARKS),
+        STORE_BOOKMARK_CODE => Ok(STORE_BOOKMARK),
+        DELETE_BOOKMARK_CODE => Ok(DELETE_BOOKMARK),
         GET_STREAM_CODE => Ok(GET_STREAM),
         GET_STREAMS_CODE => Ok(GET_STREAMS),
         CREATE_STREAM_CODE => Ok(CREATE_STREAM),
@@ -155,14 +238,27 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         DELETE_TOPIC_CODE => Ok(DELETE_TOPIC),
         UPDATE_TOPIC_CODE => Ok(UPDATE_TOPIC),
         PURGE_TOPIC_CODE => Ok(PURGE_TOPIC),
+        SET_TOPIC_THROTTLE_CODE => Ok(SET_TOPIC_THROTTLE),
+        GET_PARTITION_CODE => Ok(GET_PARTITION),
         CREATE_PARTITIONS_CODE => Ok(CREATE_PARTITIONS),
         DELETE_PARTITIONS_CODE => Ok(DELETE_PARTITIONS),
+        UPDATE_PARTITION_CODE => Ok(UPDATE_PARTITION),
+        MOVE_PARTITION_CODE => Ok(MOVE_PARTITION),
+        VERIFY_SEGMENTS_CODE => Ok(VERIFY_SEGMENTS),
         GET_CONSUMER_GROUP_CODE => Ok(GET_CONSUMER_GROUP),
         GET_CONSUMER_GROUPS_CODE => Ok(GET_CONSUMER_GROUPS),
         CREATE_CONSUMER_GROUP_CODE => Ok(CREATE_CONSUMER_GROUP),
         DELETE_CONSUMER_GROUP_CODE => Ok(DELETE_CONSUMER_GROUP),
         JOIN_CONSUMER_GROUP_CODE => Ok(JOIN_CONSUMER_GROUP),
         LEAVE_CONSUMER_GROUP_CODE => Ok(LEAVE_CONSUMER_GROUP),
+        HEARTBEAT_CONSUMER_GROUP_CODE => Ok(HEARTBEAT_CONSUMER_GROUP),
+        GET_SCHEMA_CODE => Ok(GET_SCHEMA),
+        GET_SCHEMAS_CODE => Ok(GET_SCHEMAS),
+        REGISTER_SCHEMA_CODE => Ok(REGISTER_SCHEMA),
+        UPDATE_SCHEMA_COMPATIBILITY_CODE => Ok(UPDATE_SCHEMA_COMPATIBILITY),
+        GET_SUBJECT_SCHEMA_CODE => Ok(GET_SUBJECT_SCHEMA),
+        GET_QUOTAS_CODE => Ok(GET_QUOTAS),
+        SET_QUOTA_CODE => Ok(SET_QUOTA),
         GET_SNAPSHOT_FILE_CODE => Ok(GET_SNAPSHOT_FILE),
         _ => Err(IggyError::InvalidCommand),
     }
@@ -0,0 +1,223 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::compression::compression_algorithm::{CompressionAlgorithm, CUSTOM_CODEC_MIN_ID};
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use ahash::AHashMap;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// The reserved header key carrying the ID of the codec used to compress the message payload.
+pub const CODEC_HEADER_KEY: &str = "iggy-codec";
+
+/// The codec used to compress and decompress the messages' payloads.
+///
+/// Custom codecs (e.g. snappy, brotli) must use the IDs starting from `CUSTOM_CODEC_MIN_ID`,
+/// the ID is carried along with the compressed messages, so the consumers can pick the matching codec from the `CodecRegistry`.
+pub trait Codec: Send + Sync + Debug {
+    fn id(&self) -> u8;
+    fn name(&self) -> &str;
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError>;
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError>;
+}
+
+/// The codec which doesn't modify the data.
+#[derive(Debug, Default)]
+pub struct NoneCodec;
+
+impl Codec for NoneCodec {
+    fn id(&self) -> u8 {
+        CompressionAlgorithm::None.as_code()
+    }
+
+    fn name(&self) -> &str {
+        "none"
+    }
+
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        Ok(data.to_vec())
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        Ok(data.to_vec())
+    }
+}
+
+/// The registry of the codecs identified by their numeric IDs.
+#[derive(Debug, Clone)]
+pub struct CodecRegistry {
+    codecs: AHashMap<u8, Arc<dyn Codec>>,
+}
+
+impl Default for CodecRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodecRegistry {
+    /// Creates a new registry containing only the built-in `NoneCodec`.
+    pub fn new() -> Self {
+        let mut codecs: AHashMap<u8, Arc<dyn Codec>> = AHashMap::new();
+        let none_codec = NoneCodec;
+        codecs.insert(none_codec.id(), Arc::new(none_codec));
+        Self { codecs }
+    }
+
+    /// Registers the custom codec, its ID must be at least `CUSTOM_CODEC_MIN_ID` and not used by any other codec.
+    pub fn register(&mut self, codec: Arc<dyn Codec>) -> Result<(), IggyError> {
+        let id = codec.id();
+        if id < CUSTOM_CODEC_MIN_ID {
+            return Err(IggyError::InvalidCodecId(id));
+        }
+
+        if self.codecs.contains_key(&id) {
+            return Err(IggyError::CodecAlreadyRegistered(id));
+        }
+
+        self.codecs.insert(id, codec);
+        Ok(())
+    }
+
+    /// Returns the codec with the given ID.
+    pub fn get(&self, id: u8) -> Result<Arc<dyn Codec>, IggyError> {
+        self.codecs
+            .get(&id)
+            .cloned()
+            .ok_or(IggyError::CodecNotFound(id))
+    }
+
+    /// Returns the codec for the given compression algorithm.
+    pub fn get_by_algorithm(
+        &self,
+        algorithm: CompressionAlgorithm,
+    ) -> Result<Arc<dyn Codec>, IggyError> {
+        self.get(algorithm.as_code())
+    }
+
+    pub fn contains(&self, id: u8) -> bool {
+        self.codecs.contains_key(&id)
+    }
+}
+
+/// Returns the ID of the codec used to compress the message, if any.
+pub fn get_codec_id(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Result<Option<u8>, IggyError> {
+    let Some(headers) = headers else {
+        return Ok(None);
+    };
+
+    let key = HeaderKey::new(CODEC_HEADER_KEY)?;
+    headers.get(&key).map(|value| value.as_uint8()).transpose()
+}
+
+/// Sets the ID of the codec used to compress the message.
+pub fn set_codec_id(
+    headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    codec_id: u8,
+) -> Result<(), IggyError> {
+    headers.get_or_insert_with(HashMap::new).insert(
+        HeaderKey::new(CODEC_HEADER_KEY)?,
+        HeaderValue::from_uint8(codec_id)?,
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct ReverseCodec;
+
+    impl Codec for ReverseCodec {
+        fn id(&self) -> u8 {
+            200
+        }
+
+        fn name(&self) -> &str {
+            "reverse"
+        }
+
+        fn compress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+            Ok(data.iter().rev().copied().collect())
+        }
+
+        fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+            Ok(data.iter().rev().copied().collect())
+        }
+    }
+
+    #[test]
+    fn should_contain_none_codec_by_default() {
+        let registry = CodecRegistry::new();
+        let codec = registry
+            .get_by_algorithm(CompressionAlgorithm::None)
+            .unwrap();
+        assert_eq!(codec.name(), "none");
+        assert_eq!(codec.compress(b"data").unwrap(), b"data");
+    }
+
+    #[test]
+    fn should_register_custom_codec() {
+        let mut registry = CodecRegistry::new();
+        assert!(registry.register(Arc::new(ReverseCodec)).is_ok());
+        let codec = registry
+            .get_by_algorithm(CompressionAlgorithm::Custom(200))
+            .unwrap();
+        let compressed = codec.compress(b"data").unwrap();
+        assert_eq!(compressed, b"atad");
+        assert_eq!(codec.decompress(&compressed).unwrap(), b"data");
+    }
+
+    #[test]
+    fn should_not_register_codec_twice() {
+        let mut registry = CodecRegistry::new();
+        registry.register(Arc::new(ReverseCodec)).unwrap();
+        assert_eq!(
+            registry.register(Arc::new(ReverseCodec)),
+            Err(IggyError::CodecAlreadyRegistered(200))
+        );
+    }
+
+    #[test]
+    fn should_not_register_codec_with_reserved_id() {
+        let mut registry = CodecRegistry::new();
+        assert_eq!(
+            registry.register(Arc::new(NoneCodec)),
+            Err(IggyError::InvalidCodecId(1))
+        );
+    }
+
+    #[test]
+    fn should_return_error_for_unknown_codec() {
+        let registry = CodecRegistry::new();
+        assert!(registry.get(201).is_err());
+    }
+
+    #[test]
+    fn should_set_and_get_codec_id_from_headers() {
+        let mut headers = None;
+        assert_eq!(get_codec_id(&headers).unwrap(), None);
+        set_codec_id(&mut headers, 200).unwrap();
+        assert_eq!(get_codec_id(&headers).unwrap(), Some(200));
+    }
+}
@@ -27,6 +27,10 @@ use std::{
 
 use crate::error::IggyError;
 
+/// The lowest ID that can be used by the custom codecs, the lower IDs are reserved for the built-in algorithms.
+pub const CUSTOM_CODEC_MIN_ID: u8 = 128;
+const CUSTOM_CODEC_PREFIX: &str = "custom_";
+
 // for now only those, in the future will add snappy, lz4, zstd (same as in confluent kafka) in addition to that
 // we should consider brotli as well.
 /// Supported compression algorithms
@@ -37,16 +41,24 @@ pub enum CompressionAlgorithm {
     None,
     // Gzip compression algorithm
     Gzip,
+    // Custom codec registered in the `CodecRegistry` with the given ID
+    Custom(u8),
 }
 
 impl FromStr for CompressionAlgorithm {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
+        let value = s.to_lowercase();
+        match value.as_str() {
             "gzip" => Ok(CompressionAlgorithm::Gzip),
             "none" => Ok(CompressionAlgorithm::None),
-            _ => Err(format!("Unknown compression type: {}", s)),
+            _ => value
+                .strip_prefix(CUSTOM_CODEC_PREFIX)
+                .and_then(|id| id.parse::<u8>().ok())
+                .filter(|id| *id >= CUSTOM_CODEC_MIN_ID)
+                .map(CompressionAlgorithm::Custom)
+                .ok_or_else(|| format!("Unknown compression type: {}", s)),
         }
     }
 }
@@ -56,6 +68,7 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => 1,
             CompressionAlgorithm::Gzip => 2,
+            CompressionAlgorithm::Custom(id) => *id,
         }
     }
 
@@ -63,6 +76,7 @@ impl CompressionAlgorithm {
         match code {
             1 => Ok(CompressionAlgorithm::None),
             2 => Ok(CompressionAlgorithm::Gzip),
+            CUSTOM_CODEC_MIN_ID..=u8::MAX => Ok(CompressionAlgorithm::Custom(code)),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -73,6 +87,7 @@ impl Display for CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => write!(f, "none"),
             CompressionAlgorithm::Gzip => write!(f, "gzip"),
+            CompressionAlgorithm::Custom(id) => write!(f, "{CUSTOM_CODEC_PREFIX}{id}"),
         }
     }
 }
@@ -82,19 +97,13 @@ impl Serialize for CompressionAlgorithm {
     where
         S: Serializer,
     {
-        match self {
-            CompressionAlgorithm::None => serializer.serialize_str("none"),
-            CompressionAlgorithm::Gzip => serializer.serialize_str("gzip"),
-        }
+        serializer.serialize_str(&self.to_string())
     }
 }
 
 impl From<CompressionAlgorithm> for String {
     fn from(value: CompressionAlgorithm) -> Self {
-        match value {
-            CompressionAlgorithm::None => "none".to_string(),
-            CompressionAlgorithm::Gzip => "gzip".to_string(),
-        }
+        value.to_string()
     }
 }
 struct CompressionKindVisitor;
@@ -153,6 +162,27 @@ mod tests {
 
         let invalid_compression_kind = CompressionAlgorithm::from_str("gzipp");
         assert!(invalid_compression_kind.is_err());
+
+        let invalid_compression_kind = CompressionAlgorithm::from_str("custom_127");
+        assert!(invalid_compression_kind.is_err());
+    }
+
+    #[test]
+    fn test_custom() {
+        let custom = CompressionAlgorithm::from_str("Custom_200");
+        assert!(custom.is_ok());
+        assert_eq!(custom.unwrap(), CompressionAlgorithm::Custom(200));
+
+        let custom_string: String = CompressionAlgorithm::Custom(200).into();
+        assert_eq!(custom_string, "custom_200".to_string());
+
+        let custom = CompressionAlgorithm::from_code(CUSTOM_CODEC_MIN_ID);
+        assert!(custom.is_ok());
+        assert_eq!(
+            custom.unwrap(),
+            CompressionAlgorithm::Custom(CUSTOM_CODEC_MIN_ID)
+        );
+        assert_eq!(CompressionAlgorithm::Custom(255).as_code(), 255);
     }
 
     #[test]
@@ -195,7 +225,7 @@ mod tests {
         let invalid_compression_kind = CompressionAlgorithm::from_code(69);
         assert!(invalid_compression_kind.is_err());
 
-        let invalid_compression_kind = CompressionAlgorithm::from_code(255);
+        let invalid_compression_kind = CompressionAlgorithm::from_code(CUSTOM_CODEC_MIN_ID - 1);
         assert!(invalid_compression_kind.is_err());
     }
 }
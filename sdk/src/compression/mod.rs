@@ -16,4 +16,5 @@
  * under the License.
  */
 
+pub mod codec;
 pub mod compression_algorithm;
@@ -16,9 +16,15 @@
  * under the License.
  */
 
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use strum::{Display, EnumString};
 
+/// The header holding the code (u8) of the confirmation level requested by the producer for the appended messages.
+pub const CONFIRMATION_LEVEL_HEADER: &str = "iggy-confirmation";
+
 #[derive(Clone, Copy, Debug, Default, Display, Serialize, Deserialize, EnumString, PartialEq)]
 #[strum(serialize_all = "snake_case")]
 pub enum Confirmation {
@@ -27,6 +33,80 @@ pub enum Confirmation {
     NoWait,
 }
 
+/// `ConfirmationLevel` determines when the server confirms the appended messages to the producer.
+/// It's sent along with the messages, and can be also requested by the `iggy-confirmation` header of any of them,
+/// in which case the strongest of the requested levels applies.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Display,
+    Serialize,
+    Deserialize,
+    EnumString,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum ConfirmationLevel {
+    /// The messages are confirmed once appended, without waiting for them to be written to disk, even once the buffer is full.
+    NoWait,
+    /// The messages are confirmed once received and buffered by the server.
+    #[default]
+    Received,
+    /// The messages are confirmed once written to disk and synced (fsync).
+    Persisted,
+}
+
+impl ConfirmationLevel {
+    /// Returns the code of the confirmation level.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ConfirmationLevel::NoWait => 0,
+            ConfirmationLevel::Received => 1,
+            ConfirmationLevel::Persisted => 2,
+        }
+    }
+
+    /// Returns the confirmation level from the given code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            0 => Ok(ConfirmationLevel::NoWait),
+            1 => Ok(ConfirmationLevel::Received),
+            2 => Ok(ConfirmationLevel::Persisted),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+/// Returns the confirmation level, if the headers contain a valid confirmation level header.
+pub fn get_confirmation_level(
+    headers: &HashMap<HeaderKey, HeaderValue>,
+) -> Option<ConfirmationLevel> {
+    let code = headers
+        .get(&HeaderKey::new(CONFIRMATION_LEVEL_HEADER).unwrap())?
+        .as_uint8()
+        .ok()?;
+    ConfirmationLevel::from_code(code).ok()
+}
+
+/// Sets the confirmation level requested for the message.
+pub fn set_confirmation_level(
+    headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    level: ConfirmationLevel,
+) -> Result<(), IggyError> {
+    headers.get_or_insert_with(HashMap::new).insert(
+        HeaderKey::new(CONFIRMATION_LEVEL_HEADER)?,
+        HeaderValue::from_uint8(level.as_code())?,
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,4 +131,50 @@ mod tests {
     fn test_default() {
         assert_eq!(Confirmation::default(), Confirmation::Wait);
     }
+
+    #[test]
+    fn confirmation_level_should_be_read_from_headers() {
+        let mut headers = None;
+        set_confirmation_level(&mut headers, ConfirmationLevel::Persisted).unwrap();
+        let headers = headers.unwrap();
+        assert_eq!(
+            get_confirmation_level(&headers),
+            Some(ConfirmationLevel::Persisted)
+        );
+    }
+
+    #[test]
+    fn confirmation_level_should_not_be_read_from_headers_without_it() {
+        assert_eq!(get_confirmation_level(&HashMap::new()), None);
+    }
+
+    #[test]
+    fn persisted_confirmation_level_should_be_stronger_than_received() {
+        assert!(ConfirmationLevel::Persisted > ConfirmationLevel::Received);
+        assert!(ConfirmationLevel::Received > ConfirmationLevel::NoWait);
+        assert_eq!(ConfirmationLevel::default(), ConfirmationLevel::Received);
+        assert_eq!(
+            ConfirmationLevel::from_str("persisted").unwrap(),
+            ConfirmationLevel::Persisted
+        );
+    }
+
+    #[test]
+    fn confirmation_level_should_be_mapped_from_code() {
+        for level in [
+            ConfirmationLevel::NoWait,
+            ConfirmationLevel::Received,
+            ConfirmationLevel::Persisted,
+        ] {
+            assert_eq!(
+                ConfirmationLevel::from_code(level.as_code()).unwrap(),
+                level
+            );
+        }
+        assert!(ConfirmationLevel::from_code(3).is_err());
+        assert_eq!(
+            ConfirmationLevel::from_str("no_wait").unwrap(),
+            ConfirmationLevel::NoWait
+        );
+    }
 }
@@ -32,6 +32,7 @@ use std::fmt::Display;
 /// - `id`: the unique identifier of the consumer.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Consumer {
     /// The type of consumer. It can be either `Consumer` or `ConsumerGroup`.
     #[serde(skip)]
@@ -39,6 +40,7 @@ pub struct Consumer {
     /// The unique identifier of the consumer.
     #[serde_as(as = "DisplayFromStr")]
     #[serde(default = "default_id")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub id: Identifier,
 }
 
@@ -0,0 +1,123 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The strategy used by the server to assign the topic partitions to the consumer group members.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum AssignmentStrategy {
+    /// Each member gets a contiguous range of partitions.
+    Range,
+    /// The partitions are distributed one by one across the members.
+    #[default]
+    RoundRobin,
+    /// The partitions are distributed evenly, while the existing assignment is preserved as much as possible to minimize partitions movement on member churn.
+    Sticky,
+    /// Same as `Sticky`, but the partitions which have to be moved are revoked first, and assigned to the new owner once the previous owner has acknowledged the revocation by polling again.
+    CooperativeSticky,
+}
+
+impl AssignmentStrategy {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            AssignmentStrategy::Range => 1,
+            AssignmentStrategy::RoundRobin => 2,
+            AssignmentStrategy::Sticky => 3,
+            AssignmentStrategy::CooperativeSticky => 4,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(AssignmentStrategy::Range),
+            2 => Ok(AssignmentStrategy::RoundRobin),
+            3 => Ok(AssignmentStrategy::Sticky),
+            4 => Ok(AssignmentStrategy::CooperativeSticky),
+            _ => Err(IggyError::InvalidAssignmentStrategy(code)),
+        }
+    }
+}
+
+impl FromStr for AssignmentStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "range" => Ok(AssignmentStrategy::Range),
+            "round_robin" => Ok(AssignmentStrategy::RoundRobin),
+            "sticky" => Ok(AssignmentStrategy::Sticky),
+            "cooperative_sticky" => Ok(AssignmentStrategy::CooperativeSticky),
+            _ => Err(format!("Unknown assignment strategy: {}", s)),
+        }
+    }
+}
+
+impl Display for AssignmentStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssignmentStrategy::Range => write!(f, "range"),
+            AssignmentStrategy::RoundRobin => write!(f, "round_robin"),
+            AssignmentStrategy::Sticky => write!(f, "sticky"),
+            AssignmentStrategy::CooperativeSticky => write!(f, "cooperative_sticky"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_converted_to_and_from_code() {
+        for strategy in [
+            AssignmentStrategy::Range,
+            AssignmentStrategy::RoundRobin,
+            AssignmentStrategy::Sticky,
+            AssignmentStrategy::CooperativeSticky,
+        ] {
+            assert_eq!(
+                AssignmentStrategy::from_code(strategy.as_code()).unwrap(),
+                strategy
+            );
+        }
+    }
+
+    #[test]
+    fn should_be_parsed_from_string() {
+        assert_eq!(
+            AssignmentStrategy::from_str("cooperative-sticky").unwrap(),
+            AssignmentStrategy::CooperativeSticky
+        );
+        assert_eq!(
+            AssignmentStrategy::from_str("ROUND_ROBIN").unwrap(),
+            AssignmentStrategy::RoundRobin
+        );
+        assert!(AssignmentStrategy::from_str("random").is_err());
+    }
+
+    #[test]
+    fn should_fail_for_invalid_code() {
+        assert!(AssignmentStrategy::from_code(0).is_err());
+        assert!(AssignmentStrategy::from_code(5).is_err());
+    }
+}
@@ -26,12 +26,18 @@ use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
+/// The consumer group details hold only the members, as returned to the clients which don't send the details version.
+pub const LEGACY_DETAILS_VERSION: u8 = 0;
+/// The consumer group details hold the generation, assignment strategy and topic deletion flag following the members.
+pub const CONSUMER_GROUP_DETAILS_VERSION: u8 = 1;
+
 /// `GetConsumerGroup` command retrieves the consumer group from the topic.
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `group_id` - unique consumer group ID (numeric or name).
-#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+/// - `details_version` - the version of the details layout understood by the client, the legacy one if it's missing.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct GetConsumerGroup {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
@@ -42,6 +48,20 @@ pub struct GetConsumerGroup {
     /// Unique consumer group ID (numeric or name).
     #[serde(skip)]
     pub group_id: Identifier,
+    /// The version of the details layout understood by the client.
+    #[serde(skip)]
+    pub details_version: u8,
+}
+
+impl Default for GetConsumerGroup {
+    fn default() -> Self {
+        GetConsumerGroup {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            group_id: Identifier::default(),
+            details_version: CONSUMER_GROUP_DETAILS_VERSION,
+        }
+    }
 }
 
 impl Command for GetConsumerGroup {
@@ -62,11 +82,12 @@ impl BytesSerializable for GetConsumerGroup {
         let topic_id_bytes = self.topic_id.to_bytes();
         let group_id_bytes = self.group_id.to_bytes();
         let mut bytes = BytesMut::with_capacity(
-            stream_id_bytes.len() + topic_id_bytes.len() + group_id_bytes.len(),
+            stream_id_bytes.len() + topic_id_bytes.len() + group_id_bytes.len() + 1,
         );
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
         bytes.put_slice(&group_id_bytes);
+        bytes.put_u8(self.details_version);
         bytes.freeze()
     }
 
@@ -81,10 +102,17 @@ impl BytesSerializable for GetConsumerGroup {
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
         position += topic_id.get_size_bytes().as_bytes_usize();
         let group_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += group_id.get_size_bytes().as_bytes_usize();
+        // The clients which don't know about the details version expect the legacy layout.
+        let details_version = bytes
+            .get(position)
+            .copied()
+            .unwrap_or(LEGACY_DETAILS_VERSION);
         let command = GetConsumerGroup {
             stream_id,
             topic_id,
             group_id,
+            details_version,
         };
         Ok(command)
     }
@@ -106,6 +134,7 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             group_id: Identifier::numeric(3).unwrap(),
+            details_version: CONSUMER_GROUP_DETAILS_VERSION,
         };
 
         let bytes = command.to_bytes();
@@ -115,11 +144,14 @@ mod tests {
         let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
         position += topic_id.get_size_bytes().as_bytes_usize();
         let group_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += group_id.get_size_bytes().as_bytes_usize();
+        let details_version = bytes[position];
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(group_id, command.group_id);
+        assert_eq!(details_version, command.details_version);
     }
 
     #[test]
@@ -136,6 +168,7 @@ mod tests {
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
         bytes.put_slice(&group_id_bytes);
+        bytes.put_u8(CONSUMER_GROUP_DETAILS_VERSION);
         let command = GetConsumerGroup::from_bytes(bytes.freeze());
         assert!(command.is_ok());
 
@@ -143,5 +176,16 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.group_id, group_id);
+        assert_eq!(command.details_version, CONSUMER_GROUP_DETAILS_VERSION);
+    }
+
+    #[test]
+    fn should_be_deserialized_with_legacy_details_version_from_bytes_without_it() {
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&Identifier::numeric(1).unwrap().to_bytes());
+        bytes.put_slice(&Identifier::numeric(2).unwrap().to_bytes());
+        bytes.put_slice(&Identifier::numeric(3).unwrap().to_bytes());
+        let command = GetConsumerGroup::from_bytes(bytes.freeze()).unwrap();
+        assert_eq!(command.details_version, LEGACY_DETAILS_VERSION);
     }
 }
@@ -18,6 +18,7 @@
 
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::{Command, JOIN_CONSUMER_GROUP_CODE};
+use crate::consumer_groups::assignment_strategy::AssignmentStrategy;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::utils::sizeable::Sizeable;
@@ -31,6 +32,7 @@ use std::fmt::Display;
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `group_id` - unique consumer group ID (numeric or name).
+/// - `assignment_strategy` - optional assignment strategy expected by the member, the member can't join the group using a different one.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct JoinConsumerGroup {
     /// Unique stream ID (numeric or name).
@@ -42,6 +44,9 @@ pub struct JoinConsumerGroup {
     /// Unique consumer group ID (numeric or name).
     #[serde(skip)]
     pub group_id: Identifier,
+    /// Optional assignment strategy expected by the member.
+    #[serde(default)]
+    pub assignment_strategy: Option<AssignmentStrategy>,
 }
 
 impl Command for JoinConsumerGroup {
@@ -62,11 +67,15 @@ impl BytesSerializable for JoinConsumerGroup {
         let topic_id_bytes = self.topic_id.to_bytes();
         let group_id_bytes = self.group_id.to_bytes();
         let mut bytes = BytesMut::with_capacity(
-            stream_id_bytes.len() + topic_id_bytes.len() + group_id_bytes.len(),
+            1 + stream_id_bytes.len() + topic_id_bytes.len() + group_id_bytes.len(),
         );
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
         bytes.put_slice(&group_id_bytes);
+        bytes.put_u8(
+            self.assignment_strategy
+                .map_or(0, |assignment_strategy| assignment_strategy.as_code()),
+        );
         bytes.freeze()
     }
 
@@ -81,10 +90,16 @@ impl BytesSerializable for JoinConsumerGroup {
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
         position += topic_id.get_size_bytes().as_bytes_usize();
         let group_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += group_id.get_size_bytes().as_bytes_usize();
+        let assignment_strategy = match bytes.get(position) {
+            None | Some(0) => None,
+            Some(code) => Some(AssignmentStrategy::from_code(*code)?),
+        };
         let command = JoinConsumerGroup {
             stream_id,
             topic_id,
             group_id,
+            assignment_strategy,
         };
         Ok(command)
     }
@@ -106,6 +121,7 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             group_id: Identifier::numeric(3).unwrap(),
+            assignment_strategy: Some(AssignmentStrategy::Range),
         };
 
         let bytes = command.to_bytes();
@@ -115,11 +131,14 @@ mod tests {
         let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
         position += topic_id.get_size_bytes().as_bytes_usize();
         let group_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += group_id.get_size_bytes().as_bytes_usize();
+        let assignment_strategy = AssignmentStrategy::from_code(bytes[position]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(group_id, command.group_id);
+        assert_eq!(Some(assignment_strategy), command.assignment_strategy);
     }
 
     #[test]
@@ -143,5 +162,6 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.group_id, group_id);
+        assert_eq!(command.assignment_strategy, None);
     }
 }
@@ -16,10 +16,12 @@
  * under the License.
  */
 
+pub mod assignment_strategy;
 pub mod create_consumer_group;
 pub mod delete_consumer_group;
 pub mod get_consumer_group;
 pub mod get_consumer_groups;
+pub mod heartbeat_consumer_group;
 pub mod join_consumer_group;
 pub mod leave_consumer_group;
 
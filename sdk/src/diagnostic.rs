@@ -19,17 +19,26 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+/// The connection lifecycle events published by the client, which can be consumed via `subscribe_events()`.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Display, Copy, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum DiagnosticEvent {
+    /// The client has been shut down and cannot be used anymore.
     #[display("shutdown")]
     Shutdown,
+    /// The connection to the server has been closed or could not be established.
     #[display("disconnected")]
     Disconnected,
+    /// The connection to the server has been established, but the client is not authenticated yet.
     #[display("connected")]
     Connected,
+    /// The client is trying to re-establish the connection to the server.
+    #[display("reconnecting")]
+    Reconnecting,
+    /// The client has been authenticated, either with the user credentials or a personal access token.
     #[display("signed_in")]
     SignedIn,
+    /// The client has signed out, but it's still connected to the server.
     #[display("signed_out")]
     SignedOut,
 }
@@ -62,10 +62,16 @@ pub enum IggyError {
     StateFileCorrupted = 15,
     #[error("Invalid state entry checksum: {0}, expected: {1}, for index: {2}")]
     InvalidStateEntryChecksum(u32, u32, u64) = 16,
+    #[error("Cannot migrate data directory from version: {0} to version: {1}")]
+    CannotMigrateData(u32, u32) = 17,
+    #[error("Data directory version: {0} is newer than the supported version: {1}")]
+    UnsupportedDataVersion(u32, u32) = 18,
     #[error("Cannot open database, Path: {0}")]
     CannotOpenDatabase(String) = 19,
     #[error("Resource with key: {0} was not found.")]
     ResourceNotFound(String) = 20,
+    #[error("Invalid state entry index: {0}, expected: {1}")]
+    InvalidStateEntryIndex(u64, u64) = 21,
     #[error("Stale client")]
     StaleClient = 30,
     #[error("TCP error")]
@@ -76,6 +82,10 @@ pub enum IggyError {
     InvalidServerAddress = 33,
     #[error("Invalid client address")]
     InvalidClientAddress = 34,
+    #[error("Client access denied")]
+    ClientAccessDenied = 35,
+    #[error("Invalid frame checksum: {0}, expected: {1}")]
+    InvalidFrameChecksum(u32, u32) = 36,
     #[error("Unauthenticated")]
     Unauthenticated = 40,
     #[error("Unauthorized")]
@@ -108,6 +118,10 @@ pub enum IggyError {
     PersonalAccessTokenExpired(String, u32) = 54,
     #[error("Users limit reached.")]
     UsersLimitReached = 55,
+    #[error("Invalid permission pattern: {0}")]
+    InvalidPermissionPattern(String) = 56,
+    #[error("Invalid personal access token scope")]
+    InvalidPersonalAccessTokenScope = 57,
     #[error("Not connected")]
     NotConnected = 61,
     #[error("Client shutdown")]
@@ -148,10 +162,66 @@ pub enum IggyError {
     InvalidBooleanValue = 83,
     #[error("Invalid number value")]
     InvalidNumberValue = 84,
+    #[error("Invalid codec ID: {0}")]
+    InvalidCodecId(u8) = 85,
+    #[error("Codec with ID: {0} is already registered")]
+    CodecAlreadyRegistered(u8) = 86,
+    #[error("Codec with ID: {0} was not found")]
+    CodecNotFound(u8) = 87,
+    #[error("Cannot compress data")]
+    CannotCompressData = 88,
+    #[error("Cannot decompress data")]
+    CannotDecompressData = 89,
     #[error("Client with ID: {0} was not found.")]
     ClientNotFound(u32) = 100,
     #[error("Invalid client ID")]
     InvalidClientId = 101,
+    #[error("Invalid backup name")]
+    InvalidBackupName = 110,
+    #[error("Backup with name: {0} already exists")]
+    BackupAlreadyExists(String) = 111,
+    #[error("Backup with name: {0} was not found")]
+    BackupNotFound(String) = 112,
+    #[error("Invalid backup manifest: {0}")]
+    InvalidBackupManifest(String) = 113,
+    #[error("Node is not the cluster leader, leader node ID: {0}")]
+    NotClusterLeader(u32) = 120,
+    #[error("Cluster quorum was not reached")]
+    ClusterQuorumNotReached = 121,
+    #[error(
+        "Node is not the leader of partition: {0} for topic: {1}, stream: {2}, leader node ID: {3}"
+    )]
+    NotPartitionLeader(u32, u32, u32, u32) = 122,
+    #[error("Not enough in-sync replicas: {0}, required: {1}")]
+    NotEnoughInSyncReplicas(u32, u32) = 123,
+    #[error("Server is shutting down")]
+    ServerShuttingDown = 124,
+    #[error("Standby server is not promoted")]
+    StandbyNotPromoted = 125,
+    #[error("State log of the standby server diverged from the primary at entry: {0}")]
+    StandbyStateDiverged(u64) = 126,
+    #[error("Stream: {0} is outside of the tenant namespace")]
+    TenantNamespaceViolation(String) = 127,
+    #[error("Tenant: {0} has reached the quota of {1} streams")]
+    TenantStreamsQuotaExceeded(String, u32) = 128,
+    #[error("Tenant: {0} has reached the quota of {1} topics")]
+    TenantTopicsQuotaExceeded(String, u32) = 129,
+    #[error("Tenant: {0} has reached the quota of {1} partitions")]
+    TenantPartitionsQuotaExceeded(String, u32) = 130,
+    #[error("Tenant: {0} has reached the storage quota of {1} bytes")]
+    TenantStorageQuotaExceeded(String, u64) = 131,
+    #[error("Tenant: {0} has exceeded the throughput quota")]
+    TenantThroughputQuotaExceeded(String) = 132,
+    #[error("Throughput quotas are disabled")]
+    QuotasDisabled = 133,
+    #[error("Invalid quota: {0}")]
+    InvalidQuota(String) = 134,
+    #[error("Throughput quota of {0} has been exceeded")]
+    QuotaExceeded(String) = 135,
+    #[error(
+        "Storage limit of {0} has been exceeded, disk usage: {1} bytes, hard limit: {2} bytes"
+    )]
+    StorageLimitExceeded(String, u64, u64) = 136,
     #[error("Connection closed")]
     ConnectionClosed = 206,
     #[error("Cannot parse header kind from {0}")]
@@ -170,6 +240,8 @@ pub enum IggyError {
     CannotCreateEndpoint = 305,
     #[error("Cannot parse URL")]
     CannotParseUrl = 306,
+    #[error("Requests limit of {0} per second has been exceeded")]
+    RequestsLimitExceeded(u32) = 307,
     #[error("Cannot create streams directory, Path: {0}")]
     CannotCreateStreamsDirectory(String) = 1000,
     #[error("Cannot create stream with ID: {0} directory, Path: {1}")]
@@ -250,6 +322,32 @@ pub enum IggyError {
     CannotReadTopics(u32) = 2017,
     #[error("Invalid replication factor")]
     InvalidReplicationFactor = 2018,
+    #[error("Invalid topic schema: {0}")]
+    InvalidTopicSchema(String) = 2019,
+    #[error("Invalid dead letter policy: {0}")]
+    InvalidDeadLetterPolicy(String) = 2020,
+    #[error("Invalid message sampling policy: {0}")]
+    InvalidMessageSamplingPolicy(String) = 2021,
+    #[error("Invalid compaction policy: {0}")]
+    InvalidCompactionPolicy(String) = 2022,
+    #[error("Invalid retention policy: {0}")]
+    InvalidRetentionPolicy(String) = 2023,
+    #[error("Invalid tiering policy: {0}")]
+    InvalidTieringPolicy(String) = 2024,
+    #[error("Invalid topic throttle: {0}")]
+    InvalidTopicThrottle(String) = 2025,
+    #[error("Topic with ID: {0} for stream with ID: {1} is throttled, max throughput has been exceeded.")]
+    TopicThrottled(u32, u32) = 2026,
+    #[error("Invalid fsync policy: {0}")]
+    InvalidFsyncPolicy(String) = 2027,
+    #[error("Topic with ID: {0} for stream with ID: {1} is being deleted.")]
+    TopicBeingDeleted(u32, u32) = 2028,
+    #[error("Invalid segment rollover policy: {0}")]
+    InvalidSegmentRolloverPolicy(String) = 2029,
+    #[error("Invalid queue policy: {0}")]
+    InvalidQueuePolicy(String) = 2030,
+    #[error("Topic with ID: {0} for stream with ID: {1} is not in the queue mode.")]
+    TopicNotInQueueMode(u32, u32) = 2031,
     #[error("Cannot create partition with ID: {0} for stream with ID: {1} and topic with ID: {2}")]
     CannotCreatePartition(u32, u32, u32) = 3000,
     #[error(
@@ -282,10 +380,36 @@ pub enum IggyError {
     CannotDeleteConsumerOffsetFile(String) = 3011,
     #[error("Failed to create consumer offsets directory for path: {0}")]
     CannotCreateConsumerOffsetsDirectory(String) = 3012,
+    #[error(
+        "Partition with name: {0} for topic with ID: {1} for stream with ID: {2} was not found."
+    )]
+    PartitionNameNotFound(String, u32, u32) = 3013,
+    #[error(
+        "Partition with name: {0} for topic with ID: {1} for stream with ID: {2} already exists."
+    )]
+    PartitionNameAlreadyExists(String, u32, u32) = 3014,
+    #[error("Invalid partition name")]
+    InvalidPartitionName = 3015,
+    #[error("Invalid key routing policy: {0}")]
+    InvalidKeyRoutingPolicy(u8) = 3016,
+    #[error("Invalid data directory: {0}")]
+    InvalidDataDirectory(String) = 3017,
+    #[error("Partition with ID: {0} for topic with ID: {1} for stream with ID: {2} is already being moved.")]
+    PartitionAlreadyMoving(u32, u32, u32) = 3018,
+    #[error(
+        "Failed to move partition with ID: {0} for topic with ID: {1} for stream with ID: {2}"
+    )]
+    CannotMovePartition(u32, u32, u32) = 3019,
     #[error("Failed to read consumers offsets from path: {0}")]
     CannotReadConsumerOffsets(String) = 3020,
     #[error("Consumer offset for consumer with ID: {0} was not found.")]
     ConsumerOffsetNotFound(u32) = 3021,
+    #[error("Invalid bookmark name")]
+    InvalidBookmarkName = 3022,
+    #[error(
+        "Bookmark with name: {0} for topic with ID: {1} for stream with ID: {2} was not found."
+    )]
+    BookmarkNotFound(String, u32, u32) = 3023,
     #[error("Segment not found")]
     SegmentNotFound = 4000,
     #[error("Segment with start offset: {0} and partition with ID: {1} is closed")]
@@ -348,10 +472,34 @@ pub enum IggyError {
     CommandLengthError(String) = 4029,
     #[error("Incorrect Segments Count size: {0}")]
     InvalidSegmentsCount(u32) = 4030,
+    #[error("Message does not conform to the topic schema: {0}")]
+    MessageSchemaViolation(String) = 4031,
+    #[error("Cannot upload segment with start offset: {0} for partition with ID: {1} to the tiered storage")]
+    CannotUploadSegment(u64, u32) = 4032,
+    #[error("Cannot fetch segment with start offset: {0} for partition with ID: {1} from the tiered storage")]
+    CannotFetchSegment(u64, u32) = 4033,
+    #[error("Cannot initialize the tiered storage")]
+    CannotInitializeTieredStorage = 4034,
+    #[error(
+        "Pre-deletion hook failed for segment with start offset: {0} for partition with ID: {1}"
+    )]
+    PreDeletionHookFailed(u64, u32) = 4035,
     #[error("Cannot sed messages due to client disconnection")]
     CannotSendMessagesDueToClientDisconnection = 4050,
+    #[error("Correlation ID is missing")]
+    MissingCorrelationId = 4051,
+    #[error("Request with correlation ID: {0} has timed out")]
+    RequestTimedOut(u128) = 4052,
     #[error("Invalid offset: {0}")]
     InvalidOffset(u64) = 4100,
+    #[error("Transaction with ID: {0} was not found")]
+    TransactionNotFound(u64) = 4200,
+    #[error("Transaction with ID: {0} is not open")]
+    TransactionNotOpen(u64) = 4201,
+    #[error("Invalid transaction ID")]
+    InvalidTransactionId = 4202,
+    #[error("Transaction markers can be appended only by the server")]
+    TransactionMarkerNotAllowed = 4203,
     #[error("Consumer group with ID: {0} for topic with ID: {1} was not found.")]
     ConsumerGroupIdNotFound(u32, u32) = 5000,
     #[error("Consumer group with ID: {0} for topic with ID: {1} already exists.")]
@@ -370,6 +518,24 @@ pub enum IggyError {
     CannotCreateConsumerGroupInfo(u32, u32, u32) = 5007,
     #[error("Failed to delete consumer group info file for ID: {0} for topic with ID: {1} for stream with ID: {2}.")]
     CannotDeleteConsumerGroupInfo(u32, u32, u32) = 5008,
+    #[error("Invalid consumer group assignment strategy: {0}")]
+    InvalidAssignmentStrategy(u8) = 5009,
+    #[error(
+        "Consumer group with ID: {0} uses assignment strategy: {1}, but the member requested: {2}."
+    )]
+    ConsumerGroupAssignmentStrategyMismatch(u32, String, String) = 5010,
+    #[error("Schema with ID: {0} was not found.")]
+    SchemaIdNotFound(u32) = 5100,
+    #[error("Schema subject: {0} was not found.")]
+    SchemaSubjectNotFound(String) = 5101,
+    #[error("Schema version: {0} for subject: {1} was not found.")]
+    SchemaVersionNotFound(u32, String) = 5102,
+    #[error("Invalid schema subject")]
+    InvalidSchemaSubject = 5103,
+    #[error("Schema is incompatible with the latest version for subject: {0}, reason: {1}")]
+    IncompatibleSchema(String, String) = 5104,
+    #[error("Invalid schema compatibility: {0}")]
+    InvalidSchemaCompatibility(u8) = 5105,
     #[error("Base offset is missing")]
     MissingBaseOffsetRetainedMessageBatch = 6000,
     #[error("Last offset delta is missing")]
@@ -390,6 +556,8 @@ pub enum IggyError {
     CannotReadMaxTimestamp = 7003,
     #[error("Cannot read batch payload")]
     CannotReadBatchPayload = 7004,
+    #[error("Corrupted batch with base offset: {0} at position: {1}, the checksum does not match")]
+    CorruptedBatch(u64, u64) = 7005,
     #[error("Invalid connection string")]
     InvalidConnectionString = 8000,
     #[error("Snapshot file completion failed")]
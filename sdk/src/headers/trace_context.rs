@@ -0,0 +1,25 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use std::fmt::Debug;
+
+/// The trait represents the source of the W3C `traceparent` of the currently active span and is used by the `IggyProducer`.
+/// When set, the returned value is attached to the `traceparent` header of every produced message which doesn't have one yet,
+/// so that the server append span and the consumer spans can be linked to the producer span.
+pub trait TraceContextProvider: Send + Sync + Debug {
+    fn traceparent(&self) -> Option<String>;
+}
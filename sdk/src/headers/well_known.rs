@@ -0,0 +1,226 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+
+/// The header key describing the format of the message payload, e.g. `application/json`.
+pub const CONTENT_TYPE: &str = "content-type";
+
+/// The header key under which the ID of the registered schema is attached to the message.
+pub const SCHEMA_ID: &str = "iggy-schema-id";
+
+/// The header key under which the correlation ID of the request is attached to both the request and the reply.
+pub const CORRELATION_ID: &str = "iggy-correlation-id";
+
+/// The header key carrying the W3C trace context, e.g. `00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01`.
+pub const TRACEPARENT: &str = "traceparent";
+
+/// The header key counting how many times the message has already been retried.
+pub const RETRY_COUNT: &str = "iggy-retry-count";
+
+const TRACEPARENT_LENGTH: usize = 55;
+
+/// Returns the format of the message payload, if any.
+pub fn get_content_type(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Result<Option<&str>, IggyError> {
+    get(headers, CONTENT_TYPE, |value| value.as_str())
+}
+
+/// Sets the format of the message payload.
+pub fn set_content_type(
+    headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    content_type: &str,
+) -> Result<(), IggyError> {
+    set(headers, CONTENT_TYPE, content_type.parse()?)
+}
+
+/// Returns the ID of the registered schema the message was produced with, if any.
+pub fn get_schema_id(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Result<Option<u32>, IggyError> {
+    get(headers, SCHEMA_ID, |value| value.as_uint32())
+}
+
+/// Sets the ID of the registered schema the message was produced with.
+pub fn set_schema_id(
+    headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    schema_id: u32,
+) -> Result<(), IggyError> {
+    set(headers, SCHEMA_ID, HeaderValue::from_uint32(schema_id)?)
+}
+
+/// Returns the correlation ID attached to the message, if any.
+pub fn get_correlation_id(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Result<Option<u128>, IggyError> {
+    get(headers, CORRELATION_ID, |value| value.as_uint128())
+}
+
+/// Attaches the correlation ID to the message.
+pub fn set_correlation_id(
+    headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    correlation_id: u128,
+) -> Result<(), IggyError> {
+    set(
+        headers,
+        CORRELATION_ID,
+        HeaderValue::from_uint128(correlation_id)?,
+    )
+}
+
+/// Returns the W3C trace context the message was produced within, if any.
+pub fn get_traceparent(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Result<Option<&str>, IggyError> {
+    get(headers, TRACEPARENT, |value| value.as_str())
+}
+
+/// Attaches the W3C trace context, so that the consumers can continue the trace.
+///
+/// Returns `IggyError::InvalidHeaderValue` if the value is not in the `version-trace_id-parent_id-flags` format.
+pub fn set_traceparent(
+    headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    traceparent: &str,
+) -> Result<(), IggyError> {
+    if !is_valid_traceparent(traceparent) {
+        return Err(IggyError::InvalidHeaderValue);
+    }
+
+    set(headers, TRACEPARENT, traceparent.parse()?)
+}
+
+/// Returns how many times the message has already been retried, the message without the header has not been retried yet.
+pub fn get_retry_count(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Result<u32, IggyError> {
+    get(headers, RETRY_COUNT, |value| value.as_uint32()).map(|count| count.unwrap_or_default())
+}
+
+/// Sets how many times the message has already been retried.
+pub fn set_retry_count(
+    headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    retry_count: u32,
+) -> Result<(), IggyError> {
+    set(headers, RETRY_COUNT, HeaderValue::from_uint32(retry_count)?)
+}
+
+/// Increments the retry count of the message which is about to be retried, and returns the new value.
+pub fn increment_retry_count(
+    headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Result<u32, IggyError> {
+    let retry_count = get_retry_count(headers)?.saturating_add(1);
+    set_retry_count(headers, retry_count)?;
+    Ok(retry_count)
+}
+
+fn get<'a, T>(
+    headers: &'a Option<HashMap<HeaderKey, HeaderValue>>,
+    key: &str,
+    map: impl FnOnce(&'a HeaderValue) -> Result<T, IggyError>,
+) -> Result<Option<T>, IggyError> {
+    let Some(headers) = headers else {
+        return Ok(None);
+    };
+
+    let key = HeaderKey::new(key)?;
+    headers.get(&key).map(map).transpose()
+}
+
+fn set(
+    headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    key: &str,
+    value: HeaderValue,
+) -> Result<(), IggyError> {
+    headers
+        .get_or_insert_with(HashMap::new)
+        .insert(HeaderKey::new(key)?, value);
+    Ok(())
+}
+
+fn is_valid_traceparent(traceparent: &str) -> bool {
+    let parts = traceparent.split('-').collect::<Vec<_>>();
+    traceparent.len() == TRACEPARENT_LENGTH
+        && parts.len() == 4
+        && [2, 32, 16, 2].iter().zip(&parts).all(|(length, part)| {
+            part.len() == *length && part.chars().all(|c| c.is_ascii_hexdigit())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACEPARENT_VALUE: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn well_known_headers_should_be_set_and_read() {
+        let mut headers = None;
+        assert_eq!(get_content_type(&headers).unwrap(), None);
+        assert_eq!(get_traceparent(&headers).unwrap(), None);
+
+        set_content_type(&mut headers, "application/json").unwrap();
+        set_schema_id(&mut headers, 7).unwrap();
+        set_correlation_id(&mut headers, 42).unwrap();
+        set_traceparent(&mut headers, TRACEPARENT_VALUE).unwrap();
+
+        assert_eq!(
+            get_content_type(&headers).unwrap(),
+            Some("application/json")
+        );
+        assert_eq!(get_schema_id(&headers).unwrap(), Some(7));
+        assert_eq!(get_correlation_id(&headers).unwrap(), Some(42));
+        assert_eq!(get_traceparent(&headers).unwrap(), Some(TRACEPARENT_VALUE));
+    }
+
+    #[test]
+    fn retry_count_should_start_from_zero_and_be_incremented() {
+        let mut headers = None;
+        assert_eq!(get_retry_count(&headers).unwrap(), 0);
+
+        assert_eq!(increment_retry_count(&mut headers).unwrap(), 1);
+        assert_eq!(increment_retry_count(&mut headers).unwrap(), 2);
+        assert_eq!(get_retry_count(&headers).unwrap(), 2);
+    }
+
+    #[test]
+    fn invalid_traceparent_should_be_rejected() {
+        let mut headers = None;
+        assert!(set_traceparent(&mut headers, "00-invalid-01").is_err());
+        assert!(set_traceparent(
+            &mut headers,
+            "00-4bf92f3577b34da6a3ce929d0e0e473g-00f067aa0ba902b7-01"
+        )
+        .is_err());
+        assert!(headers.is_none());
+    }
+
+    #[test]
+    fn header_of_unexpected_kind_should_fail_to_be_read() {
+        let mut headers = None;
+        set_retry_count(&mut headers, 1).unwrap();
+        headers.as_mut().unwrap().insert(
+            HeaderKey::new(CONTENT_TYPE).unwrap(),
+            HeaderValue::from_uint32(1).unwrap(),
+        );
+
+        assert!(get_content_type(&headers).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bookmarks::store_bookmark::StoreBookmark;
+use crate::client::BookmarkClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::http::HttpTransport;
+use crate::identifier::Identifier;
+use crate::models::bookmark::Bookmark;
+use async_trait::async_trait;
+
+#[async_trait]
+impl BookmarkClient for HttpClient {
+    async fn get_bookmark(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<Option<Bookmark>, IggyError> {
+        let response = self
+            .get(&format!(
+                "{}/{name}",
+                get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ))
+            .await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let bookmark = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(bookmark))
+    }
+
+    async fn get_bookmarks(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Bookmark>, IggyError> {
+        let response = self
+            .get(&get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()))
+            .await?;
+        let bookmarks = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(bookmarks)
+    }
+
+    async fn store_bookmark(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+            &StoreBookmark {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                name: name.to_string(),
+                partition_id,
+                offset,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_bookmark(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<(), IggyError> {
+        self.delete(&format!(
+            "{}/{name}",
+            get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+        ))
+        .await?;
+        Ok(())
+    }
+}
+
+fn get_path(stream_id: &str, topic_id: &str) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/bookmarks")
+}
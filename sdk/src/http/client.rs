@@ -26,7 +26,8 @@ use crate::models::identity_info::IdentityInfo;
 use crate::utils::duration::IggyDuration;
 use async_broadcast::{broadcast, Receiver, Sender};
 use async_trait::async_trait;
-use reqwest::{Response, StatusCode, Url};
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+use reqwest::{Body, Response, StatusCode, Url};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
 use serde::Serialize;
@@ -285,6 +286,50 @@ impl HttpClient {
         })
     }
 
+    /// Invoke HTTP POST request to the Iggy API with the (possibly streamed) body of the given content type.
+    pub(crate) async fn post_stream(
+        &self,
+        path: &str,
+        content_type: &str,
+        body: Body,
+    ) -> Result<Response, IggyError> {
+        let url = self.get_url(path)?;
+        self.fail_if_not_authenticated(path).await?;
+        let token = self.access_token.read().await;
+        let response = self
+            .client
+            .post(url)
+            .bearer_auth(token.deref())
+            .header(CONTENT_TYPE, content_type)
+            .body(body)
+            .send()
+            .await
+            .map_err(|_| IggyError::InvalidHttpRequest)?;
+        Self::handle_response(response).await
+    }
+
+    /// Invoke HTTP GET request to the Iggy API with the query parameters, accepting the response of the given content type.
+    pub(crate) async fn get_with_query_accepting<T: Serialize + Sync + ?Sized>(
+        &self,
+        path: &str,
+        query: &T,
+        content_type: &str,
+    ) -> Result<Response, IggyError> {
+        let url = self.get_url(path)?;
+        self.fail_if_not_authenticated(path).await?;
+        let token = self.access_token.read().await;
+        let response = self
+            .client
+            .get(url)
+            .bearer_auth(token.deref())
+            .header(ACCEPT, content_type)
+            .query(query)
+            .send()
+            .await
+            .map_err(|_| IggyError::InvalidHttpRequest)?;
+        Self::handle_response(response).await
+    }
+
     async fn handle_response(response: Response) -> Result<Response, IggyError> {
         let status = response.status();
         match status.is_success() {
@@ -17,6 +17,7 @@
  */
 
 use crate::client::ConsumerGroupClient;
+use crate::consumer_groups::assignment_strategy::AssignmentStrategy;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
@@ -76,6 +77,24 @@ impl ConsumerGroupClient for HttpClient {
         topic_id: &Identifier,
         name: &str,
         group_id: Option<u32>,
+    ) -> Result<ConsumerGroupDetails, IggyError> {
+        self.create_consumer_group_with_strategy(
+            stream_id,
+            topic_id,
+            name,
+            group_id,
+            AssignmentStrategy::default(),
+        )
+        .await
+    }
+
+    async fn create_consumer_group_with_strategy(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+        assignment_strategy: AssignmentStrategy,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         let response = self
             .post(
@@ -85,6 +104,7 @@ impl ConsumerGroupClient for HttpClient {
                     topic_id: topic_id.clone(),
                     name: name.to_string(),
                     group_id,
+                    assignment_strategy,
                 },
             )
             .await?;
@@ -119,6 +139,16 @@ impl ConsumerGroupClient for HttpClient {
         Err(IggyError::FeatureUnavailable)
     }
 
+    async fn join_consumer_group_with_strategy(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+        _: &Identifier,
+        _: AssignmentStrategy,
+    ) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
     async fn leave_consumer_group(
         &self,
         _: &Identifier,
@@ -127,6 +157,15 @@ impl ConsumerGroupClient for HttpClient {
     ) -> Result<(), IggyError> {
         Err(IggyError::FeatureUnavailable)
     }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+        _: &Identifier,
+    ) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
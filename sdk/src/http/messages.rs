@@ -17,16 +17,34 @@
  */
 
 use crate::client::MessageClient;
+use crate::confirmation::ConfirmationLevel;
 use crate::consumer::Consumer;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
+use crate::messages::ack_messages::AckMessages;
 use crate::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
+use crate::messages::nack_messages::NackMessages;
 use crate::messages::poll_messages::{PollMessages, PollingStrategy};
-use crate::messages::send_messages::{Message, Partitioning, SendMessages};
+use crate::messages::reject_messages::RejectMessages;
+use crate::messages::send_messages::{self, Message, Partitioning, SendMessages};
+use crate::messages::AutoCommitMode;
 use crate::models::messages::PolledMessages;
+use crate::utils::duration::IggyDuration;
 use async_trait::async_trait;
+use futures::{stream, Stream, StreamExt};
+use reqwest::Body;
+use serde::Serialize;
+
+const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+const BINARY_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// The first line of the NDJSON stream.
+#[derive(Debug, Serialize)]
+struct NdjsonHeader<'a> {
+    partitioning: &'a Partitioning,
+}
 
 #[async_trait]
 impl MessageClient for HttpClient {
@@ -39,6 +57,28 @@ impl MessageClient for HttpClient {
         strategy: &PollingStrategy,
         count: u32,
         auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        self.poll_messages_with_commit_mode(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            AutoCommitMode::from(auto_commit),
+        )
+        .await
+    }
+
+    async fn poll_messages_with_commit_mode(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: AutoCommitMode,
     ) -> Result<PolledMessages, IggyError> {
         let response = self
             .get_with_query(
@@ -67,6 +107,24 @@ impl MessageClient for HttpClient {
         topic_id: &Identifier,
         partitioning: &Partitioning,
         messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        self.send_messages_with_confirmation(
+            stream_id,
+            topic_id,
+            partitioning,
+            messages,
+            ConfirmationLevel::default(),
+        )
+        .await
+    }
+
+    async fn send_messages_with_confirmation(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+        confirmation: ConfirmationLevel,
     ) -> Result<(), IggyError> {
         self.post(
             &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
@@ -75,6 +133,7 @@ impl MessageClient for HttpClient {
                 topic_id: topic_id.clone(),
                 partitioning: partitioning.clone(),
                 messages: messages.to_vec(),
+                confirmation,
             },
         )
         .await?;
@@ -106,12 +165,218 @@ impl MessageClient for HttpClient {
             .await?;
         Ok(())
     }
+
+    async fn reject_messages(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        self.post(
+            &get_path_reject(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+            &RejectMessages {
+                consumer: consumer.clone(),
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                offsets: offsets.to_vec(),
+                reason: reason.to_string(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn nack_messages(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        redelivery_delay: Option<IggyDuration>,
+    ) -> Result<(), IggyError> {
+        self.post(
+            &get_path_nack(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+            &NackMessages {
+                consumer: consumer.clone(),
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                offsets: offsets.to_vec(),
+                redelivery_delay,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn ack_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        self.post(
+            &get_path_ack(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+            &AckMessages {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                offsets: offsets.to_vec(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn begin_transaction(&self) -> Result<u64, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn commit_transaction(&self, _: u64) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn abort_transaction(&self, _: u64) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn send_offsets_to_transaction(
+        &self,
+        _: u64,
+        _: &Consumer,
+        _: &Identifier,
+        _: &Identifier,
+        _: Option<u32>,
+        _: u64,
+    ) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+}
+
+impl HttpClient {
+    /// Send the messages streamed as NDJSON using specified partitioning strategy to the given stream and topic by unique IDs or names,
+    /// so that the large batch of messages is never serialized into a single JSON array in memory.
+    ///
+    /// The server appends the messages in batches as they arrive, thus the ones appended before an error are retained.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    pub async fn send_messages_stream<S>(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: S,
+    ) -> Result<(), IggyError>
+    where
+        S: Stream<Item = Message> + Send + 'static,
+    {
+        let header =
+            to_ndjson_line(&NdjsonHeader { partitioning }).map_err(|_| IggyError::InvalidFormat)?;
+        let lines = stream::once(async move { Ok(header) })
+            .chain(messages.map(|message| to_ndjson_line(&message)));
+        self.post_stream(
+            &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+            NDJSON_CONTENT_TYPE,
+            Body::wrap_stream(lines),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Send the messages encoded in the binary format of the TCP protocol using specified partitioning strategy
+    /// to the given stream and topic by unique IDs or names, so that the payloads are sent as they are, without the Base64 encoding.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    pub async fn send_messages_binary(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &[Message],
+        confirmation: ConfirmationLevel,
+    ) -> Result<(), IggyError> {
+        self.post_stream(
+            &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+            BINARY_CONTENT_TYPE,
+            Body::from(send_messages::as_bytes(
+                stream_id,
+                topic_id,
+                partitioning,
+                messages,
+                confirmation,
+            )),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Poll the messages encoded in the binary format of the TCP protocol from the given stream and topic by unique IDs or names,
+    /// so that the payloads are received as they are, without the Base64 encoding.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[cfg(feature = "binary")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn poll_messages_binary(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: AutoCommitMode,
+    ) -> Result<PolledMessages, IggyError> {
+        let response = self
+            .get_with_query_accepting(
+                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &PollMessages {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    consumer: consumer.clone(),
+                    strategy: *strategy,
+                    count,
+                    auto_commit,
+                },
+                BINARY_CONTENT_TYPE,
+            )
+            .await?;
+        let payload = response
+            .bytes()
+            .await
+            .map_err(|_| IggyError::InvalidBytesResponse)?;
+        crate::binary::mapper::map_polled_messages(payload)
+    }
+}
+
+fn to_ndjson_line<T: Serialize>(value: &T) -> Result<Vec<u8>, serde_json::Error> {
+    let mut line = serde_json::to_vec(value)?;
+    line.push(b'\n');
+    Ok(line)
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
     format!("streams/{stream_id}/topics/{topic_id}/messages")
 }
 
+fn get_path_reject(stream_id: &str, topic_id: &str) -> String {
+    format!("{}/reject", get_path(stream_id, topic_id))
+}
+
+fn get_path_nack(stream_id: &str, topic_id: &str) -> String {
+    format!("{}/nack", get_path(stream_id, topic_id))
+}
+
+fn get_path_ack(stream_id: &str, topic_id: &str) -> String {
+    format!("{}/ack", get_path(stream_id, topic_id))
+}
+
 fn get_path_flush_unsaved_buffer(
     stream_id: &str,
     topic_id: &str,
@@ -22,6 +22,7 @@ use async_trait::async_trait;
 use reqwest::{Response, Url};
 use serde::Serialize;
 
+pub mod bookmarks;
 #[allow(deprecated)]
 pub mod client;
 pub mod config;
@@ -30,6 +31,8 @@ pub mod consumer_offsets;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod quotas;
+pub mod schemas;
 pub mod segments;
 pub mod streams;
 pub mod system;
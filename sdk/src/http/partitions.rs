@@ -21,17 +21,50 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
+use crate::models::partition::PartitionDetails;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::key_routing_policy::KeyRoutingPolicy;
+use crate::partitions::move_partition::MovePartition;
+use crate::partitions::update_partition::UpdatePartition;
 use async_trait::async_trait;
 
 #[async_trait]
 impl PartitionClient for HttpClient {
+    async fn get_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+    ) -> Result<Option<PartitionDetails>, IggyError> {
+        let response = self
+            .get(&get_details_path(
+                &stream_id.as_cow_str(),
+                &topic_id.as_cow_str(),
+                &partition_id.as_cow_str(),
+            ))
+            .await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let partition = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(partition))
+    }
+
     async fn create_partitions(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitions_count: u32,
+        key_routing_policy: KeyRoutingPolicy,
     ) -> Result<(), IggyError> {
         self.post(
             &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
@@ -39,6 +72,7 @@ impl PartitionClient for HttpClient {
                 stream_id: stream_id.clone(),
                 topic_id: topic_id.clone(),
                 partitions_count,
+                key_routing_policy,
             },
         )
         .await?;
@@ -62,8 +96,63 @@ impl PartitionClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn update_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+        name: Option<&str>,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &get_details_path(
+                &stream_id.as_cow_str(),
+                &topic_id.as_cow_str(),
+                &partition_id.as_cow_str(),
+            ),
+            &UpdatePartition {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id: partition_id.clone(),
+                name: name.map(|name| name.to_string()),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn move_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+        directory: &str,
+    ) -> Result<(), IggyError> {
+        self.post(
+            &format!(
+                "{}/move",
+                get_details_path(
+                    &stream_id.as_cow_str(),
+                    &topic_id.as_cow_str(),
+                    &partition_id.as_cow_str(),
+                )
+            ),
+            &MovePartition {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id: partition_id.clone(),
+                directory: directory.to_string(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
     format!("streams/{stream_id}/topics/{topic_id}/partitions")
 }
+
+fn get_details_path(stream_id: &str, topic_id: &str, partition_id: &str) -> String {
+    format!("{}/{partition_id}", get_path(stream_id, topic_id))
+}
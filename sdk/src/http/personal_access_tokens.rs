@@ -21,7 +21,9 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::models::identity_info::IdentityInfo;
-use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::personal_access_token::{
+    PersonalAccessTokenInfo, PersonalAccessTokenScope, RawPersonalAccessToken,
+};
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
@@ -44,6 +46,17 @@ impl PersonalAccessTokenClient for HttpClient {
         &self,
         name: &str,
         expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        self.create_personal_access_token_with_restrictions(name, expiry, None, None)
+            .await
+    }
+
+    async fn create_personal_access_token_with_restrictions(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+        scope: Option<PersonalAccessTokenScope>,
+        allowed_addresses: Option<Vec<String>>,
     ) -> Result<RawPersonalAccessToken, IggyError> {
         let response = self
             .post(
@@ -51,6 +64,8 @@ impl PersonalAccessTokenClient for HttpClient {
                 &CreatePersonalAccessToken {
                     name: name.to_string(),
                     expiry,
+                    scope,
+                    allowed_addresses,
                 },
             )
             .await?;
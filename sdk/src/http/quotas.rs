@@ -0,0 +1,80 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::QuotaClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::http::HttpTransport;
+use crate::identifier::Identifier;
+use crate::models::quota::{Quota, QuotaLimits};
+use crate::quotas::set_quota::SetQuota;
+use async_trait::async_trait;
+
+const PATH: &str = "/quotas";
+
+#[async_trait]
+impl QuotaClient for HttpClient {
+    async fn get_quotas(&self) -> Result<Vec<Quota>, IggyError> {
+        let response = self.get(PATH).await?;
+        let quotas = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(quotas)
+    }
+
+    async fn set_user_quota(
+        &self,
+        user_id: &Identifier,
+        limits: Option<QuotaLimits>,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!("{PATH}/users/{}", user_id.as_cow_str()),
+            &SetQuota {
+                user_id: Some(user_id.clone()),
+                limits,
+                ..SetQuota::default()
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn set_topic_quota(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        limits: Option<QuotaLimits>,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{PATH}/streams/{}/topics/{}",
+                stream_id.as_cow_str(),
+                topic_id.as_cow_str()
+            ),
+            &SetQuota {
+                stream_id: Some(stream_id.clone()),
+                topic_id: Some(topic_id.clone()),
+                limits,
+                ..SetQuota::default()
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::client::SchemaClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::http::HttpTransport;
+use crate::models::schema::Schema;
+use crate::models::topic_schema::TopicSchema;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::schema_compatibility::SchemaCompatibility;
+use crate::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use async_trait::async_trait;
+
+const PATH: &str = "/schemas";
+
+#[async_trait]
+impl SchemaClient for HttpClient {
+    async fn get_schema(&self, schema_id: u32) -> Result<Option<Schema>, IggyError> {
+        let response = self.get(&format!("{PATH}/{schema_id}")).await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let schema = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(schema))
+    }
+
+    async fn get_schemas(&self, subject: &str) -> Result<Vec<Schema>, IggyError> {
+        let response = self.get(&get_versions_path(subject)).await?;
+        let schemas = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(schemas)
+    }
+
+    async fn get_subject_schema(
+        &self,
+        subject: &str,
+        version: Option<u32>,
+    ) -> Result<Option<Schema>, IggyError> {
+        let version = version.map_or("latest".to_string(), |version| version.to_string());
+        let response = self
+            .get(&format!("{}/{version}", get_versions_path(subject)))
+            .await;
+        if let Err(error) = response {
+            if matches!(error, IggyError::ResourceNotFound(_)) {
+                return Ok(None);
+            }
+
+            return Err(error);
+        }
+
+        let schema = response?
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(Some(schema))
+    }
+
+    async fn register_schema(
+        &self,
+        subject: &str,
+        schema: TopicSchema,
+    ) -> Result<Schema, IggyError> {
+        let response = self
+            .post(
+                &get_versions_path(subject),
+                &RegisterSchema {
+                    subject: subject.to_string(),
+                    schema,
+                },
+            )
+            .await?;
+        let schema = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(schema)
+    }
+
+    async fn update_schema_compatibility(
+        &self,
+        subject: &str,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!("{PATH}/subjects/{subject}/compatibility"),
+            &UpdateSchemaCompatibility {
+                subject: subject.to_string(),
+                compatibility,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+fn get_versions_path(subject: &str) -> String {
+    format!("{PATH}/subjects/{subject}/versions")
+}
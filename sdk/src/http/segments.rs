@@ -20,7 +20,9 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
+use crate::models::segments_verification::SegmentsVerification;
 use crate::segments::delete_segments::DeleteSegments;
+use crate::segments::verify_segments::VerifySegments;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -48,6 +50,36 @@ impl SegmentClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn verify_segments(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<SegmentsVerification, IggyError> {
+        let response = self
+            .post(
+                &format!(
+                    "{}/verify",
+                    get_path(
+                        &stream_id.as_cow_str(),
+                        &topic_id.as_cow_str(),
+                        partition_id,
+                    )
+                ),
+                &VerifySegments {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                },
+            )
+            .await?;
+        let verification = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(verification)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str, partition_id: u32) -> String {
@@ -20,11 +20,20 @@ use crate::client::SystemClient;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
+use crate::identifier::Identifier;
+use crate::models::audit_entry::AuditEntry;
+use crate::models::backup::{BackupDestination, BackupInfo};
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::cluster::ClusterMetadata;
+use crate::models::config_value::ConfigValue;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
+use crate::models::user_info::UserId;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use crate::system::create_backup::CreateBackup;
+use crate::system::get_audit_log::GetAuditLog;
 use crate::system::get_snapshot::GetSnapshot;
+use crate::system::restore_backup::RestoreBackup;
 use crate::utils::duration::IggyDuration;
 use async_trait::async_trait;
 
@@ -32,6 +41,10 @@ const PING: &str = "/ping";
 const CLIENTS: &str = "/clients";
 const STATS: &str = "/stats";
 const SNAPSHOT: &str = "/snapshot";
+const CONFIG: &str = "/config";
+const AUDIT: &str = "/audit";
+const BACKUPS: &str = "/backups";
+const CLUSTER_METADATA: &str = "/cluster/metadata";
 
 #[async_trait]
 impl SystemClient for HttpClient {
@@ -74,6 +87,47 @@ impl SystemClient for HttpClient {
         Ok(clients)
     }
 
+    async fn get_config(&self) -> Result<Vec<ConfigValue>, IggyError> {
+        let response = self.get(CONFIG).await?;
+        let values = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(values)
+    }
+
+    async fn get_audit_log(
+        &self,
+        user_id: Option<UserId>,
+        action: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<AuditEntry>, IggyError> {
+        let response = self
+            .get_with_query(
+                AUDIT,
+                &GetAuditLog {
+                    user_id,
+                    action: action.map(|action| action.to_string()),
+                    limit,
+                },
+            )
+            .await?;
+        let entries = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(entries)
+    }
+
+    async fn get_cluster_metadata(&self) -> Result<ClusterMetadata, IggyError> {
+        let response = self.get(CLUSTER_METADATA).await?;
+        let metadata = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(metadata)
+    }
+
     async fn ping(&self) -> Result<(), IggyError> {
         self.get(PING).await?;
         Ok(())
@@ -104,4 +158,50 @@ impl SystemClient for HttpClient {
         let snapshot = Snapshot::new(file.to_vec());
         Ok(snapshot)
     }
+
+    async fn create_backup(
+        &self,
+        name: &str,
+        streams: &[Identifier],
+        destination: BackupDestination,
+        base: Option<&str>,
+    ) -> Result<BackupInfo, IggyError> {
+        let response = self
+            .post(
+                BACKUPS,
+                &CreateBackup {
+                    name: name.to_string(),
+                    streams: streams.to_vec(),
+                    destination,
+                    base: base.map(|base| base.to_string()),
+                },
+            )
+            .await?;
+        let backup = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(backup)
+    }
+
+    async fn restore_backup(
+        &self,
+        name: &str,
+        streams: &[Identifier],
+    ) -> Result<BackupInfo, IggyError> {
+        let response = self
+            .post(
+                &format!("{BACKUPS}/{name}/restore"),
+                &RestoreBackup {
+                    name: name.to_string(),
+                    streams: streams.to_vec(),
+                },
+            )
+            .await?;
+        let backup = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(backup)
+    }
 }
@@ -23,8 +23,13 @@ use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::topic_options::TopicOptions;
 use crate::topics::create_topic::CreateTopic;
+use crate::topics::delete_topic::DeleteTopic;
+use crate::topics::set_topic_throttle::SetTopicThrottle;
 use crate::topics::update_topic::UpdateTopic;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::topic_size::MaxTopicSize;
 use async_trait::async_trait;
@@ -76,6 +81,32 @@ impl TopicClient for HttpClient {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+    ) -> Result<TopicDetails, IggyError> {
+        self.create_topic_with_options(
+            stream_id,
+            name,
+            partitions_count,
+            compression_algorithm,
+            replication_factor,
+            topic_id,
+            message_expiry,
+            max_topic_size,
+            TopicOptions::default(),
+        )
+        .await
+    }
+
+    async fn create_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: TopicOptions,
     ) -> Result<TopicDetails, IggyError> {
         let response = self
             .post(
@@ -89,6 +120,7 @@ impl TopicClient for HttpClient {
                     topic_id,
                     message_expiry,
                     max_topic_size,
+                    options,
                 },
             )
             .await?;
@@ -119,6 +151,35 @@ impl TopicClient for HttpClient {
                 replication_factor,
                 message_expiry,
                 max_topic_size,
+                options: None,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_topic_with_options(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: MaxTopicSize,
+        options: TopicOptions,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+            &UpdateTopic {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                name: name.to_string(),
+                compression_algorithm,
+                replication_factor,
+                message_expiry,
+                max_topic_size,
+                options: Some(options),
             },
         )
         .await?;
@@ -138,6 +199,23 @@ impl TopicClient for HttpClient {
         Ok(())
     }
 
+    async fn delete_topic_deferred(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.delete_with_query(
+            &get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+            &DeleteTopic {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                deferred: true,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn purge_topic(
         &self,
         stream_id: &Identifier,
@@ -150,6 +228,29 @@ impl TopicClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn set_topic_throttle(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        max_throughput: Option<IggyByteSize>,
+        duration: Option<IggyDuration>,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/throttle",
+                &get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &SetTopicThrottle {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                max_throughput,
+                duration,
+            },
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 fn get_path(stream_id: &str) -> String {
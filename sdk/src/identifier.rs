@@ -37,6 +37,7 @@ use std::str::FromStr;
 /// - `value`: the binary value of the identifier payload.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Identifier {
     /// The kind of the identifier.
     pub kind: IdKind,
@@ -45,12 +46,14 @@ pub struct Identifier {
     pub length: u8,
     /// The binary value of the identifier payload, max length is 255 bytes.
     #[serde_as(as = "Base64")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
     pub value: Vec<u8>,
 }
 
 /// `IdKind` represents the kind of the identifier.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone, Eq)]
 #[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum IdKind {
     /// The identifier is numeric.
     #[default]
@@ -17,7 +17,9 @@
  */
 
 pub mod args;
+#[cfg(feature = "binary")]
 pub mod binary;
+pub mod bookmarks;
 pub mod bytes_serializable;
 #[cfg(feature = "iggy-cli")]
 pub mod cli;
@@ -25,19 +27,24 @@ pub mod cli_command;
 #[allow(deprecated)]
 pub mod client;
 pub mod client_error;
+#[cfg(feature = "client")]
 #[allow(deprecated)]
 pub mod client_provider;
+#[cfg(feature = "client")]
 #[allow(deprecated)]
 pub mod clients;
 pub mod command;
 pub mod compression;
 pub mod confirmation;
 pub mod consumer;
+#[cfg(feature = "client")]
 pub mod consumer_ext;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod diagnostic;
 pub mod error;
+pub mod headers;
+#[cfg(feature = "http")]
 pub mod http;
 pub mod identifier;
 pub mod locking;
@@ -47,12 +54,17 @@ pub mod partitioner;
 pub mod partitions;
 pub mod personal_access_tokens;
 pub mod prelude;
+#[cfg(feature = "quic")]
 pub mod quic;
+pub mod quotas;
+pub mod schemas;
 pub mod segments;
 pub mod snapshot;
+#[cfg(feature = "tcp")]
 pub mod stream_builder;
 pub mod streams;
 pub mod system;
+#[cfg(feature = "tcp")]
 pub mod tcp;
 pub mod topics;
 pub mod users;
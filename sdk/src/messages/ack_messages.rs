@@ -0,0 +1,213 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, ACK_MESSAGES_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The maximum number of messages which can be acknowledged at once.
+pub const MAX_ACKED_MESSAGES: usize = 1000;
+
+/// `AckMessages` command is used to acknowledge the individual messages polled from the topic in the queue mode, so that they're never redelivered.
+/// The messages which aren't acknowledged before their visibility timeout elapses are redelivered to any of the consumers polling the topic.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - partition ID from which the messages were polled.
+/// - `offsets` - offsets of the messages to be acknowledged.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AckMessages {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Partition ID from which the messages were polled.
+    pub partition_id: u32,
+    /// Offsets of the messages to be acknowledged.
+    pub offsets: Vec<u64>,
+}
+
+impl Default for AckMessages {
+    fn default() -> Self {
+        AckMessages {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: 1,
+            offsets: vec![0],
+        }
+    }
+}
+
+impl Command for AckMessages {
+    fn code(&self) -> u32 {
+        ACK_MESSAGES_CODE
+    }
+}
+
+impl Validatable<IggyError> for AckMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.offsets.is_empty() || self.offsets.len() > MAX_ACKED_MESSAGES {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for AckMessages {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            8 + stream_id_bytes.len() + topic_id_bytes.len() + 8 * self.offsets.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u32_le(self.offsets.len() as u32);
+        for offset in &self.offsets {
+            bytes.put_u64_le(*offset);
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<AckMessages, IggyError> {
+        if bytes.len() < 14 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() < position + 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let offsets_count = u32::from_le_bytes(
+            bytes[position + 4..position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 8;
+        if bytes.len() != position + 8 * offsets_count {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut offsets = Vec::with_capacity(offsets_count);
+        for _ in 0..offsets_count {
+            let offset = u64::from_le_bytes(
+                bytes[position..position + 8]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            offsets.push(offset);
+            position += 8;
+        }
+        let command = AckMessages {
+            stream_id,
+            topic_id,
+            partition_id,
+            offsets,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for AckMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            self.offsets
+                .iter()
+                .map(|offset| offset.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = AckMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("tasks").unwrap(),
+            partition_id: 3,
+            offsets: vec![5, 6, 10],
+        };
+
+        let deserialized = AckMessages::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let offsets = [7u64, 8u64];
+
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&stream_id.to_bytes());
+        bytes.put_slice(&topic_id.to_bytes());
+        bytes.put_u32_le(4);
+        bytes.put_u32_le(offsets.len() as u32);
+        for offset in offsets {
+            bytes.put_u64_le(offset);
+        }
+
+        let command = AckMessages::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, 4);
+        assert_eq!(command.offsets, offsets);
+    }
+
+    #[test]
+    fn command_without_offsets_should_be_invalid() {
+        let command = AckMessages {
+            offsets: vec![],
+            ..Default::default()
+        };
+        assert!(command.validate().is_err());
+    }
+}
@@ -0,0 +1,188 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::duration::IggyDuration;
+use bytes::{BufMut, Bytes, BytesMut};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `AutoCommitMode` specifies when the server stores the consumer offset of the polled messages.
+/// It has the following modes:
+/// - `Manual` - the offset is never stored automatically, the consumer has to store it on its own.
+/// - `BeforeDelivery` - the offset of the last polled message is stored before the messages are returned (at-most-once).
+/// - `AfterPoll` - the offset of the last polled message is stored once the consumer polls again (at-least-once).
+/// - `Interval` - the offset of the last polled message is stored once the consumer polls again, but no more often than the interval.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub enum AutoCommitMode {
+    /// The offset is never stored automatically.
+    #[default]
+    Manual,
+    /// The offset of the last polled message is stored before the messages are returned.
+    BeforeDelivery,
+    /// The offset of the last polled message is stored once the consumer polls again.
+    AfterPoll,
+    /// The offset of the last polled message is stored once the consumer polls again, no more often than the interval.
+    Interval(IggyDuration),
+}
+
+impl AutoCommitMode {
+    /// Returns code of the auto-commit mode.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            AutoCommitMode::Manual => 0,
+            AutoCommitMode::BeforeDelivery => 1,
+            AutoCommitMode::AfterPoll => 2,
+            AutoCommitMode::Interval(_) => 3,
+        }
+    }
+
+    /// Returns whether the offset is stored automatically in any way.
+    pub fn is_enabled(&self) -> bool {
+        *self != AutoCommitMode::Manual
+    }
+}
+
+impl From<bool> for AutoCommitMode {
+    fn from(auto_commit: bool) -> Self {
+        if auto_commit {
+            AutoCommitMode::BeforeDelivery
+        } else {
+            AutoCommitMode::Manual
+        }
+    }
+}
+
+impl FromStr for AutoCommitMode {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "m" | "manual" | "false" => Ok(AutoCommitMode::Manual),
+            "b" | "before_delivery" | "true" => Ok(AutoCommitMode::BeforeDelivery),
+            "a" | "after_poll" => Ok(AutoCommitMode::AfterPoll),
+            input => {
+                let Some(interval) = input
+                    .strip_prefix("interval:")
+                    .or_else(|| input.strip_prefix("i:"))
+                else {
+                    return Err(IggyError::InvalidCommand);
+                };
+                let interval =
+                    IggyDuration::from_str(interval).map_err(|_| IggyError::InvalidCommand)?;
+                Ok(AutoCommitMode::Interval(interval))
+            }
+        }
+    }
+}
+
+impl Display for AutoCommitMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AutoCommitMode::Manual => write!(f, "manual"),
+            AutoCommitMode::BeforeDelivery => write!(f, "before_delivery"),
+            AutoCommitMode::AfterPoll => write!(f, "after_poll"),
+            AutoCommitMode::Interval(interval) => write!(f, "interval:{interval}"),
+        }
+    }
+}
+
+impl BytesSerializable for AutoCommitMode {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(9);
+        bytes.put_u8(self.as_code());
+        if let AutoCommitMode::Interval(interval) = self {
+            bytes.put_u64_le(interval.as_micros());
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        match bytes[0] {
+            0 => Ok(AutoCommitMode::Manual),
+            1 => Ok(AutoCommitMode::BeforeDelivery),
+            2 => Ok(AutoCommitMode::AfterPoll),
+            3 => {
+                if bytes.len() < 9 {
+                    return Err(IggyError::InvalidCommand);
+                }
+
+                let interval = u64::from_le_bytes(
+                    bytes[1..9]
+                        .try_into()
+                        .map_err(|_| IggyError::InvalidNumberEncoding)?,
+                );
+                Ok(AutoCommitMode::Interval(IggyDuration::from(interval)))
+            }
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let modes = [
+            AutoCommitMode::Manual,
+            AutoCommitMode::BeforeDelivery,
+            AutoCommitMode::AfterPoll,
+            AutoCommitMode::Interval(IggyDuration::from_str("5s").unwrap()),
+        ];
+
+        for mode in modes {
+            let bytes = mode.to_bytes();
+            let deserialized = AutoCommitMode::from_bytes(bytes).unwrap();
+            assert_eq!(deserialized, mode);
+        }
+    }
+
+    #[test]
+    fn should_be_parsed_from_string() {
+        assert_eq!(
+            AutoCommitMode::from_str("manual").unwrap(),
+            AutoCommitMode::Manual
+        );
+        assert_eq!(
+            AutoCommitMode::from_str("true").unwrap(),
+            AutoCommitMode::BeforeDelivery
+        );
+        assert_eq!(
+            AutoCommitMode::from_str("after_poll").unwrap(),
+            AutoCommitMode::AfterPoll
+        );
+        assert_eq!(
+            AutoCommitMode::from_str("interval:1m").unwrap(),
+            AutoCommitMode::Interval(IggyDuration::from_str("1m").unwrap())
+        );
+        assert!(AutoCommitMode::from_str("interval:").is_err());
+        assert!(AutoCommitMode::from_str("sometimes").is_err());
+    }
+
+    #[test]
+    fn should_be_parsed_from_its_display() {
+        let mode = AutoCommitMode::Interval(IggyDuration::from_str("10s").unwrap());
+        assert_eq!(AutoCommitMode::from_str(&mode.to_string()).unwrap(), mode);
+    }
+}
@@ -16,20 +16,38 @@
  * under the License.
  */
 
+pub mod abort_transaction;
+pub mod ack_messages;
+mod auto_commit_mode;
+pub mod begin_transaction;
+pub mod commit_transaction;
 pub mod flush_unsaved_buffer;
+pub mod nack_messages;
 mod partitioning;
 mod partitioning_kind;
+mod payload_encoding;
 pub mod poll_messages;
 mod polling_kind;
 mod polling_strategy;
+pub mod reject_messages;
 pub mod send_messages;
+pub mod send_offsets_to_transaction;
 
-const MAX_HEADERS_SIZE: u32 = 100 * 1000;
+pub const MAX_HEADERS_SIZE: u32 = 100 * 1000;
 pub const MAX_PAYLOAD_SIZE: u32 = 10 * 1000 * 1000;
+pub use abort_transaction::AbortTransaction;
+pub use ack_messages::AckMessages;
+pub use auto_commit_mode::AutoCommitMode;
+pub use begin_transaction::BeginTransaction;
+pub use commit_transaction::CommitTransaction;
 pub use flush_unsaved_buffer::FlushUnsavedBuffer;
+pub use nack_messages::NackMessages;
 pub use partitioning::Partitioning;
 pub use partitioning_kind::PartitioningKind;
+pub use payload_encoding::PayloadEncoding;
 pub use poll_messages::PollMessages;
 pub use polling_kind::PollingKind;
 pub use polling_strategy::PollingStrategy;
+pub use reject_messages::RejectMessages;
 pub use send_messages::SendMessages;
+pub use send_offsets_to_transaction::SendOffsetsToTransaction;
@@ -0,0 +1,272 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, NACK_MESSAGES_CODE};
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::duration::IggyDuration;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The maximum number of messages which can be negatively acknowledged at once.
+pub const MAX_NACKED_MESSAGES: usize = 1000;
+
+/// `NackMessages` command is used to request the redelivery of the messages which couldn't be processed by the consumer.
+/// Each negative acknowledgement increments the delivery attempts of the message, and the message is returned again by the next polling (using `Next` strategy) once the optional redelivery delay has passed.
+/// It has additional payload:
+/// - `consumer` - the consumer that is requesting the redelivery, either the regular consumer or the consumer group.
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - partition ID from which the messages were polled. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
+/// - `offsets` - offsets of the messages to be redelivered.
+/// - `redelivery_delay` - optional delay after which the messages are redelivered, if not specified, they are redelivered by the next polling.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct NackMessages {
+    /// The consumer that is requesting the redelivery, either the regular consumer or the consumer group.
+    #[serde(flatten)]
+    pub consumer: Consumer,
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Partition ID from which the messages were polled. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
+    pub partition_id: Option<u32>,
+    /// Offsets of the messages to be redelivered.
+    pub offsets: Vec<u64>,
+    /// Optional delay after which the messages are redelivered, if not specified, they are redelivered by the next polling.
+    #[serde(default)]
+    pub redelivery_delay: Option<IggyDuration>,
+}
+
+impl Default for NackMessages {
+    fn default() -> Self {
+        NackMessages {
+            consumer: Consumer::default(),
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: Some(1),
+            offsets: vec![0],
+            redelivery_delay: None,
+        }
+    }
+}
+
+impl Command for NackMessages {
+    fn code(&self) -> u32 {
+        NACK_MESSAGES_CODE
+    }
+}
+
+impl Validatable<IggyError> for NackMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.offsets.is_empty() || self.offsets.len() > MAX_NACKED_MESSAGES {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for NackMessages {
+    fn to_bytes(&self) -> Bytes {
+        let consumer_bytes = self.consumer.to_bytes();
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            16 + consumer_bytes.len()
+                + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + 8 * self.offsets.len(),
+        );
+        bytes.put_slice(&consumer_bytes);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        if let Some(partition_id) = self.partition_id {
+            bytes.put_u32_le(partition_id);
+        } else {
+            bytes.put_u32_le(0);
+        }
+        bytes.put_u32_le(self.offsets.len() as u32);
+        for offset in &self.offsets {
+            bytes.put_u64_le(*offset);
+        }
+        match self.redelivery_delay {
+            Some(redelivery_delay) => bytes.put_u64_le(redelivery_delay.as_micros()),
+            None => bytes.put_u64_le(0),
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<NackMessages, IggyError> {
+        if bytes.len() < 25 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let consumer_kind = ConsumerKind::from_code(bytes[0])?;
+        let consumer_id = Identifier::from_bytes(bytes.slice(1..))?;
+        position += 1 + consumer_id.get_size_bytes().as_bytes_usize();
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() < position + 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let partition_id = if partition_id == 0 {
+            None
+        } else {
+            Some(partition_id)
+        };
+        let offsets_count = u32::from_le_bytes(
+            bytes[position + 4..position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 8;
+        if bytes.len() != position + 8 * offsets_count + 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut offsets = Vec::with_capacity(offsets_count);
+        for _ in 0..offsets_count {
+            let offset = u64::from_le_bytes(
+                bytes[position..position + 8]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            );
+            offsets.push(offset);
+            position += 8;
+        }
+        let redelivery_delay = u64::from_le_bytes(
+            bytes[position..position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let redelivery_delay = if redelivery_delay == 0 {
+            None
+        } else {
+            Some(IggyDuration::from(redelivery_delay))
+        };
+        let command = NackMessages {
+            consumer,
+            stream_id,
+            topic_id,
+            partition_id,
+            offsets,
+            redelivery_delay,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for NackMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.consumer,
+            self.stream_id,
+            self.topic_id,
+            self.partition_id.unwrap_or(0),
+            self.offsets
+                .iter()
+                .map(|offset| offset.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+            self.redelivery_delay.unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = NackMessages {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+            partition_id: Some(4),
+            offsets: vec![5, 6, 10],
+            redelivery_delay: Some(IggyDuration::ONE_SECOND),
+        };
+
+        let deserialized = NackMessages::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let consumer = Consumer::group(Identifier::numeric(1).unwrap());
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+        let offsets = [7u64, 8u64];
+
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&consumer.to_bytes());
+        bytes.put_slice(&stream_id.to_bytes());
+        bytes.put_slice(&topic_id.to_bytes());
+        bytes.put_u32_le(0);
+        bytes.put_u32_le(offsets.len() as u32);
+        for offset in offsets {
+            bytes.put_u64_le(offset);
+        }
+        bytes.put_u64_le(0);
+
+        let command = NackMessages::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.consumer, consumer);
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, None);
+        assert_eq!(command.offsets, offsets);
+        assert!(command.redelivery_delay.is_none());
+    }
+
+    #[test]
+    fn command_without_offsets_should_be_invalid() {
+        let command = NackMessages {
+            offsets: vec![],
+            ..Default::default()
+        };
+        assert!(command.validate().is_err());
+    }
+}
@@ -0,0 +1,156 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::utils::text;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `PayloadEncoding` specifies how the binary message payload is represented as a string in the JSON of the HTTP API.
+/// It has the following encodings:
+/// - `Base64` - the payload is encoded as Base64, which can represent any binary payload (default).
+/// - `Utf8` - the payload is the UTF-8 text as is, which can represent only the valid UTF-8 payloads.
+/// - `Hex` - the payload is encoded as the lowercase hexadecimal string, which can represent any binary payload.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum PayloadEncoding {
+    /// The payload is encoded as Base64.
+    #[default]
+    Base64,
+    /// The payload is the UTF-8 text as is.
+    #[serde(alias = "utf-8")]
+    Utf8,
+    /// The payload is encoded as the hexadecimal string.
+    Hex,
+}
+
+impl PayloadEncoding {
+    /// Encodes the payload, and returns the encoding that was actually used along with the encoded payload.
+    /// The payload which is not a valid UTF-8 text can't be encoded as such, thus it's encoded as Base64 instead.
+    pub fn encode(&self, payload: &[u8]) -> (PayloadEncoding, String) {
+        match self {
+            PayloadEncoding::Base64 => (PayloadEncoding::Base64, text::as_base64(payload)),
+            PayloadEncoding::Utf8 => match std::str::from_utf8(payload) {
+                Ok(payload) => (PayloadEncoding::Utf8, payload.to_owned()),
+                Err(_) => (PayloadEncoding::Base64, text::as_base64(payload)),
+            },
+            PayloadEncoding::Hex => (PayloadEncoding::Hex, text::as_hex(payload)),
+        }
+    }
+
+    /// Decodes the payload encoded with this encoding.
+    pub fn decode(&self, payload: &str) -> Result<Bytes, IggyError> {
+        match self {
+            PayloadEncoding::Base64 => text::from_base64_as_bytes(payload).map(Bytes::from),
+            PayloadEncoding::Utf8 => Ok(Bytes::copy_from_slice(payload.as_bytes())),
+            PayloadEncoding::Hex => text::from_hex_as_bytes(payload).map(Bytes::from),
+        }
+    }
+}
+
+impl FromStr for PayloadEncoding {
+    type Err = IggyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "base64" => Ok(PayloadEncoding::Base64),
+            "utf8" | "utf-8" => Ok(PayloadEncoding::Utf8),
+            "hex" => Ok(PayloadEncoding::Hex),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for PayloadEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PayloadEncoding::Base64 => write!(f, "base64"),
+            PayloadEncoding::Utf8 => write!(f, "utf8"),
+            PayloadEncoding::Hex => write!(f, "hex"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_payload_should_survive_encoding_and_decoding() {
+        let payload = [0u8, 159, 146, 150, 255, b'a'];
+        for encoding in [PayloadEncoding::Base64, PayloadEncoding::Hex] {
+            let (used_encoding, encoded) = encoding.encode(&payload);
+            assert_eq!(used_encoding, encoding);
+            assert_eq!(
+                encoding.decode(&encoded).unwrap(),
+                Bytes::from(payload.to_vec())
+            );
+        }
+    }
+
+    #[test]
+    fn hex_payload_should_be_lowercase() {
+        let (_, encoded) = PayloadEncoding::Hex.encode(&[0, 10, 171, 255]);
+        assert_eq!(encoded, "000aabff");
+        assert_eq!(
+            PayloadEncoding::Hex.decode("000AABFF").unwrap(),
+            Bytes::from(vec![0, 10, 171, 255])
+        );
+        assert!(PayloadEncoding::Hex.decode("abc").is_err());
+        assert!(PayloadEncoding::Hex.decode("zz").is_err());
+    }
+
+    #[test]
+    fn utf8_encoding_should_fall_back_to_base64_for_binary_payload() {
+        let (encoding, encoded) = PayloadEncoding::Utf8.encode("zażółć".as_bytes());
+        assert_eq!(encoding, PayloadEncoding::Utf8);
+        assert_eq!(encoded, "zażółć");
+
+        let payload = [0u8, 159, 146, 150];
+        let (encoding, encoded) = PayloadEncoding::Utf8.encode(&payload);
+        assert_eq!(encoding, PayloadEncoding::Base64);
+        assert_eq!(
+            encoding.decode(&encoded).unwrap(),
+            Bytes::from(payload.to_vec())
+        );
+    }
+
+    #[test]
+    fn should_be_parsed_from_string() {
+        assert_eq!(
+            PayloadEncoding::from_str("base64").unwrap(),
+            PayloadEncoding::Base64
+        );
+        assert_eq!(
+            PayloadEncoding::from_str("utf-8").unwrap(),
+            PayloadEncoding::Utf8
+        );
+        assert_eq!(
+            PayloadEncoding::from_str("utf8").unwrap(),
+            PayloadEncoding::Utf8
+        );
+        assert_eq!(
+            PayloadEncoding::from_str("hex").unwrap(),
+            PayloadEncoding::Hex
+        );
+        assert!(PayloadEncoding::from_str("binary").is_err());
+    }
+}
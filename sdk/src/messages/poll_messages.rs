@@ -21,6 +21,7 @@ use crate::command::{Command, POLL_MESSAGES_CODE};
 use crate::consumer::{Consumer, ConsumerKind};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::messages::AutoCommitMode;
 use crate::utils::sizeable::Sizeable;
 use crate::utils::timestamp::IggyTimestamp;
 use crate::validatable::Validatable;
@@ -38,7 +39,8 @@ use std::str::FromStr;
 /// - `partition_id` - partition ID from which messages will be polled. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
 /// - `strategy` - polling strategy which specifies from where to start polling messages.
 /// - `count` - number of messages to poll.
-/// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
+/// - `auto_commit` - mode in which the offset is committed on the server automatically after polling the messages.
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     /// Consumer which will poll messages. Either regular consumer or consumer group.
@@ -59,9 +61,10 @@ pub struct PollMessages {
     #[serde(default = "default_count")]
     /// Number of messages to poll.
     pub count: u32,
+    #[serde_as(as = "DisplayFromStr")]
     #[serde(default)]
-    /// Whether to commit offset on the server automatically after polling the messages.
-    pub auto_commit: bool,
+    /// Mode in which the offset is committed on the server automatically after polling the messages.
+    pub auto_commit: AutoCommitMode,
 }
 
 /// `PollingStrategy` specifies from where to start polling messages.
@@ -97,7 +100,7 @@ pub enum PollingKind {
     First,
     /// Start polling from the last message in the partition.
     Last,
-    /// Start polling from the next message after the last polled message based on the stored consumer offset. Should be used with `auto_commit` set to `true`.
+    /// Start polling from the next message after the last polled message based on the stored consumer offset. Should be used with `auto_commit` enabled.
     Next,
 }
 
@@ -110,7 +113,7 @@ impl Default for PollMessages {
             partition_id: default_partition_id(),
             strategy: default_strategy(),
             count: default_count(),
-            auto_commit: false,
+            auto_commit: AutoCommitMode::Manual,
         }
     }
 }
@@ -189,7 +192,7 @@ impl PollingStrategy {
         }
     }
 
-    /// Poll messages from the next message after the last polled message based on the stored consumer offset. Should be used with `auto_commit` set to `true`.
+    /// Poll messages from the next message after the last polled message based on the stored consumer offset. Should be used with `auto_commit` enabled.
     pub fn next() -> Self {
         Self {
             kind: PollingKind::Next,
@@ -311,8 +314,7 @@ impl BytesSerializable for PollMessages {
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
-        let auto_commit = bytes[position + 12];
-        let auto_commit = matches!(auto_commit, 1);
+        let auto_commit = AutoCommitMode::from_bytes(bytes.slice(position + 12..))?;
         let command = PollMessages {
             consumer,
             stream_id,
@@ -334,17 +336,19 @@ pub(crate) fn as_bytes(
     consumer: &Consumer,
     strategy: &PollingStrategy,
     count: u32,
-    auto_commit: bool,
+    auto_commit: AutoCommitMode,
 ) -> Bytes {
     let consumer_bytes = consumer.to_bytes();
     let stream_id_bytes = stream_id.to_bytes();
     let topic_id_bytes = topic_id.to_bytes();
     let strategy_bytes = strategy.to_bytes();
+    let auto_commit_bytes = auto_commit.to_bytes();
     let mut bytes = BytesMut::with_capacity(
-        9 + consumer_bytes.len()
+        8 + consumer_bytes.len()
             + stream_id_bytes.len()
             + topic_id_bytes.len()
-            + strategy_bytes.len(),
+            + strategy_bytes.len()
+            + auto_commit_bytes.len(),
     );
     bytes.put_slice(&consumer_bytes);
     bytes.put_slice(&stream_id_bytes);
@@ -356,11 +360,7 @@ pub(crate) fn as_bytes(
     }
     bytes.put_slice(&strategy_bytes);
     bytes.put_u32_le(count);
-    if auto_commit {
-        bytes.put_u8(1);
-    } else {
-        bytes.put_u8(0);
-    }
+    bytes.put_slice(&auto_commit_bytes);
 
     bytes.freeze()
 }
@@ -376,7 +376,7 @@ impl Display for PollMessages {
             self.partition_id.unwrap_or(0),
             self.strategy,
             self.count,
-            auto_commit_to_string(self.auto_commit)
+            self.auto_commit
         )
     }
 }
@@ -387,14 +387,6 @@ impl Display for PollingStrategy {
     }
 }
 
-fn auto_commit_to_string(auto_commit: bool) -> &'static str {
-    if auto_commit {
-        "a"
-    } else {
-        "n"
-    }
-}
-
 impl BytesSerializable for PollingStrategy {
     fn to_bytes(&self) -> Bytes {
         let mut bytes = BytesMut::with_capacity(9);
@@ -422,6 +414,7 @@ impl BytesSerializable for PollingStrategy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::duration::IggyDuration;
 
     #[test]
     fn should_be_serialized_as_bytes() {
@@ -432,7 +425,7 @@ mod tests {
             partition_id: Some(4),
             strategy: PollingStrategy::offset(2),
             count: 3,
-            auto_commit: true,
+            auto_commit: AutoCommitMode::BeforeDelivery,
         };
 
         let bytes = command.to_bytes();
@@ -457,8 +450,7 @@ mod tests {
             value,
         };
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
-        let auto_commit = bytes[position + 12];
-        let auto_commit = matches!(auto_commit, 1);
+        let auto_commit = AutoCommitMode::from_bytes(bytes.slice(position + 12..)).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer, command.consumer);
@@ -478,17 +470,19 @@ mod tests {
         let partition_id = 4u32;
         let strategy = PollingStrategy::offset(2);
         let count = 3u32;
-        let auto_commit = 1u8;
+        let auto_commit = AutoCommitMode::Interval(IggyDuration::from_str("5s").unwrap());
 
         let consumer_bytes = consumer.to_bytes();
         let stream_id_bytes = stream_id.to_bytes();
         let topic_id_bytes = topic_id.to_bytes();
         let strategy_bytes = strategy.to_bytes();
+        let auto_commit_bytes = auto_commit.to_bytes();
         let mut bytes = BytesMut::with_capacity(
-            9 + consumer_bytes.len()
+            8 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
-                + strategy_bytes.len(),
+                + strategy_bytes.len()
+                + auto_commit_bytes.len(),
         );
         bytes.put_slice(&consumer_bytes);
         bytes.put_slice(&stream_id_bytes);
@@ -496,13 +490,11 @@ mod tests {
         bytes.put_u32_le(partition_id);
         bytes.put_slice(&strategy_bytes);
         bytes.put_u32_le(count);
-        bytes.put_u8(auto_commit);
+        bytes.put_slice(&auto_commit_bytes);
 
         let command = PollMessages::from_bytes(bytes.freeze());
         assert!(command.is_ok());
 
-        let auto_commit = matches!(auto_commit, 1);
-
         let command = command.unwrap();
         assert_eq!(command.consumer, consumer);
         assert_eq!(command.stream_id, stream_id);
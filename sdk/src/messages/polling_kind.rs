@@ -16,7 +16,7 @@ pub enum PollingKind {
     First,
     /// Start polling from the last message in the partition.
     Last,
-    /// Start polling from the next message after the last polled message based on the stored consumer offset. Should be used with `auto_commit` set to `true`.
+    /// Start polling from the next message after the last polled message based on the stored consumer offset. Should be used with `auto_commit` enabled.
     Next,
 }
 
@@ -84,7 +84,7 @@ impl PollingStrategy {
         }
     }
 
-    /// Poll messages from the next message after the last polled message based on the stored consumer offset. Should be used with `auto_commit` set to `true`.
+    /// Poll messages from the next message after the last polled message based on the stored consumer offset. Should be used with `auto_commit` enabled.
     pub fn next() -> Self {
         Self {
             kind: PollingKind::Next,
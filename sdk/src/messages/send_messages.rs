@@ -18,9 +18,10 @@
 
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::{Command, SEND_MESSAGES_CODE};
+use crate::confirmation::ConfirmationLevel;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
-use crate::messages::{MAX_HEADERS_SIZE, MAX_PAYLOAD_SIZE};
+use crate::messages::{PayloadEncoding, MAX_HEADERS_SIZE, MAX_PAYLOAD_SIZE};
 use crate::models::header;
 use crate::models::header::{HeaderKey, HeaderValue};
 use crate::utils::byte_size::IggyByteSize;
@@ -44,7 +45,9 @@ const EMPTY_KEY_VALUE: Vec<u8> = vec![];
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitioning` - to which partition the messages should be sent - either provided by the client or calculated by the server.
 /// - `messages` - collection of messages to be sent.
+/// - `confirmation` - when the server confirms the messages: once appended, received (buffered) or persisted (synced to disk).
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct SendMessages {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
@@ -55,7 +58,11 @@ pub struct SendMessages {
     /// To which partition the messages should be sent - either provided by the client or calculated by the server.
     pub partitioning: Partitioning,
     /// Collection of messages to be sent.
+    #[cfg_attr(feature = "openapi", schema(value_type = Vec<EncodedMessage>))]
     pub messages: Vec<Message>,
+    /// When the server confirms the messages, `received` by default.
+    #[serde(default)]
+    pub confirmation: ConfirmationLevel,
 }
 
 /// `Partitioning` is used to specify to which partition the messages should be sent.
@@ -65,6 +72,7 @@ pub struct SendMessages {
 /// - `MessagesKey` - the partition ID is calculated by the server using the hash of the provided messages key.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Partitioning {
     /// The kind of partitioning.
     pub kind: PartitioningKind,
@@ -73,6 +81,7 @@ pub struct Partitioning {
     pub length: u8,
     #[serde_as(as = "Base64")]
     /// The binary value payload.
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
     pub value: Vec<u8>,
 }
 
@@ -89,8 +98,11 @@ impl Hash for Partitioning {
 /// - `length` - length of the payload.
 /// - `payload` - binary message payload.
 /// - `headers` - optional collection of headers.
+///
+/// When deserialized, the payload is decoded using the optional `payload_encoding` of the message (Base64 by default).
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(try_from = "EncodedMessage")]
 pub struct Message {
     /// Unique message ID, if not specified by the client (has value = 0), it will be generated by the server.
     #[serde(default = "default_message_id")]
@@ -108,6 +120,7 @@ pub struct Message {
 /// `PartitioningKind` is an enum which specifies the kind of partitioning and is used by `Partitioning`.
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Default, Copy, Clone)]
 #[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum PartitioningKind {
     /// The partition ID is calculated by the server using the round-robin algorithm.
     #[default]
@@ -128,6 +141,27 @@ fn default_message_id() -> u128 {
     0
 }
 
+#[derive(Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+struct EncodedMessage {
+    #[serde(default = "default_message_id")]
+    id: u128,
+    payload: String,
+    #[serde(default)]
+    payload_encoding: PayloadEncoding,
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<HashMap<String, HeaderValue>>))]
+    headers: Option<HashMap<HeaderKey, HeaderValue>>,
+}
+
+impl TryFrom<EncodedMessage> for Message {
+    type Error = IggyError;
+
+    fn try_from(message: EncodedMessage) -> Result<Self, Self::Error> {
+        let payload = message.payload_encoding.decode(&message.payload)?;
+        Ok(Message::new(Some(message.id), payload, message.headers))
+    }
+}
+
 impl Default for SendMessages {
     fn default() -> Self {
         SendMessages {
@@ -135,6 +169,7 @@ impl Default for SendMessages {
             topic_id: Identifier::default(),
             partitioning: Partitioning::default(),
             messages: vec![Message::default()],
+            confirmation: ConfirmationLevel::default(),
         }
     }
 }
@@ -451,6 +486,7 @@ pub(crate) fn as_bytes(
     topic_id: &Identifier,
     partitioning: &Partitioning,
     messages: &[Message],
+    confirmation: ConfirmationLevel,
 ) -> Bytes {
     let messages_size = messages
         .iter()
@@ -463,11 +499,13 @@ pub(crate) fn as_bytes(
         stream_id_bytes.len()
             + topic_id_bytes.len()
             + key_bytes.len()
+            + 1
             + messages_size.as_bytes_usize(),
     );
     bytes.put_slice(&stream_id_bytes);
     bytes.put_slice(&topic_id_bytes);
     bytes.put_slice(&key_bytes);
+    bytes.put_u8(confirmation.as_code());
     for message in messages {
         bytes.put_slice(&message.to_bytes());
     }
@@ -501,11 +539,12 @@ impl BytesSerializable for SendMessages {
             &self.topic_id,
             &self.partitioning,
             &self.messages,
+            self.confirmation,
         )
     }
 
     fn from_bytes(bytes: Bytes) -> Result<SendMessages, IggyError> {
-        if bytes.len() < 11 {
+        if bytes.len() < 12 {
             return Err(IggyError::InvalidCommand);
         }
 
@@ -516,6 +555,8 @@ impl BytesSerializable for SendMessages {
         position += topic_id.get_size_bytes().as_bytes_usize();
         let key = Partitioning::from_bytes(bytes.slice(position..))?;
         position += key.get_size_bytes().as_bytes_usize();
+        let confirmation = ConfirmationLevel::from_code(bytes[position])?;
+        position += 1;
         let messages_payloads = bytes.slice(position..);
         position = 0;
         let mut messages = Vec::new();
@@ -530,6 +571,7 @@ impl BytesSerializable for SendMessages {
             topic_id,
             partitioning: key,
             messages,
+            confirmation,
         };
         Ok(command)
     }
@@ -539,7 +581,7 @@ impl Display for SendMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|batch_len:{}|batch_size:{}",
+            "{}|{}|{}|batch_len:{}|batch_size:{}|confirmation:{}",
             self.stream_id,
             self.topic_id,
             self.partitioning,
@@ -548,6 +590,7 @@ impl Display for SendMessages {
                 .iter()
                 .map(Message::get_size_bytes)
                 .sum::<IggyByteSize>(),
+            self.confirmation,
         )
     }
 }
@@ -594,6 +637,7 @@ mod tests {
             topic_id: Identifier::numeric(2).unwrap(),
             partitioning: Partitioning::partition_id(4),
             messages,
+            confirmation: ConfirmationLevel::Persisted,
         };
 
         let bytes = command.to_bytes();
@@ -605,6 +649,8 @@ mod tests {
         position += topic_id.get_size_bytes().as_bytes_usize();
         let key = Partitioning::from_bytes(bytes.slice(position..)).unwrap();
         position += key.get_size_bytes().as_bytes_usize();
+        let confirmation = ConfirmationLevel::from_code(bytes[position]).unwrap();
+        position += 1;
         let messages = bytes.slice(position..);
         let command_messages = command
             .messages
@@ -619,6 +665,7 @@ mod tests {
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(key, command.partitioning);
+        assert_eq!(confirmation, command.confirmation);
         assert_eq!(messages, command_messages);
     }
 
@@ -641,11 +688,12 @@ mod tests {
         let key_bytes = key.to_bytes();
         let stream_id_bytes = stream_id.to_bytes();
         let topic_id_bytes = topic_id.to_bytes();
-        let current_position = stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len();
+        let current_position = stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len() + 1;
         let mut bytes = BytesMut::with_capacity(current_position);
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
         bytes.put_slice(&key_bytes);
+        bytes.put_u8(ConfirmationLevel::NoWait.as_code());
         bytes.put_slice(&messages);
         let bytes = bytes.freeze();
         let command = SendMessages::from_bytes(bytes.clone());
@@ -664,6 +712,7 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitioning, key);
+        assert_eq!(command.confirmation, ConfirmationLevel::NoWait);
         for (index, message) in command.messages.iter().enumerate() {
             let command_message = &command.messages[index];
             assert_eq!(command_message.id, message.id);
@@ -723,4 +772,30 @@ mod tests {
         let key = Partitioning::messages_key_str(&messages_key);
         assert!(key.is_err());
     }
+
+    #[test]
+    fn message_payload_should_be_deserialized_using_its_encoding() {
+        let messages: Vec<Message> = serde_json::from_str(
+            r#"[
+                {"id": 1, "payload": "AJ+SlQ=="},
+                {"id": 2, "payload": "hello", "payload_encoding": "utf-8"},
+                {"id": 3, "payload": "009f9295", "payload_encoding": "hex"}
+            ]"#,
+        )
+        .unwrap();
+
+        let binary_payload = Bytes::from(vec![0u8, 159, 146, 149]);
+        assert_eq!(messages[0].payload, binary_payload);
+        assert_eq!(messages[1].payload, Bytes::from("hello"));
+        assert_eq!(messages[1].length, 5);
+        assert_eq!(messages[2].id, 3);
+        assert_eq!(messages[2].payload, binary_payload);
+    }
+
+    #[test]
+    fn message_with_invalid_payload_for_its_encoding_should_fail_to_deserialize() {
+        let message =
+            serde_json::from_str::<Message>(r#"{"payload": "xyz", "payload_encoding": "hex"}"#);
+        assert!(message.is_err());
+    }
 }
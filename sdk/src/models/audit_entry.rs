@@ -0,0 +1,94 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::error::IggyError;
+use crate::models::user_info::UserId;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `AuditOutcome` is the result of the audited administrative action.
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    /// The action has been applied.
+    #[default]
+    Success,
+    /// The action has been rejected or failed, e.g. due to missing permissions.
+    Failure,
+}
+
+/// `AuditEntry` represents the single administrative action recorded in the append-only audit log of the server.
+/// It consists of the following fields:
+/// - `id`: the sequential identifier of the entry.
+/// - `timestamp`: the timestamp when the action has been handled.
+/// - `user_id`: the identifier of the user who invoked the action, 0 if not authenticated.
+/// - `address`: the IP address and port of the client.
+/// - `transport`: the transport via which the action has been invoked, e.g. `tcp`, `quic` or `http`.
+/// - `action`: the name of the action, e.g. `stream.create`.
+/// - `details`: the arguments of the action, the secrets are masked.
+/// - `outcome`: the result of the action.
+/// - `error`: the error message, if the action failed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AuditEntry {
+    /// The sequential identifier of the entry.
+    pub id: u64,
+    /// The timestamp when the action has been handled.
+    pub timestamp: IggyTimestamp,
+    /// The identifier of the user who invoked the action, 0 if not authenticated.
+    pub user_id: UserId,
+    /// The IP address and port of the client.
+    pub address: String,
+    /// The transport via which the action has been invoked.
+    pub transport: String,
+    /// The name of the action, e.g. `stream.create`.
+    pub action: String,
+    /// The arguments of the action, the secrets are masked.
+    pub details: String,
+    /// The result of the action.
+    pub outcome: AuditOutcome,
+    /// The error message, if the action failed.
+    pub error: Option<String>,
+}
+
+impl AuditOutcome {
+    /// Returns the code of the audit outcome.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            AuditOutcome::Success => 1,
+            AuditOutcome::Failure => 2,
+        }
+    }
+
+    /// Returns the audit outcome from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(AuditOutcome::Success),
+            2 => Ok(AuditOutcome::Failure),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for AuditOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditOutcome::Success => write!(f, "success"),
+            AuditOutcome::Failure => write!(f, "failure"),
+        }
+    }
+}
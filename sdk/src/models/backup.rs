@@ -0,0 +1,104 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `BackupDestination` is the place where the backup archive is written to.
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupDestination {
+    /// The archive directory on the local disk of the server, configured in `system.backup.archive.path`.
+    #[default]
+    Local,
+    /// The S3-compatible object storage configured in `system.backup.archive.s3`.
+    S3,
+}
+
+/// `BackupInfo` represents the consistent point-in-time archive of the server metadata and the selected streams.
+/// It consists of the following fields:
+/// - `name`: the unique name of the backup.
+/// - `destination`: the place where the archive has been written to.
+/// - `base`: the name of the previous backup the incremental backup is based on, if any.
+/// - `location`: the path of the archive directory, or the URL of the archive in the object storage.
+/// - `created_at`: the timestamp when the backup has been taken.
+/// - `streams_count`: the number of the archived streams.
+/// - `files_count`: the number of the archived files, including the manifest, without the ones archived by the base backups.
+/// - `size`: the total size of the archived files, without the ones archived by the base backups.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BackupInfo {
+    /// The unique name of the backup.
+    pub name: String,
+    /// The place where the archive has been written to.
+    pub destination: BackupDestination,
+    /// The name of the previous backup the incremental backup is based on, if any.
+    pub base: Option<String>,
+    /// The path of the archive directory, or the URL of the archive in the object storage.
+    pub location: String,
+    /// The timestamp when the backup has been taken.
+    pub created_at: IggyTimestamp,
+    /// The number of the archived streams.
+    pub streams_count: u32,
+    /// The number of the archived files, including the manifest, without the ones archived by the base backups.
+    pub files_count: u32,
+    /// The total size of the archived files, without the ones archived by the base backups.
+    pub size: IggyByteSize,
+}
+
+impl BackupDestination {
+    /// Returns the code of the backup destination.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            BackupDestination::Local => 1,
+            BackupDestination::S3 => 2,
+        }
+    }
+
+    /// Returns the backup destination from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(BackupDestination::Local),
+            2 => Ok(BackupDestination::S3),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for BackupDestination {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "local" => Ok(BackupDestination::Local),
+            "s3" => Ok(BackupDestination::S3),
+            _ => Err(format!("Invalid backup destination: {s}")),
+        }
+    }
+}
+
+impl Display for BackupDestination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackupDestination::Local => write!(f, "local"),
+            BackupDestination::S3 => write!(f, "s3"),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `Bookmark` represents the named position in the topic partition stored on the server,
+/// which is not tied to any consumer or consumer group, thus it can be used as an independent cursor, e.g. for audits.
+/// It consists of the following fields:
+/// - `name`: the unique name of the bookmark within the topic.
+/// - `partition_id`: the identifier of the partition.
+/// - `offset`: the offset of the next message to be polled from the bookmark.
+/// - `created_at`: the timestamp when the bookmark was created.
+/// - `updated_at`: the timestamp when the bookmark was moved the last time.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Bookmark {
+    /// The unique name of the bookmark within the topic.
+    pub name: String,
+    /// The identifier of the partition.
+    pub partition_id: u32,
+    /// The offset of the next message to be polled from the bookmark.
+    pub offset: u64,
+    /// The timestamp when the bookmark was created.
+    pub created_at: IggyTimestamp,
+    /// The timestamp when the bookmark was moved the last time.
+    pub updated_at: IggyTimestamp,
+}
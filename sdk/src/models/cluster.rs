@@ -0,0 +1,218 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::identifier::{IdKind, Identifier};
+use serde::{Deserialize, Serialize};
+
+/// `ClusterMetadata` represents the view of the cluster from the node which returned it.
+/// It consists of the following fields:
+/// - `enabled`: whether the cluster mode is enabled on the server. Otherwise, the remaining fields are empty.
+/// - `node_id`: the ID of the node which returned the metadata.
+/// - `leader_id`: the ID of the cluster leader accepting the metadata changes, if it's known.
+/// - `nodes`: all the nodes of the cluster, including the one which returned the metadata.
+/// - `topics`: the topics with the leaders and replicas of their partitions.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ClusterMetadata {
+    /// Whether the cluster mode is enabled on the server.
+    pub enabled: bool,
+    /// The ID of the node which returned the metadata.
+    pub node_id: u32,
+    /// The ID of the cluster leader accepting the metadata changes, if it's known.
+    pub leader_id: Option<u32>,
+    /// All the nodes of the cluster, including the one which returned the metadata.
+    pub nodes: Vec<ClusterNode>,
+    /// The topics with the leaders and replicas of their partitions.
+    pub topics: Vec<ClusterTopic>,
+}
+
+/// `ClusterNode` represents the single node of the cluster.
+/// It consists of the following fields:
+/// - `id`: the unique ID of the node.
+/// - `address`: the address the clients connect to (TCP server address).
+/// - `alive`: whether the node is alive, according to the cluster leader.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ClusterNode {
+    /// The unique ID of the node.
+    pub id: u32,
+    /// The address the clients connect to (TCP server address).
+    pub address: String,
+    /// Whether the node is alive, according to the cluster leader.
+    pub alive: bool,
+}
+
+/// `ClusterTopic` represents the topic with the placement of its partitions.
+/// It consists of the following fields:
+/// - `stream_id`: the unique ID of the stream.
+/// - `stream_name`: the unique name of the stream.
+/// - `topic_id`: the unique ID of the topic in the stream.
+/// - `topic_name`: the unique name of the topic in the stream.
+/// - `partitions`: the leaders and replicas of the partitions.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ClusterTopic {
+    /// The unique ID of the stream.
+    pub stream_id: u32,
+    /// The unique name of the stream.
+    pub stream_name: String,
+    /// The unique ID of the topic in the stream.
+    pub topic_id: u32,
+    /// The unique name of the topic in the stream.
+    pub topic_name: String,
+    /// The leaders and replicas of the partitions.
+    pub partitions: Vec<ClusterPartition>,
+}
+
+/// `ClusterPartition` represents the placement of the partition.
+/// It consists of the following fields:
+/// - `id`: the unique ID of the partition in the topic.
+/// - `leader_id`: the ID of the node accepting the appended messages, if any of the replicas is alive.
+/// - `replicas`: the IDs of the nodes storing the partition.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ClusterPartition {
+    /// The unique ID of the partition in the topic.
+    pub id: u32,
+    /// The ID of the node accepting the appended messages, if any of the replicas is alive.
+    pub leader_id: Option<u32>,
+    /// The IDs of the nodes storing the partition.
+    pub replicas: Vec<u32>,
+}
+
+impl ClusterMetadata {
+    /// Returns the node with the given ID, if it's the part of the cluster.
+    pub fn get_node(&self, node_id: u32) -> Option<&ClusterNode> {
+        self.nodes.iter().find(|node| node.id == node_id)
+    }
+
+    /// Returns the topic identified by the stream and topic ID or name.
+    pub fn get_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Option<&ClusterTopic> {
+        self.topics.iter().find(|topic| {
+            matches(stream_id, topic.stream_id, &topic.stream_name)
+                && matches(topic_id, topic.topic_id, &topic.topic_name)
+        })
+    }
+
+    /// Returns the ID of the node leading the partition, if it's known.
+    pub fn get_partition_leader(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Option<u32> {
+        self.get_topic(stream_id, topic_id)?
+            .partitions
+            .iter()
+            .find(|partition| partition.id == partition_id)?
+            .leader_id
+    }
+}
+
+fn matches(identifier: &Identifier, id: u32, name: &str) -> bool {
+    match identifier.kind {
+        IdKind::Numeric => identifier.get_u32_value().is_ok_and(|value| value == id),
+        IdKind::String => identifier
+            .get_cow_str_value()
+            .is_ok_and(|value| value == name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata() -> ClusterMetadata {
+        ClusterMetadata {
+            enabled: true,
+            node_id: 1,
+            leader_id: Some(1),
+            nodes: vec![
+                ClusterNode {
+                    id: 1,
+                    address: "127.0.0.1:8090".to_owned(),
+                    alive: true,
+                },
+                ClusterNode {
+                    id: 2,
+                    address: "127.0.0.1:8091".to_owned(),
+                    alive: true,
+                },
+            ],
+            topics: vec![ClusterTopic {
+                stream_id: 1,
+                stream_name: "orders".to_owned(),
+                topic_id: 2,
+                topic_name: "payments".to_owned(),
+                partitions: vec![
+                    ClusterPartition {
+                        id: 1,
+                        leader_id: Some(2),
+                        replicas: vec![2, 1],
+                    },
+                    ClusterPartition {
+                        id: 2,
+                        leader_id: None,
+                        replicas: vec![1],
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn partition_leader_should_be_found_by_ids_and_names() {
+        let metadata = metadata();
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_name = Identifier::named("payments").unwrap();
+        assert_eq!(
+            metadata.get_partition_leader(&stream_id, &topic_name, 1),
+            Some(2)
+        );
+        let stream_name = Identifier::named("orders").unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        assert_eq!(
+            metadata.get_partition_leader(&stream_name, &topic_id, 1),
+            Some(2)
+        );
+        assert_eq!(
+            metadata.get_node(2).map(|node| node.address.as_str()),
+            Some("127.0.0.1:8091")
+        );
+    }
+
+    #[test]
+    fn partition_leader_should_not_be_found_for_unknown_or_leaderless_partition() {
+        let metadata = metadata();
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        assert_eq!(
+            metadata.get_partition_leader(&stream_id, &topic_id, 2),
+            None
+        );
+        assert_eq!(
+            metadata.get_partition_leader(&stream_id, &topic_id, 3),
+            None
+        );
+        let unknown_topic_id = Identifier::numeric(3).unwrap();
+        assert_eq!(
+            metadata.get_partition_leader(&stream_id, &unknown_topic_id, 1),
+            None
+        );
+    }
+}
@@ -0,0 +1,244 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `CompactionKey` determines the key by which the messages of the compacted topic are deduplicated.
+/// It has the following variants:
+/// - `MessageId`: the message ID is used as the key.
+/// - `Header`: the value of the header with the given key is used as the key, messages without such header are never compacted.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum CompactionKey {
+    /// The message ID is used as the key.
+    MessageId,
+    /// The value of the header with the given key is used as the key.
+    Header(HeaderKey),
+}
+
+impl CompactionKey {
+    /// Returns the key of the message with the given ID and headers, by which the message is compacted.
+    /// The messages without the key (i.e. missing the header) are never compacted.
+    pub fn get_message_key(
+        &self,
+        id: u128,
+        headers: Option<&HashMap<HeaderKey, HeaderValue>>,
+    ) -> Option<Bytes> {
+        match self {
+            CompactionKey::MessageId => Some(Bytes::copy_from_slice(&id.to_le_bytes())),
+            CompactionKey::Header(header_key) => {
+                let value = headers?.get(header_key)?;
+                let mut key = BytesMut::with_capacity(1 + value.value.len());
+                key.put_u8(value.kind.as_code());
+                key.put_slice(&value.value);
+                Some(key.freeze())
+            }
+        }
+    }
+}
+
+/// `CompactionPolicy` is the optional `compact` cleanup policy attached to the topic.
+/// When set, the server periodically rewrites the closed segments of each partition, retaining only the latest message per key.
+/// This makes the topic suitable for changelog or table-style data, where only the most recent state of each entity matters.
+/// When not set, the topic uses the default `delete` cleanup policy based solely on the message expiry and the maximum topic size.
+/// It consists of the following fields:
+/// - `key`: the key by which the messages are compacted.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CompactionPolicy {
+    /// The key by which the messages are compacted.
+    pub key: CompactionKey,
+}
+
+impl CompactionPolicy {
+    /// Creates the compaction policy retaining the latest message per message ID.
+    pub fn by_message_id() -> Self {
+        Self {
+            key: CompactionKey::MessageId,
+        }
+    }
+
+    /// Creates the compaction policy retaining the latest message per value of the given header.
+    pub fn by_header(header_key: HeaderKey) -> Self {
+        Self {
+            key: CompactionKey::Header(header_key),
+        }
+    }
+}
+
+impl Validatable<IggyError> for CompactionPolicy {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let CompactionKey::Header(header_key) = &self.key {
+            if header_key.as_str().is_empty() || header_key.as_str().len() > 255 {
+                return Err(IggyError::InvalidCompactionPolicy(
+                    "header key must have between 1 and 255 characters".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for CompactionPolicy {
+    fn to_bytes(&self) -> Bytes {
+        match &self.key {
+            CompactionKey::MessageId => Bytes::from_static(&[1]),
+            CompactionKey::Header(header_key) => {
+                let header_key = header_key.as_str().as_bytes();
+                let mut bytes = BytesMut::with_capacity(2 + header_key.len());
+                bytes.put_u8(2);
+                bytes.put_u8(header_key.len() as u8);
+                bytes.put_slice(header_key);
+                bytes.freeze()
+            }
+        }
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<CompactionPolicy, IggyError> {
+        match bytes.first() {
+            Some(1) if bytes.len() == 1 => Ok(CompactionPolicy::by_message_id()),
+            Some(2) => {
+                if bytes.len() < 2 {
+                    return Err(IggyError::InvalidCommand);
+                }
+                let header_key_length = bytes[1] as usize;
+                if bytes.len() != 2 + header_key_length {
+                    return Err(IggyError::InvalidCommand);
+                }
+                let header_key =
+                    std::str::from_utf8(&bytes[2..]).map_err(|_| IggyError::InvalidUtf8)?;
+                Ok(CompactionPolicy::by_header(HeaderKey::from_str(
+                    header_key,
+                )?))
+            }
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for CompactionPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.key {
+            CompactionKey::MessageId => write!(f, "compact(key: message_id)"),
+            CompactionKey::Header(header_key) => write!(f, "compact(key: header {header_key})"),
+        }
+    }
+}
+
+/// Writes the optional compaction policy (used by the topic commands and responses), prefixed with the presence flag and the length.
+pub(crate) fn write_optional_compaction_policy(
+    policy: Option<&CompactionPolicy>,
+    bytes: &mut BytesMut,
+) {
+    match policy {
+        Some(policy) => {
+            let policy = policy.to_bytes();
+            bytes.put_u8(1);
+            bytes.put_u32_le(policy.len() as u32);
+            bytes.put_slice(&policy);
+        }
+        None => bytes.put_u8(0),
+    }
+}
+
+/// Reads the optional compaction policy written by `write_optional_compaction_policy`, returning it along with the number of read bytes.
+pub(crate) fn read_optional_compaction_policy(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<(Option<CompactionPolicy>, usize), IggyError> {
+    match bytes.get(position) {
+        None | Some(0) => Ok((None, 1)),
+        Some(1) => {
+            if bytes.len() < position + 5 {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy_length = u32::from_le_bytes(
+                bytes[position + 1..position + 5]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            if bytes.len() < position + 5 + policy_length {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy = CompactionPolicy::from_bytes(
+                bytes.slice(position + 5..position + 5 + policy_length),
+            )?;
+            Ok((Some(policy), 5 + policy_length))
+        }
+        Some(_) => Err(IggyError::InvalidCommand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_should_be_serialized_and_deserialized() {
+        for policy in [
+            CompactionPolicy::by_message_id(),
+            CompactionPolicy::by_header(HeaderKey::new("entity-id").unwrap()),
+        ] {
+            let deserialized = CompactionPolicy::from_bytes(policy.to_bytes()).unwrap();
+            assert_eq!(deserialized, policy);
+            assert!(deserialized.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn message_key_should_be_resolved_from_id_or_header() {
+        let key = CompactionKey::MessageId.get_message_key(7, None).unwrap();
+        assert_eq!(key.as_ref(), &7u128.to_le_bytes());
+
+        let header_key = HeaderKey::new("entity-id").unwrap();
+        let compaction_key = CompactionKey::Header(header_key.clone());
+        assert!(compaction_key.get_message_key(7, None).is_none());
+        let headers = HashMap::from([(header_key, HeaderValue::from_uint32(42).unwrap())]);
+        let key = compaction_key.get_message_key(7, Some(&headers)).unwrap();
+        assert_eq!(key[1..], 42u32.to_le_bytes());
+    }
+
+    #[test]
+    fn optional_policy_should_be_written_and_read() {
+        let policy = CompactionPolicy::by_header(HeaderKey::new("key").unwrap());
+        let mut bytes = BytesMut::new();
+        write_optional_compaction_policy(Some(&policy), &mut bytes);
+        write_optional_compaction_policy(None, &mut bytes);
+        let bytes = bytes.freeze();
+        let (read_policy, read_bytes) = read_optional_compaction_policy(&bytes, 0).unwrap();
+        assert_eq!(read_policy, Some(policy));
+        let (read_policy, _) = read_optional_compaction_policy(&bytes, read_bytes).unwrap();
+        assert!(read_policy.is_none());
+    }
+
+    #[test]
+    fn policy_with_unknown_key_kind_should_not_be_deserialized() {
+        assert!(CompactionPolicy::from_bytes(Bytes::from_static(&[3])).is_err());
+        assert!(CompactionPolicy::from_bytes(Bytes::from_static(&[2, 4, b'k'])).is_err());
+    }
+}
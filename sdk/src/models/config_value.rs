@@ -0,0 +1,91 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::Display;
+
+/// `ConfigSource` is the source from which the effective server configuration value has been resolved.
+/// The later source overrides the former one:
+/// - `Default`: the value embedded in the server binary.
+/// - `File`: the value from the configuration file.
+/// - `Environment`: the value from the `IGGY_` environment variable.
+/// - `Runtime`: the value changed once the server has started, e.g. by the command line argument or the bound address.
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigSource {
+    /// The value embedded in the server binary.
+    #[default]
+    Default,
+    /// The value from the configuration file.
+    File,
+    /// The value from the `IGGY_` environment variable.
+    Environment,
+    /// The value changed once the server has started.
+    Runtime,
+}
+
+/// `ConfigValue` represents the single effective value of the server configuration.
+/// It consists of the following fields:
+/// - `key`: the dotted path of the value, e.g. `system.segment.size`.
+/// - `value`: the JSON value, the secrets are masked.
+/// - `source`: the source from which the value has been resolved.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ConfigValue {
+    /// The dotted path of the value, e.g. `system.segment.size`.
+    pub key: String,
+    /// The JSON value, the secrets are masked.
+    pub value: Value,
+    /// The source from which the value has been resolved.
+    pub source: ConfigSource,
+}
+
+impl ConfigSource {
+    /// Returns the code of the config source.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ConfigSource::Default => 1,
+            ConfigSource::File => 2,
+            ConfigSource::Environment => 3,
+            ConfigSource::Runtime => 4,
+        }
+    }
+
+    /// Returns the config source from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(ConfigSource::Default),
+            2 => Ok(ConfigSource::File),
+            3 => Ok(ConfigSource::Environment),
+            4 => Ok(ConfigSource::Runtime),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "default"),
+            ConfigSource::File => write!(f, "file"),
+            ConfigSource::Environment => write!(f, "environment"),
+            ConfigSource::Runtime => write!(f, "runtime"),
+        }
+    }
+}
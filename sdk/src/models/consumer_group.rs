@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::consumer_groups::assignment_strategy::AssignmentStrategy;
 use serde::{Deserialize, Serialize};
 
 /// `ConsumerGroup` represents the information about a consumer group.
@@ -25,6 +26,7 @@ use serde::{Deserialize, Serialize};
 /// - `partitions_count`: the number of partitions the consumer group is consuming.
 /// - `members_count`: the number of members in the consumer group.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ConsumerGroup {
     /// The unique identifier (numeric) of the consumer group.
     pub id: u32,
@@ -42,7 +44,12 @@ pub struct ConsumerGroup {
 /// - `name`: the name of the consumer group.
 /// - `partitions_count`: the number of partitions the consumer group is consuming.
 /// - `members_count`: the number of members in the consumer group.
+/// - `generation`: the assignment generation, incremented on every rebalance.
+/// - `assignment_strategy`: the strategy used to assign the partitions to the members.
+/// - `topic_deleting`: whether the topic is being deleted, so the members should consume the remaining messages.
+/// - `members`: the collection of members in the consumer group.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ConsumerGroupDetails {
     /// The unique identifier (numeric) of the consumer group.
     pub id: u32,
@@ -52,6 +59,15 @@ pub struct ConsumerGroupDetails {
     pub partitions_count: u32,
     /// The number of members in the consumer group.
     pub members_count: u32,
+    /// The assignment generation, incremented by the server on every rebalance (member joined or left, partitions changed).
+    #[serde(default)]
+    pub generation: u32,
+    /// The strategy used to assign the partitions to the members.
+    #[serde(default)]
+    pub assignment_strategy: AssignmentStrategy,
+    /// Whether the topic is being deleted, so the members should consume the remaining messages before it's removed.
+    #[serde(default)]
+    pub topic_deleting: bool,
     /// The collection of members in the consumer group.
     pub members: Vec<ConsumerGroupMember>,
 }
@@ -62,6 +78,7 @@ pub struct ConsumerGroupDetails {
 /// - `partitions_count`: the number of partitions the consumer group member is consuming.
 /// - `partitions`: the collection of partitions the consumer group member is consuming.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ConsumerGroupMember {
     /// The unique identifier (numeric) of the consumer group member.
     pub id: u32,
@@ -70,3 +87,33 @@ pub struct ConsumerGroupMember {
     /// The collection of partitions the consumer group member is consuming.
     pub partitions: Vec<u32>,
 }
+
+/// `ConsumerGroupRebalance` represents the assignment of the consumer group member after the rebalance, pushed by the server to the member.
+/// It consists of the following fields:
+/// - `stream_id`: the unique identifier (numeric) of the stream.
+/// - `stream_name`: the name of the stream.
+/// - `topic_id`: the unique identifier (numeric) of the topic.
+/// - `topic_name`: the name of the topic.
+/// - `group_id`: the unique identifier (numeric) of the consumer group.
+/// - `group_name`: the name of the consumer group.
+/// - `generation`: the assignment generation of the consumer group after the rebalance.
+/// - `partitions`: the collection of partitions assigned to the member.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConsumerGroupRebalance {
+    /// The unique identifier (numeric) of the stream.
+    pub stream_id: u32,
+    /// The name of the stream.
+    pub stream_name: String,
+    /// The unique identifier (numeric) of the topic.
+    pub topic_id: u32,
+    /// The name of the topic.
+    pub topic_name: String,
+    /// The unique identifier (numeric) of the consumer group.
+    pub group_id: u32,
+    /// The name of the consumer group.
+    pub group_name: String,
+    /// The assignment generation of the consumer group after the rebalance.
+    pub generation: u32,
+    /// The collection of partitions assigned to the member.
+    pub partitions: Vec<u32>,
+}
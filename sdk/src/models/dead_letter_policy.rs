@@ -0,0 +1,220 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The header holding the ID of the stream from which the message was moved to the dead letter topic.
+pub const DLQ_SOURCE_STREAM_ID_HEADER: &str = "iggy-dlq-source-stream-id";
+/// The header holding the ID of the topic from which the message was moved to the dead letter topic.
+pub const DLQ_SOURCE_TOPIC_ID_HEADER: &str = "iggy-dlq-source-topic-id";
+/// The header holding the ID of the partition from which the message was moved to the dead letter topic.
+pub const DLQ_SOURCE_PARTITION_ID_HEADER: &str = "iggy-dlq-source-partition-id";
+/// The header holding the offset of the message in the source partition.
+pub const DLQ_SOURCE_OFFSET_HEADER: &str = "iggy-dlq-source-offset";
+/// The header holding the number of the failed delivery attempts.
+pub const DLQ_DELIVERY_ATTEMPTS_HEADER: &str = "iggy-dlq-delivery-attempts";
+/// The header holding the reason of the last rejection.
+pub const DLQ_REASON_HEADER: &str = "iggy-dlq-reason";
+/// The header holding the consumer (or consumer group) which rejected the message.
+pub const DLQ_CONSUMER_HEADER: &str = "iggy-dlq-consumer";
+/// The header holding the timestamp (microseconds) when the message was moved to the dead letter topic.
+pub const DLQ_REJECTED_AT_HEADER: &str = "iggy-dlq-rejected-at";
+
+/// `DeadLetterPolicy` is the optional policy attached to the topic, used by the server to move the repeatedly rejected messages to another topic.
+/// It consists of the following fields:
+/// - `stream_id`: the unique stream ID (numeric or name) of the dead letter topic.
+/// - `topic_id`: the unique topic ID (numeric or name) of the dead letter topic.
+/// - `max_delivery_attempts`: the number of rejections after which the message is moved to the dead letter topic.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DeadLetterPolicy {
+    /// The unique stream ID (numeric or name) of the dead letter topic.
+    pub stream_id: Identifier,
+    /// The unique topic ID (numeric or name) of the dead letter topic.
+    pub topic_id: Identifier,
+    /// The number of rejections after which the message is moved to the dead letter topic.
+    pub max_delivery_attempts: u32,
+}
+
+impl DeadLetterPolicy {
+    /// Creates the dead letter policy for the given topic and the maximum number of delivery attempts.
+    pub fn new(stream_id: Identifier, topic_id: Identifier, max_delivery_attempts: u32) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            max_delivery_attempts,
+        }
+    }
+}
+
+impl Validatable<IggyError> for DeadLetterPolicy {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.max_delivery_attempts == 0 {
+            return Err(IggyError::InvalidDeadLetterPolicy(
+                "max delivery attempts must be greater than 0".to_string(),
+            ));
+        }
+
+        self.stream_id.validate()?;
+        self.topic_id.validate()?;
+        Ok(())
+    }
+}
+
+impl BytesSerializable for DeadLetterPolicy {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(4 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.max_delivery_attempts);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<DeadLetterPolicy, IggyError> {
+        if bytes.len() < 10 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let max_delivery_attempts = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(DeadLetterPolicy {
+            stream_id,
+            topic_id,
+            max_delivery_attempts,
+        })
+    }
+}
+
+impl Display for DeadLetterPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{}(max attempts: {})",
+            self.stream_id, self.topic_id, self.max_delivery_attempts
+        )
+    }
+}
+
+/// Writes the optional dead letter policy (used by the topic commands and responses), prefixed with the presence flag and the length.
+pub(crate) fn write_optional_dead_letter_policy(
+    policy: Option<&DeadLetterPolicy>,
+    bytes: &mut BytesMut,
+) {
+    match policy {
+        Some(policy) => {
+            let policy = policy.to_bytes();
+            bytes.put_u8(1);
+            bytes.put_u32_le(policy.len() as u32);
+            bytes.put_slice(&policy);
+        }
+        None => bytes.put_u8(0),
+    }
+}
+
+/// Reads the optional dead letter policy written by `write_optional_dead_letter_policy`, returning it along with the number of read bytes.
+pub(crate) fn read_optional_dead_letter_policy(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<(Option<DeadLetterPolicy>, usize), IggyError> {
+    match bytes.get(position) {
+        None | Some(0) => Ok((None, 1)),
+        Some(1) => {
+            if bytes.len() < position + 5 {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy_length = u32::from_le_bytes(
+                bytes[position + 1..position + 5]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            if bytes.len() < position + 5 + policy_length {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy = DeadLetterPolicy::from_bytes(
+                bytes.slice(position + 5..position + 5 + policy_length),
+            )?;
+            Ok((Some(policy), 5 + policy_length))
+        }
+        Some(_) => Err(IggyError::InvalidCommand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_should_be_serialized_and_deserialized() {
+        let policy = DeadLetterPolicy::new(
+            Identifier::numeric(1).unwrap(),
+            Identifier::named("orders-dlq").unwrap(),
+            5,
+        );
+        let deserialized = DeadLetterPolicy::from_bytes(policy.to_bytes()).unwrap();
+        assert_eq!(deserialized, policy);
+        assert!(deserialized.validate().is_ok());
+    }
+
+    #[test]
+    fn optional_policy_should_be_written_and_read() {
+        let policy = DeadLetterPolicy::new(
+            Identifier::named("orders").unwrap(),
+            Identifier::numeric(2).unwrap(),
+            3,
+        );
+        let mut bytes = BytesMut::new();
+        write_optional_dead_letter_policy(Some(&policy), &mut bytes);
+        write_optional_dead_letter_policy(None, &mut bytes);
+        let bytes = bytes.freeze();
+        let (read_policy, read_bytes) = read_optional_dead_letter_policy(&bytes, 0).unwrap();
+        assert_eq!(read_policy, Some(policy));
+        let (read_policy, _) = read_optional_dead_letter_policy(&bytes, read_bytes).unwrap();
+        assert!(read_policy.is_none());
+    }
+
+    #[test]
+    fn policy_with_zero_max_delivery_attempts_should_be_invalid() {
+        let policy = DeadLetterPolicy::new(
+            Identifier::numeric(1).unwrap(),
+            Identifier::numeric(2).unwrap(),
+            0,
+        );
+        assert!(policy.validate().is_err());
+    }
+}
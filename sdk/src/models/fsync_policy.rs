@@ -0,0 +1,234 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::duration::IggyDuration;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `FsyncPolicy` is the optional durability policy attached to the topic, overriding the `enforce_fsync` server configuration.
+/// The persisted messages of the topic partitions are synced to the disk once either of the thresholds is exceeded:
+/// - `every_n_messages`: the optional number of the persisted messages after which the partition is synced.
+/// - `interval`: the optional interval (since the last sync of the partition) after which the partition is synced.
+///
+/// When neither of the thresholds is set, the partitions are never synced explicitly, relying on the OS flush instead.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FsyncPolicy {
+    /// The optional number of the persisted messages after which the partition is synced.
+    #[serde(default)]
+    pub every_n_messages: Option<u32>,
+    /// The optional interval after which the partition is synced.
+    #[serde(default)]
+    pub interval: Option<IggyDuration>,
+}
+
+impl FsyncPolicy {
+    /// Creates the fsync policy syncing the partition every given number of the persisted messages.
+    pub fn every_n_messages(every_n_messages: u32) -> Self {
+        Self {
+            every_n_messages: Some(every_n_messages),
+            interval: None,
+        }
+    }
+
+    /// Creates the fsync policy syncing the partition at most once per given interval.
+    pub fn interval(interval: IggyDuration) -> Self {
+        Self {
+            every_n_messages: None,
+            interval: Some(interval),
+        }
+    }
+
+    /// Creates the fsync policy which never syncs the partition explicitly, relying on the OS flush.
+    pub fn never() -> Self {
+        Self {
+            every_n_messages: None,
+            interval: None,
+        }
+    }
+
+    /// Returns true if the partition is never synced explicitly.
+    pub fn is_never(&self) -> bool {
+        self.every_n_messages.is_none() && self.interval.is_none()
+    }
+}
+
+impl Validatable<IggyError> for FsyncPolicy {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.every_n_messages == Some(0) {
+            return Err(IggyError::InvalidFsyncPolicy(
+                "number of messages must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.interval.is_some_and(|interval| interval.is_zero()) {
+            return Err(IggyError::InvalidFsyncPolicy(
+                "interval must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for FsyncPolicy {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(12);
+        bytes.put_u32_le(self.every_n_messages.unwrap_or(0));
+        bytes.put_u64_le(self.interval.map_or(0, |interval| interval.as_micros()));
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<FsyncPolicy, IggyError> {
+        if bytes.len() != 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let every_n_messages = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let interval = u64::from_le_bytes(
+            bytes[4..12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(FsyncPolicy {
+            every_n_messages: match every_n_messages {
+                0 => None,
+                every_n_messages => Some(every_n_messages),
+            },
+            interval: match interval {
+                0 => None,
+                interval => Some(IggyDuration::from(interval)),
+            },
+        })
+    }
+}
+
+impl Display for FsyncPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_never() {
+            return write!(f, "never");
+        }
+
+        write!(
+            f,
+            "every_n_messages: {}, interval: {}",
+            self.every_n_messages
+                .map_or("none".to_string(), |count| count.to_string()),
+            self.interval.map_or("none".to_string(), |interval| interval
+                .as_human_time_string())
+        )
+    }
+}
+
+/// Writes the optional fsync policy (used by the topic commands and responses), prefixed with the presence flag and the length.
+pub(crate) fn write_optional_fsync_policy(policy: Option<&FsyncPolicy>, bytes: &mut BytesMut) {
+    match policy {
+        Some(policy) => {
+            let policy = policy.to_bytes();
+            bytes.put_u8(1);
+            bytes.put_u32_le(policy.len() as u32);
+            bytes.put_slice(&policy);
+        }
+        None => bytes.put_u8(0),
+    }
+}
+
+/// Reads the optional fsync policy written by `write_optional_fsync_policy`, returning it along with the number of read bytes.
+pub(crate) fn read_optional_fsync_policy(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<(Option<FsyncPolicy>, usize), IggyError> {
+    match bytes.get(position) {
+        None | Some(0) => Ok((None, 1)),
+        Some(1) => {
+            if bytes.len() < position + 5 {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy_length = u32::from_le_bytes(
+                bytes[position + 1..position + 5]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            if bytes.len() < position + 5 + policy_length {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy =
+                FsyncPolicy::from_bytes(bytes.slice(position + 5..position + 5 + policy_length))?;
+            Ok((Some(policy), 5 + policy_length))
+        }
+        Some(_) => Err(IggyError::InvalidCommand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_should_be_serialized_and_deserialized() {
+        for policy in [
+            FsyncPolicy::every_n_messages(1000),
+            FsyncPolicy::interval(IggyDuration::new_from_secs(5)),
+            FsyncPolicy::never(),
+            FsyncPolicy {
+                every_n_messages: Some(100),
+                interval: Some(IggyDuration::new_from_secs(1)),
+            },
+        ] {
+            let deserialized = FsyncPolicy::from_bytes(policy.to_bytes()).unwrap();
+            assert_eq!(deserialized, policy);
+            assert!(deserialized.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn optional_policy_should_be_written_and_read() {
+        let policy = FsyncPolicy::every_n_messages(10);
+        let mut bytes = BytesMut::new();
+        write_optional_fsync_policy(Some(&policy), &mut bytes);
+        write_optional_fsync_policy(None, &mut bytes);
+        let bytes = bytes.freeze();
+        let (read_policy, read_bytes) = read_optional_fsync_policy(&bytes, 0).unwrap();
+        assert_eq!(read_policy, Some(policy));
+        let (read_policy, _) = read_optional_fsync_policy(&bytes, read_bytes).unwrap();
+        assert!(read_policy.is_none());
+    }
+
+    #[test]
+    fn policy_without_thresholds_should_never_sync() {
+        assert!(FsyncPolicy::never().is_never());
+        assert!(FsyncPolicy::never().validate().is_ok());
+        assert!(!FsyncPolicy::every_n_messages(1).is_never());
+    }
+
+    #[test]
+    fn policy_with_zero_thresholds_should_be_invalid() {
+        assert!(FsyncPolicy::every_n_messages(0).validate().is_err());
+        assert!(FsyncPolicy::interval(IggyDuration::from(0))
+            .validate()
+            .is_err());
+    }
+}
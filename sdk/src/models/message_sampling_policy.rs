@@ -0,0 +1,264 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The maximum size of the payload copied into the diagnostics topic for each sampled message.
+pub const MAX_SAMPLED_PAYLOAD_SIZE: u32 = 64 * 1024;
+
+/// The header holding the ID of the stream to which the sampled message was appended.
+pub const SAMPLED_SOURCE_STREAM_ID_HEADER: &str = "iggy-sampled-source-stream-id";
+/// The header holding the ID of the topic to which the sampled message was appended.
+pub const SAMPLED_SOURCE_TOPIC_ID_HEADER: &str = "iggy-sampled-source-topic-id";
+/// The header holding the ID of the sampled message.
+pub const SAMPLED_MESSAGE_ID_HEADER: &str = "iggy-sampled-message-id";
+/// The header holding the origin timestamp (microseconds) of the sampled message set by the producer.
+pub const SAMPLED_ORIGIN_TIMESTAMP_HEADER: &str = "iggy-sampled-origin-timestamp";
+/// The header holding the original length of the sampled message payload, before the truncation.
+pub const SAMPLED_PAYLOAD_LENGTH_HEADER: &str = "iggy-sampled-payload-length";
+/// The header holding the ID of the user who appended the sampled message.
+pub const SAMPLED_PRODUCER_USER_ID_HEADER: &str = "iggy-sampled-producer-user-id";
+/// The header holding the ID of the client which appended the sampled message.
+pub const SAMPLED_PRODUCER_CLIENT_ID_HEADER: &str = "iggy-sampled-producer-client-id";
+/// The header holding the address of the client which appended the sampled message.
+pub const SAMPLED_PRODUCER_ADDRESS_HEADER: &str = "iggy-sampled-producer-address";
+/// The header holding the timestamp (microseconds) when the message was sampled.
+pub const SAMPLED_AT_HEADER: &str = "iggy-sampled-at";
+
+/// `MessageSamplingPolicy` is the optional policy attached to the topic, used by the server to copy a fraction of the appended messages into the diagnostics topic.
+/// Each sampled message consists of the truncated payload and the headers describing the original message and its producer.
+/// It consists of the following fields:
+/// - `stream_id`: the unique stream ID (numeric or name) of the diagnostics topic.
+/// - `topic_id`: the unique topic ID (numeric or name) of the diagnostics topic.
+/// - `sampling_rate`: the fraction of the appended messages to be sampled, in range (0, 1].
+/// - `max_payload_size`: the maximum size of the payload copied into the diagnostics topic, 0 means that only the metadata is copied.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MessageSamplingPolicy {
+    /// The unique stream ID (numeric or name) of the diagnostics topic.
+    pub stream_id: Identifier,
+    /// The unique topic ID (numeric or name) of the diagnostics topic.
+    pub topic_id: Identifier,
+    /// The fraction of the appended messages to be sampled, in range (0, 1].
+    pub sampling_rate: f64,
+    /// The maximum size of the payload copied into the diagnostics topic, 0 means that only the metadata is copied.
+    pub max_payload_size: u32,
+}
+
+impl MessageSamplingPolicy {
+    /// Creates the sampling policy for the given diagnostics topic, sampling rate and maximum payload size.
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        sampling_rate: f64,
+        max_payload_size: u32,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            sampling_rate,
+            max_payload_size,
+        }
+    }
+}
+
+impl Validatable<IggyError> for MessageSamplingPolicy {
+    fn validate(&self) -> Result<(), IggyError> {
+        if !(self.sampling_rate > 0.0 && self.sampling_rate <= 1.0) {
+            return Err(IggyError::InvalidMessageSamplingPolicy(
+                "sampling rate must be in range (0, 1]".to_string(),
+            ));
+        }
+
+        if self.max_payload_size > MAX_SAMPLED_PAYLOAD_SIZE {
+            return Err(IggyError::InvalidMessageSamplingPolicy(format!(
+                "max payload size must not exceed {MAX_SAMPLED_PAYLOAD_SIZE} bytes"
+            )));
+        }
+
+        self.stream_id.validate()?;
+        self.topic_id.validate()?;
+        Ok(())
+    }
+}
+
+impl BytesSerializable for MessageSamplingPolicy {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(12 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_f64_le(self.sampling_rate);
+        bytes.put_u32_le(self.max_payload_size);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<MessageSamplingPolicy, IggyError> {
+        if bytes.len() < 18 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let sampling_rate = f64::from_le_bytes(
+            bytes[position..position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let max_payload_size = u32::from_le_bytes(
+            bytes[position + 8..position + 12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(MessageSamplingPolicy {
+            stream_id,
+            topic_id,
+            sampling_rate,
+            max_payload_size,
+        })
+    }
+}
+
+impl Display for MessageSamplingPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{}(rate: {}, max payload size: {})",
+            self.stream_id, self.topic_id, self.sampling_rate, self.max_payload_size
+        )
+    }
+}
+
+/// Writes the optional sampling policy (used by the topic commands and responses), prefixed with the presence flag and the length.
+pub(crate) fn write_optional_sampling_policy(
+    policy: Option<&MessageSamplingPolicy>,
+    bytes: &mut BytesMut,
+) {
+    match policy {
+        Some(policy) => {
+            let policy = policy.to_bytes();
+            bytes.put_u8(1);
+            bytes.put_u32_le(policy.len() as u32);
+            bytes.put_slice(&policy);
+        }
+        None => bytes.put_u8(0),
+    }
+}
+
+/// Reads the optional sampling policy written by `write_optional_sampling_policy`, returning it along with the number of read bytes.
+pub(crate) fn read_optional_sampling_policy(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<(Option<MessageSamplingPolicy>, usize), IggyError> {
+    match bytes.get(position) {
+        None | Some(0) => Ok((None, 1)),
+        Some(1) => {
+            if bytes.len() < position + 5 {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy_length = u32::from_le_bytes(
+                bytes[position + 1..position + 5]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            if bytes.len() < position + 5 + policy_length {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy = MessageSamplingPolicy::from_bytes(
+                bytes.slice(position + 5..position + 5 + policy_length),
+            )?;
+            Ok((Some(policy), 5 + policy_length))
+        }
+        Some(_) => Err(IggyError::InvalidCommand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_should_be_serialized_and_deserialized() {
+        let policy = MessageSamplingPolicy::new(
+            Identifier::numeric(1).unwrap(),
+            Identifier::named("diagnostics").unwrap(),
+            0.01,
+            256,
+        );
+        let deserialized = MessageSamplingPolicy::from_bytes(policy.to_bytes()).unwrap();
+        assert_eq!(deserialized, policy);
+        assert!(deserialized.validate().is_ok());
+    }
+
+    #[test]
+    fn optional_policy_should_be_written_and_read() {
+        let policy = MessageSamplingPolicy::new(
+            Identifier::named("orders").unwrap(),
+            Identifier::numeric(2).unwrap(),
+            1.0,
+            0,
+        );
+        let mut bytes = BytesMut::new();
+        write_optional_sampling_policy(Some(&policy), &mut bytes);
+        write_optional_sampling_policy(None, &mut bytes);
+        let bytes = bytes.freeze();
+        let (read_policy, read_bytes) = read_optional_sampling_policy(&bytes, 0).unwrap();
+        assert_eq!(read_policy, Some(policy));
+        let (read_policy, _) = read_optional_sampling_policy(&bytes, read_bytes).unwrap();
+        assert!(read_policy.is_none());
+    }
+
+    #[test]
+    fn policy_with_invalid_sampling_rate_should_be_invalid() {
+        for sampling_rate in [0.0, -0.5, 1.5, f64::NAN] {
+            let policy = MessageSamplingPolicy::new(
+                Identifier::numeric(1).unwrap(),
+                Identifier::numeric(2).unwrap(),
+                sampling_rate,
+                128,
+            );
+            assert!(policy.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn policy_with_too_large_payload_size_should_be_invalid() {
+        let policy = MessageSamplingPolicy::new(
+            Identifier::numeric(1).unwrap(),
+            Identifier::numeric(2).unwrap(),
+            0.5,
+            MAX_SAMPLED_PAYLOAD_SIZE + 1,
+        );
+        assert!(policy.validate().is_err());
+    }
+}
@@ -0,0 +1,67 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+
+/// The header marking the message as the tombstone, i.e. the deletion of its key in the compacted topic.
+/// The compaction retains the tombstone as the latest message of its key, so the consumers building
+/// the state stores from the topic can remove the key, instead of keeping its previous value.
+/// The payload of the tombstone is ignored.
+pub const TOMBSTONE_HEADER: &str = "iggy-tombstone";
+
+/// Returns the header key and value marking the message as the tombstone.
+pub fn tombstone_header() -> (HeaderKey, HeaderValue) {
+    (
+        HeaderKey::new(TOMBSTONE_HEADER).unwrap(),
+        HeaderValue::from_bool(true).unwrap(),
+    )
+}
+
+/// Checks if the headers contain the tombstone header set to `true`.
+pub fn is_tombstone(headers: &HashMap<HeaderKey, HeaderValue>) -> bool {
+    headers
+        .get(&HeaderKey::new(TOMBSTONE_HEADER).unwrap())
+        .and_then(|value| value.as_bool().ok())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn tombstone_should_be_read_from_headers() {
+        let (key, value) = tombstone_header();
+        let headers = HashMap::from([(key, value)]);
+        assert!(is_tombstone(&headers));
+    }
+
+    #[test]
+    fn tombstone_should_be_ignored_when_missing_invalid_or_false() {
+        assert!(!is_tombstone(&HashMap::new()));
+
+        let key = HeaderKey::new(TOMBSTONE_HEADER).unwrap();
+        let headers = HashMap::from([(key.clone(), HeaderValue::from_str("true").unwrap())]);
+        assert!(!is_tombstone(&headers));
+
+        let headers = HashMap::from([(key, HeaderValue::from_bool(false).unwrap())]);
+        assert!(!is_tombstone(&headers));
+    }
+}
@@ -0,0 +1,93 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
+use std::collections::HashMap;
+
+/// The header holding the time to live (microseconds) of the message, counted from the moment it was appended to the partition.
+/// Once elapsed, the message is no longer returned when polling, and it's removed along with its segment,
+/// regardless of the message expiry configured for the topic.
+pub const MESSAGE_TTL_HEADER: &str = "iggy-message-ttl";
+
+/// Returns the header key and value setting the time to live of the message.
+pub fn ttl_header(ttl: IggyDuration) -> (HeaderKey, HeaderValue) {
+    (
+        HeaderKey::new(MESSAGE_TTL_HEADER).unwrap(),
+        HeaderValue::from_uint64(ttl.as_micros()).unwrap(),
+    )
+}
+
+/// Returns the time to live of the message, if the headers contain a valid, non-zero TTL header.
+pub fn get_message_ttl(headers: &HashMap<HeaderKey, HeaderValue>) -> Option<IggyDuration> {
+    let ttl = headers
+        .get(&HeaderKey::new(MESSAGE_TTL_HEADER).unwrap())?
+        .as_uint64()
+        .ok()?;
+    if ttl == 0 {
+        return None;
+    }
+
+    Some(IggyDuration::from(ttl))
+}
+
+/// Returns the timestamp at which the message appended at the given timestamp (microseconds) expires.
+pub fn get_message_expiry(timestamp: u64, ttl: IggyDuration) -> IggyTimestamp {
+    timestamp.saturating_add(ttl.as_micros()).into()
+}
+
+/// Checks if the message appended at the given timestamp (microseconds) with the given TTL has expired.
+pub fn is_message_expired(timestamp: u64, ttl: IggyDuration, now: IggyTimestamp) -> bool {
+    get_message_expiry(timestamp, ttl).as_micros() <= now.as_micros()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn ttl_should_be_read_from_headers() {
+        let (key, value) = ttl_header(IggyDuration::new_from_secs(10));
+        let headers = HashMap::from([(key, value)]);
+        assert_eq!(
+            get_message_ttl(&headers),
+            Some(IggyDuration::new_from_secs(10))
+        );
+    }
+
+    #[test]
+    fn ttl_should_be_ignored_when_missing_invalid_or_zero() {
+        assert_eq!(get_message_ttl(&HashMap::new()), None);
+
+        let key = HeaderKey::new(MESSAGE_TTL_HEADER).unwrap();
+        let headers = HashMap::from([(key.clone(), HeaderValue::from_str("10s").unwrap())]);
+        assert_eq!(get_message_ttl(&headers), None);
+
+        let headers = HashMap::from([(key, HeaderValue::from_uint64(0).unwrap())]);
+        assert_eq!(get_message_ttl(&headers), None);
+    }
+
+    #[test]
+    fn message_should_expire_once_ttl_elapses() {
+        let ttl = IggyDuration::from(1_000);
+        assert!(!is_message_expired(5_000, ttl, 5_999.into()));
+        assert!(is_message_expired(5_000, ttl, 6_000.into()));
+    }
+}
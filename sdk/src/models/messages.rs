@@ -18,9 +18,14 @@
 
 use crate::bytes_serializable::BytesSerializable;
 use crate::error::IggyError;
+use crate::messages::PayloadEncoding;
+use crate::models::compaction_policy::CompactionKey;
 use crate::models::header;
 use crate::models::header::{HeaderKey, HeaderValue};
+use crate::models::message_tombstone;
+use crate::models::message_ttl;
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
 use crate::utils::sizeable::Sizeable;
 use crate::utils::timestamp::IggyTimestamp;
 use bytes::{BufMut, Bytes, BytesMut};
@@ -38,6 +43,7 @@ pub const POLLED_MESSAGE_METADATA: u32 = 8 + 1 + 8 + 4;
 /// - `partition_id`: the identifier of the partition.
 /// - `current_offset`: the current offset of the partition.
 /// - `messages`: the collection of messages.
+/// - `next_offset`: the offset to continue polling from, if the response was truncated by the server.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PolledMessages {
     /// The identifier of the partition. If it's '0', then there's no partition assigned to the consumer group member.
@@ -46,6 +52,50 @@ pub struct PolledMessages {
     pub current_offset: u64,
     /// The collection of messages.
     pub messages: Vec<PolledMessage>,
+    /// The offset of the first message that wasn't returned, because the response reached the maximum size allowed by the server.
+    /// If it's `None`, then the response wasn't truncated.
+    #[serde(default)]
+    pub next_offset: Option<u64>,
+}
+
+/// The metadata of the messages polled from the partition, returned when the messages are visited in place instead of being collected.
+/// It consists of the following fields:
+/// - `partition_id`: the identifier of the partition.
+/// - `current_offset`: the current offset of the partition.
+/// - `messages_count`: the count of the messages declared by the server.
+/// - `next_offset`: the offset to continue polling from, if the response was truncated by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolledMessagesMetadata {
+    /// The identifier of the partition. If it's '0', then there's no partition assigned to the consumer group member.
+    pub partition_id: u32,
+    /// The current offset of the partition.
+    pub current_offset: u64,
+    /// The count of the messages declared by the server.
+    pub messages_count: u32,
+    /// The offset of the first message that wasn't returned, because the response reached the maximum size allowed by the server.
+    /// If it's `None`, then the response wasn't truncated.
+    pub next_offset: Option<u64>,
+}
+
+/// The single message that is polled from the partition, borrowed from the network receive buffer.
+/// Unlike `PolledMessage`, it doesn't allocate, so the payload can be parsed in place for the maximum throughput.
+/// The headers are kept in their binary form, and can be decoded on demand with `decode_headers`.
+#[derive(Debug, Clone, Copy)]
+pub struct PolledMessageView<'a> {
+    /// The offset of the message.
+    pub offset: u64,
+    /// The state of the message.
+    pub state: MessageState,
+    /// The timestamp of the message.
+    pub timestamp: u64,
+    /// The identifier of the message.
+    pub id: u128,
+    /// The checksum of the message, can be used to verify the integrity of the message.
+    pub checksum: u32,
+    /// The binary headers of the message, empty if the message has no headers.
+    pub headers: &'a [u8],
+    /// The binary payload of the message.
+    pub payload: &'a [u8],
 }
 
 /// The single message that is polled from the partition.
@@ -58,8 +108,11 @@ pub struct PolledMessages {
 /// - `headers`: the optional headers of the message.
 /// - `length`: the length of the payload.
 /// - `payload`: the binary payload of the message.
+///
+/// When deserialized, the payload is decoded using the optional `payload_encoding` of the message (Base64 by default).
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(try_from = "EncodedPolledMessage")]
 pub struct PolledMessage {
     /// The offset of the message.
     pub offset: u64,
@@ -81,9 +134,54 @@ pub struct PolledMessage {
     pub payload: Bytes,
 }
 
+/// The collection of polled messages with the payloads encoded as strings, as returned by the HTTP API.
+/// It consists of the same fields as `PolledMessages`, except for the messages which are `EncodedPolledMessage`.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EncodedPolledMessages {
+    /// The identifier of the partition. If it's '0', then there's no partition assigned to the consumer group member.
+    pub partition_id: u32,
+    /// The current offset of the partition.
+    pub current_offset: u64,
+    /// The collection of messages with the encoded payloads.
+    pub messages: Vec<EncodedPolledMessage>,
+    /// The offset of the first message that wasn't returned, because the response reached the maximum size allowed by the server.
+    #[serde(default)]
+    pub next_offset: Option<u64>,
+}
+
+/// The single polled message with the payload encoded as a string.
+/// It consists of the same fields as `PolledMessage`, except for:
+/// - `payload`: the encoded payload of the message.
+/// - `payload_encoding`: the encoding of the payload, which might differ between the messages,
+///   e.g. the binary payload requested as UTF-8 is encoded as Base64 instead.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EncodedPolledMessage {
+    /// The offset of the message.
+    pub offset: u64,
+    /// The state of the message.
+    pub state: MessageState,
+    /// The timestamp of the message.
+    pub timestamp: u64,
+    /// The identifier of the message.
+    pub id: u128,
+    /// The checksum of the message, can be used to verify the integrity of the message.
+    pub checksum: u32,
+    /// The optional headers of the message.
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<HashMap<String, HeaderValue>>))]
+    pub headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    /// The encoded payload of the message.
+    pub payload: String,
+    /// The encoding of the payload.
+    #[serde(default)]
+    pub payload_encoding: PayloadEncoding,
+}
+
 /// The state of the message, currently only the `Available` state is used.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum MessageState {
     /// The message is available.
     Available,
@@ -170,6 +268,39 @@ impl PolledMessage {
         self.timestamp.into()
     }
 
+    /// Returns the time to live of the message, if it was set by the producer.
+    pub fn ttl(&self) -> Option<IggyDuration> {
+        self.headers.as_ref().and_then(message_ttl::get_message_ttl)
+    }
+
+    /// Returns the timestamp at which the message expires, if it has the time to live.
+    pub fn expires_at(&self) -> Option<IggyTimestamp> {
+        self.ttl()
+            .map(|ttl| message_ttl::get_message_expiry(self.timestamp, ttl))
+    }
+
+    /// Returns the remaining lifetime of the message at the given time, if it has the time to live.
+    /// The zero duration means that the message has already expired.
+    pub fn remaining_ttl(&self, now: IggyTimestamp) -> Option<IggyDuration> {
+        self.expires_at().map(|expires_at| {
+            IggyDuration::from(expires_at.as_micros().saturating_sub(now.as_micros()))
+        })
+    }
+
+    /// Returns the key by which the message is compacted, according to the compaction policy of its topic.
+    /// The consumers building the state from the compacted topic should keep only the latest message per key.
+    pub fn compaction_key(&self, key: &CompactionKey) -> Option<Bytes> {
+        key.get_message_key(self.id, self.headers.as_ref())
+    }
+
+    /// Checks if the message is the tombstone, i.e. it deletes its key in the compacted topic,
+    /// so the consumers building the state from the topic should remove the key instead of storing the payload.
+    pub fn is_tombstone(&self) -> bool {
+        self.headers
+            .as_ref()
+            .is_some_and(message_tombstone::is_tombstone)
+    }
+
     /// Extends the provided bytes with the message.
     pub fn extend(&self, bytes: &mut BytesMut) {
         bytes.put_u64_le(self.offset);
@@ -190,6 +321,73 @@ impl PolledMessage {
     }
 }
 
+impl PolledMessageView<'_> {
+    /// Returns the timestamp of the message as `IggyTimestamp`.
+    pub fn timestamp(&self) -> IggyTimestamp {
+        self.timestamp.into()
+    }
+
+    /// Decodes the headers of the message, if there are any.
+    pub fn decode_headers(&self) -> Result<Option<HashMap<HeaderKey, HeaderValue>>, IggyError> {
+        if self.headers.is_empty() {
+            return Ok(None);
+        }
+
+        HashMap::from_bytes(Bytes::copy_from_slice(self.headers)).map(Some)
+    }
+}
+
+impl EncodedPolledMessages {
+    /// Creates the collection of messages with the payloads encoded using the requested encoding.
+    pub fn new(polled_messages: PolledMessages, encoding: PayloadEncoding) -> Self {
+        EncodedPolledMessages {
+            partition_id: polled_messages.partition_id,
+            current_offset: polled_messages.current_offset,
+            messages: polled_messages
+                .messages
+                .into_iter()
+                .map(|message| EncodedPolledMessage::new(message, encoding))
+                .collect(),
+            next_offset: polled_messages.next_offset,
+        }
+    }
+}
+
+impl EncodedPolledMessage {
+    /// Creates the message with the payload encoded using the requested encoding, if possible.
+    pub fn new(message: PolledMessage, encoding: PayloadEncoding) -> Self {
+        let (payload_encoding, payload) = encoding.encode(&message.payload);
+        EncodedPolledMessage {
+            offset: message.offset,
+            state: message.state,
+            timestamp: message.timestamp,
+            id: message.id,
+            checksum: message.checksum,
+            headers: message.headers,
+            payload,
+            payload_encoding,
+        }
+    }
+}
+
+impl TryFrom<EncodedPolledMessage> for PolledMessage {
+    type Error = IggyError;
+
+    fn try_from(message: EncodedPolledMessage) -> Result<Self, Self::Error> {
+        let payload = message.payload_encoding.decode(&message.payload)?;
+        Ok(PolledMessage {
+            offset: message.offset,
+            state: message.state,
+            timestamp: message.timestamp,
+            id: message.id,
+            checksum: message.checksum,
+            length: IggyByteSize::from(payload.len() as u64),
+            payload,
+            headers: message.headers,
+        })
+    }
+}
+
 impl Sizeable for PolledMessage {
     fn get_size_bytes(&self) -> IggyByteSize {
         // Offset + State + Timestamp + ID + Checksum + Length + Payload + Headers
@@ -2,8 +2,11 @@ use super::header::get_headers_size_bytes;
 use super::message_header::{IggyMessageHeader, IGGY_MESSAGE_HEADER_SIZE};
 use crate::bytes_serializable::BytesSerializable;
 use crate::error::IggyError;
+use crate::models::message_tombstone::TOMBSTONE_HEADER;
+use crate::models::message_ttl::MESSAGE_TTL_HEADER;
 use crate::models::messaging::header::{HeaderKey, HeaderValue};
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
 use crate::utils::sizeable::Sizeable;
 use crate::utils::timestamp::IggyTimestamp;
 use bytes::{BufMut, Bytes, BytesMut};
@@ -216,6 +219,23 @@ impl IggyMessageBuilder {
         self
     }
 
+    /// Sets the time to live of the message, after which the server no longer returns it when polling.
+    pub fn ttl(self, ttl: IggyDuration) -> Self {
+        self.header(
+            HeaderKey::new(MESSAGE_TTL_HEADER).unwrap(),
+            HeaderValue::from_uint64(ttl.as_micros()).unwrap(),
+        )
+    }
+
+    /// Marks the message as the tombstone, deleting its key in the compacted topic.
+    /// The payload is still required, but the consumers ignore it.
+    pub fn tombstone(self) -> Self {
+        self.header(
+            HeaderKey::new(TOMBSTONE_HEADER).unwrap(),
+            HeaderValue::from_bool(true).unwrap(),
+        )
+    }
+
     pub fn headers(mut self, headers: impl Into<Option<HashMap<HeaderKey, HeaderValue>>>) -> Self {
         self.headers = headers.into();
         self
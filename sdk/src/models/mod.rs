@@ -16,19 +16,40 @@
  * under the License.
  */
 
+pub mod audit_entry;
+pub mod backup;
+pub mod bookmark;
 pub mod client_info;
+pub mod cluster;
+pub mod compaction_policy;
+pub mod config_value;
 pub mod consumer_group;
 pub mod consumer_offset_info;
+pub mod dead_letter_policy;
+pub mod fsync_policy;
 pub mod header;
 pub mod identity_info;
+pub mod message_sampling_policy;
+pub mod message_tombstone;
+pub mod message_ttl;
 pub mod messages;
 pub mod messaging;
 pub mod partition;
 pub mod permissions;
 pub mod personal_access_token;
+pub mod queue_policy;
+pub mod quota;
+pub mod retention_policy;
+pub mod schema;
+pub mod segment_rollover_policy;
+pub mod segments_verification;
 pub mod snapshot;
 pub mod stats;
 pub mod stream;
+pub mod tiering_policy;
 pub mod topic;
+pub mod topic_options;
+pub mod topic_schema;
+pub mod transaction;
 pub mod user_info;
 pub mod user_status;
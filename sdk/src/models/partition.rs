@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::consumer::ConsumerKind;
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
@@ -29,6 +30,7 @@ use serde::{Deserialize, Serialize};
 /// - `size_bytes`: the size of the partition in bytes.
 /// - `messages_count`: the number of messages in the partition.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Partition {
     /// Unique identifier of the partition.
     pub id: u32,
@@ -43,3 +45,72 @@ pub struct Partition {
     /// The number of messages in the partition.
     pub messages_count: u64,
 }
+
+/// `PartitionDetails` represents the detailed information about a partition.
+/// It consists of the following fields:
+/// - `id`: unique identifier of the partition.
+/// - `name`: the optional name (label) of the partition, unique within the topic.
+/// - `created_at`: the timestamp of the partition creation.
+/// - `segments_count`: the number of segments in the partition.
+/// - `current_offset`: the current offset of the partition.
+/// - `size`: the size of the partition in bytes.
+/// - `messages_count`: the number of messages in the partition.
+/// - `segments`: the collection of segments in the partition.
+/// - `consumer_offsets`: the collection of offsets stored by the consumers and consumer groups.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PartitionDetails {
+    /// Unique identifier of the partition.
+    pub id: u32,
+    /// The optional name (label) of the partition, unique within the topic.
+    pub name: Option<String>,
+    /// The timestamp of the partition creation.
+    pub created_at: IggyTimestamp,
+    /// The number of segments in the partition.
+    pub segments_count: u32,
+    /// The current offset of the partition.
+    pub current_offset: u64,
+    /// The size of the partition in bytes.
+    pub size: IggyByteSize,
+    /// The number of messages in the partition.
+    pub messages_count: u64,
+    /// The collection of segments in the partition.
+    pub segments: Vec<PartitionSegment>,
+    /// The collection of offsets stored by the consumers and consumer groups.
+    pub consumer_offsets: Vec<PartitionConsumerOffset>,
+}
+
+/// `PartitionSegment` represents the information about a segment of a partition.
+/// It consists of the following fields:
+/// - `start_offset`: the offset of the first message in the segment.
+/// - `current_offset`: the offset of the last message in the segment.
+/// - `size`: the size of the segment in bytes.
+/// - `messages_count`: the number of messages in the segment.
+/// - `is_closed`: whether the segment is closed and no longer appended to.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PartitionSegment {
+    /// The offset of the first message in the segment.
+    pub start_offset: u64,
+    /// The offset of the last message in the segment.
+    pub current_offset: u64,
+    /// The size of the segment in bytes.
+    pub size: IggyByteSize,
+    /// The number of messages in the segment.
+    pub messages_count: u64,
+    /// Whether the segment is closed and no longer appended to.
+    pub is_closed: bool,
+}
+
+/// `PartitionConsumerOffset` represents the offset stored by a consumer or consumer group for a partition.
+/// It consists of the following fields:
+/// - `kind`: the kind of the consumer.
+/// - `consumer_id`: the ID of the consumer or consumer group.
+/// - `offset`: the stored offset.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PartitionConsumerOffset {
+    /// The kind of the consumer.
+    pub kind: ConsumerKind,
+    /// The ID of the consumer or consumer group.
+    pub consumer_id: u32,
+    /// The stored offset.
+    pub offset: u64,
+}
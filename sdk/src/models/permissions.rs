@@ -18,6 +18,7 @@
 
 use crate::bytes_serializable::BytesSerializable;
 use crate::error::IggyError;
+use crate::validatable::Validatable;
 use ahash::AHashMap;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
@@ -27,17 +28,25 @@ use std::fmt::Display;
 /// It consists of global permissions and stream permissions.
 /// Global permissions are applied to all streams.
 /// Stream permissions are applied to a specific stream.
+/// Pattern permissions are applied to all the streams and topics whose names match the pattern.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Permissions {
     /// Global permissions are applied to all streams.
     pub global: GlobalPermissions,
 
     /// Stream permissions are applied to a specific stream.
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<HashMap<u32, StreamPermissions>>))]
     pub streams: Option<AHashMap<u32, StreamPermissions>>,
+
+    /// Pattern permissions are applied to all the streams and topics whose names match the pattern.
+    #[serde(default)]
+    pub patterns: Option<Vec<PatternPermissions>>,
 }
 
 /// `GlobalPermissions` are applied to all streams without a need to specify them one by one in the `streams` field.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct GlobalPermissions {
     /// `manage_servers` permission allows to manage the servers and includes all the permissions of `read_servers`.
     pub manage_servers: bool,
@@ -48,6 +57,13 @@ pub struct GlobalPermissions {
     /// - get_client
     pub read_servers: bool,
 
+    /// `manage_config` permission allows to inspect and change the server configuration at runtime.
+    /// Additionally, the following methods can be invoked:
+    /// - get_config
+    /// - update_config
+    #[serde(default)]
+    pub manage_config: bool,
+
     /// `manage_users` permission allows to manage the users and includes all the permissions of `read_users`.
     /// Additionally, the following methods can be invoked:
     /// - create_user
@@ -106,6 +122,7 @@ pub struct GlobalPermissions {
 /// `StreamPermissions` are applied to a specific stream and its all topics. If you want to define granular permissions for each topic, use the `topics` field.
 /// These permissions do not override the global permissions, but extend them, and allow more granular control over the streams and the users that can access them.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct StreamPermissions {
     /// `manage_stream` permission allows to manage the stream and includes all the permissions of `read_stream`.
     /// Also, it allows to manage all the topics of a stream, thus it has all the permissions of `manage_topics`.
@@ -140,11 +157,13 @@ pub struct StreamPermissions {
     pub send_messages: bool,
 
     /// The `topics` field allows to define the granular permissions for each topic of a stream.
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<HashMap<u32, TopicPermissions>>))]
     pub topics: Option<AHashMap<u32, TopicPermissions>>,
 }
 
 /// `TopicPermissions` are applied to a specific topic of a stream. This is the lowest level of permissions.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TopicPermissions {
     /// `manage_topic` permission allows to manage the topic and includes all the permissions of `read_topic`.
     pub manage_topic: bool,
@@ -159,12 +178,147 @@ pub struct TopicPermissions {
     pub send_messages: bool,
 }
 
+/// `PatternPermissions` are applied to all the topics whose names match the pattern, including the ones created later on.
+/// The pattern has the `stream.topic` form, where `*` matches any sequence of characters, e.g. `orders.*` matches all the topics of the `orders` stream.
+/// The stream and topic parts are separated by the first `.`, thus the `.` (as well as `*` and `\`) in the stream name must be escaped
+/// with `\`, e.g. `eu\.orders.*` matches all the topics of the `eu.orders` stream. The topic part can contain `.` as it is.
+/// The pattern without the topic part, e.g. `orders`, is the same as `orders.*`, and only such a pattern (matching all the topics) applies to the stream itself.
+/// If several patterns match the same stream or topic, the most specific one (with the most characters other than `*`) takes precedence.
+/// These permissions do not override the global and stream permissions, but extend them.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PatternPermissions {
+    /// The `stream.topic` pattern, where `*` matches any sequence of characters and `\` escapes the next character.
+    pub pattern: String,
+
+    /// `admin` permission allows to manage the matched streams and topics, and includes all the permissions of `read` and `write`.
+    /// Additionally, the following methods can be invoked:
+    /// - update_stream, delete_stream, purge_stream and create_topic (only for the pattern matching all the topics of a stream)
+    /// - update_topic, delete_topic, purge_topic
+    /// - create_partitions, delete_partitions, update_partition
+    /// - delete_segments, verify_segments
+    pub admin: bool,
+
+    /// `read` permission allows to read the matched streams and topics, manage consumer groups and consumer offsets, and poll messages.
+    pub read: bool,
+
+    /// `write` permission allows to send messages to the matched topics.
+    pub write: bool,
+}
+
+const MAX_PATTERN_LENGTH: usize = 255;
+
+impl PatternPermissions {
+    pub fn new(pattern: &str, admin: bool, read: bool, write: bool) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            admin,
+            read,
+            write,
+        }
+    }
+
+    /// Checks whether the pattern matches the stream and the topic. For the stream itself (no topic name),
+    /// the pattern must match all of its topics.
+    pub fn matches(&self, stream_name: &str, topic_name: Option<&str>) -> bool {
+        let (stream_pattern, topic_pattern) = parse_pattern(&self.pattern);
+        if !wildcard_match(&stream_pattern, stream_name) {
+            return false;
+        }
+
+        match topic_name {
+            Some(topic_name) => wildcard_match(&topic_pattern, topic_name),
+            None => topic_pattern.iter().all(Option::is_none),
+        }
+    }
+
+    /// The number of characters other than `*`, the escapes and the stream and topic separator, the higher it is, the more specific the pattern.
+    pub fn specificity(&self) -> usize {
+        let (stream_pattern, topic_pattern) = parse_pattern(&self.pattern);
+        stream_pattern
+            .iter()
+            .chain(topic_pattern.iter())
+            .filter(|c| c.is_some())
+            .count()
+    }
+}
+
+impl Validatable<IggyError> for PatternPermissions {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.pattern.is_empty()
+            || self.pattern.len() > MAX_PATTERN_LENGTH
+            || self.pattern.chars().any(char::is_whitespace)
+        {
+            return Err(IggyError::InvalidPermissionPattern(self.pattern.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits the pattern into the stream and topic parts at the first unescaped `.`, the pattern without the topic part matches all the topics.
+/// The characters are returned as literals, except for the unescaped `*` returned as `None`.
+fn parse_pattern(pattern: &str) -> (Vec<Option<char>>, Vec<Option<char>>) {
+    let mut stream_pattern = Vec::with_capacity(pattern.len());
+    let mut topic_pattern = Vec::new();
+    let mut in_topic = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '\\' => Some(chars.next().unwrap_or('\\')),
+            '*' => None,
+            '.' if !in_topic => {
+                in_topic = true;
+                continue;
+            }
+            c => Some(c),
+        };
+        if in_topic {
+            topic_pattern.push(token);
+        } else {
+            stream_pattern.push(token);
+        }
+    }
+
+    if !in_topic {
+        topic_pattern.push(None);
+    }
+    (stream_pattern, topic_pattern)
+}
+
+fn wildcard_match(pattern: &[Option<char>], value: &str) -> bool {
+    let value = value.chars().collect::<Vec<_>>();
+    let mut pattern_position = 0;
+    let mut value_position = 0;
+    let mut last_wildcard = None;
+    while value_position < value.len() {
+        if pattern_position < pattern.len() && pattern[pattern_position].is_none() {
+            last_wildcard = Some((pattern_position, value_position));
+            pattern_position += 1;
+        } else if pattern_position < pattern.len()
+            && pattern[pattern_position] == Some(value[value_position])
+        {
+            pattern_position += 1;
+            value_position += 1;
+        } else if let Some((wildcard_position, matched_position)) = last_wildcard {
+            pattern_position = wildcard_position + 1;
+            value_position = matched_position + 1;
+            last_wildcard = Some((wildcard_position, value_position));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[pattern_position..].iter().all(Option::is_none)
+}
+
 impl Permissions {
     pub fn root() -> Self {
         Self {
             global: GlobalPermissions {
                 manage_servers: true,
                 read_servers: true,
+                manage_config: true,
                 manage_users: true,
                 read_users: true,
                 manage_streams: true,
@@ -175,15 +329,29 @@ impl Permissions {
                 send_messages: true,
             },
             streams: None,
+            patterns: None,
         }
     }
 }
 
+impl Validatable<IggyError> for Permissions {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(patterns) = &self.patterns {
+            for pattern in patterns {
+                pattern.validate()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl Display for Permissions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut result = String::new();
         result.push_str(&format!("manage_servers: {}\n", self.global.manage_servers));
         result.push_str(&format!("read_servers: {}\n", self.global.read_servers));
+        result.push_str(&format!("manage_config: {}\n", self.global.manage_config));
         result.push_str(&format!("manage_users: {}\n", self.global.manage_users));
         result.push_str(&format!("read_users: {}\n", self.global.read_users));
         result.push_str(&format!("manage_streams: {}\n", self.global.manage_streams));
@@ -212,6 +380,14 @@ impl Display for Permissions {
                 }
             }
         }
+        if let Some(patterns) = &self.patterns {
+            for pattern in patterns {
+                result.push_str(&format!("pattern: {}\n", pattern.pattern));
+                result.push_str(&format!("admin: {}\n", pattern.admin));
+                result.push_str(&format!("read: {}\n", pattern.read));
+                result.push_str(&format!("write: {}\n", pattern.write));
+            }
+        }
 
         write!(f, "{}", result)
     }
@@ -272,6 +448,22 @@ impl BytesSerializable for Permissions {
         } else {
             bytes.put_u8(0);
         }
+        // Appended after the streams, so the permissions serialized before it was added can still be read.
+        bytes.put_u8(if self.global.manage_config { 1 } else { 0 });
+        if let Some(patterns) = &self.patterns {
+            bytes.put_u8(1);
+            bytes.put_u32_le(patterns.len() as u32);
+            for pattern in patterns {
+                #[allow(clippy::cast_possible_truncation)]
+                bytes.put_u8(pattern.pattern.len() as u8);
+                bytes.put_slice(pattern.pattern.as_bytes());
+                bytes.put_u8(if pattern.admin { 1 } else { 0 });
+                bytes.put_u8(if pattern.read { 1 } else { 0 });
+                bytes.put_u8(if pattern.write { 1 } else { 0 });
+            }
+        } else {
+            bytes.put_u8(0);
+        }
         bytes.freeze()
     }
 
@@ -343,10 +535,41 @@ impl BytesSerializable for Permissions {
             }
             streams = Some(streams_map);
         }
+        let manage_config = bytes.has_remaining() && bytes.get_u8() == 1;
+        let mut patterns = None;
+        if bytes.has_remaining() && bytes.get_u8() == 1 {
+            if bytes.remaining() < 4 {
+                return Err(IggyError::InvalidCommand);
+            }
+            let patterns_count = bytes.get_u32_le();
+            let mut patterns_list = Vec::new();
+            for _ in 0..patterns_count {
+                if !bytes.has_remaining() {
+                    return Err(IggyError::InvalidCommand);
+                }
+                let pattern_length = bytes.get_u8() as usize;
+                if bytes.remaining() < pattern_length + 3 {
+                    return Err(IggyError::InvalidCommand);
+                }
+                let pattern = String::from_utf8(bytes.split_to(pattern_length).to_vec())
+                    .map_err(|_| IggyError::InvalidUtf8)?;
+                let admin = bytes.get_u8() == 1;
+                let read = bytes.get_u8() == 1;
+                let write = bytes.get_u8() == 1;
+                patterns_list.push(PatternPermissions {
+                    pattern,
+                    admin,
+                    read,
+                    write,
+                });
+            }
+            patterns = Some(patterns_list);
+        }
         Ok(Self {
             global: GlobalPermissions {
                 manage_servers,
                 read_servers,
+                manage_config,
                 manage_users,
                 read_users,
                 manage_streams,
@@ -357,6 +580,7 @@ impl BytesSerializable for Permissions {
                 send_messages,
             },
             streams,
+            patterns,
         })
     }
 }
@@ -371,6 +595,7 @@ mod tests {
             global: GlobalPermissions {
                 manage_servers: true,
                 read_servers: true,
+                manage_config: true,
                 manage_users: true,
                 read_users: true,
                 manage_streams: false,
@@ -425,6 +650,10 @@ mod tests {
                     },
                 ),
             ])),
+            patterns: Some(vec![
+                PatternPermissions::new("orders.*", true, false, false),
+                PatternPermissions::new("*.audit", false, true, true),
+            ]),
         };
 
         let bytes = permissions.to_bytes();
@@ -432,4 +661,107 @@ mod tests {
 
         assert_eq!(permissions, deserialized_permissions);
     }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_manage_config() {
+        let mut permissions = Permissions::root();
+        let bytes = permissions.to_bytes();
+        let bytes = bytes.slice(..bytes.len() - 2);
+
+        let deserialized_permissions = Permissions::from_bytes(bytes).unwrap();
+
+        permissions.global.manage_config = false;
+        assert_eq!(permissions, deserialized_permissions);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_patterns() {
+        let permissions = Permissions::root();
+        let bytes = permissions.to_bytes();
+        let bytes = bytes.slice(..bytes.len() - 1);
+
+        let deserialized_permissions = Permissions::from_bytes(bytes).unwrap();
+
+        assert_eq!(permissions, deserialized_permissions);
+    }
+
+    #[test]
+    fn pattern_should_match_stream_and_topic_names() {
+        let pattern = PatternPermissions::new("orders.*", false, true, false);
+        assert!(pattern.matches("orders", Some("payments")));
+        assert!(pattern.matches("orders", None));
+        assert!(!pattern.matches("orders-eu", Some("payments")));
+
+        let pattern = PatternPermissions::new("orders", false, true, false);
+        assert!(pattern.matches("orders", Some("payments")));
+        assert!(pattern.matches("orders", None));
+
+        let pattern = PatternPermissions::new("*.pay*ts", false, true, false);
+        assert!(pattern.matches("orders", Some("payments")));
+        assert!(pattern.matches("invoices", Some("payts")));
+        assert!(!pattern.matches("orders", Some("payment")));
+        assert!(!pattern.matches("orders", None));
+    }
+
+    #[test]
+    fn pattern_should_match_stream_name_with_escaped_separator() {
+        let pattern = PatternPermissions::new(r"eu\.orders.*", false, true, false);
+        assert!(pattern.matches("eu.orders", Some("payments")));
+        assert!(pattern.matches("eu.orders", None));
+        assert!(!pattern.matches("eu", Some("orders")));
+
+        let pattern = PatternPermissions::new("eu.orders.*", false, true, false);
+        assert!(pattern.matches("eu", Some("orders.payments")));
+        assert!(!pattern.matches("eu.orders", Some("payments")));
+
+        let pattern = PatternPermissions::new(r"orders\*", false, true, false);
+        assert!(pattern.matches("orders*", None));
+        assert!(!pattern.matches("orders-eu", None));
+        assert_eq!(pattern.specificity(), 7);
+    }
+
+    #[test]
+    fn truncated_patterns_should_fail_deserialization() {
+        let permissions = Permissions {
+            patterns: Some(vec![PatternPermissions::new(
+                "orders.*", true, false, false,
+            )]),
+            ..Permissions::default()
+        };
+        let bytes = permissions.to_bytes();
+        let patterns_position = bytes.len() - (4 + 1 + "orders.*".len() + 3);
+
+        for length in patterns_position..bytes.len() {
+            assert!(matches!(
+                Permissions::from_bytes(bytes.slice(..length)),
+                Err(IggyError::InvalidCommand)
+            ));
+        }
+    }
+
+    #[test]
+    fn more_specific_pattern_should_have_higher_specificity() {
+        let stream = PatternPermissions::new("orders.*", false, true, false);
+        let topic = PatternPermissions::new("orders.pay*", false, true, false);
+        let exact = PatternPermissions::new("orders.payments", false, true, false);
+        assert!(topic.specificity() > stream.specificity());
+        assert!(exact.specificity() > topic.specificity());
+        assert_eq!(
+            stream.specificity(),
+            PatternPermissions::new("orders", false, true, false).specificity()
+        );
+    }
+
+    #[test]
+    fn pattern_should_be_validated() {
+        assert!(PatternPermissions::new("orders.*", false, true, false)
+            .validate()
+            .is_ok());
+        assert!(PatternPermissions::new("", false, true, false)
+            .validate()
+            .is_err());
+        assert!(PatternPermissions::new("orders .*", false, true, false)
+            .validate()
+            .is_err());
+    }
 }
@@ -16,8 +16,16 @@
  * under the License.
  */
 
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
 use crate::utils::timestamp::IggyTimestamp;
+use crate::validatable::Validatable;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The maximum number of the addresses (or CIDR ranges) the personal access token can be restricted to.
+pub const MAX_ALLOWED_ADDRESSES: usize = 100;
 
 /// `RawPersonalAccessToken` represents the raw personal access token - the secured token which is returned only once during the creation.
 /// It consists of the following fields:
@@ -32,10 +40,234 @@ pub struct RawPersonalAccessToken {
 /// It consists of the following fields:
 /// - `name`: the unique name of the token.
 /// - `expiry`: the optional expiry of the token.
+/// - `scope`: the optional scope limiting the actions which can be performed with the token.
+/// - `allowed_addresses`: the optional addresses (or CIDR ranges) from which the token can be used.
+/// - `last_used_at`: the optional time of the last login with the token, since the server has started.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PersonalAccessTokenInfo {
     /// The unique name of the token.
     pub name: String,
     /// The optional expiry of the token.
     pub expiry_at: Option<IggyTimestamp>,
+    /// The optional scope limiting the actions which can be performed with the token.
+    #[serde(default)]
+    pub scope: Option<PersonalAccessTokenScope>,
+    /// The optional addresses (or CIDR ranges) from which the token can be used.
+    #[serde(default)]
+    pub allowed_addresses: Option<Vec<String>>,
+    /// The optional time of the last login with the token, since the server has started.
+    #[serde(default)]
+    pub last_used_at: Option<IggyTimestamp>,
+}
+
+/// `PersonalAccessTokenScope` limits the actions which can be performed by the user logged in with the personal access token,
+/// on top of the permissions of the user, so that the token is safe to embed in the dashboards or the CI pipelines.
+/// It consists of the following fields:
+/// - `read_only`: whether only the reading actions (getting the resources, polling the messages and storing the consumer offsets) are allowed.
+/// - `streams`: the optional IDs of the streams the actions are limited to, the actions not related to any of them are not allowed.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct PersonalAccessTokenScope {
+    /// Whether only the reading actions are allowed.
+    #[serde(default)]
+    pub read_only: bool,
+    /// The optional IDs of the streams the actions are limited to.
+    #[serde(default)]
+    pub streams: Option<Vec<u32>>,
+}
+
+impl Validatable<IggyError> for PersonalAccessTokenScope {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(streams) = &self.streams {
+            if streams.is_empty() || streams.contains(&0) {
+                return Err(IggyError::InvalidPersonalAccessTokenScope);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for PersonalAccessTokenScope {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(if self.read_only { 1 } else { 0 });
+        if let Some(streams) = &self.streams {
+            bytes.put_u8(1);
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u32_le(streams.len() as u32);
+            for stream_id in streams {
+                bytes.put_u32_le(*stream_id);
+            }
+        } else {
+            bytes.put_u8(0);
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let mut bytes = bytes;
+        if bytes.remaining() < 2 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let read_only = bytes.get_u8() == 1;
+        let streams = if bytes.get_u8() == 1 {
+            if bytes.remaining() < 4 {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            let streams_count = bytes.get_u32_le() as usize;
+            if bytes.remaining() < streams_count * 4 {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            Some((0..streams_count).map(|_| bytes.get_u32_le()).collect())
+        } else {
+            None
+        };
+        Ok(Self { read_only, streams })
+    }
+}
+
+impl Display for PersonalAccessTokenScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let access = if self.read_only {
+            "read"
+        } else {
+            "read, write"
+        };
+        match &self.streams {
+            Some(streams) => write!(
+                f,
+                "{access} on streams: {}",
+                streams
+                    .iter()
+                    .map(|stream_id| stream_id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            None => write!(f, "{access} on all streams"),
+        }
+    }
+}
+
+/// Serializes the optional addresses (or CIDR ranges) the personal access token is restricted to.
+pub fn extend_allowed_addresses(allowed_addresses: &Option<Vec<String>>, bytes: &mut BytesMut) {
+    match allowed_addresses {
+        Some(allowed_addresses) => {
+            bytes.put_u8(1);
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u32_le(allowed_addresses.len() as u32);
+            for address in allowed_addresses {
+                #[allow(clippy::cast_possible_truncation)]
+                bytes.put_u8(address.len() as u8);
+                bytes.put_slice(address.as_bytes());
+            }
+        }
+        None => bytes.put_u8(0),
+    }
+}
+
+/// Deserializes the optional addresses (or CIDR ranges) the personal access token is restricted to.
+pub fn read_allowed_addresses(bytes: &mut Bytes) -> Result<Option<Vec<String>>, IggyError> {
+    if !bytes.has_remaining() || bytes.get_u8() != 1 {
+        return Ok(None);
+    }
+
+    if bytes.remaining() < 4 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let addresses_count = bytes.get_u32_le();
+    let mut allowed_addresses = Vec::new();
+    for _ in 0..addresses_count {
+        if !bytes.has_remaining() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let address_length = bytes.get_u8() as usize;
+        if bytes.remaining() < address_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let address = String::from_utf8(bytes.split_to(address_length).to_vec())
+            .map_err(|_| IggyError::InvalidUtf8)?;
+        allowed_addresses.push(address);
+    }
+    Ok(Some(allowed_addresses))
+}
+
+/// Validates the optional addresses (or CIDR ranges) the personal access token is restricted to.
+/// Their format is verified by the server, as it's the one matching the client addresses against them.
+pub fn validate_allowed_addresses(
+    allowed_addresses: &Option<Vec<String>>,
+) -> Result<(), IggyError> {
+    let Some(allowed_addresses) = allowed_addresses else {
+        return Ok(());
+    };
+
+    if allowed_addresses.is_empty()
+        || allowed_addresses.len() > MAX_ALLOWED_ADDRESSES
+        || allowed_addresses
+            .iter()
+            .any(|address| address.trim().is_empty() || address.len() > 255)
+    {
+        return Err(IggyError::InvalidClientAddress);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scope_should_be_serialized_and_deserialized() {
+        let scope = PersonalAccessTokenScope {
+            read_only: true,
+            streams: Some(vec![1, 5]),
+        };
+        let deserialized = PersonalAccessTokenScope::from_bytes(scope.to_bytes()).unwrap();
+        assert_eq!(deserialized, scope);
+
+        let scope = PersonalAccessTokenScope::default();
+        let deserialized = PersonalAccessTokenScope::from_bytes(scope.to_bytes()).unwrap();
+        assert_eq!(deserialized, scope);
+    }
+
+    #[test]
+    fn scope_should_not_be_valid_given_no_streams() {
+        let scope = PersonalAccessTokenScope {
+            read_only: false,
+            streams: Some(vec![]),
+        };
+        assert!(scope.validate().is_err());
+    }
+
+    #[test]
+    fn allowed_addresses_should_be_serialized_and_deserialized() {
+        let allowed_addresses = Some(vec!["10.0.0.0/8".to_string(), "::1".to_string()]);
+        let mut bytes = BytesMut::new();
+        extend_allowed_addresses(&allowed_addresses, &mut bytes);
+        extend_allowed_addresses(&None, &mut bytes);
+        let mut bytes = bytes.freeze();
+        assert_eq!(
+            read_allowed_addresses(&mut bytes).unwrap(),
+            allowed_addresses
+        );
+        assert_eq!(read_allowed_addresses(&mut bytes).unwrap(), None);
+        assert!(!bytes.has_remaining());
+    }
+
+    #[test]
+    fn allowed_addresses_should_not_be_valid_given_empty_list() {
+        assert!(validate_allowed_addresses(&None).is_ok());
+        assert!(validate_allowed_addresses(&Some(vec!["10.0.0.0/8".to_string()])).is_ok());
+        assert!(validate_allowed_addresses(&Some(vec![])).is_err());
+        assert!(validate_allowed_addresses(&Some(vec![" ".to_string()])).is_err());
+    }
 }
@@ -0,0 +1,174 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `QueuePolicy` is the optional policy attached to the topic, switching it to the queue mode:
+/// - `visibility_timeout`: the time for which the polled message is leased to the consumer, and hidden from the other ones, until it's acknowledged.
+///
+/// In the queue mode, all the consumers polling the topic (using `Next` strategy) share its messages without the consumer group partitioning,
+/// each message is acknowledged individually (not by storing the consumer offset), and the unacknowledged messages are redelivered once their visibility timeout elapses.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct QueuePolicy {
+    /// The time for which the polled message is hidden from the other consumers, until it's acknowledged.
+    pub visibility_timeout: IggyDuration,
+}
+
+impl QueuePolicy {
+    /// Creates the queue policy leasing the polled messages for the given visibility timeout.
+    pub fn visibility_timeout(visibility_timeout: IggyDuration) -> Self {
+        Self { visibility_timeout }
+    }
+
+    /// Returns the timestamp after which the message polled at given timestamp becomes visible again, unless it's acknowledged.
+    pub fn get_visible_at(&self, polled_at: IggyTimestamp) -> IggyTimestamp {
+        IggyTimestamp::from(polled_at.as_micros() + self.visibility_timeout.as_micros())
+    }
+}
+
+impl Validatable<IggyError> for QueuePolicy {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.visibility_timeout.is_zero() {
+            return Err(IggyError::InvalidQueuePolicy(
+                "visibility timeout must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for QueuePolicy {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(8);
+        bytes.put_u64_le(self.visibility_timeout.as_micros());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<QueuePolicy, IggyError> {
+        if bytes.len() != 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let visibility_timeout = u64::from_le_bytes(
+            bytes[0..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(QueuePolicy {
+            visibility_timeout: IggyDuration::from(visibility_timeout),
+        })
+    }
+}
+
+impl Display for QueuePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "visibility_timeout: {}",
+            self.visibility_timeout.as_human_time_string()
+        )
+    }
+}
+
+/// Writes the optional queue policy (used by the topic commands and responses), prefixed with the presence flag and the length.
+pub(crate) fn write_optional_queue_policy(policy: Option<&QueuePolicy>, bytes: &mut BytesMut) {
+    match policy {
+        Some(policy) => {
+            let policy = policy.to_bytes();
+            bytes.put_u8(1);
+            bytes.put_u32_le(policy.len() as u32);
+            bytes.put_slice(&policy);
+        }
+        None => bytes.put_u8(0),
+    }
+}
+
+/// Reads the optional queue policy written by `write_optional_queue_policy`, returning it along with the number of read bytes.
+pub(crate) fn read_optional_queue_policy(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<(Option<QueuePolicy>, usize), IggyError> {
+    match bytes.get(position) {
+        None | Some(0) => Ok((None, 1)),
+        Some(1) => {
+            if bytes.len() < position + 5 {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy_length = u32::from_le_bytes(
+                bytes[position + 1..position + 5]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            if bytes.len() < position + 5 + policy_length {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy =
+                QueuePolicy::from_bytes(bytes.slice(position + 5..position + 5 + policy_length))?;
+            Ok((Some(policy), 5 + policy_length))
+        }
+        Some(_) => Err(IggyError::InvalidCommand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_should_be_serialized_and_deserialized() {
+        let policy = QueuePolicy::visibility_timeout(IggyDuration::new_from_secs(30));
+        let deserialized = QueuePolicy::from_bytes(policy.to_bytes()).unwrap();
+        assert_eq!(deserialized, policy);
+        assert!(deserialized.validate().is_ok());
+    }
+
+    #[test]
+    fn optional_policy_should_be_written_and_read() {
+        let policy = QueuePolicy::visibility_timeout(IggyDuration::new_from_secs(60));
+        let mut bytes = BytesMut::new();
+        write_optional_queue_policy(Some(&policy), &mut bytes);
+        write_optional_queue_policy(None, &mut bytes);
+        let bytes = bytes.freeze();
+        let (read_policy, read_bytes) = read_optional_queue_policy(&bytes, 0).unwrap();
+        assert_eq!(read_policy, Some(policy));
+        let (read_policy, _) = read_optional_queue_policy(&bytes, read_bytes).unwrap();
+        assert!(read_policy.is_none());
+    }
+
+    #[test]
+    fn message_should_become_visible_once_visibility_timeout_elapsed() {
+        let policy = QueuePolicy::visibility_timeout(IggyDuration::new_from_secs(1));
+        let visible_at = policy.get_visible_at(IggyTimestamp::from(1_000_000));
+        assert_eq!(visible_at, IggyTimestamp::from(2_000_000));
+    }
+
+    #[test]
+    fn policy_with_zero_visibility_timeout_should_be_invalid() {
+        assert!(QueuePolicy::visibility_timeout(IggyDuration::from(0))
+            .validate()
+            .is_err());
+    }
+}
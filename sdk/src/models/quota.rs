@@ -0,0 +1,253 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+const USER_PRINCIPAL_KIND: u8 = 1;
+const TOPIC_PRINCIPAL_KIND: u8 = 2;
+
+/// `QuotaPrincipal` represents the user or the topic the throughput quota applies to.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum QuotaPrincipal {
+    /// The user producing or consuming the messages to or from any topic.
+    User { user_id: u32 },
+    /// The topic the messages are produced to or consumed from by any user.
+    Topic { stream_id: u32, topic_id: u32 },
+}
+
+/// `QuotaLimits` represents the maximum throughput of the principal, where `0` means unlimited.
+/// It consists of the following fields:
+/// - `produce_bytes_per_second`: the maximum size of the messages produced per second.
+/// - `produce_messages_per_second`: the maximum count of the messages produced per second.
+/// - `consume_bytes_per_second`: the maximum size of the messages consumed per second.
+/// - `consume_messages_per_second`: the maximum count of the messages consumed per second.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct QuotaLimits {
+    /// The maximum size of the messages produced per second.
+    #[serde(default)]
+    pub produce_bytes_per_second: IggyByteSize,
+    /// The maximum count of the messages produced per second.
+    #[serde(default)]
+    pub produce_messages_per_second: u64,
+    /// The maximum size of the messages consumed per second.
+    #[serde(default)]
+    pub consume_bytes_per_second: IggyByteSize,
+    /// The maximum count of the messages consumed per second.
+    #[serde(default)]
+    pub consume_messages_per_second: u64,
+}
+
+/// `Quota` represents the throughput quota of the principal along with its usage since the server start.
+/// It consists of the following fields:
+/// - `principal`: the user or the topic the quota applies to.
+/// - `limits`: the effective limits of the throughput.
+/// - `is_default`: whether the limits are the default ones from the server configuration, rather than set for the principal.
+/// - `produced_bytes`: the total size of the produced messages.
+/// - `produced_messages`: the total count of the produced messages.
+/// - `consumed_bytes`: the total size of the consumed messages.
+/// - `consumed_messages`: the total count of the consumed messages.
+/// - `delayed_requests`: the count of the requests delayed to stay within the limits.
+/// - `rejected_requests`: the count of the requests rejected for exceeding the limits.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Quota {
+    /// The user or the topic the quota applies to.
+    pub principal: QuotaPrincipal,
+    /// The effective limits of the throughput.
+    pub limits: QuotaLimits,
+    /// Whether the limits are the default ones from the server configuration.
+    pub is_default: bool,
+    /// The total size of the produced messages.
+    pub produced_bytes: u64,
+    /// The total count of the produced messages.
+    pub produced_messages: u64,
+    /// The total size of the consumed messages.
+    pub consumed_bytes: u64,
+    /// The total count of the consumed messages.
+    pub consumed_messages: u64,
+    /// The count of the requests delayed to stay within the limits.
+    pub delayed_requests: u64,
+    /// The count of the requests rejected for exceeding the limits.
+    pub rejected_requests: u64,
+}
+
+impl QuotaLimits {
+    /// Returns true if none of the limits is set.
+    pub fn is_unlimited(&self) -> bool {
+        self.produce_bytes_per_second.as_bytes_u64() == 0
+            && self.produce_messages_per_second == 0
+            && self.consume_bytes_per_second.as_bytes_u64() == 0
+            && self.consume_messages_per_second == 0
+    }
+}
+
+impl BytesSerializable for QuotaPrincipal {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(9);
+        match self {
+            QuotaPrincipal::User { user_id } => {
+                bytes.put_u8(USER_PRINCIPAL_KIND);
+                bytes.put_u32_le(*user_id);
+            }
+            QuotaPrincipal::Topic {
+                stream_id,
+                topic_id,
+            } => {
+                bytes.put_u8(TOPIC_PRINCIPAL_KIND);
+                bytes.put_u32_le(*stream_id);
+                bytes.put_u32_le(*topic_id);
+            }
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<QuotaPrincipal, IggyError> {
+        let read_u32 = |position: usize| -> Result<u32, IggyError> {
+            Ok(u32::from_le_bytes(
+                bytes
+                    .get(position..position + 4)
+                    .ok_or(IggyError::InvalidCommand)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ))
+        };
+        match bytes.first() {
+            Some(&USER_PRINCIPAL_KIND) if bytes.len() == 5 => Ok(QuotaPrincipal::User {
+                user_id: read_u32(1)?,
+            }),
+            Some(&TOPIC_PRINCIPAL_KIND) if bytes.len() == 9 => Ok(QuotaPrincipal::Topic {
+                stream_id: read_u32(1)?,
+                topic_id: read_u32(5)?,
+            }),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl BytesSerializable for QuotaLimits {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(32);
+        bytes.put_u64_le(self.produce_bytes_per_second.as_bytes_u64());
+        bytes.put_u64_le(self.produce_messages_per_second);
+        bytes.put_u64_le(self.consume_bytes_per_second.as_bytes_u64());
+        bytes.put_u64_le(self.consume_messages_per_second);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<QuotaLimits, IggyError> {
+        if bytes.len() != 32 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let read_u64 = |position: usize| -> Result<u64, IggyError> {
+            Ok(u64::from_le_bytes(
+                bytes[position..position + 8]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ))
+        };
+        Ok(QuotaLimits {
+            produce_bytes_per_second: IggyByteSize::from(read_u64(0)?),
+            produce_messages_per_second: read_u64(8)?,
+            consume_bytes_per_second: IggyByteSize::from(read_u64(16)?),
+            consume_messages_per_second: read_u64(24)?,
+        })
+    }
+}
+
+impl Display for QuotaPrincipal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuotaPrincipal::User { user_id } => write!(f, "user with ID: {user_id}"),
+            QuotaPrincipal::Topic {
+                stream_id,
+                topic_id,
+            } => write!(
+                f,
+                "topic with ID: {topic_id} in stream with ID: {stream_id}"
+            ),
+        }
+    }
+}
+
+impl Display for QuotaLimits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "produce: {}/s, {} messages/s, consume: {}/s, {} messages/s",
+            self.produce_bytes_per_second
+                .as_human_string_with_zero_as_unlimited(),
+            format_messages_limit(self.produce_messages_per_second),
+            self.consume_bytes_per_second
+                .as_human_string_with_zero_as_unlimited(),
+            format_messages_limit(self.consume_messages_per_second)
+        )
+    }
+}
+
+fn format_messages_limit(limit: u64) -> String {
+    match limit {
+        0 => "unlimited".to_string(),
+        limit => limit.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn principal_should_be_serialized_and_deserialized() {
+        for principal in [
+            QuotaPrincipal::User { user_id: 3 },
+            QuotaPrincipal::Topic {
+                stream_id: 1,
+                topic_id: 2,
+            },
+        ] {
+            let bytes = principal.to_bytes();
+            assert_eq!(QuotaPrincipal::from_bytes(bytes).unwrap(), principal);
+        }
+    }
+
+    #[test]
+    fn limits_should_be_serialized_and_deserialized() {
+        let limits = QuotaLimits {
+            produce_bytes_per_second: IggyByteSize::from(1_000_000),
+            produce_messages_per_second: 1000,
+            consume_bytes_per_second: IggyByteSize::from(2_000_000),
+            consume_messages_per_second: 0,
+        };
+
+        let bytes = limits.to_bytes();
+
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(QuotaLimits::from_bytes(bytes).unwrap(), limits);
+    }
+
+    #[test]
+    fn principal_should_not_be_deserialized_from_invalid_kind() {
+        let bytes = Bytes::from_static(&[3, 1, 0, 0, 0]);
+        assert!(QuotaPrincipal::from_bytes(bytes).is_err());
+    }
+}
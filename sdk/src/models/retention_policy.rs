@@ -0,0 +1,381 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+const MAX_PRE_DELETION_HOOK_LENGTH: usize = 255;
+
+/// `RetentionMode` determines which limits cause the oldest segments of the topic to be deleted.
+/// It has the following variants:
+/// - `Time`: the segments are deleted once all of their messages have expired (based on the topic message expiry).
+/// - `Size`: the oldest segments are deleted once the topic or partition exceeds its maximum size, the message expiry is ignored.
+/// - `TimeAndSize`: the segments are deleted when either of the above limits is exceeded.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum RetentionMode {
+    /// The segments are deleted once all of their messages have expired.
+    Time,
+    /// The oldest segments are deleted once the topic or partition exceeds its maximum size.
+    Size,
+    /// The segments are deleted when either the expiry or the size limit is exceeded.
+    TimeAndSize,
+}
+
+impl RetentionMode {
+    /// Returns the code of the retention mode.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            RetentionMode::Time => 1,
+            RetentionMode::Size => 2,
+            RetentionMode::TimeAndSize => 3,
+        }
+    }
+
+    /// Returns the retention mode from the given code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(RetentionMode::Time),
+            2 => Ok(RetentionMode::Size),
+            3 => Ok(RetentionMode::TimeAndSize),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for RetentionMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "time" => Ok(RetentionMode::Time),
+            "size" => Ok(RetentionMode::Size),
+            "time_and_size" | "time-and-size" => Ok(RetentionMode::TimeAndSize),
+            _ => Err(format!("Unknown retention mode: {s}")),
+        }
+    }
+}
+
+impl Display for RetentionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RetentionMode::Time => write!(f, "time"),
+            RetentionMode::Size => write!(f, "size"),
+            RetentionMode::TimeAndSize => write!(f, "time_and_size"),
+        }
+    }
+}
+
+/// `RetentionPolicy` is the optional retention policy attached to the topic.
+/// When not set, the topic uses the time-based retention (message expiry), and the oldest segments are deleted
+/// only when the topic is almost full and `delete_oldest_segments` is enabled in the server configuration.
+/// When set to the size-based (or combined) mode, the oldest closed segments are deleted by the background maintenance
+/// until the topic fits within its `max_topic_size` and each partition fits within the `max_partition_size`.
+/// It consists of the following fields:
+/// - `mode`: the retention mode.
+/// - `max_partition_size`: the optional maximum size of a single partition, applicable only to the size-based modes.
+/// - `pre_deletion_hook`: the optional URL of the webhook called with the segment metadata before the segment is deleted,
+///   the deletion is deferred until the webhook responds with the success status, e.g. once the segment was archived elsewhere.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RetentionPolicy {
+    /// The retention mode.
+    pub mode: RetentionMode,
+    /// The optional maximum size of a single partition.
+    #[serde(default)]
+    pub max_partition_size: Option<IggyByteSize>,
+    /// The optional URL of the webhook called before the segment is deleted.
+    #[serde(default)]
+    pub pre_deletion_hook: Option<String>,
+}
+
+impl RetentionPolicy {
+    /// Creates the time-based retention policy.
+    pub fn time() -> Self {
+        Self {
+            mode: RetentionMode::Time,
+            max_partition_size: None,
+            pre_deletion_hook: None,
+        }
+    }
+
+    /// Creates the size-based retention policy with the optional maximum partition size.
+    pub fn size(max_partition_size: Option<IggyByteSize>) -> Self {
+        Self {
+            mode: RetentionMode::Size,
+            max_partition_size,
+            pre_deletion_hook: None,
+        }
+    }
+
+    /// Creates the combined time and size-based retention policy with the optional maximum partition size.
+    pub fn time_and_size(max_partition_size: Option<IggyByteSize>) -> Self {
+        Self {
+            mode: RetentionMode::TimeAndSize,
+            max_partition_size,
+            pre_deletion_hook: None,
+        }
+    }
+
+    /// Sets the URL of the webhook called before the segment is deleted.
+    pub fn with_pre_deletion_hook(mut self, url: &str) -> Self {
+        self.pre_deletion_hook = Some(url.to_string());
+        self
+    }
+
+    /// Returns `true` if the expired segments should be deleted.
+    pub fn retains_by_time(&self) -> bool {
+        matches!(self.mode, RetentionMode::Time | RetentionMode::TimeAndSize)
+    }
+
+    /// Returns `true` if the oldest segments should be deleted once the size limits are exceeded.
+    pub fn retains_by_size(&self) -> bool {
+        matches!(self.mode, RetentionMode::Size | RetentionMode::TimeAndSize)
+    }
+}
+
+impl Validatable<IggyError> for RetentionPolicy {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(url) = &self.pre_deletion_hook {
+            if url.len() > MAX_PRE_DELETION_HOOK_LENGTH {
+                return Err(IggyError::InvalidRetentionPolicy(format!(
+                    "pre-deletion hook URL cannot be longer than {MAX_PRE_DELETION_HOOK_LENGTH} characters"
+                )));
+            }
+
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return Err(IggyError::InvalidRetentionPolicy(
+                    "pre-deletion hook must be the HTTP(S) URL".to_string(),
+                ));
+            }
+        }
+
+        let Some(max_partition_size) = self.max_partition_size else {
+            return Ok(());
+        };
+
+        if self.mode == RetentionMode::Time {
+            return Err(IggyError::InvalidRetentionPolicy(
+                "max partition size cannot be set for the time-based retention".to_string(),
+            ));
+        }
+
+        if max_partition_size.as_bytes_u64() == 0 {
+            return Err(IggyError::InvalidRetentionPolicy(
+                "max partition size must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RetentionPolicy {
+    fn to_bytes(&self) -> Bytes {
+        let hook_length = self
+            .pre_deletion_hook
+            .as_ref()
+            .map_or(0, |url| 1 + url.len());
+        let mut bytes = BytesMut::with_capacity(9 + hook_length);
+        bytes.put_u8(self.mode.as_code());
+        bytes.put_u64_le(
+            self.max_partition_size
+                .map_or(0, |size| size.as_bytes_u64()),
+        );
+        // The hook is appended only when set, so the policy without it keeps the same encoding.
+        if let Some(url) = &self.pre_deletion_hook {
+            bytes.put_u8(url.len() as u8);
+            bytes.put_slice(url.as_bytes());
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RetentionPolicy, IggyError> {
+        if bytes.len() < 9 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mode = RetentionMode::from_code(bytes[0])?;
+        let max_partition_size = u64::from_le_bytes(
+            bytes[1..9]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let max_partition_size = match max_partition_size {
+            0 => None,
+            size => Some(IggyByteSize::from(size)),
+        };
+        let pre_deletion_hook = match bytes.get(9) {
+            None => None,
+            Some(&hook_length) => {
+                if bytes.len() != 10 + hook_length as usize {
+                    return Err(IggyError::InvalidCommand);
+                }
+                let url = std::str::from_utf8(&bytes[10..])
+                    .map_err(|_| IggyError::InvalidUtf8)?
+                    .to_string();
+                Some(url)
+            }
+        };
+        Ok(RetentionPolicy {
+            mode,
+            max_partition_size,
+            pre_deletion_hook,
+        })
+    }
+}
+
+impl Display for RetentionPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.mode)?;
+        match (self.max_partition_size, &self.pre_deletion_hook) {
+            (None, None) => Ok(()),
+            (Some(max_partition_size), None) => write!(
+                f,
+                "(max_partition_size: {})",
+                max_partition_size.as_human_string()
+            ),
+            (None, Some(url)) => write!(f, "(pre_deletion_hook: {url})"),
+            (Some(max_partition_size), Some(url)) => write!(
+                f,
+                "(max_partition_size: {}, pre_deletion_hook: {url})",
+                max_partition_size.as_human_string()
+            ),
+        }
+    }
+}
+
+/// Writes the optional retention policy (used by the topic commands and responses), prefixed with the presence flag and the length.
+pub(crate) fn write_optional_retention_policy(
+    policy: Option<&RetentionPolicy>,
+    bytes: &mut BytesMut,
+) {
+    match policy {
+        Some(policy) => {
+            let policy = policy.to_bytes();
+            bytes.put_u8(1);
+            bytes.put_u32_le(policy.len() as u32);
+            bytes.put_slice(&policy);
+        }
+        None => bytes.put_u8(0),
+    }
+}
+
+/// Reads the optional retention policy written by `write_optional_retention_policy`, returning it along with the number of read bytes.
+pub(crate) fn read_optional_retention_policy(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<(Option<RetentionPolicy>, usize), IggyError> {
+    match bytes.get(position) {
+        None | Some(0) => Ok((None, 1)),
+        Some(1) => {
+            if bytes.len() < position + 5 {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy_length = u32::from_le_bytes(
+                bytes[position + 1..position + 5]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            if bytes.len() < position + 5 + policy_length {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy = RetentionPolicy::from_bytes(
+                bytes.slice(position + 5..position + 5 + policy_length),
+            )?;
+            Ok((Some(policy), 5 + policy_length))
+        }
+        Some(_) => Err(IggyError::InvalidCommand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_should_be_serialized_and_deserialized() {
+        for policy in [
+            RetentionPolicy::time(),
+            RetentionPolicy::size(None),
+            RetentionPolicy::size(Some(IggyByteSize::from(1_000_000))),
+            RetentionPolicy::time_and_size(Some(IggyByteSize::from(5_000_000))),
+            RetentionPolicy::time().with_pre_deletion_hook("http://localhost:8080/hooks/retention"),
+        ] {
+            let deserialized = RetentionPolicy::from_bytes(policy.to_bytes()).unwrap();
+            assert_eq!(deserialized, policy);
+            assert!(deserialized.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn optional_policy_should_be_written_and_read() {
+        let policy = RetentionPolicy::size(Some(IggyByteSize::from(1024)));
+        let mut bytes = BytesMut::new();
+        write_optional_retention_policy(Some(&policy), &mut bytes);
+        write_optional_retention_policy(None, &mut bytes);
+        let bytes = bytes.freeze();
+        let (read_policy, read_bytes) = read_optional_retention_policy(&bytes, 0).unwrap();
+        assert_eq!(read_policy, Some(policy));
+        let (read_policy, _) = read_optional_retention_policy(&bytes, read_bytes).unwrap();
+        assert!(read_policy.is_none());
+    }
+
+    #[test]
+    fn mode_should_be_parsed_from_string() {
+        for mode in [
+            RetentionMode::Time,
+            RetentionMode::Size,
+            RetentionMode::TimeAndSize,
+        ] {
+            assert_eq!(RetentionMode::from_str(&mode.to_string()).unwrap(), mode);
+        }
+        assert!(RetentionMode::from_str("forever").is_err());
+    }
+
+    #[test]
+    fn time_based_policy_with_max_partition_size_should_be_invalid() {
+        let policy = RetentionPolicy {
+            mode: RetentionMode::Time,
+            max_partition_size: Some(IggyByteSize::from(1024)),
+            pre_deletion_hook: None,
+        };
+        assert!(policy.validate().is_err());
+    }
+
+    #[test]
+    fn policy_with_non_http_pre_deletion_hook_should_be_invalid() {
+        let policy = RetentionPolicy::time().with_pre_deletion_hook("ftp://localhost/hooks");
+        assert!(policy.validate().is_err());
+    }
+
+    #[test]
+    fn policy_with_unknown_mode_should_not_be_deserialized() {
+        assert!(
+            RetentionPolicy::from_bytes(Bytes::from_static(&[4, 0, 0, 0, 0, 0, 0, 0, 0])).is_err()
+        );
+        assert!(RetentionPolicy::from_bytes(Bytes::from_static(&[2])).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::models::topic_schema::TopicSchema;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `Schema` represents the version of the schema registered in the schema registry.
+/// It consists of the following fields:
+/// - `id`: the globally unique identifier (numeric) of the schema, attached to the message headers by the producers.
+/// - `subject`: the subject (e.g. topic name) under which the schema is registered.
+/// - `version`: the version of the schema within the subject, starting from 1.
+/// - `created_at`: the timestamp when the schema was registered.
+/// - `schema`: the JSON Schema or protobuf definition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Schema {
+    /// The globally unique identifier (numeric) of the schema.
+    pub id: u32,
+    /// The subject under which the schema is registered.
+    pub subject: String,
+    /// The version of the schema within the subject, starting from 1.
+    pub version: u32,
+    /// The timestamp when the schema was registered.
+    pub created_at: IggyTimestamp,
+    /// The JSON Schema or protobuf definition.
+    pub schema: TopicSchema,
+}
@@ -0,0 +1,176 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `SegmentRolloverPolicy` is the optional policy attached to the topic, closing the partition segments by their age,
+/// in addition to the segment size configured on the server:
+/// - `max_age`: the age (since the segment was created) after which the segment is closed and the following messages are appended to the new one.
+///
+/// It makes the segments of the low-volume topics roll over predictably, so that they can be tiered, deleted by the retention or backed up.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SegmentRolloverPolicy {
+    /// The age after which the segment is closed.
+    pub max_age: IggyDuration,
+}
+
+impl SegmentRolloverPolicy {
+    /// Creates the segment rollover policy closing the segments older than given age.
+    pub fn max_age(max_age: IggyDuration) -> Self {
+        Self { max_age }
+    }
+
+    /// Returns true if the segment created at given timestamp should be rolled over.
+    pub fn is_rollover_due(&self, created_at: IggyTimestamp, now: IggyTimestamp) -> bool {
+        created_at.as_micros() + self.max_age.as_micros() <= now.as_micros()
+    }
+}
+
+impl Validatable<IggyError> for SegmentRolloverPolicy {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.max_age.is_zero() {
+            return Err(IggyError::InvalidSegmentRolloverPolicy(
+                "max age must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for SegmentRolloverPolicy {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(8);
+        bytes.put_u64_le(self.max_age.as_micros());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<SegmentRolloverPolicy, IggyError> {
+        if bytes.len() != 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let max_age = u64::from_le_bytes(
+            bytes[0..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(SegmentRolloverPolicy {
+            max_age: IggyDuration::from(max_age),
+        })
+    }
+}
+
+impl Display for SegmentRolloverPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "max_age: {}", self.max_age.as_human_time_string())
+    }
+}
+
+/// Writes the optional segment rollover policy (used by the topic commands and responses), prefixed with the presence flag and the length.
+pub(crate) fn write_optional_segment_rollover_policy(
+    policy: Option<&SegmentRolloverPolicy>,
+    bytes: &mut BytesMut,
+) {
+    match policy {
+        Some(policy) => {
+            let policy = policy.to_bytes();
+            bytes.put_u8(1);
+            bytes.put_u32_le(policy.len() as u32);
+            bytes.put_slice(&policy);
+        }
+        None => bytes.put_u8(0),
+    }
+}
+
+/// Reads the optional segment rollover policy written by `write_optional_segment_rollover_policy`, returning it along with the number of read bytes.
+pub(crate) fn read_optional_segment_rollover_policy(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<(Option<SegmentRolloverPolicy>, usize), IggyError> {
+    match bytes.get(position) {
+        None | Some(0) => Ok((None, 1)),
+        Some(1) => {
+            if bytes.len() < position + 5 {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy_length = u32::from_le_bytes(
+                bytes[position + 1..position + 5]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            if bytes.len() < position + 5 + policy_length {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy = SegmentRolloverPolicy::from_bytes(
+                bytes.slice(position + 5..position + 5 + policy_length),
+            )?;
+            Ok((Some(policy), 5 + policy_length))
+        }
+        Some(_) => Err(IggyError::InvalidCommand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_should_be_serialized_and_deserialized() {
+        let policy = SegmentRolloverPolicy::max_age(IggyDuration::new_from_secs(3600));
+        let deserialized = SegmentRolloverPolicy::from_bytes(policy.to_bytes()).unwrap();
+        assert_eq!(deserialized, policy);
+        assert!(deserialized.validate().is_ok());
+    }
+
+    #[test]
+    fn optional_policy_should_be_written_and_read() {
+        let policy = SegmentRolloverPolicy::max_age(IggyDuration::new_from_secs(60));
+        let mut bytes = BytesMut::new();
+        write_optional_segment_rollover_policy(Some(&policy), &mut bytes);
+        write_optional_segment_rollover_policy(None, &mut bytes);
+        let bytes = bytes.freeze();
+        let (read_policy, read_bytes) = read_optional_segment_rollover_policy(&bytes, 0).unwrap();
+        assert_eq!(read_policy, Some(policy));
+        let (read_policy, _) = read_optional_segment_rollover_policy(&bytes, read_bytes).unwrap();
+        assert!(read_policy.is_none());
+    }
+
+    #[test]
+    fn rollover_should_be_due_once_max_age_elapsed() {
+        let policy = SegmentRolloverPolicy::max_age(IggyDuration::new_from_secs(1));
+        let created_at = IggyTimestamp::from(1_000_000);
+        assert!(!policy.is_rollover_due(created_at, IggyTimestamp::from(1_999_999)));
+        assert!(policy.is_rollover_due(created_at, IggyTimestamp::from(2_000_000)));
+    }
+
+    #[test]
+    fn policy_with_zero_max_age_should_be_invalid() {
+        assert!(SegmentRolloverPolicy::max_age(IggyDuration::from(0))
+            .validate()
+            .is_err());
+    }
+}
@@ -0,0 +1,52 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use serde::{Deserialize, Serialize};
+
+/// `SegmentsVerification` represents the result of verifying the checksums of all the batches stored in the partition segments.
+/// It consists of the following fields:
+/// - `segments_count`: the number of the verified segments.
+/// - `batches_count`: the number of the verified batches.
+/// - `corrupted_batches`: the batches whose checksum doesn't match, which have been quarantined.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct SegmentsVerification {
+    /// The number of the verified segments.
+    pub segments_count: u32,
+    /// The number of the verified batches.
+    pub batches_count: u32,
+    /// The batches whose checksum doesn't match, which have been quarantined.
+    pub corrupted_batches: Vec<CorruptedBatchInfo>,
+}
+
+/// `CorruptedBatchInfo` represents the batch whose checksum doesn't match its content.
+/// It consists of the following fields:
+/// - `segment_start_offset`: the start offset of the segment containing the batch.
+/// - `base_offset`: the base offset of the batch.
+/// - `position`: the position of the batch in the segment log file.
+/// - `length`: the length of the batch in bytes, including its header.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CorruptedBatchInfo {
+    /// The start offset of the segment containing the batch.
+    pub segment_start_offset: u64,
+    /// The base offset of the batch.
+    pub base_offset: u64,
+    /// The position of the batch in the segment log file.
+    pub position: u64,
+    /// The length of the batch in bytes, including its header.
+    pub length: u64,
+}
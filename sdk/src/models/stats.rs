@@ -74,6 +74,13 @@ pub struct Stats {
     /// Cache metrics per partition
     #[serde(with = "cache_metrics_serializer")]
     pub cache_metrics: HashMap<CacheMetricsKey, CacheMetrics>,
+    /// The total size of the log and index files stored on the local disk, measured periodically.
+    #[serde(default)]
+    pub disk_usage: IggyByteSize,
+    /// The total number of the explicit syncs of the partitions to disk since the server start,
+    /// e.g. required by the `persisted` confirmation level or the fsync policy of the topic.
+    #[serde(default)]
+    pub fsyncs_count: u64,
 }
 
 /// Key for identifying a specific partition's cache metrics
@@ -181,6 +188,8 @@ impl Default for Stats {
             iggy_server_version: "unknown_iggy_version".to_string(),
             iggy_server_semver: None,
             cache_metrics: HashMap::new(),
+            disk_usage: 0.into(),
+            fsyncs_count: 0,
         }
     }
 }
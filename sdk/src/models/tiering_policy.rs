@@ -0,0 +1,233 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `TieringPolicy` is the optional tiering policy attached to the topic.
+/// When set (and the tiered storage is enabled in the server configuration), the closed segments are offloaded
+/// by the background task to the S3-compatible object storage, and transparently fetched (and cached locally) when polled.
+/// The closed segment is offloaded once it exceeds either of the thresholds:
+/// - `min_segment_age`: the optional minimum age of the segment (since its last message was appended).
+/// - `max_local_partition_size`: the optional maximum size of the segments kept locally by a single partition, the oldest ones are offloaded first.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TieringPolicy {
+    /// The optional minimum age of the closed segment to be offloaded.
+    #[serde(default)]
+    pub min_segment_age: Option<IggyDuration>,
+    /// The optional maximum size of the segments kept locally by a single partition.
+    #[serde(default)]
+    pub max_local_partition_size: Option<IggyByteSize>,
+}
+
+impl TieringPolicy {
+    /// Creates the tiering policy offloading the closed segments older than the given age.
+    pub fn age(min_segment_age: IggyDuration) -> Self {
+        Self {
+            min_segment_age: Some(min_segment_age),
+            max_local_partition_size: None,
+        }
+    }
+
+    /// Creates the tiering policy offloading the oldest closed segments once the partition exceeds the given local size.
+    pub fn size(max_local_partition_size: IggyByteSize) -> Self {
+        Self {
+            min_segment_age: None,
+            max_local_partition_size: Some(max_local_partition_size),
+        }
+    }
+}
+
+impl Validatable<IggyError> for TieringPolicy {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.min_segment_age.is_none() && self.max_local_partition_size.is_none() {
+            return Err(IggyError::InvalidTieringPolicy(
+                "either min segment age or max local partition size must be set".to_string(),
+            ));
+        }
+
+        if self.min_segment_age.is_some_and(|age| age.is_zero()) {
+            return Err(IggyError::InvalidTieringPolicy(
+                "min segment age must be greater than 0".to_string(),
+            ));
+        }
+
+        if self
+            .max_local_partition_size
+            .is_some_and(|size| size.as_bytes_u64() == 0)
+        {
+            return Err(IggyError::InvalidTieringPolicy(
+                "max local partition size must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for TieringPolicy {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(16);
+        bytes.put_u64_le(self.min_segment_age.map_or(0, |age| age.as_micros()));
+        bytes.put_u64_le(
+            self.max_local_partition_size
+                .map_or(0, |size| size.as_bytes_u64()),
+        );
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<TieringPolicy, IggyError> {
+        if bytes.len() != 16 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let min_segment_age = u64::from_le_bytes(
+            bytes[0..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let max_local_partition_size = u64::from_le_bytes(
+            bytes[8..16]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(TieringPolicy {
+            min_segment_age: match min_segment_age {
+                0 => None,
+                age => Some(IggyDuration::from(age)),
+            },
+            max_local_partition_size: match max_local_partition_size {
+                0 => None,
+                size => Some(IggyByteSize::from(size)),
+            },
+        })
+    }
+}
+
+impl Display for TieringPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min_segment_age: {}, max_local_partition_size: {}",
+            self.min_segment_age
+                .map_or("none".to_string(), |age| age.as_human_time_string()),
+            self.max_local_partition_size
+                .map_or("none".to_string(), |size| size.as_human_string())
+        )
+    }
+}
+
+/// Writes the optional tiering policy (used by the topic commands and responses), prefixed with the presence flag and the length.
+pub(crate) fn write_optional_tiering_policy(policy: Option<&TieringPolicy>, bytes: &mut BytesMut) {
+    match policy {
+        Some(policy) => {
+            let policy = policy.to_bytes();
+            bytes.put_u8(1);
+            bytes.put_u32_le(policy.len() as u32);
+            bytes.put_slice(&policy);
+        }
+        None => bytes.put_u8(0),
+    }
+}
+
+/// Reads the optional tiering policy written by `write_optional_tiering_policy`, returning it along with the number of read bytes.
+pub(crate) fn read_optional_tiering_policy(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<(Option<TieringPolicy>, usize), IggyError> {
+    match bytes.get(position) {
+        None | Some(0) => Ok((None, 1)),
+        Some(1) => {
+            if bytes.len() < position + 5 {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy_length = u32::from_le_bytes(
+                bytes[position + 1..position + 5]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            if bytes.len() < position + 5 + policy_length {
+                return Err(IggyError::InvalidCommand);
+            }
+            let policy =
+                TieringPolicy::from_bytes(bytes.slice(position + 5..position + 5 + policy_length))?;
+            Ok((Some(policy), 5 + policy_length))
+        }
+        Some(_) => Err(IggyError::InvalidCommand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_should_be_serialized_and_deserialized() {
+        for policy in [
+            TieringPolicy::age(IggyDuration::new_from_secs(3600)),
+            TieringPolicy::size(IggyByteSize::from(1_000_000)),
+            TieringPolicy {
+                min_segment_age: Some(IggyDuration::new_from_secs(60)),
+                max_local_partition_size: Some(IggyByteSize::from(5_000_000)),
+            },
+        ] {
+            let deserialized = TieringPolicy::from_bytes(policy.to_bytes()).unwrap();
+            assert_eq!(deserialized, policy);
+            assert!(deserialized.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn optional_policy_should_be_written_and_read() {
+        let policy = TieringPolicy::size(IggyByteSize::from(1024));
+        let mut bytes = BytesMut::new();
+        write_optional_tiering_policy(Some(&policy), &mut bytes);
+        write_optional_tiering_policy(None, &mut bytes);
+        let bytes = bytes.freeze();
+        let (read_policy, read_bytes) = read_optional_tiering_policy(&bytes, 0).unwrap();
+        assert_eq!(read_policy, Some(policy));
+        let (read_policy, _) = read_optional_tiering_policy(&bytes, read_bytes).unwrap();
+        assert!(read_policy.is_none());
+    }
+
+    #[test]
+    fn policy_without_thresholds_should_be_invalid() {
+        let policy = TieringPolicy {
+            min_segment_age: None,
+            max_local_partition_size: None,
+        };
+        assert!(policy.validate().is_err());
+    }
+
+    #[test]
+    fn policy_with_zero_thresholds_should_be_invalid() {
+        assert!(TieringPolicy::age(IggyDuration::from(0))
+            .validate()
+            .is_err());
+        assert!(TieringPolicy::size(IggyByteSize::from(0))
+            .validate()
+            .is_err());
+    }
+}
@@ -18,6 +18,7 @@
 
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::models::partition::Partition;
+use crate::models::topic_options::TopicOptions;
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::timestamp::IggyTimestamp;
@@ -36,6 +37,7 @@ use serde::{Deserialize, Serialize};
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Topic {
     /// The unique identifier (numeric) of the topic.
     pub id: u32,
@@ -72,7 +74,18 @@ pub struct Topic {
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
 /// - `partitions`: the collection of partitions in the topic.
+/// - `schema`: the optional schema used to validate the message payloads.
+/// - `dead_letter_policy`: the optional policy moving the repeatedly rejected messages to the dead letter topic.
+/// - `sampling_policy`: the optional policy copying a fraction of the appended messages into the diagnostics topic.
+/// - `compaction_policy`: the optional `compact` cleanup policy retaining only the latest message per key.
+/// - `retention_policy`: the optional policy deciding whether the oldest segments are deleted by the message expiry, the size limits, or both.
+/// - `tiering_policy`: the optional policy offloading the closed segments to the object storage.
+/// - `fsync_policy`: the effective policy deciding when the persisted messages are synced to the disk.
+/// - `segment_rollover_policy`: the optional policy closing the segments by their age.
+/// - `queue_policy`: the optional policy switching the topic to the queue mode, with the individually acknowledged messages.
+/// - `disk_usage`: the size of the log and index files of the topic stored on the local disk, measured periodically.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TopicDetails {
     /// The unique identifier (numeric) of the topic.
     pub id: u32,
@@ -97,4 +110,10 @@ pub struct TopicDetails {
     pub partitions_count: u32,
     /// The collection of partitions in the topic.
     pub partitions: Vec<Partition>,
+    /// The optional settings (schema and policies) of the topic, where the fsync policy is the effective one, either set for the topic or derived from the server configuration.
+    #[serde(flatten)]
+    pub options: TopicOptions,
+    /// The size of the log and index files of the topic stored on the local disk, `0` if the storage usage accounting is disabled.
+    #[serde(default)]
+    pub disk_usage: IggyByteSize,
 }
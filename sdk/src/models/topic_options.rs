@@ -0,0 +1,343 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::models::compaction_policy::{
+    read_optional_compaction_policy, write_optional_compaction_policy, CompactionPolicy,
+};
+use crate::models::dead_letter_policy::{
+    read_optional_dead_letter_policy, write_optional_dead_letter_policy, DeadLetterPolicy,
+};
+use crate::models::fsync_policy::{
+    read_optional_fsync_policy, write_optional_fsync_policy, FsyncPolicy,
+};
+use crate::models::message_sampling_policy::{
+    read_optional_sampling_policy, write_optional_sampling_policy, MessageSamplingPolicy,
+};
+use crate::models::queue_policy::{
+    read_optional_queue_policy, write_optional_queue_policy, QueuePolicy,
+};
+use crate::models::retention_policy::{
+    read_optional_retention_policy, write_optional_retention_policy, RetentionPolicy,
+};
+use crate::models::segment_rollover_policy::{
+    read_optional_segment_rollover_policy, write_optional_segment_rollover_policy,
+    SegmentRolloverPolicy,
+};
+use crate::models::tiering_policy::{
+    read_optional_tiering_policy, write_optional_tiering_policy, TieringPolicy,
+};
+use crate::models::topic_schema::{read_optional_schema, write_optional_schema, TopicSchema};
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `TopicOptions` groups the optional settings of the topic, used when creating or updating it:
+/// - `schema`: the optional schema (JSON Schema or protobuf descriptor) used to validate the message payloads.
+/// - `dead_letter_policy`: the optional policy moving the repeatedly rejected messages to the dead letter topic.
+/// - `sampling_policy`: the optional policy copying a fraction of the appended messages into the diagnostics topic.
+/// - `compaction_policy`: the optional `compact` cleanup policy retaining only the latest message per key, if `None` then the default `delete` policy is used.
+/// - `retention_policy`: the optional retention policy (time, size or both), if `None` then only the message expiry is used.
+/// - `tiering_policy`: the optional policy offloading the closed segments to the object storage, if `None` then all the segments are kept locally.
+/// - `fsync_policy`: the optional policy deciding when the persisted messages are synced to the disk, if `None` then the `enforce_fsync` server configuration is used.
+/// - `segment_rollover_policy`: the optional policy closing the segments by their age, if `None` then the segments are closed only once full.
+/// - `queue_policy`: the optional policy switching the topic to the queue mode with the individually acknowledged messages, if `None` then the topic is consumed by the offsets.
+///
+/// The default options leave all of them unset.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TopicOptions {
+    /// The optional schema used to validate the message payloads.
+    #[serde(default)]
+    pub schema: Option<TopicSchema>,
+    /// The optional policy moving the repeatedly rejected messages to the dead letter topic.
+    #[serde(default)]
+    pub dead_letter_policy: Option<DeadLetterPolicy>,
+    /// The optional policy copying a fraction of the appended messages into the diagnostics topic.
+    #[serde(default)]
+    pub sampling_policy: Option<MessageSamplingPolicy>,
+    /// The optional `compact` cleanup policy retaining only the latest message per key.
+    #[serde(default)]
+    pub compaction_policy: Option<CompactionPolicy>,
+    /// The optional retention policy (time, size or both).
+    #[serde(default)]
+    pub retention_policy: Option<RetentionPolicy>,
+    /// The optional policy offloading the closed segments to the object storage.
+    #[serde(default)]
+    pub tiering_policy: Option<TieringPolicy>,
+    /// The optional policy deciding when the persisted messages are synced to the disk.
+    #[serde(default)]
+    pub fsync_policy: Option<FsyncPolicy>,
+    /// The optional policy closing the segments by their age.
+    #[serde(default)]
+    pub segment_rollover_policy: Option<SegmentRolloverPolicy>,
+    /// The optional policy switching the topic to the queue mode.
+    #[serde(default)]
+    pub queue_policy: Option<QueuePolicy>,
+}
+
+impl TopicOptions {
+    /// Returns `true` if none of the options is set.
+    pub fn is_empty(&self) -> bool {
+        self == &TopicOptions::default()
+    }
+}
+
+impl Validatable<IggyError> for TopicOptions {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(schema) = &self.schema {
+            schema.validate()?;
+        }
+
+        if let Some(dead_letter_policy) = &self.dead_letter_policy {
+            dead_letter_policy.validate()?;
+        }
+
+        if let Some(sampling_policy) = &self.sampling_policy {
+            sampling_policy.validate()?;
+        }
+
+        if let Some(compaction_policy) = &self.compaction_policy {
+            compaction_policy.validate()?;
+        }
+
+        if let Some(retention_policy) = &self.retention_policy {
+            retention_policy.validate()?;
+        }
+
+        if let Some(tiering_policy) = &self.tiering_policy {
+            tiering_policy.validate()?;
+        }
+
+        if let Some(fsync_policy) = &self.fsync_policy {
+            fsync_policy.validate()?;
+        }
+
+        if let Some(segment_rollover_policy) = &self.segment_rollover_policy {
+            segment_rollover_policy.validate()?;
+        }
+
+        if let Some(queue_policy) = &self.queue_policy {
+            queue_policy.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for TopicOptions {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        write_optional_schema(self.schema.as_ref(), &mut bytes);
+        write_optional_dead_letter_policy(self.dead_letter_policy.as_ref(), &mut bytes);
+        write_optional_sampling_policy(self.sampling_policy.as_ref(), &mut bytes);
+        write_optional_compaction_policy(self.compaction_policy.as_ref(), &mut bytes);
+        write_optional_retention_policy(self.retention_policy.as_ref(), &mut bytes);
+        write_optional_tiering_policy(self.tiering_policy.as_ref(), &mut bytes);
+        write_optional_fsync_policy(self.fsync_policy.as_ref(), &mut bytes);
+        write_optional_segment_rollover_policy(self.segment_rollover_policy.as_ref(), &mut bytes);
+        write_optional_queue_policy(self.queue_policy.as_ref(), &mut bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<TopicOptions, IggyError> {
+        let mut position = 0;
+        let (schema, read_bytes) = read_optional_schema(&bytes, position)?;
+        position += read_bytes;
+        let (dead_letter_policy, read_bytes) = read_optional_dead_letter_policy(&bytes, position)?;
+        position += read_bytes;
+        let (sampling_policy, read_bytes) = read_optional_sampling_policy(&bytes, position)?;
+        position += read_bytes;
+        let (compaction_policy, read_bytes) = read_optional_compaction_policy(&bytes, position)?;
+        position += read_bytes;
+        let (retention_policy, read_bytes) = read_optional_retention_policy(&bytes, position)?;
+        position += read_bytes;
+        let (tiering_policy, read_bytes) = read_optional_tiering_policy(&bytes, position)?;
+        position += read_bytes;
+        let (fsync_policy, read_bytes) = read_optional_fsync_policy(&bytes, position)?;
+        position += read_bytes;
+        let (segment_rollover_policy, read_bytes) =
+            read_optional_segment_rollover_policy(&bytes, position)?;
+        position += read_bytes;
+        let (queue_policy, read_bytes) = read_optional_queue_policy(&bytes, position)?;
+        position += read_bytes;
+        if position != bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(TopicOptions {
+            schema,
+            dead_letter_policy,
+            sampling_policy,
+            compaction_policy,
+            retention_policy,
+            tiering_policy,
+            fsync_policy,
+            segment_rollover_policy,
+            queue_policy,
+        })
+    }
+}
+
+impl Display for TopicOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}",
+            self.schema
+                .as_ref()
+                .map_or("no_schema".to_string(), ToString::to_string),
+            self.dead_letter_policy
+                .as_ref()
+                .map_or("no_dead_letter_policy".to_string(), ToString::to_string),
+            self.sampling_policy
+                .as_ref()
+                .map_or("no_sampling_policy".to_string(), ToString::to_string),
+            self.compaction_policy
+                .as_ref()
+                .map_or("no_compaction_policy".to_string(), ToString::to_string),
+            self.retention_policy
+                .as_ref()
+                .map_or("no_retention_policy".to_string(), ToString::to_string),
+            self.tiering_policy
+                .as_ref()
+                .map_or("no_tiering_policy".to_string(), ToString::to_string),
+            self.fsync_policy
+                .as_ref()
+                .map_or("no_fsync_policy".to_string(), ToString::to_string),
+            self.segment_rollover_policy.as_ref().map_or(
+                "no_segment_rollover_policy".to_string(),
+                ToString::to_string
+            ),
+            self.queue_policy
+                .as_ref()
+                .map_or("no_queue_policy".to_string(), ToString::to_string)
+        )
+    }
+}
+
+/// Writes the topic options (used by the topic commands and responses) as a single section, prefixed with the presence flag and the length.
+/// The section is omitted (only the presence flag is written) when none of the options is set.
+pub fn write_optional_topic_options(options: &TopicOptions, bytes: &mut BytesMut) {
+    if options.is_empty() {
+        bytes.put_u8(0);
+        return;
+    }
+
+    let options = options.to_bytes();
+    bytes.put_u8(1);
+    bytes.put_u32_le(options.len() as u32);
+    bytes.put_slice(&options);
+}
+
+/// Reads the topic options written by `write_optional_topic_options`, returning them along with the number of read bytes.
+/// The missing section results in the default options.
+pub fn read_optional_topic_options(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<(TopicOptions, usize), IggyError> {
+    match bytes.get(position) {
+        None => Ok((TopicOptions::default(), 0)),
+        Some(0) => Ok((TopicOptions::default(), 1)),
+        Some(1) => {
+            let options_length = u32::from_le_bytes(
+                bytes
+                    .get(position + 1..position + 5)
+                    .ok_or(IggyError::InvalidCommand)?
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            let options = bytes
+                .get(position + 5..position + 5 + options_length)
+                .ok_or(IggyError::InvalidCommand)?;
+            let options = TopicOptions::from_bytes(Bytes::copy_from_slice(options))?;
+            Ok((options, 5 + options_length))
+        }
+        Some(_) => Err(IggyError::InvalidCommand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifier::Identifier;
+    use crate::utils::duration::IggyDuration;
+
+    #[test]
+    fn should_write_only_presence_flag_for_default_options() {
+        let mut bytes = BytesMut::new();
+        write_optional_topic_options(&TopicOptions::default(), &mut bytes);
+        assert_eq!(bytes.as_ref(), &[0]);
+
+        let (options, read_bytes) = read_optional_topic_options(&bytes.freeze(), 0).unwrap();
+        assert!(options.is_empty());
+        assert_eq!(read_bytes, 1);
+    }
+
+    #[test]
+    fn should_be_written_and_read_as_single_section() {
+        let options = TopicOptions {
+            dead_letter_policy: Some(DeadLetterPolicy::new(
+                Identifier::numeric(1).unwrap(),
+                Identifier::named("dlq").unwrap(),
+                3,
+            )),
+            fsync_policy: Some(FsyncPolicy {
+                every_n_messages: Some(1000),
+                interval: None,
+            }),
+            queue_policy: Some(QueuePolicy::visibility_timeout(
+                IggyDuration::new_from_secs(30),
+            )),
+            ..Default::default()
+        };
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(7);
+        write_optional_topic_options(&options, &mut bytes);
+        bytes.put_u8(7);
+        let bytes = bytes.freeze();
+
+        let (deserialized, read_bytes) = read_optional_topic_options(&bytes, 1).unwrap();
+        assert_eq!(deserialized, options);
+        assert_eq!(read_bytes, bytes.len() - 2);
+    }
+
+    #[test]
+    fn should_default_when_section_is_missing() {
+        let (options, read_bytes) = read_optional_topic_options(&Bytes::new(), 0).unwrap();
+        assert!(options.is_empty());
+        assert_eq!(read_bytes, 0);
+    }
+
+    #[test]
+    fn should_fail_to_read_truncated_section() {
+        let options = TopicOptions {
+            queue_policy: Some(QueuePolicy::visibility_timeout(
+                IggyDuration::new_from_secs(30),
+            )),
+            ..Default::default()
+        };
+        let mut bytes = BytesMut::new();
+        write_optional_topic_options(&options, &mut bytes);
+        let bytes = bytes.freeze();
+
+        assert!(read_optional_topic_options(&bytes.slice(..bytes.len() - 1), 0).is_err());
+    }
+}
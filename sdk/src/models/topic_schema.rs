@@ -0,0 +1,306 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::base64::Base64;
+use serde_with::serde_as;
+use std::fmt::Display;
+use std::str::{from_utf8, FromStr};
+
+/// The maximum size of the schema definition in bytes.
+pub const MAX_SCHEMA_DEFINITION_SIZE: usize = 1024 * 1024;
+/// The maximum length of the protobuf message type name.
+pub const MAX_MESSAGE_TYPE_LENGTH: usize = 255;
+
+/// `SchemaKind` represents the format of the topic schema.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum SchemaKind {
+    /// The payload must be a JSON document conforming to the JSON Schema.
+    Json,
+    /// The payload must be a protobuf message described by the file descriptor set.
+    Protobuf,
+}
+
+impl FromStr for SchemaKind {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "json" => Ok(SchemaKind::Json),
+            "protobuf" => Ok(SchemaKind::Protobuf),
+            _ => Err(IggyError::InvalidTopicSchema(format!(
+                "unknown schema kind: {input}"
+            ))),
+        }
+    }
+}
+
+impl Display for SchemaKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaKind::Json => write!(f, "json"),
+            SchemaKind::Protobuf => write!(f, "protobuf"),
+        }
+    }
+}
+
+impl SchemaKind {
+    /// Returns the code of the schema kind.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaKind::Json => 1,
+            SchemaKind::Protobuf => 2,
+        }
+    }
+
+    /// Returns the schema kind from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(SchemaKind::Json),
+            2 => Ok(SchemaKind::Protobuf),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+/// `TopicSchema` is the optional schema attached to the topic, used by the server to validate the payloads of the appended messages.
+/// It consists of the following fields:
+/// - `kind`: the format of the schema.
+/// - `message_type`: the fully qualified name of the protobuf message, required only for the protobuf schema.
+/// - `definition`: the JSON Schema document or the serialized protobuf `FileDescriptorSet`.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TopicSchema {
+    /// The format of the schema.
+    pub kind: SchemaKind,
+    /// The fully qualified name of the protobuf message, required only for the protobuf schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_type: Option<String>,
+    /// The JSON Schema document or the serialized protobuf `FileDescriptorSet`.
+    #[serde_as(as = "Base64")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
+    pub definition: Bytes,
+}
+
+impl TopicSchema {
+    /// Creates the JSON Schema based topic schema.
+    pub fn json(definition: &str) -> Self {
+        Self {
+            kind: SchemaKind::Json,
+            message_type: None,
+            definition: Bytes::copy_from_slice(definition.as_bytes()),
+        }
+    }
+
+    /// Creates the protobuf based topic schema from the serialized `FileDescriptorSet` and the fully qualified message name.
+    pub fn protobuf(descriptor_set: Bytes, message_type: &str) -> Self {
+        Self {
+            kind: SchemaKind::Protobuf,
+            message_type: Some(message_type.to_string()),
+            definition: descriptor_set,
+        }
+    }
+}
+
+impl Validatable<IggyError> for TopicSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.definition.is_empty() || self.definition.len() > MAX_SCHEMA_DEFINITION_SIZE {
+            return Err(IggyError::InvalidTopicSchema(format!(
+                "definition size must be between 1 and {MAX_SCHEMA_DEFINITION_SIZE} bytes"
+            )));
+        }
+
+        match (self.kind, &self.message_type) {
+            (SchemaKind::Json, None) => Ok(()),
+            (SchemaKind::Json, Some(_)) => Err(IggyError::InvalidTopicSchema(
+                "message type is supported only for protobuf schema".to_string(),
+            )),
+            (SchemaKind::Protobuf, Some(message_type))
+                if !message_type.is_empty() && message_type.len() <= MAX_MESSAGE_TYPE_LENGTH =>
+            {
+                Ok(())
+            }
+            (SchemaKind::Protobuf, _) => Err(IggyError::InvalidTopicSchema(format!(
+                "message type length must be between 1 and {MAX_MESSAGE_TYPE_LENGTH} characters for protobuf schema"
+            ))),
+        }
+    }
+}
+
+impl BytesSerializable for TopicSchema {
+    fn to_bytes(&self) -> Bytes {
+        let message_type = self.message_type.as_deref().unwrap_or_default();
+        let mut bytes = BytesMut::with_capacity(6 + message_type.len() + self.definition.len());
+        bytes.put_u8(self.kind.as_code());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(message_type.len() as u8);
+        bytes.put_slice(message_type.as_bytes());
+        bytes.put_u32_le(self.definition.len() as u32);
+        bytes.put_slice(&self.definition);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<TopicSchema, IggyError> {
+        if bytes.len() < 6 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let kind = SchemaKind::from_code(bytes[0])?;
+        let message_type_length = bytes[1] as usize;
+        let mut position = 2;
+        if bytes.len() < position + message_type_length + 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let message_type = match message_type_length {
+            0 => None,
+            length => Some(
+                from_utf8(&bytes[position..position + length])
+                    .map_err(|_| IggyError::InvalidUtf8)?
+                    .to_string(),
+            ),
+        };
+        position += message_type_length;
+        let definition_length = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 4;
+        if bytes.len() != position + definition_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(TopicSchema {
+            kind,
+            message_type,
+            definition: bytes.slice(position..position + definition_length),
+        })
+    }
+}
+
+impl Display for TopicSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.message_type {
+            Some(message_type) => write!(
+                f,
+                "{}:{}({} B)",
+                self.kind,
+                message_type,
+                self.definition.len()
+            ),
+            None => write!(f, "{}({} B)", self.kind, self.definition.len()),
+        }
+    }
+}
+
+/// Writes the optional topic schema (used by the topic commands and responses), prefixed with the presence flag and the length.
+pub(crate) fn write_optional_schema(schema: Option<&TopicSchema>, bytes: &mut BytesMut) {
+    match schema {
+        Some(schema) => {
+            let schema = schema.to_bytes();
+            bytes.put_u8(1);
+            bytes.put_u32_le(schema.len() as u32);
+            bytes.put_slice(&schema);
+        }
+        None => bytes.put_u8(0),
+    }
+}
+
+/// Reads the optional topic schema written by `write_optional_schema`, returning it along with the number of read bytes.
+pub(crate) fn read_optional_schema(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<(Option<TopicSchema>, usize), IggyError> {
+    match bytes.get(position) {
+        None | Some(0) => Ok((None, 1)),
+        Some(1) => {
+            if bytes.len() < position + 5 {
+                return Err(IggyError::InvalidCommand);
+            }
+            let schema_length = u32::from_le_bytes(
+                bytes[position + 1..position + 5]
+                    .try_into()
+                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
+            ) as usize;
+            if bytes.len() < position + 5 + schema_length {
+                return Err(IggyError::InvalidCommand);
+            }
+            let schema =
+                TopicSchema::from_bytes(bytes.slice(position + 5..position + 5 + schema_length))?;
+            Ok((Some(schema), 5 + schema_length))
+        }
+        Some(_) => Err(IggyError::InvalidCommand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_schema_should_be_serialized_and_deserialized() {
+        let schema = TopicSchema::json(r#"{"type":"object"}"#);
+        let deserialized = TopicSchema::from_bytes(schema.to_bytes()).unwrap();
+        assert_eq!(deserialized, schema);
+        assert!(deserialized.validate().is_ok());
+    }
+
+    #[test]
+    fn protobuf_schema_should_be_serialized_and_deserialized() {
+        let schema = TopicSchema::protobuf(Bytes::from_static(&[1, 2, 3]), "orders.OrderCreated");
+        let deserialized = TopicSchema::from_bytes(schema.to_bytes()).unwrap();
+        assert_eq!(deserialized, schema);
+        assert!(deserialized.validate().is_ok());
+    }
+
+    #[test]
+    fn optional_schema_should_be_written_and_read() {
+        let schema = TopicSchema::json(r#"{"type":"string"}"#);
+        let mut bytes = BytesMut::new();
+        write_optional_schema(Some(&schema), &mut bytes);
+        write_optional_schema(None, &mut bytes);
+        let bytes = bytes.freeze();
+        let (read_schema, read_bytes) = read_optional_schema(&bytes, 0).unwrap();
+        assert_eq!(read_schema, Some(schema));
+        let (read_schema, _) = read_optional_schema(&bytes, read_bytes).unwrap();
+        assert!(read_schema.is_none());
+    }
+
+    #[test]
+    fn protobuf_schema_without_message_type_should_be_invalid() {
+        let schema = TopicSchema {
+            kind: SchemaKind::Protobuf,
+            message_type: None,
+            definition: Bytes::from_static(&[1, 2, 3]),
+        };
+        assert!(schema.validate().is_err());
+    }
+
+    #[test]
+    fn empty_schema_definition_should_be_invalid() {
+        let schema = TopicSchema::json("");
+        assert!(schema.validate().is_err());
+    }
+}
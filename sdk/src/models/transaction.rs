@@ -0,0 +1,140 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// The header holding the ID (u64) of the transaction within which the message was sent.
+/// Such a message is returned when polling only once its transaction has been committed.
+pub const TRANSACTION_ID_HEADER: &str = "iggy-transaction-id";
+/// The header holding the code (u8) of the transaction marker, which is appended by the server
+/// to every partition written within the transaction, once it's committed or aborted.
+/// The markers are the control messages, which are never returned when polling.
+pub const TRANSACTION_MARKER_HEADER: &str = "iggy-transaction-marker";
+
+/// `TransactionMarker` represents the outcome of the transaction recorded in the partition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransactionMarker {
+    /// The messages sent within the transaction are visible to the consumers.
+    Commit,
+    /// The messages sent within the transaction are never visible to the consumers.
+    Abort,
+}
+
+impl TransactionMarker {
+    /// Returns the code of the transaction marker.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            TransactionMarker::Commit => 1,
+            TransactionMarker::Abort => 2,
+        }
+    }
+
+    /// Returns the transaction marker from the given code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(TransactionMarker::Commit),
+            2 => Ok(TransactionMarker::Abort),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for TransactionMarker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransactionMarker::Commit => write!(f, "commit"),
+            TransactionMarker::Abort => write!(f, "abort"),
+        }
+    }
+}
+
+/// Returns the ID of the transaction, if the headers contain a valid transaction ID header.
+pub fn get_transaction_id(headers: &HashMap<HeaderKey, HeaderValue>) -> Option<u64> {
+    headers
+        .get(&HeaderKey::new(TRANSACTION_ID_HEADER).unwrap())?
+        .as_uint64()
+        .ok()
+}
+
+/// Sets the ID of the transaction within which the message is sent.
+pub fn set_transaction_id(
+    headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    transaction_id: u64,
+) -> Result<(), IggyError> {
+    headers.get_or_insert_with(HashMap::new).insert(
+        HeaderKey::new(TRANSACTION_ID_HEADER)?,
+        HeaderValue::from_uint64(transaction_id)?,
+    );
+    Ok(())
+}
+
+/// Returns the transaction marker, if the headers contain a valid transaction marker header.
+pub fn get_transaction_marker(
+    headers: &HashMap<HeaderKey, HeaderValue>,
+) -> Option<TransactionMarker> {
+    let code = headers
+        .get(&HeaderKey::new(TRANSACTION_MARKER_HEADER).unwrap())?
+        .as_uint8()
+        .ok()?;
+    TransactionMarker::from_code(code).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_id_should_be_set_and_read_from_headers() {
+        let mut headers = None;
+        set_transaction_id(&mut headers, 7).unwrap();
+        let headers = headers.unwrap();
+        assert_eq!(get_transaction_id(&headers), Some(7));
+        assert_eq!(get_transaction_marker(&headers), None);
+        assert_eq!(get_transaction_id(&HashMap::new()), None);
+    }
+
+    #[test]
+    fn transaction_marker_should_be_read_from_headers() {
+        let mut headers = None;
+        set_transaction_id(&mut headers, 7).unwrap();
+        let mut headers = headers.unwrap();
+        headers.insert(
+            HeaderKey::new(TRANSACTION_MARKER_HEADER).unwrap(),
+            HeaderValue::from_uint8(TransactionMarker::Abort.as_code()).unwrap(),
+        );
+        assert_eq!(get_transaction_id(&headers), Some(7));
+        assert_eq!(
+            get_transaction_marker(&headers),
+            Some(TransactionMarker::Abort)
+        );
+    }
+
+    #[test]
+    fn transaction_marker_should_be_mapped_from_code() {
+        for marker in [TransactionMarker::Commit, TransactionMarker::Abort] {
+            assert_eq!(
+                TransactionMarker::from_code(marker.as_code()).unwrap(),
+                marker
+            );
+        }
+        assert!(TransactionMarker::from_code(0).is_err());
+    }
+}
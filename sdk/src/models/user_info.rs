@@ -35,6 +35,7 @@ pub type AtomicUserId = AtomicU32;
 /// - `status`: the status of the user.
 /// - `username`: the username of the user.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserInfo {
     /// The unique identifier (numeric) of the user.
     pub id: UserId,
@@ -54,6 +55,7 @@ pub struct UserInfo {
 /// - `username`: the username of the user.
 /// - `permissions`: the optional permissions of the user.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserInfoDetails {
     /// The unique identifier (numeric) of the user.
     pub id: UserId,
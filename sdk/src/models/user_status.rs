@@ -24,6 +24,7 @@ use std::str::FromStr;
 /// `UserStatus` represents the status of the user.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum UserStatus {
     /// The user is active.
     #[default]
@@ -20,6 +20,7 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::{Command, CREATE_PARTITIONS_CODE};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::partitions::key_routing_policy::KeyRoutingPolicy;
 use crate::partitions::MAX_PARTITIONS_COUNT;
 use crate::utils::sizeable::Sizeable;
 use crate::validatable::Validatable;
@@ -32,6 +33,7 @@ use std::fmt::Display;
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitions_count` - number of partitions in the topic to create, max value is 1000.
+/// - `key_routing_policy` - policy deciding whether the messages keys already routed to the existing partitions are re-hashed across all the partitions or preserved.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreatePartitions {
     /// Unique stream ID (numeric or name).
@@ -42,6 +44,9 @@ pub struct CreatePartitions {
     pub topic_id: Identifier,
     /// Number of partitions in the topic to create, max value is 1000.
     pub partitions_count: u32,
+    /// Policy deciding whether the messages keys already routed to the existing partitions are re-hashed across all the partitions or preserved.
+    #[serde(default)]
+    pub key_routing_policy: KeyRoutingPolicy,
 }
 
 impl Command for CreatePartitions {
@@ -56,6 +61,7 @@ impl Default for CreatePartitions {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             partitions_count: 1,
+            key_routing_policy: KeyRoutingPolicy::default(),
         }
     }
 }
@@ -74,10 +80,11 @@ impl BytesSerializable for CreatePartitions {
     fn to_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.to_bytes();
         let topic_id_bytes = self.topic_id.to_bytes();
-        let mut bytes = BytesMut::with_capacity(4 + stream_id_bytes.len() + topic_id_bytes.len());
+        let mut bytes = BytesMut::with_capacity(5 + stream_id_bytes.len() + topic_id_bytes.len());
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
         bytes.put_u32_le(self.partitions_count);
+        bytes.put_u8(self.key_routing_policy.as_code());
         bytes.freeze()
     }
 
@@ -96,10 +103,17 @@ impl BytesSerializable for CreatePartitions {
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
+        position += 4;
+        // The key routing policy is optional to remain compatible with the clients not sending it.
+        let key_routing_policy = match bytes.get(position) {
+            Some(code) => KeyRoutingPolicy::from_code(*code)?,
+            None => KeyRoutingPolicy::default(),
+        };
         let command = CreatePartitions {
             stream_id,
             topic_id,
             partitions_count,
+            key_routing_policy,
         };
         Ok(command)
     }
@@ -109,8 +123,8 @@ impl Display for CreatePartitions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}",
-            self.stream_id, self.topic_id, self.partitions_count
+            "{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.partitions_count, self.key_routing_policy
         )
     }
 }
@@ -126,6 +140,7 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             partitions_count: 3,
+            key_routing_policy: KeyRoutingPolicy::PreserveExisting,
         };
 
         let bytes = command.to_bytes();
@@ -136,11 +151,13 @@ mod tests {
         position += topic_id.get_size_bytes().as_bytes_usize();
         let partitions_count =
             u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let key_routing_policy = KeyRoutingPolicy::from_code(bytes[position + 4]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(partitions_count, command.partitions_count);
+        assert_eq!(key_routing_policy, command.key_routing_policy);
     }
 
     #[test]
@@ -161,5 +178,19 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitions_count, partitions_count);
+        assert_eq!(command.key_routing_policy, KeyRoutingPolicy::Rehash);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_key_routing_policy() {
+        let command = CreatePartitions {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            partitions_count: 2,
+            key_routing_policy: KeyRoutingPolicy::PreserveExisting,
+        };
+
+        let deserialized = CreatePartitions::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
     }
 }
@@ -0,0 +1,105 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The policy deciding how the messages sent with `Partitioning::messages_key` are routed once the new partitions are added to the live topic.
+///
+/// The partition for the messages key is calculated as the hash of the key modulo the number of partitions,
+/// which means that changing the number of partitions would move most of the keys to different partitions,
+/// breaking the ordering guarantees for the messages with the same key (the already stored messages are never moved).
+/// The server remembers the partition to which each key was routed, so the policy decides what happens to these routes.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyRoutingPolicy {
+    /// All the keys are re-hashed across the new number of partitions, so the existing keys might be moved to the different (also new) partitions.
+    #[default]
+    Rehash,
+    /// The existing keys stay in their current partitions, and only the keys which have not been seen yet are hashed across all the partitions (including the new ones).
+    PreserveExisting,
+}
+
+impl KeyRoutingPolicy {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            KeyRoutingPolicy::Rehash => 1,
+            KeyRoutingPolicy::PreserveExisting => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(KeyRoutingPolicy::Rehash),
+            2 => Ok(KeyRoutingPolicy::PreserveExisting),
+            _ => Err(IggyError::InvalidKeyRoutingPolicy(code)),
+        }
+    }
+}
+
+impl FromStr for KeyRoutingPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "rehash" => Ok(KeyRoutingPolicy::Rehash),
+            "preserve_existing" => Ok(KeyRoutingPolicy::PreserveExisting),
+            _ => Err(format!("Unknown key routing policy: {}", s)),
+        }
+    }
+}
+
+impl Display for KeyRoutingPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeyRoutingPolicy::Rehash => write!(f, "rehash"),
+            KeyRoutingPolicy::PreserveExisting => write!(f, "preserve_existing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_converted_to_and_from_code() {
+        for policy in [KeyRoutingPolicy::Rehash, KeyRoutingPolicy::PreserveExisting] {
+            assert_eq!(
+                KeyRoutingPolicy::from_code(policy.as_code()).unwrap(),
+                policy
+            );
+        }
+        assert!(KeyRoutingPolicy::from_code(0).is_err());
+    }
+
+    #[test]
+    fn should_be_parsed_from_string() {
+        assert_eq!(
+            KeyRoutingPolicy::from_str("preserve-existing").unwrap(),
+            KeyRoutingPolicy::PreserveExisting
+        );
+        assert_eq!(
+            KeyRoutingPolicy::from_str("REHASH").unwrap(),
+            KeyRoutingPolicy::Rehash
+        );
+        assert!(KeyRoutingPolicy::from_str("sticky").is_err());
+    }
+}
@@ -18,5 +18,11 @@
 
 pub mod create_partitions;
 pub mod delete_partitions;
+pub mod get_partition;
+pub mod key_routing_policy;
+pub mod move_partition;
+pub mod update_partition;
 
+const MAX_NAME_LENGTH: usize = 255;
+const MAX_DIRECTORY_LENGTH: usize = 255;
 const MAX_PARTITIONS_COUNT: u32 = 1000;
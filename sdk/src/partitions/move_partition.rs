@@ -0,0 +1,200 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, MOVE_PARTITION_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::partitions::MAX_DIRECTORY_LENGTH;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `MovePartition` command is used to move the data of an existing partition to a different data directory (e.g. another disk).
+/// The segments are copied in the background, and the readers and writers are switched to the new location once all of them are copied.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - unique partition ID (numeric or name).
+/// - `directory` - target data directory, which must be either the system path or one of the data directories configured on the server, max length is 255 characters.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct MovePartition {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique partition ID (numeric or name).
+    #[serde(skip)]
+    pub partition_id: Identifier,
+    /// Target data directory, max length is 255 characters.
+    pub directory: String,
+}
+
+impl Command for MovePartition {
+    fn code(&self) -> u32 {
+        MOVE_PARTITION_CODE
+    }
+}
+
+impl Validatable<IggyError> for MovePartition {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.directory.is_empty() || self.directory.len() > MAX_DIRECTORY_LENGTH {
+            return Err(IggyError::InvalidDataDirectory(self.directory.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for MovePartition {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let partition_id_bytes = self.partition_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            1 + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + partition_id_bytes.len()
+                + self.directory.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_slice(&partition_id_bytes);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.directory.len() as u8);
+        bytes.put_slice(self.directory.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<MovePartition, IggyError> {
+        if bytes.len() < 11 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += partition_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() <= position {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let directory_length = bytes[position] as usize;
+        if bytes.len() < position + 1 + directory_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let directory = from_utf8(&bytes[position + 1..position + 1 + directory_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        let command = MovePartition {
+            stream_id,
+            topic_id,
+            partition_id,
+            directory,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for MovePartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.partition_id, self.directory
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = MovePartition {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: Identifier::numeric(3).unwrap(),
+            directory: "/mnt/disk2/iggy".to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += partition_id.get_size_bytes().as_bytes_usize();
+        let directory_length = bytes[position];
+        let directory = from_utf8(&bytes[position + 1..position + 1 + directory_length as usize])
+            .unwrap()
+            .to_string();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+        assert_eq!(directory, command.directory);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let partition_id = Identifier::named("orders-eu").unwrap();
+        let directory = "/mnt/disk2/iggy".to_string();
+        let mut bytes = BytesMut::new();
+        bytes.put(stream_id.to_bytes());
+        bytes.put(topic_id.to_bytes());
+        bytes.put(partition_id.to_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(directory.len() as u8);
+        bytes.put_slice(directory.as_bytes());
+        let command = MovePartition::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, partition_id);
+        assert_eq!(command.directory, directory);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_empty_directory() {
+        let command = MovePartition {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: Identifier::numeric(3).unwrap(),
+            directory: String::new(),
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
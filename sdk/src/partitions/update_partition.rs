@@ -0,0 +1,212 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, UPDATE_PARTITION_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::partitions::MAX_NAME_LENGTH;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `UpdatePartition` command is used to update an existing partition of a topic.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - unique partition ID (numeric or name).
+/// - `name` - optional partition name (label) unique within the topic, max length is 255 characters. When not provided, the current name is removed.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdatePartition {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique partition ID (numeric or name).
+    #[serde(skip)]
+    pub partition_id: Identifier,
+    /// Optional partition name (label) unique within the topic, max length is 255 characters.
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+impl Command for UpdatePartition {
+    fn code(&self) -> u32 {
+        UPDATE_PARTITION_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdatePartition {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(name) = &self.name {
+            if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+                return Err(IggyError::InvalidPartitionName);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdatePartition {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let partition_id_bytes = self.partition_id.to_bytes();
+        let name = self.name.as_deref().unwrap_or_default();
+        let mut bytes = BytesMut::with_capacity(
+            1 + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + partition_id_bytes.len()
+                + name.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_slice(&partition_id_bytes);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<UpdatePartition, IggyError> {
+        if bytes.len() < 10 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += partition_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() <= position {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_length = bytes[position] as usize;
+        if bytes.len() < position + 1 + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name = match name_length {
+            0 => None,
+            _ => Some(
+                from_utf8(&bytes[position + 1..position + 1 + name_length])
+                    .map_err(|_| IggyError::InvalidUtf8)?
+                    .to_string(),
+            ),
+        };
+        let command = UpdatePartition {
+            stream_id,
+            topic_id,
+            partition_id,
+            name,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for UpdatePartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            self.name.as_deref().unwrap_or("no_name")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdatePartition {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: Identifier::numeric(3).unwrap(),
+            name: Some("orders-eu".to_string()),
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += partition_id.get_size_bytes().as_bytes_usize();
+        let name_length = bytes[position];
+        let name = from_utf8(&bytes[position + 1..position + 1 + name_length as usize])
+            .unwrap()
+            .to_string();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+        assert_eq!(Some(name), command.name);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let partition_id = Identifier::named("orders-eu").unwrap();
+        let name = "orders-us".to_string();
+        let mut bytes = BytesMut::new();
+        bytes.put(stream_id.to_bytes());
+        bytes.put(topic_id.to_bytes());
+        bytes.put(partition_id.to_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+        let command = UpdatePartition::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, partition_id);
+        assert_eq!(command.name, Some(name));
+    }
+
+    #[test]
+    fn should_be_deserialized_without_name() {
+        let command = UpdatePartition {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: Identifier::numeric(3).unwrap(),
+            name: None,
+        };
+
+        let deserialized = UpdatePartition::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
@@ -19,10 +19,14 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::{Command, CREATE_PERSONAL_ACCESS_TOKEN_CODE};
 use crate::error::IggyError;
+use crate::models::personal_access_token::{
+    extend_allowed_addresses, read_allowed_addresses, validate_allowed_addresses,
+    PersonalAccessTokenScope,
+};
 use crate::users::defaults::*;
 use crate::utils::expiry::IggyExpiry;
 use crate::validatable::Validatable;
-use bytes::{BufMut, Bytes, BytesMut};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use std::str::from_utf8;
@@ -31,12 +35,20 @@ use std::str::from_utf8;
 /// It has additional payload:
 /// - `name` - unique name of the token, must be between 3 and 30 characters long.
 /// - `expiry` - expiry of the token.
+/// - `scope` - optional scope limiting the actions which can be performed with the token.
+/// - `allowed_addresses` - optional addresses (or CIDR ranges) from which the token can be used.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreatePersonalAccessToken {
     /// Unique name of the token, must be between 3 and 30 characters long.
     pub name: String,
     /// Expiry of the token.
     pub expiry: IggyExpiry,
+    /// Optional scope limiting the actions which can be performed with the token.
+    #[serde(default)]
+    pub scope: Option<PersonalAccessTokenScope>,
+    /// Optional addresses (or CIDR ranges) from which the token can be used.
+    #[serde(default)]
+    pub allowed_addresses: Option<Vec<String>>,
 }
 
 impl Command for CreatePersonalAccessToken {
@@ -50,6 +62,8 @@ impl Default for CreatePersonalAccessToken {
         CreatePersonalAccessToken {
             name: "token".to_string(),
             expiry: IggyExpiry::NeverExpire,
+            scope: None,
+            allowed_addresses: None,
         }
     }
 }
@@ -63,7 +77,11 @@ impl Validatable<IggyError> for CreatePersonalAccessToken {
             return Err(IggyError::InvalidPersonalAccessTokenName);
         }
 
-        Ok(())
+        if let Some(scope) = &self.scope {
+            scope.validate()?;
+        }
+
+        validate_allowed_addresses(&self.allowed_addresses)
     }
 }
 
@@ -74,6 +92,16 @@ impl BytesSerializable for CreatePersonalAccessToken {
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
         bytes.put_u64_le(self.expiry.into());
+        if let Some(scope) = &self.scope {
+            let scope = scope.to_bytes();
+            bytes.put_u8(1);
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u32_le(scope.len() as u32);
+            bytes.put_slice(&scope);
+        } else {
+            bytes.put_u8(0);
+        }
+        extend_allowed_addresses(&self.allowed_addresses, &mut bytes);
         bytes.freeze()
     }
 
@@ -98,7 +126,30 @@ impl BytesSerializable for CreatePersonalAccessToken {
         );
         let expiry: IggyExpiry = expiry.into();
 
-        let command = CreatePersonalAccessToken { name, expiry };
+        let mut bytes = bytes.slice(position + 8..);
+        let mut scope = None;
+        if bytes.has_remaining() && bytes.get_u8() == 1 {
+            if bytes.remaining() < 4 {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            let scope_length = bytes.get_u32_le() as usize;
+            if bytes.remaining() < scope_length {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            scope = Some(PersonalAccessTokenScope::from_bytes(
+                bytes.split_to(scope_length),
+            )?);
+        }
+        let allowed_addresses = read_allowed_addresses(&mut bytes)?;
+
+        let command = CreatePersonalAccessToken {
+            name,
+            expiry,
+            scope,
+            allowed_addresses,
+        };
         Ok(command)
     }
 }
@@ -118,6 +169,8 @@ mod tests {
         let command = CreatePersonalAccessToken {
             name: "test".to_string(),
             expiry: IggyExpiry::NeverExpire,
+            scope: None,
+            allowed_addresses: None,
         };
 
         let bytes = command.to_bytes();
@@ -150,5 +203,23 @@ mod tests {
         let command = command.unwrap();
         assert_eq!(command.name, name);
         assert_eq!(command.expiry, expiry);
+        assert!(command.scope.is_none());
+        assert!(command.allowed_addresses.is_none());
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_scope_and_allowed_addresses() {
+        let command = CreatePersonalAccessToken {
+            name: "dashboard".to_string(),
+            expiry: IggyExpiry::NeverExpire,
+            scope: Some(PersonalAccessTokenScope {
+                read_only: true,
+                streams: Some(vec![1, 2]),
+            }),
+            allowed_addresses: Some(vec!["192.168.0.0/16".to_string()]),
+        };
+
+        let deserialized = CreatePersonalAccessToken::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
     }
 }
@@ -16,7 +16,9 @@ pub use crate::client::Client;
 pub use crate::error::IggyError;
 pub use crate::identifier::Identifier;
 pub use crate::messages::{
-    FlushUnsavedBuffer, Partitioning, PollMessages, PollingKind, PollingStrategy, SendMessages,
+    AbortTransaction, AckMessages, AutoCommitMode, BeginTransaction, CommitTransaction,
+    FlushUnsavedBuffer, NackMessages, Partitioning, PayloadEncoding, PollMessages, PollingKind,
+    PollingStrategy, RejectMessages, SendMessages, SendOffsetsToTransaction,
 };
 pub use crate::models::messaging::{
     HeaderKey, HeaderValue, IggyMessage, IggyMessageHeader, IggyMessageHeaderView, IggyMessageView,
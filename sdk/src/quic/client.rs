@@ -264,6 +264,7 @@ impl QuicClient {
 
         self.set_state(ClientState::Connecting).await;
         if let Some(connected_at) = self.connected_at.lock().await.as_ref() {
+            self.publish_event(DiagnosticEvent::Reconnecting).await;
             let now = IggyTimestamp::now();
             let elapsed = now.as_micros() - connected_at.as_micros();
             let interval = self.config.reconnection.reestablish_after.as_micros();
@@ -314,6 +315,7 @@ impl QuicClient {
                 let interval_str = self.config.reconnection.interval.as_human_time_string();
                 if unlimited_retries || retry_count < max_retries {
                     retry_count += 1;
+                    self.publish_event(DiagnosticEvent::Reconnecting).await;
                     info!(
                         "Retrying to connect to server ({retry_count}/{max_retries_str}): {} in: {interval_str}",
                         self.config.server_address,
@@ -356,13 +358,11 @@ impl QuicClient {
                 match credentials {
                     Credentials::UsernamePassword(username, password) => {
                         self.login_user(username, password).await?;
-                        self.publish_event(DiagnosticEvent::SignedIn).await;
                         info!("{NAME} client: {} has signed in with the user credentials, username: {username}", self.config.client_address);
                         Ok(())
                     }
                     Credentials::PersonalAccessToken(token) => {
                         self.login_with_personal_access_token(token).await?;
-                        self.publish_event(DiagnosticEvent::SignedIn).await;
                         info!(
                             "{NAME} client: {} has signed in with a personal access token.",
                             self.config.client_address
@@ -0,0 +1,199 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, SET_QUOTA_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::quota::QuotaLimits;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+const USER_ID_FLAG: u8 = 1;
+const STREAM_ID_FLAG: u8 = 1 << 1;
+const TOPIC_ID_FLAG: u8 = 1 << 2;
+const LIMITS_FLAG: u8 = 1 << 3;
+
+/// `SetQuota` command is used to set or clear the throughput quota of the user or the topic at runtime,
+/// overriding the default one from the server configuration.
+/// The quota is kept only in memory, so the default one is restored on the server restart.
+/// It has additional payload:
+/// - `user_id` - unique user ID (numeric or name), when the quota applies to the user.
+/// - `stream_id` - unique stream ID (numeric or name), when the quota applies to the topic.
+/// - `topic_id` - unique topic ID (numeric or name), when the quota applies to the topic.
+/// - `limits` - optional limits of the throughput, when not set, the default quota is restored.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct SetQuota {
+    /// Unique user ID (numeric or name), when the quota applies to the user.
+    #[serde(skip)]
+    pub user_id: Option<Identifier>,
+    /// Unique stream ID (numeric or name), when the quota applies to the topic.
+    #[serde(skip)]
+    pub stream_id: Option<Identifier>,
+    /// Unique topic ID (numeric or name), when the quota applies to the topic.
+    #[serde(skip)]
+    pub topic_id: Option<Identifier>,
+    /// Optional limits of the throughput, when not set, the default quota is restored.
+    #[serde(default)]
+    pub limits: Option<QuotaLimits>,
+}
+
+impl Command for SetQuota {
+    fn code(&self) -> u32 {
+        SET_QUOTA_CODE
+    }
+}
+
+impl Validatable<IggyError> for SetQuota {
+    fn validate(&self) -> Result<(), IggyError> {
+        match (&self.user_id, &self.stream_id, &self.topic_id) {
+            (Some(_), None, None) | (None, Some(_), Some(_)) => Ok(()),
+            _ => Err(IggyError::InvalidQuota(
+                "either user ID or stream and topic IDs must be provided".to_string(),
+            )),
+        }
+    }
+}
+
+impl BytesSerializable for SetQuota {
+    fn to_bytes(&self) -> Bytes {
+        let mut flags = 0;
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(flags);
+        for (identifier, flag) in [
+            (&self.user_id, USER_ID_FLAG),
+            (&self.stream_id, STREAM_ID_FLAG),
+            (&self.topic_id, TOPIC_ID_FLAG),
+        ] {
+            if let Some(identifier) = identifier {
+                flags |= flag;
+                bytes.put_slice(&identifier.to_bytes());
+            }
+        }
+        if let Some(limits) = &self.limits {
+            flags |= LIMITS_FLAG;
+            bytes.put_slice(&limits.to_bytes());
+        }
+        bytes[0] = flags;
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<SetQuota, IggyError> {
+        let Some(&flags) = bytes.first() else {
+            return Err(IggyError::InvalidCommand);
+        };
+
+        let mut position = 1;
+        let mut read_identifier = |flag: u8| -> Result<Option<Identifier>, IggyError> {
+            if flags & flag == 0 {
+                return Ok(None);
+            }
+
+            let identifier = Identifier::from_bytes(bytes.slice(position..))?;
+            position += identifier.get_size_bytes().as_bytes_usize();
+            Ok(Some(identifier))
+        };
+        let user_id = read_identifier(USER_ID_FLAG)?;
+        let stream_id = read_identifier(STREAM_ID_FLAG)?;
+        let topic_id = read_identifier(TOPIC_ID_FLAG)?;
+        let limits = match flags & LIMITS_FLAG {
+            0 if bytes.len() == position => None,
+            0 => return Err(IggyError::InvalidCommand),
+            _ => Some(QuotaLimits::from_bytes(bytes.slice(position..))?),
+        };
+        Ok(SetQuota {
+            user_id,
+            stream_id,
+            topic_id,
+            limits,
+        })
+    }
+}
+
+impl Display for SetQuota {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format_identifier = |identifier: &Option<Identifier>| {
+            identifier
+                .as_ref()
+                .map_or("none".to_string(), |identifier| identifier.to_string())
+        };
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            format_identifier(&self.user_id),
+            format_identifier(&self.stream_id),
+            format_identifier(&self.topic_id),
+            self.limits
+                .map_or("default".to_string(), |limits| limits.to_string())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::byte_size::IggyByteSize;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_given_topic_quota() {
+        let command = SetQuota {
+            user_id: None,
+            stream_id: Some(Identifier::numeric(1).unwrap()),
+            topic_id: Some(Identifier::named("orders").unwrap()),
+            limits: Some(QuotaLimits {
+                produce_bytes_per_second: IggyByteSize::from(1_000_000),
+                produce_messages_per_second: 1000,
+                ..QuotaLimits::default()
+            }),
+        };
+
+        let bytes = command.to_bytes();
+
+        assert_eq!(bytes[0], STREAM_ID_FLAG | TOPIC_ID_FLAG | LIMITS_FLAG);
+        assert_eq!(SetQuota::from_bytes(bytes).unwrap(), command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_given_cleared_user_quota() {
+        let command = SetQuota {
+            user_id: Some(Identifier::named("producer").unwrap()),
+            ..SetQuota::default()
+        };
+
+        let bytes = command.to_bytes();
+
+        assert_eq!(bytes[0], USER_ID_FLAG);
+        assert_eq!(SetQuota::from_bytes(bytes).unwrap(), command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_both_user_and_topic() {
+        let command = SetQuota {
+            user_id: Some(Identifier::numeric(1).unwrap()),
+            stream_id: Some(Identifier::numeric(1).unwrap()),
+            topic_id: Some(Identifier::numeric(2).unwrap()),
+            limits: None,
+        };
+
+        assert!(command.validate().is_err());
+        assert!(SetQuota::default().validate().is_err());
+    }
+}
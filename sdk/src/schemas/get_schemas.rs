@@ -0,0 +1,119 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_SCHEMAS_CODE};
+use crate::error::IggyError;
+use crate::schemas::is_valid_subject;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `GetSchemas` command retrieves all the registered versions of the schema for the subject.
+/// It has additional payload:
+/// - `subject` - the subject (e.g. topic name) under which the schema versions are registered, max length is 255 characters.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct GetSchemas {
+    /// The subject under which the schema versions are registered, max length is 255 characters.
+    #[serde(skip)]
+    pub subject: String,
+}
+
+impl Command for GetSchemas {
+    fn code(&self) -> u32 {
+        GET_SCHEMAS_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetSchemas {
+    fn validate(&self) -> Result<(), IggyError> {
+        if !is_valid_subject(&self.subject) {
+            return Err(IggyError::InvalidSchemaSubject);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetSchemas {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(1 + self.subject.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.subject.len() as u8);
+        bytes.put_slice(self.subject.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetSchemas, IggyError> {
+        if bytes.len() < 2 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subject_length = bytes[0] as usize;
+        if bytes.len() != 1 + subject_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subject = from_utf8(&bytes[1..1 + subject_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        let command = GetSchemas { subject };
+        Ok(command)
+    }
+}
+
+impl Display for GetSchemas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.subject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetSchemas {
+            subject: "orders".to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        let subject_length = bytes[0] as usize;
+        let subject = from_utf8(&bytes[1..1 + subject_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(subject, command.subject);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let subject = "orders";
+        let mut bytes = BytesMut::with_capacity(1 + subject.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(subject.len() as u8);
+        bytes.put_slice(subject.as_bytes());
+        let command = GetSchemas::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.subject, subject);
+    }
+}
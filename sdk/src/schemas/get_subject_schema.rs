@@ -0,0 +1,152 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_SUBJECT_SCHEMA_CODE};
+use crate::error::IggyError;
+use crate::schemas::is_valid_subject;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `GetSubjectSchema` command retrieves the specific version of the schema registered for the subject.
+/// It has additional payload:
+/// - `subject` - the subject under which the schema is registered, max length is 255 characters.
+/// - `version` - the version of the schema, if None is provided then the latest version is returned.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct GetSubjectSchema {
+    /// The subject under which the schema is registered, max length is 255 characters.
+    #[serde(skip)]
+    pub subject: String,
+    /// The version of the schema, if None is provided then the latest version is returned.
+    #[serde(skip)]
+    pub version: Option<u32>,
+}
+
+impl Command for GetSubjectSchema {
+    fn code(&self) -> u32 {
+        GET_SUBJECT_SCHEMA_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetSubjectSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if !is_valid_subject(&self.subject) {
+            return Err(IggyError::InvalidSchemaSubject);
+        }
+
+        if let Some(version) = self.version {
+            if version == 0 {
+                return Err(IggyError::SchemaVersionNotFound(
+                    version,
+                    self.subject.clone(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetSubjectSchema {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(5 + self.subject.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.subject.len() as u8);
+        bytes.put_slice(self.subject.as_bytes());
+        bytes.put_u32_le(self.version.unwrap_or(0));
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetSubjectSchema, IggyError> {
+        if bytes.len() < 6 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subject_length = bytes[0] as usize;
+        if bytes.len() != 5 + subject_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subject = from_utf8(&bytes[1..1 + subject_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        let position = 1 + subject_length;
+        let version = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let version = if version == 0 { None } else { Some(version) };
+        let command = GetSubjectSchema { subject, version };
+        Ok(command)
+    }
+}
+
+impl Display for GetSubjectSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.version {
+            Some(version) => write!(f, "{}|{version}", self.subject),
+            None => write!(f, "{}|latest", self.subject),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetSubjectSchema {
+            subject: "orders".to_string(),
+            version: Some(2),
+        };
+
+        let bytes = command.to_bytes();
+        let subject_length = bytes[0] as usize;
+        let subject = from_utf8(&bytes[1..1 + subject_length]).unwrap();
+        let version = u32::from_le_bytes(
+            bytes[1 + subject_length..5 + subject_length]
+                .try_into()
+                .unwrap(),
+        );
+
+        assert!(!bytes.is_empty());
+        assert_eq!(subject, command.subject);
+        assert_eq!(version, command.version.unwrap());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let subject = "orders";
+        let mut bytes = BytesMut::with_capacity(5 + subject.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(subject.len() as u8);
+        bytes.put_slice(subject.as_bytes());
+        bytes.put_u32_le(0);
+        let command = GetSubjectSchema::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.subject, subject);
+        assert_eq!(command.version, None);
+    }
+}
@@ -0,0 +1,144 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, REGISTER_SCHEMA_CODE};
+use crate::error::IggyError;
+use crate::models::topic_schema::TopicSchema;
+use crate::schemas::is_valid_subject;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `RegisterSchema` command registers a new version of the schema for the subject.
+/// The schema is checked against the latest version using the compatibility level configured for the subject,
+/// and if it's identical to the latest version, the existing schema is returned instead.
+/// It has additional payload:
+/// - `subject` - the subject (e.g. topic name) under which the schema is registered, max length is 255 characters.
+/// - `schema` - the JSON Schema or protobuf definition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct RegisterSchema {
+    /// The subject under which the schema is registered, max length is 255 characters.
+    #[serde(skip)]
+    pub subject: String,
+    /// The JSON Schema or protobuf definition.
+    pub schema: TopicSchema,
+}
+
+impl Command for RegisterSchema {
+    fn code(&self) -> u32 {
+        REGISTER_SCHEMA_CODE
+    }
+}
+
+impl Default for RegisterSchema {
+    fn default() -> Self {
+        RegisterSchema {
+            subject: "subject".to_string(),
+            schema: TopicSchema::json(r#"{"type":"object"}"#),
+        }
+    }
+}
+
+impl Validatable<IggyError> for RegisterSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if !is_valid_subject(&self.subject) {
+            return Err(IggyError::InvalidSchemaSubject);
+        }
+
+        self.schema.validate()
+    }
+}
+
+impl BytesSerializable for RegisterSchema {
+    fn to_bytes(&self) -> Bytes {
+        let schema_bytes = self.schema.to_bytes();
+        let mut bytes = BytesMut::with_capacity(1 + self.subject.len() + schema_bytes.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.subject.len() as u8);
+        bytes.put_slice(self.subject.as_bytes());
+        bytes.put_slice(&schema_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RegisterSchema, IggyError> {
+        if bytes.len() < 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subject_length = bytes[0] as usize;
+        if bytes.len() < 1 + subject_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subject = from_utf8(&bytes[1..1 + subject_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        let schema = TopicSchema::from_bytes(bytes.slice(1 + subject_length..))?;
+        let command = RegisterSchema { subject, schema };
+        Ok(command)
+    }
+}
+
+impl Display for RegisterSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.subject, self.schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RegisterSchema {
+            subject: "orders".to_string(),
+            schema: TopicSchema::json(r#"{"type":"object"}"#),
+        };
+
+        let bytes = command.to_bytes();
+        let subject_length = bytes[0] as usize;
+        let subject = from_utf8(&bytes[1..1 + subject_length]).unwrap();
+        let schema = TopicSchema::from_bytes(bytes.slice(1 + subject_length..)).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(subject, command.subject);
+        assert_eq!(schema, command.schema);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let subject = "orders";
+        let schema = TopicSchema::protobuf(Bytes::from_static(b"descriptor"), "orders.Order");
+        let schema_bytes = schema.to_bytes();
+        let mut bytes = BytesMut::with_capacity(1 + subject.len() + schema_bytes.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(subject.len() as u8);
+        bytes.put_slice(subject.as_bytes());
+        bytes.put_slice(&schema_bytes);
+        let command = RegisterSchema::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.subject, subject);
+        assert_eq!(command.schema, schema);
+    }
+}
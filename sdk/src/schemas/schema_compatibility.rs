@@ -0,0 +1,119 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The compatibility level enforced by the schema registry when a new schema version is registered for the subject.
+/// The new version is checked against the latest registered version only.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaCompatibility {
+    /// No compatibility checks are performed.
+    None,
+    /// The consumers using the new schema can read the data produced with the previous schema.
+    #[default]
+    Backward,
+    /// The consumers using the previous schema can read the data produced with the new schema.
+    Forward,
+    /// Both backward and forward compatible.
+    Full,
+}
+
+impl SchemaCompatibility {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaCompatibility::None => 1,
+            SchemaCompatibility::Backward => 2,
+            SchemaCompatibility::Forward => 3,
+            SchemaCompatibility::Full => 4,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(SchemaCompatibility::None),
+            2 => Ok(SchemaCompatibility::Backward),
+            3 => Ok(SchemaCompatibility::Forward),
+            4 => Ok(SchemaCompatibility::Full),
+            _ => Err(IggyError::InvalidSchemaCompatibility(code)),
+        }
+    }
+}
+
+impl FromStr for SchemaCompatibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(SchemaCompatibility::None),
+            "backward" => Ok(SchemaCompatibility::Backward),
+            "forward" => Ok(SchemaCompatibility::Forward),
+            "full" => Ok(SchemaCompatibility::Full),
+            _ => Err(format!("Unknown schema compatibility: {}", s)),
+        }
+    }
+}
+
+impl Display for SchemaCompatibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaCompatibility::None => write!(f, "none"),
+            SchemaCompatibility::Backward => write!(f, "backward"),
+            SchemaCompatibility::Forward => write!(f, "forward"),
+            SchemaCompatibility::Full => write!(f, "full"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_mapped_from_and_to_code() {
+        for compatibility in [
+            SchemaCompatibility::None,
+            SchemaCompatibility::Backward,
+            SchemaCompatibility::Forward,
+            SchemaCompatibility::Full,
+        ] {
+            assert_eq!(
+                SchemaCompatibility::from_code(compatibility.as_code()).unwrap(),
+                compatibility
+            );
+        }
+    }
+
+    #[test]
+    fn should_be_parsed_from_string() {
+        assert_eq!(
+            SchemaCompatibility::from_str("FULL").unwrap(),
+            SchemaCompatibility::Full
+        );
+        assert!(SchemaCompatibility::from_str("transitive").is_err());
+    }
+
+    #[test]
+    fn should_fail_for_invalid_code() {
+        assert!(SchemaCompatibility::from_code(0).is_err());
+        assert!(SchemaCompatibility::from_code(5).is_err());
+    }
+}
@@ -0,0 +1,38 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::headers::well_known;
+
+pub use well_known::{get_schema_id, set_schema_id};
+
+/// The header key under which the ID of the registered schema is attached to the message.
+pub const SCHEMA_ID_HEADER_KEY: &str = well_known::SCHEMA_ID;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_id_should_be_set_and_read_from_headers() {
+        let mut headers = None;
+        assert_eq!(get_schema_id(&headers).unwrap(), None);
+
+        set_schema_id(&mut headers, 7).unwrap();
+        assert_eq!(get_schema_id(&headers).unwrap(), Some(7));
+    }
+}
@@ -0,0 +1,275 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, CREATE_BACKUP_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::backup::BackupDestination;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+const MAX_NAME_LENGTH: usize = 255;
+
+/// `CreateBackup` command is used to take a consistent point-in-time archive of the server metadata and the selected streams.
+/// The closed segments are hard-linked (or copied, if the archive is on a different file system), and the open ones
+/// are flushed and copied up to their size at the time of taking the backup. The archive can be restored at the server startup.
+/// It has additional payload:
+/// - `name` - unique name of the backup, consisting of the alphanumeric characters, dots, dashes and underscores, max length is 255 characters.
+/// - `streams` - unique stream IDs (numeric or name) to archive, all the streams are archived if empty.
+/// - `destination` - the place where the archive is written to, either the local archive directory or the S3-compatible object storage.
+/// - `base` - optional name of the previous backup to take the incremental backup on top of, only the segments which are not
+///   already archived by the previous backup (or the ones it's based on) are archived, the metadata and the open segments are always archived.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct CreateBackup {
+    /// Unique name of the backup, max length is 255 characters.
+    pub name: String,
+    /// Unique stream IDs (numeric or name) to archive, all the streams are archived if empty.
+    #[serde(default)]
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub streams: Vec<Identifier>,
+    /// The place where the archive is written to.
+    #[serde(default)]
+    pub destination: BackupDestination,
+    /// Optional name of the previous backup to take the incremental backup on top of.
+    #[serde(default)]
+    pub base: Option<String>,
+}
+
+impl Command for CreateBackup {
+    fn code(&self) -> u32 {
+        CREATE_BACKUP_CODE
+    }
+}
+
+impl Validatable<IggyError> for CreateBackup {
+    fn validate(&self) -> Result<(), IggyError> {
+        validate_backup_name(&self.name)?;
+        if let Some(base) = &self.base {
+            validate_backup_name(base)?;
+            if base == &self.name {
+                return Err(IggyError::InvalidBackupName);
+            }
+        }
+
+        for stream_id in &self.streams {
+            stream_id.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Validates the name of the backup, which is used as the name of its archive directory.
+pub(crate) fn validate_backup_name(name: &str) -> Result<(), IggyError> {
+    if name.is_empty()
+        || name.len() > MAX_NAME_LENGTH
+        || name.starts_with('.')
+        || !name.chars().all(|character| {
+            character.is_ascii_alphanumeric() || matches!(character, '.' | '-' | '_')
+        })
+    {
+        return Err(IggyError::InvalidBackupName);
+    }
+
+    Ok(())
+}
+
+impl BytesSerializable for CreateBackup {
+    fn to_bytes(&self) -> Bytes {
+        let streams_bytes = self
+            .streams
+            .iter()
+            .map(|stream_id| stream_id.to_bytes())
+            .collect::<Vec<_>>();
+        let base = self.base.as_deref().unwrap_or_default();
+        let mut bytes = BytesMut::with_capacity(
+            3 + self.name.len()
+                + base.len()
+                + streams_bytes.iter().map(|bytes| bytes.len()).sum::<usize>(),
+        );
+        bytes.put_u8(self.destination.as_code());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(base.len() as u8);
+        bytes.put_slice(base.as_bytes());
+        for stream_bytes in streams_bytes {
+            bytes.put_slice(&stream_bytes);
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<CreateBackup, IggyError> {
+        if bytes.len() < 3 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let destination = BackupDestination::from_code(bytes[0])?;
+        let name_length = bytes[1] as usize;
+        if bytes.len() < 3 + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[2..2 + name_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        let mut position = 2 + name_length;
+        let base_length = bytes[position] as usize;
+        position += 1;
+        if bytes.len() < position + base_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let base = match base_length {
+            0 => None,
+            _ => Some(
+                from_utf8(&bytes[position..position + base_length])
+                    .map_err(|_| IggyError::InvalidUtf8)?
+                    .to_string(),
+            ),
+        };
+        position += base_length;
+        let mut streams = Vec::new();
+        while position < bytes.len() {
+            let stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+            position += stream_id.get_size_bytes().as_bytes_usize();
+            streams.push(stream_id);
+        }
+
+        let command = CreateBackup {
+            name,
+            streams,
+            destination,
+            base,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for CreateBackup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let streams = self
+            .streams
+            .iter()
+            .map(|stream_id| stream_id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.name,
+            streams,
+            self.destination,
+            self.base.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = CreateBackup {
+            name: "nightly-2024.01.01".to_string(),
+            streams: vec![
+                Identifier::numeric(1).unwrap(),
+                Identifier::named("orders").unwrap(),
+            ],
+            destination: BackupDestination::S3,
+            base: Some("weekly".to_string()),
+        };
+
+        let bytes = command.to_bytes();
+        let destination = BackupDestination::from_code(bytes[0]).unwrap();
+        let name_length = bytes[1] as usize;
+        let name = from_utf8(&bytes[2..2 + name_length]).unwrap();
+        let mut position = 2 + name_length;
+        let base_length = bytes[position] as usize;
+        position += 1;
+        let base = from_utf8(&bytes[position..position + base_length]).unwrap();
+        position += base_length;
+        let first_stream_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += first_stream_id.get_size_bytes().as_bytes_usize();
+        let second_stream_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(destination, command.destination);
+        assert_eq!(name, command.name);
+        assert_eq!(Some(base), command.base.as_deref());
+        assert_eq!(first_stream_id, command.streams[0]);
+        assert_eq!(second_stream_id, command.streams[1]);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = CreateBackup {
+            name: "before_upgrade".to_string(),
+            streams: vec![Identifier::numeric(3).unwrap()],
+            destination: BackupDestination::Local,
+            base: Some("before_upgrade.full".to_string()),
+        };
+
+        let deserialized = CreateBackup::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_streams() {
+        let command = CreateBackup {
+            name: "full".to_string(),
+            streams: Vec::new(),
+            destination: BackupDestination::Local,
+            base: None,
+        };
+
+        let deserialized = CreateBackup::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_name_with_path_separator() {
+        let command = CreateBackup {
+            name: "../state".to_string(),
+            ..Default::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_base_equal_to_name() {
+        let command = CreateBackup {
+            name: "nightly".to_string(),
+            base: Some("nightly".to_string()),
+            ..Default::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
@@ -0,0 +1,92 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, ENABLE_CONSUMER_GROUP_EVENTS_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The status of the frame pushed by the server, which isn't a response to any request but holds the consumer group rebalance.
+pub const CONSUMER_GROUP_EVENT_STATUS: u32 = u32::MAX;
+
+/// `EnableConsumerGroupEvents` command is sent by the TCP client to negotiate the notifications about the consumer group rebalances.
+/// Once the server responds with the OK status, it pushes the frame with the `CONSUMER_GROUP_EVENT_STATUS` status
+/// whenever the assignment of any consumer group the client is a member of has changed (including the group just joined), while the connection is idle.
+/// The payload of such a frame is the `ConsumerGroupRebalance`, and it's followed by the frame checksum if the frame checksums were negotiated as well.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct EnableConsumerGroupEvents {}
+
+impl Command for EnableConsumerGroupEvents {
+    fn code(&self) -> u32 {
+        ENABLE_CONSUMER_GROUP_EVENTS_CODE
+    }
+}
+
+impl Validatable<IggyError> for EnableConsumerGroupEvents {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for EnableConsumerGroupEvents {
+    fn to_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<EnableConsumerGroupEvents, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(EnableConsumerGroupEvents {})
+    }
+}
+
+impl Display for EnableConsumerGroupEvents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = EnableConsumerGroupEvents {};
+        let bytes = command.to_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let command = EnableConsumerGroupEvents::from_bytes(Bytes::new());
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_non_empty_bytes() {
+        let command = EnableConsumerGroupEvents::from_bytes(Bytes::from_static(&[0]));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,98 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, ENABLE_ZERO_COPY_POLLING_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The poll response holds the messages framed as usual, i.e. the same way as without the zero-copy polling.
+pub const MESSAGES_FRAMING: u8 = 0;
+/// The poll response holds the whole batches exactly as they are stored in the segment log file.
+pub const STORED_BATCHES_FRAMING: u8 = 1;
+
+/// `EnableZeroCopyPolling` command is sent by the TCP client right after connecting to the server,
+/// to negotiate the poll responses sent straight from the segment log files.
+/// Once the server responds with the OK status, every poll response starts with the 1 byte (u8) framing:
+/// - `0` - the messages are framed as usual,
+/// - `1` - the header (partition ID, current offset, messages count and next offset) is followed by the stored batches,
+///   each having the 28 bytes header (base offset, length, last offset delta, max timestamp and checksum) and the stored messages.
+///
+/// The server sends the stored batches only when the whole poll is served from a closed segment, without copying them to the userspace.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct EnableZeroCopyPolling {}
+
+impl Command for EnableZeroCopyPolling {
+    fn code(&self) -> u32 {
+        ENABLE_ZERO_COPY_POLLING_CODE
+    }
+}
+
+impl Validatable<IggyError> for EnableZeroCopyPolling {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for EnableZeroCopyPolling {
+    fn to_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<EnableZeroCopyPolling, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(EnableZeroCopyPolling {})
+    }
+}
+
+impl Display for EnableZeroCopyPolling {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = EnableZeroCopyPolling {};
+        let bytes = command.to_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let command = EnableZeroCopyPolling::from_bytes(Bytes::new());
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_non_empty_bytes() {
+        let command = EnableZeroCopyPolling::from_bytes(Bytes::from_static(&[0]));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,188 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_AUDIT_LOG_CODE};
+use crate::error::IggyError;
+use crate::models::user_info::UserId;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+const MAX_ACTION_LENGTH: usize = 255;
+
+/// `GetAuditLog` command is used to get the most recent entries of the audit log, from the newest to the oldest.
+/// It has additional payload:
+/// - `user_id` - optional identifier of the user who invoked the action.
+/// - `action` - optional name of the action, e.g. `stream.create`.
+/// - `limit` - maximum number of the entries to return.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetAuditLog {
+    /// Optional identifier of the user who invoked the action.
+    #[serde(default)]
+    pub user_id: Option<UserId>,
+    /// Optional name of the action, e.g. `stream.create`.
+    #[serde(default)]
+    pub action: Option<String>,
+    /// Maximum number of the entries to return.
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+}
+
+fn default_limit() -> u32 {
+    100
+}
+
+impl Default for GetAuditLog {
+    fn default() -> Self {
+        GetAuditLog {
+            user_id: None,
+            action: None,
+            limit: default_limit(),
+        }
+    }
+}
+
+impl Command for GetAuditLog {
+    fn code(&self) -> u32 {
+        GET_AUDIT_LOG_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetAuditLog {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.limit == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        if let Some(action) = &self.action {
+            if action.is_empty() || action.len() > MAX_ACTION_LENGTH {
+                return Err(IggyError::InvalidCommand);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetAuditLog {
+    fn to_bytes(&self) -> Bytes {
+        let action = self.action.as_deref().unwrap_or_default();
+        let mut bytes = BytesMut::with_capacity(9 + action.len());
+        bytes.put_u32_le(self.user_id.unwrap_or_default());
+        bytes.put_u32_le(self.limit);
+        bytes.put_u8(action.len() as u8);
+        bytes.put_slice(action.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetAuditLog, IggyError> {
+        if bytes.len() < 9 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let user_id = u32::from_le_bytes(
+            bytes[..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let limit = u32::from_le_bytes(
+            bytes[4..8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let action_length = bytes[8] as usize;
+        if bytes.len() != 9 + action_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let action = from_utf8(&bytes[9..])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        let command = GetAuditLog {
+            user_id: (user_id > 0).then_some(user_id),
+            action: (!action.is_empty()).then_some(action),
+            limit,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for GetAuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.user_id.unwrap_or_default(),
+            self.action.as_deref().unwrap_or_default(),
+            self.limit
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetAuditLog {
+            user_id: Some(1),
+            action: Some("stream.create".to_string()),
+            limit: 10,
+        };
+
+        let bytes = command.to_bytes();
+        let user_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+        let limit = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        let action_length = bytes[8] as usize;
+        let action = from_utf8(&bytes[9..9 + action_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(user_id, 1);
+        assert_eq!(limit, command.limit);
+        assert_eq!(action, "stream.create");
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = GetAuditLog {
+            user_id: None,
+            action: Some("user.delete".to_string()),
+            limit: 5,
+        };
+
+        let deserialized = GetAuditLog::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(0);
+        bytes.put_u32_le(10);
+        bytes.put_u8(5);
+        bytes.put_slice(b"user");
+
+        let command = GetAuditLog::from_bytes(bytes.freeze());
+
+        assert!(command.is_err());
+    }
+}
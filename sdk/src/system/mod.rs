@@ -16,9 +16,17 @@
  * under the License.
  */
 
+pub mod create_backup;
+pub mod enable_consumer_group_events;
+pub mod enable_frame_checksums;
+pub mod enable_zero_copy_polling;
+pub mod get_audit_log;
 pub mod get_client;
 pub mod get_clients;
+pub mod get_cluster_metadata;
+pub mod get_config;
 pub mod get_me;
 pub mod get_snapshot;
 pub mod get_stats;
 pub mod ping;
+pub mod restore_backup;
@@ -0,0 +1,174 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, RESTORE_BACKUP_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::system::create_backup::validate_backup_name;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `RestoreBackup` command is used to import the streams from the backup into the running server.
+/// The streams are restored with their original IDs, names, topics, partitions, consumer groups, message offsets
+/// and consumer offsets, so the consumers can continue from where they left off. None of the restored streams
+/// can exist on the server (neither with the same ID nor the same name).
+/// The backup is looked up in the local archive directory first, and downloaded from S3, if not found there.
+/// It has additional payload:
+/// - `name` - unique name of the backup to restore (full or incremental).
+/// - `streams` - unique stream IDs (numeric or name) to restore, all the archived streams are restored if empty.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct RestoreBackup {
+    /// Unique name of the backup to restore.
+    #[serde(skip)]
+    pub name: String,
+    /// Unique stream IDs (numeric or name) to restore, all the archived streams are restored if empty.
+    #[serde(default)]
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub streams: Vec<Identifier>,
+}
+
+impl Command for RestoreBackup {
+    fn code(&self) -> u32 {
+        RESTORE_BACKUP_CODE
+    }
+}
+
+impl Validatable<IggyError> for RestoreBackup {
+    fn validate(&self) -> Result<(), IggyError> {
+        validate_backup_name(&self.name)?;
+        for stream_id in &self.streams {
+            stream_id.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RestoreBackup {
+    fn to_bytes(&self) -> Bytes {
+        let streams_bytes = self
+            .streams
+            .iter()
+            .map(|stream_id| stream_id.to_bytes())
+            .collect::<Vec<_>>();
+        let mut bytes = BytesMut::with_capacity(
+            1 + self.name.len() + streams_bytes.iter().map(|bytes| bytes.len()).sum::<usize>(),
+        );
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        for stream_bytes in streams_bytes {
+            bytes.put_slice(&stream_bytes);
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RestoreBackup, IggyError> {
+        if bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_length = bytes[0] as usize;
+        if bytes.len() < 1 + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[1..1 + name_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        let mut position = 1 + name_length;
+        let mut streams = Vec::new();
+        while position < bytes.len() {
+            let stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+            position += stream_id.get_size_bytes().as_bytes_usize();
+            streams.push(stream_id);
+        }
+
+        let command = RestoreBackup { name, streams };
+        Ok(command)
+    }
+}
+
+impl Display for RestoreBackup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let streams = self
+            .streams
+            .iter()
+            .map(|stream_id| stream_id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{}|{}", self.name, streams)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RestoreBackup {
+            name: "nightly".to_string(),
+            streams: vec![
+                Identifier::numeric(1).unwrap(),
+                Identifier::named("orders").unwrap(),
+            ],
+        };
+
+        let bytes = command.to_bytes();
+        let name_length = bytes[0] as usize;
+        let name = from_utf8(&bytes[1..1 + name_length]).unwrap();
+        let mut position = 1 + name_length;
+        let first_stream_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += first_stream_id.get_size_bytes().as_bytes_usize();
+        let second_stream_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(name, command.name);
+        assert_eq!(first_stream_id, command.streams[0]);
+        assert_eq!(second_stream_id, command.streams[1]);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = RestoreBackup {
+            name: "nightly".to_string(),
+            streams: vec![Identifier::named("orders").unwrap()],
+        };
+
+        let deserialized = RestoreBackup::from_bytes(command.to_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_invalid_name() {
+        let command = RestoreBackup {
+            name: "../nightly".to_string(),
+            streams: Vec::new(),
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
@@ -17,46 +17,123 @@
  */
 
 use crate::binary::binary_client::BinaryClient;
+use crate::binary::mapper;
 use crate::binary::{BinaryTransport, ClientState};
+use crate::bytes_serializable::BytesSerializable;
 use crate::client::{
     AutoLogin, Client, ConnectionString, Credentials, PersonalAccessTokenClient, UserClient,
 };
-use crate::command::Command;
+use crate::command::{
+    Command, ENABLE_CONSUMER_GROUP_EVENTS_CODE, ENABLE_FRAME_CHECKSUMS_CODE,
+    ENABLE_ZERO_COPY_POLLING_CODE,
+};
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::{IggyError, IggyErrorDiscriminants};
+use crate::models::consumer_group::ConsumerGroupRebalance;
+use crate::system::enable_consumer_group_events::{
+    EnableConsumerGroupEvents, CONSUMER_GROUP_EVENT_STATUS,
+};
+use crate::system::enable_frame_checksums::EnableFrameChecksums;
+use crate::system::enable_zero_copy_polling::EnableZeroCopyPolling;
 use crate::tcp::config::TcpClientConfig;
+use crate::utils::checksum::ChecksumHasher;
 use crate::utils::duration::IggyDuration;
 use crate::utils::timestamp::IggyTimestamp;
-use async_broadcast::{broadcast, Receiver, Sender};
+use async_broadcast::{broadcast, InactiveReceiver, Receiver, Sender};
 use async_trait::async_trait;
 use bytes::{BufMut, Bytes, BytesMut};
 use rustls::pki_types::{pem::PemObject, CertificateDer, ServerName};
 use std::fmt::Debug;
 use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
 use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
 use tokio::net::TcpStream;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, MutexGuard, Notify};
 use tokio::time::sleep;
 use tokio_rustls::{TlsConnector, TlsStream};
 use tracing::{error, info, trace, warn};
 
 const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
 const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
+const FRAME_CHECKSUM_LENGTH: usize = 4;
 const NAME: &str = "Iggy";
 
 /// TCP client for interacting with the Iggy API.
 /// It requires a valid server address.
 #[derive(Debug)]
 pub struct TcpClient {
-    pub(crate) stream: Mutex<Option<ConnectionStreamKind>>,
+    pub(crate) stream: Arc<Mutex<Option<ConnectionStreamKind>>>,
     pub(crate) config: Arc<TcpClientConfig>,
     pub(crate) state: Mutex<ClientState>,
     client_address: Mutex<Option<SocketAddr>>,
     events: (Sender<DiagnosticEvent>, Receiver<DiagnosticEvent>),
     connected_at: Mutex<Option<IggyTimestamp>>,
+    frame_checksums: Arc<AtomicBool>,
+    frame_checksum_errors: Arc<AtomicU64>,
+    zero_copy_polling: AtomicBool,
+    consumer_group_events: Arc<ConsumerGroupEvents>,
+}
+
+/// The consumer group rebalances pushed by the server, read either while waiting for a response,
+/// or by the background task while the connection is idle.
+#[derive(Debug)]
+struct ConsumerGroupEvents {
+    requested: AtomicBool,
+    rebalances: (
+        Sender<ConsumerGroupRebalance>,
+        InactiveReceiver<ConsumerGroupRebalance>,
+    ),
+    // Incremented on every (re)connection and disconnection, so that the background task of the previous connection stops.
+    connection_id: AtomicU64,
+    pending_requests: AtomicUsize,
+    request_pending: Notify,
+}
+
+impl ConsumerGroupEvents {
+    fn new() -> Self {
+        let (mut sender, receiver) = broadcast(1000);
+        // The oldest rebalances are dropped if not consumed, the latest one always holds the current assignment.
+        sender.set_overflow(true);
+        Self {
+            requested: AtomicBool::new(false),
+            rebalances: (sender, receiver.deactivate()),
+            connection_id: AtomicU64::new(0),
+            pending_requests: AtomicUsize::new(0),
+            request_pending: Notify::new(),
+        }
+    }
+
+    fn publish(&self, rebalance: ConsumerGroupRebalance) {
+        trace!(
+            "Received a rebalance of consumer group with ID: {}, topic with ID: {}, stream with ID: {}, generation: {}",
+            rebalance.group_id,
+            rebalance.topic_id,
+            rebalance.stream_id,
+            rebalance.generation
+        );
+        // There might be no subscribers anymore, in which case the rebalance is simply dropped.
+        let _ = self.rebalances.0.try_broadcast(rebalance);
+    }
+}
+
+/// Marks the request waiting for the connection stream, so that the background task reading the consumer group rebalances releases it.
+struct PendingRequest<'a>(&'a ConsumerGroupEvents);
+
+impl<'a> PendingRequest<'a> {
+    fn new(events: &'a ConsumerGroupEvents) -> Self {
+        events.pending_requests.fetch_add(1, Ordering::SeqCst);
+        events.request_pending.notify_waiters();
+        Self(events)
+    }
+}
+
+impl Drop for PendingRequest<'_> {
+    fn drop(&mut self) {
+        self.0.pending_requests.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 #[async_trait]
@@ -119,6 +196,21 @@ impl TcpConnectionStream {
             writer: BufWriter::new(writer),
         }
     }
+
+    /// Waits until there are some bytes to be read, without consuming them, so it can be safely cancelled.
+    async fn wait_for_data(&mut self) -> Result<(), IggyError> {
+        match self.reader.fill_buf().await {
+            Ok([]) => Err(IggyError::ConnectionClosed),
+            Ok(_) => Ok(()),
+            Err(error) => {
+                error!(
+                    "Failed to wait for data by client: {} from the TCP connection: {error}",
+                    self.client_address
+                );
+                Err(IggyError::TcpError)
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -245,6 +337,37 @@ impl Client for TcpClient {
     async fn subscribe_events(&self) -> Receiver<DiagnosticEvent> {
         self.events.1.clone()
     }
+
+    async fn subscribe_consumer_group_rebalances(
+        &self,
+    ) -> Result<Receiver<ConsumerGroupRebalance>, IggyError> {
+        if self.config.tls_enabled {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        let receiver = self.consumer_group_events.rebalances.1.activate_cloned();
+        if self
+            .consumer_group_events
+            .requested
+            .swap(true, Ordering::SeqCst)
+        {
+            return Ok(receiver);
+        }
+
+        // Otherwise, the consumer group events are negotiated once the client has connected.
+        if matches!(
+            self.get_state().await,
+            ClientState::Connected | ClientState::Authenticating | ClientState::Authenticated
+        ) {
+            if let Err(error) = self.negotiate_consumer_group_events().await {
+                self.consumer_group_events
+                    .requested
+                    .store(false, Ordering::SeqCst);
+                return Err(error);
+            }
+        }
+        Ok(receiver)
+    }
 }
 
 #[async_trait]
@@ -307,6 +430,10 @@ impl BinaryTransport for TcpClient {
     fn get_heartbeat_interval(&self) -> IggyDuration {
         self.config.heartbeat_interval
     }
+
+    fn is_zero_copy_polling_enabled(&self) -> bool {
+        self.zero_copy_polling.load(Ordering::SeqCst)
+    }
 }
 
 impl BinaryClient for TcpClient {}
@@ -354,13 +481,92 @@ impl TcpClient {
         Ok(Self {
             config,
             client_address: Mutex::new(None),
-            stream: Mutex::new(None),
+            stream: Arc::new(Mutex::new(None)),
             state: Mutex::new(ClientState::Disconnected),
             events: broadcast(1000),
             connected_at: Mutex::new(None),
+            frame_checksums: Arc::new(AtomicBool::new(false)),
+            frame_checksum_errors: Arc::new(AtomicU64::new(0)),
+            zero_copy_polling: AtomicBool::new(false),
+            consumer_group_events: Arc::new(ConsumerGroupEvents::new()),
         })
     }
 
+    /// Returns the number of the responses received with an invalid frame checksum, if the frame checksums were negotiated with the server.
+    pub fn frame_checksum_errors(&self) -> u64 {
+        self.frame_checksum_errors.load(Ordering::Relaxed)
+    }
+
+    async fn negotiate_frame_checksums(&self) -> Result<(), IggyError> {
+        let client_address = self.get_client_address_value().await;
+        match self
+            .send_raw(
+                ENABLE_FRAME_CHECKSUMS_CODE,
+                EnableFrameChecksums {}.to_bytes(),
+            )
+            .await
+        {
+            Ok(_) => {
+                self.frame_checksums.store(true, Ordering::SeqCst);
+                info!("{NAME} client: {client_address} has negotiated the frame checksums.");
+            }
+            Err(IggyError::Disconnected) => return Err(IggyError::Disconnected),
+            Err(error) => {
+                warn!("{NAME} client: {client_address} couldn't negotiate the frame checksums, continuing without them. {error}");
+            }
+        }
+        Ok(())
+    }
+
+    async fn negotiate_zero_copy_polling(&self) -> Result<(), IggyError> {
+        let client_address = self.get_client_address_value().await;
+        match self
+            .send_raw(
+                ENABLE_ZERO_COPY_POLLING_CODE,
+                EnableZeroCopyPolling {}.to_bytes(),
+            )
+            .await
+        {
+            Ok(_) => {
+                self.zero_copy_polling.store(true, Ordering::SeqCst);
+                info!("{NAME} client: {client_address} has negotiated the zero-copy polling.");
+            }
+            Err(IggyError::Disconnected) => return Err(IggyError::Disconnected),
+            Err(error) => {
+                warn!("{NAME} client: {client_address} couldn't negotiate the zero-copy polling, continuing without it. {error}");
+            }
+        }
+        Ok(())
+    }
+
+    async fn negotiate_consumer_group_events(&self) -> Result<(), IggyError> {
+        let client_address = self.get_client_address_value().await;
+        self.send_raw(
+            ENABLE_CONSUMER_GROUP_EVENTS_CODE,
+            EnableConsumerGroupEvents {}.to_bytes(),
+        )
+        .await?;
+        info!("{NAME} client: {client_address} has negotiated the consumer group events.");
+        let connection_id = self
+            .consumer_group_events
+            .connection_id
+            .load(Ordering::SeqCst);
+        tokio::spawn(read_consumer_group_events(
+            self.stream.clone(),
+            self.consumer_group_events.clone(),
+            self.frame_checksums.clone(),
+            self.frame_checksum_errors.clone(),
+            connection_id,
+        ));
+        Ok(())
+    }
+
+    /// Locks the connection stream, making the background task reading the consumer group rebalances release it first.
+    async fn lock_stream(&self) -> MutexGuard<'_, Option<ConnectionStreamKind>> {
+        let _pending_request = PendingRequest::new(&self.consumer_group_events);
+        self.stream.lock().await
+    }
+
     async fn handle_response(
         &self,
         status: u32,
@@ -425,6 +631,7 @@ impl TcpClient {
 
         self.set_state(ClientState::Connecting).await;
         if let Some(connected_at) = self.connected_at.lock().await.as_ref() {
+            self.publish_event(DiagnosticEvent::Reconnecting).await;
             let now = IggyTimestamp::now();
             let elapsed = now.as_micros() - connected_at.as_micros();
             let interval = self.config.reconnection.reestablish_after.as_micros();
@@ -473,6 +680,7 @@ impl TcpClient {
                 let interval_str = self.config.reconnection.interval.as_human_time_string();
                 if unlimited_retries || retry_count < max_retries {
                     retry_count += 1;
+                    self.publish_event(DiagnosticEvent::Reconnecting).await;
                     info!(
                         "Retrying to connect to server ({retry_count}/{max_retries_str}): {} in: {interval_str}",
                         self.config.server_address,
@@ -561,10 +769,31 @@ impl TcpClient {
         info!(
             "{NAME} client: {client_address} has connected to server: {remote_address} at: {now}",
         );
-        self.stream.lock().await.replace(connection_stream);
+        self.lock_stream().await.replace(connection_stream);
+        self.frame_checksums.store(false, Ordering::SeqCst);
+        self.zero_copy_polling.store(false, Ordering::SeqCst);
+        self.consumer_group_events
+            .connection_id
+            .fetch_add(1, Ordering::SeqCst);
         self.set_state(ClientState::Connected).await;
         self.connected_at.lock().await.replace(now);
         self.publish_event(DiagnosticEvent::Connected).await;
+        if self.config.frame_checksums {
+            self.negotiate_frame_checksums().await?;
+        }
+        if self.config.zero_copy_polling {
+            self.negotiate_zero_copy_polling().await?;
+        }
+        if self.consumer_group_events.requested.load(Ordering::SeqCst) {
+            match self.negotiate_consumer_group_events().await {
+                Ok(()) => {}
+                Err(IggyError::Disconnected) => return Err(IggyError::Disconnected),
+                Err(error) => {
+                    warn!("{NAME} client: {client_address} couldn't negotiate the consumer group events, continuing without them. {error}");
+                }
+            }
+        }
+
         match &self.config.auto_login {
             AutoLogin::Disabled => {
                 info!("Automatic sign-in is disabled.");
@@ -597,7 +826,12 @@ impl TcpClient {
         let client_address = self.get_client_address_value().await;
         info!("{NAME} client: {client_address} is disconnecting from server...");
         self.set_state(ClientState::Disconnected).await;
-        self.stream.lock().await.take();
+        self.lock_stream().await.take();
+        self.frame_checksums.store(false, Ordering::SeqCst);
+        self.zero_copy_polling.store(false, Ordering::SeqCst);
+        self.consumer_group_events
+            .connection_id
+            .fetch_add(1, Ordering::SeqCst);
         self.publish_event(DiagnosticEvent::Disconnected).await;
         let now = IggyTimestamp::now();
         info!("{NAME} client: {client_address} has disconnected from server at: {now}.");
@@ -611,7 +845,7 @@ impl TcpClient {
 
         let client_address = self.get_client_address_value().await;
         info!("Shutting down the {NAME} TCP client: {client_address}");
-        let stream = self.stream.lock().await.take();
+        let stream = self.lock_stream().await.take();
         if let Some(mut stream) = stream {
             stream.shutdown().await?;
         }
@@ -638,42 +872,74 @@ impl TcpClient {
             _ => {}
         }
 
-        let mut stream = self.stream.lock().await;
+        let mut stream = self.lock_stream().await;
         if let Some(stream) = stream.as_mut() {
+            let frame_checksums = self.frame_checksums.load(Ordering::SeqCst);
             let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
+            let payload_length = (payload_length as u32).to_le_bytes();
+            let code_bytes = code.to_le_bytes();
             trace!("Sending a TCP request with code: {code}");
-            stream.write(&(payload_length as u32).to_le_bytes()).await?;
-            stream.write(&code.to_le_bytes()).await?;
+            stream.write(&payload_length).await?;
+            stream.write(&code_bytes).await?;
             stream.write(&payload).await?;
+            if frame_checksums {
+                let mut frame_checksum = ChecksumHasher::new();
+                frame_checksum.update(&payload_length);
+                frame_checksum.update(&code_bytes);
+                frame_checksum.update(&payload);
+                stream
+                    .write(&frame_checksum.checksum().to_le_bytes())
+                    .await?;
+            }
             stream.flush().await?;
             trace!("Sent a TCP request with code: {code}, waiting for a response...");
 
             let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
-            let read_bytes = stream.read(&mut response_buffer).await.map_err(|error| {
-                error!(
-                    "Failed to read response for TCP request with code: {code}: {error}",
-                    code = code,
-                    error = error
-                );
-                IggyError::Disconnected
-            })?;
+            let (status, length) = loop {
+                let read_bytes = stream.read(&mut response_buffer).await.map_err(|error| {
+                    error!(
+                        "Failed to read response for TCP request with code: {code}: {error}",
+                        code = code,
+                        error = error
+                    );
+                    IggyError::Disconnected
+                })?;
+
+                if read_bytes != RESPONSE_INITIAL_BYTES_LENGTH {
+                    error!("Received an invalid or empty response.");
+                    return Err(IggyError::EmptyResponse);
+                }
+
+                let (status, length) = parse_response_header(&response_buffer)?;
+                if status != CONSUMER_GROUP_EVENT_STATUS {
+                    break (status, length);
+                }
 
-            if read_bytes != RESPONSE_INITIAL_BYTES_LENGTH {
-                error!("Received an invalid or empty response.");
-                return Err(IggyError::EmptyResponse);
+                // The rebalance might have been pushed by the server right before handling the request.
+                let rebalance = read_consumer_group_rebalance(
+                    stream,
+                    &response_buffer,
+                    length,
+                    frame_checksums,
+                    &self.frame_checksum_errors,
+                )
+                .await?;
+                self.consumer_group_events.publish(rebalance);
+            };
+            let response = self.handle_response(status, length, stream).await;
+            if !frame_checksums {
+                return response;
             }
 
-            let status = u32::from_le_bytes(
-                response_buffer[..4]
-                    .try_into()
-                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
-            );
-            let length = u32::from_le_bytes(
-                response_buffer[4..]
-                    .try_into()
-                    .map_err(|_| IggyError::InvalidNumberEncoding)?,
-            );
-            return self.handle_response(status, length, stream).await;
+            let payload = response.as_ref().cloned().unwrap_or_default();
+            verify_frame_checksum(
+                &response_buffer,
+                &payload,
+                stream,
+                &self.frame_checksum_errors,
+            )
+            .await?;
+            return response;
         }
 
         error!("Cannot send data. Client is not connected.");
@@ -689,3 +955,142 @@ impl TcpClient {
         }
     }
 }
+
+fn parse_response_header(header: &[u8]) -> Result<(u32, u32), IggyError> {
+    let status = u32::from_le_bytes(
+        header[..4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let length = u32::from_le_bytes(
+        header[4..]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    Ok((status, length))
+}
+
+async fn verify_frame_checksum(
+    header: &[u8],
+    payload: &[u8],
+    stream: &mut ConnectionStreamKind,
+    frame_checksum_errors: &AtomicU64,
+) -> Result<(), IggyError> {
+    let mut checksum_buffer = [0u8; FRAME_CHECKSUM_LENGTH];
+    stream.read(&mut checksum_buffer).await?;
+    let received_checksum = u32::from_le_bytes(checksum_buffer);
+    let mut frame_checksum = ChecksumHasher::new();
+    frame_checksum.update(header);
+    frame_checksum.update(payload);
+    let expected_checksum = frame_checksum.checksum();
+    if received_checksum != expected_checksum {
+        frame_checksum_errors.fetch_add(1, Ordering::Relaxed);
+        error!("Received a response with an invalid frame checksum: {received_checksum}, expected: {expected_checksum}.");
+        return Err(IggyError::InvalidFrameChecksum(
+            received_checksum,
+            expected_checksum,
+        ));
+    }
+
+    Ok(())
+}
+
+async fn read_consumer_group_rebalance(
+    stream: &mut ConnectionStreamKind,
+    header: &[u8],
+    length: u32,
+    frame_checksums: bool,
+    frame_checksum_errors: &AtomicU64,
+) -> Result<ConsumerGroupRebalance, IggyError> {
+    let mut payload = BytesMut::with_capacity(length as usize);
+    payload.put_bytes(0, length as usize);
+    stream.read(&mut payload).await?;
+    let payload = payload.freeze();
+    if frame_checksums {
+        verify_frame_checksum(header, &payload, stream, frame_checksum_errors).await?;
+    }
+    mapper::map_consumer_group_rebalance(payload)
+}
+
+/// Reads the consumer group rebalances pushed by the server while there's no request in flight,
+/// until the client disconnects or the connection is replaced by the new one.
+async fn read_consumer_group_events(
+    stream: Arc<Mutex<Option<ConnectionStreamKind>>>,
+    events: Arc<ConsumerGroupEvents>,
+    frame_checksums: Arc<AtomicBool>,
+    frame_checksum_errors: Arc<AtomicU64>,
+    connection_id: u64,
+) {
+    loop {
+        let request_pending = events.request_pending.notified();
+        tokio::pin!(request_pending);
+        request_pending.as_mut().enable();
+        if events.pending_requests.load(Ordering::SeqCst) > 0 {
+            tokio::task::yield_now().await;
+            continue;
+        }
+
+        let mut stream = stream.lock().await;
+        if events.connection_id.load(Ordering::SeqCst) != connection_id {
+            trace!("The connection has been closed, stopping the consumer group events reader.");
+            return;
+        }
+
+        let Some(ConnectionStreamKind::Tcp(connection)) = stream.as_mut() else {
+            return;
+        };
+
+        if events.pending_requests.load(Ordering::SeqCst) > 0 {
+            drop(stream);
+            tokio::task::yield_now().await;
+            continue;
+        }
+
+        tokio::select! {
+            biased;
+            _ = &mut request_pending => continue,
+            result = connection.wait_for_data() => {
+                if let Err(error) = result {
+                    // The next request will fail as well and reconnect if needed.
+                    trace!("Stopping the consumer group events reader. {error}");
+                    return;
+                }
+            }
+        }
+
+        let Some(connection) = stream.as_mut() else {
+            return;
+        };
+        let mut header = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
+        let result = match connection.read(&mut header).await {
+            Ok(_) => parse_response_header(&header),
+            Err(error) => Err(error),
+        };
+        let result = match result {
+            Ok((CONSUMER_GROUP_EVENT_STATUS, length)) => {
+                read_consumer_group_rebalance(
+                    connection,
+                    &header,
+                    length,
+                    frame_checksums.load(Ordering::SeqCst),
+                    &frame_checksum_errors,
+                )
+                .await
+            }
+            Ok((status, _)) => {
+                // Only the rebalances can be pushed by the server, so the connection can't be used anymore.
+                error!("Received an unexpected frame with status: {status} while there's no request in flight.");
+                return;
+            }
+            Err(error) => Err(error),
+        };
+        match result {
+            Ok(rebalance) => events.publish(rebalance),
+            Err(IggyError::InvalidFrameChecksum(_, _)) => {}
+            Err(error) => {
+                warn!("Stopping the consumer group events reader. {error}");
+                return;
+            }
+        }
+    }
+}
@@ -39,6 +39,12 @@ pub struct TcpClientConfig {
     pub heartbeat_interval: IggyDuration,
     /// Disable Nagle algorithm for the TCP socket.
     pub nodelay: bool,
+    /// Whether to negotiate the CRC32 checksums of the request and response frames with the server,
+    /// detecting the data corrupted on its way e.g. by the faulty network devices.
+    pub frame_checksums: bool,
+    /// Whether to negotiate the zero-copy polling with the server, which sends the polled batches straight from the closed segments,
+    /// speeding up the replays of the large amounts of messages.
+    pub zero_copy_polling: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -60,6 +66,8 @@ impl Default for TcpClientConfig {
             auto_login: AutoLogin::Disabled,
             reconnection: TcpClientReconnectionConfig::default(),
             nodelay: false,
+            frame_checksums: false,
+            zero_copy_polling: false,
         }
     }
 }
@@ -83,6 +91,8 @@ impl Default for TcpClientReconnectionConfig {
 /// - `tls_enabled`: Default is false.
 /// - `tls_domain`: Default is "localhost".
 /// - `tls_ca_file`: Default is None.
+/// - `frame_checksums`: Default is false.
+/// - `zero_copy_polling`: Default is false.
 #[derive(Debug, Default)]
 pub struct TcpClientConfigBuilder {
     config: TcpClientConfig,
@@ -146,6 +156,18 @@ impl TcpClientConfigBuilder {
         self
     }
 
+    /// Enables the negotiation of the frame checksums with the server.
+    pub fn with_frame_checksums(mut self) -> Self {
+        self.config.frame_checksums = true;
+        self
+    }
+
+    /// Enables the negotiation of the zero-copy polling with the server.
+    pub fn with_zero_copy_polling(mut self) -> Self {
+        self.config.zero_copy_polling = true;
+        self
+    }
+
     /// Builds the TCP client configuration.
     pub fn build(self) -> TcpClientConfig {
         self.config
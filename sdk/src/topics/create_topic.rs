@@ -21,6 +21,9 @@ use crate::command::{Command, CREATE_TOPIC_CODE};
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::topic_options::{
+    read_optional_topic_options, write_optional_topic_options, TopicOptions,
+};
 use crate::topics::{MAX_NAME_LENGTH, MAX_PARTITIONS_COUNT};
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::sizeable::Sizeable;
@@ -41,7 +44,9 @@ use std::str::from_utf8;
 ///                      Can't be lower than segment size in the config.
 /// - `replication_factor` - replication factor for the topic.
 /// - `name` - unique topic name, max length is 255 characters.
+/// - `options` - optional settings (schema and policies) of the topic, see `TopicOptions`.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateTopic {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
@@ -61,6 +66,9 @@ pub struct CreateTopic {
     pub replication_factor: Option<u8>,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
+    /// Optional settings (schema and policies) of the topic.
+    #[serde(flatten)]
+    pub options: TopicOptions,
 }
 
 impl Command for CreateTopic {
@@ -80,6 +88,7 @@ impl Default for CreateTopic {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: None,
             name: "topic".to_string(),
+            options: TopicOptions::default(),
         }
     }
 }
@@ -106,6 +115,8 @@ impl Validatable<IggyError> for CreateTopic {
             }
         }
 
+        self.options.validate()?;
+
         Ok(())
     }
 }
@@ -127,6 +138,7 @@ impl BytesSerializable for CreateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        write_optional_topic_options(&self.options, &mut bytes);
         bytes.freeze()
     }
 
@@ -172,6 +184,8 @@ impl BytesSerializable for CreateTopic {
         if name.len() != name_length as usize {
             return Err(IggyError::InvalidCommand);
         }
+        let position = position + 27 + name_length as usize;
+        let (options, _) = read_optional_topic_options(&bytes, position)?;
         let command = CreateTopic {
             stream_id,
             topic_id,
@@ -181,6 +195,7 @@ impl BytesSerializable for CreateTopic {
             max_topic_size,
             replication_factor,
             name,
+            options,
         };
         Ok(command)
     }
@@ -190,14 +205,15 @@ impl Display for CreateTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id.unwrap_or(0),
             self.partitions_count,
             self.message_expiry,
             self.max_topic_size,
             self.replication_factor.unwrap_or(0),
-            self.name
+            self.name,
+            self.options
         )
     }
 }
@@ -205,6 +221,18 @@ impl Display for CreateTopic {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::compaction_policy::CompactionPolicy;
+    use crate::models::dead_letter_policy::DeadLetterPolicy;
+    use crate::models::fsync_policy::FsyncPolicy;
+    use crate::models::header::HeaderKey;
+    use crate::models::message_sampling_policy::MessageSamplingPolicy;
+    use crate::models::queue_policy::QueuePolicy;
+    use crate::models::retention_policy::RetentionPolicy;
+    use crate::models::segment_rollover_policy::SegmentRolloverPolicy;
+    use crate::models::tiering_policy::TieringPolicy;
+    use crate::models::topic_schema::TopicSchema;
+    use crate::utils::byte_size::IggyByteSize;
+    use crate::utils::duration::IggyDuration;
     use bytes::BufMut;
 
     #[test]
@@ -218,6 +246,7 @@ mod tests {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
             name: "test".to_string(),
+            options: TopicOptions::default(),
         };
         let bytes = command.to_bytes();
         let mut position = 0;
@@ -288,4 +317,52 @@ mod tests {
         assert_eq!(command.replication_factor.unwrap(), replication_factor);
         assert_eq!(command.partitions_count, partitions_count);
     }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_options() {
+        let command = CreateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            options: TopicOptions {
+                schema: Some(TopicSchema::protobuf(
+                    Bytes::from_static(&[1, 2, 3]),
+                    "orders.OrderCreated",
+                )),
+                dead_letter_policy: Some(DeadLetterPolicy::new(
+                    Identifier::numeric(1).unwrap(),
+                    Identifier::named("dlq").unwrap(),
+                    3,
+                )),
+                sampling_policy: Some(MessageSamplingPolicy::new(
+                    Identifier::numeric(1).unwrap(),
+                    Identifier::named("diagnostics").unwrap(),
+                    0.1,
+                    128,
+                )),
+                compaction_policy: Some(CompactionPolicy::by_header(
+                    HeaderKey::new("entity-id").unwrap(),
+                )),
+                retention_policy: Some(RetentionPolicy::time_and_size(Some(IggyByteSize::from(
+                    1_000_000,
+                )))),
+                tiering_policy: Some(TieringPolicy {
+                    min_segment_age: Some(IggyDuration::new_from_secs(3600)),
+                    max_local_partition_size: Some(IggyByteSize::from(1_000_000)),
+                }),
+                fsync_policy: Some(FsyncPolicy {
+                    every_n_messages: Some(1000),
+                    interval: Some(IggyDuration::new_from_secs(1)),
+                }),
+                segment_rollover_policy: Some(SegmentRolloverPolicy::max_age(
+                    IggyDuration::new_from_secs(3600),
+                )),
+                queue_policy: Some(QueuePolicy::visibility_timeout(
+                    IggyDuration::new_from_secs(30),
+                )),
+            },
+            ..Default::default()
+        };
+
+        let deserialized = CreateTopic::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
 }
@@ -27,9 +27,13 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 /// `DeleteTopic` command is used to delete a topic from a stream.
+/// The topic is removed immediately, unless the deferred deletion is requested, in which case it's only marked as being deleted,
+/// rejecting the new messages, and its data is removed once the consumer groups have consumed all of its messages
+/// or the deletion grace period configured on the server has elapsed.
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
+/// - `deferred` - whether to wait for the consumer groups, before removing the topic.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct DeleteTopic {
     /// Unique stream ID (numeric or name).
@@ -38,6 +42,9 @@ pub struct DeleteTopic {
     /// Unique topic ID (numeric or name).
     #[serde(skip)]
     pub topic_id: Identifier,
+    /// Whether to wait for the consumer groups, before removing the topic.
+    #[serde(default)]
+    pub deferred: bool,
 }
 
 impl Command for DeleteTopic {
@@ -56,9 +63,10 @@ impl BytesSerializable for DeleteTopic {
     fn to_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.to_bytes();
         let topic_id_bytes = self.topic_id.to_bytes();
-        let mut bytes = BytesMut::with_capacity(stream_id_bytes.len() + topic_id_bytes.len());
+        let mut bytes = BytesMut::with_capacity(stream_id_bytes.len() + topic_id_bytes.len() + 1);
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(if self.deferred { 1 } else { 0 });
         bytes.freeze()
     }
 
@@ -71,9 +79,17 @@ impl BytesSerializable for DeleteTopic {
         let stream_id = Identifier::from_bytes(bytes.clone())?;
         position += stream_id.get_size_bytes().as_bytes_usize();
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        // The flag is optional, so that the command sent by the older clients is still accepted.
+        let deferred = match bytes.get(position) {
+            None | Some(0) => false,
+            Some(1) => true,
+            Some(_) => return Err(IggyError::InvalidCommand),
+        };
         let command = DeleteTopic {
             stream_id,
             topic_id,
+            deferred,
         };
         Ok(command)
     }
@@ -81,7 +97,7 @@ impl BytesSerializable for DeleteTopic {
 
 impl Display for DeleteTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.stream_id, self.topic_id)
+        write!(f, "{}|{}|{}", self.stream_id, self.topic_id, self.deferred)
     }
 }
 
@@ -96,6 +112,7 @@ mod tests {
         let command = DeleteTopic {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
+            deferred: true,
         };
 
         let bytes = command.to_bytes();
@@ -103,10 +120,13 @@ mod tests {
         let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
         position += stream_id.get_size_bytes().as_bytes_usize();
         let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let deferred = bytes[position] == 1;
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
+        assert_eq!(deferred, command.deferred);
     }
 
     #[test]
@@ -116,11 +136,28 @@ mod tests {
         let mut bytes = BytesMut::new();
         bytes.put(stream_id.to_bytes());
         bytes.put(topic_id.to_bytes());
+        bytes.put_u8(1);
         let command = DeleteTopic::from_bytes(bytes.freeze());
         assert!(command.is_ok());
 
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
+        assert!(command.deferred);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_deferred_flag() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put(stream_id.to_bytes());
+        bytes.put(topic_id.to_bytes());
+
+        let command = DeleteTopic::from_bytes(bytes.freeze()).unwrap();
+
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert!(!command.deferred);
     }
 }
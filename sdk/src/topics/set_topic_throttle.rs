@@ -0,0 +1,219 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, SET_TOPIC_THROTTLE_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `SetTopicThrottle` command is used to set or clear the temporary throughput cap of the topic at runtime.
+/// When set, the messages appended to the topic above the cap are rejected until the throughput drops below it.
+/// The throttle is kept only in memory, so it's cleared on the server restart.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `max_throughput` - optional maximum size of the messages appended per second, when not set, the throttle is cleared.
+/// - `duration` - optional duration after which the throttle is lifted, when not set, it lasts until cleared.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SetTopicThrottle {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Optional maximum size of the messages appended per second, when not set, the throttle is cleared.
+    #[serde(default)]
+    pub max_throughput: Option<IggyByteSize>,
+    /// Optional duration after which the throttle is lifted.
+    #[serde(default)]
+    pub duration: Option<IggyDuration>,
+}
+
+impl Command for SetTopicThrottle {
+    fn code(&self) -> u32 {
+        SET_TOPIC_THROTTLE_CODE
+    }
+}
+
+impl Validatable<IggyError> for SetTopicThrottle {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self
+            .max_throughput
+            .is_some_and(|throughput| throughput.as_bytes_u64() == 0)
+        {
+            return Err(IggyError::InvalidTopicThrottle(
+                "max throughput must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.duration.is_some_and(|duration| duration.is_zero()) {
+            return Err(IggyError::InvalidTopicThrottle(
+                "duration must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.max_throughput.is_none() && self.duration.is_some() {
+            return Err(IggyError::InvalidTopicThrottle(
+                "duration can't be set without max throughput".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for SetTopicThrottle {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(16 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u64_le(
+            self.max_throughput
+                .map_or(0, |throughput| throughput.as_bytes_u64()),
+        );
+        bytes.put_u64_le(self.duration.map_or(0, |duration| duration.as_micros()));
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<SetTopicThrottle, IggyError> {
+        if bytes.len() < 26 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 16 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let max_throughput = u64::from_le_bytes(
+            bytes[position..position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let duration = u64::from_le_bytes(
+            bytes[position + 8..position + 16]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let command = SetTopicThrottle {
+            stream_id,
+            topic_id,
+            max_throughput: match max_throughput {
+                0 => None,
+                throughput => Some(IggyByteSize::from(throughput)),
+            },
+            duration: match duration {
+                0 => None,
+                duration => Some(IggyDuration::from(duration)),
+            },
+        };
+        Ok(command)
+    }
+}
+
+impl Display for SetTopicThrottle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.max_throughput
+                .map_or("none".to_string(), |throughput| throughput
+                    .as_human_string()),
+            self.duration.map_or("none".to_string(), |duration| duration
+                .as_human_time_string())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = SetTopicThrottle {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            max_throughput: Some(IggyByteSize::from(1_000_000)),
+            duration: Some(IggyDuration::new_from_secs(60)),
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let max_throughput = u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap());
+        let duration = u64::from_le_bytes(bytes[position + 8..position + 16].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(max_throughput, 1_000_000);
+        assert_eq!(duration, 60_000_000);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::named("orders").unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&stream_id.to_bytes());
+        bytes.put_slice(&topic_id.to_bytes());
+        bytes.put_u64_le(5_000_000);
+        bytes.put_u64_le(0);
+        let command = SetTopicThrottle::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.max_throughput, Some(IggyByteSize::from(5_000_000)));
+        assert_eq!(command.duration, None);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_duration_without_max_throughput() {
+        let command = SetTopicThrottle {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            max_throughput: None,
+            duration: Some(IggyDuration::new_from_secs(60)),
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
@@ -21,6 +21,9 @@ use crate::command::{Command, UPDATE_TOPIC_CODE};
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::topic_options::{
+    read_optional_topic_options, write_optional_topic_options, TopicOptions,
+};
 use crate::topics::MAX_NAME_LENGTH;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::sizeable::Sizeable;
@@ -40,7 +43,10 @@ use std::str::from_utf8;
 ///                      Can't be lower than segment size in the config.
 /// - `replication_factor` - replication factor for the topic.
 /// - `name` - unique topic name, max length is 255 characters.
+/// - `options` - optional settings (schema and policies) of the topic, replacing the current ones (the unset ones are removed), see `TopicOptions`.
+///               If `None` then the current settings are kept.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateTopic {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
@@ -59,6 +65,10 @@ pub struct UpdateTopic {
     pub replication_factor: Option<u8>,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
+    /// Optional settings (schema and policies) of the topic, replacing the current ones (the unset ones are removed).
+    /// If `None` then the current settings are kept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<TopicOptions>,
 }
 
 impl Command for UpdateTopic {
@@ -77,6 +87,7 @@ impl Default for UpdateTopic {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: None,
             name: "topic".to_string(),
+            options: None,
         }
     }
 }
@@ -93,6 +104,10 @@ impl Validatable<IggyError> for UpdateTopic {
             }
         }
 
+        if let Some(options) = &self.options {
+            options.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -116,6 +131,9 @@ impl BytesSerializable for UpdateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        if let Some(options) = &self.options {
+            write_optional_topic_options(options, &mut bytes);
+        }
         bytes.freeze()
     }
 
@@ -153,6 +171,11 @@ impl BytesSerializable for UpdateTopic {
         if name.len() != name_length as usize {
             return Err(IggyError::InvalidCommand);
         }
+        let position = position + 18 + name_length as usize;
+        let options = match bytes.get(position) {
+            None => None,
+            Some(_) => Some(read_optional_topic_options(&bytes, position)?.0),
+        };
         let command = UpdateTopic {
             stream_id,
             topic_id,
@@ -161,6 +184,7 @@ impl BytesSerializable for UpdateTopic {
             max_topic_size,
             replication_factor,
             name,
+            options,
         };
         Ok(command)
     }
@@ -170,13 +194,16 @@ impl Display for UpdateTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.message_expiry,
             self.max_topic_size,
             self.replication_factor.unwrap_or(0),
             self.name,
+            self.options
+                .as_ref()
+                .map_or("unchanged_options".to_string(), ToString::to_string)
         )
     }
 }
@@ -184,7 +211,18 @@ impl Display for UpdateTopic {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::compaction_policy::CompactionPolicy;
+    use crate::models::dead_letter_policy::DeadLetterPolicy;
+    use crate::models::fsync_policy::FsyncPolicy;
+    use crate::models::header::HeaderKey;
+    use crate::models::message_sampling_policy::MessageSamplingPolicy;
+    use crate::models::queue_policy::QueuePolicy;
+    use crate::models::retention_policy::RetentionPolicy;
+    use crate::models::segment_rollover_policy::SegmentRolloverPolicy;
+    use crate::models::tiering_policy::TieringPolicy;
+    use crate::models::topic_schema::TopicSchema;
     use crate::utils::byte_size::IggyByteSize;
+    use crate::utils::duration::IggyDuration;
     use bytes::BufMut;
 
     #[test]
@@ -197,6 +235,7 @@ mod tests {
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
             name: "test".to_string(),
+            options: None,
         };
 
         let bytes = command.to_bytes();
@@ -265,5 +304,66 @@ mod tests {
         assert_eq!(command.max_topic_size, max_topic_size);
         assert_eq!(command.replication_factor, Some(replication_factor));
         assert_eq!(command.name, name);
+        assert!(command.options.is_none());
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_options() {
+        let command = UpdateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            options: Some(TopicOptions {
+                schema: Some(TopicSchema::protobuf(
+                    Bytes::from_static(&[1, 2, 3]),
+                    "orders.OrderCreated",
+                )),
+                dead_letter_policy: Some(DeadLetterPolicy::new(
+                    Identifier::numeric(1).unwrap(),
+                    Identifier::named("dlq").unwrap(),
+                    3,
+                )),
+                sampling_policy: Some(MessageSamplingPolicy::new(
+                    Identifier::numeric(1).unwrap(),
+                    Identifier::named("diagnostics").unwrap(),
+                    0.1,
+                    128,
+                )),
+                compaction_policy: Some(CompactionPolicy::by_header(
+                    HeaderKey::new("entity-id").unwrap(),
+                )),
+                retention_policy: Some(RetentionPolicy::time_and_size(Some(IggyByteSize::from(
+                    1_000_000,
+                )))),
+                tiering_policy: Some(TieringPolicy {
+                    min_segment_age: Some(IggyDuration::new_from_secs(3600)),
+                    max_local_partition_size: Some(IggyByteSize::from(1_000_000)),
+                }),
+                fsync_policy: Some(FsyncPolicy {
+                    every_n_messages: Some(1000),
+                    interval: Some(IggyDuration::new_from_secs(1)),
+                }),
+                segment_rollover_policy: Some(SegmentRolloverPolicy::max_age(
+                    IggyDuration::new_from_secs(3600),
+                )),
+                queue_policy: Some(QueuePolicy::visibility_timeout(
+                    IggyDuration::new_from_secs(30),
+                )),
+            }),
+            ..Default::default()
+        };
+
+        let deserialized = UpdateTopic::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_cleared_options() {
+        let command = UpdateTopic {
+            options: Some(TopicOptions::default()),
+            ..Default::default()
+        };
+
+        let deserialized = UpdateTopic::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized.options, Some(TopicOptions::default()));
     }
 }
@@ -35,6 +35,7 @@ use std::str::from_utf8;
 /// - `status` - status of the user, can be either `active` or `inactive`.
 /// - `permissions` - optional permissions of the user. If not provided, user will have no permissions.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateUser {
     /// Unique name of the user, must be between 3 and 50 characters long.
     pub username: String,
@@ -79,6 +80,10 @@ impl Validatable<IggyError> for CreateUser {
             return Err(IggyError::InvalidPassword);
         }
 
+        if let Some(permissions) = &self.permissions {
+            permissions.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -191,6 +196,7 @@ mod tests {
                 global: GlobalPermissions {
                     manage_servers: false,
                     read_servers: true,
+                    manage_config: false,
                     manage_users: false,
                     read_users: true,
                     manage_streams: false,
@@ -201,6 +207,7 @@ mod tests {
                     send_messages: true,
                 },
                 streams: None,
+                patterns: None,
             }),
         };
 
@@ -242,6 +249,7 @@ mod tests {
             global: GlobalPermissions {
                 manage_servers: false,
                 read_servers: true,
+                manage_config: false,
                 manage_users: false,
                 read_users: true,
                 manage_streams: false,
@@ -252,6 +260,7 @@ mod tests {
                 send_messages: true,
             },
             streams: None,
+            patterns: None,
         };
         let mut bytes = BytesMut::new();
         #[allow(clippy::cast_possible_truncation)]
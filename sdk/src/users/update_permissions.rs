@@ -32,6 +32,7 @@ use std::fmt::Display;
 /// - `user_id` - unique user ID (numeric or name).
 /// - `permissions` - new permissions (optional)
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdatePermissions {
     /// Unique user ID (numeric or name).
     #[serde(skip)]
@@ -48,6 +49,10 @@ impl Command for UpdatePermissions {
 
 impl Validatable<IggyError> for UpdatePermissions {
     fn validate(&self) -> Result<(), IggyError> {
+        if let Some(permissions) = &self.permissions {
+            permissions.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -165,6 +170,7 @@ mod tests {
             global: GlobalPermissions {
                 manage_servers: true,
                 read_servers: true,
+                manage_config: false,
                 manage_users: true,
                 read_users: true,
                 manage_streams: false,
@@ -175,6 +181,7 @@ mod tests {
                 send_messages: false,
             },
             streams: None,
+            patterns: None,
         }
     }
 }
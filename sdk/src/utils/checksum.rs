@@ -19,3 +19,51 @@
 pub fn calculate(data: &[u8]) -> u32 {
     crc32fast::hash(data)
 }
+
+/// Calculates the checksum of the data provided in multiple chunks, e.g. the protocol frame read from the stream.
+#[derive(Clone, Default)]
+pub struct ChecksumHasher {
+    hasher: crc32fast::Hasher,
+}
+
+impl ChecksumHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    pub fn reset(&mut self) {
+        self.hasher.reset();
+    }
+
+    /// Returns the checksum of the data provided so far, without consuming the hasher.
+    pub fn checksum(&self) -> u32 {
+        self.hasher.clone().finalize()
+    }
+}
+
+impl std::fmt::Debug for ChecksumHasher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ChecksumHasher({})", self.checksum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum_of_chunks_should_be_equal_to_checksum_of_whole_data() {
+        let mut hasher = ChecksumHasher::new();
+        hasher.update(b"iggy ");
+        hasher.update(b"frame");
+        assert_eq!(hasher.checksum(), calculate(b"iggy frame"));
+
+        hasher.reset();
+        hasher.update(b"frame");
+        assert_eq!(hasher.checksum(), calculate(b"frame"));
+    }
+}
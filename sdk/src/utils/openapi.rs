@@ -0,0 +1,79 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! The OpenAPI schemas of the types with the custom serialization, used by the HTTP API specification.
+
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::utils::topic_size::MaxTopicSize;
+use utoipa::openapi::schema::{Schema, Type};
+use utoipa::openapi::{KnownFormat, ObjectBuilder, RefOr, SchemaFormat};
+use utoipa::{PartialSchema, ToSchema};
+
+macro_rules! impl_schema {
+    ($type:ty, $schema_type:expr, $format:expr, $description:literal) => {
+        impl PartialSchema for $type {
+            fn schema() -> RefOr<Schema> {
+                ObjectBuilder::new()
+                    .schema_type($schema_type)
+                    .format($format)
+                    .description(Some($description))
+                    .into()
+            }
+        }
+
+        impl ToSchema for $type {}
+    };
+}
+
+const U64: Option<SchemaFormat> = Some(SchemaFormat::KnownFormat(KnownFormat::UInt64));
+
+impl_schema!(
+    IggyTimestamp,
+    Type::Integer,
+    U64,
+    "The number of microseconds since the Unix epoch."
+);
+impl_schema!(
+    IggyDuration,
+    Type::Integer,
+    U64,
+    "The duration in microseconds."
+);
+impl_schema!(IggyByteSize, Type::Integer, U64, "The size in bytes.");
+impl_schema!(
+    IggyExpiry,
+    Type::Integer,
+    U64,
+    "The message expiry in microseconds, 0 for the server default and 18446744073709551615 to never expire."
+);
+impl_schema!(
+    MaxTopicSize,
+    Type::Integer,
+    U64,
+    "The maximum topic size in bytes, 0 for the server default and 18446744073709551615 for unlimited."
+);
+impl_schema!(
+    CompressionAlgorithm,
+    Type::String,
+    None,
+    "The compression algorithm, either `none` or `gzip`."
+);
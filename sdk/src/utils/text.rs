@@ -32,3 +32,22 @@ pub fn from_base64_as_bytes(value: &str) -> Result<Vec<u8>, IggyError> {
 pub fn as_base64(value: &[u8]) -> String {
     general_purpose::STANDARD.encode(value)
 }
+
+pub fn from_hex_as_bytes(value: &str) -> Result<Vec<u8>, IggyError> {
+    if !value.len().is_multiple_of(2) {
+        return Err(IggyError::InvalidFormat);
+    }
+
+    value
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair).map_err(|_| IggyError::InvalidFormat)?;
+            u8::from_str_radix(pair, 16).map_err(|_| IggyError::InvalidFormat)
+        })
+        .collect()
+}
+
+pub fn as_hex(value: &[u8]) -> String {
+    value.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -30,4 +30,47 @@ pub struct Args {
         help = "Remove system path (local_data by default) before starting. THIS WILL REMOVE ALL SAVED DATA!"
     )]
     pub fresh: bool,
+
+    #[arg(
+        long,
+        default_value_t = false,
+        help = "Run as a proxy forwarding the binary protocol (TCP) to the upstream server with the injected latency, jitter and connection resets configured in the [proxy] section."
+    )]
+    pub proxy: bool,
+
+    #[arg(
+        long,
+        default_value_t = false,
+        help = "Rebuild the indexes of all the segments from their log files, and shut down once all the partitions are loaded."
+    )]
+    pub rebuild_indexes: bool,
+
+    #[arg(
+        long,
+        default_value_t = false,
+        help = "Encrypt all the existing plaintext segments in place with the master key configured in the [system.segment_encryption] section, and shut down once done."
+    )]
+    pub encrypt_segments: bool,
+
+    #[arg(
+        long,
+        default_value_t = false,
+        help = "Check the integrity of the data directory (streams, topics and partitions against the state, segments, indexes and orphaned files), and exit without starting the server."
+    )]
+    pub fsck: bool,
+
+    #[arg(
+        long,
+        default_value_t = false,
+        requires = "fsck",
+        help = "Repair the issues found by `--fsck` which can be fixed without losing the valid data."
+    )]
+    pub repair: bool,
+
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Restore the backup with the given name from the archive directory (or download it from S3, if not found locally) before starting the server, the replaced state and streams are kept in the backup directory."
+    )]
+    pub restore: Option<String>,
 }
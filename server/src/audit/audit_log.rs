@@ -0,0 +1,249 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::system::AuditConfig;
+use crate::streaming::diagnostics::metrics::TransportLabel;
+use iggy::models::audit_entry::{AuditEntry, AuditOutcome};
+use iggy::models::user_info::UserId;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::VecDeque;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tracing::warn;
+
+/// The append-only log of the administrative actions handled by the server.
+/// The most recent entries are kept in memory to be queried, while the pending ones are taken periodically
+/// by the background task and appended to the configured sink.
+#[derive(Debug)]
+pub struct AuditLog {
+    enabled: bool,
+    max_entries: usize,
+    next_id: AtomicU64,
+    entries: Mutex<VecDeque<AuditEntry>>,
+    pending: Mutex<Vec<AuditEntry>>,
+}
+
+impl AuditLog {
+    pub fn new(config: &AuditConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            max_entries: config.max_entries as usize,
+            next_id: AtomicU64::new(1),
+            entries: Mutex::new(VecDeque::new()),
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Records the outcome of the administrative action, which failed if the error is present.
+    /// The details must not contain any secrets.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn record(
+        &self,
+        timestamp: IggyTimestamp,
+        user_id: UserId,
+        address: SocketAddr,
+        transport: TransportLabel,
+        action: &str,
+        details: String,
+        error: Option<String>,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let outcome = if error.is_some() {
+            AuditOutcome::Failure
+        } else {
+            AuditOutcome::Success
+        };
+        let entry = AuditEntry {
+            id: self.next_id.fetch_add(1, Ordering::AcqRel),
+            timestamp,
+            user_id,
+            address: address.to_string(),
+            transport: transport.to_string(),
+            action: action.to_string(),
+            details,
+            outcome,
+            error,
+        };
+
+        {
+            let mut pending = self.pending.lock().unwrap();
+            if pending.len() >= self.max_entries {
+                warn!(
+                    "Audit log sink is lagging behind, dropping the pending entry with ID: {}.",
+                    pending[0].id
+                );
+                pending.remove(0);
+            }
+            pending.push(entry.clone());
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= self.max_entries {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// Restores the entries persisted by the sink before the restart, so that they can be still queried.
+    pub fn restore(&self, restored_entries: Vec<AuditEntry>) {
+        let Some(last_id) = restored_entries.last().map(|entry| entry.id) else {
+            return;
+        };
+
+        self.next_id.fetch_max(last_id + 1, Ordering::AcqRel);
+        let mut entries = self.entries.lock().unwrap();
+        let recorded_entries = std::mem::take(&mut *entries);
+        entries.extend(restored_entries);
+        entries.extend(recorded_entries);
+        while entries.len() > self.max_entries {
+            entries.pop_front();
+        }
+    }
+
+    /// Returns the most recent entries matching the filters, from the newest to the oldest.
+    pub fn get_entries(
+        &self,
+        user_id: Option<UserId>,
+        action: Option<&str>,
+        limit: usize,
+    ) -> Vec<AuditEntry> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .iter()
+            .rev()
+            .filter(|entry| user_id.is_none_or(|user_id| entry.user_id == user_id))
+            .filter(|entry| action.is_none_or(|action| entry.action == action))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Takes the entries which haven't been appended to the sink yet.
+    pub fn take_pending(&self) -> Vec<AuditEntry> {
+        std::mem::take(&mut *self.pending.lock().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::error::IggyError;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn should_record_entries_and_return_them_from_the_newest() {
+        let audit_log = get_audit_log(10);
+        record(&audit_log, 1, "stream.create", None);
+        record(
+            &audit_log,
+            2,
+            "stream.delete",
+            Some(IggyError::Unauthorized.to_string()),
+        );
+
+        let entries = audit_log.get_entries(None, None, 10);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, 2);
+        assert_eq!(entries[0].action, "stream.delete");
+        assert_eq!(entries[0].outcome, AuditOutcome::Failure);
+        assert!(entries[0].error.is_some());
+        assert_eq!(entries[1].id, 1);
+        assert_eq!(entries[1].transport, "tcp");
+        assert_eq!(entries[1].outcome, AuditOutcome::Success);
+        assert_eq!(audit_log.take_pending().len(), 2);
+        assert!(audit_log.take_pending().is_empty());
+    }
+
+    #[test]
+    fn should_filter_entries_by_user_and_action() {
+        let audit_log = get_audit_log(10);
+        record(&audit_log, 1, "stream.create", None);
+        record(&audit_log, 2, "stream.create", None);
+        record(&audit_log, 1, "user.delete", None);
+
+        assert_eq!(audit_log.get_entries(Some(1), None, 10).len(), 2);
+        assert_eq!(
+            audit_log.get_entries(None, Some("stream.create"), 10).len(),
+            2
+        );
+        assert_eq!(
+            audit_log
+                .get_entries(Some(1), Some("user.delete"), 10)
+                .len(),
+            1
+        );
+        assert_eq!(audit_log.get_entries(None, None, 1).len(), 1);
+    }
+
+    #[test]
+    fn should_keep_only_max_entries_and_continue_ids_after_restore() {
+        let audit_log = get_audit_log(2);
+        let restored = get_audit_log(2);
+        record(&restored, 1, "stream.create", None);
+        record(&restored, 1, "stream.update", None);
+        audit_log.restore(restored.take_pending());
+        record(&audit_log, 1, "stream.delete", None);
+
+        let entries = audit_log.get_entries(None, None, 10);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, 3);
+        assert_eq!(entries[0].action, "stream.delete");
+        assert_eq!(entries[1].id, 2);
+    }
+
+    #[test]
+    fn should_not_record_entries_when_disabled() {
+        let audit_log = AuditLog::new(&AuditConfig {
+            enabled: false,
+            ..AuditConfig::default()
+        });
+        record(&audit_log, 1, "stream.create", None);
+
+        assert!(audit_log.get_entries(None, None, 10).is_empty());
+        assert!(audit_log.take_pending().is_empty());
+    }
+
+    fn get_audit_log(max_entries: u32) -> AuditLog {
+        AuditLog::new(&AuditConfig {
+            enabled: true,
+            max_entries,
+            ..AuditConfig::default()
+        })
+    }
+
+    fn record(audit_log: &AuditLog, user_id: UserId, action: &str, error: Option<String>) {
+        audit_log.record(
+            IggyTimestamp::now(),
+            user_id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+            TransportLabel::Tcp,
+            action,
+            "1|test".to_string(),
+            error,
+        );
+    }
+}
@@ -0,0 +1,170 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::audit::{AuditSink, COMPONENT};
+use crate::server_error::AuditError;
+use error_set::ErrContext;
+use iggy::models::audit_entry::AuditEntry;
+use std::collections::VecDeque;
+use std::path::Path;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tracing::{info, warn};
+
+/// Appends the audit entries to the file as JSON lines.
+#[derive(Debug)]
+pub struct FileAuditSink {
+    path: String,
+}
+
+impl FileAuditSink {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_owned(),
+        }
+    }
+
+    /// Loads the most recent entries from the file, e.g. to be queried after the restart.
+    pub async fn load(&self, max_entries: usize) -> Result<Vec<AuditEntry>, AuditError> {
+        if !Path::new(&self.path).exists() {
+            return Ok(Vec::new());
+        }
+
+        let file = fs::File::open(&self.path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to open audit log file: {}",
+                    self.path
+                )
+            })?;
+        let mut lines = BufReader::new(file).lines();
+        let mut entries = VecDeque::with_capacity(max_entries);
+        while let Some(line) = lines.next_line().await? {
+            let entry = match serde_json::from_str::<AuditEntry>(&line) {
+                Ok(entry) => entry,
+                Err(error) => {
+                    warn!(
+                        "Skipping invalid audit log entry in file: {}. {error}",
+                        self.path
+                    );
+                    continue;
+                }
+            };
+            if entries.len() == max_entries {
+                entries.pop_front();
+            }
+            entries.push_back(entry);
+        }
+        Ok(entries.into())
+    }
+}
+
+impl AuditSink for FileAuditSink {
+    async fn init(&self) -> Result<(), AuditError> {
+        let Some(directory) = Path::new(&self.path).parent() else {
+            return Ok(());
+        };
+
+        if !directory.exists() {
+            info!("Creating audit log directory: {}", directory.display());
+            fs::create_dir_all(directory)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to create audit log directory: {}",
+                        directory.display()
+                    )
+                })?;
+        }
+        Ok(())
+    }
+
+    async fn write(&self, entries: &[AuditEntry]) -> Result<(), AuditError> {
+        let mut bytes = Vec::new();
+        for entry in entries {
+            serde_json::to_writer(&mut bytes, entry).map_err(|error| {
+                AuditError::InvalidAuditEntry {
+                    reason: error.to_string(),
+                }
+            })?;
+            bytes.push(b'\n');
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to open audit log file: {}",
+                    self.path
+                )
+            })?;
+        file.write_all(&bytes).await.with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to write audit log file: {}",
+                self.path
+            )
+        })?;
+        file.sync_data().await.with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to sync audit log file: {}",
+                self.path
+            )
+        })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::audit_entry::AuditOutcome;
+    use iggy::utils::timestamp::IggyTimestamp;
+
+    #[tokio::test]
+    async fn should_append_entries_and_load_the_most_recent_ones() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("audit").join("audit.log");
+        let sink = FileAuditSink::new(path.to_str().unwrap());
+        sink.init().await.unwrap();
+
+        sink.write(&[get_entry(1), get_entry(2)]).await.unwrap();
+        sink.write(&[get_entry(3)]).await.unwrap();
+        let entries = sink.load(2).await.unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0], get_entry(2));
+        assert_eq!(entries[1], get_entry(3));
+    }
+
+    fn get_entry(id: u64) -> AuditEntry {
+        AuditEntry {
+            id,
+            timestamp: IggyTimestamp::from(1_000),
+            user_id: 1,
+            address: "127.0.0.1:1234".to_string(),
+            transport: "tcp".to_string(),
+            action: "stream.create".to_string(),
+            details: format!("{id}|test"),
+            outcome: AuditOutcome::Success,
+            error: None,
+        }
+    }
+}
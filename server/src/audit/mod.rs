@@ -0,0 +1,101 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+pub mod audit_log;
+pub mod file;
+pub mod syslog;
+pub mod topic;
+
+use crate::audit::file::FileAuditSink;
+use crate::audit::syslog::SyslogAuditSink;
+use crate::audit::topic::TopicAuditSink;
+use crate::configs::system::SystemConfig;
+use crate::server_error::AuditError;
+use crate::streaming::systems::system::SharedSystem;
+use derive_more::Display;
+use iggy::models::audit_entry::AuditEntry;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::str::FromStr;
+
+pub const COMPONENT: &str = "AUDIT";
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditSinkKindType {
+    #[default]
+    #[display("file")]
+    File,
+    #[display("syslog")]
+    Syslog,
+    #[display("topic")]
+    Topic,
+}
+
+impl FromStr for AuditSinkKindType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "file" => Ok(AuditSinkKindType::File),
+            "syslog" => Ok(AuditSinkKindType::Syslog),
+            "topic" => Ok(AuditSinkKindType::Topic),
+            _ => Err(format!("Unknown audit sink kind: {}", s)),
+        }
+    }
+}
+
+/// The destination to which the recorded audit entries are appended, in the order of their IDs.
+pub trait AuditSink: Send {
+    fn init(&self) -> impl Future<Output = Result<(), AuditError>> + Send;
+    fn write(&self, entries: &[AuditEntry]) -> impl Future<Output = Result<(), AuditError>> + Send;
+}
+
+#[derive(Debug)]
+pub enum AuditSinkKind {
+    File(FileAuditSink),
+    Syslog(SyslogAuditSink),
+    Topic(TopicAuditSink),
+}
+
+impl AuditSinkKind {
+    pub fn from_config(config: &SystemConfig, system: SharedSystem) -> Result<Self, AuditError> {
+        let sink = match config.audit.sink {
+            AuditSinkKindType::File => Self::File(FileAuditSink::new(&config.get_audit_log_path())),
+            AuditSinkKindType::Syslog => Self::Syslog(SyslogAuditSink::new(&config.audit.syslog)?),
+            AuditSinkKindType::Topic => {
+                Self::Topic(TopicAuditSink::new(&config.audit.topic, system)?)
+            }
+        };
+        Ok(sink)
+    }
+
+    pub async fn init(&self) -> Result<(), AuditError> {
+        match self {
+            Self::File(s) => s.init().await,
+            Self::Syslog(s) => s.init().await,
+            Self::Topic(s) => s.init().await,
+        }
+    }
+
+    pub async fn write(&self, entries: &[AuditEntry]) -> Result<(), AuditError> {
+        match self {
+            Self::File(s) => s.write(entries).await,
+            Self::Syslog(s) => s.write(entries).await,
+            Self::Topic(s) => s.write(entries).await,
+        }
+    }
+}
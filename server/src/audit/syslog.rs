@@ -0,0 +1,138 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::audit::{AuditSink, COMPONENT};
+use crate::configs::system::AuditSyslogSinkConfig;
+use crate::server_error::AuditError;
+use error_set::ErrContext;
+use iggy::models::audit_entry::{AuditEntry, AuditOutcome};
+use std::net::SocketAddr;
+use tokio::net::UdpSocket;
+
+const APP_NAME: &str = "iggy-server";
+// The security/authorization messages facility, as defined in RFC 5424.
+const FACILITY_AUTHPRIV: u8 = 10;
+const SEVERITY_WARNING: u8 = 4;
+const SEVERITY_NOTICE: u8 = 5;
+
+/// Sends the audit entries to the syslog server via UDP, as RFC 5424 messages with the JSON entry as the message body.
+#[derive(Debug)]
+pub struct SyslogAuditSink {
+    address: SocketAddr,
+    hostname: String,
+}
+
+impl SyslogAuditSink {
+    pub fn new(config: &AuditSyslogSinkConfig) -> Result<Self, AuditError> {
+        let address = config
+            .address
+            .parse()
+            .map_err(|_| AuditError::InvalidSyslogAddress {
+                address: config.address.clone(),
+            })?;
+        Ok(Self {
+            address,
+            hostname: sysinfo::System::host_name().unwrap_or("-".to_string()),
+        })
+    }
+
+    fn format_message(&self, entry: &AuditEntry) -> Result<String, AuditError> {
+        let severity = match entry.outcome {
+            AuditOutcome::Success => SEVERITY_NOTICE,
+            AuditOutcome::Failure => SEVERITY_WARNING,
+        };
+        let priority = FACILITY_AUTHPRIV * 8 + severity;
+        let json = serde_json::to_string(entry).map_err(|error| AuditError::InvalidAuditEntry {
+            reason: error.to_string(),
+        })?;
+        Ok(format!(
+            "<{priority}>1 {} {} {APP_NAME} {} {} - {json}",
+            entry.timestamp.to_utc_string("%Y-%m-%dT%H:%M:%S%.6fZ"),
+            self.hostname,
+            std::process::id(),
+            entry.action
+        ))
+    }
+}
+
+impl AuditSink for SyslogAuditSink {
+    async fn init(&self) -> Result<(), AuditError> {
+        Ok(())
+    }
+
+    async fn write(&self, entries: &[AuditEntry]) -> Result<(), AuditError> {
+        let local_address = if self.address.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = UdpSocket::bind(local_address)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to bind syslog socket")
+            })?;
+        for entry in entries {
+            let message = self.format_message(entry)?;
+            socket
+                .send_to(message.as_bytes(), self.address)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to send audit entry with ID: {} to syslog: {}",
+                        entry.id, self.address
+                    )
+                })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::timestamp::IggyTimestamp;
+
+    #[tokio::test]
+    async fn should_send_entries_as_rfc5424_messages() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let sink = SyslogAuditSink::new(&AuditSyslogSinkConfig {
+            address: server.local_addr().unwrap().to_string(),
+        })
+        .unwrap();
+        let entry = AuditEntry {
+            id: 1,
+            timestamp: IggyTimestamp::from(0),
+            user_id: 1,
+            address: "127.0.0.1:1234".to_string(),
+            transport: "http".to_string(),
+            action: "user.delete".to_string(),
+            details: "2".to_string(),
+            outcome: AuditOutcome::Failure,
+            error: Some("unauthorized".to_string()),
+        };
+
+        sink.write(&[entry]).await.unwrap();
+        let mut buffer = [0u8; 1024];
+        let length = server.recv(&mut buffer).await.unwrap();
+        let message = std::str::from_utf8(&buffer[..length]).unwrap();
+
+        assert!(message.starts_with("<84>1 1970-01-01T00:00:00.000000Z "));
+        assert!(message.contains(" iggy-server "));
+        assert!(message.contains(" user.delete - {"));
+        assert!(message.ends_with("\"error\":\"unauthorized\"}"));
+    }
+}
@@ -0,0 +1,117 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::audit::{AuditSink, COMPONENT};
+use crate::configs::system::AuditTopicSinkConfig;
+use crate::server_error::AuditError;
+use crate::streaming::segments::IggyMessagesMut;
+use crate::streaming::systems::system::SharedSystem;
+use bytes::Bytes;
+use error_set::ErrContext;
+use iggy::confirmation::ConfirmationLevel;
+use iggy::identifier::Identifier;
+use iggy::models::audit_entry::AuditEntry;
+use iggy::prelude::*;
+use tracing::error;
+
+/// Appends the audit entries as JSON messages to the existing topic, using the balanced partitioning.
+/// The messages are appended directly to the topic, so they are neither sampled nor audited themselves.
+#[derive(Debug)]
+pub struct TopicAuditSink {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    system: SharedSystem,
+}
+
+impl TopicAuditSink {
+    pub fn new(config: &AuditTopicSinkConfig, system: SharedSystem) -> Result<Self, AuditError> {
+        let stream_id = Identifier::from_str_value(&config.stream).map_err(|_| {
+            AuditError::AuditTopicNotFound {
+                topic: format!("{}/{}", config.stream, config.topic),
+            }
+        })?;
+        let topic_id = Identifier::from_str_value(&config.topic).map_err(|_| {
+            AuditError::AuditTopicNotFound {
+                topic: format!("{}/{}", config.stream, config.topic),
+            }
+        })?;
+        Ok(Self {
+            stream_id,
+            topic_id,
+            system,
+        })
+    }
+
+    fn topic_name(&self) -> String {
+        format!("{}/{}", self.stream_id, self.topic_id)
+    }
+}
+
+impl AuditSink for TopicAuditSink {
+    async fn init(&self) -> Result<(), AuditError> {
+        let system = self.system.read().await;
+        if let Err(error) = system
+            .get_stream(&self.stream_id)
+            .and_then(|stream| stream.get_topic(&self.topic_id))
+        {
+            // The topic might be created later on, so the entries are kept being recorded in the meantime.
+            error!(
+                "{COMPONENT} - audit topic: {} was not found, the entries will not be appended until it's created. {error}",
+                self.topic_name()
+            );
+        }
+        Ok(())
+    }
+
+    async fn write(&self, entries: &[AuditEntry]) -> Result<(), AuditError> {
+        let mut messages = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let payload =
+                serde_json::to_vec(entry).map_err(|error| AuditError::InvalidAuditEntry {
+                    reason: error.to_string(),
+                })?;
+            messages.push(IggyMessage::new(Bytes::from(payload)));
+        }
+
+        let system = self.system.read().await;
+        let topic = system
+            .get_stream(&self.stream_id)
+            .and_then(|stream| stream.get_topic(&self.topic_id))
+            .map_err(|_| AuditError::AuditTopicNotFound {
+                topic: self.topic_name(),
+            })?;
+        topic
+            .append_messages(
+                &Partitioning::balanced(),
+                IggyMessagesMut::from(messages.as_slice()),
+                ConfirmationLevel::default(),
+                system.clock().now(),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to append {} audit entries to topic: {}",
+                    entries.len(),
+                    self.topic_name()
+                )
+            })
+            .map_err(|_| AuditError::CannotAppendAuditEntries {
+                topic: self.topic_name(),
+            })?;
+        Ok(())
+    }
+}
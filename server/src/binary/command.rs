@@ -27,6 +27,7 @@ use crate::binary::handlers::personal_access_tokens::{
     create_personal_access_token_handler, delete_personal_access_token_handler,
     get_personal_access_tokens_handler, login_with_personal_access_token_handler,
 };
+use crate::binary::handlers::schemas::*;
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
 use crate::binary::handlers::topics::*;
@@ -40,45 +41,79 @@ use crate::binary::COMPONENT;
 use crate::define_server_command_enum;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::request_lanes::RequestLane;
 use bytes::{BufMut, Bytes, BytesMut};
 use enum_dispatch::enum_dispatch;
 use error_set::ErrContext;
+use iggy::bookmarks::delete_bookmark::DeleteBookmark;
+use iggy::bookmarks::get_bookmark::GetBookmark;
+use iggy::bookmarks::get_bookmarks::GetBookmarks;
+use iggy::bookmarks::store_bookmark::StoreBookmark;
 use iggy::command::*;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use iggy::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use iggy::consumer_offsets::delete_consumer_offset::DeleteConsumerOffset;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::abort_transaction::AbortTransaction;
+use iggy::messages::ack_messages::AckMessages;
+use iggy::messages::begin_transaction::BeginTransaction;
+use iggy::messages::commit_transaction::CommitTransaction;
 use iggy::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
+use iggy::messages::nack_messages::NackMessages;
 use iggy::messages::poll_messages::PollMessages;
+use iggy::messages::reject_messages::RejectMessages;
+use iggy::messages::send_offsets_to_transaction::SendOffsetsToTransaction;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::get_partition::GetPartition;
+use iggy::partitions::move_partition::MovePartition;
+use iggy::partitions::update_partition::UpdatePartition;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use iggy::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use iggy::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use iggy::quotas::get_quotas::GetQuotas;
+use iggy::quotas::set_quota::SetQuota;
+use iggy::schemas::get_schema::GetSchema;
+use iggy::schemas::get_schemas::GetSchemas;
+use iggy::schemas::get_subject_schema::GetSubjectSchema;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use iggy::segments::verify_segments::VerifySegments;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
 use iggy::streams::get_streams::GetStreams;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::system::create_backup::CreateBackup;
+use iggy::system::enable_consumer_group_events::EnableConsumerGroupEvents;
+use iggy::system::enable_frame_checksums::EnableFrameChecksums;
+use iggy::system::enable_zero_copy_polling::EnableZeroCopyPolling;
+use iggy::system::get_audit_log::GetAuditLog;
 use iggy::system::get_client::GetClient;
 use iggy::system::get_clients::GetClients;
+use iggy::system::get_cluster_metadata::GetClusterMetadata;
+use iggy::system::get_config::GetConfig;
 use iggy::system::get_me::GetMe;
 use iggy::system::get_snapshot::GetSnapshot;
 use iggy::system::get_stats::GetStats;
 use iggy::system::ping::Ping;
+use iggy::system::restore_backup::RestoreBackup;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::get_topic::GetTopic;
 use iggy::topics::get_topics::GetTopics;
 use iggy::topics::purge_topic::PurgeTopic;
+use iggy::topics::set_topic_throttle::SetTopicThrottle;
 use iggy::topics::update_topic::UpdateTopic;
 use iggy::users::change_password::ChangePassword;
 use iggy::users::create_user::CreateUser;
@@ -95,13 +130,28 @@ use tracing::{debug, error};
 
 define_server_command_enum! {
     Ping(Ping), PING_CODE, PING, false;
+    EnableFrameChecksums(EnableFrameChecksums), ENABLE_FRAME_CHECKSUMS_CODE, ENABLE_FRAME_CHECKSUMS, false;
+    EnableZeroCopyPolling(EnableZeroCopyPolling), ENABLE_ZERO_COPY_POLLING_CODE, ENABLE_ZERO_COPY_POLLING, false;
+    EnableConsumerGroupEvents(EnableConsumerGroupEvents), ENABLE_CONSUMER_GROUP_EVENTS_CODE, ENABLE_CONSUMER_GROUP_EVENTS, false;
     GetStats(GetStats), GET_STATS_CODE, GET_STATS, false;
+    GetConfig(GetConfig), GET_CONFIG_CODE, GET_CONFIG, false;
+    GetAuditLog(GetAuditLog), GET_AUDIT_LOG_CODE, GET_AUDIT_LOG, true;
+    CreateBackup(CreateBackup), CREATE_BACKUP_CODE, CREATE_BACKUP, true;
+    RestoreBackup(RestoreBackup), RESTORE_BACKUP_CODE, RESTORE_BACKUP, true;
+    GetClusterMetadata(GetClusterMetadata), GET_CLUSTER_METADATA_CODE, GET_CLUSTER_METADATA, false;
     GetMe(GetMe), GET_ME_CODE, GET_ME, false;
     GetClient(GetClient), GET_CLIENT_CODE, GET_CLIENT, true;
     GetClients(GetClients), GET_CLIENTS_CODE, GET_CLIENTS, false;
     GetSnapshot(GetSnapshot), GET_SNAPSHOT_FILE_CODE, GET_SNAPSHOT_FILE, false;
     PollMessages(PollMessages), POLL_MESSAGES_CODE, POLL_MESSAGES, true;
     FlushUnsavedBuffer(FlushUnsavedBuffer), FLUSH_UNSAVED_BUFFER_CODE, FLUSH_UNSAVED_BUFFER, true;
+    RejectMessages(RejectMessages), REJECT_MESSAGES_CODE, REJECT_MESSAGES, true;
+    NackMessages(NackMessages), NACK_MESSAGES_CODE, NACK_MESSAGES, true;
+    BeginTransaction(BeginTransaction), BEGIN_TRANSACTION_CODE, BEGIN_TRANSACTION, false;
+    CommitTransaction(CommitTransaction), COMMIT_TRANSACTION_CODE, COMMIT_TRANSACTION, true;
+    AbortTransaction(AbortTransaction), ABORT_TRANSACTION_CODE, ABORT_TRANSACTION, true;
+    SendOffsetsToTransaction(SendOffsetsToTransaction), SEND_OFFSETS_TO_TRANSACTION_CODE, SEND_OFFSETS_TO_TRANSACTION, true;
+    AckMessages(AckMessages), ACK_MESSAGES_CODE, ACK_MESSAGES, true;
     GetUser(GetUser), GET_USER_CODE, GET_USER, true;
     GetUsers(GetUsers), GET_USERS_CODE, GET_USERS, false;
     CreateUser(CreateUser), CREATE_USER_CODE, CREATE_USER, true;
@@ -119,6 +169,10 @@ define_server_command_enum! {
     GetConsumerOffset(GetConsumerOffset), GET_CONSUMER_OFFSET_CODE, GET_CONSUMER_OFFSET, true;
     StoreConsumerOffset(StoreConsumerOffset), STORE_CONSUMER_OFFSET_CODE, STORE_CONSUMER_OFFSET, true;
     DeleteConsumerOffset(DeleteConsumerOffset), DELETE_CONSUMER_OFFSET_CODE, DELETE_CONSUMER_OFFSET, true;
+    GetBookmark(GetBookmark), GET_BOOKMARK_CODE, GET_BOOKMARK, true;
+    GetBookmarks(GetBookmarks), GET_BOOKMARKS_CODE, GET_BOOKMARKS, false;
+    StoreBookmark(StoreBookmark), STORE_BOOKMARK_CODE, STORE_BOOKMARK, true;
+    DeleteBookmark(DeleteBookmark), DELETE_BOOKMARK_CODE, DELETE_BOOKMARK, true;
     GetStream(GetStream), GET_STREAM_CODE, GET_STREAM, true;
     GetStreams(GetStreams), GET_STREAMS_CODE, GET_STREAMS, false;
     CreateStream(CreateStream), CREATE_STREAM_CODE, CREATE_STREAM, true;
@@ -131,14 +185,184 @@ define_server_command_enum! {
     DeleteTopic(DeleteTopic), DELETE_TOPIC_CODE, DELETE_TOPIC, true;
     UpdateTopic(UpdateTopic), UPDATE_TOPIC_CODE, UPDATE_TOPIC, true;
     PurgeTopic(PurgeTopic), PURGE_TOPIC_CODE, PURGE_TOPIC, true;
+    SetTopicThrottle(SetTopicThrottle), SET_TOPIC_THROTTLE_CODE, SET_TOPIC_THROTTLE, true;
+    GetPartition(GetPartition), GET_PARTITION_CODE, GET_PARTITION, true;
     CreatePartitions(CreatePartitions), CREATE_PARTITIONS_CODE, CREATE_PARTITIONS, true;
     DeletePartitions(DeletePartitions), DELETE_PARTITIONS_CODE, DELETE_PARTITIONS, true;
+    UpdatePartition(UpdatePartition), UPDATE_PARTITION_CODE, UPDATE_PARTITION, true;
+    MovePartition(MovePartition), MOVE_PARTITION_CODE, MOVE_PARTITION, true;
+    VerifySegments(VerifySegments), VERIFY_SEGMENTS_CODE, VERIFY_SEGMENTS, true;
     GetConsumerGroup(GetConsumerGroup), GET_CONSUMER_GROUP_CODE, GET_CONSUMER_GROUP, true;
     GetConsumerGroups(GetConsumerGroups), GET_CONSUMER_GROUPS_CODE, GET_CONSUMER_GROUPS, false;
     CreateConsumerGroup(CreateConsumerGroup), CREATE_CONSUMER_GROUP_CODE, CREATE_CONSUMER_GROUP, true;
     DeleteConsumerGroup(DeleteConsumerGroup), DELETE_CONSUMER_GROUP_CODE, DELETE_CONSUMER_GROUP, true;
     JoinConsumerGroup(JoinConsumerGroup), JOIN_CONSUMER_GROUP_CODE, JOIN_CONSUMER_GROUP, true;
     LeaveConsumerGroup(LeaveConsumerGroup), LEAVE_CONSUMER_GROUP_CODE, LEAVE_CONSUMER_GROUP, true;
+    HeartbeatConsumerGroup(HeartbeatConsumerGroup), HEARTBEAT_CONSUMER_GROUP_CODE, HEARTBEAT_CONSUMER_GROUP, true;
+    GetSchema(GetSchema), GET_SCHEMA_CODE, GET_SCHEMA, true;
+    GetSchemas(GetSchemas), GET_SCHEMAS_CODE, GET_SCHEMAS, true;
+    GetSubjectSchema(GetSubjectSchema), GET_SUBJECT_SCHEMA_CODE, GET_SUBJECT_SCHEMA, true;
+    RegisterSchema(RegisterSchema), REGISTER_SCHEMA_CODE, REGISTER_SCHEMA, true;
+    UpdateSchemaCompatibility(UpdateSchemaCompatibility), UPDATE_SCHEMA_COMPATIBILITY_CODE, UPDATE_SCHEMA_COMPATIBILITY, true;
+    GetQuotas(GetQuotas), GET_QUOTAS_CODE, GET_QUOTAS, false;
+    SetQuota(SetQuota), SET_QUOTA_CODE, SET_QUOTA, true;
+}
+
+impl ServerCommand {
+    /// Returns true if the command changes the users, permissions or the resources of the server,
+    /// in which case it's recorded in the audit log.
+    pub fn is_administrative(&self) -> bool {
+        matches!(
+            self,
+            ServerCommand::CreateUser(_)
+                | ServerCommand::DeleteUser(_)
+                | ServerCommand::UpdateUser(_)
+                | ServerCommand::UpdatePermissions(_)
+                | ServerCommand::ChangePassword(_)
+                | ServerCommand::CreatePersonalAccessToken(_)
+                | ServerCommand::DeletePersonalAccessToken(_)
+                | ServerCommand::CreateStream(_)
+                | ServerCommand::DeleteStream(_)
+                | ServerCommand::UpdateStream(_)
+                | ServerCommand::PurgeStream(_)
+                | ServerCommand::CreateTopic(_)
+                | ServerCommand::DeleteTopic(_)
+                | ServerCommand::UpdateTopic(_)
+                | ServerCommand::PurgeTopic(_)
+                | ServerCommand::SetTopicThrottle(_)
+                | ServerCommand::CreatePartitions(_)
+                | ServerCommand::DeletePartitions(_)
+                | ServerCommand::UpdatePartition(_)
+                | ServerCommand::MovePartition(_)
+                | ServerCommand::CreateConsumerGroup(_)
+                | ServerCommand::DeleteConsumerGroup(_)
+                | ServerCommand::RegisterSchema(_)
+                | ServerCommand::UpdateSchemaCompatibility(_)
+                | ServerCommand::SetQuota(_)
+                | ServerCommand::CreateBackup(_)
+                | ServerCommand::RestoreBackup(_)
+        )
+    }
+
+    /// Returns true if the command changes the metadata replicated across the cluster nodes,
+    /// so it can be handled by the cluster leader only.
+    pub fn requires_cluster_leader(&self) -> bool {
+        self.is_administrative()
+            && !matches!(
+                self,
+                ServerCommand::CreateBackup(_) | ServerCommand::SetQuota(_)
+            )
+    }
+
+    /// Returns the lane in which the command is processed - the commands producing and consuming the messages
+    /// share the data lane, while the rest of them (e.g. managing the resources or fetching the stats)
+    /// have their own lane, so that they aren't starved behind the bursts of the data-plane traffic.
+    pub fn lane(&self) -> RequestLane {
+        match self {
+            ServerCommand::SendMessages(_)
+            | ServerCommand::PollMessages(_)
+            | ServerCommand::FlushUnsavedBuffer(_)
+            | ServerCommand::RejectMessages(_)
+            | ServerCommand::NackMessages(_)
+            | ServerCommand::AckMessages(_)
+            | ServerCommand::BeginTransaction(_)
+            | ServerCommand::CommitTransaction(_)
+            | ServerCommand::AbortTransaction(_)
+            | ServerCommand::SendOffsetsToTransaction(_)
+            | ServerCommand::GetConsumerOffset(_)
+            | ServerCommand::StoreConsumerOffset(_)
+            | ServerCommand::DeleteConsumerOffset(_) => RequestLane::Data,
+            _ => RequestLane::Admin,
+        }
+    }
+
+    /// Returns the name of the command along with the identifiers of the stream and topic it's performed on (if any),
+    /// so that the decisions of the external authorizer can be resolved before taking the system lock.
+    /// The actions authorized while handling the command are derived from its name by the permissioner.
+    pub fn authorization(&self) -> CommandAuthorization<'_> {
+        let (stream_id, topic_id) = match self {
+            ServerCommand::GetStream(command) => (Some(&command.stream_id), None),
+            ServerCommand::UpdateStream(command) => (Some(&command.stream_id), None),
+            ServerCommand::DeleteStream(command) => (Some(&command.stream_id), None),
+            ServerCommand::PurgeStream(command) => (Some(&command.stream_id), None),
+            ServerCommand::GetTopics(command) => (Some(&command.stream_id), None),
+            ServerCommand::CreateTopic(command) => (Some(&command.stream_id), None),
+            ServerCommand::GetTopic(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::GetPartition(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::UpdateTopic(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::DeleteTopic(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::PurgeTopic(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::SetTopicThrottle(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::CreatePartitions(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::DeletePartitions(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::UpdatePartition(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::MovePartition(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::VerifySegments(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::GetConsumerGroup(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::GetConsumerGroups(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::CreateConsumerGroup(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::DeleteConsumerGroup(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::JoinConsumerGroup(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::LeaveConsumerGroup(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::HeartbeatConsumerGroup(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::GetConsumerOffset(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::StoreConsumerOffset(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::DeleteConsumerOffset(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::GetBookmark(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::GetBookmarks(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::StoreBookmark(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::DeleteBookmark(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::SendOffsetsToTransaction(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            ServerCommand::PollMessages(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::AckMessages(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::NackMessages(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::RejectMessages(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::SendMessages(command) => topic(&command.stream_id, &command.topic_id),
+            ServerCommand::FlushUnsavedBuffer(command) => {
+                topic(&command.stream_id, &command.topic_id)
+            }
+            _ => (None, None),
+        };
+        (self.name(), stream_id, topic_id)
+    }
+}
+
+/// The name of the command along with the identifiers of the stream and topic it's performed on (if any).
+pub type CommandAuthorization<'a> = (&'static str, Option<&'a Identifier>, Option<&'a Identifier>);
+
+fn topic<'a>(
+    stream_id: &'a Identifier,
+    topic_id: &'a Identifier,
+) -> (Option<&'a Identifier>, Option<&'a Identifier>) {
+    (Some(stream_id), Some(topic_id))
 }
 
 #[enum_dispatch]
@@ -188,11 +412,51 @@ mod tests {
             PING_CODE,
             &Ping::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::EnableFrameChecksums(EnableFrameChecksums::default()),
+            ENABLE_FRAME_CHECKSUMS_CODE,
+            &EnableFrameChecksums::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::EnableZeroCopyPolling(EnableZeroCopyPolling::default()),
+            ENABLE_ZERO_COPY_POLLING_CODE,
+            &EnableZeroCopyPolling::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::EnableConsumerGroupEvents(EnableConsumerGroupEvents::default()),
+            ENABLE_CONSUMER_GROUP_EVENTS_CODE,
+            &EnableConsumerGroupEvents::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetStats(GetStats::default()),
             GET_STATS_CODE,
             &GetStats::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetConfig(GetConfig::default()),
+            GET_CONFIG_CODE,
+            &GetConfig::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetAuditLog(GetAuditLog::default()),
+            GET_AUDIT_LOG_CODE,
+            &GetAuditLog::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CreateBackup(CreateBackup::default()),
+            CREATE_BACKUP_CODE,
+            &CreateBackup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RestoreBackup(RestoreBackup::default()),
+            RESTORE_BACKUP_CODE,
+            &RestoreBackup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetClusterMetadata(GetClusterMetadata::default()),
+            GET_CLUSTER_METADATA_CODE,
+            &GetClusterMetadata::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetMe(GetMe::default()),
             GET_ME_CODE,
@@ -288,6 +552,26 @@ mod tests {
             GET_CONSUMER_OFFSET_CODE,
             &GetConsumerOffset::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetBookmark(GetBookmark::default()),
+            GET_BOOKMARK_CODE,
+            &GetBookmark::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetBookmarks(GetBookmarks::default()),
+            GET_BOOKMARKS_CODE,
+            &GetBookmarks::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::StoreBookmark(StoreBookmark::default()),
+            STORE_BOOKMARK_CODE,
+            &StoreBookmark::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DeleteBookmark(DeleteBookmark::default()),
+            DELETE_BOOKMARK_CODE,
+            &DeleteBookmark::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetStream(GetStream::default()),
             GET_STREAM_CODE,
@@ -348,6 +632,16 @@ mod tests {
             PURGE_TOPIC_CODE,
             &PurgeTopic::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SetTopicThrottle(SetTopicThrottle::default()),
+            SET_TOPIC_THROTTLE_CODE,
+            &SetTopicThrottle::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetPartition(GetPartition::default()),
+            GET_PARTITION_CODE,
+            &GetPartition::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::CreatePartitions(CreatePartitions::default()),
             CREATE_PARTITIONS_CODE,
@@ -358,6 +652,21 @@ mod tests {
             DELETE_PARTITIONS_CODE,
             &DeletePartitions::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdatePartition(UpdatePartition::default()),
+            UPDATE_PARTITION_CODE,
+            &UpdatePartition::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::MovePartition(MovePartition::default()),
+            MOVE_PARTITION_CODE,
+            &MovePartition::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::VerifySegments(VerifySegments::default()),
+            VERIFY_SEGMENTS_CODE,
+            &VerifySegments::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetConsumerGroup(GetConsumerGroup::default()),
             GET_CONSUMER_GROUP_CODE,
@@ -388,11 +697,121 @@ mod tests {
             LEAVE_CONSUMER_GROUP_CODE,
             &LeaveConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::HeartbeatConsumerGroup(HeartbeatConsumerGroup::default()),
+            HEARTBEAT_CONSUMER_GROUP_CODE,
+            &HeartbeatConsumerGroup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSchema(GetSchema::default()),
+            GET_SCHEMA_CODE,
+            &GetSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSchemas(GetSchemas::default()),
+            GET_SCHEMAS_CODE,
+            &GetSchemas::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSubjectSchema(GetSubjectSchema::default()),
+            GET_SUBJECT_SCHEMA_CODE,
+            &GetSubjectSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RegisterSchema(RegisterSchema::default()),
+            REGISTER_SCHEMA_CODE,
+            &RegisterSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateSchemaCompatibility(UpdateSchemaCompatibility::default()),
+            UPDATE_SCHEMA_COMPATIBILITY_CODE,
+            &UpdateSchemaCompatibility::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetQuotas(GetQuotas::default()),
+            GET_QUOTAS_CODE,
+            &GetQuotas::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SetQuota(SetQuota::default()),
+            SET_QUOTA_CODE,
+            &SetQuota::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::FlushUnsavedBuffer(FlushUnsavedBuffer::default()),
             FLUSH_UNSAVED_BUFFER_CODE,
             &FlushUnsavedBuffer::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RejectMessages(RejectMessages::default()),
+            REJECT_MESSAGES_CODE,
+            &RejectMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::NackMessages(NackMessages::default()),
+            NACK_MESSAGES_CODE,
+            &NackMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::BeginTransaction(BeginTransaction::default()),
+            BEGIN_TRANSACTION_CODE,
+            &BeginTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CommitTransaction(CommitTransaction::default()),
+            COMMIT_TRANSACTION_CODE,
+            &CommitTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::AbortTransaction(AbortTransaction::default()),
+            ABORT_TRANSACTION_CODE,
+            &AbortTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SendOffsetsToTransaction(SendOffsetsToTransaction::default()),
+            SEND_OFFSETS_TO_TRANSACTION_CODE,
+            &SendOffsetsToTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::AckMessages(AckMessages::default()),
+            ACK_MESSAGES_CODE,
+            &AckMessages::default(),
+        );
+    }
+
+    #[test]
+    fn only_commands_changing_the_server_should_be_administrative() {
+        assert!(ServerCommand::CreateStream(CreateStream::default()).is_administrative());
+        assert!(ServerCommand::UpdatePermissions(UpdatePermissions::default()).is_administrative());
+        assert!(ServerCommand::PurgeTopic(PurgeTopic::default()).is_administrative());
+        assert!(ServerCommand::CreateBackup(CreateBackup::default()).is_administrative());
+        assert!(!ServerCommand::GetStreams(GetStreams::default()).is_administrative());
+        assert!(!ServerCommand::SendMessages(SendMessages::default()).is_administrative());
+        assert!(!ServerCommand::GetAuditLog(GetAuditLog::default()).is_administrative());
+    }
+
+    #[test]
+    fn only_commands_producing_and_consuming_messages_should_use_data_lane() {
+        assert_eq!(
+            ServerCommand::SendMessages(SendMessages::default()).lane(),
+            RequestLane::Data
+        );
+        assert_eq!(
+            ServerCommand::PollMessages(PollMessages::default()).lane(),
+            RequestLane::Data
+        );
+        assert_eq!(
+            ServerCommand::StoreConsumerOffset(StoreConsumerOffset::default()).lane(),
+            RequestLane::Data
+        );
+        assert_eq!(
+            ServerCommand::CreateTopic(CreateTopic::default()).lane(),
+            RequestLane::Admin
+        );
+        assert_eq!(
+            ServerCommand::GetStats(GetStats::default()).lane(),
+            RequestLane::Admin
+        );
     }
 
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
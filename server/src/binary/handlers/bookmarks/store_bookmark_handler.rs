@@ -0,0 +1,73 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::bookmarks::COMPONENT;
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::bookmarks::store_bookmark::StoreBookmark;
+use iggy::error::IggyError;
+use tracing::debug;
+
+impl ServerCommandHandler for StoreBookmark {
+    fn code(&self) -> u32 {
+        iggy::command::STORE_BOOKMARK_CODE
+    }
+
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+        let system = system.read().await;
+        system
+            .store_bookmark(
+                session,
+                &self.stream_id,
+                &self.topic_id,
+                &self.name,
+                self.partition_id,
+                self.offset,
+            )
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store bookmark: {} for stream_id: {}, topic_id: {}, partition_id: {}, offset: {}, session: {}",
+                self.name, self.stream_id, self.topic_id, self.partition_id, self.offset, session
+            ))?;
+        sender.send_empty_ok_response().await?;
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for StoreBookmark {
+    async fn from_sender(sender: &mut SenderKind, code: u32, length: u32) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::StoreBookmark(store_bookmark) => Ok(store_bookmark),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
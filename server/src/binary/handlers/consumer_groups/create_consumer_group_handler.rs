@@ -26,6 +26,7 @@ use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use error_set::ErrContext;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::get_consumer_group::LEGACY_DETAILS_VERSION;
 use iggy::error::IggyError;
 use tracing::{debug, instrument};
 
@@ -52,6 +53,7 @@ impl ServerCommandHandler for CreateConsumerGroup {
                     &self.topic_id,
                     self.group_id,
                     &self.name,
+                    self.assignment_strategy,
                 )
                 .await
                 .with_error_context(|error| {
@@ -61,7 +63,7 @@ impl ServerCommandHandler for CreateConsumerGroup {
                     )
                 })?;
         let consumer_group = consumer_group.read().await;
-        let response = mapper::map_consumer_group(&consumer_group).await;
+        let response = mapper::map_consumer_group(&consumer_group, LEGACY_DETAILS_VERSION).await;
 
         let consumer_group_id = consumer_group.group_id;
 
@@ -72,9 +74,9 @@ impl ServerCommandHandler for CreateConsumerGroup {
         let topic_id = self.topic_id.clone();
         let group_id = self.group_id;
 
-        system
+        let commit = system
             .state
-            .apply(
+            .append(
                 session.get_user_id(),
                 &EntryCommand::CreateConsumerGroup(CreateConsumerGroupWithId { group_id: consumer_group_id, command: self }),
             )
@@ -86,6 +88,8 @@ impl ServerCommandHandler for CreateConsumerGroup {
                     stream_id, topic_id, group_id, session
                 )
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_ok_response(&response).await?;
         Ok(())
     }
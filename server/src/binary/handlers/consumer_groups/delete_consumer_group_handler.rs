@@ -61,9 +61,9 @@ impl ServerCommandHandler for DeleteConsumerGroup {
         let topic_id = self.topic_id.clone();
         let group_id = self.group_id.clone();
 
-        system
+        let commit = system
             .state
-            .apply(
+            .append(
                 session.get_user_id(),
                 &EntryCommand::DeleteConsumerGroup(self),
             )
@@ -74,6 +74,8 @@ impl ServerCommandHandler for DeleteConsumerGroup {
                     stream_id, topic_id, group_id, session
                 )
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
@@ -54,7 +54,8 @@ impl ServerCommandHandler for GetConsumerGroup {
         };
 
         let consumer_group = consumer_group.read().await;
-        let consumer_group = mapper::map_consumer_group(&consumer_group).await;
+        let consumer_group =
+            mapper::map_consumer_group(&consumer_group, self.details_version).await;
         sender.send_ok_response(&consumer_group).await?;
         Ok(())
     }
@@ -48,6 +48,7 @@ impl ServerCommandHandler for JoinConsumerGroup {
                 &self.stream_id,
                 &self.topic_id,
                 &self.group_id,
+                self.assignment_strategy,
             )
             .await
             .with_error_context(|error| {
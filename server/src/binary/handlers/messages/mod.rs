@@ -16,8 +16,15 @@
  * under the License.
  */
 
+pub mod abort_transaction_handler;
+pub mod ack_messages_handler;
+pub mod begin_transaction_handler;
+pub mod commit_transaction_handler;
 pub mod flush_unsaved_buffer_handler;
+pub mod nack_messages_handler;
 pub mod poll_messages_handler;
+pub mod reject_messages_handler;
 pub mod send_messages_handler;
+pub mod send_offsets_to_transaction_handler;
 
 pub const COMPONENT: &str = "MESSAGE_HANDLER";
@@ -19,12 +19,15 @@ use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHa
 use crate::binary::handlers::messages::COMPONENT;
 use crate::binary::handlers::utils::receive_and_validate;
 use crate::binary::sender::SenderKind;
+use crate::streaming::diagnostics::metrics::QuotaDirection;
+use crate::streaming::segments::StoredBatches;
 use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use error_set::ErrContext;
 use iggy::prelude::*;
+use iggy::system::enable_zero_copy_polling::MESSAGES_FRAMING;
 use std::io::IoSlice;
 use tracing::debug;
 
@@ -42,7 +45,43 @@ impl ServerCommandHandler for PollMessages {
     ) -> Result<(), IggyError> {
         debug!("session: {session}, command: {self}");
 
+        let zero_copy_polling = sender.is_zero_copy_polling_enabled();
+        system
+            .wait_for_quota(session, QuotaDirection::Consume, &self.stream_id, &self.topic_id)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - quota exceeded polling messages, stream_id: {}, topic_id: {}, session: {session}.",
+                    self.stream_id, self.topic_id
+                )
+            })?;
         let system = system.read().await;
+        if zero_copy_polling {
+            let stored_batches = system
+                .poll_stored_batches(
+                    session,
+                    &self.consumer,
+                    &self.stream_id,
+                    &self.topic_id,
+                    self.partition_id,
+                    &PollingArgs::new(self.strategy, self.count, self.auto_commit),
+                )
+                .await
+                .with_error_context(|error| format!(
+                    "{COMPONENT} (error: {error}) - failed to poll stored batches for consumer: {}, stream_id: {}, topic_id: {}, partition_id: {:?}, session: {session}.",
+                    self.consumer, self.stream_id, self.topic_id, self.partition_id
+                ))?;
+            if let Some(stored_batches) = stored_batches {
+                drop(system);
+                let header = stored_batches.header();
+                let StoredBatches { file, range, .. } = stored_batches;
+                sender
+                    .send_ok_response_from_file(&header, file, range.position, range.length)
+                    .await?;
+                return Ok(());
+            }
+        }
+
         let batches = system
             .poll_messages(
                 session,
@@ -64,10 +103,16 @@ impl ServerCommandHandler for PollMessages {
         // throughout the async vectored I/O operation, preventing "borrowed value does not live
         // long enough" errors while optimizing transmission by using larger chunks.
 
-        let response_length = (batches.size() + 4).to_le_bytes();
+        // Once the zero-copy polling is negotiated, the messages which weren't sent as stored are preceded by their framing.
+        let framing = [MESSAGES_FRAMING];
+        let framing_length = if zero_copy_polling { framing.len() } else { 0 };
+        let response_length = (batches.size() + 4 + framing_length as u32).to_le_bytes();
         let messages_count = batches.count().to_le_bytes();
 
-        let mut io_slices = Vec::with_capacity(batches.containers_count() + 1);
+        let mut io_slices = Vec::with_capacity(batches.containers_count() + 2);
+        if zero_copy_polling {
+            io_slices.push(IoSlice::new(&framing));
+        }
         io_slices.push(IoSlice::new(&messages_count));
         io_slices.extend(batches.iter().map(|m| IoSlice::new(m)));
 
@@ -17,11 +17,13 @@
  */
 use crate::binary::command::{BinaryServerCommand, ServerCommandHandler};
 use crate::binary::sender::SenderKind;
+use crate::streaming::diagnostics::metrics::QuotaDirection;
 use crate::streaming::segments::IggyMessagesMut;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use bytes::{Buf, BytesMut};
+use iggy::confirmation::ConfirmationLevel;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::prelude::*;
@@ -68,11 +70,16 @@ impl ServerCommandHandler for SendMessages {
         element_size = partitioning.get_size_bytes().as_bytes_usize();
         buffer.advance(element_size);
 
+        let confirmation = ConfirmationLevel::from_code(buffer.get_u8())?;
+
         let messages_count = buffer.get_u32_le();
         let messages = buffer.split();
 
         let messages = IggyMessagesMut::from_bytes(messages, messages_count);
 
+        system
+            .wait_for_quota(session, QuotaDirection::Produce, &stream_id, &topic_id)
+            .await?;
         let system = system.read().await;
         system
             .append_messages(
@@ -81,7 +88,7 @@ impl ServerCommandHandler for SendMessages {
                 &topic_id,
                 &partitioning,
                 messages,
-                None,
+                confirmation,
             )
             .await?;
         drop(system);
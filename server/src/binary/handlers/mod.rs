@@ -16,11 +16,14 @@
  * under the License.
  */
 
+pub mod bookmarks;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod quotas;
+pub mod schemas;
 pub mod segments;
 pub mod streams;
 pub mod system;
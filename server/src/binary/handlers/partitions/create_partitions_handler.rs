@@ -50,6 +50,7 @@ impl ServerCommandHandler for CreatePartitions {
                 &self.stream_id,
                 &self.topic_id,
                 self.partitions_count,
+                self.key_routing_policy,
             )
             .await
             .with_error_context(|error| {
@@ -63,9 +64,9 @@ impl ServerCommandHandler for CreatePartitions {
         let stream_id = self.stream_id.clone();
         let topic_id = self.topic_id.clone();
 
-        system
+        let commit = system
         .state
-        .apply(
+        .append(
             session.get_user_id(),
             &EntryCommand::CreatePartitions(self),
         )
@@ -76,6 +77,8 @@ impl ServerCommandHandler for CreatePartitions {
                 stream_id, topic_id, session
             )
         })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
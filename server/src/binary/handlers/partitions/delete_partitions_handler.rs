@@ -61,9 +61,9 @@ impl ServerCommandHandler for DeletePartitions {
             })?;
 
         let system = system.downgrade();
-        system
+        let commit = system
         .state
-        .apply(
+        .append(
             session.get_user_id(),
             &EntryCommand::DeletePartitions(self),
         )
@@ -74,6 +74,8 @@ impl ServerCommandHandler for DeletePartitions {
                 stream_id, topic_id, session
             )
         })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
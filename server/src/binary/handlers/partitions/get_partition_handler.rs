@@ -0,0 +1,77 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::mapper;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::partitions::get_partition::GetPartition;
+use tracing::debug;
+
+impl ServerCommandHandler for GetPartition {
+    fn code(&self) -> u32 {
+        iggy::command::GET_PARTITION_CODE
+    }
+
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+        let system = system.read().await;
+        let Ok(topic) = system.try_find_topic(session, &self.stream_id, &self.topic_id) else {
+            sender.send_empty_ok_response().await?;
+            return Ok(());
+        };
+
+        let Some(topic) = topic else {
+            sender.send_empty_ok_response().await?;
+            return Ok(());
+        };
+
+        let Ok(Some(partition)) = topic.try_get_partition(&self.partition_id) else {
+            sender.send_empty_ok_response().await?;
+            return Ok(());
+        };
+
+        let partition = partition.read().await;
+        let partition = mapper::map_partition(&partition);
+        sender.send_ok_response(&partition).await?;
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for GetPartition {
+    async fn from_sender(sender: &mut SenderKind, code: u32, length: u32) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::GetPartition(get_partition) => Ok(get_partition),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
@@ -18,5 +18,8 @@
 
 pub mod create_partitions_handler;
 pub mod delete_partitions_handler;
+pub mod get_partition_handler;
+pub mod move_partition_handler;
+pub mod update_partition_handler;
 
 pub const COMPONENT: &str = "PARTITIONS_HANDLER";
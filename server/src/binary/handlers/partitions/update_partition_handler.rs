@@ -0,0 +1,93 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::{handlers::partitions::COMPONENT, sender::SenderKind};
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::partitions::update_partition::UpdatePartition;
+use tracing::{debug, instrument};
+
+impl ServerCommandHandler for UpdatePartition {
+    fn code(&self) -> u32 {
+        iggy::command::UPDATE_PARTITION_CODE
+    }
+
+    #[instrument(skip_all, name = "trace_update_partition", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = self.stream_id.as_string(), iggy_topic_id = self.topic_id.as_string()))]
+    async fn handle(
+        mut self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+
+        let mut system = system.write().await;
+        let partition_id = system
+                .update_partition(
+                    session,
+                    &self.stream_id,
+                    &self.topic_id,
+                    &self.partition_id,
+                    self.name.as_deref(),
+                )
+                .await
+                .with_error_context(|error| format!(
+                    "{COMPONENT} (error: {error}) - failed to update partition with id: {}, topic_id: {}, stream_id: {}, session: {session}",
+                    self.partition_id, self.topic_id, self.stream_id
+                ))?;
+        // The partition might have been identified by its previous name, so the numeric ID is stored instead.
+        self.partition_id = Identifier::numeric(partition_id)?;
+
+        let topic_id = self.topic_id.clone();
+        let stream_id = self.stream_id.clone();
+        let system = system.downgrade();
+
+        let commit = system
+            .state
+            .append(session.get_user_id(), &EntryCommand::UpdatePartition(self))
+            .await
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - failed to apply update partition with id: {partition_id}, topic_id: {}, stream_id: {}, session: {session}",
+                topic_id, stream_id
+            ))?;
+        drop(system);
+        commit.wait().await?;
+        sender.send_empty_ok_response().await?;
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for UpdatePartition {
+    async fn from_sender(sender: &mut SenderKind, code: u32, length: u32) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::UpdatePartition(update_partition) => Ok(update_partition),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
@@ -48,7 +48,13 @@ impl ServerCommandHandler for CreatePersonalAccessToken {
 
         let mut system = system.write().await;
         let token = system
-                .create_personal_access_token(session, &self.name, self.expiry)
+                .create_personal_access_token(
+                    session,
+                    &self.name,
+                    self.expiry,
+                    self.scope.clone(),
+                    self.allowed_addresses.clone(),
+                )
                 .await
                 .with_error_context(|error| {
                     format!(
@@ -60,14 +66,16 @@ impl ServerCommandHandler for CreatePersonalAccessToken {
         let token_hash = PersonalAccessToken::hash_token(&token);
 
         let system = system.downgrade();
-        system
+        let commit = system
             .state
-            .apply(
+            .append(
                 session.get_user_id(),
                 &EntryCommand::CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash {
                     command: CreatePersonalAccessToken {
                         name: self.name.to_owned(),
                         expiry: self.expiry,
+                        scope: self.scope,
+                        allowed_addresses: self.allowed_addresses,
                     },
                     hash: token_hash,
                 }),
@@ -79,6 +87,8 @@ impl ServerCommandHandler for CreatePersonalAccessToken {
                     self.name
                 )
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_ok_response(&bytes).await?;
         Ok(())
     }
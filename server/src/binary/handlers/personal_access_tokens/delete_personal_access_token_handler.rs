@@ -53,9 +53,9 @@ impl ServerCommandHandler for DeletePersonalAccessToken {
                 )})?;
 
         let system = system.downgrade();
-        system
+        let commit = system
             .state
-            .apply(
+            .append(
                 session.get_user_id(),
                 &EntryCommand::DeletePersonalAccessToken(DeletePersonalAccessToken {
                     name: self.name,
@@ -65,6 +65,8 @@ impl ServerCommandHandler for DeletePersonalAccessToken {
             .with_error_context(|error| {format!(
                 "{COMPONENT} (error: {error}) - failed to apply delete personal access token with name: {token_name}, session: {session}"
             )})?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
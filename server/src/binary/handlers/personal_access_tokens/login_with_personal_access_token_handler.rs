@@ -43,8 +43,8 @@ impl ServerCommandHandler for LoginWithPersonalAccessToken {
     ) -> Result<(), IggyError> {
         debug!("session: {session}, command: {self}");
         let system = system.read().await;
-        let user = system
-            .login_with_personal_access_token(&self.token, Some(session))
+        let (user, _) = system
+            .login_with_personal_access_token(&self.token, &session.ip_address.ip(), Some(session))
             .await
             .with_error_context(|error| {
                 format!(
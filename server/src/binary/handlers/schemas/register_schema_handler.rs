@@ -0,0 +1,97 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::schemas::COMPONENT;
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::mapper;
+use crate::binary::sender::SenderKind;
+use crate::state::command::EntryCommand;
+use crate::state::models::RegisterSchemaWithId;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::schemas::register_schema::RegisterSchema;
+use tracing::{debug, instrument};
+
+impl ServerCommandHandler for RegisterSchema {
+    fn code(&self) -> u32 {
+        iggy::command::REGISTER_SCHEMA_CODE
+    }
+
+    #[instrument(skip_all, name = "trace_register_schema", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_schema_subject = self.subject.as_str()))]
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+
+        let mut system = system.write().await;
+        let (schema, created) = system
+            .register_schema(session, &self.subject, self.schema.clone())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to register schema for subject: {}, session: {session}",
+                    self.subject
+                )
+            })?;
+        let response = mapper::map_schema(schema);
+        let schema_id = schema.id;
+
+        let system = system.downgrade();
+        if created {
+            let subject = self.subject.clone();
+            let commit = system
+                .state
+                .append(
+                    session.get_user_id(),
+                    &EntryCommand::RegisterSchema(RegisterSchemaWithId {
+                        schema_id,
+                        command: self,
+                    }),
+                )
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to apply register schema for subject: {subject}, schema ID: {schema_id}, session: {session}",
+                    )
+                })?;
+            drop(system);
+            commit.wait().await?;
+        }
+        sender.send_ok_response(&response).await?;
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for RegisterSchema {
+    async fn from_sender(sender: &mut SenderKind, code: u32, length: u32) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::RegisterSchema(register_schema) => Ok(register_schema),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::schemas::COMPONENT;
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::sender::SenderKind;
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use tracing::{debug, instrument};
+
+impl ServerCommandHandler for UpdateSchemaCompatibility {
+    fn code(&self) -> u32 {
+        iggy::command::UPDATE_SCHEMA_COMPATIBILITY_CODE
+    }
+
+    #[instrument(skip_all, name = "trace_update_schema_compatibility", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_schema_subject = self.subject.as_str()))]
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+        let subject = self.subject.clone();
+
+        let mut system = system.write().await;
+        system
+            .update_schema_compatibility(session, &self.subject, self.compatibility)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to update schema compatibility for subject: {subject}, session: {session}")
+            })?;
+
+        let system = system.downgrade();
+        let commit = system
+            .state
+            .append(
+                session.get_user_id(),
+                &EntryCommand::UpdateSchemaCompatibility(self),
+            )
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to apply update schema compatibility for subject: {subject}, session: {session}")
+            })?;
+        drop(system);
+        commit.wait().await?;
+        sender.send_empty_ok_response().await?;
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for UpdateSchemaCompatibility {
+    async fn from_sender(sender: &mut SenderKind, code: u32, length: u32) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::UpdateSchemaCompatibility(update_schema_compatibility) => {
+                Ok(update_schema_compatibility)
+            }
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
@@ -54,9 +54,9 @@ pub async fn handle(
             })?;
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             session.get_user_id(),
             &EntryCommand::DeleteSegments(command),
         )
@@ -66,6 +66,8 @@ pub async fn handle(
                 "{COMPONENT} (error: {error}) - failed to apply 'delete segments' command for partition with ID: {partition_id} in topic with ID: {topic_id} in stream with ID: {stream_id}, session: {session}",
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     sender.send_empty_ok_response().await?;
     Ok(())
 }
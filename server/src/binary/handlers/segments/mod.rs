@@ -16,3 +16,4 @@
  * under the License.
  */
 pub mod delete_segments_handler;
+pub mod verify_segments_handler;
@@ -62,9 +62,9 @@ impl ServerCommandHandler for CreateStream {
         let response = mapper::map_stream(stream);
 
         let system = system.downgrade();
-        system
+        let commit = system
             .state
-            .apply(session.get_user_id(), &EntryCommand::CreateStream(CreateStreamWithId { stream_id, command: self }))
+            .append(session.get_user_id(), &EntryCommand::CreateStream(CreateStreamWithId { stream_id, command: self }))
             .await
             .with_error_context(|error| {
                 format!(
@@ -72,6 +72,8 @@ impl ServerCommandHandler for CreateStream {
                     stream_id
                 )
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_ok_response(&response).await?;
         Ok(())
     }
@@ -53,13 +53,15 @@ impl ServerCommandHandler for DeleteStream {
                 })?;
 
         let system = system.downgrade();
-        system
+        let commit = system
             .state
-            .apply(session.get_user_id(), &EntryCommand::DeleteStream(self))
+            .append(session.get_user_id(), &EntryCommand::DeleteStream(self))
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to apply delete stream with ID: {stream_id}, session: {session}")
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
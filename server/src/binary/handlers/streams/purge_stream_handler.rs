@@ -52,13 +52,15 @@ impl ServerCommandHandler for PurgeStream {
                 format!("{COMPONENT} (error: {error}) - failed to purge stream with id: {stream_id}, session: {session}")
             })?;
 
-        system
+        let commit = system
             .state
-            .apply(session.get_user_id(), &EntryCommand::PurgeStream(self))
+            .append(session.get_user_id(), &EntryCommand::PurgeStream(self))
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to apply purge stream with id: {stream_id}, session: {session}")
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
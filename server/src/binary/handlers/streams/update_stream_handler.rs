@@ -53,13 +53,15 @@ impl ServerCommandHandler for UpdateStream {
                 })?;
 
         let system = system.downgrade();
-        system
+        let commit = system
             .state
-            .apply(session.get_user_id(), &EntryCommand::UpdateStream(self))
+            .append(session.get_user_id(), &EntryCommand::UpdateStream(self))
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to apply update stream with id: {stream_id}, session: {session}")
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
@@ -0,0 +1,62 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::system::enable_consumer_group_events::EnableConsumerGroupEvents;
+use tracing::{debug, info};
+
+impl ServerCommandHandler for EnableConsumerGroupEvents {
+    fn code(&self) -> u32 {
+        iggy::command::ENABLE_CONSUMER_GROUP_EVENTS_CODE
+    }
+
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        _system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+        sender.enable_consumer_group_events().await?;
+        if sender.is_consumer_group_events_enabled() {
+            info!("Negotiated the consumer group events for session: {session}");
+        }
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for EnableConsumerGroupEvents {
+    async fn from_sender(sender: &mut SenderKind, code: u32, length: u32) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::EnableConsumerGroupEvents(enable_consumer_group_events) => {
+                Ok(enable_consumer_group_events)
+            }
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
@@ -16,11 +16,19 @@
  * under the License.
  */
 
+pub mod create_backup_handler;
+pub mod enable_consumer_group_events_handler;
+pub mod enable_frame_checksums_handler;
+pub mod enable_zero_copy_polling_handler;
+pub mod get_audit_log_handler;
 pub mod get_client_handler;
 pub mod get_clients_handler;
+pub mod get_cluster_metadata_handler;
+pub mod get_config_handler;
 pub mod get_me_handler;
 pub mod get_snapshot;
 pub mod get_stats_handler;
 pub mod ping_handler;
+pub mod restore_backup_handler;
 
 pub const COMPONENT: &str = "SYSTEM_HANDLER";
@@ -24,7 +24,6 @@ use anyhow::Result;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
 use iggy::system::ping::Ping;
-use iggy::utils::timestamp::IggyTimestamp;
 use tracing::debug;
 
 impl ServerCommandHandler for Ping {
@@ -44,7 +43,7 @@ impl ServerCommandHandler for Ping {
         let client_manager = system.client_manager.read().await;
         if let Some(client) = client_manager.try_get_client(session.client_id) {
             let mut client = client.write().await;
-            let now = IggyTimestamp::now();
+            let now = system.clock.now();
             client.last_heartbeat = now;
             debug!("Updated last heartbeat to: {now} for session: {session}");
         }
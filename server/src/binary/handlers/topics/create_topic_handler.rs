@@ -28,6 +28,7 @@ use anyhow::Result;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::topics::create_topic::CreateTopic;
+use iggy::utils::byte_size::IggyByteSize;
 use tracing::{debug, instrument};
 
 impl ServerCommandHandler for CreateTopic {
@@ -58,6 +59,7 @@ impl ServerCommandHandler for CreateTopic {
                     self.compression_algorithm,
                     self.max_topic_size,
                     self.replication_factor,
+                    self.options.clone(),
                 )
                 .await
                 .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to create topic for stream_id: {stream_id}, topic_id: {:?}",
@@ -65,14 +67,14 @@ impl ServerCommandHandler for CreateTopic {
                 ))?;
         self.message_expiry = topic.message_expiry;
         self.max_topic_size = topic.max_topic_size;
-        let response = mapper::map_topic(topic).await;
+        let response = mapper::map_topic(topic, IggyByteSize::default()).await;
 
         let topic_id = topic.topic_id;
 
         let system = system.downgrade();
-        system
+        let commit = system
             .state
-            .apply(session.get_user_id(),&EntryCommand::CreateTopic(CreateTopicWithId { topic_id, command: self}))
+            .append(session.get_user_id(),&EntryCommand::CreateTopic(CreateTopicWithId { topic_id, command: self}))
             .await
             .with_error_context(|error| {
                 format!(
@@ -80,6 +82,8 @@ impl ServerCommandHandler for CreateTopic {
                 topic_id
             )
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_ok_response(&response).await?;
         Ok(())
     }
@@ -46,21 +46,30 @@ impl ServerCommandHandler for DeleteTopic {
         let topic_id = self.topic_id.clone();
 
         let mut system = system.write().await;
+        let is_being_deleted = system.is_topic_being_deleted(&self.stream_id, &self.topic_id);
         system
-                .delete_topic(session, &self.stream_id, &self.topic_id)
+                .delete_topic(session, &self.stream_id, &self.topic_id, self.deferred)
                 .await
                 .with_error_context(|error| format!(
                     "{COMPONENT} (error: {error}) - failed to delete topic with ID: {topic_id} in stream with ID: {stream_id}, session: {session}",
                 ))?;
 
+        // The deletion has been already stored in the state, when it was requested for the first time.
+        if is_being_deleted {
+            sender.send_empty_ok_response().await?;
+            return Ok(());
+        }
+
         let system = system.downgrade();
-        system
+        let commit = system
             .state
-            .apply(session.get_user_id(), &EntryCommand::DeleteTopic(self))
+            .append(session.get_user_id(), &EntryCommand::DeleteTopic(self))
             .await
             .with_error_context(|error| format!(
                 "{COMPONENT} (error: {error}) - failed to apply delete topic with ID: {topic_id} in stream with ID: {stream_id}, session: {session}",
             ))?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
@@ -51,7 +51,10 @@ impl ServerCommandHandler for GetTopic {
             return Ok(());
         };
 
-        let topic = mapper::map_topic(topic).await;
+        let disk_usage = system
+            .storage_usage
+            .get_topic_disk_usage_bytes(topic.stream_id, topic.topic_id);
+        let topic = mapper::map_topic(topic, disk_usage.into()).await;
         sender.send_ok_response(&topic).await?;
         Ok(())
     }
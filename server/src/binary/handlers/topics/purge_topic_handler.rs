@@ -55,15 +55,17 @@ impl ServerCommandHandler for PurgeTopic {
 
         let topic_id = self.topic_id.clone();
         let stream_id = self.stream_id.clone();
-        system
+        let commit = system
             .state
-            .apply(session.get_user_id(), &EntryCommand::PurgeTopic(self))
+            .append(session.get_user_id(), &EntryCommand::PurgeTopic(self))
             .await
             .with_error_context(|error| {
                 format!(
                 "{COMPONENT} (error: {error}) - failed to apply purge topic with id: {topic_id}, stream_id: {stream_id}",
             )
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
@@ -55,6 +55,7 @@ impl ServerCommandHandler for UpdateTopic {
                     self.compression_algorithm,
                     self.max_topic_size,
                     self.replication_factor,
+                    self.options.clone(),
                 )
                 .await
                 .with_error_context(|error| format!(
@@ -68,14 +69,16 @@ impl ServerCommandHandler for UpdateTopic {
         let stream_id = self.stream_id.clone();
         let system = system.downgrade();
 
-        system
+        let commit = system
             .state
-            .apply(session.get_user_id(), &EntryCommand::UpdateTopic(self))
+            .append(session.get_user_id(), &EntryCommand::UpdateTopic(self))
             .await
             .with_error_context(|error| format!(
                 "{COMPONENT} (error: {error}) - failed to apply update topic with id: {}, stream_id: {}, session: {session}",
                 topic_id, stream_id
             ))?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
@@ -62,9 +62,9 @@ impl ServerCommandHandler for ChangePassword {
 
         // For the security of the system, we hash the password before storing it in metadata.
         let system = system.downgrade();
-        system
+        let commit = system
             .state
-            .apply(
+            .append(
                 session.get_user_id(),
                 &EntryCommand::ChangePassword(ChangePassword {
                     user_id: self.user_id.to_owned(),
@@ -79,6 +79,8 @@ impl ServerCommandHandler for ChangePassword {
                     self.user_id
                 )
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
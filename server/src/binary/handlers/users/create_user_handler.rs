@@ -68,9 +68,9 @@ impl ServerCommandHandler for CreateUser {
 
         // For the security of the system, we hash the password before storing it in metadata.
         let system = system.downgrade();
-        system
+        let commit = system
             .state
-            .apply(
+            .append(
                 session.get_user_id(),
                 &EntryCommand::CreateUser( CreateUserWithId { user_id, command:  CreateUser {
                     username: self.username.to_owned(),
@@ -86,6 +86,8 @@ impl ServerCommandHandler for CreateUser {
                     self.username
                 )
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_ok_response(&response).await?;
         Ok(())
     }
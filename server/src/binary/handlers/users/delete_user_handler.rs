@@ -56,15 +56,17 @@ impl ServerCommandHandler for DeleteUser {
 
         let system = system.downgrade();
         let user_id = self.user_id.clone();
-        system
+        let commit = system
             .state
-            .apply(session.get_user_id(), &EntryCommand::DeleteUser(self))
+            .append(session.get_user_id(), &EntryCommand::DeleteUser(self))
             .await
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - failed to apply delete user with ID: {user_id}, session: {session}",
                 )
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
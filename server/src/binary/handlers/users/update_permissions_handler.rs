@@ -52,13 +52,15 @@ impl ServerCommandHandler for UpdatePermissions {
                 ))?;
 
         let system = system.downgrade();
-        system
+        let commit = system
             .state
-            .apply(
+            .append(
                 session.get_user_id(),
                 &EntryCommand::UpdatePermissions(self),
             )
             .await?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
@@ -62,9 +62,9 @@ impl ServerCommandHandler for UpdateUser {
         let system = system.downgrade();
         let user_id = self.user_id.clone();
 
-        system
+        let commit = system
             .state
-            .apply(session.get_user_id(), &EntryCommand::UpdateUser(self))
+            .append(session.get_user_id(), &EntryCommand::UpdateUser(self))
             .await
             .with_error_context(|error| {
                 format!(
@@ -72,6 +72,8 @@ impl ServerCommandHandler for UpdateUser {
                     user_id
                 )
             })?;
+        drop(system);
+        commit.wait().await?;
         sender.send_empty_ok_response().await?;
         Ok(())
     }
@@ -63,6 +63,24 @@ macro_rules! define_server_command_enum {
                 }
             }
 
+            /// Returns the name of the command, without its payload.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(
+                        ServerCommand::$variant(_) => $display_str,
+                    )*
+                }
+            }
+
+            /// Returns the payload of the command as displayed by its inner type, e.g. to be recorded in the audit log.
+            pub fn details(&self) -> String {
+                match self {
+                    $(
+                        ServerCommand::$variant(payload) => payload.to_string(),
+                    )*
+                }
+            }
+
             /// Validate the command by delegating to the inner command’s implementation.
             pub fn validate(&self) -> Result<(), IggyError> {
                 match self {
@@ -19,16 +19,28 @@
 use crate::streaming::clients::client_manager::{Client, Transport};
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::schemas::schema::Schema;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
 use bytes::{BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
+use iggy::consumer_groups::get_consumer_group::CONSUMER_GROUP_DETAILS_VERSION;
 use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::models::audit_entry::AuditEntry;
+use iggy::models::backup::BackupInfo;
+use iggy::models::bookmark::Bookmark;
+use iggy::models::cluster::ClusterMetadata;
+use iggy::models::config_value::ConfigValue;
+use iggy::models::consumer_group::ConsumerGroupRebalance;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
 use iggy::models::messages::PolledMessages;
+use iggy::models::personal_access_token::extend_allowed_addresses;
+use iggy::models::quota::Quota;
+use iggy::models::segments_verification::SegmentsVerification;
 use iggy::models::stats::Stats;
+use iggy::models::topic_options::write_optional_topic_options;
 use iggy::models::user_info::UserId;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::sizeable::Sizeable;
@@ -78,6 +90,8 @@ pub fn map_stats(stats: &Stats) -> Bytes {
         bytes.put_u64_le(metrics.misses);
         bytes.put_f32_le(metrics.hit_ratio);
     }
+    bytes.put_u64_le(stats.disk_usage.as_bytes_u64());
+    bytes.put_u64_le(stats.fsyncs_count);
 
     bytes.freeze()
 }
@@ -90,6 +104,119 @@ pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_bookmark(bookmark: &Bookmark) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_bookmark(bookmark, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_bookmarks(bookmarks: &[Bookmark]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for bookmark in bookmarks {
+        extend_bookmark(bookmark, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_config_values(values: &[ConfigValue]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for value in values {
+        let json = value.value.to_string();
+        bytes.put_u8(value.source.as_code());
+        bytes.put_u32_le(value.key.len() as u32);
+        bytes.put_slice(value.key.as_bytes());
+        bytes.put_u32_le(json.len() as u32);
+        bytes.put_slice(json.as_bytes());
+    }
+    bytes.freeze()
+}
+
+pub fn map_audit_entries(entries: &[AuditEntry]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for entry in entries {
+        let error = entry.error.as_deref().unwrap_or_default();
+        bytes.put_u64_le(entry.id);
+        bytes.put_u64_le(entry.timestamp.into());
+        bytes.put_u32_le(entry.user_id);
+        bytes.put_u8(entry.outcome.as_code());
+        bytes.put_u8(entry.address.len() as u8);
+        bytes.put_slice(entry.address.as_bytes());
+        bytes.put_u8(entry.transport.len() as u8);
+        bytes.put_slice(entry.transport.as_bytes());
+        bytes.put_u8(entry.action.len() as u8);
+        bytes.put_slice(entry.action.as_bytes());
+        bytes.put_u32_le(entry.details.len() as u32);
+        bytes.put_slice(entry.details.as_bytes());
+        bytes.put_u32_le(error.len() as u32);
+        bytes.put_slice(error.as_bytes());
+    }
+    bytes.freeze()
+}
+
+pub fn map_backup(backup: &BackupInfo) -> Bytes {
+    let base = backup.base.as_deref().unwrap_or_default();
+    let mut bytes =
+        BytesMut::with_capacity(31 + backup.name.len() + base.len() + backup.location.len());
+    bytes.put_u8(backup.destination.as_code());
+    bytes.put_u64_le(backup.created_at.into());
+    bytes.put_u32_le(backup.streams_count);
+    bytes.put_u32_le(backup.files_count);
+    bytes.put_u64_le(backup.size.as_bytes_u64());
+    bytes.put_u8(backup.name.len() as u8);
+    bytes.put_slice(backup.name.as_bytes());
+    bytes.put_u8(base.len() as u8);
+    bytes.put_slice(base.as_bytes());
+    bytes.put_u32_le(backup.location.len() as u32);
+    bytes.put_slice(backup.location.as_bytes());
+    bytes.freeze()
+}
+
+pub fn map_cluster_metadata(metadata: &ClusterMetadata) -> Bytes {
+    let mut bytes = BytesMut::new();
+    bytes.put_u8(if metadata.enabled { 1 } else { 0 });
+    bytes.put_u32_le(metadata.node_id);
+    bytes.put_u32_le(metadata.leader_id.unwrap_or_default());
+    bytes.put_u32_le(metadata.nodes.len() as u32);
+    for node in &metadata.nodes {
+        bytes.put_u32_le(node.id);
+        bytes.put_u8(if node.alive { 1 } else { 0 });
+        bytes.put_u8(node.address.len() as u8);
+        bytes.put_slice(node.address.as_bytes());
+    }
+    for topic in &metadata.topics {
+        bytes.put_u32_le(topic.stream_id);
+        bytes.put_u8(topic.stream_name.len() as u8);
+        bytes.put_slice(topic.stream_name.as_bytes());
+        bytes.put_u32_le(topic.topic_id);
+        bytes.put_u8(topic.topic_name.len() as u8);
+        bytes.put_slice(topic.topic_name.as_bytes());
+        bytes.put_u32_le(topic.partitions.len() as u32);
+        for partition in &topic.partitions {
+            bytes.put_u32_le(partition.id);
+            bytes.put_u32_le(partition.leader_id.unwrap_or_default());
+            bytes.put_u8(partition.replicas.len() as u8);
+            for replica in &partition.replicas {
+                bytes.put_u32_le(*replica);
+            }
+        }
+    }
+    bytes.freeze()
+}
+
+pub fn map_segments_verification(verification: &SegmentsVerification) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(12 + 32 * verification.corrupted_batches.len());
+    bytes.put_u32_le(verification.segments_count);
+    bytes.put_u32_le(verification.batches_count);
+    bytes.put_u32_le(verification.corrupted_batches.len() as u32);
+    for batch in &verification.corrupted_batches {
+        bytes.put_u64_le(batch.segment_start_offset);
+        bytes.put_u64_le(batch.base_offset);
+        bytes.put_u64_le(batch.position);
+        bytes.put_u64_le(batch.length);
+    }
+    bytes.freeze()
+}
+
 pub fn map_client(client: &Client) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_client(client, &mut bytes);
@@ -139,6 +266,12 @@ pub fn map_identity_info(user_id: UserId) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_transaction_id(transaction_id: u64) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(8);
+    bytes.put_u64_le(transaction_id);
+    bytes.freeze()
+}
+
 pub fn map_raw_pat(token: &str) -> Bytes {
     let mut bytes = BytesMut::with_capacity(1 + token.len());
     bytes.put_u8(token.len() as u8);
@@ -162,10 +295,12 @@ pub fn map_polled_messages(polled_messages: &PolledMessages) -> Bytes {
         .map(|message| message.get_size_bytes())
         .sum::<IggyByteSize>();
 
-    let mut bytes = BytesMut::with_capacity(20 + messages_size.as_bytes_usize());
+    let mut bytes = BytesMut::with_capacity(28 + messages_size.as_bytes_usize());
     bytes.put_u32_le(polled_messages.partition_id);
     bytes.put_u64_le(polled_messages.current_offset);
     bytes.put_u32_le(messages_count);
+    // Offset 0 can never be the continuation point, so it stands for the response not being truncated.
+    bytes.put_u64_le(polled_messages.next_offset.unwrap_or_default());
     for message in polled_messages.messages.iter() {
         message.extend(&mut bytes);
     }
@@ -198,9 +333,11 @@ pub fn map_topics(topics: &[&Topic]) -> Bytes {
     bytes.freeze()
 }
 
-pub async fn map_topic(topic: &Topic) -> Bytes {
+pub async fn map_topic(topic: &Topic, disk_usage: IggyByteSize) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_topic(topic, &mut bytes);
+    write_optional_topic_options(&topic.get_options(), &mut bytes);
+    bytes.put_u64_le(disk_usage.as_bytes_u64());
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
         extend_partition(&partition, &mut bytes);
@@ -208,7 +345,34 @@ pub async fn map_topic(topic: &Topic) -> Bytes {
     bytes.freeze()
 }
 
-pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Bytes {
+pub fn map_partition(partition: &Partition) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_partition(partition, &mut bytes);
+    let name = partition.name.as_deref().unwrap_or_default();
+    bytes.put_u8(name.len() as u8);
+    bytes.put_slice(name.as_bytes());
+    let segments = partition.get_segments();
+    bytes.put_u32_le(segments.len() as u32);
+    for segment in segments {
+        bytes.put_u64_le(segment.start_offset);
+        bytes.put_u64_le(segment.current_offset);
+        bytes.put_u64_le(segment.size_bytes.as_bytes_u64());
+        bytes.put_u64_le(segment.get_messages_count());
+        bytes.put_u8(if segment.is_closed { 1 } else { 0 });
+    }
+    for consumer_offset in partition
+        .consumer_offsets
+        .iter()
+        .chain(partition.consumer_group_offsets.iter())
+    {
+        bytes.put_u8(consumer_offset.kind.as_code());
+        bytes.put_u32_le(consumer_offset.consumer_id);
+        bytes.put_u64_le(consumer_offset.offset);
+    }
+    bytes.freeze()
+}
+
+pub async fn map_consumer_group(consumer_group: &ConsumerGroup, details_version: u8) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_consumer_group(consumer_group, &mut bytes);
     let members = consumer_group.get_members();
@@ -221,6 +385,33 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Bytes {
             bytes.put_u32_le(partition);
         }
     }
+    // The clients reading the members until the end of the payload would fail on the fields added later.
+    if details_version >= CONSUMER_GROUP_DETAILS_VERSION {
+        bytes.put_u32_le(consumer_group.generation());
+        bytes.put_u8(consumer_group.assignment_strategy.as_code());
+        bytes.put_u8(if consumer_group.topic_deleting { 1 } else { 0 });
+    }
+    bytes.freeze()
+}
+
+pub fn map_consumer_group_rebalance(rebalance: &ConsumerGroupRebalance) -> Bytes {
+    let mut bytes = BytesMut::new();
+    bytes.put_u32_le(rebalance.stream_id);
+    bytes.put_u32_le(rebalance.topic_id);
+    bytes.put_u32_le(rebalance.group_id);
+    bytes.put_u32_le(rebalance.generation);
+    bytes.put_u32_le(rebalance.partitions.len() as u32);
+    for partition_id in &rebalance.partitions {
+        bytes.put_u32_le(*partition_id);
+    }
+    for name in [
+        &rebalance.stream_name,
+        &rebalance.topic_name,
+        &rebalance.group_name,
+    ] {
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+    }
     bytes.freeze()
 }
 
@@ -233,6 +424,36 @@ pub async fn map_consumer_groups(consumer_groups: &[&RwLock<ConsumerGroup>]) ->
     bytes.freeze()
 }
 
+pub fn map_schema(schema: &Schema) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_schema(schema, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_schemas(schemas: &[&Schema]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for schema in schemas {
+        extend_schema(schema, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_quotas(quotas: &[Quota]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for quota in quotas {
+        bytes.put_slice(&quota.principal.to_bytes());
+        bytes.put_slice(&quota.limits.to_bytes());
+        bytes.put_u8(if quota.is_default { 1 } else { 0 });
+        bytes.put_u64_le(quota.produced_bytes);
+        bytes.put_u64_le(quota.produced_messages);
+        bytes.put_u64_le(quota.consumed_bytes);
+        bytes.put_u64_le(quota.consumed_messages);
+        bytes.put_u64_le(quota.delayed_requests);
+        bytes.put_u64_le(quota.rejected_requests);
+    }
+    bytes.freeze()
+}
+
 fn extend_stream(stream: &Stream, bytes: &mut BytesMut) {
     bytes.put_u32_le(stream.stream_id);
     bytes.put_u64_le(stream.created_at.into());
@@ -274,6 +495,17 @@ fn extend_consumer_group(consumer_group: &ConsumerGroup, bytes: &mut BytesMut) {
     bytes.put_slice(consumer_group.name.as_bytes());
 }
 
+fn extend_schema(schema: &Schema, bytes: &mut BytesMut) {
+    bytes.put_u32_le(schema.id);
+    bytes.put_u32_le(schema.version);
+    bytes.put_u64_le(schema.created_at.into());
+    bytes.put_u8(schema.subject.len() as u8);
+    bytes.put_slice(schema.subject.as_bytes());
+    let schema_bytes = schema.schema.to_bytes();
+    bytes.put_u32_le(schema_bytes.len() as u32);
+    bytes.put_slice(&schema_bytes);
+}
+
 fn extend_client(client: &Client, bytes: &mut BytesMut) {
     bytes.put_u32_le(client.session.client_id);
     bytes.put_u32_le(client.user_id.unwrap_or(0));
@@ -307,4 +539,30 @@ fn extend_pat(personal_access_token: &PersonalAccessToken, bytes: &mut BytesMut)
             bytes.put_u64_le(0);
         }
     }
+    match &personal_access_token.scope {
+        Some(scope) => {
+            let scope = scope.to_bytes();
+            bytes.put_u8(1);
+            bytes.put_u32_le(scope.len() as u32);
+            bytes.put_slice(&scope);
+        }
+        None => {
+            bytes.put_u8(0);
+        }
+    }
+    extend_allowed_addresses(&personal_access_token.allowed_addresses, bytes);
+    bytes.put_u64_le(
+        personal_access_token
+            .last_used_at()
+            .map_or(0, |last_used_at| last_used_at.as_micros()),
+    );
+}
+
+fn extend_bookmark(bookmark: &Bookmark, bytes: &mut BytesMut) {
+    bytes.put_u32_le(bookmark.partition_id);
+    bytes.put_u64_le(bookmark.offset);
+    bytes.put_u64_le(bookmark.created_at.into());
+    bytes.put_u64_le(bookmark.updated_at.into());
+    bytes.put_u8(bookmark.name.len() as u8);
+    bytes.put_slice(bookmark.name.as_bytes());
 }
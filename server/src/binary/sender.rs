@@ -16,15 +16,15 @@
  * under the License.
  */
 
+use std::fs::File;
 use std::future::Future;
 
 use crate::tcp::tcp_sender::TcpSender;
-use crate::tcp::tcp_tls_sender::TcpTlsSender;
+use crate::tcp::tcp_tls_sender::{TcpTlsSender, TlsConnection};
 use crate::{quic::quic_sender::QuicSender, server_error::ServerError};
 use iggy::error::IggyError;
 use quinn::{RecvStream, SendStream};
 use tokio::net::TcpStream;
-use tokio_native_tls::TlsStream;
 
 macro_rules! forward_async_methods {
     (
@@ -48,11 +48,24 @@ macro_rules! forward_async_methods {
 
 pub trait Sender {
     fn read(&mut self, buffer: &mut [u8]) -> impl Future<Output = Result<usize, IggyError>> + Send;
+    fn verify_frame_checksum(&mut self) -> impl Future<Output = Result<(), IggyError>> + Send;
+    fn enable_frame_checksums(&mut self) -> impl Future<Output = Result<(), IggyError>> + Send;
+    fn enable_zero_copy_polling(&mut self) -> impl Future<Output = Result<(), IggyError>> + Send;
+    fn enable_consumer_group_events(
+        &mut self,
+    ) -> impl Future<Output = Result<(), IggyError>> + Send;
     fn send_empty_ok_response(&mut self) -> impl Future<Output = Result<(), IggyError>> + Send;
     fn send_ok_response(
         &mut self,
         payload: &[u8],
     ) -> impl Future<Output = Result<(), IggyError>> + Send;
+    fn send_ok_response_from_file(
+        &mut self,
+        header: &[u8],
+        file: File,
+        position: u64,
+        length: u64,
+    ) -> impl Future<Output = Result<(), IggyError>> + Send;
     fn send_error_response(
         &mut self,
         error: IggyError,
@@ -68,11 +81,19 @@ pub enum SenderKind {
 
 impl SenderKind {
     pub fn get_tcp_sender(stream: TcpStream) -> Self {
-        Self::Tcp(TcpSender { stream })
+        Self::Tcp(TcpSender {
+            stream,
+            frame_checksum: None,
+            zero_copy_polling: false,
+            consumer_group_events: false,
+        })
     }
 
-    pub fn get_tcp_tls_sender(stream: TlsStream<TcpStream>) -> Self {
-        Self::TcpTls(TcpTlsSender { stream })
+    pub fn get_tcp_tls_sender(stream: impl TlsConnection + 'static) -> Self {
+        Self::TcpTls(TcpTlsSender {
+            stream: Box::new(stream),
+            frame_checksum: None,
+        })
     }
 
     pub fn get_quic_sender(send_stream: SendStream, recv_stream: RecvStream) -> Self {
@@ -82,10 +103,47 @@ impl SenderKind {
         })
     }
 
+    /// Checks whether the poll responses are framed for sending the stored batches straight from the segment log files.
+    pub fn is_zero_copy_polling_enabled(&self) -> bool {
+        match self {
+            Self::Tcp(s) => s.zero_copy_polling,
+            Self::TcpTls(_) | Self::Quic(_) => false,
+        }
+    }
+
+    /// Checks whether the client is notified about the rebalances of the consumer groups it's a member of.
+    pub fn is_consumer_group_events_enabled(&self) -> bool {
+        match self {
+            Self::Tcp(s) => s.consumer_group_events,
+            Self::TcpTls(_) | Self::Quic(_) => false,
+        }
+    }
+
+    /// Waits until the next request can be read. Unlike reading the request, it can be safely cancelled,
+    /// so that the consumer group events are pushed to the client meanwhile.
+    pub async fn wait_for_request(&mut self) -> Result<(), IggyError> {
+        match self {
+            Self::Tcp(s) => s.wait_for_request().await,
+            Self::TcpTls(_) | Self::Quic(_) => Ok(()),
+        }
+    }
+
+    pub async fn send_consumer_group_event(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        match self {
+            Self::Tcp(s) => s.send_consumer_group_event(payload).await,
+            Self::TcpTls(_) | Self::Quic(_) => Err(IggyError::FeatureUnavailable),
+        }
+    }
+
     forward_async_methods! {
         async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError>;
+        async fn verify_frame_checksum(&mut self) -> Result<(), IggyError>;
+        async fn enable_frame_checksums(&mut self) -> Result<(), IggyError>;
+        async fn enable_zero_copy_polling(&mut self) -> Result<(), IggyError>;
+        async fn enable_consumer_group_events(&mut self) -> Result<(), IggyError>;
         async fn send_empty_ok_response(&mut self) -> Result<(), IggyError>;
         async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError>;
+        async fn send_ok_response_from_file(&mut self, header: &[u8], file: File, position: u64, length: u64) -> Result<(), IggyError>;
         async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError>;
         async fn shutdown(&mut self) -> Result<(), ServerError>;
     }
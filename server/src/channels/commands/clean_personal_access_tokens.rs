@@ -21,7 +21,6 @@ use crate::configs::server::PersonalAccessTokenCleanerConfig;
 use crate::streaming::systems::system::SharedSystem;
 use flume::Sender;
 use iggy::utils::duration::IggyDuration;
-use iggy::utils::timestamp::IggyTimestamp;
 use tokio::time;
 use tracing::{debug, error, info, instrument};
 
@@ -80,7 +79,7 @@ impl ServerCommand<CleanPersonalAccessTokensCommand> for CleanPersonalAccessToke
     async fn execute(&mut self, system: &SharedSystem, _command: CleanPersonalAccessTokensCommand) {
         // TODO: System write lock, investigate if it's necessary.
         let mut system = system.write().await;
-        let now = IggyTimestamp::now();
+        let now = system.clock.now();
         let mut deleted_tokens_count = 0;
         for (_, user) in system.users.iter_mut() {
             let expired_tokens = user
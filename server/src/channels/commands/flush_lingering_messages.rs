@@ -0,0 +1,110 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::PartitionConfig;
+use crate::streaming::systems::system::SharedSystem;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{error, info, instrument, trace};
+
+pub struct LingeringMessagesFlusher {
+    linger_time: IggyDuration,
+    sender: Sender<FlushLingeringMessagesCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct FlushLingeringMessagesCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct FlushLingeringMessagesExecutor;
+
+impl LingeringMessagesFlusher {
+    pub fn new(config: &PartitionConfig, sender: Sender<FlushLingeringMessagesCommand>) -> Self {
+        Self {
+            linger_time: config.linger_time,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if self.linger_time.is_zero() {
+            info!("Messages linger is disabled.");
+            return;
+        }
+
+        let linger_time = self.linger_time;
+        let sender = self.sender.clone();
+        info!("Messages linger is enabled, buffered messages will be written to disk after lingering for: {linger_time}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(linger_time.get_duration());
+            interval_timer.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(FlushLingeringMessagesCommand)
+                    .unwrap_or_else(|error| {
+                        error!("Failed to send FlushLingeringMessagesCommand. Error: {error}");
+                    });
+            }
+        });
+    }
+}
+
+impl ServerCommand<FlushLingeringMessagesCommand> for FlushLingeringMessagesExecutor {
+    #[instrument(skip_all, name = "trace_flush_lingering_messages")]
+    async fn execute(&mut self, system: &SharedSystem, _command: FlushLingeringMessagesCommand) {
+        match system.read().await.flush_lingering_messages().await {
+            Ok(flushed_messages_count) => {
+                if flushed_messages_count > 0 {
+                    trace!("Flushed {flushed_messages_count} lingering messages to disk.");
+                }
+            }
+            Err(error) => {
+                error!("Couldn't flush lingering messages to disk. Error: {error}");
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<FlushLingeringMessagesCommand>,
+    ) {
+        let lingering_messages_flusher =
+            LingeringMessagesFlusher::new(&config.system.partition, sender);
+        lingering_messages_flusher.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<FlushLingeringMessagesCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Lingering messages flusher receiver stopped.");
+        });
+    }
+}
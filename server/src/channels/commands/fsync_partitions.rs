@@ -0,0 +1,102 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::PartitionConfig;
+use crate::streaming::systems::system::SharedSystem;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{error, info, instrument, trace};
+
+pub struct PartitionsFsyncer {
+    check_interval: IggyDuration,
+    sender: Sender<FsyncPartitionsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct FsyncPartitionsCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct FsyncPartitionsExecutor;
+
+impl PartitionsFsyncer {
+    pub fn new(config: &PartitionConfig, sender: Sender<FsyncPartitionsCommand>) -> Self {
+        Self {
+            check_interval: config.fsync_check_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        let check_interval = self.check_interval;
+        let sender = self.sender.clone();
+        info!("Partitions of the topics with the fsync policy will be checked every: {check_interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(check_interval.get_duration());
+            interval_timer.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+            loop {
+                interval_timer.tick().await;
+                sender.send(FsyncPartitionsCommand).unwrap_or_else(|error| {
+                    error!("Failed to send FsyncPartitionsCommand. Error: {error}");
+                });
+            }
+        });
+    }
+}
+
+impl ServerCommand<FsyncPartitionsCommand> for FsyncPartitionsExecutor {
+    #[instrument(skip_all, name = "trace_fsync_partitions")]
+    async fn execute(&mut self, system: &SharedSystem, _command: FsyncPartitionsCommand) {
+        match system.read().await.fsync_due_partitions().await {
+            Ok(synced_messages_count) => {
+                if synced_messages_count > 0 {
+                    trace!("Synced {synced_messages_count} messages to disk.");
+                }
+            }
+            Err(error) => {
+                error!("Couldn't sync partitions to disk. Error: {error}");
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<FsyncPartitionsCommand>,
+    ) {
+        let partitions_fsyncer = PartitionsFsyncer::new(&config.system.partition, sender);
+        partitions_fsyncer.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<FsyncPartitionsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Partitions fsyncer receiver stopped.");
+        });
+    }
+}
@@ -20,14 +20,19 @@ use crate::archiver::ArchiverKind;
 use crate::channels::server_command::ServerCommand;
 use crate::configs::server::MessagesMaintenanceConfig;
 use crate::map_toggle_str;
+use crate::streaming::segments::RetentionHook;
 use crate::streaming::systems::system::SharedSystem;
 use crate::streaming::topics::topic::Topic;
+use crate::streaming::utils::file;
+use crate::streaming::utils::io_throttle::BackgroundIoThrottle;
 use error_set::ErrContext;
 use flume::Sender;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::compaction_policy::CompactionKey;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::time;
 use tracing::{debug, error, info, instrument, trace};
@@ -35,7 +40,10 @@ use tracing::{debug, error, info, instrument, trace};
 pub struct MessagesMaintainer {
     cleaner_enabled: bool,
     archiver_enabled: bool,
+    compactor_enabled: bool,
+    archive_before_delete: bool,
     interval: IggyDuration,
+    retention_hook_timeout: IggyDuration,
     sender: Sender<MaintainMessagesCommand>,
 }
 
@@ -43,6 +51,9 @@ pub struct MessagesMaintainer {
 pub struct MaintainMessagesCommand {
     clean_messages: bool,
     archive_messages: bool,
+    compact_messages: bool,
+    archive_before_delete: bool,
+    retention_hook_timeout: IggyDuration,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -56,13 +67,16 @@ impl MessagesMaintainer {
         Self {
             cleaner_enabled: config.cleaner_enabled,
             archiver_enabled: config.archiver_enabled,
+            compactor_enabled: config.compactor_enabled,
+            archive_before_delete: config.archive_before_delete,
             interval: config.interval,
+            retention_hook_timeout: config.retention_hook_timeout,
             sender,
         }
     }
 
     pub fn start(&self) {
-        if !self.cleaner_enabled && !self.archiver_enabled {
+        if !self.cleaner_enabled && !self.archiver_enabled && !self.compactor_enabled {
             info!("Messages maintainer is disabled.");
             return;
         }
@@ -70,12 +84,16 @@ impl MessagesMaintainer {
         let interval = self.interval;
         let sender = self.sender.clone();
         info!(
-            "Message maintainer, cleaner is {}, archiver is {}, interval: {interval}",
+            "Message maintainer, cleaner is {}, archiver is {}, compactor is {}, interval: {interval}",
             map_toggle_str(self.cleaner_enabled),
-            map_toggle_str(self.archiver_enabled)
+            map_toggle_str(self.archiver_enabled),
+            map_toggle_str(self.compactor_enabled)
         );
         let clean_messages = self.cleaner_enabled;
         let archive_messages = self.archiver_enabled;
+        let compact_messages = self.compactor_enabled;
+        let archive_before_delete = self.archive_before_delete;
+        let retention_hook_timeout = self.retention_hook_timeout;
         tokio::spawn(async move {
             let mut interval_timer = time::interval(interval.get_duration());
             loop {
@@ -84,6 +102,9 @@ impl MessagesMaintainer {
                     .send(MaintainMessagesCommand {
                         clean_messages,
                         archive_messages,
+                        compact_messages,
+                        archive_before_delete,
+                        retention_hook_timeout,
                     })
                     .unwrap_or_else(|err| {
                         error!("Failed to send MaintainMessagesCommand. Error: {}", err);
@@ -97,10 +118,65 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
     #[instrument(skip_all, name = "trace_maintain_messages")]
     async fn execute(&mut self, system: &SharedSystem, command: MaintainMessagesCommand) {
         let system = system.read().await;
+        let now = system.clock.now();
+        let retention_hook = RetentionHook::new(command.retention_hook_timeout);
+        let deletion = DeletionStages {
+            retention_hook: &retention_hook,
+            archiver: if command.archive_before_delete {
+                system.archiver.clone()
+            } else {
+                None
+            },
+            background_io: &system.background_io,
+            now,
+        };
         let streams = system.get_streams();
         for stream in streams {
             let topics = stream.get_topics();
             for topic in topics {
+                match topic.roll_over_segments(now).await {
+                    Ok(0) => {}
+                    Ok(rolled_over_segments_count) => {
+                        debug!(
+                            "Rolled over {rolled_over_segments_count} segments for stream ID: {}, topic ID: {}",
+                            topic.stream_id, topic.topic_id
+                        );
+                    }
+                    Err(error) => {
+                        error!(
+                            "Failed to roll over segments for stream ID: {}, topic ID: {}. Error: {}",
+                            topic.stream_id, topic.topic_id, error
+                        );
+                    }
+                }
+
+                if command.compact_messages {
+                    if let Some(compaction_policy) = &topic.compaction_policy {
+                        match handle_compacted_segments(
+                            topic,
+                            &compaction_policy.key,
+                            &system.background_io,
+                        )
+                        .await
+                        {
+                            Ok(deleted_segments) => {
+                                system
+                                    .metrics
+                                    .decrement_segments(deleted_segments.segments_count);
+                                system
+                                    .metrics
+                                    .decrement_messages(deleted_segments.messages_count);
+                            }
+                            Err(error) => {
+                                error!(
+                                    "Failed to compact segments for stream ID: {}, topic ID: {}. Error: {}",
+                                    topic.stream_id, topic.topic_id, error
+                                );
+                            }
+                        }
+                    }
+                }
+
                 let archiver = if command.archive_messages {
                     system.archiver.clone()
                 } else {
@@ -108,9 +184,11 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
                 };
                 let expired_segments = handle_expired_segments(
                     topic,
+                    now,
                     archiver.clone(),
                     system.config.segment.archive_expired,
                     command.clean_messages,
+                    &deletion,
                 )
                 .await;
                 if expired_segments.is_err() {
@@ -125,6 +203,7 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
                     topic,
                     archiver.clone(),
                     system.config.topic.delete_oldest_segments,
+                    &deletion,
                 )
                 .await;
                 if oldest_segments.is_err() {
@@ -180,6 +259,7 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
         if (!config.data_maintenance.archiver.enabled
             || !config.data_maintenance.messages.archiver_enabled)
             && !config.data_maintenance.messages.cleaner_enabled
+            && !config.data_maintenance.messages.compactor_enabled
         {
             return;
         }
@@ -198,6 +278,7 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
         if (!config.data_maintenance.archiver.enabled
             || !config.data_maintenance.messages.archiver_enabled)
             && !config.data_maintenance.messages.cleaner_enabled
+            && !config.data_maintenance.messages.compactor_enabled
         {
             return;
         }
@@ -212,24 +293,72 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
     }
 }
 
+async fn handle_compacted_segments(
+    topic: &Topic,
+    key: &CompactionKey,
+    background_io: &BackgroundIoThrottle,
+) -> Result<HandledSegments, IggyError> {
+    let mut compacted_segments_count = 0;
+    let mut compacted_messages_count = 0;
+    let mut deleted_segments = HandledSegments::none();
+    for partition in topic.partitions.values() {
+        // The compaction holds the partition lock, so its I/O is accounted once it's done,
+        // and the compaction of the next partition waits until the consumed budget is paid off.
+        let compacted_segments = {
+            let mut partition = partition.write().await;
+            partition.compact_segments(key).await.with_error_context(|error| {
+                format!("CHANNEL_COMMAND - failed to compact segments for stream ID: {}, topic ID: {}, partition ID: {}. {error}", topic.stream_id, topic.topic_id, partition.partition_id)
+            })?
+        };
+        background_io.throttle(compacted_segments.io_bytes).await;
+        compacted_segments_count += compacted_segments.segments_count;
+        compacted_messages_count += compacted_segments.messages_count;
+        deleted_segments.segments_count += compacted_segments.deleted_segments_count;
+        deleted_segments.messages_count += compacted_segments.deleted_messages_count;
+    }
+
+    if compacted_segments_count == 0 && deleted_segments.segments_count == 0 {
+        trace!(
+            "No segments were compacted for stream ID: {}, topic ID: {}",
+            topic.stream_id,
+            topic.topic_id
+        );
+        return Ok(deleted_segments);
+    }
+
+    info!(
+        "Compacted {} segments removing {} messages, and deleted {} fully compacted segments for stream ID: {}, topic ID: {}",
+        compacted_segments_count,
+        compacted_messages_count,
+        deleted_segments.segments_count,
+        topic.stream_id,
+        topic.topic_id
+    );
+    Ok(deleted_segments)
+}
+
 async fn handle_expired_segments(
     topic: &Topic,
+    now: IggyTimestamp,
     archiver: Option<Arc<ArchiverKind>>,
     archive: bool,
     clean: bool,
+    deletion: &DeletionStages<'_>,
 ) -> Result<HandledSegments, IggyError> {
-    let expired_segments = get_expired_segments(topic, IggyTimestamp::now()).await;
+    let expired_segments = get_expired_segments(topic, now).await;
     if expired_segments.is_empty() {
         return Ok(HandledSegments::none());
     }
 
-    if archive {
+    // The segments to be deleted are archived along with their manifests by the deletion itself.
+    let archived_before_deletion = clean && deletion.archiver.is_some();
+    if archive && !archived_before_deletion {
         if let Some(archiver) = archiver {
             info!(
                 "Archiving expired segments for stream ID: {}, topic ID: {}",
                 topic.stream_id, topic.topic_id
             );
-            archive_segments(topic, &expired_segments, archiver.clone()).await.with_error_context(|error| {
+            archive_segments(topic, &expired_segments, archiver.clone(), deletion.background_io).await.with_error_context(|error| {
                 format!("CHANNEL_COMMAND - failed to archive expired segments for stream ID: {}, topic ID: {}. {error}", topic.stream_id, topic.topic_id)
             })?;
         } else {
@@ -246,7 +375,7 @@ async fn handle_expired_segments(
             "Deleting expired segments for stream ID: {}, topic ID: {}",
             topic.stream_id, topic.topic_id
         );
-        delete_segments(topic, &expired_segments).await
+        delete_segments(topic, &expired_segments, deletion).await
     } else {
         info!(
             "Deleting expired segments is disabled for stream ID: {}, topic ID: {}",
@@ -288,6 +417,7 @@ async fn handle_oldest_segments(
     topic: &Topic,
     archiver: Option<Arc<ArchiverKind>>,
     delete_oldest_segments: bool,
+    deletion: &DeletionStages<'_>,
 ) -> Result<HandledSegments, IggyError> {
     if let Some(archiver) = archiver {
         let mut segments_to_archive = Vec::new();
@@ -337,8 +467,13 @@ async fn handle_oldest_segments(
             topic.stream_id,
             topic.topic_id,
         );
-        archive_segments(topic, &segments_to_archive, archiver.clone())
-            .await
+        archive_segments(
+            topic,
+            &segments_to_archive,
+            archiver.clone(),
+            deletion.background_io,
+        )
+        .await
             .with_error_context(|error| {
                 format!(
                     "CHANNEL_COMMAND - failed to archive segments for stream ID: {}, topic ID: {}. {error}",
@@ -347,6 +482,15 @@ async fn handle_oldest_segments(
             })?;
     }
 
+    if topic.retains_by_size() {
+        let oversized_segments = get_oversized_segments(topic).await;
+        if oversized_segments.is_empty() {
+            return Ok(HandledSegments::none());
+        }
+
+        return delete_segments(topic, &oversized_segments, deletion).await;
+    }
+
     if topic.is_unlimited() {
         debug!(
             "Topic is unlimited, oldest segments will not be deleted for stream ID: {}, topic ID: {}",
@@ -376,7 +520,35 @@ async fn handle_oldest_segments(
         return Ok(HandledSegments::none());
     }
 
-    delete_segments(topic, &oldest_segments).await
+    delete_segments(topic, &oldest_segments, deletion).await
+}
+
+async fn get_oversized_segments(topic: &Topic) -> Vec<SegmentsToHandle> {
+    let oversized_segments = topic
+        .get_oversized_segments_start_offsets_per_partition()
+        .await;
+    if oversized_segments.is_empty() {
+        debug!(
+            "No oversized segments found for stream ID: {}, topic ID: {}",
+            topic.stream_id, topic.topic_id
+        );
+        return Vec::new();
+    }
+
+    info!(
+        "Found oversized segments in {} partitions for stream ID: {}, topic ID: {}",
+        oversized_segments.len(),
+        topic.stream_id,
+        topic.topic_id
+    );
+
+    oversized_segments
+        .into_iter()
+        .map(|(partition_id, start_offsets)| SegmentsToHandle {
+            partition_id,
+            start_offsets,
+        })
+        .collect()
 }
 
 async fn get_oldest_segments(topic: &Topic) -> Vec<SegmentsToHandle> {
@@ -413,7 +585,15 @@ async fn get_oldest_segments(topic: &Topic) -> Vec<SegmentsToHandle> {
     oldest_segments
 }
 
-#[derive()]
+/// The stages the segments go through before they're deleted by the retention.
+struct DeletionStages<'a> {
+    retention_hook: &'a RetentionHook,
+    archiver: Option<Arc<ArchiverKind>>,
+    background_io: &'a BackgroundIoThrottle,
+    now: IggyTimestamp,
+}
+
+#[derive(Clone)]
 struct SegmentsToHandle {
     partition_id: u32,
     start_offsets: Vec<u64>,
@@ -438,6 +618,7 @@ async fn archive_segments(
     topic: &Topic,
     segments_to_archive: &[SegmentsToHandle],
     archiver: Arc<ArchiverKind>,
+    background_io: &BackgroundIoThrottle,
 ) -> Result<u64, IggyError> {
     if segments_to_archive.is_empty() {
         return Ok(0);
@@ -454,29 +635,46 @@ async fn archive_segments(
     for segment_to_archive in segments_to_archive {
         match topic.get_partition(segment_to_archive.partition_id) {
             Ok(partition) => {
-                let partition = partition.read().await;
-                for start_offset in &segment_to_archive.start_offsets {
-                    let segment = partition.get_segment(*start_offset);
-                    if segment.is_none() {
-                        error!(
-                            "Segment with start offset: {} not found for stream ID: {}, topic ID: {}, partition ID: {}",
-                            start_offset, topic.stream_id, topic.topic_id, partition.partition_id
-                        );
-                        continue;
+                let partition_id = segment_to_archive.partition_id;
+                let mut segments_files = Vec::with_capacity(segment_to_archive.start_offsets.len());
+                {
+                    let partition = partition.read().await;
+                    for start_offset in &segment_to_archive.start_offsets {
+                        let segment = partition.get_segment(*start_offset);
+                        if segment.is_none() {
+                            error!(
+                                "Segment with start offset: {} not found for stream ID: {}, topic ID: {}, partition ID: {}",
+                                start_offset, topic.stream_id, topic.topic_id, partition_id
+                            );
+                            continue;
+                        }
+
+                        let segment = segment.unwrap();
+                        // The log file of the offloaded segment is already kept in the tiered storage.
+                        let log_path = match segment.is_tiered() {
+                            true => segment.tiered_path.clone(),
+                            false => segment.log_path.clone(),
+                        };
+                        segments_files
+                            .push((*start_offset, [segment.index_path.clone(), log_path]));
                     }
+                }
 
-                    let segment = segment.unwrap();
-                    let files = [segment.index_path.as_ref(), segment.log_path.as_ref()];
+                // The files of the closed segments are not appended to anymore, so they're archived without holding the partition lock,
+                // which could otherwise be held for a long time while the background I/O is throttled.
+                for (start_offset, files) in segments_files {
+                    background_io.throttle(get_files_size(&files).await).await;
+                    let files = files.iter().map(String::as_str).collect::<Vec<_>>();
                     if let Err(error) = archiver.archive(&files, None).await {
                         error!(
                             "Failed to archive segment with start offset: {} for stream ID: {}, topic ID: {}, partition ID: {}. Error: {}",
-                            start_offset, topic.stream_id, topic.topic_id, partition.partition_id, error
+                            start_offset, topic.stream_id, topic.topic_id, partition_id, error
                         );
                         continue;
                     }
                     info!(
                         "Archived Segment with start offset: {}, for stream ID: {}, topic ID: {}, partition ID: {}",
-                        start_offset, topic.stream_id, topic.topic_id, partition.partition_id
+                        start_offset, topic.stream_id, topic.topic_id, partition_id
                     );
                     archived_segments += 1;
                 }
@@ -494,10 +692,25 @@ async fn archive_segments(
     Ok(archived_segments)
 }
 
+async fn get_files_size(files: &[impl AsRef<Path>]) -> u64 {
+    let mut size_bytes = 0;
+    for file in files {
+        if let Ok(metadata) = tokio::fs::metadata(file).await {
+            size_bytes += metadata.len();
+        }
+    }
+    size_bytes
+}
+
 async fn delete_segments(
     topic: &Topic,
     segments_to_delete: &[SegmentsToHandle],
+    deletion: &DeletionStages<'_>,
 ) -> Result<HandledSegments, IggyError> {
+    let segments_to_delete =
+        approve_segments_deletion(topic, segments_to_delete, deletion.retention_hook).await;
+    let segments_to_delete =
+        archive_segments_before_deletion(topic, &segments_to_delete, deletion).await;
     info!(
         "Deleting {} segments for stream ID: {}, topic ID: {}...",
         segments_to_delete.len(),
@@ -507,7 +720,7 @@ async fn delete_segments(
 
     let mut segments_count = 0;
     let mut messages_count = 0;
-    for segment_to_delete in segments_to_delete {
+    for segment_to_delete in &segments_to_delete {
         match topic.get_partition(segment_to_delete.partition_id) {
             Ok(partition) => {
                 let mut partition = partition.write().await;
@@ -543,3 +756,122 @@ async fn delete_segments(
         messages_count,
     })
 }
+
+/// Calls the pre-deletion hook of the topic retention policy (if set) with the metadata of each segment to be deleted.
+/// The rejected segment is kept along with the newer segments of its partition, and the deletion is retried on the next run.
+async fn approve_segments_deletion(
+    topic: &Topic,
+    segments_to_delete: &[SegmentsToHandle],
+    retention_hook: &RetentionHook,
+) -> Vec<SegmentsToHandle> {
+    let Some(url) = topic
+        .retention_policy
+        .as_ref()
+        .and_then(|policy| policy.pre_deletion_hook.as_deref())
+    else {
+        return segments_to_delete.to_vec();
+    };
+
+    let mut approved_segments = Vec::new();
+    for segment_to_delete in segments_to_delete {
+        let Ok(partition) = topic.get_partition(segment_to_delete.partition_id) else {
+            continue;
+        };
+
+        let mut start_offsets = Vec::new();
+        for start_offset in &segment_to_delete.start_offsets {
+            let segment = {
+                let partition = partition.read().await;
+                let Some(segment) = partition.get_segment(*start_offset) else {
+                    break;
+                };
+                segment.get_deletion_metadata().await
+            };
+            if retention_hook.call(url, &segment).await.is_err() {
+                break;
+            }
+            start_offsets.push(*start_offset);
+        }
+
+        if !start_offsets.is_empty() {
+            approved_segments.push(SegmentsToHandle {
+                partition_id: segment_to_delete.partition_id,
+                start_offsets,
+            });
+        }
+    }
+
+    approved_segments
+}
+
+/// Archives the files of each segment to be deleted along with its manifest, if archiving the segments before the deletion is enabled.
+/// The segment which couldn't be archived is kept along with the newer segments of its partition, and the deletion is retried on the next run.
+async fn archive_segments_before_deletion(
+    topic: &Topic,
+    segments_to_delete: &[SegmentsToHandle],
+    deletion: &DeletionStages<'_>,
+) -> Vec<SegmentsToHandle> {
+    let Some(archiver) = &deletion.archiver else {
+        return segments_to_delete.to_vec();
+    };
+
+    let mut archived_segments = Vec::new();
+    for segment_to_delete in segments_to_delete {
+        let partition_id = segment_to_delete.partition_id;
+        let Ok(partition) = topic.get_partition(partition_id) else {
+            continue;
+        };
+
+        let mut start_offsets = Vec::new();
+        for start_offset in &segment_to_delete.start_offsets {
+            let (manifest, manifest_path) = {
+                let partition = partition.read().await;
+                let Some(segment) = partition.get_segment(*start_offset) else {
+                    break;
+                };
+                let manifest = segment.get_archive_manifest(deletion.now).await;
+                match segment.write_archive_manifest(&manifest).await {
+                    Ok(manifest_path) => (manifest, manifest_path),
+                    Err(error) => {
+                        error!(
+                            "Failed to write archive manifest of segment with start offset: {start_offset} for stream ID: {}, topic ID: {}, partition ID: {partition_id}. Error: {error}",
+                            topic.stream_id, topic.topic_id
+                        );
+                        break;
+                    }
+                }
+            };
+
+            let mut files = manifest.get_files();
+            files.push(&manifest_path);
+            deletion
+                .background_io
+                .throttle(get_files_size(&files).await)
+                .await;
+            let archived = archiver.archive(&files, None).await;
+            let _ = file::remove(&manifest_path).await;
+            if let Err(error) = archived {
+                error!(
+                    "Failed to archive segment with start offset: {start_offset} before deletion for stream ID: {}, topic ID: {}, partition ID: {partition_id}, the deletion is deferred. Error: {error}",
+                    topic.stream_id, topic.topic_id
+                );
+                break;
+            }
+
+            info!(
+                "Archived segment with start offset: {start_offset} before deletion for stream ID: {}, topic ID: {}, partition ID: {partition_id}, manifest: {manifest_path}",
+                topic.stream_id, topic.topic_id
+            );
+            start_offsets.push(*start_offset);
+        }
+
+        if !start_offsets.is_empty() {
+            archived_segments.push(SegmentsToHandle {
+                partition_id,
+                start_offsets,
+            });
+        }
+    }
+
+    archived_segments
+}
@@ -18,7 +18,16 @@
 
 pub mod archive_state;
 pub mod clean_personal_access_tokens;
+pub mod flush_lingering_messages;
+pub mod fsync_partitions;
 pub mod maintain_messages;
+pub mod monitor_consumer_groups;
+pub mod monitor_storage_usage;
 pub mod print_sysinfo;
+pub mod reload_certificate_denylist;
+pub mod remove_deleted_topics;
 pub mod save_messages;
+pub mod tier_segments;
+pub mod verify_consumer_groups;
 pub mod verify_heartbeats;
+pub mod write_audit_log;
@@ -0,0 +1,347 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::ConsumerGroupSloConfig;
+use crate::streaming::diagnostics::metrics::{ConsumerGroupLabels, ConsumerGroupMeasurement};
+use crate::streaming::systems::system::SharedSystem;
+use ahash::{AHashMap, AHashSet};
+use flume::Sender;
+use iggy::locking::IggySharedMutFn;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use reqwest::header::CONTENT_TYPE;
+use serde::Serialize;
+use tokio::time;
+use tracing::{debug, error, info, instrument, warn};
+
+pub struct MonitorConsumerGroups {
+    interval: IggyDuration,
+    max_lag: IggyDuration,
+    sender: Sender<MonitorConsumerGroupsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MonitorConsumerGroupsCommand {
+    max_lag: IggyDuration,
+}
+
+#[derive(Debug, Default)]
+pub struct MonitorConsumerGroupsExecutor {
+    groups: AHashMap<ConsumerGroupLabels, ConsumerGroupState>,
+    hook: Option<ConsumerGroupSloHook>,
+}
+
+/// The last measurement of the consumer group, used to calculate the consumption rate and detect the SLO transitions.
+#[derive(Debug, Clone, Copy)]
+struct ConsumerGroupState {
+    consumed_messages: u64,
+    measured_at: IggyTimestamp,
+    slo_breached: bool,
+}
+
+/// The notification sent to the SLO webhook once the consumer group breaches or recovers from its SLO.
+#[derive(Debug, Serialize)]
+pub struct ConsumerGroupSloAlert {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub group_id: u32,
+    pub group_name: String,
+    pub breached: bool,
+    pub lag_messages: u64,
+    pub lag_seconds: f64,
+    pub max_lag_seconds: f64,
+    pub consumption_rate: f64,
+    pub timestamp: u64,
+}
+
+/// Calls the webhook configured for the consumer groups SLO.
+#[derive(Debug)]
+struct ConsumerGroupSloHook {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl MonitorConsumerGroups {
+    pub fn new(
+        config: &ConsumerGroupSloConfig,
+        sender: Sender<MonitorConsumerGroupsCommand>,
+    ) -> Self {
+        Self {
+            interval: config.interval,
+            max_lag: config.max_lag,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        let interval = self.interval;
+        let max_lag = self.max_lag;
+        let sender = self.sender.clone();
+        info!("Consumer groups will be monitored every: {interval}. Maximum lag: {max_lag}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                debug!("Monitoring consumer groups...");
+                sender
+                    .send(MonitorConsumerGroupsCommand { max_lag })
+                    .unwrap_or_else(|error| {
+                        error!("Failed to send MonitorConsumerGroups. Error: {}", error);
+                    });
+            }
+        });
+    }
+}
+
+impl ConsumerGroupSloHook {
+    fn new(url: &str, timeout: IggyDuration) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(timeout.get_duration())
+            .build()
+            .unwrap_or_default();
+        Self {
+            url: url.to_owned(),
+            client,
+        }
+    }
+
+    async fn call(&self, alert: &ConsumerGroupSloAlert) {
+        let url = &self.url;
+        let body = match serde_json::to_vec(alert) {
+            Ok(body) => body,
+            Err(error) => {
+                error!(
+                    "Failed to serialize SLO alert for consumer group: {}. {error}",
+                    alert.group_id
+                );
+                return;
+            }
+        };
+        let response = match self
+            .client
+            .post(url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await
+        {
+            Ok(response) => response,
+            Err(error) => {
+                warn!(
+                    "Failed to call SLO webhook: {url} for consumer group: {} for topic with ID: {} and stream with ID: {}. {error}",
+                    alert.group_id, alert.topic_id, alert.stream_id
+                );
+                return;
+            }
+        };
+
+        let status = response.status();
+        if !status.is_success() {
+            warn!(
+                "SLO webhook: {url} responded with status: {status} for consumer group: {} for topic with ID: {} and stream with ID: {}.",
+                alert.group_id, alert.topic_id, alert.stream_id
+            );
+        }
+    }
+}
+
+impl MonitorConsumerGroupsExecutor {
+    fn measure(
+        previous: Option<&ConsumerGroupState>,
+        consumed_messages: u64,
+        lag_messages: u64,
+        oldest_pending_timestamp: Option<u64>,
+        now: IggyTimestamp,
+        max_lag: IggyDuration,
+    ) -> ConsumerGroupMeasurement {
+        let lag_seconds = oldest_pending_timestamp
+            .map(|timestamp| now.as_micros().saturating_sub(timestamp) as f64 / 1_000_000.0)
+            .unwrap_or_default();
+        let consumption_rate = match previous {
+            Some(previous) if now.as_micros() > previous.measured_at.as_micros() => {
+                let elapsed =
+                    (now.as_micros() - previous.measured_at.as_micros()) as f64 / 1_000_000.0;
+                consumed_messages.saturating_sub(previous.consumed_messages) as f64 / elapsed
+            }
+            _ => 0.0,
+        };
+        ConsumerGroupMeasurement {
+            consumption_rate,
+            lag_messages,
+            lag_seconds,
+            slo_breached: lag_seconds > max_lag.as_secs_f64(),
+        }
+    }
+}
+
+impl ServerCommand<MonitorConsumerGroupsCommand> for MonitorConsumerGroupsExecutor {
+    #[instrument(skip_all, name = "trace_monitor_consumer_groups")]
+    async fn execute(&mut self, system: &SharedSystem, command: MonitorConsumerGroupsCommand) {
+        let system = system.read().await;
+        let now = system.clock.now();
+        let mut measured_groups = AHashSet::new();
+        let mut alerts = Vec::new();
+        for stream in system.get_streams() {
+            for topic in stream.get_topics() {
+                let partitions = topic.get_partitions();
+                for consumer_group in topic.get_consumer_groups() {
+                    let consumer_group = consumer_group.read().await;
+                    let group_id = consumer_group.group_id;
+                    let mut consumed_messages = 0;
+                    let mut lag_messages = 0;
+                    let mut oldest_pending_timestamp: Option<u64> = None;
+                    for partition in &partitions {
+                        let partition = partition.read().await;
+                        let lag = match partition.get_consumer_group_lag(group_id).await {
+                            Ok(lag) => lag,
+                            Err(error) => {
+                                error!(
+                                    "Failed to get lag of consumer group: {group_id} for partition with ID: {} for topic with ID: {} and stream with ID: {}. Error: {error}",
+                                    partition.partition_id, topic.topic_id, stream.stream_id
+                                );
+                                continue;
+                            }
+                        };
+                        consumed_messages += lag.consumed_messages;
+                        lag_messages += lag.lag_messages;
+                        if let Some(timestamp) = lag.oldest_pending_timestamp {
+                            oldest_pending_timestamp = Some(
+                                oldest_pending_timestamp
+                                    .map_or(timestamp, |oldest| oldest.min(timestamp)),
+                            );
+                        }
+                    }
+
+                    let labels = ConsumerGroupLabels {
+                        stream_id: stream.stream_id,
+                        topic_id: topic.topic_id,
+                        group_id,
+                    };
+                    let previous = self.groups.get(&labels).copied();
+                    let measurement = Self::measure(
+                        previous.as_ref(),
+                        consumed_messages,
+                        lag_messages,
+                        oldest_pending_timestamp,
+                        now,
+                        command.max_lag,
+                    );
+                    system
+                        .metrics
+                        .set_consumer_group_measurement(&labels, &measurement);
+
+                    let was_breached = previous.is_some_and(|previous| previous.slo_breached);
+                    if measurement.slo_breached != was_breached {
+                        if measurement.slo_breached {
+                            system
+                                .metrics
+                                .increment_consumer_group_slo_breaches(&labels);
+                            warn!(
+                                "Consumer group: {group_id} for topic with ID: {} and stream with ID: {} has breached its SLO, lag: {:.3} s ({} messages), maximum lag: {}, consumption rate: {:.3} messages/s.",
+                                topic.topic_id, stream.stream_id, measurement.lag_seconds, measurement.lag_messages, command.max_lag, measurement.consumption_rate
+                            );
+                        } else {
+                            info!(
+                                "Consumer group: {group_id} for topic with ID: {} and stream with ID: {} has recovered from its SLO breach, lag: {:.3} s ({} messages).",
+                                topic.topic_id, stream.stream_id, measurement.lag_seconds, measurement.lag_messages
+                            );
+                        }
+                        alerts.push(ConsumerGroupSloAlert {
+                            stream_id: stream.stream_id,
+                            topic_id: topic.topic_id,
+                            group_id,
+                            group_name: consumer_group.name.clone(),
+                            breached: measurement.slo_breached,
+                            lag_messages: measurement.lag_messages,
+                            lag_seconds: measurement.lag_seconds,
+                            max_lag_seconds: command.max_lag.as_secs_f64(),
+                            consumption_rate: measurement.consumption_rate,
+                            timestamp: now.as_micros(),
+                        });
+                    }
+
+                    self.groups.insert(
+                        labels.clone(),
+                        ConsumerGroupState {
+                            consumed_messages,
+                            measured_at: now,
+                            slo_breached: measurement.slo_breached,
+                        },
+                    );
+                    measured_groups.insert(labels);
+                }
+            }
+        }
+
+        self.groups.retain(|labels, _| {
+            if measured_groups.contains(labels) {
+                return true;
+            }
+
+            system.metrics.remove_consumer_group(labels);
+            false
+        });
+        drop(system);
+
+        let Some(hook) = &self.hook else {
+            return;
+        };
+
+        for alert in alerts {
+            hook.call(&alert).await;
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<MonitorConsumerGroupsCommand>,
+    ) {
+        let slo = &config.system.consumer_group.slo;
+        if !slo.enabled {
+            return;
+        }
+
+        let monitor_consumer_groups = MonitorConsumerGroups::new(slo, sender);
+        monitor_consumer_groups.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<MonitorConsumerGroupsCommand>,
+    ) {
+        let slo = &config.system.consumer_group.slo;
+        if !slo.webhook_url.is_empty() {
+            self.hook = Some(ConsumerGroupSloHook::new(
+                &slo.webhook_url,
+                slo.webhook_timeout,
+            ));
+        }
+
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Consumer groups monitor receiver stopped.");
+        });
+    }
+}
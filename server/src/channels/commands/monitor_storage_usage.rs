@@ -0,0 +1,173 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ServerConfig;
+use crate::streaming::diagnostics::metrics::{PartitionLabels, StreamLabels, TopicLabels};
+use crate::streaming::storage_usage::storage_usage_tracker::{
+    StorageResource, StorageUsageLevel, StorageUsageSnapshot,
+};
+use crate::streaming::systems::system::SharedSystem;
+use flume::Sender;
+use iggy::locking::IggySharedMutFn;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info, instrument, warn};
+
+pub struct MonitorStorageUsage {
+    interval: IggyDuration,
+    sender: Sender<MonitorStorageUsageCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MonitorStorageUsageCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct MonitorStorageUsageExecutor;
+
+impl MonitorStorageUsage {
+    pub fn new(interval: IggyDuration, sender: Sender<MonitorStorageUsageCommand>) -> Self {
+        Self { interval, sender }
+    }
+
+    pub fn start(&self) {
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Storage usage will be measured every: {interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                debug!("Measuring storage usage...");
+                sender
+                    .send(MonitorStorageUsageCommand)
+                    .unwrap_or_else(|error| {
+                        error!("Failed to send MonitorStorageUsage. Error: {}", error);
+                    });
+            }
+        });
+    }
+}
+
+impl ServerCommand<MonitorStorageUsageCommand> for MonitorStorageUsageExecutor {
+    #[instrument(skip_all, name = "trace_monitor_storage_usage")]
+    async fn execute(&mut self, system: &SharedSystem, _command: MonitorStorageUsageCommand) {
+        let system = system.read().await;
+        let mut snapshot = StorageUsageSnapshot::default();
+        for stream in system.get_streams() {
+            for topic in stream.get_topics() {
+                for partition in topic.get_partitions() {
+                    let partition = partition.read().await;
+                    snapshot.add_partition(
+                        stream.stream_id,
+                        topic.topic_id,
+                        partition.partition_id,
+                        partition.get_disk_usage_bytes(),
+                    );
+                }
+            }
+        }
+
+        let storage_usage = &system.storage_usage;
+        system.metrics.clear_storage_usage();
+        for (stream_id, disk_usage_bytes) in snapshot.streams() {
+            let level =
+                storage_usage.get_level(StorageResource::Stream(stream_id), disk_usage_bytes);
+            system.metrics.set_stream_storage_usage(
+                &StreamLabels { stream_id },
+                disk_usage_bytes,
+                level,
+            );
+        }
+        for ((stream_id, topic_id), disk_usage_bytes) in snapshot.topics() {
+            let level = storage_usage.get_level(
+                StorageResource::Topic(stream_id, topic_id),
+                disk_usage_bytes,
+            );
+            system.metrics.set_topic_storage_usage(
+                &TopicLabels {
+                    stream_id,
+                    topic_id,
+                },
+                disk_usage_bytes,
+                level,
+            );
+        }
+        for ((stream_id, topic_id, partition_id), disk_usage_bytes) in snapshot.partitions() {
+            system.metrics.set_partition_disk_usage(
+                &PartitionLabels {
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                },
+                disk_usage_bytes,
+            );
+        }
+
+        for transition in storage_usage.update(snapshot) {
+            let disk_usage = IggyByteSize::from(transition.disk_usage_bytes);
+            match transition.level {
+                StorageUsageLevel::HardLimitExceeded => warn!(
+                    "Disk usage of {}: {disk_usage} has exceeded the hard limit: {}.",
+                    transition.resource, transition.limits.hard_limit
+                ),
+                StorageUsageLevel::SoftLimitExceeded => warn!(
+                    "Disk usage of {}: {disk_usage} has exceeded the soft limit: {}.",
+                    transition.resource, transition.limits.soft_limit
+                ),
+                StorageUsageLevel::Normal => info!(
+                    "Disk usage of {}: {disk_usage} is back below the limits, previous level: {}.",
+                    transition.resource, transition.previous_level
+                ),
+            }
+        }
+        system
+            .metrics
+            .set_disk_usage(storage_usage.get_total_disk_usage_bytes());
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<MonitorStorageUsageCommand>,
+    ) {
+        let storage_usage = &config.system.storage_usage;
+        if !storage_usage.enabled {
+            return;
+        }
+
+        let monitor_storage_usage = MonitorStorageUsage::new(storage_usage.interval, sender);
+        monitor_storage_usage.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: flume::Receiver<MonitorStorageUsageCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Storage usage monitor receiver stopped.");
+        });
+    }
+}
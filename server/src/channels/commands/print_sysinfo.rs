@@ -17,11 +17,13 @@
  */
 
 use crate::{
-    channels::server_command::ServerCommand, configs::server::ServerConfig,
-    streaming::systems::system::SharedSystem,
+    channels::server_command::ServerCommand,
+    configs::server::ServerConfig,
+    streaming::{cache::memory_tracker::CacheMemoryTracker, systems::system::SharedSystem},
 };
 use flume::{Receiver, Sender};
 use human_repr::HumanCount;
+use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use tokio::time::{self};
 use tracing::{error, info, warn};
@@ -86,8 +88,11 @@ impl ServerCommand<SysInfoPrintCommand> for SysInfoPrintExecutor {
             .iter()
             .fold(0, |acc, (_, metrics)| acc + metrics.misses);
         let cache_ratio = cache_hits as f64 / (cache_hits + cache_misses) as f64;
+        let cache_tracker_metrics = CacheMemoryTracker::get_instance()
+            .map(|memory_tracker| memory_tracker.metrics())
+            .unwrap_or_default();
 
-        info!("CPU: {:.2}% / {:.2}% (IggyUsage/Total), Mem: {:.2}% / {} / {} / {} (Free/IggyUsage/TotalUsed/Total), Clients: {}, Messages processed: {}, Read: {}, Written: {}, Cache: {}/{}/{:.2} (Hits/Misses/Ratio), Uptime: {}",
+        info!("CPU: {:.2}% / {:.2}% (IggyUsage/Total), Mem: {:.2}% / {} / {} / {} (Free/IggyUsage/TotalUsed/Total), Clients: {}, Messages processed: {}, Read: {}, Written: {}, Cache: {}/{}/{:.2}/{} (Hits/Misses/Ratio/Evictions), Cache size: {} / {} (Used/Limit), Prefetch: {}/{}/{} (Messages/Hits/Discarded), Uptime: {}",
               stats.cpu_usage,
               stats.total_cpu_usage,
               free_memory_percent,
@@ -101,6 +106,12 @@ impl ServerCommand<SysInfoPrintCommand> for SysInfoPrintExecutor {
               cache_hits.human_count_bare(),
               cache_misses.human_count_bare(),
               cache_ratio,
+              cache_tracker_metrics.evicted_messages.human_count_bare(),
+              IggyByteSize::from(cache_tracker_metrics.used_bytes),
+              IggyByteSize::from(cache_tracker_metrics.limit_bytes),
+              cache_tracker_metrics.prefetched_messages.human_count_bare(),
+              cache_tracker_metrics.prefetch_hits.human_count_bare(),
+              cache_tracker_metrics.prefetch_discarded_messages.human_count_bare(),
               stats.run_time);
     }
 
@@ -0,0 +1,120 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ServerConfig;
+use crate::configs::system::CertificateAuthConfig;
+use crate::streaming::systems::system::SharedSystem;
+use flume::{Receiver, Sender};
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info, instrument, warn};
+
+pub struct CertificateDenylistReloader {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<ReloadCertificateDenylistCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ReloadCertificateDenylistCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct ReloadCertificateDenylistExecutor;
+
+impl CertificateDenylistReloader {
+    pub fn new(
+        config: &CertificateAuthConfig,
+        sender: Sender<ReloadCertificateDenylistCommand>,
+    ) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.denylist_reload_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled || self.interval.is_zero() {
+            info!("Certificate denylist reloader is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Certificate denylist reloader is enabled, the denylist will be reloaded every: {interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            // The denylist has been already loaded at startup.
+            interval_timer.tick().await;
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(ReloadCertificateDenylistCommand)
+                    .unwrap_or_else(|error| {
+                        error!(
+                            "Failed to send ReloadCertificateDenylistCommand. Error: {}",
+                            error
+                        );
+                    });
+            }
+        });
+    }
+}
+
+impl ServerCommand<ReloadCertificateDenylistCommand> for ReloadCertificateDenylistExecutor {
+    #[instrument(skip_all, name = "trace_reload_certificate_denylist")]
+    async fn execute(&mut self, system: &SharedSystem, _command: ReloadCertificateDenylistCommand) {
+        let mut system = system.write().await;
+        match system.certificate_auth.reload_denylist() {
+            Ok(revoked_certificates) => {
+                debug!(
+                    "Reloaded certificate denylist, revoked certificates: {revoked_certificates}."
+                )
+            }
+            Err(error) => {
+                error!("Failed to reload certificate denylist, keeping the previous one. {error}")
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<ReloadCertificateDenylistCommand>,
+    ) {
+        let reloader = CertificateDenylistReloader::new(&config.system.certificate_auth, sender);
+        reloader.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<ReloadCertificateDenylistCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            warn!("Certificate denylist reloader stopped receiving commands.");
+        });
+    }
+}
@@ -0,0 +1,112 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::TopicConfig;
+use crate::streaming::systems::system::SharedSystem;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{error, info, instrument};
+
+pub struct DeletedTopicsRemover {
+    grace_period: IggyDuration,
+    interval: IggyDuration,
+    sender: Sender<RemoveDeletedTopicsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RemoveDeletedTopicsCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct RemoveDeletedTopicsExecutor;
+
+impl DeletedTopicsRemover {
+    pub fn new(config: &TopicConfig, sender: Sender<RemoveDeletedTopicsCommand>) -> Self {
+        Self {
+            grace_period: config.deletion_grace_period,
+            interval: config.deletion_check_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if self.grace_period.is_zero() {
+            info!("Topic deletion grace period is disabled, the topics are deleted immediately.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Topic deletion grace period: {}, the pending deletions will be checked every: {interval}.",
+            self.grace_period
+        );
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(RemoveDeletedTopicsCommand)
+                    .unwrap_or_else(|error| {
+                        error!(
+                            "Failed to send RemoveDeletedTopicsCommand. Error: {}",
+                            error
+                        );
+                    });
+            }
+        });
+    }
+}
+
+impl ServerCommand<RemoveDeletedTopicsCommand> for RemoveDeletedTopicsExecutor {
+    #[instrument(skip_all, name = "trace_remove_deleted_topics")]
+    async fn execute(&mut self, system: &SharedSystem, _command: RemoveDeletedTopicsCommand) {
+        // The system is only locked for writing, when there are any topics being deleted.
+        if !system.read().await.has_topics_being_deleted() {
+            return;
+        }
+
+        system.write().await.remove_deleted_topics().await;
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<RemoveDeletedTopicsCommand>,
+    ) {
+        let deleted_topics_remover = DeletedTopicsRemover::new(&config.system.topic, sender);
+        deleted_topics_remover.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<RemoveDeletedTopicsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Deleted topics remover receiver stopped.");
+        });
+    }
+}
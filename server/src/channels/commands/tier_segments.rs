@@ -0,0 +1,217 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::TieringConfig;
+use crate::streaming::segments::{evict_cached_segments, TieredSegment, TieredStorage};
+use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::utils::io_throttle::BackgroundIoThrottle;
+use flume::Sender;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info, instrument, warn};
+
+pub struct SegmentsTierer {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<TierSegmentsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct TierSegmentsCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct TierSegmentsExecutor;
+
+impl SegmentsTierer {
+    pub fn new(config: &TieringConfig, sender: Sender<TierSegmentsCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Segments tierer is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Segments tierer is enabled, closed segments will be offloaded to the tiered storage every: {interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender.send(TierSegmentsCommand).unwrap_or_else(|error| {
+                    error!("Failed to send TierSegmentsCommand. Error: {}", error);
+                });
+            }
+        });
+    }
+}
+
+impl ServerCommand<TierSegmentsCommand> for TierSegmentsExecutor {
+    #[instrument(skip_all, name = "trace_tier_segments")]
+    async fn execute(&mut self, system: &SharedSystem, _command: TierSegmentsCommand) {
+        let system = system.read().await;
+        let storage = match TieredStorage::new(&system.config.tiering.s3) {
+            Ok(storage) => storage,
+            Err(error) => {
+                error!("Failed to initialize the tiered storage. Error: {error}");
+                return;
+            }
+        };
+
+        let now = system.clock.now();
+        let mut offloaded_segments = 0;
+        for stream in system.get_streams() {
+            for topic in stream.get_topics() {
+                let segments_to_offload = topic.get_segments_to_offload_per_partition(now).await;
+                for (partition_id, start_offsets) in segments_to_offload {
+                    match offload_segments(
+                        topic,
+                        partition_id,
+                        &start_offsets,
+                        &storage,
+                        &system.background_io,
+                    )
+                    .await
+                    {
+                        Ok(count) => offloaded_segments += count,
+                        Err(error) => {
+                            error!(
+                                "Failed to offload segments for stream ID: {}, topic ID: {}, partition ID: {partition_id}. Error: {error}",
+                                topic.stream_id, topic.topic_id
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        if offloaded_segments > 0 {
+            info!("Offloaded {offloaded_segments} segment(s) to the tiered storage.");
+        } else {
+            debug!("No segments were offloaded to the tiered storage.");
+        }
+
+        let cache_path = system.config.get_tiering_cache_path();
+        match evict_cached_segments(&cache_path, system.config.tiering.max_cache_size).await {
+            Ok(0) => {}
+            Ok(evicted) => {
+                info!(
+                    "Evicted {evicted} segment(s) fetched from the tiered storage from the cache."
+                )
+            }
+            Err(error) => {
+                error!("Failed to evict segments from the tiered storage cache. Error: {error}")
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<TierSegmentsCommand>,
+    ) {
+        let segments_tierer = SegmentsTierer::new(&config.system.tiering, sender);
+        segments_tierer.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<TierSegmentsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Segments tierer receiver stopped.");
+        });
+    }
+}
+
+async fn offload_segments(
+    topic: &Topic,
+    partition_id: u32,
+    start_offsets: &[u64],
+    storage: &TieredStorage,
+    background_io: &BackgroundIoThrottle,
+) -> Result<u32, IggyError> {
+    let partition = topic.get_partition(partition_id)?;
+    let mut offloaded_segments = 0;
+    for &start_offset in start_offsets {
+        let (object_key, log_path, size_bytes, end_timestamp) = {
+            let partition = partition.read().await;
+            let Some(segment) = partition.get_segment(start_offset) else {
+                continue;
+            };
+            let end_timestamp = segment
+                .get_last_message_timestamp()
+                .await
+                .unwrap_or(segment.end_timestamp);
+            (
+                segment.get_tiered_object_key(),
+                segment.log_path.clone(),
+                segment.size_bytes.as_bytes_u64(),
+                end_timestamp,
+            )
+        };
+
+        // The log file of the closed segment is not appended to anymore, so it's uploaded without holding the partition lock.
+        background_io.throttle(size_bytes).await;
+        storage
+            .upload(&object_key, &log_path)
+            .await
+            .map_err(|_| IggyError::CannotUploadSegment(start_offset, partition_id))?;
+
+        let mut partition = partition.write().await;
+        let segment = partition
+            .get_segment_mut(start_offset)
+            .filter(|segment| segment.size_bytes.as_bytes_u64() == size_bytes);
+        let Some(segment) = segment else {
+            warn!(
+                "Segment with start offset: {start_offset} for stream ID: {}, topic ID: {}, partition ID: {partition_id} was deleted or compacted while being offloaded.",
+                topic.stream_id, topic.topic_id
+            );
+            let _ = storage.delete(&object_key).await;
+            continue;
+        };
+
+        segment
+            .complete_offload(TieredSegment {
+                object_key,
+                log_size_bytes: size_bytes,
+                end_timestamp,
+            })
+            .await?;
+        offloaded_segments += 1;
+    }
+
+    Ok(offloaded_segments)
+}
@@ -0,0 +1,163 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::ConsumerGroupConfig;
+use crate::streaming::systems::system::SharedSystem;
+use flume::Sender;
+use iggy::identifier::Identifier;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info, instrument, warn};
+
+pub struct VerifyConsumerGroups {
+    interval: IggyDuration,
+    session_timeout: IggyDuration,
+    sender: Sender<VerifyConsumerGroupsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct VerifyConsumerGroupsCommand {
+    session_timeout: IggyDuration,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct VerifyConsumerGroupsExecutor;
+
+impl VerifyConsumerGroups {
+    pub fn new(config: &ConsumerGroupConfig, sender: Sender<VerifyConsumerGroupsCommand>) -> Self {
+        Self {
+            interval: config.interval,
+            session_timeout: config.session_timeout,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        let interval = self.interval;
+        let session_timeout = self.session_timeout;
+        let sender = self.sender.clone();
+        if session_timeout.is_zero() {
+            info!(
+                "Consumer groups will be verified every: {interval}. Session timeout is disabled."
+            );
+        } else {
+            info!("Consumer groups will be verified every: {interval}. Session timeout: {session_timeout}.");
+        }
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                debug!("Verifying consumer groups...");
+                sender
+                    .send(VerifyConsumerGroupsCommand { session_timeout })
+                    .unwrap_or_else(|error| {
+                        error!("Failed to send VerifyConsumerGroups. Error: {}", error);
+                    });
+            }
+        });
+    }
+}
+
+impl ServerCommand<VerifyConsumerGroupsCommand> for VerifyConsumerGroupsExecutor {
+    #[instrument(skip_all, name = "trace_verify_consumer_groups")]
+    async fn execute(&mut self, system: &SharedSystem, command: VerifyConsumerGroupsCommand) {
+        let system = system.read().await;
+        let now = system.clock.now();
+        let mut expired_members = Vec::new();
+        for stream in system.get_streams() {
+            for topic in stream.get_topics() {
+                for consumer_group in topic.get_consumer_groups() {
+                    let mut consumer_group = consumer_group.write().await;
+                    if consumer_group.rebalance_if_due(now).await {
+                        debug!(
+                            "Performed delayed rebalance of consumer group: {} for topic with ID: {} and stream with ID: {}.",
+                            consumer_group.group_id, topic.topic_id, stream.stream_id
+                        );
+                    }
+
+                    for member_id in consumer_group
+                        .get_expired_members(now, command.session_timeout)
+                        .await
+                    {
+                        warn!(
+                            "Session of member with ID: {member_id} in consumer group: {} for topic with ID: {} and stream with ID: {} has expired, timeout: {}.",
+                            consumer_group.group_id, topic.topic_id, stream.stream_id, command.session_timeout
+                        );
+                        expired_members.push((
+                            stream.stream_id,
+                            topic.topic_id,
+                            consumer_group.group_id,
+                            member_id,
+                        ));
+                    }
+                }
+            }
+        }
+
+        if expired_members.is_empty() {
+            return;
+        }
+
+        let count = expired_members.len();
+        info!("Evicting {count} expired consumer group members...");
+        for (stream_id, topic_id, group_id, member_id) in expired_members {
+            let (Ok(stream_id), Ok(topic_id), Ok(group_id)) = (
+                Identifier::numeric(stream_id),
+                Identifier::numeric(topic_id),
+                Identifier::numeric(group_id),
+            ) else {
+                continue;
+            };
+
+            if let Err(error) = system
+                .leave_consumer_group_by_client(&stream_id, &topic_id, &group_id, member_id)
+                .await
+            {
+                error!("Failed to evict member with ID: {member_id} from consumer group: {group_id} for topic with ID: {topic_id} and stream with ID: {stream_id}. Error: {error}");
+            }
+        }
+        info!("Evicted {count} expired consumer group members.");
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<VerifyConsumerGroupsCommand>,
+    ) {
+        let verify_consumer_groups =
+            VerifyConsumerGroups::new(&config.system.consumer_group, sender);
+        verify_consumer_groups.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<VerifyConsumerGroupsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Consumer groups verifier receiver stopped.");
+        });
+    }
+}
@@ -90,8 +90,9 @@ impl ServerCommand<VerifyHeartbeatsCommand> for VerifyHeartbeatsExecutor {
             clients = client_manager.get_clients();
         }
 
-        let now = IggyTimestamp::now();
-        let heartbeat_to = IggyTimestamp::from(now.as_micros() - command.interval.as_micros());
+        let now = system.clock.now();
+        let heartbeat_to =
+            IggyTimestamp::from(now.as_micros().saturating_sub(command.interval.as_micros()));
         debug!("Verifying heartbeats at: {now}, max allowed timestamp: {heartbeat_to}");
         let mut stale_clients = Vec::new();
         for client in clients {
@@ -0,0 +1,140 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::audit::AuditSinkKind;
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::AuditConfig;
+use crate::streaming::systems::system::SharedSystem;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{error, info, instrument, warn};
+
+pub struct AuditLogWriter {
+    interval: IggyDuration,
+    sender: Sender<WriteAuditLogCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct WriteAuditLogCommand;
+
+#[derive(Debug, Default)]
+pub struct WriteAuditLogExecutor {
+    sink: Option<AuditSinkKind>,
+}
+
+impl AuditLogWriter {
+    pub fn new(config: &AuditConfig, sender: Sender<WriteAuditLogCommand>) -> Self {
+        Self {
+            interval: config.flush_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Audit log entries will be written every: {interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender.send(WriteAuditLogCommand).unwrap_or_else(|error| {
+                    error!("Failed to send WriteAuditLogCommand. Error: {}", error);
+                });
+            }
+        });
+    }
+}
+
+impl ServerCommand<WriteAuditLogCommand> for WriteAuditLogExecutor {
+    #[instrument(skip_all, name = "trace_write_audit_log")]
+    async fn execute(&mut self, system: &SharedSystem, _command: WriteAuditLogCommand) {
+        let Some(sink) = &self.sink else {
+            return;
+        };
+
+        let entries = system.read().await.audit_log.take_pending();
+        if entries.is_empty() {
+            return;
+        }
+
+        if let Err(error) = sink.write(&entries).await {
+            error!(
+                "Failed to write {} audit log entries with IDs: {}..{}. Error: {}",
+                entries.len(),
+                entries[0].id,
+                entries[entries.len() - 1].id,
+                error
+            );
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<WriteAuditLogCommand>,
+    ) {
+        if !config.system.audit.enabled {
+            return;
+        }
+
+        let audit_log_writer = AuditLogWriter::new(&config.system.audit, sender);
+        audit_log_writer.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<WriteAuditLogCommand>,
+    ) {
+        if !config.system.audit.enabled {
+            return;
+        }
+
+        match AuditSinkKind::from_config(&config.system, system.clone()) {
+            Ok(sink) => self.sink = Some(sink),
+            Err(error) => {
+                error!("Failed to create audit log sink, the entries will not be written. Error: {error}");
+            }
+        }
+
+        let max_entries = config.system.audit.max_entries as usize;
+        tokio::spawn(async move {
+            let system = system.clone();
+            if let Some(sink) = &self.sink {
+                if let Err(error) = sink.init().await {
+                    error!("Failed to initialize audit log sink. Error: {error}");
+                }
+                // Only the file can be read back, so that the entries recorded before the restart can be still queried.
+                if let AuditSinkKind::File(file) = sink {
+                    match file.load(max_entries).await {
+                        Ok(entries) => system.read().await.audit_log.restore(entries),
+                        Err(error) => warn!("Failed to load audit log entries. Error: {error}"),
+                    }
+                }
+            }
+
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Audit log writer receiver stopped.");
+        });
+    }
+}
@@ -0,0 +1,197 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cluster::raft::CommittedEntries;
+use crate::cluster::rpc::{read_message, write_message, Request, Response};
+use crate::configs::cluster::ClusterConfig;
+use crate::streaming::systems::system::SharedSystem;
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+use tokio::time::sleep;
+use tracing::{debug, error, info, warn};
+
+pub mod raft;
+pub mod replication;
+pub mod rpc;
+pub mod state;
+
+pub const COMPONENT: &str = "CLUSTER";
+
+/// Starts the cluster node - the listener for the requests of the other (authenticated) nodes, the election and heartbeat ticker,
+/// the task applying the entries replicated from the leader, and the one fetching the messages of the partition replicas.
+/// Returns the address the node is listening on.
+pub async fn start(config: ClusterConfig, system: SharedSystem) -> SocketAddr {
+    info!("Initializing cluster node with ID: {}...", config.id);
+    let (raft, replication) = {
+        let system = system.read().await;
+        (
+            system
+                .cluster
+                .clone()
+                .expect("Cluster should be configured for the system"),
+            system
+                .replication
+                .clone()
+                .expect("Partition replication should be configured for the system"),
+        )
+    };
+    let listener = TcpListener::bind(&config.address)
+        .await
+        .unwrap_or_else(|error| {
+            panic!(
+                "Unable to bind cluster node to address: {}. {error}",
+                config.address
+            )
+        });
+    let addr = listener
+        .local_addr()
+        .expect("Failed to get local address for cluster listener");
+    raft.start().await;
+    info!(
+        "Cluster node with ID: {} has started on: {addr}, nodes: {}, quorum: {}.",
+        config.id,
+        config.nodes.len() + 1,
+        config.quorum()
+    );
+
+    let node = raft.clone();
+    let node_replication = replication.clone();
+    let node_system = system.clone();
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    debug!("Accepted new cluster connection: {address}");
+                    let node = node.clone();
+                    let replication = node_replication.clone();
+                    let system = node_system.clone();
+                    tokio::spawn(async move {
+                        let (peer_id, mut stream) = match node.transport().accept(stream).await {
+                            Ok(accepted) => accepted,
+                            Err(error) => {
+                                warn!("Rejected cluster connection: {address}. {error}");
+                                return;
+                            }
+                        };
+                        debug!("Authenticated cluster connection: {address} of node with ID: {peer_id}.");
+                        loop {
+                            let request: Request = match read_message(&mut stream).await {
+                                Ok(request) => request,
+                                Err(error) => {
+                                    debug!(
+                                        "Cluster connection: {address} has been closed. {error}"
+                                    );
+                                    break;
+                                }
+                            };
+                            let response = match request {
+                                // The node can fetch the messages only as itself, i.e. of the partitions it's the replica of.
+                                Request::FetchMessages {
+                                    stream_id,
+                                    topic_id,
+                                    partition_id,
+                                    replica_id,
+                                    offset,
+                                    count,
+                                } if replica_id == peer_id => replication
+                                    .handle_fetch(
+                                        &system,
+                                        stream_id,
+                                        topic_id,
+                                        partition_id,
+                                        replica_id,
+                                        offset,
+                                        count,
+                                    )
+                                    .await
+                                    .unwrap_or_else(|error| Response::Error {
+                                        code: error.as_code(),
+                                    }),
+                                request => node.handle(peer_id, request).await,
+                            };
+                            if let Err(error) = write_message(&mut stream, &response).await {
+                                debug!(
+                                    "Unable to respond to cluster connection: {address}. {error}"
+                                );
+                                break;
+                            }
+                        }
+                    });
+                }
+                Err(error) => error!("Unable to accept cluster connection: {error}"),
+            }
+        }
+    });
+
+    let node = raft.clone();
+    let heartbeat_interval = config.heartbeat_interval.get_duration();
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = sleep(heartbeat_interval) => {},
+                _ = node.replication_requested() => {},
+            }
+            node.tick().await;
+        }
+    });
+
+    let fetch_system = system.clone();
+    let fetch_interval = config.replica_fetch_interval.get_duration();
+    tokio::spawn(async move {
+        loop {
+            sleep(fetch_interval).await;
+            replication.fetch_replicas(&fetch_system).await;
+        }
+    });
+
+    tokio::spawn(async move {
+        let mut commit = raft.subscribe_commit();
+        while commit.changed().await.is_ok() {
+            let entries = match raft.take_committed_entries().await {
+                CommittedEntries::Apply(entries) => entries,
+                CommittedEntries::Reload(entries) => {
+                    if let Err(error) = system.write().await.reload_state(entries).await {
+                        error!("{COMPONENT} (error: {error}) - failed to reload system state from committed state entries.");
+                    }
+                    continue;
+                }
+            };
+            if entries.is_empty() {
+                continue;
+            }
+
+            // Each entry is authorized once the previous ones are applied, as it can refer to the streams and topics created by them.
+            for entry in entries {
+                let result = match system.resolve_state_entry(&entry).await {
+                    Ok((session, command)) => {
+                        system
+                            .write()
+                            .await
+                            .apply_state_entry(&session, &entry, command)
+                            .await
+                    }
+                    Err(error) => Err(error),
+                };
+                if let Err(error) = result {
+                    error!("{COMPONENT} (error: {error}) - failed to apply replicated state entry: {entry}");
+                }
+            }
+        }
+    });
+    addr
+}
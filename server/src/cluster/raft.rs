@@ -0,0 +1,1082 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cluster::rpc::{ClusterTransport, Peer, Request, Response};
+use crate::cluster::COMPONENT;
+use crate::configs::cluster::ClusterConfig;
+use crate::state::command::EntryCommand;
+use crate::state::entry::StateEntry;
+use crate::state::file::FileState;
+use ahash::AHashMap;
+use bytes::Bytes;
+use error_set::ErrContext;
+use futures::future::join_all;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{watch, Mutex, Notify};
+use tokio::time::{sleep, timeout, Instant};
+use tracing::{error, info, warn};
+
+const MAX_ENTRIES_PER_REQUEST: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Follower,
+    Candidate,
+    Leader,
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Role::Follower => write!(f, "follower"),
+            Role::Candidate => write!(f, "candidate"),
+            Role::Leader => write!(f, "leader"),
+        }
+    }
+}
+
+/// The term and the vote, which must survive the restart of the node, so it never votes twice in the same term.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PersistedState {
+    term: u64,
+    voted_for: Option<u32>,
+}
+
+/// The committed entries to be applied to the system of the node.
+#[derive(Debug)]
+pub enum CommittedEntries {
+    /// The entries following the already applied ones.
+    Apply(Vec<StateEntry>),
+    /// All the committed entries, as the system has the changes which haven't been committed
+    /// (and might not be anymore), so it must be reloaded from them.
+    Reload(Vec<StateEntry>),
+}
+
+/// The entry appended to the log of the leader, which might not be committed yet.
+#[derive(Debug, Clone, Copy)]
+pub struct PendingEntry {
+    count: u64,
+    term: u64,
+}
+
+/// The log is the state log of the node, and its entries are identified by their position (count),
+/// which is the same as the index of the state entry.
+#[derive(Debug)]
+struct RaftState {
+    role: Role,
+    term: u64,
+    voted_for: Option<u32>,
+    leader_id: Option<u32>,
+    log: Vec<StateEntry>,
+    commit_count: u64,
+    applied_count: u64,
+    reload_required: bool,
+    next_counts: AHashMap<u32, u64>,
+    match_counts: AHashMap<u32, u64>,
+    election_deadline: Instant,
+    alive_nodes: Vec<u32>,
+}
+
+impl RaftState {
+    fn log_count(&self) -> u64 {
+        self.log.len() as u64
+    }
+
+    fn last_log_term(&self) -> u64 {
+        self.log.last().map(|entry| entry.term).unwrap_or_default()
+    }
+
+    fn log_term(&self, count: u64) -> u64 {
+        if count == 0 {
+            return 0;
+        }
+        self.log
+            .get(count as usize - 1)
+            .map(|entry| entry.term)
+            .unwrap_or_default()
+    }
+}
+
+/// The Raft consensus of the cluster node, replicating the state log (metadata) from the leader to the followers.
+/// Only the leader accepts the metadata changes, and they're acknowledged once stored by the quorum of the nodes.
+#[derive(Debug)]
+pub struct RaftNode {
+    config: ClusterConfig,
+    path: String,
+    file_state: Arc<FileState>,
+    transport: Arc<ClusterTransport>,
+    peers: Vec<Arc<Peer>>,
+    state: Mutex<RaftState>,
+    commit: watch::Sender<u64>,
+    replication: Notify,
+    started: AtomicBool,
+    leaving: AtomicBool,
+}
+
+impl RaftNode {
+    pub fn new(
+        config: ClusterConfig,
+        path: &str,
+        file_state: Arc<FileState>,
+        transport: Arc<ClusterTransport>,
+    ) -> Self {
+        let peers = config
+            .nodes
+            .iter()
+            .map(|node| Arc::new(Peer::new(node.id, &node.address, transport.clone())))
+            .collect();
+        Self {
+            peers,
+            path: path.to_owned(),
+            file_state,
+            transport,
+            state: Mutex::new(RaftState {
+                role: Role::Follower,
+                term: 0,
+                voted_for: None,
+                leader_id: None,
+                log: Vec::new(),
+                commit_count: 0,
+                applied_count: 0,
+                reload_required: false,
+                next_counts: AHashMap::new(),
+                match_counts: AHashMap::new(),
+                election_deadline: Instant::now(),
+                // Until the leader shares its view, all the nodes are assumed to be alive.
+                alive_nodes: config.node_ids(),
+            }),
+            commit: watch::Sender::new(0),
+            replication: Notify::new(),
+            started: AtomicBool::new(false),
+            leaving: AtomicBool::new(false),
+            config,
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.config.id
+    }
+
+    pub fn config(&self) -> &ClusterConfig {
+        &self.config
+    }
+
+    pub fn transport(&self) -> &Arc<ClusterTransport> {
+        &self.transport
+    }
+
+    /// Loads the persisted term and vote, and the entries of the state log, which are already applied to the system.
+    pub async fn init(&self, entries: &[StateEntry]) -> Result<(), IggyError> {
+        let persisted_state = self.load_persisted_state().await?;
+        let mut state = self.state.lock().await;
+        state.term = persisted_state.term;
+        state.voted_for = persisted_state.voted_for;
+        state.log = entries.to_vec();
+        state.commit_count = state.log_count();
+        state.applied_count = state.log_count();
+        self.commit.send_replace(state.commit_count);
+        self.file_state.set_term_and_leader(state.term, 0);
+        info!(
+            "Initialized cluster node with ID: {}, term: {}, state entries: {}.",
+            self.config.id,
+            state.term,
+            state.log.len()
+        );
+        Ok(())
+    }
+
+    /// Joins the cluster - until then, the appended entries (e.g. the root user created on the first start) are stored locally only.
+    pub async fn start(&self) {
+        let mut state = self.state.lock().await;
+        state.election_deadline = self.next_election_deadline();
+        self.started.store(true, Ordering::SeqCst);
+    }
+
+    pub async fn role(&self) -> Role {
+        self.state.lock().await.role
+    }
+
+    pub async fn leader_id(&self) -> Option<u32> {
+        self.state.lock().await.leader_id
+    }
+
+    /// Returns the IDs of the nodes reachable by the leader (as of its last heartbeat), sorted.
+    pub async fn alive_nodes(&self) -> Vec<u32> {
+        self.state.lock().await.alive_nodes.clone()
+    }
+
+    pub async fn ensure_leader(&self) -> Result<(), IggyError> {
+        let state = self.state.lock().await;
+        if state.role == Role::Leader {
+            return Ok(());
+        }
+
+        Err(IggyError::NotClusterLeader(
+            state.leader_id.unwrap_or_default(),
+        ))
+    }
+
+    /// Appends the command to the state log and waits until it's replicated to the quorum of the nodes.
+    pub async fn append(&self, user_id: u32, command: &EntryCommand) -> Result<(), IggyError> {
+        match self.append_entry(user_id, command).await? {
+            Some(entry) => self.wait_committed(entry).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Appends the command to the state log, returning the entry to wait for until it's replicated to the quorum of the nodes,
+    /// or none if the node hasn't joined the cluster yet, so the entry is committed right away.
+    pub async fn append_entry(
+        &self,
+        user_id: u32,
+        command: &EntryCommand,
+    ) -> Result<Option<PendingEntry>, IggyError> {
+        let mut state = self.state.lock().await;
+        if !self.started.load(Ordering::SeqCst) {
+            let entry = self.file_state.append(user_id, command).await?;
+            state.log.push(entry);
+            state.commit_count = state.log_count();
+            state.applied_count = state.log_count();
+            self.commit.send_replace(state.commit_count);
+            return Ok(None);
+        }
+
+        if state.role != Role::Leader {
+            error!(
+                "{COMPONENT} - cannot append command: {command} on the {} node with ID: {}.",
+                state.role, self.config.id
+            );
+            return Err(IggyError::NotClusterLeader(
+                state.leader_id.unwrap_or_default(),
+            ));
+        }
+
+        let entry = self
+            .file_state
+            .append(user_id, command)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to append command: {command}")
+            })?;
+        state.log.push(entry);
+        // The entries of the leader are applied to the system before being appended.
+        state.applied_count = state.log_count();
+        let entry = PendingEntry {
+            count: state.log_count(),
+            term: state.term,
+        };
+        self.replication.notify_one();
+        Ok(Some(entry))
+    }
+
+    /// Waits until the entry is replicated to the quorum of the nodes. The entry is already applied to the system
+    /// of the leader, so if it's not committed in time, the leader steps down and reloads the system from the committed
+    /// entries - the entry is applied again only once it's committed.
+    pub async fn wait_committed(&self, entry: PendingEntry) -> Result<(), IggyError> {
+        let PendingEntry { count, term } = entry;
+        let mut commit = self.commit.subscribe();
+        let replication_timeout = self.config.replication_timeout.get_duration();
+        let replicated = timeout(
+            replication_timeout,
+            commit.wait_for(|commit_count| *commit_count >= count),
+        )
+        .await
+        .is_ok_and(|result| result.is_ok());
+
+        let mut state = self.state.lock().await;
+        // The entry might have been replaced by the one of the new leader, if this node has lost the leadership meanwhile.
+        if replicated && state.log_term(count) == term {
+            return Ok(());
+        }
+
+        error!(
+            "{COMPONENT} - state entry with index: {} was not replicated to the quorum of {} node(s) in: {}.",
+            count - 1,
+            self.config.quorum(),
+            self.config.replication_timeout
+        );
+        if state.commit_count < count && state.applied_count >= count {
+            let current_term = state.term;
+            self.become_follower(&mut state, current_term).await;
+            self.require_reload(&mut state);
+        }
+        Err(IggyError::ClusterQuorumNotReached)
+    }
+
+    /// Leaves the cluster before the node is shut down - the leader (or the node itself, if it's the leader)
+    /// excludes it from the alive nodes, so the other replicas take over its partitions, and then it steps down
+    /// as the leader. Returns false if the node wasn't excluded within the timeout.
+    pub async fn leave(&self, leave_timeout: Duration) -> bool {
+        if !self.started.load(Ordering::SeqCst) {
+            return true;
+        }
+
+        info!("Node with ID: {} is leaving the cluster.", self.config.id);
+        self.leaving.store(true, Ordering::SeqCst);
+        self.replication.notify_one();
+        let excluded = timeout(leave_timeout, async {
+            while self.alive_nodes().await.contains(&self.config.id) {
+                sleep(self.request_timeout()).await;
+            }
+        })
+        .await
+        .is_ok();
+
+        if self.role().await == Role::Leader {
+            // One more heartbeat shares the alive nodes without this one with the followers.
+            sleep(self.request_timeout()).await;
+            let mut state = self.state.lock().await;
+            let term = state.term;
+            self.become_follower(&mut state, term).await;
+        }
+        excluded
+    }
+
+    fn is_leaving(&self) -> bool {
+        self.leaving.load(Ordering::SeqCst)
+    }
+
+    /// Waits until the leader has the new entries to replicate.
+    pub async fn replication_requested(&self) {
+        self.replication.notified().await;
+    }
+
+    pub fn subscribe_commit(&self) -> watch::Receiver<u64> {
+        self.commit.subscribe()
+    }
+
+    /// Returns the committed entries which are not applied to the system yet, marking them as applied,
+    /// or all the committed ones, if the system must be reloaded from them.
+    pub async fn take_committed_entries(&self) -> CommittedEntries {
+        let mut state = self.state.lock().await;
+        if state.reload_required {
+            state.reload_required = false;
+            state.applied_count = state.commit_count;
+            return CommittedEntries::Reload(state.log[..state.commit_count as usize].to_vec());
+        }
+
+        if state.applied_count >= state.commit_count {
+            return CommittedEntries::Apply(Vec::new());
+        }
+
+        let entries = state.log[state.applied_count as usize..state.commit_count as usize].to_vec();
+        state.applied_count = state.commit_count;
+        CommittedEntries::Apply(entries)
+    }
+
+    /// Discards the applied entries which haven't been committed, so the system is reloaded from the committed ones.
+    fn require_reload(&self, state: &mut RaftState) {
+        warn!(
+            "{COMPONENT} - node with ID: {} has applied state entries which haven't been committed, its system will be reloaded from: {} committed entries.",
+            self.config.id, state.commit_count
+        );
+        state.reload_required = true;
+        state.applied_count = state.commit_count;
+        self.commit.send_modify(|_| {});
+    }
+
+    /// Sends the heartbeat (with the entries to replicate) as the leader, or starts the election once the leader is gone.
+    pub async fn tick(&self) {
+        let (role, election_deadline) = {
+            let state = self.state.lock().await;
+            (state.role, state.election_deadline)
+        };
+        if role == Role::Leader {
+            self.replicate().await;
+        } else if !self.is_leaving() && Instant::now() >= election_deadline {
+            self.start_election().await;
+        }
+    }
+
+    /// Handles the Raft request of the authenticated node, which can vote or lead only as itself.
+    pub async fn handle(&self, node_id: u32, request: Request) -> Response {
+        match request {
+            Request::RequestVote {
+                term,
+                candidate_id,
+                last_log_count,
+                last_log_term,
+            } if candidate_id == node_id => {
+                self.handle_vote(term, candidate_id, last_log_count, last_log_term)
+                    .await
+            }
+            Request::AppendEntries {
+                term,
+                leader_id,
+                prev_log_count,
+                prev_log_term,
+                entries,
+                leader_commit_count,
+                alive_nodes,
+            } if leader_id == node_id => {
+                self.handle_append(
+                    term,
+                    leader_id,
+                    prev_log_count,
+                    prev_log_term,
+                    entries,
+                    leader_commit_count,
+                    alive_nodes,
+                )
+                .await
+            }
+            request => {
+                warn!("{COMPONENT} - rejected request: {request:?} of node with ID: {node_id}.");
+                Response::Error {
+                    code: IggyError::InvalidCommand.as_code(),
+                }
+            }
+        }
+    }
+
+    async fn handle_vote(
+        &self,
+        term: u64,
+        candidate_id: u32,
+        last_log_count: u64,
+        last_log_term: u64,
+    ) -> Response {
+        let mut state = self.state.lock().await;
+        if term > state.term {
+            self.become_follower(&mut state, term).await;
+        }
+
+        let up_to_date =
+            (last_log_term, last_log_count) >= (state.last_log_term(), state.log_count());
+        let can_vote = state.voted_for.is_none() || state.voted_for == Some(candidate_id);
+        let mut granted = term == state.term && up_to_date && can_vote;
+        if granted {
+            let voted_for = state.voted_for.replace(candidate_id);
+            // The vote is granted only once it's persisted, so the node never votes twice in the same term after the restart.
+            if let Err(error) = self.persist_state(&state).await {
+                error!("{COMPONENT} (error: {error}) - failed to persist the vote for node with ID: {candidate_id} in term: {term}, the vote is denied.");
+                state.voted_for = voted_for;
+                granted = false;
+            } else {
+                state.election_deadline = self.next_election_deadline();
+                info!("Voted for node with ID: {candidate_id} in term: {term}.");
+            }
+        }
+
+        Response::Vote {
+            term: state.term,
+            granted,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn handle_append(
+        &self,
+        term: u64,
+        leader_id: u32,
+        prev_log_count: u64,
+        prev_log_term: u64,
+        entries: Vec<Vec<u8>>,
+        leader_commit_count: u64,
+        alive_nodes: Vec<u32>,
+    ) -> Response {
+        let mut state = self.state.lock().await;
+        if term < state.term {
+            return Response::Append {
+                term: state.term,
+                success: false,
+                match_count: 0,
+                leaving: self.is_leaving(),
+            };
+        }
+
+        if term > state.term || state.role != Role::Follower {
+            self.become_follower(&mut state, term).await;
+        }
+        if state.leader_id != Some(leader_id) {
+            info!("Node with ID: {leader_id} is the cluster leader in term: {term}.");
+            state.leader_id = Some(leader_id);
+            self.file_state.set_term_and_leader(term, leader_id);
+        }
+        state.election_deadline = self.next_election_deadline();
+        state.alive_nodes = alive_nodes;
+
+        if prev_log_count > state.log_count() {
+            return Response::Append {
+                term,
+                success: false,
+                match_count: state.log_count(),
+                leaving: self.is_leaving(),
+            };
+        }
+
+        if state.log_term(prev_log_count) != prev_log_term {
+            return Response::Append {
+                term,
+                success: false,
+                match_count: prev_log_count.saturating_sub(1),
+                leaving: self.is_leaving(),
+            };
+        }
+
+        let entries_count = entries.len() as u64;
+        if let Err(error) = self
+            .append_entries(&mut state, prev_log_count, entries)
+            .await
+        {
+            error!("{COMPONENT} (error: {error}) - failed to append replicated state entries from the leader with ID: {leader_id}.");
+            let match_count = state.log_count().min(prev_log_count);
+            return Response::Append {
+                term,
+                success: false,
+                match_count,
+                leaving: self.is_leaving(),
+            };
+        }
+
+        let match_count = prev_log_count + entries_count;
+        let commit_count = leader_commit_count.min(match_count);
+        if commit_count > state.commit_count {
+            state.commit_count = commit_count;
+            self.commit.send_replace(commit_count);
+        }
+
+        Response::Append {
+            term,
+            success: true,
+            match_count,
+            leaving: self.is_leaving(),
+        }
+    }
+
+    async fn append_entries(
+        &self,
+        state: &mut RaftState,
+        prev_log_count: u64,
+        entries: Vec<Vec<u8>>,
+    ) -> Result<(), IggyError> {
+        for (offset, bytes) in entries.into_iter().enumerate() {
+            let entry = StateEntry::from_bytes(Bytes::from(bytes))?;
+            let count = prev_log_count + offset as u64;
+            if count < state.log_count() {
+                if state.log[count as usize].term == entry.term {
+                    continue;
+                }
+
+                self.file_state.truncate(count).await?;
+                state.log.truncate(count as usize);
+                state.commit_count = state.commit_count.min(count);
+                if count < state.applied_count {
+                    // The replaced entries have been applied (by the former leader), so the system is reloaded without them.
+                    self.require_reload(state);
+                }
+            }
+
+            self.file_state.append_entry(&entry).await?;
+            state.log.push(entry);
+        }
+        Ok(())
+    }
+
+    async fn start_election(&self) {
+        let (term, request) = {
+            let mut state = self.state.lock().await;
+            state.role = Role::Candidate;
+            state.term += 1;
+            state.voted_for = Some(self.config.id);
+            state.leader_id = None;
+            state.election_deadline = self.next_election_deadline();
+            if let Err(error) = self.persist_state(&state).await {
+                error!(
+                    "{COMPONENT} (error: {error}) - failed to persist the term: {}, node with ID: {} cannot start the election.",
+                    state.term, self.config.id
+                );
+                state.role = Role::Follower;
+                return;
+            }
+            info!(
+                "Node with ID: {} is starting the election in term: {}.",
+                self.config.id, state.term
+            );
+            let request = Request::RequestVote {
+                term: state.term,
+                candidate_id: self.config.id,
+                last_log_count: state.log_count(),
+                last_log_term: state.last_log_term(),
+            };
+            (state.term, request)
+        };
+
+        let request_timeout = self.request_timeout();
+        let responses = join_all(
+            self.peers
+                .iter()
+                .map(|peer| peer.send(&request, request_timeout)),
+        )
+        .await;
+
+        let mut state = self.state.lock().await;
+        if state.term != term || state.role != Role::Candidate {
+            return;
+        }
+
+        let mut votes = 1;
+        for response in responses.into_iter().flatten() {
+            let Response::Vote {
+                term: peer_term,
+                granted,
+            } = response
+            else {
+                continue;
+            };
+            if peer_term > term {
+                self.become_follower(&mut state, peer_term).await;
+                return;
+            }
+            if granted {
+                votes += 1;
+            }
+        }
+
+        if votes >= self.config.quorum() {
+            self.become_leader(&mut state);
+        } else {
+            info!(
+                "Node with ID: {} received {votes} vote(s) in term: {term}, quorum: {}.",
+                self.config.id,
+                self.config.quorum()
+            );
+        }
+    }
+
+    async fn replicate(&self) {
+        let (term, requests) = {
+            let state = self.state.lock().await;
+            if state.role != Role::Leader {
+                return;
+            }
+
+            let requests = self
+                .peers
+                .iter()
+                .map(|peer| {
+                    let next_count = state
+                        .next_counts
+                        .get(&peer.id)
+                        .copied()
+                        .unwrap_or_default()
+                        .min(state.log_count());
+                    let entries = state.log[next_count as usize..]
+                        .iter()
+                        .take(MAX_ENTRIES_PER_REQUEST)
+                        .map(|entry| entry.to_bytes().to_vec())
+                        .collect();
+                    let request = Request::AppendEntries {
+                        term: state.term,
+                        leader_id: self.config.id,
+                        prev_log_count: next_count,
+                        prev_log_term: state.log_term(next_count),
+                        entries,
+                        leader_commit_count: state.commit_count,
+                        alive_nodes: state.alive_nodes.clone(),
+                    };
+                    (peer.clone(), request)
+                })
+                .collect::<Vec<_>>();
+            (state.term, requests)
+        };
+
+        let request_timeout = self.request_timeout();
+        let responses = join_all(requests.iter().map(|(peer, request)| async move {
+            (peer.id, peer.send(request, request_timeout).await)
+        }))
+        .await;
+
+        let mut state = self.state.lock().await;
+        if state.term != term || state.role != Role::Leader {
+            return;
+        }
+
+        // The nodes leaving the cluster are not alive anymore, so their partitions are taken over by the other replicas.
+        let mut alive_nodes = Vec::new();
+        if !self.is_leaving() {
+            alive_nodes.push(self.config.id);
+        }
+        for (peer_id, response) in responses {
+            let Ok(Response::Append {
+                term: peer_term,
+                success,
+                match_count,
+                leaving,
+            }) = response
+            else {
+                continue;
+            };
+            if peer_term > term {
+                self.become_follower(&mut state, peer_term).await;
+                return;
+            }
+
+            if !leaving {
+                alive_nodes.push(peer_id);
+            }
+            if success {
+                state.match_counts.insert(peer_id, match_count);
+                state.next_counts.insert(peer_id, match_count);
+            } else {
+                let next_count = state.next_counts.entry(peer_id).or_default();
+                *next_count = match_count.min(next_count.saturating_sub(1));
+            }
+        }
+        alive_nodes.sort_unstable();
+        if alive_nodes != state.alive_nodes {
+            info!("Alive cluster nodes: {alive_nodes:?}.");
+            state.alive_nodes = alive_nodes;
+        }
+        self.advance_commit(&mut state);
+    }
+
+    /// Commits the entries stored by the quorum of the nodes. As in Raft, only the entries of the current term
+    /// are committed by counting the replicas, and the previous ones are committed along with them.
+    fn advance_commit(&self, state: &mut RaftState) {
+        let mut match_counts = self
+            .peers
+            .iter()
+            .map(|peer| {
+                state
+                    .match_counts
+                    .get(&peer.id)
+                    .copied()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        match_counts.push(state.log_count());
+        match_counts.sort_unstable_by(|a, b| b.cmp(a));
+        let commit_count = match_counts[self.config.quorum() - 1];
+        if commit_count > state.commit_count && state.log_term(commit_count) == state.term {
+            state.commit_count = commit_count;
+            self.commit.send_replace(commit_count);
+        }
+    }
+
+    fn become_leader(&self, state: &mut RaftState) {
+        state.role = Role::Leader;
+        state.leader_id = Some(self.config.id);
+        let log_count = state.log_count();
+        state.next_counts = self.peers.iter().map(|peer| (peer.id, log_count)).collect();
+        state.match_counts = self.peers.iter().map(|peer| (peer.id, 0)).collect();
+        self.file_state
+            .set_term_and_leader(state.term, self.config.id);
+        info!(
+            "Node with ID: {} has become the cluster leader in term: {}.",
+            self.config.id, state.term
+        );
+        self.advance_commit(state);
+        self.replication.notify_one();
+    }
+
+    async fn become_follower(&self, state: &mut RaftState, term: u64) {
+        if term > state.term {
+            state.term = term;
+            state.voted_for = None;
+            state.leader_id = None;
+            if let Err(error) = self.persist_state(state).await {
+                error!("{COMPONENT} (error: {error}) - failed to persist the term: {term}.");
+            }
+        }
+        if state.role != Role::Follower {
+            info!(
+                "Node with ID: {} is no longer the cluster {} in term: {term}.",
+                self.config.id, state.role
+            );
+            state.role = Role::Follower;
+        }
+        state.election_deadline = self.next_election_deadline();
+    }
+
+    fn next_election_deadline(&self) -> Instant {
+        let election_timeout = self.config.election_timeout.as_micros();
+        let election_timeout = rand::rng().random_range(election_timeout..=2 * election_timeout);
+        Instant::now() + Duration::from_micros(election_timeout)
+    }
+
+    fn request_timeout(&self) -> Duration {
+        self.config.heartbeat_interval.get_duration()
+    }
+
+    async fn load_persisted_state(&self) -> Result<PersistedState, IggyError> {
+        if !Path::new(&self.path).exists() {
+            return Ok(PersistedState::default());
+        }
+
+        let bytes = tokio::fs::read(&self.path)
+            .await
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let (persisted_state, _) =
+            bincode::serde::decode_from_slice(&bytes, bincode::config::standard())
+                .map_err(|_| IggyError::CannotDeserializeResource)?;
+        Ok(persisted_state)
+    }
+
+    async fn persist_state(&self, state: &RaftState) -> Result<(), IggyError> {
+        let persisted_state = PersistedState {
+            term: state.term,
+            voted_for: state.voted_for,
+        };
+        let bytes = bincode::serde::encode_to_vec(&persisted_state, bincode::config::standard())
+            .map_err(|error| {
+                error!("{COMPONENT} - failed to serialize cluster state. {error}");
+                IggyError::CannotSerializeResource
+            })?;
+        tokio::fs::write(&self.path, bytes).await.map_err(|error| {
+            error!(
+                "{COMPONENT} - failed to persist cluster state, path: {}. {error}",
+                self.path
+            );
+            IggyError::CannotWriteToFile
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::models::CreateStreamWithId;
+    use crate::streaming::persistence::persister::{FilePersister, PersisterKind};
+    use crate::versioning::SemanticVersion;
+    use iggy::streams::create_stream::CreateStream;
+    use tempfile::TempDir;
+
+    fn node() -> (TempDir, RaftNode) {
+        let tempdir = TempDir::new().unwrap();
+        let path = tempdir.path().to_str().unwrap().to_owned();
+        let node = node_with_state_path(&path, &format!("{path}/cluster"));
+        (tempdir, node)
+    }
+
+    fn node_with_state_path(path: &str, state_path: &str) -> RaftNode {
+        let config = ClusterConfig::default();
+        let transport = Arc::new(ClusterTransport::new(&config).unwrap());
+        RaftNode::new(config, state_path, Arc::new(file_state(path)), transport)
+    }
+
+    fn file_state(path: &str) -> FileState {
+        FileState::new(
+            &format!("{path}/log"),
+            &SemanticVersion::current().unwrap(),
+            Arc::new(PersisterKind::File(FilePersister)),
+            None,
+        )
+    }
+
+    /// Returns the first state entry of the leader in the term.
+    async fn entry(term: u64, name: &str) -> StateEntry {
+        let tempdir = TempDir::new().unwrap();
+        let file_state = file_state(tempdir.path().to_str().unwrap());
+        file_state.set_term_and_leader(term, 2);
+        let command = EntryCommand::CreateStream(CreateStreamWithId {
+            stream_id: 1,
+            command: CreateStream {
+                stream_id: Some(1),
+                name: name.to_owned(),
+            },
+        });
+        file_state.append(1, &command).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_grant_single_vote_per_term() {
+        let (_tempdir, node) = node();
+        let response = node.handle_vote(1, 2, 0, 0).await;
+        assert!(matches!(
+            response,
+            Response::Vote {
+                term: 1,
+                granted: true
+            }
+        ));
+
+        let response = node.handle_vote(1, 3, 0, 0).await;
+        assert!(matches!(
+            response,
+            Response::Vote {
+                term: 1,
+                granted: false
+            }
+        ));
+
+        let response = node.handle_vote(2, 3, 0, 0).await;
+        assert!(matches!(
+            response,
+            Response::Vote {
+                term: 2,
+                granted: true
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_deny_vote_which_cannot_be_persisted() {
+        let tempdir = TempDir::new().unwrap();
+        let path = tempdir.path().to_str().unwrap();
+        let node = node_with_state_path(path, &format!("{path}/missing/cluster"));
+
+        let response = node.handle_vote(1, 2, 0, 0).await;
+        assert!(matches!(
+            response,
+            Response::Vote {
+                term: 1,
+                granted: false
+            }
+        ));
+        assert_eq!(node.state.lock().await.voted_for, None);
+    }
+
+    #[tokio::test]
+    async fn should_reload_state_when_applied_entries_conflict_with_leader() {
+        let (_tempdir, node) = node();
+        let applied_entry = entry(1, "applied").await;
+        node.file_state.append_entry(&applied_entry).await.unwrap();
+        node.init(&[applied_entry]).await.unwrap();
+
+        let leader_entry = entry(2, "committed").await;
+        let response = node
+            .handle_append(
+                2,
+                2,
+                0,
+                0,
+                vec![leader_entry.to_bytes().to_vec()],
+                1,
+                vec![1, 2],
+            )
+            .await;
+        assert!(matches!(
+            response,
+            Response::Append {
+                term: 2,
+                success: true,
+                match_count: 1,
+                ..
+            }
+        ));
+
+        let CommittedEntries::Reload(entries) = node.take_committed_entries().await else {
+            panic!("System should be reloaded from the committed entries");
+        };
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].term, 2);
+        assert!(matches!(
+            node.take_committed_entries().await,
+            CommittedEntries::Apply(entries) if entries.is_empty()
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_reject_requests_from_previous_term() {
+        let (_tempdir, node) = node();
+        node.handle_vote(2, 2, 0, 0).await;
+
+        let response = node.handle_vote(1, 3, 0, 0).await;
+        assert!(matches!(
+            response,
+            Response::Vote {
+                term: 2,
+                granted: false
+            }
+        ));
+
+        let response = node
+            .handle_append(1, 3, 0, 0, Vec::new(), 0, Vec::new())
+            .await;
+        assert!(matches!(
+            response,
+            Response::Append {
+                term: 2,
+                success: false,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_follow_leader_of_current_term() {
+        let (_tempdir, node) = node();
+        let response = node
+            .handle_append(1, 2, 0, 0, Vec::new(), 0, vec![1, 2])
+            .await;
+        assert!(matches!(
+            response,
+            Response::Append {
+                term: 1,
+                success: true,
+                match_count: 0,
+                leaving: false
+            }
+        ));
+        assert_eq!(node.role().await, Role::Follower);
+        assert_eq!(node.leader_id().await, Some(2));
+        assert_eq!(node.alive_nodes().await, vec![1, 2]);
+        assert!(matches!(
+            node.ensure_leader().await,
+            Err(IggyError::NotClusterLeader(2))
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_reject_append_with_mismatched_term_of_empty_log() {
+        let (_tempdir, node) = node();
+        let response = node
+            .handle_append(1, 2, 0, 1, Vec::new(), 0, vec![1, 2])
+            .await;
+        assert!(matches!(
+            response,
+            Response::Append {
+                term: 1,
+                success: false,
+                match_count: 0,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn leaving_node_should_report_it_to_the_leader() {
+        let (_tempdir, node) = node();
+        node.start().await;
+        node.leaving.store(true, Ordering::SeqCst);
+        let response = node
+            .handle_append(1, 2, 0, 0, Vec::new(), 0, vec![1, 2])
+            .await;
+        assert!(matches!(
+            response,
+            Response::Append {
+                success: true,
+                leaving: true,
+                ..
+            }
+        ));
+
+        let response = node.handle_append(1, 2, 0, 0, Vec::new(), 0, vec![2]).await;
+        assert!(matches!(response, Response::Append { leaving: true, .. }));
+        assert!(node.leave(Duration::from_millis(10)).await);
+    }
+}
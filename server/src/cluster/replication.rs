@@ -0,0 +1,577 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cluster::raft::RaftNode;
+use crate::cluster::rpc::{Peer, Request, Response};
+use crate::cluster::COMPONENT;
+use crate::configs::cluster::ClusterConfig;
+use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::systems::system::SharedSystem;
+use ahash::AHashMap;
+use bytes::{Bytes, BytesMut};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, Notify};
+use tokio::time::{timeout, Instant};
+use tracing::{debug, error, info, warn};
+
+type PartitionKey = (u32, u32, u32);
+
+/// The progress of the follower replica, as seen by the partition leader.
+#[derive(Debug)]
+struct ReplicaProgress {
+    next_offset: u64,
+    caught_up_at: Option<Instant>,
+}
+
+/// Replicates the messages of the partitions across the cluster nodes. Each partition is assigned to the
+/// `replication_factor` nodes, the first alive one being its leader accepting the appends, while the others
+/// keep fetching the messages from it. The leader tracks which replicas are in sync, to acknowledge the appends.
+#[derive(Debug)]
+pub struct PartitionReplication {
+    config: ClusterConfig,
+    raft: Arc<RaftNode>,
+    peers: AHashMap<u32, Arc<Peer>>,
+    progress: Mutex<AHashMap<PartitionKey, AHashMap<u32, ReplicaProgress>>>,
+    fetched: Notify,
+}
+
+impl PartitionReplication {
+    pub fn new(config: ClusterConfig, raft: Arc<RaftNode>) -> Self {
+        let peers = config
+            .nodes
+            .iter()
+            .map(|node| {
+                let peer = Peer::new(node.id, &node.address, raft.transport().clone());
+                (node.id, Arc::new(peer))
+            })
+            .collect();
+        Self {
+            config,
+            raft,
+            peers,
+            progress: Mutex::new(AHashMap::new()),
+            fetched: Notify::new(),
+        }
+    }
+
+    /// Returns the IDs of the nodes storing the partition, the preferred leader being the first one.
+    pub fn replicas(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        replication_factor: u8,
+    ) -> Vec<u32> {
+        assign_replicas(
+            &self.config.node_ids(),
+            stream_id,
+            topic_id,
+            partition_id,
+            replication_factor,
+        )
+    }
+
+    /// Returns the ID of the partition leader - the first of its replicas which is alive, according to the cluster leader.
+    pub async fn leader(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        replication_factor: u8,
+    ) -> Option<u32> {
+        let alive_nodes = self.raft.alive_nodes().await;
+        self.replicas(stream_id, topic_id, partition_id, replication_factor)
+            .into_iter()
+            .find(|node_id| alive_nodes.contains(node_id))
+    }
+
+    /// Fails with the ID of the partition leader (or 0 if there's none) if this node isn't the one.
+    pub async fn ensure_partition_leader(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        replication_factor: u8,
+    ) -> Result<(), IggyError> {
+        let leader_id = self
+            .leader(stream_id, topic_id, partition_id, replication_factor)
+            .await;
+        if leader_id == Some(self.config.id) {
+            return Ok(());
+        }
+
+        Err(IggyError::NotPartitionLeader(
+            partition_id,
+            topic_id,
+            stream_id,
+            leader_id.unwrap_or_default(),
+        ))
+    }
+
+    /// Waits until enough replicas (including the leader) have fetched the messages up to the given offset.
+    /// Fails right away if there are fewer in-sync replicas than required, or once the replication timeout expires.
+    pub async fn wait_for_acks(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        replication_factor: u8,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        let required = self
+            .config
+            .min_insync_replicas
+            .min(replication_factor as u32);
+        if required <= 1 {
+            return Ok(());
+        }
+
+        let key = (stream_id, topic_id, partition_id);
+        let in_sync = self.count_in_sync_replicas(&key).await;
+        if in_sync < required {
+            return Err(IggyError::NotEnoughInSyncReplicas(in_sync, required));
+        }
+
+        let replication_timeout = self.config.replication_timeout.get_duration();
+        let acknowledged = timeout(replication_timeout, async {
+            loop {
+                let fetched = self.fetched.notified();
+                if self.count_acks(&key, offset).await >= required {
+                    return;
+                }
+                fetched.await;
+            }
+        })
+        .await;
+        if acknowledged.is_err() {
+            let acks = self.count_acks(&key, offset).await;
+            return Err(IggyError::NotEnoughInSyncReplicas(acks, required));
+        }
+        Ok(())
+    }
+
+    /// Returns the messages of the partition starting at the requested offset, and records the progress of the replica.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn handle_fetch(
+        &self,
+        system: &SharedSystem,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        replica_id: u32,
+        offset: u64,
+        count: u32,
+    ) -> Result<Response, IggyError> {
+        let system = system.read().await;
+        let topic = system
+            .get_stream(&Identifier::numeric(stream_id)?)?
+            .get_topic(&Identifier::numeric(topic_id)?)?;
+        self.ensure_partition_leader(stream_id, topic_id, partition_id, topic.replication_factor)
+            .await?;
+        if !self
+            .replicas(stream_id, topic_id, partition_id, topic.replication_factor)
+            .contains(&replica_id)
+        {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition = topic.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        let leader_offset = partition
+            .should_increment_offset
+            .then_some(partition.current_offset);
+        let mut messages = BytesMut::new();
+        if matches!(leader_offset, Some(leader_offset) if offset <= leader_offset) {
+            for message in partition
+                .get_messages_by_offset(offset, count)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to get messages to replicate, partition: {partition_id}, topic: {topic_id}, stream: {stream_id}, offset: {offset}")
+                })?
+            {
+                message.extend(&mut messages);
+            }
+        }
+        drop(partition);
+        drop(system);
+
+        self.record_fetch(
+            (stream_id, topic_id, partition_id),
+            replica_id,
+            offset,
+            leader_offset,
+        )
+        .await;
+        Ok(Response::Messages {
+            leader_offset,
+            messages: messages.to_vec(),
+        })
+    }
+
+    /// Fetches the new messages of all the partitions this node is the follower replica of.
+    pub async fn fetch_replicas(&self, system: &SharedSystem) {
+        let mut partitions = Vec::new();
+        let clock = {
+            let system = system.read().await;
+            for stream in system.get_streams() {
+                for topic in stream.get_topics() {
+                    if topic.replication_factor <= 1 {
+                        continue;
+                    }
+
+                    for partition in topic.get_partitions() {
+                        let partition_id = partition.read().await.partition_id;
+                        if !self
+                            .replicas(
+                                topic.stream_id,
+                                topic.topic_id,
+                                partition_id,
+                                topic.replication_factor,
+                            )
+                            .contains(&self.config.id)
+                        {
+                            continue;
+                        }
+
+                        let Some(leader_id) = self
+                            .leader(
+                                topic.stream_id,
+                                topic.topic_id,
+                                partition_id,
+                                topic.replication_factor,
+                            )
+                            .await
+                        else {
+                            continue;
+                        };
+                        if leader_id != self.config.id {
+                            partitions.push((leader_id, partition));
+                        }
+                    }
+                }
+            }
+            system.clock().clone()
+        };
+
+        for (leader_id, partition) in partitions {
+            let Some(peer) = self.peers.get(&leader_id) else {
+                continue;
+            };
+
+            let (stream_id, topic_id, partition_id, offset) = {
+                let partition = partition.read().await;
+                let offset = if partition.should_increment_offset {
+                    partition.current_offset + 1
+                } else {
+                    0
+                };
+                (
+                    partition.stream_id,
+                    partition.topic_id,
+                    partition.partition_id,
+                    offset,
+                )
+            };
+            let request = Request::FetchMessages {
+                stream_id,
+                topic_id,
+                partition_id,
+                replica_id: self.config.id,
+                offset,
+                count: self.config.replica_fetch_messages,
+            };
+            let messages = match peer
+                .send(&request, self.config.replication_timeout.get_duration())
+                .await
+            {
+                Ok(Response::Messages { messages, .. }) => messages,
+                Ok(Response::Error { code }) => {
+                    debug!("Node with ID: {leader_id} has rejected the fetch of partition: {partition_id} for topic: {topic_id}, stream: {stream_id}, error code: {code}.");
+                    continue;
+                }
+                Ok(response) => {
+                    error!("Received unexpected response: {response:?} to the fetch of partition: {partition_id} for topic: {topic_id}, stream: {stream_id}.");
+                    continue;
+                }
+                Err(error) => {
+                    debug!("Unable to fetch partition: {partition_id} for topic: {topic_id}, stream: {stream_id} from node with ID: {leader_id}. {error}");
+                    continue;
+                }
+            };
+            if messages.is_empty() {
+                continue;
+            }
+
+            let result = match decode_messages(Bytes::from(messages)) {
+                Ok(messages) => {
+                    partition
+                        .write()
+                        .await
+                        .append_replicated_messages(messages, clock.now())
+                        .await
+                }
+                Err(error) => Err(error),
+            };
+            match result {
+                Ok(count) => debug!("Replicated {count} messages of partition: {partition_id} for topic: {topic_id}, stream: {stream_id} from node with ID: {leader_id}."),
+                Err(error) => error!("{COMPONENT} (error: {error}) - failed to replicate messages of partition: {partition_id} for topic: {topic_id}, stream: {stream_id} from node with ID: {leader_id}"),
+            }
+        }
+    }
+
+    /// Hands off the leadership of the partitions before the node leaves the cluster - waits until their alive replicas
+    /// have fetched all the messages, and then leaves the cluster, so the next replicas take over as the leaders.
+    /// Returns false if either of the steps didn't complete within the timeout.
+    pub async fn hand_off(&self, system: &SharedSystem, handoff_timeout: Duration) -> bool {
+        let deadline = Instant::now() + handoff_timeout;
+        let alive_nodes = self.raft.alive_nodes().await;
+        let mut partitions = Vec::new();
+        {
+            let system = system.read().await;
+            for stream in system.get_streams() {
+                for topic in stream.get_topics() {
+                    if topic.replication_factor <= 1 {
+                        continue;
+                    }
+
+                    for partition in topic.get_partitions() {
+                        let partition = partition.read().await;
+                        if !partition.should_increment_offset {
+                            continue;
+                        }
+
+                        let replicas = self.replicas(
+                            topic.stream_id,
+                            topic.topic_id,
+                            partition.partition_id,
+                            topic.replication_factor,
+                        );
+                        let Some(leader_id) = replicas
+                            .iter()
+                            .find(|node_id| alive_nodes.contains(node_id))
+                        else {
+                            continue;
+                        };
+                        if *leader_id != self.config.id {
+                            continue;
+                        }
+
+                        let alive_replicas = replicas
+                            .iter()
+                            .filter(|node_id| alive_nodes.contains(node_id))
+                            .count() as u32;
+                        partitions.push((
+                            (topic.stream_id, topic.topic_id, partition.partition_id),
+                            partition.current_offset,
+                            alive_replicas,
+                        ));
+                    }
+                }
+            }
+        }
+
+        info!(
+            "Handing off the leadership of {} partition(s) of node with ID: {}...",
+            partitions.len(),
+            self.config.id
+        );
+        let caught_up = timeout(handoff_timeout, async {
+            for (key, offset, alive_replicas) in &partitions {
+                loop {
+                    let fetched = self.fetched.notified();
+                    if self.count_acks(key, *offset).await >= *alive_replicas {
+                        break;
+                    }
+                    fetched.await;
+                }
+            }
+        })
+        .await
+        .is_ok();
+        if !caught_up {
+            warn!(
+                "Replicas of the partitions led by node with ID: {} didn't catch up in: {} ms.",
+                self.config.id,
+                handoff_timeout.as_millis()
+            );
+        }
+
+        let left = self
+            .raft
+            .leave(deadline.saturating_duration_since(Instant::now()))
+            .await;
+        if !left {
+            warn!(
+                "Node with ID: {} wasn't excluded from the alive cluster nodes before the timeout.",
+                self.config.id
+            );
+        }
+        caught_up && left
+    }
+
+    async fn record_fetch(
+        &self,
+        key: PartitionKey,
+        replica_id: u32,
+        next_offset: u64,
+        leader_offset: Option<u64>,
+    ) {
+        let caught_up =
+            !matches!(leader_offset, Some(leader_offset) if next_offset <= leader_offset);
+        let mut progress = self.progress.lock().await;
+        let replica = progress
+            .entry(key)
+            .or_default()
+            .entry(replica_id)
+            .or_insert_with(|| {
+                info!(
+                    "Replica with ID: {replica_id} has started fetching partition: {} for topic: {}, stream: {}.",
+                    key.2, key.1, key.0
+                );
+                ReplicaProgress {
+                    next_offset,
+                    caught_up_at: None,
+                }
+            });
+        replica.next_offset = next_offset;
+        if caught_up {
+            replica.caught_up_at = Some(Instant::now());
+        }
+        drop(progress);
+        self.fetched.notify_waiters();
+    }
+
+    async fn count_in_sync_replicas(&self, key: &PartitionKey) -> u32 {
+        let max_lag = self.config.replica_max_lag.get_duration();
+        let progress = self.progress.lock().await;
+        let followers = progress.get(key).map_or(0, |replicas| {
+            replicas
+                .values()
+                .filter(|replica| {
+                    replica
+                        .caught_up_at
+                        .is_some_and(|caught_up_at| caught_up_at.elapsed() <= max_lag)
+                })
+                .count() as u32
+        });
+        followers + 1
+    }
+
+    async fn count_acks(&self, key: &PartitionKey, offset: u64) -> u32 {
+        let progress = self.progress.lock().await;
+        let followers = progress.get(key).map_or(0, |replicas| {
+            replicas
+                .values()
+                .filter(|replica| replica.next_offset > offset)
+                .count() as u32
+        });
+        followers + 1
+    }
+}
+
+/// Assigns the partition to the `replication_factor` nodes in the round-robin fashion, so the leaders are spread across the cluster.
+fn assign_replicas(
+    node_ids: &[u32],
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+    replication_factor: u8,
+) -> Vec<u32> {
+    if node_ids.is_empty() {
+        return Vec::new();
+    }
+
+    let count = (replication_factor.max(1) as usize).min(node_ids.len());
+    let start = (stream_id as usize + topic_id as usize + partition_id as usize) % node_ids.len();
+    (0..count)
+        .map(|index| node_ids[(start + index) % node_ids.len()])
+        .collect()
+}
+
+/// Decodes the messages serialized by the partition leader, each one prefixed with its length.
+pub(crate) fn decode_messages(mut bytes: Bytes) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
+    let mut messages = Vec::new();
+    while !bytes.is_empty() {
+        if bytes.len() < 4 {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        let length = u32::from_le_bytes(bytes[..4].try_into().unwrap()) as usize;
+        if bytes.len() < 4 + length {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        let message = RetainedMessage::try_from_bytes(bytes.slice(4..4 + length))?;
+        messages.push(Arc::new(message));
+        bytes = bytes.slice(4 + length..);
+    }
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::messages::MessageState;
+
+    #[test]
+    fn replicas_should_be_capped_at_the_number_of_nodes() {
+        let replicas = assign_replicas(&[1, 2, 3], 1, 1, 1, 5);
+        assert_eq!(replicas.len(), 3);
+        assert_eq!(assign_replicas(&[1, 2, 3], 1, 1, 1, 0).len(), 1);
+    }
+
+    #[test]
+    fn partition_leaders_should_be_spread_across_the_nodes() {
+        let node_ids = [1, 2, 3];
+        let leaders = (1..=3)
+            .map(|partition_id| assign_replicas(&node_ids, 1, 1, partition_id, 2)[0])
+            .collect::<Vec<_>>();
+        assert_eq!(leaders, vec![1, 2, 3]);
+        assert_eq!(assign_replicas(&node_ids, 1, 1, 3, 2), vec![3, 1]);
+    }
+
+    #[test]
+    fn messages_should_be_decoded_in_order() {
+        let mut bytes = BytesMut::new();
+        for offset in 0..3 {
+            let message = RetainedMessage {
+                id: offset as u128,
+                offset,
+                timestamp: 1000 + offset,
+                checksum: 0,
+                message_state: MessageState::Available,
+                headers: None,
+                payload: Bytes::from(format!("message {offset}")),
+            };
+            message.extend(&mut bytes);
+        }
+
+        let messages = decode_messages(bytes.freeze()).unwrap();
+        assert_eq!(messages.len(), 3);
+        for (offset, message) in messages.iter().enumerate() {
+            assert_eq!(message.offset, offset as u64);
+            assert_eq!(message.timestamp, 1000 + offset as u64);
+            assert_eq!(message.payload, Bytes::from(format!("message {offset}")));
+        }
+    }
+}
@@ -0,0 +1,535 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cluster::COMPONENT;
+use crate::configs::cluster::{ClusterConfig, ClusterTlsConfig};
+use crate::streaming::clients::certificate_auth::crypto_provider;
+use anyhow::{anyhow, Context};
+use iggy::error::IggyError;
+use ring::hmac;
+use rustls::pki_types::{CertificateDer, ServerName};
+use rustls::RootCertStore;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Formatter};
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+use tokio::time::timeout;
+use tokio_rustls::{TlsAcceptor, TlsConnector};
+use tracing::{debug, error, warn};
+
+const MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024;
+const NONCE_LENGTH: usize = 32;
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+const NODE_KEY_CONTEXT: &[u8] = b"iggy-cluster-node";
+
+/// The requests exchanged between the cluster nodes - the Raft ones replicating the state log,
+/// with the entries being the (not encrypted) bytes of the state entries, and the fetches of the partition replicas.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Request {
+    RequestVote {
+        term: u64,
+        candidate_id: u32,
+        last_log_count: u64,
+        last_log_term: u64,
+    },
+    AppendEntries {
+        term: u64,
+        leader_id: u32,
+        prev_log_count: u64,
+        prev_log_term: u64,
+        entries: Vec<Vec<u8>>,
+        leader_commit_count: u64,
+        alive_nodes: Vec<u32>,
+    },
+    FetchMessages {
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        replica_id: u32,
+        offset: u64,
+        count: u32,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Response {
+    Vote {
+        term: u64,
+        granted: bool,
+    },
+    Append {
+        term: u64,
+        success: bool,
+        match_count: u64,
+        leaving: bool,
+    },
+    Messages {
+        leader_offset: Option<u64>,
+        messages: Vec<u8>,
+    },
+    Error {
+        code: u32,
+    },
+}
+
+/// The messages exchanged by the nodes authenticating each other with the shared secret, before any request is sent.
+/// Each node proves it knows the secret by signing both nonces with the key derived from the secret for its ID,
+/// so the secret is never sent over the network, and the proof of one node can't be replayed as the one of the other node.
+#[derive(Debug, Serialize, Deserialize)]
+enum Handshake {
+    Hello {
+        node_id: u32,
+        nonce: [u8; NONCE_LENGTH],
+    },
+    Challenge {
+        node_id: u32,
+        nonce: [u8; NONCE_LENGTH],
+        proof: Vec<u8>,
+    },
+    Proof {
+        proof: Vec<u8>,
+    },
+}
+
+/// The stream of the connection between the cluster nodes, encrypted if TLS is enabled.
+pub trait PeerStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> PeerStream for T {}
+
+/// Connects and accepts the connections between the cluster nodes, which are authenticated with the shared secret,
+/// and encrypted with TLS if it's enabled. Only the configured nodes are allowed to connect.
+///
+/// The secret is shared by all the nodes, so it only keeps out the ones which don't know it - any node knowing it
+/// can derive the key of the other one. Only the handshake is authenticated, the messages exchanged afterwards
+/// are neither signed nor encrypted unless TLS is enabled, so without TLS the nodes must communicate over the trusted network.
+pub struct ClusterTransport {
+    node_id: u32,
+    node_ids: Vec<u32>,
+    key: hmac::Key,
+    tls: Option<ClusterTls>,
+}
+
+struct ClusterTls {
+    acceptor: TlsAcceptor,
+    connector: TlsConnector,
+    domain: ServerName<'static>,
+}
+
+impl Debug for ClusterTransport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClusterTransport")
+            .field("node_id", &self.node_id)
+            .field("node_ids", &self.node_ids)
+            .field("tls", &self.tls.is_some())
+            .finish()
+    }
+}
+
+impl ClusterTransport {
+    pub fn new(config: &ClusterConfig) -> anyhow::Result<Self> {
+        let tls = match config.tls.enabled {
+            true => Some(ClusterTls::new(&config.tls)?),
+            false => {
+                warn!("{COMPONENT} - TLS is disabled, the requests exchanged by the cluster nodes after the handshake are not authenticated nor encrypted.");
+                None
+            }
+        };
+        Ok(Self {
+            node_id: config.id,
+            node_ids: config.nodes.iter().map(|node| node.id).collect(),
+            key: hmac::Key::new(hmac::HMAC_SHA256, config.secret.as_bytes()),
+            tls,
+        })
+    }
+
+    /// Connects to the node with the ID, verifying that it knows the shared secret, and proving this node knows it too.
+    pub async fn connect(
+        &self,
+        node_id: u32,
+        address: &str,
+    ) -> Result<Box<dyn PeerStream>, IggyError> {
+        let stream = TcpStream::connect(address).await.map_err(|error| {
+            debug!("{COMPONENT} - cannot connect to node with ID: {node_id}, address: {address}. {error}");
+            IggyError::CannotEstablishConnection
+        })?;
+        stream
+            .set_nodelay(true)
+            .map_err(|_| IggyError::CannotEstablishConnection)?;
+        let mut stream: Box<dyn PeerStream> = match &self.tls {
+            Some(tls) => Box::new(
+                tls.connector
+                    .connect(tls.domain.clone(), stream)
+                    .await
+                    .map_err(|error| {
+                        warn!("{COMPONENT} - failed TLS handshake with node with ID: {node_id}, address: {address}. {error}");
+                        IggyError::CannotEstablishConnection
+                    })?,
+            ),
+            None => Box::new(stream),
+        };
+
+        let nonce = rand::random::<[u8; NONCE_LENGTH]>();
+        write_message(
+            &mut stream,
+            &Handshake::Hello {
+                node_id: self.node_id,
+                nonce,
+            },
+        )
+        .await?;
+        let Handshake::Challenge {
+            node_id: peer_id,
+            nonce: peer_nonce,
+            proof,
+        } = read_message(&mut stream).await?
+        else {
+            return Err(IggyError::InvalidCommand);
+        };
+        if peer_id != node_id || !self.verify(&nonce, &peer_nonce, peer_id, &proof) {
+            warn!("{COMPONENT} - node with ID: {node_id}, address: {address} has failed to authenticate as node with ID: {peer_id}.");
+            return Err(IggyError::Unauthenticated);
+        }
+
+        let proof = self.sign(&peer_nonce, &nonce, self.node_id);
+        write_message(&mut stream, &Handshake::Proof { proof }).await?;
+        Ok(stream)
+    }
+
+    /// Accepts the connection of the other node, returning its ID once it has proven it knows the shared secret.
+    pub async fn accept(&self, stream: TcpStream) -> Result<(u32, Box<dyn PeerStream>), IggyError> {
+        timeout(HANDSHAKE_TIMEOUT, self.authenticate(stream))
+            .await
+            .unwrap_or(Err(IggyError::TcpError))
+    }
+
+    async fn authenticate(
+        &self,
+        stream: TcpStream,
+    ) -> Result<(u32, Box<dyn PeerStream>), IggyError> {
+        let mut stream: Box<dyn PeerStream> = match &self.tls {
+            Some(tls) => Box::new(tls.acceptor.accept(stream).await.map_err(|error| {
+                warn!("{COMPONENT} - failed TLS handshake with cluster connection. {error}");
+                IggyError::CannotEstablishConnection
+            })?),
+            None => Box::new(stream),
+        };
+
+        let Handshake::Hello {
+            node_id: peer_id,
+            nonce: peer_nonce,
+        } = read_message(&mut stream).await?
+        else {
+            return Err(IggyError::InvalidCommand);
+        };
+        if !self.node_ids.contains(&peer_id) {
+            warn!("{COMPONENT} - rejected connection of unknown node with ID: {peer_id}.");
+            return Err(IggyError::Unauthenticated);
+        }
+
+        let nonce = rand::random::<[u8; NONCE_LENGTH]>();
+        let proof = self.sign(&peer_nonce, &nonce, self.node_id);
+        write_message(
+            &mut stream,
+            &Handshake::Challenge {
+                node_id: self.node_id,
+                nonce,
+                proof,
+            },
+        )
+        .await?;
+        let Handshake::Proof { proof } = read_message(&mut stream).await? else {
+            return Err(IggyError::InvalidCommand);
+        };
+        if !self.verify(&nonce, &peer_nonce, peer_id, &proof) {
+            warn!("{COMPONENT} - node with ID: {peer_id} has failed to authenticate.");
+            return Err(IggyError::Unauthenticated);
+        }
+        Ok((peer_id, stream))
+    }
+
+    /// Signs the nonce of the other node, followed by the nonce of the signing node and its ID, with the key of the signing node.
+    fn sign(&self, nonce: &[u8], own_nonce: &[u8], node_id: u32) -> Vec<u8> {
+        hmac::sign(
+            &self.node_key(node_id),
+            &Self::challenge(nonce, own_nonce, node_id),
+        )
+        .as_ref()
+        .to_vec()
+    }
+
+    fn verify(&self, own_nonce: &[u8], nonce: &[u8], node_id: u32, proof: &[u8]) -> bool {
+        hmac::verify(
+            &self.node_key(node_id),
+            &Self::challenge(own_nonce, nonce, node_id),
+            proof,
+        )
+        .is_ok()
+    }
+
+    /// Derives the key of the node from the shared secret, so the proofs signed by the nodes are bound to their IDs.
+    fn node_key(&self, node_id: u32) -> hmac::Key {
+        let mut context = Vec::with_capacity(NODE_KEY_CONTEXT.len() + 4);
+        context.extend_from_slice(NODE_KEY_CONTEXT);
+        context.extend(node_id.to_le_bytes());
+        hmac::Key::new(hmac::HMAC_SHA256, hmac::sign(&self.key, &context).as_ref())
+    }
+
+    fn challenge(nonce: &[u8], signer_nonce: &[u8], signer_id: u32) -> Vec<u8> {
+        let mut challenge = Vec::with_capacity(2 * NONCE_LENGTH + 4);
+        challenge.extend_from_slice(nonce);
+        challenge.extend_from_slice(signer_nonce);
+        challenge.extend(signer_id.to_le_bytes());
+        challenge
+    }
+}
+
+impl ClusterTls {
+    fn new(config: &ClusterTlsConfig) -> anyhow::Result<Self> {
+        let certificates = load_certificates(&config.cert_file)?;
+        let key = rustls_pemfile::private_key(&mut BufReader::new(
+            File::open(&config.key_file).context("Unable to open TLS key file.")?,
+        ))
+        .context("Unable to read TLS key file.")?
+        .ok_or_else(|| anyhow!("TLS key file does not contain the private key."))?;
+        let server_config = rustls::ServerConfig::builder_with_provider(crypto_provider())
+            .with_safe_default_protocol_versions()
+            .and_then(|builder| {
+                builder
+                    .with_no_client_auth()
+                    .with_single_cert(certificates, key)
+            })
+            .context("Unable to create cluster TLS server config.")?;
+
+        let mut roots = RootCertStore::empty();
+        for certificate in load_certificates(&config.ca_file)? {
+            roots
+                .add(certificate)
+                .context("Unable to add cluster certificate authority.")?;
+        }
+        let client_config = rustls::ClientConfig::builder_with_provider(crypto_provider())
+            .with_safe_default_protocol_versions()
+            .context("Unable to create cluster TLS client config.")?
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        Ok(Self {
+            acceptor: TlsAcceptor::from(Arc::new(server_config)),
+            connector: TlsConnector::from(Arc::new(client_config)),
+            domain: ServerName::try_from(config.domain.clone())
+                .context("Invalid cluster TLS domain.")?,
+        })
+    }
+}
+
+fn load_certificates(path: &str) -> anyhow::Result<Vec<CertificateDer<'static>>> {
+    let file =
+        File::open(path).with_context(|| format!("Unable to open certificate file: {path}."))?;
+    let certificates = rustls_pemfile::certs(&mut BufReader::new(file))
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("Unable to read certificate file: {path}."))?;
+    if certificates.is_empty() {
+        return Err(anyhow!("No certificates found in: {path}."));
+    }
+    Ok(certificates)
+}
+
+/// The other node of the cluster, with the (authenticated) connection kept open between the requests.
+pub struct Peer {
+    pub id: u32,
+    pub address: String,
+    transport: Arc<ClusterTransport>,
+    connection: Mutex<Option<Box<dyn PeerStream>>>,
+}
+
+impl Debug for Peer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Peer")
+            .field("id", &self.id)
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+impl Peer {
+    pub fn new(id: u32, address: &str, transport: Arc<ClusterTransport>) -> Self {
+        Self {
+            id,
+            address: address.to_owned(),
+            transport,
+            connection: Mutex::new(None),
+        }
+    }
+
+    /// Sends the request and waits for the response, reconnecting if there's no open connection.
+    /// The connection is dropped on any error, so the next request starts with the new one.
+    pub async fn send(
+        &self,
+        request: &Request,
+        request_timeout: Duration,
+    ) -> Result<Response, IggyError> {
+        let mut connection = self.connection.lock().await;
+        let result = timeout(request_timeout, async {
+            if connection.is_none() {
+                *connection = Some(self.transport.connect(self.id, &self.address).await?);
+            }
+
+            let stream = connection.as_mut().expect("Connection should be open");
+            write_message(stream, request).await?;
+            read_message(stream).await
+        })
+        .await
+        .unwrap_or(Err(IggyError::TcpError));
+
+        if result.is_err() {
+            *connection = None;
+        }
+        result
+    }
+}
+
+pub async fn write_message<T: Serialize>(
+    stream: &mut (impl AsyncWrite + Unpin),
+    message: &T,
+) -> Result<(), IggyError> {
+    let payload = bincode::serde::encode_to_vec(message, bincode::config::standard())
+        .map_err(|_| IggyError::CannotSerializeResource)?;
+    let mut bytes = Vec::with_capacity(4 + payload.len());
+    bytes.extend((payload.len() as u32).to_le_bytes());
+    bytes.extend(payload);
+    stream
+        .write_all(&bytes)
+        .await
+        .map_err(|_| IggyError::TcpError)
+}
+
+pub async fn read_message<T: DeserializeOwned>(
+    stream: &mut (impl AsyncRead + Unpin),
+) -> Result<T, IggyError> {
+    let length = stream
+        .read_u32_le()
+        .await
+        .map_err(|_| IggyError::ConnectionClosed)? as usize;
+    if length > MAX_MESSAGE_SIZE {
+        error!("{COMPONENT} - received message of size: {length} exceeding the limit: {MAX_MESSAGE_SIZE}");
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let mut payload = vec![0; length];
+    stream
+        .read_exact(&mut payload)
+        .await
+        .map_err(|_| IggyError::TcpError)?;
+    let (message, _) = bincode::serde::decode_from_slice(&payload, bincode::config::standard())
+        .map_err(|_| IggyError::CannotDeserializeResource)?;
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::cluster::ClusterNodeConfig;
+    use tokio::net::TcpListener;
+
+    fn transport(id: u32, peer_id: u32, secret: &str) -> ClusterTransport {
+        let config = ClusterConfig {
+            id,
+            secret: secret.to_owned(),
+            nodes: vec![ClusterNodeConfig {
+                id: peer_id,
+                address: "127.0.0.1:0".to_owned(),
+                client_address: "127.0.0.1:0".to_owned(),
+            }],
+            ..ClusterConfig::default()
+        };
+        ClusterTransport::new(&config).unwrap()
+    }
+
+    async fn handshake(
+        acceptor: ClusterTransport,
+        connector: ClusterTransport,
+        node_id: u32,
+    ) -> (
+        Result<u32, IggyError>,
+        Result<Box<dyn PeerStream>, IggyError>,
+    ) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let accepted = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            acceptor.accept(stream).await.map(|(peer_id, _)| peer_id)
+        });
+        let connected = connector.connect(node_id, &address).await;
+        (accepted.await.unwrap(), connected)
+    }
+
+    #[tokio::test]
+    async fn nodes_sharing_secret_should_authenticate_each_other() {
+        let (accepted, connected) =
+            handshake(transport(1, 2, "secret"), transport(2, 1, "secret"), 1).await;
+        assert_eq!(accepted.unwrap(), 2);
+        assert!(connected.is_ok());
+    }
+
+    #[tokio::test]
+    async fn node_with_different_secret_should_be_rejected() {
+        let (accepted, connected) =
+            handshake(transport(1, 2, "secret"), transport(2, 1, "other"), 1).await;
+        assert!(accepted.is_err());
+        assert_eq!(
+            connected.err().unwrap().as_code(),
+            IggyError::Unauthenticated.as_code()
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_node_should_be_rejected() {
+        let (accepted, connected) =
+            handshake(transport(1, 2, "secret"), transport(3, 1, "secret"), 1).await;
+        assert_eq!(
+            accepted.err().unwrap().as_code(),
+            IggyError::Unauthenticated.as_code()
+        );
+        assert!(connected.is_err());
+    }
+
+    #[test]
+    fn proof_should_be_bound_to_node_id() {
+        let transport = transport(1, 2, "secret");
+        let nonce = [1; NONCE_LENGTH];
+        let signer_nonce = [2; NONCE_LENGTH];
+        let proof = transport.sign(&nonce, &signer_nonce, 2);
+        assert!(transport.verify(&nonce, &signer_nonce, 2, &proof));
+        assert!(!transport.verify(&nonce, &signer_nonce, 3, &proof));
+    }
+
+    #[tokio::test]
+    async fn node_impersonated_by_other_one_should_be_rejected() {
+        let (accepted, connected) =
+            handshake(transport(1, 2, "secret"), transport(2, 1, "secret"), 3).await;
+        assert!(accepted.is_err());
+        assert_eq!(
+            connected.err().unwrap().as_code(),
+            IggyError::Unauthenticated.as_code()
+        );
+    }
+}
@@ -0,0 +1,67 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cluster::raft::RaftNode;
+use crate::state::command::EntryCommand;
+use crate::state::entry::StateEntry;
+use crate::state::file::FileState;
+use crate::state::{State, StateCommit};
+use iggy::error::IggyError;
+use std::sync::Arc;
+
+/// The state log replicated across the cluster nodes - the entries are appended by the leader only,
+/// and the followers receive them from the leader.
+#[derive(Debug)]
+pub struct ReplicatedState {
+    file_state: Arc<FileState>,
+    raft: Arc<RaftNode>,
+}
+
+impl ReplicatedState {
+    pub fn new(file_state: Arc<FileState>, raft: Arc<RaftNode>) -> Self {
+        Self { file_state, raft }
+    }
+
+    /// Appends the command to the state log, returning the commit to wait for until it's replicated to the quorum of the nodes.
+    pub async fn append(
+        &self,
+        user_id: u32,
+        command: &EntryCommand,
+    ) -> Result<StateCommit, IggyError> {
+        Ok(match self.raft.append_entry(user_id, command).await? {
+            Some(entry) => StateCommit::Replicated(self.raft.clone(), entry),
+            None => StateCommit::Committed,
+        })
+    }
+}
+
+impl State for ReplicatedState {
+    async fn init(&self) -> Result<Vec<StateEntry>, IggyError> {
+        let entries = self.file_state.init().await?;
+        self.raft.init(&entries).await?;
+        Ok(entries)
+    }
+
+    async fn load_entries(&self) -> Result<Vec<StateEntry>, IggyError> {
+        self.file_state.load_entries().await
+    }
+
+    async fn apply(&self, user_id: u32, command: &EntryCommand) -> Result<(), IggyError> {
+        self.raft.append(user_id, command).await
+    }
+}
@@ -18,42 +18,74 @@
 
 use bytes::{BufMut, Bytes, BytesMut};
 use error_set::ErrContext;
+use iggy::bookmarks::delete_bookmark::DeleteBookmark;
+use iggy::bookmarks::get_bookmark::GetBookmark;
+use iggy::bookmarks::get_bookmarks::GetBookmarks;
+use iggy::bookmarks::store_bookmark::StoreBookmark;
 use iggy::command::*;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use iggy::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use iggy::consumer_offsets::delete_consumer_offset::DeleteConsumerOffset;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::error::IggyError;
+use iggy::messages::abort_transaction::AbortTransaction;
+use iggy::messages::ack_messages::AckMessages;
+use iggy::messages::begin_transaction::BeginTransaction;
+use iggy::messages::commit_transaction::CommitTransaction;
+use iggy::messages::nack_messages::NackMessages;
 use iggy::messages::poll_messages::PollMessages;
+use iggy::messages::reject_messages::RejectMessages;
 use iggy::messages::send_messages::SendMessages;
+use iggy::messages::send_offsets_to_transaction::SendOffsetsToTransaction;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::get_partition::GetPartition;
+use iggy::partitions::move_partition::MovePartition;
+use iggy::partitions::update_partition::UpdatePartition;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use iggy::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use iggy::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use iggy::quotas::get_quotas::GetQuotas;
+use iggy::quotas::set_quota::SetQuota;
+use iggy::schemas::get_schema::GetSchema;
+use iggy::schemas::get_schemas::GetSchemas;
+use iggy::schemas::get_subject_schema::GetSubjectSchema;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use iggy::segments::verify_segments::VerifySegments;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
 use iggy::streams::get_streams::GetStreams;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::system::create_backup::CreateBackup;
+use iggy::system::enable_consumer_group_events::EnableConsumerGroupEvents;
+use iggy::system::enable_frame_checksums::EnableFrameChecksums;
+use iggy::system::enable_zero_copy_polling::EnableZeroCopyPolling;
+use iggy::system::get_audit_log::GetAuditLog;
 use iggy::system::get_client::GetClient;
 use iggy::system::get_clients::GetClients;
+use iggy::system::get_cluster_metadata::GetClusterMetadata;
+use iggy::system::get_config::GetConfig;
 use iggy::system::get_me::GetMe;
 use iggy::system::get_snapshot::GetSnapshot;
 use iggy::system::get_stats::GetStats;
 use iggy::system::ping::Ping;
+use iggy::system::restore_backup::RestoreBackup;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::get_topic::GetTopic;
 use iggy::topics::get_topics::GetTopics;
 use iggy::topics::purge_topic::PurgeTopic;
+use iggy::topics::set_topic_throttle::SetTopicThrottle;
 use iggy::topics::update_topic::UpdateTopic;
 use iggy::users::change_password::ChangePassword;
 use iggy::users::create_user::CreateUser;
@@ -75,7 +107,15 @@ use tracing::error;
 #[derive(Debug, PartialEq, EnumString)]
 pub enum ServerCommand {
     Ping(Ping),
+    EnableFrameChecksums(EnableFrameChecksums),
+    EnableZeroCopyPolling(EnableZeroCopyPolling),
+    EnableConsumerGroupEvents(EnableConsumerGroupEvents),
     GetStats(GetStats),
+    GetConfig(GetConfig),
+    GetAuditLog(GetAuditLog),
+    CreateBackup(CreateBackup),
+    RestoreBackup(RestoreBackup),
+    GetClusterMetadata(GetClusterMetadata),
     GetMe(GetMe),
     GetClient(GetClient),
     GetClients(GetClients),
@@ -95,9 +135,20 @@ pub enum ServerCommand {
     SendMessages(SendMessages),
     PollMessages(PollMessages),
     FlushUnsavedBuffer(FlushUnsavedBuffer),
+    RejectMessages(RejectMessages),
+    NackMessages(NackMessages),
+    BeginTransaction(BeginTransaction),
+    CommitTransaction(CommitTransaction),
+    AbortTransaction(AbortTransaction),
+    SendOffsetsToTransaction(SendOffsetsToTransaction),
+    AckMessages(AckMessages),
     GetConsumerOffset(GetConsumerOffset),
     StoreConsumerOffset(StoreConsumerOffset),
     DeleteConsumerOffset(DeleteConsumerOffset),
+    GetBookmark(GetBookmark),
+    GetBookmarks(GetBookmarks),
+    StoreBookmark(StoreBookmark),
+    DeleteBookmark(DeleteBookmark),
     GetStream(GetStream),
     GetStreams(GetStreams),
     CreateStream(CreateStream),
@@ -110,14 +161,27 @@ pub enum ServerCommand {
     DeleteTopic(DeleteTopic),
     UpdateTopic(UpdateTopic),
     PurgeTopic(PurgeTopic),
+    SetTopicThrottle(SetTopicThrottle),
+    GetPartition(GetPartition),
     CreatePartitions(CreatePartitions),
     DeletePartitions(DeletePartitions),
+    UpdatePartition(UpdatePartition),
+    MovePartition(MovePartition),
+    VerifySegments(VerifySegments),
     GetConsumerGroup(GetConsumerGroup),
     GetConsumerGroups(GetConsumerGroups),
     CreateConsumerGroup(CreateConsumerGroup),
     DeleteConsumerGroup(DeleteConsumerGroup),
     JoinConsumerGroup(JoinConsumerGroup),
     LeaveConsumerGroup(LeaveConsumerGroup),
+    HeartbeatConsumerGroup(HeartbeatConsumerGroup),
+    GetSchema(GetSchema),
+    GetSchemas(GetSchemas),
+    GetSubjectSchema(GetSubjectSchema),
+    RegisterSchema(RegisterSchema),
+    UpdateSchemaCompatibility(UpdateSchemaCompatibility),
+    GetQuotas(GetQuotas),
+    SetQuota(SetQuota),
     GetSnapshotFile(GetSnapshot),
 }
 
@@ -125,7 +189,15 @@ impl BytesSerializable for ServerCommand {
     fn to_bytes(&self) -> Bytes {
         match self {
             ServerCommand::Ping(payload) => as_bytes(payload),
+            ServerCommand::EnableFrameChecksums(payload) => as_bytes(payload),
+            ServerCommand::EnableZeroCopyPolling(payload) => as_bytes(payload),
+            ServerCommand::EnableConsumerGroupEvents(payload) => as_bytes(payload),
             ServerCommand::GetStats(payload) => as_bytes(payload),
+            ServerCommand::GetConfig(payload) => as_bytes(payload),
+            ServerCommand::GetAuditLog(payload) => as_bytes(payload),
+            ServerCommand::CreateBackup(payload) => as_bytes(payload),
+            ServerCommand::RestoreBackup(payload) => as_bytes(payload),
+            ServerCommand::GetClusterMetadata(payload) => as_bytes(payload),
             ServerCommand::GetMe(payload) => as_bytes(payload),
             ServerCommand::GetClient(payload) => as_bytes(payload),
             ServerCommand::GetClients(payload) => as_bytes(payload),
@@ -146,6 +218,10 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::PollMessages(payload) => as_bytes(payload),
             ServerCommand::StoreConsumerOffset(payload) => as_bytes(payload),
             ServerCommand::DeleteConsumerOffset(payload) => as_bytes(payload),
+            ServerCommand::GetBookmark(payload) => as_bytes(payload),
+            ServerCommand::GetBookmarks(payload) => as_bytes(payload),
+            ServerCommand::StoreBookmark(payload) => as_bytes(payload),
+            ServerCommand::DeleteBookmark(payload) => as_bytes(payload),
             ServerCommand::GetConsumerOffset(payload) => as_bytes(payload),
             ServerCommand::GetStream(payload) => as_bytes(payload),
             ServerCommand::GetStreams(payload) => as_bytes(payload),
@@ -159,15 +235,35 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::DeleteTopic(payload) => as_bytes(payload),
             ServerCommand::UpdateTopic(payload) => as_bytes(payload),
             ServerCommand::PurgeTopic(payload) => as_bytes(payload),
+            ServerCommand::SetTopicThrottle(payload) => as_bytes(payload),
+            ServerCommand::GetPartition(payload) => as_bytes(payload),
             ServerCommand::CreatePartitions(payload) => as_bytes(payload),
             ServerCommand::DeletePartitions(payload) => as_bytes(payload),
+            ServerCommand::UpdatePartition(payload) => as_bytes(payload),
+            ServerCommand::MovePartition(payload) => as_bytes(payload),
+            ServerCommand::VerifySegments(payload) => as_bytes(payload),
             ServerCommand::GetConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::GetConsumerGroups(payload) => as_bytes(payload),
             ServerCommand::CreateConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::DeleteConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::JoinConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::LeaveConsumerGroup(payload) => as_bytes(payload),
+            ServerCommand::HeartbeatConsumerGroup(payload) => as_bytes(payload),
+            ServerCommand::GetSchema(payload) => as_bytes(payload),
+            ServerCommand::GetSchemas(payload) => as_bytes(payload),
+            ServerCommand::GetSubjectSchema(payload) => as_bytes(payload),
+            ServerCommand::RegisterSchema(payload) => as_bytes(payload),
+            ServerCommand::UpdateSchemaCompatibility(payload) => as_bytes(payload),
+            ServerCommand::GetQuotas(payload) => as_bytes(payload),
+            ServerCommand::SetQuota(payload) => as_bytes(payload),
             ServerCommand::FlushUnsavedBuffer(payload) => as_bytes(payload),
+            ServerCommand::RejectMessages(payload) => as_bytes(payload),
+            ServerCommand::NackMessages(payload) => as_bytes(payload),
+            ServerCommand::BeginTransaction(payload) => as_bytes(payload),
+            ServerCommand::CommitTransaction(payload) => as_bytes(payload),
+            ServerCommand::AbortTransaction(payload) => as_bytes(payload),
+            ServerCommand::SendOffsetsToTransaction(payload) => as_bytes(payload),
+            ServerCommand::AckMessages(payload) => as_bytes(payload),
             ServerCommand::GetSnapshotFile(payload) => as_bytes(payload),
         }
     }
@@ -182,7 +278,29 @@ impl BytesSerializable for ServerCommand {
         let payload = bytes.slice(4..);
         match code {
             PING_CODE => Ok(ServerCommand::Ping(Ping::from_bytes(payload)?)),
+            ENABLE_FRAME_CHECKSUMS_CODE => Ok(ServerCommand::EnableFrameChecksums(
+                EnableFrameChecksums::from_bytes(payload)?,
+            )),
+            ENABLE_ZERO_COPY_POLLING_CODE => Ok(ServerCommand::EnableZeroCopyPolling(
+                EnableZeroCopyPolling::from_bytes(payload)?,
+            )),
+            ENABLE_CONSUMER_GROUP_EVENTS_CODE => Ok(ServerCommand::EnableConsumerGroupEvents(
+                EnableConsumerGroupEvents::from_bytes(payload)?,
+            )),
             GET_STATS_CODE => Ok(ServerCommand::GetStats(GetStats::from_bytes(payload)?)),
+            GET_CONFIG_CODE => Ok(ServerCommand::GetConfig(GetConfig::from_bytes(payload)?)),
+            GET_AUDIT_LOG_CODE => Ok(ServerCommand::GetAuditLog(GetAuditLog::from_bytes(
+                payload,
+            )?)),
+            CREATE_BACKUP_CODE => Ok(ServerCommand::CreateBackup(CreateBackup::from_bytes(
+                payload,
+            )?)),
+            RESTORE_BACKUP_CODE => Ok(ServerCommand::RestoreBackup(RestoreBackup::from_bytes(
+                payload,
+            )?)),
+            GET_CLUSTER_METADATA_CODE => Ok(ServerCommand::GetClusterMetadata(
+                GetClusterMetadata::from_bytes(payload)?,
+            )),
             GET_ME_CODE => Ok(ServerCommand::GetMe(GetMe::from_bytes(payload)?)),
             GET_CLIENT_CODE => Ok(ServerCommand::GetClient(GetClient::from_bytes(payload)?)),
             GET_CLIENTS_CODE => Ok(ServerCommand::GetClients(GetClients::from_bytes(payload)?)),
@@ -222,12 +340,45 @@ impl BytesSerializable for ServerCommand {
             FLUSH_UNSAVED_BUFFER_CODE => Ok(ServerCommand::FlushUnsavedBuffer(
                 FlushUnsavedBuffer::from_bytes(payload)?,
             )),
+            REJECT_MESSAGES_CODE => Ok(ServerCommand::RejectMessages(RejectMessages::from_bytes(
+                payload,
+            )?)),
+            NACK_MESSAGES_CODE => Ok(ServerCommand::NackMessages(NackMessages::from_bytes(
+                payload,
+            )?)),
+            BEGIN_TRANSACTION_CODE => Ok(ServerCommand::BeginTransaction(
+                BeginTransaction::from_bytes(payload)?,
+            )),
+            COMMIT_TRANSACTION_CODE => Ok(ServerCommand::CommitTransaction(
+                CommitTransaction::from_bytes(payload)?,
+            )),
+            ABORT_TRANSACTION_CODE => Ok(ServerCommand::AbortTransaction(
+                AbortTransaction::from_bytes(payload)?,
+            )),
+            SEND_OFFSETS_TO_TRANSACTION_CODE => Ok(ServerCommand::SendOffsetsToTransaction(
+                SendOffsetsToTransaction::from_bytes(payload)?,
+            )),
+            ACK_MESSAGES_CODE => Ok(ServerCommand::AckMessages(AckMessages::from_bytes(
+                payload,
+            )?)),
             STORE_CONSUMER_OFFSET_CODE => Ok(ServerCommand::StoreConsumerOffset(
                 StoreConsumerOffset::from_bytes(payload)?,
             )),
             DELETE_CONSUMER_OFFSET_CODE => Ok(ServerCommand::DeleteConsumerOffset(
                 DeleteConsumerOffset::from_bytes(payload)?,
             )),
+            GET_BOOKMARK_CODE => Ok(ServerCommand::GetBookmark(GetBookmark::from_bytes(
+                payload,
+            )?)),
+            GET_BOOKMARKS_CODE => Ok(ServerCommand::GetBookmarks(GetBookmarks::from_bytes(
+                payload,
+            )?)),
+            STORE_BOOKMARK_CODE => Ok(ServerCommand::StoreBookmark(StoreBookmark::from_bytes(
+                payload,
+            )?)),
+            DELETE_BOOKMARK_CODE => Ok(ServerCommand::DeleteBookmark(DeleteBookmark::from_bytes(
+                payload,
+            )?)),
             GET_CONSUMER_OFFSET_CODE => Ok(ServerCommand::GetConsumerOffset(
                 GetConsumerOffset::from_bytes(payload)?,
             )),
@@ -257,12 +408,27 @@ impl BytesSerializable for ServerCommand {
                 payload,
             )?)),
             PURGE_TOPIC_CODE => Ok(ServerCommand::PurgeTopic(PurgeTopic::from_bytes(payload)?)),
+            SET_TOPIC_THROTTLE_CODE => Ok(ServerCommand::SetTopicThrottle(
+                SetTopicThrottle::from_bytes(payload)?,
+            )),
+            GET_PARTITION_CODE => Ok(ServerCommand::GetPartition(GetPartition::from_bytes(
+                payload,
+            )?)),
             CREATE_PARTITIONS_CODE => Ok(ServerCommand::CreatePartitions(
                 CreatePartitions::from_bytes(payload)?,
             )),
             DELETE_PARTITIONS_CODE => Ok(ServerCommand::DeletePartitions(
                 DeletePartitions::from_bytes(payload)?,
             )),
+            UPDATE_PARTITION_CODE => Ok(ServerCommand::UpdatePartition(
+                UpdatePartition::from_bytes(payload)?,
+            )),
+            MOVE_PARTITION_CODE => Ok(ServerCommand::MovePartition(MovePartition::from_bytes(
+                payload,
+            )?)),
+            VERIFY_SEGMENTS_CODE => Ok(ServerCommand::VerifySegments(VerifySegments::from_bytes(
+                payload,
+            )?)),
             GET_CONSUMER_GROUP_CODE => Ok(ServerCommand::GetConsumerGroup(
                 GetConsumerGroup::from_bytes(payload)?,
             )),
@@ -281,6 +447,22 @@ impl BytesSerializable for ServerCommand {
             LEAVE_CONSUMER_GROUP_CODE => Ok(ServerCommand::LeaveConsumerGroup(
                 LeaveConsumerGroup::from_bytes(payload)?,
             )),
+            HEARTBEAT_CONSUMER_GROUP_CODE => Ok(ServerCommand::HeartbeatConsumerGroup(
+                HeartbeatConsumerGroup::from_bytes(payload)?,
+            )),
+            GET_SCHEMA_CODE => Ok(ServerCommand::GetSchema(GetSchema::from_bytes(payload)?)),
+            GET_SCHEMAS_CODE => Ok(ServerCommand::GetSchemas(GetSchemas::from_bytes(payload)?)),
+            GET_SUBJECT_SCHEMA_CODE => Ok(ServerCommand::GetSubjectSchema(
+                GetSubjectSchema::from_bytes(payload)?,
+            )),
+            REGISTER_SCHEMA_CODE => Ok(ServerCommand::RegisterSchema(RegisterSchema::from_bytes(
+                payload,
+            )?)),
+            UPDATE_SCHEMA_COMPATIBILITY_CODE => Ok(ServerCommand::UpdateSchemaCompatibility(
+                UpdateSchemaCompatibility::from_bytes(payload)?,
+            )),
+            GET_QUOTAS_CODE => Ok(ServerCommand::GetQuotas(GetQuotas::from_bytes(payload)?)),
+            SET_QUOTA_CODE => Ok(ServerCommand::SetQuota(SetQuota::from_bytes(payload)?)),
             GET_SNAPSHOT_FILE_CODE => Ok(ServerCommand::GetSnapshotFile(GetSnapshot::from_bytes(
                 payload,
             )?)),
@@ -304,7 +486,15 @@ impl Validatable<IggyError> for ServerCommand {
     fn validate(&self) -> Result<(), IggyError> {
         match self {
             ServerCommand::Ping(command) => command.validate(),
+            ServerCommand::EnableFrameChecksums(command) => command.validate(),
+            ServerCommand::EnableZeroCopyPolling(command) => command.validate(),
+            ServerCommand::EnableConsumerGroupEvents(command) => command.validate(),
             ServerCommand::GetStats(command) => command.validate(),
+            ServerCommand::GetConfig(command) => command.validate(),
+            ServerCommand::GetAuditLog(command) => command.validate(),
+            ServerCommand::CreateBackup(command) => command.validate(),
+            ServerCommand::RestoreBackup(command) => command.validate(),
+            ServerCommand::GetClusterMetadata(command) => command.validate(),
             ServerCommand::GetMe(command) => command.validate(),
             ServerCommand::GetClient(command) => command.validate(),
             ServerCommand::GetClients(command) => command.validate(),
@@ -325,6 +515,10 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::PollMessages(command) => command.validate(),
             ServerCommand::StoreConsumerOffset(command) => command.validate(),
             ServerCommand::DeleteConsumerOffset(command) => command.validate(),
+            ServerCommand::GetBookmark(command) => command.validate(),
+            ServerCommand::GetBookmarks(command) => command.validate(),
+            ServerCommand::StoreBookmark(command) => command.validate(),
+            ServerCommand::DeleteBookmark(command) => command.validate(),
             ServerCommand::GetConsumerOffset(command) => command.validate(),
             ServerCommand::GetStream(command) => command.validate(),
             ServerCommand::GetStreams(command) => command.validate(),
@@ -338,15 +532,35 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::DeleteTopic(command) => command.validate(),
             ServerCommand::UpdateTopic(command) => command.validate(),
             ServerCommand::PurgeTopic(command) => command.validate(),
+            ServerCommand::SetTopicThrottle(command) => command.validate(),
+            ServerCommand::GetPartition(command) => command.validate(),
             ServerCommand::CreatePartitions(command) => command.validate(),
             ServerCommand::DeletePartitions(command) => command.validate(),
+            ServerCommand::UpdatePartition(command) => command.validate(),
+            ServerCommand::MovePartition(command) => command.validate(),
+            ServerCommand::VerifySegments(command) => command.validate(),
             ServerCommand::GetConsumerGroup(command) => command.validate(),
             ServerCommand::GetConsumerGroups(command) => command.validate(),
             ServerCommand::CreateConsumerGroup(command) => command.validate(),
             ServerCommand::DeleteConsumerGroup(command) => command.validate(),
             ServerCommand::JoinConsumerGroup(command) => command.validate(),
             ServerCommand::LeaveConsumerGroup(command) => command.validate(),
+            ServerCommand::HeartbeatConsumerGroup(command) => command.validate(),
+            ServerCommand::GetSchema(command) => command.validate(),
+            ServerCommand::GetSchemas(command) => command.validate(),
+            ServerCommand::GetSubjectSchema(command) => command.validate(),
+            ServerCommand::RegisterSchema(command) => command.validate(),
+            ServerCommand::UpdateSchemaCompatibility(command) => command.validate(),
+            ServerCommand::GetQuotas(command) => command.validate(),
+            ServerCommand::SetQuota(command) => command.validate(),
             ServerCommand::FlushUnsavedBuffer(command) => command.validate(),
+            ServerCommand::RejectMessages(command) => command.validate(),
+            ServerCommand::NackMessages(command) => command.validate(),
+            ServerCommand::BeginTransaction(command) => command.validate(),
+            ServerCommand::CommitTransaction(command) => command.validate(),
+            ServerCommand::AbortTransaction(command) => command.validate(),
+            ServerCommand::SendOffsetsToTransaction(command) => command.validate(),
+            ServerCommand::AckMessages(command) => command.validate(),
             ServerCommand::GetSnapshotFile(command) => command.validate(),
         }
     }
@@ -356,7 +570,23 @@ impl Display for ServerCommand {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             ServerCommand::Ping(_) => write!(formatter, "{PING}"),
+            ServerCommand::EnableFrameChecksums(_) => {
+                write!(formatter, "{ENABLE_FRAME_CHECKSUMS}")
+            }
+            ServerCommand::EnableZeroCopyPolling(_) => {
+                write!(formatter, "{ENABLE_ZERO_COPY_POLLING}")
+            }
+            ServerCommand::EnableConsumerGroupEvents(_) => {
+                write!(formatter, "{ENABLE_CONSUMER_GROUP_EVENTS}")
+            }
             ServerCommand::GetStats(_) => write!(formatter, "{GET_STATS}"),
+            ServerCommand::GetConfig(_) => write!(formatter, "{GET_CONFIG}"),
+            ServerCommand::GetAuditLog(payload) => write!(formatter, "{GET_AUDIT_LOG}|{payload}"),
+            ServerCommand::CreateBackup(payload) => write!(formatter, "{CREATE_BACKUP}|{payload}"),
+            ServerCommand::RestoreBackup(payload) => {
+                write!(formatter, "{RESTORE_BACKUP}|{payload}")
+            }
+            ServerCommand::GetClusterMetadata(_) => write!(formatter, "{GET_CLUSTER_METADATA}"),
             ServerCommand::GetMe(_) => write!(formatter, "{GET_ME}"),
             ServerCommand::GetClient(payload) => write!(formatter, "{GET_CLIENT}|{payload}"),
             ServerCommand::GetClients(_) => write!(formatter, "{GET_CLIENTS}"),
@@ -397,12 +627,25 @@ impl Display for ServerCommand {
             ServerCommand::DeleteTopic(payload) => write!(formatter, "{DELETE_TOPIC}|{payload}"),
             ServerCommand::UpdateTopic(payload) => write!(formatter, "{UPDATE_TOPIC}|{payload}"),
             ServerCommand::PurgeTopic(payload) => write!(formatter, "{PURGE_TOPIC}|{payload}"),
+            ServerCommand::SetTopicThrottle(payload) => {
+                write!(formatter, "{SET_TOPIC_THROTTLE}|{payload}")
+            }
+            ServerCommand::GetPartition(payload) => write!(formatter, "{GET_PARTITION}|{payload}"),
             ServerCommand::CreatePartitions(payload) => {
                 write!(formatter, "{CREATE_PARTITIONS}|{payload}")
             }
             ServerCommand::DeletePartitions(payload) => {
                 write!(formatter, "{DELETE_PARTITIONS}|{payload}")
             }
+            ServerCommand::UpdatePartition(payload) => {
+                write!(formatter, "{UPDATE_PARTITION}|{payload}")
+            }
+            ServerCommand::MovePartition(payload) => {
+                write!(formatter, "{MOVE_PARTITION}|{payload}")
+            }
+            ServerCommand::VerifySegments(payload) => {
+                write!(formatter, "{VERIFY_SEGMENTS}|{payload}")
+            }
             ServerCommand::PollMessages(payload) => write!(formatter, "{POLL_MESSAGES}|{payload}"),
             ServerCommand::SendMessages(payload) => write!(formatter, "{SEND_MESSAGES}|{payload}"),
             ServerCommand::StoreConsumerOffset(payload) => {
@@ -411,6 +654,14 @@ impl Display for ServerCommand {
             ServerCommand::DeleteConsumerOffset(payload) => {
                 write!(formatter, "{DELETE_CONSUMER_OFFSET}|{payload}")
             }
+            ServerCommand::GetBookmark(payload) => write!(formatter, "{GET_BOOKMARK}|{payload}"),
+            ServerCommand::GetBookmarks(payload) => write!(formatter, "{GET_BOOKMARKS}|{payload}"),
+            ServerCommand::StoreBookmark(payload) => {
+                write!(formatter, "{STORE_BOOKMARK}|{payload}")
+            }
+            ServerCommand::DeleteBookmark(payload) => {
+                write!(formatter, "{DELETE_BOOKMARK}|{payload}")
+            }
             ServerCommand::GetConsumerOffset(payload) => {
                 write!(formatter, "{GET_CONSUMER_OFFSET}|{payload}")
             }
@@ -432,9 +683,48 @@ impl Display for ServerCommand {
             ServerCommand::LeaveConsumerGroup(payload) => {
                 write!(formatter, "{LEAVE_CONSUMER_GROUP}|{payload}")
             }
+            ServerCommand::HeartbeatConsumerGroup(payload) => {
+                write!(formatter, "{HEARTBEAT_CONSUMER_GROUP}|{payload}")
+            }
+            ServerCommand::GetSchema(payload) => {
+                write!(formatter, "{GET_SCHEMA}|{payload}")
+            }
+            ServerCommand::GetSchemas(payload) => {
+                write!(formatter, "{GET_SCHEMAS}|{payload}")
+            }
+            ServerCommand::GetSubjectSchema(payload) => {
+                write!(formatter, "{GET_SUBJECT_SCHEMA}|{payload}")
+            }
+            ServerCommand::RegisterSchema(payload) => {
+                write!(formatter, "{REGISTER_SCHEMA}|{payload}")
+            }
+            ServerCommand::UpdateSchemaCompatibility(payload) => {
+                write!(formatter, "{UPDATE_SCHEMA_COMPATIBILITY}|{payload}")
+            }
+            ServerCommand::GetQuotas(payload) => write!(formatter, "{GET_QUOTAS}|{payload}"),
+            ServerCommand::SetQuota(payload) => write!(formatter, "{SET_QUOTA}|{payload}"),
             ServerCommand::FlushUnsavedBuffer(payload) => {
                 write!(formatter, "{FLUSH_UNSAVED_BUFFER}|{payload}")
             }
+            ServerCommand::RejectMessages(payload) => {
+                write!(formatter, "{REJECT_MESSAGES}|{payload}")
+            }
+            ServerCommand::NackMessages(payload) => {
+                write!(formatter, "{NACK_MESSAGES}|{payload}")
+            }
+            ServerCommand::BeginTransaction(_) => write!(formatter, "{BEGIN_TRANSACTION}"),
+            ServerCommand::CommitTransaction(payload) => {
+                write!(formatter, "{COMMIT_TRANSACTION}|{payload}")
+            }
+            ServerCommand::AbortTransaction(payload) => {
+                write!(formatter, "{ABORT_TRANSACTION}|{payload}")
+            }
+            ServerCommand::SendOffsetsToTransaction(payload) => {
+                write!(formatter, "{SEND_OFFSETS_TO_TRANSACTION}|{payload}")
+            }
+            ServerCommand::AckMessages(payload) => {
+                write!(formatter, "{ACK_MESSAGES}|{payload}")
+            }
             ServerCommand::GetSnapshotFile(payload) => {
                 write!(formatter, "{GET_SNAPSHOT_FILE}|{payload}")
             }
@@ -453,11 +743,51 @@ mod tests {
             PING_CODE,
             &Ping::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::EnableFrameChecksums(EnableFrameChecksums::default()),
+            ENABLE_FRAME_CHECKSUMS_CODE,
+            &EnableFrameChecksums::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::EnableZeroCopyPolling(EnableZeroCopyPolling::default()),
+            ENABLE_ZERO_COPY_POLLING_CODE,
+            &EnableZeroCopyPolling::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::EnableConsumerGroupEvents(EnableConsumerGroupEvents::default()),
+            ENABLE_CONSUMER_GROUP_EVENTS_CODE,
+            &EnableConsumerGroupEvents::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetStats(GetStats::default()),
             GET_STATS_CODE,
             &GetStats::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetConfig(GetConfig::default()),
+            GET_CONFIG_CODE,
+            &GetConfig::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetAuditLog(GetAuditLog::default()),
+            GET_AUDIT_LOG_CODE,
+            &GetAuditLog::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CreateBackup(CreateBackup::default()),
+            CREATE_BACKUP_CODE,
+            &CreateBackup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RestoreBackup(RestoreBackup::default()),
+            RESTORE_BACKUP_CODE,
+            &RestoreBackup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetClusterMetadata(GetClusterMetadata::default()),
+            GET_CLUSTER_METADATA_CODE,
+            &GetClusterMetadata::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetMe(GetMe::default()),
             GET_ME_CODE,
@@ -558,6 +888,26 @@ mod tests {
             GET_CONSUMER_OFFSET_CODE,
             &GetConsumerOffset::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetBookmark(GetBookmark::default()),
+            GET_BOOKMARK_CODE,
+            &GetBookmark::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetBookmarks(GetBookmarks::default()),
+            GET_BOOKMARKS_CODE,
+            &GetBookmarks::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::StoreBookmark(StoreBookmark::default()),
+            STORE_BOOKMARK_CODE,
+            &StoreBookmark::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DeleteBookmark(DeleteBookmark::default()),
+            DELETE_BOOKMARK_CODE,
+            &DeleteBookmark::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetStream(GetStream::default()),
             GET_STREAM_CODE,
@@ -618,6 +968,16 @@ mod tests {
             PURGE_TOPIC_CODE,
             &PurgeTopic::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SetTopicThrottle(SetTopicThrottle::default()),
+            SET_TOPIC_THROTTLE_CODE,
+            &SetTopicThrottle::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetPartition(GetPartition::default()),
+            GET_PARTITION_CODE,
+            &GetPartition::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::CreatePartitions(CreatePartitions::default()),
             CREATE_PARTITIONS_CODE,
@@ -628,6 +988,21 @@ mod tests {
             DELETE_PARTITIONS_CODE,
             &DeletePartitions::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdatePartition(UpdatePartition::default()),
+            UPDATE_PARTITION_CODE,
+            &UpdatePartition::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::MovePartition(MovePartition::default()),
+            MOVE_PARTITION_CODE,
+            &MovePartition::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::VerifySegments(VerifySegments::default()),
+            VERIFY_SEGMENTS_CODE,
+            &VerifySegments::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetConsumerGroup(GetConsumerGroup::default()),
             GET_CONSUMER_GROUP_CODE,
@@ -658,11 +1033,86 @@ mod tests {
             LEAVE_CONSUMER_GROUP_CODE,
             &LeaveConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::HeartbeatConsumerGroup(HeartbeatConsumerGroup::default()),
+            HEARTBEAT_CONSUMER_GROUP_CODE,
+            &HeartbeatConsumerGroup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSchema(GetSchema::default()),
+            GET_SCHEMA_CODE,
+            &GetSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSchemas(GetSchemas::default()),
+            GET_SCHEMAS_CODE,
+            &GetSchemas::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSubjectSchema(GetSubjectSchema::default()),
+            GET_SUBJECT_SCHEMA_CODE,
+            &GetSubjectSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RegisterSchema(RegisterSchema::default()),
+            REGISTER_SCHEMA_CODE,
+            &RegisterSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateSchemaCompatibility(UpdateSchemaCompatibility::default()),
+            UPDATE_SCHEMA_COMPATIBILITY_CODE,
+            &UpdateSchemaCompatibility::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetQuotas(GetQuotas::default()),
+            GET_QUOTAS_CODE,
+            &GetQuotas::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SetQuota(SetQuota::default()),
+            SET_QUOTA_CODE,
+            &SetQuota::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::FlushUnsavedBuffer(FlushUnsavedBuffer::default()),
             FLUSH_UNSAVED_BUFFER_CODE,
             &FlushUnsavedBuffer::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RejectMessages(RejectMessages::default()),
+            REJECT_MESSAGES_CODE,
+            &RejectMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::NackMessages(NackMessages::default()),
+            NACK_MESSAGES_CODE,
+            &NackMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::BeginTransaction(BeginTransaction::default()),
+            BEGIN_TRANSACTION_CODE,
+            &BeginTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CommitTransaction(CommitTransaction::default()),
+            COMMIT_TRANSACTION_CODE,
+            &CommitTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::AbortTransaction(AbortTransaction::default()),
+            ABORT_TRANSACTION_CODE,
+            &AbortTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SendOffsetsToTransaction(SendOffsetsToTransaction::default()),
+            SEND_OFFSETS_TO_TRANSACTION_CODE,
+            &SendOffsetsToTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::AckMessages(AckMessages::default()),
+            ACK_MESSAGES_CODE,
+            &AckMessages::default(),
+        );
     }
 
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
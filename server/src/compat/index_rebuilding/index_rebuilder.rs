@@ -16,11 +16,13 @@
  * under the License.
  */
 
+use crate::streaming::segments::INDEX_SIZE;
 use crate::streaming::utils::file;
 use crate::{
     server_error::CompatError, streaming::batching::message_batch::RETAINED_BATCH_HEADER_LEN,
 };
 use std::io::SeekFrom;
+use tokio::fs::File;
 use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader, BufWriter};
 
 // Same struct as RetainedMessageBatch, but without payload
@@ -53,6 +55,8 @@ impl IndexRebuilder {
         let length = reader.read_u32_le().await?;
         let last_offset_delta = reader.read_u32_le().await?;
         let max_timestamp = reader.read_u64_le().await?;
+        // Skip the checksum (4 bytes), it's verified by the log reader
+        let _checksum = reader.read_u32_le().await?;
 
         Ok(BatchHeader {
             base_offset,
@@ -81,21 +85,63 @@ impl IndexRebuilder {
         Ok(())
     }
 
+    /// Checks whether the index file is missing, or doesn't cover all the batches of the log file,
+    /// e.g. because it was truncated or the server stopped before it was written.
+    pub async fn needs_rebuild(&self) -> Result<bool, CompatError> {
+        if !file::exists(&self.index_path).await? {
+            return Ok(true);
+        }
+
+        let index_size = tokio::fs::metadata(&self.index_path).await?.len();
+        let log_size = tokio::fs::metadata(&self.log_path).await?.len();
+        if index_size % INDEX_SIZE != 0 {
+            return Ok(true);
+        }
+
+        if index_size == 0 || log_size == 0 {
+            return Ok(index_size != log_size);
+        }
+
+        // The last index must point to the last batch, which ends exactly at the end of the log file.
+        let mut index = File::open(&self.index_path).await?;
+        index
+            .seek(SeekFrom::Start(index_size - INDEX_SIZE + 4))
+            .await?;
+        let position = index.read_u32_le().await? as u64;
+        if position + RETAINED_BATCH_HEADER_LEN > log_size {
+            return Ok(true);
+        }
+
+        let mut reader = BufReader::new(file::open(&self.log_path).await?);
+        reader.seek(SeekFrom::Start(position)).await?;
+        let header = Self::read_batch_header(&mut reader).await?;
+        Ok(position + RETAINED_BATCH_HEADER_LEN + header.length as u64 != log_size)
+    }
+
     pub async fn rebuild(&self) -> Result<(), CompatError> {
+        let log_size = tokio::fs::metadata(&self.log_path).await?.len();
         let mut reader = BufReader::new(file::open(&self.log_path).await?);
         let mut writer = BufWriter::new(file::overwrite(&self.index_path).await?);
         let mut position = 0;
         let mut next_position;
+        let mut indexes_count = 0;
 
         loop {
             match Self::read_batch_header(&mut reader).await {
                 Ok(header) => {
+                    // The incomplete batch at the end of the log file isn't indexed
+                    if position as u64 + RETAINED_BATCH_HEADER_LEN + header.length as u64 > log_size
+                    {
+                        break;
+                    }
+
                     // Calculate next position before writing current entry
                     next_position = position + RETAINED_BATCH_HEADER_LEN as u32 + header.length;
 
                     // Write index entry using current position
                     Self::write_index_entry(&mut writer, &header, position, self.start_offset)
                         .await?;
+                    indexes_count += 1;
 
                     // Skip batch messages
                     reader.seek(SeekFrom::Current(header.length as i64)).await?;
@@ -109,6 +155,8 @@ impl IndexRebuilder {
         }
 
         writer.flush().await?;
+        // Drop the stale entries left behind by the previous, longer index file
+        writer.get_mut().set_len(indexes_count * INDEX_SIZE).await?;
         Ok(())
     }
 }
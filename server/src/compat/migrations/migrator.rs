@@ -0,0 +1,415 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::compat::migrations::{DataMigration, COMPONENT, MIGRATIONS};
+use crate::configs::system::SystemConfig;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs;
+use tracing::{error, info, warn};
+
+/// Upgrades the data directory created by the older server version to the current layout on startup,
+/// before any of its data is loaded.
+///
+/// The version of the layout is stored in the marker file of the state directory, and the data directory without it
+/// is considered either new, or created before the marker was introduced, if it already contains any data.
+/// Before applying the pending migrations, the data directory is copied to the backup directory,
+/// which is restored if any migration fails, so the server can be started again with the previous version.
+#[derive(Debug)]
+pub struct Migrator {
+    config: Arc<SystemConfig>,
+}
+
+impl Migrator {
+    pub fn new(config: Arc<SystemConfig>) -> Self {
+        Self { config }
+    }
+
+    /// Applies the pending migrations and returns the applied ones.
+    pub async fn migrate(&self) -> Result<Vec<&'static DataMigration>, IggyError> {
+        self.apply(MIGRATIONS).await
+    }
+
+    async fn apply<'a>(
+        &self,
+        migrations: &'a [DataMigration],
+    ) -> Result<Vec<&'a DataMigration>, IggyError> {
+        let current_version = migrations.last().map_or(0, |migration| migration.version);
+        let version = match self.load_version().await? {
+            Some(version) => version,
+            None if self.is_new().await => {
+                self.save_version(current_version).await?;
+                return Ok(Vec::new());
+            }
+            None => 0,
+        };
+
+        if version > current_version {
+            error!("Data directory version: {version} is newer than the supported version: {current_version}, possible downgrade.");
+            return Err(IggyError::UnsupportedDataVersion(version, current_version));
+        }
+
+        let pending = migrations
+            .iter()
+            .filter(|migration| migration.version > version)
+            .collect::<Vec<_>>();
+        if pending.is_empty() {
+            info!("Data directory version: {version} is up to date.");
+            return Ok(pending);
+        }
+
+        let backup_path = format!(
+            "{}/{version}_{}",
+            self.config.get_migration_backup_path(),
+            IggyTimestamp::now().as_micros()
+        );
+        info!(
+            "Migrating data directory from version: {version} to version: {current_version}, backing it up at path: {backup_path}..."
+        );
+        self.backup(&backup_path).await?;
+        for migration in &pending {
+            info!(
+                "Applying migration to version: {}, name: {}...",
+                migration.version, migration.name
+            );
+            if let Err(error) = (migration.apply)(&self.config).await {
+                error!(
+                    "Failed to apply migration to version: {}, name: {}, error: {error}, restoring the backup...",
+                    migration.version, migration.name
+                );
+                self.restore(&backup_path).await?;
+                warn!("Restored data directory version: {version} from the backup.");
+                return Err(IggyError::CannotMigrateData(version, migration.version));
+            }
+        }
+
+        self.save_version(current_version).await?;
+        info!(
+            "Migrated data directory to version: {current_version}, the backup at path: {backup_path} can be removed once the upgrade is verified."
+        );
+        Ok(pending)
+    }
+
+    async fn load_version(&self) -> Result<Option<u32>, IggyError> {
+        let path = self.config.get_data_version_path();
+        let version = match fs::read_to_string(&path).await {
+            Ok(version) => version,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(error) => {
+                error!(
+                    "{COMPONENT} (error: {error}) - failed to read data version at path: {path}"
+                );
+                return Err(IggyError::CannotReadFile);
+            }
+        };
+        version
+            .trim()
+            .parse::<u32>()
+            .map(Some)
+            .map_err(|_| IggyError::InvalidVersion(version))
+    }
+
+    async fn save_version(&self, version: u32) -> Result<(), IggyError> {
+        let state_path = self.config.get_state_path();
+        fs::create_dir_all(&state_path)
+            .await
+            .map_err(|_| IggyError::CannotCreateStateDirectory(state_path))?;
+        let path = self.config.get_data_version_path();
+        let temp_path = format!("{path}.tmp");
+        fs::write(&temp_path, version.to_string())
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to write data version at path: {temp_path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        fs::rename(&temp_path, &path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to rename data version file to path: {path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)
+    }
+
+    /// The data directory is new, when neither the state nor the streams have been created yet.
+    async fn is_new(&self) -> bool {
+        !Path::new(&self.config.get_state_log_path()).exists()
+            && !Path::new(&self.config.get_streams_path()).exists()
+    }
+
+    /// Copies the data directory without the backups and the runtime files to the backup path.
+    /// The partitions moved to the other data directories are backed up as the links only.
+    async fn backup(&self, backup_path: &str) -> Result<(), IggyError> {
+        fs::create_dir_all(backup_path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create backup directory at path: {backup_path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        for path in self.list_data().await? {
+            let target_path = Path::new(backup_path).join(path.file_name().unwrap());
+            copy(&path, &target_path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to back up path: {}",
+                        path.display()
+                    )
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the data directory, except the backups and the runtime files, with the backup.
+    async fn restore(&self, backup_path: &str) -> Result<(), IggyError> {
+        for path in self.list_data().await? {
+            let metadata = fs::symlink_metadata(&path)
+                .await
+                .map_err(|_| IggyError::CannotReadFileMetadata)?;
+            let result = if metadata.is_dir() {
+                fs::remove_dir_all(&path).await
+            } else {
+                fs::remove_file(&path).await
+            };
+            result
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to remove path: {}",
+                        path.display()
+                    )
+                })
+                .map_err(|_| IggyError::CannotDeleteFile)?;
+        }
+
+        let mut entries = fs::read_dir(backup_path)
+            .await
+            .map_err(|_| IggyError::CannotReadFile)?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|_| IggyError::CannotReadFile)?
+        {
+            let target_path = Path::new(&self.config.get_system_path()).join(entry.file_name());
+            copy(&entry.path(), &target_path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to restore path: {}",
+                        target_path.display()
+                    )
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+        }
+        Ok(())
+    }
+
+    /// Lists the top level entries of the data directory, except the backups and the runtime files.
+    async fn list_data(&self) -> Result<Vec<PathBuf>, IggyError> {
+        let excluded_paths = [
+            PathBuf::from(self.config.get_backup_path()),
+            PathBuf::from(self.config.get_runtime_path()),
+        ];
+        let mut paths = Vec::new();
+        let mut entries = fs::read_dir(self.config.get_system_path())
+            .await
+            .map_err(|_| IggyError::CannotReadFile)?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|_| IggyError::CannotReadFile)?
+        {
+            let path = entry.path();
+            if !excluded_paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+        Ok(paths)
+    }
+}
+
+/// Copies the file or the whole directory, preserving the links instead of following them.
+async fn copy(source: &Path, target: &Path) -> std::io::Result<()> {
+    let mut paths = vec![(source.to_path_buf(), target.to_path_buf())];
+    while let Some((source, target)) = paths.pop() {
+        let metadata = fs::symlink_metadata(&source).await?;
+        if metadata.is_symlink() {
+            fs::symlink(fs::read_link(&source).await?, &target).await?;
+        } else if metadata.is_dir() {
+            fs::create_dir_all(&target).await?;
+            let mut entries = fs::read_dir(&source).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                paths.push((entry.path(), target.join(entry.file_name())));
+            }
+        } else {
+            fs::copy(&source, &target).await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future::BoxFuture;
+    use tempfile::TempDir;
+
+    const MIGRATIONS: &[DataMigration] = &[
+        DataMigration {
+            version: 1,
+            name: "first",
+            apply: create_first_file,
+        },
+        DataMigration {
+            version: 2,
+            name: "second",
+            apply: create_second_file,
+        },
+    ];
+
+    const FAILING_MIGRATIONS: &[DataMigration] = &[
+        DataMigration {
+            version: 1,
+            name: "first",
+            apply: create_first_file,
+        },
+        DataMigration {
+            version: 2,
+            name: "failing",
+            apply: fail,
+        },
+    ];
+
+    fn create_first_file(config: &SystemConfig) -> BoxFuture<'_, Result<(), IggyError>> {
+        Box::pin(async move {
+            fs::write(format!("{}/first", config.get_streams_path()), "1")
+                .await
+                .map_err(|_| IggyError::CannotWriteToFile)
+        })
+    }
+
+    fn create_second_file(config: &SystemConfig) -> BoxFuture<'_, Result<(), IggyError>> {
+        Box::pin(async move {
+            fs::write(format!("{}/second", config.get_streams_path()), "2")
+                .await
+                .map_err(|_| IggyError::CannotWriteToFile)
+        })
+    }
+
+    fn fail(_: &SystemConfig) -> BoxFuture<'_, Result<(), IggyError>> {
+        Box::pin(async { Err(IggyError::CannotReadFile) })
+    }
+
+    #[tokio::test]
+    async fn should_mark_new_data_directory_with_current_version() {
+        let (_dir, migrator) = create_migrator(false).await;
+
+        let applied = migrator.apply(MIGRATIONS).await.unwrap();
+
+        assert!(applied.is_empty());
+        assert_eq!(migrator.load_version().await.unwrap(), Some(2));
+        assert!(!Path::new(&migrator.config.get_backup_path()).exists());
+    }
+
+    #[tokio::test]
+    async fn should_apply_all_migrations_to_legacy_data_directory() {
+        let (_dir, migrator) = create_migrator(true).await;
+
+        let applied = migrator.apply(MIGRATIONS).await.unwrap();
+
+        assert_eq!(applied.len(), 2);
+        assert_eq!(migrator.load_version().await.unwrap(), Some(2));
+        let streams_path = migrator.config.get_streams_path();
+        assert!(Path::new(&format!("{streams_path}/first")).exists());
+        assert!(Path::new(&format!("{streams_path}/second")).exists());
+        assert!(Path::new(&format!("{streams_path}/data")).exists());
+        let backup = single_backup(&migrator).await;
+        assert!(backup.join("streams/data").exists());
+        assert!(!backup.join("streams/first").exists());
+    }
+
+    #[tokio::test]
+    async fn should_apply_only_pending_migrations() {
+        let (_dir, migrator) = create_migrator(true).await;
+        migrator.save_version(1).await.unwrap();
+
+        let applied = migrator.apply(MIGRATIONS).await.unwrap();
+
+        assert_eq!(applied.len(), 1);
+        assert_eq!(applied[0].name, "second");
+        let streams_path = migrator.config.get_streams_path();
+        assert!(!Path::new(&format!("{streams_path}/first")).exists());
+        assert!(Path::new(&format!("{streams_path}/second")).exists());
+
+        let applied = migrator.apply(MIGRATIONS).await.unwrap();
+        assert!(applied.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_restore_backup_when_migration_fails() {
+        let (_dir, migrator) = create_migrator(true).await;
+
+        let result = migrator.apply(FAILING_MIGRATIONS).await;
+
+        assert!(matches!(result, Err(IggyError::CannotMigrateData(0, 2))));
+        assert_eq!(migrator.load_version().await.unwrap(), None);
+        let streams_path = migrator.config.get_streams_path();
+        assert!(!Path::new(&format!("{streams_path}/first")).exists());
+        assert!(Path::new(&format!("{streams_path}/data")).exists());
+    }
+
+    #[tokio::test]
+    async fn should_fail_when_data_directory_is_newer() {
+        let (_dir, migrator) = create_migrator(true).await;
+        migrator.save_version(3).await.unwrap();
+
+        let result = migrator.apply(MIGRATIONS).await;
+
+        assert!(matches!(
+            result,
+            Err(IggyError::UnsupportedDataVersion(3, 2))
+        ));
+    }
+
+    async fn create_migrator(with_data: bool) -> (TempDir, Migrator) {
+        let dir = TempDir::new().unwrap();
+        let config = Arc::new(SystemConfig {
+            path: dir.path().to_str().unwrap().to_string(),
+            ..Default::default()
+        });
+        if with_data {
+            fs::create_dir_all(config.get_streams_path()).await.unwrap();
+            fs::write(format!("{}/data", config.get_streams_path()), "data")
+                .await
+                .unwrap();
+        }
+        (dir, Migrator::new(config))
+    }
+
+    async fn single_backup(migrator: &Migrator) -> PathBuf {
+        let mut entries = fs::read_dir(migrator.config.get_migration_backup_path())
+            .await
+            .unwrap();
+        let backup = entries.next_entry().await.unwrap().unwrap().path();
+        assert!(entries.next_entry().await.unwrap().is_none());
+        backup
+    }
+}
@@ -0,0 +1,41 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+pub mod migrator;
+mod time_indexes;
+
+use crate::configs::system::SystemConfig;
+use futures::future::BoxFuture;
+use iggy::error::IggyError;
+
+pub const COMPONENT: &str = "COMPAT_MIGRATIONS";
+
+/// The single step upgrading the data directory from the previous version of its layout to `version`.
+pub struct DataMigration {
+    pub version: u32,
+    pub name: &'static str,
+    pub apply: fn(&SystemConfig) -> BoxFuture<'_, Result<(), IggyError>>,
+}
+
+/// All the migrations ordered by their versions, the last one defines the current version of the data directory layout.
+/// The migrations must never be removed or reordered, as the version is persisted in the data directory.
+pub const MIGRATIONS: &[DataMigration] = &[DataMigration {
+    version: 1,
+    name: "rebuild_legacy_time_indexes",
+    apply: time_indexes::rebuild_legacy_time_indexes,
+}];
@@ -0,0 +1,116 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::compat::index_rebuilding::index_rebuilder::IndexRebuilder;
+use crate::compat::migrations::COMPONENT;
+use crate::configs::system::SystemConfig;
+use crate::streaming::segments::{INDEX_EXTENSION, LOG_EXTENSION};
+use error_set::ErrContext;
+use futures::future::BoxFuture;
+use iggy::error::IggyError;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tracing::info;
+
+const TIME_INDEX_EXTENSION: &str = "timeindex";
+
+/// Before the timestamps were stored in the offset indexes, each segment had the separate time index,
+/// so the offset indexes of such segments are rebuilt from their logs, and the legacy time indexes are removed.
+pub fn rebuild_legacy_time_indexes(config: &SystemConfig) -> BoxFuture<'_, Result<(), IggyError>> {
+    Box::pin(async move {
+        let streams_path = config.get_streams_path();
+        if !Path::new(&streams_path).exists() {
+            return Ok(());
+        }
+
+        let mut rebuilt_indexes = 0;
+        for time_index_path in find_time_indexes(&streams_path).await? {
+            let segment_path = time_index_path.with_extension("");
+            let Some(start_offset) = segment_path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.parse::<u64>().ok())
+            else {
+                continue;
+            };
+
+            let log_path = time_index_path.with_extension(LOG_EXTENSION);
+            if log_path.exists() {
+                let index_path = time_index_path.with_extension(INDEX_EXTENSION);
+                IndexRebuilder::new(
+                    log_path.to_string_lossy().to_string(),
+                    index_path.to_string_lossy().to_string(),
+                    start_offset,
+                )
+                .rebuild()
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to rebuild index at path: {}",
+                        index_path.display()
+                    )
+                })
+                .map_err(|_| IggyError::CannotReadFile)?;
+                rebuilt_indexes += 1;
+            }
+
+            fs::remove_file(&time_index_path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to remove legacy time index at path: {}",
+                        time_index_path.display()
+                    )
+                })
+                .map_err(|_| IggyError::CannotDeleteFile)?;
+        }
+
+        info!("Rebuilt {rebuilt_indexes} indexes with the legacy time indexes.");
+        Ok(())
+    })
+}
+
+/// Finds the legacy time indexes of all the segments, including the partitions moved to the other data directories.
+async fn find_time_indexes(streams_path: &str) -> Result<Vec<PathBuf>, IggyError> {
+    let mut time_indexes = Vec::new();
+    let mut directories = vec![PathBuf::from(streams_path)];
+    while let Some(directory) = directories.pop() {
+        let mut entries = fs::read_dir(&directory)
+            .await
+            .map_err(|_| IggyError::CannotReadFile)?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|_| IggyError::CannotReadFile)?
+        {
+            let path = entry.path();
+            let metadata = fs::metadata(&path)
+                .await
+                .map_err(|_| IggyError::CannotReadFileMetadata)?;
+            if metadata.is_dir() {
+                directories.push(path);
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == TIME_INDEX_EXTENSION)
+            {
+                time_indexes.push(path);
+            }
+        }
+    }
+    Ok(time_indexes)
+}
@@ -17,3 +17,4 @@
  */
 
 pub mod index_rebuilding;
+pub mod migrations;
@@ -0,0 +1,104 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use iggy::utils::duration::IggyDuration;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use serde_with::DisplayFromStr;
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ClusterConfig {
+    pub enabled: bool,
+    pub id: u32,
+    pub address: String,
+    pub client_address: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub election_timeout: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub heartbeat_interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub replication_timeout: IggyDuration,
+    pub min_insync_replicas: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub replica_fetch_interval: IggyDuration,
+    pub replica_fetch_messages: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub replica_max_lag: IggyDuration,
+    pub secret: String,
+    pub tls: ClusterTlsConfig,
+    #[serde(default)]
+    pub nodes: Vec<ClusterNodeConfig>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ClusterTlsConfig {
+    pub enabled: bool,
+    pub cert_file: String,
+    pub key_file: String,
+    pub ca_file: String,
+    pub domain: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ClusterNodeConfig {
+    pub id: u32,
+    pub address: String,
+    pub client_address: String,
+}
+
+impl ClusterConfig {
+    /// Returns the number of nodes (including this one) required to elect the leader or commit the entry.
+    pub fn quorum(&self) -> usize {
+        (self.nodes.len() + 1) / 2 + 1
+    }
+
+    /// Returns the IDs of all the nodes (including this one), sorted, so the partition replicas are assigned the same way on each node.
+    pub fn node_ids(&self) -> Vec<u32> {
+        let mut node_ids = self.nodes.iter().map(|node| node.id).collect::<Vec<_>>();
+        node_ids.push(self.id);
+        node_ids.sort_unstable();
+        node_ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(nodes_count: u32) -> ClusterConfig {
+        ClusterConfig {
+            nodes: (2..nodes_count + 2)
+                .map(|id| ClusterNodeConfig {
+                    id,
+                    address: format!("127.0.0.1:{}", 8094 + id),
+                    client_address: format!("127.0.0.1:{}", 8089 + id),
+                })
+                .collect(),
+            ..ClusterConfig::default()
+        }
+    }
+
+    #[test]
+    fn quorum_should_be_the_majority_of_the_nodes() {
+        assert_eq!(config(0).quorum(), 1);
+        assert_eq!(config(1).quorum(), 2);
+        assert_eq!(config(2).quorum(), 2);
+        assert_eq!(config(3).quorum(), 3);
+        assert_eq!(config(4).quorum(), 3);
+    }
+}
@@ -17,28 +17,36 @@
  */
 
 use crate::configs::server::ServerConfig;
+use crate::configs::sources::ConfigSources;
 use crate::server_error::ConfigError;
 use crate::IGGY_ROOT_PASSWORD_ENV;
 use figment::{
     providers::{Format, Toml},
     value::{Dict, Map as FigmentMap, Tag, Value as FigmentValue},
-    Error, Figment, Metadata, Profile, Provider,
+    Error, Figment, Metadata, Profile, Provider, Source,
 };
+use iggy::models::config_value::ConfigSource;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 use std::{env, future::Future, path::Path};
 use toml::{map::Map, Value as TomlValue};
-use tracing::debug;
+use tracing::{debug, info};
 
 const DEFAULT_CONFIG_PROVIDER: &str = "file";
 const DEFAULT_CONFIG_PATH: &str = "configs/server.toml";
-const SECRET_KEYS: [&str; 6] = [
+const RUNTIME_CONFIG_EXTENSION: &str = "runtime.toml";
+const ENV_CONFIG_PROVIDER_NAME: &str = "iggy-server config";
+const SECRET_KEYS: [&str; 7] = [
     IGGY_ROOT_PASSWORD_ENV,
     "IGGY_DATA_MAINTENANCE_ARCHIVER_S3_KEY_SECRET",
     "IGGY_HTTP_JWT_ENCODING_SECRET",
     "IGGY_HTTP_JWT_DECODING_SECRET",
     "IGGY_TCP_TLS_PASSWORD",
     "IGGY_SYSTEM_ENCRYPTION_KEY",
+    "IGGY_SYSTEM_BACKUP_ARCHIVE_S3_KEY_SECRET",
 ];
 
+#[derive(Debug)]
 pub enum ConfigProviderKind {
     File(FileConfigProvider),
 }
@@ -49,10 +57,26 @@ impl ConfigProviderKind {
             Self::File(p) => p.load_config().await,
         }
     }
+
+    pub async fn save_runtime_values(
+        &self,
+        values: &BTreeMap<String, String>,
+    ) -> Result<(), ConfigError> {
+        match self {
+            Self::File(p) => p.save_runtime_values(values).await,
+        }
+    }
 }
 
 pub trait ConfigProvider {
     fn load_config(&self) -> impl Future<Output = Result<ServerConfig, ConfigError>>;
+
+    /// Persists the configuration values changed at runtime, keyed by the dotted path, e.g. `system.cache.size`,
+    /// so they are applied again once the server is restarted.
+    fn save_runtime_values(
+        &self,
+        values: &BTreeMap<String, String>,
+    ) -> impl Future<Output = Result<(), ConfigError>>;
 }
 
 #[derive(Debug)]
@@ -68,6 +92,11 @@ impl FileConfigProvider {
     pub fn new(path: String) -> Self {
         Self { path }
     }
+
+    /// The values changed at runtime are kept next to the configuration file, e.g. `configs/server.runtime.toml`.
+    fn get_runtime_path(&self) -> PathBuf {
+        Path::new(&self.path).with_extension(RUNTIME_CONFIG_EXTENSION)
+    }
 }
 
 impl CustomEnvProvider {
@@ -202,7 +231,7 @@ impl CustomEnvProvider {
 
 impl Provider for CustomEnvProvider {
     fn metadata(&self) -> Metadata {
-        Metadata::named("iggy-server config")
+        Metadata::named(ENV_CONFIG_PROVIDER_NAME)
     }
 
     fn data(&self) -> Result<FigmentMap<Profile, Dict>, Error> {
@@ -257,6 +286,25 @@ pub fn resolve(config_provider_type: &str) -> Result<ConfigProviderKind, ConfigE
     }
 }
 
+/// Resolves the source of each configuration value from the metadata of the provider which has set it.
+fn resolve_sources(figment: &Figment, config: &ServerConfig, runtime_path: &Path) -> ConfigSources {
+    let mut sources = ConfigSources::default();
+    for key in config.get_keys() {
+        let Some(metadata) = figment.find_metadata(&key) else {
+            continue;
+        };
+
+        let source = match &metadata.source {
+            _ if metadata.name == ENV_CONFIG_PROVIDER_NAME => ConfigSource::Environment,
+            Some(Source::File(path)) if path.ends_with(runtime_path) => ConfigSource::Runtime,
+            Some(Source::File(_)) => ConfigSource::File,
+            _ => ConfigSource::Default,
+        };
+        sources.set(&key, source);
+    }
+    sources
+}
+
 /// This does exactly the same as Figment does internally.
 fn file_exists<P: AsRef<Path>>(path: P) -> bool {
     let path = path.as_ref();
@@ -308,11 +356,22 @@ impl ConfigProvider for FileConfigProvider {
         // Merge environment variables into the configuration
         config_builder = config_builder.merge(CustomEnvProvider::new("IGGY_"));
 
+        // The values changed at runtime take precedence over all the other sources
+        let runtime_path = self.get_runtime_path();
+        if file_exists(&runtime_path) {
+            println!(
+                "Found runtime configuration file at path: '{}'.",
+                runtime_path.display()
+            );
+            config_builder = config_builder.merge(Toml::file(&runtime_path));
+        }
+
         // Finally, attempt to extract the final configuration
         let config_result: Result<ServerConfig, figment::Error> = config_builder.extract();
 
         match config_result {
-            Ok(config) => {
+            Ok(mut config) => {
+                config.sources = resolve_sources(&config_builder, &config, &runtime_path);
                 println!("Config loaded successfully.");
                 println!("Using Config: {config}");
                 Ok(config)
@@ -320,4 +379,46 @@ impl ConfigProvider for FileConfigProvider {
             Err(_) => Err(ConfigError::CannotLoadConfiguration),
         }
     }
+
+    async fn save_runtime_values(
+        &self,
+        values: &BTreeMap<String, String>,
+    ) -> Result<(), ConfigError> {
+        let runtime_path = self.get_runtime_path();
+        let mut table = match tokio::fs::read_to_string(&runtime_path).await {
+            Ok(content) => toml::from_str::<Map<String, TomlValue>>(&content)
+                .map_err(|_| ConfigError::CannotSaveConfiguration)?,
+            Err(_) => Map::new(),
+        };
+        for (key, value) in values {
+            insert_dotted_value(&mut table, key, value);
+        }
+
+        let content = toml::to_string(&table).map_err(|_| ConfigError::CannotSaveConfiguration)?;
+        tokio::fs::write(&runtime_path, content)
+            .await
+            .map_err(|_| ConfigError::CannotSaveConfiguration)?;
+        info!(
+            "Saved the configuration values changed at runtime to: '{}'.",
+            runtime_path.display()
+        );
+        Ok(())
+    }
+}
+
+fn insert_dotted_value(table: &mut Map<String, TomlValue>, key: &str, value: &str) {
+    let Some((section, key)) = key.split_once('.') else {
+        table.insert(key.to_owned(), TomlValue::String(value.to_owned()));
+        return;
+    };
+
+    let entry = table
+        .entry(section.to_owned())
+        .or_insert(TomlValue::Table(Map::new()));
+    if !entry.is_table() {
+        *entry = TomlValue::Table(Map::new());
+    }
+    if let TomlValue::Table(inner_table) = entry {
+        insert_dotted_value(inner_table, key, value);
+    }
 }
@@ -16,25 +16,38 @@
  * under the License.
  */
 
+use iggy::models::quota::QuotaLimits;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 
+use crate::configs::cluster::{ClusterConfig, ClusterTlsConfig};
 use crate::configs::http::{
-    HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
+    HttpConfig, HttpCorsConfig, HttpCorsRouteConfig, HttpJwtConfig, HttpMetricsConfig,
+    HttpRateLimitConfig, HttpRateLimitQuotaConfig, HttpSecurityHeadersConfig, HttpTlsConfig,
+    HttpWebSocketConfig,
 };
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    ArchiverConfig, DataMaintenanceConfig, HeartbeatConfig, MessageSaverConfig,
+    ArchiverConfig, BackgroundIoConfig, DataMaintenanceConfig, HeartbeatConfig, MessageSaverConfig,
     MessagesMaintenanceConfig, PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig,
-    ServerConfig, StateMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig,
-    TelemetryTracesConfig,
+    ProxyConfig, ServerConfig, ShutdownConfig, StateMaintenanceConfig, TelemetryConfig,
+    TelemetryLogsConfig, TelemetryTracesConfig,
 };
+use crate::configs::sources::ConfigSources;
+use crate::configs::standby::{StandbyConfig, StandbyShippingConfig};
 use crate::configs::system::{
-    BackupConfig, CacheConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig,
-    LoggingConfig, MessageDeduplicationConfig, PartitionConfig, RecoveryConfig, RuntimeConfig,
-    SegmentConfig, StateConfig, StreamConfig, SystemConfig, TopicConfig,
+    AuditConfig, AuditFileSinkConfig, AuditSyslogSinkConfig, AuditTopicSinkConfig,
+    AuthorizationConfig, BackupArchiveConfig, BackupConfig, BufferPoolConfig, CacheConfig,
+    CertificateAuthConfig, ClientAccessConfig, CompatibilityConfig, CompressionConfig,
+    ConsumerGroupConfig, ConsumerGroupSloConfig, EncryptionConfig, LoggingConfig,
+    MessageDeduplicationConfig, MigrationConfig, OpaAuthorizerConfig, PartitionConfig,
+    PolicyAuthorizerConfig, PollingConfig, QuotasConfig, RecoveryConfig, RequestLanesConfig,
+    RuntimeConfig, SegmentConfig, SegmentEncryptionConfig, StateConfig, StorageLimits,
+    StorageUsageConfig, StreamConfig, SystemConfig, TenancyConfig, TieringConfig, TieringS3Config,
+    TopicConfig,
 };
 use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -50,6 +63,10 @@ impl Default for ServerConfig {
         ServerConfig {
             data_maintenance: DataMaintenanceConfig::default(),
             heartbeat: HeartbeatConfig::default(),
+            shutdown: ShutdownConfig::default(),
+            proxy: ProxyConfig::default(),
+            cluster: ClusterConfig::default(),
+            standby: StandbyConfig::default(),
             message_saver: MessageSaverConfig::default(),
             personal_access_token: PersonalAccessTokenConfig::default(),
             system: Arc::new(SystemConfig::default()),
@@ -57,6 +74,7 @@ impl Default for ServerConfig {
             tcp: TcpConfig::default(),
             http: HttpConfig::default(),
             telemetry: TelemetryConfig::default(),
+            sources: ConfigSources::default(),
         }
     }
 }
@@ -82,12 +100,23 @@ impl Default for MessagesMaintenanceConfig {
         MessagesMaintenanceConfig {
             archiver_enabled: SERVER_CONFIG.data_maintenance.messages.archiver_enabled,
             cleaner_enabled: SERVER_CONFIG.data_maintenance.messages.cleaner_enabled,
+            compactor_enabled: SERVER_CONFIG.data_maintenance.messages.compactor_enabled,
+            archive_before_delete: SERVER_CONFIG
+                .data_maintenance
+                .messages
+                .archive_before_delete,
             interval: SERVER_CONFIG
                 .data_maintenance
                 .messages
                 .interval
                 .parse()
                 .unwrap(),
+            retention_hook_timeout: SERVER_CONFIG
+                .data_maintenance
+                .messages
+                .retention_hook_timeout
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -107,6 +136,20 @@ impl Default for StateMaintenanceConfig {
     }
 }
 
+impl Default for BackgroundIoConfig {
+    fn default() -> BackgroundIoConfig {
+        BackgroundIoConfig {
+            throttling_enabled: SERVER_CONFIG.data_maintenance.io.throttling_enabled,
+            max_throughput: SERVER_CONFIG
+                .data_maintenance
+                .io
+                .max_throughput
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
 impl Default for QuicConfig {
     fn default() -> QuicConfig {
         QuicConfig {
@@ -134,6 +177,13 @@ impl Default for QuicCertificateConfig {
             self_signed: SERVER_CONFIG.quic.certificate.self_signed,
             cert_file: SERVER_CONFIG.quic.certificate.cert_file.parse().unwrap(),
             key_file: SERVER_CONFIG.quic.certificate.key_file.parse().unwrap(),
+            client_certificates: SERVER_CONFIG.quic.certificate.client_certificates,
+            reload_interval: SERVER_CONFIG
+                .quic
+                .certificate
+                .reload_interval
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -156,6 +206,8 @@ impl Default for TcpTlsConfig {
             enabled: SERVER_CONFIG.tcp.tls.enabled,
             certificate: SERVER_CONFIG.tcp.tls.certificate.parse().unwrap(),
             password: SERVER_CONFIG.tcp.tls.password.parse().unwrap(),
+            client_certificates: SERVER_CONFIG.tcp.tls.client_certificates,
+            reload_interval: SERVER_CONFIG.tcp.tls.reload_interval.parse().unwrap(),
         }
     }
 }
@@ -180,8 +232,11 @@ impl Default for HttpConfig {
             address: SERVER_CONFIG.http.address.parse().unwrap(),
             max_request_size: SERVER_CONFIG.http.max_request_size.parse().unwrap(),
             cors: HttpCorsConfig::default(),
+            security_headers: HttpSecurityHeadersConfig::default(),
+            rate_limit: HttpRateLimitConfig::default(),
             jwt: HttpJwtConfig::default(),
             metrics: HttpMetricsConfig::default(),
+            websocket: HttpWebSocketConfig::default(),
             tls: HttpTlsConfig::default(),
         }
     }
@@ -221,6 +276,94 @@ impl Default for HttpCorsConfig {
                 .collect(),
             allow_credentials: SERVER_CONFIG.http.cors.allow_credentials,
             allow_private_network: SERVER_CONFIG.http.cors.allow_private_network,
+            max_age: SERVER_CONFIG.http.cors.max_age.parse().unwrap(),
+            health: HttpCorsRouteConfig::default(),
+        }
+    }
+}
+
+impl Default for HttpCorsRouteConfig {
+    fn default() -> HttpCorsRouteConfig {
+        HttpCorsRouteConfig {
+            enabled: SERVER_CONFIG.http.cors.health.enabled,
+            allowed_origins: SERVER_CONFIG
+                .http
+                .cors
+                .health
+                .allowed_origins
+                .iter()
+                .map(|s| s.parse().unwrap())
+                .collect(),
+            allowed_methods: SERVER_CONFIG
+                .http
+                .cors
+                .health
+                .allowed_methods
+                .iter()
+                .map(|s| s.parse().unwrap())
+                .collect(),
+        }
+    }
+}
+
+impl Default for HttpSecurityHeadersConfig {
+    fn default() -> HttpSecurityHeadersConfig {
+        HttpSecurityHeadersConfig {
+            enabled: SERVER_CONFIG.http.security_headers.enabled,
+            content_type_options: SERVER_CONFIG
+                .http
+                .security_headers
+                .content_type_options
+                .parse()
+                .unwrap(),
+            frame_options: SERVER_CONFIG
+                .http
+                .security_headers
+                .frame_options
+                .parse()
+                .unwrap(),
+            referrer_policy: SERVER_CONFIG
+                .http
+                .security_headers
+                .referrer_policy
+                .parse()
+                .unwrap(),
+            content_security_policy: SERVER_CONFIG
+                .http
+                .security_headers
+                .content_security_policy
+                .parse()
+                .unwrap(),
+            strict_transport_security: SERVER_CONFIG
+                .http
+                .security_headers
+                .strict_transport_security
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for HttpRateLimitConfig {
+    fn default() -> HttpRateLimitConfig {
+        HttpRateLimitConfig {
+            enabled: SERVER_CONFIG.http.rate_limit.enabled,
+            cleanup_interval: SERVER_CONFIG
+                .http
+                .rate_limit
+                .cleanup_interval
+                .parse()
+                .unwrap(),
+            ip: HttpRateLimitQuotaConfig {
+                enabled: SERVER_CONFIG.http.rate_limit.ip.enabled,
+                requests_per_second: SERVER_CONFIG.http.rate_limit.ip.requests_per_second as u32,
+                burst: SERVER_CONFIG.http.rate_limit.ip.burst as u32,
+            },
+            user: HttpRateLimitQuotaConfig {
+                enabled: SERVER_CONFIG.http.rate_limit.user.enabled,
+                requests_per_second: SERVER_CONFIG.http.rate_limit.user.requests_per_second as u32,
+                burst: SERVER_CONFIG.http.rate_limit.user.burst as u32,
+            },
         }
     }
 }
@@ -251,6 +394,7 @@ impl Default for HttpJwtConfig {
             encoding_secret: SERVER_CONFIG.http.jwt.encoding_secret.parse().unwrap(),
             decoding_secret: SERVER_CONFIG.http.jwt.decoding_secret.parse().unwrap(),
             use_base64_secret: SERVER_CONFIG.http.jwt.use_base_64_secret,
+            providers: Vec::new(),
         }
     }
 }
@@ -264,12 +408,23 @@ impl Default for HttpMetricsConfig {
     }
 }
 
+impl Default for HttpWebSocketConfig {
+    fn default() -> HttpWebSocketConfig {
+        HttpWebSocketConfig {
+            enabled: SERVER_CONFIG.http.websocket.enabled,
+            max_requests_per_second: SERVER_CONFIG.http.websocket.max_requests_per_second as u32,
+            poll_interval: SERVER_CONFIG.http.websocket.poll_interval.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for HttpTlsConfig {
     fn default() -> HttpTlsConfig {
         HttpTlsConfig {
             enabled: SERVER_CONFIG.http.tls.enabled,
             cert_file: SERVER_CONFIG.http.tls.cert_file.parse().unwrap(),
             key_file: SERVER_CONFIG.http.tls.key_file.parse().unwrap(),
+            reload_interval: SERVER_CONFIG.http.tls.reload_interval.parse().unwrap(),
         }
     }
 }
@@ -317,6 +472,7 @@ impl Default for SystemConfig {
             cache: CacheConfig::default(),
             stream: StreamConfig::default(),
             encryption: EncryptionConfig::default(),
+            segment_encryption: SegmentEncryptionConfig::default(),
             topic: TopicConfig::default(),
             partition: PartitionConfig::default(),
             segment: SegmentConfig::default(),
@@ -324,6 +480,18 @@ impl Default for SystemConfig {
             compression: CompressionConfig::default(),
             message_deduplication: MessageDeduplicationConfig::default(),
             recovery: RecoveryConfig::default(),
+            consumer_group: ConsumerGroupConfig::default(),
+            client_access: ClientAccessConfig::default(),
+            certificate_auth: CertificateAuthConfig::default(),
+            polling: PollingConfig::default(),
+            tiering: TieringConfig::default(),
+            audit: AuditConfig::default(),
+            authorization: AuthorizationConfig::default(),
+            tenancy: TenancyConfig::default(),
+            quotas: QuotasConfig::default(),
+            storage_usage: StorageUsageConfig::default(),
+            request_lanes: RequestLanesConfig::default(),
+            buffer_pool: BufferPoolConfig::default(),
         }
     }
 }
@@ -333,6 +501,8 @@ impl Default for BackupConfig {
         BackupConfig {
             path: SERVER_CONFIG.system.backup.path.parse().unwrap(),
             compatibility: CompatibilityConfig::default(),
+            migration: MigrationConfig::default(),
+            archive: BackupArchiveConfig::default(),
         }
     }
 }
@@ -351,6 +521,31 @@ impl Default for CompatibilityConfig {
     }
 }
 
+impl Default for MigrationConfig {
+    fn default() -> Self {
+        MigrationConfig {
+            path: SERVER_CONFIG.system.backup.migration.path.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for BackupArchiveConfig {
+    fn default() -> Self {
+        let archive = &SERVER_CONFIG.system.backup.archive;
+        BackupArchiveConfig {
+            path: archive.path.parse().unwrap(),
+            s3_prefix: archive.s_3_prefix.parse().unwrap(),
+            s3: TieringS3Config {
+                key_id: archive.s_3.key_id.parse().unwrap(),
+                key_secret: archive.s_3.key_secret.parse().unwrap(),
+                bucket: archive.s_3.bucket.parse().unwrap(),
+                endpoint: Some(archive.s_3.endpoint.parse().unwrap()),
+                region: Some(archive.s_3.region.parse().unwrap()),
+            },
+        }
+    }
+}
+
 impl Default for HeartbeatConfig {
     fn default() -> HeartbeatConfig {
         HeartbeatConfig {
@@ -360,6 +555,86 @@ impl Default for HeartbeatConfig {
     }
 }
 
+impl Default for ShutdownConfig {
+    fn default() -> ShutdownConfig {
+        ShutdownConfig {
+            timeout: SERVER_CONFIG.shutdown.timeout.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for ProxyConfig {
+    fn default() -> ProxyConfig {
+        ProxyConfig {
+            address: SERVER_CONFIG.proxy.address.parse().unwrap(),
+            upstream_address: SERVER_CONFIG.proxy.upstream_address.parse().unwrap(),
+            latency: SERVER_CONFIG.proxy.latency.parse().unwrap(),
+            jitter: SERVER_CONFIG.proxy.jitter.parse().unwrap(),
+            reset_probability: SERVER_CONFIG.proxy.reset_probability,
+        }
+    }
+}
+
+impl Default for ClusterConfig {
+    fn default() -> ClusterConfig {
+        ClusterConfig {
+            enabled: SERVER_CONFIG.cluster.enabled,
+            id: SERVER_CONFIG.cluster.id as u32,
+            address: SERVER_CONFIG.cluster.address.parse().unwrap(),
+            client_address: SERVER_CONFIG.cluster.client_address.parse().unwrap(),
+            election_timeout: SERVER_CONFIG.cluster.election_timeout.parse().unwrap(),
+            heartbeat_interval: SERVER_CONFIG.cluster.heartbeat_interval.parse().unwrap(),
+            replication_timeout: SERVER_CONFIG.cluster.replication_timeout.parse().unwrap(),
+            min_insync_replicas: SERVER_CONFIG.cluster.min_insync_replicas as u32,
+            replica_fetch_interval: SERVER_CONFIG
+                .cluster
+                .replica_fetch_interval
+                .parse()
+                .unwrap(),
+            replica_fetch_messages: SERVER_CONFIG.cluster.replica_fetch_messages as u32,
+            replica_max_lag: SERVER_CONFIG.cluster.replica_max_lag.parse().unwrap(),
+            secret: SERVER_CONFIG.cluster.secret.parse().unwrap(),
+            tls: ClusterTlsConfig::default(),
+            nodes: Vec::new(),
+        }
+    }
+}
+
+impl Default for ClusterTlsConfig {
+    fn default() -> ClusterTlsConfig {
+        ClusterTlsConfig {
+            enabled: SERVER_CONFIG.cluster.tls.enabled,
+            cert_file: SERVER_CONFIG.cluster.tls.cert_file.parse().unwrap(),
+            key_file: SERVER_CONFIG.cluster.tls.key_file.parse().unwrap(),
+            ca_file: SERVER_CONFIG.cluster.tls.ca_file.parse().unwrap(),
+            domain: SERVER_CONFIG.cluster.tls.domain.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for StandbyConfig {
+    fn default() -> StandbyConfig {
+        StandbyConfig {
+            enabled: SERVER_CONFIG.standby.enabled,
+            primary_address: SERVER_CONFIG.standby.primary_address.parse().unwrap(),
+            sync_interval: SERVER_CONFIG.standby.sync_interval.parse().unwrap(),
+            fetch_messages: SERVER_CONFIG.standby.fetch_messages as u32,
+            fetch_state_entries: SERVER_CONFIG.standby.fetch_state_entries as u32,
+            request_timeout: SERVER_CONFIG.standby.request_timeout.parse().unwrap(),
+            shipping: StandbyShippingConfig::default(),
+        }
+    }
+}
+
+impl Default for StandbyShippingConfig {
+    fn default() -> StandbyShippingConfig {
+        StandbyShippingConfig {
+            enabled: SERVER_CONFIG.standby.shipping.enabled,
+            address: SERVER_CONFIG.standby.shipping.address.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for RuntimeConfig {
     fn default() -> RuntimeConfig {
         RuntimeConfig {
@@ -404,6 +679,14 @@ impl Default for CacheConfig {
         CacheConfig {
             enabled: SERVER_CONFIG.system.cache.enabled,
             size: SERVER_CONFIG.system.cache.size.parse().unwrap(),
+            partition_min_size: SERVER_CONFIG
+                .system
+                .cache
+                .partition_min_size
+                .parse()
+                .unwrap(),
+            read_window: SERVER_CONFIG.system.cache.read_window.parse().unwrap(),
+            prefetch_messages_count: SERVER_CONFIG.system.cache.prefetch_messages_count as u32,
         }
     }
 }
@@ -417,6 +700,32 @@ impl Default for EncryptionConfig {
     }
 }
 
+impl Default for SegmentEncryptionConfig {
+    fn default() -> SegmentEncryptionConfig {
+        SegmentEncryptionConfig {
+            enabled: SERVER_CONFIG.system.segment_encryption.enabled,
+            master_key_source: SERVER_CONFIG
+                .system
+                .segment_encryption
+                .master_key_source
+                .parse()
+                .unwrap(),
+            master_key_path: SERVER_CONFIG
+                .system
+                .segment_encryption
+                .master_key_path
+                .parse()
+                .unwrap(),
+            master_key_env: SERVER_CONFIG
+                .system
+                .segment_encryption
+                .master_key_env
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
 impl Default for StreamConfig {
     fn default() -> StreamConfig {
         StreamConfig {
@@ -431,6 +740,18 @@ impl Default for TopicConfig {
             path: SERVER_CONFIG.system.topic.path.parse().unwrap(),
             max_size: SERVER_CONFIG.system.topic.max_size.parse().unwrap(),
             delete_oldest_segments: SERVER_CONFIG.system.topic.delete_oldest_segments,
+            deletion_grace_period: SERVER_CONFIG
+                .system
+                .topic
+                .deletion_grace_period
+                .parse()
+                .unwrap(),
+            deletion_check_interval: SERVER_CONFIG
+                .system
+                .topic
+                .deletion_check_interval
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -441,8 +762,22 @@ impl Default for PartitionConfig {
             path: SERVER_CONFIG.system.partition.path.parse().unwrap(),
             messages_required_to_save: SERVER_CONFIG.system.partition.messages_required_to_save
                 as u32,
+            linger_time: SERVER_CONFIG.system.partition.linger_time.parse().unwrap(),
+            max_accumulation_size: SERVER_CONFIG
+                .system
+                .partition
+                .max_accumulation_size
+                .parse()
+                .unwrap(),
             enforce_fsync: SERVER_CONFIG.system.partition.enforce_fsync,
+            fsync_check_interval: SERVER_CONFIG
+                .system
+                .partition
+                .fsync_check_interval
+                .parse()
+                .unwrap(),
             validate_checksum: SERVER_CONFIG.system.partition.validate_checksum,
+            data_directories: Vec::new(),
         }
     }
 }
@@ -460,6 +795,7 @@ impl Default for SegmentConfig {
                 .server_confirmation
                 .parse()
                 .unwrap(),
+            io_backend: SERVER_CONFIG.system.segment.io_backend.parse().unwrap(),
         }
     }
 }
@@ -494,6 +830,321 @@ impl Default for RecoveryConfig {
     fn default() -> RecoveryConfig {
         RecoveryConfig {
             recreate_missing_state: SERVER_CONFIG.system.recovery.recreate_missing_state,
+            rebuild_indexes: SERVER_CONFIG.system.recovery.rebuild_indexes,
+        }
+    }
+}
+
+impl Default for PollingConfig {
+    fn default() -> PollingConfig {
+        PollingConfig {
+            max_response_size: SERVER_CONFIG
+                .system
+                .polling
+                .max_response_size
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for TieringConfig {
+    fn default() -> TieringConfig {
+        TieringConfig {
+            enabled: SERVER_CONFIG.system.tiering.enabled,
+            cache_path: SERVER_CONFIG.system.tiering.cache_path.parse().unwrap(),
+            max_cache_size: SERVER_CONFIG.system.tiering.max_cache_size.parse().unwrap(),
+            interval: SERVER_CONFIG.system.tiering.interval.parse().unwrap(),
+            s3: TieringS3Config::default(),
+        }
+    }
+}
+
+impl Default for AuditConfig {
+    fn default() -> AuditConfig {
+        AuditConfig {
+            enabled: SERVER_CONFIG.system.audit.enabled,
+            max_entries: SERVER_CONFIG.system.audit.max_entries as u32,
+            flush_interval: SERVER_CONFIG.system.audit.flush_interval.parse().unwrap(),
+            sink: SERVER_CONFIG.system.audit.sink.parse().unwrap(),
+            file: AuditFileSinkConfig::default(),
+            syslog: AuditSyslogSinkConfig::default(),
+            topic: AuditTopicSinkConfig::default(),
+        }
+    }
+}
+
+impl Default for AuditFileSinkConfig {
+    fn default() -> AuditFileSinkConfig {
+        AuditFileSinkConfig {
+            path: SERVER_CONFIG.system.audit.file.path.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for AuditSyslogSinkConfig {
+    fn default() -> AuditSyslogSinkConfig {
+        AuditSyslogSinkConfig {
+            address: SERVER_CONFIG.system.audit.syslog.address.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for AuditTopicSinkConfig {
+    fn default() -> AuditTopicSinkConfig {
+        AuditTopicSinkConfig {
+            stream: SERVER_CONFIG.system.audit.topic.stream.parse().unwrap(),
+            topic: SERVER_CONFIG.system.audit.topic.topic.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for TenancyConfig {
+    fn default() -> TenancyConfig {
+        TenancyConfig {
+            enabled: SERVER_CONFIG.system.tenancy.enabled,
+            tenants: HashMap::new(),
+        }
+    }
+}
+
+impl Default for QuotasConfig {
+    fn default() -> QuotasConfig {
+        let quotas = &SERVER_CONFIG.system.quotas;
+        QuotasConfig {
+            enabled: quotas.enabled,
+            mode: quotas.mode.parse().unwrap(),
+            max_delay: quotas.max_delay.parse().unwrap(),
+            user: QuotaLimits {
+                produce_bytes_per_second: quotas.user.produce_bytes_per_second.parse().unwrap(),
+                produce_messages_per_second: quotas.user.produce_messages_per_second as u64,
+                consume_bytes_per_second: quotas.user.consume_bytes_per_second.parse().unwrap(),
+                consume_messages_per_second: quotas.user.consume_messages_per_second as u64,
+            },
+            topic: QuotaLimits {
+                produce_bytes_per_second: quotas.topic.produce_bytes_per_second.parse().unwrap(),
+                produce_messages_per_second: quotas.topic.produce_messages_per_second as u64,
+                consume_bytes_per_second: quotas.topic.consume_bytes_per_second.parse().unwrap(),
+                consume_messages_per_second: quotas.topic.consume_messages_per_second as u64,
+            },
+        }
+    }
+}
+
+impl Default for StorageUsageConfig {
+    fn default() -> StorageUsageConfig {
+        let storage_usage = &SERVER_CONFIG.system.storage_usage;
+        StorageUsageConfig {
+            enabled: storage_usage.enabled,
+            interval: storage_usage.interval.parse().unwrap(),
+            reject_on_hard_limit: storage_usage.reject_on_hard_limit,
+            stream: StorageLimits {
+                soft_limit: storage_usage.stream.soft_limit.parse().unwrap(),
+                hard_limit: storage_usage.stream.hard_limit.parse().unwrap(),
+            },
+            topic: StorageLimits {
+                soft_limit: storage_usage.topic.soft_limit.parse().unwrap(),
+                hard_limit: storage_usage.topic.hard_limit.parse().unwrap(),
+            },
+        }
+    }
+}
+
+impl Default for RequestLanesConfig {
+    fn default() -> RequestLanesConfig {
+        RequestLanesConfig {
+            admin_max_concurrency: SERVER_CONFIG.system.request_lanes.admin_max_concurrency as u32,
+            data_max_concurrency: SERVER_CONFIG.system.request_lanes.data_max_concurrency as u32,
+        }
+    }
+}
+
+impl Default for BufferPoolConfig {
+    fn default() -> BufferPoolConfig {
+        BufferPoolConfig {
+            enabled: SERVER_CONFIG.system.buffer_pool.enabled,
+            max_buffers: SERVER_CONFIG.system.buffer_pool.max_buffers as u32,
+            max_buffer_size: SERVER_CONFIG
+                .system
+                .buffer_pool
+                .max_buffer_size
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for AuthorizationConfig {
+    fn default() -> AuthorizationConfig {
+        AuthorizationConfig {
+            backend: SERVER_CONFIG.system.authorization.backend.parse().unwrap(),
+            opa: OpaAuthorizerConfig::default(),
+            policy: PolicyAuthorizerConfig::default(),
+        }
+    }
+}
+
+impl Default for OpaAuthorizerConfig {
+    fn default() -> OpaAuthorizerConfig {
+        OpaAuthorizerConfig {
+            url: SERVER_CONFIG.system.authorization.opa.url.parse().unwrap(),
+            timeout: SERVER_CONFIG
+                .system
+                .authorization
+                .opa
+                .timeout
+                .parse()
+                .unwrap(),
+            cache_ttl: SERVER_CONFIG
+                .system
+                .authorization
+                .opa
+                .cache_ttl
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for PolicyAuthorizerConfig {
+    fn default() -> PolicyAuthorizerConfig {
+        PolicyAuthorizerConfig {
+            path: SERVER_CONFIG
+                .system
+                .authorization
+                .policy
+                .path
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for TieringS3Config {
+    fn default() -> TieringS3Config {
+        TieringS3Config {
+            key_id: SERVER_CONFIG.system.tiering.s_3.key_id.parse().unwrap(),
+            key_secret: SERVER_CONFIG.system.tiering.s_3.key_secret.parse().unwrap(),
+            bucket: SERVER_CONFIG.system.tiering.s_3.bucket.parse().unwrap(),
+            endpoint: Some(SERVER_CONFIG.system.tiering.s_3.endpoint.parse().unwrap()),
+            region: Some(SERVER_CONFIG.system.tiering.s_3.region.parse().unwrap()),
+        }
+    }
+}
+
+impl Default for ClientAccessConfig {
+    fn default() -> ClientAccessConfig {
+        ClientAccessConfig {
+            enabled: SERVER_CONFIG.system.client_access.enabled,
+            allowed_addresses: SERVER_CONFIG
+                .system
+                .client_access
+                .allowed_addresses
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            denied_addresses: Vec::new(),
+            allowed_users: SERVER_CONFIG
+                .system
+                .client_access
+                .allowed_users
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            denied_users: Vec::new(),
+        }
+    }
+}
+
+impl Default for CertificateAuthConfig {
+    fn default() -> CertificateAuthConfig {
+        CertificateAuthConfig {
+            enabled: SERVER_CONFIG.system.certificate_auth.enabled,
+            ca_file: SERVER_CONFIG
+                .system
+                .certificate_auth
+                .ca_file
+                .parse()
+                .unwrap(),
+            users: HashMap::new(),
+            common_name_as_username: SERVER_CONFIG
+                .system
+                .certificate_auth
+                .common_name_as_username,
+            denylist_path: SERVER_CONFIG
+                .system
+                .certificate_auth
+                .denylist_path
+                .parse()
+                .unwrap(),
+            denylist_reload_interval: SERVER_CONFIG
+                .system
+                .certificate_auth
+                .denylist_reload_interval
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for ConsumerGroupConfig {
+    fn default() -> ConsumerGroupConfig {
+        ConsumerGroupConfig {
+            interval: SERVER_CONFIG
+                .system
+                .consumer_group
+                .interval
+                .parse()
+                .unwrap(),
+            session_timeout: SERVER_CONFIG
+                .system
+                .consumer_group
+                .session_timeout
+                .parse()
+                .unwrap(),
+            rebalance_delay: SERVER_CONFIG
+                .system
+                .consumer_group
+                .rebalance_delay
+                .parse()
+                .unwrap(),
+            slo: ConsumerGroupSloConfig::default(),
+        }
+    }
+}
+
+impl Default for ConsumerGroupSloConfig {
+    fn default() -> ConsumerGroupSloConfig {
+        ConsumerGroupSloConfig {
+            enabled: SERVER_CONFIG.system.consumer_group.slo.enabled,
+            interval: SERVER_CONFIG
+                .system
+                .consumer_group
+                .slo
+                .interval
+                .parse()
+                .unwrap(),
+            max_lag: SERVER_CONFIG
+                .system
+                .consumer_group
+                .slo
+                .max_lag
+                .parse()
+                .unwrap(),
+            webhook_url: SERVER_CONFIG
+                .system
+                .consumer_group
+                .slo
+                .webhook_url
+                .parse()
+                .unwrap(),
+            webhook_timeout: SERVER_CONFIG
+                .system
+                .consumer_group
+                .slo
+                .webhook_timeout
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -16,15 +16,26 @@
  * under the License.
  */
 
+use crate::configs::cluster::{ClusterConfig, ClusterTlsConfig};
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    ArchiverConfig, DataMaintenanceConfig, DiskArchiverConfig, HeartbeatConfig,
-    MessagesMaintenanceConfig, S3ArchiverConfig, StateMaintenanceConfig, TelemetryConfig,
-    TelemetryLogsConfig, TelemetryTracesConfig,
+    ArchiverConfig, BackgroundIoConfig, DataMaintenanceConfig, DiskArchiverConfig, HeartbeatConfig,
+    MessagesMaintenanceConfig, ProxyConfig, S3ArchiverConfig, ShutdownConfig,
+    StateMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig, TelemetryTracesConfig,
+};
+use crate::configs::standby::StandbyConfig;
+use crate::configs::system::{
+    AuditConfig, AuthorizationConfig, BufferPoolConfig, CertificateAuthConfig, ClientAccessConfig,
+    ConsumerGroupConfig, ConsumerGroupSloConfig, MessageDeduplicationConfig, PollingConfig,
+    QuotasConfig, RequestLanesConfig, SegmentEncryptionConfig, StorageLimits, StorageUsageConfig,
+    TenancyConfig, TieringConfig,
 };
-use crate::configs::system::MessageDeduplicationConfig;
 use crate::configs::{
-    http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
+    http::{
+        HttpConfig, HttpCorsConfig, HttpCorsRouteConfig, HttpJwtConfig, HttpJwtProviderConfig,
+        HttpMetricsConfig, HttpRateLimitConfig, HttpRateLimitQuotaConfig,
+        HttpSecurityHeadersConfig, HttpTlsConfig, HttpWebSocketConfig,
+    },
     resource_quota::MemoryResourceQuota,
     server::{MessageSaverConfig, ServerConfig},
     system::{
@@ -39,8 +50,8 @@ impl Display for HttpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, max_request_size: {}, cors: {}, jwt: {}, metrics: {}, tls: {} }}",
-            self.enabled, self.address, self.max_request_size, self.cors, self.jwt, self.metrics, self.tls
+            "{{ enabled: {}, address: {}, max_request_size: {}, cors: {}, security_headers: {}, rate_limit: {}, jwt: {}, metrics: {}, websocket: {}, tls: {} }}",
+            self.enabled, self.address, self.max_request_size, self.cors, self.security_headers, self.rate_limit, self.jwt, self.metrics, self.websocket, self.tls
         )
     }
 }
@@ -49,18 +60,88 @@ impl Display for HttpCorsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ enabled: {}, allowed_methods: {:?}, allowed_origins: {:?}, allowed_headers: {:?}, exposed_headers: {:?}, allow_credentials: {}, allow_private_network: {} }}",
-          self.enabled, self.allowed_methods, self.allowed_origins, self.allowed_headers, self.exposed_headers, self.allow_credentials, self.allow_private_network
+          "{{ enabled: {}, allowed_methods: {:?}, allowed_origins: {:?}, allowed_headers: {:?}, exposed_headers: {:?}, allow_credentials: {}, allow_private_network: {}, max_age: {}, health: {} }}",
+          self.enabled, self.allowed_methods, self.allowed_origins, self.allowed_headers, self.exposed_headers, self.allow_credentials, self.allow_private_network, self.max_age, self.health
       )
     }
 }
 
+impl Display for HttpCorsRouteConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, allowed_origins: {:?}, allowed_methods: {:?} }}",
+            self.enabled, self.allowed_origins, self.allowed_methods
+        )
+    }
+}
+
+impl Display for HttpSecurityHeadersConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, content_type_options: {}, frame_options: {}, referrer_policy: {}, content_security_policy: {}, strict_transport_security: {} }}",
+            self.enabled,
+            self.content_type_options,
+            self.frame_options,
+            self.referrer_policy,
+            self.content_security_policy,
+            self.strict_transport_security
+        )
+    }
+}
+
+impl Display for HttpRateLimitConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, cleanup_interval: {}, ip: {}, user: {} }}",
+            self.enabled, self.cleanup_interval, self.ip, self.user
+        )
+    }
+}
+
+impl Display for HttpRateLimitQuotaConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, requests_per_second: {}, burst: {} }}",
+            self.enabled, self.requests_per_second, self.burst
+        )
+    }
+}
+
 impl Display for HttpJwtConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ algorithm: {}, audience: {}, access_token_expiry: {}, use_base64_secret: {} }}",
-            self.algorithm, self.audience, self.access_token_expiry, self.use_base64_secret
+            "{{ algorithm: {}, audience: {}, access_token_expiry: {}, use_base64_secret: {}, providers: [{}] }}",
+            self.algorithm,
+            self.audience,
+            self.access_token_expiry,
+            self.use_base64_secret,
+            self.providers
+                .iter()
+                .map(|provider| provider.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl Display for HttpJwtProviderConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ name: {}, issuer: {}, audiences: {:?}, jwks_url: {}, jwks_refresh_interval: {}, subject_users: {}, roles_claim: {}, role_users: {} }}",
+            self.name,
+            self.issuer,
+            self.audiences,
+            self.jwks_url,
+            self.jwks_refresh_interval,
+            self.subject_users.len(),
+            self.roles_claim,
+            self.role_users.len()
         )
     }
 }
@@ -75,12 +156,22 @@ impl Display for HttpMetricsConfig {
     }
 }
 
+impl Display for HttpWebSocketConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, max_requests_per_second: {}, poll_interval: {} }}",
+            self.enabled, self.max_requests_per_second, self.poll_interval
+        )
+    }
+}
+
 impl Display for HttpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, cert_file: {}, key_file: {} }}",
-            self.enabled, self.cert_file, self.key_file
+            "{{ enabled: {}, cert_file: {}, key_file: {}, reload_interval: {} }}",
+            self.enabled, self.cert_file, self.key_file, self.reload_interval
         )
     }
 }
@@ -108,8 +199,12 @@ impl Display for QuicCertificateConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ self_signed: {}, cert_file: {}, key_file: {} }}",
-            self.self_signed, self.cert_file, self.key_file
+            "{{ self_signed: {}, cert_file: {}, key_file: {}, client_certificates: {}, reload_interval: {} }}",
+            self.self_signed,
+            self.cert_file,
+            self.key_file,
+            self.client_certificates,
+            self.reload_interval
         )
     }
 }
@@ -137,8 +232,8 @@ impl Display for DataMaintenanceConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ archiver: {}, messages: {}, state: {} }}",
-            self.archiver, self.messages, self.state
+            "{{ archiver: {}, messages: {}, state: {}, io: {} }}",
+            self.archiver, self.messages, self.state, self.io
         )
     }
 }
@@ -184,8 +279,13 @@ impl Display for MessagesMaintenanceConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ archiver_enabled: {}, cleaner_enabled: {}, interval: {} }}",
-            self.archiver_enabled, self.cleaner_enabled, self.interval
+            "{{ archiver_enabled: {}, cleaner_enabled: {}, compactor_enabled: {}, archive_before_delete: {}, interval: {}, retention_hook_timeout: {} }}",
+            self.archiver_enabled,
+            self.cleaner_enabled,
+            self.compactor_enabled,
+            self.archive_before_delete,
+            self.interval,
+            self.retention_hook_timeout
         )
     }
 }
@@ -200,12 +300,22 @@ impl Display for StateMaintenanceConfig {
     }
 }
 
+impl Display for BackgroundIoConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ throttling_enabled: {}, max_throughput: {} }}",
+            self.throttling_enabled, self.max_throughput
+        )
+    }
+}
+
 impl Display for ServerConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, system: {}, quic: {}, tcp: {}, http: {}, telemetry: {} }}",
-            self.data_maintenance, self.message_saver, self.heartbeat, self.system, self.quic, self.tcp, self.http, self.telemetry
+            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, shutdown: {}, proxy: {}, cluster: {}, standby: {}, system: {}, quic: {}, tcp: {}, http: {}, telemetry: {} }}",
+            self.data_maintenance, self.message_saver, self.heartbeat, self.shutdown, self.proxy, self.cluster, self.standby, self.system, self.quic, self.tcp, self.http, self.telemetry
         )
     }
 }
@@ -222,7 +332,15 @@ impl Display for MessageSaverConfig {
 
 impl Display for CacheConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ enabled: {}, size: {} }}", self.enabled, self.size)
+        write!(
+            f,
+            "{{ enabled: {}, size: {}, partition_min_size: {}, read_window: {}, prefetch_messages_count: {} }}",
+            self.enabled,
+            self.size,
+            self.partition_min_size,
+            self.read_window,
+            self.prefetch_messages_count
+        )
     }
 }
 
@@ -236,12 +354,91 @@ impl Display for HeartbeatConfig {
     }
 }
 
+impl Display for ShutdownConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ timeout: {} }}", self.timeout)
+    }
+}
+
+impl Display for ProxyConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ address: {}, upstream_address: {}, latency: {}, jitter: {}, reset_probability: {} }}",
+            self.address, self.upstream_address, self.latency, self.jitter, self.reset_probability
+        )
+    }
+}
+
+impl Display for StandbyConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, primary_address: {}, sync_interval: {}, fetch_messages: {}, fetch_state_entries: {}, request_timeout: {}, shipping: {{ enabled: {}, address: {} }} }}",
+            self.enabled,
+            self.primary_address,
+            self.sync_interval,
+            self.fetch_messages,
+            self.fetch_state_entries,
+            self.request_timeout,
+            self.shipping.enabled,
+            self.shipping.address
+        )
+    }
+}
+
+impl Display for ClusterConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, id: {}, address: {}, client_address: {}, election_timeout: {}, heartbeat_interval: {}, replication_timeout: {}, min_insync_replicas: {}, replica_fetch_interval: {}, replica_fetch_messages: {}, replica_max_lag: {}, tls: {}, nodes: [{}] }}",
+            self.enabled,
+            self.id,
+            self.address,
+            self.client_address,
+            self.election_timeout,
+            self.heartbeat_interval,
+            self.replication_timeout,
+            self.min_insync_replicas,
+            self.replica_fetch_interval,
+            self.replica_fetch_messages,
+            self.replica_max_lag,
+            self.tls,
+            self.nodes
+                .iter()
+                .map(|node| format!("{}@{} ({})", node.id, node.address, node.client_address))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl Display for ClusterTlsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, cert_file: {}, key_file: {}, ca_file: {}, domain: {} }}",
+            self.enabled, self.cert_file, self.key_file, self.ca_file, self.domain
+        )
+    }
+}
+
 impl Display for EncryptionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ enabled: {} }}", self.enabled)
     }
 }
 
+impl Display for SegmentEncryptionConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, master_key_source: {}, master_key_path: {}, master_key_env: {} }}",
+            self.enabled, self.master_key_source, self.master_key_path, self.master_key_env
+        )
+    }
+}
+
 impl Display for StreamConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ path: {} }}", self.path)
@@ -252,8 +449,12 @@ impl Display for TopicConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ path: {}, max_size: {}, delete_oldest_segments: {} }}",
-            self.path, self.max_size, self.delete_oldest_segments
+            "{{ path: {}, max_size: {}, delete_oldest_segments: {}, deletion_grace_period: {}, deletion_check_interval: {} }}",
+            self.path,
+            self.max_size,
+            self.delete_oldest_segments,
+            self.deletion_grace_period,
+            self.deletion_check_interval
         )
     }
 }
@@ -262,15 +463,183 @@ impl Display for PartitionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {} }}",
+          "{{ path: {}, messages_required_to_save: {}, linger_time: {}, max_accumulation_size: {}, enforce_fsync: {}, fsync_check_interval: {}, validate_checksum: {}, data_directories: {:?} }}",
           self.path,
           self.messages_required_to_save,
+          self.linger_time,
+          self.max_accumulation_size,
           self.enforce_fsync,
-          self.validate_checksum
+          self.fsync_check_interval,
+          self.validate_checksum,
+          self.data_directories
       )
     }
 }
 
+impl Display for ConsumerGroupConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ interval: {}, session_timeout: {}, rebalance_delay: {}, slo: {} }}",
+            self.interval, self.session_timeout, self.rebalance_delay, self.slo
+        )
+    }
+}
+
+impl Display for ConsumerGroupSloConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {}, max_lag: {}, webhook_url: {}, webhook_timeout: {} }}",
+            self.enabled, self.interval, self.max_lag, self.webhook_url, self.webhook_timeout
+        )
+    }
+}
+
+impl Display for CertificateAuthConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, ca_file: {}, users: {:?}, common_name_as_username: {}, denylist_path: {}, denylist_reload_interval: {} }}",
+            self.enabled,
+            self.ca_file,
+            self.users,
+            self.common_name_as_username,
+            self.denylist_path,
+            self.denylist_reload_interval
+        )
+    }
+}
+
+impl Display for ClientAccessConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, allowed_addresses: {:?}, denied_addresses: {:?}, allowed_users: {:?}, denied_users: {:?} }}",
+            self.enabled,
+            self.allowed_addresses,
+            self.denied_addresses,
+            self.allowed_users,
+            self.denied_users
+        )
+    }
+}
+
+impl Display for TieringConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, cache_path: {}, max_cache_size: {}, interval: {}, s3: {{ bucket: {}, endpoint: {:?}, region: {:?} }} }}",
+            self.enabled,
+            self.cache_path,
+            self.max_cache_size,
+            self.interval,
+            self.s3.bucket,
+            self.s3.endpoint,
+            self.s3.region
+        )
+    }
+}
+
+impl Display for AuditConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, max_entries: {}, flush_interval: {}, sink: {}, file: {{ path: {} }}, syslog: {{ address: {} }}, topic: {{ stream: {}, topic: {} }} }}",
+            self.enabled,
+            self.max_entries,
+            self.flush_interval,
+            self.sink,
+            self.file.path,
+            self.syslog.address,
+            self.topic.stream,
+            self.topic.topic
+        )
+    }
+}
+
+impl Display for TenancyConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, tenants: {:?} }}",
+            self.enabled,
+            self.tenants.keys().collect::<Vec<_>>()
+        )
+    }
+}
+
+impl Display for QuotasConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, mode: {}, max_delay: {}, user: {}, topic: {} }}",
+            self.enabled, self.mode, self.max_delay, self.user, self.topic
+        )
+    }
+}
+
+impl Display for StorageUsageConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {}, reject_on_hard_limit: {}, stream: {}, topic: {} }}",
+            self.enabled, self.interval, self.reject_on_hard_limit, self.stream, self.topic
+        )
+    }
+}
+
+impl Display for RequestLanesConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ admin_max_concurrency: {}, data_max_concurrency: {} }}",
+            self.admin_max_concurrency, self.data_max_concurrency
+        )
+    }
+}
+
+impl Display for BufferPoolConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, max_buffers: {}, max_buffer_size: {} }}",
+            self.enabled, self.max_buffers, self.max_buffer_size
+        )
+    }
+}
+
+impl Display for StorageLimits {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ soft_limit: {}, hard_limit: {} }}",
+            self.soft_limit.as_human_string_with_zero_as_unlimited(),
+            self.hard_limit.as_human_string_with_zero_as_unlimited()
+        )
+    }
+}
+
+impl Display for AuthorizationConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ backend: {}, opa: {{ url: {}, timeout: {}, cache_ttl: {} }}, policy: {{ path: {} }} }}",
+            self.backend,
+            self.opa.url,
+            self.opa.timeout,
+            self.opa.cache_ttl,
+            self.policy.path
+        )
+    }
+}
+
+impl Display for PollingConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ max_response_size: {} }}", self.max_response_size)
+    }
+}
+
 impl Display for MessageDeduplicationConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -285,8 +654,8 @@ impl Display for SegmentConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ size_bytes: {}, cache_indexes: {}, message_expiry: {}, archive_expired: {}, server_confirmation: {} }}",
-            self.size, self.cache_indexes, self.message_expiry, self.archive_expired, self.server_confirmation,
+            "{{ size_bytes: {}, cache_indexes: {}, message_expiry: {}, archive_expired: {}, server_confirmation: {}, io_backend: {} }}",
+            self.size, self.cache_indexes, self.message_expiry, self.archive_expired, self.server_confirmation, self.io_backend,
         )
     }
 }
@@ -318,8 +687,8 @@ impl Display for TcpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, certificate: {} }}",
-            self.enabled, self.certificate
+            "{{ enabled: {}, certificate: {}, client_certificates: {}, reload_interval: {} }}",
+            self.enabled, self.certificate, self.client_certificates, self.reload_interval
         )
     }
 }
@@ -378,7 +747,7 @@ impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, state: {} }}",
+          "{{ path: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, segment_encryption: {}, state: {}, consumer_group: {}, client_access: {}, certificate_auth: {}, polling: {}, tiering: {}, audit: {}, authorization: {}, tenancy: {}, quotas: {}, storage_usage: {}, request_lanes: {}, buffer_pool: {} }}",
           self.path,
           self.logging,
           self.cache,
@@ -387,7 +756,20 @@ impl Display for SystemConfig {
           self.partition,
           self.segment,
           self.encryption,
+          self.segment_encryption,
           self.state,
+          self.consumer_group,
+          self.client_access,
+          self.certificate_auth,
+          self.polling,
+          self.tiering,
+          self.audit,
+          self.authorization,
+          self.tenancy,
+          self.quotas,
+          self.storage_usage,
+          self.request_lanes,
+          self.buffer_pool,
       )
     }
 }
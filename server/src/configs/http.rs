@@ -24,6 +24,7 @@ use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::collections::HashMap;
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct HttpConfig {
@@ -31,11 +32,15 @@ pub struct HttpConfig {
     pub address: String,
     pub max_request_size: IggyByteSize,
     pub cors: HttpCorsConfig,
+    pub security_headers: HttpSecurityHeadersConfig,
+    pub rate_limit: HttpRateLimitConfig,
     pub jwt: HttpJwtConfig,
     pub metrics: HttpMetricsConfig,
+    pub websocket: HttpWebSocketConfig,
     pub tls: HttpTlsConfig,
 }
 
+#[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct HttpCorsConfig {
     pub enabled: bool,
@@ -45,6 +50,43 @@ pub struct HttpCorsConfig {
     pub exposed_headers: Vec<String>,
     pub allow_credentials: bool,
     pub allow_private_network: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_age: IggyDuration,
+    pub health: HttpCorsRouteConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HttpCorsRouteConfig {
+    pub enabled: bool,
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HttpSecurityHeadersConfig {
+    pub enabled: bool,
+    pub content_type_options: String,
+    pub frame_options: String,
+    pub referrer_policy: String,
+    pub content_security_policy: String,
+    pub strict_transport_security: String,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HttpRateLimitConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub cleanup_interval: IggyDuration,
+    pub ip: HttpRateLimitQuotaConfig,
+    pub user: HttpRateLimitQuotaConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HttpRateLimitQuotaConfig {
+    pub enabled: bool,
+    pub requests_per_second: u32,
+    pub burst: u32,
 }
 
 #[serde_as]
@@ -64,6 +106,26 @@ pub struct HttpJwtConfig {
     pub encoding_secret: String,
     pub decoding_secret: String,
     pub use_base64_secret: bool,
+    #[serde(default)]
+    pub providers: Vec<HttpJwtProviderConfig>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HttpJwtProviderConfig {
+    pub name: String,
+    pub issuer: String,
+    #[serde(default)]
+    pub audiences: Vec<String>,
+    pub jwks_url: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub jwks_refresh_interval: IggyDuration,
+    #[serde(default)]
+    pub subject_users: HashMap<String, String>,
+    #[serde(default)]
+    pub roles_claim: String,
+    #[serde(default)]
+    pub role_users: HashMap<String, String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -72,17 +134,29 @@ pub struct HttpMetricsConfig {
     pub endpoint: String,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HttpWebSocketConfig {
+    pub enabled: bool,
+    pub max_requests_per_second: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub poll_interval: IggyDuration,
+}
+
 #[derive(Debug)]
 pub enum JwtSecret {
     Default(String),
     Base64(String),
 }
 
+#[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct HttpTlsConfig {
     pub enabled: bool,
     pub cert_file: String,
     pub key_file: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub reload_interval: IggyDuration,
 }
 
 impl HttpJwtConfig {
@@ -19,14 +19,18 @@
 pub mod server;
 pub mod system;
 
+pub mod cluster;
 pub mod http;
 pub mod quic;
+pub mod standby;
 pub mod tcp;
 
 pub mod config_provider;
 pub mod defaults;
 pub mod displays;
 pub mod resource_quota;
+pub mod runtime;
+pub mod sources;
 pub mod validators;
 
 pub const COMPONENT: &str = "CONFIG";
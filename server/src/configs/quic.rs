@@ -39,9 +39,13 @@ pub struct QuicConfig {
     pub certificate: QuicCertificateConfig,
 }
 
+#[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct QuicCertificateConfig {
     pub self_signed: bool,
     pub cert_file: String,
     pub key_file: String,
+    pub client_certificates: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub reload_interval: IggyDuration,
 }
@@ -0,0 +1,224 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::SystemConfig;
+use iggy::error::IggyError;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use iggy::validatable::Validatable;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use tracing::error;
+use tracing::level_filters::LevelFilter;
+
+pub const CACHE_SIZE_KEY: &str = "system.cache.size";
+pub const MESSAGE_EXPIRY_KEY: &str = "system.segment.message_expiry";
+pub const TOPIC_MAX_SIZE_KEY: &str = "system.topic.max_size";
+pub const LOG_LEVEL_KEY: &str = "system.logging.level";
+
+/// The configuration values which can be changed once the server has started, without restarting it.
+pub const RUNTIME_CONFIG_KEYS: [&str; 4] = [
+    CACHE_SIZE_KEY,
+    MESSAGE_EXPIRY_KEY,
+    TOPIC_MAX_SIZE_KEY,
+    LOG_LEVEL_KEY,
+];
+
+/// The changes of the configuration values made at runtime, keyed by the dotted path, e.g. `system.cache.size`.
+/// The values are kept as strings, in the same format as in the configuration file.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RuntimeConfigChanges {
+    values: BTreeMap<String, String>,
+}
+
+impl RuntimeConfigChanges {
+    /// Parses the values of the given section, e.g. `system.cache`, whose keys are relative to the section.
+    pub fn parse(section: &str, values: BTreeMap<String, Value>) -> Result<Self, IggyError> {
+        if values.is_empty() {
+            error!("No configuration values to change in section: {section}.");
+            return Err(IggyError::InvalidConfiguration);
+        }
+
+        let mut changes = BTreeMap::new();
+        for (key, value) in values {
+            let key = format!("{section}.{key}");
+            if !RUNTIME_CONFIG_KEYS.contains(&key.as_str()) {
+                error!("Configuration value: {key} cannot be changed at runtime.");
+                return Err(IggyError::InvalidConfiguration);
+            }
+
+            let value = match value {
+                Value::String(value) => value,
+                Value::Number(value) => value.to_string(),
+                value => {
+                    error!("Invalid value: {value} of configuration: {key}.");
+                    return Err(IggyError::InvalidConfiguration);
+                }
+            };
+            changes.insert(key, value);
+        }
+        Ok(Self { values: changes })
+    }
+
+    pub fn values(&self) -> &BTreeMap<String, String> {
+        &self.values
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+
+    /// Applies the changes to the configuration and validates the result.
+    pub fn apply(&self, config: &mut SystemConfig) -> Result<(), IggyError> {
+        for (key, value) in &self.values {
+            match key.as_str() {
+                CACHE_SIZE_KEY => config.cache.size = parse(key, value)?,
+                MESSAGE_EXPIRY_KEY => config.segment.message_expiry = parse(key, value)?,
+                TOPIC_MAX_SIZE_KEY => config.topic.max_size = parse(key, value)?,
+                LOG_LEVEL_KEY => {
+                    let level: LevelFilter = parse(key, value)?;
+                    config.logging.level = level.to_string().to_lowercase();
+                }
+                _ => {
+                    error!("Configuration value: {key} cannot be changed at runtime.");
+                    return Err(IggyError::InvalidConfiguration);
+                }
+            }
+        }
+
+        if self.contains(CACHE_SIZE_KEY) && config.cache.validate().is_err() {
+            error!("Invalid cache size: {}.", config.cache.size);
+            return Err(IggyError::InvalidConfiguration);
+        }
+
+        if let IggyExpiry::ServerDefault = config.segment.message_expiry {
+            error!("Message expiry cannot be set to the server default.");
+            return Err(IggyError::InvalidConfiguration);
+        }
+
+        let topic_size = match config.topic.max_size {
+            MaxTopicSize::Custom(size) => size.as_bytes_u64(),
+            MaxTopicSize::Unlimited => u64::MAX,
+            MaxTopicSize::ServerDefault => {
+                error!("Max topic size cannot be set to the server default.");
+                return Err(IggyError::InvalidConfiguration);
+            }
+        };
+        if topic_size < config.segment.size.as_bytes_u64() {
+            error!(
+                "Max topic size: {} cannot be lower than the segment size: {}.",
+                config.topic.max_size, config.segment.size
+            );
+            return Err(IggyError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+fn parse<T>(key: &str, value: &str) -> Result<T, IggyError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value.parse::<T>().map_err(|error| {
+        error!("Invalid value: {value} of configuration: {key}, error: {error}");
+        IggyError::InvalidConfiguration
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::server::ServerConfig;
+    use iggy::utils::byte_size::IggyByteSize;
+    use iggy::utils::duration::IggyDuration;
+
+    fn values(values: &[(&str, Value)]) -> BTreeMap<String, Value> {
+        values
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn should_apply_changes_of_runtime_values() {
+        let mut config = (*ServerConfig::default().system).clone();
+        let changes = RuntimeConfigChanges::parse(
+            "system.segment",
+            values(&[("message_expiry", Value::from("1 day"))]),
+        )
+        .unwrap();
+
+        changes.apply(&mut config).unwrap();
+
+        assert_eq!(
+            config.segment.message_expiry,
+            IggyExpiry::ExpireDuration(IggyDuration::from_str("1 day").unwrap())
+        );
+    }
+
+    #[test]
+    fn should_normalize_log_level() {
+        let mut config = (*ServerConfig::default().system).clone();
+        let changes = RuntimeConfigChanges::parse(
+            "system.logging",
+            values(&[("level", Value::from("DEBUG"))]),
+        )
+        .unwrap();
+
+        changes.apply(&mut config).unwrap();
+
+        assert_eq!(config.logging.level, "debug");
+    }
+
+    #[test]
+    fn should_not_parse_values_which_cannot_be_changed_at_runtime() {
+        let changes =
+            RuntimeConfigChanges::parse("system.segment", values(&[("size", Value::from("1 GB"))]));
+
+        assert!(changes.is_err());
+    }
+
+    #[test]
+    fn should_not_apply_topic_size_lower_than_segment_size() {
+        let mut config = (*ServerConfig::default().system).clone();
+        config.segment.size = IggyByteSize::from_str("1 GB").unwrap();
+        let changes = RuntimeConfigChanges::parse(
+            "system.topic",
+            values(&[("max_size", Value::from("100 MB"))]),
+        )
+        .unwrap();
+
+        assert!(changes.apply(&mut config).is_err());
+    }
+
+    #[test]
+    fn should_not_apply_invalid_log_level() {
+        let mut config = (*ServerConfig::default().system).clone();
+        let changes = RuntimeConfigChanges::parse(
+            "system.logging",
+            values(&[("level", Value::from("verbose"))]),
+        )
+        .unwrap();
+
+        assert!(changes.apply(&mut config).is_err());
+    }
+}
@@ -17,15 +17,19 @@
  */
 
 use crate::archiver::ArchiverKindType;
+use crate::configs::cluster::ClusterConfig;
 use crate::configs::config_provider::ConfigProviderKind;
 use crate::configs::http::HttpConfig;
 use crate::configs::quic::QuicConfig;
+use crate::configs::sources::ConfigSources;
+use crate::configs::standby::StandbyConfig;
 use crate::configs::system::SystemConfig;
 use crate::configs::tcp::TcpConfig;
 use crate::configs::COMPONENT;
 use crate::server_error::ConfigError;
 use derive_more::Display;
 use error_set::ErrContext;
+use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use iggy::validatable::Validatable;
 use serde::{Deserialize, Serialize};
@@ -40,11 +44,17 @@ pub struct ServerConfig {
     pub message_saver: MessageSaverConfig,
     pub personal_access_token: PersonalAccessTokenConfig,
     pub heartbeat: HeartbeatConfig,
+    pub shutdown: ShutdownConfig,
+    pub proxy: ProxyConfig,
+    pub cluster: ClusterConfig,
+    pub standby: StandbyConfig,
     pub system: Arc<SystemConfig>,
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
     pub http: HttpConfig,
     pub telemetry: TelemetryConfig,
+    #[serde(skip)]
+    pub sources: ConfigSources,
 }
 
 #[serde_as]
@@ -53,6 +63,7 @@ pub struct DataMaintenanceConfig {
     pub archiver: ArchiverConfig,
     pub messages: MessagesMaintenanceConfig,
     pub state: StateMaintenanceConfig,
+    pub io: BackgroundIoConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -68,8 +79,12 @@ pub struct ArchiverConfig {
 pub struct MessagesMaintenanceConfig {
     pub archiver_enabled: bool,
     pub cleaner_enabled: bool,
+    pub compactor_enabled: bool,
+    pub archive_before_delete: bool,
     #[serde_as(as = "DisplayFromStr")]
     pub interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retention_hook_timeout: IggyDuration,
 }
 
 #[serde_as]
@@ -81,6 +96,12 @@ pub struct StateMaintenanceConfig {
     pub interval: IggyDuration,
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct BackgroundIoConfig {
+    pub throttling_enabled: bool,
+    pub max_throughput: IggyByteSize,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct DiskArchiverConfig {
     pub path: String,
@@ -127,6 +148,25 @@ pub struct HeartbeatConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ShutdownConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ProxyConfig {
+    pub address: String,
+    pub upstream_address: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub latency: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub jitter: IggyDuration,
+    pub reset_probability: f64,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TelemetryConfig {
     pub enabled: bool,
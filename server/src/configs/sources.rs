@@ -0,0 +1,144 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::server::ServerConfig;
+use iggy::models::config_value::{ConfigSource, ConfigValue};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+const MASKED_VALUE: &str = "******";
+const SECRET_KEYS: [&str; 7] = [
+    "data_maintenance.archiver.s3.key_secret",
+    "http.jwt.encoding_secret",
+    "http.jwt.decoding_secret",
+    "tcp.tls.password",
+    "system.encryption.key",
+    "system.tiering.s3.key_secret",
+    "system.backup.archive.s3.key_secret",
+];
+
+/// The sources of the configuration values, keyed by the dotted path, e.g. `system.segment.size`.
+/// The values which are not present have been resolved from the defaults.
+#[derive(Debug, Default, Clone)]
+pub struct ConfigSources {
+    sources: BTreeMap<String, ConfigSource>,
+}
+
+impl ConfigSources {
+    pub fn get(&self, key: &str) -> ConfigSource {
+        self.sources.get(key).copied().unwrap_or_default()
+    }
+
+    pub fn set(&mut self, key: &str, source: ConfigSource) {
+        if source == ConfigSource::Default {
+            self.sources.remove(key);
+            return;
+        }
+
+        self.sources.insert(key.to_owned(), source);
+    }
+}
+
+impl ServerConfig {
+    /// Returns the dotted paths of all the configuration values, the arrays are treated as the single values.
+    pub fn get_keys(&self) -> Vec<String> {
+        let mut keys = Vec::new();
+        if let Ok(value) = serde_json::to_value(self) {
+            collect_values("", value, &mut |key, _| keys.push(key));
+        }
+        keys
+    }
+
+    /// Returns the fully resolved configuration values with their sources, the secrets are masked.
+    pub fn get_effective_values(&self) -> Vec<ConfigValue> {
+        let mut values = Vec::new();
+        if let Ok(value) = serde_json::to_value(self) {
+            collect_values("", value, &mut |key, value| {
+                let value = if SECRET_KEYS.contains(&key.as_str()) {
+                    Value::String(MASKED_VALUE.to_owned())
+                } else {
+                    value
+                };
+                let source = self.sources.get(&key);
+                values.push(ConfigValue { key, value, source });
+            });
+        }
+        values
+    }
+}
+
+fn collect_values(prefix: &str, value: Value, collect: &mut impl FnMut(String, Value)) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                collect_values(&key, value, collect);
+            }
+        }
+        value => collect(prefix.to_owned(), value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn effective_values_should_contain_all_keys_with_their_sources() {
+        let mut config = ServerConfig::default();
+        config
+            .sources
+            .set("system.segment.size", ConfigSource::Environment);
+        config.sources.set("http.address", ConfigSource::Runtime);
+
+        let keys = config.get_keys();
+        let values = config.get_effective_values();
+
+        assert_eq!(values.len(), keys.len());
+        let get_source = |key: &str| {
+            values
+                .iter()
+                .find(|value| value.key == key)
+                .map(|value| value.source)
+                .unwrap()
+        };
+        assert_eq!(get_source("system.segment.size"), ConfigSource::Environment);
+        assert_eq!(get_source("http.address"), ConfigSource::Runtime);
+        assert_eq!(get_source("tcp.address"), ConfigSource::Default);
+    }
+
+    #[test]
+    fn secret_values_should_be_masked() {
+        let config = ServerConfig::default();
+
+        let values = config.get_effective_values();
+
+        let secrets = values
+            .iter()
+            .filter(|value| SECRET_KEYS.contains(&value.key.as_str()))
+            .collect::<Vec<_>>();
+        assert!(!secrets.is_empty());
+        for secret in secrets {
+            assert_eq!(secret.value, Value::String(MASKED_VALUE.to_owned()));
+        }
+    }
+}
@@ -16,8 +16,12 @@
  * under the License.
  */
 
+use crate::audit::AuditSinkKindType;
 use crate::configs::resource_quota::MemoryResourceQuota;
+use crate::streaming::users::authorizers::AuthorizerKindType;
+use derive_more::Display;
 use iggy::confirmation::Confirmation;
+use iggy::models::quota::QuotaLimits;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
@@ -27,8 +31,10 @@ use iggy::{
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::collections::HashMap;
+use std::str::FromStr;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SystemConfig {
     pub path: String,
     pub backup: BackupConfig,
@@ -41,40 +47,67 @@ pub struct SystemConfig {
     pub partition: PartitionConfig,
     pub segment: SegmentConfig,
     pub encryption: EncryptionConfig,
+    pub segment_encryption: SegmentEncryptionConfig,
     pub compression: CompressionConfig,
     pub message_deduplication: MessageDeduplicationConfig,
     pub recovery: RecoveryConfig,
+    pub consumer_group: ConsumerGroupConfig,
+    pub client_access: ClientAccessConfig,
+    pub certificate_auth: CertificateAuthConfig,
+    pub polling: PollingConfig,
+    pub tiering: TieringConfig,
+    pub audit: AuditConfig,
+    pub authorization: AuthorizationConfig,
+    pub tenancy: TenancyConfig,
+    pub quotas: QuotasConfig,
+    pub storage_usage: StorageUsageConfig,
+    pub request_lanes: RequestLanesConfig,
+    pub buffer_pool: BufferPoolConfig,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BackupConfig {
     pub path: String,
     pub compatibility: CompatibilityConfig,
+    pub migration: MigrationConfig,
+    pub archive: BackupArchiveConfig,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CompatibilityConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MigrationConfig {
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BackupArchiveConfig {
+    pub path: String,
+    pub s3_prefix: String,
+    pub s3: TieringS3Config,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DatabaseConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RuntimeConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CompressionConfig {
     pub allow_override: bool,
     pub default_algorithm: CompressionAlgorithm,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct LoggingConfig {
     pub path: String,
     pub level: String,
@@ -85,42 +118,89 @@ pub struct LoggingConfig {
     pub sysinfo_print_interval: IggyDuration,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CacheConfig {
     pub enabled: bool,
     pub size: MemoryResourceQuota,
+    pub partition_min_size: IggyByteSize,
+    #[serde_as(as = "DisplayFromStr")]
+    pub read_window: IggyDuration,
+    pub prefetch_messages_count: u32,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EncryptionConfig {
     pub enabled: bool,
     pub key: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SegmentEncryptionConfig {
+    pub enabled: bool,
+    pub master_key_source: MasterKeySource,
+    pub master_key_path: String,
+    pub master_key_env: String,
+}
+
+/// The source of the master key wrapping the data keys of the encrypted segments.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum MasterKeySource {
+    #[default]
+    #[display("file")]
+    File,
+    #[display("env")]
+    Env,
+}
+
+impl FromStr for MasterKeySource {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "file" => Ok(MasterKeySource::File),
+            "env" => Ok(MasterKeySource::Env),
+            _ => Err(format!("Unknown master key source: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct StreamConfig {
     pub path: String,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TopicConfig {
     pub path: String,
     #[serde_as(as = "DisplayFromStr")]
     pub max_size: MaxTopicSize,
     pub delete_oldest_segments: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub deletion_grace_period: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub deletion_check_interval: IggyDuration,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PartitionConfig {
     pub path: String,
     pub messages_required_to_save: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub linger_time: IggyDuration,
+    pub max_accumulation_size: IggyByteSize,
     pub enforce_fsync: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub fsync_check_interval: IggyDuration,
     pub validate_checksum: bool,
+    #[serde(default)]
+    pub data_directories: Vec<String>,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MessageDeduplicationConfig {
     pub enabled: bool,
     pub max_entries: u64,
@@ -128,13 +208,233 @@ pub struct MessageDeduplicationConfig {
     pub expiry: IggyDuration,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RecoveryConfig {
     pub recreate_missing_state: bool,
+    pub rebuild_indexes: bool,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConsumerGroupConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub session_timeout: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub rebalance_delay: IggyDuration,
+    pub slo: ConsumerGroupSloConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConsumerGroupSloConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_lag: IggyDuration,
+    pub webhook_url: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub webhook_timeout: IggyDuration,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ClientAccessConfig {
+    pub enabled: bool,
+    pub allowed_addresses: Vec<String>,
+    #[serde(default)]
+    pub denied_addresses: Vec<String>,
+    pub allowed_users: Vec<String>,
+    #[serde(default)]
+    pub denied_users: Vec<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CertificateAuthConfig {
+    pub enabled: bool,
+    pub ca_file: String,
+    #[serde(default)]
+    pub users: HashMap<String, String>,
+    #[serde(default)]
+    pub common_name_as_username: bool,
+    pub denylist_path: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub denylist_reload_interval: IggyDuration,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PollingConfig {
+    pub max_response_size: IggyByteSize,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TieringConfig {
+    pub enabled: bool,
+    pub cache_path: String,
+    pub max_cache_size: IggyByteSize,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    pub s3: TieringS3Config,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AuditConfig {
+    pub enabled: bool,
+    pub max_entries: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub flush_interval: IggyDuration,
+    pub sink: AuditSinkKindType,
+    pub file: AuditFileSinkConfig,
+    pub syslog: AuditSyslogSinkConfig,
+    pub topic: AuditTopicSinkConfig,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AuditFileSinkConfig {
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AuditSyslogSinkConfig {
+    pub address: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AuditTopicSinkConfig {
+    pub stream: String,
+    pub topic: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AuthorizationConfig {
+    pub backend: AuthorizerKindType,
+    pub opa: OpaAuthorizerConfig,
+    pub policy: PolicyAuthorizerConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OpaAuthorizerConfig {
+    pub url: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub cache_ttl: IggyDuration,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PolicyAuthorizerConfig {
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TenancyConfig {
+    pub enabled: bool,
+    #[serde(default)]
+    pub tenants: HashMap<String, TenantConfig>,
+}
+
+/// The namespace the users of the tenant are confined to, and the quotas of its streams, where `0` means unlimited.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TenantConfig {
+    pub prefix: String,
+    pub users: Vec<String>,
+    #[serde(default)]
+    pub max_streams: u32,
+    #[serde(default)]
+    pub max_topics: u32,
+    #[serde(default)]
+    pub max_partitions: u32,
+    #[serde(default)]
+    pub max_storage: IggyByteSize,
+    #[serde(default)]
+    pub max_throughput: IggyByteSize,
+}
+
+/// The default throughput quotas of each user and topic, which can be overridden at runtime.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QuotasConfig {
+    pub enabled: bool,
+    pub mode: QuotaMode,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_delay: IggyDuration,
+    pub user: QuotaLimits,
+    pub topic: QuotaLimits,
+}
+
+/// The way of handling the requests exceeding the quota.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaMode {
+    #[default]
+    #[display("delay")]
+    Delay,
+    #[display("reject")]
+    Reject,
+}
+
+impl FromStr for QuotaMode {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "delay" => Ok(QuotaMode::Delay),
+            "reject" => Ok(QuotaMode::Reject),
+            _ => Err(format!("Unknown quota mode: {}", s)),
+        }
+    }
+}
+
+/// The disk usage accounting of the streams and topics, measured periodically along with the thresholds triggering the alerts.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StorageUsageConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    pub reject_on_hard_limit: bool,
+    pub stream: StorageLimits,
+    pub topic: StorageLimits,
+}
+
+/// The disk usage thresholds, where `0` means no threshold.
+/// Exceeding the soft limit only raises the warning, while exceeding the hard one can also reject the producers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct StorageLimits {
+    pub soft_limit: IggyByteSize,
+    pub hard_limit: IggyByteSize,
+}
+
+/// The concurrency limits of the lanes processing the administrative and the data-plane commands.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct RequestLanesConfig {
+    pub admin_max_concurrency: u32,
+    pub data_max_concurrency: u32,
+}
+
+/// The pool of the buffers reused for building the message batches on the append path.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct BufferPoolConfig {
+    pub enabled: bool,
+    pub max_buffers: u32,
+    pub max_buffer_size: IggyByteSize,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TieringS3Config {
+    pub key_id: String,
+    pub key_secret: String,
+    pub bucket: String,
+    pub endpoint: Option<String>,
+    pub region: Option<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SegmentConfig {
     pub size: IggyByteSize,
     pub cache_indexes: bool,
@@ -143,10 +443,33 @@ pub struct SegmentConfig {
     pub archive_expired: bool,
     #[serde_as(as = "DisplayFromStr")]
     pub server_confirmation: Confirmation,
+    pub io_backend: IoBackend,
+}
+
+/// The backend used for reading and writing the segment log files.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum IoBackend {
+    #[default]
+    #[display("tokio")]
+    Tokio,
+    #[display("io_uring")]
+    IoUring,
+}
+
+impl FromStr for IoBackend {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "tokio" => Ok(IoBackend::Tokio),
+            "io_uring" | "io-uring" => Ok(IoBackend::IoUring),
+            _ => Err(format!("Unknown I/O backend: {}", s)),
+        }
+    }
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct StateConfig {
     pub enforce_fsync: bool,
     pub max_file_operation_retries: u32,
@@ -167,6 +490,10 @@ impl SystemConfig {
         format!("{}/log", self.get_state_path())
     }
 
+    pub fn get_cluster_state_path(&self) -> String {
+        format!("{}/cluster", self.get_state_path())
+    }
+
     pub fn get_state_info_path(&self) -> String {
         format!("{}/info", self.get_state_path())
     }
@@ -186,6 +513,18 @@ impl SystemConfig {
         )
     }
 
+    pub fn get_migration_backup_path(&self) -> String {
+        format!("{}/{}", self.get_backup_path(), self.backup.migration.path)
+    }
+
+    pub fn get_backup_archive_path(&self) -> String {
+        format!("{}/{}", self.get_backup_path(), self.backup.archive.path)
+    }
+
+    pub fn get_data_version_path(&self) -> String {
+        format!("{}/data_version", self.get_state_path())
+    }
+
     pub fn get_runtime_path(&self) -> String {
         format!("{}/{}", self.get_system_path(), self.runtime.path)
     }
@@ -214,6 +553,14 @@ impl SystemConfig {
         )
     }
 
+    pub fn get_key_routes_path(&self, stream_id: u32, topic_id: u32) -> String {
+        format!("{}/key_routes", self.get_topic_path(stream_id, topic_id))
+    }
+
+    pub fn get_bookmarks_path(&self, stream_id: u32, topic_id: u32) -> String {
+        format!("{}/bookmarks", self.get_topic_path(stream_id, topic_id))
+    }
+
     pub fn get_partition_path(&self, stream_id: u32, topic_id: u32, partition_id: u32) -> String {
         format!(
             "{}/{}",
@@ -222,6 +569,33 @@ impl SystemConfig {
         )
     }
 
+    /// Checks if the partitions can be stored in the provided directory, which is either the system path or one of the configured data directories.
+    pub fn is_data_directory(&self, directory: &str) -> bool {
+        directory == self.path
+            || self
+                .partition
+                .data_directories
+                .iter()
+                .any(|data_directory| data_directory == directory)
+    }
+
+    pub fn get_data_directory_partition_path(
+        &self,
+        directory: &str,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> String {
+        if directory == self.path {
+            return self.get_partition_path(stream_id, topic_id, partition_id);
+        }
+
+        format!(
+            "{directory}/{}/{stream_id}/{}/{topic_id}/{}/{partition_id}",
+            self.stream.path, self.topic.path, self.partition.path
+        )
+    }
+
     pub fn get_offsets_path(&self, stream_id: u32, topic_id: u32, partition_id: u32) -> String {
         format!(
             "{}/offsets",
@@ -253,6 +627,14 @@ impl SystemConfig {
         )
     }
 
+    pub fn get_audit_log_path(&self) -> String {
+        format!("{}/{}", self.get_system_path(), self.audit.file.path)
+    }
+
+    pub fn get_tiering_cache_path(&self) -> String {
+        format!("{}/{}", self.get_system_path(), self.tiering.cache_path)
+    }
+
     pub fn get_segment_path(
         &self,
         stream_id: u32,
@@ -30,11 +30,15 @@ pub struct TcpConfig {
     pub socket: TcpSocketConfig,
 }
 
+#[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TcpTlsConfig {
     pub enabled: bool,
     pub certificate: String,
     pub password: String,
+    pub client_certificates: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub reload_interval: IggyDuration,
 }
 
 #[serde_as]
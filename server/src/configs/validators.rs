@@ -19,22 +19,37 @@
 extern crate sysinfo;
 
 use super::server::{
-    ArchiverConfig, DataMaintenanceConfig, MessageSaverConfig, MessagesMaintenanceConfig,
-    StateMaintenanceConfig, TelemetryConfig,
+    ArchiverConfig, BackgroundIoConfig, DataMaintenanceConfig, MessageSaverConfig,
+    MessagesMaintenanceConfig, StateMaintenanceConfig, TelemetryConfig,
 };
 use super::system::CompressionConfig;
 use crate::archiver::ArchiverKindType;
-use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
-use crate::configs::system::{CacheConfig, SegmentConfig};
+use crate::audit::AuditSinkKindType;
+use crate::configs::cluster::ClusterConfig;
+use crate::configs::http::{
+    HttpJwtConfig, HttpJwtProviderConfig, HttpRateLimitConfig, HttpRateLimitQuotaConfig,
+};
+use crate::configs::server::{PersonalAccessTokenConfig, ProxyConfig, ServerConfig};
+use crate::configs::standby::StandbyConfig;
+use crate::configs::system::{
+    AuditConfig, AuthorizationConfig, BufferPoolConfig, CacheConfig, CertificateAuthConfig,
+    ClientAccessConfig, ConsumerGroupConfig, ConsumerGroupSloConfig, MasterKeySource,
+    PartitionConfig, PollingConfig, QuotaMode, QuotasConfig, RequestLanesConfig, SegmentConfig,
+    SegmentEncryptionConfig, StorageLimits, StorageUsageConfig, TenancyConfig, TieringConfig,
+};
 use crate::configs::COMPONENT;
 use crate::server_error::ConfigError;
+use crate::streaming::clients::access_rules::ClientAccessRules;
 use crate::streaming::segments::*;
+use crate::streaming::users::authorizers::AuthorizerKindType;
 use error_set::ErrContext;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
 use iggy::validatable::Validatable;
+use std::collections::HashSet;
+use std::net::SocketAddr;
 use sysinfo::{Pid, ProcessesToUpdate, System};
 
 impl Validatable<ConfigError> for ServerConfig {
@@ -52,9 +67,23 @@ impl Validatable<ConfigError> for ServerConfig {
         self.system.segment.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate segment config")
         })?;
+        self.system
+            .segment_encryption
+            .validate()
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to validate segment encryption config"
+                )
+            })?;
         self.system.cache.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate cache config")
         })?;
+        self.system
+            .partition
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate partition config")
+            })?;
         self.system
             .compression
             .validate()
@@ -64,6 +93,85 @@ impl Validatable<ConfigError> for ServerConfig {
         self.telemetry.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate telemetry config")
         })?;
+        self.proxy.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate proxy config")
+        })?;
+        self.cluster.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate cluster config")
+        })?;
+        self.standby.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate standby config")
+        })?;
+        // The standby replaces the clustering for the deployments not using it, so they can't be combined.
+        if self.cluster.enabled && (self.standby.enabled || self.standby.shipping.enabled) {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+        self.system
+            .consumer_group
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate consumer group config")
+            })?;
+        self.system
+            .client_access
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate client access config")
+            })?;
+        self.system
+            .certificate_auth
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate certificate auth config")
+            })?;
+        self.system.polling.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate polling config")
+        })?;
+        self.system.tiering.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate tiering config")
+        })?;
+        self.system.audit.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate audit config")
+        })?;
+        self.system
+            .authorization
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate authorization config")
+            })?;
+        self.system.tenancy.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate tenancy config")
+        })?;
+        self.system.quotas.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate quotas config")
+        })?;
+        self.system
+            .storage_usage
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate storage usage config")
+            })?;
+        self.system
+            .request_lanes
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate request lanes config")
+            })?;
+        self.system
+            .buffer_pool
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate buffer pool config")
+            })?;
+        self.http
+            .rate_limit
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate HTTP rate limit config")
+            })?;
+        self.http.jwt.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate HTTP JWT config")
+        })?;
 
         let topic_size = match self.system.topic.max_size {
             MaxTopicSize::Custom(size) => Ok(size.as_bytes_u64()),
@@ -85,6 +193,12 @@ impl Validatable<ConfigError> for ServerConfig {
             return Err(ConfigError::InvalidConfiguration);
         }
 
+        let client_certificates = (self.tcp.tls.enabled && self.tcp.tls.client_certificates)
+            || (self.quic.enabled && self.quic.certificate.client_certificates);
+        if client_certificates && !self.system.certificate_auth.enabled {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
         Ok(())
     }
 }
@@ -152,6 +266,10 @@ impl Validatable<ConfigError> for CacheConfig {
             return Err(ConfigError::CacheConfigValidationFailure);
         }
 
+        if self.partition_min_size > limit_bytes || self.read_window.is_zero() {
+            return Err(ConfigError::CacheConfigValidationFailure);
+        }
+
         if limit_bytes > (total_memory as f64 * 0.75) as u64 {
             println!(
                 "Cache configuration -> cache size exceeds 75% of total memory. Set to: {} ({:.2}% of total memory: {}).",
@@ -178,6 +296,20 @@ impl Validatable<ConfigError> for SegmentConfig {
     }
 }
 
+impl Validatable<ConfigError> for PartitionConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.linger_time.is_zero() && self.max_accumulation_size.as_bytes_u64() == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.fsync_check_interval.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ConfigError> for MessageSaverConfig {
     fn validate(&self) -> Result<(), ConfigError> {
         if self.enabled && self.interval.is_zero() {
@@ -188,6 +320,393 @@ impl Validatable<ConfigError> for MessageSaverConfig {
     }
 }
 
+impl Validatable<ConfigError> for ProxyConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !(0.0..=1.0).contains(&self.reset_probability) {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ClusterConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.id == 0
+            || self.address.parse::<SocketAddr>().is_err()
+            || self.client_address.is_empty()
+            || self.secret.is_empty()
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.tls.enabled
+            && (self.tls.cert_file.is_empty()
+                || self.tls.key_file.is_empty()
+                || self.tls.ca_file.is_empty()
+                || self.tls.domain.is_empty())
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.heartbeat_interval.is_zero()
+            || self.heartbeat_interval.as_micros() >= self.election_timeout.as_micros()
+            || self.replication_timeout.is_zero()
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.min_insync_replicas == 0
+            || self.min_insync_replicas as usize > self.nodes.len() + 1
+            || self.replica_fetch_interval.is_zero()
+            || self.replica_fetch_messages == 0
+            || self.replica_max_lag.as_micros() <= self.replica_fetch_interval.as_micros()
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        let mut ids = HashSet::from([self.id]);
+        for node in &self.nodes {
+            if !ids.insert(node.id)
+                || node.address.parse::<SocketAddr>().is_err()
+                || node.client_address.is_empty()
+            {
+                return Err(ConfigError::InvalidConfiguration);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for StandbyConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.shipping.enabled && self.shipping.address.parse::<SocketAddr>().is_err() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.primary_address.is_empty()
+            || self.sync_interval.is_zero()
+            || self.fetch_messages == 0
+            || self.fetch_state_entries == 0
+            || self.request_timeout.is_zero()
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ConsumerGroupConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.interval.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        self.slo.validate()
+    }
+}
+
+impl Validatable<ConfigError> for HttpRateLimitConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.cleanup_interval.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        self.ip.validate()?;
+        self.user.validate()
+    }
+}
+
+impl Validatable<ConfigError> for HttpRateLimitQuotaConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.requests_per_second == 0 || self.burst == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for HttpJwtConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        for (index, provider) in self.providers.iter().enumerate() {
+            provider.validate()?;
+            if self.valid_issuers.contains(&provider.issuer)
+                || self.providers[..index]
+                    .iter()
+                    .any(|other| other.issuer == provider.issuer)
+            {
+                return Err(ConfigError::InvalidConfiguration);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for HttpJwtProviderConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.name.is_empty() || self.issuer.is_empty() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.subject_users.is_empty() && self.role_users.is_empty() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if !(self.jwks_url.starts_with("http://") || self.jwks_url.starts_with("https://")) {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.jwks_refresh_interval.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if !self.role_users.is_empty() && self.roles_claim.is_empty() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ConsumerGroupSloConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.interval.is_zero() || self.max_lag.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if !self.webhook_url.is_empty() && reqwest::Url::parse(&self.webhook_url).is_err() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ClientAccessConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if ClientAccessRules::from_config(self).is_err() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for CertificateAuthConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.ca_file.trim().is_empty() || self.denylist_path.trim().is_empty() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self
+            .users
+            .iter()
+            .any(|(subject, username)| subject.trim().is_empty() || username.trim().is_empty())
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for SegmentEncryptionConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let source = match self.master_key_source {
+            MasterKeySource::File => &self.master_key_path,
+            MasterKeySource::Env => &self.master_key_env,
+        };
+        if source.trim().is_empty() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for PollingConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_response_size.as_bytes_u64() == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for TieringConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.cache_path.is_empty()
+            || self.s3.bucket.is_empty()
+            || self.max_cache_size.as_bytes_u64() == 0
+            || self.interval.is_zero()
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for AuditConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.max_entries == 0 || self.flush_interval.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        let is_sink_valid = match self.sink {
+            AuditSinkKindType::File => !self.file.path.is_empty(),
+            AuditSinkKindType::Syslog => self.syslog.address.parse::<SocketAddr>().is_ok(),
+            AuditSinkKindType::Topic => {
+                !self.topic.stream.is_empty() && !self.topic.topic.is_empty()
+            }
+        };
+        if !is_sink_valid {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for AuthorizationConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        let is_backend_valid = match self.backend {
+            AuthorizerKindType::Builtin => true,
+            AuthorizerKindType::Opa => {
+                (self.opa.url.starts_with("http://") || self.opa.url.starts_with("https://"))
+                    && !self.opa.timeout.is_zero()
+            }
+            AuthorizerKindType::Policy => !self.policy.path.is_empty(),
+        };
+        if !is_backend_valid {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for TenancyConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        // The user can belong to a single tenant only.
+        let mut users = HashSet::new();
+        for tenant in self.tenants.values() {
+            if tenant.prefix.trim().is_empty() {
+                return Err(ConfigError::InvalidConfiguration);
+            }
+
+            if !tenant.users.iter().all(|user| users.insert(user)) {
+                return Err(ConfigError::InvalidConfiguration);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for QuotasConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.mode == QuotaMode::Delay && self.max_delay.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for StorageUsageConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.interval.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        self.stream.validate()?;
+        self.topic.validate()
+    }
+}
+
+impl Validatable<ConfigError> for RequestLanesConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.admin_max_concurrency == 0 || self.data_max_concurrency == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for BufferPoolConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.enabled && (self.max_buffers == 0 || self.max_buffer_size.as_bytes_u64() == 0) {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for StorageLimits {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.soft_limit.as_bytes_u64() > 0
+            && self.hard_limit.as_bytes_u64() > 0
+            && self.soft_limit.as_bytes_u64() > self.hard_limit.as_bytes_u64()
+        {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ConfigError> for DataMaintenanceConfig {
     fn validate(&self) -> Result<(), ConfigError> {
         self.archiver.validate().with_error_context(|error| {
@@ -196,9 +715,15 @@ impl Validatable<ConfigError> for DataMaintenanceConfig {
         self.messages.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate messages maintenance config")
         })?;
+        if self.messages.archive_before_delete && !self.archiver.enabled {
+            return Err(ConfigError::InvalidConfiguration);
+        }
         self.state.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate state maintenance config")
         })?;
+        self.io.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate background I/O config")
+        })?;
         Ok(())
     }
 }
@@ -274,6 +799,16 @@ impl Validatable<ConfigError> for StateMaintenanceConfig {
     }
 }
 
+impl Validatable<ConfigError> for BackgroundIoConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.throttling_enabled && self.max_throughput.as_bytes_u64() == 0 {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ConfigError> for PersonalAccessTokenConfig {
     fn validate(&self) -> Result<(), ConfigError> {
         if self.max_tokens_per_user == 0 {
@@ -0,0 +1,119 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::{AppState, RequestDetails};
+use crate::streaming::diagnostics::metrics::TransportLabel;
+use crate::streaming::users::authorizers::{PROMOTE_STANDBY, SHUTDOWN_SERVER, UPDATE_CONFIG};
+use axum::body::Body;
+use axum::{
+    extract::{MatchedPath, State},
+    http::{Method, Request, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use iggy::command::*;
+use std::sync::Arc;
+
+/// Records the administrative requests in the audit log, using the same action names as the binary commands.
+/// The request path is recorded as the details, since the body might contain the secrets, e.g. passwords.
+pub async fn audit(
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let action = request
+        .extensions()
+        .get::<MatchedPath>()
+        .and_then(|path| get_action(request.method(), path.as_str()));
+    let Some(action) = action else {
+        return Ok(next.run(request).await);
+    };
+
+    let user_id = request
+        .extensions()
+        .get::<Identity>()
+        .map(|identity| identity.user_id)
+        .unwrap_or_default();
+    let Some(ip_address) = request
+        .extensions()
+        .get::<RequestDetails>()
+        .map(|details| details.ip_address)
+    else {
+        return Ok(next.run(request).await);
+    };
+
+    let details = format!("{} {}", request.method(), request.uri().path());
+    let response = next.run(request).await;
+    let status = response.status();
+    let error = (status.is_client_error() || status.is_server_error()).then(|| status.to_string());
+    let system = state.system.read().await;
+    system.audit_log.record(
+        system.clock().now(),
+        user_id,
+        ip_address,
+        TransportLabel::Http,
+        action,
+        details,
+        error,
+    );
+    Ok(response)
+}
+
+pub(crate) fn get_action(method: &Method, path: &str) -> Option<&'static str> {
+    let action = match (method.as_str(), path) {
+        ("POST", "/users") => CREATE_USER,
+        ("PUT", "/users/{user_id}") => UPDATE_USER,
+        ("DELETE", "/users/{user_id}") => DELETE_USER,
+        ("PUT", "/users/{user_id}/permissions") => UPDATE_PERMISSIONS,
+        ("PUT", "/users/{user_id}/password") => CHANGE_PASSWORD,
+        ("POST", "/personal-access-tokens") => CREATE_PERSONAL_ACCESS_TOKEN,
+        ("DELETE", "/personal-access-tokens/{name}") => DELETE_PERSONAL_ACCESS_TOKEN,
+        ("POST", "/streams") => CREATE_STREAM,
+        ("PUT", "/streams/{stream_id}") => UPDATE_STREAM,
+        ("DELETE", "/streams/{stream_id}") => DELETE_STREAM,
+        ("DELETE", "/streams/{stream_id}/purge") => PURGE_STREAM,
+        ("POST", "/streams/{stream_id}/topics") => CREATE_TOPIC,
+        ("PUT", "/streams/{stream_id}/topics/{topic_id}") => UPDATE_TOPIC,
+        ("DELETE", "/streams/{stream_id}/topics/{topic_id}") => DELETE_TOPIC,
+        ("DELETE", "/streams/{stream_id}/topics/{topic_id}/purge") => PURGE_TOPIC,
+        ("PUT", "/streams/{stream_id}/topics/{topic_id}/throttle") => SET_TOPIC_THROTTLE,
+        ("POST", "/streams/{stream_id}/topics/{topic_id}/partitions") => CREATE_PARTITIONS,
+        ("DELETE", "/streams/{stream_id}/topics/{topic_id}/partitions") => DELETE_PARTITIONS,
+        ("PUT", "/streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}") => {
+            UPDATE_PARTITION
+        }
+        ("POST", "/streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}/move") => {
+            MOVE_PARTITION
+        }
+        ("POST", "/streams/{stream_id}/topics/{topic_id}/consumer-groups") => CREATE_CONSUMER_GROUP,
+        ("DELETE", "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}") => {
+            DELETE_CONSUMER_GROUP
+        }
+        ("POST", "/schemas/subjects/{subject}/versions") => REGISTER_SCHEMA,
+        ("PUT", "/schemas/subjects/{subject}/compatibility") => UPDATE_SCHEMA_COMPATIBILITY,
+        ("PUT", "/config/{section}") => UPDATE_CONFIG,
+        ("POST", "/backups") => CREATE_BACKUP,
+        ("POST", "/backups/{name}/restore") => RESTORE_BACKUP,
+        ("POST", "/shutdown") => SHUTDOWN_SERVER,
+        ("POST", "/standby/promote") => PROMOTE_STANDBY,
+        ("PUT", "/quotas/users/{user_id}") => SET_QUOTA,
+        ("PUT", "/quotas/streams/{stream_id}/topics/{topic_id}") => SET_QUOTA,
+        _ => return None,
+    };
+    Some(action)
+}
@@ -0,0 +1,183 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::http::COMPONENT;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy::bookmarks::delete_bookmark::DeleteBookmark;
+use iggy::bookmarks::get_bookmark::GetBookmark;
+use iggy::bookmarks::store_bookmark::StoreBookmark;
+use iggy::command::{DELETE_CONSUMER_OFFSET, GET_CONSUMER_OFFSET, STORE_CONSUMER_OFFSET};
+use iggy::identifier::Identifier;
+use iggy::models::bookmark::Bookmark;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/bookmarks",
+            get(get_bookmarks).put(store_bookmark),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/bookmarks/{name}",
+            get(get_bookmark).delete(delete_bookmark),
+        )
+        .with_state(state)
+}
+
+async fn get_bookmark(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, name)): Path<(String, String, String)>,
+) -> Result<Json<Bookmark>, CustomError> {
+    let query = GetBookmark {
+        stream_id: Identifier::from_str_value(&stream_id)?,
+        topic_id: Identifier::from_str_value(&topic_id)?,
+        name,
+    };
+    query.validate()?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            GET_CONSUMER_OFFSET,
+            Some(&query.stream_id),
+            Some(&query.topic_id),
+        )
+        .await;
+    let system = state.system.read().await;
+    let Ok(bookmark) = system.get_bookmark(
+        &identity.session(),
+        &query.stream_id,
+        &query.topic_id,
+        &query.name,
+    ) else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    let Some(bookmark) = bookmark else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    Ok(Json(bookmark))
+}
+
+async fn get_bookmarks(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<Json<Vec<Bookmark>>, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            GET_CONSUMER_OFFSET,
+            Some(&stream_id),
+            Some(&topic_id),
+        )
+        .await;
+    let system = state.system.read().await;
+    let bookmarks = system
+        .get_bookmarks(
+            &identity.session(),
+            &stream_id,
+            &topic_id,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get bookmarks, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
+    Ok(Json(bookmarks))
+}
+
+async fn store_bookmark(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut command: Json<StoreBookmark>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            STORE_CONSUMER_OFFSET,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
+    let system = state.system.read().await;
+    system
+        .store_bookmark(
+            &identity.session(),
+            &command.0.stream_id,
+            &command.0.topic_id,
+            &command.0.name,
+            command.0.partition_id,
+            command.0.offset,
+        )
+        .await
+        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store bookmark: {}, stream ID: {}, topic ID: {}, partition ID: {}", command.0.name, stream_id, topic_id, command.0.partition_id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn delete_bookmark(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, name)): Path<(String, String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let command = DeleteBookmark {
+        stream_id: Identifier::from_str_value(&stream_id)?,
+        topic_id: Identifier::from_str_value(&topic_id)?,
+        name,
+    };
+    command.validate()?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            DELETE_CONSUMER_OFFSET,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
+    let system = state.system.read().await;
+    system
+        .delete_bookmark(
+            &identity.session(),
+            &command.stream_id,
+            &command.topic_id,
+            &command.name,
+        )
+        .await
+        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete bookmark: {}, stream ID: {}, topic ID: {}", command.name, stream_id, topic_id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
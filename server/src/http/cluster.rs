@@ -0,0 +1,60 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::audit::get_action;
+use crate::http::error::CustomError;
+use crate::http::shared::AppState;
+use crate::streaming::users::authorizers::{PROMOTE_STANDBY, SHUTDOWN_SERVER, UPDATE_CONFIG};
+use axum::body::Body;
+use axum::{
+    extract::{MatchedPath, State},
+    http::Request,
+    middleware::Next,
+    response::Response,
+};
+use iggy::command::{CREATE_BACKUP, SET_QUOTA};
+use std::sync::Arc;
+
+/// Rejects the requests changing the metadata on the cluster follower, returning the ID of the leader node instead,
+/// and on the standby server which hasn't been promoted yet.
+/// The same requests as in the audit log are matched, except the ones affecting this node only.
+pub async fn cluster_leader(
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, CustomError> {
+    let requires_leader = request
+        .extensions()
+        .get::<MatchedPath>()
+        .and_then(|path| get_action(request.method(), path.as_str()))
+        .is_some_and(|action| {
+            ![
+                CREATE_BACKUP,
+                UPDATE_CONFIG,
+                SHUTDOWN_SERVER,
+                PROMOTE_STANDBY,
+                SET_QUOTA,
+            ]
+            .contains(&action)
+        });
+    if requires_leader {
+        state.system.read().await.ensure_cluster_leader().await?;
+    }
+
+    Ok(next.run(request).await)
+}
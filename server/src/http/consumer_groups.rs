@@ -16,19 +16,21 @@
  * under the License.
  */
 
-use crate::http::error::CustomError;
+use crate::http::error::{CustomError, ErrorResponse};
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
 use crate::state::command::EntryCommand;
 use crate::state::models::CreateConsumerGroupWithId;
-use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::get;
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
+use iggy::command::{
+    CREATE_CONSUMER_GROUP, DELETE_CONSUMER_GROUP, GET_CONSUMER_GROUP, GET_CONSUMER_GROUPS,
+};
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use iggy::identifier::Identifier;
@@ -36,6 +38,7 @@ use iggy::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 use tracing::instrument;
+use utoipa::OpenApi;
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -50,6 +53,30 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+/// The OpenAPI specification of the consumer groups endpoints.
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_consumer_groups,
+    create_consumer_group,
+    get_consumer_group,
+    delete_consumer_group
+))]
+pub struct ConsumerGroupsApi;
+
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("group_id" = String, Path, description = "Unique consumer group ID (numeric or name)."),
+    ),
+    responses(
+        (status = 200, description = "The consumer group details.", body = ConsumerGroupDetails),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 async fn get_consumer_group(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -58,9 +85,18 @@ async fn get_consumer_group(
     let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
     let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
     let identifier_group_id = Identifier::from_str_value(&group_id)?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            GET_CONSUMER_GROUP,
+            Some(&identifier_stream_id),
+            Some(&identifier_topic_id),
+        )
+        .await;
     let system = state.system.read().await;
     let Ok(consumer_group) = system.get_consumer_group(
-        &Session::stateless(identity.user_id, identity.ip_address),
+        &identity.session(),
         &identifier_stream_id,
         &identifier_topic_id,
         &identifier_group_id,
@@ -76,6 +112,19 @@ async fn get_consumer_group(
     Ok(Json(consumer_group))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+    ),
+    responses(
+        (status = 200, description = "The consumer groups of the topic.", body = Vec<ConsumerGroup>),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 async fn get_consumer_groups(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -83,16 +132,35 @@ async fn get_consumer_groups(
 ) -> Result<Json<Vec<ConsumerGroup>>, CustomError> {
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let topic_id = Identifier::from_str_value(&topic_id)?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            GET_CONSUMER_GROUPS,
+            Some(&stream_id),
+            Some(&topic_id),
+        )
+        .await;
     let system = state.system.read().await;
-    let consumer_groups = system.get_consumer_groups(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &stream_id,
-        &topic_id,
-    )?;
+    let consumer_groups = system.get_consumer_groups(&identity.session(), &stream_id, &topic_id)?;
     let consumer_groups = mapper::map_consumer_groups(&consumer_groups).await;
     Ok(Json(consumer_groups))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+    ),
+    request_body = CreateConsumerGroup,
+    responses(
+        (status = 201, description = "The created consumer group.", body = ConsumerGroupDetails),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_create_consumer_group", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn create_consumer_group(
     State(state): State<Arc<AppState>>,
@@ -103,14 +171,24 @@ async fn create_consumer_group(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            CREATE_CONSUMER_GROUP,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
     let mut system = state.system.write().await;
     let consumer_group = system
             .create_consumer_group(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &identity.session(),
                 &command.stream_id,
                 &command.topic_id,
                 command.group_id,
                 &command.name,
+                command.assignment_strategy,
             )
             .await
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to create consumer group, stream ID: {}, topic ID: {}, group ID: {:?}", stream_id, topic_id, command.group_id))?;
@@ -120,17 +198,33 @@ async fn create_consumer_group(
     drop(consumer_group);
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             identity.user_id,
             &EntryCommand::CreateConsumerGroup(CreateConsumerGroupWithId { group_id, command }),
         )
         .await?;
+    drop(system);
+    commit.wait().await?;
 
     Ok((StatusCode::CREATED, Json(consumer_group_details)))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}",
+    tag = "consumer_groups",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("group_id" = String, Path, description = "Unique consumer group ID (numeric or name)."),
+    ),
+    responses(
+        (status = 204, description = "The consumer group has been deleted."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_delete_consumer_group", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_group_id = group_id))]
 async fn delete_consumer_group(
     State(state): State<Arc<AppState>>,
@@ -141,10 +235,19 @@ async fn delete_consumer_group(
     let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
     let identifier_group_id = Identifier::from_str_value(&group_id)?;
 
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            DELETE_CONSUMER_GROUP,
+            Some(&identifier_stream_id),
+            Some(&identifier_topic_id),
+        )
+        .await;
     let mut system = state.system.write().await;
     system
             .delete_consumer_group(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &identity.session(),
                 &identifier_stream_id,
                 &identifier_topic_id,
                 &identifier_group_id,
@@ -153,9 +256,9 @@ async fn delete_consumer_group(
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete consumer group with ID: {group_id} for topic with ID: {topic_id} in stream with ID: {stream_id}"))?;
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             identity.user_id,
             &EntryCommand::DeleteConsumerGroup(DeleteConsumerGroup {
                 stream_id: identifier_stream_id,
@@ -164,6 +267,8 @@ async fn delete_consumer_group(
             }),
         )
         .await?;
+    drop(system);
+    commit.wait().await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
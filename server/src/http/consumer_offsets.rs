@@ -20,12 +20,12 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
-use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::routing::{delete, get};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
+use iggy::command::{DELETE_CONSUMER_OFFSET, GET_CONSUMER_OFFSET, STORE_CONSUMER_OFFSET};
 use iggy::consumer::Consumer;
 use iggy::consumer_offsets::delete_consumer_offset::DeleteConsumerOffset;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
@@ -57,11 +57,20 @@ async fn get_consumer_offset(
     query.stream_id = Identifier::from_str_value(&stream_id)?;
     query.topic_id = Identifier::from_str_value(&topic_id)?;
     query.validate()?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            GET_CONSUMER_OFFSET,
+            Some(&query.stream_id),
+            Some(&query.topic_id),
+        )
+        .await;
     let consumer = Consumer::new(query.0.consumer.id);
     let system = state.system.read().await;
     let Ok(offset) = system
         .get_consumer_offset(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &consumer,
             &query.0.stream_id,
             &query.0.topic_id,
@@ -88,11 +97,20 @@ async fn store_consumer_offset(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            STORE_CONSUMER_OFFSET,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
     let consumer = Consumer::new(command.0.consumer.id);
     let system = state.system.read().await;
     system
         .store_consumer_offset(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             consumer,
             &command.0.stream_id,
             &command.0.topic_id,
@@ -111,10 +129,19 @@ async fn delete_consumer_offset(
     query: Query<DeleteConsumerOffset>,
 ) -> Result<StatusCode, CustomError> {
     let consumer = Consumer::new(consumer_id.try_into()?);
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            DELETE_CONSUMER_OFFSET,
+            Some(&query.stream_id),
+            Some(&query.topic_id),
+        )
+        .await;
     let system = state.system.read().await;
     system
         .delete_consumer_offset(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             consumer,
             &query.stream_id,
             &query.topic_id,
@@ -17,6 +17,7 @@
  */
 
 use crate::http::shared::RequestDetails;
+use crate::log::trace_context;
 use crate::streaming::utils::random_id;
 use axum::body::Body;
 use axum::{
@@ -27,7 +28,7 @@ use axum::{
 };
 use std::net::SocketAddr;
 use tokio::time::Instant;
-use tracing::{debug, error};
+use tracing::{debug, error, info_span, Instrument, Span};
 
 pub async fn request_diagnostics(
     ConnectInfo(ip_address): ConnectInfo<SocketAddr>,
@@ -45,12 +46,21 @@ pub async fn request_diagnostics(
         request.method(),
         path_and_query,
     );
+    // The span continues the trace of the client, if it was passed via the `traceparent` header.
+    let span = if trace_context::is_enabled() {
+        let span =
+            info_span!("trace_http_request", method = %request.method(), path = %path_and_query);
+        trace_context::set_remote_parent(&span, request.headers());
+        span
+    } else {
+        Span::none()
+    };
     request.extensions_mut().insert(RequestDetails {
         request_id,
         ip_address,
     });
     let now = Instant::now();
-    let result = Ok(next.run(request).await);
+    let result = Ok(next.run(request).instrument(span).await);
     if let Ok(response) = &result {
         let status = response.status();
         if status != StatusCode::NOT_FOUND && status >= StatusCode::BAD_REQUEST {
@@ -23,6 +23,7 @@ use iggy::error::IggyError;
 use serde::Serialize;
 use thiserror::Error;
 use tracing::error;
+use utoipa::ToSchema;
 
 #[derive(Debug, Error)]
 pub enum CustomError {
@@ -32,7 +33,7 @@ pub enum CustomError {
     ResourceNotFound,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorResponse {
     pub id: u32,
     pub code: String,
@@ -49,18 +50,43 @@ impl IntoResponse for CustomError {
                     IggyError::StreamIdNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::TopicIdNotFound(_, _) => StatusCode::NOT_FOUND,
                     IggyError::PartitionNotFound(_, _, _) => StatusCode::NOT_FOUND,
+                    IggyError::PartitionNameNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     IggyError::SegmentNotFound => StatusCode::NOT_FOUND,
                     IggyError::ClientNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::ConsumerGroupIdNotFound(_, _) => StatusCode::NOT_FOUND,
                     IggyError::ConsumerGroupNameNotFound(_, _) => StatusCode::NOT_FOUND,
                     IggyError::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     IggyError::ConsumerOffsetNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::BookmarkNotFound(_, _, _) => StatusCode::NOT_FOUND,
+                    IggyError::SchemaIdNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::SchemaSubjectNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::SchemaVersionNotFound(_, _) => StatusCode::NOT_FOUND,
+                    IggyError::IncompatibleSchema(_, _) => StatusCode::CONFLICT,
                     IggyError::ResourceNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::BackupNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::BackupAlreadyExists(_) => StatusCode::CONFLICT,
                     IggyError::Unauthenticated => StatusCode::UNAUTHORIZED,
                     IggyError::AccessTokenMissing => StatusCode::UNAUTHORIZED,
                     IggyError::InvalidAccessToken => StatusCode::UNAUTHORIZED,
                     IggyError::InvalidPersonalAccessToken => StatusCode::UNAUTHORIZED,
                     IggyError::Unauthorized => StatusCode::FORBIDDEN,
+                    IggyError::ClientAccessDenied => StatusCode::FORBIDDEN,
+                    IggyError::TopicThrottled(_, _) => StatusCode::TOO_MANY_REQUESTS,
+                    IggyError::TopicBeingDeleted(_, _) => StatusCode::CONFLICT,
+                    IggyError::NotClusterLeader(_) => StatusCode::SERVICE_UNAVAILABLE,
+                    IggyError::ClusterQuorumNotReached => StatusCode::SERVICE_UNAVAILABLE,
+                    IggyError::NotPartitionLeader(_, _, _, _) => StatusCode::SERVICE_UNAVAILABLE,
+                    IggyError::NotEnoughInSyncReplicas(_, _) => StatusCode::SERVICE_UNAVAILABLE,
+                    IggyError::ServerShuttingDown => StatusCode::SERVICE_UNAVAILABLE,
+                    IggyError::StandbyNotPromoted => StatusCode::SERVICE_UNAVAILABLE,
+                    IggyError::TenantNamespaceViolation(_) => StatusCode::FORBIDDEN,
+                    IggyError::TenantStreamsQuotaExceeded(_, _) => StatusCode::FORBIDDEN,
+                    IggyError::TenantTopicsQuotaExceeded(_, _) => StatusCode::FORBIDDEN,
+                    IggyError::TenantPartitionsQuotaExceeded(_, _) => StatusCode::FORBIDDEN,
+                    IggyError::TenantStorageQuotaExceeded(_, _) => StatusCode::INSUFFICIENT_STORAGE,
+                    IggyError::TenantThroughputQuotaExceeded(_) => StatusCode::TOO_MANY_REQUESTS,
+                    IggyError::QuotaExceeded(_) => StatusCode::TOO_MANY_REQUESTS,
+                    IggyError::StorageLimitExceeded(_, _, _) => StatusCode::INSUFFICIENT_STORAGE,
                     _ => StatusCode::BAD_REQUEST,
                 };
                 (status_code, Json(ErrorResponse::from_error(error)))
@@ -95,6 +121,9 @@ impl ErrorResponse {
                 IggyError::StreamNameAlreadyExists(_) => Some("name".to_string()),
                 IggyError::InvalidTopicName => Some("name".to_string()),
                 IggyError::TopicNameAlreadyExists(_, _) => Some("name".to_string()),
+                IggyError::InvalidPartitionName => Some("name".to_string()),
+                IggyError::PartitionNameAlreadyExists(_, _, _) => Some("name".to_string()),
+                IggyError::InvalidDataDirectory(_) => Some("directory".to_string()),
                 IggyError::InvalidStreamId => Some("stream_id".to_string()),
                 IggyError::StreamIdAlreadyExists(_) => Some("stream_id".to_string()),
                 IggyError::InvalidTopicId => Some("topic_id".to_string()),
@@ -107,6 +136,8 @@ impl ErrorResponse {
                 IggyError::ConsumerGroupNameAlreadyExists(_, _) => Some("name".to_string()),
                 IggyError::UserAlreadyExists => Some("username".to_string()),
                 IggyError::PersonalAccessTokenAlreadyExists(_, _) => Some("name".to_string()),
+                IggyError::InvalidBackupName => Some("name".to_string()),
+                IggyError::BackupAlreadyExists(_) => Some("name".to_string()),
                 _ => None,
             },
         }
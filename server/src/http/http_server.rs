@@ -17,18 +17,27 @@
  */
 
 use crate::configs::http::{HttpConfig, HttpCorsConfig};
+use crate::http::audit::audit;
+use crate::http::client_access::client_access;
+use crate::http::cluster::cluster_leader;
 use crate::http::diagnostics::request_diagnostics;
 use crate::http::jwt::cleaner::start_expired_tokens_cleaner;
 use crate::http::jwt::jwt_manager::JwtManager;
 use crate::http::jwt::middleware::jwt_auth;
+use crate::http::jwt::providers::JwtProviders;
 use crate::http::metrics::metrics;
+use crate::http::rate_limit::{rate_limit, start_rate_limits_cleaner, HttpRateLimits};
+use crate::http::security_headers::{security_headers, SecurityHeaders};
 use crate::http::shared::AppState;
+use crate::http::shutdown::in_flight_requests;
 use crate::http::*;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::certificate_watcher::CertificateWatcher;
 use axum::extract::DefaultBodyLimit;
 use axum::http::Method;
 use axum::{middleware, Router};
 use axum_server::tls_rustls::RustlsConfig;
+use axum_server::Handle;
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -53,15 +62,87 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         .merge(topics::router(app_state.clone()))
         .merge(consumer_groups::router(app_state.clone()))
         .merge(consumer_offsets::router(app_state.clone()))
+        .merge(bookmarks::router(app_state.clone()))
+        .merge(schemas::router(app_state.clone()))
+        .merge(quotas::router(app_state.clone()))
         .merge(partitions::router(app_state.clone()))
         .merge(messages::router(app_state.clone()))
+        .merge(openapi::router());
+
+    if config.websocket.enabled {
+        app = app.merge(websocket::router(app_state.clone(), &config.websocket));
+    }
+
+    let requires_leader = {
+        let system = app_state.system.read().await;
+        system.is_clustered() || system.is_standby()
+    };
+    if requires_leader {
+        app = app.layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            cluster_leader,
+        ));
+    }
+
+    if app_state.system.read().await.audit_log.is_enabled() {
+        app = app.layer(middleware::from_fn_with_state(app_state.clone(), audit));
+    }
+
+    if app_state.rate_limits.is_enabled() {
+        app = app.layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            rate_limit,
+        ));
+        start_rate_limits_cleaner(app_state.clone(), config.rate_limit.cleanup_interval);
+    }
+
+    app = app
         .layer(DefaultBodyLimit::max(
             config.max_request_size.as_bytes_u64() as usize,
         ))
-        .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
+        .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth))
+        .layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            client_access,
+        ))
+        .layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            in_flight_requests,
+        ));
 
     if config.cors.enabled {
-        app = app.layer(configure_cors(config.cors));
+        app = app.layer(configure_cors(
+            &config.cors,
+            &config.cors.allowed_origins,
+            &config.cors.allowed_methods,
+        ));
+    }
+
+    let mut health = system::health_router().layer(middleware::from_fn_with_state(
+        app_state.clone(),
+        client_access,
+    ));
+    if config.cors.health.enabled {
+        health = health.layer(configure_cors(
+            &config.cors,
+            &config.cors.health.allowed_origins,
+            &config.cors.health.allowed_methods,
+        ));
+    } else if config.cors.enabled {
+        health = health.layer(configure_cors(
+            &config.cors,
+            &config.cors.allowed_origins,
+            &config.cors.allowed_methods,
+        ));
+    }
+    app = app.merge(health);
+
+    if config.security_headers.enabled {
+        let headers = SecurityHeaders::from_config(&config.security_headers, config.tls.enabled);
+        app = app.layer(middleware::from_fn_with_state(
+            Arc::new(headers),
+            security_headers,
+        ));
     }
 
     if config.metrics.enabled {
@@ -70,6 +151,7 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
 
     start_expired_tokens_cleaner(app_state.clone());
     app = app.layer(middleware::from_fn(request_diagnostics));
+    let shutdown = app_state.shutdown.clone();
 
     if !config.tls.enabled {
         let listener = tokio::net::TcpListener::bind(config.address.clone())
@@ -84,6 +166,7 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
                 listener,
                 app.into_make_service_with_connect_info::<SocketAddr>(),
             )
+            .with_graceful_shutdown(async move { shutdown.requested().await })
             .await
             {
                 error!("Failed to start {api_name} server, error {}", error);
@@ -93,11 +176,27 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         address
     } else {
         let tls_config = RustlsConfig::from_pem_file(
-            PathBuf::from(config.tls.cert_file),
-            PathBuf::from(config.tls.key_file),
+            PathBuf::from(&config.tls.cert_file),
+            PathBuf::from(&config.tls.key_file),
         )
         .await
         .unwrap();
+        let reloaded_tls_config = tls_config.clone();
+        let (cert_file, key_file) = (config.tls.cert_file.clone(), config.tls.key_file.clone());
+        CertificateWatcher::new(
+            &[&config.tls.cert_file, &config.tls.key_file],
+            config.tls.reload_interval,
+        )
+        .start(api_name, move || {
+            let tls_config = reloaded_tls_config.clone();
+            let (cert_file, key_file) = (cert_file.clone(), key_file.clone());
+            async move {
+                tls_config
+                    .reload_from_pem_file(cert_file, key_file)
+                    .await
+                    .map_err(anyhow::Error::from)
+            }
+        });
 
         let listener = std::net::TcpListener::bind(config.address).unwrap();
         let address = listener
@@ -106,8 +205,15 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
 
         info!("Started {api_name} on: {address}");
 
+        let handle = Handle::new();
+        let shutdown_handle = handle.clone();
+        tokio::task::spawn(async move {
+            shutdown.requested().await;
+            shutdown_handle.graceful_shutdown(None);
+        });
         tokio::task::spawn(async move {
             if let Err(error) = axum_server::from_tcp_rustls(listener, tls_config)
+                .handle(handle)
                 .serve(app.into_make_service_with_connect_info::<SocketAddr>())
                 .await
             {
@@ -122,10 +228,12 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
 async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppState> {
     let tokens_path;
     let persister;
+    let shutdown;
     {
         let system = system.read().await;
         tokens_path = system.config.get_state_tokens_path();
         persister = system.storage.persister.clone();
+        shutdown = system.shutdown_coordinator();
     }
 
     let jwt_manager = JwtManager::from_config(persister, &tokens_path, &config.jwt);
@@ -138,14 +246,27 @@ async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppSt
         panic!("Failed to load revoked access tokens");
     }
 
+    let jwt_providers = JwtProviders::from_config(&config.jwt);
+    if let Err(error) = jwt_providers {
+        panic!("Failed to initialize JWT providers: {}", error);
+    }
+
     Arc::new(AppState {
         jwt_manager,
+        jwt_providers: jwt_providers.unwrap(),
+        rate_limits: HttpRateLimits::from_config(&config.rate_limit),
+        shutdown,
         system,
     })
 }
 
-fn configure_cors(config: HttpCorsConfig) -> CorsLayer {
-    let allowed_origins = match config.allowed_origins {
+/// Configures the CORS layer using the given origins and methods, which can be overridden for the specific routes.
+fn configure_cors(
+    config: &HttpCorsConfig,
+    allowed_origins: &[String],
+    allowed_methods: &[String],
+) -> CorsLayer {
+    let allowed_origins = match allowed_origins {
         origins if origins.is_empty() => AllowOrigin::default(),
         origins if origins.first().unwrap() == "*" => AllowOrigin::any(),
         origins => AllowOrigin::list(origins.iter().map(|s| s.parse().unwrap())),
@@ -165,8 +286,7 @@ fn configure_cors(config: HttpCorsConfig) -> CorsLayer {
         .map(|s| s.parse().unwrap())
         .collect::<Vec<_>>();
 
-    let allowed_methods = config
-        .allowed_methods
+    let allowed_methods = allowed_methods
         .iter()
         .filter(|s| !s.is_empty())
         .map(|s| match s.to_uppercase().as_str() {
@@ -183,11 +303,16 @@ fn configure_cors(config: HttpCorsConfig) -> CorsLayer {
         })
         .collect::<Vec<_>>();
 
-    CorsLayer::new()
+    let cors = CorsLayer::new()
         .allow_methods(allowed_methods)
         .allow_origin(allowed_origins)
         .allow_headers(allowed_headers)
         .expose_headers(exposed_headers)
         .allow_credentials(config.allow_credentials)
-        .allow_private_network(config.allow_private_network)
+        .allow_private_network(config.allow_private_network);
+    if config.max_age.is_zero() {
+        return cors;
+    }
+
+    cors.max_age(config.max_age.get_duration())
 }
@@ -17,7 +17,6 @@
  */
 
 use crate::http::shared::AppState;
-use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::{error, trace};
@@ -28,7 +27,7 @@ pub fn start_expired_tokens_cleaner(app_state: Arc<AppState>) {
         loop {
             interval_timer.tick().await;
             trace!("Deleting expired tokens...");
-            let now = IggyTimestamp::now().to_secs();
+            let now = app_state.system.read().await.clock.now().to_secs();
             app_state
                 .jwt_manager
                 .delete_expired_revoked_tokens(now)
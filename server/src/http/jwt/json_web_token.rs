@@ -16,9 +16,12 @@
  * under the License.
  */
 
+use crate::streaming::session::Session;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
 use iggy::models::user_info::UserId;
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct Identity {
@@ -26,6 +29,34 @@ pub struct Identity {
     pub token_expiry: u64,
     pub user_id: UserId,
     pub ip_address: SocketAddr,
+    pub token_scope: Option<Arc<PersonalAccessTokenScope>>,
+    session: Arc<Session>,
+}
+
+impl Identity {
+    pub fn new(
+        token_id: String,
+        token_expiry: u64,
+        user_id: UserId,
+        ip_address: SocketAddr,
+        token_scope: Option<Arc<PersonalAccessTokenScope>>,
+    ) -> Self {
+        let session = Session::stateless(user_id, ip_address).with_token_scope(token_scope.clone());
+        Self {
+            token_id,
+            token_expiry,
+            user_id,
+            ip_address,
+            token_scope,
+            session: Arc::new(session),
+        }
+    }
+
+    /// Returns the stateless session of the authenticated user, limited to the scope of its personal access token (if any).
+    /// The session is shared by the whole request, so that the decisions of the external authorizer resolved for it are kept.
+    pub fn session(&self) -> Arc<Session> {
+        self.session.clone()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -37,6 +68,9 @@ pub struct JwtClaims {
     pub iat: u64,
     pub exp: u64,
     pub nbf: u64,
+    // Set only for the access tokens issued when logging in with the scoped personal access token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<PersonalAccessTokenScope>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -26,6 +26,7 @@ use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
 use iggy::models::user_info::UserId;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
@@ -169,7 +170,11 @@ impl JwtManager {
         Ok(())
     }
 
-    pub fn generate(&self, user_id: UserId) -> Result<GeneratedToken, IggyError> {
+    pub fn generate(
+        &self,
+        user_id: UserId,
+        scope: Option<PersonalAccessTokenScope>,
+    ) -> Result<GeneratedToken, IggyError> {
         let header = Header::new(self.issuer.algorithm);
         let now = IggyTimestamp::now().to_secs();
         let iat = now;
@@ -188,6 +193,7 @@ impl JwtManager {
             iat,
             exp,
             nbf,
+            scope,
         };
 
         let access_token = encode::<JwtClaims>(&header, &claims, &self.issuer.key);
@@ -233,7 +239,7 @@ impl JwtManager {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to save revoked access token: {id}")
             })?;
-        self.generate(jwt_claims.claims.sub)
+        self.generate(jwt_claims.claims.sub, jwt_claims.claims.scope)
     }
 
     pub fn decode(
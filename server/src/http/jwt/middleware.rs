@@ -31,13 +31,17 @@ use std::sync::Arc;
 const COMPONENT: &str = "JWT_MIDDLEWARE";
 const AUTHORIZATION: &str = "authorization";
 const BEARER: &str = "Bearer ";
+const ACCESS_TOKEN_PARAM: &str = "access_token";
+const WEBSOCKET_PATH: &str = "/ws";
 const UNAUTHORIZED: StatusCode = StatusCode::UNAUTHORIZED;
 
 const PUBLIC_PATHS: &[&str] = &[
     "/",
     "/metrics",
+    "/openapi.json",
     "/ping",
     "/stats",
+    "/swagger-ui",
     "/users/login",
     "/users/refresh-token",
     "/personal-access-tokens/login",
@@ -52,24 +56,47 @@ pub async fn jwt_auth(
         return Ok(next.run(request).await);
     }
 
-    let bearer = request
-        .headers()
-        .get(AUTHORIZATION)
-        .ok_or(UNAUTHORIZED)
-        .with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - missing or inaccessible Authorization header")
-        })?
-        .to_str()
-        .with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - invalid authorization header format")
-        })
-        .map_err(|_| UNAUTHORIZED)?;
+    let jwt_token = get_jwt_token(&request)?;
+    if let Some(provider) = state.jwt_providers.find(jwt_token) {
+        let claims = provider
+            .validate(jwt_token)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to validate JWT issued by provider: {}",
+                    provider.name()
+                )
+            })
+            .map_err(|_| UNAUTHORIZED)?;
+        if state.jwt_manager.is_token_revoked(&claims.token_id).await {
+            return Err(StatusCode::UNAUTHORIZED);
+        }
 
-    if !bearer.starts_with(BEARER) {
-        return Err(StatusCode::UNAUTHORIZED);
+        let user_id = {
+            let system = state.system.read().await;
+            provider
+                .find_user(&system, &claims)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to map JWT issued by provider: {} to user",
+                        provider.name()
+                    )
+                })
+                .map_err(|_| UNAUTHORIZED)?
+                .id
+        };
+        let request_details = request.extensions().get::<RequestDetails>().unwrap();
+        let identity = Identity::new(
+            claims.token_id,
+            claims.expiry,
+            user_id,
+            request_details.ip_address,
+            None,
+        );
+        request.extensions_mut().insert(identity);
+        return Ok(next.run(request).await);
     }
 
-    let jwt_token = &bearer[BEARER.len()..];
     let token_header = jsonwebtoken::decode_header(jwt_token)
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to decode JWT header")
@@ -91,12 +118,54 @@ pub async fn jwt_auth(
     }
 
     let request_details = request.extensions().get::<RequestDetails>().unwrap();
-    let identity = Identity {
-        token_id: jwt_claims.claims.jti,
-        token_expiry: jwt_claims.claims.exp,
-        user_id: jwt_claims.claims.sub,
-        ip_address: request_details.ip_address,
-    };
+    let identity = Identity::new(
+        jwt_claims.claims.jti,
+        jwt_claims.claims.exp,
+        jwt_claims.claims.sub,
+        request_details.ip_address,
+        jwt_claims.claims.scope.map(Arc::new),
+    );
     request.extensions_mut().insert(identity);
     Ok(next.run(request).await)
 }
+
+/// Returns the JWT from the Authorization header, or from the `access_token` query parameter
+/// when connecting to the WebSocket endpoint, as the browsers cannot set the headers for the WebSocket requests.
+fn get_jwt_token(request: &Request<Body>) -> Result<&str, StatusCode> {
+    if request.uri().path() == WEBSOCKET_PATH && request.headers().get(AUTHORIZATION).is_none() {
+        return request
+            .uri()
+            .query()
+            .and_then(|query| {
+                query.split('&').find_map(|param| {
+                    param
+                        .strip_prefix(ACCESS_TOKEN_PARAM)
+                        .and_then(|value| value.strip_prefix('='))
+                })
+            })
+            .filter(|token| !token.is_empty())
+            .ok_or(UNAUTHORIZED)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - missing access token query parameter")
+            });
+    }
+
+    let bearer = request
+        .headers()
+        .get(AUTHORIZATION)
+        .ok_or(UNAUTHORIZED)
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - missing or inaccessible Authorization header")
+        })?
+        .to_str()
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - invalid authorization header format")
+        })
+        .map_err(|_| UNAUTHORIZED)?;
+
+    if !bearer.starts_with(BEARER) {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    Ok(&bearer[BEARER.len()..])
+}
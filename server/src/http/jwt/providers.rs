@@ -0,0 +1,504 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::http::{HttpJwtConfig, HttpJwtProviderConfig};
+use crate::http::jwt::COMPONENT;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user::User;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMut;
+use iggy::locking::IggySharedMutFn;
+use iggy::utils::duration::IggyDuration;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::time::{Duration, Instant};
+use tracing::{error, info, warn};
+
+const JWKS_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+// The keys are fetched again for the unknown key ID at most this often, so that the tokens with the bogus key IDs can't flood the provider.
+const MIN_JWKS_REFETCH_INTERVAL: Duration = Duration::from_secs(30);
+const TOKEN_ID_CLAIM: &str = "jti";
+const SUBJECT_CLAIM: &str = "sub";
+const ISSUED_AT_CLAIM: &str = "iat";
+const EXPIRY_CLAIM: &str = "exp";
+
+/// The external OAuth2/OIDC providers, whose JWTs are accepted by the HTTP server in addition to the ones it issues.
+pub struct JwtProviders {
+    providers: Vec<JwtProvider>,
+}
+
+/// Validates the JWTs issued by the single external provider, using the keys fetched from its JWKS endpoint.
+pub struct JwtProvider {
+    config: HttpJwtProviderConfig,
+    clock_skew: IggyDuration,
+    client: reqwest::Client,
+    keys: IggySharedMut<JwksCache>,
+}
+
+#[derive(Default)]
+struct JwksCache {
+    keys: Vec<(Option<String>, DecodingKey)>,
+    fetched_at: Option<Instant>,
+}
+
+/// The claims of the validated external JWT, used to map it to the iggy user.
+#[derive(Debug)]
+pub struct ExternalClaims {
+    pub token_id: String,
+    pub expiry: u64,
+    pub subject: Option<String>,
+    pub roles: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssuerClaim {
+    iss: String,
+}
+
+impl JwtProviders {
+    pub fn from_config(config: &HttpJwtConfig) -> Result<Self, IggyError> {
+        let mut providers = Vec::with_capacity(config.providers.len());
+        for provider in &config.providers {
+            let client = reqwest::Client::builder()
+                .timeout(JWKS_REQUEST_TIMEOUT)
+                .build()
+                .map_err(|_| IggyError::InvalidConfiguration)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to create HTTP client for JWT provider: {}",
+                        provider.name
+                    )
+                })?;
+            info!(
+                "Accepting JWTs issued by the external provider: {}, issuer: {}",
+                provider.name, provider.issuer
+            );
+            providers.push(JwtProvider {
+                config: provider.clone(),
+                clock_skew: config.clock_skew,
+                client,
+                keys: IggySharedMut::new(JwksCache::default()),
+            });
+        }
+
+        Ok(Self { providers })
+    }
+
+    /// Returns the provider which issued the token, based on its (not yet validated) `iss` claim.
+    pub fn find(&self, token: &str) -> Option<&JwtProvider> {
+        if self.providers.is_empty() {
+            return None;
+        }
+
+        let issuer = Self::read_issuer(token)?;
+        self.providers
+            .iter()
+            .find(|provider| provider.config.issuer == issuer)
+    }
+
+    fn read_issuer(token: &str) -> Option<String> {
+        let mut validation = Validation::default();
+        validation.insecure_disable_signature_validation();
+        validation.validate_exp = false;
+        validation.validate_aud = false;
+        validation.required_spec_claims.clear();
+        jsonwebtoken::decode::<IssuerClaim>(token, &DecodingKey::from_secret(&[]), &validation)
+            .ok()
+            .map(|token| token.claims.iss)
+    }
+}
+
+impl JwtProvider {
+    pub fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    pub async fn validate(&self, token: &str) -> Result<ExternalClaims, IggyError> {
+        let header =
+            jsonwebtoken::decode_header(token).map_err(|_| IggyError::InvalidAccessToken)?;
+        // Only the asymmetric algorithms are accepted, so the public keys can't be used as the HMAC secrets.
+        if matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            return Err(IggyError::InvalidJwtAlgorithm(format!("{:?}", header.alg)));
+        }
+
+        let key = self.get_key(header.kid.as_deref()).await?;
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&self.config.issuer]);
+        if self.config.audiences.is_empty() {
+            validation.validate_aud = false;
+        } else {
+            validation.set_audience(&self.config.audiences);
+        }
+        validation.leeway = self.clock_skew.as_secs() as u64;
+
+        let claims = jsonwebtoken::decode::<Map<String, Value>>(token, &key, &validation)
+            .map_err(|_| IggyError::Unauthenticated)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - invalid JWT issued by provider: {}",
+                    self.config.name
+                )
+            })?
+            .claims;
+        Ok(self.map_claims(&claims))
+    }
+
+    /// Maps the token to the user assigned to its subject, or to the user of its first role that has one assigned.
+    pub fn find_user<'a>(
+        &self,
+        system: &'a System,
+        claims: &ExternalClaims,
+    ) -> Result<&'a User, IggyError> {
+        for username in self.mapped_usernames(claims) {
+            let Ok(Some(user)) = system.try_get_user(&Identifier::named(username)?) else {
+                continue;
+            };
+
+            if !user.is_active() || !system.client_access.is_user_allowed(&user.username) {
+                warn!(
+                    "User: {} with ID: {} mapped from the JWT issued by provider: {} is not allowed to log in.",
+                    user.username, user.id, self.config.name
+                );
+                return Err(IggyError::Unauthenticated);
+            }
+
+            return Ok(user);
+        }
+
+        warn!(
+            "No user found for the JWT issued by provider: {}, subject: {:?}, roles: {:?}",
+            self.config.name, claims.subject, claims.roles
+        );
+        Err(IggyError::Unauthenticated)
+    }
+
+    /// Returns the usernames the token can be mapped to, in order. Only the mappings configured for this provider are used,
+    /// so the subject (unique only within its issuer) or the username claimed by any trusted issuer can't take over a local user.
+    fn mapped_usernames<'a>(
+        &'a self,
+        claims: &'a ExternalClaims,
+    ) -> impl Iterator<Item = &'a String> + 'a {
+        claims
+            .subject
+            .iter()
+            .filter_map(|subject| self.config.subject_users.get(subject))
+            .chain(
+                claims
+                    .roles
+                    .iter()
+                    .filter_map(|role| self.config.role_users.get(role)),
+            )
+    }
+
+    fn map_claims(&self, claims: &Map<String, Value>) -> ExternalClaims {
+        let expiry = claims
+            .get(EXPIRY_CLAIM)
+            .and_then(Value::as_u64)
+            .unwrap_or_default();
+        // Not every provider sets the token ID, and it's needed to revoke the token on logout.
+        let token_id = match claims.get(TOKEN_ID_CLAIM).and_then(Value::as_str) {
+            Some(token_id) => format!("{}:{token_id}", self.config.issuer),
+            None => format!(
+                "{}:{}:{}",
+                self.config.issuer,
+                claims
+                    .get(SUBJECT_CLAIM)
+                    .and_then(Value::as_str)
+                    .unwrap_or_default(),
+                claims
+                    .get(ISSUED_AT_CLAIM)
+                    .and_then(Value::as_u64)
+                    .unwrap_or_default()
+            ),
+        };
+        let subject = claims
+            .get(SUBJECT_CLAIM)
+            .and_then(Value::as_str)
+            .map(|subject| subject.to_owned());
+        let roles = match claims.get(&self.config.roles_claim) {
+            Some(Value::Array(roles)) => roles
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|role| role.to_owned())
+                .collect(),
+            Some(Value::String(roles)) => roles
+                .split_whitespace()
+                .map(|role| role.to_owned())
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        ExternalClaims {
+            token_id,
+            expiry,
+            subject,
+            roles,
+        }
+    }
+
+    async fn get_key(&self, key_id: Option<&str>) -> Result<DecodingKey, IggyError> {
+        {
+            let cache = self.keys.read().await;
+            let refresh_interval = self.config.jwks_refresh_interval.get_duration();
+            let is_stale = cache
+                .fetched_at
+                .is_none_or(|fetched_at| fetched_at.elapsed() >= refresh_interval);
+            if !is_stale {
+                if let Some(key) = cache.find(key_id) {
+                    return Ok(key);
+                }
+
+                if cache
+                    .fetched_at
+                    .is_some_and(|fetched_at| fetched_at.elapsed() < MIN_JWKS_REFETCH_INTERVAL)
+                {
+                    return Err(IggyError::Unauthenticated);
+                }
+            }
+        }
+
+        self.fetch_keys().await?;
+        self.keys
+            .read()
+            .await
+            .find(key_id)
+            .ok_or(IggyError::Unauthenticated)
+    }
+
+    async fn fetch_keys(&self) -> Result<(), IggyError> {
+        let jwks = match self.request_keys().await {
+            Ok(jwks) => jwks,
+            Err(reason) => {
+                error!(
+                    "Failed to fetch JWKS for JWT provider: {} from: {}. {reason}",
+                    self.config.name, self.config.jwks_url
+                );
+                // Keep using the previously fetched keys, but don't retry immediately.
+                self.keys.write().await.fetched_at = Some(Instant::now());
+                return Err(IggyError::Unauthenticated);
+            }
+        };
+
+        let keys = jwks
+            .keys
+            .iter()
+            .filter_map(|jwk| {
+                DecodingKey::from_jwk(jwk)
+                    .ok()
+                    .map(|key| (jwk.common.key_id.clone(), key))
+            })
+            .collect::<Vec<_>>();
+        info!(
+            "Fetched {} key(s) for JWT provider: {}",
+            keys.len(),
+            self.config.name
+        );
+        let mut cache = self.keys.write().await;
+        cache.keys = keys;
+        cache.fetched_at = Some(Instant::now());
+        Ok(())
+    }
+
+    async fn request_keys(&self) -> Result<JwkSet, String> {
+        let response = self
+            .client
+            .get(&self.config.jwks_url)
+            .send()
+            .await
+            .map_err(|error| error.to_string())?;
+        if !response.status().is_success() {
+            return Err(format!("Unexpected status: {}", response.status()));
+        }
+
+        let body = response.bytes().await.map_err(|error| error.to_string())?;
+        serde_json::from_slice(&body).map_err(|error| error.to_string())
+    }
+}
+
+impl JwksCache {
+    /// Finds the key by its ID, or the only key of the set if the token doesn't specify one.
+    fn find(&self, key_id: Option<&str>) -> Option<DecodingKey> {
+        match key_id {
+            Some(key_id) => self
+                .keys
+                .iter()
+                .find(|(id, _)| id.as_deref() == Some(key_id))
+                .map(|(_, key)| key.clone()),
+            None if self.keys.len() == 1 => Some(self.keys[0].1.clone()),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{EncodingKey, Header};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    const ISSUER: &str = "https://idp.example.com";
+
+    fn provider(name: &str, issuer: &str) -> JwtProvider {
+        JwtProvider {
+            config: HttpJwtProviderConfig {
+                name: name.to_string(),
+                issuer: issuer.to_string(),
+                audiences: Vec::new(),
+                jwks_url: "http://127.0.0.1:1/jwks".to_string(),
+                jwks_refresh_interval: IggyDuration::from(60_000_000),
+                subject_users: HashMap::from([("subject-1".to_string(), "iggy".to_string())]),
+                roles_claim: "roles".to_string(),
+                role_users: HashMap::from([("readers".to_string(), "reader".to_string())]),
+            },
+            clock_skew: IggyDuration::from(0),
+            client: reqwest::Client::new(),
+            keys: IggySharedMut::new(JwksCache::default()),
+        }
+    }
+
+    fn token(header: Header, claims: Value) -> String {
+        jsonwebtoken::encode(&header, &claims, &EncodingKey::from_secret(b"secret")).unwrap()
+    }
+
+    fn claims(provider: &JwtProvider, claims: Value) -> ExternalClaims {
+        provider.map_claims(claims.as_object().unwrap())
+    }
+
+    #[test]
+    fn should_find_provider_only_by_configured_issuer() {
+        let providers = JwtProviders {
+            providers: vec![provider("idp", ISSUER)],
+        };
+
+        let known = token(Header::default(), json!({ "iss": ISSUER }));
+        let unknown = token(
+            Header::default(),
+            json!({ "iss": "https://other.example.com" }),
+        );
+        let missing = token(Header::default(), json!({ "sub": "subject-1" }));
+
+        assert_eq!(providers.find(&known).map(JwtProvider::name), Some("idp"));
+        assert!(providers.find(&unknown).is_none());
+        assert!(providers.find(&missing).is_none());
+        assert!(providers.find("not-a-token").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_reject_token_signed_with_hmac_algorithm() {
+        let provider = provider("idp", ISSUER);
+        let token = token(
+            Header::new(Algorithm::HS256),
+            json!({ "iss": ISSUER, "sub": "subject-1", "exp": u64::MAX }),
+        );
+
+        let result = provider.validate(&token).await;
+
+        assert!(matches!(result, Err(IggyError::InvalidJwtAlgorithm(_))));
+    }
+
+    #[test]
+    fn should_map_token_by_subject_and_not_by_claimed_username() {
+        let provider = provider("idp", ISSUER);
+
+        let mapped = claims(&provider, json!({ "sub": "subject-1" }));
+        let claimed_username = claims(
+            &provider,
+            json!({ "sub": "subject-2", "preferred_username": "iggy", "username": "iggy" }),
+        );
+        let missing_subject = claims(&provider, json!({ "preferred_username": "iggy" }));
+
+        assert_eq!(
+            provider.mapped_usernames(&mapped).collect::<Vec<_>>(),
+            vec!["iggy"]
+        );
+        assert_eq!(provider.mapped_usernames(&claimed_username).count(), 0);
+        assert_eq!(provider.mapped_usernames(&missing_subject).count(), 0);
+    }
+
+    #[test]
+    fn should_not_map_subject_configured_for_another_provider() {
+        let provider = provider("idp", ISSUER);
+        let mut other_provider = self::provider("other", "https://other.example.com");
+        other_provider.config.subject_users =
+            HashMap::from([("subject-2".to_string(), "iggy".to_string())]);
+
+        let claims = claims(&provider, json!({ "sub": "subject-2" }));
+
+        assert_eq!(provider.mapped_usernames(&claims).count(), 0);
+        assert_eq!(
+            other_provider.mapped_usernames(&claims).collect::<Vec<_>>(),
+            vec!["iggy"]
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_user_of_first_mapped_role() {
+        let provider = provider("idp", ISSUER);
+
+        let array_roles = claims(
+            &provider,
+            json!({ "sub": "subject-2", "roles": ["writers", "readers"] }),
+        );
+        let string_roles = claims(&provider, json!({ "roles": "writers readers" }));
+        let unmapped_roles = claims(&provider, json!({ "roles": ["writers"] }));
+
+        assert_eq!(
+            provider.mapped_usernames(&array_roles).collect::<Vec<_>>(),
+            vec!["reader"]
+        );
+        assert_eq!(
+            provider.mapped_usernames(&string_roles).collect::<Vec<_>>(),
+            vec!["reader"]
+        );
+        assert_eq!(provider.mapped_usernames(&unmapped_roles).count(), 0);
+    }
+
+    #[test]
+    fn should_scope_token_id_to_issuer() {
+        let provider = provider("idp", ISSUER);
+
+        let with_id = claims(&provider, json!({ "jti": "token-1" }));
+        let without_id = claims(&provider, json!({ "sub": "subject-1", "iat": 100 }));
+
+        assert_eq!(with_id.token_id, format!("{ISSUER}:token-1"));
+        assert_eq!(without_id.token_id, format!("{ISSUER}:subject-1:100"));
+    }
+
+    #[test]
+    fn should_not_pick_key_without_id_from_multiple_keys() {
+        let key = DecodingKey::from_secret(b"key");
+        let cache = JwksCache {
+            keys: vec![
+                (Some("first".to_string()), key.clone()),
+                (Some("second".to_string()), key),
+            ],
+            fetched_at: Some(Instant::now()),
+        };
+
+        assert!(cache.find(Some("second")).is_some());
+        assert!(cache.find(Some("unknown")).is_none());
+        assert!(cache.find(None).is_none());
+    }
+}
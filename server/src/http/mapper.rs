@@ -18,7 +18,9 @@
 
 use crate::http::jwt::json_web_token::GeneratedToken;
 use crate::streaming::clients::client_manager::Client;
+use crate::streaming::partitions::partition::Partition;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::schemas::schema::Schema;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
@@ -28,10 +30,13 @@ use iggy::locking::IggySharedMutFn;
 use iggy::models::client_info::ConsumerGroupInfo;
 use iggy::models::consumer_group::{ConsumerGroupDetails, ConsumerGroupMember};
 use iggy::models::identity_info::{IdentityInfo, TokenInfo};
+use iggy::models::partition::{PartitionConsumerOffset, PartitionDetails, PartitionSegment};
 use iggy::models::personal_access_token::PersonalAccessTokenInfo;
+use iggy::models::schema::Schema as SchemaInfo;
 use iggy::models::stream::StreamDetails;
 use iggy::models::topic::TopicDetails;
 use iggy::models::user_info::{UserInfo, UserInfoDetails};
+use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::sizeable::Sizeable;
 use tokio::sync::RwLock;
 
@@ -89,7 +94,7 @@ pub fn map_topics(topics: &[&Topic]) -> Vec<iggy::models::topic::Topic> {
     topics_data
 }
 
-pub async fn map_topic(topic: &Topic) -> TopicDetails {
+pub async fn map_topic(topic: &Topic, disk_usage: IggyByteSize) -> TopicDetails {
     let mut topic_details = TopicDetails {
         id: topic.topic_id,
         created_at: topic.created_at,
@@ -102,6 +107,8 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
         compression_algorithm: topic.compression_algorithm,
         max_topic_size: topic.max_topic_size,
         replication_factor: topic.replication_factor,
+        options: topic.get_options(),
+        disk_usage,
     };
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
@@ -120,6 +127,39 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
     topic_details
 }
 
+pub fn map_partition(partition: &Partition) -> PartitionDetails {
+    PartitionDetails {
+        id: partition.partition_id,
+        name: partition.name.clone(),
+        created_at: partition.created_at,
+        segments_count: partition.get_segments().len() as u32,
+        current_offset: partition.current_offset,
+        size: partition.get_size_bytes(),
+        messages_count: partition.get_messages_count(),
+        segments: partition
+            .get_segments()
+            .iter()
+            .map(|segment| PartitionSegment {
+                start_offset: segment.start_offset,
+                current_offset: segment.current_offset,
+                size: segment.size_bytes,
+                messages_count: segment.get_messages_count(),
+                is_closed: segment.is_closed,
+            })
+            .collect(),
+        consumer_offsets: partition
+            .consumer_offsets
+            .iter()
+            .chain(partition.consumer_group_offsets.iter())
+            .map(|consumer_offset| PartitionConsumerOffset {
+                kind: consumer_offset.kind,
+                consumer_id: consumer_offset.consumer_id,
+                offset: consumer_offset.offset,
+            })
+            .collect(),
+    }
+}
+
 pub fn map_user(user: &User) -> UserInfoDetails {
     UserInfoDetails {
         id: user.id,
@@ -153,6 +193,9 @@ pub fn map_personal_access_tokens(
         let personal_access_token = PersonalAccessTokenInfo {
             name: personal_access_token.name.clone(),
             expiry_at: personal_access_token.expiry_at,
+            scope: personal_access_token.scope.clone(),
+            allowed_addresses: personal_access_token.allowed_addresses.clone(),
+            last_used_at: personal_access_token.last_used_at(),
         };
         personal_access_tokens_data.push(personal_access_token);
     }
@@ -224,6 +267,9 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> ConsumerGroup
         name: consumer_group.name.clone(),
         partitions_count: consumer_group.partitions_count,
         members_count: consumer_group.get_members().len() as u32,
+        generation: consumer_group.generation(),
+        assignment_strategy: consumer_group.assignment_strategy,
+        topic_deleting: consumer_group.topic_deleting,
         members: Vec::new(),
     };
     let members = consumer_group.get_members();
@@ -248,3 +294,17 @@ pub fn map_generated_access_token_to_identity_info(token: GeneratedToken) -> Ide
         }),
     }
 }
+
+pub fn map_schema(schema: &Schema) -> SchemaInfo {
+    SchemaInfo {
+        id: schema.id,
+        subject: schema.subject.clone(),
+        version: schema.version,
+        created_at: schema.created_at,
+        schema: schema.schema.clone(),
+    }
+}
+
+pub fn map_schemas(schemas: &[&Schema]) -> Vec<SchemaInfo> {
+    schemas.iter().map(|schema| map_schema(schema)).collect()
+}
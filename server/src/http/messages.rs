@@ -16,26 +16,59 @@
  * under the License.
  */
 
-use crate::http::error::CustomError;
+use crate::binary::mapper;
+use crate::http::error::{CustomError, ErrorResponse};
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
+use crate::streaming::diagnostics::batch_timings::BatchTimings;
+use crate::streaming::diagnostics::metrics::QuotaDirection;
 use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
 use crate::streaming::utils::random_id;
-use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
-use axum::routing::get;
+use axum::body::Body;
+use axum::extract::{FromRequest, Path, Query, Request, State};
+use axum::http::header::{ACCEPT, CONTENT_TYPE};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
+use bytes::{Bytes, BytesMut};
 use error_set::ErrContext;
+use futures::{stream, Stream, StreamExt};
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::command::{POLL_MESSAGES, SEND_MESSAGES};
+use iggy::confirmation::ConfirmationLevel;
 use iggy::consumer::Consumer;
+use iggy::error::IggyError;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::PollMessages;
-use iggy::messages::send_messages::SendMessages;
-use iggy::models::messages::PolledMessages;
+use iggy::messages::ack_messages::AckMessages;
+use iggy::messages::nack_messages::NackMessages;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::reject_messages::RejectMessages;
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::{PayloadEncoding, MAX_HEADERS_SIZE, MAX_PAYLOAD_SIZE};
+use iggy::models::messages::{EncodedPolledMessage, EncodedPolledMessages, PolledMessages};
+use iggy::utils::duration::IggyDuration;
 use iggy::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::str::FromStr;
 use std::sync::Arc;
-use tracing::instrument;
+use tracing::{instrument, trace};
+use utoipa::OpenApi;
+
+const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+// The messages are sent and polled using the same binary format as in the TCP protocol, without any encoding of the payloads.
+const BINARY_CONTENT_TYPE: &str = "application/octet-stream";
+// The maximum number of messages appended or polled at once, and thus sent in a single NDJSON chunk.
+const NDJSON_BATCH_SIZE: usize = 1000;
+// The single message is encoded as JSON with the Base64 or hex payload, so its line is larger than the raw payload.
+const MAX_NDJSON_LINE_SIZE: usize = 3 * (MAX_PAYLOAD_SIZE + MAX_HEADERS_SIZE) as usize;
+const SSE_MESSAGE_EVENT: &str = "message";
+const SSE_ERROR_EVENT: &str = "error";
+const DEFAULT_SSE_POLL_INTERVAL: &str = "100ms";
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -43,28 +76,103 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/messages",
             get(poll_messages).post(send_messages),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/messages/sse",
+            get(poll_messages_sse),
+        )
         .route(
             "/streams/{stream_id}/topics/{topic_id}/messages/flush/{partition_id}/{fsync}",
             get(flush_unsaved_buffer),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/messages/reject",
+            post(reject_messages),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/messages/nack",
+            post(nack_messages),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/messages/ack",
+            post(ack_messages),
+        )
         .with_state(state)
 }
 
+/// The OpenAPI specification of the messages endpoints.
+#[derive(OpenApi)]
+#[openapi(paths(
+    poll_messages,
+    send_messages,
+    poll_messages_sse,
+    flush_unsaved_buffer,
+    reject_messages,
+    nack_messages,
+    ack_messages
+))]
+pub struct MessagesApi;
+
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("id" = Option<String>, Query, description = "Unique consumer ID (numeric or name)."),
+        ("partition_id" = Option<u32>, Query, description = "Partition ID, assigned by the server when not set."),
+        ("kind" = Option<String>, Query, description = "Polling kind: `offset`, `timestamp`, `first`, `last` or `next`."),
+        ("value" = Option<u64>, Query, description = "Offset or timestamp, depending on the polling kind."),
+        ("count" = Option<u32>, Query, description = "Maximum number of messages to poll."),
+        ("auto_commit" = Option<String>, Query, description = "Offset commit mode: `manual`, `before_delivery`, `after_poll` or `interval:<duration>`."),
+        ("payload_encoding" = Option<PayloadEncoding>, Query, description = "Encoding of the returned payloads."),
+        ("format" = Option<String>, Query, description = "Response format, either `json` or `ndjson` to stream the messages line by line."),
+    ),
+    responses(
+        (status = 200, description = "The polled messages, or the binary polled messages as in the TCP protocol with `Accept: application/octet-stream`.", body = EncodedPolledMessages),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 async fn poll_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
+    headers: HeaderMap,
     mut query: Query<PollMessages>,
-) -> Result<Json<PolledMessages>, CustomError> {
+    Query(options): Query<PollMessagesOptions>,
+) -> Result<Response, CustomError> {
     query.stream_id = Identifier::from_str_value(&stream_id)?;
     query.topic_id = Identifier::from_str_value(&topic_id)?;
     query.validate()?;
 
+    if options.format == PollMessagesFormat::Ndjson {
+        return poll_messages_stream(state, &identity, query.0, options.payload_encoding).await;
+    }
+
     let consumer = Consumer::new(query.0.consumer.id);
+    let session = identity.session();
+    state
+        .system
+        .resolve_authorization(
+            &session,
+            POLL_MESSAGES,
+            Some(&query.0.stream_id),
+            Some(&query.0.topic_id),
+        )
+        .await;
+    state
+        .system
+        .wait_for_quota(
+            &session,
+            QuotaDirection::Consume,
+            &query.0.stream_id,
+            &query.0.topic_id,
+        )
+        .await?;
     let system = state.system.read().await;
     let polled_messages = system
         .poll_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &session,
             &consumer,
             &query.0.stream_id,
             &query.0.topic_id,
@@ -78,17 +186,554 @@ async fn poll_messages(
                 stream_id, topic_id, query.0.partition_id
             )
         })?;
-    Ok(Json(polled_messages))
+    if accepts(&headers, BINARY_CONTENT_TYPE) {
+        return Ok((
+            [(CONTENT_TYPE, BINARY_CONTENT_TYPE)],
+            mapper::map_polled_messages(&polled_messages),
+        )
+            .into_response());
+    }
+
+    Ok(Json(EncodedPolledMessages::new(
+        polled_messages,
+        options.payload_encoding,
+    ))
+    .into_response())
 }
 
+/// The options of the polled messages response, JSON with the Base64 payloads by default.
+#[derive(Debug, Deserialize)]
+struct PollMessagesOptions {
+    #[serde(default)]
+    payload_encoding: PayloadEncoding,
+    #[serde(default)]
+    format: PollMessagesFormat,
+}
+
+/// The format of the polled messages response, either the single JSON document,
+/// or the NDJSON stream with the partition on the first line, followed by a single message per line.
+#[derive(Debug, Default, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum PollMessagesFormat {
+    #[default]
+    Json,
+    Ndjson,
+}
+
+/// The first line of the polled messages NDJSON stream.
+#[derive(Debug, Serialize)]
+struct NdjsonPollHeader {
+    partition_id: u32,
+    current_offset: u64,
+}
+
+/// Streams the polled messages as NDJSON, so that the whole response is never kept in memory.
+/// The messages are polled in the batches of at most `NDJSON_BATCH_SIZE` messages, each sent as a separate chunk,
+/// and each batch is committed according to `auto_commit` once it's polled.
+/// The first batch is polled before responding, so that its error is returned with the matching status code,
+/// while the error of any next batch ends the stream.
+async fn poll_messages_stream(
+    state: Arc<AppState>,
+    identity: &Identity,
+    command: PollMessages,
+    payload_encoding: PayloadEncoding,
+) -> Result<Response, CustomError> {
+    let mut consumer = NdjsonConsumer {
+        state,
+        session: identity.session(),
+        consumer: Consumer::new(command.consumer.id.clone()),
+        strategy: command.strategy,
+        remaining_count: command.count,
+        command,
+        payload_encoding,
+        completed: false,
+    };
+    let polled_messages = consumer.poll_messages().await?;
+    let mut first_chunk = to_ndjson_line(&NdjsonPollHeader {
+        partition_id: polled_messages.partition_id,
+        current_offset: polled_messages.current_offset,
+    })?;
+    first_chunk.extend_from_slice(&consumer.to_chunk(polled_messages)?);
+    let chunks = stream::once(async move { Ok(Bytes::from(first_chunk)) }).chain(stream::unfold(
+        consumer,
+        |mut consumer| async move {
+            if consumer.completed {
+                return None;
+            }
+
+            let chunk = match consumer.poll_messages().await {
+                Ok(polled_messages) => consumer.to_chunk(polled_messages).map(Bytes::from),
+                Err(error) => {
+                    consumer.completed = true;
+                    Err(error)
+                }
+            };
+            Some((chunk, consumer))
+        },
+    ));
+    Ok((
+        [(CONTENT_TYPE, NDJSON_CONTENT_TYPE)],
+        Body::from_stream(chunks),
+    )
+        .into_response())
+}
+
+/// The state of the consumer polling the messages in the batches for the NDJSON stream.
+struct NdjsonConsumer {
+    state: Arc<AppState>,
+    session: Arc<Session>,
+    consumer: Consumer,
+    command: PollMessages,
+    strategy: PollingStrategy,
+    remaining_count: u32,
+    payload_encoding: PayloadEncoding,
+    completed: bool,
+}
+
+impl NdjsonConsumer {
+    /// Polls the next batch, continuing after its last message, until the requested count or the end of the partition is reached.
+    async fn poll_messages(&mut self) -> Result<PolledMessages, IggyError> {
+        let count = self.remaining_count.min(NDJSON_BATCH_SIZE as u32);
+        self.state
+            .system
+            .resolve_authorization(
+                &self.session,
+                POLL_MESSAGES,
+                Some(&self.command.stream_id),
+                Some(&self.command.topic_id),
+            )
+            .await;
+        self.state
+            .system
+            .wait_for_quota(
+                &self.session,
+                QuotaDirection::Consume,
+                &self.command.stream_id,
+                &self.command.topic_id,
+            )
+            .await?;
+        let system = self.state.system.read().await;
+        let polled_messages = system
+            .poll_messages(
+                &self.session,
+                &self.consumer,
+                &self.command.stream_id,
+                &self.command.topic_id,
+                self.command.partition_id,
+                PollingArgs::new(self.strategy, count, self.command.auto_commit),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to poll messages for NDJSON, stream ID: {}, topic ID: {}, partition ID: {:?}",
+                    self.command.stream_id, self.command.topic_id, self.command.partition_id
+                )
+            })?;
+
+        self.remaining_count = self
+            .remaining_count
+            .saturating_sub(polled_messages.messages.len() as u32);
+        match polled_messages.messages.last() {
+            Some(last_message) if self.remaining_count > 0 => {
+                let next_offset = polled_messages
+                    .next_offset
+                    .unwrap_or(last_message.offset + 1);
+                self.strategy = PollingStrategy::offset(next_offset);
+            }
+            _ => self.completed = true,
+        }
+        Ok(polled_messages)
+    }
+
+    fn to_chunk(&self, polled_messages: PolledMessages) -> Result<Vec<u8>, IggyError> {
+        let mut chunk = Vec::new();
+        for message in polled_messages.messages {
+            chunk.extend_from_slice(&to_ndjson_line(&EncodedPolledMessage::new(
+                message,
+                self.payload_encoding,
+            ))?);
+        }
+        Ok(chunk)
+    }
+}
+
+fn to_ndjson_line<T: Serialize>(value: &T) -> Result<Vec<u8>, IggyError> {
+    let mut line = serde_json::to_vec(value).map_err(|_| IggyError::InvalidFormat)?;
+    line.push(b'\n');
+    Ok(line)
+}
+
+/// The options of the messages consumed as the server-sent events.
+#[serde_as]
+#[derive(Debug, Deserialize)]
+struct SseMessagesOptions {
+    #[serde(default)]
+    payload_encoding: PayloadEncoding,
+    /// The interval of polling the partition again, once there are no new messages.
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default = "default_sse_poll_interval")]
+    poll_interval: IggyDuration,
+}
+
+fn default_sse_poll_interval() -> IggyDuration {
+    IggyDuration::from_str(DEFAULT_SSE_POLL_INTERVAL).unwrap()
+}
+
+/// Streams the new messages as the server-sent events, until the client disconnects.
+/// Each polled message is sent as the `message` event with its offset as the event ID,
+/// and the polling continues after the last sent message, committing the offset according to `auto_commit`.
+/// If the polling fails, the `error` event is sent and the stream ends.
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages/sse",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("id" = Option<String>, Query, description = "Unique consumer ID (numeric or name)."),
+        ("partition_id" = Option<u32>, Query, description = "Partition ID, assigned by the server when not set."),
+        ("kind" = Option<String>, Query, description = "Polling kind: `offset`, `timestamp`, `first`, `last` or `next`."),
+        ("value" = Option<u64>, Query, description = "Offset or timestamp, depending on the polling kind."),
+        ("count" = Option<u32>, Query, description = "Maximum number of messages to poll."),
+        ("auto_commit" = Option<String>, Query, description = "Offset commit mode: `manual`, `before_delivery`, `after_poll` or `interval:<duration>`."),
+        ("payload_encoding" = Option<PayloadEncoding>, Query, description = "Encoding of the returned payloads."),
+        ("poll_interval" = Option<String>, Query, description = "Interval of polling again once there are no new messages."),
+    ),
+    responses(
+        (status = 200, description = "The stream of the server-sent events, each carrying the single polled message.", body = EncodedPolledMessage, content_type = "text/event-stream"),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
+async fn poll_messages_sse(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<PollMessages>,
+    Query(options): Query<SseMessagesOptions>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+
+    let command = query.0;
+    let consumer = SseConsumer {
+        state,
+        session: identity.session(),
+        consumer: Consumer::new(command.consumer.id.clone()),
+        strategy: command.strategy,
+        command,
+        options,
+        completed: false,
+    };
+    let events = stream::unfold(consumer, |mut consumer| async move {
+        let events = consumer.poll_events().await?;
+        Some((stream::iter(events), consumer))
+    })
+    .flatten();
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// The state of the consumer polling the messages for the server-sent events stream.
+struct SseConsumer {
+    state: Arc<AppState>,
+    session: Arc<Session>,
+    consumer: Consumer,
+    command: PollMessages,
+    strategy: PollingStrategy,
+    options: SseMessagesOptions,
+    completed: bool,
+}
+
+impl SseConsumer {
+    /// Polls the messages until there are any, and returns them as the events, or `None` once the stream is completed.
+    async fn poll_events(&mut self) -> Option<Vec<Result<Event, axum::Error>>> {
+        if self.completed {
+            return None;
+        }
+
+        loop {
+            self.state
+                .system
+                .resolve_authorization(
+                    &self.session,
+                    POLL_MESSAGES,
+                    Some(&self.command.stream_id),
+                    Some(&self.command.topic_id),
+                )
+                .await;
+            let polled_messages = {
+                let system = self.state.system.read().await;
+                system
+                    .poll_messages(
+                        &self.session,
+                        &self.consumer,
+                        &self.command.stream_id,
+                        &self.command.topic_id,
+                        self.command.partition_id,
+                        PollingArgs::new(self.strategy, self.command.count, self.command.auto_commit),
+                    )
+                    .await
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to poll messages for SSE, stream ID: {}, topic ID: {}, partition ID: {:?}",
+                            self.command.stream_id, self.command.topic_id, self.command.partition_id
+                        )
+                    })
+            };
+            let polled_messages = match polled_messages {
+                Ok(polled_messages) => {
+                    EncodedPolledMessages::new(polled_messages, self.options.payload_encoding)
+                }
+                Err(error) => {
+                    self.completed = true;
+                    let event = Event::default()
+                        .event(SSE_ERROR_EVENT)
+                        .data(error.to_string());
+                    return Some(vec![Ok(event)]);
+                }
+            };
+
+            let Some(last_message) = polled_messages.messages.last() else {
+                tokio::time::sleep(self.options.poll_interval.get_duration()).await;
+                continue;
+            };
+
+            // The consumer continues after the last sent message, regardless of the initial strategy.
+            let next_offset = polled_messages
+                .next_offset
+                .unwrap_or(last_message.offset + 1);
+            self.strategy = PollingStrategy::offset(next_offset);
+            let events = polled_messages
+                .messages
+                .into_iter()
+                .map(|message| {
+                    Event::default()
+                        .event(SSE_MESSAGE_EVENT)
+                        .id(message.offset.to_string())
+                        .json_data(message)
+                })
+                .collect();
+            return Some(events);
+        }
+    }
+}
+
+/// The body of the send messages request, either the JSON with all the messages,
+/// the binary command encoded as in the TCP protocol,
+/// or the NDJSON stream with the partitioning on the first line, followed by a single message per line.
+enum SendMessagesBody {
+    Json(SendMessages),
+    Binary(SendMessages),
+    Ndjson(Body),
+}
+
+/// The first line of the NDJSON stream.
+#[derive(Debug, Deserialize)]
+struct NdjsonHeader {
+    partitioning: Partitioning,
+    #[serde(default)]
+    confirmation: ConfirmationLevel,
+}
+
+impl<S: Send + Sync> FromRequest<S> for SendMessagesBody {
+    type Rejection = Response;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        if has_content_type(request.headers(), NDJSON_CONTENT_TYPE) {
+            return Ok(SendMessagesBody::Ndjson(request.into_body()));
+        }
+
+        if has_content_type(request.headers(), BINARY_CONTENT_TYPE) {
+            let bytes = Bytes::from_request(request, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+            let command = SendMessages::from_bytes(bytes)
+                .map_err(|error| CustomError::from(error).into_response())?;
+            return Ok(SendMessagesBody::Binary(command));
+        }
+
+        let Json(command) = Json::<SendMessages>::from_request(request, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        Ok(SendMessagesBody::Json(command))
+    }
+}
+
+/// The request header enabling the per-stage timing breakdown of the appended batch in the `Server-Timing` response header.
+const TIMING_HEADER: HeaderName = HeaderName::from_static("x-iggy-timing");
+const SERVER_TIMING_HEADER: HeaderName = HeaderName::from_static("server-timing");
+
+fn has_content_type(headers: &HeaderMap, expected_content_type: &str) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with(expected_content_type))
+}
+
+fn accepts(headers: &HeaderMap, expected_content_type: &str) -> bool {
+    headers
+        .get(ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .is_some_and(|accept| {
+            accept
+                .split(',')
+                .any(|media_type| media_type.trim().starts_with(expected_content_type))
+        })
+}
+
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+    ),
+    request_body(
+        content = SendMessages,
+        description = "The messages to send, or the binary command encoded as in the TCP protocol with `Content-Type: application/octet-stream`."
+    ),
+    responses(
+        (status = 201, description = "The messages have been sent. When the request contains the `x-iggy-timing` header, the `Server-Timing` header contains the time spent in validation, partitioning, cache insert and persistence."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 async fn send_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
-    Json(mut command): Json<SendMessages>,
-) -> Result<StatusCode, CustomError> {
-    command.stream_id = Identifier::from_str_value(&stream_id)?;
-    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    headers: HeaderMap,
+    body: SendMessagesBody,
+) -> Result<Response, CustomError> {
+    let timings = match body {
+        SendMessagesBody::Json(mut command) | SendMessagesBody::Binary(mut command) => {
+            command.stream_id = Identifier::from_str_value(&stream_id)?;
+            command.topic_id = Identifier::from_str_value(&topic_id)?;
+            append_messages(&state, &identity, command).await?
+        }
+        SendMessagesBody::Ndjson(body) => {
+            send_messages_stream(&state, &identity, &stream_id, &topic_id, body).await?
+        }
+    };
+
+    if !headers.contains_key(TIMING_HEADER) {
+        return Ok(StatusCode::CREATED.into_response());
+    }
+
+    let server_timing = HeaderValue::from_str(&timings.to_server_timing())
+        .map_err(|_| IggyError::InvalidHttpRequest)?;
+    Ok((StatusCode::CREATED, [(SERVER_TIMING_HEADER, server_timing)]).into_response())
+}
+
+/// Appends the messages streamed as NDJSON in the batches, so that the whole request is never kept in memory.
+/// Each batch is appended as soon as it's complete, thus the batches appended before an invalid line are retained.
+/// The returned timings are the sum of the timings of all the appended batches.
+async fn send_messages_stream(
+    state: &AppState,
+    identity: &Identity,
+    stream_id: &str,
+    topic_id: &str,
+    body: Body,
+) -> Result<BatchTimings, CustomError> {
+    let stream_id = Identifier::from_str_value(stream_id)?;
+    let topic_id = Identifier::from_str_value(topic_id)?;
+    let mut chunks = body.into_data_stream();
+    let mut buffer = BytesMut::new();
+    let mut partitioning = None;
+    let mut confirmation = ConfirmationLevel::default();
+    let mut messages = Vec::new();
+    let mut payload_size = 0;
+    let mut headers_size = 0;
+    let mut appended_count = 0;
+    let mut timings = BatchTimings::default();
+    let mut completed = false;
+    while !completed {
+        match chunks.next().await {
+            Some(chunk) => {
+                let chunk = chunk.map_err(|_| IggyError::InvalidHttpRequest)?;
+                buffer.extend_from_slice(&chunk);
+            }
+            None => completed = true,
+        }
+
+        loop {
+            let line = match buffer.iter().position(|byte| *byte == b'\n') {
+                Some(position) => buffer.split_to(position + 1).freeze().slice(..position),
+                None if completed && !buffer.is_empty() => buffer.split().freeze(),
+                None => break,
+            };
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+
+            let Some(partitioning) = &partitioning else {
+                let header = serde_json::from_slice::<NdjsonHeader>(&line)
+                    .map_err(|_| IggyError::InvalidFormat)?;
+                partitioning = Some(header.partitioning);
+                confirmation = header.confirmation;
+                continue;
+            };
+
+            let message =
+                serde_json::from_slice::<Message>(&line).map_err(|_| IggyError::InvalidFormat)?;
+            let message_payload_size = message.payload.len() as u32;
+            let message_headers_size = message.headers.as_ref().map_or(0, |headers| {
+                headers
+                    .values()
+                    .map(|header| header.value.len() as u32)
+                    .sum::<u32>()
+            });
+            // The batch is appended before it would exceed the limits validated for a single request.
+            if messages.len() == NDJSON_BATCH_SIZE
+                || (!messages.is_empty()
+                    && (payload_size + message_payload_size > MAX_PAYLOAD_SIZE
+                        || headers_size + message_headers_size > MAX_HEADERS_SIZE))
+            {
+                appended_count += messages.len();
+                let command = SendMessages {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partitioning: partitioning.clone(),
+                    messages: std::mem::take(&mut messages),
+                    confirmation,
+                };
+                timings += append_messages(state, identity, command).await?;
+                payload_size = 0;
+                headers_size = 0;
+            }
+
+            payload_size += message_payload_size;
+            headers_size += message_headers_size;
+            messages.push(message);
+        }
+
+        if buffer.len() > MAX_NDJSON_LINE_SIZE {
+            return Err(IggyError::TooBigMessagePayload.into());
+        }
+    }
+
+    let Some(partitioning) = partitioning else {
+        return Err(IggyError::InvalidFormat.into());
+    };
+
+    appended_count += messages.len();
+    let command = SendMessages {
+        stream_id,
+        topic_id,
+        partitioning,
+        messages,
+        confirmation,
+    };
+    timings += append_messages(state, identity, command).await?;
+    trace!("Appended {appended_count} messages streamed as NDJSON, {timings}.");
+    Ok(timings)
+}
+
+pub(super) async fn append_messages(
+    state: &AppState,
+    identity: &Identity,
+    mut command: SendMessages,
+) -> Result<BatchTimings, IggyError> {
     command.partitioning.length = command.partitioning.value.len() as u8;
     command.messages.iter_mut().for_each(|msg| {
         if msg.id == 0 {
@@ -98,19 +743,28 @@ async fn send_messages(
     command.validate()?;
 
     let messages = command.messages;
-    let command_stream_id = command.stream_id;
-    let command_topic_id = command.topic_id;
+    let stream_id = command.stream_id;
+    let topic_id = command.topic_id;
     let partitioning = command.partitioning;
+    let confirmation = command.confirmation;
+    let session = identity.session();
+    state
+        .system
+        .resolve_authorization(&session, SEND_MESSAGES, Some(&stream_id), Some(&topic_id))
+        .await;
+    state
+        .system
+        .wait_for_quota(&session, QuotaDirection::Produce, &stream_id, &topic_id)
+        .await?;
     let system = state.system.read().await;
-    // TODO(haze): Add confirmation level after testing is complete
-    system
+    let timings = system
         .append_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            command_stream_id,
-            command_topic_id,
+            &session,
+            stream_id.clone(),
+            topic_id.clone(),
             partitioning,
             messages,
-            None,
+            confirmation,
         )
         .await
         .with_error_context(|error| {
@@ -119,9 +773,24 @@ async fn send_messages(
                 stream_id, topic_id
             )
         })?;
-    Ok(StatusCode::CREATED)
+    Ok(timings)
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages/flush/{partition_id}/{fsync}",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("partition_id" = u32, Path, description = "Partition ID."),
+        ("fsync" = bool, Path, description = "Whether to sync the flushed messages to disk."),
+    ),
+    responses(
+        (status = 204, description = "The unsaved buffer has been flushed."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_flush_unsaved_buffer", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_partition_id = partition_id, iggy_fsync = fsync))]
 async fn flush_unsaved_buffer(
     State(state): State<Arc<AppState>>,
@@ -130,10 +799,19 @@ async fn flush_unsaved_buffer(
 ) -> Result<StatusCode, CustomError> {
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let topic_id = Identifier::from_str_value(&topic_id)?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            SEND_MESSAGES,
+            Some(&stream_id),
+            Some(&topic_id),
+        )
+        .await;
     let system = state.system.read().await;
     system
         .flush_unsaved_buffer(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             stream_id,
             topic_id,
             partition_id,
@@ -142,3 +820,148 @@ async fn flush_unsaved_buffer(
         .await?;
     Ok(StatusCode::OK)
 }
+
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages/reject",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+    ),
+    request_body = RejectMessages,
+    responses(
+        (status = 204, description = "The messages have been rejected."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
+#[instrument(skip_all, name = "trace_reject_messages", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn reject_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut command: Json<RejectMessages>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            POLL_MESSAGES,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
+    let system = state.system.read().await;
+    system
+        .reject_messages(
+            &identity.session(),
+            &command.0.consumer,
+            &command.0.stream_id,
+            &command.0.topic_id,
+            command.0.partition_id,
+            &command.0.offsets,
+            &command.0.reason,
+        )
+        .await
+        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to reject messages, stream ID: {}, topic ID: {}, partition ID: {:?}", stream_id, topic_id, command.0.partition_id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages/nack",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+    ),
+    request_body = NackMessages,
+    responses(
+        (status = 204, description = "The messages have been negatively acknowledged."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
+#[instrument(skip_all, name = "trace_nack_messages", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn nack_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut command: Json<NackMessages>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            POLL_MESSAGES,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
+    let system = state.system.read().await;
+    system
+        .nack_messages(
+            &identity.session(),
+            &command.0.consumer,
+            &command.0.stream_id,
+            &command.0.topic_id,
+            command.0.partition_id,
+            &command.0.offsets,
+            command.0.redelivery_delay,
+        )
+        .await
+        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to nack messages, stream ID: {}, topic ID: {}, partition ID: {:?}", stream_id, topic_id, command.0.partition_id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages/ack",
+    tag = "messages",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+    ),
+    request_body = AckMessages,
+    responses(
+        (status = 204, description = "The messages polled from the topic in the queue mode have been acknowledged."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
+#[instrument(skip_all, name = "trace_ack_messages", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn ack_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut command: Json<AckMessages>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            POLL_MESSAGES,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
+    let system = state.system.read().await;
+    system
+        .ack_messages(
+            &identity.session(),
+            &command.0.stream_id,
+            &command.0.topic_id,
+            command.0.partition_id,
+            &command.0.offsets,
+        )
+        .await
+        .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to ack messages, stream ID: {}, topic ID: {}, partition ID: {}", stream_id, topic_id, command.0.partition_id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
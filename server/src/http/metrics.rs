@@ -17,14 +17,16 @@
  */
 
 use crate::http::shared::AppState;
+use crate::streaming::diagnostics::metrics::TransportLabel;
 use axum::body::Body;
 use axum::{
-    extract::State,
+    extract::{MatchedPath, State},
     http::{Request, StatusCode},
     middleware::Next,
     response::Response,
 };
 use std::sync::Arc;
+use std::time::Instant;
 
 pub async fn metrics(
     State(state): State<Arc<AppState>>,
@@ -32,5 +34,19 @@ pub async fn metrics(
     next: Next,
 ) -> Result<Response, StatusCode> {
     state.system.read().await.metrics.increment_http_requests();
-    Ok(next.run(request).await)
+    // The route template is used rather than the path, so that the series don't grow with the resource IDs.
+    let command = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| format!("{} {}", request.method(), path.as_str()));
+    let started_at = Instant::now();
+    let response = next.run(request).await;
+    if let Some(command) = command {
+        state.system.read().await.metrics.record_command(
+            &command,
+            TransportLabel::Http,
+            started_at.elapsed(),
+        );
+    }
+    Ok(response)
 }
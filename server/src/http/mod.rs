@@ -16,6 +16,10 @@
  * under the License.
  */
 
+pub mod audit;
+pub mod bookmarks;
+pub mod client_access;
+pub mod cluster;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod diagnostics;
@@ -25,12 +29,19 @@ pub mod jwt;
 mod mapper;
 pub mod messages;
 pub mod metrics;
+pub mod openapi;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod quotas;
+pub mod rate_limit;
+pub mod schemas;
+pub mod security_headers;
 mod shared;
+pub mod shutdown;
 pub mod streams;
 pub mod system;
 pub mod topics;
 pub mod users;
+pub mod websocket;
 
 pub const COMPONENT: &str = "HTTP";
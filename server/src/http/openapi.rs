@@ -0,0 +1,110 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::{consumer_groups, messages, streams, topics, users};
+use axum::extract::State;
+use axum::response::Html;
+use axum::routing::get;
+use axum::{Json, Router};
+use std::sync::Arc;
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+const SWAGGER_UI: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8" />
+  <title>Iggy HTTP API</title>
+  <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css" />
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js" crossorigin></script>
+  <script>
+    window.onload = () => {
+      window.ui = SwaggerUIBundle({ url: "/openapi.json", dom_id: "#swagger-ui" });
+    };
+  </script>
+</body>
+</html>
+"##;
+
+pub fn router() -> Router {
+    Router::new()
+        .route("/openapi.json", get(get_openapi))
+        .route("/swagger-ui", get(get_swagger_ui))
+        .with_state(Arc::new(openapi()))
+}
+
+/// The root of the OpenAPI specification, all the endpoints require the JWT access token obtained by logging in,
+/// unless stated otherwise.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Iggy HTTP API",
+        description = "The HTTP transport of the Iggy message streaming platform."
+    ),
+    modifiers(&SecurityAddon),
+    security(("bearer_auth" = [])),
+    tags(
+        (name = "streams", description = "Managing the streams."),
+        (name = "topics", description = "Managing the topics of the streams."),
+        (name = "consumer_groups", description = "Managing the consumer groups of the topics."),
+        (name = "messages", description = "Sending and polling the messages."),
+        (name = "users", description = "Managing the users and their authentication.")
+    )
+)]
+struct ApiDoc;
+
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
+/// Builds the OpenAPI specification by merging the specifications of the endpoints of each resource.
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(streams::StreamsApi::openapi());
+    openapi.merge(topics::TopicsApi::openapi());
+    openapi.merge(consumer_groups::ConsumerGroupsApi::openapi());
+    openapi.merge(messages::MessagesApi::openapi());
+    openapi.merge(users::UsersApi::openapi());
+    openapi
+}
+
+async fn get_openapi(
+    State(openapi): State<Arc<utoipa::openapi::OpenApi>>,
+) -> Json<utoipa::openapi::OpenApi> {
+    Json(openapi.as_ref().clone())
+}
+
+async fn get_swagger_ui() -> Html<&'static str> {
+    Html(SWAGGER_UI)
+}
@@ -18,18 +18,28 @@
 
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
 use crate::state::command::EntryCommand;
-use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::post;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
+use iggy::command::{
+    CREATE_PARTITIONS, DELETE_PARTITIONS, GET_TOPIC, MOVE_PARTITION, UPDATE_PARTITION,
+    VERIFY_SEGMENTS,
+};
 use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::partition::PartitionDetails;
+use iggy::models::segments_verification::SegmentsVerification;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::move_partition::MovePartition;
+use iggy::partitions::update_partition::UpdatePartition;
+use iggy::segments::verify_segments::VerifySegments;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 use tracing::instrument;
@@ -40,9 +50,56 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/partitions",
             post(create_partitions).delete(delete_partitions),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}",
+            get(get_partition).put(update_partition),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}/move",
+            post(move_partition),
+        )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}/verify",
+            post(verify_segments),
+        )
         .with_state(state)
 }
 
+async fn get_partition(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, String)>,
+) -> Result<Json<PartitionDetails>, CustomError> {
+    let identity_stream_id = Identifier::from_str_value(&stream_id)?;
+    let identity_topic_id = Identifier::from_str_value(&topic_id)?;
+    let identity_partition_id = Identifier::from_str_value(&partition_id)?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            GET_TOPIC,
+            Some(&identity_stream_id),
+            Some(&identity_topic_id),
+        )
+        .await;
+    let system = state.system.read().await;
+    let Ok(topic) =
+        system.try_find_topic(&identity.session(), &identity_stream_id, &identity_topic_id)
+    else {
+        return Err(CustomError::ResourceNotFound);
+    };
+    let Some(topic) = topic else {
+        return Err(CustomError::ResourceNotFound);
+    };
+    let Some(partition) = topic.try_get_partition(&identity_partition_id)? else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    let partition = partition.read().await;
+    let partition = mapper::map_partition(&partition);
+    Ok(Json(partition))
+}
+
 #[instrument(skip_all, name = "trace_create_partitions", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn create_partitions(
     State(state): State<Arc<AppState>>,
@@ -54,13 +111,23 @@ async fn create_partitions(
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
 
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            CREATE_PARTITIONS,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
     let mut system = state.system.write().await;
     system
             .create_partitions(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &identity.session(),
                 &command.stream_id,
                 &command.topic_id,
                 command.partitions_count,
+                command.key_routing_policy,
             )
             .await
             .with_error_context(|error| {
@@ -71,9 +138,9 @@ async fn create_partitions(
             })?;
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(identity.user_id, &EntryCommand::CreatePartitions(command))
+        .append(identity.user_id, &EntryCommand::CreatePartitions(command))
         .await
         .with_error_context(|error| {
             format!(
@@ -81,6 +148,8 @@ async fn create_partitions(
                 stream_id, topic_id
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(StatusCode::CREATED)
 }
 
@@ -95,10 +164,19 @@ async fn delete_partitions(
     query.topic_id = Identifier::from_str_value(&topic_id)?;
     query.validate()?;
 
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            DELETE_PARTITIONS,
+            Some(&query.stream_id),
+            Some(&query.topic_id),
+        )
+        .await;
     let mut system = state.system.write().await;
     system
             .delete_partitions(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &identity.session(),
                 &query.stream_id.clone(),
                 &query.topic_id.clone(),
                 query.partitions_count,
@@ -112,9 +190,9 @@ async fn delete_partitions(
             })?;
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             identity.user_id,
             &EntryCommand::DeletePartitions(DeletePartitions {
                 stream_id: query.stream_id.clone(),
@@ -129,5 +207,140 @@ async fn delete_partitions(
                 stream_id, topic_id
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[instrument(skip_all, name = "trace_update_partition", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_partition_id = partition_id))]
+async fn update_partition(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, String)>,
+    Json(mut command): Json<UpdatePartition>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.partition_id = Identifier::from_str_value(&partition_id)?;
+    command.validate()?;
+
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            UPDATE_PARTITION,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
+    let mut system = state.system.write().await;
+    let identity_partition_id = system
+            .update_partition(
+                &identity.session(),
+                &command.stream_id,
+                &command.topic_id,
+                &command.partition_id,
+                command.name.as_deref(),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to update partition, stream ID: {}, topic ID: {}, partition ID: {}",
+                    stream_id, topic_id, partition_id
+                )
+            })?;
+    command.partition_id = Identifier::numeric(identity_partition_id)?;
+
+    let system = system.downgrade();
+    let commit = system
+        .state
+        .append(identity.user_id, &EntryCommand::UpdatePartition(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update partition, stream ID: {}, topic ID: {}, partition ID: {}",
+                stream_id, topic_id, partition_id
+            )
+        })?;
+    drop(system);
+    commit.wait().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip_all, name = "trace_move_partition", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_partition_id = partition_id))]
+async fn move_partition(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, String)>,
+    Json(mut command): Json<MovePartition>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.partition_id = Identifier::from_str_value(&partition_id)?;
+    command.validate()?;
+
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            MOVE_PARTITION,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
+    let system = state.system.read().await;
+    system
+            .move_partition(
+                &identity.session(),
+                &command.stream_id,
+                &command.topic_id,
+                &command.partition_id,
+                &command.directory,
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to move partition, stream ID: {}, topic ID: {}, partition ID: {}, directory: {}",
+                    stream_id, topic_id, partition_id, command.directory
+                )
+            })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn verify_segments(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+    Json(mut command): Json<VerifySegments>,
+) -> Result<Json<SegmentsVerification>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.partition_id = partition_id;
+    command.validate()?;
+
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            VERIFY_SEGMENTS,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
+    let system = state.system.read().await;
+    let verification = system
+            .verify_segments(
+                &identity.session(),
+                &command.stream_id,
+                &command.topic_id,
+                command.partition_id,
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to verify segments, stream ID: {}, topic ID: {}, partition ID: {}",
+                    stream_id, topic_id, partition_id
+                )
+            })?;
+    Ok(Json(verification))
+}
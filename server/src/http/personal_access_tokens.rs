@@ -20,12 +20,11 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_access_token_to_identity_info;
-use crate::http::shared::AppState;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::http::COMPONENT;
 use crate::state::command::EntryCommand;
 use crate::state::models::CreatePersonalAccessTokenWithHash;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
-use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::{delete, get, post};
@@ -63,7 +62,7 @@ async fn get_personal_access_tokens(
 ) -> Result<Json<Vec<PersonalAccessTokenInfo>>, CustomError> {
     let system = state.system.read().await;
     let personal_access_tokens = system
-        .get_personal_access_tokens(&Session::stateless(identity.user_id, identity.ip_address))
+        .get_personal_access_tokens(&identity.session())
         .await
         .with_error_context(|error| {
             format!(
@@ -86,9 +85,11 @@ async fn create_personal_access_token(
     let mut system = state.system.write().await;
     let token = system
             .create_personal_access_token(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &identity.session(),
                 &command.name,
                 command.expiry,
+                command.scope.clone(),
+                command.allowed_addresses.clone(),
             )
             .await
             .with_error_context(|error| {
@@ -100,9 +101,9 @@ async fn create_personal_access_token(
 
     let system = system.downgrade();
     let token_hash = PersonalAccessToken::hash_token(&token);
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             identity.user_id,
             &EntryCommand::CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash {
                 command,
@@ -116,6 +117,8 @@ async fn create_personal_access_token(
                 identity.user_id
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(Json(RawPersonalAccessToken { token }))
 }
 
@@ -128,7 +131,7 @@ async fn delete_personal_access_token(
     let mut system = state.system.write().await;
     system
             .delete_personal_access_token(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &identity.session(),
                 &name,
             )
             .await
@@ -140,9 +143,9 @@ async fn delete_personal_access_token(
             })?;
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             identity.user_id,
             &EntryCommand::DeletePersonalAccessToken(DeletePersonalAccessToken { name }),
         )
@@ -153,22 +156,27 @@ async fn delete_personal_access_token(
                 identity.user_id
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
 #[instrument(skip_all, name = "trace_login_with_personal_access_token")]
 async fn login_with_personal_access_token(
     State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
     Json(command): Json<LoginWithPersonalAccessToken>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
     command.validate()?;
     let system = state.system.read().await;
-    let user = system
-        .login_with_personal_access_token(&command.token, None)
+    let (user, personal_access_token) = system
+        .login_with_personal_access_token(&command.token, &request_details.ip_address.ip(), None)
         .await
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to login with personal access token")
         })?;
-    let tokens = state.jwt_manager.generate(user.id)?;
+    let tokens = state
+        .jwt_manager
+        .generate(user.id, personal_access_token.scope.clone())?;
     Ok(Json(map_generated_access_token_to_identity_info(tokens)))
 }
@@ -0,0 +1,107 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::http::COMPONENT;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, put};
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy::command::{GET_QUOTAS, SET_QUOTA};
+use iggy::identifier::Identifier;
+use iggy::models::quota::Quota;
+use iggy::quotas::set_quota::SetQuota;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/quotas", get(get_quotas))
+        .route("/quotas/users/{user_id}", put(set_user_quota))
+        .route(
+            "/quotas/streams/{stream_id}/topics/{topic_id}",
+            put(set_topic_quota),
+        )
+        .with_state(state)
+}
+
+async fn get_quotas(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<Quota>>, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_QUOTAS, None, None)
+        .await;
+    let system = state.system.read().await;
+    let quotas = system
+        .get_quotas(&identity.session())
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get quotas")
+        })?;
+    Ok(Json(quotas))
+}
+
+async fn set_user_quota(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+    mut command: Json<SetQuota>,
+) -> Result<StatusCode, CustomError> {
+    command.user_id = Some(Identifier::from_str_value(&user_id)?);
+    set_quota(&state, &identity, command.0).await
+}
+
+async fn set_topic_quota(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut command: Json<SetQuota>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Some(Identifier::from_str_value(&stream_id)?);
+    command.topic_id = Some(Identifier::from_str_value(&topic_id)?);
+    set_quota(&state, &identity, command.0).await
+}
+
+async fn set_quota(
+    state: &AppState,
+    identity: &Identity,
+    command: SetQuota,
+) -> Result<StatusCode, CustomError> {
+    command.validate()?;
+    state
+        .system
+        .resolve_authorization(&identity.session(), SET_QUOTA, None, None)
+        .await;
+    let system = state.system.read().await;
+    system
+        .set_quota(
+            &identity.session(),
+            command.user_id.as_ref(),
+            command.stream_id.as_ref(),
+            command.topic_id.as_ref(),
+            command.limits,
+        )
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to set quota: {command}")
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
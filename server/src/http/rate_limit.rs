@@ -0,0 +1,228 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::http::{HttpRateLimitConfig, HttpRateLimitQuotaConfig};
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::{AppState, RequestDetails};
+use crate::streaming::utils::rate_limiter::{RateLimiter, RateQuota, RateQuotaUsage};
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::header::RETRY_AFTER;
+use axum::http::{Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use iggy::models::user_info::UserId;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::Serialize;
+use std::hash::Hash;
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::{debug, trace};
+
+const MICROS_PER_SECOND: u64 = 1_000_000;
+
+/// The rate limits of the HTTP requests sent from each IP address and by each authenticated user.
+#[derive(Debug, Default)]
+pub struct HttpRateLimits {
+    ip: Option<RateLimiter<IpAddr>>,
+    user: Option<RateLimiter<UserId>>,
+}
+
+/// The current consumption of the HTTP rate limits, returned by the diagnostics endpoint.
+#[derive(Debug, Serialize)]
+pub struct HttpRateLimitsUsage {
+    pub ip: Option<HttpRateLimitUsage<IpAddr>>,
+    pub user: Option<HttpRateLimitUsage<UserId>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HttpRateLimitUsage<K> {
+    pub requests_per_second: u32,
+    pub burst: u32,
+    pub usage: Vec<RateQuotaUsage<K>>,
+}
+
+impl HttpRateLimits {
+    pub fn from_config(config: &HttpRateLimitConfig) -> Self {
+        if !config.enabled {
+            return Self::default();
+        }
+
+        Self {
+            ip: create_limiter(&config.ip),
+            user: create_limiter(&config.user),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.ip.is_some() || self.user.is_some()
+    }
+
+    /// Consumes the request from the quotas of the IP address and the user (if authenticated),
+    /// or returns the time after which the request will be accepted. Both quotas are checked before
+    /// consuming either of them, so the rejected request isn't charged to any of them.
+    pub fn try_acquire(
+        &self,
+        ip_address: IpAddr,
+        user_id: Option<UserId>,
+        now: IggyTimestamp,
+    ) -> Result<(), IggyDuration> {
+        let user = self.user.as_ref().zip(user_id);
+        if let Some(limiter) = &self.ip {
+            limiter.check(&ip_address, now)?;
+        }
+        if let Some((limiter, user_id)) = user {
+            limiter.check(&user_id, now)?;
+        }
+
+        if let Some(limiter) = &self.ip {
+            limiter.try_acquire(ip_address, now)?;
+        }
+        if let Some((limiter, user_id)) = user {
+            // The concurrent request could have consumed the user's quota after it was checked.
+            if let Err(retry_after) = limiter.try_acquire(user_id, now) {
+                if let Some(limiter) = &self.ip {
+                    limiter.release(&ip_address);
+                }
+                return Err(retry_after);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_usage(&self, now: IggyTimestamp) -> HttpRateLimitsUsage {
+        HttpRateLimitsUsage {
+            ip: self.ip.as_ref().map(|limiter| get_usage(limiter, now)),
+            user: self.user.as_ref().map(|limiter| get_usage(limiter, now)),
+        }
+    }
+
+    /// Removes the limits of the IP addresses and users, which have refilled their quotas.
+    pub fn remove_idle(&self, now: IggyTimestamp) -> usize {
+        let ip_count = self
+            .ip
+            .as_ref()
+            .map_or(0, |limiter| limiter.remove_idle(now));
+        let user_count = self
+            .user
+            .as_ref()
+            .map_or(0, |limiter| limiter.remove_idle(now));
+        ip_count + user_count
+    }
+}
+
+fn create_limiter<K: Hash + Eq + Clone>(
+    config: &HttpRateLimitQuotaConfig,
+) -> Option<RateLimiter<K>> {
+    config.enabled.then(|| {
+        RateLimiter::new(RateQuota {
+            requests_per_second: config.requests_per_second,
+            burst: config.burst,
+        })
+    })
+}
+
+fn get_usage<K: Hash + Eq + Clone>(
+    limiter: &RateLimiter<K>,
+    now: IggyTimestamp,
+) -> HttpRateLimitUsage<K> {
+    let quota = limiter.quota();
+    HttpRateLimitUsage {
+        requests_per_second: quota.requests_per_second,
+        burst: quota.burst,
+        usage: limiter.get_usage(now),
+    }
+}
+
+/// Rejects the requests exceeding the rate limits with `429 Too Many Requests` and the `Retry-After` header (in seconds).
+/// Must be applied after the JWT authentication, so that the requests of the authenticated users are limited per user as well.
+pub async fn rate_limit(
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let ip_address = request
+        .extensions()
+        .get::<RequestDetails>()
+        .unwrap()
+        .ip_address
+        .ip();
+    let user_id = request
+        .extensions()
+        .get::<Identity>()
+        .map(|identity| identity.user_id);
+    if let Err(retry_after) =
+        state
+            .rate_limits
+            .try_acquire(ip_address, user_id, IggyTimestamp::now())
+    {
+        let retry_after = retry_after.as_micros().div_ceil(MICROS_PER_SECOND).max(1);
+        debug!("Rate limit exceeded for IP address: {ip_address}, user ID: {user_id:?}, retry after: {retry_after} s.");
+        return (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(RETRY_AFTER, retry_after.to_string())],
+        )
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+pub fn start_rate_limits_cleaner(app_state: Arc<AppState>, interval: IggyDuration) {
+    tokio::spawn(async move {
+        let mut interval_timer = tokio::time::interval(interval.get_duration());
+        loop {
+            interval_timer.tick().await;
+            let removed = app_state.rate_limits.remove_idle(IggyTimestamp::now());
+            trace!("Removed {removed} idle HTTP rate limits.");
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quota(requests_per_second: u32, burst: u32) -> HttpRateLimitQuotaConfig {
+        HttpRateLimitQuotaConfig {
+            enabled: true,
+            requests_per_second,
+            burst,
+        }
+    }
+
+    #[test]
+    fn request_rejected_by_user_quota_should_not_be_charged_to_ip_quota() {
+        let limits = HttpRateLimits::from_config(&HttpRateLimitConfig {
+            enabled: true,
+            cleanup_interval: IggyDuration::from(60_000_000),
+            ip: quota(10, 2),
+            user: quota(10, 1),
+        });
+        let ip_address: IpAddr = "127.0.0.1".parse().unwrap();
+        let now = IggyTimestamp::from(1_000_000);
+
+        assert!(limits.try_acquire(ip_address, Some(1), now).is_ok());
+        assert!(limits.try_acquire(ip_address, Some(1), now).is_err());
+        assert!(limits.try_acquire(ip_address, Some(1), now).is_err());
+
+        // The IP address still has the request left, as the rejected ones haven't consumed it.
+        assert!(limits.try_acquire(ip_address, Some(2), now).is_ok());
+        assert!(limits.try_acquire(ip_address, Some(3), now).is_err());
+    }
+}
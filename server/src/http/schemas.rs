@@ -0,0 +1,206 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
+use crate::http::shared::AppState;
+use crate::http::COMPONENT;
+use crate::state::command::EntryCommand;
+use crate::state::models::RegisterSchemaWithId;
+use crate::streaming::users::authorizers::MANAGE_SCHEMAS;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, put};
+use axum::{Extension, Json, Router};
+use error_set::ErrContext;
+use iggy::command::GET_SCHEMAS;
+use iggy::error::IggyError;
+use iggy::models::schema::Schema;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+use tracing::instrument;
+
+const LATEST_VERSION: &str = "latest";
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/schemas/{schema_id}", get(get_schema))
+        .route(
+            "/schemas/subjects/{subject}/versions",
+            get(get_schemas).post(register_schema),
+        )
+        .route(
+            "/schemas/subjects/{subject}/versions/{version}",
+            get(get_subject_schema),
+        )
+        .route(
+            "/schemas/subjects/{subject}/compatibility",
+            put(update_schema_compatibility),
+        )
+        .with_state(state)
+}
+
+async fn get_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(schema_id): Path<u32>,
+) -> Result<Json<Schema>, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_SCHEMAS, None, None)
+        .await;
+    let system = state.system.read().await;
+    let Ok(schema) = system.get_schema(&identity.session(), schema_id) else {
+        return Err(CustomError::ResourceNotFound);
+    };
+    let Some(schema) = schema else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    Ok(Json(mapper::map_schema(schema)))
+}
+
+async fn get_schemas(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(subject): Path<String>,
+) -> Result<Json<Vec<Schema>>, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_SCHEMAS, None, None)
+        .await;
+    let system = state.system.read().await;
+    let schemas = system.get_schemas(&identity.session(), &subject)?;
+    Ok(Json(mapper::map_schemas(&schemas)))
+}
+
+async fn get_subject_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((subject, version)): Path<(String, String)>,
+) -> Result<Json<Schema>, CustomError> {
+    let version = match version.as_str() {
+        LATEST_VERSION => None,
+        version => Some(
+            version
+                .parse::<u32>()
+                .map_err(|_| IggyError::InvalidNumberValue)?,
+        ),
+    };
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_SCHEMAS, None, None)
+        .await;
+    let system = state.system.read().await;
+    let Ok(schema) = system.get_subject_schema(&identity.session(), &subject, version) else {
+        return Err(CustomError::ResourceNotFound);
+    };
+    let Some(schema) = schema else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    Ok(Json(mapper::map_schema(schema)))
+}
+
+#[instrument(skip_all, name = "trace_register_schema", fields(iggy_user_id = identity.user_id, iggy_schema_subject = subject))]
+async fn register_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(subject): Path<String>,
+    Json(mut command): Json<RegisterSchema>,
+) -> Result<(StatusCode, Json<Schema>), CustomError> {
+    command.subject = subject;
+    command.validate()?;
+
+    state
+        .system
+        .resolve_authorization(&identity.session(), MANAGE_SCHEMAS, None, None)
+        .await;
+    let mut system = state.system.write().await;
+    let (schema, created) = system
+        .register_schema(
+            &identity.session(),
+            &command.subject,
+            command.schema.clone(),
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to register schema, subject: {}",
+                command.subject
+            )
+        })?;
+    let schema = mapper::map_schema(schema);
+    if !created {
+        return Ok((StatusCode::OK, Json(schema)));
+    }
+
+    let system = system.downgrade();
+    let commit = system
+        .state
+        .append(
+            identity.user_id,
+            &EntryCommand::RegisterSchema(RegisterSchemaWithId {
+                schema_id: schema.id,
+                command,
+            }),
+        )
+        .await?;
+    drop(system);
+    commit.wait().await?;
+    Ok((StatusCode::CREATED, Json(schema)))
+}
+
+#[instrument(skip_all, name = "trace_update_schema_compatibility", fields(iggy_user_id = identity.user_id, iggy_schema_subject = subject))]
+async fn update_schema_compatibility(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(subject): Path<String>,
+    Json(mut command): Json<UpdateSchemaCompatibility>,
+) -> Result<StatusCode, CustomError> {
+    command.subject = subject;
+    command.validate()?;
+
+    state
+        .system
+        .resolve_authorization(&identity.session(), MANAGE_SCHEMAS, None, None)
+        .await;
+    let mut system = state.system.write().await;
+    system
+        .update_schema_compatibility(&identity.session(), &command.subject, command.compatibility)
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update schema compatibility, subject: {}",
+                command.subject
+            )
+        })?;
+
+    let system = system.downgrade();
+    let commit = system
+        .state
+        .append(
+            identity.user_id,
+            &EntryCommand::UpdateSchemaCompatibility(command),
+        )
+        .await?;
+    drop(system);
+    commit.wait().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
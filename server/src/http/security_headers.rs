@@ -0,0 +1,74 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::http::HttpSecurityHeadersConfig;
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::header::{
+    CONTENT_SECURITY_POLICY, REFERRER_POLICY, STRICT_TRANSPORT_SECURITY, X_CONTENT_TYPE_OPTIONS,
+    X_FRAME_OPTIONS,
+};
+use axum::http::{HeaderName, HeaderValue, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use std::sync::Arc;
+
+/// The security headers added to every response, unless the endpoint has already set them.
+#[derive(Debug, Default)]
+pub struct SecurityHeaders {
+    headers: Vec<(HeaderName, HeaderValue)>,
+}
+
+impl SecurityHeaders {
+    /// Creates the headers from the configuration, skipping the empty values.
+    /// The `Strict-Transport-Security` header is added only when TLS is enabled, as the browsers ignore it otherwise.
+    pub fn from_config(config: &HttpSecurityHeadersConfig, tls_enabled: bool) -> Self {
+        let mut headers = vec![
+            (X_CONTENT_TYPE_OPTIONS, &config.content_type_options),
+            (X_FRAME_OPTIONS, &config.frame_options),
+            (REFERRER_POLICY, &config.referrer_policy),
+            (CONTENT_SECURITY_POLICY, &config.content_security_policy),
+        ];
+        if tls_enabled {
+            headers.push((STRICT_TRANSPORT_SECURITY, &config.strict_transport_security));
+        }
+
+        let headers = headers
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(name, value)| {
+                let value = HeaderValue::from_str(value)
+                    .unwrap_or_else(|_| panic!("Invalid value of HTTP header {name}: {value}"));
+                (name, value)
+            })
+            .collect();
+        Self { headers }
+    }
+}
+
+pub async fn security_headers(
+    State(security_headers): State<Arc<SecurityHeaders>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+    for (name, value) in &security_headers.headers {
+        headers.entry(name.clone()).or_insert_with(|| value.clone());
+    }
+    response
+}
@@ -17,12 +17,19 @@
  */
 
 use crate::http::jwt::jwt_manager::JwtManager;
+use crate::http::jwt::providers::JwtProviders;
+use crate::http::rate_limit::HttpRateLimits;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::shutdown::ShutdownCoordinator;
 use std::net::SocketAddr;
+use std::sync::Arc;
 use ulid::Ulid;
 
 pub struct AppState {
     pub jwt_manager: JwtManager,
+    pub jwt_providers: JwtProviders,
+    pub rate_limits: HttpRateLimits,
+    pub shutdown: Arc<ShutdownCoordinator>,
     pub system: SharedSystem,
 }
 
@@ -0,0 +1,33 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::http::error::CustomError;
+use crate::http::shared::AppState;
+use axum::body::Body;
+use axum::{extract::State, http::Request, middleware::Next, response::Response};
+use std::sync::Arc;
+
+/// Tracks the in-flight requests, so the graceful shutdown waits for them to finish,
+/// and rejects the new ones once the shutdown has been requested.
+pub async fn in_flight_requests(
+    State(state): State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, CustomError> {
+    let _in_flight = state.shutdown.begin_request()?;
+    Ok(next.run(request).await)
+}
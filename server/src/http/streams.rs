@@ -16,17 +16,19 @@
  * under the License.
  */
 
-use crate::http::error::CustomError;
+use crate::http::error::{CustomError, ErrorResponse};
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
-use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::{delete, get};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
+use iggy::command::{
+    CREATE_STREAM, DELETE_STREAM, GET_STREAM, GET_STREAMS, PURGE_STREAM, UPDATE_STREAM,
+};
 use iggy::identifier::Identifier;
 use iggy::models::stream::{Stream, StreamDetails};
 use iggy::streams::create_stream::CreateStream;
@@ -39,6 +41,7 @@ use crate::state::command::EntryCommand;
 use crate::state::models::CreateStreamWithId;
 use std::sync::Arc;
 use tracing::instrument;
+use utoipa::OpenApi;
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -51,17 +54,42 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+/// The OpenAPI specification of the streams endpoints.
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_streams,
+    create_stream,
+    get_stream,
+    update_stream,
+    delete_stream,
+    purge_stream
+))]
+pub struct StreamsApi;
+
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}",
+    tag = "streams",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+    ),
+    responses(
+        (status = 200, description = "The stream details.", body = StreamDetails),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 async fn get_stream(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path(stream_id): Path<String>,
 ) -> Result<Json<StreamDetails>, CustomError> {
-    let system = state.system.read().await;
     let stream_id = Identifier::from_str_value(&stream_id)?;
-    let Ok(stream) = system.try_find_stream(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &stream_id,
-    ) else {
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_STREAM, Some(&stream_id), None)
+        .await;
+    let system = state.system.read().await;
+    let Ok(stream) = system.try_find_stream(&identity.session(), &stream_id) else {
         return Err(CustomError::ResourceNotFound);
     };
     let Some(stream) = stream else {
@@ -72,13 +100,26 @@ async fn get_stream(
     Ok(Json(stream))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams",
+    tag = "streams",
+    responses(
+        (status = 200, description = "The streams.", body = Vec<Stream>),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 async fn get_streams(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
 ) -> Result<Json<Vec<Stream>>, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_STREAMS, None, None)
+        .await;
     let system = state.system.read().await;
     let streams = system
-        .find_streams(&Session::stateless(identity.user_id, identity.ip_address))
+        .find_streams(&identity.session())
         .with_error_context(|error| {
             format!(
                 "{COMPONENT} (error: {error}) - failed to find streams, user ID: {}",
@@ -89,6 +130,16 @@ async fn get_streams(
     Ok(Json(streams))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams",
+    tag = "streams",
+    request_body = CreateStream,
+    responses(
+        (status = 200, description = "The created stream.", body = StreamDetails),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_create_stream", fields(iggy_user_id = identity.user_id))]
 async fn create_stream(
     State(state): State<Arc<AppState>>,
@@ -97,13 +148,13 @@ async fn create_stream(
 ) -> Result<Json<StreamDetails>, CustomError> {
     command.validate()?;
 
+    state
+        .system
+        .resolve_authorization(&identity.session(), CREATE_STREAM, None, None)
+        .await;
     let mut system = state.system.write().await;
     let stream = system
-        .create_stream(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            command.stream_id,
-            &command.name,
-        )
+        .create_stream(&identity.session(), command.stream_id, &command.name)
         .await
         .with_error_context(|error| {
             format!(
@@ -115,18 +166,33 @@ async fn create_stream(
     let response = Json(mapper::map_stream(stream));
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(identity.user_id, &EntryCommand::CreateStream(CreateStreamWithId{ stream_id, command} ))
+        .append(identity.user_id, &EntryCommand::CreateStream(CreateStreamWithId{ stream_id, command} ))
         .await
         .with_error_context(|error| {
             format!(
                 "{COMPONENT} (error: {error}) - failed to apply create stream, stream ID: {stream_id}",
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(response)
 }
 
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}",
+    tag = "streams",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+    ),
+    request_body = UpdateStream,
+    responses(
+        (status = 204, description = "The stream has been updated."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_update_stream", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn update_stream(
     State(state): State<Arc<AppState>>,
@@ -137,13 +203,18 @@ async fn update_stream(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.validate()?;
 
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            UPDATE_STREAM,
+            Some(&command.stream_id),
+            None,
+        )
+        .await;
     let mut system = state.system.write().await;
     system
-        .update_stream(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &command.stream_id,
-            &command.name,
-        )
+        .update_stream(&identity.session(), &command.stream_id, &command.name)
         .await
         .with_error_context(|error| {
             format!(
@@ -153,9 +224,9 @@ async fn update_stream(
         })?;
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(identity.user_id, &EntryCommand::UpdateStream(command))
+        .append(identity.user_id, &EntryCommand::UpdateStream(command))
         .await
         .with_error_context(|error| {
             format!(
@@ -163,9 +234,23 @@ async fn update_stream(
                 stream_id
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}",
+    tag = "streams",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+    ),
+    responses(
+        (status = 204, description = "The stream has been deleted."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_delete_stream", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn delete_stream(
     State(state): State<Arc<AppState>>,
@@ -174,21 +259,27 @@ async fn delete_stream(
 ) -> Result<StatusCode, CustomError> {
     let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
 
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            DELETE_STREAM,
+            Some(&identifier_stream_id),
+            None,
+        )
+        .await;
     let mut system = state.system.write().await;
     system
-        .delete_stream(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &identifier_stream_id,
-        )
+        .delete_stream(&identity.session(), &identifier_stream_id)
         .await
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to delete stream with ID: {stream_id}",)
         })?;
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             identity.user_id,
             &EntryCommand::DeleteStream(DeleteStream {
                 stream_id: identifier_stream_id,
@@ -200,9 +291,23 @@ async fn delete_stream(
                 "{COMPONENT} (error: {error}) - failed to apply delete stream with ID: {stream_id}",
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/purge",
+    tag = "streams",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+    ),
+    responses(
+        (status = 204, description = "The stream has been purged."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_purge_stream", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn purge_stream(
     State(state): State<Arc<AppState>>,
@@ -210,12 +315,18 @@ async fn purge_stream(
     Path(stream_id): Path<String>,
 ) -> Result<StatusCode, CustomError> {
     let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            PURGE_STREAM,
+            Some(&identifier_stream_id),
+            None,
+        )
+        .await;
     let system = state.system.read().await;
     system
-        .purge_stream(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &identifier_stream_id,
-        )
+        .purge_stream(&identity.session(), &identifier_stream_id)
         .await
         .with_error_context(|error| {
             format!(
@@ -223,9 +334,9 @@ async fn purge_stream(
                 stream_id
             )
         })?;
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             identity.user_id,
             &EntryCommand::PurgeStream(PurgeStream {
                 stream_id: identifier_stream_id,
@@ -238,5 +349,7 @@ async fn purge_stream(
                 stream_id
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(StatusCode::NO_CONTENT)
 }
@@ -20,23 +20,38 @@ use crate::configs::http::HttpMetricsConfig;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
+use crate::http::rate_limit::HttpRateLimitsUsage;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
-use crate::streaming::session::Session;
+use crate::streaming::tenants::tenant_registry::TenantUsage;
+use crate::streaming::users::authorizers::{PROMOTE_STANDBY, SHUTDOWN_SERVER, UPDATE_CONFIG};
 use axum::body::Body;
-use axum::extract::{Path, State};
-use axum::http::{header, HeaderMap};
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
 use axum::response::IntoResponse;
-use axum::routing::{get, post};
+use axum::routing::{get, post, put};
 use axum::{Extension, Json, Router};
 use bytes::Bytes;
 use chrono::Local;
 use error_set::ErrContext;
+use iggy::command::{
+    CREATE_BACKUP, GET_AUDIT_LOG, GET_CLIENT, GET_CLIENTS, GET_CONFIG, GET_STATS, RESTORE_BACKUP,
+};
 use iggy::locking::IggySharedMutFn;
+use iggy::models::audit_entry::AuditEntry;
+use iggy::models::backup::BackupInfo;
 use iggy::models::client_info::{ClientInfo, ClientInfoDetails};
+use iggy::models::cluster::ClusterMetadata;
+use iggy::models::config_value::ConfigValue;
 use iggy::models::stats::Stats;
+use iggy::system::create_backup::CreateBackup;
+use iggy::system::get_audit_log::GetAuditLog;
 use iggy::system::get_snapshot::GetSnapshot;
+use iggy::system::restore_backup::RestoreBackup;
+use iggy::utils::timestamp::IggyTimestamp;
 use iggy::validatable::Validatable;
+use serde_json::Value;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 const NAME: &str = "Iggy API";
@@ -45,11 +60,20 @@ const PONG: &str = "pong";
 pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Router {
     let mut router = Router::new()
         .route("/", get(|| async { NAME }))
-        .route("/ping", get(|| async { PONG }))
         .route("/stats", get(get_stats))
+        .route("/stats/tenants", get(get_tenants))
         .route("/clients", get(get_clients))
         .route("/clients/{client_id}", get(get_client))
-        .route("/snapshot", post(get_snapshot));
+        .route("/config", get(get_config))
+        .route("/config/{section}", put(update_config))
+        .route("/audit", get(get_audit_log))
+        .route("/snapshot", post(get_snapshot))
+        .route("/backups", post(create_backup))
+        .route("/backups/{name}/restore", post(restore_backup))
+        .route("/cluster/metadata", get(get_cluster_metadata))
+        .route("/shutdown", post(shutdown))
+        .route("/standby/promote", post(promote_standby))
+        .route("/diagnostics/rate-limits", get(get_rate_limits));
     if metrics_config.enabled {
         router = router.route(&metrics_config.endpoint, get(get_metrics));
     }
@@ -57,8 +81,16 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
     router.with_state(state)
 }
 
+/// The public health endpoint, routed separately so that it can have its own CORS rules.
+pub fn health_router() -> Router {
+    Router::new().route("/ping", get(|| async { PONG }))
+}
+
 async fn get_metrics(State(state): State<Arc<AppState>>) -> Result<String, CustomError> {
     let system = state.system.read().await;
+    system.refresh_partition_metrics().await;
+    system.refresh_request_lane_metrics();
+    system.refresh_buffer_pool_metrics();
     Ok(system.metrics.get_formatted_output())
 }
 
@@ -70,17 +102,38 @@ async fn get_stats(State(state): State<Arc<AppState>>) -> Result<Json<Stats>, Cu
     Ok(Json(stats))
 }
 
+async fn get_tenants(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<TenantUsage>>, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_STATS, None, None)
+        .await;
+    let system = state.system.read().await;
+    let tenants = system
+        .get_tenants(&identity.session())
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get tenants, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(tenants))
+}
+
 async fn get_client(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path(client_id): Path<u32>,
 ) -> Result<Json<ClientInfoDetails>, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_CLIENT, None, None)
+        .await;
     let system = state.system.read().await;
     let Ok(client) = system
-        .get_client(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            client_id,
-        )
+        .get_client(&identity.session(), client_id)
         .await
         .with_error_context(|error| {
             format!(
@@ -104,9 +157,13 @@ async fn get_clients(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
 ) -> Result<Json<Vec<ClientInfo>>, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_CLIENTS, None, None)
+        .await;
     let system = state.system.read().await;
     let clients = system
-        .get_clients(&Session::stateless(identity.user_id, identity.ip_address))
+        .get_clients(&identity.session())
         .await
         .with_error_context(|error| {
             format!(
@@ -118,6 +175,210 @@ async fn get_clients(
     Ok(Json(clients))
 }
 
+async fn get_config(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<ConfigValue>>, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_CONFIG, None, None)
+        .await;
+    let system = state.system.read().await;
+    let config = system
+        .get_effective_config(&identity.session())
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get effective config, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(config))
+}
+
+async fn get_cluster_metadata(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<ClusterMetadata>, CustomError> {
+    let system = state.system.read().await;
+    let metadata = system
+        .get_cluster_metadata(&identity.session())
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get cluster metadata, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(metadata))
+}
+
+async fn get_audit_log(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    query: Query<GetAuditLog>,
+) -> Result<Json<Vec<AuditEntry>>, CustomError> {
+    query.validate()?;
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_AUDIT_LOG, None, None)
+        .await;
+    let system = state.system.read().await;
+    let entries = system
+        .get_audit_log(&identity.session(), &query.0)
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get audit log, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(entries))
+}
+
+async fn create_backup(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<CreateBackup>,
+) -> Result<(StatusCode, Json<BackupInfo>), CustomError> {
+    command.validate()?;
+    state
+        .system
+        .resolve_authorization(&identity.session(), CREATE_BACKUP, None, None)
+        .await;
+    let system = state.system.read().await;
+    let backup = system
+        .create_backup(&identity.session(), &command)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to create backup with name: {}, user ID: {}",
+                command.name, identity.user_id
+            )
+        })?;
+    Ok((StatusCode::CREATED, Json(backup)))
+}
+
+async fn restore_backup(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(name): Path<String>,
+    Json(mut command): Json<RestoreBackup>,
+) -> Result<Json<BackupInfo>, CustomError> {
+    command.name = name;
+    command.validate()?;
+    state
+        .system
+        .resolve_authorization(&identity.session(), RESTORE_BACKUP, None, None)
+        .await;
+    let mut system = state.system.write().await;
+    let backup = system
+        .restore_backup_streams(
+            &identity.session(),
+            &command,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to restore backup with name: {}, user ID: {}",
+                command.name, identity.user_id
+            )
+        })?;
+    Ok(Json(backup))
+}
+
+async fn shutdown(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<StatusCode, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), SHUTDOWN_SERVER, None, None)
+        .await;
+    state
+        .system
+        .read()
+        .await
+        .request_shutdown(&identity.session())
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to request shutdown, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn promote_standby(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<StatusCode, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), PROMOTE_STANDBY, None, None)
+        .await;
+    state
+        .system
+        .read()
+        .await
+        .promote_standby(&identity.session())
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to promote standby, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn update_config(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(section): Path<String>,
+    Json(values): Json<BTreeMap<String, Value>>,
+) -> Result<Json<Vec<ConfigValue>>, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), UPDATE_CONFIG, None, None)
+        .await;
+    let mut system = state.system.write().await;
+    let config = system
+        .update_config(
+            &identity.session(),
+            &section,
+            values,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update config section: {section}, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(config))
+}
+
+async fn get_rate_limits(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<HttpRateLimitsUsage>, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_STATS, None, None)
+        .await;
+    state
+        .system
+        .read()
+        .await
+        .permissioner
+        .get_stats(&identity.session())
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - permission denied to get rate limits for user with ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(state.rate_limits.get_usage(IggyTimestamp::now())))
+}
+
 async fn get_snapshot(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -125,7 +386,7 @@ async fn get_snapshot(
 ) -> Result<impl IntoResponse, CustomError> {
     command.validate()?;
 
-    let session = Session::stateless(identity.user_id, identity.ip_address);
+    let session = identity.session();
     let system = state.system.read().await;
 
     let snapshot = system
@@ -16,28 +16,34 @@
  * under the License.
  */
 
-use crate::http::error::CustomError;
+use crate::http::error::{CustomError, ErrorResponse};
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::http::COMPONENT;
 use crate::state::command::EntryCommand;
 use crate::state::models::CreateTopicWithId;
-use crate::streaming::session::Session;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
+use axum::routing::{delete, get, put};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
+use iggy::command::{
+    CREATE_TOPIC, DELETE_TOPIC, GET_TOPIC, GET_TOPICS, PURGE_TOPIC, SET_TOPIC_THROTTLE,
+    UPDATE_TOPIC,
+};
 use iggy::identifier::Identifier;
 use iggy::models::topic::{Topic, TopicDetails};
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::purge_topic::PurgeTopic;
+use iggy::topics::set_topic_throttle::SetTopicThrottle;
 use iggy::topics::update_topic::UpdateTopic;
+use iggy::utils::byte_size::IggyByteSize;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 use tracing::instrument;
+use utoipa::OpenApi;
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -53,44 +59,97 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/{stream_id}/topics/{topic_id}/purge",
             delete(purge_topic),
         )
+        .route(
+            "/streams/{stream_id}/topics/{topic_id}/throttle",
+            put(set_topic_throttle),
+        )
         .with_state(state)
 }
 
+/// The OpenAPI specification of the topics endpoints.
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_topics,
+    create_topic,
+    get_topic,
+    update_topic,
+    delete_topic,
+    purge_topic,
+    set_topic_throttle
+))]
+pub struct TopicsApi;
+
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+    ),
+    responses(
+        (status = 200, description = "The topic details.", body = TopicDetails),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 async fn get_topic(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
 ) -> Result<Json<TopicDetails>, CustomError> {
-    let system = state.system.read().await;
     let identity_stream_id = Identifier::from_str_value(&stream_id)?;
     let identity_topic_id = Identifier::from_str_value(&topic_id)?;
-    let Ok(topic) = system.try_find_topic(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &identity_stream_id,
-        &identity_topic_id,
-    ) else {
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            GET_TOPIC,
+            Some(&identity_stream_id),
+            Some(&identity_topic_id),
+        )
+        .await;
+    let system = state.system.read().await;
+    let Ok(topic) =
+        system.try_find_topic(&identity.session(), &identity_stream_id, &identity_topic_id)
+    else {
         return Err(CustomError::ResourceNotFound);
     };
     let Some(topic) = topic else {
         return Err(CustomError::ResourceNotFound);
     };
 
-    let topic = mapper::map_topic(topic).await;
+    let disk_usage = system
+        .storage_usage
+        .get_topic_disk_usage_bytes(topic.stream_id, topic.topic_id);
+    let topic = mapper::map_topic(topic, disk_usage.into()).await;
     Ok(Json(topic))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+    ),
+    responses(
+        (status = 200, description = "The topics of the stream.", body = Vec<Topic>),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 async fn get_topics(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path(stream_id): Path<String>,
 ) -> Result<Json<Vec<Topic>>, CustomError> {
     let stream_id = Identifier::from_str_value(&stream_id)?;
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_TOPICS, Some(&stream_id), None)
+        .await;
     let system = state.system.read().await;
     let topics = system
-        .find_topics(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &stream_id,
-        )
+        .find_topics(&identity.session(), &stream_id)
         .with_error_context(|error| {
             format!(
                 "{COMPONENT} (error: {error}) - failed to find topics for stream with ID: {}",
@@ -101,6 +160,19 @@ async fn get_topics(
     Ok(Json(topics))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+    ),
+    request_body = CreateTopic,
+    responses(
+        (status = 200, description = "The created topic.", body = TopicDetails),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_create_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn create_topic(
     State(state): State<Arc<AppState>>,
@@ -111,10 +183,19 @@ async fn create_topic(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.validate()?;
 
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            CREATE_TOPIC,
+            Some(&command.stream_id),
+            None,
+        )
+        .await;
     let mut system = state.system.write().await;
     let topic = system
         .create_topic(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.stream_id,
             command.topic_id,
             &command.name,
@@ -123,6 +204,7 @@ async fn create_topic(
             command.compression_algorithm,
             command.max_topic_size,
             command.replication_factor,
+            command.options.clone(),
         )
         .await
         .with_error_context(|error| {
@@ -134,21 +216,37 @@ async fn create_topic(
     command.message_expiry = topic.message_expiry;
     command.max_topic_size = topic.max_topic_size;
     let topic_id = topic.topic_id;
-    let response = Json(mapper::map_topic(topic).await);
+    let response = Json(mapper::map_topic(topic, IggyByteSize::default()).await);
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(identity.user_id, &EntryCommand::CreateTopic(CreateTopicWithId { topic_id, command } ))
+        .append(identity.user_id, &EntryCommand::CreateTopic(CreateTopicWithId { topic_id, command } ))
         .await
         .with_error_context(|error| {
             format!(
                 "{COMPONENT} (error: {error}) - failed to apply create topic, stream ID: {stream_id}",
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(response)
 }
 
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}/topics/{topic_id}",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+    ),
+    request_body = UpdateTopic,
+    responses(
+        (status = 204, description = "The topic has been updated."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_update_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn update_topic(
     State(state): State<Arc<AppState>>,
@@ -160,10 +258,19 @@ async fn update_topic(
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
 
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            UPDATE_TOPIC,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
     let mut system = state.system.write().await;
     let topic = system
             .update_topic(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &identity.session(),
                 &command.stream_id,
                 &command.topic_id,
                 &command.name,
@@ -171,6 +278,7 @@ async fn update_topic(
                 command.compression_algorithm,
                 command.max_topic_size,
                 command.replication_factor,
+                command.options.clone(),
             )
             .await
             .with_error_context(|error| {
@@ -183,9 +291,9 @@ async fn update_topic(
     command.max_topic_size = topic.max_topic_size;
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(identity.user_id, &EntryCommand::UpdateTopic(command))
+        .append(identity.user_id, &EntryCommand::UpdateTopic(command))
         .await
         .with_error_context(|error| {
             format!(
@@ -193,24 +301,53 @@ async fn update_topic(
                 stream_id, topic_id
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+        ("deferred" = Option<bool>, Query, description = "Whether to wait for the consumer groups, before removing the topic."),
+    ),
+    responses(
+        (status = 204, description = "The topic has been deleted."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_delete_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn delete_topic(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
+    Query(query): Query<DeleteTopic>,
 ) -> Result<StatusCode, CustomError> {
     let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
     let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
 
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            DELETE_TOPIC,
+            Some(&identifier_stream_id),
+            Some(&identifier_topic_id),
+        )
+        .await;
     let mut system = state.system.write().await;
+    let is_being_deleted =
+        system.is_topic_being_deleted(&identifier_stream_id, &identifier_topic_id);
     system
             .delete_topic(
-                &Session::stateless(identity.user_id, identity.ip_address),
+                &identity.session(),
                 &identifier_stream_id,
                 &identifier_topic_id,
+                query.deferred,
             )
             .await
             .with_error_context(|error| {
@@ -219,14 +356,19 @@ async fn delete_topic(
                 )
             })?;
 
+    if is_being_deleted {
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             identity.user_id,
             &EntryCommand::DeleteTopic(DeleteTopic {
                 stream_id: identifier_stream_id,
                 topic_id: identifier_topic_id,
+                deferred: query.deferred,
             }),
         )
         .await
@@ -236,9 +378,24 @@ async fn delete_topic(
                 stream_id, topic_id
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/purge",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+    ),
+    responses(
+        (status = 204, description = "The topic has been purged."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_purge_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn purge_topic(
     State(state): State<Arc<AppState>>,
@@ -247,10 +404,19 @@ async fn purge_topic(
 ) -> Result<StatusCode, CustomError> {
     let identifier_stream_id = Identifier::from_str_value(&stream_id)?;
     let identifier_topic_id = Identifier::from_str_value(&topic_id)?;
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            PURGE_TOPIC,
+            Some(&identifier_stream_id),
+            Some(&identifier_topic_id),
+        )
+        .await;
     let system = state.system.read().await;
     system
         .purge_topic(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &identifier_stream_id,
             &identifier_topic_id,
         )
@@ -261,9 +427,9 @@ async fn purge_topic(
                 stream_id, topic_id
             )
         })?;
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             identity.user_id,
             &EntryCommand::PurgeTopic(PurgeTopic {
                 stream_id: identifier_stream_id,
@@ -277,5 +443,59 @@ async fn purge_topic(
                 stream_id, topic_id
             )
         })?;
+    drop(system);
+    commit.wait().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}/topics/{topic_id}/throttle",
+    tag = "topics",
+    params(
+        ("stream_id" = String, Path, description = "Unique stream ID (numeric or name)."),
+        ("topic_id" = String, Path, description = "Unique topic ID (numeric or name)."),
+    ),
+    request_body = SetTopicThrottle,
+    responses(
+        (status = 204, description = "The topic throttle has been set."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
+#[instrument(skip_all, name = "trace_set_topic_throttle", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn set_topic_throttle(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<SetTopicThrottle>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    state
+        .system
+        .resolve_authorization(
+            &identity.session(),
+            SET_TOPIC_THROTTLE,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
+    let system = state.system.read().await;
+    system
+        .set_topic_throttle(
+            &identity.session(),
+            &command.stream_id,
+            &command.topic_id,
+            command.max_throughput,
+            command.duration,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to set topic throttle, stream ID: {}, topic ID: {}",
+                stream_id, topic_id
+            )
+        })?;
     Ok(StatusCode::NO_CONTENT)
 }
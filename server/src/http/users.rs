@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use crate::http::error::CustomError;
+use crate::http::error::{CustomError, ErrorResponse};
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_access_token_to_identity_info;
@@ -24,13 +24,15 @@ use crate::http::shared::AppState;
 use crate::http::COMPONENT;
 use crate::state::command::EntryCommand;
 use crate::state::models::CreateUserWithId;
-use crate::streaming::session::Session;
 use crate::streaming::utils::crypto;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::{delete, get, post, put};
 use axum::{Extension, Json, Router};
 use error_set::ErrContext;
+use iggy::command::{
+    CHANGE_PASSWORD, CREATE_USER, DELETE_USER, GET_USER, GET_USERS, UPDATE_PERMISSIONS, UPDATE_USER,
+};
 use iggy::identifier::Identifier;
 use iggy::models::identity_info::IdentityInfo;
 use iggy::models::user_info::{UserInfo, UserInfoDetails};
@@ -44,6 +46,7 @@ use iggy::validatable::Validatable;
 use serde::Deserialize;
 use std::sync::Arc;
 use tracing::instrument;
+use utoipa::{OpenApi, ToSchema};
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -60,17 +63,46 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+/// The OpenAPI specification of the users endpoints.
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_users,
+    create_user,
+    get_user,
+    update_user,
+    delete_user,
+    update_permissions,
+    change_password,
+    login_user,
+    logout_user,
+    refresh_token
+))]
+pub struct UsersApi;
+
+#[utoipa::path(
+    get,
+    path = "/users/{user_id}",
+    tag = "users",
+    params(
+        ("user_id" = String, Path, description = "Unique user ID (numeric or username)."),
+    ),
+    responses(
+        (status = 200, description = "The user details.", body = UserInfoDetails),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 async fn get_user(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path(user_id): Path<String>,
 ) -> Result<Json<UserInfoDetails>, CustomError> {
     let identifier_user_id = Identifier::from_str_value(&user_id)?;
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_USER, None, None)
+        .await;
     let system = state.system.read().await;
-    let Ok(user) = system.find_user(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &identifier_user_id,
-    ) else {
+    let Ok(user) = system.find_user(&identity.session(), &identifier_user_id) else {
         return Err(CustomError::ResourceNotFound);
     };
     let Some(user) = user else {
@@ -81,13 +113,26 @@ async fn get_user(
     Ok(Json(user))
 }
 
+#[utoipa::path(
+    get,
+    path = "/users",
+    tag = "users",
+    responses(
+        (status = 200, description = "The users.", body = Vec<UserInfo>),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 async fn get_users(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
 ) -> Result<Json<Vec<UserInfo>>, CustomError> {
+    state
+        .system
+        .resolve_authorization(&identity.session(), GET_USERS, None, None)
+        .await;
     let system = state.system.read().await;
     let users = system
-        .get_users(&Session::stateless(identity.user_id, identity.ip_address))
+        .get_users(&identity.session())
         .await
         .with_error_context(|error| {
             format!(
@@ -99,6 +144,16 @@ async fn get_users(
     Ok(Json(users))
 }
 
+#[utoipa::path(
+    post,
+    path = "/users",
+    tag = "users",
+    request_body = CreateUser,
+    responses(
+        (status = 200, description = "The created user.", body = UserInfoDetails),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_create_user", fields(iggy_user_id = identity.user_id))]
 async fn create_user(
     State(state): State<Arc<AppState>>,
@@ -107,10 +162,14 @@ async fn create_user(
 ) -> Result<Json<UserInfoDetails>, CustomError> {
     command.validate()?;
 
+    state
+        .system
+        .resolve_authorization(&identity.session(), CREATE_USER, None, None)
+        .await;
     let mut system = state.system.write().await;
     let user = system
         .create_user(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.username,
             &command.password,
             command.status,
@@ -128,9 +187,9 @@ async fn create_user(
 
     // For the security of the system, we hash the password before storing it in metadata.
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             identity.user_id,
             &EntryCommand::CreateUser(CreateUserWithId {
                 user_id: user_id,
@@ -149,10 +208,25 @@ async fn create_user(
                 command.username
             )
         })?;
+    drop(system);
+    commit.wait().await?;
 
     Ok(response)
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}",
+    tag = "users",
+    params(
+        ("user_id" = String, Path, description = "Unique user ID (numeric or username)."),
+    ),
+    request_body = UpdateUser,
+    responses(
+        (status = 204, description = "The user has been updated."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_update_user", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
 async fn update_user(
     State(state): State<Arc<AppState>>,
@@ -163,10 +237,14 @@ async fn update_user(
     command.user_id = Identifier::from_str_value(&user_id)?;
     command.validate()?;
 
+    state
+        .system
+        .resolve_authorization(&identity.session(), UPDATE_USER, None, None)
+        .await;
     let mut system = state.system.write().await;
     system
         .update_user(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.user_id,
             command.username.clone(),
             command.status,
@@ -180,9 +258,9 @@ async fn update_user(
         })?;
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(identity.user_id, &EntryCommand::UpdateUser(command))
+        .append(identity.user_id, &EntryCommand::UpdateUser(command))
         .await
         .with_error_context(|error| {
             format!(
@@ -190,9 +268,24 @@ async fn update_user(
                 user_id
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}/permissions",
+    tag = "users",
+    params(
+        ("user_id" = String, Path, description = "Unique user ID (numeric or username)."),
+    ),
+    request_body = UpdatePermissions,
+    responses(
+        (status = 204, description = "The user permissions have been updated."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_update_permissions", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
 async fn update_permissions(
     State(state): State<Arc<AppState>>,
@@ -203,10 +296,14 @@ async fn update_permissions(
     command.user_id = Identifier::from_str_value(&user_id)?;
     command.validate()?;
 
+    state
+        .system
+        .resolve_authorization(&identity.session(), UPDATE_PERMISSIONS, None, None)
+        .await;
     let mut system = state.system.write().await;
     system
         .update_permissions(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.user_id,
             command.permissions.clone(),
         )
@@ -219,9 +316,9 @@ async fn update_permissions(
         })?;
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(identity.user_id, &EntryCommand::UpdatePermissions(command))
+        .append(identity.user_id, &EntryCommand::UpdatePermissions(command))
         .await
         .with_error_context(|error| {
             format!(
@@ -229,9 +326,24 @@ async fn update_permissions(
                 user_id
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}/password",
+    tag = "users",
+    params(
+        ("user_id" = String, Path, description = "Unique user ID (numeric or username)."),
+    ),
+    request_body = ChangePassword,
+    responses(
+        (status = 204, description = "The user password has been changed."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_change_password", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
 async fn change_password(
     State(state): State<Arc<AppState>>,
@@ -242,10 +354,14 @@ async fn change_password(
     command.user_id = Identifier::from_str_value(&user_id)?;
     command.validate()?;
 
+    state
+        .system
+        .resolve_authorization(&identity.session(), CHANGE_PASSWORD, None, None)
+        .await;
     let mut system = state.system.write().await;
     system
         .change_password(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &identity.session(),
             &command.user_id,
             &command.current_password,
             &command.new_password,
@@ -260,9 +376,9 @@ async fn change_password(
 
     // For the security of the system, we hash the password before storing it in metadata.
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             identity.user_id,
             &EntryCommand::ChangePassword(ChangePassword {
                 user_id: command.user_id,
@@ -277,9 +393,23 @@ async fn change_password(
                 user_id
             )
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/users/{user_id}",
+    tag = "users",
+    params(
+        ("user_id" = String, Path, description = "Unique user ID (numeric or username)."),
+    ),
+    responses(
+        (status = 204, description = "The user has been deleted."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_delete_user", fields(iggy_user_id = identity.user_id, iggy_deleted_user_id = user_id))]
 async fn delete_user(
     State(state): State<Arc<AppState>>,
@@ -288,21 +418,22 @@ async fn delete_user(
 ) -> Result<StatusCode, CustomError> {
     let identifier_user_id = Identifier::from_str_value(&user_id)?;
 
+    state
+        .system
+        .resolve_authorization(&identity.session(), DELETE_USER, None, None)
+        .await;
     let mut system = state.system.write().await;
     system
-        .delete_user(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            &identifier_user_id,
-        )
+        .delete_user(&identity.session(), &identifier_user_id)
         .await
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to delete user with ID: {user_id}")
         })?;
 
     let system = system.downgrade();
-    system
+    let commit = system
         .state
-        .apply(
+        .append(
             identity.user_id,
             &EntryCommand::DeleteUser(DeleteUser {
                 user_id: identifier_user_id,
@@ -312,9 +443,22 @@ async fn delete_user(
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to apply delete user with ID: {user_id}")
         })?;
+    drop(system);
+    commit.wait().await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/users/login",
+    tag = "users",
+    request_body = LoginUser,
+    security(()),
+    responses(
+        (status = 200, description = "The identity with the access token.", body = IdentityInfo),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_login_user")]
 async fn login_user(
     State(state): State<Arc<AppState>>,
@@ -331,10 +475,19 @@ async fn login_user(
                 command.username
             )
         })?;
-    let tokens = state.jwt_manager.generate(user.id)?;
+    let tokens = state.jwt_manager.generate(user.id, None)?;
     Ok(Json(map_generated_access_token_to_identity_info(tokens)))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/users/logout",
+    tag = "users",
+    responses(
+        (status = 204, description = "The user has been logged out."),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 #[instrument(skip_all, name = "trace_logout_user", fields(iggy_user_id = identity.user_id))]
 async fn logout_user(
     State(state): State<Arc<AppState>>,
@@ -342,7 +495,7 @@ async fn logout_user(
 ) -> Result<StatusCode, CustomError> {
     let system = state.system.read().await;
     system
-        .logout_user(&Session::stateless(identity.user_id, identity.ip_address))
+        .logout_user(&identity.session())
         .await
         .with_error_context(|error| {
             format!(
@@ -363,6 +516,17 @@ async fn logout_user(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/users/refresh-token",
+    tag = "users",
+    request_body = RefreshToken,
+    security(()),
+    responses(
+        (status = 200, description = "The identity with the new access token.", body = IdentityInfo),
+        (status = "default", description = "The request has failed.", body = ErrorResponse),
+    )
+)]
 async fn refresh_token(
     State(state): State<Arc<AppState>>,
     Json(command): Json<RefreshToken>,
@@ -377,7 +541,7 @@ async fn refresh_token(
     Ok(Json(map_generated_access_token_to_identity_info(token)))
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 struct RefreshToken {
     token: String,
 }
@@ -0,0 +1,478 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::http::HttpWebSocketConfig;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::messages::append_messages;
+use crate::http::shared::AppState;
+use crate::http::COMPONENT;
+use crate::streaming::diagnostics::metrics::QuotaDirection;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use axum::extract::ws::{Message as WebSocketMessage, WebSocket, WebSocketUpgrade};
+use axum::extract::State;
+use axum::response::Response;
+use axum::routing::get;
+use axum::{Extension, Router};
+use bytes::Bytes;
+use error_set::ErrContext;
+use futures::{SinkExt, StreamExt};
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::command::POLL_MESSAGES;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::SendMessages;
+use iggy::messages::PayloadEncoding;
+use iggy::models::messages::{EncodedPolledMessages, PolledMessages};
+use iggy::utils::duration::IggyDuration;
+use iggy::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{debug, error};
+
+const WEBSOCKET_PATH: &str = "/ws";
+const RESPONSES_BUFFER_SIZE: usize = 1000;
+const REQUEST_ID_SIZE: usize = 4;
+
+/// The state of the WebSocket endpoint, shared by all the connections.
+#[derive(Clone)]
+struct WebSocketState {
+    app: Arc<AppState>,
+    config: HttpWebSocketConfig,
+}
+
+pub fn router(state: Arc<AppState>, config: &HttpWebSocketConfig) -> Router {
+    Router::new()
+        .route(WEBSOCKET_PATH, get(connect))
+        .with_state(WebSocketState {
+            app: state,
+            config: config.clone(),
+        })
+}
+
+/// The JSON request sent as the text frame, where `id` is chosen by the client to match the response.
+#[derive(Debug, Deserialize)]
+struct WebSocketRequest {
+    id: u32,
+    #[serde(flatten)]
+    command: WebSocketCommand,
+}
+
+/// The command of the request, where `body` has the same fields as the body of the HTTP send messages request,
+/// or the query of the HTTP poll messages request.
+#[serde_as]
+#[derive(Debug, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+enum WebSocketCommand {
+    Send {
+        stream_id: String,
+        topic_id: String,
+        body: SendMessages,
+    },
+    Poll {
+        stream_id: String,
+        topic_id: String,
+        body: PollMessages,
+        #[serde(default)]
+        payload_encoding: PayloadEncoding,
+    },
+    /// Pushes the new messages until unsubscribed, continuing after the last pushed message.
+    Subscribe {
+        stream_id: String,
+        topic_id: String,
+        body: PollMessages,
+        #[serde(default)]
+        payload_encoding: PayloadEncoding,
+        #[serde_as(as = "Option<DisplayFromStr>")]
+        #[serde(default)]
+        poll_interval: Option<IggyDuration>,
+    },
+    /// Stops the subscription created by the `subscribe` request with the given ID.
+    Unsubscribe { subscription_id: u32 },
+}
+
+/// The JSON response sent as the text frame, where `id` is the ID of the request.
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum WebSocketResponse {
+    Ok {
+        id: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        messages: Option<EncodedPolledMessages>,
+    },
+    Error {
+        id: u32,
+        code: u32,
+        reason: String,
+    },
+    /// The new messages pushed for the subscription, where `id` is the ID of the `subscribe` request.
+    Messages {
+        id: u32,
+        messages: EncodedPolledMessages,
+    },
+}
+
+impl WebSocketResponse {
+    fn error(id: u32, error: &IggyError) -> Self {
+        WebSocketResponse::Error {
+            id,
+            code: error.as_code(),
+            reason: error.to_string(),
+        }
+    }
+}
+
+/// The token bucket limiting the number of requests per second on a single connection.
+struct RequestsLimiter {
+    limit: u32,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RequestsLimiter {
+    fn new(limit: u32) -> Self {
+        RequestsLimiter {
+            limit,
+            tokens: limit as f64,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn try_acquire(&mut self) -> bool {
+        if self.limit == 0 {
+            return true;
+        }
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.last_refill = now;
+        self.tokens = (self.tokens + elapsed * self.limit as f64).min(self.limit as f64);
+        if self.tokens < 1.0 {
+            return false;
+        }
+
+        self.tokens -= 1.0;
+        true
+    }
+}
+
+async fn connect(
+    State(state): State<WebSocketState>,
+    Extension(identity): Extension<Identity>,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    upgrade.on_upgrade(move |socket| handle_connection(state, identity, socket))
+}
+
+/// Handles the requests until the client disconnects, the responses and the subscriptions messages
+/// are sent through the channel, so that the subscriptions don't block the requests.
+async fn handle_connection(state: WebSocketState, identity: Identity, socket: WebSocket) {
+    let (mut sender, mut receiver) = socket.split();
+    let (responses, mut responses_receiver) =
+        mpsc::channel::<WebSocketResponse>(RESPONSES_BUFFER_SIZE);
+    let writer = tokio::spawn(async move {
+        while let Some(response) = responses_receiver.recv().await {
+            let response = match serde_json::to_string(&response) {
+                Ok(response) => response,
+                Err(error) => {
+                    error!("{COMPONENT} - failed to serialize WebSocket response, error: {error}");
+                    continue;
+                }
+            };
+            if sender
+                .send(WebSocketMessage::Text(response.into()))
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+
+    debug!(
+        "WebSocket connection for user with ID: {} has been established.",
+        identity.user_id
+    );
+    let mut limiter = RequestsLimiter::new(state.config.max_requests_per_second);
+    let mut subscriptions = HashMap::<u32, JoinHandle<()>>::new();
+    while let Some(Ok(frame)) = receiver.next().await {
+        let request = match frame {
+            WebSocketMessage::Text(text) => serde_json::from_str::<WebSocketRequest>(&text)
+                .map(|request| (request.id, WebSocketRequestKind::Json(request.command)))
+                .map_err(|_| IggyError::InvalidFormat),
+            WebSocketMessage::Binary(bytes) => read_binary_request(bytes),
+            WebSocketMessage::Close(_) => break,
+            _ => continue,
+        };
+
+        if !limiter.try_acquire() {
+            let id = request.as_ref().map_or(0, |(id, _)| *id);
+            let error = IggyError::RequestsLimitExceeded(state.config.max_requests_per_second);
+            if responses
+                .send(WebSocketResponse::error(id, &error))
+                .await
+                .is_err()
+            {
+                break;
+            }
+            continue;
+        }
+
+        let response = match request {
+            Ok((id, request)) => handle_request(
+                &state,
+                &identity,
+                &responses,
+                &mut subscriptions,
+                id,
+                request,
+            )
+            .await
+            .unwrap_or_else(|error| WebSocketResponse::error(id, &error)),
+            Err(error) => WebSocketResponse::error(0, &error),
+        };
+        if responses.send(response).await.is_err() {
+            break;
+        }
+    }
+
+    for subscription in subscriptions.into_values() {
+        subscription.abort();
+    }
+    drop(responses);
+    let _ = writer.await;
+    debug!(
+        "WebSocket connection for user with ID: {} has been closed.",
+        identity.user_id
+    );
+}
+
+/// The request sent either as the JSON text frame, or as the binary frame with the send messages command.
+enum WebSocketRequestKind {
+    Json(WebSocketCommand),
+    SendBinary(SendMessages),
+}
+
+/// Reads the binary frame consisting of the request ID (u32, little endian),
+/// followed by the send messages command serialized as in the binary protocol.
+fn read_binary_request(bytes: Bytes) -> Result<(u32, WebSocketRequestKind), IggyError> {
+    if bytes.len() <= REQUEST_ID_SIZE {
+        return Err(IggyError::InvalidFormat);
+    }
+
+    let id = u32::from_le_bytes(
+        bytes[..REQUEST_ID_SIZE]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let command = SendMessages::from_bytes(bytes.slice(REQUEST_ID_SIZE..))?;
+    Ok((id, WebSocketRequestKind::SendBinary(command)))
+}
+
+async fn handle_request(
+    state: &WebSocketState,
+    identity: &Identity,
+    responses: &mpsc::Sender<WebSocketResponse>,
+    subscriptions: &mut HashMap<u32, JoinHandle<()>>,
+    id: u32,
+    request: WebSocketRequestKind,
+) -> Result<WebSocketResponse, IggyError> {
+    let command = match request {
+        WebSocketRequestKind::Json(command) => command,
+        WebSocketRequestKind::SendBinary(command) => {
+            append_messages(&state.app, identity, command).await?;
+            return Ok(WebSocketResponse::Ok { id, messages: None });
+        }
+    };
+
+    match command {
+        WebSocketCommand::Send {
+            stream_id,
+            topic_id,
+            mut body,
+        } => {
+            body.stream_id = Identifier::from_str_value(&stream_id)?;
+            body.topic_id = Identifier::from_str_value(&topic_id)?;
+            append_messages(&state.app, identity, body).await?;
+            Ok(WebSocketResponse::Ok { id, messages: None })
+        }
+        WebSocketCommand::Poll {
+            stream_id,
+            topic_id,
+            body,
+            payload_encoding,
+        } => {
+            let command = get_poll_messages_command(&stream_id, &topic_id, body)?;
+            let session = identity.session();
+            let polled_messages =
+                poll_messages(&state.app, &session, &command, command.strategy).await?;
+            Ok(WebSocketResponse::Ok {
+                id,
+                messages: Some(EncodedPolledMessages::new(
+                    polled_messages,
+                    payload_encoding,
+                )),
+            })
+        }
+        WebSocketCommand::Subscribe {
+            stream_id,
+            topic_id,
+            body,
+            payload_encoding,
+            poll_interval,
+        } => {
+            let command = get_poll_messages_command(&stream_id, &topic_id, body)?;
+            let subscription = WebSocketSubscription {
+                id,
+                state: state.app.clone(),
+                session: identity.session(),
+                strategy: command.strategy,
+                command,
+                payload_encoding,
+                poll_interval: poll_interval.unwrap_or(state.config.poll_interval),
+                responses: responses.clone(),
+            };
+            if let Some(previous) = subscriptions.insert(id, tokio::spawn(subscription.run())) {
+                previous.abort();
+            }
+            Ok(WebSocketResponse::Ok { id, messages: None })
+        }
+        WebSocketCommand::Unsubscribe { subscription_id } => {
+            let Some(subscription) = subscriptions.remove(&subscription_id) else {
+                return Err(IggyError::ResourceNotFound(format!(
+                    "subscription with ID: {subscription_id}"
+                )));
+            };
+            subscription.abort();
+            Ok(WebSocketResponse::Ok { id, messages: None })
+        }
+    }
+}
+
+fn get_poll_messages_command(
+    stream_id: &str,
+    topic_id: &str,
+    mut command: PollMessages,
+) -> Result<PollMessages, IggyError> {
+    command.stream_id = Identifier::from_str_value(stream_id)?;
+    command.topic_id = Identifier::from_str_value(topic_id)?;
+    command.validate()?;
+    Ok(command)
+}
+
+async fn poll_messages(
+    state: &AppState,
+    session: &Session,
+    command: &PollMessages,
+    strategy: PollingStrategy,
+) -> Result<PolledMessages, IggyError> {
+    state
+        .system
+        .resolve_authorization(
+            session,
+            POLL_MESSAGES,
+            Some(&command.stream_id),
+            Some(&command.topic_id),
+        )
+        .await;
+    state
+        .system
+        .wait_for_quota(
+            session,
+            QuotaDirection::Consume,
+            &command.stream_id,
+            &command.topic_id,
+        )
+        .await?;
+    let system = state.system.read().await;
+    system
+        .poll_messages(
+            session,
+            &Consumer::new(command.consumer.id.clone()),
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            PollingArgs::new(strategy, command.count, command.auto_commit),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to poll messages over WebSocket, stream ID: {}, topic ID: {}, partition ID: {:?}",
+                command.stream_id, command.topic_id, command.partition_id
+            )
+        })
+}
+
+/// The subscription polling the messages and pushing them to the client, until it's aborted,
+/// the connection is closed or the polling fails, in which case the error response is pushed.
+struct WebSocketSubscription {
+    id: u32,
+    state: Arc<AppState>,
+    session: Arc<Session>,
+    command: PollMessages,
+    strategy: PollingStrategy,
+    payload_encoding: PayloadEncoding,
+    poll_interval: IggyDuration,
+    responses: mpsc::Sender<WebSocketResponse>,
+}
+
+impl WebSocketSubscription {
+    async fn run(mut self) {
+        loop {
+            let polled_messages =
+                match poll_messages(&self.state, &self.session, &self.command, self.strategy).await
+                {
+                    Ok(polled_messages) => {
+                        EncodedPolledMessages::new(polled_messages, self.payload_encoding)
+                    }
+                    Err(error) => {
+                        let _ = self
+                            .responses
+                            .send(WebSocketResponse::error(self.id, &error))
+                            .await;
+                        return;
+                    }
+                };
+
+            let Some(last_message) = polled_messages.messages.last() else {
+                tokio::time::sleep(self.poll_interval.get_duration()).await;
+                continue;
+            };
+
+            // The subscription continues after the last pushed message, regardless of the initial strategy.
+            let next_offset = polled_messages
+                .next_offset
+                .unwrap_or(last_message.offset + 1);
+            self.strategy = PollingStrategy::offset(next_offset);
+            let response = WebSocketResponse::Messages {
+                id: self.id,
+                messages: polled_messages,
+            };
+            if self.responses.send(response).await.is_err() {
+                return;
+            }
+        }
+    }
+}
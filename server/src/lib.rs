@@ -28,15 +28,19 @@ compile_error!("iggy-server doesn't support windows.");
 
 pub mod archiver;
 pub mod args;
+pub mod audit;
 pub mod binary;
 pub mod channels;
+pub mod cluster;
 pub mod command;
 pub(crate) mod compat;
 pub mod configs;
 pub mod http;
 pub mod log;
+pub mod proxy;
 pub mod quic;
 pub mod server_error;
+pub mod standby;
 pub mod state;
 pub mod streaming;
 pub mod tcp;
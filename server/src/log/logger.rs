@@ -18,6 +18,7 @@
 
 use crate::configs::server::{TelemetryConfig, TelemetryTransport};
 use crate::configs::system::LoggingConfig;
+use crate::log::trace_context;
 use crate::server_error::LogError;
 use crate::VERSION;
 use opentelemetry::global;
@@ -30,6 +31,7 @@ use opentelemetry_sdk::propagation::TraceContextPropagator;
 use opentelemetry_sdk::runtime;
 use opentelemetry_sdk::trace::span_processor_with_async_runtime;
 use opentelemetry_sdk::Resource;
+use std::fmt::{Debug, Formatter};
 use std::io::{self, Write};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -127,6 +129,36 @@ pub struct Logging {
     telemetry_config: TelemetryConfig,
 }
 
+/// Changes the log level of the already initialized logging, e.g. when the configuration is updated at runtime.
+#[derive(Clone)]
+pub struct LogLevelHandle {
+    filtering_stdout_reload_handle: ReloadHandle,
+    filtering_file_reload_handle: ReloadHandle,
+}
+
+impl LogLevelHandle {
+    pub fn set_level(&self, level: &str) -> Result<(), LogError> {
+        let level = LevelFilter::from_str(&level.to_uppercase())
+            .map_err(|_| LogError::FilterReloadFailure)?;
+        for handle in [
+            &self.filtering_stdout_reload_handle,
+            &self.filtering_file_reload_handle,
+        ] {
+            handle
+                .modify(|layer| *layer = level.boxed())
+                .map_err(|_| LogError::FilterReloadFailure)?;
+        }
+        info!("Log level changed to: {level}.");
+        Ok(())
+    }
+}
+
+impl Debug for LogLevelHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogLevelHandle").finish_non_exhaustive()
+    }
+}
+
 impl Logging {
     pub fn new(telemetry_config: TelemetryConfig) -> Self {
         Self {
@@ -260,6 +292,7 @@ impl Logging {
         let tracer = tracer_provider.tracer(service_name);
         global::set_tracer_provider(tracer_provider.clone());
         global::set_text_map_propagator(TraceContextPropagator::new());
+        trace_context::enable();
 
         Registry::default()
             .with(layers)
@@ -356,6 +389,14 @@ impl Logging {
         Ok(())
     }
 
+    /// Returns the handle to change the log level at runtime, once the logging has been initialized.
+    pub fn get_level_handle(&self) -> Option<LogLevelHandle> {
+        Some(LogLevelHandle {
+            filtering_stdout_reload_handle: self.filtering_stdout_reload_handle.clone()?,
+            filtering_file_reload_handle: self.filtering_file_reload_handle.clone()?,
+        })
+    }
+
     // RUST_LOG always takes precedence over config
     fn get_filtering_level(config: Option<&LoggingConfig>) -> LevelFilter {
         if let Ok(rust_log) = std::env::var("RUST_LOG") {
@@ -21,3 +21,11 @@ pub mod logger;
 
 #[cfg(feature = "tokio-console")]
 pub mod tokio_console;
+
+pub mod trace_context;
+
+#[cfg(not(feature = "tokio-console"))]
+pub use logger::LogLevelHandle;
+
+#[cfg(feature = "tokio-console")]
+pub use tokio_console::LogLevelHandle;
@@ -23,6 +23,16 @@ use tracing_subscriber::prelude::*;
 
 pub struct Logging {}
 
+/// The log level cannot be changed at runtime, as all the traces are sent to the tokio console.
+#[derive(Debug, Clone)]
+pub struct LogLevelHandle {}
+
+impl LogLevelHandle {
+    pub fn set_level(&self, _level: &str) -> Result<(), ServerError> {
+        Ok(())
+    }
+}
+
 impl Logging {
     pub fn new(_: TelemetryConfig) -> Self {
         Self {}
@@ -44,6 +54,10 @@ impl Logging {
     ) -> Result<(), ServerError> {
         Ok(())
     }
+
+    pub fn get_level_handle(&self) -> Option<LogLevelHandle> {
+        None
+    }
 }
 
 impl Default for Logging {
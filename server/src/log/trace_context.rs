@@ -0,0 +1,168 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::segments::IggyMessagesMut;
+use ahash::AHashSet;
+use axum::http::HeaderMap;
+use bytes::Bytes;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::headers::well_known::{self, TRACEPARENT};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use lending_iterator::prelude::*;
+use opentelemetry::global;
+use opentelemetry::propagation::Extractor;
+use opentelemetry::trace::TraceContextExt;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// The maximum number of the producer trace contexts linked to the single span, so that the large batches don't bloat it.
+const MAX_LINKS: usize = 128;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables the propagation of the W3C trace context, once the traces are exported.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Checks whether the traces are exported, so that the trace context is worth reading from the requests and messages.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Returns the distinct W3C trace contexts attached by the producers to the messages, read from their `traceparent` header.
+pub fn get_message_traceparents(messages: &mut IggyMessagesMut) -> AHashSet<String> {
+    let mut traceparents = AHashSet::new();
+    let mut messages = messages.iter_mut();
+    while let Some(message) = messages.next() {
+        if traceparents.len() == MAX_LINKS {
+            break;
+        }
+
+        let Some(user_headers) = message.user_headers() else {
+            continue;
+        };
+
+        let Ok(headers) =
+            HashMap::<HeaderKey, HeaderValue>::from_bytes(Bytes::copy_from_slice(user_headers))
+        else {
+            continue;
+        };
+
+        if let Ok(Some(traceparent)) = well_known::get_traceparent(&Some(headers)) {
+            traceparents.insert(traceparent.to_owned());
+        }
+    }
+    traceparents
+}
+
+/// Links the span to the trace contexts of the producers, so that their spans lead to the span appending the messages.
+pub fn link_span<'a>(span: &Span, traceparents: impl IntoIterator<Item = &'a String>) {
+    for traceparent in traceparents {
+        let context = global::get_text_map_propagator(|propagator| {
+            propagator.extract(&TraceparentExtractor(traceparent))
+        });
+        let span_context = context.span().span_context().clone();
+        if span_context.is_valid() {
+            span.add_link(span_context);
+        }
+    }
+}
+
+/// Sets the trace context of the HTTP request, passed via the `traceparent` header, as the parent of the span.
+pub fn set_remote_parent(span: &Span, headers: &HeaderMap) {
+    let context = global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderMapExtractor(headers))
+    });
+    if context.span().span_context().is_valid() {
+        span.set_parent(context);
+    }
+}
+
+struct TraceparentExtractor<'a>(&'a str);
+
+impl Extractor for TraceparentExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        (key == TRACEPARENT).then_some(self.0)
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        vec![TRACEPARENT]
+    }
+}
+
+struct HeaderMapExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderMapExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::messaging::{self, IggyMessage};
+    use std::str::FromStr;
+
+    const TRACEPARENT_VALUE: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn should_return_distinct_traceparents_of_messages() {
+        let mut messages = IggyMessagesMut::from_messages(
+            &[
+                message(Some(TRACEPARENT_VALUE)),
+                message(Some(TRACEPARENT_VALUE)),
+                message(None),
+            ],
+            0,
+        );
+
+        let traceparents = get_message_traceparents(&mut messages);
+
+        assert_eq!(traceparents.len(), 1);
+        assert!(traceparents.contains(TRACEPARENT_VALUE));
+    }
+
+    #[test]
+    fn should_extract_traceparent_only() {
+        let extractor = TraceparentExtractor(TRACEPARENT_VALUE);
+
+        assert_eq!(extractor.get(TRACEPARENT), Some(TRACEPARENT_VALUE));
+        assert_eq!(extractor.get("tracestate"), None);
+    }
+
+    fn message(traceparent: Option<&str>) -> IggyMessage {
+        let builder = IggyMessage::builder().payload(Bytes::from("message"));
+        match traceparent {
+            Some(traceparent) => builder
+                .header(
+                    messaging::HeaderKey::new(TRACEPARENT).unwrap(),
+                    messaging::HeaderValue::from_str(traceparent).unwrap(),
+                )
+                .build(),
+            None => builder.build(),
+        }
+    }
+}
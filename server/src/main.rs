@@ -20,14 +20,25 @@ use anyhow::Result;
 use clap::Parser;
 use dotenvy::dotenv;
 use figlet_rs::FIGfont;
+use iggy::models::config_value::ConfigSource;
 use server::args::Args;
 use server::channels::commands::archive_state::ArchiveStateExecutor;
 use server::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
+use server::channels::commands::flush_lingering_messages::FlushLingeringMessagesExecutor;
+use server::channels::commands::fsync_partitions::FsyncPartitionsExecutor;
 use server::channels::commands::maintain_messages::MaintainMessagesExecutor;
+use server::channels::commands::monitor_consumer_groups::MonitorConsumerGroupsExecutor;
+use server::channels::commands::monitor_storage_usage::MonitorStorageUsageExecutor;
 use server::channels::commands::print_sysinfo::SysInfoPrintExecutor;
+use server::channels::commands::reload_certificate_denylist::ReloadCertificateDenylistExecutor;
+use server::channels::commands::remove_deleted_topics::RemoveDeletedTopicsExecutor;
 use server::channels::commands::save_messages::SaveMessagesExecutor;
+use server::channels::commands::tier_segments::TierSegmentsExecutor;
+use server::channels::commands::verify_consumer_groups::VerifyConsumerGroupsExecutor;
 use server::channels::commands::verify_heartbeats::VerifyHeartbeatsExecutor;
+use server::channels::commands::write_audit_log::WriteAuditLogExecutor;
 use server::channels::handler::BackgroundServerCommandHandler;
+use server::cluster;
 use server::configs::config_provider;
 use server::configs::server::ServerConfig;
 use server::http::http_server;
@@ -35,12 +46,15 @@ use server::http::http_server;
 use server::log::logger::Logging;
 #[cfg(feature = "tokio-console")]
 use server::log::tokio_console::Logging;
+use server::proxy::proxy_server;
 use server::quic::quic_server;
 use server::server_error::ServerError;
+use server::standby;
 use server::streaming::systems::system::{SharedSystem, System};
 use server::tcp::tcp_server;
+use std::sync::Arc;
 use tokio::time::Instant;
-use tracing::{info, instrument};
+use tracing::{info, instrument, warn};
 
 #[tokio::main]
 #[instrument(skip_all, name = "trace_start_server")]
@@ -63,8 +77,30 @@ async fn main() -> Result<(), ServerError> {
 
     let args = Args::parse();
     let config_provider = config_provider::resolve(&args.config_provider)?;
-    let config = ServerConfig::load(&config_provider).await?;
-    if args.fresh {
+    let mut config = ServerConfig::load(&config_provider).await?;
+    if args.rebuild_indexes {
+        // The system config has just been loaded, so it's not shared yet.
+        Arc::get_mut(&mut config.system)
+            .expect("System config should not be shared before starting the server")
+            .recovery
+            .rebuild_indexes = true;
+        config
+            .sources
+            .set("system.recovery.rebuild_indexes", ConfigSource::Runtime);
+    }
+    if args.restore.is_some() {
+        // The restored state contains all the streams, but the backup might contain the data of the selected ones only.
+        Arc::get_mut(&mut config.system)
+            .expect("System config should not be shared before starting the server")
+            .recovery
+            .recreate_missing_state = true;
+        config.sources.set(
+            "system.recovery.recreate_missing_state",
+            ConfigSource::Runtime,
+        );
+    }
+    // The proxy doesn't own any data, so `--fresh` must never wipe the data directory of the server it runs next to.
+    if args.fresh && !args.proxy {
         let system_path = config.system.get_system_path();
         if tokio::fs::metadata(&system_path).await.is_ok() {
             println!(
@@ -90,11 +126,85 @@ async fn main() -> Result<(), ServerError> {
     #[cfg(not(feature = "disable-mimalloc"))]
     info!("Using mimalloc allocator");
 
-    let system = SharedSystem::new(System::new(
+    #[cfg(unix)]
+    let (mut ctrl_c, mut sigterm) = {
+        use tokio::signal::unix::{signal, SignalKind};
+        (
+            signal(SignalKind::interrupt())?,
+            signal(SignalKind::terminate())?,
+        )
+    };
+
+    if args.proxy {
+        proxy_server::start(config.proxy).await;
+        info!(
+            "Iggy proxy has started - overall startup took {} ms.",
+            startup_timestamp.elapsed().as_millis()
+        );
+
+        #[cfg(unix)]
+        tokio::select! {
+            _ = ctrl_c.recv() => {
+                info!("Received SIGINT. Shutting down Iggy proxy...");
+            },
+            _ = sigterm.recv() => {
+                info!("Received SIGTERM. Shutting down Iggy proxy...");
+            }
+        }
+
+        #[cfg(windows)]
+        if let Err(err) = tokio::signal::ctrl_c().await {
+            eprintln!("Unable to listen for shutdown signal: {}", err);
+        }
+
+        return Ok(());
+    }
+
+    let mut system = System::new(
         config.system.clone(),
         config.data_maintenance.clone(),
         config.personal_access_token.clone(),
-    ));
+    );
+    if config.cluster.enabled {
+        system = system.with_cluster(&config.cluster);
+    }
+    if config.standby.enabled {
+        system = system.with_standby(&config.standby);
+    }
+    let system = SharedSystem::new(system);
+
+    // The data directory is checked before the system is initialized, as loading the streams already repairs some of the issues.
+    if args.fsck {
+        let report = system.read().await.fsck(args.repair).await?;
+        for finding in &report.findings {
+            if finding.repaired {
+                info!("Repaired: {}.", finding.issue);
+            } else if finding.issue.is_repairable() {
+                warn!("{}, it can be repaired with `--repair`.", finding.issue);
+            } else {
+                warn!("{}.", finding.issue);
+            }
+        }
+        info!(
+            "Checked the data directory, found {} issue(s), {} unresolved - it took {} ms.",
+            report.findings.len(),
+            report.unresolved_count(),
+            startup_timestamp.elapsed().as_millis()
+        );
+        return Ok(());
+    }
+
+    if let Some(name) = &args.restore {
+        let backup = system.read().await.restore_backup(name).await?;
+        info!(
+            "Restored backup with name: {} of {} stream(s), {} file(s), size: {} - it took {} ms.",
+            backup.name,
+            backup.streams_count,
+            backup.files_count,
+            backup.size,
+            startup_timestamp.elapsed().as_millis()
+        );
+    }
 
     // Workaround to ensure that the statistics are initialized before the server
     // loads streams and starts accepting connections. This is necessary to
@@ -102,38 +212,97 @@ async fn main() -> Result<(), ServerError> {
     system.write().await.get_stats().await?;
     system.write().await.init().await?;
 
+    if args.encrypt_segments {
+        let encrypted_segments_count = system.read().await.encrypt_segments().await?;
+        system.write().await.shutdown().await?;
+        info!(
+            "Encrypted {encrypted_segments_count} segment(s), shutting down because `--encrypt-segments` flag was set - it took {} ms.",
+            startup_timestamp.elapsed().as_millis()
+        );
+        return Ok(());
+    }
+
+    if args.rebuild_indexes {
+        system.write().await.shutdown().await?;
+        info!(
+            "Rebuilt the indexes of all the segments, shutting down because `--rebuild-indexes` flag was set - it took {} ms.",
+            startup_timestamp.elapsed().as_millis()
+        );
+        return Ok(());
+    }
+
     let _command_handler = BackgroundServerCommandHandler::new(system.clone(), &config)
         .install_handler(SaveMessagesExecutor)
+        .install_handler(FlushLingeringMessagesExecutor)
+        .install_handler(FsyncPartitionsExecutor)
         .install_handler(MaintainMessagesExecutor)
+        .install_handler(TierSegmentsExecutor)
         .install_handler(ArchiveStateExecutor)
         .install_handler(CleanPersonalAccessTokensExecutor)
+        .install_handler(RemoveDeletedTopicsExecutor)
         .install_handler(SysInfoPrintExecutor)
-        .install_handler(VerifyHeartbeatsExecutor);
-
-    #[cfg(unix)]
-    let (mut ctrl_c, mut sigterm) = {
-        use tokio::signal::unix::{signal, SignalKind};
-        (
-            signal(SignalKind::interrupt())?,
-            signal(SignalKind::terminate())?,
-        )
-    };
+        .install_handler(VerifyHeartbeatsExecutor)
+        .install_handler(ReloadCertificateDenylistExecutor)
+        .install_handler(VerifyConsumerGroupsExecutor)
+        .install_handler(MonitorConsumerGroupsExecutor::default())
+        .install_handler(MonitorStorageUsageExecutor)
+        .install_handler(WriteAuditLogExecutor::default());
 
     let mut current_config = config.clone();
 
+    if config.cluster.enabled {
+        let cluster_addr = cluster::start(config.cluster, system.clone()).await;
+        if current_config.cluster.address != cluster_addr.to_string() {
+            current_config.cluster.address = cluster_addr.to_string();
+            current_config
+                .sources
+                .set("cluster.address", ConfigSource::Runtime);
+        }
+    }
+
+    if config.standby.shipping.enabled {
+        let shipping_addr =
+            standby::shipping::start(config.standby.shipping.clone(), system.clone()).await;
+        if current_config.standby.shipping.address != shipping_addr.to_string() {
+            current_config.standby.shipping.address = shipping_addr.to_string();
+            current_config
+                .sources
+                .set("standby.shipping.address", ConfigSource::Runtime);
+        }
+    }
+
+    if config.standby.enabled {
+        standby::start(config.standby.clone(), system.clone()).await;
+    }
+
     if config.http.enabled {
         let http_addr = http_server::start(config.http, system.clone()).await;
-        current_config.http.address = http_addr.to_string();
+        if current_config.http.address != http_addr.to_string() {
+            current_config.http.address = http_addr.to_string();
+            current_config
+                .sources
+                .set("http.address", ConfigSource::Runtime);
+        }
     }
 
     if config.quic.enabled {
-        let quic_addr = quic_server::start(config.quic, system.clone());
-        current_config.quic.address = quic_addr.to_string();
+        let quic_addr = quic_server::start(config.quic, system.clone()).await;
+        if current_config.quic.address != quic_addr.to_string() {
+            current_config.quic.address = quic_addr.to_string();
+            current_config
+                .sources
+                .set("quic.address", ConfigSource::Runtime);
+        }
     }
 
     if config.tcp.enabled {
         let tcp_addr = tcp_server::start(config.tcp, system.clone()).await;
-        current_config.tcp.address = tcp_addr.to_string();
+        if current_config.tcp.address != tcp_addr.to_string() {
+            current_config.tcp.address = tcp_addr.to_string();
+            current_config
+                .sources
+                .set("tcp.address", ConfigSource::Runtime);
+        }
     }
 
     let runtime_path = current_config.system.get_runtime_path();
@@ -141,6 +310,12 @@ async fn main() -> Result<(), ServerError> {
     let current_config_content =
         toml::to_string(&current_config).expect("Cannot serialize current_config");
     tokio::fs::write(current_config_path, current_config_content).await?;
+    {
+        let mut system = system.write().await;
+        system.set_effective_config(&current_config);
+        system.set_config_provider(config_provider);
+        system.set_log_level_handle(logging.get_level_handle());
+    }
 
     let elapsed_time = startup_timestamp.elapsed();
     info!(
@@ -148,6 +323,7 @@ async fn main() -> Result<(), ServerError> {
         elapsed_time.as_millis()
     );
 
+    let shutdown = system.read().await.shutdown_coordinator();
     #[cfg(unix)]
     tokio::select! {
         _ = ctrl_c.recv() => {
@@ -155,6 +331,9 @@ async fn main() -> Result<(), ServerError> {
         },
         _ = sigterm.recv() => {
             info!("Received SIGTERM. Shutting down Iggy server...");
+        },
+        _ = shutdown.requested() => {
+            info!("Received shutdown request. Shutting down Iggy server...");
         }
     }
 
@@ -169,8 +348,7 @@ async fn main() -> Result<(), ServerError> {
     }
 
     let shutdown_timestamp = Instant::now();
-    let mut system = system.write().await;
-    system.shutdown().await?;
+    system.shutdown_gracefully(&config.shutdown).await?;
     let elapsed_time = shutdown_timestamp.elapsed();
 
     info!(
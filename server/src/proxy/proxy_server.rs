@@ -0,0 +1,147 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::server::ProxyConfig;
+use iggy::utils::duration::IggyDuration;
+use rand::Rng;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Notify;
+use tokio::time::sleep;
+use tracing::{error, info, trace, warn};
+
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Starts the proxy forwarding the binary protocol (TCP) to the upstream server with the injected latency, jitter and connection resets.
+/// Returns the address the proxy is listening on.
+pub async fn start(config: ProxyConfig) -> SocketAddr {
+    info!("Initializing Iggy proxy...");
+    let listener = TcpListener::bind(&config.address)
+        .await
+        .unwrap_or_else(|error| {
+            panic!(
+                "Unable to bind proxy to address: {}. {error}",
+                config.address
+            )
+        });
+    let addr = listener
+        .local_addr()
+        .expect("Failed to get local address for proxy listener");
+    info!(
+        "Iggy proxy has started on: {addr}, forwarding to: {}, latency: {}, jitter: {}, reset probability: {}",
+        config.upstream_address, config.latency, config.jitter, config.reset_probability
+    );
+
+    let config = Arc::new(config);
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    info!("Accepted new proxy connection: {address}");
+                    let config = config.clone();
+                    tokio::spawn(async move {
+                        if let Err(error) = handle_connection(stream, &config).await {
+                            warn!("Proxy connection: {address} has failed. {error}");
+                        }
+                        info!("Proxy connection: {address} has been closed.");
+                    });
+                }
+                Err(error) => error!("Unable to accept proxy connection: {error}"),
+            }
+        }
+    });
+    addr
+}
+
+async fn handle_connection(client: TcpStream, config: &ProxyConfig) -> std::io::Result<()> {
+    let upstream = TcpStream::connect(&config.upstream_address).await?;
+    if config.reset_probability > 0.0 {
+        // Closing the socket with zero linger sends RST instead of FIN, which is how the resets are injected.
+        client.set_linger(Some(Duration::ZERO))?;
+        upstream.set_linger(Some(Duration::ZERO))?;
+    }
+    client.set_nodelay(true)?;
+    upstream.set_nodelay(true)?;
+
+    let (client_reader, client_writer) = client.into_split();
+    let (upstream_reader, upstream_writer) = upstream.into_split();
+    let reset = Arc::new(Notify::new());
+    let requests = forward(
+        "request",
+        client_reader,
+        upstream_writer,
+        config,
+        reset.clone(),
+    );
+    let responses = forward("response", upstream_reader, client_writer, config, reset);
+    tokio::select! {
+        result = requests => result,
+        result = responses => result,
+    }
+}
+
+async fn forward(
+    direction: &str,
+    mut reader: OwnedReadHalf,
+    mut writer: OwnedWriteHalf,
+    config: &ProxyConfig,
+    reset: Arc<Notify>,
+) -> std::io::Result<()> {
+    let mut buffer = vec![0; BUFFER_SIZE];
+    loop {
+        let read_bytes = tokio::select! {
+            read_bytes = reader.read(&mut buffer) => read_bytes?,
+            _ = reset.notified() => return Ok(()),
+        };
+        if read_bytes == 0 {
+            return Ok(());
+        }
+
+        let delay = get_delay(config.latency, config.jitter);
+        if !delay.is_zero() {
+            trace!("Delaying {direction} of {read_bytes} bytes by: {delay:?}");
+            sleep(delay).await;
+        }
+
+        if should_reset(config.reset_probability) {
+            warn!("Resetting connection while forwarding {direction} of {read_bytes} bytes.");
+            reset.notify_waiters();
+            return Ok(());
+        }
+
+        writer.write_all(&buffer[..read_bytes]).await?;
+    }
+}
+
+fn get_delay(latency: IggyDuration, jitter: IggyDuration) -> Duration {
+    let jitter_micros = jitter.as_micros();
+    let jitter = if jitter_micros == 0 {
+        0
+    } else {
+        rand::rng().random_range(0..=jitter_micros)
+    };
+    latency.get_duration() + Duration::from_micros(jitter)
+}
+
+fn should_reset(reset_probability: f64) -> bool {
+    reset_probability > 0.0 && rand::rng().random_bool(reset_probability)
+}
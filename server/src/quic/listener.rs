@@ -16,19 +16,22 @@
  * under the License.
  */
 
-use crate::binary::command;
+use crate::binary::command::{ServerCommand, ServerCommandHandler};
 use crate::binary::sender::SenderKind;
-use crate::command::ServerCommand;
 use crate::server_error::ConnectionError;
 use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::diagnostics::metrics::TransportLabel;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::shutdown::ShutdownCoordinator;
 use anyhow::{anyhow, Context};
 use bytes::Bytes;
-use iggy::validatable::Validatable;
-use iggy::{bytes_serializable::BytesSerializable, messages::MAX_PAYLOAD_SIZE};
+use iggy::messages::MAX_PAYLOAD_SIZE;
 use quinn::{Connection, Endpoint, RecvStream, SendStream};
-use tracing::{debug, error, info};
+use rustls::pki_types::CertificateDer;
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{debug, error, info, warn};
 
 const LISTENERS_COUNT: u32 = 10;
 const INITIAL_BYTES_LENGTH: usize = 4;
@@ -38,7 +41,18 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
         let endpoint = endpoint.clone();
         let system = system.clone();
         tokio::spawn(async move {
-            while let Some(incoming_connection) = endpoint.accept().await {
+            let shutdown = system.read().await.shutdown_coordinator();
+            loop {
+                let incoming_connection = tokio::select! {
+                    incoming_connection = endpoint.accept() => incoming_connection,
+                    _ = shutdown.requested() => {
+                        info!("QUIC listener has stopped accepting new connections.");
+                        break;
+                    }
+                };
+                let Some(incoming_connection) = incoming_connection else {
+                    break;
+                };
                 info!(
                     "Incoming connection from client: {}",
                     incoming_connection.remote_address()
@@ -53,8 +67,11 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
                     continue;
                 }
                 let incoming_connection = incoming_connection.unwrap();
+                let shutdown = shutdown.clone();
                 tokio::spawn(async move {
-                    if let Err(error) = handle_connection(incoming_connection, system).await {
+                    if let Err(error) =
+                        handle_connection(incoming_connection, system, shutdown).await
+                    {
                         error!("Connection has failed: {error}");
                     }
                 });
@@ -66,10 +83,16 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
 async fn handle_connection(
     incoming_connection: quinn::Connecting,
     system: SharedSystem,
+    shutdown: Arc<ShutdownCoordinator>,
 ) -> Result<(), ConnectionError> {
     let connection = incoming_connection.await?;
     let address = connection.remote_address();
     info!("Client has connected: {address}");
+    if !system.read().await.is_client_address_allowed(&address) {
+        connection.close(0u32.into(), b"client access denied");
+        return Ok(());
+    }
+
     let session = system
         .read()
         .await
@@ -77,12 +100,28 @@ async fn handle_connection(
         .await;
 
     let client_id = session.client_id;
+    if let Some(certificate) = peer_certificate(&connection) {
+        let authenticated = system
+            .read()
+            .await
+            .login_user_with_certificate(&certificate, &session)
+            .await
+            .map(|_| ());
+        if let Err(error) = authenticated {
+            warn!("Failed to authenticate client: {client_id}, address: {address} by certificate. {error}");
+            connection.close(0u32.into(), b"client certificate denied");
+            system.read().await.delete_client(client_id).await;
+            return Ok(());
+        }
+    }
+
     while let Some(stream) = accept_stream(&connection, &system, client_id).await? {
         let system = system.clone();
         let session = session.clone();
+        let shutdown = shutdown.clone();
 
         let handle_stream_task = async move {
-            if let Err(err) = handle_stream(stream, system, session).await {
+            if let Err(err) = handle_stream(stream, system, session, shutdown).await {
                 error!("Error when handling QUIC stream: {:?}", err)
             }
         };
@@ -93,6 +132,16 @@ async fn handle_connection(
 
 type BiStream = (SendStream, RecvStream);
 
+/// Returns the end-entity certificate presented by the client, only requested when the client certificates are enabled.
+fn peer_certificate(connection: &Connection) -> Option<Vec<u8>> {
+    connection
+        .peer_identity()?
+        .downcast::<Vec<CertificateDer<'static>>>()
+        .ok()?
+        .first()
+        .map(|certificate| certificate.to_vec())
+}
+
 async fn accept_stream(
     connection: &Connection,
     system: &SharedSystem,
@@ -117,6 +166,7 @@ async fn handle_stream(
     stream: BiStream,
     system: SharedSystem,
     session: impl AsRef<Session>,
+    shutdown: Arc<ShutdownCoordinator>,
 ) -> anyhow::Result<()> {
     let (send_stream, mut recv_stream) = stream;
     // TODO: read to BytesMut instead of Vec<u8>
@@ -125,9 +175,10 @@ async fn handle_stream(
         .await
         .with_context(|| "Error when reading the QUIC request.")?;
 
-    if request.len() < INITIAL_BYTES_LENGTH {
+    if request.len() < 2 * INITIAL_BYTES_LENGTH {
         return Err(anyhow!(
-            "Unable to read the QUIC request length, expected: {INITIAL_BYTES_LENGTH} bytes, received: {} bytes.",
+            "Unable to read the QUIC request length and code, expected: {} bytes, received: {} bytes.",
+            2 * INITIAL_BYTES_LENGTH,
             request.len()
         ));
     }
@@ -137,9 +188,15 @@ async fn handle_stream(
         .try_into()
         .map(u32::from_le_bytes)
         .unwrap_or_default();
-    let command =
-        ServerCommand::from_bytes(Bytes::copy_from_slice(&request[INITIAL_BYTES_LENGTH..]))
-            .with_context(|| "Error when reading the QUIC request command.")?;
+    let code = request[INITIAL_BYTES_LENGTH..2 * INITIAL_BYTES_LENGTH]
+        .try_into()
+        .map(u32::from_le_bytes)
+        .unwrap_or_default();
+    let command = ServerCommand::from_code_and_payload(
+        code,
+        Bytes::copy_from_slice(&request[2 * INITIAL_BYTES_LENGTH..]),
+    )
+    .with_context(|| "Error when reading the QUIC request command.")?;
     command
         .validate()
         .with_context(|| "Error when validating the QUIC command.")?;
@@ -147,7 +204,47 @@ async fn handle_stream(
     debug!("Received a QUIC command: {command}, payload size: {length}");
 
     let mut sender = SenderKind::get_quic_sender(send_stream, recv_stream);
-    command::handle(command, &mut sender, session.as_ref(), system.clone())
-        .await
-        .with_context(|| "Error when handling the QUIC request.")
+    let _in_flight = match shutdown.begin_request() {
+        Ok(in_flight) => in_flight,
+        Err(error) => {
+            sender.send_error_response(error).await?;
+            return Ok(());
+        }
+    };
+    if command.requires_cluster_leader() {
+        if let Err(error) = system.read().await.ensure_cluster_leader().await {
+            sender.send_error_response(error).await?;
+            return Ok(());
+        }
+    }
+
+    let (name, stream_id, topic_id) = command.authorization();
+    system
+        .resolve_authorization(session.as_ref(), name, stream_id, topic_id)
+        .await;
+
+    let request_lanes = system.read().await.request_lanes();
+    let _lane_permit = request_lanes.acquire(command.lane()).await;
+    let command_name = command.name();
+    let audit_details = command.is_administrative().then(|| command.details());
+    let started_at = Instant::now();
+    let result = command
+        .handle(&mut sender, length, session.as_ref(), &system)
+        .await;
+    {
+        let system = system.read().await;
+        system
+            .metrics
+            .record_command(command_name, TransportLabel::Quic, started_at.elapsed());
+        if let Some(details) = audit_details {
+            system.record_audit_entry(
+                session.as_ref(),
+                TransportLabel::Quic,
+                command_name,
+                details,
+                &result,
+            );
+        }
+    }
+    result.with_context(|| "Error when handling the QUIC request.")
 }
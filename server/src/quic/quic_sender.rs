@@ -21,6 +21,7 @@ use crate::{binary::sender::Sender, server_error::ServerError};
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use quinn::{RecvStream, SendStream};
+use std::fs::File;
 use tracing::{debug, error};
 
 const STATUS_OK: &[u8] = &[0; 4];
@@ -41,6 +42,28 @@ impl Sender for QuicSender {
         read_bytes.ok_or(IggyError::QuicError)
     }
 
+    async fn verify_frame_checksum(&mut self) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn enable_frame_checksums(&mut self) -> Result<(), IggyError> {
+        // QUIC already protects the integrity of the packets on its own.
+        self.send_error_response(IggyError::FeatureUnavailable)
+            .await
+    }
+
+    async fn enable_zero_copy_polling(&mut self) -> Result<(), IggyError> {
+        // The stored batches can't be sent straight from the file to the QUIC stream.
+        self.send_error_response(IggyError::FeatureUnavailable)
+            .await
+    }
+
+    async fn enable_consumer_group_events(&mut self) -> Result<(), IggyError> {
+        // Each request is sent over the new QUIC stream, so there's no connection to push the events to.
+        self.send_error_response(IggyError::FeatureUnavailable)
+            .await
+    }
+
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
         self.send_ok_response(&[]).await
     }
@@ -49,6 +72,16 @@ impl Sender for QuicSender {
         self.send_response(STATUS_OK, payload).await
     }
 
+    async fn send_ok_response_from_file(
+        &mut self,
+        _header: &[u8],
+        _file: File,
+        _position: u64,
+        _length: u64,
+    ) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
         self.send_response(&error.as_code().to_le_bytes(), &[])
             .await
@@ -23,44 +23,78 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use error_set::ErrContext;
+use quinn::crypto::rustls::QuicServerConfig;
 use quinn::{Endpoint, IdleTimeout, VarInt};
 use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::danger::ClientCertVerifier;
 use tracing::info;
 
 use crate::configs::quic::QuicConfig;
 use crate::quic::listener;
 use crate::quic::COMPONENT;
 use crate::server_error::QuicError;
+use crate::streaming::clients::certificate_auth::crypto_provider;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::certificate_watcher::CertificateWatcher;
 
 /// Starts the QUIC server.
 /// Returns the address the server is listening on.
-pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
+pub async fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
     info!("Initializing Iggy QUIC server...");
     let address = config.address.parse().unwrap();
-    let quic_config = configure_quic(config);
+    let verifier = match config.certificate.client_certificates {
+        true => Some(
+            system
+                .read()
+                .await
+                .certificate_auth
+                .client_verifier()
+                .expect("Client certificate authentication is not enabled."),
+        ),
+        false => None,
+    };
+    let quic_config = configure_quic(config.clone(), verifier.clone());
     if let Err(error) = quic_config {
         panic!("Error when configuring QUIC: {:?}", error);
     }
 
     let endpoint = Endpoint::server(quic_config.unwrap(), address).unwrap();
     let addr = endpoint.local_addr().unwrap();
+    if !config.certificate.self_signed {
+        let reloaded_endpoint = endpoint.clone();
+        CertificateWatcher::new(
+            &[&config.certificate.cert_file, &config.certificate.key_file],
+            config.certificate.reload_interval,
+        )
+        .start("QUIC", move || {
+            let result = configure_quic(config.clone(), verifier.clone())
+                .map(|server_config| reloaded_endpoint.set_server_config(Some(server_config)))
+                .map_err(anyhow::Error::from);
+            async move { result }
+        });
+    }
     listener::start(endpoint, system);
     info!("Iggy QUIC server has started on: {:?}", addr);
     addr
 }
 
-fn configure_quic(config: QuicConfig) -> Result<quinn::ServerConfig, QuicError> {
+fn configure_quic(
+    config: QuicConfig,
+    verifier: Option<Arc<dyn ClientCertVerifier>>,
+) -> Result<quinn::ServerConfig, QuicError> {
     let (certificate, key) = match config.certificate.self_signed {
         true => generate_self_signed_cert()?,
         false => load_certificates(&config.certificate.cert_file, &config.certificate.key_file)?,
     };
 
-    let mut server_config = quinn::ServerConfig::with_single_cert(certificate, key)
-        .with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - failed to create server config")
-        })
-        .map_err(|_| QuicError::ConfigCreationError)?;
+    let mut server_config = match verifier {
+        Some(verifier) => configure_client_certificates(certificate, key, verifier)?,
+        None => quinn::ServerConfig::with_single_cert(certificate, key)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create server config")
+            })
+            .map_err(|_| QuicError::ConfigCreationError)?,
+    };
     let mut transport = quinn::TransportConfig::default();
     transport.initial_mtu(config.initial_mtu.as_bytes_u64() as u16);
     transport.send_window(config.send_window.as_bytes_u64());
@@ -95,6 +129,31 @@ fn configure_quic(config: QuicConfig) -> Result<quinn::ServerConfig, QuicError>
     Ok(server_config)
 }
 
+/// Creates the server config requiring the clients to present a certificate accepted by the verifier.
+fn configure_client_certificates(
+    certificate: Vec<CertificateDer<'static>>,
+    key: PrivateKeyDer<'static>,
+    verifier: Arc<dyn ClientCertVerifier>,
+) -> Result<quinn::ServerConfig, QuicError> {
+    let crypto = rustls::ServerConfig::builder_with_provider(crypto_provider())
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .and_then(|builder| {
+            builder
+                .with_client_cert_verifier(verifier)
+                .with_single_cert(certificate, key)
+        })
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to create TLS config with client certificate verification")
+        })
+        .map_err(|_| QuicError::ConfigCreationError)?;
+    let crypto = QuicServerConfig::try_from(crypto)
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to create QUIC crypto config")
+        })
+        .map_err(|_| QuicError::ConfigCreationError)?;
+    Ok(quinn::ServerConfig::with_crypto(Arc::new(crypto)))
+}
+
 fn generate_self_signed_cert<'a>() -> Result<(Vec<CertificateDer<'a>>, PrivateKeyDer<'a>), QuicError>
 {
     let certificate = rcgen::generate_simple_self_signed(vec!["localhost".into()]).unwrap();
@@ -22,7 +22,7 @@ use std::array::TryFromSliceError;
 use tokio::io;
 
 error_set!(
-    ServerError = ConfigError || ArchiverError || ConnectionError || LogError || CompatError || QuicError;
+    ServerError = ConfigError || ArchiverError || AuditError || AuthorizationError || ConnectionError || LogError || CompatError || QuicError;
 
     IoError = {
         #[display("IO error")]
@@ -42,6 +42,9 @@ error_set!(
         #[display("Cannot load configuration")]
         CannotLoadConfiguration,
 
+        #[display("Cannot save configuration")]
+        CannotSaveConfiguration,
+
         #[display("Invalid configuration")]
         InvalidConfiguration,
 
@@ -63,6 +66,28 @@ error_set!(
         CannotArchiveFile { file_path: String },
     } || IoError;
 
+    AuditError = {
+        #[display("Invalid audit entry: {}", reason)]
+        InvalidAuditEntry { reason: String },
+
+        #[display("Invalid syslog address: {}", address)]
+        InvalidSyslogAddress { address: String },
+
+        #[display("Audit topic not found: {}", topic)]
+        AuditTopicNotFound { topic: String },
+
+        #[display("Cannot append audit entries to topic: {}", topic)]
+        CannotAppendAuditEntries { topic: String },
+    } || IoError;
+
+    AuthorizationError = {
+        #[display("Invalid authorization policy file: {}, reason: {}", path, reason)]
+        InvalidPolicyFile { path: String, reason: String },
+
+        #[display("Cannot create OPA client for: {}", url)]
+        CannotCreateOpaClient { url: String },
+    } || IoError;
+
     ConnectionError = {
         #[display("Connection error")]
         QuicConnectionError(QuicConnectionError),
@@ -0,0 +1,361 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cluster::replication::decode_messages;
+use crate::cluster::rpc::{read_message, write_message};
+use crate::configs::standby::StandbyConfig;
+use crate::standby::{ShippingRequest, ShippingResponse, COMPONENT};
+use crate::state::entry::StateEntry;
+use crate::state::StateKind;
+use crate::streaming::diagnostics::metrics::StandbyLag;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::systems::system::SharedSystem;
+use bytes::Bytes;
+use error_set::ErrContext;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+use tokio::time::{timeout, Instant};
+use tracing::{debug, error, info};
+
+/// The position of the standby in the state log of the primary.
+#[derive(Debug, Clone, Copy)]
+struct StatePosition {
+    entries_count: u64,
+    last_checksum: Option<u32>,
+}
+
+/// The standby side of the shipping - pulls the state entries and the messages of the partitions from the primary server,
+/// and applies them locally, preserving their offsets. Until it's promoted, the standby rejects the writes of the clients.
+#[derive(Debug)]
+pub struct StandbyFollower {
+    config: StandbyConfig,
+    connection: Mutex<Option<TcpStream>>,
+    state_position: Mutex<Option<StatePosition>>,
+    last_lag: std::sync::Mutex<StandbyLag>,
+    caught_up_at: std::sync::Mutex<Instant>,
+    promoted: AtomicBool,
+}
+
+impl StandbyFollower {
+    pub fn new(config: StandbyConfig) -> Self {
+        Self {
+            config,
+            connection: Mutex::new(None),
+            state_position: Mutex::new(None),
+            last_lag: std::sync::Mutex::new(StandbyLag::default()),
+            caught_up_at: std::sync::Mutex::new(Instant::now()),
+            promoted: AtomicBool::new(false),
+        }
+    }
+
+    pub fn is_promoted(&self) -> bool {
+        self.promoted.load(Ordering::SeqCst)
+    }
+
+    /// Stops syncing with the primary, so the standby starts accepting the writes. Returns false if it was already promoted.
+    pub fn promote(&self) -> bool {
+        !self.promoted.swap(true, Ordering::SeqCst)
+    }
+
+    /// Returns the last measured lag, with the time elapsed since the standby has last caught up with the primary.
+    pub fn lag_since_caught_up(&self) -> StandbyLag {
+        StandbyLag {
+            seconds: self.caught_up_at.lock().unwrap().elapsed().as_secs_f64(),
+            ..*self.last_lag.lock().unwrap()
+        }
+    }
+
+    /// Pulls the new state entries first, so the streams, topics and partitions exist before their messages are pulled.
+    /// Returns how far the standby is still behind the primary.
+    pub async fn sync(&self, system: &SharedSystem) -> Result<StandbyLag, IggyError> {
+        let state_entries = self.sync_state(system).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to sync state entries")
+        })?;
+        let messages = self
+            .sync_partitions(system)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to sync partitions")
+            })?;
+        if state_entries == 0 && messages == 0 {
+            *self.caught_up_at.lock().unwrap() = Instant::now();
+        }
+
+        let lag = StandbyLag {
+            state_entries,
+            messages,
+            seconds: self.caught_up_at.lock().unwrap().elapsed().as_secs_f64(),
+        };
+        *self.last_lag.lock().unwrap() = lag;
+        Ok(lag)
+    }
+
+    async fn sync_state(&self, system: &SharedSystem) -> Result<u64, IggyError> {
+        let mut position = self.state_position.lock().await;
+        if position.is_none() {
+            let entries = system.read().await.state.load_entries().await?;
+            *position = Some(StatePosition {
+                entries_count: entries.len() as u64,
+                last_checksum: entries.last().map(|entry| entry.checksum),
+            });
+        }
+        let position = position.as_mut().expect("State position should be loaded");
+
+        let request = ShippingRequest::FetchStateEntries {
+            entries_count: position.entries_count,
+            last_checksum: position.last_checksum,
+            count: self.config.fetch_state_entries,
+        };
+        let (entries, primary_entries_count) = match self.send(&request).await? {
+            ShippingResponse::StateEntries {
+                entries,
+                entries_count,
+            } => (entries, entries_count),
+            ShippingResponse::Error { code } => return Err(IggyError::from_code(code)),
+            response => {
+                error!("Received unexpected response: {response:?} to the fetch of state entries.");
+                return Err(IggyError::InvalidCommand);
+            }
+        };
+        if entries.is_empty() {
+            return Ok(primary_entries_count.saturating_sub(position.entries_count));
+        }
+
+        let state = system.read().await.state.clone();
+        let StateKind::File(file_state) = state.as_ref() else {
+            return Err(IggyError::InvalidCommand);
+        };
+        for bytes in entries {
+            let entry = StateEntry::from_bytes(Bytes::from(bytes))?;
+            // The entry is authorized once the previous ones are applied, as it can refer to the streams and topics created by them.
+            let resolved = system.resolve_state_entry(&entry).await;
+            let mut system = system.write().await;
+            file_state.append_entry(&entry).await?;
+            position.entries_count += 1;
+            position.last_checksum = Some(entry.checksum);
+            let result = match resolved {
+                Ok((session, command)) => system.apply_state_entry(&session, &entry, command).await,
+                Err(error) => Err(error),
+            };
+            if let Err(error) = result {
+                error!(
+                    "{COMPONENT} (error: {error}) - failed to apply shipped state entry: {entry}"
+                );
+            }
+        }
+        debug!(
+            "Synced state entries with the primary, entries: {}/{primary_entries_count}.",
+            position.entries_count
+        );
+        Ok(primary_entries_count.saturating_sub(position.entries_count))
+    }
+
+    async fn sync_partitions(&self, system: &SharedSystem) -> Result<u64, IggyError> {
+        let mut partitions = Vec::new();
+        let clock = {
+            let system = system.read().await;
+            for stream in system.get_streams() {
+                for topic in stream.get_topics() {
+                    partitions.extend(topic.get_partitions());
+                }
+            }
+            system.clock().clone()
+        };
+
+        let mut lag = 0;
+        for partition in partitions {
+            let (stream_id, topic_id, partition_id, offset) = {
+                let partition = partition.read().await;
+                let offset = if partition.should_increment_offset {
+                    partition.current_offset + 1
+                } else {
+                    0
+                };
+                (
+                    partition.stream_id,
+                    partition.topic_id,
+                    partition.partition_id,
+                    offset,
+                )
+            };
+            let request = ShippingRequest::FetchMessages {
+                stream_id,
+                topic_id,
+                partition_id,
+                offset,
+                count: self.config.fetch_messages,
+            };
+            let (current_offset, messages, consumer_offsets, consumer_group_offsets) = match self
+                .send(&request)
+                .await
+            {
+                Ok(ShippingResponse::Messages {
+                    current_offset,
+                    messages,
+                    consumer_offsets,
+                    consumer_group_offsets,
+                }) => (
+                    current_offset,
+                    messages,
+                    consumer_offsets,
+                    consumer_group_offsets,
+                ),
+                // The partition might have been deleted on the primary, which is synced with the next state entries.
+                Ok(ShippingResponse::Error { code }) => {
+                    debug!("Primary server has rejected the fetch of partition: {partition_id} for topic: {topic_id}, stream: {stream_id}, error code: {code}.");
+                    continue;
+                }
+                Ok(response) => {
+                    error!("Received unexpected response: {response:?} to the fetch of partition: {partition_id} for topic: {topic_id}, stream: {stream_id}.");
+                    continue;
+                }
+                Err(error) => return Err(error),
+            };
+
+            let mut partition = partition.write().await;
+            if !messages.is_empty() {
+                let messages = decode_messages(Bytes::from(messages))?;
+                let count = partition.append_replicated_messages(messages, clock.now()).await.with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to append shipped messages of partition: {partition_id} for topic: {topic_id}, stream: {stream_id}")
+                })?;
+                debug!("Synced {count} messages of partition: {partition_id} for topic: {topic_id}, stream: {stream_id}.");
+            }
+
+            let local_offset = partition
+                .should_increment_offset
+                .then_some(partition.current_offset);
+            lag += messages_lag(current_offset, local_offset);
+            if local_offset.is_none() {
+                continue;
+            }
+
+            let consumers = consumer_offsets
+                .into_iter()
+                .map(|(consumer_id, offset)| {
+                    (PollingConsumer::Consumer(consumer_id, partition_id), offset)
+                })
+                .chain(
+                    consumer_group_offsets
+                        .into_iter()
+                        .map(|(group_id, offset)| {
+                            (PollingConsumer::ConsumerGroup(group_id, 0), offset)
+                        }),
+                );
+            for (consumer, offset) in consumers {
+                if offset > partition.current_offset
+                    || stored_offset(&partition, &consumer) == Some(offset)
+                {
+                    continue;
+                }
+
+                partition.store_consumer_offset(consumer, offset).await.with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to store shipped offset: {offset} for {consumer}, partition: {partition_id}, topic: {topic_id}, stream: {stream_id}")
+                })?;
+            }
+        }
+        Ok(lag)
+    }
+
+    /// Sends the request to the primary and waits for the response, reconnecting if there's no open connection.
+    async fn send(&self, request: &ShippingRequest) -> Result<ShippingResponse, IggyError> {
+        let mut connection = self.connection.lock().await;
+        let result = timeout(self.config.request_timeout.get_duration(), async {
+            if connection.is_none() {
+                let stream = TcpStream::connect(&self.config.primary_address)
+                    .await
+                    .map_err(|error| {
+                        debug!(
+                            "{COMPONENT} - cannot connect to primary server: {}. {error}",
+                            self.config.primary_address
+                        );
+                        IggyError::CannotEstablishConnection
+                    })?;
+                stream
+                    .set_nodelay(true)
+                    .map_err(|_| IggyError::CannotEstablishConnection)?;
+                info!(
+                    "Connected to primary server: {}.",
+                    self.config.primary_address
+                );
+                *connection = Some(stream);
+            }
+
+            let stream = connection.as_mut().expect("Connection should be open");
+            write_message(stream, request).await?;
+            read_message(stream).await
+        })
+        .await
+        .unwrap_or(Err(IggyError::TcpError));
+
+        if result.is_err() {
+            *connection = None;
+        }
+        result
+    }
+}
+
+fn stored_offset(partition: &Partition, consumer: &PollingConsumer) -> Option<u64> {
+    match consumer {
+        PollingConsumer::Consumer(consumer_id, _) => partition
+            .consumer_offsets
+            .get(consumer_id)
+            .map(|offset| offset.offset),
+        PollingConsumer::ConsumerGroup(group_id, _) => partition
+            .consumer_group_offsets
+            .get(group_id)
+            .map(|offset| offset.offset),
+    }
+}
+
+/// Returns the number of messages stored by the primary, but not yet by the standby.
+fn messages_lag(primary_offset: Option<u64>, standby_offset: Option<u64>) -> u64 {
+    match (primary_offset, standby_offset) {
+        (Some(primary_offset), Some(standby_offset)) => {
+            primary_offset.saturating_sub(standby_offset)
+        }
+        (Some(primary_offset), None) => primary_offset + 1,
+        (None, _) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_lag_should_count_the_messages_missing_on_the_standby() {
+        assert_eq!(messages_lag(Some(10), Some(7)), 3);
+        assert_eq!(messages_lag(Some(10), Some(10)), 0);
+        assert_eq!(messages_lag(Some(4), None), 5);
+        assert_eq!(messages_lag(None, None), 0);
+    }
+
+    #[test]
+    fn standby_should_be_promoted_once() {
+        let follower = StandbyFollower::new(StandbyConfig::default());
+        assert!(!follower.is_promoted());
+        assert!(follower.promote());
+        assert!(follower.is_promoted());
+        assert!(!follower.promote());
+    }
+}
@@ -0,0 +1,113 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::standby::StandbyConfig;
+use crate::standby::follower::StandbyFollower;
+use crate::streaming::systems::system::SharedSystem;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::time::sleep;
+use tracing::{debug, error, info};
+
+pub mod follower;
+pub mod shipping;
+
+pub const COMPONENT: &str = "STANDBY";
+
+/// The request sent by the standby server to the primary one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ShippingRequest {
+    /// Fetches the state entries following the ones already stored by the standby.
+    /// The checksum of its last entry lets the primary detect the diverged state log.
+    FetchStateEntries {
+        entries_count: u64,
+        last_checksum: Option<u32>,
+        count: u32,
+    },
+    /// Fetches the messages of the partition starting at the given offset, along with its consumer offsets.
+    FetchMessages {
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        offset: u64,
+        count: u32,
+    },
+}
+
+/// The response of the primary server to the standby one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ShippingResponse {
+    StateEntries {
+        entries: Vec<Vec<u8>>,
+        entries_count: u64,
+    },
+    Messages {
+        current_offset: Option<u64>,
+        messages: Vec<u8>,
+        consumer_offsets: Vec<(u32, u64)>,
+        consumer_group_offsets: Vec<(u32, u64)>,
+    },
+    Error {
+        code: u32,
+    },
+}
+
+/// Starts the standby server - the task continuously pulling the state entries and the messages from the primary,
+/// until the standby is promoted.
+pub async fn start(config: StandbyConfig, system: SharedSystem) {
+    let follower = system
+        .read()
+        .await
+        .standby
+        .clone()
+        .expect("Standby should be configured for the system");
+    info!(
+        "Standby server has started, primary: {}, sync interval: {}.",
+        config.primary_address, config.sync_interval
+    );
+
+    let sync_interval = config.sync_interval.get_duration();
+    tokio::spawn(async move {
+        loop {
+            sleep(sync_interval).await;
+            if follower.is_promoted() {
+                info!("Standby server has been promoted, stopped syncing with the primary.");
+                break;
+            }
+
+            sync(&follower, &system).await;
+        }
+    });
+}
+
+async fn sync(follower: &Arc<StandbyFollower>, system: &SharedSystem) {
+    match follower.sync(system).await {
+        Ok(lag) => {
+            debug!(
+                "Standby lag - state entries: {}, messages: {}, seconds: {:.3}.",
+                lag.state_entries, lag.messages, lag.seconds
+            );
+            system.read().await.metrics.set_standby_lag(&lag);
+        }
+        Err(error) => {
+            error!("{COMPONENT} (error: {error}) - failed to sync with the primary server");
+            let lag = follower.lag_since_caught_up();
+            system.read().await.metrics.set_standby_lag(&lag);
+        }
+    }
+}
@@ -0,0 +1,265 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cluster::rpc::{read_message, write_message};
+use crate::configs::standby::StandbyShippingConfig;
+use crate::standby::{ShippingRequest, ShippingResponse, COMPONENT};
+use crate::state::entry::StateEntry;
+use crate::state::StateKind;
+use crate::streaming::systems::system::SharedSystem;
+use bytes::BytesMut;
+use error_set::ErrContext;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+use tracing::{debug, error, info, warn};
+
+/// Starts the listener serving the state entries and the messages of the partitions to the standby servers.
+/// Returns the address the primary is listening on.
+pub async fn start(config: StandbyShippingConfig, system: SharedSystem) -> SocketAddr {
+    let listener = TcpListener::bind(&config.address)
+        .await
+        .unwrap_or_else(|error| {
+            panic!(
+                "Unable to bind standby shipping to address: {}. {error}",
+                config.address
+            )
+        });
+    let addr = listener
+        .local_addr()
+        .expect("Failed to get local address for standby shipping listener");
+    info!("Standby shipping has started on: {addr}.");
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((mut stream, address)) => {
+                    info!("Accepted new standby connection: {address}");
+                    let system = system.clone();
+                    tokio::spawn(async move {
+                        loop {
+                            let request: ShippingRequest = match read_message(&mut stream).await {
+                                Ok(request) => request,
+                                Err(error) => {
+                                    debug!(
+                                        "Standby connection: {address} has been closed. {error}"
+                                    );
+                                    break;
+                                }
+                            };
+                            let response = handle(&system, request).await.unwrap_or_else(|error| {
+                                ShippingResponse::Error {
+                                    code: error.as_code(),
+                                }
+                            });
+                            if let Err(error) = write_message(&mut stream, &response).await {
+                                debug!(
+                                    "Unable to respond to standby connection: {address}. {error}"
+                                );
+                                break;
+                            }
+                        }
+                    });
+                }
+                Err(error) => error!("Unable to accept standby connection: {error}"),
+            }
+        }
+    });
+    addr
+}
+
+async fn handle(
+    system: &SharedSystem,
+    request: ShippingRequest,
+) -> Result<ShippingResponse, IggyError> {
+    match request {
+        ShippingRequest::FetchStateEntries {
+            entries_count,
+            last_checksum,
+            count,
+        } => fetch_state_entries(system, entries_count, last_checksum, count).await,
+        ShippingRequest::FetchMessages {
+            stream_id,
+            topic_id,
+            partition_id,
+            offset,
+            count,
+        } => fetch_messages(system, stream_id, topic_id, partition_id, offset, count).await,
+    }
+}
+
+async fn fetch_state_entries(
+    system: &SharedSystem,
+    entries_count: u64,
+    last_checksum: Option<u32>,
+    count: u32,
+) -> Result<ShippingResponse, IggyError> {
+    let state = system.read().await.state.clone();
+    let StateKind::File(file_state) = state.as_ref() else {
+        return Err(IggyError::InvalidCommand);
+    };
+
+    let primary_entries_count = file_state.entries_count();
+    if entries_count == primary_entries_count {
+        return Ok(ShippingResponse::StateEntries {
+            entries: Vec::new(),
+            entries_count: primary_entries_count,
+        });
+    }
+
+    let entries = state.load_entries().await.with_error_context(|error| {
+        format!("{COMPONENT} (error: {error}) - failed to load state entries to ship")
+    })?;
+    let entries = select_state_entries(&entries, entries_count, last_checksum, count)
+        .inspect_err(|_| {
+            warn!("State log of the standby server with: {entries_count} entries has diverged from the primary one with: {} entries.", entries.len());
+        })?;
+    Ok(ShippingResponse::StateEntries {
+        entries: entries
+            .iter()
+            .map(|entry| entry.to_bytes().to_vec())
+            .collect(),
+        entries_count: primary_entries_count,
+    })
+}
+
+async fn fetch_messages(
+    system: &SharedSystem,
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+    offset: u64,
+    count: u32,
+) -> Result<ShippingResponse, IggyError> {
+    let system = system.read().await;
+    let partition = system
+        .get_stream(&Identifier::numeric(stream_id)?)?
+        .get_topic(&Identifier::numeric(topic_id)?)?
+        .get_partition(partition_id)?;
+    let partition = partition.read().await;
+    let current_offset = partition
+        .should_increment_offset
+        .then_some(partition.current_offset);
+    let mut messages = BytesMut::new();
+    if matches!(current_offset, Some(current_offset) if offset <= current_offset) {
+        for message in partition
+            .get_messages_by_offset(offset, count)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get messages to ship, partition: {partition_id}, topic: {topic_id}, stream: {stream_id}, offset: {offset}")
+            })?
+        {
+            message.extend(&mut messages);
+        }
+    }
+
+    Ok(ShippingResponse::Messages {
+        current_offset,
+        messages: messages.to_vec(),
+        consumer_offsets: partition
+            .consumer_offsets
+            .iter()
+            .map(|offset| (*offset.key(), offset.offset))
+            .collect(),
+        consumer_group_offsets: partition
+            .consumer_group_offsets
+            .iter()
+            .map(|offset| (*offset.key(), offset.offset))
+            .collect(),
+    })
+}
+
+/// Returns the entries following the ones stored by the standby, or fails if its state log doesn't match the primary one -
+/// it either has more entries, or its last entry is different.
+fn select_state_entries(
+    entries: &[StateEntry],
+    entries_count: u64,
+    last_checksum: Option<u32>,
+    count: u32,
+) -> Result<&[StateEntry], IggyError> {
+    if entries_count > entries.len() as u64 {
+        return Err(IggyError::StandbyStateDiverged(entries_count));
+    }
+
+    let start = entries_count as usize;
+    if start > 0 && Some(entries[start - 1].checksum) != last_checksum {
+        return Err(IggyError::StandbyStateDiverged(entries_count));
+    }
+
+    let end = entries.len().min(start + count as usize);
+    Ok(&entries[start..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::utils::timestamp::IggyTimestamp;
+
+    fn entries(count: u64) -> Vec<StateEntry> {
+        (0..count)
+            .map(|index| {
+                StateEntry::new(
+                    index,
+                    0,
+                    0,
+                    1,
+                    0,
+                    IggyTimestamp::now(),
+                    1,
+                    100 + index as u32,
+                    Bytes::new(),
+                    Bytes::new(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn entries_following_the_standby_ones_should_be_selected() {
+        let entries = entries(5);
+        let selected = select_state_entries(&entries, 2, Some(101), 2).unwrap();
+        assert_eq!(
+            selected.iter().map(|entry| entry.index).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert_eq!(
+            select_state_entries(&entries, 0, None, 10).unwrap().len(),
+            5
+        );
+        assert!(select_state_entries(&entries, 5, Some(104), 10)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn diverged_standby_state_should_be_rejected() {
+        let entries = entries(3);
+        assert!(matches!(
+            select_state_entries(&entries, 2, Some(999), 10),
+            Err(IggyError::StandbyStateDiverged(2))
+        ));
+        assert!(matches!(
+            select_state_entries(&entries, 4, Some(103), 10),
+            Err(IggyError::StandbyStateDiverged(4))
+        ));
+    }
+}
@@ -18,22 +18,32 @@
 
 use crate::state::models::{
     CreateConsumerGroupWithId, CreatePersonalAccessTokenWithHash, CreateStreamWithId,
-    CreateTopicWithId, CreateUserWithId,
+    CreateTopicWithId, CreateUserWithId, RegisterSchemaWithId,
 };
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::{
-    Command, CHANGE_PASSWORD_CODE, CREATE_CONSUMER_GROUP_CODE, CREATE_PARTITIONS_CODE,
-    CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_STREAM_CODE, CREATE_TOPIC_CODE, CREATE_USER_CODE,
-    DELETE_CONSUMER_GROUP_CODE, DELETE_PARTITIONS_CODE, DELETE_PERSONAL_ACCESS_TOKEN_CODE,
-    DELETE_STREAM_CODE, DELETE_TOPIC_CODE, DELETE_USER_CODE, PURGE_STREAM_CODE, PURGE_TOPIC_CODE,
-    UPDATE_PERMISSIONS_CODE, UPDATE_STREAM_CODE, UPDATE_TOPIC_CODE, UPDATE_USER_CODE,
+    Command, CHANGE_PASSWORD, CHANGE_PASSWORD_CODE, CREATE_CONSUMER_GROUP,
+    CREATE_CONSUMER_GROUP_CODE, CREATE_PARTITIONS, CREATE_PARTITIONS_CODE,
+    CREATE_PERSONAL_ACCESS_TOKEN, CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_STREAM,
+    CREATE_STREAM_CODE, CREATE_TOPIC, CREATE_TOPIC_CODE, CREATE_USER, CREATE_USER_CODE,
+    DELETE_CONSUMER_GROUP, DELETE_CONSUMER_GROUP_CODE, DELETE_PARTITIONS, DELETE_PARTITIONS_CODE,
+    DELETE_PERSONAL_ACCESS_TOKEN, DELETE_PERSONAL_ACCESS_TOKEN_CODE, DELETE_SEGMENTS,
+    DELETE_STREAM, DELETE_STREAM_CODE, DELETE_TOPIC, DELETE_TOPIC_CODE, DELETE_USER,
+    DELETE_USER_CODE, PURGE_STREAM, PURGE_STREAM_CODE, PURGE_TOPIC, PURGE_TOPIC_CODE,
+    REGISTER_SCHEMA, REGISTER_SCHEMA_CODE, UPDATE_PARTITION, UPDATE_PARTITION_CODE,
+    UPDATE_PERMISSIONS, UPDATE_PERMISSIONS_CODE, UPDATE_SCHEMA_COMPATIBILITY,
+    UPDATE_SCHEMA_COMPATIBILITY_CODE, UPDATE_STREAM, UPDATE_STREAM_CODE, UPDATE_TOPIC,
+    UPDATE_TOPIC_CODE, UPDATE_USER, UPDATE_USER_CODE,
 };
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use iggy::error::IggyError;
+use iggy::identifier::Identifier;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::update_partition::UpdatePartition;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use iggy::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
 use iggy::segments::delete_segments::DeleteSegments;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::purge_stream::PurgeStream;
@@ -59,6 +69,7 @@ pub enum EntryCommand {
     PurgeTopic(PurgeTopic),
     CreatePartitions(CreatePartitions),
     DeletePartitions(DeletePartitions),
+    UpdatePartition(UpdatePartition),
     DeleteSegments(DeleteSegments),
     CreateConsumerGroup(CreateConsumerGroupWithId),
     DeleteConsumerGroup(DeleteConsumerGroup),
@@ -69,6 +80,82 @@ pub enum EntryCommand {
     UpdatePermissions(UpdatePermissions),
     CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash),
     DeletePersonalAccessToken(DeletePersonalAccessToken),
+    RegisterSchema(RegisterSchemaWithId),
+    UpdateSchemaCompatibility(UpdateSchemaCompatibility),
+}
+
+impl EntryCommand {
+    /// Returns the name of the command along with the identifiers of the stream and topic it's performed on (if any),
+    /// so that the decisions of the external authorizer can be resolved before the replicated entry is applied.
+    pub fn authorization(&self) -> (&'static str, Option<&Identifier>, Option<&Identifier>) {
+        match self {
+            EntryCommand::CreateStream(_) => (CREATE_STREAM, None, None),
+            EntryCommand::UpdateStream(command) => (UPDATE_STREAM, Some(&command.stream_id), None),
+            EntryCommand::DeleteStream(command) => (DELETE_STREAM, Some(&command.stream_id), None),
+            EntryCommand::PurgeStream(command) => (PURGE_STREAM, Some(&command.stream_id), None),
+            EntryCommand::CreateTopic(command) => {
+                (CREATE_TOPIC, Some(&command.command.stream_id), None)
+            }
+            EntryCommand::UpdateTopic(command) => (
+                UPDATE_TOPIC,
+                Some(&command.stream_id),
+                Some(&command.topic_id),
+            ),
+            EntryCommand::DeleteTopic(command) => (
+                DELETE_TOPIC,
+                Some(&command.stream_id),
+                Some(&command.topic_id),
+            ),
+            EntryCommand::PurgeTopic(command) => (
+                PURGE_TOPIC,
+                Some(&command.stream_id),
+                Some(&command.topic_id),
+            ),
+            EntryCommand::CreatePartitions(command) => (
+                CREATE_PARTITIONS,
+                Some(&command.stream_id),
+                Some(&command.topic_id),
+            ),
+            EntryCommand::DeletePartitions(command) => (
+                DELETE_PARTITIONS,
+                Some(&command.stream_id),
+                Some(&command.topic_id),
+            ),
+            EntryCommand::UpdatePartition(command) => (
+                UPDATE_PARTITION,
+                Some(&command.stream_id),
+                Some(&command.topic_id),
+            ),
+            EntryCommand::DeleteSegments(command) => (
+                DELETE_SEGMENTS,
+                Some(&command.stream_id),
+                Some(&command.topic_id),
+            ),
+            EntryCommand::CreateConsumerGroup(command) => (
+                CREATE_CONSUMER_GROUP,
+                Some(&command.command.stream_id),
+                Some(&command.command.topic_id),
+            ),
+            EntryCommand::DeleteConsumerGroup(command) => (
+                DELETE_CONSUMER_GROUP,
+                Some(&command.stream_id),
+                Some(&command.topic_id),
+            ),
+            EntryCommand::CreateUser(_) => (CREATE_USER, None, None),
+            EntryCommand::UpdateUser(_) => (UPDATE_USER, None, None),
+            EntryCommand::DeleteUser(_) => (DELETE_USER, None, None),
+            EntryCommand::ChangePassword(_) => (CHANGE_PASSWORD, None, None),
+            EntryCommand::UpdatePermissions(_) => (UPDATE_PERMISSIONS, None, None),
+            EntryCommand::CreatePersonalAccessToken(_) => {
+                (CREATE_PERSONAL_ACCESS_TOKEN, None, None)
+            }
+            EntryCommand::DeletePersonalAccessToken(_) => {
+                (DELETE_PERSONAL_ACCESS_TOKEN, None, None)
+            }
+            EntryCommand::RegisterSchema(_) => (REGISTER_SCHEMA, None, None),
+            EntryCommand::UpdateSchemaCompatibility(_) => (UPDATE_SCHEMA_COMPATIBILITY, None, None),
+        }
+    }
 }
 
 impl BytesSerializable for EntryCommand {
@@ -84,6 +171,7 @@ impl BytesSerializable for EntryCommand {
             EntryCommand::PurgeTopic(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreatePartitions(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeletePartitions(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdatePartition(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteSegments(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreateConsumerGroup(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteConsumerGroup(command) => (command.code(), command.to_bytes()),
@@ -98,6 +186,10 @@ impl BytesSerializable for EntryCommand {
             EntryCommand::DeletePersonalAccessToken(command) => {
                 (command.code(), command.to_bytes())
             }
+            EntryCommand::RegisterSchema(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateSchemaCompatibility(command) => {
+                (command.code(), command.to_bytes())
+            }
         };
 
         let mut bytes = BytesMut::with_capacity(4 + 4 + command.len());
@@ -137,6 +229,9 @@ impl BytesSerializable for EntryCommand {
             DELETE_PARTITIONS_CODE => Ok(EntryCommand::DeletePartitions(
                 DeletePartitions::from_bytes(payload)?,
             )),
+            UPDATE_PARTITION_CODE => Ok(EntryCommand::UpdatePartition(
+                UpdatePartition::from_bytes(payload)?,
+            )),
             CREATE_CONSUMER_GROUP_CODE => Ok(EntryCommand::CreateConsumerGroup(
                 CreateConsumerGroupWithId::from_bytes(payload)?,
             )),
@@ -160,6 +255,12 @@ impl BytesSerializable for EntryCommand {
             DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(EntryCommand::DeletePersonalAccessToken(
                 DeletePersonalAccessToken::from_bytes(payload)?,
             )),
+            REGISTER_SCHEMA_CODE => Ok(EntryCommand::RegisterSchema(
+                RegisterSchemaWithId::from_bytes(payload)?,
+            )),
+            UPDATE_SCHEMA_COMPATIBILITY_CODE => Ok(EntryCommand::UpdateSchemaCompatibility(
+                UpdateSchemaCompatibility::from_bytes(payload)?,
+            )),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -178,6 +279,7 @@ impl Display for EntryCommand {
             EntryCommand::PurgeTopic(command) => write!(f, "PurgeTopic({})", command),
             EntryCommand::CreatePartitions(command) => write!(f, "CreatePartitions({})", command),
             EntryCommand::DeletePartitions(command) => write!(f, "DeletePartitions({})", command),
+            EntryCommand::UpdatePartition(command) => write!(f, "UpdatePartition({})", command),
             EntryCommand::DeleteSegments(command) => write!(f, "DeleteSegments({})", command),
             EntryCommand::CreateConsumerGroup(command) => {
                 write!(f, "CreateConsumerGroup({})", command)
@@ -196,6 +298,10 @@ impl Display for EntryCommand {
             EntryCommand::DeletePersonalAccessToken(command) => {
                 write!(f, "DeletePersonalAccessToken({})", command)
             }
+            EntryCommand::RegisterSchema(command) => write!(f, "RegisterSchema({})", command),
+            EntryCommand::UpdateSchemaCompatibility(command) => {
+                write!(f, "UpdateSchemaCompatibility({})", command)
+            }
         }
     }
 }
@@ -80,6 +80,165 @@ impl FileState {
     pub fn term(&self) -> u64 {
         self.term.load(Ordering::SeqCst)
     }
+
+    /// Sets the election term and the leader ID stored in the appended entries.
+    pub fn set_term_and_leader(&self, term: u64, leader_id: u32) {
+        self.term.store(term, Ordering::SeqCst);
+        self.current_leader.store(leader_id, Ordering::SeqCst);
+    }
+
+    /// Appends the entry with the given command and returns it (with the command not encrypted).
+    pub async fn append(
+        &self,
+        user_id: u32,
+        command: &EntryCommand,
+    ) -> Result<StateEntry, IggyError> {
+        debug!("Applying state entry with command: {command}, user ID: {user_id}");
+        let timestamp = IggyTimestamp::now();
+        let index = if self.entries_count.load(Ordering::SeqCst) == 0 {
+            0
+        } else {
+            self.current_index.fetch_add(1, Ordering::SeqCst) + 1
+        };
+        let term = self.term.load(Ordering::SeqCst);
+        let current_leader = self.current_leader.load(Ordering::SeqCst);
+        let version = self.version;
+        let flags = 0;
+        let context = Bytes::new();
+        let command = command.to_bytes();
+        let checksum = StateEntry::calculate_checksum(
+            index,
+            term,
+            current_leader,
+            version,
+            flags,
+            timestamp,
+            user_id,
+            &context,
+            &command,
+        );
+
+        let entry = StateEntry::new(
+            index,
+            term,
+            current_leader,
+            version,
+            flags,
+            timestamp,
+            user_id,
+            checksum,
+            context,
+            command,
+        );
+        let bytes = self.encode_entry(&entry)?;
+        self.entries_count.fetch_add(1, Ordering::SeqCst);
+        self.persister
+            .append(&self.path, &bytes)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to append state entry data to file, path: {}, data size: {}",
+                    self.path,
+                    bytes.len()
+                )
+            })?;
+        debug!("Applied state entry: {entry}");
+        Ok(entry)
+    }
+
+    /// Appends the entry replicated from the other node, which must directly follow the last entry.
+    pub async fn append_entry(&self, entry: &StateEntry) -> Result<(), IggyError> {
+        let entries_count = self.entries_count.load(Ordering::SeqCst);
+        if entry.index != entries_count {
+            error!(
+                "Cannot append state entry with index: {}, expected index: {entries_count}",
+                entry.index
+            );
+            return Err(IggyError::InvalidStateEntryIndex(
+                entry.index,
+                entries_count,
+            ));
+        }
+
+        let bytes = self.encode_entry(entry)?;
+        self.persister
+            .append(&self.path, &bytes)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to append replicated state entry to file, path: {}, index: {}",
+                    self.path, entry.index
+                )
+            })?;
+        self.current_index.store(entry.index, Ordering::SeqCst);
+        self.entries_count.fetch_add(1, Ordering::SeqCst);
+        debug!("Appended replicated state entry: {entry}");
+        Ok(())
+    }
+
+    /// Removes all the entries starting from the given index, e.g. the ones conflicting with the leader's log.
+    pub async fn truncate(&self, index: u64) -> Result<(), IggyError> {
+        let entries = self.load_entries().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to load entries to truncate")
+        })?;
+        let mut bytes = BytesMut::new();
+        for entry in entries.iter().take_while(|entry| entry.index < index) {
+            bytes.extend(self.encode_entry(entry)?);
+        }
+
+        self.persister
+            .overwrite(&self.path, &bytes)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to overwrite truncated state file, path: {}",
+                    self.path
+                )
+            })?;
+        let entries_count = index.min(entries.len() as u64);
+        self.entries_count.store(entries_count, Ordering::SeqCst);
+        self.current_index
+            .store(entries_count.saturating_sub(1), Ordering::SeqCst);
+        info!("Truncated state entries starting from index: {index}, remaining entries: {entries_count}");
+        Ok(())
+    }
+
+    fn encode_entry(&self, entry: &StateEntry) -> Result<Bytes, IggyError> {
+        let Some(encryptor) = &self.encryptor else {
+            return Ok(entry.to_bytes());
+        };
+
+        debug!("Encrypting state entry command with index: {}", entry.index);
+        let command_code = entry.command.slice(0..4).get_u32_le();
+        let command_length = entry.command.slice(4..8).get_u32_le() as usize;
+        let command_payload = entry.command.slice(8..8 + command_length);
+        let encrypted_command_payload =
+            encryptor
+                .encrypt(&command_payload)
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to encrypt state entry command, index: {}",
+                        entry.index
+                    )
+                })?;
+        let mut command = BytesMut::with_capacity(4 + 4 + encrypted_command_payload.len());
+        command.put_u32_le(command_code);
+        command.put_u32_le(encrypted_command_payload.len() as u32);
+        command.extend(encrypted_command_payload);
+        let entry = StateEntry::new(
+            entry.index,
+            entry.term,
+            entry.leader_id,
+            entry.version,
+            entry.flags,
+            entry.timestamp,
+            entry.user_id,
+            entry.checksum,
+            entry.context.clone(),
+            command.freeze(),
+        );
+        Ok(entry.to_bytes())
+    }
 }
 
 impl State for FileState {
@@ -306,77 +465,7 @@ impl State for FileState {
     }
 
     async fn apply(&self, user_id: u32, command: &EntryCommand) -> Result<(), IggyError> {
-        debug!("Applying state entry with command: {command}, user ID: {user_id}");
-        let timestamp = IggyTimestamp::now();
-        let index = if self.entries_count.load(Ordering::SeqCst) == 0 {
-            0
-        } else {
-            self.current_index.fetch_add(1, Ordering::SeqCst) + 1
-        };
-        let term = self.term.load(Ordering::SeqCst);
-        let current_leader = self.current_leader.load(Ordering::SeqCst);
-        let version = self.version;
-        let flags = 0;
-        let context = Bytes::new();
-        let mut command = command.to_bytes();
-        let checksum = StateEntry::calculate_checksum(
-            index,
-            term,
-            current_leader,
-            version,
-            flags,
-            timestamp,
-            user_id,
-            &context,
-            &command,
-        );
-
-        if let Some(encryptor) = &self.encryptor {
-            debug!("Encrypting state entry command with index: {index}");
-            let command_code = command.slice(0..4).get_u32_le();
-            let mut command_length = command.slice(4..8).get_u32_le() as usize;
-            let command_payload = command.slice(8..8 + command_length);
-            let encrypted_command_payload = encryptor
-                .encrypt(&command_payload)
-                .with_error_context(|error| {
-                    format!(
-                        "{COMPONENT} (error: {error}) - failed to encrypt state entry command, index: {}",
-                        index
-                    )
-                })?;
-            command_length = encrypted_command_payload.len();
-            let mut command_bytes = BytesMut::with_capacity(4 + 4 + command_length);
-            command_bytes.put_u32_le(command_code);
-            command_bytes.put_u32_le(command_length as u32);
-            command_bytes.extend(encrypted_command_payload);
-            command = command_bytes.freeze();
-        }
-
-        let entry = StateEntry::new(
-            index,
-            term,
-            current_leader,
-            version,
-            flags,
-            timestamp,
-            user_id,
-            checksum,
-            context,
-            command,
-        );
-        let bytes = entry.to_bytes();
-        self.entries_count.fetch_add(1, Ordering::SeqCst);
-        self.persister
-            .append(&self.path, &bytes)
-            .await
-            .with_error_context(|error| {
-                format!(
-                    "{COMPONENT} (error: {error}) - failed to append state entry data to file, path: {}, data size: {}",
-                    self.path,
-                    bytes.len()
-                )
-            })?;
-        debug!("Applied state entry: {entry}");
+        self.append(user_id, command).await?;
         Ok(())
     }
 }
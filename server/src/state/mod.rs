@@ -16,6 +16,8 @@
  * under the License.
  */
 
+use crate::cluster::raft::{PendingEntry, RaftNode};
+use crate::cluster::state::ReplicatedState;
 use crate::state::command::EntryCommand;
 use crate::state::entry::StateEntry;
 use iggy::error::IggyError;
@@ -23,6 +25,7 @@ use iggy::error::IggyError;
 use mockall::automock;
 use std::fmt::Debug;
 use std::future::Future;
+use std::sync::Arc;
 
 pub mod command;
 pub mod entry;
@@ -35,10 +38,28 @@ pub const COMPONENT: &str = "STATE";
 #[derive(Debug)]
 pub enum StateKind {
     File(file::FileState),
+    Replicated(ReplicatedState),
     #[cfg(test)]
     Mock(MockState),
 }
 
+/// The command appended to the state log, which might not be committed yet.
+#[derive(Debug)]
+pub enum StateCommit {
+    Committed,
+    Replicated(Arc<RaftNode>, PendingEntry),
+}
+
+impl StateCommit {
+    /// Waits until the command is committed, e.g. replicated to the quorum of the cluster nodes.
+    pub async fn wait(self) -> Result<(), IggyError> {
+        match self {
+            Self::Committed => Ok(()),
+            Self::Replicated(raft, entry) => raft.wait_committed(entry).await,
+        }
+    }
+}
+
 #[cfg_attr(test, automock)]
 pub trait State: Send {
     fn init(&self) -> impl Future<Output = Result<Vec<StateEntry>, IggyError>> + Send;
@@ -54,6 +75,7 @@ impl StateKind {
     pub async fn init(&self) -> Result<Vec<StateEntry>, IggyError> {
         match self {
             Self::File(s) => s.init().await,
+            Self::Replicated(s) => s.init().await,
             #[cfg(test)]
             Self::Mock(s) => s.init().await,
         }
@@ -62,6 +84,7 @@ impl StateKind {
     pub async fn load_entries(&self) -> Result<Vec<StateEntry>, IggyError> {
         match self {
             Self::File(s) => s.load_entries().await,
+            Self::Replicated(s) => s.load_entries().await,
             #[cfg(test)]
             Self::Mock(s) => s.load_entries().await,
         }
@@ -70,8 +93,26 @@ impl StateKind {
     pub async fn apply(&self, user_id: u32, command: &EntryCommand) -> Result<(), IggyError> {
         match self {
             Self::File(s) => s.apply(user_id, command).await,
+            Self::Replicated(s) => s.apply(user_id, command).await,
             #[cfg(test)]
             Self::Mock(s) => s.apply(user_id, command).await,
         }
     }
+
+    /// Appends the command to the state log, returning the commit to wait for once the system lock is released.
+    /// The command is appended while the lock is still held, so the entries are in the order the changes were applied
+    /// to the system, while waiting for the replicated state to be committed by the quorum of the cluster nodes doesn't block the system.
+    pub async fn append(
+        &self,
+        user_id: u32,
+        command: &EntryCommand,
+    ) -> Result<StateCommit, IggyError> {
+        match self {
+            Self::Replicated(s) => s.append(user_id, command).await,
+            _ => {
+                self.apply(user_id, command).await?;
+                Ok(StateCommit::Committed)
+            }
+        }
+    }
 }
@@ -24,6 +24,7 @@ use iggy::command::Command;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::error::IggyError;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use iggy::schemas::register_schema::RegisterSchema;
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::users::create_user::CreateUser;
@@ -63,6 +64,12 @@ pub struct CreatePersonalAccessTokenWithHash {
     pub command: CreatePersonalAccessToken,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RegisterSchemaWithId {
+    pub schema_id: u32,
+    pub command: RegisterSchema,
+}
+
 impl Validatable<IggyError> for CreateStreamWithId {
     fn validate(&self) -> Result<(), IggyError> {
         self.command.validate()
@@ -123,6 +130,18 @@ impl Command for CreatePersonalAccessTokenWithHash {
     }
 }
 
+impl Validatable<IggyError> for RegisterSchemaWithId {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.command.validate()
+    }
+}
+
+impl Command for RegisterSchemaWithId {
+    fn code(&self) -> u32 {
+        self.command.code()
+    }
+}
+
 impl Display for CreateStreamWithId {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
@@ -173,6 +192,16 @@ impl Display for CreatePersonalAccessTokenWithHash {
     }
 }
 
+impl Display for RegisterSchemaWithId {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "RegisterSchemaWithId {{ command: {}, schema_id: {} }}",
+            self.command, self.schema_id
+        )
+    }
+}
+
 impl BytesSerializable for CreateStreamWithId {
     fn to_bytes(&self) -> Bytes {
         let mut bytes = BytesMut::new();
@@ -297,6 +326,47 @@ impl BytesSerializable for CreateConsumerGroupWithId {
     }
 }
 
+impl BytesSerializable for RegisterSchemaWithId {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(self.schema_id);
+        let command_bytes = self.command.to_bytes();
+        bytes.put_u32_le(command_bytes.len() as u32);
+        bytes.put_slice(&command_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        let mut position = 0;
+        let schema_id = u32::from_le_bytes(
+            bytes[position..4]
+                .try_into()
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to parse schema ID")
+                })
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let command_length = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to parse register schema command length")
+                })
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        position += 4;
+        let command_bytes = bytes.slice(position..position + command_length as usize);
+        let command = RegisterSchema::from_bytes(command_bytes).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to parse register schema command")
+        })?;
+        Ok(Self { schema_id, command })
+    }
+}
+
 impl BytesSerializable for CreateUserWithId {
     fn to_bytes(&self) -> Bytes {
         let mut bytes = BytesMut::new();
@@ -21,10 +21,15 @@ use crate::streaming::personal_access_tokens::personal_access_token::PersonalAcc
 use ahash::AHashMap;
 use error_set::ErrContext;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer_groups::assignment_strategy::AssignmentStrategy;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::models::permissions::Permissions;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
+use iggy::models::topic_options::TopicOptions;
+use iggy::models::topic_schema::TopicSchema;
 use iggy::models::user_status::UserStatus;
+use iggy::schemas::schema_compatibility::SchemaCompatibility;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::timestamp::IggyTimestamp;
 use iggy::utils::topic_size::MaxTopicSize;
@@ -35,6 +40,8 @@ use tracing::{debug, info};
 pub struct SystemState {
     pub streams: AHashMap<u32, StreamState>,
     pub users: AHashMap<u32, UserState>,
+    pub schemas: AHashMap<u32, SchemaState>,
+    pub subjects_compatibility: AHashMap<String, SchemaCompatibility>,
 }
 
 #[derive(Debug)]
@@ -55,12 +62,14 @@ pub struct TopicState {
     pub message_expiry: IggyExpiry,
     pub max_topic_size: MaxTopicSize,
     pub replication_factor: Option<u8>,
+    pub options: TopicOptions,
     pub created_at: IggyTimestamp,
 }
 
 #[derive(Debug)]
 pub struct PartitionState {
     pub id: u32,
+    pub name: Option<String>,
     pub created_at: IggyTimestamp,
 }
 
@@ -69,6 +78,8 @@ pub struct PersonalAccessTokenState {
     pub name: String,
     pub token_hash: String,
     pub expiry_at: Option<IggyTimestamp>,
+    pub scope: Option<PersonalAccessTokenScope>,
+    pub allowed_addresses: Option<Vec<String>>,
 }
 
 #[derive(Debug)]
@@ -82,16 +93,28 @@ pub struct UserState {
     pub personal_access_tokens: AHashMap<String, PersonalAccessTokenState>,
 }
 
+#[derive(Debug)]
+pub struct SchemaState {
+    pub id: u32,
+    pub subject: String,
+    pub version: u32,
+    pub schema: TopicSchema,
+    pub created_at: IggyTimestamp,
+}
+
 #[derive(Debug)]
 pub struct ConsumerGroupState {
     pub id: u32,
     pub name: String,
+    pub assignment_strategy: AssignmentStrategy,
 }
 
 impl SystemState {
     pub async fn init(entries: Vec<StateEntry>) -> Result<Self, IggyError> {
         let mut streams = AHashMap::new();
         let mut users = AHashMap::new();
+        let mut schemas: AHashMap<u32, SchemaState> = AHashMap::new();
+        let mut subjects_compatibility = AHashMap::new();
         for entry in entries {
             debug!("Processing state entry: {entry}",);
             match entry.command().with_error_context(|error| {
@@ -142,6 +165,7 @@ impl SystemState {
                         message_expiry: command.message_expiry,
                         max_topic_size: command.max_topic_size,
                         replication_factor: command.replication_factor,
+                        options: command.options,
                         created_at: entry.timestamp,
                         partitions: if command.partitions_count > 0 {
                             let mut partitions = AHashMap::new();
@@ -150,6 +174,7 @@ impl SystemState {
                                     i,
                                     PartitionState {
                                         id: i,
+                                        name: None,
                                         created_at: entry.timestamp,
                                     },
                                 );
@@ -176,6 +201,9 @@ impl SystemState {
                     topic.message_expiry = command.message_expiry;
                     topic.max_topic_size = command.max_topic_size;
                     topic.replication_factor = command.replication_factor;
+                    if let Some(options) = command.options {
+                        topic.options = options;
+                    }
                 }
                 EntryCommand::DeleteTopic(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
@@ -222,6 +250,7 @@ impl SystemState {
                             last_partition_id + i,
                             PartitionState {
                                 id: last_partition_id + i,
+                                name: None,
                                 created_at: entry.timestamp,
                             },
                         );
@@ -251,6 +280,22 @@ impl SystemState {
                         topic.partitions.remove(&(last_partition_id - i));
                     }
                 }
+                EntryCommand::UpdatePartition(command) => {
+                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    let partition_id = find_partition_id(&topic.partitions, &command.partition_id);
+                    let partition = topic.partitions.get_mut(&partition_id).unwrap_or_else(|| {
+                        panic!("{}", format!("Partition: {partition_id} not found"))
+                    });
+                    partition.name = command.name;
+                }
                 EntryCommand::DeleteSegments(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
                     let stream = streams
@@ -286,6 +331,7 @@ impl SystemState {
                     let consumer_group = ConsumerGroupState {
                         id: consumer_group_id,
                         name: command.name,
+                        assignment_strategy: command.assignment_strategy,
                     };
                     topic
                         .consumer_groups
@@ -380,6 +426,8 @@ impl SystemState {
                             name: command.command.name,
                             token_hash,
                             expiry_at,
+                            scope: command.command.scope,
+                            allowed_addresses: command.command.allowed_addresses,
                         },
                     );
                 }
@@ -398,10 +446,35 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
                     user.personal_access_tokens.remove(&command.name);
                 }
+                EntryCommand::RegisterSchema(command) => {
+                    let schema_id = command.schema_id;
+                    let command = command.command;
+                    let version = schemas
+                        .values()
+                        .filter(|schema| schema.subject == command.subject)
+                        .count() as u32
+                        + 1;
+                    let schema = SchemaState {
+                        id: schema_id,
+                        subject: command.subject,
+                        version,
+                        schema: command.schema,
+                        created_at: entry.timestamp,
+                    };
+                    schemas.insert(schema.id, schema);
+                }
+                EntryCommand::UpdateSchemaCompatibility(command) => {
+                    subjects_compatibility.insert(command.subject, command.compatibility);
+                }
             }
         }
 
-        let state = SystemState { streams, users };
+        let state = SystemState {
+            streams,
+            users,
+            schemas,
+            subjects_compatibility,
+        };
         debug!("+++ State +++");
         debug!("{state}");
         debug!("+++ State +++");
@@ -445,6 +518,24 @@ fn find_topic_id(topics: &AHashMap<u32, TopicState>, topic_id: &Identifier) -> u
     }
 }
 
+fn find_partition_id(partitions: &AHashMap<u32, PartitionState>, partition_id: &Identifier) -> u32 {
+    match partition_id.kind {
+        IdKind::Numeric => partition_id
+            .get_u32_value()
+            .unwrap_or_else(|_| panic!("{}", format!("Invalid partition ID: {partition_id}"))),
+        IdKind::String => {
+            let name = partition_id.get_cow_str_value().unwrap_or_else(|_| {
+                panic!("{}", format!("Invalid partition name: {partition_id}"))
+            });
+            let partition = partitions
+                .values()
+                .find(|p| p.name.as_deref() == Some(name.as_ref()))
+                .unwrap_or_else(|| panic!("{}", format!("Partition: {name} not found")));
+            partition.id
+        }
+    }
+}
+
 fn find_consumer_group_id(
     groups: &AHashMap<u32, ConsumerGroupState>,
     group_id: &Identifier,
@@ -496,13 +587,32 @@ impl Display for SystemState {
             write!(f, "\n================\n")?;
             write!(f, "{}", user.1)?;
         }
+        write!(f, "Schemas:")?;
+        for schema in self.schemas.iter() {
+            write!(f, "\n================\n")?;
+            write!(f, "{}", schema.1)?;
+        }
         Ok(())
     }
 }
 
+impl Display for SchemaState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Schema -> ID: {}, Subject: {}, Version: {}, Schema: {}",
+            self.id, self.subject, self.version, self.schema
+        )
+    }
+}
+
 impl Display for ConsumerGroupState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "ConsumerGroup -> ID: {}, Name: {}", self.id, self.name)
+        write!(
+            f,
+            "ConsumerGroup -> ID: {}, Name: {}, Assignment strategy: {}",
+            self.id, self.name, self.assignment_strategy
+        )
     }
 }
 
@@ -549,8 +659,10 @@ impl Display for PartitionState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Partition -> ID: {}, Created At: {}",
-            self.id, self.created_at
+            "Partition -> ID: {}, Name: {}, Created At: {}",
+            self.id,
+            self.name.as_deref().unwrap_or("none"),
+            self.created_at
         )
     }
 }
@@ -18,7 +18,7 @@
 
 use super::message_batch::{RetainedMessageBatch, RETAINED_BATCH_HEADER_LEN};
 use crate::streaming::models::messages::RetainedMessage;
-use bytes::BytesMut;
+use crate::streaming::utils::buffer_pool::BufferPool;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::sizeable::Sizeable;
 use std::sync::Arc;
@@ -97,7 +97,10 @@ impl BatchAccumulator {
         self.base_offset
     }
 
-    pub fn materialize_batch_and_update_state(&mut self) -> RetainedMessageBatch {
+    pub fn materialize_batch_and_update_state(
+        &mut self,
+        buffer_pool: &BufferPool,
+    ) -> RetainedMessageBatch {
         let batch_base_offset = self.base_offset;
         let batch_last_offset_delta = (self.current_offset - self.base_offset) as u32;
 
@@ -108,9 +111,9 @@ impl BatchAccumulator {
             0
         };
 
-        let messages = std::mem::take(&mut self.messages);
-        let mut bytes = BytesMut::with_capacity(self.current_size.as_bytes_u64() as usize);
-        for message in messages {
+        // The buffer of the previously written batch is reused if available, so building the batch doesn't allocate.
+        let mut bytes = buffer_pool.acquire(self.current_size.as_bytes_u64() as usize);
+        for message in self.messages.drain(..) {
             message.extend(&mut bytes);
         }
 
@@ -20,9 +20,13 @@ use crate::streaming::batching::batch_filter::BatchItemizer;
 use crate::streaming::batching::iterator::IntoMessagesIterator;
 use crate::streaming::models::messages::RetainedMessage;
 use bytes::Bytes;
+use iggy::utils::checksum::ChecksumHasher;
 use iggy::utils::{byte_size::IggyByteSize, sizeable::Sizeable};
 
-pub const RETAINED_BATCH_HEADER_LEN: u64 = 8 + 8 + 4 + 4;
+/// The stored batch header consists of the base offset, length, last offset delta, max timestamp and checksum.
+pub const RETAINED_BATCH_HEADER_LEN: u64 = 8 + 4 + 4 + 8 + 4;
+/// The checksum is the last field of the header, calculated over the preceding fields and the batch payload.
+pub const RETAINED_BATCH_CHECKSUM_POSITION: usize = 24;
 
 #[derive(Debug)]
 pub struct RetainedMessageBatch {
@@ -64,18 +68,30 @@ impl RetainedMessageBatch {
         self.base_offset + self.last_offset_delta as u64
     }
 
-    pub fn header_as_bytes(&self) -> [u8; 24] {
-        let mut header: [u8; 24] = [0u8; 24];
+    pub fn header_as_bytes(&self) -> [u8; RETAINED_BATCH_HEADER_LEN as usize] {
+        let mut header = [0u8; RETAINED_BATCH_HEADER_LEN as usize];
 
         header[0..8].copy_from_slice(&self.base_offset.to_le_bytes());
         header[8..12].copy_from_slice(&(self.length.as_bytes_u64() as u32).to_le_bytes());
         header[12..16].copy_from_slice(&self.last_offset_delta.to_le_bytes());
         header[16..24].copy_from_slice(&self.max_timestamp.to_le_bytes());
+        let checksum =
+            calculate_batch_checksum(&header[..RETAINED_BATCH_CHECKSUM_POSITION], &self.bytes);
+        header[RETAINED_BATCH_CHECKSUM_POSITION..].copy_from_slice(&checksum.to_le_bytes());
 
         header
     }
 }
 
+/// Calculates the checksum of the stored batch, covering the header fields (except the checksum itself) and the payload,
+/// so that both the corrupted header and messages are detected on reading.
+pub fn calculate_batch_checksum(header: &[u8], payload: &[u8]) -> u32 {
+    let mut hasher = ChecksumHasher::new();
+    hasher.update(&header[..RETAINED_BATCH_CHECKSUM_POSITION]);
+    hasher.update(payload);
+    hasher.checksum()
+}
+
 impl<'a, T, U> BatchItemizer<RetainedMessage, &'a U, T> for T
 where
     T: Iterator<Item = &'a U>,
@@ -18,7 +18,7 @@
 
 use crate::streaming::local_sizeable::RealSize;
 
-use super::memory_tracker::CacheMemoryTracker;
+use super::memory_tracker::{CacheKey, CacheMemoryTracker, PartitionCacheUsage};
 use atone::Vc;
 use iggy::utils::byte_size::IggyByteSize;
 use std::fmt::Debug;
@@ -32,26 +32,33 @@ use std::sync::{
 pub struct SmartCache<T: RealSize + Debug> {
     buffer: Vc<T>,
     memory_tracker: Arc<CacheMemoryTracker>,
+    key: CacheKey,
+    usage: Arc<PartitionCacheUsage>,
     current_size: IggyByteSize,
     hits: AtomicU64,
     misses: AtomicU64,
+    evictions: AtomicU64,
 }
 
 impl<T> SmartCache<T>
 where
     T: RealSize + Clone + Debug,
 {
-    pub fn new() -> Self {
+    pub fn new(key: CacheKey) -> Self {
         let current_size = IggyByteSize::default();
         let buffer = Vc::new();
         let memory_tracker = CacheMemoryTracker::get_instance().unwrap();
+        let usage = memory_tracker.register_partition(key);
 
         Self {
             buffer,
             memory_tracker,
+            key,
+            usage,
             current_size,
             hits: AtomicU64::new(0),
             misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
         }
     }
 
@@ -70,33 +77,32 @@ where
         let element_size = element.real_size();
 
         while !self.memory_tracker.will_fit_into_cache(element_size) {
-            if let Some(oldest_element) = self.buffer.pop_front() {
-                let oldest_size = oldest_element.real_size();
-                self.memory_tracker
-                    .decrement_used_memory(oldest_size.as_bytes_u64());
-                self.current_size -= oldest_size;
+            if self.evict_oldest().is_none() {
+                break;
             }
         }
 
-        self.memory_tracker
-            .increment_used_memory(element_size.as_bytes_u64());
-        self.current_size += element_size;
+        self.add_size(element_size);
         self.buffer.push_back(element);
     }
 
-    /// Removes the oldest elements until there's enough space for the new element.
+    /// Removes the oldest elements until at least the given size is removed or the buffer is empty.
     pub fn evict_by_size(&mut self, size_to_remove: u64) {
-        let mut removed_size = IggyByteSize::default();
-
-        while let Some(element) = self.buffer.pop_front() {
-            if removed_size >= size_to_remove {
+        let mut removed_size = 0;
+        while removed_size < size_to_remove {
+            let Some(element_size) = self.evict_oldest() else {
                 break;
-            }
-            let elem_size = element.real_size();
-            self.memory_tracker
-                .decrement_used_memory(elem_size.as_bytes_u64());
-            self.current_size -= elem_size;
-            removed_size += elem_size;
+            };
+            removed_size += element_size.as_bytes_u64();
+        }
+    }
+
+    /// Removes the oldest elements exceeding the budget of the partition, which depends on its recent reads.
+    pub fn evict_over_budget(&mut self) {
+        let budget = self.memory_tracker.partition_budget(&self.usage);
+        let size = self.current_size.as_bytes_u64();
+        if size > budget {
+            self.evict_by_size(size - budget);
         }
     }
 
@@ -105,6 +111,7 @@ where
         self.memory_tracker
             .decrement_used_memory(self.current_size.as_bytes_u64());
         self.current_size = IggyByteSize::default();
+        self.usage.set_size_bytes(0);
     }
 
     pub fn is_empty(&self) -> bool {
@@ -115,24 +122,18 @@ where
         self.current_size
     }
 
-    /// Extends the buffer with the given elements, and always adding the elements,
-    /// even if it exceeds the memory limit.
+    /// Extends the buffer with the given elements and then evicts the oldest elements exceeding the partition budget.
     pub fn extend(&mut self, elements: impl IntoIterator<Item = T>) {
-        let elements = elements.into_iter().inspect(|element| {
-            let element_size = element.real_size();
-            self.memory_tracker
-                .increment_used_memory(element_size.as_bytes_u64());
-            self.current_size += element_size;
-        });
-        self.buffer.extend(elements);
+        for element in elements {
+            self.add_size(element.real_size());
+            self.buffer.push_back(element);
+        }
+        self.evict_over_budget();
     }
 
     /// Always appends the element into the buffer, even if it exceeds the memory limit.
     pub fn append(&mut self, element: T) {
-        let element_size = element.real_size();
-        self.memory_tracker
-            .increment_used_memory(element_size.as_bytes_u64());
-        self.current_size += element_size;
+        self.add_size(element.real_size());
         self.buffer.push(element);
     }
 
@@ -158,15 +159,48 @@ where
             hits,
             misses,
             hit_ratio,
+            evictions: self.evictions.load(Ordering::Relaxed),
+            size: self.current_size,
+            budget: IggyByteSize::from(self.memory_tracker.partition_budget(&self.usage)),
         }
     }
 
     pub fn record_hit(&self) {
         self.hits.fetch_add(1, Ordering::Relaxed);
+        self.memory_tracker.record_read(&self.usage, true);
     }
 
     pub fn record_miss(&self) {
         self.misses.fetch_add(1, Ordering::Relaxed);
+        self.memory_tracker.record_read(&self.usage, false);
+    }
+
+    fn add_size(&mut self, size: IggyByteSize) {
+        self.memory_tracker
+            .increment_used_memory(size.as_bytes_u64());
+        self.current_size += size;
+        self.usage.set_size_bytes(self.current_size.as_bytes_u64());
+    }
+
+    fn evict_oldest(&mut self) -> Option<IggyByteSize> {
+        let element_size = self.buffer.pop_front()?.real_size();
+        self.memory_tracker
+            .decrement_used_memory(element_size.as_bytes_u64());
+        self.memory_tracker
+            .record_eviction(1, element_size.as_bytes_u64());
+        self.current_size -= element_size;
+        self.usage.set_size_bytes(self.current_size.as_bytes_u64());
+        self.evictions.fetch_add(1, Ordering::Relaxed);
+        Some(element_size)
+    }
+}
+
+impl<T: RealSize + Debug> Drop for SmartCache<T> {
+    fn drop(&mut self) {
+        self.memory_tracker
+            .decrement_used_memory(self.current_size.as_bytes_u64());
+        self.memory_tracker
+            .unregister_partition(&self.key, &self.usage);
     }
 }
 
@@ -181,15 +215,12 @@ where
     }
 }
 
-impl<T: RealSize + Clone + Debug> Default for SmartCache<T> {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 #[derive(Debug)]
 pub struct CacheMetrics {
     pub hits: u64,
     pub misses: u64,
     pub hit_ratio: f32,
+    pub evictions: u64,
+    pub size: IggyByteSize,
+    pub budget: IggyByteSize,
 }
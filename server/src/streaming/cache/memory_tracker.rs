@@ -18,9 +18,10 @@
 
 extern crate sysinfo;
 
-use crate::configs::resource_quota::MemoryResourceQuota;
 use crate::configs::system::CacheConfig;
+use dashmap::DashMap;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, OnceLock};
 use sysinfo::System;
@@ -28,10 +29,55 @@ use tracing::info;
 
 static INSTANCE: OnceLock<Option<Arc<CacheMemoryTracker>>> = OnceLock::new();
 
+/// Tracks the memory used by the messages caches of all partitions.
+///
+/// Each partition gets the budget of at least `partition_min_size`, and the rest of the cache size
+/// is shared proportionally to the recent reads of the partitions, so the hot partitions can cache more messages.
+/// The reads are halved after each `read_window`, to follow the changes of the read traffic.
 #[derive(Debug)]
 pub struct CacheMemoryTracker {
     used_memory_bytes: AtomicU64,
-    limit_bytes: IggyByteSize,
+    limit_bytes: AtomicU64,
+    partition_min_size_bytes: u64,
+    read_window_micros: u64,
+    read_window_started_at: AtomicU64,
+    total_reads: AtomicU64,
+    partitions: DashMap<CacheKey, Arc<PartitionCacheUsage>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evicted_messages: AtomicU64,
+    evicted_bytes: AtomicU64,
+    prefetched_messages: AtomicU64,
+    prefetch_hits: AtomicU64,
+    prefetch_discarded_messages: AtomicU64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+}
+
+/// The cache usage of a single partition, shared between its cache and the tracker.
+#[derive(Debug, Default)]
+pub struct PartitionCacheUsage {
+    size_bytes: AtomicU64,
+    reads: AtomicU64,
+    last_read_at: AtomicU64,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CacheTrackerMetrics {
+    pub used_bytes: u64,
+    pub limit_bytes: u64,
+    pub hits: u64,
+    pub misses: u64,
+    pub evicted_messages: u64,
+    pub evicted_bytes: u64,
+    pub prefetched_messages: u64,
+    pub prefetch_hits: u64,
+    pub prefetch_discarded_messages: u64,
 }
 
 type MessageSize = u64;
@@ -41,7 +87,7 @@ impl CacheMemoryTracker {
         INSTANCE
             .get_or_init(|| {
                 if config.enabled {
-                    Some(Arc::new(CacheMemoryTracker::new(config.size.clone())))
+                    Some(Arc::new(CacheMemoryTracker::new(config)))
                 } else {
                     info!("Cache memory tracker disabled");
                     None
@@ -54,7 +100,7 @@ impl CacheMemoryTracker {
         INSTANCE.get().cloned().flatten()
     }
 
-    fn new(limit: MemoryResourceQuota) -> Self {
+    pub(crate) fn new(config: &CacheConfig) -> Self {
         let mut sys = System::new_all();
         sys.refresh_all();
 
@@ -63,16 +109,28 @@ impl CacheMemoryTracker {
         let free_memory_percentage =
             free_memory.as_bytes_u64() as f64 / total_memory_bytes.as_bytes_u64() as f64 * 100.0;
         let used_memory_bytes = AtomicU64::new(0);
-        let limit_bytes = limit.into();
+        let limit_bytes = config.size.clone().into();
 
         info!(
-            "Cache memory tracker started, cache: {}, total memory: {}, free memory: {}, free memory percentage: {:.2}%",
-            limit_bytes.as_human_string(), total_memory_bytes.as_human_string(), free_memory, free_memory_percentage
+            "Cache memory tracker started, cache: {}, partition min size: {}, read window: {}, total memory: {}, free memory: {}, free memory percentage: {:.2}%",
+            limit_bytes.as_human_string(), config.partition_min_size, config.read_window, total_memory_bytes.as_human_string(), free_memory, free_memory_percentage
         );
 
         CacheMemoryTracker {
             used_memory_bytes,
-            limit_bytes,
+            limit_bytes: AtomicU64::new(limit_bytes.as_bytes_u64()),
+            partition_min_size_bytes: config.partition_min_size.as_bytes_u64(),
+            read_window_micros: config.read_window.as_micros(),
+            read_window_started_at: AtomicU64::new(IggyTimestamp::now().as_micros()),
+            total_reads: AtomicU64::new(0),
+            partitions: DashMap::new(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evicted_messages: AtomicU64::new(0),
+            evicted_bytes: AtomicU64::new(0),
+            prefetched_messages: AtomicU64::new(0),
+            prefetch_hits: AtomicU64::new(0),
+            prefetch_discarded_messages: AtomicU64::new(0),
         }
     }
 
@@ -114,6 +172,245 @@ impl CacheMemoryTracker {
 
     pub fn will_fit_into_cache(&self, requested_size: IggyByteSize) -> bool {
         IggyByteSize::from(self.used_memory_bytes.load(Ordering::SeqCst)) + requested_size
-            <= self.limit_bytes
+            <= self.limit_bytes()
+    }
+
+    pub fn limit_bytes(&self) -> IggyByteSize {
+        IggyByteSize::from(self.limit_bytes.load(Ordering::SeqCst))
+    }
+
+    /// Changes the cache size at runtime, the messages which no longer fit into it are not evicted here.
+    pub fn set_limit_bytes(&self, limit_bytes: IggyByteSize) {
+        self.limit_bytes
+            .store(limit_bytes.as_bytes_u64(), Ordering::SeqCst);
+        info!(
+            "Cache memory tracker limit changed to: {}",
+            limit_bytes.as_human_string()
+        );
+    }
+
+    /// Returns the number of bytes by which the used memory exceeds the cache size.
+    pub fn excess_bytes(&self) -> u64 {
+        self.used_memory_bytes
+            .load(Ordering::SeqCst)
+            .saturating_sub(self.limit_bytes.load(Ordering::SeqCst))
+    }
+
+    pub fn register_partition(&self, key: CacheKey) -> Arc<PartitionCacheUsage> {
+        let usage = Arc::new(PartitionCacheUsage::default());
+        self.partitions.insert(key, usage.clone());
+        usage
+    }
+
+    /// Removes the partition, unless it was already registered again with the new usage, e.g. after being recreated.
+    pub fn unregister_partition(&self, key: &CacheKey, usage: &Arc<PartitionCacheUsage>) {
+        if self
+            .partitions
+            .remove_if(key, |_, registered| Arc::ptr_eq(registered, usage))
+            .is_some()
+        {
+            let reads = usage.reads.load(Ordering::Relaxed);
+            let _ = self
+                .total_reads
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |total| {
+                    Some(total.saturating_sub(reads))
+                });
+        }
+    }
+
+    pub fn record_read(&self, usage: &PartitionCacheUsage, hit: bool) {
+        let now = IggyTimestamp::now().as_micros();
+        usage.reads.fetch_add(1, Ordering::Relaxed);
+        usage.last_read_at.store(now, Ordering::Relaxed);
+        self.total_reads.fetch_add(1, Ordering::Relaxed);
+        if hit {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+        self.decay_reads_if_window_elapsed(now);
+    }
+
+    pub fn record_eviction(&self, messages: u64, bytes: u64) {
+        self.evicted_messages.fetch_add(messages, Ordering::Relaxed);
+        self.evicted_bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub fn record_prefetch(&self, messages: u64) {
+        self.prefetched_messages
+            .fetch_add(messages, Ordering::Relaxed);
+    }
+
+    pub fn record_prefetch_hit(&self) {
+        self.prefetch_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records the prefetched messages, which were released without being polled.
+    pub fn record_prefetch_discard(&self, messages: u64) {
+        self.prefetch_discarded_messages
+            .fetch_add(messages, Ordering::Relaxed);
+    }
+
+    /// Returns the number of bytes the partition is allowed to cache.
+    pub fn partition_budget(&self, usage: &PartitionCacheUsage) -> u64 {
+        let limit_bytes = self.limit_bytes.load(Ordering::SeqCst);
+        let partitions = self.partitions.len().max(1) as u64;
+        let shared_bytes =
+            limit_bytes.saturating_sub(self.partition_min_size_bytes.saturating_mul(partitions));
+        let total_reads = self.total_reads.load(Ordering::Relaxed);
+        let share_bytes = if total_reads == 0 {
+            shared_bytes / partitions
+        } else {
+            let reads = usage.reads.load(Ordering::Relaxed).min(total_reads);
+            (shared_bytes as u128 * reads as u128 / total_reads as u128) as u64
+        };
+        (self.partition_min_size_bytes + share_bytes).min(limit_bytes)
+    }
+
+    /// Returns the partitions with the cached messages, starting from the least recently read one.
+    pub fn least_recently_read_partitions(&self) -> Vec<CacheKey> {
+        let mut partitions = self
+            .partitions
+            .iter()
+            .filter(|entry| entry.value().size_bytes.load(Ordering::Relaxed) > 0)
+            .map(|entry| {
+                (
+                    *entry.key(),
+                    entry.value().last_read_at.load(Ordering::Relaxed),
+                )
+            })
+            .collect::<Vec<_>>();
+        partitions.sort_by_key(|(_, last_read_at)| *last_read_at);
+        partitions.into_iter().map(|(key, _)| key).collect()
+    }
+
+    pub fn metrics(&self) -> CacheTrackerMetrics {
+        CacheTrackerMetrics {
+            used_bytes: self.used_memory_bytes.load(Ordering::SeqCst),
+            limit_bytes: self.limit_bytes.load(Ordering::SeqCst),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evicted_messages: self.evicted_messages.load(Ordering::Relaxed),
+            evicted_bytes: self.evicted_bytes.load(Ordering::Relaxed),
+            prefetched_messages: self.prefetched_messages.load(Ordering::Relaxed),
+            prefetch_hits: self.prefetch_hits.load(Ordering::Relaxed),
+            prefetch_discarded_messages: self.prefetch_discarded_messages.load(Ordering::Relaxed),
+        }
+    }
+
+    fn decay_reads_if_window_elapsed(&self, now: u64) {
+        let window_started_at = self.read_window_started_at.load(Ordering::Relaxed);
+        if now.saturating_sub(window_started_at) < self.read_window_micros
+            || self
+                .read_window_started_at
+                .compare_exchange(window_started_at, now, Ordering::AcqRel, Ordering::Relaxed)
+                .is_err()
+        {
+            return;
+        }
+
+        // The reads recorded concurrently with the decay might be lost, which is fine for the approximate budgets.
+        let mut total_reads = 0;
+        for partition in self.partitions.iter() {
+            let reads = partition.value().reads.load(Ordering::Relaxed) / 2;
+            partition.value().reads.store(reads, Ordering::Relaxed);
+            total_reads += reads;
+        }
+        self.total_reads.store(total_reads, Ordering::Relaxed);
+    }
+}
+
+impl PartitionCacheUsage {
+    pub fn set_size_bytes(&self, size_bytes: u64) {
+        self.size_bytes.store(size_bytes, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::resource_quota::MemoryResourceQuota;
+
+    fn create_tracker(read_window: &str) -> CacheMemoryTracker {
+        CacheMemoryTracker::new(&CacheConfig {
+            enabled: true,
+            size: MemoryResourceQuota::Bytes(IggyByteSize::from(10_000)),
+            partition_min_size: IggyByteSize::from(1_000),
+            read_window: read_window.parse().unwrap(),
+            prefetch_messages_count: 1_000,
+        })
+    }
+
+    fn key(partition_id: u32) -> CacheKey {
+        CacheKey {
+            stream_id: 1,
+            topic_id: 1,
+            partition_id,
+        }
+    }
+
+    #[test]
+    fn should_split_cache_size_evenly_without_reads() {
+        let tracker = create_tracker("1 m");
+        let first = tracker.register_partition(key(1));
+        let second = tracker.register_partition(key(2));
+
+        assert_eq!(tracker.partition_budget(&first), 5_000);
+        assert_eq!(tracker.partition_budget(&second), 5_000);
+    }
+
+    #[test]
+    fn should_give_bigger_budget_to_partition_with_more_reads() {
+        let tracker = create_tracker("1 m");
+        let hot = tracker.register_partition(key(1));
+        let cold = tracker.register_partition(key(2));
+        for _ in 0..3 {
+            tracker.record_read(&hot, true);
+        }
+        tracker.record_read(&cold, false);
+
+        assert_eq!(tracker.partition_budget(&hot), 1_000 + 6_000);
+        assert_eq!(tracker.partition_budget(&cold), 1_000 + 2_000);
+        let metrics = tracker.metrics();
+        assert_eq!(metrics.hits, 3);
+        assert_eq!(metrics.misses, 1);
+    }
+
+    #[test]
+    fn should_halve_reads_after_read_window() {
+        let tracker = create_tracker("1 us");
+        let usage = tracker.register_partition(key(1));
+        for _ in 0..4 {
+            usage.reads.fetch_add(1, Ordering::Relaxed);
+            tracker.total_reads.fetch_add(1, Ordering::Relaxed);
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        tracker.record_read(&usage, true);
+
+        assert_eq!(usage.reads.load(Ordering::Relaxed), 2);
+        assert_eq!(tracker.total_reads.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn should_return_least_recently_read_partitions_with_cached_messages() {
+        let tracker = create_tracker("1 m");
+        let first = tracker.register_partition(key(1));
+        let second = tracker.register_partition(key(2));
+        let empty = tracker.register_partition(key(3));
+        first.set_size_bytes(100);
+        second.set_size_bytes(100);
+        tracker.record_read(&second, true);
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        tracker.record_read(&first, true);
+        tracker.record_read(&empty, true);
+
+        assert_eq!(
+            tracker.least_recently_read_partitions(),
+            vec![key(2), key(1)]
+        );
+
+        tracker.unregister_partition(&key(2), &second);
+        assert_eq!(tracker.least_recently_read_partitions(), vec![key(1)]);
     }
 }
@@ -0,0 +1,236 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::ClientAccessConfig;
+use iggy::error::IggyError;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+const ANY_USER: &str = "*";
+
+/// The set of rules deciding which clients can connect to the server (by their IP address)
+/// and log in (by their username). Denied entries always take precedence over the allowed ones.
+#[derive(Debug, Default)]
+pub struct ClientAccessRules {
+    enabled: bool,
+    allowed_addresses: Vec<AddressRange>,
+    denied_addresses: Vec<AddressRange>,
+    allowed_users: Vec<String>,
+    denied_users: Vec<String>,
+}
+
+/// The IP address range in the CIDR notation, e.g. `10.0.0.0/8`.
+/// The plain IP address is treated as the range containing only this address.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AddressRange {
+    network: IpAddr,
+    prefix_length: u8,
+}
+
+impl ClientAccessRules {
+    pub fn from_config(config: &ClientAccessConfig) -> Result<Self, IggyError> {
+        Ok(Self {
+            enabled: config.enabled,
+            allowed_addresses: parse_ranges(&config.allowed_addresses)?,
+            denied_addresses: parse_ranges(&config.denied_addresses)?,
+            allowed_users: config.allowed_users.clone(),
+            denied_users: config.denied_users.clone(),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn is_address_allowed(&self, address: &IpAddr) -> bool {
+        if !self.enabled {
+            return true;
+        }
+
+        let address = address.to_canonical();
+        if self
+            .denied_addresses
+            .iter()
+            .any(|range| range.contains(&address))
+        {
+            return false;
+        }
+
+        self.allowed_addresses
+            .iter()
+            .any(|range| range.contains(&address))
+    }
+
+    pub fn is_user_allowed(&self, username: &str) -> bool {
+        if !self.enabled {
+            return true;
+        }
+
+        if self
+            .denied_users
+            .iter()
+            .any(|user| user == ANY_USER || user == username)
+        {
+            return false;
+        }
+
+        self.allowed_users
+            .iter()
+            .any(|user| user == ANY_USER || user == username)
+    }
+}
+
+impl AddressRange {
+    pub fn contains(&self, address: &IpAddr) -> bool {
+        match (self.network, address) {
+            (IpAddr::V4(network), IpAddr::V4(address)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - self.prefix_length as u32)
+                    .unwrap_or(0);
+                u32::from(network) & mask == u32::from(*address) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(address)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - self.prefix_length as u32)
+                    .unwrap_or(0);
+                u128::from(network) & mask == u128::from(*address) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for AddressRange {
+    type Err = IggyError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (address, prefix_length) = match value.split_once('/') {
+            Some((address, prefix_length)) => (address, Some(prefix_length)),
+            None => (value, None),
+        };
+        let network = address
+            .trim()
+            .parse::<IpAddr>()
+            .map_err(|_| IggyError::InvalidClientAddress)?
+            .to_canonical();
+        let max_prefix_length = match network {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        let prefix_length = match prefix_length {
+            Some(prefix_length) => prefix_length
+                .trim()
+                .parse::<u8>()
+                .map_err(|_| IggyError::InvalidClientAddress)?,
+            None => max_prefix_length,
+        };
+        if prefix_length > max_prefix_length {
+            return Err(IggyError::InvalidClientAddress);
+        }
+
+        Ok(Self {
+            network,
+            prefix_length,
+        })
+    }
+}
+
+fn parse_ranges(values: &[String]) -> Result<Vec<AddressRange>, IggyError> {
+    values.iter().map(|value| value.parse()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(
+        allowed_addresses: &[&str],
+        denied_addresses: &[&str],
+        allowed_users: &[&str],
+        denied_users: &[&str],
+    ) -> ClientAccessRules {
+        let to_strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect();
+        ClientAccessRules::from_config(&ClientAccessConfig {
+            enabled: true,
+            allowed_addresses: to_strings(allowed_addresses),
+            denied_addresses: to_strings(denied_addresses),
+            allowed_users: to_strings(allowed_users),
+            denied_users: to_strings(denied_users),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn address_range_should_match_addresses_within_prefix() {
+        let range: AddressRange = "10.1.0.0/16".parse().unwrap();
+        assert!(range.contains(&"10.1.2.3".parse().unwrap()));
+        assert!(!range.contains(&"10.2.0.1".parse().unwrap()));
+        assert!(!range.contains(&"::1".parse().unwrap()));
+
+        let range: AddressRange = "fd00::/8".parse().unwrap();
+        assert!(range.contains(&"fd12::1".parse().unwrap()));
+        assert!(!range.contains(&"fe80::1".parse().unwrap()));
+
+        let range: AddressRange = "127.0.0.1".parse().unwrap();
+        assert!(range.contains(&"127.0.0.1".parse().unwrap()));
+        assert!(!range.contains(&"127.0.0.2".parse().unwrap()));
+
+        let range: AddressRange = "0.0.0.0/0".parse().unwrap();
+        assert!(range.contains(&"192.168.1.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn address_range_should_be_rejected_when_invalid() {
+        assert!("10.0.0.0/33".parse::<AddressRange>().is_err());
+        assert!("::/129".parse::<AddressRange>().is_err());
+        assert!("10.0.0/8".parse::<AddressRange>().is_err());
+        assert!("localhost".parse::<AddressRange>().is_err());
+    }
+
+    #[test]
+    fn denied_address_should_take_precedence_over_allowed_one() {
+        let rules = rules(&["10.0.0.0/8"], &["10.0.5.0/24"], &["*"], &[]);
+        assert!(rules.is_address_allowed(&"10.0.4.1".parse().unwrap()));
+        assert!(!rules.is_address_allowed(&"10.0.5.1".parse().unwrap()));
+        assert!(!rules.is_address_allowed(&"192.168.0.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn ipv4_mapped_address_should_be_matched_as_ipv4() {
+        let rules = rules(&["127.0.0.1"], &[], &["*"], &[]);
+        assert!(rules.is_address_allowed(&"::ffff:127.0.0.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn denied_user_should_take_precedence_over_allowed_one() {
+        let denied_rules = rules(&["0.0.0.0/0"], &[], &["*"], &["guest"]);
+        assert!(denied_rules.is_user_allowed("iggy"));
+        assert!(!denied_rules.is_user_allowed("guest"));
+
+        let allowed_rules = rules(&["0.0.0.0/0"], &[], &["iggy"], &[]);
+        assert!(allowed_rules.is_user_allowed("iggy"));
+        assert!(!allowed_rules.is_user_allowed("guest"));
+    }
+
+    #[test]
+    fn all_clients_should_be_allowed_when_rules_are_disabled() {
+        let rules = ClientAccessRules::default();
+        assert!(rules.is_address_allowed(&"10.0.0.1".parse().unwrap()));
+        assert!(rules.is_user_allowed("guest"));
+    }
+}
@@ -0,0 +1,389 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::CertificateAuthConfig;
+use iggy::error::IggyError;
+use openssl::hash::MessageDigest;
+use openssl::nid::Nid;
+use openssl::x509::X509;
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::CertificateDer;
+use rustls::server::danger::ClientCertVerifier;
+use rustls::server::WebPkiClientVerifier;
+use rustls::RootCertStore;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufReader, ErrorKind};
+use std::sync::Arc;
+use tracing::{error, warn};
+
+const FINGERPRINT_LENGTH: usize = 64;
+
+/// Authenticates the clients by the certificates presented during the TLS handshake (mutual TLS).
+/// The certificate is verified against the configured certificate authorities, then its subject
+/// common name is mapped to the username, unless the certificate is on the (reloadable) denylist.
+/// The certificates without the mapping are rejected, unless using the common name as the username is explicitly enabled.
+#[derive(Debug, Default)]
+pub struct CertificateAuthenticator {
+    enabled: bool,
+    verifier: Option<Arc<dyn ClientCertVerifier>>,
+    users: HashMap<String, String>,
+    common_name_as_username: bool,
+    denylist_path: String,
+    revoked_fingerprints: HashSet<String>,
+    revoked_subjects: HashSet<String>,
+}
+
+/// The identity of the client read from its end-entity certificate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientCertificate {
+    pub common_name: String,
+    /// SHA-256 fingerprint of the DER-encoded certificate, as the lowercase hex string.
+    pub fingerprint: String,
+}
+
+impl CertificateAuthenticator {
+    pub fn from_config(config: &CertificateAuthConfig) -> Result<Self, IggyError> {
+        if !config.enabled {
+            return Ok(Self::default());
+        }
+
+        let mut authenticator = Self {
+            enabled: true,
+            verifier: Some(load_verifier(&config.ca_file)?),
+            users: config.users.clone(),
+            common_name_as_username: config.common_name_as_username,
+            denylist_path: config.denylist_path.clone(),
+            revoked_fingerprints: HashSet::new(),
+            revoked_subjects: HashSet::new(),
+        };
+        authenticator.reload_denylist()?;
+        Ok(authenticator)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the verifier requiring the clients to present a certificate issued by the configured authorities.
+    pub fn client_verifier(&self) -> Result<Arc<dyn ClientCertVerifier>, IggyError> {
+        self.verifier.clone().ok_or(IggyError::InvalidConfiguration)
+    }
+
+    /// Reads the denylist file again, replacing the previously revoked certificates.
+    /// Returns the number of the revoked certificates.
+    pub fn reload_denylist(&mut self) -> Result<usize, IggyError> {
+        if !self.enabled {
+            return Ok(0);
+        }
+
+        let content = match std::fs::read_to_string(&self.denylist_path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+            Err(error) => {
+                error!(
+                    "Cannot read the certificate denylist: {}. {error}",
+                    self.denylist_path
+                );
+                return Err(IggyError::CannotReadFile);
+            }
+        };
+
+        let (fingerprints, subjects) = parse_denylist(&content);
+        self.revoked_fingerprints = fingerprints;
+        self.revoked_subjects = subjects;
+        Ok(self.revoked_fingerprints.len() + self.revoked_subjects.len())
+    }
+
+    /// Returns the username of the client presenting the already verified certificate.
+    pub fn authenticate(&self, certificate: &[u8]) -> Result<String, IggyError> {
+        if !self.enabled {
+            return Err(IggyError::Unauthenticated);
+        }
+
+        let certificate = ClientCertificate::from_der(certificate)?;
+        if self.is_revoked(&certificate) {
+            warn!(
+                "Client certificate: {} with fingerprint: {} is revoked.",
+                certificate.common_name, certificate.fingerprint
+            );
+            return Err(IggyError::ClientAccessDenied);
+        }
+
+        if let Some(username) = self.users.get(&certificate.common_name) {
+            return Ok(username.clone());
+        }
+
+        if self.common_name_as_username {
+            return Ok(certificate.common_name);
+        }
+
+        warn!(
+            "Client certificate: {} with fingerprint: {} is not mapped to any user.",
+            certificate.common_name, certificate.fingerprint
+        );
+        Err(IggyError::Unauthenticated)
+    }
+
+    fn is_revoked(&self, certificate: &ClientCertificate) -> bool {
+        self.revoked_fingerprints.contains(&certificate.fingerprint)
+            || self.revoked_subjects.contains(&certificate.common_name)
+    }
+}
+
+impl ClientCertificate {
+    pub fn from_der(der: &[u8]) -> Result<Self, IggyError> {
+        let certificate = X509::from_der(der).map_err(|_| IggyError::InvalidTlsCertificate)?;
+        let common_name = certificate
+            .subject_name()
+            .entries_by_nid(Nid::COMMONNAME)
+            .next()
+            .and_then(|entry| entry.data().as_utf8().ok())
+            .map(|common_name| common_name.to_string())
+            .filter(|common_name| !common_name.is_empty())
+            .ok_or(IggyError::InvalidTlsCertificate)?;
+        let fingerprint = certificate
+            .digest(MessageDigest::sha256())
+            .map_err(|_| IggyError::InvalidTlsCertificate)?
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+        Ok(Self {
+            common_name,
+            fingerprint,
+        })
+    }
+}
+
+/// The cryptography used by the TLS listeners authenticating the clients by their certificates.
+pub fn crypto_provider() -> Arc<CryptoProvider> {
+    Arc::new(rustls::crypto::ring::default_provider())
+}
+
+fn load_verifier(ca_file: &str) -> Result<Arc<dyn ClientCertVerifier>, IggyError> {
+    let file = File::open(ca_file).map_err(|error| {
+        error!("Cannot open the client certificate authorities file: {ca_file}. {error}");
+        IggyError::InvalidTlsCertificatePath
+    })?;
+    let certificates = rustls_pemfile::certs(&mut BufReader::new(file))
+        .collect::<Result<Vec<CertificateDer<'static>>, _>>()
+        .map_err(|_| IggyError::InvalidTlsCertificate)?;
+    if certificates.is_empty() {
+        error!("No client certificate authorities found in: {ca_file}.");
+        return Err(IggyError::InvalidTlsCertificate);
+    }
+
+    let mut roots = RootCertStore::empty();
+    for certificate in certificates {
+        roots
+            .add(certificate)
+            .map_err(|_| IggyError::FailedToAddCertificate)?;
+    }
+
+    WebPkiClientVerifier::builder_with_provider(Arc::new(roots), crypto_provider())
+        .build()
+        .map_err(|error| {
+            error!("Cannot create the client certificate verifier. {error}");
+            IggyError::InvalidTlsCertificate
+        })
+}
+
+/// Splits the denylist entries into the certificate fingerprints and the subject common names.
+fn parse_denylist(content: &str) -> (HashSet<String>, HashSet<String>) {
+    let mut fingerprints = HashSet::new();
+    let mut subjects = HashSet::new();
+    for entry in content.lines().map(str::trim) {
+        if entry.is_empty() || entry.starts_with('#') {
+            continue;
+        }
+
+        let fingerprint = entry.replace(':', "");
+        if fingerprint.len() == FINGERPRINT_LENGTH
+            && fingerprint.chars().all(|c| c.is_ascii_hexdigit())
+        {
+            fingerprints.insert(fingerprint.to_lowercase());
+        } else {
+            subjects.insert(entry.to_string());
+        }
+    }
+    (fingerprints, subjects)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+    use rcgen::{BasicConstraints, CertificateParams, DnType, IsCa, KeyPair};
+    use std::str::FromStr;
+    use tempfile::TempDir;
+
+    fn certificate(common_name: &str) -> Vec<u8> {
+        let mut params = CertificateParams::new(Vec::<String>::new()).unwrap();
+        params
+            .distinguished_name
+            .push(DnType::CommonName, common_name);
+        let key = KeyPair::generate().unwrap();
+        params.self_signed(&key).unwrap().der().to_vec()
+    }
+
+    fn authenticator(
+        directory: &TempDir,
+        users: &[(&str, &str)],
+        denylist: &str,
+    ) -> CertificateAuthenticator {
+        authenticator_with_fallback(directory, users, denylist, false)
+    }
+
+    fn authenticator_with_fallback(
+        directory: &TempDir,
+        users: &[(&str, &str)],
+        denylist: &str,
+        common_name_as_username: bool,
+    ) -> CertificateAuthenticator {
+        let mut params = CertificateParams::new(Vec::<String>::new()).unwrap();
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        params
+            .distinguished_name
+            .push(DnType::CommonName, "iggy-ca");
+        let key = KeyPair::generate().unwrap();
+        let ca_file = directory.path().join("ca.pem");
+        std::fs::write(&ca_file, params.self_signed(&key).unwrap().pem()).unwrap();
+        let denylist_path = directory.path().join("denylist.txt");
+        std::fs::write(&denylist_path, denylist).unwrap();
+
+        CertificateAuthenticator::from_config(&CertificateAuthConfig {
+            enabled: true,
+            ca_file: ca_file.to_string_lossy().to_string(),
+            users: users
+                .iter()
+                .map(|(subject, username)| (subject.to_string(), username.to_string()))
+                .collect(),
+            common_name_as_username,
+            denylist_path: denylist_path.to_string_lossy().to_string(),
+            denylist_reload_interval: IggyDuration::from_str("30 s").unwrap(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn client_certificate_should_be_read_from_der() {
+        let der = certificate("orders");
+        let certificate = ClientCertificate::from_der(&der).unwrap();
+        assert_eq!(certificate.common_name, "orders");
+        assert_eq!(certificate.fingerprint.len(), FINGERPRINT_LENGTH);
+        assert!(ClientCertificate::from_der(b"invalid").is_err());
+    }
+
+    #[test]
+    fn common_name_should_be_mapped_to_username() {
+        let directory = TempDir::new().unwrap();
+        let authenticator = authenticator(&directory, &[("orders-service.internal", "orders")], "");
+        assert!(authenticator.client_verifier().is_ok());
+        assert_eq!(
+            authenticator
+                .authenticate(&certificate("orders-service.internal"))
+                .unwrap(),
+            "orders"
+        );
+    }
+
+    #[test]
+    fn unmapped_common_name_should_be_rejected() {
+        let directory = TempDir::new().unwrap();
+        let authenticator = authenticator(&directory, &[("orders-service.internal", "orders")], "");
+        assert!(matches!(
+            authenticator.authenticate(&certificate("iggy")),
+            Err(IggyError::Unauthenticated)
+        ));
+        assert!(matches!(
+            authenticator.authenticate(&certificate("orders")),
+            Err(IggyError::Unauthenticated)
+        ));
+    }
+
+    #[test]
+    fn unmapped_common_name_should_be_used_as_username_only_when_enabled() {
+        let directory = TempDir::new().unwrap();
+        let authenticator = authenticator_with_fallback(
+            &directory,
+            &[("orders-service.internal", "orders")],
+            "",
+            true,
+        );
+        assert_eq!(
+            authenticator
+                .authenticate(&certificate("orders-service.internal"))
+                .unwrap(),
+            "orders"
+        );
+        assert_eq!(
+            authenticator
+                .authenticate(&certificate("payments"))
+                .unwrap(),
+            "payments"
+        );
+    }
+
+    #[test]
+    fn revoked_certificate_should_be_denied() {
+        let directory = TempDir::new().unwrap();
+        let revoked = certificate("orders");
+        let fingerprint = ClientCertificate::from_der(&revoked).unwrap().fingerprint;
+        let fingerprint = fingerprint
+            .to_uppercase()
+            .as_bytes()
+            .chunks(2)
+            .map(|chunk| std::str::from_utf8(chunk).unwrap())
+            .collect::<Vec<_>>()
+            .join(":");
+        let mut authenticator = authenticator(
+            &directory,
+            &[("orders", "orders"), ("payments", "payments")],
+            &format!("# revoked\n{fingerprint}\n"),
+        );
+
+        assert!(matches!(
+            authenticator.authenticate(&revoked),
+            Err(IggyError::ClientAccessDenied)
+        ));
+        assert!(authenticator.authenticate(&certificate("orders")).is_ok());
+
+        std::fs::write(&authenticator.denylist_path, "payments\n").unwrap();
+        assert_eq!(authenticator.reload_denylist().unwrap(), 1);
+        assert!(authenticator.authenticate(&revoked).is_ok());
+        assert!(authenticator
+            .authenticate(&certificate("payments"))
+            .is_err());
+    }
+
+    #[test]
+    fn missing_denylist_should_revoke_no_certificates() {
+        let directory = TempDir::new().unwrap();
+        let mut authenticator = authenticator(&directory, &[("payments", "payments")], "payments");
+        std::fs::remove_file(&authenticator.denylist_path).unwrap();
+        assert_eq!(authenticator.reload_denylist().unwrap(), 0);
+        assert!(authenticator.authenticate(&certificate("payments")).is_ok());
+    }
+
+    #[test]
+    fn disabled_authenticator_should_not_authenticate() {
+        let authenticator = CertificateAuthenticator::default();
+        assert!(authenticator.client_verifier().is_err());
+        assert!(authenticator.authenticate(&certificate("orders")).is_err());
+    }
+}
@@ -65,7 +65,12 @@ impl Display for Transport {
 }
 
 impl ClientManager {
-    pub fn add_client(&mut self, address: &SocketAddr, transport: Transport) -> Arc<Session> {
+    pub fn add_client(
+        &mut self,
+        address: &SocketAddr,
+        transport: Transport,
+        now: IggyTimestamp,
+    ) -> Arc<Session> {
         let client_id = hash::calculate_32(address.to_string().as_bytes());
         let session = Arc::new(Session::from_client_id(client_id, *address));
         let client = Client {
@@ -73,7 +78,7 @@ impl ClientManager {
             session: session.clone(),
             transport,
             consumer_groups: Vec::new(),
-            last_heartbeat: IggyTimestamp::now(),
+            last_heartbeat: now,
         };
         self.clients.insert(client_id, IggySharedMut::new(client));
         session
@@ -16,4 +16,6 @@
  * under the License.
  */
 
+pub mod access_rules;
+pub mod certificate_auth;
 pub mod client_manager;
@@ -0,0 +1,115 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use std::fmt::{Display, Formatter};
+use std::ops::AddAssign;
+use std::time::Duration;
+
+/// The time the appended batch of messages spent in each stage of the write path,
+/// so that the latency observed by the producer can be attributed to a specific stage.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BatchTimings {
+    /// Authentication, permissions, throttling and the validation of the messages against the topic (e.g. its schema).
+    pub validation: Duration,
+    /// Choosing the partition the messages are appended to.
+    pub partitioning: Duration,
+    /// Appending the messages to the segment buffer and inserting them into the cache.
+    pub cache_insert: Duration,
+    /// Writing the messages to disk, along with the fsync, if it was required by the partition or the confirmation level.
+    pub persistence: Duration,
+}
+
+impl BatchTimings {
+    pub fn total(&self) -> Duration {
+        self.validation + self.partitioning + self.cache_insert + self.persistence
+    }
+
+    /// Returns the timings in the format of the `Server-Timing` HTTP header, with the durations in milliseconds.
+    pub fn to_server_timing(&self) -> String {
+        [
+            ("validation", self.validation),
+            ("partitioning", self.partitioning),
+            ("cache_insert", self.cache_insert),
+            ("persistence", self.persistence),
+            ("total", self.total()),
+        ]
+        .iter()
+        .map(|(stage, duration)| format!("{stage};dur={:.3}", duration.as_secs_f64() * 1000.0))
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+}
+
+impl AddAssign for BatchTimings {
+    fn add_assign(&mut self, other: Self) {
+        self.validation += other.validation;
+        self.partitioning += other.partitioning;
+        self.cache_insert += other.cache_insert;
+        self.persistence += other.persistence;
+    }
+}
+
+impl Display for BatchTimings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "validation: {} µs, partitioning: {} µs, cache insert: {} µs, persistence: {} µs",
+            self.validation.as_micros(),
+            self.partitioning.as_micros(),
+            self.cache_insert.as_micros(),
+            self.persistence.as_micros()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn server_timing_should_contain_all_stages_and_total_in_milliseconds() {
+        let timings = BatchTimings {
+            validation: Duration::from_micros(100),
+            partitioning: Duration::from_micros(20),
+            cache_insert: Duration::from_micros(1500),
+            persistence: Duration::from_millis(3),
+        };
+
+        assert_eq!(
+            timings.to_server_timing(),
+            "validation;dur=0.100, partitioning;dur=0.020, cache_insert;dur=1.500, persistence;dur=3.000, total;dur=4.620"
+        );
+    }
+
+    #[test]
+    fn timings_should_be_summed_per_stage() {
+        let mut timings = BatchTimings {
+            validation: Duration::from_micros(1),
+            partitioning: Duration::from_micros(2),
+            cache_insert: Duration::from_micros(3),
+            persistence: Duration::from_micros(4),
+        };
+
+        timings += timings;
+
+        assert_eq!(timings.validation, Duration::from_micros(2));
+        assert_eq!(timings.partitioning, Duration::from_micros(4));
+        assert_eq!(timings.cache_insert, Duration::from_micros(6));
+        assert_eq!(timings.persistence, Duration::from_micros(8));
+        assert_eq!(timings.total(), Duration::from_micros(20));
+    }
+}
@@ -16,16 +16,148 @@
  * under the License.
  */
 
+use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::diagnostics::batch_timings::BatchTimings;
+use crate::streaming::storage_usage::storage_usage_tracker::StorageUsageLevel;
+use crate::streaming::utils::buffer_pool::BufferPoolMetrics;
+use crate::streaming::utils::request_lanes::RequestLane;
 use prometheus_client::encoding::text::encode;
+use prometheus_client::encoding::{EncodeLabelSet, EncodeLabelValue};
 use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
 use prometheus_client::registry::Registry;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::AtomicU64;
+use std::time::Duration;
 use tracing::error;
 
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct ConsumerGroupLabels {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub group_id: u32,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct StreamLabels {
+    pub stream_id: u32,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct TopicLabels {
+    pub stream_id: u32,
+    pub topic_id: u32,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct PartitionLabels {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct CommandLabels {
+    pub command: String,
+    pub transport: TransportLabel,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct TransportLabels {
+    pub transport: TransportLabel,
+}
+
+/// The transport via which the command was received, shared by the TCP, QUIC and HTTP handlers.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, EncodeLabelValue)]
+pub(crate) enum TransportLabel {
+    Tcp,
+    Quic,
+    Http,
+}
+
+impl Display for TransportLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransportLabel::Tcp => write!(f, "tcp"),
+            TransportLabel::Quic => write!(f, "quic"),
+            TransportLabel::Http => write!(f, "http"),
+        }
+    }
+}
+
+impl From<Transport> for TransportLabel {
+    fn from(transport: Transport) -> Self {
+        match transport {
+            Transport::Tcp => TransportLabel::Tcp,
+            Transport::Quic => TransportLabel::Quic,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct RequestLaneLabels {
+    pub lane: RequestLane,
+}
+
+/// The statistics of the partition, refreshed on demand when the metrics are scraped.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct PartitionMeasurement {
+    pub size_bytes: u64,
+    pub messages: u64,
+    pub segments: u32,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct AppendStageLabels {
+    pub stage: AppendStage,
+}
+
+/// The stage of the write path, see `BatchTimings`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, EncodeLabelValue)]
+pub(crate) enum AppendStage {
+    Validation,
+    Partitioning,
+    CacheInsert,
+    Persistence,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct QuotaLabels {
+    pub direction: QuotaDirection,
+}
+
+/// The direction of the throughput limited by the quota.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, EncodeLabelValue)]
+pub(crate) enum QuotaDirection {
+    Produce,
+    Consume,
+}
+
+/// The lag and the consumption rate of the consumer group, measured by the consumer groups monitor.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct ConsumerGroupMeasurement {
+    pub consumption_rate: f64,
+    pub lag_messages: u64,
+    pub lag_seconds: f64,
+    pub slo_breached: bool,
+}
+
+/// How far the standby server is behind the primary one.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct StandbyLag {
+    pub state_entries: u64,
+    pub messages: u64,
+    pub seconds: f64,
+}
+
 #[derive(Debug)]
 pub(crate) struct Metrics {
     registry: Registry,
     http_requests: Counter,
+    frame_checksum_errors: Counter,
     streams: Gauge,
     topics: Gauge,
     partitions: Gauge,
@@ -33,6 +165,43 @@ pub(crate) struct Metrics {
     messages: Gauge,
     users: Gauge,
     clients: Gauge,
+    cache_size_bytes: Gauge,
+    cache_hits: Gauge,
+    cache_misses: Gauge,
+    cache_evicted_messages: Gauge,
+    cache_prefetched_messages: Gauge,
+    cache_prefetch_hits: Gauge,
+    cache_prefetch_discarded_messages: Gauge,
+    cache_hit_ratio: Gauge<f64, AtomicU64>,
+    batch_buffer_pooled: Gauge,
+    batch_buffer_allocations: Gauge,
+    batch_buffer_reuses: Gauge,
+    topic_messages_in: Family<TopicLabels, Counter>,
+    topic_messages_out: Family<TopicLabels, Counter>,
+    partition_size_bytes: Family<PartitionLabels, Gauge>,
+    partition_messages: Family<PartitionLabels, Gauge>,
+    partition_segments: Family<PartitionLabels, Gauge>,
+    connections: Family<TransportLabels, Gauge>,
+    command_duration_seconds: Family<CommandLabels, Histogram, fn() -> Histogram>,
+    consumer_group_consumption_rate: Family<ConsumerGroupLabels, Gauge<f64, AtomicU64>>,
+    consumer_group_lag_messages: Family<ConsumerGroupLabels, Gauge>,
+    consumer_group_lag_seconds: Family<ConsumerGroupLabels, Gauge<f64, AtomicU64>>,
+    consumer_group_slo_breached: Family<ConsumerGroupLabels, Gauge>,
+    consumer_group_slo_breaches: Family<ConsumerGroupLabels, Counter>,
+    append_stage_seconds: Family<AppendStageLabels, Histogram, fn() -> Histogram>,
+    quota_delayed_requests: Family<QuotaLabels, Counter>,
+    quota_rejected_requests: Family<QuotaLabels, Counter>,
+    disk_usage_bytes: Gauge,
+    stream_disk_usage_bytes: Family<StreamLabels, Gauge>,
+    stream_storage_usage_level: Family<StreamLabels, Gauge>,
+    topic_disk_usage_bytes: Family<TopicLabels, Gauge>,
+    topic_storage_usage_level: Family<TopicLabels, Gauge>,
+    partition_disk_usage_bytes: Family<PartitionLabels, Gauge>,
+    standby_lag_state_entries: Gauge,
+    standby_lag_messages: Gauge,
+    standby_lag_seconds: Gauge<f64, AtomicU64>,
+    request_lane_queue_depth: Family<RequestLaneLabels, Gauge>,
+    request_lane_active_requests: Family<RequestLaneLabels, Gauge>,
 }
 
 impl Metrics {
@@ -40,6 +209,7 @@ impl Metrics {
         let mut metrics = Metrics {
             registry: <Registry>::default(),
             http_requests: Counter::default(),
+            frame_checksum_errors: Counter::default(),
             streams: Gauge::default(),
             topics: Gauge::default(),
             partitions: Gauge::default(),
@@ -47,9 +217,56 @@ impl Metrics {
             messages: Gauge::default(),
             users: Gauge::default(),
             clients: Gauge::default(),
+            cache_size_bytes: Gauge::default(),
+            cache_hits: Gauge::default(),
+            cache_misses: Gauge::default(),
+            cache_evicted_messages: Gauge::default(),
+            cache_prefetched_messages: Gauge::default(),
+            cache_prefetch_hits: Gauge::default(),
+            cache_prefetch_discarded_messages: Gauge::default(),
+            cache_hit_ratio: Gauge::default(),
+            batch_buffer_pooled: Gauge::default(),
+            batch_buffer_allocations: Gauge::default(),
+            batch_buffer_reuses: Gauge::default(),
+            topic_messages_in: Family::default(),
+            topic_messages_out: Family::default(),
+            partition_size_bytes: Family::default(),
+            partition_messages: Family::default(),
+            partition_segments: Family::default(),
+            connections: Family::default(),
+            // From 10 µs up to ~2.6 s.
+            command_duration_seconds: Family::new_with_constructor(|| {
+                Histogram::new(exponential_buckets(0.00001, 4.0, 10))
+            }),
+            consumer_group_consumption_rate: Family::default(),
+            consumer_group_lag_messages: Family::default(),
+            consumer_group_lag_seconds: Family::default(),
+            consumer_group_slo_breached: Family::default(),
+            consumer_group_slo_breaches: Family::default(),
+            // From 10 µs up to ~2.6 s.
+            append_stage_seconds: Family::new_with_constructor(|| {
+                Histogram::new(exponential_buckets(0.00001, 4.0, 10))
+            }),
+            quota_delayed_requests: Family::default(),
+            quota_rejected_requests: Family::default(),
+            disk_usage_bytes: Gauge::default(),
+            stream_disk_usage_bytes: Family::default(),
+            stream_storage_usage_level: Family::default(),
+            topic_disk_usage_bytes: Family::default(),
+            topic_storage_usage_level: Family::default(),
+            partition_disk_usage_bytes: Family::default(),
+            standby_lag_state_entries: Gauge::default(),
+            standby_lag_messages: Gauge::default(),
+            standby_lag_seconds: Gauge::default(),
+            request_lane_queue_depth: Family::default(),
+            request_lane_active_requests: Family::default(),
         };
 
         metrics.register_counter("http_requests", metrics.http_requests.clone());
+        metrics.register_counter(
+            "frame_checksum_errors",
+            metrics.frame_checksum_errors.clone(),
+        );
         metrics.register_gauge("streams", metrics.streams.clone());
         metrics.register_gauge("topics", metrics.topics.clone());
         metrics.register_gauge("partitions", metrics.partitions.clone());
@@ -57,6 +274,172 @@ impl Metrics {
         metrics.register_gauge("messages", metrics.messages.clone());
         metrics.register_gauge("users", metrics.users.clone());
         metrics.register_gauge("clients", metrics.clients.clone());
+        metrics.register_gauge("cache_size_bytes", metrics.cache_size_bytes.clone());
+        metrics.register_gauge("cache_hits", metrics.cache_hits.clone());
+        metrics.register_gauge("cache_misses", metrics.cache_misses.clone());
+        metrics.register_gauge(
+            "cache_evicted_messages",
+            metrics.cache_evicted_messages.clone(),
+        );
+        metrics.register_gauge(
+            "cache_prefetched_messages",
+            metrics.cache_prefetched_messages.clone(),
+        );
+        metrics.register_gauge("cache_prefetch_hits", metrics.cache_prefetch_hits.clone());
+        metrics.register_gauge(
+            "cache_prefetch_discarded_messages",
+            metrics.cache_prefetch_discarded_messages.clone(),
+        );
+        metrics.registry.register(
+            "cache_hit_ratio",
+            "ratio of the cache hits to all the cache lookups",
+            metrics.cache_hit_ratio.clone(),
+        );
+        metrics.registry.register(
+            "batch_buffer_pooled",
+            "number of the idle batch buffers kept in the pool",
+            metrics.batch_buffer_pooled.clone(),
+        );
+        metrics.registry.register(
+            "batch_buffer_allocations",
+            "number of the batch buffers allocated, because none was available in the pool",
+            metrics.batch_buffer_allocations.clone(),
+        );
+        metrics.registry.register(
+            "batch_buffer_reuses",
+            "number of the batch buffers reused from the pool",
+            metrics.batch_buffer_reuses.clone(),
+        );
+        metrics.registry.register(
+            "topic_messages_in",
+            "total count of the messages appended to the topic",
+            metrics.topic_messages_in.clone(),
+        );
+        metrics.registry.register(
+            "topic_messages_out",
+            "total count of the messages polled from the topic",
+            metrics.topic_messages_out.clone(),
+        );
+        metrics.registry.register(
+            "partition_size_bytes",
+            "size of the messages stored in the partition",
+            metrics.partition_size_bytes.clone(),
+        );
+        metrics.registry.register(
+            "partition_messages",
+            "count of the messages stored in the partition",
+            metrics.partition_messages.clone(),
+        );
+        metrics.registry.register(
+            "partition_segments",
+            "count of the segments of the partition",
+            metrics.partition_segments.clone(),
+        );
+        metrics.registry.register(
+            "connections",
+            "count of the connected clients per transport",
+            metrics.connections.clone(),
+        );
+        metrics.registry.register(
+            "command_duration_seconds",
+            "time spent handling the command, per transport",
+            metrics.command_duration_seconds.clone(),
+        );
+        metrics.registry.register(
+            "consumer_group_consumption_rate",
+            "messages consumed per second by the consumer group",
+            metrics.consumer_group_consumption_rate.clone(),
+        );
+        metrics.registry.register(
+            "consumer_group_lag_messages",
+            "messages not yet consumed by the consumer group",
+            metrics.consumer_group_lag_messages.clone(),
+        );
+        metrics.registry.register(
+            "consumer_group_lag_seconds",
+            "age of the oldest message not yet consumed by the consumer group",
+            metrics.consumer_group_lag_seconds.clone(),
+        );
+        metrics.registry.register(
+            "consumer_group_slo_breached",
+            "whether the consumer group exceeds the maximum lag of its SLO",
+            metrics.consumer_group_slo_breached.clone(),
+        );
+        metrics.registry.register(
+            "consumer_group_slo_breaches",
+            "total count of the SLO breaches of the consumer group",
+            metrics.consumer_group_slo_breaches.clone(),
+        );
+        metrics.registry.register(
+            "append_stage_seconds",
+            "time the appended batches spent in each stage of the write path",
+            metrics.append_stage_seconds.clone(),
+        );
+        metrics.registry.register(
+            "quota_delayed_requests",
+            "total count of the requests delayed by the throughput quotas",
+            metrics.quota_delayed_requests.clone(),
+        );
+        metrics.registry.register(
+            "quota_rejected_requests",
+            "total count of the requests rejected by the throughput quotas",
+            metrics.quota_rejected_requests.clone(),
+        );
+        metrics.registry.register(
+            "disk_usage_bytes",
+            "size of the log and index files of all the streams stored on the local disk",
+            metrics.disk_usage_bytes.clone(),
+        );
+        metrics.registry.register(
+            "stream_disk_usage_bytes",
+            "size of the log and index files of the stream stored on the local disk",
+            metrics.stream_disk_usage_bytes.clone(),
+        );
+        metrics.registry.register(
+            "stream_storage_usage_level",
+            "disk usage of the stream relative to its limits: 0 - normal, 1 - soft limit exceeded, 2 - hard limit exceeded",
+            metrics.stream_storage_usage_level.clone(),
+        );
+        metrics.registry.register(
+            "topic_disk_usage_bytes",
+            "size of the log and index files of the topic stored on the local disk",
+            metrics.topic_disk_usage_bytes.clone(),
+        );
+        metrics.registry.register(
+            "topic_storage_usage_level",
+            "disk usage of the topic relative to its limits: 0 - normal, 1 - soft limit exceeded, 2 - hard limit exceeded",
+            metrics.topic_storage_usage_level.clone(),
+        );
+        metrics.registry.register(
+            "partition_disk_usage_bytes",
+            "size of the log and index files of the partition stored on the local disk",
+            metrics.partition_disk_usage_bytes.clone(),
+        );
+        metrics.registry.register(
+            "standby_lag_state_entries",
+            "state entries of the primary server not yet replicated by the standby",
+            metrics.standby_lag_state_entries.clone(),
+        );
+        metrics.registry.register(
+            "standby_lag_messages",
+            "messages of the primary server not yet replicated by the standby",
+            metrics.standby_lag_messages.clone(),
+        );
+        metrics.registry.register(
+            "standby_lag_seconds",
+            "time since the standby has last caught up with the primary server",
+            metrics.standby_lag_seconds.clone(),
+        );
+        metrics.registry.register(
+            "request_lane_queue_depth",
+            "number of the commands waiting for the free slot of the request lane",
+            metrics.request_lane_queue_depth.clone(),
+        );
+        metrics.registry.register(
+            "request_lane_active_requests",
+            "number of the commands being processed in the request lane",
+            metrics.request_lane_active_requests.clone(),
+        );
 
         metrics
     }
@@ -72,6 +455,27 @@ impl Metrics {
     }
 
     pub fn get_formatted_output(&self) -> String {
+        // The cache is tracked globally, so its metrics are read on demand.
+        if let Some(memory_tracker) = CacheMemoryTracker::get_instance() {
+            let cache_metrics = memory_tracker.metrics();
+            self.cache_size_bytes.set(cache_metrics.used_bytes as i64);
+            self.cache_hits.set(cache_metrics.hits as i64);
+            self.cache_misses.set(cache_metrics.misses as i64);
+            self.cache_evicted_messages
+                .set(cache_metrics.evicted_messages as i64);
+            self.cache_prefetched_messages
+                .set(cache_metrics.prefetched_messages as i64);
+            self.cache_prefetch_hits
+                .set(cache_metrics.prefetch_hits as i64);
+            self.cache_prefetch_discarded_messages
+                .set(cache_metrics.prefetch_discarded_messages as i64);
+            let lookups = cache_metrics.hits + cache_metrics.misses;
+            if lookups > 0 {
+                self.cache_hit_ratio
+                    .set(cache_metrics.hits as f64 / lookups as f64);
+            }
+        }
+
         let mut buffer = String::new();
         if let Err(err) = encode(&mut buffer, &self.registry) {
             error!("Failed to encode metrics: {}", err);
@@ -83,6 +487,10 @@ impl Metrics {
         self.http_requests.inc();
     }
 
+    pub fn increment_frame_checksum_errors(&self) {
+        self.frame_checksum_errors.inc();
+    }
+
     pub fn increment_streams(&self, count: u32) {
         self.streams.inc_by(count as i64);
     }
@@ -138,4 +546,300 @@ impl Metrics {
     pub fn decrement_clients(&self, count: u32) {
         self.clients.dec_by(count as i64);
     }
+
+    pub fn increment_connections(&self, transport: TransportLabel) {
+        self.connections
+            .get_or_create(&TransportLabels { transport })
+            .inc();
+    }
+
+    pub fn decrement_connections(&self, transport: TransportLabel) {
+        self.connections
+            .get_or_create(&TransportLabels { transport })
+            .dec();
+    }
+
+    pub fn increment_topic_messages_in(&self, labels: &TopicLabels, count: u32) {
+        self.topic_messages_in
+            .get_or_create(labels)
+            .inc_by(count as u64);
+    }
+
+    pub fn increment_topic_messages_out(&self, labels: &TopicLabels, count: u32) {
+        self.topic_messages_out
+            .get_or_create(labels)
+            .inc_by(count as u64);
+    }
+
+    pub fn remove_topic(&self, labels: &TopicLabels) {
+        self.topic_messages_in.remove(labels);
+        self.topic_messages_out.remove(labels);
+    }
+
+    /// Removes the series of all the partitions, so that the deleted ones are not reported after the next refresh.
+    pub fn clear_partitions(&self) {
+        self.partition_size_bytes.clear();
+        self.partition_messages.clear();
+        self.partition_segments.clear();
+    }
+
+    pub fn set_partition_measurement(
+        &self,
+        labels: &PartitionLabels,
+        measurement: &PartitionMeasurement,
+    ) {
+        self.partition_size_bytes
+            .get_or_create(labels)
+            .set(measurement.size_bytes as i64);
+        self.partition_messages
+            .get_or_create(labels)
+            .set(measurement.messages as i64);
+        self.partition_segments
+            .get_or_create(labels)
+            .set(measurement.segments as i64);
+    }
+
+    pub fn record_command(&self, command: &str, transport: TransportLabel, duration: Duration) {
+        self.command_duration_seconds
+            .get_or_create(&CommandLabels {
+                command: command.to_owned(),
+                transport,
+            })
+            .observe(duration.as_secs_f64());
+    }
+
+    pub fn set_consumer_group_measurement(
+        &self,
+        labels: &ConsumerGroupLabels,
+        measurement: &ConsumerGroupMeasurement,
+    ) {
+        self.consumer_group_consumption_rate
+            .get_or_create(labels)
+            .set(measurement.consumption_rate);
+        self.consumer_group_lag_messages
+            .get_or_create(labels)
+            .set(measurement.lag_messages as i64);
+        self.consumer_group_lag_seconds
+            .get_or_create(labels)
+            .set(measurement.lag_seconds);
+        self.consumer_group_slo_breached
+            .get_or_create(labels)
+            .set(measurement.slo_breached as i64);
+    }
+
+    pub fn record_batch_timings(&self, timings: &BatchTimings) {
+        for (stage, duration) in [
+            (AppendStage::Validation, timings.validation),
+            (AppendStage::Partitioning, timings.partitioning),
+            (AppendStage::CacheInsert, timings.cache_insert),
+            (AppendStage::Persistence, timings.persistence),
+        ] {
+            self.append_stage_seconds
+                .get_or_create(&AppendStageLabels { stage })
+                .observe(duration.as_secs_f64());
+        }
+    }
+
+    pub fn set_buffer_pool_metrics(&self, pool_metrics: &BufferPoolMetrics) {
+        self.batch_buffer_pooled
+            .set(pool_metrics.pooled_buffers as i64);
+        self.batch_buffer_allocations
+            .set(pool_metrics.allocations as i64);
+        self.batch_buffer_reuses.set(pool_metrics.reuses as i64);
+    }
+
+    pub fn set_standby_lag(&self, lag: &StandbyLag) {
+        self.standby_lag_state_entries.set(lag.state_entries as i64);
+        self.standby_lag_messages.set(lag.messages as i64);
+        self.standby_lag_seconds.set(lag.seconds);
+    }
+
+    pub fn set_request_lane_measurement(
+        &self,
+        lane: RequestLane,
+        queue_depth: usize,
+        active: usize,
+    ) {
+        let labels = RequestLaneLabels { lane };
+        self.request_lane_queue_depth
+            .get_or_create(&labels)
+            .set(queue_depth as i64);
+        self.request_lane_active_requests
+            .get_or_create(&labels)
+            .set(active as i64);
+    }
+
+    pub fn increment_consumer_group_slo_breaches(&self, labels: &ConsumerGroupLabels) {
+        self.consumer_group_slo_breaches.get_or_create(labels).inc();
+    }
+
+    pub fn increment_quota_delayed_requests(&self, direction: QuotaDirection) {
+        self.quota_delayed_requests
+            .get_or_create(&QuotaLabels { direction })
+            .inc();
+    }
+
+    pub fn increment_quota_rejected_requests(&self, direction: QuotaDirection) {
+        self.quota_rejected_requests
+            .get_or_create(&QuotaLabels { direction })
+            .inc();
+    }
+
+    /// Removes the disk usage series, so that the deleted streams, topics and partitions are not reported after the next measurement.
+    pub fn clear_storage_usage(&self) {
+        self.stream_disk_usage_bytes.clear();
+        self.stream_storage_usage_level.clear();
+        self.topic_disk_usage_bytes.clear();
+        self.topic_storage_usage_level.clear();
+        self.partition_disk_usage_bytes.clear();
+    }
+
+    pub fn set_disk_usage(&self, disk_usage_bytes: u64) {
+        self.disk_usage_bytes.set(disk_usage_bytes as i64);
+    }
+
+    pub fn set_stream_storage_usage(
+        &self,
+        labels: &StreamLabels,
+        disk_usage_bytes: u64,
+        level: StorageUsageLevel,
+    ) {
+        self.stream_disk_usage_bytes
+            .get_or_create(labels)
+            .set(disk_usage_bytes as i64);
+        self.stream_storage_usage_level
+            .get_or_create(labels)
+            .set(Self::storage_usage_level_value(level));
+    }
+
+    pub fn set_topic_storage_usage(
+        &self,
+        labels: &TopicLabels,
+        disk_usage_bytes: u64,
+        level: StorageUsageLevel,
+    ) {
+        self.topic_disk_usage_bytes
+            .get_or_create(labels)
+            .set(disk_usage_bytes as i64);
+        self.topic_storage_usage_level
+            .get_or_create(labels)
+            .set(Self::storage_usage_level_value(level));
+    }
+
+    pub fn set_partition_disk_usage(&self, labels: &PartitionLabels, disk_usage_bytes: u64) {
+        self.partition_disk_usage_bytes
+            .get_or_create(labels)
+            .set(disk_usage_bytes as i64);
+    }
+
+    fn storage_usage_level_value(level: StorageUsageLevel) -> i64 {
+        match level {
+            StorageUsageLevel::Normal => 0,
+            StorageUsageLevel::SoftLimitExceeded => 1,
+            StorageUsageLevel::HardLimitExceeded => 2,
+        }
+    }
+
+    pub fn remove_consumer_group(&self, labels: &ConsumerGroupLabels) {
+        self.consumer_group_consumption_rate.remove(labels);
+        self.consumer_group_lag_messages.remove(labels);
+        self.consumer_group_lag_seconds.remove(labels);
+        self.consumer_group_slo_breached.remove(labels);
+        self.consumer_group_slo_breaches.remove(labels);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_export_topic_and_partition_series() {
+        let metrics = Metrics::init();
+        let topic = TopicLabels {
+            stream_id: 1,
+            topic_id: 2,
+        };
+        metrics.increment_topic_messages_in(&topic, 3);
+        metrics.increment_topic_messages_out(&topic, 2);
+        metrics.set_partition_measurement(
+            &PartitionLabels {
+                stream_id: 1,
+                topic_id: 2,
+                partition_id: 3,
+            },
+            &PartitionMeasurement {
+                size_bytes: 1024,
+                messages: 3,
+                segments: 1,
+            },
+        );
+
+        let output = metrics.get_formatted_output();
+
+        assert!(output.contains("topic_messages_in_total{stream_id=\"1\",topic_id=\"2\"} 3"));
+        assert!(output.contains("topic_messages_out_total{stream_id=\"1\",topic_id=\"2\"} 2"));
+        assert!(output.contains(
+            "partition_size_bytes{stream_id=\"1\",topic_id=\"2\",partition_id=\"3\"} 1024"
+        ));
+        assert!(output
+            .contains("partition_segments{stream_id=\"1\",topic_id=\"2\",partition_id=\"3\"} 1"));
+    }
+
+    #[test]
+    fn should_remove_series_of_deleted_topic_and_partitions() {
+        let metrics = Metrics::init();
+        let topic = TopicLabels {
+            stream_id: 1,
+            topic_id: 2,
+        };
+        metrics.increment_topic_messages_in(&topic, 3);
+        metrics.set_partition_measurement(
+            &PartitionLabels {
+                stream_id: 1,
+                topic_id: 2,
+                partition_id: 3,
+            },
+            &PartitionMeasurement::default(),
+        );
+
+        metrics.remove_topic(&topic);
+        metrics.clear_partitions();
+
+        let output = metrics.get_formatted_output();
+        assert!(!output.contains("stream_id=\"1\""));
+    }
+
+    #[test]
+    fn should_export_command_durations_and_connections_per_transport() {
+        let metrics = Metrics::init();
+        metrics.record_command("ping", TransportLabel::Tcp, Duration::from_millis(1));
+        metrics.record_command("GET /stats", TransportLabel::Http, Duration::from_millis(2));
+        metrics.increment_connections(TransportLabel::Quic);
+        metrics.increment_connections(TransportLabel::Quic);
+        metrics.decrement_connections(TransportLabel::Quic);
+
+        let output = metrics.get_formatted_output();
+
+        assert!(
+            output.contains("command_duration_seconds_count{command=\"ping\",transport=\"Tcp\"} 1")
+        );
+        assert!(output.contains(
+            "command_duration_seconds_count{command=\"GET /stats\",transport=\"Http\"} 1"
+        ));
+        assert!(output.contains("connections{transport=\"Quic\"} 1"));
+    }
+
+    #[test]
+    fn should_export_queue_depth_per_request_lane() {
+        let metrics = Metrics::init();
+        metrics.set_request_lane_measurement(RequestLane::Admin, 0, 1);
+        metrics.set_request_lane_measurement(RequestLane::Data, 3, 8);
+
+        let output = metrics.get_formatted_output();
+
+        assert!(output.contains("request_lane_queue_depth{lane=\"Admin\"} 0"));
+        assert!(output.contains("request_lane_queue_depth{lane=\"Data\"} 3"));
+        assert!(output.contains("request_lane_active_requests{lane=\"Data\"} 8"));
+    }
 }
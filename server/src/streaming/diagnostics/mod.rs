@@ -16,4 +16,5 @@
  * under the License.
  */
 
+pub mod batch_timings;
 pub mod metrics;
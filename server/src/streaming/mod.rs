@@ -27,11 +27,16 @@ pub mod partitions;
 pub mod persistence;
 pub mod personal_access_tokens;
 pub mod polling_consumer;
+pub mod quotas;
+pub mod schemas;
 pub mod segments;
 pub mod session;
 pub mod storage;
+pub mod storage_usage;
 pub mod streams;
 pub mod systems;
+pub mod tenants;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 pub mod utils;
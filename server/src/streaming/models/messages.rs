@@ -23,10 +23,15 @@ use bytes::{BufMut, Bytes, BytesMut};
 use error_set::ErrContext;
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::error::IggyError;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::message_ttl;
 use iggy::models::messages::PolledMessage;
+use iggy::models::transaction::{self, TransactionMarker};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::checksum;
+use iggy::utils::duration::IggyDuration;
 use iggy::utils::sizeable::Sizeable;
+use iggy::utils::timestamp::IggyTimestamp;
 use iggy::{messages::send_messages::Message, models::messages::MessageState};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -40,6 +45,7 @@ pub struct PolledMessages {
     pub partition_id: u32,
     pub current_offset: u64,
     pub messages: Vec<Arc<PolledMessage>>,
+    pub next_offset: Option<u64>,
 }
 
 #[derive(Debug)]
@@ -71,6 +77,29 @@ impl RetainedMessage {
 }
 
 impl RetainedMessage {
+    /// Returns the time to live of the message, if it was set by the producer.
+    pub fn ttl(&self) -> Option<IggyDuration> {
+        let headers = HashMap::<HeaderKey, HeaderValue>::from_bytes(self.headers.clone()?).ok()?;
+        message_ttl::get_message_ttl(&headers)
+    }
+
+    /// Checks if the message has the time to live, which has already elapsed.
+    pub fn is_expired(&self, now: IggyTimestamp) -> bool {
+        self.ttl()
+            .is_some_and(|ttl| message_ttl::is_message_expired(self.timestamp, ttl, now))
+    }
+
+    /// Returns the ID of the transaction within which the message was sent, and the transaction marker if it's the control message
+    /// appended by the server once the transaction was completed. Returns `None` if the message wasn't sent within the transaction.
+    pub fn transaction(&self) -> Option<(u64, Option<TransactionMarker>)> {
+        let headers = HashMap::<HeaderKey, HeaderValue>::from_bytes(self.headers.clone()?).ok()?;
+        let transaction_id = transaction::get_transaction_id(&headers)?;
+        Some((
+            transaction_id,
+            transaction::get_transaction_marker(&headers),
+        ))
+    }
+
     pub fn new(offset: u64, timestamp: u64, message: Message) -> Self {
         RetainedMessage {
             offset,
@@ -88,7 +117,6 @@ impl RetainedMessage {
         let id = self.id;
         let offset = self.offset;
         let timestamp = self.timestamp;
-        let payload = self.payload.clone();
         let checksum = self.checksum;
         let message_state = self.message_state;
         let headers = &self.headers;
@@ -106,7 +134,7 @@ impl RetainedMessage {
         } else {
             bytes.put_u32_le(0u32);
         }
-        bytes.put_slice(&payload);
+        bytes.put_slice(&self.payload);
     }
 
     pub fn try_from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
@@ -16,13 +16,19 @@
  * under the License.
  */
 
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::partition::{
+    AutoCommitState, ConsumerGroupLag, ConsumerOffset, Partition,
+};
 use crate::streaming::partitions::COMPONENT;
 use crate::streaming::polling_consumer::PollingConsumer;
+use ahash::AHashMap;
 use dashmap::DashMap;
 use error_set::ErrContext;
 use iggy::consumer::ConsumerKind;
 use iggy::error::IggyError;
+use iggy::messages::AutoCommitMode;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::BTreeMap;
 use tracing::trace;
 
 impl Partition {
@@ -93,6 +99,10 @@ impl Partition {
         consumer_id: u32,
         offset: u64,
     ) -> Result<(), IggyError> {
+        if let Some(mut auto_commit) = self.get_auto_commits(kind).get_mut(&consumer_id) {
+            auto_commit.delivered_offset = None;
+        }
+
         let consumer_offsets = self.get_consumer_offsets(kind);
         if let Some(mut consumer_offset) = consumer_offsets.get_mut(&consumer_id) {
             consumer_offset.offset = offset;
@@ -178,6 +188,213 @@ impl Partition {
         }
     }
 
+    fn get_delivery_attempts(&self, kind: ConsumerKind) -> &DashMap<u32, AHashMap<u64, u32>> {
+        match kind {
+            ConsumerKind::Consumer => &self.consumer_delivery_attempts,
+            ConsumerKind::ConsumerGroup => &self.consumer_group_delivery_attempts,
+        }
+    }
+
+    /// Increments the delivery attempts of the message with the given offset for the consumer, returning the updated value.
+    pub fn register_delivery_failure(
+        &self,
+        consumer: PollingConsumer,
+        offset: u64,
+    ) -> Result<u32, IggyError> {
+        if offset > self.current_offset {
+            return Err(IggyError::InvalidOffset(offset));
+        }
+
+        let (kind, consumer_id) = Self::get_consumer_kind_and_id(consumer);
+        let mut delivery_attempts = self
+            .get_delivery_attempts(kind)
+            .entry(consumer_id)
+            .or_default();
+        let attempts = delivery_attempts.entry(offset).or_insert(0);
+        *attempts += 1;
+        let attempts = *attempts;
+        trace!(
+            "Registered delivery failure: {attempts} for {consumer}, offset: {offset}, partition: {}.",
+            self.partition_id
+        );
+        Ok(attempts)
+    }
+
+    /// Returns the number of the failed delivery attempts of the message with the given offset for the consumer.
+    pub fn get_delivery_attempts_count(&self, consumer: PollingConsumer, offset: u64) -> u32 {
+        let (kind, consumer_id) = Self::get_consumer_kind_and_id(consumer);
+        self.get_delivery_attempts(kind)
+            .get(&consumer_id)
+            .and_then(|delivery_attempts| delivery_attempts.get(&offset).copied())
+            .unwrap_or_default()
+    }
+
+    /// Removes the tracked delivery attempts of the message with the given offset, e.g. once it's been moved to the dead letter topic.
+    pub fn clear_delivery_attempts(&self, consumer: PollingConsumer, offset: u64) {
+        let (kind, consumer_id) = Self::get_consumer_kind_and_id(consumer);
+        let delivery_attempts = self.get_delivery_attempts(kind);
+        if let Some(mut attempts) = delivery_attempts.get_mut(&consumer_id) {
+            attempts.remove(&offset);
+        }
+        delivery_attempts.remove_if(&consumer_id, |_, attempts| attempts.is_empty());
+        self.cancel_redelivery(consumer, offset);
+    }
+
+    fn get_redeliveries(&self, kind: ConsumerKind) -> &DashMap<u32, BTreeMap<u64, IggyTimestamp>> {
+        match kind {
+            ConsumerKind::Consumer => &self.consumer_redeliveries,
+            ConsumerKind::ConsumerGroup => &self.consumer_group_redeliveries,
+        }
+    }
+
+    /// Schedules the redelivery of the message with the given offset for the consumer, once the given timestamp has been reached.
+    pub fn schedule_redelivery(
+        &self,
+        consumer: PollingConsumer,
+        offset: u64,
+        redeliver_at: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        if offset > self.current_offset {
+            return Err(IggyError::InvalidOffset(offset));
+        }
+
+        let (kind, consumer_id) = Self::get_consumer_kind_and_id(consumer);
+        self.get_redeliveries(kind)
+            .entry(consumer_id)
+            .or_default()
+            .insert(offset, redeliver_at);
+        trace!(
+            "Scheduled redelivery at: {redeliver_at} for {consumer}, offset: {offset}, partition: {}.",
+            self.partition_id
+        );
+        Ok(())
+    }
+
+    /// Removes and returns (ordered by offset) up to `count` offsets of the messages which are due for the redelivery to the consumer.
+    pub fn take_due_redeliveries(
+        &self,
+        consumer: PollingConsumer,
+        now: IggyTimestamp,
+        count: u32,
+    ) -> Vec<u64> {
+        let (kind, consumer_id) = Self::get_consumer_kind_and_id(consumer);
+        let redeliveries = self.get_redeliveries(kind);
+        let Some(mut scheduled) = redeliveries.get_mut(&consumer_id) else {
+            return Vec::new();
+        };
+
+        let offsets = scheduled
+            .iter()
+            .filter(|(_, redeliver_at)| redeliver_at.as_micros() <= now.as_micros())
+            .map(|(offset, _)| *offset)
+            .take(count as usize)
+            .collect::<Vec<_>>();
+        for offset in &offsets {
+            scheduled.remove(offset);
+        }
+        drop(scheduled);
+        redeliveries.remove_if(&consumer_id, |_, scheduled| scheduled.is_empty());
+        offsets
+    }
+
+    /// Cancels the scheduled redelivery of the message with the given offset, if any.
+    pub fn cancel_redelivery(&self, consumer: PollingConsumer, offset: u64) {
+        let (kind, consumer_id) = Self::get_consumer_kind_and_id(consumer);
+        let redeliveries = self.get_redeliveries(kind);
+        if let Some(mut scheduled) = redeliveries.get_mut(&consumer_id) {
+            scheduled.remove(&offset);
+        }
+        redeliveries.remove_if(&consumer_id, |_, scheduled| scheduled.is_empty());
+    }
+
+    fn get_auto_commits(&self, kind: ConsumerKind) -> &DashMap<u32, AutoCommitState> {
+        match kind {
+            ConsumerKind::Consumer => &self.consumer_auto_commits,
+            ConsumerKind::ConsumerGroup => &self.consumer_group_auto_commits,
+        }
+    }
+
+    /// Returns the offset of the last message delivered to the consumer, which is yet to be committed by the server.
+    pub fn get_delivered_offset(&self, consumer: PollingConsumer) -> Option<u64> {
+        let (kind, consumer_id) = Self::get_consumer_kind_and_id(consumer);
+        self.get_auto_commits(kind)
+            .get(&consumer_id)
+            .and_then(|auto_commit| auto_commit.delivered_offset)
+    }
+
+    /// Auto-commits the offset of the last message polled by the consumer depending on the mode.
+    /// In the `AfterPoll` and `Interval` modes, the offset is only tracked as delivered, and committed once the consumer polls again.
+    pub async fn auto_commit_offset(
+        &self,
+        consumer: PollingConsumer,
+        mode: AutoCommitMode,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        match mode {
+            AutoCommitMode::Manual => Ok(()),
+            AutoCommitMode::BeforeDelivery => self.store_consumer_offset(consumer, offset).await,
+            AutoCommitMode::AfterPoll | AutoCommitMode::Interval(_) => {
+                let (kind, consumer_id) = Self::get_consumer_kind_and_id(consumer);
+                self.get_auto_commits(kind)
+                    .entry(consumer_id)
+                    .or_default()
+                    .delivered_offset = Some(offset);
+                trace!(
+                    "Delivered offset: {offset} will be committed on the next poll for {consumer}, partition: {}.",
+                    self.partition_id
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Commits the offset delivered by the previous poll, once the consumer polls again using the `AfterPoll` or `Interval` mode.
+    /// In the other modes, the delivered offset is discarded, as it's no longer going to be committed by the server.
+    pub async fn commit_delivered_offset(
+        &self,
+        consumer: PollingConsumer,
+        mode: AutoCommitMode,
+        now: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        let (kind, consumer_id) = Self::get_consumer_kind_and_id(consumer);
+        let Some(mut auto_commit) = self.get_auto_commits(kind).get_mut(&consumer_id) else {
+            return Ok(());
+        };
+        let Some(offset) = auto_commit.delivered_offset else {
+            return Ok(());
+        };
+
+        let should_commit = match mode {
+            AutoCommitMode::Manual | AutoCommitMode::BeforeDelivery => {
+                auto_commit.delivered_offset = None;
+                false
+            }
+            AutoCommitMode::AfterPoll => true,
+            AutoCommitMode::Interval(interval) => match auto_commit.committed_at {
+                Some(committed_at) => {
+                    now.as_micros() >= committed_at.as_micros() + interval.as_micros()
+                }
+                None => true,
+            },
+        };
+        if !should_commit {
+            return Ok(());
+        }
+
+        auto_commit.committed_at = Some(now);
+        drop(auto_commit);
+        self.store_consumer_offset(consumer, offset).await
+    }
+
+    fn get_consumer_kind_and_id(consumer: PollingConsumer) -> (ConsumerKind, u32) {
+        match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => (ConsumerKind::Consumer, consumer_id),
+            PollingConsumer::ConsumerGroup(consumer_id, _) => {
+                (ConsumerKind::ConsumerGroup, consumer_id)
+            }
+        }
+    }
+
     fn log_consumer_offset(&self, consumer_offset: &ConsumerOffset) {
         trace!("Loaded consumer offset value: {} for {} with ID: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
                 consumer_offset.offset,
@@ -203,6 +420,9 @@ impl Partition {
                     .consumer_offsets
                     .remove(&consumer_id)
                     .ok_or(IggyError::ConsumerOffsetNotFound(consumer_id))?;
+                self.consumer_delivery_attempts.remove(&consumer_id);
+                self.consumer_redeliveries.remove(&consumer_id);
+                self.consumer_auto_commits.remove(&consumer_id);
                 self.storage.partition.delete_consumer_offset(&offset.path).await
                     .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete consumer offset, consumer ID: {consumer_id}, partition ID: {partition_id}"))?;
             }
@@ -211,6 +431,9 @@ impl Partition {
                     .consumer_group_offsets
                     .remove(&consumer_id)
                     .ok_or(IggyError::ConsumerOffsetNotFound(consumer_id))?;
+                self.consumer_group_delivery_attempts.remove(&consumer_id);
+                self.consumer_group_redeliveries.remove(&consumer_id);
+                self.consumer_group_auto_commits.remove(&consumer_id);
                 self.storage.partition.delete_consumer_offset(&offset.path).await
                     .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete consumer group offset, consumer ID: {consumer_id}, partition ID: {partition_id}"))?;
             }
@@ -218,4 +441,39 @@ impl Partition {
         trace!("Deleted consumer offset for consumer: {consumer}, partition ID: {partition_id}.");
         Ok(())
     }
+
+    pub async fn get_consumer_group_lag(
+        &self,
+        group_id: u32,
+    ) -> Result<ConsumerGroupLag, IggyError> {
+        let next_offset = self
+            .consumer_group_offsets
+            .get(&group_id)
+            .map(|consumer_offset| consumer_offset.offset + 1)
+            .unwrap_or(0);
+        let end_offset = match self.should_increment_offset {
+            true => self.current_offset + 1,
+            false => 0,
+        };
+        let lag_messages = end_offset.saturating_sub(next_offset);
+        if lag_messages == 0 {
+            return Ok(ConsumerGroupLag {
+                consumed_messages: next_offset,
+                lag_messages,
+                oldest_pending_timestamp: None,
+            });
+        }
+
+        let oldest_pending_timestamp = self
+            .get_messages_by_offset(next_offset, 1)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get oldest pending message for consumer group ID: {group_id}, partition ID: {}", self.partition_id))?
+            .first()
+            .map(|message| message.timestamp);
+        Ok(ConsumerGroupLag {
+            consumed_messages: next_offset,
+            lag_messages,
+            oldest_pending_timestamp,
+        })
+    }
 }
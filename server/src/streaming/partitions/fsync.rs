@@ -0,0 +1,207 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::fsync_policy::FsyncPolicy;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::atomic::Ordering;
+use tracing::trace;
+
+impl Partition {
+    /// Sets the fsync policy of the parent topic, which replaces the fsync after every write
+    /// (enforced by the server configuration) with the explicit sync once the policy thresholds are exceeded.
+    pub fn set_fsync_policy(&mut self, fsync_policy: Option<FsyncPolicy>) {
+        self.fsync_policy = fsync_policy;
+        let enforce_fsync = self.enforces_fsync();
+        for segment in self.segments.iter_mut() {
+            segment.set_enforce_fsync(enforce_fsync);
+        }
+        self.unsynced_messages_count = 0;
+    }
+
+    /// Returns true if the segments are synced after every write, which is the case only for the topics without the fsync policy.
+    pub fn enforces_fsync(&self) -> bool {
+        self.fsync_policy.is_none() && self.config.partition.enforce_fsync
+    }
+
+    /// Syncs the last segment once the persisted messages exceed either of the thresholds of the fsync policy.
+    pub(crate) async fn fsync_if_required(
+        &mut self,
+        persisted_messages_count: u32,
+        now: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        let Some(fsync_policy) = &self.fsync_policy else {
+            return Ok(());
+        };
+
+        self.unsynced_messages_count += persisted_messages_count;
+        if !is_fsync_due(
+            fsync_policy,
+            self.unsynced_messages_count,
+            self.last_fsync_at,
+            now,
+        ) {
+            return Ok(());
+        }
+
+        self.fsync_last_segment(now).await
+    }
+
+    /// Returns true if the messages persisted since the last sync exceed either of the thresholds of the fsync policy.
+    pub fn should_fsync(&self, now: IggyTimestamp) -> bool {
+        self.fsync_policy.as_ref().is_some_and(|fsync_policy| {
+            is_fsync_due(
+                fsync_policy,
+                self.unsynced_messages_count,
+                self.last_fsync_at,
+                now,
+            )
+        })
+    }
+
+    /// Syncs the last segment once the fsync policy is due, e.g. its interval has elapsed since the last sync, and returns the count of the synced messages.
+    /// Used by the background fsync timer, as the interval may elapse long after the last append to the partition.
+    pub async fn fsync_if_due(&mut self, now: IggyTimestamp) -> Result<u32, IggyError> {
+        if !self.should_fsync(now) {
+            return Ok(0);
+        }
+
+        let synced_messages_count = self.unsynced_messages_count;
+        self.fsync_last_segment(now).await?;
+        Ok(synced_messages_count)
+    }
+
+    /// Syncs the messages persisted since the last sync regardless of the thresholds, used before the last segment is replaced.
+    pub(crate) async fn fsync_unsynced_messages(
+        &mut self,
+        now: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        let syncs_explicitly = self
+            .fsync_policy
+            .as_ref()
+            .is_some_and(|policy| !policy.is_never());
+        if !syncs_explicitly || self.unsynced_messages_count == 0 {
+            return Ok(());
+        }
+
+        self.fsync_last_segment(now).await
+    }
+
+    /// Syncs the last segment unless it's already synced after every write, used when the producer awaits the persisted messages.
+    pub(crate) async fn fsync_persisted_messages(
+        &mut self,
+        now: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        if self.enforces_fsync() {
+            return Ok(());
+        }
+
+        self.fsync_last_segment(now).await
+    }
+
+    async fn fsync_last_segment(&mut self, now: IggyTimestamp) -> Result<(), IggyError> {
+        let last_segment = self.segments.last().ok_or(IggyError::SegmentNotFound)?;
+        last_segment.fsync().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to fsync segment: {last_segment}")
+        })?;
+        trace!(
+            "Synced {} message(s) of segment with start offset: {} for partition with ID: {}.",
+            self.unsynced_messages_count,
+            last_segment.start_offset,
+            self.partition_id
+        );
+        self.unsynced_messages_count = 0;
+        self.last_fsync_at = now;
+        self.storage.fsyncs_count.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+fn is_fsync_due(
+    fsync_policy: &FsyncPolicy,
+    unsynced_messages_count: u32,
+    last_fsync_at: IggyTimestamp,
+    now: IggyTimestamp,
+) -> bool {
+    if unsynced_messages_count == 0 {
+        return false;
+    }
+
+    if fsync_policy
+        .every_n_messages
+        .is_some_and(|count| unsynced_messages_count >= count)
+    {
+        return true;
+    }
+
+    fsync_policy
+        .interval
+        .is_some_and(|interval| now.as_micros() >= last_fsync_at.as_micros() + interval.as_micros())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+
+    #[test]
+    fn fsync_should_be_due_once_messages_count_is_exceeded() {
+        let policy = FsyncPolicy::every_n_messages(10);
+        let now = IggyTimestamp::from(1_000_000);
+        assert!(!is_fsync_due(&policy, 9, now, now));
+        assert!(is_fsync_due(&policy, 10, now, now));
+    }
+
+    #[test]
+    fn fsync_should_be_due_once_interval_elapsed() {
+        let policy = FsyncPolicy::interval(IggyDuration::new_from_secs(1));
+        let last_fsync_at = IggyTimestamp::from(1_000_000);
+        assert!(!is_fsync_due(
+            &policy,
+            1,
+            last_fsync_at,
+            IggyTimestamp::from(1_999_999)
+        ));
+        assert!(is_fsync_due(
+            &policy,
+            1,
+            last_fsync_at,
+            IggyTimestamp::from(2_000_000)
+        ));
+        assert!(!is_fsync_due(
+            &policy,
+            0,
+            last_fsync_at,
+            IggyTimestamp::from(2_000_000)
+        ));
+    }
+
+    #[test]
+    fn fsync_should_never_be_due_without_thresholds() {
+        let policy = FsyncPolicy::never();
+        assert!(!is_fsync_due(
+            &policy,
+            u32::MAX,
+            IggyTimestamp::from(0),
+            IggyTimestamp::from(u64::MAX / 2)
+        ));
+    }
+}
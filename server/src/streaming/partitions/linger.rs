@@ -0,0 +1,165 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use tokio::sync::watch;
+
+/// Coalesces the small appends to the partition into larger disk writes, by holding the buffered messages
+/// for up to the linger time, or until their size exceeds the maximum accumulation size.
+///
+/// Every write of the buffered messages bumps the flush epoch, which the producers requiring the `persisted`
+/// confirmation level wait for, once they've released the partition lock.
+#[derive(Debug)]
+pub struct MessagesLinger {
+    linger_time: IggyDuration,
+    max_accumulation_size: u64,
+    accumulated_size: u64,
+    first_appended_at: Option<IggyTimestamp>,
+    flush_epoch: watch::Sender<u64>,
+}
+
+impl MessagesLinger {
+    pub fn new(linger_time: IggyDuration, max_accumulation_size: IggyByteSize) -> Self {
+        let (flush_epoch, _) = watch::channel(0);
+        Self {
+            linger_time,
+            max_accumulation_size: max_accumulation_size.as_bytes_u64(),
+            accumulated_size: 0,
+            first_appended_at: None,
+            flush_epoch,
+        }
+    }
+
+    /// Records the append of the messages of the given size, which are buffered until the next flush.
+    pub fn record_append(&mut self, size_bytes: u64, now: IggyTimestamp) {
+        self.accumulated_size += size_bytes;
+        self.first_appended_at.get_or_insert(now);
+    }
+
+    /// Returns true if the buffered messages should be written to disk, either because they've lingered
+    /// for long enough or because their size exceeds the maximum accumulation size.
+    pub fn should_flush(&self, now: IggyTimestamp) -> bool {
+        self.accumulated_size >= self.max_accumulation_size || self.is_due(now)
+    }
+
+    /// Returns true if the buffered messages have lingered for at least the linger time.
+    pub fn is_due(&self, now: IggyTimestamp) -> bool {
+        self.first_appended_at.is_some_and(|first_appended_at| {
+            now.as_micros()
+                .saturating_sub(first_appended_at.as_micros())
+                >= self.linger_time.as_micros()
+        })
+    }
+
+    pub fn has_buffered_messages(&self) -> bool {
+        self.first_appended_at.is_some()
+    }
+
+    /// Resets the accumulation once the buffered messages are written to disk (or dropped, e.g. when purging the partition),
+    /// and notifies the producers waiting for them.
+    pub fn complete_flush(&mut self) {
+        self.accumulated_size = 0;
+        self.first_appended_at = None;
+        self.flush_epoch.send_modify(|epoch| *epoch += 1);
+    }
+
+    /// Returns the receiver of the flush epoch, which is bumped once the currently buffered messages are written to disk.
+    /// The current epoch is marked as seen, so the receiver is only notified about the following flushes.
+    pub fn subscribe(&self) -> watch::Receiver<u64> {
+        self.flush_epoch.subscribe()
+    }
+}
+
+/// Waits until the messages buffered when subscribing are written to disk.
+/// Returns immediately if the partition (and its linger) is dropped in the meantime.
+pub async fn wait_for_flush(mut receiver: watch::Receiver<u64>) {
+    let _ = receiver.changed().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn linger() -> MessagesLinger {
+        MessagesLinger::new(
+            IggyDuration::from_str("5ms").unwrap(),
+            IggyByteSize::from(1000),
+        )
+    }
+
+    #[test]
+    fn empty_linger_should_not_be_due() {
+        let linger = linger();
+        assert!(!linger.has_buffered_messages());
+        assert!(!linger.should_flush(IggyTimestamp::now()));
+    }
+
+    #[test]
+    fn buffered_messages_should_be_flushed_after_linger_time() {
+        let mut linger = linger();
+        let now = IggyTimestamp::now();
+        linger.record_append(100, now);
+        assert!(!linger.should_flush(now));
+        let later = IggyTimestamp::from(now.as_micros() + 5_000);
+        assert!(linger.is_due(later));
+        assert!(linger.should_flush(later));
+    }
+
+    #[test]
+    fn buffered_messages_should_be_flushed_once_exceeding_max_accumulation_size() {
+        let mut linger = linger();
+        let now = IggyTimestamp::now();
+        linger.record_append(600, now);
+        assert!(!linger.should_flush(now));
+        linger.record_append(400, now);
+        assert!(!linger.is_due(now));
+        assert!(linger.should_flush(now));
+    }
+
+    #[test]
+    fn linger_time_should_start_with_first_buffered_append() {
+        let mut linger = linger();
+        let now = IggyTimestamp::now();
+        linger.record_append(1, now);
+        linger.record_append(1, IggyTimestamp::from(now.as_micros() + 4_000));
+        assert!(linger.is_due(IggyTimestamp::from(now.as_micros() + 5_000)));
+    }
+
+    #[tokio::test]
+    async fn completing_flush_should_reset_accumulation_and_notify_waiters() {
+        let mut linger = linger();
+        linger.record_append(2000, IggyTimestamp::now());
+        let waiter = tokio::spawn(wait_for_flush(linger.subscribe()));
+        tokio::task::yield_now().await;
+        linger.complete_flush();
+        waiter.await.unwrap();
+        assert!(!linger.has_buffered_messages());
+        assert!(!linger.should_flush(IggyTimestamp::now()));
+    }
+
+    #[tokio::test]
+    async fn waiter_should_return_once_linger_is_dropped() {
+        let linger = linger();
+        let receiver = linger.subscribe();
+        drop(linger);
+        wait_for_flush(receiver).await;
+    }
+}
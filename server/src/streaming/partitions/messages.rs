@@ -18,6 +18,7 @@
 
 use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
 use crate::streaming::batching::iterator::IntoMessagesIterator;
+use crate::streaming::diagnostics::batch_timings::BatchTimings;
 use crate::streaming::models::messages::RetainedMessage;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::partitions::COMPONENT;
@@ -30,6 +31,8 @@ use iggy::messages::send_messages::Message;
 use iggy::models::messages::POLLED_MESSAGE_METADATA;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::{atomic::Ordering, Arc};
+use std::time::Instant;
+use tokio::sync::watch;
 use tracing::{trace, warn};
 
 const EMPTY_MESSAGES: Vec<RetainedMessage> = vec![];
@@ -99,10 +102,53 @@ impl Partition {
         }
 
         let end_offset = self.get_end_offset(start_offset, count);
+        let mut messages = self
+            .get_messages_by_offset_range(start_offset, end_offset, count)
+            .await?;
+
+        // The compacted segments contain the gaps in the offsets, so keep reading the following offsets until the requested count is reached.
+        // The range is doubled on each read, to skip the large gaps without reading the same segment over and over.
+        let mut next_offset = messages
+            .last()
+            .map_or(end_offset, |message| message.offset.max(end_offset))
+            + 1;
+        let mut range_count = count;
+        while messages.len() < count as usize && next_offset <= self.current_offset {
+            let remaining_count = count as usize - messages.len();
+            range_count = range_count.saturating_mul(2);
+            let end_offset = self.get_end_offset(next_offset, range_count);
+            let mut following_messages = self
+                .get_messages_by_offset_range(next_offset, end_offset, range_count)
+                .await?;
+            next_offset = following_messages
+                .last()
+                .map_or(end_offset, |message| message.offset.max(end_offset))
+                + 1;
+            following_messages.truncate(remaining_count);
+            messages.extend(following_messages);
+        }
+
+        Ok(messages)
+    }
+
+    async fn get_messages_by_offset_range(
+        &self,
+        start_offset: u64,
+        end_offset: u64,
+        count: u32,
+    ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
         if let Some(cached) = self.try_get_messages_from_cache(start_offset, end_offset) {
             return Ok(cached);
         }
 
+        if let Some(prefetched) = self
+            .read_ahead
+            .as_ref()
+            .and_then(|read_ahead| read_ahead.get_messages(start_offset, end_offset, count))
+        {
+            return Ok(prefetched);
+        }
+
         let segments = self.filter_segments_by_offsets(start_offset, end_offset);
         match segments.len() {
             0 => Ok(Vec::new()),
@@ -115,6 +161,50 @@ impl Partition {
         }
     }
 
+    /// Retrieves the whole batches starting at the offset (up to a specified count and size), if they can be sent from the closed segment as they are stored.
+    pub async fn get_stored_batches(
+        &self,
+        start_offset: u64,
+        count: u32,
+        max_size: u64,
+    ) -> Result<Option<StoredBatches>, IggyError> {
+        let Some(segment) = self.segments.iter().find(|segment| {
+            segment.start_offset <= start_offset && start_offset <= segment.current_offset
+        }) else {
+            return Ok(None);
+        };
+
+        let Some(range) = segment
+            .get_stored_batches_range(start_offset, count, max_size)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get stored batches range, start offset: {start_offset}, count: {count}, segment: {segment}"))?
+        else {
+            return Ok(None);
+        };
+
+        // The log file is opened while the partition is still locked, so the batches can be sent even if the segment is deleted meanwhile.
+        let file = std::fs::File::open(&segment.log_path)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to open log file: {}",
+                    segment.log_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        trace!(
+            "Got {} stored messages from offset: {start_offset} to: {} for partition: {}.",
+            range.messages_count,
+            range.last_offset,
+            self.partition_id
+        );
+        Ok(Some(StoredBatches {
+            partition_id: self.partition_id,
+            current_offset: self.current_offset,
+            file,
+            range,
+        }))
+    }
+
     // Retrieves the first messages (up to a specified count).
     pub async fn get_first_messages(
         &self,
@@ -148,28 +238,36 @@ impl Partition {
             PollingConsumer::ConsumerGroup(group_id, _) => (&self.consumer_group_offsets, group_id),
         };
 
-        let consumer_offset = consumer_offsets.get(&consumer_id);
-        if consumer_offset.is_none() {
+        // The offset delivered by the previous poll, which is yet to be committed by the server, takes precedence.
+        let stored_offset = consumer_offsets
+            .get(&consumer_id)
+            .map(|consumer_offset| consumer_offset.offset);
+        let consumer_offset = match (stored_offset, self.get_delivered_offset(consumer)) {
+            (Some(stored_offset), Some(delivered_offset)) => {
+                Some(stored_offset.max(delivered_offset))
+            }
+            (stored_offset, delivered_offset) => stored_offset.or(delivered_offset),
+        };
+        let Some(consumer_offset) = consumer_offset else {
             trace!(
                 "Consumer: {} hasn't stored offset for partition: {}, returning the first messages...",
                 consumer_id,
                 self.partition_id
             );
             return self.get_first_messages(count).await;
-        }
+        };
 
-        let consumer_offset = consumer_offset.unwrap();
-        if consumer_offset.offset == self.current_offset {
+        if consumer_offset == self.current_offset {
             trace!(
                 "Consumer: {} has the latest offset: {} for partition: {}, returning empty messages...",
                 consumer_id,
-                consumer_offset.offset,
+                consumer_offset,
                 self.partition_id
             );
             return Ok(Vec::new());
         }
 
-        let offset = consumer_offset.offset + 1;
+        let offset = consumer_offset + 1;
         trace!(
             "Getting next messages for {} for partition: {} from offset: {}...",
             consumer_id,
@@ -180,6 +278,52 @@ impl Partition {
         self.get_messages_by_offset(offset, count).await
     }
 
+    /// Records the messages polled by the consumer, and returns the offset to prefetch its following messages from, if it reads sequentially.
+    pub fn record_consumer_read(
+        &self,
+        consumer: PollingConsumer,
+        start_offset: u64,
+        next_offset: u64,
+    ) -> Option<u64> {
+        let read_ahead = self.read_ahead.as_ref()?;
+        // There's no need to prefetch the messages, which are already cached.
+        let first_cached_offset = self
+            .cache
+            .as_ref()
+            .filter(|cache| !cache.is_empty())
+            .map_or(u64::MAX, |cache| cache[0].offset);
+        let limit_offset = first_cached_offset.min(self.current_offset + 1);
+        read_ahead.record_read(consumer, start_offset, next_offset, limit_offset)
+    }
+
+    /// Reads the following messages of the sequential consumer from disk, so that its next polls are served from memory.
+    pub async fn prefetch_messages(&self, consumer: PollingConsumer, start_offset: u64) {
+        let Some(read_ahead) = self.read_ahead.as_ref() else {
+            return;
+        };
+
+        match self
+            .get_messages_by_offset(start_offset, read_ahead.messages_count())
+            .await
+        {
+            Ok(messages) => {
+                trace!(
+                    "Prefetched {} messages from offset: {start_offset} for {consumer} in partition: {}.",
+                    messages.len(),
+                    self.partition_id
+                );
+                read_ahead.complete_prefetch(consumer, start_offset, messages);
+            }
+            Err(error) => {
+                warn!(
+                    "Failed to prefetch messages from offset: {start_offset} for {consumer} in partition: {}, error: {error}",
+                    self.partition_id
+                );
+                read_ahead.cancel_prefetch(consumer, start_offset);
+            }
+        }
+    }
+
     fn get_end_offset(&self, offset: u64, count: u32) -> u64 {
         let mut end_offset = offset + (count - 1) as u64;
         let segment = self.segments.last().unwrap();
@@ -370,22 +514,17 @@ impl Partition {
         appendable_batch_info: AppendableBatchInfo,
         messages: Vec<Message>,
         confirmation: Option<Confirmation>,
-    ) -> Result<(), IggyError> {
-        {
-            let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
-            if last_segment.is_closed {
-                let start_offset = last_segment.end_offset + 1;
-                trace!(
-                    "Current segment is closed, creating new segment with start offset: {} for partition with ID: {}...",
-                    start_offset, self.partition_id
-                );
-                self.add_persisted_segment(start_offset).await.with_error_context(|error| format!(
-                    "{COMPONENT} (error: {error}) - failed to add persisted segment, partition: {}, start offset: {}",
-                    self, start_offset,
-                ))?;
-            }
-        }
-
+        now: IggyTimestamp,
+    ) -> Result<BatchTimings, IggyError> {
+        // Rolling over the segment writes to disk, thus it's a part of the persistence.
+        let started_at = Instant::now();
+        self.ensure_open_segment(now).await?;
+
+        let mut timings = BatchTimings {
+            persistence: started_at.elapsed(),
+            ..Default::default()
+        };
+        let cache_insert_started_at = Instant::now();
         let batch_size = appendable_batch_info.batch_size
             + ((POLLED_MESSAGE_METADATA * messages.len() as u32) as u64).into();
         let base_offset = if !self.should_increment_offset {
@@ -394,6 +533,7 @@ impl Partition {
             self.current_offset + 1
         };
 
+        let timestamp = now.as_micros();
         let mut messages_count = 0u32;
         let mut retained_messages = Vec::with_capacity(messages.len());
         if let Some(message_deduplicator) = &self.message_deduplicator {
@@ -405,23 +545,22 @@ impl Partition {
                     );
                     continue;
                 }
-                let now = IggyTimestamp::now().as_micros();
                 let message_offset = base_offset + messages_count as u64;
-                let message = Arc::new(RetainedMessage::new(message_offset, now, message));
+                let message = Arc::new(RetainedMessage::new(message_offset, timestamp, message));
                 retained_messages.push(message.clone());
                 messages_count += 1;
             }
         } else {
             for message in messages {
-                let now = IggyTimestamp::now().as_micros();
                 let message_offset = base_offset + messages_count as u64;
-                let message = Arc::new(RetainedMessage::new(message_offset, now, message));
+                let message = Arc::new(RetainedMessage::new(message_offset, timestamp, message));
                 retained_messages.push(message.clone());
                 messages_count += 1;
             }
         }
         if messages_count == 0 {
-            return Ok(());
+            timings.cache_insert = cache_insert_started_at.elapsed();
+            return Ok(timings);
         }
 
         let last_offset = base_offset + (messages_count - 1) as u64;
@@ -449,9 +588,20 @@ impl Partition {
         }
 
         self.unsaved_messages_count += messages_count;
+        let linger_expired = match self.linger.as_mut() {
+            Some(linger) => {
+                linger.record_append(batch_size.as_bytes_u64(), now);
+                linger.should_flush(now)
+            }
+            None => false,
+        };
+        timings.cache_insert = cache_insert_started_at.elapsed();
+        let persistence_started_at = Instant::now();
+        let mut persisted_messages_count = 0;
         {
             let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
             if self.unsaved_messages_count >= self.config.partition.messages_required_to_save
+                || linger_expired
                 || last_segment.is_full().await
             {
                 trace!(
@@ -461,10 +611,50 @@ impl Partition {
                 );
 
                 last_segment.persist_messages(confirmation).await.unwrap();
+                persisted_messages_count = self.unsaved_messages_count;
                 self.unsaved_messages_count = 0;
+                if let Some(linger) = self.linger.as_mut() {
+                    linger.complete_flush();
+                }
             }
         }
 
+        self.fsync_if_required(persisted_messages_count, now)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to fsync partition: {self}")
+            })?;
+        timings.persistence += persistence_started_at.elapsed();
+        Ok(timings)
+    }
+
+    /// Rolls over the last segment if needed, and adds the new one if it's closed, so the messages can be appended to it.
+    pub(crate) async fn ensure_open_segment(
+        &mut self,
+        now: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        self.roll_over_segment(now)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to roll over segment, partition: {self}"
+                )
+            })?;
+        let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+        if last_segment.is_closed {
+            let start_offset = last_segment.end_offset + 1;
+            trace!(
+                "Current segment is closed, creating new segment with start offset: {} for partition with ID: {}...",
+                start_offset, self.partition_id
+            );
+            self.fsync_unsynced_messages(now).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to fsync closed segment, partition: {self}")
+            })?;
+            self.add_persisted_segment(start_offset).await.with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - failed to add persisted segment, partition: {}, start offset: {}",
+                self, start_offset,
+            ))?;
+        }
         Ok(())
     }
 
@@ -472,10 +662,16 @@ impl Partition {
         self.messages_count.load(Ordering::SeqCst)
     }
 
-    pub async fn flush_unsaved_buffer(&mut self, fsync: bool) -> Result<(), IggyError> {
-        let _fsync = fsync;
+    pub async fn flush_unsaved_buffer(
+        &mut self,
+        fsync: bool,
+        now: IggyTimestamp,
+    ) -> Result<(), IggyError> {
         if self.unsaved_messages_count == 0 {
-            return Ok(());
+            if fsync {
+                return self.fsync_persisted_messages(now).await;
+            }
+            return self.fsync_if_required(0, now).await;
         }
 
         let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
@@ -488,16 +684,58 @@ impl Partition {
         // Make sure all of the messages from the accumulator are persisted
         // no leftover from one round trip.
         while last_segment.unsaved_messages.is_some() {
-            last_segment.persist_messages(None).await.unwrap();
+            last_segment
+                .persist_messages(fsync.then_some(Confirmation::Wait))
+                .await
+                .unwrap();
         }
+        let persisted_messages_count = self.unsaved_messages_count;
         self.unsaved_messages_count = 0;
-        Ok(())
+        if let Some(linger) = self.linger.as_mut() {
+            linger.complete_flush();
+        }
+        if fsync {
+            self.unsynced_messages_count += persisted_messages_count;
+            return self.fsync_persisted_messages(now).await;
+        }
+        self.fsync_if_required(persisted_messages_count, now).await
+    }
+
+    /// Returns true if the buffered messages have lingered for long enough to be written to disk.
+    pub fn is_linger_due(&self, now: IggyTimestamp) -> bool {
+        self.linger
+            .as_ref()
+            .is_some_and(|linger| linger.is_due(now))
+    }
+
+    /// Writes the buffered messages to disk, if they have lingered for long enough, and returns their count.
+    pub async fn flush_lingering_messages(&mut self, now: IggyTimestamp) -> Result<u32, IggyError> {
+        if !self.is_linger_due(now) {
+            return Ok(0);
+        }
+
+        let messages_count = self.unsaved_messages_count;
+        self.flush_unsaved_buffer(false, now).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to flush lingering messages, partition: {self}")
+        })?;
+        Ok(messages_count)
+    }
+
+    /// Returns the receiver notified once the currently buffered messages are written to disk,
+    /// or `None` if there are no buffered messages, or they are not lingered (then they're written to disk immediately).
+    pub fn subscribe_to_flush(&self) -> Option<watch::Receiver<u64>> {
+        self.linger
+            .as_ref()
+            .filter(|linger| linger.has_buffered_messages())
+            .map(|linger| linger.subscribe())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use iggy::models::fsync_policy::FsyncPolicy;
     use iggy::utils::byte_size::IggyByteSize;
+    use iggy::utils::duration::IggyDuration;
     use iggy::utils::expiry::IggyExpiry;
     use iggy::utils::sizeable::Sizeable;
     use std::sync::atomic::{AtomicU32, AtomicU64};
@@ -522,7 +760,7 @@ mod tests {
             partition_id: partition.partition_id,
         };
         partition
-            .append_messages(appendable_batch_info, messages, None)
+            .append_messages(appendable_batch_info, messages, None, IggyTimestamp::now())
             .await
             .unwrap();
 
@@ -547,7 +785,7 @@ mod tests {
             partition_id: partition.partition_id,
         };
         partition
-            .append_messages(appendable_batch_info, messages, None)
+            .append_messages(appendable_batch_info, messages, None, IggyTimestamp::now())
             .await
             .unwrap();
 
@@ -558,6 +796,100 @@ mod tests {
         assert_eq!(loaded_messages.len(), unique_messages_count);
     }
 
+    #[tokio::test]
+    async fn given_buffered_messages_flush_with_fsync_should_persist_and_sync_them() {
+        let (mut partition, _tempdir) = create_partition(false).await;
+        partition.set_fsync_policy(Some(FsyncPolicy::never()));
+        let messages = create_messages();
+        let appendable_batch_info = AppendableBatchInfo {
+            batch_size: messages
+                .iter()
+                .map(|m| m.get_size_bytes())
+                .sum::<IggyByteSize>(),
+            partition_id: partition.partition_id,
+        };
+        partition
+            .append_messages(appendable_batch_info, messages, None, IggyTimestamp::now())
+            .await
+            .unwrap();
+        assert!(partition.unsaved_messages_count > 0);
+        let now = IggyTimestamp::from(partition.last_fsync_at.as_micros() + 1);
+
+        partition.flush_unsaved_buffer(true, now).await.unwrap();
+
+        assert_eq!(partition.unsaved_messages_count, 0);
+        assert_eq!(partition.unsynced_messages_count, 0);
+        assert_eq!(partition.last_fsync_at, now);
+        assert!(partition
+            .segments
+            .last()
+            .unwrap()
+            .unsaved_messages
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn given_buffered_messages_flush_without_fsync_should_not_sync_them() {
+        let (mut partition, _tempdir) = create_partition(false).await;
+        partition.set_fsync_policy(Some(FsyncPolicy::never()));
+        let messages = create_messages();
+        let messages_count = messages.len() as u32;
+        let appendable_batch_info = AppendableBatchInfo {
+            batch_size: messages
+                .iter()
+                .map(|m| m.get_size_bytes())
+                .sum::<IggyByteSize>(),
+            partition_id: partition.partition_id,
+        };
+        partition
+            .append_messages(appendable_batch_info, messages, None, IggyTimestamp::now())
+            .await
+            .unwrap();
+        let last_fsync_at = partition.last_fsync_at;
+
+        partition
+            .flush_unsaved_buffer(false, IggyTimestamp::now())
+            .await
+            .unwrap();
+
+        assert_eq!(partition.unsaved_messages_count, 0);
+        assert_eq!(partition.unsynced_messages_count, messages_count);
+        assert_eq!(partition.last_fsync_at, last_fsync_at);
+    }
+
+    #[tokio::test]
+    async fn given_interval_fsync_policy_unsynced_messages_should_be_synced_once_interval_elapsed()
+    {
+        let (mut partition, _tempdir) = create_partition(false).await;
+        partition.set_fsync_policy(Some(FsyncPolicy::interval(IggyDuration::ONE_SECOND)));
+        let messages = create_messages();
+        let messages_count = messages.len() as u32;
+        let appendable_batch_info = AppendableBatchInfo {
+            batch_size: messages
+                .iter()
+                .map(|m| m.get_size_bytes())
+                .sum::<IggyByteSize>(),
+            partition_id: partition.partition_id,
+        };
+        let now = partition.last_fsync_at;
+        partition
+            .append_messages(appendable_batch_info, messages, None, now)
+            .await
+            .unwrap();
+        partition.flush_unsaved_buffer(false, now).await.unwrap();
+        assert_eq!(partition.unsynced_messages_count, messages_count);
+
+        let now = IggyTimestamp::from(now.as_micros() + 999_999);
+        assert_eq!(partition.fsync_if_due(now).await.unwrap(), 0);
+        assert_eq!(partition.unsynced_messages_count, messages_count);
+
+        let now = IggyTimestamp::from(now.as_micros() + 1);
+        assert_eq!(partition.fsync_if_due(now).await.unwrap(), messages_count);
+        assert_eq!(partition.unsynced_messages_count, 0);
+        assert_eq!(partition.last_fsync_at, now);
+        assert!(!partition.should_fsync(now));
+    }
+
     async fn create_partition(deduplication_enabled: bool) -> (Partition, TempDir) {
         let stream_id = 1;
         let topic_id = 2;
@@ -0,0 +1,398 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use crate::streaming::utils::clock::SharedClock;
+use ahash::{AHashMap, AHashSet};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMut;
+use iggy::locking::IggySharedMutFn;
+use iggy::utils::byte_size::IggyByteSize;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tokio::fs;
+use tracing::{error, info, warn};
+
+const MOVING_EXTENSION: &str = "moving";
+const MOVED_EXTENSION: &str = "moved";
+const LINK_EXTENSION: &str = "link";
+
+/// The version of the copied file, used to detect the files modified since they were copied.
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct CopiedFile {
+    size: u64,
+    modified_at: Option<SystemTime>,
+}
+
+impl Partition {
+    /// Returns the path of the directory, in which the data of the partition is stored.
+    /// Once the partition is moved to another data directory, the partition path is a link to that directory.
+    pub async fn get_data_path(&self) -> String {
+        match fs::read_link(&self.partition_path).await {
+            Ok(path) => path.to_string_lossy().to_string(),
+            Err(_) => self.partition_path.clone(),
+        }
+    }
+}
+
+/// Moves the data of the partition to the target path, which should be executed in the background.
+///
+/// The files are copied first without locking the partition, so it can still be used for reading and writing.
+/// Then, the partition is locked, the files modified in the meantime are copied again, and the readers and writers are switched to the new location.
+pub async fn move_partition(
+    partition: IggySharedMut<Partition>,
+    target_path: String,
+    clock: SharedClock,
+) -> Result<(), IggyError> {
+    let result = move_partition_data(&partition, &target_path, &clock).await;
+    partition.write().await.is_moving = false;
+    result
+}
+
+async fn move_partition_data(
+    partition: &IggySharedMut<Partition>,
+    target_path: &str,
+    clock: &SharedClock,
+) -> Result<(), IggyError> {
+    let (partition_id, topic_id, stream_id, source_path, copy_path) = {
+        let partition = partition.read().await;
+        let source_path = partition.get_data_path().await;
+        // Moving back to the system path, the data is copied next to the link, which is replaced at the end.
+        let copy_path = if target_path == partition.partition_path {
+            format!("{}.{MOVING_EXTENSION}", partition.partition_path)
+        } else {
+            target_path.to_owned()
+        };
+        info!(
+            "Moving partition with ID: {} for topic with ID: {} and stream with ID: {} from: {source_path} to: {target_path}...",
+            partition.partition_id, partition.topic_id, partition.stream_id
+        );
+        (
+            partition.partition_id,
+            partition.topic_id,
+            partition.stream_id,
+            source_path,
+            copy_path,
+        )
+    };
+    let cannot_move = |error: std::io::Error| {
+        error!("Failed to move partition with ID: {partition_id} for topic with ID: {topic_id} and stream with ID: {stream_id} to: {target_path}. {error}");
+        IggyError::CannotMovePartition(partition_id, topic_id, stream_id)
+    };
+
+    if Path::new(&copy_path).exists() {
+        warn!("Removing the leftovers of the previous move in: {copy_path}");
+        fs::remove_dir_all(&copy_path).await.map_err(cannot_move)?;
+    }
+
+    let mut copied_files = AHashMap::new();
+    let copied_bytes = sync_directory(&source_path, &copy_path, &mut copied_files)
+        .await
+        .map_err(cannot_move)?;
+    info!(
+        "Copied {} of partition data from: {source_path} to: {copy_path}, switching to the new location...",
+        IggyByteSize::from(copied_bytes)
+    );
+
+    let mut partition = partition.write().await;
+    if fs::symlink_metadata(&partition.partition_path)
+        .await
+        .is_err()
+    {
+        warn!("Partition with ID: {partition_id} for topic with ID: {topic_id} and stream with ID: {stream_id} was deleted while being moved.");
+        let _ = fs::remove_dir_all(&copy_path).await;
+        return Err(IggyError::PartitionNotFound(
+            partition_id,
+            topic_id,
+            stream_id,
+        ));
+    }
+
+    partition.flush_unsaved_buffer(true, clock.now()).await.with_error_context(|error| {
+        format!("{COMPONENT} (error: {error}) - failed to flush unsaved buffer before moving partition: {partition}")
+    })?;
+    let mut writable_segments = AHashSet::new();
+    for segment in partition.segments.iter_mut() {
+        if segment.close_files().await {
+            writable_segments.insert(segment.start_offset);
+        }
+    }
+
+    let switch_result = match sync_directory(&source_path, &copy_path, &mut copied_files).await {
+        Ok(_) => switch_location(&partition.partition_path, &copy_path, target_path).await,
+        Err(error) => Err(error),
+    };
+    for segment in partition.segments.iter_mut() {
+        if segment.is_tiered() {
+            segment.initialize_tiered_reading().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize reading after moving offloaded segment: {segment}")
+            })?;
+            continue;
+        }
+        if writable_segments.contains(&segment.start_offset) {
+            segment.initialize_writing().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize writing after moving segment: {segment}")
+            })?;
+        }
+        segment.initialize_reading().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to initialize reading after moving segment: {segment}")
+        })?;
+    }
+
+    let moved_path = match switch_result {
+        Ok(moved_path) => moved_path,
+        Err(error) => {
+            let _ = fs::remove_dir_all(&copy_path).await;
+            return Err(cannot_move(error));
+        }
+    };
+
+    drop(partition);
+    info!("Moved partition with ID: {partition_id} for topic with ID: {topic_id} and stream with ID: {stream_id} to: {target_path}.");
+    if let Err(error) = fs::remove_dir_all(&moved_path).await {
+        error!(
+            "Failed to remove the previous location: {moved_path} of the moved partition. {error}"
+        );
+    }
+    Ok(())
+}
+
+/// Atomically switches the partition path to the new location, and returns the path of the previous location to be removed.
+async fn switch_location(
+    partition_path: &str,
+    copy_path: &str,
+    target_path: &str,
+) -> Result<String, std::io::Error> {
+    let metadata = fs::symlink_metadata(partition_path).await?;
+    if !metadata.is_symlink() {
+        let moved_path = format!("{partition_path}.{MOVED_EXTENSION}");
+        fs::rename(partition_path, &moved_path).await?;
+        if let Err(error) = create_link(target_path, partition_path).await {
+            fs::rename(&moved_path, partition_path).await?;
+            return Err(error);
+        }
+        return Ok(moved_path);
+    }
+
+    let moved_path = fs::read_link(partition_path)
+        .await?
+        .to_string_lossy()
+        .to_string();
+    if target_path == partition_path {
+        fs::remove_file(partition_path).await?;
+        if let Err(error) = fs::rename(copy_path, partition_path).await {
+            create_link(&moved_path, partition_path).await?;
+            return Err(error);
+        }
+        return Ok(moved_path);
+    }
+
+    let link_path = format!("{partition_path}.{LINK_EXTENSION}");
+    let _ = fs::remove_file(&link_path).await;
+    create_link(target_path, &link_path).await?;
+    fs::rename(&link_path, partition_path).await?;
+    Ok(moved_path)
+}
+
+#[cfg(unix)]
+async fn create_link(target_path: &str, link_path: &str) -> Result<(), std::io::Error> {
+    fs::symlink(target_path, link_path).await
+}
+
+#[cfg(windows)]
+async fn create_link(target_path: &str, link_path: &str) -> Result<(), std::io::Error> {
+    fs::symlink_dir(target_path, link_path).await
+}
+
+/// Copies the files from the source to the target directory, skipping the ones which were not modified since they were copied,
+/// and removes the previously copied files, which no longer exist in the source directory. Returns the number of copied bytes.
+async fn sync_directory(
+    source_path: &str,
+    target_path: &str,
+    copied_files: &mut AHashMap<PathBuf, CopiedFile>,
+) -> Result<u64, std::io::Error> {
+    let mut copied_bytes = 0;
+    let mut existing_files = AHashSet::new();
+    let mut directories = vec![PathBuf::new()];
+    while let Some(directory) = directories.pop() {
+        fs::create_dir_all(Path::new(target_path).join(&directory)).await?;
+        let mut entries = fs::read_dir(Path::new(source_path).join(&directory)).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = directory.join(entry.file_name());
+            let metadata = entry.metadata().await?;
+            if metadata.is_dir() {
+                directories.push(path);
+                continue;
+            }
+
+            let file = CopiedFile {
+                size: metadata.len(),
+                modified_at: metadata.modified().ok(),
+            };
+            if copied_files.get(&path) != Some(&file) {
+                copied_bytes += fs::copy(entry.path(), Path::new(target_path).join(&path)).await?;
+                copied_files.insert(path.clone(), file);
+            }
+            existing_files.insert(path);
+        }
+    }
+
+    let removed_files = copied_files
+        .keys()
+        .filter(|path| !existing_files.contains(*path))
+        .cloned()
+        .collect::<Vec<_>>();
+    for path in removed_files {
+        fs::remove_file(Path::new(target_path).join(&path)).await?;
+        copied_files.remove(&path);
+    }
+    Ok(copied_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::{CacheConfig, PartitionConfig, SystemConfig};
+    use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
+    use crate::streaming::partitions::create_messages;
+    use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
+    use crate::streaming::storage::SystemStorage;
+    use crate::streaming::utils::clock::system_clock;
+    use iggy::utils::expiry::IggyExpiry;
+    use iggy::utils::sizeable::Sizeable;
+    use iggy::utils::timestamp::IggyTimestamp;
+    use std::sync::atomic::{AtomicU32, AtomicU64};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    const STREAM_ID: u32 = 1;
+    const TOPIC_ID: u32 = 2;
+    const PARTITION_ID: u32 = 3;
+
+    #[tokio::test]
+    async fn should_move_partition_to_data_directory_and_back() {
+        let system_dir = TempDir::new().unwrap();
+        let data_dir = TempDir::new().unwrap();
+        let data_directory = data_dir.path().to_str().unwrap().to_string();
+        let config = Arc::new(SystemConfig {
+            path: system_dir.path().to_str().unwrap().to_string(),
+            cache: CacheConfig {
+                enabled: false,
+                ..Default::default()
+            },
+            partition: PartitionConfig {
+                data_directories: vec![data_directory.clone()],
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let partition = create_partition(config.clone()).await;
+        let messages_count = append_messages(&partition).await;
+        let partition_path = partition.read().await.partition_path.clone();
+        let target_path = config.get_data_directory_partition_path(
+            &data_directory,
+            STREAM_ID,
+            TOPIC_ID,
+            PARTITION_ID,
+        );
+
+        move_partition(partition.clone(), target_path.clone(), system_clock())
+            .await
+            .unwrap();
+
+        assert_eq!(partition.read().await.get_data_path().await, target_path);
+        assert!(fs::symlink_metadata(&partition_path)
+            .await
+            .unwrap()
+            .is_symlink());
+        assert!(!partition.read().await.is_moving);
+        let messages_count = messages_count + append_messages(&partition).await;
+        assert_messages_count(&partition, messages_count).await;
+
+        move_partition(partition.clone(), partition_path.clone(), system_clock())
+            .await
+            .unwrap();
+
+        assert_eq!(partition.read().await.get_data_path().await, partition_path);
+        assert!(!fs::symlink_metadata(&partition_path)
+            .await
+            .unwrap()
+            .is_symlink());
+        assert!(!Path::new(&target_path).exists());
+        assert_messages_count(&partition, messages_count).await;
+    }
+
+    async fn create_partition(config: Arc<SystemConfig>) -> IggySharedMut<Partition> {
+        let storage = Arc::new(SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        ));
+        let mut partition = Partition::create(
+            STREAM_ID,
+            TOPIC_ID,
+            PARTITION_ID,
+            true,
+            config,
+            storage,
+            IggyExpiry::NeverExpire,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            IggyTimestamp::now(),
+        )
+        .await;
+        partition.persist().await.unwrap();
+        IggySharedMut::new(partition)
+    }
+
+    async fn append_messages(partition: &IggySharedMut<Partition>) -> u32 {
+        let mut partition = partition.write().await;
+        let messages = create_messages();
+        let messages_count = messages.len() as u32;
+        let appendable_batch_info = AppendableBatchInfo {
+            batch_size: messages
+                .iter()
+                .map(|message| message.get_size_bytes())
+                .sum::<IggyByteSize>(),
+            partition_id: partition.partition_id,
+        };
+        partition
+            .append_messages(appendable_batch_info, messages, None, IggyTimestamp::now())
+            .await
+            .unwrap();
+        partition
+            .flush_unsaved_buffer(true, IggyTimestamp::now())
+            .await
+            .unwrap();
+        messages_count
+    }
+
+    async fn assert_messages_count(partition: &IggySharedMut<Partition>, messages_count: u32) {
+        let messages = partition
+            .read()
+            .await
+            .get_messages_by_offset(0, messages_count * 2)
+            .await
+            .unwrap();
+        assert_eq!(messages.len(), messages_count as usize);
+    }
+}
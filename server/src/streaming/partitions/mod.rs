@@ -20,9 +20,16 @@ use bytes::Bytes;
 use iggy::messages::send_messages;
 
 pub mod consumer_offsets;
+pub mod fsync;
+pub mod linger;
 pub mod messages;
+pub mod migration;
 pub mod partition;
 pub mod persistence;
+pub mod queue;
+pub mod read_ahead;
+pub mod replication;
+pub mod rollover;
 pub mod segments;
 pub mod storage;
 
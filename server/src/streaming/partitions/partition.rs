@@ -18,19 +18,26 @@
 
 use crate::configs::system::SystemConfig;
 use crate::streaming::cache::buffer::SmartCache;
-use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::cache::memory_tracker::{CacheKey, CacheMemoryTracker};
 use crate::streaming::deduplication::message_deduplicator::MessageDeduplicator;
 use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::partitions::linger::MessagesLinger;
+use crate::streaming::partitions::queue::MessageQueue;
+use crate::streaming::partitions::read_ahead::ReadAhead;
 use crate::streaming::segments::*;
 use crate::streaming::storage::SystemStorage;
+use ahash::AHashMap;
 use dashmap::DashMap;
 use iggy::consumer::ConsumerKind;
+use iggy::models::fsync_policy::FsyncPolicy;
+use iggy::models::segment_rollover_policy::SegmentRolloverPolicy;
 use iggy::models::stats::CacheMetrics;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
 use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
@@ -40,6 +47,7 @@ pub struct Partition {
     pub stream_id: u32,
     pub topic_id: u32,
     pub partition_id: u32,
+    pub name: Option<String>,
     pub partition_path: String,
     pub offsets_path: String,
     pub consumer_offsets_path: String,
@@ -47,8 +55,10 @@ pub struct Partition {
     pub current_offset: u64,
     pub cache: Option<SmartCache<Arc<RetainedMessage>>>,
     pub cached_memory_tracker: Option<Arc<CacheMemoryTracker>>,
+    pub read_ahead: Option<ReadAhead>,
     pub message_deduplicator: Option<MessageDeduplicator>,
     pub unsaved_messages_count: u32,
+    pub(crate) linger: Option<MessagesLinger>,
     pub should_increment_offset: bool,
     pub created_at: IggyTimestamp,
     pub avg_timestamp_delta: IggyDuration,
@@ -62,7 +72,20 @@ pub struct Partition {
     pub(crate) message_expiry: IggyExpiry,
     pub(crate) consumer_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) consumer_group_offsets: DashMap<u32, ConsumerOffset>,
+    pub(crate) consumer_delivery_attempts: DashMap<u32, AHashMap<u64, u32>>,
+    pub(crate) consumer_group_delivery_attempts: DashMap<u32, AHashMap<u64, u32>>,
+    pub(crate) consumer_redeliveries: DashMap<u32, BTreeMap<u64, IggyTimestamp>>,
+    pub(crate) consumer_group_redeliveries: DashMap<u32, BTreeMap<u64, IggyTimestamp>>,
+    pub(crate) consumer_auto_commits: DashMap<u32, AutoCommitState>,
+    pub(crate) consumer_group_auto_commits: DashMap<u32, AutoCommitState>,
     pub(crate) segments: Vec<Segment>,
+    pub(crate) compacted_offset: Option<u64>,
+    pub(crate) is_moving: bool,
+    pub(crate) fsync_policy: Option<FsyncPolicy>,
+    pub(crate) unsynced_messages_count: u32,
+    pub(crate) last_fsync_at: IggyTimestamp,
+    pub(crate) segment_rollover_policy: Option<SegmentRolloverPolicy>,
+    pub(crate) queue: Option<std::sync::Mutex<MessageQueue>>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
 }
@@ -75,6 +98,26 @@ pub struct ConsumerOffset {
     pub path: Arc<String>,
 }
 
+/// The lag of the consumer group in the partition, based on its stored offset.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct ConsumerGroupLag {
+    /// The number of messages consumed by the group, i.e. the offset following the stored one.
+    pub consumed_messages: u64,
+    /// The number of messages appended after the stored offset.
+    pub lag_messages: u64,
+    /// The timestamp of the oldest message which is yet to be consumed by the group.
+    pub oldest_pending_timestamp: Option<u64>,
+}
+
+/// The state of the offset auto-commit, which is performed by the server once the consumer polls again.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct AutoCommitState {
+    /// The offset of the last message delivered to the consumer, which is yet to be committed.
+    pub delivered_offset: Option<u64>,
+    /// The time of the last auto-commit.
+    pub committed_at: Option<IggyTimestamp>,
+}
+
 impl ConsumerOffset {
     pub fn new(kind: ConsumerKind, consumer_id: u32, offset: u64, path: &str) -> ConsumerOffset {
         ConsumerOffset {
@@ -113,14 +156,31 @@ impl Partition {
             false => (None, None),
             true => (
                 CacheMemoryTracker::initialize(&config.cache),
-                Some(SmartCache::new()),
+                Some(SmartCache::new(CacheKey {
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                })),
             ),
         };
+        let read_ahead = cached_memory_tracker
+            .as_ref()
+            .filter(|_| config.cache.prefetch_messages_count > 0)
+            .map(|memory_tracker| {
+                ReadAhead::new(config.cache.prefetch_messages_count, memory_tracker.clone())
+            });
+        let linger = (!config.partition.linger_time.is_zero()).then(|| {
+            MessagesLinger::new(
+                config.partition.linger_time,
+                config.partition.max_accumulation_size,
+            )
+        });
 
         let mut partition = Partition {
             stream_id,
             topic_id,
             partition_id,
+            name: None,
             partition_path,
             offsets_path,
             consumer_offsets_path,
@@ -128,6 +188,7 @@ impl Partition {
             message_expiry,
             cache: messages,
             cached_memory_tracker,
+            read_ahead,
             message_deduplicator: match config.message_deduplication.enabled {
                 true => Some(MessageDeduplicator::new(
                     if config.message_deduplication.max_entries > 0 {
@@ -146,11 +207,25 @@ impl Partition {
                 false => None,
             },
             segments: vec![],
+            compacted_offset: None,
+            is_moving: false,
+            fsync_policy: None,
+            unsynced_messages_count: 0,
+            last_fsync_at: created_at,
+            segment_rollover_policy: None,
+            queue: None,
             current_offset: 0,
             unsaved_messages_count: 0,
+            linger,
             should_increment_offset: false,
             consumer_offsets: DashMap::new(),
             consumer_group_offsets: DashMap::new(),
+            consumer_delivery_attempts: DashMap::new(),
+            consumer_group_delivery_attempts: DashMap::new(),
+            consumer_redeliveries: DashMap::new(),
+            consumer_group_redeliveries: DashMap::new(),
+            consumer_auto_commits: DashMap::new(),
+            consumer_group_auto_commits: DashMap::new(),
             config,
             storage,
             created_at,
@@ -171,6 +246,8 @@ impl Partition {
                 partition_id,
                 0,
                 partition.config.clone(),
+                partition.storage.buffer_pool.clone(),
+                partition.storage.segment_encryption.clone(),
                 partition.message_expiry,
                 partition.size_of_parent_stream.clone(),
                 partition.size_of_parent_topic.clone(),
@@ -231,7 +308,9 @@ mod tests {
     use crate::configs::system::{CacheConfig, SystemConfig};
     use crate::streaming::partitions::partition::Partition;
     use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
+    use crate::streaming::polling_consumer::PollingConsumer;
     use crate::streaming::storage::SystemStorage;
+    use iggy::messages::AutoCommitMode;
     use iggy::utils::duration::IggyDuration;
     use iggy::utils::expiry::IggyExpiry;
     use iggy::utils::timestamp::IggyTimestamp;
@@ -309,6 +388,7 @@ mod tests {
                 cache: CacheConfig {
                     enabled: false,
                     size: "0".parse().unwrap(),
+                    ..Default::default()
                 },
                 ..Default::default()
             }),
@@ -356,4 +436,168 @@ mod tests {
         .await;
         assert!(partition.segments.is_empty());
     }
+
+    #[tokio::test]
+    async fn should_track_delivery_attempts_per_consumer() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let config = Arc::new(SystemConfig {
+            path: tempdir.path().to_str().unwrap().to_string(),
+            ..Default::default()
+        });
+        let storage = Arc::new(SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        ));
+
+        let mut partition = Partition::create(
+            1,
+            1,
+            1,
+            false,
+            config,
+            storage,
+            IggyExpiry::NeverExpire,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            IggyTimestamp::now(),
+        )
+        .await;
+        partition.current_offset = 10;
+        let consumer = PollingConsumer::Consumer(1, 1);
+        let consumer_group = PollingConsumer::ConsumerGroup(1, 2);
+
+        assert_eq!(partition.register_delivery_failure(consumer, 5).unwrap(), 1);
+        assert_eq!(partition.register_delivery_failure(consumer, 5).unwrap(), 2);
+        assert_eq!(
+            partition
+                .register_delivery_failure(consumer_group, 5)
+                .unwrap(),
+            1
+        );
+        assert!(partition.register_delivery_failure(consumer, 11).is_err());
+        assert_eq!(partition.get_delivery_attempts_count(consumer, 5), 2);
+
+        partition.clear_delivery_attempts(consumer, 5);
+        assert_eq!(partition.get_delivery_attempts_count(consumer, 5), 0);
+        assert_eq!(partition.get_delivery_attempts_count(consumer_group, 5), 1);
+        assert!(partition.consumer_delivery_attempts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_return_only_due_redeliveries_ordered_by_offset() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let config = Arc::new(SystemConfig {
+            path: tempdir.path().to_str().unwrap().to_string(),
+            ..Default::default()
+        });
+        let storage = Arc::new(SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        ));
+
+        let mut partition = Partition::create(
+            1,
+            1,
+            1,
+            false,
+            config,
+            storage,
+            IggyExpiry::NeverExpire,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            IggyTimestamp::now(),
+        )
+        .await;
+        partition.current_offset = 10;
+        let consumer = PollingConsumer::Consumer(1, 1);
+        let now = IggyTimestamp::from(1_000_000);
+        let later = IggyTimestamp::from(2_000_000);
+
+        partition.schedule_redelivery(consumer, 7, now).unwrap();
+        partition.schedule_redelivery(consumer, 3, now).unwrap();
+        partition.schedule_redelivery(consumer, 5, later).unwrap();
+        assert!(partition.schedule_redelivery(consumer, 11, now).is_err());
+
+        assert_eq!(
+            partition.take_due_redeliveries(consumer, now, 10),
+            vec![3, 7]
+        );
+        assert!(partition
+            .take_due_redeliveries(consumer, now, 10)
+            .is_empty());
+        assert_eq!(
+            partition.take_due_redeliveries(consumer, later, 10),
+            vec![5]
+        );
+        assert!(partition.consumer_redeliveries.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_track_delivered_offset_until_it_is_committed_or_discarded() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let config = Arc::new(SystemConfig {
+            path: tempdir.path().to_str().unwrap().to_string(),
+            ..Default::default()
+        });
+        let storage = Arc::new(SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        ));
+
+        let mut partition = Partition::create(
+            1,
+            1,
+            1,
+            false,
+            config,
+            storage,
+            IggyExpiry::NeverExpire,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            IggyTimestamp::now(),
+        )
+        .await;
+        partition.current_offset = 10;
+        let consumer = PollingConsumer::Consumer(1, 1);
+        let interval = AutoCommitMode::Interval(IggyDuration::from(1_000_000));
+        let now = IggyTimestamp::from(1_500_000);
+
+        partition
+            .auto_commit_offset(consumer, AutoCommitMode::Manual, 3)
+            .await
+            .unwrap();
+        assert_eq!(partition.get_delivered_offset(consumer), None);
+
+        partition
+            .auto_commit_offset(consumer, AutoCommitMode::AfterPoll, 5)
+            .await
+            .unwrap();
+        assert_eq!(partition.get_delivered_offset(consumer), Some(5));
+
+        partition
+            .consumer_auto_commits
+            .get_mut(&1)
+            .unwrap()
+            .committed_at = Some(IggyTimestamp::from(1_000_000));
+        partition
+            .commit_delivered_offset(consumer, interval, now)
+            .await
+            .unwrap();
+        assert_eq!(partition.get_delivered_offset(consumer), Some(5));
+
+        partition
+            .commit_delivered_offset(consumer, AutoCommitMode::Manual, now)
+            .await
+            .unwrap();
+        assert_eq!(partition.get_delivered_offset(consumer), None);
+    }
 }
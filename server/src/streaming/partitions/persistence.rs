@@ -57,6 +57,12 @@ impl Partition {
         if let Some(cache) = self.cache.as_mut() {
             cache.purge();
         }
+        if let Some(read_ahead) = self.read_ahead.as_ref() {
+            read_ahead.clear();
+        }
+        if let Some(linger) = self.linger.as_mut() {
+            linger.complete_flush();
+        }
         for segment in &mut self.segments {
             segment.delete().await.with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to delete segment: {segment}",)
@@ -0,0 +1,333 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::queue_policy::QueuePolicy;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use tokio::fs;
+use tracing::trace;
+
+/// The messages of the partition shared by all the consumers polling the topic in the queue mode.
+/// Each polled message is leased until its visibility timeout elapses, and it's redelivered afterwards, unless it has been acknowledged.
+#[derive(Debug)]
+pub struct MessageQueue {
+    policy: QueuePolicy,
+    /// The offset of the next message which has never been leased.
+    next_offset: u64,
+    /// The offsets of the leased messages, along with the timestamp when they become visible again.
+    leases: BTreeMap<u64, IggyTimestamp>,
+}
+
+impl MessageQueue {
+    pub fn new(policy: QueuePolicy, next_offset: u64) -> Self {
+        Self {
+            policy,
+            next_offset,
+            leases: BTreeMap::new(),
+        }
+    }
+
+    pub fn set_policy(&mut self, policy: QueuePolicy) {
+        self.policy = policy;
+    }
+
+    /// Leases up to `count` messages polled at the given time, starting with the ones whose visibility timeout has elapsed,
+    /// followed by the ones which have never been leased, up to the last offset of the partition (if it has any messages).
+    /// The messages before the first offset (e.g. deleted by the retention) are skipped. Returns the leased offsets in ascending order.
+    pub fn lease(
+        &mut self,
+        first_offset: u64,
+        last_offset: Option<u64>,
+        count: u32,
+        now: IggyTimestamp,
+    ) -> Vec<u64> {
+        let count = count as usize;
+        let visible_at = self.policy.get_visible_at(now);
+        let mut offsets = self
+            .leases
+            .iter()
+            .filter(|(_, visible_at)| visible_at.as_micros() <= now.as_micros())
+            .map(|(offset, _)| *offset)
+            .take(count)
+            .collect::<Vec<_>>();
+        for offset in &offsets {
+            self.leases.insert(*offset, visible_at);
+        }
+
+        self.next_offset = self.next_offset.max(first_offset);
+        if let Some(last_offset) = last_offset {
+            while offsets.len() < count && self.next_offset <= last_offset {
+                self.leases.insert(self.next_offset, visible_at);
+                offsets.push(self.next_offset);
+                self.next_offset += 1;
+            }
+        }
+
+        offsets.sort_unstable();
+        offsets
+    }
+
+    /// Removes the lease of the acknowledged message, returning false if the message wasn't leased.
+    pub fn acknowledge(&mut self, offset: u64) -> bool {
+        self.leases.remove(&offset).is_some()
+    }
+
+    /// Makes the leased message visible again at the given time, returning false if the message wasn't leased.
+    pub fn release(&mut self, offset: u64, visible_at: IggyTimestamp) -> bool {
+        match self.leases.get_mut(&offset) {
+            Some(lease) => {
+                *lease = visible_at;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the offset up to which (inclusive) all the messages have been acknowledged, if any.
+    pub fn get_acknowledged_offset(&self) -> Option<u64> {
+        self.leases
+            .keys()
+            .next()
+            .copied()
+            .unwrap_or(self.next_offset)
+            .checked_sub(1)
+    }
+
+    /// Returns the number of the leased messages which are yet to be acknowledged.
+    pub fn get_in_flight_count(&self) -> usize {
+        self.leases.len()
+    }
+}
+
+impl Partition {
+    /// Sets the queue policy of the parent topic. Once the queue mode is enabled, the persisted acknowledged offset is restored,
+    /// so that the acknowledged messages aren't redelivered, while disabling it removes the persisted offset.
+    pub async fn set_queue_policy(
+        &mut self,
+        queue_policy: Option<QueuePolicy>,
+    ) -> Result<(), IggyError> {
+        let Some(queue_policy) = queue_policy else {
+            if self.queue.take().is_some() {
+                let path = self.get_queue_offset_path();
+                self.storage
+                    .partition
+                    .delete_consumer_offset(&path)
+                    .await
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to delete queue offset, path: {path}")
+                    })?;
+            }
+            return Ok(());
+        };
+
+        if let Some(queue) = &self.queue {
+            queue.lock().unwrap().set_policy(queue_policy);
+            return Ok(());
+        }
+
+        let next_offset = match self.load_queue_offset().await? {
+            Some(acknowledged_offset) => acknowledged_offset + 1,
+            None => self
+                .segments
+                .first()
+                .map(|segment| segment.start_offset)
+                .unwrap_or(0),
+        };
+        self.queue = Some(Mutex::new(MessageQueue::new(queue_policy, next_offset)));
+        Ok(())
+    }
+
+    pub fn is_queue(&self) -> bool {
+        self.queue.is_some()
+    }
+
+    /// Leases up to `count` messages to the consumer polling the partition in the queue mode, returning their offsets.
+    pub fn lease_queue_messages(&self, count: u32, now: IggyTimestamp) -> Vec<u64> {
+        let Some(queue) = &self.queue else {
+            return Vec::new();
+        };
+
+        let first_offset = self
+            .segments
+            .first()
+            .map(|segment| segment.start_offset)
+            .unwrap_or(0);
+        let last_offset = if self.should_increment_offset {
+            Some(self.current_offset)
+        } else {
+            None
+        };
+        queue
+            .lock()
+            .unwrap()
+            .lease(first_offset, last_offset, count, now)
+    }
+
+    /// Acknowledges the leased messages, returning the offset up to which all the messages have been acknowledged, if it has advanced.
+    pub fn acknowledge_queue_messages(&self, offsets: &[u64]) -> Option<u64> {
+        let queue = self.queue.as_ref()?;
+        let mut queue = queue.lock().unwrap();
+        let acknowledged_offset = queue.get_acknowledged_offset();
+        for offset in offsets {
+            if !queue.acknowledge(*offset) {
+                trace!(
+                    "Message with offset: {offset} is not leased in partition with ID: {}, skipping acknowledgement.",
+                    self.partition_id
+                );
+            }
+        }
+
+        let updated_acknowledged_offset = queue.get_acknowledged_offset();
+        if updated_acknowledged_offset > acknowledged_offset {
+            updated_acknowledged_offset
+        } else {
+            None
+        }
+    }
+
+    /// Makes the leased messages visible again at the given time, e.g. once they've been negatively acknowledged.
+    pub fn release_queue_messages(&self, offsets: &[u64], visible_at: IggyTimestamp) {
+        let Some(queue) = &self.queue else {
+            return;
+        };
+
+        let mut queue = queue.lock().unwrap();
+        for offset in offsets {
+            queue.release(*offset, visible_at);
+        }
+    }
+
+    pub fn get_queue_in_flight_count(&self) -> usize {
+        self.queue
+            .as_ref()
+            .map_or(0, |queue| queue.lock().unwrap().get_in_flight_count())
+    }
+
+    /// Persists the offset up to which all the messages have been acknowledged.
+    pub async fn store_queue_offset(&self, acknowledged_offset: u64) -> Result<(), IggyError> {
+        let path = self.get_queue_offset_path();
+        self.storage
+            .partition
+            .save_consumer_offset(acknowledged_offset, &path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to store queue offset: {acknowledged_offset}, path: {path}")
+            })
+    }
+
+    async fn load_queue_offset(&self) -> Result<Option<u64>, IggyError> {
+        let path = self.get_queue_offset_path();
+        let Ok(bytes) = fs::read(&path).await else {
+            return Ok(None);
+        };
+
+        let offset = u64::from_le_bytes(
+            bytes
+                .get(0..8)
+                .ok_or(IggyError::CannotReadFile)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        Ok(Some(offset))
+    }
+
+    fn get_queue_offset_path(&self) -> String {
+        format!("{}/queue", self.offsets_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::duration::IggyDuration;
+
+    fn queue(next_offset: u64) -> MessageQueue {
+        MessageQueue::new(
+            QueuePolicy::visibility_timeout(IggyDuration::new_from_secs(1)),
+            next_offset,
+        )
+    }
+
+    #[test]
+    fn messages_should_be_leased_only_once_until_visibility_timeout_elapses() {
+        let mut queue = queue(0);
+        let now = IggyTimestamp::from(1_000_000);
+        assert_eq!(queue.lease(0, Some(4), 3, now), vec![0, 1, 2]);
+        assert_eq!(queue.lease(0, Some(4), 3, now), vec![3, 4]);
+        assert!(queue.lease(0, Some(4), 3, now).is_empty());
+        assert_eq!(queue.get_in_flight_count(), 5);
+
+        let later = IggyTimestamp::from(2_000_000);
+        assert_eq!(queue.lease(0, Some(4), 2, later), vec![0, 1]);
+    }
+
+    #[test]
+    fn acknowledged_messages_should_not_be_redelivered() {
+        let mut queue = queue(0);
+        let now = IggyTimestamp::from(1_000_000);
+        queue.lease(0, Some(2), 3, now);
+        assert!(queue.acknowledge(1));
+        assert!(!queue.acknowledge(1));
+
+        let later = IggyTimestamp::from(2_000_000);
+        assert_eq!(queue.lease(0, Some(2), 3, later), vec![0, 2]);
+    }
+
+    #[test]
+    fn acknowledged_offset_should_advance_only_up_to_the_oldest_lease() {
+        let mut queue = queue(0);
+        assert!(queue.get_acknowledged_offset().is_none());
+
+        queue.lease(0, Some(3), 4, IggyTimestamp::from(1_000_000));
+        queue.acknowledge(1);
+        queue.acknowledge(2);
+        assert!(queue.get_acknowledged_offset().is_none());
+
+        queue.acknowledge(0);
+        assert_eq!(queue.get_acknowledged_offset(), Some(2));
+
+        queue.acknowledge(3);
+        assert_eq!(queue.get_acknowledged_offset(), Some(3));
+    }
+
+    #[test]
+    fn released_message_should_become_visible_at_given_time() {
+        let mut queue = queue(0);
+        let now = IggyTimestamp::from(1_000_000);
+        queue.lease(0, Some(1), 2, now);
+        assert!(queue.release(1, now));
+        assert!(!queue.release(5, now));
+        assert_eq!(queue.lease(0, Some(1), 2, now), vec![1]);
+    }
+
+    #[test]
+    fn messages_before_first_offset_should_be_skipped() {
+        let mut queue = queue(2);
+        assert_eq!(
+            queue.lease(10, Some(11), 5, IggyTimestamp::from(1_000_000)),
+            vec![10, 11]
+        );
+        assert!(queue
+            .lease(10, None, 5, IggyTimestamp::from(1_000_000))
+            .is_empty());
+    }
+}
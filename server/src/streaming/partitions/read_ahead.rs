@@ -0,0 +1,378 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::local_sizeable::RealSize;
+use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::polling_consumer::PollingConsumer;
+use dashmap::DashMap;
+use iggy::utils::byte_size::IggyByteSize;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// The number of the consecutive sequential polls after which the following messages of the consumer are prefetched.
+const SEQUENTIAL_READS_TO_PREFETCH: u32 = 2;
+
+/// Tracks the read patterns of the consumers polling the partition, and holds the messages prefetched from disk
+/// for the ones reading sequentially, so that their following polls are served from memory.
+///
+/// The poll is sequential if it continues from where the previous one of the consumer ended, allowing to skip forward
+/// by less than the prefetched messages count, e.g. over the expired or compacted messages.
+/// The prefetched messages count towards the cache size, and are released once polled or when the consumer stops reading sequentially.
+#[derive(Debug)]
+pub struct ReadAhead {
+    messages_count: u32,
+    memory_tracker: Arc<CacheMemoryTracker>,
+    consumers: DashMap<PollingConsumer, ConsumerReadPattern>,
+}
+
+#[derive(Debug, Default)]
+struct ConsumerReadPattern {
+    next_offset: Option<u64>,
+    sequential_reads: u32,
+    /// The start offset of the prefetch in progress, if any.
+    prefetching_offset: Option<u64>,
+    prefetched: Option<PrefetchedMessages>,
+}
+
+#[derive(Debug)]
+struct PrefetchedMessages {
+    start_offset: u64,
+    end_offset: u64,
+    messages: VecDeque<Arc<RetainedMessage>>,
+    size_bytes: u64,
+}
+
+impl ReadAhead {
+    pub fn new(messages_count: u32, memory_tracker: Arc<CacheMemoryTracker>) -> Self {
+        Self {
+            messages_count,
+            memory_tracker,
+            consumers: DashMap::new(),
+        }
+    }
+
+    pub fn messages_count(&self) -> u32 {
+        self.messages_count
+    }
+
+    /// Records the poll of the messages from `start_offset` up to `next_offset` (exclusive), and returns the offset
+    /// to prefetch the following messages from, if the consumer reads sequentially and has less than half of the prefetched messages left.
+    /// Nothing is prefetched from `limit_offset` on, as these messages are either cached or not appended yet.
+    pub fn record_read(
+        &self,
+        consumer: PollingConsumer,
+        start_offset: u64,
+        next_offset: u64,
+        limit_offset: u64,
+    ) -> Option<u64> {
+        let mut pattern = self.consumers.entry(consumer).or_default();
+        let is_sequential = pattern.next_offset.is_some_and(|expected_offset| {
+            start_offset >= expected_offset
+                && start_offset - expected_offset < self.messages_count as u64
+        });
+        pattern.next_offset = Some(next_offset);
+        if !is_sequential {
+            pattern.sequential_reads = 0;
+            pattern.prefetching_offset = None;
+            if let Some(prefetched) = pattern.prefetched.take() {
+                self.memory_tracker
+                    .record_prefetch_discard(prefetched.messages.len() as u64);
+                self.release(prefetched);
+            }
+            return None;
+        }
+
+        pattern.sequential_reads = pattern.sequential_reads.saturating_add(1);
+        self.remove_polled_messages(&mut pattern);
+        if pattern.sequential_reads < SEQUENTIAL_READS_TO_PREFETCH
+            || pattern.prefetching_offset.is_some()
+        {
+            return None;
+        }
+
+        let prefetch_offset = pattern
+            .prefetched
+            .as_ref()
+            .map_or(next_offset, |prefetched| prefetched.end_offset + 1);
+        if prefetch_offset >= limit_offset
+            || prefetch_offset - next_offset >= self.messages_count as u64 / 2
+        {
+            return None;
+        }
+
+        pattern.prefetching_offset = Some(prefetch_offset);
+        Some(prefetch_offset)
+    }
+
+    /// Stores the messages prefetched from `start_offset`, unless the consumer stopped reading sequentially meanwhile,
+    /// or they don't fit into the cache.
+    pub fn complete_prefetch(
+        &self,
+        consumer: PollingConsumer,
+        start_offset: u64,
+        messages: Vec<Arc<RetainedMessage>>,
+    ) {
+        let Some(mut pattern) = self.consumers.get_mut(&consumer) else {
+            return;
+        };
+        if pattern.prefetching_offset != Some(start_offset) {
+            return;
+        }
+
+        pattern.prefetching_offset = None;
+        let Some(end_offset) = messages.last().map(|message| message.offset) else {
+            return;
+        };
+
+        let size_bytes = messages
+            .iter()
+            .map(|message| message.real_size().as_bytes_u64())
+            .sum::<u64>();
+        if !self
+            .memory_tracker
+            .will_fit_into_cache(IggyByteSize::from(size_bytes))
+        {
+            return;
+        }
+
+        self.memory_tracker.increment_used_memory(size_bytes);
+        self.memory_tracker.record_prefetch(messages.len() as u64);
+        match pattern.prefetched.as_mut() {
+            Some(prefetched) if prefetched.end_offset + 1 == start_offset => {
+                prefetched.end_offset = end_offset;
+                prefetched.size_bytes += size_bytes;
+                prefetched.messages.extend(messages);
+            }
+            _ => {
+                if let Some(prefetched) = pattern.prefetched.replace(PrefetchedMessages {
+                    start_offset,
+                    end_offset,
+                    messages: messages.into(),
+                    size_bytes,
+                }) {
+                    self.release(prefetched);
+                }
+            }
+        }
+        // The consumer might have already polled some of the messages from disk, while they were being prefetched.
+        self.remove_polled_messages(&mut pattern);
+    }
+
+    /// Lets the consumer prefetch again, after the prefetch from `start_offset` has failed.
+    pub fn cancel_prefetch(&self, consumer: PollingConsumer, start_offset: u64) {
+        if let Some(mut pattern) = self.consumers.get_mut(&consumer) {
+            if pattern.prefetching_offset == Some(start_offset) {
+                pattern.prefetching_offset = None;
+            }
+        }
+    }
+
+    /// Returns the prefetched messages from `start_offset` to `end_offset` (up to a specified count),
+    /// if any consumer has prefetched the whole range.
+    pub fn get_messages(
+        &self,
+        start_offset: u64,
+        end_offset: u64,
+        count: u32,
+    ) -> Option<Vec<Arc<RetainedMessage>>> {
+        for pattern in self.consumers.iter() {
+            let Some(prefetched) = pattern.prefetched.as_ref() else {
+                continue;
+            };
+            if start_offset < prefetched.start_offset || end_offset > prefetched.end_offset {
+                continue;
+            }
+
+            let start_index = prefetched
+                .messages
+                .partition_point(|message| message.offset < start_offset);
+            let messages = prefetched
+                .messages
+                .range(start_index..)
+                .take_while(|message| message.offset <= end_offset)
+                .take(count as usize)
+                .cloned()
+                .collect();
+            self.memory_tracker.record_prefetch_hit();
+            return Some(messages);
+        }
+        None
+    }
+
+    /// Forgets the read patterns of all consumers and releases their prefetched messages.
+    pub fn clear(&self) {
+        self.consumers.retain(|_, pattern| {
+            if let Some(prefetched) = pattern.prefetched.take() {
+                self.release(prefetched);
+            }
+            false
+        });
+    }
+
+    fn remove_polled_messages(&self, pattern: &mut ConsumerReadPattern) {
+        let Some(next_offset) = pattern.next_offset else {
+            return;
+        };
+        let Some(prefetched) = pattern.prefetched.as_mut() else {
+            return;
+        };
+        if next_offset > prefetched.end_offset {
+            let prefetched = pattern.prefetched.take().unwrap();
+            self.release(prefetched);
+            return;
+        }
+
+        let mut released_bytes = 0;
+        while prefetched
+            .messages
+            .front()
+            .is_some_and(|message| message.offset < next_offset)
+        {
+            let message = prefetched.messages.pop_front().unwrap();
+            released_bytes += message.real_size().as_bytes_u64();
+        }
+        prefetched.start_offset = prefetched.start_offset.max(next_offset);
+        prefetched.size_bytes -= released_bytes;
+        self.memory_tracker.decrement_used_memory(released_bytes);
+    }
+
+    fn release(&self, prefetched: PrefetchedMessages) {
+        self.memory_tracker
+            .decrement_used_memory(prefetched.size_bytes);
+    }
+}
+
+impl Drop for ReadAhead {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::resource_quota::MemoryResourceQuota;
+    use crate::configs::system::CacheConfig;
+    use bytes::Bytes;
+    use iggy::messages::send_messages::Message;
+
+    const CONSUMER: PollingConsumer = PollingConsumer::Consumer(1, 1);
+
+    fn create_read_ahead(messages_count: u32) -> ReadAhead {
+        let memory_tracker = Arc::new(CacheMemoryTracker::new(&CacheConfig {
+            enabled: true,
+            size: MemoryResourceQuota::Bytes(IggyByteSize::from(1_000_000)),
+            partition_min_size: IggyByteSize::from(1_000),
+            read_window: "1 m".parse().unwrap(),
+            prefetch_messages_count: messages_count,
+        }));
+        ReadAhead::new(messages_count, memory_tracker)
+    }
+
+    fn create_messages(start_offset: u64, count: u64) -> Vec<Arc<RetainedMessage>> {
+        (start_offset..start_offset + count)
+            .map(|offset| {
+                Arc::new(RetainedMessage::new(
+                    offset,
+                    offset,
+                    Message::new(None, Bytes::from("message"), None),
+                ))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_prefetch_following_messages_after_sequential_reads() {
+        let read_ahead = create_read_ahead(10);
+
+        assert_eq!(read_ahead.record_read(CONSUMER, 0, 5, 100), None);
+        assert_eq!(read_ahead.record_read(CONSUMER, 5, 10, 100), None);
+        assert_eq!(read_ahead.record_read(CONSUMER, 10, 15, 100), Some(15));
+        // The prefetch is already in progress.
+        assert_eq!(read_ahead.record_read(CONSUMER, 15, 20, 100), None);
+    }
+
+    #[test]
+    fn should_not_prefetch_messages_from_limit_offset() {
+        let read_ahead = create_read_ahead(10);
+
+        read_ahead.record_read(CONSUMER, 0, 5, 100);
+        read_ahead.record_read(CONSUMER, 5, 10, 100);
+
+        assert_eq!(read_ahead.record_read(CONSUMER, 10, 15, 15), None);
+    }
+
+    #[test]
+    fn should_serve_prefetched_messages_and_release_polled_ones() {
+        let read_ahead = create_read_ahead(10);
+        read_ahead.record_read(CONSUMER, 0, 5, 100);
+        read_ahead.record_read(CONSUMER, 5, 10, 100);
+        let prefetch_offset = read_ahead.record_read(CONSUMER, 10, 15, 100).unwrap();
+        read_ahead.complete_prefetch(CONSUMER, prefetch_offset, create_messages(15, 10));
+
+        let messages = read_ahead.get_messages(15, 19, 5).unwrap();
+        assert_eq!(
+            messages
+                .iter()
+                .map(|message| message.offset)
+                .collect::<Vec<_>>(),
+            vec![15, 16, 17, 18, 19]
+        );
+        assert!(read_ahead.get_messages(20, 29, 10).is_none());
+
+        let used_bytes = read_ahead.memory_tracker.metrics().used_bytes;
+        read_ahead.record_read(CONSUMER, 15, 20, 100);
+        assert!(read_ahead.memory_tracker.metrics().used_bytes < used_bytes);
+        assert!(read_ahead.get_messages(15, 19, 5).is_none());
+
+        let metrics = read_ahead.memory_tracker.metrics();
+        assert_eq!(metrics.prefetched_messages, 10);
+        assert_eq!(metrics.prefetch_hits, 1);
+    }
+
+    #[test]
+    fn should_discard_prefetched_messages_when_consumer_stops_reading_sequentially() {
+        let read_ahead = create_read_ahead(10);
+        read_ahead.record_read(CONSUMER, 0, 5, 100);
+        read_ahead.record_read(CONSUMER, 5, 10, 100);
+        let prefetch_offset = read_ahead.record_read(CONSUMER, 10, 15, 100).unwrap();
+        read_ahead.complete_prefetch(CONSUMER, prefetch_offset, create_messages(15, 10));
+
+        assert_eq!(read_ahead.record_read(CONSUMER, 50, 55, 100), None);
+
+        assert!(read_ahead.get_messages(15, 19, 5).is_none());
+        let metrics = read_ahead.memory_tracker.metrics();
+        assert_eq!(metrics.prefetch_discarded_messages, 10);
+        assert_eq!(metrics.used_bytes, 0);
+    }
+
+    #[test]
+    fn should_ignore_prefetch_completed_after_consumer_stopped_reading_sequentially() {
+        let read_ahead = create_read_ahead(10);
+        read_ahead.record_read(CONSUMER, 0, 5, 100);
+        read_ahead.record_read(CONSUMER, 5, 10, 100);
+        let prefetch_offset = read_ahead.record_read(CONSUMER, 10, 15, 100).unwrap();
+        read_ahead.record_read(CONSUMER, 50, 55, 100);
+
+        read_ahead.complete_prefetch(CONSUMER, prefetch_offset, create_messages(15, 10));
+
+        assert!(read_ahead.get_messages(15, 19, 5).is_none());
+        assert_eq!(read_ahead.memory_tracker.metrics().used_bytes, 0);
+    }
+}
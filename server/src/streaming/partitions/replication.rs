@@ -0,0 +1,111 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::local_sizeable::LocalSizeable;
+use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::Arc;
+use tracing::trace;
+
+impl Partition {
+    /// Appends the messages fetched from the partition leader, preserving their offsets and timestamps.
+    /// The messages which are already stored in the partition are skipped. Returns the number of the appended messages.
+    pub async fn append_replicated_messages(
+        &mut self,
+        messages: Vec<Arc<RetainedMessage>>,
+        now: IggyTimestamp,
+    ) -> Result<u32, IggyError> {
+        let next_offset = if self.should_increment_offset {
+            self.current_offset + 1
+        } else {
+            0
+        };
+        let messages = messages
+            .into_iter()
+            .filter(|message| message.offset >= next_offset)
+            .collect::<Vec<_>>();
+        let Some(last_message) = messages.last() else {
+            return Ok(0);
+        };
+        if messages[0].offset != next_offset {
+            return Err(IggyError::InvalidOffset(messages[0].offset));
+        }
+
+        let last_offset = last_message.offset;
+        self.ensure_open_segment(now).await?;
+        let messages_count = messages.len() as u32;
+        let batch_size = messages
+            .iter()
+            .map(|message| message.get_size_bytes())
+            .sum::<IggyByteSize>();
+        {
+            let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+            last_segment
+                .append_batch(batch_size, messages_count, &messages)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to append replicated batch into last segment: {last_segment}",
+                    )
+                })?;
+        }
+
+        self.current_offset = last_offset;
+        self.should_increment_offset = true;
+        if let Some(cache) = &mut self.cache {
+            cache.extend(messages);
+        }
+
+        self.unsaved_messages_count += messages_count;
+        let mut persisted_messages_count = 0;
+        {
+            let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+            if self.unsaved_messages_count >= self.config.partition.messages_required_to_save
+                || last_segment.is_full().await
+            {
+                trace!(
+                    "Segment with start offset: {} for partition with ID: {} will be persisted on disk...",
+                    last_segment.start_offset,
+                    self.partition_id
+                );
+                last_segment
+                    .persist_messages(None)
+                    .await
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to persist replicated messages, segment: {last_segment}",
+                        )
+                    })?;
+                persisted_messages_count = self.unsaved_messages_count;
+                self.unsaved_messages_count = 0;
+            }
+        }
+
+        self.fsync_if_required(persisted_messages_count, now)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to fsync partition: {self}")
+            })?;
+        Ok(messages_count)
+    }
+}
@@ -0,0 +1,185 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::segment_rollover_policy::SegmentRolloverPolicy;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::info;
+
+impl Partition {
+    /// Sets the segment rollover policy of the parent topic, which closes the segments by their age, in addition to their size.
+    pub fn set_segment_rollover_policy(
+        &mut self,
+        segment_rollover_policy: Option<SegmentRolloverPolicy>,
+    ) {
+        self.segment_rollover_policy = segment_rollover_policy;
+    }
+
+    /// Returns true if the last segment holds any messages and is older than the max age of the segment rollover policy.
+    pub fn is_segment_rollover_due(&self, now: IggyTimestamp) -> bool {
+        let Some(policy) = &self.segment_rollover_policy else {
+            return false;
+        };
+        let Some(last_segment) = self.segments.last() else {
+            return false;
+        };
+        if last_segment.is_closed || last_segment.get_messages_count() == 0 {
+            return false;
+        }
+
+        policy.is_rollover_due(IggyTimestamp::from(last_segment.start_timestamp), now)
+    }
+
+    /// Writes the buffered messages to disk and closes the last segment, if it's older than the max age of the segment rollover policy.
+    /// The new segment is created once the following messages are appended. Returns true if the segment was closed.
+    pub async fn roll_over_segment(&mut self, now: IggyTimestamp) -> Result<bool, IggyError> {
+        if !self.is_segment_rollover_due(now) {
+            return Ok(false);
+        }
+
+        self.flush_unsaved_buffer(false, now).await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to flush unsaved buffer before segment rollover, partition: {self}")
+        })?;
+        let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+        last_segment.close().await;
+        info!(
+            "Rolled over segment with start offset: {} for partition with ID: {}, topic with ID: {} and stream with ID: {}, as it exceeded the max age.",
+            last_segment.start_offset, self.partition_id, self.topic_id, self.stream_id
+        );
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
+    use crate::streaming::partitions::create_messages;
+    use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
+    use crate::streaming::storage::SystemStorage;
+    use iggy::utils::byte_size::IggyByteSize;
+    use iggy::utils::duration::IggyDuration;
+    use iggy::utils::expiry::IggyExpiry;
+    use iggy::utils::sizeable::Sizeable;
+    use std::sync::atomic::{AtomicU32, AtomicU64};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn given_empty_segment_rollover_should_not_be_due() {
+        let (mut partition, _tempdir) = create_partition().await;
+        partition.set_segment_rollover_policy(Some(SegmentRolloverPolicy::max_age(
+            IggyDuration::new_from_secs(1),
+        )));
+
+        assert!(!partition.is_segment_rollover_due(get_timestamp_after(&partition, 2)));
+    }
+
+    #[tokio::test]
+    async fn given_segment_older_than_max_age_it_should_be_rolled_over() {
+        let (mut partition, _tempdir) = create_partition().await;
+        partition.set_segment_rollover_policy(Some(SegmentRolloverPolicy::max_age(
+            IggyDuration::new_from_secs(60),
+        )));
+        append_messages(&mut partition).await;
+
+        assert!(!partition
+            .roll_over_segment(get_timestamp_after(&partition, 59))
+            .await
+            .unwrap());
+        assert!(partition
+            .roll_over_segment(get_timestamp_after(&partition, 60))
+            .await
+            .unwrap());
+        let last_segment = partition.segments.last().unwrap();
+        assert!(last_segment.is_closed);
+        assert_eq!(last_segment.end_offset, partition.current_offset);
+        assert_eq!(partition.unsaved_messages_count, 0);
+
+        append_messages(&mut partition).await;
+        assert_eq!(partition.get_segments_count(), 2);
+        assert!(!partition.segments.last().unwrap().is_closed);
+    }
+
+    #[tokio::test]
+    async fn given_no_policy_segment_should_never_be_rolled_over() {
+        let (mut partition, _tempdir) = create_partition().await;
+        append_messages(&mut partition).await;
+
+        assert!(!partition
+            .roll_over_segment(get_timestamp_after(&partition, u32::MAX as u64))
+            .await
+            .unwrap());
+    }
+
+    fn get_timestamp_after(partition: &Partition, secs: u64) -> IggyTimestamp {
+        let start_timestamp = partition.segments.last().unwrap().start_timestamp;
+        IggyTimestamp::from(start_timestamp + secs * 1_000_000)
+    }
+
+    async fn append_messages(partition: &mut Partition) {
+        let messages = create_messages();
+        let appendable_batch_info = AppendableBatchInfo {
+            batch_size: messages
+                .iter()
+                .map(|m| m.get_size_bytes())
+                .sum::<IggyByteSize>(),
+            partition_id: partition.partition_id,
+        };
+        partition
+            .append_messages(appendable_batch_info, messages, None, IggyTimestamp::now())
+            .await
+            .unwrap();
+    }
+
+    async fn create_partition() -> (Partition, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let config = Arc::new(SystemConfig {
+            path: temp_dir.path().to_path_buf().to_str().unwrap().to_string(),
+            ..Default::default()
+        });
+        let storage = Arc::new(SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        ));
+
+        (
+            Partition::create(
+                1,
+                2,
+                3,
+                true,
+                config,
+                storage,
+                IggyExpiry::NeverExpire,
+                Arc::new(AtomicU64::new(0)),
+                Arc::new(AtomicU64::new(0)),
+                Arc::new(AtomicU64::new(0)),
+                Arc::new(AtomicU64::new(0)),
+                Arc::new(AtomicU32::new(0)),
+                IggyTimestamp::now(),
+            )
+            .await,
+            temp_dir,
+        )
+    }
+}
@@ -21,21 +21,85 @@ use std::sync::atomic::Ordering;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::partitions::COMPONENT;
 use crate::streaming::segments::*;
+use ahash::AHashMap;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::models::compaction_policy::CompactionKey;
+use iggy::models::segments_verification::{CorruptedBatchInfo, SegmentsVerification};
 use iggy::utils::timestamp::IggyTimestamp;
-use tracing::info;
+use tracing::{info, trace};
 
 pub struct DeletedSegment {
     pub end_offset: u64,
     pub messages_count: u64,
 }
 
+#[derive(Debug, Default)]
+pub struct CompactedSegments {
+    pub segments_count: u32,
+    pub deleted_segments_count: u32,
+    pub messages_count: u64,
+    pub deleted_messages_count: u64,
+    pub io_bytes: u64,
+}
+
 impl Partition {
+    /// Verifies the checksums of the batches in all the segments, the corrupted ones are quarantined and skipped on reading.
+    pub async fn verify_segments(&self) -> Result<SegmentsVerification, IggyError> {
+        let mut verification = SegmentsVerification::default();
+        for segment in &self.segments {
+            let batches = segment.verify().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to verify segment: {segment}")
+            })?;
+            verification.segments_count += 1;
+            verification.batches_count += batches.batches_count;
+            for batch in batches.corrupted_batches {
+                verification.corrupted_batches.push(CorruptedBatchInfo {
+                    segment_start_offset: segment.start_offset,
+                    base_offset: batch.base_offset,
+                    position: batch.position,
+                    length: batch.length,
+                });
+            }
+        }
+
+        info!(
+            "Verified {} segments with {} batches, {} corrupted, for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
+            verification.segments_count,
+            verification.batches_count,
+            verification.corrupted_batches.len(),
+            self.partition_id,
+            self.stream_id,
+            self.topic_id
+        );
+        Ok(verification)
+    }
+
+    /// Encrypts all the plaintext segments in place, returns the number of the encrypted segments.
+    pub async fn encrypt_segments(&mut self) -> Result<u32, IggyError> {
+        let mut encrypted_segments_count = 0;
+        for segment in self.segments.iter_mut() {
+            if segment.encrypt().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to encrypt segment: {segment}")
+            })? {
+                encrypted_segments_count += 1;
+            }
+        }
+        Ok(encrypted_segments_count)
+    }
+
     pub fn get_segments_count(&self) -> u32 {
         self.segments.len() as u32
     }
 
+    /// Returns the size of the log and index files of all the segments stored on the local disk.
+    pub fn get_disk_usage_bytes(&self) -> u64 {
+        self.segments
+            .iter()
+            .map(|segment| segment.get_disk_usage_bytes())
+            .sum()
+    }
+
     pub fn get_segments(&self) -> &Vec<Segment> {
         &self.segments
     }
@@ -79,6 +143,8 @@ impl Partition {
             self.partition_id,
             start_offset,
             self.config.clone(),
+            self.storage.buffer_pool.clone(),
+            self.storage.segment_encryption.clone(),
             self.message_expiry,
             self.size_of_parent_stream.clone(),
             self.size_of_parent_topic.clone(),
@@ -87,6 +153,7 @@ impl Partition {
             self.messages_count_of_parent_topic.clone(),
             self.messages_count.clone(),
         );
+        new_segment.set_enforce_fsync(self.enforces_fsync());
 
         new_segment.persist().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to persist new segment: {new_segment}",)
@@ -130,4 +197,71 @@ impl Partition {
         );
         Ok(deleted_segment)
     }
+
+    /// Compacts the closed segments, retaining only the latest message per key.
+    /// The latest offsets are resolved from all the segments, but the last segment is never compacted, as it's still being appended to.
+    /// The segments in which none of the messages is retained are deleted.
+    pub async fn compact_segments(
+        &mut self,
+        key: &CompactionKey,
+    ) -> Result<CompactedSegments, IggyError> {
+        let mut compacted_segments = CompactedSegments::default();
+        if self.segments.len() < 2 || self.compacted_offset == Some(self.current_offset) {
+            trace!(
+                "No segments to compact for partition with ID: {}, stream with ID: {}, topic with ID: {}",
+                self.partition_id, self.stream_id, self.topic_id
+            );
+            return Ok(compacted_segments);
+        }
+
+        let mut latest_offsets = AHashMap::new();
+        for segment in &self.segments {
+            let messages = segment.get_all_messages().await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load messages to resolve compaction keys, segment: {segment}")
+            })?;
+            for message in messages {
+                if let Some(message_key) = get_compaction_key(&message, key) {
+                    latest_offsets.insert(message_key, message.offset);
+                }
+            }
+        }
+
+        let compacted_offset = self.current_offset;
+        let last_segment_start_offset = self.segments.last().unwrap().start_offset;
+        let mut empty_segments = Vec::new();
+        for segment in self.segments.iter_mut() {
+            if !segment.is_closed || segment.start_offset == last_segment_start_offset {
+                continue;
+            }
+
+            let compacted_segment = segment
+                .compact(|message| match get_compaction_key(message, key) {
+                    Some(message_key) => latest_offsets.get(&message_key) == Some(&message.offset),
+                    None => true,
+                })
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to compact segment: {segment}")
+                })?;
+            compacted_segments.io_bytes += compacted_segment.io_bytes;
+            if compacted_segment.is_empty {
+                empty_segments.push(segment.start_offset);
+                continue;
+            }
+
+            if compacted_segment.removed_messages_count > 0 {
+                compacted_segments.segments_count += 1;
+                compacted_segments.messages_count += compacted_segment.removed_messages_count;
+            }
+        }
+
+        for start_offset in empty_segments {
+            let deleted_segment = self.delete_segment(start_offset).await?;
+            compacted_segments.deleted_segments_count += 1;
+            compacted_segments.deleted_messages_count += deleted_segment.messages_count;
+        }
+
+        self.compacted_offset = Some(compacted_offset);
+        Ok(compacted_segments)
+    }
 }
@@ -58,6 +58,7 @@ impl PartitionStorage for FilePartitionStorage {
             partition.partition_id, partition.stream_id, partition.topic_id, partition.partition_path
         );
         partition.created_at = state.created_at;
+        partition.name = state.name;
         let dir_entries = fs::read_dir(&partition.partition_path).await;
         if fs::read_dir(&partition.partition_path)
                 .await
@@ -78,8 +79,13 @@ impl PartitionStorage for FilePartitionStorage {
         let mut dir_entries = dir_entries.unwrap();
         while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
             let path = dir_entry.path();
-            let extension = path.extension();
-            if extension.is_none() || extension.unwrap() != LOG_EXTENSION {
+            let Some(extension) = path.extension().and_then(|extension| extension.to_str()) else {
+                continue;
+            };
+            // The segment offloaded to the tiered storage has only the marker in place of its log file.
+            if extension != LOG_EXTENSION
+                && (extension != TIERED_EXTENSION || path.with_extension(LOG_EXTENSION).exists())
+            {
                 continue;
             }
             let metadata = dir_entry.metadata().await.unwrap();
@@ -91,7 +97,7 @@ impl PartitionStorage for FilePartitionStorage {
                 .file_name()
                 .into_string()
                 .unwrap()
-                .replace(&format!(".{}", LOG_EXTENSION), "");
+                .replace(&format!(".{}", extension), "");
 
             let start_offset = log_file_name.parse::<u64>().unwrap();
             let mut segment = Segment::create(
@@ -100,6 +106,8 @@ impl PartitionStorage for FilePartitionStorage {
                 partition.partition_id,
                 start_offset,
                 partition.config.clone(),
+                partition.storage.buffer_pool.clone(),
+                partition.storage.segment_encryption.clone(),
                 partition.message_expiry,
                 partition.size_of_parent_stream.clone(),
                 partition.size_of_parent_topic.clone(),
@@ -113,34 +121,47 @@ impl PartitionStorage for FilePartitionStorage {
             let log_path = segment.log_path.to_owned();
             let time_index_path = index_path.replace(INDEX_EXTENSION, "timeindex");
 
-            let index_cache_enabled = partition.config.segment.cache_indexes;
-
-            let index_path_exists = tokio::fs::try_exists(&index_path).await.unwrap();
             let time_index_path_exists = tokio::fs::try_exists(&time_index_path).await.unwrap();
-
-            // Rebuild indexes in 2 cases:
-            // 1. Index cache is enabled and index at path does not exists.
-            // 2. Index cache is enabled and time index at path exists.
-            if index_cache_enabled && (!index_path_exists || time_index_path_exists) {
-                warn!(
-                    "Index at path {} does not exist, rebuilding it based on {}...",
-                    index_path, log_path
-                );
-                let now = tokio::time::Instant::now();
-                let index_rebuilder =
-                    IndexRebuilder::new(log_path.clone(), index_path.clone(), start_offset);
-                index_rebuilder.rebuild().await.unwrap_or_else(|e| {
-                    panic!(
-                        "Failed to rebuild index for partition with ID: {} for
-                    stream with ID: {} and topic with ID: {}. Error: {e}",
-                        partition.partition_id, partition.stream_id, partition.topic_id,
-                    )
-                });
-                info!(
-                    "Rebuilding index for path {} finished, it took {} ms",
-                    index_path,
-                    now.elapsed().as_millis()
-                );
+            let index_rebuilder =
+                IndexRebuilder::new(log_path.clone(), index_path.clone(), start_offset);
+
+            // Rebuild indexes in 3 cases:
+            // 1. Index at path does not exist, or it's truncated and doesn't cover the whole log.
+            // 2. Time index at path exists (legacy format).
+            // 3. Full rebuild is forced in the recovery config.
+            if extension == LOG_EXTENSION {
+                let rebuild_reason = if partition.config.recovery.rebuild_indexes {
+                    Some("rebuild is forced")
+                } else if time_index_path_exists {
+                    Some("legacy time index exists")
+                } else if index_rebuilder.needs_rebuild().await.unwrap_or(true) {
+                    Some("it's missing or truncated")
+                } else {
+                    None
+                };
+
+                if let Some(rebuild_reason) = rebuild_reason {
+                    warn!(
+                        "Rebuilding index at path {} based on {}, because {rebuild_reason}...",
+                        index_path, log_path
+                    );
+                    let now = tokio::time::Instant::now();
+                    index_rebuilder
+                        .rebuild()
+                        .await
+                        .with_error_context(|error| {
+                            format!(
+                                "{COMPONENT} (error: {error}) - failed to rebuild index for partition with ID: {} for stream with ID: {} and topic with ID: {}",
+                                partition.partition_id, partition.stream_id, partition.topic_id,
+                            )
+                        })
+                        .map_err(|_| IggyError::CannotReadFile)?;
+                    info!(
+                        "Rebuilding index for path {} finished, it took {} ms",
+                        index_path,
+                        now.elapsed().as_millis()
+                    );
+                }
             }
 
             // Remove legacy time index if it exists.
@@ -164,7 +185,7 @@ impl PartitionStorage for FilePartitionStorage {
                 partition.should_increment_offset = segment.size_bytes > 0;
             }
 
-            if partition.config.partition.validate_checksum {
+            if partition.config.partition.validate_checksum && !segment.is_tiered() {
                 info!("Validating messages checksum for partition with ID: {} and segment with start offset: {}...", partition.partition_id, segment.start_offset);
                 segment.load_message_checksums().await?;
                 info!("Validated messages checksum for partition with ID: {} and segment with start offset: {}.", partition.partition_id, segment.start_offset);
@@ -172,7 +193,11 @@ impl PartitionStorage for FilePartitionStorage {
 
             // Load the unique message IDs for the partition if the deduplication feature is enabled.
             let mut unique_message_ids_count = 0;
-            if let Some(message_deduplicator) = &partition.message_deduplicator {
+            if let Some(message_deduplicator) = partition
+                .message_deduplicator
+                .as_ref()
+                .filter(|_| !segment.is_tiered())
+            {
                 info!("Loading unique message IDs for partition with ID: {} and segment with start offset: {}...", partition.partition_id, segment.start_offset);
                 let message_ids = segment.load_message_ids().await.with_error_context(|error| {
                     format!("{COMPONENT} (error: {error}) - failed to load message ids, segment: {segment}",)
@@ -211,6 +236,12 @@ impl PartitionStorage for FilePartitionStorage {
             }
 
             segment.end_offset = end_offsets[end_offset_index];
+            // The compacted segments are smaller than the max segment size, yet only the last segment can be appended to.
+            if !segment.is_closed {
+                segment.is_closed = true;
+                segment.unsaved_messages = None;
+                segment.shutdown_writing().await;
+            }
         }
 
         if !partition.segments.is_empty() {
@@ -338,6 +369,18 @@ impl PartitionStorage for FilePartitionStorage {
             ));
         }
 
+        // The partition moved to another data directory is linked from the partition path, thus both need to be deleted.
+        if let Ok(data_path) = fs::read_link(&partition.partition_path).await {
+            if fs::remove_dir_all(&data_path).await.is_err() {
+                error!("Cannot delete partition data directory: {} for partition with ID: {} for topic with ID: {} for stream with ID: {}.", data_path.display(), partition.partition_id, partition.topic_id, partition.stream_id);
+                return Err(IggyError::CannotDeletePartitionDirectory(
+                    partition.partition_id,
+                    partition.stream_id,
+                    partition.topic_id,
+                ));
+            }
+        }
+
         if fs::remove_dir_all(&partition.partition_path).await.is_err() {
             error!("Cannot delete partition directory: {} for partition with ID: {} for topic with ID: {} for stream with ID: {}.", partition.partition_path, partition.partition_id, partition.topic_id, partition.stream_id);
             return Err(IggyError::CannotDeletePartitionDirectory(
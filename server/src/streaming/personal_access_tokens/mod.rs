@@ -17,3 +17,4 @@
  */
 
 pub mod personal_access_token;
+pub mod token_scope;
@@ -16,12 +16,16 @@
  * under the License.
  */
 
+use crate::streaming::clients::access_rules::AddressRange;
 use crate::streaming::utils::hash;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
 use iggy::models::user_info::UserId;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::text::as_base64;
 use iggy::utils::timestamp::IggyTimestamp;
 use ring::rand::SecureRandom;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 const SIZE: usize = 50;
 
@@ -31,6 +35,11 @@ pub struct PersonalAccessToken {
     pub name: String,
     pub token: String,
     pub expiry_at: Option<IggyTimestamp>,
+    pub scope: Option<PersonalAccessTokenScope>,
+    pub allowed_addresses: Option<Vec<String>>,
+    allowed_ranges: Option<Vec<AddressRange>>,
+    // The last usage is tracked only in memory, to avoid writing the state on each login.
+    last_used_at: AtomicU64,
 }
 
 impl PersonalAccessToken {
@@ -40,6 +49,8 @@ impl PersonalAccessToken {
         name: &str,
         now: IggyTimestamp,
         expiry: IggyExpiry,
+        scope: Option<PersonalAccessTokenScope>,
+        allowed_addresses: Option<Vec<String>>,
     ) -> (Self, String) {
         let mut buffer: [u8; SIZE] = [0; SIZE];
         let system_random = ring::rand::SystemRandom::new();
@@ -47,12 +58,14 @@ impl PersonalAccessToken {
         let token = as_base64(&buffer);
         let token_hash = Self::hash_token(&token);
         (
-            Self {
+            Self::raw(
                 user_id,
-                name: name.to_string(),
-                token: token_hash,
-                expiry_at: Self::calculate_expiry_at(now, expiry),
-            },
+                name,
+                &token_hash,
+                Self::calculate_expiry_at(now, expiry),
+                scope,
+                allowed_addresses,
+            ),
             token,
         )
     }
@@ -62,12 +75,25 @@ impl PersonalAccessToken {
         name: &str,
         token_hash: &str,
         expiry_at: Option<IggyTimestamp>,
+        scope: Option<PersonalAccessTokenScope>,
+        allowed_addresses: Option<Vec<String>>,
     ) -> Self {
+        // The addresses are validated when the token is created, so the invalid ones can be only skipped here.
+        let allowed_ranges = allowed_addresses.as_ref().map(|addresses| {
+            addresses
+                .iter()
+                .filter_map(|address| address.parse::<AddressRange>().ok())
+                .collect()
+        });
         Self {
             user_id,
             name: name.into(),
             token: token_hash.into(),
             expiry_at,
+            scope,
+            allowed_addresses,
+            allowed_ranges,
+            last_used_at: AtomicU64::new(0),
         }
     }
 
@@ -78,6 +104,25 @@ impl PersonalAccessToken {
         }
     }
 
+    pub fn is_address_allowed(&self, address: &IpAddr) -> bool {
+        let address = address.to_canonical();
+        match &self.allowed_ranges {
+            None => true,
+            Some(ranges) => ranges.iter().any(|range| range.contains(&address)),
+        }
+    }
+
+    pub fn last_used_at(&self) -> Option<IggyTimestamp> {
+        match self.last_used_at.load(Ordering::Acquire) {
+            0 => None,
+            last_used_at => Some(last_used_at.into()),
+        }
+    }
+
+    pub fn mark_used(&self, now: IggyTimestamp) {
+        self.last_used_at.store(now.as_micros(), Ordering::Release)
+    }
+
     pub fn hash_token(token: &str) -> String {
         hash::calculate_256(token.as_bytes())
     }
@@ -105,7 +150,7 @@ mod tests {
         let now = IggyTimestamp::now();
         let name = "test_token";
         let (personal_access_token, raw_token) =
-            PersonalAccessToken::new(user_id, name, now, IggyExpiry::NeverExpire);
+            PersonalAccessToken::new(user_id, name, now, IggyExpiry::NeverExpire, None, None);
         assert_eq!(personal_access_token.name, name);
         assert!(!personal_access_token.token.is_empty());
         assert!(!raw_token.is_empty());
@@ -123,8 +168,34 @@ mod tests {
         let expiry_ms = 10;
         let expiry = IggyExpiry::ExpireDuration(IggyDuration::from(expiry_ms));
         let name = "test_token";
-        let (personal_access_token, _) = PersonalAccessToken::new(user_id, name, now, expiry);
+        let (personal_access_token, _) =
+            PersonalAccessToken::new(user_id, name, now, expiry, None, None);
         let later = IggyTimestamp::from(now.as_micros() + expiry_ms + 1);
         assert!(personal_access_token.is_expired(later));
     }
+
+    #[test]
+    fn personal_access_token_should_be_usable_only_from_allowed_addresses() {
+        let (personal_access_token, _) = PersonalAccessToken::new(
+            1,
+            "test_token",
+            IggyTimestamp::now(),
+            IggyExpiry::NeverExpire,
+            None,
+            Some(vec!["10.0.0.0/8".to_string(), "192.168.1.10".to_string()]),
+        );
+        assert!(personal_access_token.is_address_allowed(&"10.1.2.3".parse().unwrap()));
+        assert!(personal_access_token.is_address_allowed(&"192.168.1.10".parse().unwrap()));
+        assert!(!personal_access_token.is_address_allowed(&"192.168.1.11".parse().unwrap()));
+    }
+
+    #[test]
+    fn personal_access_token_last_usage_should_be_tracked() {
+        let now = IggyTimestamp::now();
+        let (personal_access_token, _) =
+            PersonalAccessToken::new(1, "test_token", now, IggyExpiry::NeverExpire, None, None);
+        assert!(personal_access_token.last_used_at().is_none());
+        personal_access_token.mark_used(now);
+        assert_eq!(personal_access_token.last_used_at(), Some(now));
+    }
 }
@@ -0,0 +1,117 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::users::authorizers::AuthorizationRequest;
+use iggy::command::*;
+use iggy::error::IggyError;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
+use tracing::debug;
+
+/// The actions allowed for the read-only personal access tokens.
+/// Storing the consumer offsets and the consumer group membership are included, as the consumers need them to read the messages.
+const READ_ONLY_ACTIONS: &[&str] = &[
+    GET_STATS,
+    GET_CONFIG,
+    GET_AUDIT_LOG,
+    GET_CLIENT,
+    GET_CLIENTS,
+    GET_USER,
+    GET_USERS,
+    GET_STREAM,
+    GET_STREAMS,
+    GET_TOPIC,
+    GET_TOPICS,
+    GET_CONSUMER_GROUP,
+    GET_CONSUMER_GROUPS,
+    JOIN_CONSUMER_GROUP,
+    LEAVE_CONSUMER_GROUP,
+    HEARTBEAT_CONSUMER_GROUP,
+    POLL_MESSAGES,
+    GET_CONSUMER_OFFSET,
+    STORE_CONSUMER_OFFSET,
+    GET_SCHEMAS,
+];
+
+/// Verifies if the action is allowed by the scope of the personal access token the session has been created with,
+/// before it's passed to the authorizer. The sessions without the scope aren't limited.
+pub fn authorize(
+    scope: Option<&PersonalAccessTokenScope>,
+    request: &AuthorizationRequest,
+) -> Result<(), IggyError> {
+    let Some(scope) = scope else {
+        return Ok(());
+    };
+
+    if is_allowed(scope, request) {
+        return Ok(());
+    }
+
+    debug!(
+        "Action: {} for user with ID: {} is not allowed by the personal access token scope.",
+        request.action, request.user_id
+    );
+    Err(IggyError::Unauthorized)
+}
+
+fn is_allowed(scope: &PersonalAccessTokenScope, request: &AuthorizationRequest) -> bool {
+    if scope.read_only && !READ_ONLY_ACTIONS.contains(&request.action) {
+        return false;
+    }
+
+    match (&scope.streams, request.stream_id) {
+        (None, _) => true,
+        (Some(streams), Some(stream_id)) => streams.contains(&stream_id),
+        (Some(_), None) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn any_action_should_be_allowed_without_token_scope() {
+        assert!(authorize(None, &AuthorizationRequest::new(1, CREATE_STREAM)).is_ok());
+    }
+
+    #[test]
+    fn only_read_actions_should_be_allowed_given_read_only_scope() {
+        let scope = PersonalAccessTokenScope {
+            read_only: true,
+            streams: None,
+        };
+        let scope = Some(&scope);
+        assert!(authorize(scope, &AuthorizationRequest::topic(1, POLL_MESSAGES, 1, 1)).is_ok());
+        assert!(authorize(scope, &AuthorizationRequest::new(1, GET_STREAMS)).is_ok());
+        assert!(authorize(scope, &AuthorizationRequest::topic(1, SEND_MESSAGES, 1, 1)).is_err());
+        assert!(authorize(scope, &AuthorizationRequest::new(1, CREATE_USER)).is_err());
+    }
+
+    #[test]
+    fn only_actions_on_given_streams_should_be_allowed_given_streams_scope() {
+        let scope = PersonalAccessTokenScope {
+            read_only: false,
+            streams: Some(vec![1, 2]),
+        };
+        let scope = Some(&scope);
+        assert!(authorize(scope, &AuthorizationRequest::topic(1, SEND_MESSAGES, 2, 1)).is_ok());
+        assert!(authorize(scope, &AuthorizationRequest::stream(1, DELETE_STREAM, 1)).is_ok());
+        assert!(authorize(scope, &AuthorizationRequest::topic(1, POLL_MESSAGES, 3, 1)).is_err());
+        assert!(authorize(scope, &AuthorizationRequest::new(1, GET_STREAMS)).is_err());
+    }
+}
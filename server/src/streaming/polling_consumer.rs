@@ -20,7 +20,7 @@ use crate::streaming::utils::hash;
 use iggy::identifier::{IdKind, Identifier};
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub enum PollingConsumer {
     Consumer(u32, u32),      // Consumer ID + Partition ID
     ConsumerGroup(u32, u32), // Consumer Group ID + Member ID
@@ -0,0 +1,407 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::{QuotaMode, QuotasConfig};
+use crate::streaming::diagnostics::metrics::QuotaDirection;
+use crate::streaming::topics::throttling::TopicThrottle;
+use ahash::AHashMap;
+use iggy::error::IggyError;
+use iggy::models::quota::{Quota, QuotaLimits, QuotaPrincipal};
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::Mutex;
+
+/// The throughput quotas of the users and topics, using the limits from the configuration unless overridden at runtime.
+/// The overrides and the usage are kept only in memory, so they are reset on the server restart.
+#[derive(Debug)]
+pub struct QuotaManager {
+    enabled: bool,
+    mode: QuotaMode,
+    max_delay: IggyDuration,
+    user_limits: QuotaLimits,
+    topic_limits: QuotaLimits,
+    quotas: Mutex<AHashMap<QuotaPrincipal, QuotaState>>,
+}
+
+#[derive(Debug)]
+struct QuotaState {
+    limits: QuotaLimits,
+    is_default: bool,
+    produce_bytes: Option<TopicThrottle>,
+    produce_messages: Option<TopicThrottle>,
+    consume_bytes: Option<TopicThrottle>,
+    consume_messages: Option<TopicThrottle>,
+    produced_bytes: u64,
+    produced_messages: u64,
+    consumed_bytes: u64,
+    consumed_messages: u64,
+    delayed_requests: u64,
+    rejected_requests: u64,
+}
+
+impl QuotaManager {
+    pub fn new(config: &QuotasConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            mode: config.mode,
+            max_delay: config.max_delay,
+            user_limits: config.user,
+            topic_limits: config.topic,
+            quotas: Mutex::new(AHashMap::new()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Overrides the limits of the principal, or restores the default ones if the limits are not provided.
+    pub fn set_limits(&self, principal: QuotaPrincipal, limits: Option<QuotaLimits>) {
+        let default_limits = self.default_limits(&principal);
+        let mut quotas = self.quotas.lock().unwrap();
+        let state = quotas
+            .entry(principal)
+            .or_insert_with(|| QuotaState::new(default_limits));
+        state.is_default = limits.is_none();
+        state.limits = limits.unwrap_or(default_limits);
+    }
+
+    /// Returns the time the request has to be delayed for to stay within the quotas of all the principals,
+    /// or fails if it exceeds the max delay (or any delay at all in the reject mode).
+    pub fn acquire(
+        &self,
+        direction: QuotaDirection,
+        principals: &[QuotaPrincipal],
+        now: IggyTimestamp,
+    ) -> Result<IggyDuration, IggyError> {
+        if !self.enabled {
+            return Ok(IggyDuration::default());
+        }
+
+        let mut quotas = self.quotas.lock().unwrap();
+        let mut delay = 0;
+        let mut exceeded_principal = None;
+        for principal in principals {
+            let default_limits = self.default_limits(principal);
+            let state = quotas
+                .entry(*principal)
+                .or_insert_with(|| QuotaState::new(default_limits));
+            let principal_delay = state.delay(direction, now);
+            if principal_delay > delay {
+                delay = principal_delay;
+                exceeded_principal = Some(*principal);
+            }
+        }
+
+        let Some(principal) = exceeded_principal else {
+            return Ok(IggyDuration::default());
+        };
+
+        let state = quotas.get_mut(&principal).unwrap();
+        if self.mode == QuotaMode::Reject || delay > self.max_delay.as_micros() {
+            state.rejected_requests += 1;
+            return Err(IggyError::QuotaExceeded(principal.to_string()));
+        }
+
+        state.delayed_requests += 1;
+        Ok(IggyDuration::from(delay))
+    }
+
+    /// Records the messages produced or consumed by the request, counted against the quotas of all the principals.
+    pub fn record(
+        &self,
+        direction: QuotaDirection,
+        principals: &[QuotaPrincipal],
+        bytes: u64,
+        messages: u64,
+        now: IggyTimestamp,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let mut quotas = self.quotas.lock().unwrap();
+        for principal in principals {
+            let default_limits = self.default_limits(principal);
+            quotas
+                .entry(*principal)
+                .or_insert_with(|| QuotaState::new(default_limits))
+                .record(direction, bytes, messages, now);
+        }
+    }
+
+    pub fn get_quotas(&self) -> Vec<Quota> {
+        let quotas = self.quotas.lock().unwrap();
+        let mut quotas = quotas
+            .iter()
+            .map(|(principal, state)| state.to_quota(*principal))
+            .collect::<Vec<_>>();
+        quotas.sort_by_key(|quota| match quota.principal {
+            QuotaPrincipal::User { user_id } => (0, user_id, 0),
+            QuotaPrincipal::Topic {
+                stream_id,
+                topic_id,
+            } => (1, stream_id, topic_id),
+        });
+        quotas
+    }
+
+    pub fn remove(&self, principal: &QuotaPrincipal) {
+        self.quotas.lock().unwrap().remove(principal);
+    }
+
+    pub fn remove_stream(&self, stream_id: u32) {
+        self.quotas.lock().unwrap().retain(|principal, _| {
+            !matches!(principal, QuotaPrincipal::Topic { stream_id: id, .. } if *id == stream_id)
+        });
+    }
+
+    fn default_limits(&self, principal: &QuotaPrincipal) -> QuotaLimits {
+        match principal {
+            QuotaPrincipal::User { .. } => self.user_limits,
+            QuotaPrincipal::Topic { .. } => self.topic_limits,
+        }
+    }
+}
+
+impl QuotaState {
+    fn new(limits: QuotaLimits) -> Self {
+        Self {
+            limits,
+            is_default: true,
+            produce_bytes: None,
+            produce_messages: None,
+            consume_bytes: None,
+            consume_messages: None,
+            produced_bytes: 0,
+            produced_messages: 0,
+            consumed_bytes: 0,
+            consumed_messages: 0,
+            delayed_requests: 0,
+            rejected_requests: 0,
+        }
+    }
+
+    fn delay(&mut self, direction: QuotaDirection, now: IggyTimestamp) -> u64 {
+        self.throttles(direction, now)
+            .into_iter()
+            .flatten()
+            .map(|throttle| throttle.delay(now))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn record(&mut self, direction: QuotaDirection, bytes: u64, messages: u64, now: IggyTimestamp) {
+        match direction {
+            QuotaDirection::Produce => {
+                self.produced_bytes += bytes;
+                self.produced_messages += messages;
+            }
+            QuotaDirection::Consume => {
+                self.consumed_bytes += bytes;
+                self.consumed_messages += messages;
+            }
+        }
+
+        let [bytes_throttle, messages_throttle] = self.throttles(direction, now);
+        if let Some(throttle) = bytes_throttle {
+            throttle.record(bytes, now);
+        }
+        if let Some(throttle) = messages_throttle {
+            throttle.record(messages, now);
+        }
+    }
+
+    /// Returns the throttles of the bytes and the messages, recreated whenever their limits have changed.
+    fn throttles(
+        &mut self,
+        direction: QuotaDirection,
+        now: IggyTimestamp,
+    ) -> [Option<&mut TopicThrottle>; 2] {
+        match direction {
+            QuotaDirection::Produce => [
+                throttle(
+                    &mut self.produce_bytes,
+                    self.limits.produce_bytes_per_second.as_bytes_u64(),
+                    now,
+                ),
+                throttle(
+                    &mut self.produce_messages,
+                    self.limits.produce_messages_per_second,
+                    now,
+                ),
+            ],
+            QuotaDirection::Consume => [
+                throttle(
+                    &mut self.consume_bytes,
+                    self.limits.consume_bytes_per_second.as_bytes_u64(),
+                    now,
+                ),
+                throttle(
+                    &mut self.consume_messages,
+                    self.limits.consume_messages_per_second,
+                    now,
+                ),
+            ],
+        }
+    }
+
+    fn to_quota(&self, principal: QuotaPrincipal) -> Quota {
+        Quota {
+            principal,
+            limits: self.limits,
+            is_default: self.is_default,
+            produced_bytes: self.produced_bytes,
+            produced_messages: self.produced_messages,
+            consumed_bytes: self.consumed_bytes,
+            consumed_messages: self.consumed_messages,
+            delayed_requests: self.delayed_requests,
+            rejected_requests: self.rejected_requests,
+        }
+    }
+}
+
+fn throttle(
+    throttle: &mut Option<TopicThrottle>,
+    rate: u64,
+    now: IggyTimestamp,
+) -> Option<&mut TopicThrottle> {
+    if rate == 0 {
+        *throttle = None;
+        return None;
+    }
+
+    // The throttle of the messages counts each message as a single byte.
+    if !matches!(throttle, Some(current) if current.max_throughput.as_bytes_u64() == rate) {
+        *throttle = Some(TopicThrottle::new(IggyByteSize::from(rate), None, now));
+    }
+    throttle.as_mut()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER: QuotaPrincipal = QuotaPrincipal::User { user_id: 1 };
+    const TOPIC: QuotaPrincipal = QuotaPrincipal::Topic {
+        stream_id: 1,
+        topic_id: 1,
+    };
+
+    fn manager(mode: QuotaMode) -> QuotaManager {
+        QuotaManager::new(&config(true, mode))
+    }
+
+    fn config(enabled: bool, mode: QuotaMode) -> QuotasConfig {
+        QuotasConfig {
+            enabled,
+            mode,
+            max_delay: IggyDuration::new_from_secs(1),
+            user: QuotaLimits {
+                produce_messages_per_second: 100,
+                ..Default::default()
+            },
+            topic: QuotaLimits::default(),
+        }
+    }
+
+    #[test]
+    fn requests_exceeding_quota_should_be_delayed() {
+        let manager = manager(QuotaMode::Delay);
+        let now = IggyTimestamp::from(1_000_000);
+        let principals = [USER, TOPIC];
+
+        let delay = manager.acquire(QuotaDirection::Produce, &principals, now);
+        assert!(delay.unwrap().is_zero());
+        manager.record(QuotaDirection::Produce, &principals, 1000, 150, now);
+
+        let delay = manager
+            .acquire(QuotaDirection::Produce, &principals, now)
+            .unwrap();
+        assert_eq!(delay.as_micros(), 510_000);
+        let delay = manager.acquire(QuotaDirection::Consume, &principals, now);
+        assert!(delay.unwrap().is_zero());
+
+        let quotas = manager.get_quotas();
+        assert_eq!(quotas.len(), 2);
+        assert_eq!(quotas[0].principal, USER);
+        assert_eq!(quotas[0].produced_messages, 150);
+        assert_eq!(quotas[0].delayed_requests, 1);
+        assert_eq!(quotas[1].principal, TOPIC);
+        assert_eq!(quotas[1].produced_bytes, 1000);
+        assert_eq!(quotas[1].delayed_requests, 0);
+    }
+
+    #[test]
+    fn requests_exceeding_max_delay_should_be_rejected() {
+        let manager = manager(QuotaMode::Delay);
+        let now = IggyTimestamp::from(1_000_000);
+
+        manager.record(QuotaDirection::Produce, &[USER], 0, 250, now);
+        assert!(matches!(
+            manager.acquire(QuotaDirection::Produce, &[USER], now),
+            Err(IggyError::QuotaExceeded(_))
+        ));
+        assert_eq!(manager.get_quotas()[0].rejected_requests, 1);
+    }
+
+    #[test]
+    fn requests_exceeding_quota_should_be_rejected_in_reject_mode() {
+        let manager = manager(QuotaMode::Reject);
+        let now = IggyTimestamp::from(1_000_000);
+
+        manager.record(QuotaDirection::Produce, &[USER], 0, 100, now);
+        assert!(matches!(
+            manager.acquire(QuotaDirection::Produce, &[USER], now),
+            Err(IggyError::QuotaExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn overridden_limits_should_apply_until_restored_to_default() {
+        let manager = manager(QuotaMode::Reject);
+        let now = IggyTimestamp::from(1_000_000);
+
+        manager.set_limits(USER, Some(QuotaLimits::default()));
+        manager.record(QuotaDirection::Produce, &[USER], 0, 1000, now);
+        assert!(manager
+            .acquire(QuotaDirection::Produce, &[USER], now)
+            .is_ok());
+        assert!(!manager.get_quotas()[0].is_default);
+
+        manager.set_limits(USER, None);
+        manager.record(QuotaDirection::Produce, &[USER], 0, 1000, now);
+        assert!(manager
+            .acquire(QuotaDirection::Produce, &[USER], now)
+            .is_err());
+        assert!(manager.get_quotas()[0].is_default);
+    }
+
+    #[test]
+    fn quotas_should_be_ignored_when_disabled() {
+        let manager = QuotaManager::new(&config(false, QuotaMode::Reject));
+        let now = IggyTimestamp::from(1_000_000);
+
+        manager.record(QuotaDirection::Produce, &[USER], 0, 1000, now);
+        assert!(manager
+            .acquire(QuotaDirection::Produce, &[USER], now)
+            .is_ok());
+        assert!(manager.get_quotas().is_empty());
+    }
+}
@@ -0,0 +1,288 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::models::topic_schema::{SchemaKind, TopicSchema};
+use iggy::schemas::schema_compatibility::SchemaCompatibility;
+use prost_reflect::{Cardinality, DescriptorPool, Kind, MessageDescriptor};
+use serde_json::{Map, Value};
+
+/// Checks whether the new version of the schema is compatible with the previous one according to the compatibility level,
+/// returning the reason of the incompatibility as an error.
+///
+/// The JSON schemas are compared by the `type`, `properties`, `required` and `additionalProperties` keywords (including the nested objects),
+/// while the protobuf messages are compared by the field numbers, types and cardinality.
+pub fn check_compatibility(
+    compatibility: SchemaCompatibility,
+    previous: &TopicSchema,
+    next: &TopicSchema,
+) -> Result<(), String> {
+    if compatibility == SchemaCompatibility::None {
+        return Ok(());
+    }
+
+    if previous.kind != next.kind {
+        return Err(format!(
+            "schema kind cannot be changed from {} to {}",
+            previous.kind, next.kind
+        ));
+    }
+
+    match compatibility {
+        SchemaCompatibility::None => Ok(()),
+        SchemaCompatibility::Backward => can_read(next, previous),
+        SchemaCompatibility::Forward => can_read(previous, next),
+        SchemaCompatibility::Full => {
+            can_read(next, previous)?;
+            can_read(previous, next)
+        }
+    }
+}
+
+/// Checks whether the data written using the writer schema can be read using the reader schema.
+fn can_read(reader: &TopicSchema, writer: &TopicSchema) -> Result<(), String> {
+    match reader.kind {
+        SchemaKind::Json => can_read_json("$", &parse_json(reader)?, &parse_json(writer)?),
+        SchemaKind::Protobuf => {
+            can_read_protobuf(&parse_protobuf(reader)?, &parse_protobuf(writer)?)
+        }
+    }
+}
+
+fn can_read_json(path: &str, reader: &Value, writer: &Value) -> Result<(), String> {
+    if let (Some(reader_type), Some(writer_type)) = (reader.get("type"), writer.get("type")) {
+        if reader_type != writer_type {
+            return Err(format!(
+                "type of {path} was changed from {writer_type} to {reader_type}"
+            ));
+        }
+    }
+
+    let writer_required = get_required(writer);
+    for property in get_required(reader) {
+        if !writer_required.contains(&property) {
+            return Err(format!(
+                "property {path}.{property} is required by the reader schema, but not by the writer schema"
+            ));
+        }
+    }
+
+    let empty = Map::new();
+    let reader_properties = get_properties(reader).unwrap_or(&empty);
+    let writer_properties = get_properties(writer).unwrap_or(&empty);
+    if reader.get("additionalProperties") == Some(&Value::Bool(false)) {
+        if let Some(property) = writer_properties
+            .keys()
+            .find(|property| !reader_properties.contains_key(*property))
+        {
+            return Err(format!(
+                "property {path}.{property} is not allowed by the reader schema"
+            ));
+        }
+    }
+
+    for (name, reader_property) in reader_properties {
+        if let Some(writer_property) = writer_properties.get(name) {
+            can_read_json(&format!("{path}.{name}"), reader_property, writer_property)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn get_properties(schema: &Value) -> Option<&Map<String, Value>> {
+    schema.get("properties").and_then(Value::as_object)
+}
+
+fn get_required(schema: &Value) -> Vec<&str> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| required.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+fn can_read_protobuf(reader: &MessageDescriptor, writer: &MessageDescriptor) -> Result<(), String> {
+    for reader_field in reader.fields() {
+        let Some(writer_field) = writer.get_field(reader_field.number()) else {
+            if reader_field.cardinality() == Cardinality::Required {
+                return Err(format!(
+                    "required field {} (number: {}) is missing in the writer schema",
+                    reader_field.name(),
+                    reader_field.number()
+                ));
+            }
+            continue;
+        };
+
+        let reader_kind = get_kind_name(&reader_field.kind());
+        let writer_kind = get_kind_name(&writer_field.kind());
+        if reader_kind != writer_kind
+            || reader_field.is_list() != writer_field.is_list()
+            || reader_field.is_map() != writer_field.is_map()
+        {
+            return Err(format!(
+                "field number: {} was changed from {} ({writer_kind}) to {} ({reader_kind})",
+                reader_field.number(),
+                writer_field.name(),
+                reader_field.name(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn get_kind_name(kind: &Kind) -> String {
+    match kind {
+        Kind::Message(message) => message.full_name().to_string(),
+        Kind::Enum(enumeration) => enumeration.full_name().to_string(),
+        kind => format!("{kind:?}").to_lowercase(),
+    }
+}
+
+fn parse_json(schema: &TopicSchema) -> Result<Value, String> {
+    serde_json::from_slice(&schema.definition)
+        .map_err(|error| format!("invalid JSON document: {error}"))
+}
+
+fn parse_protobuf(schema: &TopicSchema) -> Result<MessageDescriptor, String> {
+    let message_type = schema.message_type.as_deref().unwrap_or_default();
+    DescriptorPool::decode(schema.definition.clone())
+        .map_err(|error| format!("invalid protobuf file descriptor set: {error}"))?
+        .get_message_by_name(message_type)
+        .ok_or_else(|| format!("protobuf message type: {message_type} was not found"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use prost_reflect::prost::Message;
+    use prost_reflect::prost_types::field_descriptor_proto::{Label, Type};
+    use prost_reflect::prost_types::{
+        DescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet,
+    };
+
+    const ORDER_V1: &str = r#"{
+        "type": "object",
+        "properties": {
+            "id": { "type": "integer" },
+            "product": { "type": "string" }
+        },
+        "required": ["id"]
+    }"#;
+
+    #[test]
+    fn adding_optional_json_property_should_be_fully_compatible() {
+        let next = r#"{
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "product": { "type": "string" },
+                "quantity": { "type": "integer" }
+            },
+            "required": ["id"]
+        }"#;
+        assert!(check_compatibility(
+            SchemaCompatibility::Full,
+            &TopicSchema::json(ORDER_V1),
+            &TopicSchema::json(next)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn adding_required_json_property_should_be_only_forward_compatible() {
+        let next = r#"{
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "product": { "type": "string" }
+            },
+            "required": ["id", "product"]
+        }"#;
+        let previous = TopicSchema::json(ORDER_V1);
+        let next = TopicSchema::json(next);
+        assert!(check_compatibility(SchemaCompatibility::Backward, &previous, &next).is_err());
+        assert!(check_compatibility(SchemaCompatibility::Forward, &previous, &next).is_ok());
+        assert!(check_compatibility(SchemaCompatibility::Full, &previous, &next).is_err());
+    }
+
+    #[test]
+    fn changing_json_property_type_should_be_incompatible() {
+        let next = r#"{
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "product": { "type": "string" }
+            },
+            "required": ["id"]
+        }"#;
+        let previous = TopicSchema::json(ORDER_V1);
+        let next = TopicSchema::json(next);
+        assert!(check_compatibility(SchemaCompatibility::Backward, &previous, &next).is_err());
+        assert!(check_compatibility(SchemaCompatibility::None, &previous, &next).is_ok());
+    }
+
+    #[test]
+    fn changing_schema_kind_should_be_incompatible() {
+        let previous = TopicSchema::json(ORDER_V1);
+        let next = order_protobuf_schema(&[("id", 1, Type::Int64)]);
+        assert!(check_compatibility(SchemaCompatibility::Forward, &previous, &next).is_err());
+    }
+
+    #[test]
+    fn adding_protobuf_field_should_be_fully_compatible() {
+        let previous = order_protobuf_schema(&[("id", 1, Type::Int64)]);
+        let next = order_protobuf_schema(&[("id", 1, Type::Int64), ("product", 2, Type::String)]);
+        assert!(check_compatibility(SchemaCompatibility::Full, &previous, &next).is_ok());
+    }
+
+    #[test]
+    fn changing_protobuf_field_type_should_be_incompatible() {
+        let previous = order_protobuf_schema(&[("id", 1, Type::Int64)]);
+        let next = order_protobuf_schema(&[("id", 1, Type::String)]);
+        assert!(check_compatibility(SchemaCompatibility::Backward, &previous, &next).is_err());
+    }
+
+    fn order_protobuf_schema(fields: &[(&str, i32, Type)]) -> TopicSchema {
+        let message = DescriptorProto {
+            name: Some("Order".to_string()),
+            field: fields
+                .iter()
+                .map(|(name, number, field_type)| FieldDescriptorProto {
+                    name: Some(name.to_string()),
+                    number: Some(*number),
+                    label: Some(Label::Optional as i32),
+                    r#type: Some(*field_type as i32),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let file = FileDescriptorProto {
+            name: Some("orders.proto".to_string()),
+            package: Some("orders".to_string()),
+            message_type: vec![message],
+            syntax: Some("proto3".to_string()),
+            ..Default::default()
+        };
+        let descriptor_set = FileDescriptorSet { file: vec![file] };
+        TopicSchema::protobuf(Bytes::from(descriptor_set.encode_to_vec()), "orders.Order")
+    }
+}
@@ -0,0 +1,49 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::models::topic_schema::TopicSchema;
+use iggy::utils::timestamp::IggyTimestamp;
+
+/// The version of the schema registered for the subject.
+/// The ID is unique across all the subjects, so it can be attached to the message headers on its own.
+#[derive(Debug, Clone)]
+pub struct Schema {
+    pub id: u32,
+    pub subject: String,
+    pub version: u32,
+    pub schema: TopicSchema,
+    pub created_at: IggyTimestamp,
+}
+
+impl Schema {
+    pub fn new(
+        id: u32,
+        subject: &str,
+        version: u32,
+        schema: TopicSchema,
+        created_at: IggyTimestamp,
+    ) -> Self {
+        Self {
+            id,
+            subject: subject.to_string(),
+            version,
+            schema,
+            created_at,
+        }
+    }
+}
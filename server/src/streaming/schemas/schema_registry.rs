@@ -0,0 +1,215 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::schemas::compatibility::check_compatibility;
+use crate::streaming::schemas::schema::Schema;
+use crate::streaming::schemas::COMPONENT;
+use crate::streaming::topics::schema::MessageSchema;
+use ahash::AHashMap;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::topic_schema::TopicSchema;
+use iggy::schemas::schema_compatibility::SchemaCompatibility;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::info;
+
+/// The registry of the versioned schemas grouped by the subjects (e.g. topic names).
+/// Each subject has its own compatibility level, which is enforced when the new versions are registered.
+#[derive(Debug, Default)]
+pub struct SchemaRegistry {
+    schemas: AHashMap<u32, Schema>,
+    subjects: AHashMap<String, Subject>,
+    current_schema_id: u32,
+}
+
+#[derive(Debug, Default)]
+struct Subject {
+    compatibility: SchemaCompatibility,
+    schema_ids: Vec<u32>,
+}
+
+impl SchemaRegistry {
+    pub fn get_schema(&self, schema_id: u32) -> Option<&Schema> {
+        self.schemas.get(&schema_id)
+    }
+
+    pub fn get_schemas(&self, subject: &str) -> Result<Vec<&Schema>, IggyError> {
+        let Some(subject_versions) = self.subjects.get(subject) else {
+            return Err(IggyError::SchemaSubjectNotFound(subject.to_string()));
+        };
+
+        Ok(subject_versions
+            .schema_ids
+            .iter()
+            .filter_map(|schema_id| self.schemas.get(schema_id))
+            .collect())
+    }
+
+    /// Returns the specific version of the schema for the subject, or the latest one if no version is provided.
+    pub fn get_subject_schema(&self, subject: &str, version: Option<u32>) -> Option<&Schema> {
+        let schema_ids = &self.subjects.get(subject)?.schema_ids;
+        let schema_id = match version {
+            Some(version) => schema_ids.get(version.checked_sub(1)? as usize)?,
+            None => schema_ids.last()?,
+        };
+        self.schemas.get(schema_id)
+    }
+
+    pub fn get_compatibility(&self, subject: &str) -> SchemaCompatibility {
+        self.subjects
+            .get(subject)
+            .map(|subject| subject.compatibility)
+            .unwrap_or_default()
+    }
+
+    /// Registers the new version of the schema for the subject, returning the schema and the flag indicating whether it was created.
+    /// If the schema is identical to the latest version, the latest version is returned instead.
+    pub fn register_schema(
+        &mut self,
+        subject: &str,
+        schema: TopicSchema,
+    ) -> Result<(&Schema, bool), IggyError> {
+        MessageSchema::new(schema.clone()).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - invalid schema definition for subject: {subject}"
+            )
+        })?;
+
+        let compatibility = self.get_compatibility(subject);
+        if let Some(latest) = self.get_subject_schema(subject, None) {
+            if latest.schema == schema {
+                let schema_id = latest.id;
+                return Ok((&self.schemas[&schema_id], false));
+            }
+
+            check_compatibility(compatibility, &latest.schema, &schema)
+                .map_err(|reason| IggyError::IncompatibleSchema(subject.to_string(), reason))?;
+        }
+
+        let schema_id = self.current_schema_id + 1;
+        let version = self.add_schema(schema_id, subject, schema, IggyTimestamp::now());
+        info!("Registered schema with ID: {schema_id}, version: {version} for subject: {subject} using {compatibility} compatibility.");
+        Ok((&self.schemas[&schema_id], true))
+    }
+
+    /// Adds the already registered schema (e.g. loaded from the state) without performing any checks, returning its version.
+    pub fn add_schema(
+        &mut self,
+        schema_id: u32,
+        subject: &str,
+        schema: TopicSchema,
+        created_at: IggyTimestamp,
+    ) -> u32 {
+        let subject_versions = self.subjects.entry(subject.to_string()).or_default();
+        subject_versions.schema_ids.push(schema_id);
+        let version = subject_versions.schema_ids.len() as u32;
+        self.schemas.insert(
+            schema_id,
+            Schema::new(schema_id, subject, version, schema, created_at),
+        );
+        self.current_schema_id = self.current_schema_id.max(schema_id);
+        version
+    }
+
+    /// Sets the compatibility level for the subject, which doesn't have to contain any schemas yet.
+    pub fn set_compatibility(&mut self, subject: &str, compatibility: SchemaCompatibility) {
+        self.subjects
+            .entry(subject.to_string())
+            .or_default()
+            .compatibility = compatibility;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER_V1: &str = r#"{"type": "object", "properties": {"id": {"type": "integer"}}}"#;
+    const ORDER_V2: &str = r#"{"type": "object", "properties": {"id": {"type": "integer"}, "product": {"type": "string"}}}"#;
+    const ORDER_V3: &str = r#"{"type": "object", "properties": {"id": {"type": "string"}}}"#;
+
+    #[test]
+    fn compatible_schemas_should_be_registered_as_next_versions() {
+        let mut registry = SchemaRegistry::default();
+        let (schema, created) = registry
+            .register_schema("orders", TopicSchema::json(ORDER_V1))
+            .unwrap();
+        assert!(created);
+        assert_eq!(schema.id, 1);
+        assert_eq!(schema.version, 1);
+
+        let (schema, created) = registry
+            .register_schema("orders", TopicSchema::json(ORDER_V2))
+            .unwrap();
+        assert!(created);
+        assert_eq!(schema.id, 2);
+        assert_eq!(schema.version, 2);
+
+        let (schema, _) = registry
+            .register_schema("payments", TopicSchema::json(ORDER_V1))
+            .unwrap();
+        assert_eq!(schema.id, 3);
+        assert_eq!(schema.version, 1);
+
+        assert_eq!(registry.get_schemas("orders").unwrap().len(), 2);
+        assert_eq!(registry.get_subject_schema("orders", None).unwrap().id, 2);
+        assert_eq!(
+            registry.get_subject_schema("orders", Some(1)).unwrap().id,
+            1
+        );
+        assert!(registry.get_subject_schema("orders", Some(3)).is_none());
+    }
+
+    #[test]
+    fn identical_schema_should_not_be_registered_again() {
+        let mut registry = SchemaRegistry::default();
+        registry
+            .register_schema("orders", TopicSchema::json(ORDER_V1))
+            .unwrap();
+        let (schema, created) = registry
+            .register_schema("orders", TopicSchema::json(ORDER_V1))
+            .unwrap();
+        assert!(!created);
+        assert_eq!(schema.id, 1);
+        assert_eq!(registry.get_schemas("orders").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn incompatible_schema_should_be_rejected_unless_compatibility_is_disabled() {
+        let mut registry = SchemaRegistry::default();
+        registry
+            .register_schema("orders", TopicSchema::json(ORDER_V1))
+            .unwrap();
+        let result = registry.register_schema("orders", TopicSchema::json(ORDER_V3));
+        assert!(matches!(result, Err(IggyError::IncompatibleSchema(_, _))));
+
+        registry.set_compatibility("orders", SchemaCompatibility::None);
+        let (schema, _) = registry
+            .register_schema("orders", TopicSchema::json(ORDER_V3))
+            .unwrap();
+        assert_eq!(schema.version, 2);
+    }
+
+    #[test]
+    fn invalid_schema_should_be_rejected() {
+        let mut registry = SchemaRegistry::default();
+        let result = registry.register_schema("orders", TopicSchema::json(r#"{"type": 1}"#));
+        assert!(matches!(result, Err(IggyError::InvalidTopicSchema(_))));
+        assert!(registry.get_schemas("orders").is_err());
+    }
+}
@@ -0,0 +1,200 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::segments::encryption;
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::segments::{INDEX_EXTENSION, MANIFEST_EXTENSION};
+use crate::streaming::utils::file;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+const COMPONENT: &str = "STREAMING_SEGMENT_ARCHIVE";
+pub const ARCHIVE_MANIFEST_VERSION: u32 = 1;
+
+/// The manifest archived along with the files of the segment before it's deleted by the retention.
+/// The segment can be re-imported by copying the archived files back to their original paths before the server is started,
+/// the log file of the tiered segment is restored from its object key instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentArchiveManifest {
+    pub version: u32,
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub start_offset: u64,
+    pub end_offset: u64,
+    pub messages_count: u64,
+    pub size_bytes: u64,
+    pub end_timestamp: u64,
+    pub archived_at: u64,
+    pub index_path: String,
+    pub log_path: Option<String>,
+    pub key_path: Option<String>,
+    pub tiered_path: Option<String>,
+    pub tiered_object_key: Option<String>,
+}
+
+impl SegmentArchiveManifest {
+    /// Returns the paths of all the local files of the segment, which should be archived along with the manifest.
+    pub fn get_files(&self) -> Vec<&str> {
+        let mut files = vec![self.index_path.as_str()];
+        files.extend(self.log_path.as_deref());
+        files.extend(self.key_path.as_deref());
+        files.extend(self.tiered_path.as_deref());
+        files
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, IggyError> {
+        let manifest: SegmentArchiveManifest =
+            serde_json::from_slice(bytes).map_err(|_| IggyError::CannotDeserializeResource)?;
+        if manifest.version > ARCHIVE_MANIFEST_VERSION {
+            return Err(IggyError::CannotDeserializeResource);
+        }
+
+        Ok(manifest)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, IggyError> {
+        serde_json::to_vec_pretty(self).map_err(|_| IggyError::CannotSerializeResource)
+    }
+}
+
+impl Segment {
+    /// Returns the path of the manifest file, stored next to the index file of the segment.
+    pub fn get_archive_manifest_path(&self) -> String {
+        let path = self
+            .index_path
+            .strip_suffix(&format!(".{INDEX_EXTENSION}"))
+            .unwrap_or(&self.index_path);
+        format!("{path}.{MANIFEST_EXTENSION}")
+    }
+
+    pub async fn get_archive_manifest(&self, archived_at: IggyTimestamp) -> SegmentArchiveManifest {
+        let segment = self.get_deletion_metadata().await;
+        let key_path = encryption::get_key_path(&self.log_path);
+        let key_path = match file::exists(&key_path).await.unwrap_or(false) {
+            true => Some(key_path),
+            false => None,
+        };
+        SegmentArchiveManifest {
+            version: ARCHIVE_MANIFEST_VERSION,
+            stream_id: segment.stream_id,
+            topic_id: segment.topic_id,
+            partition_id: segment.partition_id,
+            start_offset: segment.start_offset,
+            end_offset: segment.end_offset,
+            messages_count: segment.messages_count,
+            size_bytes: segment.size_bytes,
+            end_timestamp: segment.end_timestamp,
+            archived_at: archived_at.as_micros(),
+            index_path: segment.index_path,
+            log_path: segment.log_path,
+            key_path,
+            tiered_path: match self.is_tiered() {
+                true => Some(self.tiered_path.clone()),
+                false => None,
+            },
+            tiered_object_key: segment.tiered_object_key,
+        }
+    }
+
+    /// Writes the manifest next to the segment files, so that it can be archived along with them, returning its path.
+    pub async fn write_archive_manifest(
+        &self,
+        manifest: &SegmentArchiveManifest,
+    ) -> Result<String, IggyError> {
+        let path = self.get_archive_manifest_path();
+        fs::write(&path, manifest.to_bytes()?)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to write segment archive manifest: {path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(log_path: Option<&str>, tiered_object_key: Option<&str>) -> SegmentArchiveManifest {
+        SegmentArchiveManifest {
+            version: ARCHIVE_MANIFEST_VERSION,
+            stream_id: 1,
+            topic_id: 2,
+            partition_id: 3,
+            start_offset: 100,
+            end_offset: 199,
+            messages_count: 100,
+            size_bytes: 1024,
+            end_timestamp: 1_000_000,
+            archived_at: 2_000_000,
+            index_path: "streams/1/topics/2/partitions/3/00000000000000000100.index".to_string(),
+            log_path: log_path.map(ToString::to_string),
+            key_path: None,
+            tiered_path: tiered_object_key
+                .map(|_| "streams/1/topics/2/partitions/3/00000000000000000100.tiered".to_string()),
+            tiered_object_key: tiered_object_key.map(ToString::to_string),
+        }
+    }
+
+    #[test]
+    fn manifest_should_be_serialized_and_deserialized() {
+        let manifest = manifest(
+            Some("streams/1/topics/2/partitions/3/00000000000000000100.log"),
+            None,
+        );
+        let deserialized =
+            SegmentArchiveManifest::from_bytes(&manifest.to_bytes().unwrap()).unwrap();
+        assert_eq!(deserialized, manifest);
+    }
+
+    #[test]
+    fn manifest_of_newer_version_should_be_rejected() {
+        let mut manifest = manifest(None, Some("1/2/3/00000000000000000100.log"));
+        manifest.version = ARCHIVE_MANIFEST_VERSION + 1;
+        assert!(SegmentArchiveManifest::from_bytes(&manifest.to_bytes().unwrap()).is_err());
+    }
+
+    #[test]
+    fn files_should_include_the_log_or_the_tiered_marker() {
+        let local = manifest(
+            Some("streams/1/topics/2/partitions/3/00000000000000000100.log"),
+            None,
+        );
+        assert_eq!(
+            local.get_files(),
+            vec![
+                "streams/1/topics/2/partitions/3/00000000000000000100.index",
+                "streams/1/topics/2/partitions/3/00000000000000000100.log"
+            ]
+        );
+
+        let tiered = manifest(None, Some("1/2/3/00000000000000000100.log"));
+        assert_eq!(
+            tiered.get_files(),
+            vec![
+                "streams/1/topics/2/partitions/3/00000000000000000100.index",
+                "streams/1/topics/2/partitions/3/00000000000000000100.tiered"
+            ]
+        );
+    }
+}
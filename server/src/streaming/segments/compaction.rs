@@ -0,0 +1,225 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::batching::batch_accumulator::BatchAccumulator;
+use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::segments::indexes::{Index, INDEX_SIZE};
+use crate::streaming::segments::segment::Segment;
+use bytes::{BufMut, Bytes, BytesMut};
+use error_set::ErrContext;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use iggy::models::compaction_policy::CompactionKey;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::sizeable::Sizeable;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use tokio::fs::{rename, File};
+use tokio::io::AsyncWriteExt;
+use tracing::{info, warn};
+
+const COMPACTED_FILE_EXTENSION: &str = "compacted";
+
+#[derive(Debug, Default)]
+pub struct CompactedSegment {
+    pub removed_messages_count: u64,
+    pub removed_bytes: u64,
+    pub is_empty: bool,
+    /// The bytes read and written while compacting the segment, accounted by the background I/O throttle.
+    pub io_bytes: u64,
+}
+
+/// Returns the key by which the message is compacted, the messages without the key are never compacted.
+/// The tombstones are compacted as any other message, thus the tombstone is retained as the latest message of its key.
+pub fn get_compaction_key(message: &RetainedMessage, key: &CompactionKey) -> Option<Bytes> {
+    match key {
+        CompactionKey::MessageId => key.get_message_key(message.id, None),
+        CompactionKey::Header(_) => {
+            let headers =
+                HashMap::<HeaderKey, HeaderValue>::from_bytes(message.headers.clone()?).ok()?;
+            key.get_message_key(message.id, Some(&headers))
+        }
+    }
+}
+
+impl Segment {
+    /// Rewrites the closed segment, retaining only the messages for which `retain` returns true.
+    /// The retained messages keep their offsets, thus the compacted segment contains the gaps in the offsets.
+    /// The messages count of the segment is derived from its offsets range, so it's not changed by the compaction.
+    /// If none of the messages is retained, the segment is left intact and marked as empty, so it can be deleted instead.
+    pub async fn compact<F>(&mut self, retain: F) -> Result<CompactedSegment, IggyError>
+    where
+        F: Fn(&RetainedMessage) -> bool,
+    {
+        if !self.is_closed {
+            warn!("Cannot compact the segment which is not closed: {self}");
+            return Ok(CompactedSegment::default());
+        }
+
+        if self.is_tiered() {
+            warn!("Cannot compact the segment offloaded to the tiered storage: {self}");
+            return Ok(CompactedSegment::default());
+        }
+
+        let messages = self.get_all_messages().await.with_error_context(|error| {
+            format!("Failed to load messages to be compacted for {self}. {error}")
+        })?;
+        let read_bytes = self.size_bytes.as_bytes_u64();
+        let messages_count = messages.len();
+        let retained_messages = messages
+            .into_iter()
+            .filter(|message| retain(message))
+            .collect::<Vec<_>>();
+        let removed_messages_count = (messages_count - retained_messages.len()) as u64;
+        if removed_messages_count == 0 {
+            return Ok(CompactedSegment {
+                io_bytes: read_bytes,
+                ..Default::default()
+            });
+        }
+
+        if retained_messages.is_empty() {
+            return Ok(CompactedSegment {
+                removed_messages_count,
+                removed_bytes: self.size_bytes.as_bytes_u64(),
+                is_empty: true,
+                io_bytes: read_bytes,
+            });
+        }
+
+        let (log_bytes, indexes) = self.build_compacted_segment(&retained_messages)?;
+        let mut index_bytes = BytesMut::with_capacity(indexes.len() * INDEX_SIZE as usize);
+        for index in &indexes {
+            index_bytes.put_u32_le(index.offset);
+            index_bytes.put_u32_le(index.position);
+            index_bytes.put_u64_le(index.timestamp);
+        }
+
+        let compacted_log_path = format!("{}.{COMPACTED_FILE_EXTENSION}", self.log_path);
+        let compacted_index_path = format!("{}.{COMPACTED_FILE_EXTENSION}", self.index_path);
+        write_compacted_file(&compacted_log_path, &log_bytes).await?;
+        write_compacted_file(&compacted_index_path, &index_bytes).await?;
+
+        // The files are replaced by renaming, so the readers must be reopened to see the compacted segment.
+        self.shutdown_reading().await;
+        rename(&compacted_log_path, &self.log_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to replace log file: {} with compacted one. {error}",
+                    self.log_path
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        rename(&compacted_index_path, &self.index_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "Failed to replace index file: {} with compacted one. {error}",
+                    self.index_path
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        self.initialize_reading().await?;
+
+        let compacted_size_bytes = log_bytes.len() as u64;
+        let removed_bytes = self
+            .size_bytes
+            .as_bytes_u64()
+            .saturating_sub(compacted_size_bytes);
+        self.size_bytes = IggyByteSize::from(compacted_size_bytes);
+        self.last_index_position = compacted_size_bytes as u32;
+        if self.indexes.is_some() {
+            self.indexes = Some(indexes);
+        }
+
+        self.size_of_parent_stream
+            .fetch_sub(removed_bytes, Ordering::SeqCst);
+        self.size_of_parent_topic
+            .fetch_sub(removed_bytes, Ordering::SeqCst);
+        self.size_of_parent_partition
+            .fetch_sub(removed_bytes, Ordering::SeqCst);
+
+        info!(
+            "Compacted segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}, removed {} messages ({}).",
+            self.start_offset,
+            self.partition_id,
+            self.topic_id,
+            self.stream_id,
+            removed_messages_count,
+            IggyByteSize::from(removed_bytes)
+        );
+
+        Ok(CompactedSegment {
+            removed_messages_count,
+            removed_bytes,
+            is_empty: false,
+            io_bytes: read_bytes + compacted_size_bytes + index_bytes.len() as u64,
+        })
+    }
+
+    fn build_compacted_segment(
+        &self,
+        messages: &[Arc<RetainedMessage>],
+    ) -> Result<(Bytes, Vec<Index>), IggyError> {
+        let messages_per_batch =
+            std::cmp::max(self.config.partition.messages_required_to_save as usize, 1);
+        let mut log_bytes = BytesMut::new();
+        let mut indexes = Vec::with_capacity(messages.len() / messages_per_batch + 1);
+        for messages in messages.chunks(messages_per_batch) {
+            let batch_size = messages
+                .iter()
+                .map(|message| message.get_size_bytes())
+                .sum::<IggyByteSize>();
+            let mut batch_accumulator = BatchAccumulator::new(messages[0].offset, messages.len());
+            batch_accumulator.append(batch_size, messages);
+            let batch = batch_accumulator.materialize_batch_and_update_state(&self.buffer_pool);
+            let batch = match &self.cipher {
+                Some(cipher) => cipher.encrypt_batch(batch)?,
+                None => batch,
+            };
+            indexes.push(Index {
+                offset: (batch.get_last_offset() - self.start_offset) as u32,
+                position: log_bytes.len() as u32,
+                timestamp: batch.max_timestamp,
+            });
+            log_bytes.put_slice(&batch.header_as_bytes());
+            log_bytes.put_slice(&batch.bytes);
+        }
+
+        Ok((log_bytes.freeze(), indexes))
+    }
+}
+
+async fn write_compacted_file(path: &str, bytes: &[u8]) -> Result<(), IggyError> {
+    let mut file = File::create(path)
+        .await
+        .with_error_context(|error| format!("Failed to create compacted file: {path}. {error}"))
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    file.write_all(bytes)
+        .await
+        .with_error_context(|error| format!("Failed to write compacted file: {path}. {error}"))
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    file.sync_all()
+        .await
+        .with_error_context(|error| format!("Failed to fsync compacted file: {path}. {error}"))
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    Ok(())
+}
@@ -0,0 +1,539 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! At-rest encryption of the segment log files, transparent to the clients.
+//!
+//! Every encrypted segment has its own random data key, encrypting the payloads of the stored batches
+//! with AES-256-GCM. The data key is wrapped by the master key and kept next to the log file, so the
+//! segment can be read only as long as the master key is available. The batch headers are stored as
+//! they are, thus the indexes, checksums and recovery work the same way for the encrypted segments.
+
+use crate::configs::system::{MasterKeySource, SegmentEncryptionConfig};
+use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use crate::streaming::segments::indexes::{Index, INDEX_SIZE};
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::segments::KEY_EXTENSION;
+use bytes::{BufMut, Bytes, BytesMut};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
+use iggy::utils::text;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::fmt::Debug;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::task::spawn_blocking;
+use tracing::{error, info, warn};
+
+/// The size of the data key, and of the master key used by the built-in provider.
+pub const DATA_KEY_SIZE: usize = 32;
+/// The nonce prepended and the authentication tag appended to every encrypted payload.
+pub const ENCRYPTION_OVERHEAD: u64 = 12 + 16;
+
+/// Wraps and unwraps the data keys of the segments with the master key.
+/// The keys are wrapped once per segment, when it's created or opened, so the provider backed by
+/// an external key management system can call it synchronously. Such a provider can be plugged in
+/// with [`SegmentEncryption::new`] instead of the built-in one, reading the master key from a file or
+/// an environment variable.
+pub trait MasterKeyProvider: Send + Sync + Debug {
+    /// The name stored along with the wrapped data key, to tell which provider has wrapped it.
+    fn name(&self) -> &str;
+    fn wrap_key(&self, key: &[u8]) -> Result<Vec<u8>, IggyError>;
+    fn unwrap_key(&self, wrapped_key: &[u8]) -> Result<Vec<u8>, IggyError>;
+}
+
+/// The built-in provider, wrapping the data keys with the base64 encoded master key read from a file or an environment variable.
+#[derive(Debug)]
+pub struct LocalMasterKeyProvider {
+    encryptor: Aes256GcmEncryptor,
+}
+
+impl LocalMasterKeyProvider {
+    pub const NAME: &'static str = "local";
+
+    pub fn new(master_key: &[u8]) -> Result<Self, IggyError> {
+        Ok(Self {
+            encryptor: Aes256GcmEncryptor::new(master_key)?,
+        })
+    }
+
+    pub fn from_config(config: &SegmentEncryptionConfig) -> Result<Self, IggyError> {
+        let master_key = match config.master_key_source {
+            MasterKeySource::File => std::fs::read_to_string(&config.master_key_path)
+                .with_error_context(|error| {
+                    format!(
+                        "Failed to read segment master key file: {}. {error}",
+                        config.master_key_path
+                    )
+                })
+                .map_err(|_| IggyError::CannotReadFile)?,
+            MasterKeySource::Env => std::env::var(&config.master_key_env)
+                .with_error_context(|error| {
+                    format!(
+                        "Failed to read segment master key from environment variable: {}. {error}",
+                        config.master_key_env
+                    )
+                })
+                .map_err(|_| IggyError::InvalidEncryptionKey)?,
+        };
+        let master_key = text::from_base64_as_bytes(master_key.trim())
+            .map_err(|_| IggyError::InvalidEncryptionKey)?;
+        Self::new(&master_key)
+    }
+}
+
+impl MasterKeyProvider for LocalMasterKeyProvider {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn wrap_key(&self, key: &[u8]) -> Result<Vec<u8>, IggyError> {
+        self.encryptor.encrypt(key)
+    }
+
+    fn unwrap_key(&self, wrapped_key: &[u8]) -> Result<Vec<u8>, IggyError> {
+        if wrapped_key.len() < ENCRYPTION_OVERHEAD as usize {
+            return Err(IggyError::CannotDecryptData);
+        }
+        self.encryptor.decrypt(wrapped_key)
+    }
+}
+
+/// The master key provider and the encryption settings shared by all the segments, kept in the system storage.
+#[derive(Debug, Default)]
+pub struct SegmentEncryption {
+    provider: Option<Arc<dyn MasterKeyProvider>>,
+    encrypt_new_segments: bool,
+}
+
+impl SegmentEncryption {
+    /// Creates the segment encryption with the given master key provider, e.g. the one backed by a key management system.
+    pub fn new(provider: Option<Arc<dyn MasterKeyProvider>>, encrypt_new_segments: bool) -> Self {
+        let encrypt_new_segments = encrypt_new_segments && provider.is_some();
+        Self {
+            provider,
+            encrypt_new_segments,
+        }
+    }
+
+    /// Creates the segment encryption with the built-in provider. The master key is required only if the encryption
+    /// is enabled, otherwise it's used (when available) to read the segments which have been already encrypted.
+    /// The missing master key of the enabled encryption is reported by [`SegmentEncryption::validate`].
+    pub fn from_config(config: &SegmentEncryptionConfig) -> Self {
+        let provider = match LocalMasterKeyProvider::from_config(config) {
+            Ok(provider) => Some(Arc::new(provider) as Arc<dyn MasterKeyProvider>),
+            Err(error) if config.enabled => {
+                error!("Cannot load the master key for the segment encryption. {error}");
+                return Self {
+                    provider: None,
+                    encrypt_new_segments: true,
+                };
+            }
+            Err(_) => None,
+        };
+        Self::new(provider, config.enabled)
+    }
+
+    /// Fails if the encryption is enabled, but the master key is not available, should be called before any segment is loaded.
+    pub fn validate(&self) -> Result<(), IggyError> {
+        if self.encrypt_new_segments && self.provider.is_none() {
+            return Err(IggyError::InvalidEncryptionKey);
+        }
+
+        if self.encrypt_new_segments {
+            info!("Segment encryption is enabled, the new segments will be encrypted.");
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the new segments are encrypted.
+    pub fn is_enabled(&self) -> bool {
+        self.encrypt_new_segments && self.provider.is_some()
+    }
+
+    fn provider(&self) -> Option<Arc<dyn MasterKeyProvider>> {
+        self.provider.clone()
+    }
+}
+
+pub fn get_key_path(log_path: &str) -> String {
+    format!("{log_path}.{KEY_EXTENSION}")
+}
+
+/// Encrypts and decrypts the payloads of the batches stored in a single segment with its data key.
+#[derive(Debug)]
+pub struct SegmentCipher {
+    encryptor: Aes256GcmEncryptor,
+}
+
+impl SegmentCipher {
+    pub fn new(data_key: &[u8]) -> Result<Self, IggyError> {
+        Ok(Self {
+            encryptor: Aes256GcmEncryptor::new(data_key)?,
+        })
+    }
+
+    /// Generates the data key of the segment and stores it, wrapped by the master key, next to the log file.
+    pub async fn create(encryption: &SegmentEncryption, log_path: &str) -> Result<Self, IggyError> {
+        let data_key = generate_data_key()?;
+        store_data_key(encryption, log_path, &data_key).await?;
+        Self::new(&data_key)
+    }
+
+    /// Loads the data key of the segment, if it's encrypted, otherwise returns `None`.
+    pub async fn load(
+        encryption: &SegmentEncryption,
+        log_path: &str,
+    ) -> Result<Option<Self>, IggyError> {
+        let key_path = get_key_path(log_path);
+        let content = match fs::read(&key_path).await {
+            Ok(content) => content,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => {
+                error!("Failed to read segment key file: {key_path}. {error}");
+                return Err(IggyError::CannotReadFile);
+            }
+        };
+
+        let Some(provider) = encryption.provider() else {
+            error!("Segment: {log_path} is encrypted, but the master key is not available.");
+            return Err(IggyError::InvalidEncryptionKey);
+        };
+        let (provider_name, wrapped_key) = deserialize_key_file(&content).ok_or_else(|| {
+            error!("Segment key file: {key_path} is corrupted.");
+            IggyError::InvalidEncryptionKey
+        })?;
+        if provider_name != provider.name() {
+            error!(
+                "Segment key file: {key_path} has been wrapped by the master key provider: {provider_name}, but the current one is: {}.",
+                provider.name()
+            );
+            return Err(IggyError::InvalidEncryptionKey);
+        }
+
+        let wrapped_key = wrapped_key.to_vec();
+        let data_key = spawn_blocking(move || provider.unwrap_key(&wrapped_key))
+            .await
+            .map_err(|_| IggyError::CannotDecryptData)?
+            .with_error_context(|error| {
+                format!("Failed to unwrap the data key of segment: {log_path}. {error}")
+            })?;
+        Self::new(&data_key).map(Some)
+    }
+
+    /// Returns the batch with the encrypted payload, the header fields are retained, except for the length.
+    pub fn encrypt_batch(
+        &self,
+        batch: RetainedMessageBatch,
+    ) -> Result<RetainedMessageBatch, IggyError> {
+        let payload = self.encryptor.encrypt(&batch.bytes)?;
+        Ok(RetainedMessageBatch::new(
+            batch.base_offset,
+            batch.last_offset_delta,
+            batch.max_timestamp,
+            IggyByteSize::from(payload.len() as u64),
+            Bytes::from(payload),
+        ))
+    }
+
+    pub fn decrypt(&self, payload: &[u8]) -> Result<Vec<u8>, IggyError> {
+        if payload.len() < ENCRYPTION_OVERHEAD as usize {
+            return Err(IggyError::CannotDecryptData);
+        }
+        self.encryptor.decrypt(payload)
+    }
+}
+
+/// The extension of the encrypted log and index files written next to the plaintext ones, until they're replaced.
+pub const ENCRYPTING_FILE_EXTENSION: &str = "encrypting";
+
+/// Completes or rolls back the encryption of the segment interrupted before its files were replaced.
+/// The key file is written once the encrypted files are synced, thus its presence means the encryption
+/// has been committed and the remaining encrypted files only have to be renamed, otherwise they're removed.
+/// Returns `true` if there was the interrupted encryption.
+pub async fn recover_interrupted_encryption(
+    log_path: &str,
+    index_path: &str,
+) -> Result<bool, IggyError> {
+    let encrypting_log_path = format!("{log_path}.{ENCRYPTING_FILE_EXTENSION}");
+    let encrypting_index_path = format!("{index_path}.{ENCRYPTING_FILE_EXTENSION}");
+    let log_exists = fs::try_exists(&encrypting_log_path).await.unwrap_or(false);
+    let index_exists = fs::try_exists(&encrypting_index_path)
+        .await
+        .unwrap_or(false);
+    if !log_exists && !index_exists {
+        return Ok(false);
+    }
+
+    let committed = fs::try_exists(get_key_path(log_path))
+        .await
+        .unwrap_or(false);
+    if committed && log_exists {
+        warn!("Completing the interrupted encryption of segment: {log_path}.");
+        // The index is replaced before the log file, so the remaining encrypted log file means the index may still be pending.
+        if index_exists {
+            replace_file(&encrypting_index_path, index_path).await?;
+        }
+        replace_file(&encrypting_log_path, log_path).await?;
+        return Ok(true);
+    }
+
+    warn!("Rolling back the interrupted encryption of segment: {log_path}.");
+    for path in [&encrypting_log_path, &encrypting_index_path] {
+        match fs::remove_file(path).await {
+            Ok(()) => {}
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+            Err(error) => {
+                error!("Failed to remove file: {path} of the interrupted encryption. {error}");
+                return Err(IggyError::CannotDeleteFile);
+            }
+        }
+    }
+    Ok(true)
+}
+
+async fn replace_file(source: &str, target: &str) -> Result<(), IggyError> {
+    fs::rename(source, target)
+        .await
+        .with_error_context(|error| {
+            format!("Failed to replace file: {target} with encrypted one. {error}")
+        })
+        .map_err(|_| IggyError::CannotWriteToFile)
+}
+
+impl Segment {
+    /// Encrypts the plaintext segment in place, rewriting all its batches with the new data key.
+    /// Returns `false` if there's nothing to encrypt, i.e. the segment is already encrypted or offloaded to the tiered storage.
+    /// It's meant to be run by the maintenance tool, before the server starts serving the clients.
+    pub async fn encrypt(&mut self) -> Result<bool, IggyError> {
+        if self.is_encrypted() || self.is_tiered() {
+            return Ok(false);
+        }
+
+        if self.encryption.provider().is_none() {
+            error!("Cannot encrypt {self}, the master key is not available.");
+            return Err(IggyError::InvalidEncryptionKey);
+        }
+
+        let Some(log_reader) = self.log_reader.as_ref() else {
+            return Ok(false);
+        };
+        let mut batches = Vec::new();
+        log_reader
+            .load_batches_by_size_with_callback(u64::MAX, |batch| {
+                batches.push(batch);
+                Ok(())
+            })
+            .await
+            .with_error_context(|error| {
+                format!("Failed to load batches to be encrypted for {self}. {error}")
+            })?;
+
+        let encrypting_log_path = format!("{}.{ENCRYPTING_FILE_EXTENSION}", self.log_path);
+        let encrypting_index_path = format!("{}.{ENCRYPTING_FILE_EXTENSION}", self.index_path);
+        let data_key = generate_data_key()?;
+        let cipher = SegmentCipher::new(&data_key)?;
+        let mut log_bytes = BytesMut::new();
+        let mut indexes = Vec::with_capacity(batches.len());
+        for batch in batches {
+            let batch = cipher.encrypt_batch(batch)?;
+            indexes.push(Index {
+                offset: (batch.get_last_offset() - self.start_offset) as u32,
+                position: log_bytes.len() as u32,
+                timestamp: batch.max_timestamp,
+            });
+            log_bytes.put_slice(&batch.header_as_bytes());
+            log_bytes.put_slice(&batch.bytes);
+        }
+        let mut index_bytes = BytesMut::with_capacity(indexes.len() * INDEX_SIZE as usize);
+        for index in &indexes {
+            index_bytes.put_u32_le(index.offset);
+            index_bytes.put_u32_le(index.position);
+            index_bytes.put_u64_le(index.timestamp);
+        }
+        write_synced_file(&encrypting_log_path, &log_bytes).await?;
+        write_synced_file(&encrypting_index_path, &index_bytes).await?;
+
+        // Writing the key file commits the encryption, so the interrupted one can be completed when the segment is loaded
+        // (see `recover_interrupted_encryption`). Then the index is replaced, and the log file goes last.
+        let writable = self.close_files().await;
+        store_data_key(&self.encryption, &self.log_path, &data_key).await?;
+        replace_file(&encrypting_index_path, &self.index_path).await?;
+        replace_file(&encrypting_log_path, &self.log_path).await?;
+
+        self.cipher = Some(Arc::new(cipher));
+        if writable {
+            self.initialize_writing().await?;
+        }
+        self.initialize_reading().await?;
+
+        let encrypted_size_bytes = log_bytes.len() as u64;
+        let added_bytes = encrypted_size_bytes.saturating_sub(self.size_bytes.as_bytes_u64());
+        self.size_bytes = IggyByteSize::from(encrypted_size_bytes);
+        self.last_index_position = encrypted_size_bytes as u32;
+        if self.indexes.is_some() {
+            self.indexes = Some(indexes);
+        }
+        self.size_of_parent_stream
+            .fetch_add(added_bytes, Ordering::SeqCst);
+        self.size_of_parent_topic
+            .fetch_add(added_bytes, Ordering::SeqCst);
+        self.size_of_parent_partition
+            .fetch_add(added_bytes, Ordering::SeqCst);
+
+        info!(
+            "Encrypted segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}, size: {}.",
+            self.start_offset,
+            self.partition_id,
+            self.topic_id,
+            self.stream_id,
+            IggyByteSize::from(encrypted_size_bytes)
+        );
+        Ok(true)
+    }
+}
+
+fn generate_data_key() -> Result<[u8; DATA_KEY_SIZE], IggyError> {
+    let mut data_key = [0u8; DATA_KEY_SIZE];
+    SystemRandom::new()
+        .fill(&mut data_key)
+        .map_err(|_| IggyError::CannotEncryptData)?;
+    Ok(data_key)
+}
+
+/// Wraps the data key by the master key and stores it next to the log file, the segment is treated as encrypted once it exists.
+async fn store_data_key(
+    encryption: &SegmentEncryption,
+    log_path: &str,
+    data_key: &[u8; DATA_KEY_SIZE],
+) -> Result<(), IggyError> {
+    let provider = encryption
+        .provider()
+        .ok_or(IggyError::InvalidEncryptionKey)?;
+    let wrapped_key = {
+        let provider = provider.clone();
+        let data_key = *data_key;
+        spawn_blocking(move || provider.wrap_key(&data_key))
+            .await
+            .map_err(|_| IggyError::CannotEncryptData)??
+    };
+    let key_path = get_key_path(log_path);
+    write_synced_file(
+        &key_path,
+        &serialize_key_file(provider.name(), &wrapped_key),
+    )
+    .await
+    .with_error_context(|error| format!("Failed to write segment key file: {key_path}. {error}"))
+}
+
+async fn write_synced_file(path: &str, bytes: &[u8]) -> Result<(), IggyError> {
+    let mut file = fs::File::create(path)
+        .await
+        .with_error_context(|error| format!("Failed to create file: {path}. {error}"))
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    file.write_all(bytes)
+        .await
+        .with_error_context(|error| format!("Failed to write file: {path}. {error}"))
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    file.sync_all()
+        .await
+        .with_error_context(|error| format!("Failed to fsync file: {path}. {error}"))
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    Ok(())
+}
+
+/// The key file consists of the provider name (prefixed with its length) and the wrapped data key.
+fn serialize_key_file(provider_name: &str, wrapped_key: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(1 + provider_name.len() + wrapped_key.len());
+    bytes.push(provider_name.len() as u8);
+    bytes.extend_from_slice(provider_name.as_bytes());
+    bytes.extend_from_slice(wrapped_key);
+    bytes
+}
+
+fn deserialize_key_file(bytes: &[u8]) -> Option<(&str, &[u8])> {
+    let name_length = *bytes.first()? as usize;
+    let name = bytes.get(1..1 + name_length)?;
+    let name = std::str::from_utf8(name).ok()?;
+    let wrapped_key = &bytes[1 + name_length..];
+    if wrapped_key.is_empty() {
+        return None;
+    }
+    Some((name, wrapped_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_wrap_and_unwrap_data_key_with_local_provider() {
+        let provider = LocalMasterKeyProvider::new(&[1; 32]).unwrap();
+        let data_key = [7; DATA_KEY_SIZE];
+        let wrapped_key = provider.wrap_key(&data_key).unwrap();
+        assert_ne!(wrapped_key.as_slice(), data_key.as_slice());
+        assert_eq!(provider.unwrap_key(&wrapped_key).unwrap(), data_key);
+    }
+
+    #[test]
+    fn should_not_unwrap_data_key_with_another_master_key() {
+        let provider = LocalMasterKeyProvider::new(&[1; 32]).unwrap();
+        let another_provider = LocalMasterKeyProvider::new(&[2; 32]).unwrap();
+        let wrapped_key = provider.wrap_key(&[7; DATA_KEY_SIZE]).unwrap();
+        assert!(another_provider.unwrap_key(&wrapped_key).is_err());
+        assert!(provider.unwrap_key(&wrapped_key[..8]).is_err());
+    }
+
+    #[test]
+    fn should_encrypt_batch_payload_retaining_header_fields() {
+        let cipher = SegmentCipher::new(&[3; DATA_KEY_SIZE]).unwrap();
+        let payload = Bytes::from_static(b"messages");
+        let batch = RetainedMessageBatch::new(10, 4, 1000, IggyByteSize::from(8), payload.clone());
+
+        let encrypted_batch = cipher.encrypt_batch(batch).unwrap();
+
+        assert_eq!(encrypted_batch.base_offset, 10);
+        assert_eq!(encrypted_batch.last_offset_delta, 4);
+        assert_eq!(encrypted_batch.max_timestamp, 1000);
+        assert_eq!(
+            encrypted_batch.length.as_bytes_u64(),
+            payload.len() as u64 + ENCRYPTION_OVERHEAD
+        );
+        assert_ne!(encrypted_batch.bytes, payload);
+        assert_eq!(cipher.decrypt(&encrypted_batch.bytes).unwrap(), payload);
+    }
+
+    #[test]
+    fn should_not_decrypt_truncated_payload() {
+        let cipher = SegmentCipher::new(&[3; DATA_KEY_SIZE]).unwrap();
+        assert!(cipher.decrypt(&[0; 8]).is_err());
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_key_file() {
+        let bytes = serialize_key_file("local", &[1, 2, 3]);
+        let (name, wrapped_key) = deserialize_key_file(&bytes).unwrap();
+        assert_eq!(name, "local");
+        assert_eq!(wrapped_key, &[1, 2, 3]);
+        assert!(deserialize_key_file(&bytes[..4]).is_none());
+        assert!(deserialize_key_file(&[]).is_none());
+    }
+}
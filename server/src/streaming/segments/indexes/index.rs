@@ -99,6 +99,8 @@ impl IndexRange {
 mod tests {
     use super::*;
     use crate::configs::system::{SegmentConfig, SystemConfig};
+    use crate::streaming::segments::encryption::SegmentEncryption;
+    use crate::streaming::utils::buffer_pool::BufferPool;
     use iggy::utils::expiry::IggyExpiry;
     use std::sync::atomic::AtomicU64;
     use std::sync::Arc;
@@ -121,7 +123,9 @@ mod tests {
             topic_id,
             partition_id,
             start_offset,
-            config,
+            config.clone(),
+            Arc::new(BufferPool::new(&config.buffer_pool)),
+            Arc::new(SegmentEncryption::default()),
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
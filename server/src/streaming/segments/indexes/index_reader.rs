@@ -22,24 +22,26 @@ use super::{
 };
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use memmap2::{Mmap, MmapOptions};
 use std::{
     fs::{File, OpenOptions},
-    io::ErrorKind,
-    os::unix::fs::FileExt,
     sync::{
         atomic::{AtomicU64, Ordering},
-        Arc,
+        Arc, RwLock,
     },
 };
-use tokio::task::spawn_blocking;
-use tracing::{error, trace};
+use tracing::trace;
 
 /// A dedicated struct for reading from the index file.
+///
+/// The index file is memory-mapped and searched in place, so the indexes don't have to be loaded into the heap.
+/// The mapping is refreshed lazily, whenever the index file has grown since it was mapped.
 #[derive(Debug)]
 pub struct SegmentIndexReader {
     file_path: String,
     file: Arc<File>,
     index_size_bytes: Arc<AtomicU64>,
+    mmap: RwLock<Option<Arc<Mmap>>>,
 }
 
 impl SegmentIndexReader {
@@ -66,48 +68,49 @@ impl SegmentIndexReader {
             file_path: file_path.to_string(),
             file: Arc::new(file),
             index_size_bytes,
+            mmap: RwLock::new(None),
         })
     }
 
+    /// Returns the number of the complete indexes in the index file.
+    pub fn indexes_count(&self) -> u64 {
+        self.file_size() / INDEX_SIZE
+    }
+
     /// Loads all indexes from the index file.
     pub async fn load_all_indexes_impl(&self) -> Result<Vec<Index>, IggyError> {
-        let file_size = self.file_size();
-        if file_size == 0 {
+        let Some(indexes) = self.mapped_indexes()? else {
             trace!("Index file {} is empty.", self.file_path);
             return Ok(Vec::new());
-        }
-
-        let buf = match self.read_at(0, file_size).await {
-            Ok(buf) => buf,
-            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(Vec::new()),
-            Err(error) => {
-                error!(
-                    "Error reading batch header at offset 0 in file {}: {error}",
-                    self.file_path
-                );
-                return Err(IggyError::CannotReadFile);
-            }
         };
 
-        let indexes: Vec<Index> = buf
-            .chunks_exact(INDEX_SIZE as usize)
-            .map(parse_index)
+        (0..indexes.count())
+            .map(|position| indexes.get(position))
             .collect::<Result<Vec<_>, IggyError>>()
             .with_error_context(|error| {
                 format!(
                     "Failed to parse indexes in file {}: {error}",
                     self.file_path
                 )
-            })?;
-        if indexes.len() as u64 != file_size / INDEX_SIZE {
-            error!(
-                "Loaded {} indexes from disk, expected {}, file {} is probably corrupted!",
-                indexes.len(),
-                file_size / INDEX_SIZE,
-                self.file_path
-            );
-        }
-        Ok(indexes)
+            })
+    }
+
+    /// Loads the last index from the index file, without loading the preceding ones.
+    pub async fn load_last_index_impl(&self) -> Result<Option<Index>, IggyError> {
+        let Some(indexes) = self.mapped_indexes()? else {
+            trace!("Index file {} is empty.", self.file_path);
+            return Ok(None);
+        };
+
+        indexes
+            .get(indexes.count() - 1)
+            .map(Some)
+            .with_error_context(|error| {
+                format!(
+                    "Failed to parse the last index in file {}: {error}",
+                    self.file_path
+                )
+            })
     }
 
     /// Loads an index range from the index file given a start/end offset.
@@ -126,94 +129,123 @@ impl SegmentIndexReader {
             );
             return Ok(None);
         }
-        let file_size = self.file_size();
-        if file_size == 0 {
+        let Some(indexes) = self.mapped_indexes()? else {
             trace!("Index file {} is empty.", self.file_path);
             return Ok(None);
-        }
-        trace!("Index file length: {} bytes.", file_size);
+        };
 
         let relative_start_offset = (index_start_offset - segment_start_offset) as u32;
         let relative_end_offset = (index_end_offset - segment_start_offset) as u32;
-        let mut index_range = IndexRange::default();
-
-        let buf = match self.read_at(0, file_size).await {
-            Ok(buf) => buf,
-            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
-            Err(error) => {
-                error!(
-                    "Error reading batch header at offset 0 in file {}: {error}",
-                    self.file_path
-                );
-                return Err(IggyError::CannotReadFile);
-            }
-        };
-        let mut last_index = Index::default();
-        for chunk in buf.chunks_exact(INDEX_SIZE as usize) {
-            let current_index = parse_index(chunk).with_error_context(|error| {
-                format!("Failed to parse index {}: {error}", self.file_path)
-            })?;
-            if current_index.offset >= relative_start_offset
-                && index_range.start == Index::default()
-            {
-                index_range.start = current_index;
-            }
-            if current_index.offset >= relative_end_offset {
-                index_range.end = current_index;
-                break;
-            }
-            last_index = current_index;
-        }
-        if index_range.end == Index::default() {
-            index_range.end = last_index;
+        let count = indexes.count();
+
+        let start_position =
+            indexes.partition_point(|index| index.offset < relative_start_offset)?;
+        if start_position == count {
+            trace!(
+                "Index for start offset {} not found in file {}.",
+                index_start_offset,
+                self.file_path
+            );
+            return Ok(None);
         }
-        Ok(Some(index_range))
+        let end_position = indexes.partition_point(|index| index.offset < relative_end_offset)?;
+
+        Ok(Some(IndexRange {
+            start: indexes.get(start_position)?,
+            end: indexes.get(end_position.min(count - 1))?,
+        }))
     }
 
     pub async fn load_index_for_timestamp_impl(
         &self,
         timestamp: u64,
     ) -> Result<Option<Index>, IggyError> {
-        let file_size = self.file_size();
-        if file_size == 0 {
+        let Some(indexes) = self.mapped_indexes()? else {
             trace!("Index file {} is empty.", self.file_path);
             return Ok(Some(Index::default()));
-        }
-
-        let buf = match self.read_at(0, file_size).await {
-            Ok(buf) => buf,
-            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
-            Err(error) => {
-                error!(
-                    "Error reading batch header at offset 0 in file {}: {error}",
-                    self.file_path
-                );
-                return Err(IggyError::CannotReadFile);
-            }
         };
-        let mut last_index: Option<Index> = None;
-        for chunk in buf.chunks_exact(INDEX_SIZE as usize) {
-            let current = parse_index(chunk)?;
-            if current.timestamp >= timestamp {
-                return Ok(Some(last_index.unwrap_or_default()));
-            }
-            last_index = Some(current);
+
+        let position = indexes.partition_point(|index| index.timestamp < timestamp)?;
+        match position {
+            position if position == indexes.count() => Ok(None),
+            0 => Ok(Some(Index::default())),
+            position => indexes.get(position - 1).map(Some),
         }
-        Ok(None)
     }
 
     fn file_size(&self) -> u64 {
         self.index_size_bytes.load(Ordering::Acquire)
     }
 
-    async fn read_at(&self, offset: u64, len: u64) -> Result<Vec<u8>, std::io::Error> {
-        let file = self.file.clone();
-        spawn_blocking(move || {
-            let mut buf = vec![0u8; len as usize];
-            file.read_exact_at(&mut buf, offset)?;
-            Ok(buf)
+    /// Returns the view of the complete indexes in the index file, remapping it if it has grown.
+    fn mapped_indexes(&self) -> Result<Option<MappedIndexes>, IggyError> {
+        let mapped_size = self.indexes_count() * INDEX_SIZE;
+        if mapped_size == 0 {
+            return Ok(None);
+        }
+
+        if let Some(mmap) = self.mmap.read().unwrap().as_ref() {
+            if mmap.len() as u64 >= mapped_size {
+                return Ok(Some(MappedIndexes::new(mmap.clone(), mapped_size)));
+            }
+        }
+
+        // SAFETY: the index file is only appended to while it's open, it's never truncated in place,
+        // as the compaction replaces it by renaming and reopens the reader afterwards.
+        let mmap = unsafe {
+            MmapOptions::new()
+                .len(mapped_size as usize)
+                .map(self.file.as_ref())
+        }
+        .with_error_context(|error| {
+            format!(
+                "Failed to memory-map index file: {}. {error}",
+                self.file_path
+            )
         })
-        .await?
+        .map_err(|_| IggyError::CannotReadFile)?;
+        let mmap = Arc::new(mmap);
+        *self.mmap.write().unwrap() = Some(mmap.clone());
+        Ok(Some(MappedIndexes::new(mmap, mapped_size)))
+    }
+}
+
+/// The indexes read directly from the memory-mapped index file.
+struct MappedIndexes {
+    mmap: Arc<Mmap>,
+    count: usize,
+}
+
+impl MappedIndexes {
+    fn new(mmap: Arc<Mmap>, size: u64) -> Self {
+        Self {
+            mmap,
+            count: (size / INDEX_SIZE) as usize,
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn get(&self, position: usize) -> Result<Index, IggyError> {
+        let start = position * INDEX_SIZE as usize;
+        parse_index(&self.mmap[start..start + INDEX_SIZE as usize])
+    }
+
+    /// Returns the position of the first index for which the predicate is false,
+    /// the indexes are ordered by both offset and timestamp.
+    fn partition_point(&self, predicate: impl Fn(&Index) -> bool) -> Result<usize, IggyError> {
+        let (mut low, mut high) = (0, self.count);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if predicate(&self.get(middle)?) {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        Ok(low)
     }
 }
 
@@ -242,3 +274,71 @@ fn parse_index(chunk: &[u8]) -> Result<Index, IggyError> {
         timestamp,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn append_indexes(file_path: &str, indexes: &[(u32, u32, u64)]) {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(file_path)
+            .unwrap();
+        for (offset, position, timestamp) in indexes {
+            file.write_all(&offset.to_le_bytes()).unwrap();
+            file.write_all(&position.to_le_bytes()).unwrap();
+            file.write_all(&timestamp.to_le_bytes()).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn should_search_memory_mapped_indexes_and_remap_when_file_grows() {
+        let directory = std::env::temp_dir().join(format!("index-reader-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_path = directory.join("00000000000000000000.index");
+        let file_path = file_path.to_str().unwrap();
+        append_indexes(file_path, &[(5, 0, 1000), (20, 100, 2000), (35, 200, 3000)]);
+
+        let index_size_bytes = Arc::new(AtomicU64::new(0));
+        let reader = SegmentIndexReader::new(file_path, index_size_bytes.clone())
+            .await
+            .unwrap();
+        assert_eq!(reader.indexes_count(), 3);
+
+        let range = reader
+            .load_index_range_impl(15, 30, 0)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(range.start.offset, 20);
+        assert_eq!(range.end.offset, 35);
+        assert!(reader
+            .load_index_range_impl(40, 50, 0)
+            .await
+            .unwrap()
+            .is_none());
+
+        let index = reader
+            .load_index_for_timestamp_impl(2500)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(index.offset, 20);
+        assert!(reader
+            .load_index_for_timestamp_impl(3500)
+            .await
+            .unwrap()
+            .is_none());
+
+        append_indexes(file_path, &[(50, 300, 4000)]);
+        index_size_bytes.fetch_add(INDEX_SIZE, Ordering::Release);
+
+        let last_index = reader.load_last_index_impl().await.unwrap().unwrap();
+        assert_eq!(last_index.offset, 50);
+        assert_eq!(last_index.position, 300);
+        assert_eq!(reader.load_all_indexes_impl().await.unwrap().len(), 4);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -103,6 +103,11 @@ impl SegmentIndexWriter {
         Ok(())
     }
 
+    /// Enables or disables the fsync after every saved index.
+    pub fn set_fsync(&mut self, fsync: bool) {
+        self.fsync = fsync;
+    }
+
     pub async fn fsync(&self) -> Result<(), IggyError> {
         self.file
             .sync_all()
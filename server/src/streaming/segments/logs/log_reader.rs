@@ -16,12 +16,17 @@
  * under the License.
  */
 
+use super::quarantine::{CorruptedBatch, Quarantine};
+use super::uring;
 use crate::streaming::{
     batching::{
         iterator::IntoMessagesIterator,
-        message_batch::{RetainedMessageBatch, RETAINED_BATCH_HEADER_LEN},
+        message_batch::{
+            calculate_batch_checksum, RetainedMessageBatch, RETAINED_BATCH_CHECKSUM_POSITION,
+            RETAINED_BATCH_HEADER_LEN,
+        },
     },
-    segments::indexes::IndexRange,
+    segments::{encryption::SegmentCipher, indexes::IndexRange},
 };
 use bytes::BytesMut;
 use error_set::ErrContext;
@@ -46,11 +51,31 @@ pub struct SegmentLogReader {
     file_path: String,
     file: Arc<File>,
     log_size_bytes: Arc<AtomicU64>,
+    quarantine: Quarantine,
+    cipher: Option<Arc<SegmentCipher>>,
+}
+
+/// The batch read from the log file, which is corrupted when its checksum doesn't match.
+#[derive(Debug)]
+enum StoredBatch {
+    Valid(RetainedMessageBatch),
+    Corrupted(CorruptedBatch),
+}
+
+/// The result of reading all the batches from the log file.
+#[derive(Debug, Default)]
+pub struct BatchesVerification {
+    pub batches_count: u32,
+    pub corrupted_batches: Vec<CorruptedBatch>,
 }
 
 impl SegmentLogReader {
-    /// Opens the log file in read mode.
-    pub async fn new(file_path: &str, log_size_bytes: Arc<AtomicU64>) -> Result<Self, IggyError> {
+    /// Opens the log file in read mode, the payloads of the encrypted segment are decrypted with the given cipher.
+    pub async fn new(
+        file_path: &str,
+        log_size_bytes: Arc<AtomicU64>,
+        cipher: Option<Arc<SegmentCipher>>,
+    ) -> Result<Self, IggyError> {
         let file = OpenOptions::new()
             .read(true)
             .open(file_path)
@@ -87,9 +112,42 @@ impl SegmentLogReader {
             file_path: file_path.to_string(),
             file: Arc::new(file),
             log_size_bytes,
+            quarantine: Quarantine::load(file_path),
+            cipher,
         })
     }
 
+    /// Reads all the batches verifying their checksums, the corrupted ones are quarantined.
+    pub async fn verify_batches_impl(&self) -> Result<BatchesVerification, IggyError> {
+        let mut verification = BatchesVerification::default();
+        let mut file_size = self.file_size();
+        let mut offset = 0_u64;
+        while offset < file_size {
+            file_size = self.file_size();
+            match self.read_batch_at(offset, file_size).await? {
+                Some((batch, bytes_read)) => {
+                    offset += bytes_read;
+                    verification.batches_count += 1;
+                    if let StoredBatch::Corrupted(batch) = batch {
+                        verification.corrupted_batches.push(batch);
+                    }
+                }
+                None => {
+                    break;
+                }
+            }
+        }
+
+        trace!(
+            "Verified {} message batches, {} corrupted, {} quarantined in total for log file: {}.",
+            verification.batches_count,
+            verification.corrupted_batches.len(),
+            self.quarantine.count(),
+            self.file_path
+        );
+        Ok(verification)
+    }
+
     /// Loads message batches given an index range.
     pub async fn load_batches_by_range_impl(
         &self,
@@ -234,11 +292,33 @@ impl SegmentLogReader {
         Ok(())
     }
 
+    /// Reads the next valid batch, skipping the corrupted ones, so the subsequent batches are still served.
+    /// The returned number of bytes read includes the skipped batches.
     async fn read_next_batch(
         &self,
-        offset: u64,
+        mut offset: u64,
         file_size: u64,
     ) -> Result<Option<(RetainedMessageBatch, u64)>, IggyError> {
+        let mut skipped_bytes = 0;
+        loop {
+            match self.read_batch_at(offset, file_size).await? {
+                Some((StoredBatch::Valid(batch), bytes_read)) => {
+                    return Ok(Some((batch, skipped_bytes + bytes_read)));
+                }
+                Some((StoredBatch::Corrupted(_), bytes_read)) => {
+                    offset += bytes_read;
+                    skipped_bytes += bytes_read;
+                }
+                None => return Ok(None),
+            }
+        }
+    }
+
+    async fn read_batch_at(
+        &self,
+        offset: u64,
+        file_size: u64,
+    ) -> Result<Option<(StoredBatch, u64)>, IggyError> {
         let batch_header_size = RETAINED_BATCH_HEADER_LEN;
         if offset + batch_header_size > file_size {
             return Ok(None);
@@ -331,15 +411,42 @@ impl SegmentLogReader {
         };
 
         let bytes_read = batch_header_size + payload_len as u64;
+        let checksum = u32::from_le_bytes(
+            header_buf[RETAINED_BATCH_CHECKSUM_POSITION..batch_header_size as usize]
+                .try_into()
+                .unwrap(),
+        );
+        if calculate_batch_checksum(&header_buf, &payload_buf) != checksum {
+            let corrupted_batch = CorruptedBatch {
+                base_offset: batch_base_offset,
+                position: offset,
+                length: bytes_read,
+            };
+            let mut bytes = header_buf;
+            bytes.extend_from_slice(&payload_buf);
+            self.quarantine.add(&corrupted_batch, &bytes);
+            return Ok(Some((StoredBatch::Corrupted(corrupted_batch), bytes_read)));
+        }
+
+        // The checksum covers the stored (encrypted) payload, so the corrupted batches are quarantined without decrypting them.
+        let payload = match &self.cipher {
+            Some(cipher) => cipher.decrypt(&payload_buf).with_error_context(|error| {
+                format!(
+                    "Failed to decrypt batch payload at offset {payload_offset} in file {}. {error}",
+                    self.file_path
+                )
+            })?,
+            None => payload_buf,
+        };
         let batch = RetainedMessageBatch::new(
             batch_base_offset,
             last_offset_delta,
             max_timestamp,
-            IggyByteSize::from(payload_len as u64),
-            BytesMut::from(&payload_buf[..]).freeze(),
+            IggyByteSize::from(payload.len() as u64),
+            BytesMut::from(&payload[..]).freeze(),
         );
 
-        Ok(Some((batch, bytes_read)))
+        Ok(Some((StoredBatch::Valid(batch), bytes_read)))
     }
 
     fn file_size(&self) -> u64 {
@@ -348,6 +455,10 @@ impl SegmentLogReader {
 
     async fn read_at(&self, offset: u64, len: u64) -> Result<Vec<u8>, std::io::Error> {
         let file = self.file.clone();
+        if uring::is_enabled() {
+            return uring::read_at(file, offset, len).await;
+        }
+
         spawn_blocking(move || {
             let mut buf = vec![0u8; len as usize];
             file.read_exact_at(&mut buf, offset)?;
@@ -16,25 +16,22 @@
  * under the License.
  */
 
-use super::PersisterTask;
+use super::{uring, PersisterTask};
 use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use crate::streaming::utils::buffer_pool::BufferPool;
+use crate::streaming::utils::file::write_all_vectored;
+use bytes::Bytes;
 use error_set::ErrContext;
 use iggy::{
     confirmation::Confirmation,
     error::IggyError,
     utils::{byte_size::IggyByteSize, duration::IggyDuration, sizeable::Sizeable},
 };
-use std::{
-    io::IoSlice,
-    sync::{
-        atomic::{AtomicU64, Ordering},
-        Arc,
-    },
-};
-use tokio::{
-    fs::{File, OpenOptions},
-    io::AsyncWriteExt,
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
 };
+use tokio::fs::{File, OpenOptions};
 use tracing::{error, trace};
 
 /// A dedicated struct for writing to the log file.
@@ -43,10 +40,14 @@ pub struct SegmentLogWriter {
     file_path: String,
     /// Holds the file for synchronous writes; when asynchronous persistence is enabled, this will be None.
     file: Option<File>,
+    /// Holds the standard handle of the same file, opened once for the synchronous writes, which are submitted
+    /// to the io_uring backend when it's enabled, or written on the blocking thread pool otherwise.
+    std_file: Option<Arc<std::fs::File>>,
     /// When set, asynchronous writes are handled by this persister task.
     persister_task: Option<PersisterTask>,
     log_size_bytes: Arc<AtomicU64>,
     fsync: bool,
+    buffer_pool: Arc<BufferPool>,
 }
 
 impl SegmentLogWriter {
@@ -62,6 +63,7 @@ impl SegmentLogWriter {
         server_confirmation: Confirmation,
         max_file_operation_retries: u32,
         retry_delay: IggyDuration,
+        buffer_pool: Arc<BufferPool>,
     ) -> Result<Self, IggyError> {
         let file = OpenOptions::new()
             .write(true)
@@ -94,18 +96,32 @@ impl SegmentLogWriter {
                     log_size_bytes.clone(),
                     max_file_operation_retries,
                     retry_delay,
+                    buffer_pool.clone(),
                 );
                 (None, Some(persister))
             }
             Confirmation::Wait => (Some(file), None),
         };
 
+        let std_file = match &file {
+            Some(file) => Some(Arc::new(
+                file.try_clone()
+                    .await
+                    .map_err(|_| IggyError::CannotReadFile)?
+                    .into_std()
+                    .await,
+            )),
+            None => None,
+        };
+
         Ok(Self {
             file_path: file_path.to_string(),
             file,
+            std_file,
             persister_task,
             log_size_bytes,
             fsync,
+            buffer_pool,
         })
     }
 
@@ -146,23 +162,43 @@ impl SegmentLogWriter {
 
     /// Write a batch of bytes to the log file and return the new file position.
     async fn write_batch(&mut self, batch_to_write: RetainedMessageBatch) -> Result<(), IggyError> {
-        if let Some(ref mut file) = self.file {
-            let header = batch_to_write.header_as_bytes();
-            let batch_bytes = batch_to_write.bytes;
-            let slices = [IoSlice::new(&header), IoSlice::new(&batch_bytes)];
+        let Some(file) = &self.std_file else {
+            error!("File handle is not available for synchronous write.");
+            return Err(IggyError::CannotWriteToFile);
+        };
 
-            file.write_vectored(&slices)
+        if uring::is_enabled() {
+            let header = batch_to_write.header_as_bytes();
+            let mut bytes = Vec::with_capacity(header.len() + batch_to_write.bytes.len());
+            bytes.extend_from_slice(&header);
+            bytes.extend_from_slice(&batch_to_write.bytes);
+            let offset = self.log_size_bytes.load(Ordering::Acquire);
+            uring::write_at(file.clone(), offset, bytes)
                 .await
                 .with_error_context(|error| {
                     format!("Failed to log to file: {}. {error}", self.file_path)
                 })
                 .map_err(|_| IggyError::CannotWriteToFile)?;
+            self.buffer_pool.reclaim(batch_to_write.bytes);
 
-            Ok(())
-        } else {
-            error!("File handle is not available for synchronous write.");
-            Err(IggyError::CannotWriteToFile)
+            return Ok(());
         }
+
+        let header = Bytes::copy_from_slice(&batch_to_write.header_as_bytes());
+        let batch_bytes = batch_to_write.bytes;
+        write_all_vectored(file.clone(), vec![header, batch_bytes.clone()], &mut 0)
+            .await
+            .with_error_context(|error| {
+                format!("Failed to log to file: {}. {error}", self.file_path)
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        self.buffer_pool.reclaim(batch_bytes);
+        Ok(())
+    }
+
+    /// Enables or disables the fsync after every synchronous write, the persister task keeps the setting it was started with.
+    pub fn set_fsync(&mut self, fsync: bool) {
+        self.fsync = fsync;
     }
 
     pub async fn fsync(&self) -> Result<(), IggyError> {
@@ -19,7 +19,12 @@
 mod log_reader;
 mod log_writer;
 mod persister_task;
+mod quarantine;
+pub mod uring;
 
+pub use log_reader::BatchesVerification;
 pub use log_reader::SegmentLogReader;
 pub use log_writer::SegmentLogWriter;
 pub use persister_task::PersisterTask;
+pub use quarantine::CorruptedBatch;
+pub use quarantine::Quarantine;
@@ -17,17 +17,19 @@
  */
 
 use crate::streaming::batching::message_batch::{RetainedMessageBatch, RETAINED_BATCH_HEADER_LEN};
+use crate::streaming::utils::buffer_pool::BufferPool;
+use crate::streaming::utils::file::{sync_all, write_all_vectored};
+use bytes::Bytes;
 use flume::{unbounded, Receiver};
 use iggy::{error::IggyError, utils::duration::IggyDuration};
 use std::{
-    io::IoSlice,
     sync::{
         atomic::{AtomicU64, Ordering},
         Arc,
     },
     time::Duration,
 };
-use tokio::{fs::File, io::AsyncWriteExt, select, time::sleep};
+use tokio::{fs::File, select, time::sleep};
 use tracing::{error, trace, warn};
 
 #[derive(Debug)]
@@ -54,6 +56,7 @@ impl PersisterTask {
         log_file_size: Arc<AtomicU64>,
         max_retries: u32,
         retry_delay: IggyDuration,
+        buffer_pool: Arc<BufferPool>,
     ) -> Self {
         let (sender, receiver) = unbounded();
         let log_file_size_clone = log_file_size.clone();
@@ -67,6 +70,7 @@ impl PersisterTask {
                 max_retries,
                 retry_delay,
                 log_file_size_clone,
+                buffer_pool,
             )
             .await;
         });
@@ -181,24 +185,28 @@ impl PersisterTask {
 
     /// The background task loop. Processes write requests until the channel is closed.
     async fn run(
-        mut file: File,
+        file: File,
         file_path: String,
         receiver: Receiver<PersisterTaskCommand>,
         fsync: bool,
         max_retries: u32,
         retry_delay: IggyDuration,
         log_file_size: Arc<AtomicU64>,
+        buffer_pool: Arc<BufferPool>,
     ) {
+        // The standard handle is used for all the writes, so that it's not converted for every batch.
+        let file = Arc::new(file.into_std().await);
         while let Ok(request) = receiver.recv_async().await {
             match request {
                 PersisterTaskCommand::WriteRequest(batch_to_write) => {
                     match Self::write_with_retries(
-                        &mut file,
+                        &file,
                         &file_path,
                         batch_to_write,
                         fsync,
                         max_retries,
                         retry_delay,
+                        &buffer_pool,
                     )
                     .await
                     {
@@ -215,7 +223,7 @@ impl PersisterTask {
                 }
                 PersisterTaskCommand::Shutdown => {
                     trace!("LogPersisterTask for file {file_path} received shutdown command");
-                    if let Err(e) = file.sync_all().await {
+                    if let Err(e) = sync_all(file.clone()).await {
                         error!(
                             "Failed to sync_all() in LogPersisterTask for file {file_path}: {:?}",
                             e
@@ -230,25 +238,33 @@ impl PersisterTask {
 
     /// Writes the provided data to the file using simple retry logic.
     async fn write_with_retries(
-        file: &mut File,
+        file: &Arc<std::fs::File>,
         file_path: &str,
         batch_to_write: RetainedMessageBatch,
         fsync: bool,
         max_retries: u32,
         retry_delay: IggyDuration,
+        buffer_pool: &BufferPool,
     ) -> Result<u64, IggyError> {
-        let header = batch_to_write.header_as_bytes();
+        let header = Bytes::copy_from_slice(&batch_to_write.header_as_bytes());
         let batch_bytes = batch_to_write.bytes;
-        let slices = [IoSlice::new(&header), IoSlice::new(&batch_bytes)];
         let bytes_written = RETAINED_BATCH_HEADER_LEN + batch_bytes.len() as u64;
 
+        // The retry continues from the last written byte, so the partially written batch isn't appended twice,
+        // and once the whole batch has been written, only the failed fsync is retried.
+        let mut written = 0;
         let mut attempts = 0;
         loop {
-            match file.write_vectored(&slices).await {
+            let buffers = vec![header.clone(), batch_bytes.clone()];
+            let result = write_all_vectored(file.clone(), buffers, &mut written).await;
+            match result {
                 Ok(_) => {
                     if fsync {
-                        match file.sync_all().await {
-                            Ok(_) => return Ok(bytes_written),
+                        match sync_all(file.clone()).await {
+                            Ok(_) => {
+                                buffer_pool.reclaim(batch_bytes);
+                                return Ok(bytes_written);
+                            }
                             Err(e) => {
                                 attempts += 1;
                                 error!(
@@ -258,6 +274,7 @@ impl PersisterTask {
                             }
                         }
                     } else {
+                        buffer_pool.reclaim(batch_bytes);
                         return Ok(bytes_written);
                     }
                 }
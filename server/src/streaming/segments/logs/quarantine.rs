@@ -0,0 +1,150 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::segments::QUARANTINE_EXTENSION;
+use ahash::AHashSet;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::sync::Mutex;
+use tracing::{error, warn};
+
+const RECORD_HEADER_LEN: usize = 8 + 8;
+
+/// The batch whose checksum doesn't match its content, found while reading the log file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CorruptedBatch {
+    pub base_offset: u64,
+    pub position: u64,
+    pub length: u64,
+}
+
+/// Keeps the copies of the corrupted batches in the quarantine file next to the log file, so they can be inspected
+/// or recovered, while the readers skip them and continue with the subsequent batches.
+/// Each record consists of the position (u64) and the length (u64) of the batch in the log file, followed by its raw bytes.
+#[derive(Debug)]
+pub struct Quarantine {
+    path: String,
+    positions: Mutex<AHashSet<u64>>,
+}
+
+impl Quarantine {
+    /// Loads the positions of the batches already quarantined for the given log file.
+    pub fn load(log_path: &str) -> Self {
+        let path = format!("{log_path}.{QUARANTINE_EXTENSION}");
+        let positions = match std::fs::read(&path) {
+            Ok(bytes) => read_positions(&bytes),
+            Err(_) => AHashSet::new(),
+        };
+        if !positions.is_empty() {
+            warn!(
+                "Found {} quarantined batches for log file: {log_path}.",
+                positions.len()
+            );
+        }
+
+        Self {
+            path,
+            positions: Mutex::new(positions),
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.positions.lock().unwrap().len()
+    }
+
+    /// Stores the copy of the corrupted batch, unless it's already quarantined.
+    pub fn add(&self, batch: &CorruptedBatch, bytes: &[u8]) {
+        let mut positions = self.positions.lock().unwrap();
+        if positions.contains(&batch.position) {
+            return;
+        }
+
+        error!(
+            "{}, quarantining {} bytes in file: {}.",
+            IggyError::CorruptedBatch(batch.base_offset, batch.position),
+            batch.length,
+            self.path
+        );
+        let mut record = Vec::with_capacity(RECORD_HEADER_LEN + bytes.len());
+        record.extend_from_slice(&batch.position.to_le_bytes());
+        record.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+        record.extend_from_slice(bytes);
+        let result = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(&record))
+            .with_error_context(|error| {
+                format!("Failed to write quarantine file: {}. {error}", self.path)
+            });
+        if result.is_ok() {
+            positions.insert(batch.position);
+        }
+    }
+}
+
+fn read_positions(bytes: &[u8]) -> AHashSet<u64> {
+    let mut positions = AHashSet::new();
+    let mut offset = 0;
+    while offset + RECORD_HEADER_LEN <= bytes.len() {
+        let position = u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+        let length = u64::from_le_bytes(bytes[offset + 8..offset + 16].try_into().unwrap());
+        positions.insert(position);
+        offset += RECORD_HEADER_LEN + length as usize;
+    }
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_quarantine_corrupted_batch_only_once() {
+        let directory = std::env::temp_dir().join(format!("quarantine-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let log_path = directory.join("00000000000000000001.log");
+        let log_path = log_path.to_str().unwrap();
+        let batch = CorruptedBatch {
+            base_offset: 10,
+            position: 100,
+            length: 3,
+        };
+
+        let quarantine = Quarantine::load(log_path);
+        quarantine.add(&batch, &[1, 2, 3]);
+        quarantine.add(&batch, &[1, 2, 3]);
+        assert_eq!(quarantine.count(), 1);
+
+        let quarantine = Quarantine::load(log_path);
+        quarantine.add(
+            &CorruptedBatch {
+                position: 200,
+                ..batch
+            },
+            &[4, 5],
+        );
+        assert_eq!(quarantine.count(), 2);
+
+        let bytes = std::fs::read(format!("{log_path}.{QUARANTINE_EXTENSION}")).unwrap();
+        assert_eq!(bytes.len(), 2 * RECORD_HEADER_LEN + 5);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,334 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Optional io_uring backend for the segment log reads and writes.
+//!
+//! When enabled, a single driver thread owns the ring and serves the positioned reads and writes
+//! submitted by the log readers and writers, so the async tasks no longer occupy the blocking
+//! thread pool while waiting for the disk. When io_uring isn't compiled in or can't be
+//! initialized, the `tokio` backend is used instead.
+
+use crate::configs::system::IoBackend;
+use std::fs::File;
+use std::io;
+use std::sync::{Arc, OnceLock};
+use tracing::{info, warn};
+
+static DRIVER: OnceLock<Option<driver::Driver>> = OnceLock::new();
+
+/// Initializes the I/O backend, should be called once, before any segment is loaded.
+pub fn init(backend: IoBackend) {
+    let driver = match backend {
+        IoBackend::Tokio => None,
+        IoBackend::IoUring => match driver::Driver::start() {
+            Ok(driver) => {
+                info!("Using io_uring backend for the segment log files.");
+                Some(driver)
+            }
+            Err(error) => {
+                warn!("Cannot initialize io_uring backend, falling back to tokio. {error}");
+                None
+            }
+        },
+    };
+
+    if DRIVER.set(driver).is_err() {
+        warn!("I/O backend has been already initialized.");
+    }
+}
+
+/// Returns `true` if the reads and writes are handled by the io_uring driver.
+pub fn is_enabled() -> bool {
+    get_driver().is_some()
+}
+
+/// Reads exactly `len` bytes from the file, starting at the given offset.
+pub async fn read_at(file: Arc<File>, offset: u64, len: u64) -> io::Result<Vec<u8>> {
+    let driver = get_driver().ok_or_else(disabled)?;
+    driver
+        .execute(
+            file,
+            offset,
+            driver::Operation::Read(vec![0u8; len as usize]),
+        )
+        .await
+}
+
+/// Writes all the bytes to the file, starting at the given offset.
+pub async fn write_at(file: Arc<File>, offset: u64, bytes: Vec<u8>) -> io::Result<()> {
+    let driver = get_driver().ok_or_else(disabled)?;
+    driver
+        .execute(file, offset, driver::Operation::Write(bytes))
+        .await
+        .map(|_| ())
+}
+
+fn get_driver() -> Option<&'static driver::Driver> {
+    DRIVER.get().and_then(|driver| driver.as_ref())
+}
+
+fn disabled() -> io::Error {
+    io::Error::new(io::ErrorKind::Unsupported, "io_uring backend is disabled")
+}
+
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+mod driver {
+    use io_uring::{opcode, types, IoUring};
+    use std::collections::{HashMap, VecDeque};
+    use std::fs::File;
+    use std::io;
+    use std::os::unix::io::AsRawFd;
+    use std::sync::Arc;
+    use tokio::sync::oneshot;
+    use tracing::error;
+
+    const RING_ENTRIES: u32 = 256;
+
+    pub enum Operation {
+        Read(Vec<u8>),
+        Write(Vec<u8>),
+    }
+
+    struct Request {
+        file: Arc<File>,
+        offset: u64,
+        operation: Operation,
+        reply: oneshot::Sender<io::Result<Vec<u8>>>,
+    }
+
+    struct InFlight {
+        // Keeps the file descriptor open until the operation completes.
+        file: Arc<File>,
+        offset: u64,
+        operation: Operation,
+        done: usize,
+        reply: oneshot::Sender<io::Result<Vec<u8>>>,
+    }
+
+    impl InFlight {
+        fn len(&self) -> usize {
+            match &self.operation {
+                Operation::Read(buffer) | Operation::Write(buffer) => buffer.len(),
+            }
+        }
+
+        fn complete(self, result: io::Result<()>) {
+            let result = result.map(|_| match self.operation {
+                Operation::Read(buffer) | Operation::Write(buffer) => buffer,
+            });
+            let _ = self.reply.send(result);
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Driver {
+        sender: flume::Sender<Request>,
+    }
+
+    impl Driver {
+        pub fn start() -> io::Result<Self> {
+            let ring = IoUring::new(RING_ENTRIES)?;
+            let (sender, receiver) = flume::unbounded();
+            std::thread::Builder::new()
+                .name("iggy-io-uring".to_string())
+                .spawn(move || {
+                    if let Err(error) = run(ring, receiver) {
+                        error!("io_uring driver has stopped. {error}");
+                    }
+                })?;
+            Ok(Self { sender })
+        }
+
+        pub async fn execute(
+            &self,
+            file: Arc<File>,
+            offset: u64,
+            operation: Operation,
+        ) -> io::Result<Vec<u8>> {
+            let (reply, response) = oneshot::channel();
+            self.sender
+                .send_async(Request {
+                    file,
+                    offset,
+                    operation,
+                    reply,
+                })
+                .await
+                .map_err(|_| io::Error::other("io_uring driver is not running"))?;
+            response
+                .await
+                .map_err(|_| io::Error::other("io_uring driver has dropped the request"))?
+        }
+    }
+
+    fn run(mut ring: IoUring, receiver: flume::Receiver<Request>) -> io::Result<()> {
+        let mut in_flight = HashMap::<u64, InFlight>::new();
+        let mut pending = VecDeque::<u64>::new();
+        let mut next_key = 0u64;
+        loop {
+            if in_flight.is_empty() {
+                // Nothing to wait for, block until the next request arrives.
+                let Ok(request) = receiver.recv() else {
+                    return Ok(());
+                };
+                enqueue(request, &mut next_key, &mut in_flight, &mut pending);
+            }
+
+            while let Ok(request) = receiver.try_recv() {
+                enqueue(request, &mut next_key, &mut in_flight, &mut pending);
+            }
+
+            while let Some(key) = pending.front().copied() {
+                let Some(operation) = in_flight.get_mut(&key) else {
+                    pending.pop_front();
+                    continue;
+                };
+                if !push(&mut ring, key, operation) {
+                    break;
+                }
+                pending.pop_front();
+            }
+
+            match ring.submit_and_wait(1) {
+                Ok(_) => {}
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            }
+
+            let completions = ring
+                .completion()
+                .map(|entry| (entry.user_data(), entry.result()))
+                .collect::<Vec<_>>();
+            for (key, result) in completions {
+                let Some(mut operation) = in_flight.remove(&key) else {
+                    continue;
+                };
+
+                if result < 0 {
+                    let error = io::Error::from_raw_os_error(-result);
+                    if error.kind() == io::ErrorKind::Interrupted {
+                        in_flight.insert(key, operation);
+                        pending.push_back(key);
+                    } else {
+                        operation.complete(Err(error));
+                    }
+                    continue;
+                }
+
+                if result == 0 {
+                    let error = match operation.operation {
+                        Operation::Read(_) => io::ErrorKind::UnexpectedEof,
+                        Operation::Write(_) => io::ErrorKind::WriteZero,
+                    };
+                    operation.complete(Err(error.into()));
+                    continue;
+                }
+
+                // Short reads and writes are resubmitted for the remaining bytes.
+                operation.done += result as usize;
+                if operation.done < operation.len() {
+                    in_flight.insert(key, operation);
+                    pending.push_back(key);
+                    continue;
+                }
+
+                operation.complete(Ok(()));
+            }
+        }
+    }
+
+    fn enqueue(
+        request: Request,
+        next_key: &mut u64,
+        in_flight: &mut HashMap<u64, InFlight>,
+        pending: &mut VecDeque<u64>,
+    ) {
+        let key = *next_key;
+        *next_key = next_key.wrapping_add(1);
+        in_flight.insert(
+            key,
+            InFlight {
+                file: request.file,
+                offset: request.offset,
+                operation: request.operation,
+                done: 0,
+                reply: request.reply,
+            },
+        );
+        pending.push_back(key);
+    }
+
+    /// Pushes the remaining part of the operation to the submission queue, returns `false` if the queue is full.
+    fn push(ring: &mut IoUring, key: u64, operation: &mut InFlight) -> bool {
+        let fd = types::Fd(operation.file.as_raw_fd());
+        let offset = operation.offset + operation.done as u64;
+        let done = operation.done;
+        let entry = match &mut operation.operation {
+            Operation::Read(buffer) => {
+                let remaining = &mut buffer[done..];
+                opcode::Read::new(fd, remaining.as_mut_ptr(), remaining.len() as u32)
+                    .offset(offset)
+                    .build()
+            }
+            Operation::Write(buffer) => {
+                let remaining = &buffer[done..];
+                opcode::Write::new(fd, remaining.as_ptr(), remaining.len() as u32)
+                    .offset(offset)
+                    .build()
+            }
+        }
+        .user_data(key);
+
+        // SAFETY: the buffer and the file are owned by the in-flight operation,
+        // which is kept until its completion entry is reaped.
+        unsafe { ring.submission().push(&entry).is_ok() }
+    }
+}
+
+#[cfg(not(all(target_os = "linux", feature = "io-uring")))]
+mod driver {
+    use std::fs::File;
+    use std::io;
+    use std::sync::Arc;
+
+    pub enum Operation {
+        Read(Vec<u8>),
+        Write(Vec<u8>),
+    }
+
+    #[derive(Debug)]
+    pub struct Driver;
+
+    impl Driver {
+        pub fn start() -> io::Result<Self> {
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "the server was built without the io-uring feature",
+            ))
+        }
+
+        pub async fn execute(
+            &self,
+            _file: Arc<File>,
+            _offset: u64,
+            _operation: Operation,
+        ) -> io::Result<Vec<u8>> {
+            unreachable!("io_uring driver can't be started without the io-uring feature")
+        }
+    }
+}
@@ -16,14 +16,34 @@
  * under the License.
  */
 
+mod archive_manifest;
+mod compaction;
+pub mod encryption;
 mod indexes;
 mod logs;
 mod reading_messages;
+mod retention_hook;
 mod segment;
+mod stored_batches;
+mod tiering;
 mod types;
 mod writing_messages;
+pub use archive_manifest::SegmentArchiveManifest;
+pub use compaction::get_compaction_key;
+pub use compaction::CompactedSegment;
 pub use indexes::Index;
+pub use indexes::INDEX_SIZE;
+pub use logs::uring;
+pub use logs::BatchesVerification;
+pub use logs::CorruptedBatch;
+pub use logs::Quarantine;
+pub use retention_hook::RetentionHook;
+pub use retention_hook::SegmentDeletion;
 pub use segment::Segment;
+pub use stored_batches::StoredBatches;
+pub use tiering::evict_cached_segments;
+pub use tiering::TieredSegment;
+pub use tiering::TieredStorage;
 pub use types::IggyBatch;
 pub use types::IggyMessageHeaderViewMut;
 pub use types::IggyMessageViewMut;
@@ -32,4 +52,8 @@ pub use types::IggyMessagesMut;
 
 pub const LOG_EXTENSION: &str = "log";
 pub const INDEX_EXTENSION: &str = "index";
+pub const TIERED_EXTENSION: &str = "tiered";
+pub const QUARANTINE_EXTENSION: &str = "quarantine";
+pub const KEY_EXTENSION: &str = "key";
+pub const MANIFEST_EXTENSION: &str = "manifest";
 pub const SEGMENT_MAX_SIZE_BYTES: u64 = 1000 * 1000 * 1000;
@@ -21,6 +21,7 @@ use crate::streaming::batching::message_batch::RetainedMessageBatch;
 use crate::streaming::batching::{batch_filter::BatchItemizer, iterator::IntoMessagesIterator};
 use crate::streaming::models::messages::RetainedMessage;
 use crate::streaming::segments::segment::Segment;
+use crate::streaming::segments::BatchesVerification;
 use error_set::ErrContext;
 use iggy::{
     error::IggyError,
@@ -148,9 +149,8 @@ impl Segment {
     ) -> Result<Vec<RetainedMessageBatch>, IggyError> {
         let mut batches = Vec::new();
         let mut total_size_bytes = IggyByteSize::default();
-        self.log_reader
-            .as_ref()
-            .unwrap()
+        self.get_log_reader()
+            .await?
             .load_batches_by_size_with_callback(size_bytes, |batch| {
                 total_size_bytes += batch.get_size_bytes();
                 batches.push(batch);
@@ -189,9 +189,8 @@ impl Segment {
         trace!("Loading message batches for index range: {:?}", index_range);
 
         let batches = self
-            .log_reader
-            .as_ref()
-            .unwrap()
+            .get_log_reader()
+            .await?
             .load_batches_by_range_impl(index_range)
             .await
             .with_error_context(|error| {
@@ -267,9 +266,8 @@ impl Segment {
 
     /// Loads and verifies message checksums from the log file.
     pub async fn load_message_checksums(&self) -> Result<(), IggyError> {
-        self.log_reader
-            .as_ref()
-            .unwrap()
+        self.get_log_reader()
+            .await?
             .load_batches_by_range_with_callback(&IndexRange::max_range(), |batch| {
                 for message in batch.into_messages_iter() {
                     let calculated_checksum = checksum::calculate(&message.payload);
@@ -296,13 +294,21 @@ impl Segment {
         Ok(())
     }
 
+    /// Verifies the checksums of all the batches in the log file, the corrupted ones are quarantined.
+    pub async fn verify(&self) -> Result<BatchesVerification, IggyError> {
+        self.get_log_reader()
+            .await?
+            .verify_batches_impl()
+            .await
+            .with_error_context(|error| format!("Failed to verify batches for {}. {error}", self))
+    }
+
     /// Loads and returns all message IDs from the log file.
     pub async fn load_message_ids(&self) -> Result<Vec<u128>, IggyError> {
         trace!("Loading message IDs from log file: {}", self.log_path);
         let ids = self
-            .log_reader
-            .as_ref()
-            .unwrap()
+            .get_log_reader()
+            .await?
             .load_message_ids_impl()
             .await
             .with_error_context(|error| {
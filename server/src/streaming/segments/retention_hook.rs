@@ -0,0 +1,117 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::segments::segment::Segment;
+use iggy::error::IggyError;
+use iggy::utils::duration::IggyDuration;
+use reqwest::header::CONTENT_TYPE;
+use serde::Serialize;
+use tracing::{debug, warn};
+
+/// The metadata of the segment sent to the pre-deletion hook of the topic retention policy.
+/// The log path is not set for the segment offloaded to the tiered storage, its object key is set instead.
+#[derive(Debug, Serialize)]
+pub struct SegmentDeletion {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub start_offset: u64,
+    pub end_offset: u64,
+    pub messages_count: u64,
+    pub size_bytes: u64,
+    pub end_timestamp: u64,
+    pub index_path: String,
+    pub log_path: Option<String>,
+    pub tiered_object_key: Option<String>,
+}
+
+/// Calls the webhook configured in the topic retention policy before the segment is deleted.
+#[derive(Debug)]
+pub struct RetentionHook {
+    client: reqwest::Client,
+}
+
+impl RetentionHook {
+    pub fn new(timeout: IggyDuration) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(timeout.get_duration())
+            .build()
+            .unwrap_or_default();
+        Self { client }
+    }
+
+    /// Posts the segment metadata to the hook, the segment can be deleted only if the hook responded with the success status.
+    pub async fn call(&self, url: &str, segment: &SegmentDeletion) -> Result<(), IggyError> {
+        let failed =
+            || IggyError::PreDeletionHookFailed(segment.start_offset, segment.partition_id);
+        let body = serde_json::to_vec(segment).map_err(|_| failed())?;
+        let response = self
+            .client
+            .post(url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await
+            .map_err(|error| {
+                warn!(
+                    "Failed to call pre-deletion hook: {url} for segment with start offset: {} for partition with ID: {}. {error}",
+                    segment.start_offset, segment.partition_id
+                );
+                failed()
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            warn!(
+                "Pre-deletion hook: {url} responded with status: {status} for segment with start offset: {} for partition with ID: {}, the deletion is deferred.",
+                segment.start_offset, segment.partition_id
+            );
+            return Err(failed());
+        }
+
+        debug!(
+            "Pre-deletion hook: {url} accepted segment with start offset: {} for partition with ID: {}.",
+            segment.start_offset, segment.partition_id
+        );
+        Ok(())
+    }
+}
+
+impl Segment {
+    pub async fn get_deletion_metadata(&self) -> SegmentDeletion {
+        SegmentDeletion {
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            partition_id: self.partition_id,
+            start_offset: self.start_offset,
+            end_offset: self.current_offset,
+            messages_count: self.get_messages_count(),
+            size_bytes: self.size_bytes.as_bytes_u64(),
+            end_timestamp: self
+                .get_last_message_timestamp()
+                .await
+                .unwrap_or(self.end_timestamp),
+            index_path: self.index_path.clone(),
+            log_path: match self.is_tiered() {
+                true => None,
+                false => Some(self.log_path.clone()),
+            },
+            tiered_object_key: self.tiered.as_ref().map(|tiered| tiered.object_key.clone()),
+        }
+    }
+}
@@ -16,19 +16,23 @@
  * under the License.
  */
 
+use super::encryption::{self, SegmentCipher, SegmentEncryption};
 use super::indexes::*;
 use super::logs::*;
 use crate::configs::system::SystemConfig;
 use crate::streaming::batching::batch_accumulator::BatchAccumulator;
 use crate::streaming::segments::*;
+use crate::streaming::utils::buffer_pool::BufferPool;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::timestamp::IggyTimestamp;
+use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::fs::remove_file;
+use tokio::sync::OnceCell;
 use tracing::{info, warn};
 
 #[derive(Debug)]
@@ -43,6 +47,7 @@ pub struct Segment {
     pub current_offset: u64,
     pub index_path: String,
     pub log_path: String,
+    pub tiered_path: String,
     pub size_bytes: IggyByteSize,
     pub last_index_position: u32,
     pub max_size_bytes: IggyByteSize,
@@ -63,6 +68,12 @@ pub struct Segment {
     pub indexes: Option<Vec<Index>>,
     pub(super) log_size_bytes: Arc<AtomicU64>,
     pub(super) index_size_bytes: Arc<AtomicU64>,
+    pub tiered: Option<TieredSegment>,
+    pub(super) plain_messages: OnceCell<bool>,
+    pub(super) enforce_fsync: bool,
+    pub(super) cipher: Option<Arc<SegmentCipher>>,
+    pub(super) buffer_pool: Arc<BufferPool>,
+    pub(super) encryption: Arc<SegmentEncryption>,
 }
 
 impl Segment {
@@ -73,6 +84,8 @@ impl Segment {
         partition_id: u32,
         start_offset: u64,
         config: Arc<SystemConfig>,
+        buffer_pool: Arc<BufferPool>,
+        encryption: Arc<SegmentEncryption>,
         message_expiry: IggyExpiry,
         size_of_parent_stream: Arc<AtomicU64>,
         size_of_parent_topic: Arc<AtomicU64>,
@@ -84,6 +97,7 @@ impl Segment {
         let path = config.get_segment_path(stream_id, topic_id, partition_id, start_offset);
         let log_path = Self::get_log_path(&path);
         let index_path = Self::get_index_path(&path);
+        let tiered_path = Self::get_tiered_path(&path);
         let message_expiry = match message_expiry {
             IggyExpiry::ServerDefault => config.segment.message_expiry,
             _ => message_expiry,
@@ -104,6 +118,7 @@ impl Segment {
             current_offset: start_offset,
             log_path,
             index_path,
+            tiered_path,
             size_bytes: IggyByteSize::from(0),
             last_index_position: 0,
             max_size_bytes: config.segment.size,
@@ -121,9 +136,15 @@ impl Segment {
             messages_count_of_parent_stream,
             messages_count_of_parent_topic,
             messages_count_of_parent_partition,
+            enforce_fsync: config.partition.enforce_fsync,
             config,
             log_size_bytes: Arc::new(AtomicU64::new(0)),
             index_size_bytes: Arc::new(AtomicU64::new(0)),
+            tiered: None,
+            plain_messages: OnceCell::new(),
+            cipher: None,
+            buffer_pool,
+            encryption,
         }
     }
 
@@ -134,7 +155,16 @@ impl Segment {
             self.log_path, self.index_path
         );
 
-        if self.log_reader.is_none() || self.index_reader.is_none() {
+        encryption::recover_interrupted_encryption(&self.log_path, &self.index_path).await?;
+        self.load_tiered_marker().await?;
+        if let Some(tiered) = &self.tiered {
+            // The log file of the offloaded segment is kept in the tiered storage, only the indexes are read locally.
+            self.log_size_bytes
+                .store(tiered.log_size_bytes, Ordering::Release);
+            self.end_timestamp = tiered.end_timestamp;
+            self.is_closed = true;
+            self.initialize_tiered_reading().await?;
+        } else if self.log_reader.is_none() || self.index_reader.is_none() {
             self.initialize_writing().await?;
             self.initialize_reading().await?;
         }
@@ -146,35 +176,37 @@ impl Segment {
         self.size_bytes = IggyByteSize::from(log_size_bytes);
         self.last_index_position = log_size_bytes as _;
 
-        self.indexes = Some(
-            self.index_reader
-                .as_ref()
-                .unwrap()
+        // Without the indexes cache only the last index is read, the lookups search the memory-mapped index file.
+        let index_reader = self.index_reader.as_ref().unwrap();
+        let last_index = if self.config.segment.cache_indexes {
+            let indexes = index_reader
                 .load_all_indexes_impl()
                 .await
                 .with_error_context(|error| format!("Failed to load indexes for {self}. {error}"))
-                .map_err(|_| IggyError::CannotReadFile)?,
-        );
-
-        let last_index_offset = if self.indexes.as_ref().unwrap().is_empty() {
-            0_u64
+                .map_err(|_| IggyError::CannotReadFile)?;
+            let last_index = indexes.last().copied();
+            self.indexes = Some(indexes);
+            last_index
         } else {
-            self.indexes.as_ref().unwrap().last().unwrap().offset as u64
+            index_reader
+                .load_last_index_impl()
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to load the last index for {self}. {error}")
+                })
+                .map_err(|_| IggyError::CannotReadFile)?
         };
 
+        let last_index_offset = last_index.map_or(0, |index| index.offset as u64);
         self.current_offset = self.start_offset + last_index_offset;
 
         info!("Loaded {} indexes for segment with start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
-              self.indexes.as_ref().unwrap().len(),
+              self.index_reader.as_ref().unwrap().indexes_count(),
               self.start_offset,
               self.partition_id,
               self.topic_id,
               self.stream_id);
 
-        if !self.config.segment.cache_indexes {
-            self.indexes = None;
-        }
-
         if self.is_full().await {
             self.is_closed = true;
         }
@@ -220,9 +252,10 @@ impl Segment {
     }
 
     pub async fn initialize_writing(&mut self) -> Result<(), IggyError> {
+        self.initialize_cipher().await?;
         // TODO(hubcio): consider splitting enforce_fsync for index/log to separate entries in config
-        let log_fsync = self.config.partition.enforce_fsync;
-        let index_fsync = self.config.partition.enforce_fsync;
+        let log_fsync = self.enforce_fsync;
+        let index_fsync = self.enforce_fsync;
 
         let server_confirmation = self.config.segment.server_confirmation;
         let max_file_operation_retries = self.config.state.max_file_operation_retries;
@@ -235,6 +268,7 @@ impl Segment {
             server_confirmation,
             max_file_operation_retries,
             retry_delay,
+            self.buffer_pool.clone(),
         )
         .await?;
 
@@ -248,7 +282,13 @@ impl Segment {
     }
 
     pub async fn initialize_reading(&mut self) -> Result<(), IggyError> {
-        let log_reader = SegmentLogReader::new(&self.log_path, self.log_size_bytes.clone()).await?;
+        self.initialize_cipher().await?;
+        let log_reader = SegmentLogReader::new(
+            &self.log_path,
+            self.log_size_bytes.clone(),
+            self.cipher.clone(),
+        )
+        .await?;
         // TODO(hubcio): there is no need to store open fd for reader if we have index cache enabled
         let index_reader =
             SegmentIndexReader::new(&self.index_path, self.index_size_bytes.clone()).await?;
@@ -258,12 +298,70 @@ impl Segment {
         Ok(())
     }
 
-    pub async fn is_full(&self) -> bool {
-        if self.size_bytes >= self.max_size_bytes {
-            return true;
+    /// Loads the data key of the encrypted segment, or generates it for the new one, if the encryption is enabled.
+    /// The existing plaintext segments are left as they are, until they're encrypted in place.
+    async fn initialize_cipher(&mut self) -> Result<(), IggyError> {
+        if self.cipher.is_some() {
+            return Ok(());
+        }
+
+        if let Some(cipher) = SegmentCipher::load(&self.encryption, &self.log_path).await? {
+            self.cipher = Some(Arc::new(cipher));
+            return Ok(());
+        }
+
+        let is_empty = tokio::fs::metadata(&self.log_path)
+            .await
+            .map_or(true, |metadata| metadata.len() == 0);
+        if self.encryption.is_enabled() && is_empty && !self.is_tiered() {
+            self.cipher = Some(Arc::new(
+                SegmentCipher::create(&self.encryption, &self.log_path).await?,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the payloads of the stored batches are encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.cipher.is_some()
+    }
+
+    /// Returns the size of the log and index files of the segment stored on the local disk.
+    /// The log file of the tiered segment is kept in the tiered storage, so only its indexes are counted.
+    pub fn get_disk_usage_bytes(&self) -> u64 {
+        let index_size_bytes = self.index_size_bytes.load(Ordering::Acquire);
+        if self.is_tiered() {
+            return index_size_bytes;
         }
 
-        self.is_expired(IggyTimestamp::now()).await
+        self.log_size_bytes.load(Ordering::Acquire) + index_size_bytes
+    }
+
+    /// Enables or disables the fsync after every write to the log and index files of the segment.
+    pub fn set_enforce_fsync(&mut self, enforce_fsync: bool) {
+        self.enforce_fsync = enforce_fsync;
+        if let Some(log_writer) = self.log_writer.as_mut() {
+            log_writer.set_fsync(enforce_fsync);
+        }
+        if let Some(index_writer) = self.index_writer.as_mut() {
+            index_writer.set_fsync(enforce_fsync);
+        }
+    }
+
+    /// Syncs the log and index files of the segment to the disk.
+    pub async fn fsync(&self) -> Result<(), IggyError> {
+        if let Some(log_writer) = self.log_writer.as_ref() {
+            log_writer.fsync().await?;
+        }
+        if let Some(index_writer) = self.index_writer.as_ref() {
+            index_writer.fsync().await?;
+        }
+        Ok(())
+    }
+
+    /// Returns true if the segment exceeds its max size. The expiry isn't checked here, as only the closed segments expire.
+    pub async fn is_full(&self) -> bool {
+        self.size_bytes >= self.max_size_bytes
     }
 
     pub async fn is_expired(&self, now: IggyTimestamp) -> bool {
@@ -272,24 +370,51 @@ impl Segment {
         }
 
         match self.message_expiry {
-            IggyExpiry::NeverExpire => false,
-            IggyExpiry::ServerDefault => false,
+            IggyExpiry::NeverExpire => {}
+            IggyExpiry::ServerDefault => {}
             IggyExpiry::ExpireDuration(expiry) => {
-                let last_messages = self.get_messages_by_offset(self.current_offset, 1).await;
-                if last_messages.is_err() {
-                    return false;
+                if let Some(tiered) = &self.tiered {
+                    return tiered.end_timestamp + expiry.as_micros() <= now.as_micros();
                 }
 
-                let last_messages = last_messages.unwrap();
-                if last_messages.is_empty() {
-                    return false;
+                let last_messages = self.get_messages_by_offset(self.current_offset, 1).await;
+                if let Ok(last_messages) = last_messages {
+                    if let Some(last_message) = last_messages.first() {
+                        if last_message.timestamp + expiry.as_micros() <= now.as_micros() {
+                            return true;
+                        }
+                    }
                 }
-
-                let last_message = &last_messages[0];
-                let last_message_timestamp = last_message.timestamp;
-                last_message_timestamp + expiry.as_micros() <= now.as_micros()
             }
         }
+
+        // Scanning all the messages of the offloaded segment would require fetching it from the tiered storage.
+        if self.is_tiered() {
+            return false;
+        }
+
+        self.are_all_messages_expired(now).await
+    }
+
+    /// Checks if all the messages in the segment have the TTL set by the producer, which has already elapsed.
+    /// Such a segment can be removed before the message expiry configured for the topic.
+    async fn are_all_messages_expired(&self, now: IggyTimestamp) -> bool {
+        // Most of the segments contain messages without the TTL, so check the last message first to avoid reading the whole segment.
+        let Ok(last_messages) = self.get_messages_by_offset(self.current_offset, 1).await else {
+            return false;
+        };
+        if !last_messages
+            .first()
+            .is_some_and(|message| message.is_expired(now))
+        {
+            return false;
+        }
+
+        let count = (self.current_offset - self.start_offset + 1) as u32;
+        let Ok(messages) = self.get_messages_by_offset(self.start_offset, count).await else {
+            return false;
+        };
+        !messages.is_empty() && messages.iter().all(|message| message.is_expired(now))
     }
 
     pub async fn shutdown_reading(&mut self) {
@@ -324,6 +449,22 @@ impl Segment {
         }
     }
 
+    /// Waits for the pending writes and closes all the files of the segment, e.g. before they are moved to another directory.
+    /// Returns `true` if the segment was open for writing, thus the writing should be initialized again once the files are reopened.
+    pub async fn close_files(&mut self) -> bool {
+        self.shutdown_reading().await;
+        if let Some(index_writer) = self.index_writer.take() {
+            let _ = index_writer.fsync().await;
+        }
+        let Some(log_writer) = self.log_writer.take() else {
+            return false;
+        };
+
+        let _ = log_writer.fsync().await;
+        log_writer.shutdown_persister_task().await;
+        true
+    }
+
     pub async fn delete(&mut self) -> Result<(), IggyError> {
         let segment_size = self.size_bytes;
         let segment_count_of_messages = self.get_messages_count();
@@ -348,6 +489,29 @@ impl Segment {
             .with_error_context(|error| {
                 format!("Failed to delete index file: {}. {error}", self.index_path)
             });
+        let quarantine_path = format!("{}.{QUARANTINE_EXTENSION}", self.log_path);
+        if Path::new(&quarantine_path).exists() {
+            let _ = remove_file(&quarantine_path)
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to delete quarantine file: {quarantine_path}. {error}")
+                });
+        }
+        let key_path = encryption::get_key_path(&self.log_path);
+        if Path::new(&key_path).exists() {
+            let _ = remove_file(&key_path).await.with_error_context(|error| {
+                format!("Failed to delete segment key file: {key_path}. {error}")
+            });
+        }
+        let manifest_path = self.get_archive_manifest_path();
+        if Path::new(&manifest_path).exists() {
+            let _ = remove_file(&manifest_path)
+                .await
+                .with_error_context(|error| {
+                    format!("Failed to delete segment archive manifest: {manifest_path}. {error}")
+                });
+        }
+        self.delete_tiered().await;
 
         let segment_size_bytes = self.size_bytes.as_bytes_u64();
         self.size_of_parent_stream
@@ -378,13 +542,17 @@ impl Segment {
     fn get_index_path(path: &str) -> String {
         format!("{}.{}", path, INDEX_EXTENSION)
     }
+
+    fn get_tiered_path(path: &str) -> String {
+        format!("{}.{}", path, TIERED_EXTENSION)
+    }
 }
 
 impl std::fmt::Display for Segment {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Segment {{ stream ID: {}, topic ID: {}, partition_id: {}, start_offset: {}, end_offset: {}, current_offset: {}, size_bytes: {}, last_index_position: {}, max_size_bytes: {}, closed: {} }}",
+            "Segment {{ stream ID: {}, topic ID: {}, partition_id: {}, start_offset: {}, end_offset: {}, current_offset: {}, size_bytes: {}, last_index_position: {}, max_size_bytes: {}, closed: {}, tiered: {}, encrypted: {} }}",
             self.stream_id,
             self.topic_id,
             self.partition_id,
@@ -394,7 +562,9 @@ impl std::fmt::Display for Segment {
             self.size_bytes,
             self.last_index_position,
             self.max_size_bytes,
-            self.is_closed
+            self.is_closed,
+            self.is_tiered(),
+            self.is_encrypted()
         )
     }
 }
@@ -428,7 +598,9 @@ mod tests {
             topic_id,
             partition_id,
             start_offset,
-            config,
+            config.clone(),
+            Arc::new(BufferPool::new(&config.buffer_pool)),
+            Arc::new(SegmentEncryption::default()),
             message_expiry,
             size_of_parent_stream,
             size_of_parent_topic,
@@ -480,7 +652,9 @@ mod tests {
             topic_id,
             partition_id,
             start_offset,
-            config,
+            config.clone(),
+            Arc::new(BufferPool::new(&config.buffer_pool)),
+            Arc::new(SegmentEncryption::default()),
             message_expiry,
             size_of_parent_stream,
             size_of_parent_topic,
@@ -0,0 +1,275 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::indexes::*;
+use crate::streaming::batching::iterator::IntoMessagesIterator;
+use crate::streaming::batching::message_batch::RETAINED_BATCH_HEADER_LEN;
+use crate::streaming::segments::segment::Segment;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::system::enable_zero_copy_polling::STORED_BATCHES_FRAMING;
+use std::fs::File;
+use std::sync::atomic::Ordering;
+use tracing::trace;
+
+const COMPONENT: &str = "STREAMING_SEGMENT";
+
+/// The range of the segment log file holding the whole stored batches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StoredBatchesRange {
+    pub position: u64,
+    pub length: u64,
+    pub messages_count: u32,
+    pub last_offset: u64,
+    /// Whether the following batch was left out, because the response would exceed the maximum size.
+    pub truncated: bool,
+}
+
+/// The batches polled from the closed segment, which are sent to the client straight from its log file.
+#[derive(Debug)]
+pub struct StoredBatches {
+    pub partition_id: u32,
+    pub current_offset: u64,
+    pub file: File,
+    pub range: StoredBatchesRange,
+}
+
+impl StoredBatches {
+    /// Returns the framing followed by the header of the poll response (partition ID, current offset, messages count and next offset).
+    pub fn header(&self) -> [u8; 25] {
+        let next_offset = if self.range.truncated {
+            self.range.last_offset + 1
+        } else {
+            0
+        };
+        let mut header = [0u8; 25];
+        header[0] = STORED_BATCHES_FRAMING;
+        header[1..5].copy_from_slice(&self.partition_id.to_le_bytes());
+        header[5..13].copy_from_slice(&self.current_offset.to_le_bytes());
+        header[13..17].copy_from_slice(&self.range.messages_count.to_le_bytes());
+        header[17..25].copy_from_slice(&next_offset.to_le_bytes());
+        header
+    }
+}
+
+impl Segment {
+    /// Returns the range of the log file holding the whole batches starting at the given offset, if they can be sent as they are stored.
+    /// It's possible only for the local closed segment which is not encrypted, once the offset is the base offset of the stored batch,
+    /// and none of the messages has to be filtered out.
+    pub async fn get_stored_batches_range(
+        &self,
+        start_offset: u64,
+        count: u32,
+        max_size: u64,
+    ) -> Result<Option<StoredBatchesRange>, IggyError> {
+        if !self.is_closed
+            || self.tiered.is_some()
+            || self.is_encrypted()
+            || start_offset < self.start_offset
+            || start_offset > self.current_offset
+        {
+            return Ok(None);
+        }
+
+        if !self.has_only_plain_messages().await? {
+            trace!("Segment {self} contains the messages which have to be filtered, so they can't be sent as stored.");
+            return Ok(None);
+        }
+
+        let loaded_indexes;
+        let indexes = match &self.indexes {
+            Some(indexes) => indexes,
+            None => {
+                let Some(index_reader) = &self.index_reader else {
+                    return Ok(None);
+                };
+                loaded_indexes = index_reader
+                    .load_all_indexes_impl()
+                    .await
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to load indexes for {self}")
+                    })?;
+                &loaded_indexes
+            }
+        };
+
+        Ok(find_stored_batches_range(
+            indexes,
+            self.start_offset,
+            self.log_size_bytes.load(Ordering::Acquire),
+            start_offset,
+            count,
+            max_size,
+        ))
+    }
+
+    /// Checks that none of the messages carries the time to live or belongs to the transaction, as such ones might be filtered out when polled.
+    /// The log file is scanned only once, as the closed segment doesn't change anymore.
+    async fn has_only_plain_messages(&self) -> Result<bool, IggyError> {
+        self.plain_messages
+            .get_or_try_init(|| async {
+                let mut plain = true;
+                self.get_log_reader()
+                    .await?
+                    .load_batches_by_range_with_callback(&IndexRange::max_range(), |batch| {
+                        plain = plain
+                            && !batch.into_messages_iter().any(|message| {
+                                message.ttl().is_some() || message.transaction().is_some()
+                            });
+                        Ok(())
+                    })
+                    .await
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to scan messages for {self}")
+                    })?;
+                Ok::<bool, IggyError>(plain)
+            })
+            .await
+            .copied()
+    }
+}
+
+/// Finds the whole batches starting at the given offset, which fit within the count of messages and the maximum size.
+/// Each index points at the beginning of the stored batch and holds the relative offset of its last message.
+fn find_stored_batches_range(
+    indexes: &[Index],
+    segment_start_offset: u64,
+    log_size: u64,
+    start_offset: u64,
+    count: u32,
+    max_size: u64,
+) -> Option<StoredBatchesRange> {
+    let relative_start_offset = (start_offset - segment_start_offset) as u32;
+    let first = indexes.partition_point(|index| index.offset < relative_start_offset);
+    if first == indexes.len() {
+        return None;
+    }
+
+    let mut base_offset = match first {
+        0 => 0,
+        _ => indexes[first - 1].offset + 1,
+    };
+    if base_offset != relative_start_offset {
+        // The batch would have to be split, as it starts before the requested offset.
+        return None;
+    }
+
+    let position = indexes[first].position as u64;
+    let mut end_position = position;
+    let mut messages_count = 0;
+    let mut last_offset = 0;
+    let mut truncated = false;
+    for (i, index) in indexes.iter().enumerate().skip(first) {
+        let batch_end_position = indexes
+            .get(i + 1)
+            .map_or(log_size, |next| next.position as u64);
+        let batch_messages_count = index.offset - base_offset + 1;
+        if messages_count + batch_messages_count > count {
+            break;
+        }
+
+        if messages_count > 0 && batch_end_position - position > max_size {
+            truncated = true;
+            break;
+        }
+
+        messages_count += batch_messages_count;
+        end_position = batch_end_position;
+        last_offset = index.offset;
+        base_offset = index.offset + 1;
+    }
+
+    if messages_count == 0 || end_position - position < RETAINED_BATCH_HEADER_LEN {
+        return None;
+    }
+
+    Some(StoredBatchesRange {
+        position,
+        length: end_position - position,
+        messages_count,
+        last_offset: segment_start_offset + last_offset as u64,
+        truncated,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Three batches of 3, 2 and 5 messages, 100 bytes each.
+    fn indexes() -> Vec<Index> {
+        vec![
+            Index {
+                offset: 2,
+                position: 0,
+                timestamp: 1,
+            },
+            Index {
+                offset: 4,
+                position: 100,
+                timestamp: 2,
+            },
+            Index {
+                offset: 9,
+                position: 200,
+                timestamp: 3,
+            },
+        ]
+    }
+
+    #[test]
+    fn should_find_whole_batches_fitting_the_count() {
+        let range = find_stored_batches_range(&indexes(), 10, 300, 13, 6, 1000).unwrap();
+        assert_eq!(
+            range,
+            StoredBatchesRange {
+                position: 100,
+                length: 100,
+                messages_count: 2,
+                last_offset: 14,
+                truncated: false,
+            }
+        );
+
+        let range = find_stored_batches_range(&indexes(), 10, 300, 10, 10, 1000).unwrap();
+        assert_eq!(range.position, 0);
+        assert_eq!(range.length, 300);
+        assert_eq!(range.messages_count, 10);
+        assert_eq!(range.last_offset, 19);
+    }
+
+    #[test]
+    fn should_not_find_range_when_offset_is_within_batch_or_first_batch_exceeds_count() {
+        assert!(find_stored_batches_range(&indexes(), 10, 300, 11, 10, 1000).is_none());
+        assert!(find_stored_batches_range(&indexes(), 10, 300, 20, 10, 1000).is_none());
+        assert!(find_stored_batches_range(&indexes(), 10, 300, 15, 4, 1000).is_none());
+    }
+
+    #[test]
+    fn should_truncate_range_reaching_maximum_size() {
+        let range = find_stored_batches_range(&indexes(), 10, 300, 10, 10, 150).unwrap();
+        assert_eq!(range.length, 100);
+        assert_eq!(range.messages_count, 3);
+        assert_eq!(range.last_offset, 12);
+        assert!(range.truncated);
+
+        let range = find_stored_batches_range(&indexes(), 10, 300, 10, 10, 50).unwrap();
+        assert_eq!(range.length, 100);
+        assert!(range.truncated);
+    }
+}
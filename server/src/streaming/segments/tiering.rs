@@ -0,0 +1,477 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::TieringS3Config;
+use crate::streaming::segments::encryption::SegmentCipher;
+use crate::streaming::segments::indexes::SegmentIndexReader;
+use crate::streaming::segments::logs::SegmentLogReader;
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::segments::LOG_EXTENSION;
+use crate::streaming::utils::file;
+use atone::Vc;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::timestamp::IggyTimestamp;
+use s3::creds::Credentials;
+use s3::{Bucket, Region};
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+use std::time::SystemTime;
+use tokio::fs;
+use tracing::{debug, error, info, warn};
+
+const COMPONENT: &str = "STREAMING_TIERED_STORAGE";
+
+/// The metadata of the segment, which log file has been offloaded to the tiered storage.
+/// It's stored next to the index file, as the marker replacing the local log file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TieredSegment {
+    pub object_key: String,
+    pub log_size_bytes: u64,
+    pub end_timestamp: u64,
+}
+
+/// The S3-compatible (S3, MinIO, GCS etc.) object storage for the offloaded segments.
+#[derive(Debug)]
+pub struct TieredStorage {
+    bucket: Box<Bucket>,
+}
+
+impl TieredStorage {
+    pub fn new(config: &TieringS3Config) -> Result<Self, IggyError> {
+        let credentials = Credentials::new(
+            Some(&config.key_id),
+            Some(&config.key_secret),
+            None,
+            None,
+            None,
+        )
+        .map_err(|_| IggyError::CannotInitializeTieredStorage)?;
+
+        let bucket = Bucket::new(
+            &config.bucket,
+            Region::Custom {
+                endpoint: config.endpoint.clone().unwrap_or_default(),
+                region: config.region.clone().unwrap_or_default(),
+            },
+            credentials,
+        )
+        .map_err(|_| IggyError::CannotInitializeTieredStorage)?
+        .with_path_style();
+        Ok(Self { bucket })
+    }
+
+    pub async fn upload(&self, object_key: &str, path: &str) -> Result<(), IggyError> {
+        debug!("Uploading file: {path} to the tiered storage as: {object_key}");
+        let mut file = file::open(path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to open file: {path} for upload")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let response = self
+            .bucket
+            .put_object_stream(&mut file, object_key)
+            .await
+            .map_err(|error| {
+                error!("Cannot upload file: {path} to the tiered storage: {error}");
+                IggyError::CannotWriteToFile
+            })?;
+        let status = response.status_code();
+        if status != 200 {
+            error!("Cannot upload file: {path} to the tiered storage, received an invalid status code: {status}.");
+            return Err(IggyError::CannotWriteToFile);
+        }
+
+        debug!("Uploaded file: {path} to the tiered storage as: {object_key}");
+        Ok(())
+    }
+
+    pub async fn download(&self, object_key: &str, path: &str) -> Result<(), IggyError> {
+        debug!("Downloading object: {object_key} from the tiered storage to: {path}");
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent)
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to create directory for: {path}")
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+        }
+
+        // Download to the temporary file first, so the concurrent readers never see a partially fetched log.
+        let tmp_path = format!("{path}.{}.tmp", IggyTimestamp::now().as_micros());
+        let mut file = fs::File::create(&tmp_path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create file: {tmp_path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        let status = self
+            .bucket
+            .get_object_to_writer(object_key, &mut file)
+            .await
+            .map_err(|error| {
+                error!("Cannot download object: {object_key} from the tiered storage: {error}");
+                IggyError::CannotReadFile
+            });
+        drop(file);
+        match status {
+            Ok(200) => {}
+            Ok(status) => {
+                error!("Cannot download object: {object_key} from the tiered storage, received an invalid status code: {status}.");
+                let _ = fs::remove_file(&tmp_path).await;
+                return Err(IggyError::CannotReadFile);
+            }
+            Err(error) => {
+                let _ = fs::remove_file(&tmp_path).await;
+                return Err(error);
+            }
+        }
+
+        file::rename(&tmp_path, path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to rename file: {tmp_path} to: {path}"
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+        debug!("Downloaded object: {object_key} from the tiered storage to: {path}");
+        Ok(())
+    }
+
+    pub async fn delete(&self, object_key: &str) -> Result<(), IggyError> {
+        self.bucket
+            .delete_object(object_key)
+            .await
+            .map_err(|error| {
+                error!("Cannot delete object: {object_key} from the tiered storage: {error}");
+                IggyError::CannotDeleteFile
+            })?;
+        Ok(())
+    }
+}
+
+/// The log reader of the segment, either the local one or the one opened for the log fetched from the tiered storage.
+pub(super) enum LogReader<'a> {
+    Local(&'a SegmentLogReader),
+    Fetched(SegmentLogReader),
+}
+
+impl Deref for LogReader<'_> {
+    type Target = SegmentLogReader;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            LogReader::Local(reader) => reader,
+            LogReader::Fetched(reader) => reader,
+        }
+    }
+}
+
+impl Segment {
+    pub fn is_tiered(&self) -> bool {
+        self.tiered.is_some()
+    }
+
+    pub fn get_tiered_object_key(&self) -> String {
+        format!(
+            "{}/{}/{}/{:0>20}.{LOG_EXTENSION}",
+            self.stream_id, self.topic_id, self.partition_id, self.start_offset
+        )
+    }
+
+    fn get_tiered_cache_path(&self) -> String {
+        format!(
+            "{}/{}/{}/{}/{:0>20}.{LOG_EXTENSION}",
+            self.config.get_tiering_cache_path(),
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            self.start_offset
+        )
+    }
+
+    /// Returns the timestamp of the last message, which for the offloaded segment is kept in its marker.
+    pub async fn get_last_message_timestamp(&self) -> Option<u64> {
+        if let Some(tiered) = &self.tiered {
+            return Some(tiered.end_timestamp);
+        }
+
+        self.get_messages_by_offset(self.current_offset, 1)
+            .await
+            .ok()?
+            .first()
+            .map(|message| message.timestamp)
+    }
+
+    /// Loads the marker of the segment offloaded to the tiered storage, if it exists.
+    pub(super) async fn load_tiered_marker(&mut self) -> Result<(), IggyError> {
+        if !file::exists(&self.tiered_path).await.unwrap_or(false) {
+            return Ok(());
+        }
+
+        let content = fs::read(&self.tiered_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read tiered segment marker: {}",
+                    self.tiered_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let tiered = serde_json::from_slice::<TieredSegment>(&content)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to deserialize tiered segment marker: {}",
+                    self.tiered_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        // The log file might remain if the server was stopped right after storing the marker.
+        if file::exists(&self.log_path).await.unwrap_or(false) {
+            warn!(
+                "Removing the log file: {} of the segment already offloaded to the tiered storage.",
+                self.log_path
+            );
+            let _ = file::remove(&self.log_path).await;
+        }
+        self.tiered = Some(tiered);
+        Ok(())
+    }
+
+    /// Opens only the index file, as the log file of the offloaded segment is fetched on demand.
+    pub async fn initialize_tiered_reading(&mut self) -> Result<(), IggyError> {
+        if self.cipher.is_none() {
+            self.cipher = SegmentCipher::load(&self.encryption, &self.log_path)
+                .await?
+                .map(Arc::new);
+        }
+        let index_reader =
+            SegmentIndexReader::new(&self.index_path, self.index_size_bytes.clone()).await?;
+        self.index_reader = Some(index_reader);
+        Ok(())
+    }
+
+    /// Stores the marker of the uploaded segment and removes its local log file, so only the indexes remain on disk.
+    pub async fn complete_offload(&mut self, tiered: TieredSegment) -> Result<(), IggyError> {
+        let content = serde_json::to_vec(&tiered).map_err(|_| IggyError::CannotWriteToFile)?;
+        fs::write(&self.tiered_path, content)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to write tiered segment marker: {}",
+                    self.tiered_path
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+
+        self.close_files().await;
+        self.initialize_tiered_reading().await?;
+        file::remove(&self.log_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to remove offloaded log file: {}",
+                    self.log_path
+                )
+            })
+            .map_err(|_| IggyError::CannotDeleteFile)?;
+        info!(
+            "Offloaded segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {} to the tiered storage as: {}.",
+            self.start_offset, self.partition_id, self.topic_id, self.stream_id, tiered.object_key
+        );
+        self.tiered = Some(tiered);
+        Ok(())
+    }
+
+    /// Removes the marker, the cached log file and the object of the offloaded segment.
+    pub(super) async fn delete_tiered(&mut self) {
+        let Some(tiered) = self.tiered.take() else {
+            return;
+        };
+
+        let _ = file::remove(&self.tiered_path).await;
+        let _ = file::remove(&self.get_tiered_cache_path()).await;
+        match TieredStorage::new(&self.config.tiering.s3) {
+            Ok(storage) => {
+                if let Err(error) = storage.delete(&tiered.object_key).await {
+                    warn!(
+                        "Failed to delete object: {} of the offloaded segment from the tiered storage. {error}",
+                        tiered.object_key
+                    );
+                }
+            }
+            Err(error) => {
+                warn!(
+                    "Failed to initialize the tiered storage for deleting object: {}. {error}",
+                    tiered.object_key
+                );
+            }
+        }
+    }
+
+    /// Returns the local log reader, or fetches the offloaded log file into the cache and opens it.
+    pub(super) async fn get_log_reader(&self) -> Result<LogReader<'_>, IggyError> {
+        if let Some(log_reader) = &self.log_reader {
+            return Ok(LogReader::Local(log_reader));
+        }
+
+        let Some(tiered) = &self.tiered else {
+            return Err(IggyError::CannotReadFile);
+        };
+
+        let path = self.get_tiered_cache_path();
+        if file::exists(&path).await.unwrap_or(false) {
+            // Refresh the modification time, as it's used for evicting the least recently used logs.
+            if let Ok(file) = std::fs::File::options().write(true).open(&path) {
+                let _ = file.set_modified(SystemTime::now());
+            }
+        } else {
+            let storage = TieredStorage::new(&self.config.tiering.s3)?;
+            storage
+                .download(&tiered.object_key, &path)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to fetch offloaded segment: {self}"
+                    )
+                })
+                .map_err(|_| IggyError::CannotFetchSegment(self.start_offset, self.partition_id))?;
+        }
+
+        let log_reader =
+            SegmentLogReader::new(&path, Arc::new(AtomicU64::new(0)), self.cipher.clone()).await?;
+        Ok(LogReader::Fetched(log_reader))
+    }
+}
+
+/// Removes the least recently used logs fetched from the tiered storage, until the cache fits the max size.
+pub async fn evict_cached_segments(
+    cache_path: &str,
+    max_cache_size: IggyByteSize,
+) -> Result<u64, IggyError> {
+    if !file::exists(cache_path).await.unwrap_or(false) {
+        return Ok(0);
+    }
+
+    let mut files = Vec::new();
+    let mut queue: Vc<PathBuf> = Vc::new();
+    queue.push_back(PathBuf::from(cache_path));
+    while let Some(current_path) = queue.pop_front() {
+        let mut entries = fs::read_dir(&current_path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read tiered storage cache directory: {current_path:?}")
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let Ok(metadata) = entry.metadata().await else {
+                continue;
+            };
+            if metadata.is_dir() {
+                queue.push_back(entry.path());
+                continue;
+            }
+
+            let modified_at = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            files.push((entry.path(), metadata.len(), modified_at));
+        }
+    }
+
+    let mut evicted = 0;
+    for path in select_files_to_evict(files, max_cache_size.as_bytes_u64()) {
+        if let Err(error) = fs::remove_file(&path).await {
+            warn!("Failed to evict cached segment: {path:?}. {error}");
+            continue;
+        }
+        evicted += 1;
+    }
+    Ok(evicted)
+}
+
+fn select_files_to_evict(
+    mut files: Vec<(PathBuf, u64, SystemTime)>,
+    max_size_bytes: u64,
+) -> Vec<PathBuf> {
+    let mut total_size_bytes = files.iter().map(|(_, size, _)| size).sum::<u64>();
+    if total_size_bytes <= max_size_bytes {
+        return Vec::new();
+    }
+
+    files.sort_by_key(|(_, _, modified_at)| *modified_at);
+    let mut evicted = Vec::new();
+    for (path, size, _) in files {
+        if total_size_bytes <= max_size_bytes {
+            break;
+        }
+        total_size_bytes -= size;
+        evicted.push(path);
+    }
+    evicted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn cached_file(name: &str, size: u64, age_secs: u64) -> (PathBuf, u64, SystemTime) {
+        (
+            PathBuf::from(name),
+            size,
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1000 - age_secs),
+        )
+    }
+
+    #[test]
+    fn should_not_evict_any_files_when_cache_fits_max_size() {
+        let files = vec![cached_file("a", 100, 10), cached_file("b", 100, 5)];
+        assert!(select_files_to_evict(files, 200).is_empty());
+    }
+
+    #[test]
+    fn should_evict_least_recently_used_files_until_cache_fits_max_size() {
+        let files = vec![
+            cached_file("recent", 100, 1),
+            cached_file("oldest", 100, 30),
+            cached_file("older", 100, 20),
+        ];
+        let evicted = select_files_to_evict(files, 150);
+        assert_eq!(
+            evicted,
+            vec![PathBuf::from("oldest"), PathBuf::from("older")]
+        );
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_tiered_segment_marker() {
+        let tiered = TieredSegment {
+            object_key: "1/2/3/00000000000000000100.log".to_owned(),
+            log_size_bytes: 1000,
+            end_timestamp: 123,
+        };
+        let content = serde_json::to_vec(&tiered).unwrap();
+        let deserialized = serde_json::from_slice::<TieredSegment>(&content).unwrap();
+        assert_eq!(deserialized, tiered);
+    }
+}
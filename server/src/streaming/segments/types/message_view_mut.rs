@@ -39,6 +39,24 @@ impl<'a> IggyMessageViewMut<'a> {
         IggyMessageHeaderViewMut::new(hdr_slice)
     }
 
+    /// Get the message payload
+    pub fn payload(&self) -> &[u8] {
+        let payload_length = self.msg_header().payload_length() as usize;
+        &self.buffer[self.payload_offset..self.payload_offset + payload_length]
+    }
+
+    /// Get the user headers of the message, if any
+    pub fn user_headers(&self) -> Option<&[u8]> {
+        let hdr_view = self.msg_header();
+        let headers_length = hdr_view.headers_length() as usize;
+        if headers_length == 0 {
+            return None;
+        }
+
+        let headers_offset = self.payload_offset + hdr_view.payload_length() as usize;
+        Some(&self.buffer[headers_offset..headers_offset + headers_length])
+    }
+
     /// Returns the size of the entire message (header + payload + user headers).
     pub fn size(&self) -> usize {
         // TODO(hubcio): remove unwraps()
@@ -123,11 +123,18 @@ impl Segment {
             self.partition_id
         );
 
-        let batch = batch_accumulator.materialize_batch_and_update_state();
-        let batch_size = batch.get_size_bytes();
-        if batch_size > 0 {
+        let batch = batch_accumulator.materialize_batch_and_update_state(&self.buffer_pool);
+        let plain_batch_size = batch.get_size_bytes();
+        if plain_batch_size > 0 {
             self.unsaved_messages = Some(batch_accumulator);
         }
+        let batch = match &self.cipher {
+            Some(cipher) => cipher.encrypt_batch(batch).with_error_context(|error| {
+                format!("Failed to encrypt batch of size {plain_batch_size} for {self}. {error}")
+            })?,
+            None => batch,
+        };
+        let batch_size = batch.get_size_bytes();
         let confirmation = match confirmation {
             Some(val) => val,
             None => self.config.segment.server_confirmation,
@@ -149,14 +156,17 @@ impl Segment {
             .await
             .with_error_context(|error| format!("Failed to save index for {self}. {error}"))?;
 
+        // The payload size has been already added when appending the messages, so only the header and the encryption overhead remain.
+        let stored_overhead =
+            RETAINED_BATCH_HEADER_LEN + batch_size.as_bytes_u64() - plain_batch_size.as_bytes_u64();
         self.last_index_position += batch_size.as_bytes_u64() as u32;
-        self.size_bytes += IggyByteSize::from(RETAINED_BATCH_HEADER_LEN);
+        self.size_bytes += IggyByteSize::from(stored_overhead);
         self.size_of_parent_stream
-            .fetch_add(RETAINED_BATCH_HEADER_LEN, Ordering::AcqRel);
+            .fetch_add(stored_overhead, Ordering::AcqRel);
         self.size_of_parent_topic
-            .fetch_add(RETAINED_BATCH_HEADER_LEN, Ordering::AcqRel);
+            .fetch_add(stored_overhead, Ordering::AcqRel);
         self.size_of_parent_partition
-            .fetch_add(RETAINED_BATCH_HEADER_LEN, Ordering::AcqRel);
+            .fetch_add(stored_overhead, Ordering::AcqRel);
 
         trace!(
             "Saved {} messages on disk in segment with start offset: {} for partition with ID: {}, total bytes written: {}.",
@@ -167,15 +177,20 @@ impl Segment {
         );
 
         if self.is_full().await {
-            self.end_offset = self.current_offset;
-            self.is_closed = true;
-            self.unsaved_messages = None;
-            self.shutdown_writing().await;
-            info!(
-                "Closed segment with start offset: {}, end offset: {} for partition with ID: {}.",
-                self.start_offset, self.end_offset, self.partition_id
-            );
+            self.close().await;
         }
         Ok(unsaved_messages_number)
     }
+
+    /// Closes the segment for writing, the following messages are appended to the next segment.
+    pub async fn close(&mut self) {
+        self.end_offset = self.current_offset;
+        self.is_closed = true;
+        self.unsaved_messages = None;
+        self.shutdown_writing().await;
+        info!(
+            "Closed segment with start offset: {}, end offset: {} for partition with ID: {}.",
+            self.start_offset, self.end_offset, self.partition_id
+        );
+    }
 }
@@ -16,16 +16,23 @@
  * under the License.
  */
 
+use crate::streaming::users::authorizers::AuthorizationRequest;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
 use iggy::models::user_info::{AtomicUserId, UserId};
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
 
 // This might be extended with more fields in the future e.g. custom name, permissions etc.
 #[derive(Debug)]
 pub struct Session {
     user_id: AtomicUserId,
     active: AtomicBool,
+    token_scope: RwLock<Option<Arc<PersonalAccessTokenScope>>>,
+    resolved_decisions: RwLock<HashMap<AuthorizationRequest, (bool, Instant)>>,
     pub client_id: u32,
     pub ip_address: SocketAddr,
 }
@@ -35,6 +42,8 @@ impl Session {
         Self {
             client_id,
             active: AtomicBool::new(true),
+            token_scope: RwLock::new(None),
+            resolved_decisions: RwLock::new(HashMap::new()),
             user_id: AtomicUserId::new(user_id),
             ip_address,
         }
@@ -44,6 +53,12 @@ impl Session {
         Self::new(0, user_id, ip_address)
     }
 
+    /// Limits the actions of the session to the scope of the personal access token it has been created for.
+    pub fn with_token_scope(self, scope: Option<Arc<PersonalAccessTokenScope>>) -> Self {
+        *self.token_scope.write().unwrap() = scope;
+        self
+    }
+
     pub fn from_client_id(client_id: u32, ip_address: SocketAddr) -> Self {
         Self::new(client_id, 0, ip_address)
     }
@@ -61,9 +76,46 @@ impl Session {
     }
 
     pub fn clear_user_id(&self) {
+        self.set_token_scope(None);
         self.set_user_id(0)
     }
 
+    /// Returns the scope of the personal access token the client has logged in with, if any.
+    pub fn get_token_scope(&self) -> Option<Arc<PersonalAccessTokenScope>> {
+        self.token_scope.read().unwrap().clone()
+    }
+
+    pub fn set_token_scope(&self, scope: Option<PersonalAccessTokenScope>) {
+        *self.token_scope.write().unwrap() = scope.map(Arc::new);
+    }
+
+    /// Stores the decision of the external authorizer resolved before the command handled on the session takes the system lock,
+    /// valid until it expires, replacing the previous one, or removes it if the decision couldn't be made, so that the action is denied.
+    /// The expired decisions are evicted, so that the session only keeps the ones which can still be used.
+    pub fn set_resolved_decision(
+        &self,
+        request: AuthorizationRequest,
+        decision: Option<(bool, Instant)>,
+    ) {
+        let now = Instant::now();
+        let mut decisions = self.resolved_decisions.write().unwrap();
+        decisions.retain(|_, (_, expires_at)| *expires_at > now);
+        match decision {
+            Some(decision) => decisions.insert(request, decision),
+            None => decisions.remove(&request),
+        };
+    }
+
+    /// Returns the decision resolved for the request, unless it has expired.
+    pub fn get_resolved_decision(&self, request: &AuthorizationRequest) -> Option<bool> {
+        self.resolved_decisions
+            .read()
+            .unwrap()
+            .get(request)
+            .filter(|(_, expires_at)| *expires_at > Instant::now())
+            .map(|(allowed, _)| *allowed)
+    }
+
     pub fn is_active(&self) -> bool {
         self.active.load(Ordering::Acquire)
     }
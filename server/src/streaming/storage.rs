@@ -21,18 +21,21 @@ use crate::configs::system::SystemConfig;
 use crate::state::system::{PartitionState, StreamState, TopicState};
 use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
 use crate::streaming::partitions::storage::FilePartitionStorage;
+use crate::streaming::segments::encryption::SegmentEncryption;
 use crate::streaming::streams::storage::FileStreamStorage;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::info::SystemInfo;
 use crate::streaming::systems::storage::FileSystemInfoStorage;
 use crate::streaming::topics::storage::FileTopicStorage;
 use crate::streaming::topics::topic::Topic;
+use crate::streaming::utils::buffer_pool::BufferPool;
 use iggy::consumer::ConsumerKind;
 use iggy::error::IggyError;
 #[cfg(test)]
 use mockall::automock;
 use std::fmt::Debug;
 use std::future::Future;
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
 macro_rules! forward_async_methods {
@@ -148,6 +151,10 @@ pub struct SystemStorage {
     pub topic: Arc<TopicStorageKind>,
     pub partition: Arc<PartitionStorageKind>,
     pub persister: Arc<PersisterKind>,
+    pub buffer_pool: Arc<BufferPool>,
+    pub segment_encryption: Arc<SegmentEncryption>,
+    /// The number of the explicit syncs of the partitions to disk since the server start.
+    pub fsyncs_count: AtomicU64,
 }
 
 impl SystemStorage {
@@ -162,6 +169,11 @@ impl SystemStorage {
             partition: Arc::new(PartitionStorageKind::File(FilePartitionStorage::new(
                 persister.clone(),
             ))),
+            buffer_pool: Arc::new(BufferPool::new(&config.buffer_pool)),
+            segment_encryption: Arc::new(SegmentEncryption::from_config(
+                &config.segment_encryption,
+            )),
+            fsyncs_count: AtomicU64::new(0),
             persister,
         }
     }
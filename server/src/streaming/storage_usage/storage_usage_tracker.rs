@@ -0,0 +1,405 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::{StorageLimits, StorageUsageConfig};
+use ahash::AHashMap;
+use derive_more::Display;
+use iggy::error::IggyError;
+use std::sync::RwLock;
+
+/// The disk usage of the stream or topic relative to its limits.
+#[derive(Debug, Default, Display, Clone, Copy, PartialEq, Eq)]
+pub enum StorageUsageLevel {
+    #[default]
+    #[display("normal")]
+    Normal,
+    #[display("soft_limit_exceeded")]
+    SoftLimitExceeded,
+    #[display("hard_limit_exceeded")]
+    HardLimitExceeded,
+}
+
+impl StorageUsageLevel {
+    pub fn of(disk_usage_bytes: u64, limits: &StorageLimits) -> Self {
+        let hard_limit = limits.hard_limit.as_bytes_u64();
+        if hard_limit > 0 && disk_usage_bytes >= hard_limit {
+            return StorageUsageLevel::HardLimitExceeded;
+        }
+
+        let soft_limit = limits.soft_limit.as_bytes_u64();
+        if soft_limit > 0 && disk_usage_bytes >= soft_limit {
+            return StorageUsageLevel::SoftLimitExceeded;
+        }
+
+        StorageUsageLevel::Normal
+    }
+}
+
+/// The stream or topic, whose disk usage is limited.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StorageResource {
+    #[display("stream with ID: {_0}")]
+    Stream(u32),
+    #[display("topic with ID: {_1} in stream with ID: {_0}")]
+    Topic(u32, u32),
+}
+
+/// The disk usage of all the partitions, measured by the storage usage monitor.
+#[derive(Debug, Default, Clone)]
+pub struct StorageUsageSnapshot {
+    streams: AHashMap<u32, u64>,
+    topics: AHashMap<(u32, u32), u64>,
+    partitions: AHashMap<(u32, u32, u32), u64>,
+}
+
+impl StorageUsageSnapshot {
+    pub fn add_partition(
+        &mut self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        disk_usage_bytes: u64,
+    ) {
+        *self.streams.entry(stream_id).or_default() += disk_usage_bytes;
+        *self.topics.entry((stream_id, topic_id)).or_default() += disk_usage_bytes;
+        *self
+            .partitions
+            .entry((stream_id, topic_id, partition_id))
+            .or_default() += disk_usage_bytes;
+    }
+
+    pub fn streams(&self) -> impl Iterator<Item = (u32, u64)> + '_ {
+        self.streams.iter().map(|(key, usage)| (*key, *usage))
+    }
+
+    pub fn partitions(&self) -> impl Iterator<Item = ((u32, u32, u32), u64)> + '_ {
+        self.partitions.iter().map(|(key, usage)| (*key, *usage))
+    }
+
+    pub fn topics(&self) -> impl Iterator<Item = ((u32, u32), u64)> + '_ {
+        self.topics.iter().map(|(key, usage)| (*key, *usage))
+    }
+
+    fn resources(&self) -> impl Iterator<Item = (StorageResource, u64)> + '_ {
+        self.streams()
+            .map(|(stream_id, usage)| (StorageResource::Stream(stream_id), usage))
+            .chain(self.topics().map(|((stream_id, topic_id), usage)| {
+                (StorageResource::Topic(stream_id, topic_id), usage)
+            }))
+    }
+}
+
+/// The change of the usage level of the stream or topic, reported once the limit is exceeded or no longer exceeded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StorageUsageTransition {
+    pub resource: StorageResource,
+    pub disk_usage_bytes: u64,
+    pub limits: StorageLimits,
+    pub previous_level: StorageUsageLevel,
+    pub level: StorageUsageLevel,
+}
+
+/// The disk usage of the streams, topics and partitions, refreshed periodically by the storage usage monitor,
+/// so that it can be reported and checked when appending the messages without measuring the segments on demand.
+#[derive(Debug)]
+pub struct StorageUsageTracker {
+    enabled: bool,
+    reject_on_hard_limit: bool,
+    stream_limits: StorageLimits,
+    topic_limits: StorageLimits,
+    usage: RwLock<StorageUsage>,
+}
+
+#[derive(Debug, Default)]
+struct StorageUsage {
+    snapshot: StorageUsageSnapshot,
+    levels: AHashMap<StorageResource, StorageUsageLevel>,
+}
+
+impl StorageUsageTracker {
+    pub fn new(config: &StorageUsageConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            reject_on_hard_limit: config.reject_on_hard_limit,
+            stream_limits: config.stream,
+            topic_limits: config.topic,
+            usage: RwLock::new(StorageUsage::default()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Replaces the disk usage with the latest measurement, returning the streams and topics which have changed their usage level.
+    pub fn update(&self, snapshot: StorageUsageSnapshot) -> Vec<StorageUsageTransition> {
+        let mut levels = AHashMap::new();
+        let mut transitions = Vec::new();
+        let mut usage = self.usage.write().unwrap();
+        for (resource, disk_usage_bytes) in snapshot.resources() {
+            let limits = self.get_limits(resource);
+            let level = self.get_level(resource, disk_usage_bytes);
+            let previous_level = usage.levels.get(&resource).copied().unwrap_or_default();
+            if level != previous_level {
+                transitions.push(StorageUsageTransition {
+                    resource,
+                    disk_usage_bytes,
+                    limits,
+                    previous_level,
+                    level,
+                });
+            }
+            if level != StorageUsageLevel::Normal {
+                levels.insert(resource, level);
+            }
+        }
+
+        usage.snapshot = snapshot;
+        usage.levels = levels;
+        transitions
+    }
+
+    /// Returns the usage level of the stream or topic with the given disk usage, according to its limits.
+    pub fn get_level(&self, resource: StorageResource, disk_usage_bytes: u64) -> StorageUsageLevel {
+        StorageUsageLevel::of(disk_usage_bytes, &self.get_limits(resource))
+    }
+
+    pub fn get_total_disk_usage_bytes(&self) -> u64 {
+        let usage = self.usage.read().unwrap();
+        usage.snapshot.streams.values().sum()
+    }
+
+    pub fn get_topic_disk_usage_bytes(&self, stream_id: u32, topic_id: u32) -> u64 {
+        let usage = self.usage.read().unwrap();
+        usage
+            .snapshot
+            .topics
+            .get(&(stream_id, topic_id))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Fails if the topic or its stream has exceeded the hard limit, and the producers should be rejected.
+    pub fn ensure_can_append(&self, stream_id: u32, topic_id: u32) -> Result<(), IggyError> {
+        if !self.enabled || !self.reject_on_hard_limit {
+            return Ok(());
+        }
+
+        let usage = self.usage.read().unwrap();
+        for resource in [
+            StorageResource::Topic(stream_id, topic_id),
+            StorageResource::Stream(stream_id),
+        ] {
+            if usage.levels.get(&resource) != Some(&StorageUsageLevel::HardLimitExceeded) {
+                continue;
+            }
+
+            let disk_usage_bytes = match resource {
+                StorageResource::Stream(stream_id) => usage.snapshot.streams.get(&stream_id),
+                StorageResource::Topic(stream_id, topic_id) => {
+                    usage.snapshot.topics.get(&(stream_id, topic_id))
+                }
+            };
+            return Err(IggyError::StorageLimitExceeded(
+                resource.to_string(),
+                disk_usage_bytes.copied().unwrap_or_default(),
+                self.get_limits(resource).hard_limit.as_bytes_u64(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Removes the usage of the deleted topic, so that the topic created with the same ID is not rejected until the next measurement.
+    pub fn remove_topic(&self, stream_id: u32, topic_id: u32) {
+        let mut usage = self.usage.write().unwrap();
+        usage
+            .levels
+            .remove(&StorageResource::Topic(stream_id, topic_id));
+        if let Some(disk_usage_bytes) = usage.snapshot.topics.remove(&(stream_id, topic_id)) {
+            if let Some(stream_usage) = usage.snapshot.streams.get_mut(&stream_id) {
+                *stream_usage = stream_usage.saturating_sub(disk_usage_bytes);
+            }
+        }
+        usage
+            .snapshot
+            .partitions
+            .retain(|(partition_stream_id, partition_topic_id, _), _| {
+                *partition_stream_id != stream_id || *partition_topic_id != topic_id
+            });
+    }
+
+    /// Removes the usage of the deleted stream and all its topics.
+    pub fn remove_stream(&self, stream_id: u32) {
+        let mut usage = self.usage.write().unwrap();
+        usage.levels.retain(|resource, _| match resource {
+            StorageResource::Stream(id) | StorageResource::Topic(id, _) => *id != stream_id,
+        });
+        usage.snapshot.streams.remove(&stream_id);
+        usage
+            .snapshot
+            .topics
+            .retain(|(topic_stream_id, _), _| *topic_stream_id != stream_id);
+        usage
+            .snapshot
+            .partitions
+            .retain(|(partition_stream_id, _, _), _| *partition_stream_id != stream_id);
+    }
+
+    fn get_limits(&self, resource: StorageResource) -> StorageLimits {
+        match resource {
+            StorageResource::Stream(_) => self.stream_limits,
+            StorageResource::Topic(_, _) => self.topic_limits,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::byte_size::IggyByteSize;
+
+    fn limits(soft_limit: u64, hard_limit: u64) -> StorageLimits {
+        StorageLimits {
+            soft_limit: IggyByteSize::from(soft_limit),
+            hard_limit: IggyByteSize::from(hard_limit),
+        }
+    }
+
+    fn tracker(reject_on_hard_limit: bool) -> StorageUsageTracker {
+        StorageUsageTracker::new(&StorageUsageConfig {
+            enabled: true,
+            interval: "10 s".parse().unwrap(),
+            reject_on_hard_limit,
+            stream: limits(0, 1000),
+            topic: limits(100, 200),
+        })
+    }
+
+    fn snapshot(partitions: &[(u32, u32, u32, u64)]) -> StorageUsageSnapshot {
+        let mut snapshot = StorageUsageSnapshot::default();
+        for (stream_id, topic_id, partition_id, disk_usage_bytes) in partitions {
+            snapshot.add_partition(*stream_id, *topic_id, *partition_id, *disk_usage_bytes);
+        }
+        snapshot
+    }
+
+    #[test]
+    fn level_should_depend_on_the_enabled_limits() {
+        assert_eq!(
+            StorageUsageLevel::of(99, &limits(100, 200)),
+            StorageUsageLevel::Normal
+        );
+        assert_eq!(
+            StorageUsageLevel::of(100, &limits(100, 200)),
+            StorageUsageLevel::SoftLimitExceeded
+        );
+        assert_eq!(
+            StorageUsageLevel::of(200, &limits(100, 200)),
+            StorageUsageLevel::HardLimitExceeded
+        );
+        assert_eq!(
+            StorageUsageLevel::of(u64::MAX, &limits(0, 0)),
+            StorageUsageLevel::Normal
+        );
+    }
+
+    #[test]
+    fn snapshot_should_sum_the_partitions_per_topic_and_stream() {
+        let tracker = tracker(false);
+        tracker.update(snapshot(&[
+            (1, 1, 1, 10),
+            (1, 1, 2, 20),
+            (1, 2, 1, 30),
+            (2, 1, 1, 40),
+        ]));
+        assert_eq!(tracker.get_topic_disk_usage_bytes(1, 1), 30);
+        assert_eq!(tracker.get_topic_disk_usage_bytes(1, 2), 30);
+        assert_eq!(tracker.get_topic_disk_usage_bytes(3, 1), 0);
+        assert_eq!(tracker.get_total_disk_usage_bytes(), 100);
+    }
+
+    #[test]
+    fn transitions_should_be_reported_only_once_the_level_changes() {
+        let tracker = tracker(false);
+        let transitions = tracker.update(snapshot(&[(1, 1, 1, 150)]));
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(transitions[0].resource, StorageResource::Topic(1, 1));
+        assert_eq!(transitions[0].level, StorageUsageLevel::SoftLimitExceeded);
+
+        assert!(tracker.update(snapshot(&[(1, 1, 1, 160)])).is_empty());
+
+        let transitions = tracker.update(snapshot(&[(1, 1, 1, 50)]));
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(
+            transitions[0].previous_level,
+            StorageUsageLevel::SoftLimitExceeded
+        );
+        assert_eq!(transitions[0].level, StorageUsageLevel::Normal);
+    }
+
+    #[test]
+    fn append_should_be_rejected_only_above_the_hard_limit_when_enabled() {
+        let rejecting_tracker = tracker(true);
+        rejecting_tracker.update(snapshot(&[(1, 1, 1, 150)]));
+        assert!(rejecting_tracker.ensure_can_append(1, 1).is_ok());
+
+        rejecting_tracker.update(snapshot(&[(1, 1, 1, 250)]));
+        assert!(matches!(
+            rejecting_tracker.ensure_can_append(1, 1),
+            Err(IggyError::StorageLimitExceeded(_, 250, 200))
+        ));
+        assert!(rejecting_tracker.ensure_can_append(1, 2).is_ok());
+
+        let warning_tracker = tracker(false);
+        warning_tracker.update(snapshot(&[(1, 1, 1, 250)]));
+        assert!(warning_tracker.ensure_can_append(1, 1).is_ok());
+    }
+
+    #[test]
+    fn append_should_be_rejected_once_the_stream_exceeds_its_hard_limit() {
+        let tracker = tracker(true);
+        tracker.update(snapshot(&[
+            (1, 1, 1, 150),
+            (1, 2, 1, 150),
+            (1, 3, 1, 150),
+            (1, 4, 1, 150),
+            (1, 5, 1, 150),
+            (1, 6, 1, 150),
+            (1, 7, 1, 150),
+        ]));
+        assert!(matches!(
+            tracker.ensure_can_append(1, 8),
+            Err(IggyError::StorageLimitExceeded(_, 1050, 1000))
+        ));
+
+        tracker.remove_stream(1);
+        assert!(tracker.ensure_can_append(1, 8).is_ok());
+        assert_eq!(tracker.get_total_disk_usage_bytes(), 0);
+    }
+
+    #[test]
+    fn removed_topic_should_not_be_rejected() {
+        let tracker = tracker(true);
+        tracker.update(snapshot(&[(1, 1, 1, 250), (1, 2, 1, 50)]));
+        tracker.remove_topic(1, 1);
+        assert!(tracker.ensure_can_append(1, 1).is_ok());
+        assert_eq!(tracker.get_topic_disk_usage_bytes(1, 1), 0);
+        assert_eq!(tracker.get_total_disk_usage_bytes(), 50);
+    }
+}
@@ -21,6 +21,7 @@ use crate::streaming::streams::stream::Stream;
 use crate::streaming::streams::COMPONENT;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
 
 impl Stream {
     pub async fn load(&mut self, state: StreamState) -> Result<(), IggyError> {
@@ -71,6 +72,18 @@ impl Stream {
         Ok(saved_messages_number)
     }
 
+    /// Syncs the messages persisted since the last sync of the topics with the fsync policy, used on the shutdown.
+    pub async fn fsync_unsynced_messages(&self, now: IggyTimestamp) -> Result<(), IggyError> {
+        for topic in self.get_topics() {
+            topic.fsync_unsynced_messages(now).await.with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to fsync messages for topic: {topic} in stream: {self}"
+                )
+            })?;
+        }
+        Ok(())
+    }
+
     pub async fn purge(&self) -> Result<(), IggyError> {
         for topic in self.get_topics() {
             topic.purge().await.with_error_context(|error| {
@@ -24,6 +24,7 @@ use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
+use iggy::models::topic_options::TopicOptions;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
 use std::sync::atomic::Ordering;
@@ -44,6 +45,7 @@ impl Stream {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        options: TopicOptions,
     ) -> Result<u32, IggyError> {
         let max_topic_size = Topic::get_max_topic_size(max_topic_size, &self.config)?;
         if self.topics_ids.contains_key(name) {
@@ -74,7 +76,7 @@ impl Stream {
             return Err(IggyError::TopicIdAlreadyExists(id, self.stream_id));
         }
 
-        let topic = Topic::create(
+        let mut topic = Topic::create(
             self.stream_id,
             id,
             name,
@@ -90,6 +92,12 @@ impl Stream {
             replication_factor,
         )
         .await?;
+        topic
+            .set_options(options)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to set options for topic: {topic}")
+            })?;
         topic.persist().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to persist topic: {topic}")
         })?;
@@ -107,6 +115,7 @@ impl Stream {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        options: Option<TopicOptions>,
     ) -> Result<(), IggyError> {
         let message_expiry = Topic::get_message_expiry(message_expiry, &self.config);
         let max_topic_size = Topic::get_max_topic_size(max_topic_size, &self.config)?;
@@ -129,6 +138,21 @@ impl Stream {
             }
         }
 
+        // The options are set first, so the invalid schema is rejected before the topic is renamed.
+        if let Some(options) = options {
+            let topic = self.get_topic_mut(id).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get mutable reference to topic with id {id}")
+            })?;
+            topic
+                .set_options(options)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to set options for topic: {topic}"
+                    )
+                })?;
+        }
+
         let old_topic_name = {
             let topic = self.get_topic(id).with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to get topic with id: {id}")
@@ -312,6 +336,7 @@ mod tests {
                 compression_algorithm,
                 max_topic_size,
                 1,
+                TopicOptions::default(),
             )
             .await
             .unwrap();
@@ -0,0 +1,67 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::streaming::diagnostics::metrics::TransportLabel;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::audit_entry::AuditEntry;
+use iggy::system::get_audit_log::GetAuditLog;
+
+impl System {
+    /// Returns the most recent audit log entries matching the query, newest first.
+    pub fn get_audit_log(
+        &self,
+        session: &Session,
+        query: &GetAuditLog,
+    ) -> Result<Vec<AuditEntry>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_audit_log(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get audit log for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        Ok(self
+            .audit_log
+            .get_entries(query.user_id, query.action.as_deref(), query.limit as usize))
+    }
+
+    /// Records the administrative action performed by the session's user in the audit log.
+    pub(crate) fn record_audit_entry(
+        &self,
+        session: &Session,
+        transport: TransportLabel,
+        action: &str,
+        details: String,
+        result: &Result<(), IggyError>,
+    ) {
+        self.audit_log.record(
+            self.clock.now(),
+            session.get_user_id(),
+            session.ip_address,
+            transport,
+            action,
+            details,
+            result.as_ref().err().map(|error| error.to_string()),
+        );
+    }
+}
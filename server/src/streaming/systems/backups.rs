@@ -0,0 +1,1180 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//! Consistent point-in-time backups of the metadata and the selected streams, taken with the `backup create` command,
+//! restored with the `--restore <name>` server argument before the system is initialized, or imported into the running
+//! server stream by stream with the `backup restore` command.
+//!
+//! The metadata can't change while the backup is taken, as the system is locked for reading for its whole duration.
+//! The partitions of the selected streams are locked only until their unsaved messages are flushed and their files
+//! are captured: the segment files of the closed segments are immutable (the compaction and the encryption replace
+//! them with the new files), so they're hard-linked into the archive, or copied if the archive is on a different
+//! file system. The files of the open segments are copied up to their size at the time of taking the backup,
+//! once the partitions are unlocked again. The tiered segments are archived as their markers only, the objects
+//! remain in the tiered storage, same as the master key of the encrypted segments remains in its source.
+//!
+//! The incremental backup doesn't archive the segment files which are already archived by its base backup (or the
+//! ones the base is based on) with the same size, its manifest references the backup which holds them instead,
+//! so any backup can be restored on its own, as long as the referenced backups are available too.
+
+use crate::state::command::EntryCommand;
+use crate::state::file::FileState;
+use crate::state::models::{CreateConsumerGroupWithId, CreateStreamWithId, CreateTopicWithId};
+use crate::state::system::{StreamState, SystemState};
+use crate::state::State;
+use crate::streaming::persistence::persister::{FilePersister, PersisterKind};
+use crate::streaming::segments::{TieredStorage, INDEX_EXTENSION, KEY_EXTENSION, LOG_EXTENSION};
+use crate::streaming::session::Session;
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::systems::fsck::LEFTOVER_EXTENSIONS;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use crate::versioning::SemanticVersion;
+use ahash::{AHashMap, AHashSet};
+use error_set::ErrContext;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::error::IggyError;
+use iggy::identifier::{IdKind, Identifier};
+use iggy::locking::IggySharedMutFn;
+use iggy::models::backup::{BackupDestination, BackupInfo};
+use iggy::partitions::update_partition::UpdatePartition;
+use iggy::streams::create_stream::CreateStream;
+use iggy::system::create_backup::CreateBackup;
+use iggy::system::restore_backup::RestoreBackup;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::fs;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tracing::{error, info};
+
+const MANIFEST_FILE: &str = "manifest.json";
+const MANIFEST_VERSION: u32 = 1;
+const STAGING_EXTENSION: &str = "staging";
+const STATE_PATH: &str = "state";
+const STATE_LOG_FILE: &str = "log";
+/// Subpath of the backup directory where the state and the streams replaced by the restored backup are moved to.
+const REPLACED_PATH: &str = "replaced";
+
+/// The manifest of the backup, written once all the other files are archived.
+/// The paths of the directories and the files are relative to the system path.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct BackupManifest {
+    version: u32,
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    base: Option<String>,
+    created_at: IggyTimestamp,
+    streams: Vec<u32>,
+    #[serde(default)]
+    directories: Vec<String>,
+    files: Vec<BackupFile>,
+}
+
+/// The archived file, held by the backup itself, or by the base backup given in `backup`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+struct BackupFile {
+    path: String,
+    size: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    backup: Option<String>,
+}
+
+/// The file of the open segment, copied up to its size captured while the partition was locked.
+#[derive(Debug, PartialEq)]
+struct OpenFile {
+    source_path: String,
+    path: String,
+    size: u64,
+}
+
+impl BackupManifest {
+    /// Returns the path of the file in the archive directory of the backup which holds it.
+    fn get_source_path(&self, archive_path: &str, file: &BackupFile) -> String {
+        let backup = file.backup.as_deref().unwrap_or(&self.name);
+        format!("{archive_path}/{backup}/{}", file.path)
+    }
+
+    fn to_info(&self, destination: BackupDestination, location: String) -> BackupInfo {
+        let own_files = self
+            .files
+            .iter()
+            .filter(|file| file.backup.is_none())
+            .collect::<Vec<_>>();
+        BackupInfo {
+            name: self.name.clone(),
+            destination,
+            base: self.base.clone(),
+            location,
+            created_at: self.created_at,
+            streams_count: self.streams.len() as u32,
+            files_count: own_files.len() as u32 + 1,
+            size: own_files.iter().map(|file| file.size).sum::<u64>().into(),
+        }
+    }
+}
+
+/// Collects the directories and the files of the backup in its staging directory.
+struct Archive {
+    staging_path: String,
+    open_paths: AHashSet<String>,
+    base_files: AHashMap<String, BackupFile>,
+    directories: Vec<String>,
+    files: Vec<BackupFile>,
+    open_files: Vec<OpenFile>,
+}
+
+impl Archive {
+    fn new(staging_path: &str, base: Option<&BackupManifest>) -> Self {
+        let mut base_files = AHashMap::new();
+        if let Some(base) = base {
+            for file in base.files.iter().filter(|file| is_segment_file(&file.path)) {
+                let backup = file.backup.clone().unwrap_or_else(|| base.name.clone());
+                base_files.insert(
+                    file.path.clone(),
+                    BackupFile {
+                        backup: Some(backup),
+                        ..file.clone()
+                    },
+                );
+            }
+        }
+
+        Self {
+            staging_path: staging_path.to_string(),
+            open_paths: AHashSet::new(),
+            base_files,
+            directories: Vec::new(),
+            files: Vec::new(),
+            open_files: Vec::new(),
+        }
+    }
+
+    /// Archives all the files of the directory (following the links of the partitions moved to the other data directories),
+    /// except the leftovers of the interrupted compaction or encryption. The segment files are hard-linked, unless they
+    /// belong to the open segments, whose copying is deferred, or they're already archived by the base backup.
+    /// All the other files, which can be modified in place, are copied right away.
+    async fn add_directory(&mut self, source_path: &str, path: &str) -> Result<(), IggyError> {
+        let mut directories = vec![(source_path.to_string(), path.to_string())];
+        while let Some((source_path, path)) = directories.pop() {
+            let mut entries = match fs::read_dir(&source_path).await {
+                Ok(entries) => entries,
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(error) => {
+                    error!(
+                        "{COMPONENT} (error: {error}) - failed to read directory: {source_path}"
+                    );
+                    return Err(IggyError::CannotReadFile);
+                }
+            };
+            self.directories.push(path.clone());
+            while let Some(entry) = entries
+                .next_entry()
+                .await
+                .map_err(|_| IggyError::CannotReadFile)?
+            {
+                let name = entry.file_name().to_string_lossy().to_string();
+                let entry_source_path = format!("{source_path}/{name}");
+                let entry_path = format!("{path}/{name}");
+                let metadata = fs::metadata(&entry_source_path)
+                    .await
+                    .map_err(|_| IggyError::CannotReadFileMetadata)?;
+                if metadata.is_dir() {
+                    directories.push((entry_source_path, entry_path));
+                    continue;
+                }
+
+                if LEFTOVER_EXTENSIONS.contains(&get_extension(&name)) {
+                    continue;
+                }
+
+                if self.open_paths.contains(&entry_source_path) {
+                    self.open_files.push(OpenFile {
+                        source_path: entry_source_path,
+                        path: entry_path,
+                        size: metadata.len(),
+                    });
+                    continue;
+                }
+
+                if let Some(base_file) = self
+                    .base_files
+                    .get(&entry_path)
+                    .filter(|base_file| base_file.size == metadata.len())
+                {
+                    self.files.push(base_file.clone());
+                    continue;
+                }
+
+                let target_path = format!("{}/{entry_path}", self.staging_path);
+                let size = archive_file(&entry_source_path, &target_path, is_segment_file(&name))
+                    .await
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to archive file: {entry_source_path}"
+                        )
+                    })
+                    .map_err(|_| IggyError::CannotWriteToFile)?;
+                self.files.push(BackupFile {
+                    path: entry_path,
+                    size,
+                    backup: None,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the files of the open segments, captured while the partitions were locked.
+    async fn copy_open_files(&mut self) -> Result<(), IggyError> {
+        for open_file in std::mem::take(&mut self.open_files) {
+            let target_path = format!("{}/{}", self.staging_path, open_file.path);
+            copy_file_prefix(&open_file.source_path, &target_path, open_file.size)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to copy file: {} to backup",
+                        open_file.source_path
+                    )
+                })
+                .map_err(|_| IggyError::CannotWriteToFile)?;
+            self.files.push(BackupFile {
+                path: open_file.path,
+                size: open_file.size,
+                backup: None,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl System {
+    /// Takes the consistent point-in-time backup of the metadata and the selected streams (all of them, if none is selected),
+    /// and writes it to the local archive directory or the S3-compatible object storage. If the base backup is given,
+    /// the segment files it already holds are referenced rather than archived again.
+    pub async fn create_backup(
+        &self,
+        session: &Session,
+        command: &CreateBackup,
+    ) -> Result<BackupInfo, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .create_backup(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to create backup for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let streams = self.get_backup_streams(command)?;
+        let archive_path = self.config.get_backup_archive_path();
+        let backup_path = format!("{archive_path}/{}", command.name);
+        if command.destination == BackupDestination::Local && Path::new(&backup_path).exists() {
+            return Err(IggyError::BackupAlreadyExists(command.name.clone()));
+        }
+
+        let base = match &command.base {
+            Some(base) => Some(self.load_backup_manifest(base).await?),
+            None => None,
+        };
+        let staging_path = format!("{backup_path}.{STAGING_EXTENSION}");
+        remove_directory(&staging_path).await?;
+        let created_at = IggyTimestamp::now();
+        info!(
+            "Creating backup with name: {} of {} stream(s)...",
+            command.name,
+            streams.len()
+        );
+
+        let mut archive = Archive::new(&staging_path, base.as_ref());
+        archive
+            .add_directory(&self.config.get_state_path(), STATE_PATH)
+            .await?;
+
+        let mut partitions = Vec::new();
+        for stream in &streams {
+            for topic in stream.topics.values() {
+                partitions.extend(topic.partitions.values());
+            }
+        }
+        let mut locked_partitions = Vec::with_capacity(partitions.len());
+        for partition in partitions {
+            locked_partitions.push(partition.write().await);
+        }
+
+        for partition in locked_partitions.iter_mut() {
+            partition.flush_unsaved_buffer(true, self.clock.now()).await.with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to flush unsaved messages for partition with ID: {}, topic ID: {}, stream ID: {}",
+                    partition.partition_id, partition.topic_id, partition.stream_id
+                )
+            })?;
+            if let Some(segment) = partition
+                .segments
+                .last()
+                .filter(|segment| !segment.is_closed)
+            {
+                archive.open_paths.insert(segment.log_path.clone());
+                archive.open_paths.insert(segment.index_path.clone());
+            }
+        }
+        for stream in &streams {
+            archive
+                .add_directory(
+                    &stream.path,
+                    &format!("{}/{}", self.config.stream.path, stream.stream_id),
+                )
+                .await?;
+        }
+        drop(locked_partitions);
+        archive.copy_open_files().await?;
+
+        let manifest = BackupManifest {
+            version: MANIFEST_VERSION,
+            name: command.name.clone(),
+            base: command.base.clone(),
+            created_at,
+            streams: streams.iter().map(|stream| stream.stream_id).collect(),
+            directories: archive.directories,
+            files: archive.files,
+        };
+        write_manifest(&staging_path, &manifest).await?;
+        let location = match command.destination {
+            BackupDestination::Local => {
+                fs::rename(&staging_path, &backup_path)
+                    .await
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to rename backup directory: {staging_path} to: {backup_path}")
+                    })
+                    .map_err(|_| IggyError::CannotWriteToFile)?;
+                backup_path
+            }
+            BackupDestination::S3 => {
+                let result = self.upload_backup(&staging_path, &manifest).await;
+                remove_directory(&staging_path).await?;
+                result?
+            }
+        };
+
+        let backup = manifest.to_info(command.destination, location);
+        info!(
+            "Created backup with name: {} at: {}, archived {} file(s) of {} stream(s), size: {}.",
+            backup.name, backup.location, backup.files_count, backup.streams_count, backup.size
+        );
+        Ok(backup)
+    }
+
+    /// Restores the backup with the given name from the local archive directory, or downloads it from S3 first,
+    /// if it's not found locally. The current state and streams are moved aside to the backup directory, so nothing is lost.
+    /// It must be called before the system is initialized.
+    pub async fn restore_backup(&self, name: &str) -> Result<BackupInfo, IggyError> {
+        let (manifest, destination) = self.fetch_backup(name).await?;
+        let archive_path = self.config.get_backup_archive_path();
+        let replaced_path = format!(
+            "{}/{REPLACED_PATH}/{}",
+            self.config.get_backup_path(),
+            IggyTimestamp::now().as_micros()
+        );
+        restore_files(
+            &archive_path,
+            &self.config.get_system_path(),
+            &[STATE_PATH, &self.config.stream.path],
+            &replaced_path,
+            &manifest,
+        )
+        .await?;
+        info!(
+            "Restored backup with name: {name} created at: {}, the replaced state and streams were moved to: {replaced_path}.",
+            manifest.created_at.to_local_string("%Y-%m-%d %H:%M:%S")
+        );
+
+        Ok(manifest.to_info(destination, format!("{archive_path}/{name}")))
+    }
+
+    /// Imports the selected streams (all the archived ones, if none is selected) from the backup into the running server,
+    /// with their original IDs, topics, partitions and consumer groups, so the message offsets and the consumer offsets
+    /// stored along with the partitions are preserved. None of the streams can already exist.
+    pub async fn restore_backup_streams(
+        &mut self,
+        session: &Session,
+        command: &RestoreBackup,
+    ) -> Result<BackupInfo, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .restore_backup(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to restore backup for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let (manifest, destination) = self.fetch_backup(&command.name).await?;
+        let archive_path = self.config.get_backup_archive_path();
+        let backup_path = format!("{archive_path}/{}", command.name);
+        verify_files(&archive_path, &manifest).await?;
+        let streams = self
+            .load_backup_streams(&backup_path, &manifest, &command.streams)
+            .await?;
+        for stream in &streams {
+            if self.streams.contains_key(&stream.id) {
+                return Err(IggyError::StreamIdAlreadyExists(stream.id));
+            }
+
+            if self.streams_ids.contains_key(&stream.name) {
+                return Err(IggyError::StreamNameAlreadyExists(stream.name.clone()));
+            }
+        }
+
+        let streams_count = streams.len() as u32;
+        for stream in streams {
+            self.import_stream(session.get_user_id(), &archive_path, &manifest, stream)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to restore stream from backup with name: {}",
+                        command.name
+                    )
+                })?;
+        }
+
+        Ok(BackupInfo {
+            streams_count,
+            ..manifest.to_info(destination, backup_path)
+        })
+    }
+
+    /// Copies the files of the stream from the backup, loads it, and only then adds it to the state and the system,
+    /// so the stream which failed to load leaves nothing behind.
+    async fn import_stream(
+        &mut self,
+        user_id: u32,
+        archive_path: &str,
+        manifest: &BackupManifest,
+        state: StreamState,
+    ) -> Result<(), IggyError> {
+        let stream_id = state.id;
+        let stream_path = self.config.get_stream_path(stream_id);
+        let prefix = format!("{}/{stream_id}", self.config.stream.path);
+        remove_directory(&stream_path).await?;
+        copy_backup_files(
+            archive_path,
+            &self.config.get_system_path(),
+            manifest,
+            |path| path == prefix || path.starts_with(&format!("{prefix}/")),
+        )
+        .await?;
+
+        let entries = get_stream_state_entries(&state)?;
+        let mut stream = Stream::empty(
+            stream_id,
+            &state.name,
+            self.config.clone(),
+            self.storage.clone(),
+        );
+        stream.created_at = state.created_at;
+        if let Err(error) = stream.load(state).await {
+            remove_directory(&stream_path).await?;
+            return Err(error);
+        }
+
+        for entry in &entries {
+            self.state.apply(user_id, entry).await?;
+        }
+        info!(
+            "Restored stream with ID: {stream_id}, name: {} from backup with name: {}.",
+            stream.name, manifest.name
+        );
+        self.add_loaded_stream(stream);
+        Ok(())
+    }
+
+    /// Reads the streams archived in the backup from its state log, selecting the given ones (or all of them, if none is given).
+    async fn load_backup_streams(
+        &self,
+        backup_path: &str,
+        manifest: &BackupManifest,
+        stream_ids: &[Identifier],
+    ) -> Result<Vec<StreamState>, IggyError> {
+        let state = FileState::new(
+            &format!("{backup_path}/{STATE_PATH}/{STATE_LOG_FILE}"),
+            &SemanticVersion::current()?,
+            Arc::new(PersisterKind::File(FilePersister)),
+            self.encryptor.clone(),
+        );
+        let entries = state.load_entries().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to load state of backup: {backup_path}")
+        })?;
+        let mut streams = SystemState::init(entries).await?.streams;
+        streams.retain(|stream_id, _| manifest.streams.contains(stream_id));
+        if stream_ids.is_empty() {
+            let mut streams = streams.into_values().collect::<Vec<_>>();
+            streams.sort_by_key(|stream| stream.id);
+            return Ok(streams);
+        }
+
+        let mut selected_streams = Vec::new();
+        for stream_id in stream_ids {
+            let id = match stream_id.kind {
+                IdKind::Numeric => {
+                    let id = stream_id.get_u32_value()?;
+                    streams
+                        .contains_key(&id)
+                        .then_some(id)
+                        .ok_or(IggyError::StreamIdNotFound(id))?
+                }
+                IdKind::String => {
+                    let name = stream_id.get_string_value()?;
+                    streams
+                        .values()
+                        .find(|stream| stream.name == name)
+                        .map(|stream| stream.id)
+                        .ok_or(IggyError::StreamNameNotFound(name))?
+                }
+            };
+            if let Some(stream) = streams.remove(&id) {
+                selected_streams.push(stream);
+            }
+        }
+        Ok(selected_streams)
+    }
+
+    fn get_backup_streams(&self, command: &CreateBackup) -> Result<Vec<&Stream>, IggyError> {
+        let mut streams: Vec<&Stream> = Vec::new();
+        if command.streams.is_empty() {
+            streams.extend(self.streams.values());
+        }
+        for stream_id in &command.streams {
+            let stream = self.get_stream(stream_id).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id} for backup")
+            })?;
+            if !streams
+                .iter()
+                .any(|existing_stream| existing_stream.stream_id == stream.stream_id)
+            {
+                streams.push(stream);
+            }
+        }
+        streams.sort_by_key(|stream| stream.stream_id);
+        Ok(streams)
+    }
+
+    /// Makes the backup, along with the base backups holding its files, available in the local archive directory,
+    /// downloading the missing ones from S3. Returns the manifest of the backup and its original destination.
+    async fn fetch_backup(
+        &self,
+        name: &str,
+    ) -> Result<(BackupManifest, BackupDestination), IggyError> {
+        let archive_path = self.config.get_backup_archive_path();
+        let mut destination = BackupDestination::Local;
+        if !Path::new(&format!("{archive_path}/{name}/{MANIFEST_FILE}")).exists() {
+            info!("Backup with name: {name} was not found locally, downloading it from S3...");
+            self.download_backup(name, &format!("{archive_path}/{name}"))
+                .await?;
+            destination = BackupDestination::S3;
+        }
+
+        let manifest = read_manifest(&format!("{archive_path}/{name}")).await?;
+        let base_backups = manifest
+            .files
+            .iter()
+            .filter_map(|file| file.backup.as_deref())
+            .collect::<AHashSet<_>>();
+        for base_backup in base_backups {
+            let base_backup_path = format!("{archive_path}/{base_backup}");
+            if !Path::new(&format!("{base_backup_path}/{MANIFEST_FILE}")).exists() {
+                info!("Base backup with name: {base_backup} was not found locally, downloading it from S3...");
+                self.download_backup(base_backup, &base_backup_path).await?;
+            }
+        }
+        Ok((manifest, destination))
+    }
+
+    /// Reads the manifest of the backup from the local archive directory, or downloads just the manifest from S3.
+    async fn load_backup_manifest(&self, name: &str) -> Result<BackupManifest, IggyError> {
+        let backup_path = format!("{}/{name}", self.config.get_backup_archive_path());
+        if Path::new(&format!("{backup_path}/{MANIFEST_FILE}")).exists() {
+            return read_manifest(&backup_path).await;
+        }
+
+        let storage = TieredStorage::new(&self.config.backup.archive.s3)?;
+        let staging_path = format!("{backup_path}.{MANIFEST_FILE}.{STAGING_EXTENSION}");
+        storage
+            .download(
+                &format!("{}/{MANIFEST_FILE}", self.get_backup_object_prefix(name)),
+                &format!("{staging_path}/{MANIFEST_FILE}"),
+            )
+            .await
+            .map_err(|_| IggyError::BackupNotFound(name.to_string()))?;
+        let manifest = read_manifest(&staging_path).await;
+        remove_directory(&staging_path).await?;
+        manifest
+    }
+
+    /// Uploads the files held by the backup and then the manifest, so the incomplete upload is never restored.
+    async fn upload_backup(
+        &self,
+        staging_path: &str,
+        manifest: &BackupManifest,
+    ) -> Result<String, IggyError> {
+        let storage = TieredStorage::new(&self.config.backup.archive.s3)?;
+        let prefix = self.get_backup_object_prefix(&manifest.name);
+        for file in manifest.files.iter().filter(|file| file.backup.is_none()) {
+            storage
+                .upload(
+                    &format!("{prefix}/{}", file.path),
+                    &format!("{staging_path}/{}", file.path),
+                )
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to upload file: {} of backup with name: {}",
+                        file.path, manifest.name
+                    )
+                })?;
+        }
+        storage
+            .upload(
+                &format!("{prefix}/{MANIFEST_FILE}"),
+                &format!("{staging_path}/{MANIFEST_FILE}"),
+            )
+            .await?;
+        Ok(format!(
+            "s3://{}/{prefix}",
+            self.config.backup.archive.s3.bucket
+        ))
+    }
+
+    /// Downloads the manifest and then the files held by the backup.
+    async fn download_backup(&self, name: &str, backup_path: &str) -> Result<(), IggyError> {
+        let storage = TieredStorage::new(&self.config.backup.archive.s3)?;
+        let prefix = self.get_backup_object_prefix(name);
+        let staging_path = format!("{backup_path}.{STAGING_EXTENSION}");
+        remove_directory(&staging_path).await?;
+        storage
+            .download(
+                &format!("{prefix}/{MANIFEST_FILE}"),
+                &format!("{staging_path}/{MANIFEST_FILE}"),
+            )
+            .await
+            .map_err(|_| IggyError::BackupNotFound(name.to_string()))?;
+        let manifest = read_manifest(&staging_path).await?;
+        for file in manifest.files.iter().filter(|file| file.backup.is_none()) {
+            storage
+                .download(
+                    &format!("{prefix}/{}", file.path),
+                    &format!("{staging_path}/{}", file.path),
+                )
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to download file: {} of backup with name: {name}",
+                        file.path
+                    )
+                })?;
+        }
+        fs::rename(&staging_path, backup_path)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to rename backup directory: {staging_path} to: {backup_path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)
+    }
+
+    fn get_backup_object_prefix(&self, name: &str) -> String {
+        let prefix = self.config.backup.archive.s3_prefix.trim_matches('/');
+        if prefix.is_empty() {
+            return name.to_string();
+        }
+
+        format!("{prefix}/{name}")
+    }
+}
+
+/// Returns the state entries recreating the stream with its original IDs, topics, partitions and consumer groups.
+fn get_stream_state_entries(state: &StreamState) -> Result<Vec<EntryCommand>, IggyError> {
+    let stream_id = Identifier::numeric(state.id)?;
+    let mut entries = vec![EntryCommand::CreateStream(CreateStreamWithId {
+        stream_id: state.id,
+        command: CreateStream {
+            stream_id: Some(state.id),
+            name: state.name.clone(),
+        },
+    })];
+    let mut topics = state.topics.values().collect::<Vec<_>>();
+    topics.sort_by_key(|topic| topic.id);
+    for topic in topics {
+        let topic_id = Identifier::numeric(topic.id)?;
+        entries.push(EntryCommand::CreateTopic(CreateTopicWithId {
+            topic_id: topic.id,
+            command: CreateTopic {
+                stream_id: stream_id.clone(),
+                topic_id: Some(topic.id),
+                partitions_count: topic.partitions.len() as u32,
+                compression_algorithm: topic.compression_algorithm,
+                message_expiry: topic.message_expiry,
+                max_topic_size: topic.max_topic_size,
+                replication_factor: topic.replication_factor,
+                name: topic.name.clone(),
+                options: topic.options.clone(),
+            },
+        }));
+
+        let mut partitions = topic.partitions.values().collect::<Vec<_>>();
+        partitions.sort_by_key(|partition| partition.id);
+        for partition in partitions {
+            if partition.name.is_some() {
+                entries.push(EntryCommand::UpdatePartition(UpdatePartition {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id: Identifier::numeric(partition.id)?,
+                    name: partition.name.clone(),
+                }));
+            }
+        }
+
+        let mut consumer_groups = topic.consumer_groups.values().collect::<Vec<_>>();
+        consumer_groups.sort_by_key(|consumer_group| consumer_group.id);
+        for consumer_group in consumer_groups {
+            entries.push(EntryCommand::CreateConsumerGroup(
+                CreateConsumerGroupWithId {
+                    group_id: consumer_group.id,
+                    command: CreateConsumerGroup {
+                        stream_id: stream_id.clone(),
+                        topic_id: topic_id.clone(),
+                        group_id: Some(consumer_group.id),
+                        name: consumer_group.name.clone(),
+                        assignment_strategy: consumer_group.assignment_strategy,
+                    },
+                },
+            ));
+        }
+    }
+    Ok(entries)
+}
+
+fn get_extension(name: &str) -> &str {
+    Path::new(name)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+}
+
+fn is_segment_file(path: &str) -> bool {
+    [LOG_EXTENSION, INDEX_EXTENSION, KEY_EXTENSION].contains(&get_extension(path))
+}
+
+/// Hard-links the immutable file, falling back to copying it when the target is on a different file system,
+/// or copies the file which can be modified in place. Returns the size of the archived file.
+async fn archive_file(
+    source_path: &str,
+    target_path: &str,
+    is_immutable: bool,
+) -> Result<u64, std::io::Error> {
+    if let Some(parent) = Path::new(target_path).parent() {
+        fs::create_dir_all(parent).await?;
+    }
+
+    if is_immutable && fs::hard_link(source_path, target_path).await.is_ok() {
+        return Ok(fs::metadata(target_path).await?.len());
+    }
+
+    fs::copy(source_path, target_path).await
+}
+
+/// Copies the first `size` bytes of the file, i.e. its content at the time the size was captured.
+async fn copy_file_prefix(
+    source_path: &str,
+    target_path: &str,
+    size: u64,
+) -> Result<(), std::io::Error> {
+    if let Some(parent) = Path::new(target_path).parent() {
+        fs::create_dir_all(parent).await?;
+    }
+
+    let source = fs::File::open(source_path).await?;
+    let mut target = fs::File::create(target_path).await?;
+    let copied_bytes = tokio::io::copy(&mut source.take(size), &mut target).await?;
+    if copied_bytes != size {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            format!("copied {copied_bytes} of {size} bytes"),
+        ));
+    }
+
+    target.sync_all().await
+}
+
+/// Writes the manifest to the backup directory.
+async fn write_manifest(backup_path: &str, manifest: &BackupManifest) -> Result<(), IggyError> {
+    let path = format!("{backup_path}/{MANIFEST_FILE}");
+    let bytes = serde_json::to_vec_pretty(manifest)
+        .map_err(|error| IggyError::InvalidBackupManifest(error.to_string()))?;
+    fs::create_dir_all(backup_path)
+        .await
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    let mut file = fs::File::create(&path)
+        .await
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    file.write_all(&bytes)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to write backup manifest: {path}")
+        })
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    file.sync_all().await.map_err(|_| IggyError::CannotSyncFile)
+}
+
+async fn read_manifest(backup_path: &str) -> Result<BackupManifest, IggyError> {
+    let path = format!("{backup_path}/{MANIFEST_FILE}");
+    let mut bytes = Vec::new();
+    fs::File::open(&path)
+        .await
+        .map_err(|_| IggyError::BackupNotFound(backup_path.to_string()))?
+        .read_to_end(&mut bytes)
+        .await
+        .map_err(|_| IggyError::CannotReadFile)?;
+    let manifest: BackupManifest = serde_json::from_slice(&bytes)
+        .map_err(|error| IggyError::InvalidBackupManifest(error.to_string()))?;
+    if manifest.version != MANIFEST_VERSION {
+        return Err(IggyError::InvalidBackupManifest(format!(
+            "unsupported version: {}",
+            manifest.version
+        )));
+    }
+
+    Ok(manifest)
+}
+
+/// Verifies that all the files listed in the manifest are present in the backups holding them, with their archived size.
+async fn verify_files(archive_path: &str, manifest: &BackupManifest) -> Result<(), IggyError> {
+    for file in &manifest.files {
+        let path = manifest.get_source_path(archive_path, file);
+        let size = fs::metadata(&path)
+            .await
+            .map(|metadata| metadata.len())
+            .ok();
+        if size != Some(file.size) {
+            return Err(IggyError::InvalidBackupManifest(format!(
+                "file: {} is missing or has an invalid size",
+                file.path
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Verifies the backup, moves the top level directories of the system path aside, and copies the files of the backup
+/// in their place. The files are copied rather than hard-linked, as the restored open segments are appended to.
+async fn restore_files(
+    archive_path: &str,
+    system_path: &str,
+    replaced_directories: &[&str],
+    replaced_path: &str,
+    manifest: &BackupManifest,
+) -> Result<(), IggyError> {
+    verify_files(archive_path, manifest).await?;
+    fs::create_dir_all(replaced_path)
+        .await
+        .map_err(|_| IggyError::CannotWriteToFile)?;
+    for directory in replaced_directories {
+        let path = format!("{system_path}/{directory}");
+        if !Path::new(&path).exists() {
+            continue;
+        }
+
+        fs::rename(&path, format!("{replaced_path}/{directory}"))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to move directory: {path} to: {replaced_path}")
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+    }
+
+    copy_backup_files(archive_path, system_path, manifest, |_| true).await
+}
+
+/// Recreates the directories and copies the files of the backup, whose paths match the filter, into the system path.
+async fn copy_backup_files(
+    archive_path: &str,
+    system_path: &str,
+    manifest: &BackupManifest,
+    filter: impl Fn(&str) -> bool,
+) -> Result<(), IggyError> {
+    for directory in manifest.directories.iter().filter(|path| filter(path)) {
+        let path = format!("{system_path}/{directory}");
+        fs::create_dir_all(&path)
+            .await
+            .map_err(|_| IggyError::CannotCreateBaseDirectory(path))?;
+    }
+
+    for file in manifest.files.iter().filter(|file| filter(&file.path)) {
+        let source_path = manifest.get_source_path(archive_path, file);
+        let target_path = format!("{system_path}/{}", file.path);
+        archive_file(&source_path, &target_path, false)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to restore file: {}",
+                    file.path
+                )
+            })
+            .map_err(|_| IggyError::CannotWriteToFile)?;
+    }
+    Ok(())
+}
+
+async fn remove_directory(path: &str) -> Result<(), IggyError> {
+    if !Path::new(path).exists() {
+        return Ok(());
+    }
+
+    fs::remove_dir_all(path)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to remove directory: {path}")
+        })
+        .map_err(|_| IggyError::CannotDeleteFile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    async fn write_file(path: &str, content: &[u8]) {
+        fs::create_dir_all(Path::new(path).parent().unwrap())
+            .await
+            .unwrap();
+        fs::write(path, content).await.unwrap();
+    }
+
+    fn segment_path(partition_path: &str, start_offset: u64) -> String {
+        format!("{partition_path}/{start_offset:0>20}.{LOG_EXTENSION}")
+    }
+
+    fn manifest(name: &str, files: Vec<BackupFile>) -> BackupManifest {
+        BackupManifest {
+            version: MANIFEST_VERSION,
+            name: name.to_string(),
+            base: None,
+            created_at: IggyTimestamp::now(),
+            streams: vec![1],
+            directories: Vec::new(),
+            files,
+        }
+    }
+
+    fn file(path: &str, size: u64, backup: Option<&str>) -> BackupFile {
+        BackupFile {
+            path: path.to_string(),
+            size,
+            backup: backup.map(|backup| backup.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_archive_closed_segments_and_defer_open_ones() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().to_str().unwrap();
+        let partition_path = format!("{root}/streams/1/topics/1/partitions/1");
+        let closed_log_path = segment_path(&partition_path, 0);
+        let open_log_path = segment_path(&partition_path, 10);
+        write_file(&closed_log_path, b"closed").await;
+        write_file(&open_log_path, b"open").await;
+        write_file(&format!("{partition_path}/offsets/consumers/1"), b"5").await;
+        write_file(&format!("{closed_log_path}.compacted"), b"leftover").await;
+        let mut archive = Archive::new(&format!("{root}/staging"), None);
+        archive.open_paths.insert(open_log_path.clone());
+
+        archive
+            .add_directory(&format!("{root}/streams/1"), "streams/1")
+            .await
+            .unwrap();
+
+        archive.files.sort_by(|a, b| a.path.cmp(&b.path));
+        assert_eq!(
+            archive.files,
+            vec![
+                file(&segment_path("streams/1/topics/1/partitions/1", 0), 6, None),
+                file(
+                    "streams/1/topics/1/partitions/1/offsets/consumers/1",
+                    1,
+                    None
+                ),
+            ]
+        );
+        assert_eq!(
+            archive.open_files,
+            vec![OpenFile {
+                source_path: open_log_path,
+                path: segment_path("streams/1/topics/1/partitions/1", 10),
+                size: 4,
+            }]
+        );
+        assert!(archive
+            .directories
+            .contains(&"streams/1/topics/1/partitions/1/offsets/consumers".to_string()));
+    }
+
+    #[tokio::test]
+    async fn should_reference_unchanged_segments_archived_by_base_backup() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().to_str().unwrap();
+        let partition_path = format!("{root}/streams/1/topics/1/partitions/1");
+        write_file(&segment_path(&partition_path, 0), b"closed").await;
+        write_file(&segment_path(&partition_path, 10), b"grown").await;
+        write_file(&segment_path(&partition_path, 20), b"new").await;
+        let path = "streams/1/topics/1/partitions/1";
+        let mut base = manifest(
+            "incremental",
+            vec![
+                file(&segment_path(path, 0), 6, Some("full")),
+                file(&segment_path(path, 10), 2, None),
+            ],
+        );
+        base.base = Some("full".to_string());
+        let mut archive = Archive::new(&format!("{root}/staging"), Some(&base));
+
+        archive
+            .add_directory(&format!("{root}/streams/1"), "streams/1")
+            .await
+            .unwrap();
+
+        archive.files.sort_by(|a, b| a.path.cmp(&b.path));
+        assert_eq!(
+            archive.files,
+            vec![
+                file(&segment_path(path, 0), 6, Some("full")),
+                file(&segment_path(path, 10), 5, None),
+                file(&segment_path(path, 20), 3, None),
+            ]
+        );
+        assert!(!Path::new(&format!("{root}/staging/{}", segment_path(path, 0))).exists());
+    }
+
+    #[tokio::test]
+    async fn should_copy_open_file_up_to_captured_size() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().to_str().unwrap();
+        let source_path = format!("{root}/source.log");
+        let target_path = format!("{root}/backup/target.log");
+        write_file(&source_path, b"captured-appended").await;
+
+        copy_file_prefix(&source_path, &target_path, 8)
+            .await
+            .unwrap();
+
+        assert_eq!(fs::read(&target_path).await.unwrap(), b"captured");
+    }
+
+    #[tokio::test]
+    async fn should_restore_files_from_backup_and_its_base() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().to_str().unwrap();
+        let archive_path = format!("{root}/archives");
+        let system_path = format!("{root}/system");
+        let replaced_path = format!("{root}/replaced");
+        write_file(&format!("{archive_path}/full/streams/1/closed"), b"base").await;
+        write_file(&format!("{archive_path}/nightly/state/log"), b"backup").await;
+        write_file(&format!("{system_path}/state/log"), b"current").await;
+        write_file(&format!("{system_path}/streams/2/file"), b"current").await;
+        let mut manifest = manifest(
+            "nightly",
+            vec![
+                file("state/log", 6, None),
+                file("streams/1/closed", 4, Some("full")),
+            ],
+        );
+        manifest.base = Some("full".to_string());
+        manifest.directories = vec!["streams/1/offsets".to_string()];
+        write_manifest(&format!("{archive_path}/nightly"), &manifest)
+            .await
+            .unwrap();
+        assert_eq!(
+            read_manifest(&format!("{archive_path}/nightly"))
+                .await
+                .unwrap(),
+            manifest
+        );
+
+        restore_files(
+            &archive_path,
+            &system_path,
+            &["state", "streams"],
+            &replaced_path,
+            &manifest,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            fs::read(format!("{system_path}/state/log")).await.unwrap(),
+            b"backup"
+        );
+        assert_eq!(
+            fs::read(format!("{system_path}/streams/1/closed"))
+                .await
+                .unwrap(),
+            b"base"
+        );
+        assert!(Path::new(&format!("{system_path}/streams/1/offsets")).is_dir());
+        assert!(!Path::new(&format!("{system_path}/streams/2")).exists());
+        assert_eq!(
+            fs::read(format!("{replaced_path}/state/log"))
+                .await
+                .unwrap(),
+            b"current"
+        );
+        assert!(Path::new(&format!("{replaced_path}/streams/2/file")).exists());
+    }
+
+    #[tokio::test]
+    async fn should_not_restore_backup_with_missing_base_file() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().to_str().unwrap();
+        let archive_path = format!("{root}/archives");
+        let system_path = format!("{root}/system");
+        write_file(&format!("{system_path}/state/log"), b"current").await;
+        write_file(&format!("{archive_path}/nightly/state/log"), b"backup").await;
+        let manifest = manifest(
+            "nightly",
+            vec![
+                file("state/log", 6, None),
+                file("streams/1/closed", 4, Some("full")),
+            ],
+        );
+
+        let result = restore_files(
+            &archive_path,
+            &system_path,
+            &["state"],
+            &format!("{root}/replaced"),
+            &manifest,
+        )
+        .await;
+
+        assert!(matches!(result, Err(IggyError::InvalidBackupManifest(_))));
+        assert_eq!(
+            fs::read(format!("{system_path}/state/log")).await.unwrap(),
+            b"current"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::bookmark::Bookmark;
+
+impl System {
+    pub fn get_bookmark(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<Option<Bookmark>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let Some(topic) = self.try_find_topic(session, stream_id, topic_id)? else {
+            return Ok(None);
+        };
+
+        self.permissioner.get_consumer_offset(
+            session,
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - permission denied to get bookmark: {name} for user with ID: {} in topic with ID: {topic_id} and stream with ID: {stream_id}",
+                session.get_user_id(),
+            )
+        })?;
+
+        Ok(topic.bookmarks.get(name).map(|bookmark| bookmark.clone()))
+    }
+
+    pub fn get_bookmarks(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Bookmark>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
+        self.permissioner.get_consumer_offset(
+            session,
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - permission denied to get bookmarks for user with ID: {} in topic with ID: {topic_id} and stream with ID: {stream_id}",
+                session.get_user_id(),
+            )
+        })?;
+
+        Ok(topic.get_bookmarks())
+    }
+
+    pub async fn store_bookmark(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
+        self.permissioner.store_consumer_offset(
+            session,
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - permission denied to store bookmark: {name} for user with ID: {} in topic with ID: {topic_id} and stream with ID: {stream_id}",
+                session.get_user_id(),
+            )
+        })?;
+
+        topic.store_bookmark(name, partition_id, offset).await
+    }
+
+    pub async fn delete_bookmark(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
+        self.permissioner.delete_consumer_offset(
+            session,
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - permission denied to delete bookmark: {name} for user with ID: {} in topic with ID: {topic_id} and stream with ID: {stream_id}",
+                session.get_user_id(),
+            )
+        })?;
+
+        topic.delete_bookmark(name).await
+    }
+}
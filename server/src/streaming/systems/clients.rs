@@ -27,14 +27,24 @@ use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 impl System {
+    pub fn is_client_address_allowed(&self, address: &SocketAddr) -> bool {
+        if self.client_access.is_address_allowed(&address.ip()) {
+            return true;
+        }
+
+        warn!("Client with IP address: {address} is not allowed to connect.");
+        false
+    }
+
     pub async fn add_client(&self, address: &SocketAddr, transport: Transport) -> Arc<Session> {
         let mut client_manager = self.client_manager.write().await;
-        let session = client_manager.add_client(address, transport);
+        let session = client_manager.add_client(address, transport, self.clock.now());
         info!("Added {transport} client with session: {session} for IP address: {address}");
         self.metrics.increment_clients(1);
+        self.metrics.increment_connections(transport.into());
         session
     }
 
@@ -52,6 +62,7 @@ impl System {
             self.metrics.decrement_clients(1);
             let client = client.unwrap();
             let client = client.read().await;
+            self.metrics.decrement_connections(client.transport.into());
             consumer_groups = client
                 .consumer_groups
                 .iter()
@@ -64,6 +75,7 @@ impl System {
             );
         }
 
+        self.abort_client_transactions(client_id).await;
         for (stream_id, topic_id, consumer_group_id) in consumer_groups.into_iter() {
             _ = self
                 .leave_consumer_group_by_client(
@@ -83,7 +95,7 @@ impl System {
     ) -> Result<Option<IggySharedMut<Client>>, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .get_client(session.get_user_id())
+            .get_client(session)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get client with ID: {client_id} by user ID: {}",
@@ -101,7 +113,7 @@ impl System {
     ) -> Result<Vec<IggySharedMut<Client>>, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .get_clients(session.get_user_id())
+            .get_clients(session)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - failed to get clients by user ID {}",
@@ -0,0 +1,403 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::command::EntryCommand;
+use crate::state::entry::StateEntry;
+use crate::state::system::SystemState;
+use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::schemas::schema_registry::SchemaRegistry;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::users::user::User;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::cluster::{ClusterMetadata, ClusterNode, ClusterPartition, ClusterTopic};
+use std::net::{Ipv4Addr, SocketAddr};
+use tracing::{debug, info};
+
+impl System {
+    pub fn is_clustered(&self) -> bool {
+        self.cluster.is_some()
+    }
+
+    /// Returns the error pointing to the leader if the node is the cluster follower, which cannot change the metadata.
+    /// The standby server which hasn't been promoted yet cannot change it either.
+    pub async fn ensure_cluster_leader(&self) -> Result<(), IggyError> {
+        self.ensure_promoted()?;
+        match &self.cluster {
+            Some(raft) => raft.ensure_leader().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the nodes of the cluster, and the leaders and replicas of the partitions, used by the clients to route the messages.
+    /// If the clustering is disabled, the metadata is empty.
+    pub async fn get_cluster_metadata(
+        &self,
+        session: &Session,
+    ) -> Result<ClusterMetadata, IggyError> {
+        self.ensure_authenticated(session)?;
+        let (Some(raft), Some(replication)) = (&self.cluster, &self.replication) else {
+            return Ok(ClusterMetadata::default());
+        };
+
+        let config = raft.config();
+        let alive_nodes = raft.alive_nodes().await;
+        let mut nodes = vec![ClusterNode {
+            id: config.id,
+            address: config.client_address.clone(),
+            alive: alive_nodes.contains(&config.id),
+        }];
+        nodes.extend(config.nodes.iter().map(|node| ClusterNode {
+            id: node.id,
+            address: node.client_address.clone(),
+            alive: alive_nodes.contains(&node.id),
+        }));
+        nodes.sort_by_key(|node| node.id);
+
+        let mut topics = Vec::new();
+        for stream in self.get_streams() {
+            for topic in stream.get_topics() {
+                let mut partition_ids = topic.partitions.keys().copied().collect::<Vec<_>>();
+                partition_ids.sort_unstable();
+                let mut partitions = Vec::with_capacity(partition_ids.len());
+                for partition_id in partition_ids {
+                    partitions.push(ClusterPartition {
+                        id: partition_id,
+                        leader_id: replication
+                            .leader(
+                                topic.stream_id,
+                                topic.topic_id,
+                                partition_id,
+                                topic.replication_factor,
+                            )
+                            .await,
+                        replicas: replication.replicas(
+                            topic.stream_id,
+                            topic.topic_id,
+                            partition_id,
+                            topic.replication_factor,
+                        ),
+                    });
+                }
+                topics.push(ClusterTopic {
+                    stream_id: stream.stream_id,
+                    stream_name: stream.name.clone(),
+                    topic_id: topic.topic_id,
+                    topic_name: topic.name.clone(),
+                    partitions,
+                });
+            }
+        }
+
+        Ok(ClusterMetadata {
+            enabled: true,
+            node_id: config.id,
+            leader_id: raft.leader_id().await,
+            nodes,
+            topics,
+        })
+    }
+
+    /// The topic can be replicated to at most all the cluster nodes. Without the clustering, the replication factor is only stored.
+    pub fn validate_replication_factor(&self, replication_factor: u8) -> Result<(), IggyError> {
+        let Some(raft) = &self.cluster else {
+            return Ok(());
+        };
+        if replication_factor == 0 || replication_factor as usize > raft.config().nodes.len() + 1 {
+            return Err(IggyError::InvalidReplicationFactor);
+        }
+        Ok(())
+    }
+
+    /// Applies the committed state entry replicated from the cluster leader to the in-memory system.
+    /// The entry is already stored in the state log, so it's not applied to the state again.
+    /// It's authorized as the user who has performed it on the leader, with the session returned by `resolve_state_entry`.
+    pub async fn apply_state_entry(
+        &mut self,
+        session: &Session,
+        entry: &StateEntry,
+        command: EntryCommand,
+    ) -> Result<(), IggyError> {
+        debug!("Applying replicated state entry: {entry}, command: {command}");
+        match command {
+            EntryCommand::CreateStream(command) => {
+                self.create_stream(session, Some(command.stream_id), &command.command.name)
+                    .await?;
+            }
+            EntryCommand::UpdateStream(command) => {
+                self.update_stream(session, &command.stream_id, &command.name)
+                    .await?;
+            }
+            EntryCommand::DeleteStream(command) => {
+                self.delete_stream(session, &command.stream_id).await?;
+            }
+            EntryCommand::PurgeStream(command) => {
+                self.purge_stream(session, &command.stream_id).await?;
+            }
+            EntryCommand::CreateTopic(command) => {
+                let topic_id = command.topic_id;
+                let command = command.command;
+                self.create_topic(
+                    session,
+                    &command.stream_id,
+                    Some(topic_id),
+                    &command.name,
+                    command.partitions_count,
+                    command.message_expiry,
+                    command.compression_algorithm,
+                    command.max_topic_size,
+                    command.replication_factor,
+                    command.options,
+                )
+                .await?;
+            }
+            EntryCommand::UpdateTopic(command) => {
+                self.update_topic(
+                    session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.name,
+                    command.message_expiry,
+                    command.compression_algorithm,
+                    command.max_topic_size,
+                    command.replication_factor,
+                    command.options,
+                )
+                .await?;
+            }
+            EntryCommand::DeleteTopic(command) => {
+                self.delete_topic(session, &command.stream_id, &command.topic_id, false)
+                    .await?;
+            }
+            EntryCommand::PurgeTopic(command) => {
+                self.purge_topic(session, &command.stream_id, &command.topic_id)
+                    .await?;
+            }
+            EntryCommand::CreatePartitions(command) => {
+                self.create_partitions(
+                    session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partitions_count,
+                    command.key_routing_policy,
+                )
+                .await?;
+            }
+            EntryCommand::DeletePartitions(command) => {
+                self.delete_partitions(
+                    session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partitions_count,
+                )
+                .await?;
+            }
+            EntryCommand::UpdatePartition(command) => {
+                self.update_partition(
+                    session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.partition_id,
+                    command.name.as_deref(),
+                )
+                .await?;
+            }
+            EntryCommand::DeleteSegments(command) => {
+                self.delete_segments(
+                    session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partition_id,
+                    command.segments_count,
+                )
+                .await?;
+            }
+            EntryCommand::CreateConsumerGroup(command) => {
+                let group_id = command.group_id;
+                let command = command.command;
+                self.create_consumer_group(
+                    session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    Some(group_id),
+                    &command.name,
+                    command.assignment_strategy,
+                )
+                .await?;
+            }
+            EntryCommand::DeleteConsumerGroup(command) => {
+                self.delete_consumer_group(
+                    session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.group_id,
+                )
+                .await?;
+            }
+            EntryCommand::CreateUser(command) => {
+                let user_id = command.user_id;
+                let command = command.command;
+                // The password is already hashed by the leader.
+                let user = User::with_password(
+                    user_id,
+                    &command.username,
+                    command.password,
+                    command.status,
+                    command.permissions,
+                );
+                self.add_replicated_user(user);
+            }
+            EntryCommand::UpdateUser(command) => {
+                self.update_user(session, &command.user_id, command.username, command.status)
+                    .await?;
+            }
+            EntryCommand::DeleteUser(command) => {
+                self.delete_user(session, &command.user_id).await?;
+            }
+            EntryCommand::ChangePassword(command) => {
+                let user = self.get_user_mut(&command.user_id).with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to get user with ID: {} to change password",
+                        command.user_id
+                    )
+                })?;
+                // The password is already hashed by the leader.
+                user.password = command.new_password;
+                info!(
+                    "Changed password for replicated user: {} with ID: {}.",
+                    user.username, user.id
+                );
+            }
+            EntryCommand::UpdatePermissions(command) => {
+                self.update_permissions(session, &command.user_id, command.permissions)
+                    .await?;
+            }
+            EntryCommand::CreatePersonalAccessToken(command) => {
+                let user_id = entry.user_id;
+                let expiry_at = PersonalAccessToken::calculate_expiry_at(
+                    entry.timestamp,
+                    command.command.expiry,
+                );
+                let personal_access_token = PersonalAccessToken::raw(
+                    user_id,
+                    &command.command.name,
+                    &command.hash,
+                    expiry_at,
+                    command.command.scope,
+                    command.command.allowed_addresses,
+                );
+                let user = self
+                    .get_user_mut(&Identifier::numeric(user_id)?)
+                    .with_error_context(|error| {
+                        format!(
+                            "{COMPONENT} (error: {error}) - failed to get user with ID: {user_id} to create personal access token"
+                        )
+                    })?;
+                user.personal_access_tokens
+                    .insert(command.hash, personal_access_token);
+            }
+            EntryCommand::DeletePersonalAccessToken(command) => {
+                self.delete_personal_access_token(session, &command.name)
+                    .await?;
+            }
+            EntryCommand::RegisterSchema(command) => {
+                self.register_schema(session, &command.command.subject, command.command.schema)?;
+            }
+            EntryCommand::UpdateSchemaCompatibility(command) => {
+                self.update_schema_compatibility(session, &command.subject, command.compatibility)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl System {
+    /// Reloads the users, streams and schemas from the committed state entries, discarding the changes applied
+    /// by the node which haven't been committed, e.g. by the leader which couldn't replicate them to the quorum.
+    /// The streams created by such changes are removed from disk, while the deleted ones are recreated empty
+    /// (if `recovery.recreate_missing_state` is enabled), as their data is already gone.
+    pub async fn reload_state(&mut self, entries: Vec<StateEntry>) -> Result<(), IggyError> {
+        info!(
+            "Reloading system state from: {} committed state entries...",
+            entries.len()
+        );
+        for stream in std::mem::take(&mut self.streams).into_values() {
+            self.metrics.decrement_streams(1);
+            self.metrics.decrement_topics(stream.get_topics_count());
+            self.metrics
+                .decrement_partitions(stream.get_partitions_count());
+            self.metrics.decrement_segments(stream.get_segments_count());
+            self.metrics.decrement_messages(stream.get_messages_count());
+            for topic in stream.get_topics() {
+                self.permissioner
+                    .unregister_topic(stream.stream_id, topic.topic_id);
+            }
+            self.permissioner.unregister_stream(stream.stream_id);
+        }
+        self.streams_ids.clear();
+        self.metrics.decrement_users(self.users.len() as u32);
+        self.users.clear();
+        self.schema_registry = SchemaRegistry::default();
+
+        let system_state = SystemState::init(entries)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize system state")
+            })?;
+        self.load_users(system_state.users.into_values().collect())
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to reload users")
+            })?;
+        self.load_streams(system_state.streams.into_values().collect())
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to reload streams")
+            })?;
+        self.load_schemas(
+            system_state.schemas.into_values().collect(),
+            system_state.subjects_compatibility,
+        );
+        info!("Reloaded system state.");
+        Ok(())
+    }
+}
+
+impl SharedSystem {
+    /// Reads the command of the replicated state entry and resolves the decisions of the external authorizer (if any)
+    /// for the user who has performed it, without holding the system lock, returning the session to apply the entry with.
+    pub async fn resolve_state_entry(
+        &self,
+        entry: &StateEntry,
+    ) -> Result<(Session, EntryCommand), IggyError> {
+        let command = entry.command().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to read replicated state entry command: {entry}")
+        })?;
+        let session = Session::stateless(
+            entry.user_id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+        );
+        let (name, stream_id, topic_id) = command.authorization();
+        self.resolve_authorization(&session, name, stream_id, topic_id)
+            .await;
+        Ok((session, command))
+    }
+}
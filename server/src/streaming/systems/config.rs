@@ -0,0 +1,171 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::config_provider::ConfigProviderKind;
+use crate::configs::runtime::{RuntimeConfigChanges, CACHE_SIZE_KEY, LOG_LEVEL_KEY};
+use crate::configs::server::ServerConfig;
+use crate::configs::system::SystemConfig;
+use crate::log::LogLevelHandle;
+use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::config_value::{ConfigSource, ConfigValue};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::{error, info};
+
+impl System {
+    /// Keeps the effective configuration, which already includes the values changed once the server has started.
+    pub fn set_effective_config(&mut self, config: &ServerConfig) {
+        self.effective_config = config.get_effective_values();
+    }
+
+    /// Keeps the config provider, to which the configuration values changed at runtime are persisted.
+    pub fn set_config_provider(&mut self, config_provider: ConfigProviderKind) {
+        self.config_provider = Some(config_provider);
+    }
+
+    pub fn set_log_level_handle(&mut self, log_level_handle: Option<LogLevelHandle>) {
+        self.log_level_handle = log_level_handle;
+    }
+
+    pub fn get_effective_config(&self, session: &Session) -> Result<Vec<ConfigValue>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_config(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get effective config for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        Ok(self.effective_config.clone())
+    }
+
+    /// Changes the values of the given configuration section, e.g. `system.cache`, without restarting the server.
+    /// Only the values listed in `RUNTIME_CONFIG_KEYS` can be changed, and they are persisted to the config provider.
+    pub async fn update_config(
+        &mut self,
+        session: &Session,
+        section: &str,
+        values: BTreeMap<String, Value>,
+    ) -> Result<Vec<ConfigValue>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_config(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update config for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let changes = RuntimeConfigChanges::parse(section, values).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - invalid config values in section: {section}")
+        })?;
+        let mut config = (*self.config).clone();
+        changes.apply(&mut config).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to apply config values in section: {section}")
+        })?;
+
+        if let Some(config_provider) = &self.config_provider {
+            if let Err(error) = config_provider.save_runtime_values(changes.values()).await {
+                error!("{COMPONENT} - failed to save config values in section: {section}, error: {error}");
+                return Err(IggyError::CannotWriteToFile);
+            }
+        }
+
+        if changes.contains(LOG_LEVEL_KEY) {
+            if let Some(log_level_handle) = &self.log_level_handle {
+                if let Err(error) = log_level_handle.set_level(&config.logging.level) {
+                    error!("{COMPONENT} - failed to change log level, error: {error}");
+                }
+            }
+        }
+
+        let config = Arc::new(config);
+        self.replace_config(config.clone()).await;
+        if changes.contains(CACHE_SIZE_KEY) {
+            if let Some(memory_tracker) = CacheMemoryTracker::get_instance() {
+                memory_tracker.set_limit_bytes(config.cache.size.clone().into());
+            }
+            self.evict_least_recently_read_caches().await;
+        }
+
+        let updated_values = self.update_effective_config(&config, &changes);
+        info!(
+            "Changed config values in section: {section} by user with ID: {}",
+            session.get_user_id()
+        );
+        Ok(updated_values)
+    }
+
+    /// Shares the changed configuration with all the streams, topics, partitions and segments,
+    /// so that the values which are resolved on demand, e.g. the defaults of the new topics, are up to date.
+    async fn replace_config(&mut self, config: Arc<SystemConfig>) {
+        for stream in self.streams.values_mut() {
+            stream.config = config.clone();
+            for topic in stream.topics.values_mut() {
+                topic.config = config.clone();
+                for partition in topic.partitions.values() {
+                    let mut partition = partition.write().await;
+                    partition.config = config.clone();
+                    for segment in partition.segments.iter_mut() {
+                        segment.config = config.clone();
+                    }
+                }
+            }
+        }
+        self.config = config;
+    }
+
+    fn update_effective_config(
+        &mut self,
+        config: &SystemConfig,
+        changes: &RuntimeConfigChanges,
+    ) -> Vec<ConfigValue> {
+        let Ok(config) = serde_json::to_value(config) else {
+            return Vec::new();
+        };
+
+        let mut updated_values = Vec::new();
+        for key in changes.values().keys() {
+            let pointer = format!("/{}", key.trim_start_matches("system.").replace('.', "/"));
+            let Some(value) = config.pointer(&pointer) else {
+                continue;
+            };
+
+            let Some(effective_value) = self
+                .effective_config
+                .iter_mut()
+                .find(|effective_value| &effective_value.key == key)
+            else {
+                continue;
+            };
+            effective_value.value = value.clone();
+            effective_value.source = ConfigSource::Runtime;
+            updated_values.push(effective_value.clone());
+        }
+        updated_values
+    }
+}
@@ -21,10 +21,12 @@ use crate::streaming::systems::system::System;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use error_set::ErrContext;
+use iggy::consumer_groups::assignment_strategy::AssignmentStrategy;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMutFn;
-use tokio::sync::RwLock;
+use iggy::models::consumer_group::ConsumerGroupRebalance;
+use tokio::sync::{watch, RwLock};
 
 impl System {
     pub fn get_consumer_group(
@@ -40,7 +42,7 @@ impl System {
         };
 
         self.permissioner
-            .get_consumer_group(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .get_consumer_group(session, topic.stream_id, topic.topic_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get consumer group with ID: {group_id} for user with ID: {} in topic with ID: {topic_id} and stream with ID: {stream_id}",
@@ -62,7 +64,7 @@ impl System {
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
 
         self.permissioner
-            .get_consumer_groups(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .get_consumer_groups(session, topic.stream_id, topic.topic_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get consumer groups in topic with ID: {topic_id} and stream with ID: {stream_id} for user with ID: {}",
@@ -73,6 +75,62 @@ impl System {
         Ok(topic.get_consumer_groups())
     }
 
+    /// Returns the current assignments of the consumer groups the client is a member of,
+    /// along with the receivers notified once any of these groups has been rebalanced.
+    pub async fn get_consumer_group_rebalances(
+        &self,
+        client_id: u32,
+    ) -> Vec<(ConsumerGroupRebalance, watch::Receiver<u32>)> {
+        let Some(client) = self.client_manager.read().await.try_get_client(client_id) else {
+            return Vec::new();
+        };
+
+        let memberships = client
+            .read()
+            .await
+            .consumer_groups
+            .iter()
+            .map(|group| (group.stream_id, group.topic_id, group.group_id))
+            .collect::<Vec<_>>();
+        let mut rebalances = Vec::with_capacity(memberships.len());
+        for (stream_id, topic_id, group_id) in memberships {
+            let Ok(stream) = Identifier::numeric(stream_id).and_then(|id| self.get_stream(&id))
+            else {
+                continue;
+            };
+            let Ok(topic) = Identifier::numeric(topic_id).and_then(|id| stream.get_topic(&id))
+            else {
+                continue;
+            };
+            let Ok(consumer_group) = topic.get_consumer_group_by_id(group_id) else {
+                continue;
+            };
+
+            let consumer_group = consumer_group.read().await;
+            // Subscribed before reading the assignment, so that no rebalance can be missed.
+            let receiver = consumer_group.subscribe_rebalances();
+            let generation = consumer_group.generation();
+            let Some(partitions) = consumer_group.get_member_partitions(client_id).await else {
+                continue;
+            };
+
+            rebalances.push((
+                ConsumerGroupRebalance {
+                    stream_id,
+                    stream_name: stream.name.clone(),
+                    topic_id,
+                    topic_name: topic.name.clone(),
+                    group_id,
+                    group_name: consumer_group.name.clone(),
+                    generation,
+                    partitions,
+                },
+                receiver,
+            ));
+        }
+        rebalances
+    }
+
     pub async fn create_consumer_group(
         &mut self,
         session: &Session,
@@ -80,6 +138,7 @@ impl System {
         topic_id: &Identifier,
         group_id: Option<u32>,
         name: &str,
+        assignment_strategy: AssignmentStrategy,
     ) -> Result<&RwLock<ConsumerGroup>, IggyError> {
         self.ensure_authenticated(session)?;
         {
@@ -87,7 +146,7 @@ impl System {
                 .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
 
             self.permissioner.create_consumer_group(
-                session.get_user_id(),
+                session,
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to create consumer group for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
@@ -98,7 +157,7 @@ impl System {
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
 
         topic
-            .create_consumer_group(group_id, name)
+            .create_consumer_group(group_id, name, assignment_strategy)
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to create consumer group with name: {name}")
@@ -120,7 +179,7 @@ impl System {
                 .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
 
             self.permissioner.delete_consumer_group(
-                session.get_user_id(),
+                session,
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to delete consumer group for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
@@ -167,6 +226,7 @@ impl System {
         stream_id: &Identifier,
         topic_id: &Identifier,
         consumer_group_id: &Identifier,
+        assignment_strategy: Option<AssignmentStrategy>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let stream_id_value;
@@ -181,7 +241,7 @@ impl System {
                 })?;
 
             self.permissioner.join_consumer_group(
-                session.get_user_id(),
+                session,
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to join consumer group for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
@@ -209,7 +269,12 @@ impl System {
             }
 
             topic
-                .join_consumer_group(consumer_group_id, session.client_id)
+                .join_consumer_group(
+                    consumer_group_id,
+                    session.client_id,
+                    assignment_strategy,
+                    self.clock.now(),
+                )
                 .await
                 .with_error_context(|error| {
                     format!(
@@ -252,7 +317,7 @@ impl System {
                 })?;
 
             self.permissioner.leave_consumer_group(
-                session.get_user_id(),
+                session,
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to leave consumer group for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
@@ -273,6 +338,39 @@ impl System {
         })
     }
 
+    pub async fn heartbeat_consumer_group(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self
+            .find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}",
+                )
+            })?;
+
+        self.permissioner.heartbeat_consumer_group(
+            session,
+            topic.stream_id,
+            topic.topic_id,
+        ).with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to send heartbeat to consumer group for user {} on stream ID: {}, topic ID: {}", session.get_user_id(), topic.stream_id, topic.topic_id))?;
+
+        topic
+            .heartbeat_consumer_group(consumer_group_id, session.client_id, self.clock.now())
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to send heartbeat to consumer group: {consumer_group_id} for client ID: {}",
+                    session.client_id
+                )
+            })
+    }
+
     pub async fn leave_consumer_group_by_client(
         &self,
         stream_id: &Identifier,
@@ -309,7 +407,7 @@ impl System {
             stream_id_value = stream.stream_id;
             topic_id_value = topic.topic_id;
             topic
-                .leave_consumer_group(consumer_group_id, client_id)
+                .leave_consumer_group(consumer_group_id, client_id, self.clock.now())
                 .await
                 .with_error_context(|error| {
                     format!("{COMPONENT} (error: {error}) - failed leave consumer group, client ID {client_id}",)
@@ -36,13 +36,11 @@ impl System {
         offset: u64,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        self.ensure_promoted()?;
         let topic = self.find_topic(session, stream_id, topic_id)
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
-        self.permissioner.store_consumer_offset(
-            session.get_user_id(),
-            topic.stream_id,
-            topic.topic_id,
-        )?;
+        self.permissioner
+            .store_consumer_offset(session, topic.stream_id, topic.topic_id)?;
 
         topic
             .store_consumer_offset(consumer, offset, partition_id, session.client_id)
@@ -63,7 +61,7 @@ impl System {
         };
 
         self.permissioner.get_consumer_offset(
-            session.get_user_id(),
+            session,
             topic.stream_id,
             topic.topic_id,
         ).with_error_context(|error| {
@@ -87,10 +85,11 @@ impl System {
         partition_id: Option<u32>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        self.ensure_promoted()?;
         let topic = self.find_topic(session, stream_id, topic_id)
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
         self.permissioner.delete_consumer_offset(
-            session.get_user_id(),
+            session,
             topic.stream_id,
             topic.topic_id,
         ).with_error_context(|error| {
@@ -0,0 +1,700 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Integrity check of the data directory, run with the `--fsck` server argument instead of starting the server.
+//!
+//! The streams, topics and partitions found on disk are compared with the ones recorded in the state,
+//! and the files of every partition are checked for the orphaned, leftover, truncated and corrupted
+//! segments, as well as the indexes not matching their log files. With `--repair`, the issues which
+//! can be fixed without losing the valid data are repaired in place.
+
+use crate::compat::index_rebuilding::index_rebuilder::IndexRebuilder;
+use crate::state::system::SystemState;
+use crate::streaming::batching::message_batch::{
+    calculate_batch_checksum, RETAINED_BATCH_CHECKSUM_POSITION, RETAINED_BATCH_HEADER_LEN,
+};
+use crate::streaming::segments::encryption::{
+    recover_interrupted_encryption, ENCRYPTING_FILE_EXTENSION,
+};
+use crate::streaming::segments::{
+    CorruptedBatch, Quarantine, INDEX_EXTENSION, KEY_EXTENSION, LOG_EXTENSION,
+    QUARANTINE_EXTENSION, TIERED_EXTENSION,
+};
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use ahash::{AHashMap, AHashSet};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use std::fmt::{Display, Formatter};
+use std::io::SeekFrom;
+use std::path::Path;
+use tokio::fs;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, BufReader};
+use tracing::{error, info};
+
+/// The files left behind by the compaction or encryption interrupted before the segment files were replaced.
+pub(crate) const LEFTOVER_EXTENSIONS: [&str; 2] = ["compacted", "encrypting"];
+
+/// The issue found in the data directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FsckIssue {
+    /// The stream, topic or partition directory which doesn't exist in the state, it's removed when the server starts.
+    OrphanedDirectory(String),
+    /// The stream, topic or partition recorded in the state, whose directory is missing.
+    MissingDirectory(String),
+    /// The directory whose name isn't a valid ID, which fails loading the streams, topics or partitions.
+    InvalidDirectory(String),
+    /// The index, key or quarantine file of the segment whose log file doesn't exist.
+    OrphanedFile(String),
+    /// The temporary file of the interrupted compaction.
+    LeftoverFile(String),
+    /// The log file of the segment whose encryption was interrupted, it's completed or rolled back when the segment is loaded.
+    InterruptedEncryption(String),
+    /// The index which is missing or doesn't cover all the batches of its log file.
+    InconsistentIndex(String),
+    /// The log file ending with the incomplete batch, e.g. after the crash in the middle of the write.
+    TruncatedLog {
+        path: String,
+        valid_size: u64,
+        size: u64,
+    },
+    /// The batch whose checksum doesn't match its content, it's quarantined and skipped when read.
+    CorruptedBatch { path: String, position: u64 },
+}
+
+impl FsckIssue {
+    /// Returns `true` if the issue can be repaired without losing the valid data.
+    pub fn is_repairable(&self) -> bool {
+        matches!(
+            self,
+            FsckIssue::OrphanedDirectory(_)
+                | FsckIssue::OrphanedFile(_)
+                | FsckIssue::LeftoverFile(_)
+                | FsckIssue::InterruptedEncryption(_)
+                | FsckIssue::InconsistentIndex(_)
+                | FsckIssue::TruncatedLog { .. }
+        )
+    }
+}
+
+impl Display for FsckIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FsckIssue::OrphanedDirectory(path) => {
+                write!(f, "Directory: {path} doesn't exist in the state")
+            }
+            FsckIssue::MissingDirectory(path) => write!(
+                f,
+                "Directory: {path} is missing, it can be recreated by enabling `system.recovery.recreate_missing_state`"
+            ),
+            FsckIssue::InvalidDirectory(path) => {
+                write!(f, "Directory: {path} has an invalid name")
+            }
+            FsckIssue::OrphanedFile(path) => {
+                write!(f, "File: {path} doesn't belong to any segment")
+            }
+            FsckIssue::LeftoverFile(path) => {
+                write!(f, "File: {path} is a leftover of the interrupted operation")
+            }
+            FsckIssue::InterruptedEncryption(path) => {
+                write!(f, "Log file: {path} has the interrupted encryption")
+            }
+            FsckIssue::InconsistentIndex(path) => {
+                write!(f, "Index: {path} doesn't match its log file")
+            }
+            FsckIssue::TruncatedLog {
+                path,
+                valid_size,
+                size,
+            } => write!(
+                f,
+                "Log file: {path} of size {size} ends with the incomplete batch at position {valid_size}"
+            ),
+            FsckIssue::CorruptedBatch { path, position } => write!(
+                f,
+                "Log file: {path} contains the corrupted batch at position {position}"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FsckFinding {
+    pub issue: FsckIssue,
+    pub repaired: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct FsckReport {
+    pub streams_count: u32,
+    pub topics_count: u32,
+    pub partitions_count: u32,
+    pub segments_count: u32,
+    pub findings: Vec<FsckFinding>,
+}
+
+impl FsckReport {
+    /// Returns the number of the issues which remain after the check (and the optional repair).
+    pub fn unresolved_count(&self) -> usize {
+        self.findings
+            .iter()
+            .filter(|finding| !finding.repaired)
+            .count()
+    }
+
+    fn add(&mut self, issue: FsckIssue, repaired: bool) {
+        self.findings.push(FsckFinding { issue, repaired });
+    }
+}
+
+impl System {
+    /// Checks the integrity of the data directory against the state, without loading the streams.
+    /// With `repair` enabled, the repairable issues are fixed in place.
+    pub async fn fsck(&self, repair: bool) -> Result<FsckReport, IggyError> {
+        if !Path::new(&self.config.get_state_log_path()).exists() {
+            return Err(IggyError::StateFileNotFound);
+        }
+
+        let entries = self
+            .state
+            .load_entries()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load state entries")
+            })?;
+        let system_state = SystemState::init(entries)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize system state")
+            })?;
+
+        let mut report = FsckReport::default();
+        let streams_path = self.config.get_streams_path();
+        let stream_ids = system_state.streams.keys().copied().collect();
+        for stream_id in check_directories(&streams_path, &stream_ids, repair, &mut report).await? {
+            report.streams_count += 1;
+            let stream = &system_state.streams[&stream_id];
+            let topics_path = self.config.get_topics_path(stream_id);
+            let topic_ids = stream.topics.keys().copied().collect();
+            for topic_id in check_directories(&topics_path, &topic_ids, repair, &mut report).await?
+            {
+                report.topics_count += 1;
+                let topic = &stream.topics[&topic_id];
+                let partitions_path = self.config.get_partitions_path(stream_id, topic_id);
+                let partition_ids = topic.partitions.keys().copied().collect();
+                for partition_id in
+                    check_directories(&partitions_path, &partition_ids, repair, &mut report).await?
+                {
+                    report.partitions_count += 1;
+                    let partition_path =
+                        self.config
+                            .get_partition_path(stream_id, topic_id, partition_id);
+                    check_partition(&partition_path, repair, &mut report).await?;
+                }
+            }
+        }
+
+        info!(
+            "Checked {} stream(s), {} topic(s), {} partition(s) and {} segment(s), found {} issue(s), {} unresolved.",
+            report.streams_count,
+            report.topics_count,
+            report.partitions_count,
+            report.segments_count,
+            report.findings.len(),
+            report.unresolved_count()
+        );
+        Ok(report)
+    }
+}
+
+/// Compares the directories named by the IDs with the expected ones, returns the IDs of the directories to be checked further.
+async fn check_directories(
+    path: &str,
+    expected_ids: &AHashSet<u32>,
+    repair: bool,
+    report: &mut FsckReport,
+) -> Result<Vec<u32>, IggyError> {
+    let mut found_ids = Vec::new();
+    let mut dir_entries = match fs::read_dir(path).await {
+        Ok(dir_entries) => Some(dir_entries),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => None,
+        Err(error) => {
+            error!("{COMPONENT} (error: {error}) - failed to read directory: {path}");
+            return Err(IggyError::CannotReadFile);
+        }
+    };
+
+    while let Some(dir_entry) = next_dir_entry(dir_entries.as_mut()).await {
+        let entry_path = dir_entry.path().to_string_lossy().to_string();
+        if !fs::metadata(&entry_path)
+            .await
+            .is_ok_and(|metadata| metadata.is_dir())
+        {
+            continue;
+        }
+
+        let Ok(id) = dir_entry.file_name().to_string_lossy().parse::<u32>() else {
+            report.add(FsckIssue::InvalidDirectory(entry_path), false);
+            continue;
+        };
+        if expected_ids.contains(&id) {
+            found_ids.push(id);
+            continue;
+        }
+
+        let repaired = repair && fs::remove_dir_all(&entry_path).await.is_ok();
+        report.add(FsckIssue::OrphanedDirectory(entry_path), repaired);
+    }
+
+    let found = found_ids.iter().copied().collect::<AHashSet<_>>();
+    for id in expected_ids.difference(&found) {
+        report.add(FsckIssue::MissingDirectory(format!("{path}/{id}")), false);
+    }
+
+    found_ids.sort();
+    Ok(found_ids)
+}
+
+async fn next_dir_entry(dir_entries: Option<&mut fs::ReadDir>) -> Option<fs::DirEntry> {
+    dir_entries?.next_entry().await.unwrap_or(None)
+}
+
+/// Checks the segment files of the partition, grouped by their start offsets.
+async fn check_partition(
+    path: &str,
+    repair: bool,
+    report: &mut FsckReport,
+) -> Result<(), IggyError> {
+    let mut segments: AHashMap<u64, AHashSet<String>> = AHashMap::new();
+    let mut interrupted_encryptions = AHashSet::new();
+    let mut dir_entries = fs::read_dir(path)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to read partition directory: {path}")
+        })
+        .map_err(|_| IggyError::CannotReadPartitions)?;
+    while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+        if dir_entry
+            .file_type()
+            .await
+            .is_ok_and(|file_type| file_type.is_dir())
+        {
+            continue;
+        }
+
+        let file_name = dir_entry.file_name().to_string_lossy().to_string();
+        let file_path = format!("{path}/{file_name}");
+        if file_name.ends_with(&format!(".{ENCRYPTING_FILE_EXTENSION}")) {
+            if let Some(start_offset) = file_name
+                .split_once('.')
+                .and_then(|(start_offset, _)| start_offset.parse::<u64>().ok())
+            {
+                interrupted_encryptions.insert(start_offset);
+            }
+            continue;
+        }
+
+        if file_name
+            .split('.')
+            .skip(1)
+            .any(|extension| LEFTOVER_EXTENSIONS.contains(&extension))
+        {
+            let repaired = repair && fs::remove_file(&file_path).await.is_ok();
+            report.add(FsckIssue::LeftoverFile(file_path), repaired);
+            continue;
+        }
+
+        let Some((start_offset, suffix)) = file_name.split_once('.') else {
+            continue;
+        };
+        let Ok(start_offset) = start_offset.parse::<u64>() else {
+            continue;
+        };
+        segments
+            .entry(start_offset)
+            .or_default()
+            .insert(suffix.to_string());
+    }
+
+    let mut start_offsets = segments.keys().copied().collect::<Vec<_>>();
+    start_offsets.sort();
+    for start_offset in start_offsets {
+        let suffixes = &segments[&start_offset];
+        let segment_path = format!("{path}/{start_offset:0>20}");
+        let log_path = format!("{segment_path}.{LOG_EXTENSION}");
+        if interrupted_encryptions.contains(&start_offset) {
+            let index_path = format!("{segment_path}.{INDEX_EXTENSION}");
+            let repaired = repair
+                && recover_interrupted_encryption(&log_path, &index_path)
+                    .await
+                    .is_ok();
+            report.add(FsckIssue::InterruptedEncryption(log_path.clone()), repaired);
+        }
+
+        if suffixes.contains(LOG_EXTENSION) {
+            report.segments_count += 1;
+            check_segment(&log_path, &segment_path, start_offset, repair, report).await?;
+            continue;
+        }
+
+        // The segment offloaded to the tiered storage has only the marker in place of its log file.
+        if suffixes.contains(TIERED_EXTENSION) {
+            report.segments_count += 1;
+            continue;
+        }
+
+        for suffix in [
+            INDEX_EXTENSION.to_string(),
+            format!("{LOG_EXTENSION}.{KEY_EXTENSION}"),
+            format!("{LOG_EXTENSION}.{QUARANTINE_EXTENSION}"),
+        ] {
+            if suffixes.contains(&suffix) {
+                let file_path = format!("{segment_path}.{suffix}");
+                let repaired = repair && fs::remove_file(&file_path).await.is_ok();
+                report.add(FsckIssue::OrphanedFile(file_path), repaired);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the batches of the log file and its index, the incomplete batch at the end of the log file is quarantined
+/// and truncated on repair, then the index is rebuilt if it doesn't match the log file.
+async fn check_segment(
+    log_path: &str,
+    segment_path: &str,
+    start_offset: u64,
+    repair: bool,
+    report: &mut FsckReport,
+) -> Result<(), IggyError> {
+    let scan = scan_log(log_path)
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to scan log file: {log_path}")
+        })
+        .map_err(|_| IggyError::CannotReadFile)?;
+    for position in scan.corrupted_positions {
+        report.add(
+            FsckIssue::CorruptedBatch {
+                path: log_path.to_string(),
+                position,
+            },
+            false,
+        );
+    }
+
+    if scan.valid_size < scan.size {
+        let repaired = repair && truncate_log(log_path, scan.valid_size).await.is_ok();
+        report.add(
+            FsckIssue::TruncatedLog {
+                path: log_path.to_string(),
+                valid_size: scan.valid_size,
+                size: scan.size,
+            },
+            repaired,
+        );
+    }
+
+    let index_path = format!("{segment_path}.{INDEX_EXTENSION}");
+    let index_rebuilder =
+        IndexRebuilder::new(log_path.to_string(), index_path.clone(), start_offset);
+    if index_rebuilder.needs_rebuild().await.unwrap_or(true) {
+        let repaired = repair && index_rebuilder.rebuild().await.is_ok();
+        report.add(FsckIssue::InconsistentIndex(index_path), repaired);
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+struct LogScan {
+    size: u64,
+    valid_size: u64,
+    corrupted_positions: Vec<u64>,
+}
+
+/// Reads all the complete batches of the log file, verifying their checksums.
+async fn scan_log(log_path: &str) -> Result<LogScan, std::io::Error> {
+    let size = fs::metadata(log_path).await?.len();
+    let mut reader = BufReader::new(fs::File::open(log_path).await?);
+    let mut header = [0u8; RETAINED_BATCH_HEADER_LEN as usize];
+    let mut position = 0;
+    let mut corrupted_positions = Vec::new();
+    while position + RETAINED_BATCH_HEADER_LEN <= size {
+        reader.read_exact(&mut header).await?;
+        let length = u32::from_le_bytes(header[8..12].try_into().unwrap()) as u64;
+        if position + RETAINED_BATCH_HEADER_LEN + length > size {
+            break;
+        }
+
+        let mut payload = vec![0u8; length as usize];
+        reader.read_exact(&mut payload).await?;
+        let checksum = u32::from_le_bytes(
+            header[RETAINED_BATCH_CHECKSUM_POSITION..]
+                .try_into()
+                .unwrap(),
+        );
+        if calculate_batch_checksum(&header, &payload) != checksum {
+            corrupted_positions.push(position);
+        }
+        position += RETAINED_BATCH_HEADER_LEN + length;
+    }
+
+    Ok(LogScan {
+        size,
+        valid_size: position,
+        corrupted_positions,
+    })
+}
+
+/// Moves the incomplete batch at the end of the log file to the quarantine, so it can be still inspected, and truncates the log file.
+async fn truncate_log(log_path: &str, valid_size: u64) -> Result<(), std::io::Error> {
+    let mut file = fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(log_path)
+        .await?;
+    file.seek(SeekFrom::Start(valid_size)).await?;
+    let mut tail = Vec::new();
+    file.read_to_end(&mut tail).await?;
+    let base_offset = tail
+        .get(0..8)
+        .map_or(0, |bytes| u64::from_le_bytes(bytes.try_into().unwrap()));
+    let batch = CorruptedBatch {
+        base_offset,
+        position: valid_size,
+        length: tail.len() as u64,
+    };
+    Quarantine::load(log_path).add(&batch, &tail);
+    file.set_len(valid_size).await?;
+    file.sync_all().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::batching::message_batch::RetainedMessageBatch;
+    use bytes::Bytes;
+    use iggy::utils::byte_size::IggyByteSize;
+
+    fn batch_bytes(base_offset: u64, payload: &'static [u8]) -> Vec<u8> {
+        let batch = RetainedMessageBatch::new(
+            base_offset,
+            0,
+            1000,
+            IggyByteSize::from(payload.len() as u64),
+            Bytes::from_static(payload),
+        );
+        let mut bytes = batch.header_as_bytes().to_vec();
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    async fn write_segment(path: &str, bytes: &[u8]) {
+        let log_path = format!("{path}/{:0>20}.{LOG_EXTENSION}", 0);
+        let index_path = format!("{path}/{:0>20}.{INDEX_EXTENSION}", 0);
+        fs::write(&log_path, bytes).await.unwrap();
+        IndexRebuilder::new(log_path, index_path, 0)
+            .rebuild()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_not_report_issues_for_consistent_partition() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().to_str().unwrap();
+        let mut bytes = batch_bytes(0, b"first");
+        bytes.extend(batch_bytes(1, b"second"));
+        write_segment(path, &bytes).await;
+
+        let mut report = FsckReport::default();
+        check_partition(path, false, &mut report).await.unwrap();
+
+        assert_eq!(report.segments_count, 1);
+        assert!(report.findings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_report_and_repair_truncated_log_and_its_index() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().to_str().unwrap();
+        let valid_bytes = batch_bytes(0, b"first");
+        write_segment(path, &valid_bytes).await;
+        let mut bytes = valid_bytes.clone();
+        bytes.extend(&batch_bytes(1, b"second")[..10]);
+        let log_path = format!("{path}/{:0>20}.{LOG_EXTENSION}", 0);
+        fs::write(&log_path, &bytes).await.unwrap();
+
+        let truncated_log = FsckIssue::TruncatedLog {
+            path: log_path.clone(),
+            valid_size: valid_bytes.len() as u64,
+            size: bytes.len() as u64,
+        };
+
+        let mut report = FsckReport::default();
+        check_partition(path, false, &mut report).await.unwrap();
+        assert_eq!(report.unresolved_count(), 2);
+        assert_eq!(report.findings[0].issue, truncated_log);
+        assert_eq!(
+            report.findings[1].issue,
+            FsckIssue::InconsistentIndex(format!("{path}/{:0>20}.{INDEX_EXTENSION}", 0))
+        );
+
+        // Once the incomplete batch is truncated, the index matches the log file again.
+        let mut report = FsckReport::default();
+        check_partition(path, true, &mut report).await.unwrap();
+        assert_eq!(
+            report.findings,
+            vec![FsckFinding {
+                issue: truncated_log,
+                repaired: true,
+            }]
+        );
+        assert_eq!(
+            fs::metadata(&log_path).await.unwrap().len(),
+            valid_bytes.len() as u64
+        );
+        assert!(Path::new(&format!("{log_path}.{QUARANTINE_EXTENSION}")).exists());
+
+        let mut report = FsckReport::default();
+        check_partition(path, false, &mut report).await.unwrap();
+        assert!(report.findings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_report_corrupted_batch_and_missing_index() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().to_str().unwrap();
+        let mut bytes = batch_bytes(0, b"first");
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        let log_path = format!("{path}/{:0>20}.{LOG_EXTENSION}", 0);
+        fs::write(&log_path, &bytes).await.unwrap();
+
+        let mut report = FsckReport::default();
+        check_partition(path, false, &mut report).await.unwrap();
+
+        let issues = report
+            .findings
+            .into_iter()
+            .map(|finding| finding.issue)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            issues,
+            vec![
+                FsckIssue::CorruptedBatch {
+                    path: log_path,
+                    position: 0
+                },
+                FsckIssue::InconsistentIndex(format!("{path}/{:0>20}.{INDEX_EXTENSION}", 0)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_remove_orphaned_and_leftover_files_on_repair() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().to_str().unwrap();
+        let orphaned_index_path = format!("{path}/{:0>20}.{INDEX_EXTENSION}", 5);
+        let leftover_path = format!("{path}/{:0>20}.{LOG_EXTENSION}.compacted", 5);
+        fs::write(&orphaned_index_path, [0u8; 16]).await.unwrap();
+        fs::write(&leftover_path, [0u8; 8]).await.unwrap();
+
+        let mut report = FsckReport::default();
+        check_partition(path, true, &mut report).await.unwrap();
+
+        assert_eq!(report.findings.len(), 2);
+        assert!(report.findings.iter().all(|finding| finding.repaired));
+        assert!(!Path::new(&orphaned_index_path).exists());
+        assert!(!Path::new(&leftover_path).exists());
+    }
+
+    #[tokio::test]
+    async fn should_complete_committed_and_roll_back_uncommitted_encryption_on_repair() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().to_str().unwrap();
+        let plaintext_bytes = batch_bytes(0, b"first");
+        write_segment(path, &plaintext_bytes).await;
+        let log_path = format!("{path}/{:0>20}.{LOG_EXTENSION}", 0);
+        let encrypting_log_path = format!("{log_path}.{ENCRYPTING_FILE_EXTENSION}");
+        let encrypted_bytes = batch_bytes(0, b"encrypted");
+        fs::write(&encrypting_log_path, &encrypted_bytes)
+            .await
+            .unwrap();
+
+        // Without the key file the encryption hasn't been committed, so the plaintext segment is kept.
+        let mut report = FsckReport::default();
+        check_partition(path, true, &mut report).await.unwrap();
+        assert_eq!(
+            report.findings,
+            vec![FsckFinding {
+                issue: FsckIssue::InterruptedEncryption(log_path.clone()),
+                repaired: true,
+            }]
+        );
+        assert!(!Path::new(&encrypting_log_path).exists());
+        assert_eq!(fs::read(&log_path).await.unwrap(), plaintext_bytes);
+
+        fs::write(&encrypting_log_path, &encrypted_bytes)
+            .await
+            .unwrap();
+        fs::write(format!("{log_path}.{KEY_EXTENSION}"), [1u8; 8])
+            .await
+            .unwrap();
+        let mut report = FsckReport::default();
+        check_partition(path, true, &mut report).await.unwrap();
+        assert_eq!(
+            report.findings[0].issue,
+            FsckIssue::InterruptedEncryption(log_path.clone())
+        );
+        assert!(report.findings.iter().all(|finding| finding.repaired));
+        assert!(!Path::new(&encrypting_log_path).exists());
+        assert_eq!(fs::read(&log_path).await.unwrap(), encrypted_bytes);
+    }
+
+    #[tokio::test]
+    async fn should_report_orphaned_and_missing_directories() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().to_str().unwrap();
+        fs::create_dir(format!("{path}/1")).await.unwrap();
+        fs::create_dir(format!("{path}/3")).await.unwrap();
+        fs::create_dir(format!("{path}/invalid")).await.unwrap();
+        let expected_ids = AHashSet::from_iter([1, 2]);
+
+        let mut report = FsckReport::default();
+        let found_ids = check_directories(path, &expected_ids, false, &mut report)
+            .await
+            .unwrap();
+
+        assert_eq!(found_ids, vec![1]);
+        assert_eq!(report.findings.len(), 3);
+        assert!(report.findings.contains(&FsckFinding {
+            issue: FsckIssue::OrphanedDirectory(format!("{path}/3")),
+            repaired: false,
+        }));
+        assert!(report.findings.contains(&FsckFinding {
+            issue: FsckIssue::MissingDirectory(format!("{path}/2")),
+            repaired: false,
+        }));
+        assert!(report.findings.contains(&FsckFinding {
+            issue: FsckIssue::InvalidDirectory(format!("{path}/invalid")),
+            repaired: false,
+        }));
+    }
+}
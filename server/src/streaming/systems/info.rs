@@ -16,9 +16,11 @@
  * under the License.
  */
 
+use crate::compat::migrations::DataMigration;
 use crate::streaming::systems::system::System;
 use crate::versioning::SemanticVersion;
 use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::DefaultHasher;
 use std::fmt::Display;
@@ -47,7 +49,10 @@ pub struct Migration {
 }
 
 impl System {
-    pub(crate) async fn load_version(&mut self) -> Result<(), IggyError> {
+    pub(crate) async fn load_version(
+        &mut self,
+        applied_migrations: &[&DataMigration],
+    ) -> Result<(), IggyError> {
         let current_version = SemanticVersion::current()?;
         let mut system_info;
         let load_system_info = self.storage.info.load().await;
@@ -79,6 +84,20 @@ impl System {
                 .await?;
         }
 
+        if !applied_migrations.is_empty() {
+            let applied_at = IggyTimestamp::now().as_micros();
+            system_info.migrations.extend(
+                applied_migrations
+                    .iter()
+                    .map(|migration| Migration::new(migration.version, migration.name, applied_at)),
+            );
+            self.storage.info.save(&system_info).await?;
+            info!(
+                "Recorded {} applied data directory migrations.",
+                applied_migrations.len()
+            );
+        }
+
         Ok(())
     }
 
@@ -93,6 +112,19 @@ impl System {
     }
 }
 
+impl Migration {
+    pub fn new(id: u32, name: &str, applied_at: u64) -> Self {
+        let mut hasher = DefaultHasher::new();
+        name.hash(&mut hasher);
+        Self {
+            id,
+            name: name.to_string(),
+            hash: hasher.finish().to_string(),
+            applied_at,
+        }
+    }
+}
+
 impl SystemInfo {
     pub fn update_version(&mut self, version: &SemanticVersion) {
         self.version.version = version.to_string();
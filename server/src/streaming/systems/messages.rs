@@ -16,17 +16,31 @@
  * under the License.
  */
 
-use crate::streaming::segments::{IggyBatch, IggyMessages, IggyMessagesMut};
+use crate::log::trace_context;
+use crate::streaming::diagnostics::batch_timings::BatchTimings;
+use crate::streaming::diagnostics::metrics::{QuotaDirection, TopicLabels};
+use crate::streaming::segments::{IggyBatch, IggyMessages, IggyMessagesMut, StoredBatches};
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::dead_letters::DeadLetter;
+use crate::streaming::topics::topic::Topic;
 use bytes::Bytes;
 use error_set::ErrContext;
-use iggy::confirmation::Confirmation;
-use iggy::consumer::Consumer;
+use iggy::confirmation::ConfirmationLevel;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::locking::IggySharedMutFn;
+use iggy::models::dead_letter_policy::{
+    DLQ_CONSUMER_HEADER, DLQ_DELIVERY_ATTEMPTS_HEADER, DLQ_REASON_HEADER, DLQ_REJECTED_AT_HEADER,
+    DLQ_SOURCE_OFFSET_HEADER, DLQ_SOURCE_PARTITION_ID_HEADER, DLQ_SOURCE_STREAM_ID_HEADER,
+    DLQ_SOURCE_TOPIC_ID_HEADER,
+};
 use iggy::prelude::*;
+use iggy::utils::duration::IggyDuration;
 use iggy::{error::IggyError, identifier::Identifier};
-use tracing::{error, trace};
+use std::str::FromStr;
+use std::time::Instant;
+use tracing::{error, trace, warn, Span};
 
 impl System {
     pub async fn poll_messages(
@@ -45,7 +59,7 @@ impl System {
 
         let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
         self.permissioner
-             .poll_messages(session.get_user_id(), topic.stream_id, topic.topic_id)
+             .poll_messages(session, topic.stream_id, topic.topic_id)
              .with_error_context(|error| format!(
                  "{COMPONENT} (error: {error}) - permission denied to poll messages for user {} on stream_id: {}, topic_id: {}",
                  session.get_user_id(),
@@ -57,10 +71,34 @@ impl System {
             return Err(IggyError::NoPartitions(topic.topic_id, topic.stream_id));
         }
 
+        // In the queue mode, the next messages are leased to the polling client regardless of the consumer,
+        // so that all the clients share the work, while the other strategies can be still used to browse the messages.
+        if topic.queue_policy.is_some() && args.strategy.kind == PollingKind::Next {
+            let leased = topic
+                .lease_queue_messages(partition_id, session.client_id, args.count, self.clock.now())
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to lease queue messages, client ID: {}, partition ID: {:?}", session.client_id, partition_id))?;
+            self.metrics.increment_topic_messages_out(
+                &TopicLabels {
+                    stream_id: topic.stream_id,
+                    topic_id: topic.topic_id,
+                },
+                leased.messages.len() as u32,
+            );
+            self.record_quota_usage(
+                session,
+                QuotaDirection::Consume,
+                topic,
+                leased.size() as u64,
+                leased.count() as u64,
+            );
+            return Ok(leased);
+        }
+
         // There might be no partition assigned, if it's the consumer group member without any partitions.
         // TODO: Fix me
         let Some((polling_consumer, partition_id)) = topic
-             .resolve_consumer_with_partition_id(consumer, session.client_id, partition_id, true)
+             .resolve_consumer_with_partition_id(consumer, session.client_id, partition_id, Some(self.clock.now()))
              .await
              .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer with partition id, consumer: {consumer}, client ID: {}, partition ID: {:?}", session.client_id, partition_id))? else {
              // TODO: Fix me
@@ -69,25 +107,78 @@ impl System {
                  messages: vec![],
                  partition_id: 0,
                  current_offset: 0,
+                 next_offset: None,
              })
              */
              todo!()
          };
 
+        // The offset delivered by the previous poll is committed first, so that the consumer continues after it.
+        topic
+            .commit_delivered_consumer_offset(polling_consumer, partition_id, args.auto_commit, self.clock.now())
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to commit delivered offset, consumer: {polling_consumer}, partition ID: {partition_id}"))?;
+
+        // The negatively acknowledged messages which are due for the redelivery take precedence over the next ones.
+        if args.strategy.kind == PollingKind::Next {
+            if let Some(redelivered) = topic
+                .get_redelivered_messages(polling_consumer, partition_id, self.clock.now(), args.count)
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get redelivered messages, consumer: {polling_consumer}, partition ID: {partition_id}"))?
+            {
+                self.metrics.increment_topic_messages_out(
+                    &TopicLabels {
+                        stream_id: topic.stream_id,
+                        topic_id: topic.topic_id,
+                    },
+                    redelivered.messages.len() as u32,
+                );
+                self.record_quota_usage(
+                    session,
+                    QuotaDirection::Consume,
+                    topic,
+                    redelivered.size() as u64,
+                    redelivered.count() as u64,
+                );
+                return Ok(redelivered);
+            }
+        }
+
         let result = topic
-            .get_messages(polling_consumer, partition_id, args.strategy, args.count)
+            .get_messages(
+                polling_consumer,
+                partition_id,
+                args.strategy,
+                args.count,
+                self.clock.now(),
+                &self.transactions,
+            )
             .await?;
 
-        Ok(result)
+        if let Some(last_message) = result.messages.last() {
+            let offset = last_message.offset;
+            trace!("Last offset: {offset} will be automatically committed in mode: {} for {consumer}, stream: {stream_id}, topic: {topic_id}, partition: {partition_id}", args.auto_commit);
+            topic
+                .auto_commit_consumer_offset(polling_consumer, partition_id, args.auto_commit, offset)
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to auto-commit consumer offset, polling consumer: {polling_consumer}, offset: {offset}, partition ID: {partition_id}"))?;
+        }
 
-        // let offset = polled_messages.messages.last().unwrap().offset;
-        // if args.auto_commit {
-        //     trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, stream_id, topic_id, partition_id);
-        //     topic
-        //         .store_consumer_offset_internal(polling_consumer, offset, partition_id)
-        //         .await
-        //         .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset internal, polling consumer: {}, offset: {}, partition ID: {}", polling_consumer, offset, partition_id)) ?;
-        // }
+        self.metrics.increment_topic_messages_out(
+            &TopicLabels {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+            },
+            result.count(),
+        );
+        self.record_quota_usage(
+            session,
+            QuotaDirection::Consume,
+            topic,
+            result.size() as u64,
+            result.count() as u64,
+        );
+        Ok(result)
 
         // if self.encryptor.is_none() {
         //     return Ok(result);
@@ -120,19 +211,109 @@ impl System {
         // Ok(polled_messages)
     }
 
+    /// Polls the whole batches stored in the closed segment, so that they can be sent to the client straight from its log file.
+    /// Returns `None` if the messages have to be polled regularly, e.g. because they're encrypted or the strategy depends on the consumer state.
+    pub async fn poll_stored_batches(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        args: &PollingArgs,
+    ) -> Result<Option<StoredBatches>, IggyError> {
+        self.ensure_authenticated(session)?;
+        if args.count == 0
+            || self.encryptor.is_some()
+            || !matches!(args.strategy.kind, PollingKind::Offset | PollingKind::First)
+            // The partition of the consumer group member might be resolved only once per poll.
+            || (consumer.kind == ConsumerKind::ConsumerGroup && partition_id.is_none())
+        {
+            return Ok(None);
+        }
+
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner
+            .poll_messages(session, topic.stream_id, topic.topic_id)
+            .with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - permission denied to poll messages for user {} on stream_id: {}, topic_id: {}",
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id
+            ))?;
+
+        if !topic.has_partitions() {
+            return Err(IggyError::NoPartitions(topic.topic_id, topic.stream_id));
+        }
+
+        let Some((polling_consumer, partition_id)) = topic
+            .resolve_consumer_with_partition_id(consumer, session.client_id, partition_id, None)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer with partition id, consumer: {consumer}, client ID: {}, partition ID: {:?}", session.client_id, partition_id))? else {
+            return Ok(None);
+        };
+
+        let partition = topic.get_partition(partition_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - partition not found, partition ID: {partition_id}"))?;
+        let partition = partition.read().await;
+        let start_offset = match args.strategy.kind {
+            PollingKind::First => partition
+                .get_segments()
+                .first()
+                .map(|segment| segment.start_offset)
+                .unwrap_or(0),
+            _ => args.strategy.value,
+        };
+        let max_size = topic.config.polling.max_response_size.as_bytes_u64();
+        let Some(stored_batches) = partition
+            .get_stored_batches(start_offset, args.count, max_size)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get stored batches, partition ID: {partition_id}, start offset: {start_offset}"))? else {
+            return Ok(None);
+        };
+        drop(partition);
+
+        topic
+            .commit_delivered_consumer_offset(polling_consumer, partition_id, args.auto_commit, self.clock.now())
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to commit delivered offset, consumer: {polling_consumer}, partition ID: {partition_id}"))?;
+        let offset = stored_batches.range.last_offset;
+        trace!("Last offset: {offset} will be automatically committed in mode: {} for {consumer}, stream: {stream_id}, topic: {topic_id}, partition: {partition_id}", args.auto_commit);
+        topic
+            .auto_commit_consumer_offset(polling_consumer, partition_id, args.auto_commit, offset)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to auto-commit consumer offset, polling consumer: {polling_consumer}, offset: {offset}, partition ID: {partition_id}"))?;
+        self.metrics.increment_topic_messages_out(
+            &TopicLabels {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+            },
+            stored_batches.range.messages_count,
+        );
+        self.record_quota_usage(
+            session,
+            QuotaDirection::Consume,
+            topic,
+            stored_batches.range.length,
+            stored_batches.range.messages_count as u64,
+        );
+        Ok(Some(stored_batches))
+    }
+
     pub async fn append_messages(
         &self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitioning: &Partitioning,
-        messages: IggyMessagesMut,
-        confirmation: Option<Confirmation>,
-    ) -> Result<(), IggyError> {
+        mut messages: IggyMessagesMut,
+        confirmation: ConfirmationLevel,
+    ) -> Result<BatchTimings, IggyError> {
+        let validation_started_at = Instant::now();
         self.ensure_authenticated(session)?;
+        self.ensure_promoted()?;
         let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
         self.permissioner.append_messages(
-             session.get_user_id(),
+             session,
              topic.stream_id,
              topic.topic_id,
          ).with_error_context(|error| format!(
@@ -169,19 +350,93 @@ impl System {
         }
         */
 
-        /*
-        if let Some(memory_tracker) = CacheMemoryTracker::get_instance() {
-            if !memory_tracker.will_fit_into_cache(batch_size_bytes) {
-                self.clean_cache(batch_size_bytes).await;
+        // In the cluster, the messages are accepted by the partition leader only, so the partition is resolved upfront.
+        let replicated_partition_id = match &self.replication {
+            Some(replication) => {
+                let partition_id = topic.get_partition_id(partitioning).await?;
+                replication
+                    .ensure_partition_leader(
+                        topic.stream_id,
+                        topic.topic_id,
+                        partition_id,
+                        topic.replication_factor,
+                    )
+                    .await?;
+                Some(partition_id)
             }
-        }
-        */
+            None => None,
+        };
+        let replicated_partitioning = replicated_partition_id.map(Partitioning::partition_id);
+        let partitioning = replicated_partitioning.as_ref().unwrap_or(partitioning);
+
         topic
-            .append_messages(partitioning, messages, confirmation)
+            .throttle_messages(messages.size() as u64, self.clock.now())
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - throttled appending messages for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        self.ensure_tenant_append_quota(topic.stream_id, messages.size() as u64)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - tenant quota exceeded appending messages for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        self.storage_usage
+            .ensure_can_append(topic.stream_id, topic.topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - storage limit exceeded appending messages for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        let transaction_ids = topic
+            .get_transaction_ids(&mut messages)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - invalid transaction headers for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        for transaction_id in transaction_ids {
+            self.transactions
+                .register_topic(transaction_id, session.client_id, topic.stream_id, topic.topic_id)
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to append messages within transaction: {transaction_id} for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        }
+        // The producers may attach their trace context to the messages, so that their spans are linked to the append.
+        if trace_context::is_enabled() {
+            let traceparents = trace_context::get_message_traceparents(&mut messages);
+            trace_context::link_span(&Span::current(), &traceparents);
+        }
+        let sampled_messages = topic
+            .sample_messages(&mut messages, session, self.clock.now())
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to sample messages for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        let validation_time = validation_started_at.elapsed();
+        let messages_count = messages.count();
+        let messages_size = messages.size() as u64;
+        let mut timings = topic
+            .append_messages(partitioning, messages, confirmation, self.clock.now())
             .await?;
+        if let (Some(replication), Some(partition_id)) =
+            (&self.replication, replicated_partition_id)
+        {
+            let offset = topic
+                .get_partition(partition_id)?
+                .read()
+                .await
+                .current_offset;
+            replication
+                .wait_for_acks(topic.stream_id, topic.topic_id, partition_id, topic.replication_factor, offset)
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - messages were not acknowledged by the replicas of partition: {partition_id}, stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        }
+        timings.validation += validation_time;
+        self.metrics.record_batch_timings(&timings);
+        self.metrics.increment_topic_messages_in(
+            &TopicLabels {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+            },
+            messages_count,
+        );
+        self.record_quota_usage(
+            session,
+            QuotaDirection::Produce,
+            topic,
+            messages_size,
+            messages_count as u64,
+        );
+        trace!("Appended messages to stream_id: {stream_id}, topic_id: {topic_id}, {timings}.");
+        if !sampled_messages.is_empty() {
+            self.append_sampled_messages(topic, sampled_messages).await;
+        }
+        // The partition budgets may exceed the cache size, when there are many partitions with the minimum budget.
+        self.evict_least_recently_read_caches().await;
         //TODO: Fix me
         //self.metrics.increment_messages(messages_count);
-        Ok(())
+        Ok(timings)
     }
 
     pub async fn flush_unsaved_buffer(
@@ -196,7 +451,7 @@ impl System {
         let topic = self.find_topic(session, &stream_id, &topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
         // Reuse those permissions as if you can append messages you can flush them
         self.permissioner.append_messages(
-             session.get_user_id(),
+             session,
              topic.stream_id,
              topic.topic_id,
          ).with_error_context(|error| format!(
@@ -205,20 +460,315 @@ impl System {
              topic.stream_id,
              topic.topic_id
          ))?;
-        topic.flush_unsaved_buffer(partition_id, fsync).await?;
+        topic
+            .flush_unsaved_buffer(partition_id, fsync, self.clock.now())
+            .await?;
+        Ok(())
+    }
+
+    /// Syncs the partitions of the topics with the fsync policy, once it's due, e.g. its interval has elapsed since the last sync.
+    pub async fn fsync_due_partitions(&self) -> Result<u32, IggyError> {
+        let now = self.clock.now();
+        let mut synced_messages_count = 0;
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                synced_messages_count += topic
+                    .fsync_due_partitions(now)
+                    .await
+                    .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to fsync partitions for topic: {topic}"))?;
+            }
+        }
+        Ok(synced_messages_count)
+    }
+
+    /// Writes the buffered messages of all the partitions to disk, once they've lingered for long enough.
+    pub async fn flush_lingering_messages(&self) -> Result<u32, IggyError> {
+        let now = self.clock.now();
+        let mut flushed_messages_count = 0;
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                flushed_messages_count += topic
+                    .flush_lingering_messages(now)
+                    .await
+                    .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to flush lingering messages for topic: {topic}"))?;
+            }
+        }
+        Ok(flushed_messages_count)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn reject_messages(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        reason: &str,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        // Rejecting the messages is a part of consuming them, so the same permissions are required.
+        self.permissioner
+             .poll_messages(session, topic.stream_id, topic.topic_id)
+             .with_error_context(|error| format!(
+                 "{COMPONENT} (error: {error}) - permission denied to reject messages for user {} on stream_id: {}, topic_id: {}",
+                 session.get_user_id(),
+                 topic.stream_id,
+                 topic.topic_id
+             ))?;
+
+        let (polling_consumer, dead_letters) = topic
+            .reject_messages(consumer, partition_id, offsets, session.client_id)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to reject messages for consumer: {consumer}, stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        if dead_letters.is_empty() {
+            return Ok(());
+        }
+
+        let Some(dead_letter_policy) = &topic.dead_letter_policy else {
+            return Ok(());
+        };
+
+        // The messages are moved by the server, so the permission to append to the dead letter topic is not required.
+        let dead_letter_topic = self
+            .get_stream(&dead_letter_policy.stream_id)?
+            .get_topic(&dead_letter_policy.topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - dead letter topic: {dead_letter_policy} not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        let rejected_at = self.clock.now().as_micros();
+        let messages = dead_letters
+            .iter()
+            .map(|dead_letter| {
+                create_dead_letter_message(
+                    dead_letter,
+                    topic.stream_id,
+                    topic.topic_id,
+                    consumer,
+                    reason,
+                    rejected_at,
+                )
+            })
+            .collect::<Result<Vec<_>, IggyError>>()?;
+        dead_letter_topic
+            .append_messages(
+                &Partitioning::balanced(),
+                IggyMessagesMut::from(messages.as_slice()),
+                ConfirmationLevel::default(),
+                self.clock.now(),
+            )
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to move {} message(s) to dead letter topic: {dead_letter_policy}", messages.len()))?;
+        topic
+            .clear_delivery_attempts(polling_consumer, &dead_letters)
+            .await?;
+        trace!(
+            "Moved {} rejected message(s) from stream_id: {stream_id}, topic_id: {topic_id} to dead letter topic: {dead_letter_policy}.",
+            dead_letters.len()
+        );
+        Ok(())
+    }
+
+    /// Appends the sampled messages to the diagnostics topic configured in the sampling policy of the source topic.
+    /// The sampling is a best-effort diagnostics mechanism, so the failure is only logged and doesn't affect the producer.
+    async fn append_sampled_messages(&self, topic: &Topic, sampled_messages: Vec<IggyMessage>) {
+        let Some(sampling_policy) = &topic.sampling_policy else {
+            return;
+        };
+
+        let diagnostics_topic = match self
+            .get_stream(&sampling_policy.stream_id)
+            .and_then(|stream| stream.get_topic(&sampling_policy.topic_id))
+        {
+            Ok(diagnostics_topic) => diagnostics_topic,
+            Err(error) => {
+                warn!(
+                    "Diagnostics topic: {sampling_policy} for stream ID: {}, topic ID: {} was not found, {} sampled message(s) will be dropped. {error}",
+                    topic.stream_id,
+                    topic.topic_id,
+                    sampled_messages.len()
+                );
+                return;
+            }
+        };
+
+        if let Err(error) = diagnostics_topic
+            .append_messages(
+                &Partitioning::balanced(),
+                IggyMessagesMut::from(sampled_messages.as_slice()),
+                ConfirmationLevel::default(),
+                self.clock.now(),
+            )
+            .await
+        {
+            warn!(
+                "Failed to append {} sampled message(s) from stream ID: {}, topic ID: {} to diagnostics topic: {sampling_policy}. {error}",
+                sampled_messages.len(),
+                topic.stream_id,
+                topic.topic_id
+            );
+            return;
+        }
+
+        trace!(
+            "Appended {} sampled message(s) from stream ID: {}, topic ID: {} to diagnostics topic: {sampling_policy}.",
+            sampled_messages.len(),
+            topic.stream_id,
+            topic.topic_id
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn nack_messages(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        redelivery_delay: Option<IggyDuration>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        // Negatively acknowledging the messages is a part of consuming them, so the same permissions are required.
+        self.permissioner
+             .poll_messages(session, topic.stream_id, topic.topic_id)
+             .with_error_context(|error| format!(
+                 "{COMPONENT} (error: {error}) - permission denied to nack messages for user {} on stream_id: {}, topic_id: {}",
+                 session.get_user_id(),
+                 topic.stream_id,
+                 topic.topic_id
+             ))?;
+
+        let now = self.clock.now();
+        let redeliver_at = match redelivery_delay {
+            Some(redelivery_delay) => {
+                IggyTimestamp::from(now.as_micros() + redelivery_delay.as_micros())
+            }
+            None => now,
+        };
+        if topic.queue_policy.is_some() {
+            let partition_id = partition_id.ok_or(IggyError::PartitionNotFound(
+                0,
+                topic.topic_id,
+                topic.stream_id,
+            ))?;
+            topic
+                .release_queue_messages(partition_id, offsets, redeliver_at)
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to release queue messages, stream_id: {stream_id}, topic_id: {topic_id}, partition ID: {partition_id}"))?;
+            trace!(
+                "Released {} queue message(s) visible at: {redeliver_at}, stream_id: {stream_id}, topic_id: {topic_id}, partition ID: {partition_id}.",
+                offsets.len()
+            );
+            return Ok(());
+        }
+
+        let polling_consumer = topic
+            .nack_messages(consumer, partition_id, offsets, session.client_id, redeliver_at)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to nack messages for consumer: {consumer}, stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        trace!(
+            "Scheduled redelivery of {} message(s) at: {redeliver_at} for {polling_consumer}, stream_id: {stream_id}, topic_id: {topic_id}.",
+            offsets.len()
+        );
         Ok(())
     }
+
+    pub async fn ack_messages(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        // Acknowledging the messages is a part of consuming them, so the same permissions are required.
+        self.permissioner
+             .poll_messages(session, topic.stream_id, topic.topic_id)
+             .with_error_context(|error| format!(
+                 "{COMPONENT} (error: {error}) - permission denied to ack messages for user {} on stream_id: {}, topic_id: {}",
+                 session.get_user_id(),
+                 topic.stream_id,
+                 topic.topic_id
+             ))?;
+
+        topic
+            .ack_queue_messages(partition_id, offsets)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to ack messages, stream_id: {stream_id}, topic_id: {topic_id}, partition ID: {partition_id}"))?;
+        trace!(
+            "Acknowledged {} message(s), stream_id: {stream_id}, topic_id: {topic_id}, partition ID: {partition_id}.",
+            offsets.len()
+        );
+        Ok(())
+    }
+}
+
+fn create_dead_letter_message(
+    dead_letter: &DeadLetter,
+    stream_id: u32,
+    topic_id: u32,
+    consumer: &Consumer,
+    reason: &str,
+    rejected_at: u64,
+) -> Result<IggyMessage, IggyError> {
+    let message = &dead_letter.message;
+    let mut headers = message.headers.clone().unwrap_or_default();
+    headers.insert(
+        HeaderKey::new(DLQ_SOURCE_STREAM_ID_HEADER)?,
+        HeaderValue::from_uint32(stream_id)?,
+    );
+    headers.insert(
+        HeaderKey::new(DLQ_SOURCE_TOPIC_ID_HEADER)?,
+        HeaderValue::from_uint32(topic_id)?,
+    );
+    headers.insert(
+        HeaderKey::new(DLQ_SOURCE_PARTITION_ID_HEADER)?,
+        HeaderValue::from_uint32(dead_letter.partition_id)?,
+    );
+    headers.insert(
+        HeaderKey::new(DLQ_SOURCE_OFFSET_HEADER)?,
+        HeaderValue::from_uint64(message.offset)?,
+    );
+    headers.insert(
+        HeaderKey::new(DLQ_DELIVERY_ATTEMPTS_HEADER)?,
+        HeaderValue::from_uint32(dead_letter.delivery_attempts)?,
+    );
+    headers.insert(
+        HeaderKey::new(DLQ_CONSUMER_HEADER)?,
+        HeaderValue::from_str(&consumer.to_string())?,
+    );
+    headers.insert(
+        HeaderKey::new(DLQ_REJECTED_AT_HEADER)?,
+        HeaderValue::from_uint64(rejected_at)?,
+    );
+    if !reason.is_empty() {
+        headers.insert(
+            HeaderKey::new(DLQ_REASON_HEADER)?,
+            HeaderValue::from_str(reason)?,
+        );
+    }
+
+    Ok(IggyMessage::builder()
+        .id(message.id)
+        .payload(message.payload.clone())
+        .headers(headers)
+        .build())
 }
 
 #[derive(Debug)]
 pub struct PollingArgs {
     pub strategy: PollingStrategy,
     pub count: u32,
-    pub auto_commit: bool,
+    pub auto_commit: AutoCommitMode,
 }
 
 impl PollingArgs {
-    pub fn new(strategy: PollingStrategy, count: u32, auto_commit: bool) -> Self {
+    pub fn new(strategy: PollingStrategy, count: u32, auto_commit: AutoCommitMode) -> Self {
         Self {
             strategy,
             count,
@@ -16,20 +16,32 @@
  * under the License.
  */
 
+pub mod audit;
+pub mod backups;
+pub mod bookmarks;
 pub mod clients;
+pub mod cluster;
+pub mod config;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod fsck;
 pub mod info;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod quotas;
+pub mod schemas;
 pub mod segments;
+pub mod shutdown;
 pub mod snapshot;
+pub mod standby;
 pub mod stats;
 pub mod storage;
 pub mod streams;
 pub mod system;
+pub mod tenants;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 
 pub const COMPONENT: &str = "STREAMING_SYSTEMS";
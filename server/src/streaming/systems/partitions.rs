@@ -22,6 +22,7 @@ use crate::streaming::systems::COMPONENT;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::partitions::key_routing_policy::KeyRoutingPolicy;
 
 impl System {
     pub async fn create_partitions(
@@ -30,12 +31,13 @@ impl System {
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitions_count: u32,
+        key_routing_policy: KeyRoutingPolicy,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         {
             let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
             self.permissioner.create_partitions(
-                session.get_user_id(),
+                session,
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!(
@@ -44,6 +46,7 @@ impl System {
                 topic.stream_id,
                 topic.topic_id
             ))?;
+            self.ensure_tenant_topics_quota(topic.stream_id, 0, partitions_count)?;
         }
 
         let topic = self
@@ -60,6 +63,12 @@ impl System {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to add persisted partitions, topic: {topic}")
             })?;
+        topic
+            .apply_key_routing_policy(key_routing_policy)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to apply key routing policy: {key_routing_policy}, topic: {topic}")
+            })?;
         topic.reassign_consumer_groups().await;
         self.metrics.increment_partitions(partitions_count);
         self.metrics.increment_segments(partitions_count);
@@ -77,7 +86,7 @@ impl System {
         {
             let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
             self.permissioner.delete_partitions(
-                session.get_user_id(),
+                session,
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!(
@@ -110,4 +119,69 @@ impl System {
         }
         Ok(())
     }
+
+    pub async fn update_partition(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+        name: Option<&str>,
+    ) -> Result<u32, IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+            self.permissioner.update_partition(
+                session,
+                topic.stream_id,
+                topic.topic_id,
+            ).with_error_context(|error| format!(
+                "{COMPONENT} (error: {error}) - permission denied to update partition for user {} on stream ID: {}, topic ID: {}",
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id
+            ))?;
+        }
+
+        let topic = self
+            .get_stream_mut(stream_id)?
+            .get_topic_mut(topic_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to get mutable reference to stream with id: {stream_id}"
+                )
+            })?;
+        topic
+            .update_partition(partition_id, name)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to update partition with ID: {partition_id}, topic: {topic}")
+            })
+    }
+
+    pub async fn move_partition(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: &Identifier,
+        directory: &str,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .move_partition(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to move partition for user {}",
+                    session.get_user_id()
+                )
+            })?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream ID: {stream_id}, topic_id: {topic_id}"))?;
+        topic
+            .move_partition(partition_id, directory, self.clock.clone())
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to move partition with ID: {partition_id} to directory: {directory}, topic: {topic}")
+            })
+    }
 }
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::streaming::clients::access_rules::AddressRange;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
@@ -23,9 +24,10 @@ use crate::streaming::systems::COMPONENT;
 use crate::streaming::users::user::User;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::models::personal_access_token::PersonalAccessTokenScope;
 use iggy::utils::expiry::IggyExpiry;
-use iggy::utils::timestamp::IggyTimestamp;
-use tracing::{error, info};
+use std::net::IpAddr;
+use tracing::{error, info, warn};
 
 impl System {
     pub async fn get_personal_access_tokens(
@@ -53,9 +55,24 @@ impl System {
         session: &Session,
         name: &str,
         expiry: IggyExpiry,
+        scope: Option<PersonalAccessTokenScope>,
+        allowed_addresses: Option<Vec<String>>,
     ) -> Result<String, IggyError> {
         self.ensure_authenticated(session)?;
         let user_id = session.get_user_id();
+        if session.get_token_scope().is_some() {
+            error!("User with ID: {user_id} logged in with the scoped personal access token cannot create personal access tokens.");
+            return Err(IggyError::Unauthorized);
+        }
+
+        if let Some(allowed_addresses) = &allowed_addresses {
+            for address in allowed_addresses {
+                address.parse::<AddressRange>().with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - invalid personal access token address: {address}")
+                })?;
+            }
+        }
+
         let identifier = user_id.try_into()?;
         {
             let user = self.get_user(&identifier).with_error_context(|error| {
@@ -73,6 +90,7 @@ impl System {
             }
         }
 
+        let now = self.clock.now();
         let user = self.get_user_mut(&identifier).with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
         })?;
@@ -91,7 +109,7 @@ impl System {
 
         info!("Creating personal access token: {name} for user with ID: {user_id}...");
         let (personal_access_token, token) =
-            PersonalAccessToken::new(user_id, name, IggyTimestamp::now(), expiry);
+            PersonalAccessToken::new(user_id, name, now, expiry, scope, allowed_addresses);
         user.personal_access_tokens
             .insert(personal_access_token.token.clone(), personal_access_token);
         info!("Created personal access token: {name} for user with ID: {user_id}.");
@@ -105,6 +123,11 @@ impl System {
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let user_id = session.get_user_id();
+        if session.get_token_scope().is_some() {
+            error!("User with ID: {user_id} logged in with the scoped personal access token cannot delete personal access tokens.");
+            return Err(IggyError::Unauthorized);
+        }
+
         let user = self
             .get_user_mut(&user_id.try_into()?)
             .with_error_context(|error| {
@@ -134,11 +157,14 @@ impl System {
         Ok(())
     }
 
+    /// Logs in the user with the personal access token, used from the provided IP address,
+    /// and returns the user along with the token, as its scope limits the actions of the user.
     pub async fn login_with_personal_access_token(
         &self,
         token: &str,
+        ip_address: &IpAddr,
         session: Option<&Session>,
-    ) -> Result<&User, IggyError> {
+    ) -> Result<(&User, &PersonalAccessToken), IggyError> {
         let token_hash = PersonalAccessToken::hash_token(token);
         let mut personal_access_token = None;
         for user in self.users.values() {
@@ -154,7 +180,7 @@ impl System {
         }
 
         let personal_access_token = personal_access_token.unwrap();
-        if personal_access_token.is_expired(IggyTimestamp::now()) {
+        if personal_access_token.is_expired(self.clock.now()) {
             error!(
                 "Personal access token: {} for user with ID: {} has expired.",
                 personal_access_token.name, personal_access_token.user_id
@@ -165,6 +191,14 @@ impl System {
             ));
         }
 
+        if !personal_access_token.is_address_allowed(ip_address) {
+            warn!(
+                "Personal access token: {} for user with ID: {} cannot be used from address: {ip_address}.",
+                personal_access_token.name, personal_access_token.user_id
+            );
+            return Err(IggyError::ClientAccessDenied);
+        }
+
         let user = self
             .get_user(&personal_access_token.user_id.try_into()?)
             .with_error_context(|error| {
@@ -173,7 +207,88 @@ impl System {
                     personal_access_token.user_id
                 )
             })?;
-        self.login_user_with_credentials(&user.username, None, session)
+        let user = self
+            .login_user_with_credentials(&user.username, None, session)
+            .await?;
+        personal_access_token.mark_used(self.clock.now());
+        if let Some(session) = session {
+            session.set_token_scope(personal_access_token.scope.clone());
+        }
+        Ok((user, personal_access_token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
+    use crate::configs::system::SystemConfig;
+    use crate::state::{MockState, StateKind};
+    use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
+    use crate::streaming::storage::SystemStorage;
+    use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+    use std::{
+        net::{Ipv4Addr, SocketAddr},
+        sync::Arc,
+    };
+
+    #[tokio::test]
+    async fn scoped_personal_access_token_should_not_delete_personal_access_tokens() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let config = Arc::new(SystemConfig {
+            path: tempdir.path().to_str().unwrap().to_string(),
+            ..Default::default()
+        });
+        let storage = SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        );
+        let mut system = System::create(
+            config,
+            storage,
+            Arc::new(StateKind::Mock(MockState::new())),
+            None,
+            DataMaintenanceConfig::default(),
+            PersonalAccessTokenConfig::default(),
+        );
+        let root = User::root(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD);
+        let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234);
+        let session = Session::new(1, root.id, address);
+        let read_only_session = Session::new(2, root.id, address).with_token_scope(Some(Arc::new(
+            PersonalAccessTokenScope {
+                read_only: true,
+                streams: None,
+            },
+        )));
+        system.users.insert(root.id, root);
+        system
+            .create_personal_access_token(&session, "test", IggyExpiry::NeverExpire, None, None)
+            .await
+            .unwrap();
+
+        let result = system
+            .delete_personal_access_token(&read_only_session, "test")
+            .await;
+        assert!(matches!(result, Err(IggyError::Unauthorized)));
+        let result = system
+            .create_personal_access_token(
+                &read_only_session,
+                "other",
+                IggyExpiry::NeverExpire,
+                None,
+                None,
+            )
+            .await;
+        assert!(matches!(result, Err(IggyError::Unauthorized)));
+
+        system
+            .delete_personal_access_token(&session, "test")
+            .await
+            .unwrap();
+        assert!(system
+            .get_personal_access_tokens(&session)
             .await
+            .unwrap()
+            .is_empty());
     }
 }
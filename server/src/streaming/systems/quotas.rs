@@ -0,0 +1,180 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::diagnostics::metrics::QuotaDirection;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::topics::topic::Topic;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::quota::{Quota, QuotaLimits, QuotaPrincipal};
+use iggy::utils::duration::IggyDuration;
+use tokio::time::sleep;
+use tracing::info;
+
+impl System {
+    pub fn get_quotas(&self, session: &Session) -> Result<Vec<Quota>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_quotas(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get quotas for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        Ok(self.quotas.get_quotas())
+    }
+
+    /// Overrides the limits of the user or the topic, or restores the default ones if the limits are not provided.
+    pub fn set_quota(
+        &self,
+        session: &Session,
+        user_id: Option<&Identifier>,
+        stream_id: Option<&Identifier>,
+        topic_id: Option<&Identifier>,
+        limits: Option<QuotaLimits>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .set_quota(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to set quota for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        if !self.quotas.is_enabled() {
+            return Err(IggyError::QuotasDisabled);
+        }
+
+        let principal = match (user_id, stream_id, topic_id) {
+            (Some(user_id), None, None) => {
+                let user = self.get_user(user_id).with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to get user with ID: {user_id}")
+                })?;
+                QuotaPrincipal::User { user_id: user.id }
+            }
+            (None, Some(stream_id), Some(topic_id)) => {
+                let topic = self
+                    .find_topic(session, stream_id, topic_id)
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id} in stream with ID: {stream_id}")
+                    })?;
+                QuotaPrincipal::Topic {
+                    stream_id: topic.stream_id,
+                    topic_id: topic.topic_id,
+                }
+            }
+            _ => {
+                return Err(IggyError::InvalidQuota(
+                    "either the user or the stream and the topic must be provided".to_owned(),
+                ))
+            }
+        };
+
+        self.quotas.set_limits(principal, limits);
+        match limits {
+            Some(limits) => info!("Quota of {principal} has been set to: {limits}."),
+            None => info!("Quota of {principal} has been restored to the default."),
+        }
+        Ok(())
+    }
+
+    /// Returns the time the request of the user producing or consuming the messages of the topic has to be delayed for,
+    /// to stay within the quotas of both, or fails if it would exceed them anyway.
+    /// The request is not delayed here, as it's up to the caller to wait without holding the system lock.
+    pub fn acquire_quota(
+        &self,
+        session: &Session,
+        direction: QuotaDirection,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<IggyDuration, IggyError> {
+        if !self.quotas.is_enabled() {
+            return Ok(IggyDuration::default());
+        }
+
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id} in stream with ID: {stream_id}")
+        })?;
+        let principals = get_quota_principals(session, topic);
+        match self
+            .quotas
+            .acquire(direction, &principals, self.clock.now())
+        {
+            Ok(delay) => {
+                if !delay.is_zero() {
+                    self.metrics.increment_quota_delayed_requests(direction);
+                }
+                Ok(delay)
+            }
+            Err(error) => {
+                self.metrics.increment_quota_rejected_requests(direction);
+                Err(error)
+            }
+        }
+    }
+
+    pub(crate) fn record_quota_usage(
+        &self,
+        session: &Session,
+        direction: QuotaDirection,
+        topic: &Topic,
+        bytes: u64,
+        messages: u64,
+    ) {
+        let principals = get_quota_principals(session, topic);
+        self.quotas
+            .record(direction, &principals, bytes, messages, self.clock.now());
+    }
+}
+
+impl SharedSystem {
+    /// Delays the request until it fits within the quotas, without holding the system lock while waiting.
+    pub async fn wait_for_quota(
+        &self,
+        session: &Session,
+        direction: QuotaDirection,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        let delay = self
+            .read()
+            .await
+            .acquire_quota(session, direction, stream_id, topic_id)?;
+        if !delay.is_zero() {
+            sleep(delay.get_duration()).await;
+        }
+        Ok(())
+    }
+}
+
+fn get_quota_principals(session: &Session, topic: &Topic) -> [QuotaPrincipal; 2] {
+    [
+        QuotaPrincipal::User {
+            user_id: session.get_user_id(),
+        },
+        QuotaPrincipal::Topic {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+        },
+    ]
+}
@@ -0,0 +1,151 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::system::SchemaState;
+use crate::streaming::schemas::schema::Schema;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use ahash::AHashMap;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::topic_schema::TopicSchema;
+use iggy::schemas::schema_compatibility::SchemaCompatibility;
+use tracing::info;
+
+impl System {
+    pub fn get_schema(
+        &self,
+        session: &Session,
+        schema_id: u32,
+    ) -> Result<Option<&Schema>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_schemas(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get schema with ID: {schema_id} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+
+        Ok(self.schema_registry.get_schema(schema_id))
+    }
+
+    pub fn get_schemas(&self, session: &Session, subject: &str) -> Result<Vec<&Schema>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_schemas(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get schemas for subject: {subject} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+
+        self.schema_registry.get_schemas(subject)
+    }
+
+    pub fn get_subject_schema(
+        &self,
+        session: &Session,
+        subject: &str,
+        version: Option<u32>,
+    ) -> Result<Option<&Schema>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_schemas(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get schema version: {version:?} for subject: {subject} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+
+        Ok(self.schema_registry.get_subject_schema(subject, version))
+    }
+
+    /// Registers the new version of the schema, returning the schema and the flag indicating whether it was created (and has to be applied to the state).
+    pub fn register_schema(
+        &mut self,
+        session: &Session,
+        subject: &str,
+        schema: TopicSchema,
+    ) -> Result<(&Schema, bool), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .manage_schemas(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to register schema for subject: {subject} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+
+        self.schema_registry
+            .register_schema(subject, schema)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to register schema for subject: {subject}")
+            })
+    }
+
+    pub fn update_schema_compatibility(
+        &mut self,
+        session: &Session,
+        subject: &str,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .manage_schemas(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update schema compatibility for subject: {subject} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+
+        self.schema_registry
+            .set_compatibility(subject, compatibility);
+        info!("Updated schema compatibility for subject: {subject} to {compatibility}.");
+        Ok(())
+    }
+
+    pub(crate) fn load_schemas(
+        &mut self,
+        mut schemas: Vec<SchemaState>,
+        subjects_compatibility: AHashMap<String, SchemaCompatibility>,
+    ) {
+        info!("Loading {} schemas...", schemas.len());
+        schemas.sort_by_key(|schema| schema.id);
+        for schema in schemas {
+            self.schema_registry.add_schema(
+                schema.id,
+                &schema.subject,
+                schema.schema,
+                schema.created_at,
+            );
+        }
+
+        for (subject, compatibility) in subjects_compatibility {
+            self.schema_registry
+                .set_compatibility(&subject, compatibility);
+        }
+        info!("Loaded schemas.");
+    }
+}
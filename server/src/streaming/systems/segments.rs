@@ -22,6 +22,8 @@ use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::segments_verification::SegmentsVerification;
+use tracing::info;
 
 impl System {
     pub async fn delete_segments(
@@ -39,7 +41,7 @@ impl System {
             let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
 
             self.permissioner.delete_segments(
-                session.get_user_id(),
+                session,
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| format!(
@@ -106,4 +108,49 @@ impl System {
         self.metrics.decrement_messages(deleted_messages_count);
         Ok(())
     }
+
+    pub async fn verify_segments(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<SegmentsVerification, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id).with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic not found for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        self.permissioner
+            .verify_segments(session, topic.stream_id, topic.topic_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to verify segments for user {} on Stream ID: {}, Topic ID: {}",
+                    session.get_user_id(),
+                    topic.stream_id,
+                    topic.topic_id
+                )
+            })?;
+
+        let partition = topic.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        partition.verify_segments().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to verify segments for partition with ID: {partition_id}, topic: {topic}")
+        })
+    }
+
+    /// Encrypts all the plaintext segments of all the partitions in place, with the data keys wrapped by the configured master key.
+    pub async fn encrypt_segments(&self) -> Result<u32, IggyError> {
+        let mut encrypted_segments_count = 0;
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let mut partition = partition.write().await;
+                    encrypted_segments_count += partition.encrypt_segments().await.with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to encrypt segments for partition with ID: {}, topic: {topic}", partition.partition_id)
+                    })?;
+                }
+            }
+        }
+
+        info!("Encrypted {encrypted_segments_count} segment(s).");
+        Ok(encrypted_segments_count)
+    }
 }
@@ -0,0 +1,100 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::server::ShutdownConfig;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::utils::shutdown::ShutdownCoordinator;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use std::sync::Arc;
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+impl System {
+    pub fn shutdown_coordinator(&self) -> Arc<ShutdownCoordinator> {
+        self.shutdown.clone()
+    }
+
+    /// Requests the graceful shutdown of the server, which is then performed the same way as on SIGTERM.
+    pub fn request_shutdown(&self, session: &Session) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .shutdown_server(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to shutdown server for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        if self.shutdown.request() {
+            info!(
+                "Shutdown has been requested by user with ID: {}.",
+                session.get_user_id()
+            );
+        }
+        Ok(())
+    }
+}
+
+impl SharedSystem {
+    /// Shuts the server down gracefully - the listeners stop accepting the new connections, the in-flight requests
+    /// are given the time to finish, the unsaved messages are flushed, and in the cluster mode, the leadership
+    /// of the partitions is handed off to the other replicas. All the steps share the same timeout.
+    pub async fn shutdown_gracefully(&self, config: &ShutdownConfig) -> Result<(), IggyError> {
+        let deadline = Instant::now() + config.timeout.get_duration();
+        let (coordinator, replication) = {
+            let system = self.read().await;
+            (system.shutdown.clone(), system.replication.clone())
+        };
+        coordinator.request();
+
+        let in_flight_count = coordinator.in_flight_count();
+        if in_flight_count > 0 {
+            info!("Waiting for {in_flight_count} in-flight request(s) to finish...");
+        }
+        if !coordinator
+            .drain(deadline.saturating_duration_since(Instant::now()))
+            .await
+        {
+            warn!(
+                "{} in-flight request(s) didn't finish in: {}.",
+                coordinator.in_flight_count(),
+                config.timeout
+            );
+        }
+
+        self.write()
+            .await
+            .shutdown()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to flush unsaved messages")
+            })?;
+
+        if let Some(replication) = replication {
+            if replication
+                .hand_off(self, deadline.saturating_duration_since(Instant::now()))
+                .await
+            {
+                info!("Partition leadership has been handed off.");
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use tracing::info;
+
+impl System {
+    pub fn is_standby(&self) -> bool {
+        self.standby.is_some()
+    }
+
+    /// Returns the error if the server is the standby which hasn't been promoted yet, so it cannot accept the writes.
+    pub fn ensure_promoted(&self) -> Result<(), IggyError> {
+        match &self.standby {
+            Some(standby) if !standby.is_promoted() => Err(IggyError::StandbyNotPromoted),
+            _ => Ok(()),
+        }
+    }
+
+    /// Promotes the standby to the primary server - it stops syncing with the previous primary and starts accepting the writes.
+    pub fn promote_standby(&self, session: &Session) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .promote_standby(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to promote standby for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        let Some(standby) = &self.standby else {
+            return Err(IggyError::InvalidCommand);
+        };
+
+        if standby.promote() {
+            info!(
+                "Standby server has been promoted by user with ID: {}.",
+                session.get_user_id()
+            );
+        }
+        Ok(())
+    }
+}
@@ -16,14 +16,18 @@
  * under the License.
  */
 
+use crate::streaming::diagnostics::metrics::{PartitionLabels, PartitionMeasurement};
 use crate::streaming::systems::system::System;
+use crate::streaming::utils::request_lanes::RequestLane;
 use crate::versioning::SemanticVersion;
 use crate::VERSION;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
 use iggy::models::stats::{CacheMetricsKey, Stats};
 use iggy::utils::duration::IggyDuration;
+use iggy::utils::sizeable::Sizeable;
 use std::collections::HashMap;
+use std::sync::atomic::Ordering;
 use std::sync::OnceLock;
 use sysinfo::{Pid, ProcessesToUpdate, System as SysinfoSystem};
 use tokio::sync::Mutex;
@@ -38,6 +42,46 @@ fn sysinfo() -> &'static Mutex<SysinfoSystem> {
 }
 
 impl System {
+    /// Refreshes the metrics of all the partitions, which are measured on demand when the metrics are scraped.
+    pub async fn refresh_partition_metrics(&self) {
+        self.metrics.clear_partitions();
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let partition = partition.read().await;
+                    let labels = PartitionLabels {
+                        stream_id: stream.stream_id,
+                        topic_id: topic.topic_id,
+                        partition_id: partition.partition_id,
+                    };
+                    let measurement = PartitionMeasurement {
+                        size_bytes: partition.get_size_bytes().as_bytes_u64(),
+                        messages: partition.get_messages_count(),
+                        segments: partition.get_segments_count(),
+                    };
+                    self.metrics
+                        .set_partition_measurement(&labels, &measurement);
+                }
+            }
+        }
+    }
+
+    /// Refreshes the queue depth and the number of the active commands of each request lane.
+    pub fn refresh_request_lane_metrics(&self) {
+        for lane in [RequestLane::Admin, RequestLane::Data] {
+            self.metrics.set_request_lane_measurement(
+                lane,
+                self.request_lanes.queue_depth(lane),
+                self.request_lanes.active_count(lane),
+            );
+        }
+    }
+
+    pub fn refresh_buffer_pool_metrics(&self) {
+        self.metrics
+            .set_buffer_pool_metrics(&self.storage.buffer_pool.get_metrics());
+    }
+
     pub async fn get_stats(&self) -> Result<Stats, IggyError> {
         let mut sys = sysinfo().lock().await;
         let process_id = std::process::id();
@@ -60,13 +104,13 @@ impl System {
         for stream in self.streams.values() {
             for topic in stream.topics.values() {
                 for partition in topic.partitions.values() {
+                    let partition = partition.read().await;
                     let key = CacheMetricsKey {
                         stream_id: stream.stream_id,
                         topic_id: topic.topic_id,
-                        partition_id: partition.read().await.partition_id,
+                        partition_id: partition.partition_id,
                     };
-                    let metrics = partition.read().await.get_cache_metrics();
-                    cache_metrics.insert(key, metrics);
+                    cache_metrics.insert(key, partition.get_cache_metrics());
                 }
             }
         }
@@ -86,6 +130,8 @@ impl System {
                 .ok()
                 .and_then(|v| v.get_numeric_version().ok()),
             cache_metrics,
+            disk_usage: self.storage_usage.get_total_disk_usage_bytes().into(),
+            fsyncs_count: self.storage.fsyncs_count.load(Ordering::Relaxed),
             ..Default::default()
         };
 
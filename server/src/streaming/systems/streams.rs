@@ -17,6 +17,7 @@
  */
 
 use crate::state::system::StreamState;
+use crate::streaming::diagnostics::metrics::TopicLabels;
 use crate::streaming::session::Session;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::system::System;
@@ -27,7 +28,6 @@ use futures::future::try_join_all;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
-use std::cell::RefCell;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tokio::fs;
 use tokio::fs::read_dir;
@@ -120,19 +120,16 @@ impl System {
             .into_iter()
             .map(|s| (s.id, s))
             .collect::<AHashMap<_, _>>();
-        let loaded_streams = RefCell::new(Vec::new());
         let load_stream_tasks = unloaded_streams.into_iter().map(|mut stream| {
             let state = streams_states.remove(&stream.stream_id).unwrap();
-            let load_stream_task = async {
+            async move {
                 stream.load(state).await?;
-                loaded_streams.borrow_mut().push(stream);
-                Result::<(), IggyError>::Ok(())
-            };
-            load_stream_task
+                Result::<Stream, IggyError>::Ok(stream)
+            }
         });
-        try_join_all(load_stream_tasks).await?;
+        let loaded_streams = try_join_all(load_stream_tasks).await?;
 
-        for stream in loaded_streams.take() {
+        for stream in loaded_streams {
             if self.streams.contains_key(&stream.stream_id) {
                 error!("Stream with ID: '{}' already exists.", &stream.stream_id);
                 continue;
@@ -143,22 +140,34 @@ impl System {
                 continue;
             }
 
-            self.metrics.increment_streams(1);
-            self.metrics.increment_topics(stream.get_topics_count());
-            self.metrics
-                .increment_partitions(stream.get_partitions_count());
-            self.metrics.increment_segments(stream.get_segments_count());
-            self.metrics.increment_messages(stream.get_messages_count());
-
-            self.streams_ids
-                .insert(stream.name.clone(), stream.stream_id);
-            self.streams.insert(stream.stream_id, stream);
+            self.add_loaded_stream(stream);
         }
 
         info!("Loaded {} stream(s) from disk.", self.streams.len());
         Ok(())
     }
 
+    /// Registers the stream loaded from disk, along with its topics, in the metrics and the permissioner.
+    pub(crate) fn add_loaded_stream(&mut self, stream: Stream) {
+        self.metrics.increment_streams(1);
+        self.metrics.increment_topics(stream.get_topics_count());
+        self.metrics
+            .increment_partitions(stream.get_partitions_count());
+        self.metrics.increment_segments(stream.get_segments_count());
+        self.metrics.increment_messages(stream.get_messages_count());
+
+        self.permissioner
+            .register_stream(stream.stream_id, &stream.name);
+        for topic in stream.get_topics() {
+            self.permissioner
+                .register_topic(stream.stream_id, topic.topic_id, &topic.name);
+        }
+
+        self.streams_ids
+            .insert(stream.name.clone(), stream.stream_id);
+        self.streams.insert(stream.stream_id, stream);
+    }
+
     pub fn get_streams(&self) -> Vec<&Stream> {
         self.streams.values().collect()
     }
@@ -166,14 +175,21 @@ impl System {
     pub fn find_streams(&self, session: &Session) -> Result<Vec<&Stream>, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .get_streams(session.get_user_id())
+            .get_streams(session)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get streams for user {}",
                     session.get_user_id(),
                 )
             })?;
-        Ok(self.get_streams())
+        Ok(self
+            .get_streams()
+            .into_iter()
+            .filter(|stream| {
+                self.permissioner
+                    .is_stream_visible(session.get_user_id(), stream.stream_id)
+            })
+            .collect())
     }
 
     pub fn find_stream(
@@ -185,7 +201,7 @@ impl System {
         let stream = self.get_stream(identifier);
         if let Ok(stream) = stream {
             self.permissioner
-                .get_stream(session.get_user_id(), stream.stream_id)
+                .get_stream(session, stream.stream_id)
                 .with_error_context(|error| {
                     format!(
                         "{COMPONENT} (error: {error}) - permission denied to get stream for user {}",
@@ -209,7 +225,7 @@ impl System {
         };
 
         self.permissioner
-            .get_stream(session.get_user_id(), stream.stream_id)
+            .get_stream(session, stream.stream_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get stream with ID: {identifier} for user with ID: {}",
@@ -294,7 +310,8 @@ impl System {
         name: &str,
     ) -> Result<&Stream, IggyError> {
         self.ensure_authenticated(session)?;
-        self.permissioner.create_stream(session.get_user_id())?;
+        self.permissioner.create_stream(session)?;
+        self.ensure_tenant_stream_quota(session.get_user_id(), name, None)?;
         if self.streams_ids.contains_key(name) {
             return Err(IggyError::StreamNameAlreadyExists(name.to_owned()));
         }
@@ -323,6 +340,7 @@ impl System {
         let stream = Stream::create(id, name, self.config.clone(), self.storage.clone());
         stream.persist().await?;
         info!("Created stream with ID: {id}, name: '{name}'.");
+        self.permissioner.register_stream(stream.stream_id, name);
         self.streams_ids.insert(name.to_owned(), stream.stream_id);
         self.streams.insert(stream.stream_id, stream);
         self.metrics.increment_streams(1);
@@ -345,7 +363,7 @@ impl System {
         }
 
         self.permissioner
-            .update_stream(session.get_user_id(), stream_id)
+            .update_stream(session, stream_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - failed to update stream, user ID: {}, stream ID: {}",
@@ -354,6 +372,7 @@ impl System {
                 )
             })?;
 
+        self.ensure_tenant_stream_quota(session.get_user_id(), name, Some(stream_id))?;
         {
             if let Some(stream_id_by_name) = self.streams_ids.get(name) {
                 if *stream_id_by_name != stream_id {
@@ -375,6 +394,7 @@ impl System {
         {
             self.streams_ids.remove(&old_name);
             self.streams_ids.insert(name.to_owned(), stream_id);
+            self.permissioner.register_stream(stream_id, name);
         }
 
         info!("Stream with ID '{id}' updated. Old name: '{old_name}' changed to: '{name}'.");
@@ -392,7 +412,7 @@ impl System {
         })?;
         let stream_id = stream.stream_id;
         self.permissioner
-            .delete_stream(session.get_user_id(), stream_id)
+            .delete_stream(session, stream_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to delete stream for user {}, stream ID: {}",
@@ -411,8 +431,17 @@ impl System {
             .decrement_partitions(stream.get_partitions_count());
         self.metrics.decrement_messages(stream.get_messages_count());
         self.metrics.decrement_segments(stream.get_segments_count());
+        for topic in stream.topics.values() {
+            self.metrics.remove_topic(&TopicLabels {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+            });
+        }
         self.streams.remove(&stream_id);
         self.streams_ids.remove(&stream_name);
+        self.permissioner.unregister_stream(stream_id);
+        self.quotas.remove_stream(stream_id);
+        self.storage_usage.remove_stream(stream_id);
         let current_stream_id = CURRENT_STREAM_ID.load(Ordering::SeqCst);
         if current_stream_id > stream_id {
             CURRENT_STREAM_ID.store(stream_id, Ordering::SeqCst);
@@ -434,7 +463,7 @@ impl System {
             format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
         })?;
         self.permissioner
-            .purge_stream(session.get_user_id(), stream.stream_id)
+            .purge_stream(session, stream.stream_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to purge stream for user {}, stream ID: {}",
@@ -17,30 +17,54 @@
  */
 
 use crate::archiver::{ArchiverKind, ArchiverKindType};
+use crate::audit::audit_log::AuditLog;
+use crate::cluster::raft::RaftNode;
+use crate::cluster::replication::PartitionReplication;
+use crate::cluster::rpc::ClusterTransport;
+use crate::cluster::state::ReplicatedState;
+use crate::compat::migrations::migrator::Migrator;
+use crate::configs::cluster::ClusterConfig;
+use crate::configs::config_provider::ConfigProviderKind;
 use crate::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
+use crate::configs::standby::StandbyConfig;
 use crate::configs::system::SystemConfig;
+use crate::log::LogLevelHandle;
 use crate::map_toggle_str;
+use crate::standby::follower::StandbyFollower;
 use crate::state::file::FileState;
 use crate::state::system::SystemState;
 use crate::state::StateKind;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::clients::access_rules::ClientAccessRules;
+use crate::streaming::clients::certificate_auth::CertificateAuthenticator;
 use crate::streaming::clients::client_manager::ClientManager;
 use crate::streaming::diagnostics::metrics::Metrics;
 use crate::streaming::persistence::persister::*;
+use crate::streaming::quotas::quota_manager::QuotaManager;
+use crate::streaming::schemas::schema_registry::SchemaRegistry;
+use crate::streaming::segments::uring;
 use crate::streaming::session::Session;
 use crate::streaming::storage::SystemStorage;
+use crate::streaming::storage_usage::storage_usage_tracker::StorageUsageTracker;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::COMPONENT;
+use crate::streaming::tenants::tenant_registry::TenantRegistry;
+use crate::streaming::transactions::transaction_coordinator::TransactionCoordinator;
+use crate::streaming::users::authorizers::AuthorizerKind;
 use crate::streaming::users::permissioner::Permissioner;
 use crate::streaming::users::user::User;
+use crate::streaming::utils::clock::{system_clock, SharedClock};
+use crate::streaming::utils::io_throttle::BackgroundIoThrottle;
+use crate::streaming::utils::request_lanes::RequestLanes;
+use crate::streaming::utils::shutdown::ShutdownCoordinator;
 use crate::versioning::SemanticVersion;
 use ahash::AHashMap;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::config_value::ConfigValue;
 use iggy::models::user_info::UserId;
-use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::crypto::{Aes256GcmEncryptor, EncryptorKind};
 use std::path::Path;
 use std::sync::Arc;
@@ -89,8 +113,26 @@ pub struct System {
     pub(crate) client_manager: IggySharedMut<ClientManager>,
     pub(crate) encryptor: Option<Arc<EncryptorKind>>,
     pub(crate) metrics: Metrics,
+    pub(crate) audit_log: AuditLog,
     pub(crate) state: Arc<StateKind>,
     pub(crate) archiver: Option<Arc<ArchiverKind>>,
+    pub(crate) background_io: Arc<BackgroundIoThrottle>,
+    pub(crate) schema_registry: SchemaRegistry,
+    pub(crate) clock: SharedClock,
+    pub(crate) client_access: ClientAccessRules,
+    pub(crate) certificate_auth: CertificateAuthenticator,
+    pub(crate) transactions: TransactionCoordinator,
+    pub(crate) effective_config: Vec<ConfigValue>,
+    pub(crate) config_provider: Option<ConfigProviderKind>,
+    pub(crate) log_level_handle: Option<LogLevelHandle>,
+    pub(crate) cluster: Option<Arc<RaftNode>>,
+    pub(crate) replication: Option<Arc<PartitionReplication>>,
+    pub(crate) shutdown: Arc<ShutdownCoordinator>,
+    pub(crate) request_lanes: Arc<RequestLanes>,
+    pub(crate) standby: Option<Arc<StandbyFollower>>,
+    pub(crate) tenants: TenantRegistry,
+    pub(crate) quotas: QuotaManager,
+    pub(crate) storage_usage: StorageUsageTracker,
     pub personal_access_token: PersonalAccessTokenConfig,
 }
 
@@ -176,6 +218,67 @@ impl System {
             None
         };
 
+        let background_io = Arc::new(BackgroundIoThrottle::new(&data_maintenance_config.io));
+        if background_io.is_enabled() {
+            info!(
+                "Background I/O throttling is enabled, max throughput: {}/s",
+                data_maintenance_config.io.max_throughput
+            );
+        }
+
+        let client_access = ClientAccessRules::from_config(&system_config.client_access)
+            .expect("Invalid client access config");
+        if client_access.is_enabled() {
+            info!("Client access rules are enabled.");
+        }
+
+        let certificate_auth =
+            CertificateAuthenticator::from_config(&system_config.certificate_auth)
+                .expect("Invalid certificate auth config");
+        if certificate_auth.is_enabled() {
+            info!("Client certificate authentication is enabled.");
+        }
+
+        let authorizer = AuthorizerKind::from_config(&system_config.authorization)
+            .expect("Invalid authorization config");
+        info!("Authorization backend: {}.", authorizer.kind());
+
+        let tenants = TenantRegistry::from_config(&system_config.tenancy);
+        if tenants.is_enabled() {
+            info!(
+                "Multi-tenancy is enabled, tenants: {}.",
+                tenants.tenants().len()
+            );
+        }
+
+        let quotas = QuotaManager::new(&system_config.quotas);
+        if quotas.is_enabled() {
+            info!(
+                "Throughput quotas are enabled, mode: {}, user: {}, topic: {}.",
+                system_config.quotas.mode, system_config.quotas.user, system_config.quotas.topic
+            );
+        }
+
+        let storage_usage = StorageUsageTracker::new(&system_config.storage_usage);
+        if storage_usage.is_enabled() {
+            info!(
+                "Storage usage accounting is enabled, interval: {}, stream: {}, topic: {}, reject on hard limit: {}.",
+                system_config.storage_usage.interval,
+                system_config.storage_usage.stream,
+                system_config.storage_usage.topic,
+                system_config.storage_usage.reject_on_hard_limit
+            );
+        }
+
+        let request_lanes = Arc::new(RequestLanes::new(&system_config.request_lanes));
+        info!("Request lanes: {}.", system_config.request_lanes);
+
+        let audit_log = AuditLog::new(&system_config.audit);
+        if audit_log.is_enabled() {
+            info!("Audit log is enabled, sink: {}.", system_config.audit.sink);
+        }
+
+        let clock = system_clock();
         System {
             config: system_config,
             streams: AHashMap::new(),
@@ -183,15 +286,101 @@ impl System {
             storage: Arc::new(storage),
             encryptor,
             client_manager: IggySharedMut::new(ClientManager::default()),
-            permissioner: Permissioner::default(),
+            permissioner: Permissioner::new(authorizer),
             metrics: Metrics::init(),
+            audit_log,
             users: AHashMap::new(),
             state,
             personal_access_token: pat_config,
             archiver,
+            background_io,
+            schema_registry: SchemaRegistry::default(),
+            transactions: TransactionCoordinator::new(clock.now()),
+            clock,
+            client_access,
+            certificate_auth,
+            effective_config: Vec::new(),
+            config_provider: None,
+            log_level_handle: None,
+            cluster: None,
+            replication: None,
+            shutdown: Arc::new(ShutdownCoordinator::default()),
+            request_lanes,
+            standby: None,
+            tenants,
+            quotas,
+            storage_usage,
+        }
+    }
+
+    /// Uses the given clock instead of the system time, e.g. the manual clock advanced by the tests.
+    /// The transaction IDs are seeded again, as they're derived from the current time.
+    pub fn with_clock(self, clock: SharedClock) -> Self {
+        Self {
+            transactions: TransactionCoordinator::new(clock.now()),
+            clock,
+            ..self
+        }
+    }
+
+    pub fn request_lanes(&self) -> Arc<RequestLanes> {
+        self.request_lanes.clone()
+    }
+
+    /// Replicates the state log across the cluster nodes, so the metadata changes are accepted by the leader only,
+    /// and the messages of the partitions across their replicas, so they're accepted by the partition leaders only.
+    pub fn with_cluster(self, config: &ClusterConfig) -> Self {
+        let version = SemanticVersion::current().expect("Invalid version");
+        let file_state = Arc::new(FileState::new(
+            &self.config.get_state_log_path(),
+            &version,
+            Self::resolve_persister(self.config.state.enforce_fsync),
+            self.encryptor.clone(),
+        ));
+        let transport = ClusterTransport::new(config).unwrap_or_else(|error| {
+            panic!("Unable to initialize the cluster transport. {error:#}")
+        });
+        let raft = Arc::new(RaftNode::new(
+            config.clone(),
+            &self.config.get_cluster_state_path(),
+            file_state.clone(),
+            Arc::new(transport),
+        ));
+        info!(
+            "Clustering is enabled, node ID: {}, nodes: {}.",
+            config.id,
+            config.nodes.len() + 1
+        );
+        Self {
+            state: Arc::new(StateKind::Replicated(ReplicatedState::new(
+                file_state,
+                raft.clone(),
+            ))),
+            replication: Some(Arc::new(PartitionReplication::new(
+                config.clone(),
+                raft.clone(),
+            ))),
+            cluster: Some(raft),
+            ..self
+        }
+    }
+
+    /// Keeps the system in sync with the primary server, rejecting the writes of the clients until it's promoted.
+    pub fn with_standby(self, config: &StandbyConfig) -> Self {
+        info!(
+            "Standby mode is enabled, primary: {}.",
+            config.primary_address
+        );
+        Self {
+            standby: Some(Arc::new(StandbyFollower::new(config.clone()))),
+            ..self
         }
     }
 
+    pub fn clock(&self) -> &SharedClock {
+        &self.clock
+    }
+
     #[instrument(skip_all, name = "trace_system_init")]
     pub async fn init(&mut self) -> Result<(), IggyError> {
         let system_path = self.config.get_system_path();
@@ -199,6 +388,13 @@ impl System {
             return Err(IggyError::CannotCreateBaseDirectory(system_path));
         }
 
+        let migrations = Migrator::new(self.config.clone())
+            .migrate()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to migrate data directory")
+            })?;
+
         let state_path = self.config.get_state_path();
         if !Path::new(&state_path).exists() && create_dir_all(&state_path).await.is_err() {
             return Err(IggyError::CannotCreateStateDirectory(state_path));
@@ -222,6 +418,19 @@ impl System {
             "Initializing system, data will be stored at: {}",
             self.config.get_system_path()
         );
+        uring::init(self.config.segment.io_backend);
+        if self.config.buffer_pool.enabled {
+            info!(
+                "Batch buffer pool enabled, max buffers: {}, max buffer size: {}",
+                self.config.buffer_pool.max_buffers, self.config.buffer_pool.max_buffer_size
+            );
+        }
+        self.storage
+            .segment_encryption
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to initialize segment encryption")
+            })?;
 
         let state_entries = self.state.init().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to initialize state entries")
@@ -232,9 +441,11 @@ impl System {
                 format!("{COMPONENT} (error: {error}) - failed to initialize system state")
             })?;
         let now = Instant::now();
-        self.load_version().await.with_error_context(|error| {
-            format!("{COMPONENT} (error: {error}) - failed to load version")
-        })?;
+        self.load_version(&migrations)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to load version")
+            })?;
         self.load_users(system_state.users.into_values().collect())
             .await
             .with_error_context(|error| {
@@ -245,6 +456,10 @@ impl System {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to load streams")
             })?;
+        self.load_schemas(
+            system_state.schemas.into_values().collect(),
+            system_state.subjects_compatibility,
+        );
         if let Some(archiver) = self.archiver.as_ref() {
             archiver
                 .init()
@@ -258,6 +473,10 @@ impl System {
     #[instrument(skip_all, name = "trace_shutdown")]
     pub async fn shutdown(&mut self) -> Result<(), IggyError> {
         self.persist_messages().await?;
+        let now = self.clock.now();
+        for stream in self.streams.values() {
+            stream.fsync_unsynced_messages(now).await?;
+        }
         Ok(())
     }
 
@@ -286,21 +505,36 @@ impl System {
         }
     }
 
-    pub async fn clean_cache(&self, size_to_clean: IggyByteSize) {
-        for stream in self.streams.values() {
-            for topic in stream.get_topics() {
-                for partition in topic.get_partitions().into_iter() {
-                    tokio::task::spawn(async move {
-                        let memory_tracker = CacheMemoryTracker::get_instance().unwrap();
-                        let mut partition_guard = partition.write().await;
-                        let cache = &mut partition_guard.cache.as_mut().unwrap();
-                        let size_to_remove = (cache.current_size().as_bytes_u64() as f64
-                            / memory_tracker.usage_bytes().as_bytes_u64() as f64
-                            * size_to_clean.as_bytes_u64() as f64)
-                            .ceil() as u64;
-                        cache.evict_by_size(size_to_remove * CACHE_OVER_EVICTION_FACTOR);
-                    });
-                }
+    /// Evicts the cached messages of the least recently read partitions, until the cache fits into its size.
+    pub async fn evict_least_recently_read_caches(&self) {
+        let Some(memory_tracker) = CacheMemoryTracker::get_instance() else {
+            return;
+        };
+        let excess_bytes = memory_tracker.excess_bytes();
+        if excess_bytes == 0 {
+            return;
+        }
+
+        let mut size_to_remove = excess_bytes * CACHE_OVER_EVICTION_FACTOR;
+        for key in memory_tracker.least_recently_read_partitions() {
+            let Some(partition) = self
+                .streams
+                .get(&key.stream_id)
+                .and_then(|stream| stream.topics.get(&key.topic_id))
+                .and_then(|topic| topic.get_partition(key.partition_id).ok())
+            else {
+                continue;
+            };
+            let mut partition = partition.write().await;
+            let Some(cache) = partition.cache.as_mut() else {
+                continue;
+            };
+            let size_before = cache.current_size().as_bytes_u64();
+            cache.evict_by_size(size_to_remove);
+            size_to_remove =
+                size_to_remove.saturating_sub(size_before - cache.current_size().as_bytes_u64());
+            if size_to_remove == 0 {
+                break;
             }
         }
     }
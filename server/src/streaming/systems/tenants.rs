@@ -0,0 +1,192 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::tenants::tenant_registry::{Tenant, TenantUsage};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::models::user_info::UserId;
+
+impl System {
+    /// Confines the user to the namespace of the tenant it belongs to by the username, if any.
+    pub(crate) fn assign_user_tenant(&mut self, user_id: UserId, username: &str) {
+        let prefix = self
+            .tenants
+            .get_by_username(username)
+            .map(|tenant| tenant.prefix.as_str());
+        self.permissioner.set_user_namespace(user_id, prefix);
+    }
+
+    /// Returns the usage of all the tenants, or only of the one the user belongs to.
+    pub fn get_tenants(&self, session: &Session) -> Result<Vec<TenantUsage>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let username = self
+            .users
+            .get(&session.get_user_id())
+            .map(|user| user.username.as_str())
+            .unwrap_or_default();
+        if let Some(tenant) = self.tenants.get_by_username(username) {
+            return Ok(vec![self.get_tenant_usage(tenant)]);
+        }
+
+        self.permissioner
+            .get_stats(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get tenants for user with ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        Ok(self
+            .tenants
+            .tenants()
+            .iter()
+            .map(|tenant| self.get_tenant_usage(tenant))
+            .collect())
+    }
+
+    fn get_tenant_usage(&self, tenant: &Tenant) -> TenantUsage {
+        let mut usage = TenantUsage {
+            name: tenant.name.clone(),
+            prefix: tenant.prefix.clone(),
+            users: tenant.users.clone(),
+            streams: 0,
+            topics: 0,
+            partitions: 0,
+            size_bytes: 0,
+            appended_bytes: tenant.appended_bytes(),
+            throttled_batches: tenant.throttled_batches(),
+            quotas: tenant.quotas,
+        };
+        for stream in self
+            .streams
+            .values()
+            .filter(|stream| tenant.contains_stream(&stream.name))
+        {
+            usage.streams += 1;
+            usage.topics += stream.topics.len() as u32;
+            usage.partitions += stream.get_partitions_count();
+            usage.size_bytes += stream.get_size().as_bytes_u64();
+        }
+        usage
+    }
+
+    /// Fails if the user cannot create the stream with the given name (or rename the existing one to it) outside of its namespace,
+    /// or if the tenant owning the name has reached its streams quota. The renamed stream doesn't count towards the quota.
+    pub(crate) fn ensure_tenant_stream_quota(
+        &self,
+        user_id: UserId,
+        name: &str,
+        renamed_stream_id: Option<u32>,
+    ) -> Result<(), IggyError> {
+        self.permissioner.authorize_stream_name(user_id, name)?;
+        let Some(tenant) = self.tenants.get_by_stream(name) else {
+            return Ok(());
+        };
+
+        let max_streams = tenant.quotas.max_streams;
+        if max_streams == 0 {
+            return Ok(());
+        }
+
+        let streams = self
+            .streams
+            .values()
+            .filter(|stream| Some(stream.stream_id) != renamed_stream_id)
+            .filter(|stream| tenant.contains_stream(&stream.name))
+            .count() as u32;
+        if streams >= max_streams {
+            return Err(IggyError::TenantStreamsQuotaExceeded(
+                tenant.name.clone(),
+                max_streams,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Fails if adding the topics and the partitions to the stream would exceed the quotas of the tenant owning it.
+    pub(crate) fn ensure_tenant_topics_quota(
+        &self,
+        stream_id: u32,
+        topics_count: u32,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        let Some(tenant) = self.get_stream_tenant(stream_id) else {
+            return Ok(());
+        };
+
+        let quotas = tenant.quotas;
+        let usage = self.get_tenant_usage(tenant);
+        if quotas.max_topics > 0
+            && topics_count > 0
+            && usage.topics + topics_count > quotas.max_topics
+        {
+            return Err(IggyError::TenantTopicsQuotaExceeded(
+                tenant.name.clone(),
+                quotas.max_topics,
+            ));
+        }
+
+        if quotas.max_partitions > 0 && usage.partitions + partitions_count > quotas.max_partitions
+        {
+            return Err(IggyError::TenantPartitionsQuotaExceeded(
+                tenant.name.clone(),
+                quotas.max_partitions,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Fails if the tenant owning the stream has run out of its storage, or exceeded its throughput.
+    pub(crate) fn ensure_tenant_append_quota(
+        &self,
+        stream_id: u32,
+        size_bytes: u64,
+    ) -> Result<(), IggyError> {
+        let Some(tenant) = self.get_stream_tenant(stream_id) else {
+            return Ok(());
+        };
+
+        let max_storage_bytes = tenant.quotas.max_storage_bytes;
+        if max_storage_bytes > 0 {
+            let size_bytes = self
+                .streams
+                .values()
+                .filter(|stream| tenant.contains_stream(&stream.name))
+                .map(|stream| stream.get_size().as_bytes_u64())
+                .sum::<u64>();
+            if size_bytes >= max_storage_bytes {
+                return Err(IggyError::TenantStorageQuotaExceeded(
+                    tenant.name.clone(),
+                    max_storage_bytes,
+                ));
+            }
+        }
+
+        tenant.throttle(size_bytes, self.clock.now())
+    }
+
+    fn get_stream_tenant(&self, stream_id: u32) -> Option<&Tenant> {
+        let stream = self.streams.get(&stream_id)?;
+        self.tenants.get_by_stream(&stream.name)
+    }
+}
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::streaming::diagnostics::metrics::TopicLabels;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::systems::COMPONENT;
@@ -25,8 +26,15 @@ use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::dead_letter_policy::DeadLetterPolicy;
+use iggy::models::message_sampling_policy::MessageSamplingPolicy;
+use iggy::models::quota::QuotaPrincipal;
+use iggy::models::topic_options::TopicOptions;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
+use tracing::{error, info};
 
 impl System {
     pub fn find_topic(
@@ -44,7 +52,7 @@ impl System {
         let topic = stream.get_topic(topic_id);
         if let Ok(topic) = topic {
             self.permissioner
-                .get_topic(session.get_user_id(), stream.stream_id, topic.topic_id)
+                .get_topic(session, stream.stream_id, topic.topic_id)
                 .with_error_context(|error| {
                     format!(
                         "{COMPONENT} (error: {error}) - permission denied to get topic with ID: {topic_id} in stream with ID: {stream_id} for user with ID: {}",
@@ -67,7 +75,7 @@ impl System {
             format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
         })?;
         self.permissioner
-            .get_topics(session.get_user_id(), stream.stream_id)
+            .get_topics(session, stream.stream_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get topics in stream with ID: {stream_id} for user with ID: {}",
@@ -98,7 +106,7 @@ impl System {
         };
 
         self.permissioner
-            .get_topic(session.get_user_id(), stream.stream_id, topic.topic_id)
+            .get_topic(session, stream.stream_id, topic.topic_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get topic with ID: {topic_id} in stream with ID: {stream_id} for user with ID: {}",
@@ -120,6 +128,7 @@ impl System {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: Option<u8>,
+        options: TopicOptions,
     ) -> Result<&Topic, IggyError> {
         self.ensure_authenticated(session)?;
         {
@@ -127,13 +136,33 @@ impl System {
                 format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {stream_id}")
             })?;
             self.permissioner
-                .create_topic(session.get_user_id(), stream.stream_id)
+                .create_topic(session, stream.stream_id)
                 .with_error_context(|error| {
                     format!(
                         "{COMPONENT} (error: {error}) - permission denied to create topic with name: {name} in stream with ID: {stream_id} for user with ID: {}",
                         session.get_user_id(),
                     )
                 })?;
+            self.ensure_tenant_topics_quota(stream.stream_id, 1, partitions_count)?;
+        }
+
+        self.validate_replication_factor(replication_factor.unwrap_or(1))
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - invalid replication factor for topic with name: {name} in stream with ID: {stream_id}")
+            })?;
+
+        if let Some(dead_letter_policy) = &options.dead_letter_policy {
+            self.validate_dead_letter_policy(dead_letter_policy, None)
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - invalid dead letter policy: {dead_letter_policy} for topic with name: {name} in stream with ID: {stream_id}")
+                })?;
+        }
+
+        if let Some(sampling_policy) = &options.sampling_policy {
+            self.validate_sampling_policy(sampling_policy, None)
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - invalid sampling policy: {sampling_policy} for topic with name: {name} in stream with ID: {stream_id}")
+                })?;
         }
 
         let created_topic_id = self
@@ -146,6 +175,7 @@ impl System {
                 compression_algorithm,
                 max_topic_size,
                 replication_factor.unwrap_or(1),
+                options,
             )
             .await
             .with_error_context(|error| {
@@ -155,6 +185,9 @@ impl System {
         self.metrics.increment_topics(1);
         self.metrics.increment_partitions(partitions_count);
         self.metrics.increment_segments(partitions_count);
+        let created_stream_id = self.get_stream(stream_id)?.stream_id;
+        self.permissioner
+            .register_topic(created_stream_id, created_topic_id, name);
 
         self.get_stream(stream_id)
             .with_error_context(|error| {
@@ -179,6 +212,7 @@ impl System {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: Option<u8>,
+        options: Option<TopicOptions>,
     ) -> Result<&Topic, IggyError> {
         self.ensure_authenticated(session)?;
         {
@@ -190,7 +224,7 @@ impl System {
                     )
                 })?;
             self.permissioner.update_topic(
-                session.get_user_id(),
+                session,
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| {
@@ -201,6 +235,31 @@ impl System {
                     topic.topic_id,
                 )
             })?;
+
+            self.validate_replication_factor(replication_factor.unwrap_or(1))
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - invalid replication factor for topic with ID: {topic_id} in stream with ID: {stream_id}")
+                })?;
+
+            if let Some(dead_letter_policy) = options
+                .as_ref()
+                .and_then(|options| options.dead_letter_policy.as_ref())
+            {
+                self.validate_dead_letter_policy(dead_letter_policy, Some(topic))
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - invalid dead letter policy: {dead_letter_policy} for topic with ID: {topic_id} in stream with ID: {stream_id}")
+                    })?;
+            }
+
+            if let Some(sampling_policy) = options
+                .as_ref()
+                .and_then(|options| options.sampling_policy.as_ref())
+            {
+                self.validate_sampling_policy(sampling_policy, Some(topic))
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - invalid sampling policy: {sampling_policy} for topic with ID: {topic_id} in stream with ID: {stream_id}")
+                    })?;
+            }
         }
 
         self.get_stream_mut(stream_id)?
@@ -211,6 +270,7 @@ impl System {
                 compression_algorithm,
                 max_topic_size,
                 replication_factor.unwrap_or(1),
+                options,
             )
             .await
             .with_error_context(|error| {
@@ -219,6 +279,11 @@ impl System {
                 )
             })?;
 
+        let topic = self.get_stream(stream_id)?.get_topic(topic_id)?;
+        let (updated_stream_id, updated_topic_id) = (topic.stream_id, topic.topic_id);
+        self.permissioner
+            .register_topic(updated_stream_id, updated_topic_id, name);
+
         // TODO: if message_expiry is changed, we need to check if we need to purge messages based on the new expiry
         // TODO: if max_size_bytes is changed, we need to check if we need to purge messages based on the new size
         // TODO: if replication_factor is changed, we need to do `something`
@@ -232,14 +297,16 @@ impl System {
             })
     }
 
+    /// Deletes the topic, or if the deletion is deferred, only marks it as being deleted if the consumer groups haven't consumed
+    /// all of its messages yet, in which case it's removed once they do or the grace period elapses.
     pub async fn delete_topic(
         &mut self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
+        deferred: bool,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
-        let stream_id_value;
         {
             let topic = self
                 .find_topic(session, stream_id, topic_id)
@@ -247,7 +314,7 @@ impl System {
                     format!("{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id} in stream with ID: {stream_id}")
                 })?;
             self.permissioner.delete_topic(
-                session.get_user_id(),
+                session,
                 topic.stream_id,
                 topic.topic_id,
             ).with_error_context(|error| {
@@ -256,24 +323,102 @@ impl System {
                     session.get_user_id(),
                 )
             })?;
-            stream_id_value = topic.stream_id;
         }
 
+        let grace_period = self.config.topic.deletion_grace_period;
+        if deferred && !grace_period.is_zero() {
+            let now = self.clock.now();
+            let topic = self
+                .get_stream_mut(stream_id)?
+                .get_topic_mut(topic_id)
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to get topic with ID: {topic_id} in stream with ID: {stream_id}")
+                })?;
+            topic.mark_as_deleting(now, grace_period);
+            if !topic.is_deletion_due(now).await {
+                return Ok(());
+            }
+        }
+
+        self.remove_topic(stream_id, topic_id).await
+    }
+
+    /// Checks whether the deletion of the topic has already been requested, so that it's stored in the state only once.
+    pub fn is_topic_being_deleted(&self, stream_id: &Identifier, topic_id: &Identifier) -> bool {
+        self.get_stream(stream_id)
+            .and_then(|stream| stream.get_topic(topic_id))
+            .is_ok_and(|topic| topic.is_deleting())
+    }
+
+    pub fn has_topics_being_deleted(&self) -> bool {
+        self.streams
+            .values()
+            .any(|stream| stream.get_topics().iter().any(|topic| topic.is_deleting()))
+    }
+
+    /// Removes the topics whose pending deletion is due.
+    pub async fn remove_deleted_topics(&mut self) {
+        let now = self.clock.now();
+        let mut deleted_topics = Vec::new();
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                if topic.is_deletion_due(now).await {
+                    deleted_topics.push((stream.stream_id, topic.topic_id));
+                }
+            }
+        }
+
+        for (stream_id, topic_id) in deleted_topics {
+            let (Ok(stream_id), Ok(topic_id)) = (
+                Identifier::numeric(stream_id),
+                Identifier::numeric(topic_id),
+            ) else {
+                continue;
+            };
+
+            match self.remove_topic(&stream_id, &topic_id).await {
+                Ok(()) => info!(
+                    "Completed deletion of topic with ID: {topic_id} in stream with ID: {stream_id}."
+                ),
+                Err(error) => error!(
+                    "Failed to complete deletion of topic with ID: {topic_id} in stream with ID: {stream_id}. Error: {error}"
+                ),
+            }
+        }
+    }
+
+    async fn remove_topic(
+        &mut self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
         let topic = self
             .get_stream_mut(stream_id)?
             .delete_topic(topic_id)
             .await
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete topic with ID: {topic_id} in stream with ID: {stream_id}"))?;
 
+        self.permissioner
+            .unregister_topic(topic.stream_id, topic.topic_id);
+        self.quotas.remove(&QuotaPrincipal::Topic {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+        });
+        self.storage_usage
+            .remove_topic(topic.stream_id, topic.topic_id);
         self.metrics.decrement_topics(1);
         self.metrics
             .decrement_partitions(topic.get_partitions_count());
         self.metrics.decrement_messages(topic.get_messages_count());
         self.metrics
             .decrement_segments(topic.get_segments_count().await);
+        self.metrics.remove_topic(&TopicLabels {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+        });
         let client_manager = self.client_manager.read().await;
         client_manager
-            .delete_consumer_groups_for_topic(stream_id_value, topic.topic_id)
+            .delete_consumer_groups_for_topic(topic.stream_id, topic.topic_id)
             .await;
         Ok(())
     }
@@ -290,7 +435,7 @@ impl System {
                 format!("{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id} in stream with ID: {stream_id}")
             })?;
         self.permissioner
-            .purge_topic(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .purge_topic(session, topic.stream_id, topic.topic_id)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to purge topic with ID: {topic_id} in stream with ID: {stream_id} for user with ID: {}",
@@ -301,4 +446,85 @@ impl System {
             format!("{COMPONENT} (error: {error}) - failed to purge topic with ID: {topic_id} in stream with ID: {stream_id}")
         })
     }
+
+    pub fn set_topic_throttle(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        max_throughput: Option<IggyByteSize>,
+        duration: Option<IggyDuration>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .set_topic_throttle(session)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to set throttle of topic with ID: {topic_id} in stream with ID: {stream_id} for user with ID: {}",
+                    session.get_user_id(),
+                )
+            })?;
+        let topic = self
+            .find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to find topic with ID: {topic_id} in stream with ID: {stream_id}")
+            })?;
+        topic.set_throttle(max_throughput, duration, self.clock.now());
+        match max_throughput {
+            Some(max_throughput) => info!(
+                "Topic with ID: {} in stream with ID: {} has been throttled to max throughput: {}/s for: {}.",
+                topic.topic_id,
+                topic.stream_id,
+                max_throughput.as_human_string(),
+                duration.map_or("unlimited".to_string(), |duration| duration.as_human_time_string())
+            ),
+            None => info!(
+                "Throttle of topic with ID: {} in stream with ID: {} has been cleared.",
+                topic.topic_id, topic.stream_id
+            ),
+        }
+        Ok(())
+    }
+
+    fn validate_dead_letter_policy(
+        &self,
+        dead_letter_policy: &DeadLetterPolicy,
+        source_topic: Option<&Topic>,
+    ) -> Result<(), IggyError> {
+        let dead_letter_topic = self
+            .get_stream(&dead_letter_policy.stream_id)?
+            .get_topic(&dead_letter_policy.topic_id)?;
+        if let Some(source_topic) = source_topic {
+            if source_topic.stream_id == dead_letter_topic.stream_id
+                && source_topic.topic_id == dead_letter_topic.topic_id
+            {
+                return Err(IggyError::InvalidDeadLetterPolicy(
+                    "dead letter topic must be different from the source topic".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_sampling_policy(
+        &self,
+        sampling_policy: &MessageSamplingPolicy,
+        source_topic: Option<&Topic>,
+    ) -> Result<(), IggyError> {
+        let diagnostics_topic = self
+            .get_stream(&sampling_policy.stream_id)?
+            .get_topic(&sampling_policy.topic_id)?;
+        if let Some(source_topic) = source_topic {
+            if source_topic.stream_id == diagnostics_topic.stream_id
+                && source_topic.topic_id == diagnostics_topic.topic_id
+            {
+                return Err(IggyError::InvalidMessageSamplingPolicy(
+                    "diagnostics topic must be different from the source topic".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
 }
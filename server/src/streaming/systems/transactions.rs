@@ -0,0 +1,194 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::transactions::transaction_coordinator::TransactionOffset;
+use error_set::ErrContext;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::transaction::TransactionMarker;
+use tracing::{info, trace, warn};
+
+impl System {
+    pub fn begin_transaction(&self, session: &Session) -> Result<u64, IggyError> {
+        self.ensure_authenticated(session)?;
+        let transaction_id = self.transactions.begin(session.client_id, self.clock.now());
+        info!(
+            "Began transaction with ID: {transaction_id} for client with ID: {}.",
+            session.client_id
+        );
+        Ok(transaction_id)
+    }
+
+    pub async fn commit_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.authorize_transaction_topics(session, transaction_id)?;
+        self.complete_transaction(session.client_id, transaction_id, TransactionMarker::Commit)
+            .await
+    }
+
+    pub async fn abort_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.authorize_transaction_topics(session, transaction_id)?;
+        self.complete_transaction(session.client_id, transaction_id, TransactionMarker::Abort)
+            .await
+    }
+
+    /// Authorizes appending the transaction markers to all the topics written within the transaction.
+    fn authorize_transaction_topics(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), IggyError> {
+        for (stream_id, topic_id) in self
+            .transactions
+            .get_topics(transaction_id, session.client_id)?
+        {
+            self.permissioner
+                .append_messages(session, stream_id, topic_id)
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - permission denied to complete transaction: {transaction_id} for user with ID: {} in topic: {topic_id}, stream: {stream_id}", session.get_user_id()))?;
+        }
+        Ok(())
+    }
+
+    /// Sends the consumer offset to the transaction, so that it's stored only once the transaction is committed.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn send_offsets_to_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - topic with ID: {topic_id} was not found in stream with ID: {stream_id}"))?;
+        self.permissioner
+            .store_consumer_offset(session, topic.stream_id, topic.topic_id)?;
+
+        let Some((polling_consumer, partition_id)) = topic
+            .resolve_consumer_with_partition_id(consumer, session.client_id, partition_id, None)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer with partition id, consumer: {consumer}, client ID: {}, partition ID: {:?}", session.client_id, partition_id))? else {
+            return Err(IggyError::ConsumerOffsetNotFound(session.client_id));
+        };
+
+        // Validate the offset upfront, as once the transaction is committed, its offsets can't be rejected anymore.
+        let partition = topic.get_partition(partition_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get partition with ID: {partition_id}")
+        })?;
+        if offset > partition.read().await.current_offset {
+            return Err(IggyError::InvalidOffset(offset));
+        }
+
+        self.transactions.register_offset(
+            transaction_id,
+            session.client_id,
+            TransactionOffset {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+                partition_id,
+                consumer: polling_consumer,
+                offset,
+            },
+        )?;
+        trace!("Sent offset: {offset} for {polling_consumer} in partition: {partition_id}, topic: {}, stream: {} to transaction with ID: {transaction_id}.", topic.topic_id, topic.stream_id);
+        Ok(())
+    }
+
+    /// Aborts the transactions left open by the disconnected client.
+    pub(crate) async fn abort_client_transactions(&self, client_id: u32) {
+        for transaction_id in self.transactions.get_client_transactions(client_id) {
+            if let Err(error) = self
+                .complete_transaction(client_id, transaction_id, TransactionMarker::Abort)
+                .await
+            {
+                warn!("Failed to abort transaction with ID: {transaction_id} for disconnected client with ID: {client_id}. {error}");
+            }
+        }
+    }
+
+    /// Appends the transaction markers to all the topics written within the transaction, and only then completes the transaction,
+    /// so that the messages become visible to the consumers of all the partitions at once.
+    /// The consumer offsets sent to the committed transaction are stored right after its commit markers, and discarded on abort.
+    async fn complete_transaction(
+        &self,
+        client_id: u32,
+        transaction_id: u64,
+        marker: TransactionMarker,
+    ) -> Result<(), IggyError> {
+        let topics = self.transactions.get_topics(transaction_id, client_id)?;
+        let offsets = self.transactions.get_offsets(transaction_id, client_id)?;
+        for (stream_id, topic_id) in topics {
+            let topic = match self
+                .get_stream(&Identifier::numeric(stream_id)?)
+                .and_then(|stream| stream.get_topic(&Identifier::numeric(topic_id)?))
+            {
+                Ok(topic) => topic,
+                Err(error) => {
+                    warn!("Topic with ID: {topic_id} for stream with ID: {stream_id} written within transaction: {transaction_id} was not found, skipping the {marker} marker. {error}");
+                    continue;
+                }
+            };
+
+            topic
+                .append_transaction_marker(transaction_id, marker, self.clock.now())
+                .await
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to append transaction: {transaction_id} {marker} marker for stream_id: {stream_id}, topic_id: {topic_id}"))?;
+        }
+
+        if marker == TransactionMarker::Commit {
+            // The commit markers are already appended, so the transaction can't be rolled back anymore.
+            for offset in offsets {
+                if let Err(error) = self.store_transaction_offset(&offset).await {
+                    warn!("Failed to store offset: {} for {} in partition: {}, topic: {}, stream: {} of transaction: {transaction_id}. {error}",
+                        offset.offset, offset.consumer, offset.partition_id, offset.topic_id, offset.stream_id);
+                }
+            }
+        }
+
+        self.transactions
+            .complete(transaction_id, client_id, marker)?;
+        info!("Completed transaction with ID: {transaction_id} with {marker} for client with ID: {client_id}.");
+        Ok(())
+    }
+
+    async fn store_transaction_offset(&self, offset: &TransactionOffset) -> Result<(), IggyError> {
+        let topic = self
+            .get_stream(&Identifier::numeric(offset.stream_id)?)?
+            .get_topic(&Identifier::numeric(offset.topic_id)?)?;
+        topic
+            .store_consumer_offset_internal(offset.consumer, offset.offset, offset.partition_id)
+            .await
+    }
+}
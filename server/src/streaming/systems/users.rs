@@ -21,16 +21,18 @@ use crate::state::models::CreateUserWithId;
 use crate::state::system::UserState;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::session::Session;
-use crate::streaming::systems::system::System;
+use crate::streaming::systems::system::{SharedSystem, System};
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::users::user::User;
 use crate::streaming::utils::crypto;
 use crate::{IGGY_ROOT_PASSWORD_ENV, IGGY_ROOT_USERNAME_ENV};
 use error_set::ErrContext;
+use iggy::command::{ABORT_TRANSACTION, COMMIT_TRANSACTION};
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
 use iggy::models::permissions::Permissions;
+use iggy::models::quota::QuotaPrincipal;
 use iggy::models::user_status::UserStatus;
 use iggy::users::create_user::CreateUser;
 use iggy::users::defaults::*;
@@ -44,7 +46,9 @@ const MAX_USERS: usize = u32::MAX as usize;
 impl System {
     pub(crate) async fn load_users(&mut self, users: Vec<UserState>) -> Result<(), IggyError> {
         info!("Loading users...");
-        if users.is_empty() {
+        if users.is_empty() && self.is_standby() {
+            info!("No users found, the root user will be synced from the primary server.");
+        } else if users.is_empty() {
             info!("No users found, creating the root user...");
             let root = Self::create_root_user();
             let command = CreateUserWithId {
@@ -90,6 +94,8 @@ impl System {
                             &token.name,
                             &token.token_hash,
                             token.expiry_at,
+                            token.scope,
+                            token.allowed_addresses,
                         ),
                     )
                 })
@@ -102,6 +108,13 @@ impl System {
         USER_ID.store(current_user_id + 1, Ordering::SeqCst);
         self.permissioner
             .init(&self.users.values().collect::<Vec<&User>>());
+        for user in self.users.values() {
+            let prefix = self
+                .tenants
+                .get_by_username(&user.username)
+                .map(|tenant| tenant.prefix.as_str());
+            self.permissioner.set_user_namespace(user.id, prefix);
+        }
         self.metrics.increment_users(users_count as u32);
         info!("Initialized {} user(s).", users_count);
         Ok(())
@@ -152,7 +165,7 @@ impl System {
 
         let session_user_id = session.get_user_id();
         if user.id != session_user_id {
-            self.permissioner.get_user(session_user_id).with_error_context(|error| {
+            self.permissioner.get_user(session).with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get user with ID: {user_id} for current user with ID: {session_user_id}"
                 )
@@ -201,7 +214,7 @@ impl System {
     pub async fn get_users(&self, session: &Session) -> Result<Vec<&User>, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .get_users(session.get_user_id())
+            .get_users(session)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to get users for user with id: {}",
@@ -221,7 +234,7 @@ impl System {
     ) -> Result<&User, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .create_user(session.get_user_id())
+            .create_user(session)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to create user for user with id: {}",
@@ -244,6 +257,7 @@ impl System {
         let user = User::new(user_id, username, password, status, permissions.clone());
         self.permissioner
             .init_permissions_for_user(user_id, permissions);
+        self.assign_user_tenant(user_id, username);
         self.users.insert(user.id, user);
         info!("Created user: {username} with ID: {user_id}.");
         self.metrics.increment_users(1);
@@ -253,6 +267,21 @@ impl System {
             })
     }
 
+    /// Adds the user replicated from the cluster leader, whose password is already hashed.
+    pub(crate) fn add_replicated_user(&mut self, user: User) {
+        let user_id = user.id;
+        USER_ID.fetch_max(user_id + 1, Ordering::SeqCst);
+        self.permissioner
+            .init_permissions_for_user(user_id, user.permissions.clone());
+        self.assign_user_tenant(user_id, &user.username);
+        info!(
+            "Added replicated user: {} with ID: {user_id}.",
+            user.username
+        );
+        self.users.insert(user_id, user);
+        self.metrics.increment_users(1);
+    }
+
     pub async fn delete_user(
         &mut self,
         session: &Session,
@@ -263,7 +292,7 @@ impl System {
         let existing_username;
         {
             self.permissioner
-                .delete_user(session.get_user_id())
+                .delete_user(session)
                 .with_error_context(|error| {
                     format!(
                         "{COMPONENT} (error: {error}) - permission denied to delete user for user with id: {}",
@@ -289,6 +318,9 @@ impl System {
             .ok_or(IggyError::ResourceNotFound(user_id.to_string()))?;
         self.permissioner
             .delete_permissions_for_user(existing_user_id);
+        self.quotas.remove(&QuotaPrincipal::User {
+            user_id: existing_user_id,
+        });
         let mut client_manager = self.client_manager.write().await;
         client_manager
             .delete_clients_for_user(existing_user_id)
@@ -312,7 +344,7 @@ impl System {
     ) -> Result<&User, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner
-            .update_user(session.get_user_id())
+            .update_user(session)
             .with_error_context(|error| {
                 format!(
                     "{COMPONENT} (error: {error}) - permission denied to update user for user with id: {}",
@@ -332,7 +364,7 @@ impl System {
         let user = self.get_user_mut(user_id).with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
         })?;
-        if let Some(username) = username {
+        if let Some(username) = username.clone() {
             user.username = username;
         }
 
@@ -340,8 +372,12 @@ impl System {
             user.status = status;
         }
 
+        let updated_user_id = user.id;
         info!("Updated user: {} with ID: {}.", user.username, user.id);
-        Ok(user)
+        if let Some(username) = username {
+            self.assign_user_tenant(updated_user_id, &username);
+        }
+        self.get_user(&Identifier::numeric(updated_user_id)?)
     }
 
     pub async fn update_permissions(
@@ -354,7 +390,7 @@ impl System {
 
         {
             self.permissioner
-                .update_permissions(session.get_user_id())
+                .update_permissions(session)
                 .with_error_context(|error| {
                     format!(
                         "{COMPONENT} (error: {error}) - permission denied to update permissions for user with id: {}", session.get_user_id()
@@ -403,7 +439,7 @@ impl System {
             })?;
             let session_user_id = session.get_user_id();
             if user.id != session_user_id {
-                self.permissioner.change_password(session_user_id)?;
+                self.permissioner.change_password(session)?;
             }
         }
 
@@ -436,6 +472,25 @@ impl System {
             .await
     }
 
+    /// Logs in the user mapped from the client certificate, already verified during the TLS handshake.
+    pub async fn login_user_with_certificate(
+        &self,
+        certificate: &[u8],
+        session: &Session,
+    ) -> Result<&User, IggyError> {
+        let username = self
+            .certificate_auth
+            .authenticate(certificate)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to authenticate client certificate, client ID: {}",
+                    session.client_id
+                )
+            })?;
+        self.login_user_with_credentials(&username, None, Some(session))
+            .await
+    }
+
     pub async fn login_user_with_credentials(
         &self,
         username: &str,
@@ -466,6 +521,20 @@ impl System {
             }
         }
 
+        if !self.client_access.is_user_allowed(username) {
+            warn!(
+                "User: {username} with ID: {} is not allowed to log in.",
+                user.id
+            );
+            return Err(IggyError::ClientAccessDenied);
+        }
+
+        if let Some(session) = session {
+            if !self.is_client_address_allowed(&session.ip_address) {
+                return Err(IggyError::ClientAccessDenied);
+            }
+        }
+
         info!("Logged in user: {username} with ID: {}.", user.id);
         if session.is_none() {
             return Ok(user);
@@ -521,3 +590,63 @@ impl System {
         Ok(())
     }
 }
+
+impl SharedSystem {
+    /// Resolves the decisions of the external authorizer (if any) required to perform the action (or handle the command)
+    /// on the stream and topic, without holding the system lock while they're being made, and stores them on the session,
+    /// so that the command only checks them once it takes the lock.
+    pub async fn resolve_authorization(
+        &self,
+        session: &Session,
+        action: &'static str,
+        stream_id: Option<&Identifier>,
+        topic_id: Option<&Identifier>,
+    ) {
+        let (authorizer, requests) = {
+            let system = self.read().await;
+            let Some(authorizer) = system.permissioner.external_authorizer() else {
+                return;
+            };
+            if !session.is_authenticated() {
+                return;
+            }
+
+            let user_id = session.get_user_id();
+            let requests = if matches!(action, COMMIT_TRANSACTION | ABORT_TRANSACTION) {
+                // The transaction markers are appended to all the topics written within the open transactions of the client.
+                system
+                    .transactions
+                    .get_client_transactions(session.client_id)
+                    .into_iter()
+                    .flat_map(|id| {
+                        system
+                            .transactions
+                            .get_topics(id, session.client_id)
+                            .unwrap_or_default()
+                    })
+                    .flat_map(|(stream_id, topic_id)| {
+                        system.permissioner.authorization_requests(
+                            user_id,
+                            action,
+                            Some(stream_id),
+                            Some(topic_id),
+                        )
+                    })
+                    .collect()
+            } else {
+                let stream = stream_id.and_then(|stream_id| system.get_stream(stream_id).ok());
+                let topic = stream
+                    .zip(topic_id)
+                    .and_then(|(stream, topic_id)| stream.get_topic(topic_id).ok());
+                system.permissioner.authorization_requests(
+                    user_id,
+                    action,
+                    stream.map(|stream| stream.stream_id),
+                    topic.map(|topic| topic.topic_id),
+                )
+            };
+            (authorizer, requests)
+        };
+        authorizer.resolve(session, &requests).await;
+    }
+}
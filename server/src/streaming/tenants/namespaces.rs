@@ -0,0 +1,148 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::users::authorizers::AuthorizationRequest;
+use ahash::AHashMap;
+use iggy::error::IggyError;
+use iggy::models::user_info::UserId;
+use tracing::debug;
+
+/// The namespaces the users of the tenants are confined to, verified for every action on the stream before it's passed to the authorizer.
+/// The names of the streams are tracked here as well, as the actions refer to the streams by their IDs.
+#[derive(Debug, Default)]
+pub struct TenantNamespaces {
+    users: AHashMap<UserId, String>,
+    streams: AHashMap<u32, String>,
+}
+
+impl TenantNamespaces {
+    /// Confines the user to the streams named with the given prefix, or lifts the confinement if there's no prefix.
+    pub fn set_user_prefix(&mut self, user_id: UserId, prefix: Option<&str>) {
+        match prefix {
+            Some(prefix) => {
+                self.users.insert(user_id, prefix.to_owned());
+            }
+            None => {
+                self.users.remove(&user_id);
+            }
+        }
+    }
+
+    pub fn register_stream(&mut self, stream_id: u32, name: &str) {
+        self.streams.insert(stream_id, name.to_owned());
+    }
+
+    pub fn unregister_stream(&mut self, stream_id: u32) {
+        self.streams.remove(&stream_id);
+    }
+
+    pub fn is_stream_visible(&self, user_id: UserId, stream_id: u32) -> bool {
+        let Some(prefix) = self.users.get(&user_id) else {
+            return true;
+        };
+
+        self.streams
+            .get(&stream_id)
+            .is_some_and(|name| name.starts_with(prefix.as_str()))
+    }
+
+    /// Verifies if the stream with the given name can be created (or the existing one renamed to it) by the user.
+    pub fn authorize_stream_name(&self, user_id: UserId, name: &str) -> Result<(), IggyError> {
+        match self.users.get(&user_id) {
+            Some(prefix) if !name.starts_with(prefix.as_str()) => {
+                debug!("Stream: {name} is outside of the namespace: {prefix} of user with ID: {user_id}.");
+                Err(IggyError::TenantNamespaceViolation(name.to_owned()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn authorize(&self, request: &AuthorizationRequest) -> Result<(), IggyError> {
+        let Some(stream_id) = request.stream_id else {
+            return Ok(());
+        };
+
+        if self.is_stream_visible(request.user_id, stream_id) {
+            return Ok(());
+        }
+
+        let name = self
+            .streams
+            .get(&stream_id)
+            .cloned()
+            .unwrap_or_else(|| stream_id.to_string());
+        Err(IggyError::TenantNamespaceViolation(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::command::{CREATE_TOPIC, GET_STREAMS, POLL_MESSAGES};
+
+    fn namespaces() -> TenantNamespaces {
+        let mut namespaces = TenantNamespaces::default();
+        namespaces.register_stream(1, "acme.orders");
+        namespaces.register_stream(2, "globex.orders");
+        namespaces.set_user_prefix(10, Some("acme."));
+        namespaces
+    }
+
+    #[test]
+    fn confined_user_should_access_only_streams_in_namespace() {
+        let namespaces = namespaces();
+        assert!(namespaces
+            .authorize(&AuthorizationRequest::topic(10, POLL_MESSAGES, 1, 1))
+            .is_ok());
+        assert!(matches!(
+            namespaces.authorize(&AuthorizationRequest::stream(10, CREATE_TOPIC, 2)),
+            Err(IggyError::TenantNamespaceViolation(name)) if name == "globex.orders"
+        ));
+        assert!(namespaces
+            .authorize(&AuthorizationRequest::new(10, GET_STREAMS))
+            .is_ok());
+        assert!(namespaces.is_stream_visible(10, 1));
+        assert!(!namespaces.is_stream_visible(10, 2));
+    }
+
+    #[test]
+    fn user_without_tenant_should_access_all_streams() {
+        let mut namespaces = namespaces();
+        assert!(namespaces
+            .authorize(&AuthorizationRequest::stream(20, CREATE_TOPIC, 2))
+            .is_ok());
+        assert!(namespaces
+            .authorize_stream_name(20, "globex.payments")
+            .is_ok());
+
+        namespaces.set_user_prefix(10, None);
+        assert!(namespaces.is_stream_visible(10, 2));
+    }
+
+    #[test]
+    fn confined_user_should_create_streams_only_with_prefix() {
+        let namespaces = namespaces();
+        assert!(namespaces
+            .authorize_stream_name(10, "acme.payments")
+            .is_ok());
+        assert!(matches!(
+            namespaces.authorize_stream_name(10, "payments"),
+            Err(IggyError::TenantNamespaceViolation(_))
+        ));
+    }
+}
@@ -0,0 +1,223 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::TenancyConfig;
+use crate::streaming::topics::throttling::TopicThrottle;
+use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// The tenants configured for the server. The streams belong to the tenant by the prefix of their names,
+/// while the users belong to it by their usernames, and are confined to its namespace.
+#[derive(Debug, Default)]
+pub struct TenantRegistry {
+    enabled: bool,
+    tenants: Vec<Tenant>,
+}
+
+/// The limits shared by all the streams of the tenant, where `0` means unlimited.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize)]
+pub struct TenantQuotas {
+    pub max_streams: u32,
+    pub max_topics: u32,
+    pub max_partitions: u32,
+    pub max_storage_bytes: u64,
+    pub max_throughput_bytes: u64,
+}
+
+#[derive(Debug)]
+pub struct Tenant {
+    pub name: String,
+    pub prefix: String,
+    pub users: Vec<String>,
+    pub quotas: TenantQuotas,
+    throttle: Mutex<Option<TopicThrottle>>,
+    appended_bytes: AtomicU64,
+    throttled_batches: AtomicU64,
+}
+
+/// The resources used by the tenant, compared to its quotas.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TenantUsage {
+    pub name: String,
+    pub prefix: String,
+    pub users: Vec<String>,
+    pub streams: u32,
+    pub topics: u32,
+    pub partitions: u32,
+    pub size_bytes: u64,
+    pub appended_bytes: u64,
+    pub throttled_batches: u64,
+    pub quotas: TenantQuotas,
+}
+
+impl TenantRegistry {
+    pub fn from_config(config: &TenancyConfig) -> Self {
+        let mut tenants = config
+            .tenants
+            .iter()
+            .map(|(name, tenant)| Tenant {
+                name: name.clone(),
+                prefix: tenant.prefix.clone(),
+                users: tenant.users.clone(),
+                quotas: TenantQuotas {
+                    max_streams: tenant.max_streams,
+                    max_topics: tenant.max_topics,
+                    max_partitions: tenant.max_partitions,
+                    max_storage_bytes: tenant.max_storage.as_bytes_u64(),
+                    max_throughput_bytes: tenant.max_throughput.as_bytes_u64(),
+                },
+                throttle: Mutex::new(None),
+                appended_bytes: AtomicU64::new(0),
+                throttled_batches: AtomicU64::new(0),
+            })
+            .collect::<Vec<_>>();
+        tenants.sort_by(|first, second| first.name.cmp(&second.name));
+        Self {
+            enabled: config.enabled,
+            tenants,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn tenants(&self) -> &[Tenant] {
+        if !self.enabled {
+            return &[];
+        }
+
+        &self.tenants
+    }
+
+    pub fn get_by_username(&self, username: &str) -> Option<&Tenant> {
+        self.tenants()
+            .iter()
+            .find(|tenant| tenant.users.iter().any(|user| user == username))
+    }
+
+    /// Returns the tenant owning the stream, the one with the longest matching prefix if there are many.
+    pub fn get_by_stream(&self, name: &str) -> Option<&Tenant> {
+        self.tenants()
+            .iter()
+            .filter(|tenant| tenant.contains_stream(name))
+            .max_by_key(|tenant| tenant.prefix.len())
+    }
+}
+
+impl Tenant {
+    pub fn contains_stream(&self, name: &str) -> bool {
+        name.starts_with(&self.prefix)
+    }
+
+    pub fn appended_bytes(&self) -> u64 {
+        self.appended_bytes.load(Ordering::Relaxed)
+    }
+
+    pub fn throttled_batches(&self) -> u64 {
+        self.throttled_batches.load(Ordering::Relaxed)
+    }
+
+    /// Consumes the size of the appended messages from the throughput quota of the tenant, shared by all its streams.
+    pub fn throttle(&self, size_bytes: u64, now: IggyTimestamp) -> Result<(), IggyError> {
+        if self.quotas.max_throughput_bytes > 0 {
+            let mut throttle = self.throttle.lock().unwrap();
+            let throttle = throttle.get_or_insert_with(|| {
+                TopicThrottle::new(self.quotas.max_throughput_bytes.into(), None, now)
+            });
+            if !throttle.try_consume(size_bytes, now) {
+                self.throttled_batches.fetch_add(1, Ordering::Relaxed);
+                return Err(IggyError::TenantThroughputQuotaExceeded(self.name.clone()));
+            }
+        }
+
+        self.appended_bytes.fetch_add(size_bytes, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::TenantConfig;
+    use iggy::utils::byte_size::IggyByteSize;
+    use std::collections::HashMap;
+
+    fn tenant_config(prefix: &str, users: &[&str]) -> TenantConfig {
+        TenantConfig {
+            prefix: prefix.to_owned(),
+            users: users.iter().map(|user| user.to_string()).collect(),
+            max_streams: 0,
+            max_topics: 0,
+            max_partitions: 0,
+            max_storage: IggyByteSize::default(),
+            max_throughput: IggyByteSize::from(1000),
+        }
+    }
+
+    fn registry(enabled: bool) -> TenantRegistry {
+        TenantRegistry::from_config(&TenancyConfig {
+            enabled,
+            tenants: HashMap::from([
+                ("acme".to_owned(), tenant_config("acme.", &["alice"])),
+                ("acme-eu".to_owned(), tenant_config("acme.eu.", &["bob"])),
+            ]),
+        })
+    }
+
+    #[test]
+    fn tenants_should_be_resolved_by_username_and_stream_prefix() {
+        let registry = registry(true);
+        assert_eq!(registry.get_by_username("alice").unwrap().name, "acme");
+        assert!(registry.get_by_username("carol").is_none());
+        assert_eq!(registry.get_by_stream("acme.orders").unwrap().name, "acme");
+        assert_eq!(
+            registry.get_by_stream("acme.eu.orders").unwrap().name,
+            "acme-eu"
+        );
+        assert!(registry.get_by_stream("orders").is_none());
+    }
+
+    #[test]
+    fn tenants_should_be_ignored_when_disabled() {
+        let registry = registry(false);
+        assert!(registry.tenants().is_empty());
+        assert!(registry.get_by_username("alice").is_none());
+        assert!(registry.get_by_stream("acme.orders").is_none());
+    }
+
+    #[test]
+    fn appends_exceeding_throughput_quota_should_be_rejected() {
+        let registry = registry(true);
+        let tenant = registry.get_by_username("alice").unwrap();
+        let now = IggyTimestamp::from(1_000_000);
+        assert!(tenant.throttle(1500, now).is_ok());
+        assert!(matches!(
+            tenant.throttle(100, now),
+            Err(IggyError::TenantThroughputQuotaExceeded(name)) if name == "acme"
+        ));
+        assert_eq!(tenant.appended_bytes(), 1500);
+        assert_eq!(tenant.throttled_batches(), 1);
+
+        assert!(tenant.throttle(100, IggyTimestamp::from(2_000_000)).is_ok());
+        assert_eq!(tenant.appended_bytes(), 1600);
+    }
+}
@@ -0,0 +1,251 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::topics::COMPONENT;
+use bytes::{BufMut, BytesMut};
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::bookmark::Bookmark;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::path::Path;
+use std::str::from_utf8;
+use tokio::fs;
+use tracing::{info, trace};
+
+const BOOKMARK_HEADER_SIZE: usize = 29;
+
+impl Topic {
+    pub fn get_bookmark(&self, name: &str) -> Result<Bookmark, IggyError> {
+        self.bookmarks
+            .get(name)
+            .map(|bookmark| bookmark.clone())
+            .ok_or_else(|| {
+                IggyError::BookmarkNotFound(name.to_owned(), self.topic_id, self.stream_id)
+            })
+    }
+
+    pub fn get_bookmarks(&self) -> Vec<Bookmark> {
+        let mut bookmarks = self
+            .bookmarks
+            .iter()
+            .map(|bookmark| bookmark.value().clone())
+            .collect::<Vec<_>>();
+        bookmarks.sort_by(|x, y| x.name.cmp(&y.name));
+        bookmarks
+    }
+
+    /// Creates the bookmark or moves the existing one to the given position.
+    /// The offset points to the next message to be polled, thus it can be at most one past the current partition offset.
+    pub async fn store_bookmark(
+        &self,
+        name: &str,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        let partition = self.get_partition(partition_id).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get partition with ID: {partition_id} for bookmark: {name}"
+            )
+        })?;
+        let current_offset = partition.read().await.current_offset;
+        if offset > current_offset + 1 {
+            return Err(IggyError::InvalidOffset(offset));
+        }
+
+        let now = IggyTimestamp::now();
+        self.bookmarks
+            .entry(name.to_owned())
+            .and_modify(|bookmark| {
+                bookmark.partition_id = partition_id;
+                bookmark.offset = offset;
+                bookmark.updated_at = now;
+            })
+            .or_insert_with(|| Bookmark {
+                name: name.to_owned(),
+                partition_id,
+                offset,
+                created_at: now,
+                updated_at: now,
+            });
+        self.persist_bookmarks().await?;
+        trace!(
+            "Stored bookmark: {name} at offset: {offset} in partition with ID: {partition_id} for topic with ID: {} and stream with ID: {}",
+            self.topic_id,
+            self.stream_id
+        );
+        Ok(())
+    }
+
+    pub async fn delete_bookmark(&self, name: &str) -> Result<(), IggyError> {
+        if self.bookmarks.remove(name).is_none() {
+            return Err(IggyError::BookmarkNotFound(
+                name.to_owned(),
+                self.topic_id,
+                self.stream_id,
+            ));
+        }
+
+        self.persist_bookmarks().await
+    }
+
+    pub(crate) async fn load_bookmarks(&self) -> Result<(), IggyError> {
+        if !Path::new(&self.bookmarks_path).exists() {
+            return Ok(());
+        }
+
+        let bytes = fs::read(&self.bookmarks_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read bookmarks from path: {}",
+                    self.bookmarks_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        for bookmark in read_bookmarks(&bytes)? {
+            if self.partitions.contains_key(&bookmark.partition_id) {
+                self.bookmarks.insert(bookmark.name.clone(), bookmark);
+            }
+        }
+
+        info!(
+            "Loaded {} bookmarks for topic with ID: {} and stream with ID: {}.",
+            self.bookmarks.len(),
+            self.topic_id,
+            self.stream_id
+        );
+        Ok(())
+    }
+
+    async fn persist_bookmarks(&self) -> Result<(), IggyError> {
+        self.storage
+            .persister
+            .overwrite(&self.bookmarks_path, &map_bookmarks(&self.get_bookmarks()))
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to persist bookmarks for topic with ID: {} and stream with ID: {}",
+                    self.topic_id, self.stream_id
+                )
+            })
+    }
+}
+
+fn map_bookmarks(bookmarks: &[Bookmark]) -> BytesMut {
+    let mut bytes = BytesMut::new();
+    for bookmark in bookmarks {
+        bytes.put_u32_le(bookmark.partition_id);
+        bytes.put_u64_le(bookmark.offset);
+        bytes.put_u64_le(bookmark.created_at.into());
+        bytes.put_u64_le(bookmark.updated_at.into());
+        bytes.put_u8(bookmark.name.len() as u8);
+        bytes.put_slice(bookmark.name.as_bytes());
+    }
+    bytes
+}
+
+fn read_bookmarks(bytes: &[u8]) -> Result<Vec<Bookmark>, IggyError> {
+    let mut bookmarks = Vec::new();
+    let mut position = 0;
+    while position + BOOKMARK_HEADER_SIZE <= bytes.len() {
+        let partition_id = u32::from_le_bytes(
+            bytes[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let offset = u64::from_le_bytes(
+            bytes[position + 4..position + 12]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let created_at = u64::from_le_bytes(
+            bytes[position + 12..position + 20]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let updated_at = u64::from_le_bytes(
+            bytes[position + 20..position + 28]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let name_length = bytes[position + 28] as usize;
+        let name_position = position + BOOKMARK_HEADER_SIZE;
+        if name_position + name_length > bytes.len() {
+            return Err(IggyError::InvalidBookmarkName);
+        }
+
+        let name = from_utf8(&bytes[name_position..name_position + name_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        bookmarks.push(Bookmark {
+            name,
+            partition_id,
+            offset,
+            created_at: created_at.into(),
+            updated_at: updated_at.into(),
+        });
+        position = name_position + name_length;
+    }
+    Ok(bookmarks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bookmarks_should_be_written_and_read() {
+        let bookmarks = vec![
+            Bookmark {
+                name: "audit".to_string(),
+                partition_id: 1,
+                offset: 42,
+                created_at: 1.into(),
+                updated_at: 2.into(),
+            },
+            Bookmark {
+                name: "replay".to_string(),
+                partition_id: 3,
+                offset: 0,
+                created_at: 3.into(),
+                updated_at: 3.into(),
+            },
+        ];
+
+        let bytes = map_bookmarks(&bookmarks);
+        let read_bookmarks = read_bookmarks(&bytes).unwrap();
+
+        assert_eq!(read_bookmarks, bookmarks);
+    }
+
+    #[test]
+    fn bookmarks_with_truncated_name_should_not_be_read() {
+        let mut bytes = map_bookmarks(&[Bookmark {
+            name: "audit".to_string(),
+            partition_id: 1,
+            offset: 42,
+            created_at: 1.into(),
+            updated_at: 2.into(),
+        }]);
+        bytes.truncate(bytes.len() - 1);
+
+        assert!(read_bookmarks(&bytes).is_err());
+    }
+}
@@ -16,10 +16,15 @@
  * under the License.
  */
 
+use crate::streaming::topics::consumer_group_assignment::get_assignor;
 use ahash::AHashMap;
+use iggy::consumer_groups::assignment_strategy::AssignmentStrategy;
 use iggy::error::IggyError;
-use tokio::sync::RwLock;
-use tracing::trace;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tokio::sync::{watch, RwLock};
+use tracing::{info, trace};
 
 #[derive(Debug)]
 pub struct ConsumerGroup {
@@ -27,7 +32,17 @@ pub struct ConsumerGroup {
     pub group_id: u32,
     pub name: String,
     pub partitions_count: u32,
+    pub assignment_strategy: AssignmentStrategy,
+    /// Whether the topic is being deleted, so the members should consume the remaining messages before it's removed.
+    pub topic_deleting: bool,
+    generation: AtomicU32,
     members: AHashMap<u32, RwLock<ConsumerGroupMember>>,
+    // Partition ID -> (previous owner ID, new owner ID), used by the cooperative sticky strategy.
+    pending_moves: RwLock<AHashMap<u32, (u32, u32)>>,
+    rebalance_delay: IggyDuration,
+    pending_rebalance_at: Option<IggyTimestamp>,
+    // Notified with the new generation, so that the members can be informed about their current assignment.
+    rebalances: watch::Sender<u32>,
 }
 
 #[derive(Debug)]
@@ -36,32 +51,90 @@ pub struct ConsumerGroupMember {
     partitions: AHashMap<u32, u32>,
     current_partition_index: Option<u32>,
     current_partition_id: Option<u32>,
+    last_heartbeat: IggyTimestamp,
 }
 
 impl ConsumerGroup {
-    pub fn new(topic_id: u32, group_id: u32, name: &str, partitions_count: u32) -> ConsumerGroup {
+    pub fn new(
+        topic_id: u32,
+        group_id: u32,
+        name: &str,
+        partitions_count: u32,
+        assignment_strategy: AssignmentStrategy,
+    ) -> ConsumerGroup {
         ConsumerGroup {
             topic_id,
             group_id,
             name: name.to_string(),
             partitions_count,
+            assignment_strategy,
+            topic_deleting: false,
+            generation: AtomicU32::new(0),
             members: AHashMap::new(),
+            pending_moves: RwLock::new(AHashMap::new()),
+            rebalance_delay: IggyDuration::default(),
+            pending_rebalance_at: None,
+            rebalances: watch::channel(0).0,
         }
     }
 
+    /// Delays the rebalance after the members have joined or left the group, so that the subsequent changes are handled at once.
+    pub fn with_rebalance_delay(self, rebalance_delay: IggyDuration) -> Self {
+        Self {
+            rebalance_delay,
+            ..self
+        }
+    }
+
+    pub fn generation(&self) -> u32 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    /// The strategy is chosen (and persisted) when creating the group, so the member requesting a different one can't join it.
+    pub fn ensure_assignment_strategy(
+        &self,
+        assignment_strategy: AssignmentStrategy,
+    ) -> Result<(), IggyError> {
+        if self.assignment_strategy == assignment_strategy {
+            return Ok(());
+        }
+
+        Err(IggyError::ConsumerGroupAssignmentStrategyMismatch(
+            self.group_id,
+            self.assignment_strategy.to_string(),
+            assignment_strategy.to_string(),
+        ))
+    }
+
     pub fn get_members(&self) -> Vec<&RwLock<ConsumerGroupMember>> {
         self.members.values().collect()
     }
 
+    pub async fn get_member_partitions(&self, member_id: u32) -> Option<Vec<u32>> {
+        let member = self.members.get(&member_id)?;
+        Some(member.read().await.get_partitions())
+    }
+
+    pub fn subscribe_rebalances(&self) -> watch::Receiver<u32> {
+        self.rebalances.subscribe()
+    }
+
     pub async fn reassign_partitions(&mut self, partitions_count: u32) {
         self.partitions_count = partitions_count;
         self.assign_partitions().await;
     }
 
-    pub async fn calculate_partition_id(&self, member_id: u32) -> Result<Option<u32>, IggyError> {
+    pub async fn calculate_partition_id(
+        &self,
+        member_id: u32,
+        now: IggyTimestamp,
+    ) -> Result<Option<u32>, IggyError> {
+        self.complete_pending_moves(member_id).await;
         let member = self.members.get(&member_id);
         if let Some(member) = member {
-            return Ok(member.write().await.calculate_partition_id());
+            let mut member = member.write().await;
+            member.last_heartbeat = now;
+            return Ok(member.calculate_partition_id());
         }
         Err(IggyError::ConsumerGroupMemberNotFound(
             member_id,
@@ -82,7 +155,42 @@ impl ConsumerGroup {
         ))
     }
 
-    pub async fn add_member(&mut self, member_id: u32) {
+    pub async fn heartbeat(&self, member_id: u32, now: IggyTimestamp) -> Result<(), IggyError> {
+        let member = self.members.get(&member_id);
+        if let Some(member) = member {
+            member.write().await.last_heartbeat = now;
+            return Ok(());
+        }
+        Err(IggyError::ConsumerGroupMemberNotFound(
+            member_id,
+            self.group_id,
+            self.topic_id,
+        ))
+    }
+
+    pub async fn get_expired_members(
+        &self,
+        now: IggyTimestamp,
+        session_timeout: IggyDuration,
+    ) -> Vec<u32> {
+        if session_timeout.is_zero() {
+            return Vec::new();
+        }
+
+        let mut expired_members = Vec::new();
+        for member in self.members.values() {
+            let member = member.read().await;
+            let elapsed = now
+                .as_micros()
+                .saturating_sub(member.last_heartbeat.as_micros());
+            if elapsed > session_timeout.as_micros() {
+                expired_members.push(member.id);
+            }
+        }
+        expired_members
+    }
+
+    pub async fn add_member(&mut self, member_id: u32, now: IggyTimestamp) {
         self.members.insert(
             member_id,
             RwLock::new(ConsumerGroupMember {
@@ -90,6 +198,7 @@ impl ConsumerGroup {
                 partitions: AHashMap::new(),
                 current_partition_index: None,
                 current_partition_id: None,
+                last_heartbeat: now,
             }),
         );
         trace!(
@@ -98,10 +207,10 @@ impl ConsumerGroup {
             self.group_id,
             self.topic_id
         );
-        self.assign_partitions().await;
+        self.request_rebalance(now).await;
     }
 
-    pub async fn delete_member(&mut self, member_id: u32) {
+    pub async fn delete_member(&mut self, member_id: u32, now: IggyTimestamp) {
         if self.members.remove(&member_id).is_some() {
             trace!(
                 "Deleted member with ID: {} in consumer group: {} for topic with ID: {}",
@@ -109,40 +218,158 @@ impl ConsumerGroup {
                 self.group_id,
                 self.topic_id
             );
+            self.request_rebalance(now).await;
+        }
+    }
+
+    pub async fn rebalance_if_due(&mut self, now: IggyTimestamp) -> bool {
+        let Some(rebalance_at) = self.pending_rebalance_at else {
+            return false;
+        };
+
+        if now.as_micros() < rebalance_at.as_micros() {
+            return false;
+        }
+
+        self.assign_partitions().await;
+        true
+    }
+
+    async fn request_rebalance(&mut self, now: IggyTimestamp) {
+        if self.rebalance_delay.is_zero() {
             self.assign_partitions().await;
+            return;
+        }
+
+        if self.pending_rebalance_at.is_some() {
+            return;
         }
+
+        // Until the delayed rebalance, the new members have no partitions assigned, and the partitions of the members that left are not consumed.
+        let rebalance_at = now.as_micros() + self.rebalance_delay.as_micros();
+        self.pending_rebalance_at = Some(rebalance_at.into());
+        trace!(
+            "Scheduled rebalance of consumer group: {} for topic with ID: {} in: {}",
+            self.group_id,
+            self.topic_id,
+            self.rebalance_delay
+        );
     }
 
     async fn assign_partitions(&mut self) {
-        let mut members = self.members.values_mut().collect::<Vec<_>>();
-        if members.is_empty() {
+        // Every rebalance starts a new generation, which allows the members to detect that their assignment might have changed.
+        let generation = self
+            .generation
+            .fetch_add(1, Ordering::SeqCst)
+            .wrapping_add(1);
+        info!(
+            "Rebalancing consumer group: {} for topic with ID: {} using: {} strategy, generation: {}, members: {}, partitions: {}",
+            self.group_id,
+            self.topic_id,
+            self.assignment_strategy,
+            generation,
+            self.members.len(),
+            self.partitions_count
+        );
+        // The partitions which were still being moved are unowned at this point, so they can be assigned right away.
+        self.pending_moves.get_mut().clear();
+        self.pending_rebalance_at = None;
+        if self.members.is_empty() {
             return;
         }
 
-        let members_count = members.len() as u32;
-        for member in members.iter_mut() {
-            let mut member = member.write().await;
+        let mut members_ids = self.members.keys().copied().collect::<Vec<_>>();
+        members_ids.sort_unstable();
+        let partitions_ids = (1..=self.partitions_count).collect::<Vec<_>>();
+        let mut current_assignment = AHashMap::with_capacity(members_ids.len());
+        for member in self.members.values_mut() {
+            let member = member.get_mut();
+            current_assignment.insert(member.id, member.get_partitions());
+        }
+
+        let mut assignment = get_assignor(self.assignment_strategy).assign(
+            &members_ids,
+            &partitions_ids,
+            &current_assignment,
+        );
+        if self.assignment_strategy == AssignmentStrategy::CooperativeSticky {
+            self.revoke_moved_partitions(&current_assignment, &mut assignment);
+        }
+
+        for member in self.members.values_mut() {
+            let member = member.get_mut();
             member.current_partition_index = None;
             member.current_partition_id = None;
             member.partitions.clear();
+            let Some(partitions) = assignment.remove(&member.id) else {
+                continue;
+            };
+
+            for partition_id in partitions {
+                member.assign_partition(partition_id);
+                trace!("Assigned partition ID: {} to member with ID: {} for topic with ID: {} in consumer group: {}",
+                    partition_id, member.id, self.topic_id, self.group_id)
+            }
         }
+        self.rebalances.send_replace(generation);
+    }
 
-        for partition_index in 0..self.partitions_count {
-            let partition_id = partition_index + 1;
-            let member_index = partition_index % members_count;
-            let member = members.get(member_index as usize).unwrap();
-            let mut member = member.write().await;
-            let member_partition_index = member.partitions.len() as u32;
-            member
-                .partitions
-                .insert(member_partition_index, partition_id);
-            if member.current_partition_id.is_none() {
-                member.current_partition_id = Some(partition_id);
-                member.current_partition_index = Some(member_partition_index);
+    fn revoke_moved_partitions(
+        &mut self,
+        current_assignment: &AHashMap<u32, Vec<u32>>,
+        assignment: &mut AHashMap<u32, Vec<u32>>,
+    ) {
+        let pending_moves = self.pending_moves.get_mut();
+        for (previous_owner_id, partitions) in current_assignment {
+            for partition_id in partitions {
+                let Some((new_owner_id, new_partitions)) = assignment
+                    .iter_mut()
+                    .find(|(_, new_partitions)| new_partitions.contains(partition_id))
+                else {
+                    continue;
+                };
+
+                if new_owner_id == previous_owner_id {
+                    continue;
+                }
+
+                new_partitions.retain(|id| id != partition_id);
+                pending_moves.insert(*partition_id, (*previous_owner_id, *new_owner_id));
+                trace!("Revoked partition ID: {} from member with ID: {} before assigning it to member with ID: {} for topic with ID: {} in consumer group: {}",
+                    partition_id, previous_owner_id, new_owner_id, self.topic_id, self.group_id)
+            }
+        }
+    }
+
+    // The previous owner polling again means that it has acknowledged the revocation, so the partitions can be handed over.
+    async fn complete_pending_moves(&self, member_id: u32) {
+        let mut pending_moves = self.pending_moves.write().await;
+        if pending_moves.is_empty() {
+            return;
+        }
+
+        let completed_moves = pending_moves
+            .iter()
+            .filter(|(_, (previous_owner_id, _))| *previous_owner_id == member_id)
+            .map(|(partition_id, (_, new_owner_id))| (*partition_id, *new_owner_id))
+            .collect::<Vec<_>>();
+        if completed_moves.is_empty() {
+            return;
+        }
+
+        for (partition_id, new_owner_id) in completed_moves {
+            pending_moves.remove(&partition_id);
+            if let Some(member) = self.members.get(&new_owner_id) {
+                member.write().await.assign_partition(partition_id);
+                trace!("Moved partition ID: {} from member with ID: {} to member with ID: {} for topic with ID: {} in consumer group: {}",
+                    partition_id, member_id, new_owner_id, self.topic_id, self.group_id)
             }
-            trace!("Assigned partition ID: {} to member with ID: {} for topic with ID: {} in consumer group: {}",
-                partition_id, member.id, self.topic_id, self.group_id)
         }
+        let generation = self
+            .generation
+            .fetch_add(1, Ordering::SeqCst)
+            .wrapping_add(1);
+        self.rebalances.send_replace(generation);
     }
 }
 
@@ -151,6 +378,15 @@ impl ConsumerGroupMember {
         self.partitions.values().copied().collect()
     }
 
+    fn assign_partition(&mut self, partition_id: u32) {
+        let partition_index = self.partitions.len() as u32;
+        self.partitions.insert(partition_index, partition_id);
+        if self.current_partition_id.is_none() {
+            self.current_partition_id = Some(partition_id);
+            self.current_partition_index = Some(partition_index);
+        }
+    }
+
     pub fn calculate_partition_id(&mut self) -> Option<u32> {
         let partition_index = self.current_partition_index?;
         let Some(partition_id) = self.partitions.get(&partition_index) else {
@@ -181,22 +417,20 @@ impl ConsumerGroupMember {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::streaming::utils::clock::{Clock, ManualClock};
 
     #[tokio::test]
     async fn should_calculate_partition_id_using_round_robin() {
         let member_id = 123;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            group_id: 1,
-            name: "test".to_string(),
-            partitions_count: 3,
-            members: AHashMap::new(),
-        };
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 3, AssignmentStrategy::RoundRobin);
 
-        consumer_group.add_member(member_id).await;
+        consumer_group
+            .add_member(member_id, IggyTimestamp::now())
+            .await;
         for i in 0..1000 {
             let partition_id = consumer_group
-                .calculate_partition_id(member_id)
+                .calculate_partition_id(member_id, IggyTimestamp::now())
                 .await
                 .unwrap()
                 .expect("Partition ID not found");
@@ -207,15 +441,12 @@ mod tests {
     #[tokio::test]
     async fn should_assign_all_partitions_to_the_only_single_member() {
         let member_id = 123;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            group_id: 1,
-            name: "test".to_string(),
-            partitions_count: 3,
-            members: AHashMap::new(),
-        };
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 3, AssignmentStrategy::RoundRobin);
 
-        consumer_group.add_member(member_id).await;
+        consumer_group
+            .add_member(member_id, IggyTimestamp::now())
+            .await;
         let member = consumer_group.members.get(&member_id).unwrap();
         let member = member.read().await;
         assert_eq!(
@@ -232,16 +463,15 @@ mod tests {
     async fn should_assign_partitions_to_the_multiple_members() {
         let member1_id = 123;
         let member2_id = 456;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            group_id: 1,
-            name: "test".to_string(),
-            partitions_count: 3,
-            members: AHashMap::new(),
-        };
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 3, AssignmentStrategy::RoundRobin);
 
-        consumer_group.add_member(member1_id).await;
-        consumer_group.add_member(member2_id).await;
+        consumer_group
+            .add_member(member1_id, IggyTimestamp::now())
+            .await;
+        consumer_group
+            .add_member(member2_id, IggyTimestamp::now())
+            .await;
         let member1 = consumer_group.members.get(&member1_id).unwrap();
         let member2 = consumer_group.members.get(&member2_id).unwrap();
         let member1 = member1.read().await;
@@ -269,16 +499,15 @@ mod tests {
     async fn should_assign_only_single_partition_to_the_only_single_member() {
         let member1_id = 123;
         let member2_id = 456;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            group_id: 1,
-            name: "test".to_string(),
-            partitions_count: 1,
-            members: AHashMap::new(),
-        };
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 1, AssignmentStrategy::RoundRobin);
 
-        consumer_group.add_member(member1_id).await;
-        consumer_group.add_member(member2_id).await;
+        consumer_group
+            .add_member(member1_id, IggyTimestamp::now())
+            .await;
+        consumer_group
+            .add_member(member2_id, IggyTimestamp::now())
+            .await;
         let member1 = consumer_group.members.get(&member1_id).unwrap();
         let member2 = consumer_group.members.get(&member2_id).unwrap();
         let member1 = member1.read().await;
@@ -290,4 +519,246 @@ mod tests {
             assert_eq!(member2.partitions.len(), 1);
         }
     }
+
+    #[tokio::test]
+    async fn should_start_new_generation_on_each_rebalance() {
+        let member1_id = 123;
+        let member2_id = 456;
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 3, AssignmentStrategy::default());
+        assert_eq!(consumer_group.generation(), 0);
+
+        consumer_group
+            .add_member(member1_id, IggyTimestamp::now())
+            .await;
+        assert_eq!(consumer_group.generation(), 1);
+        consumer_group
+            .add_member(member2_id, IggyTimestamp::now())
+            .await;
+        assert_eq!(consumer_group.generation(), 2);
+        consumer_group.reassign_partitions(5).await;
+        assert_eq!(consumer_group.generation(), 3);
+        consumer_group
+            .delete_member(member2_id, IggyTimestamp::now())
+            .await;
+        assert_eq!(consumer_group.generation(), 4);
+
+        let unknown_member_id = 789;
+        consumer_group
+            .delete_member(unknown_member_id, IggyTimestamp::now())
+            .await;
+        assert_eq!(consumer_group.generation(), 4);
+    }
+
+    #[tokio::test]
+    async fn should_notify_rebalance_with_new_generation() {
+        let member_id = 123;
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 3, AssignmentStrategy::default());
+        let mut rebalances = consumer_group.subscribe_rebalances();
+        assert!(!rebalances.has_changed().unwrap());
+
+        consumer_group
+            .add_member(member_id, IggyTimestamp::now())
+            .await;
+        assert!(rebalances.has_changed().unwrap());
+        assert_eq!(*rebalances.borrow_and_update(), consumer_group.generation());
+        assert_eq!(
+            consumer_group
+                .get_member_partitions(member_id)
+                .await
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn should_keep_partitions_of_existing_members_using_sticky_strategy() {
+        let member1_id = 123;
+        let member2_id = 456;
+        let member3_id = 789;
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 6, AssignmentStrategy::Sticky);
+
+        consumer_group
+            .add_member(member1_id, IggyTimestamp::now())
+            .await;
+        consumer_group
+            .add_member(member2_id, IggyTimestamp::now())
+            .await;
+        let partitions_before_join = consumer_group.members[&member1_id]
+            .read()
+            .await
+            .get_partitions();
+        assert_eq!(partitions_before_join.len(), 3);
+
+        consumer_group
+            .add_member(member3_id, IggyTimestamp::now())
+            .await;
+        let partitions_after_join = consumer_group.members[&member1_id]
+            .read()
+            .await
+            .get_partitions();
+        assert_eq!(partitions_after_join.len(), 2);
+        assert!(partitions_after_join
+            .iter()
+            .all(|id| partitions_before_join.contains(id)));
+
+        consumer_group
+            .delete_member(member3_id, IggyTimestamp::now())
+            .await;
+        let partitions_after_leave = consumer_group.members[&member1_id]
+            .read()
+            .await
+            .get_partitions();
+        assert_eq!(partitions_after_leave.len(), 3);
+        assert!(partitions_after_join
+            .iter()
+            .all(|id| partitions_after_leave.contains(id)));
+    }
+
+    #[tokio::test]
+    async fn should_move_partitions_once_revoked_using_cooperative_sticky_strategy() {
+        let member1_id = 123;
+        let member2_id = 456;
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, "test", 4, AssignmentStrategy::CooperativeSticky);
+
+        consumer_group
+            .add_member(member1_id, IggyTimestamp::now())
+            .await;
+        consumer_group
+            .add_member(member2_id, IggyTimestamp::now())
+            .await;
+        let generation = consumer_group.generation();
+        assert_eq!(
+            consumer_group.members[&member1_id]
+                .read()
+                .await
+                .partitions
+                .len(),
+            2
+        );
+        assert!(consumer_group.members[&member2_id]
+            .read()
+            .await
+            .partitions
+            .is_empty());
+        assert_eq!(
+            consumer_group
+                .calculate_partition_id(member2_id, IggyTimestamp::now())
+                .await
+                .unwrap(),
+            None
+        );
+
+        consumer_group
+            .calculate_partition_id(member1_id, IggyTimestamp::now())
+            .await
+            .unwrap();
+        assert_eq!(consumer_group.generation(), generation + 1);
+        assert_eq!(
+            consumer_group.members[&member2_id]
+                .read()
+                .await
+                .partitions
+                .len(),
+            2
+        );
+        assert!(consumer_group
+            .calculate_partition_id(member2_id, IggyTimestamp::now())
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn should_reject_assignment_strategy_other_than_chosen_when_creating_group() {
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 3, AssignmentStrategy::Range);
+        assert!(consumer_group
+            .ensure_assignment_strategy(AssignmentStrategy::Sticky)
+            .is_err());
+        consumer_group.add_member(123, IggyTimestamp::now()).await;
+        assert!(consumer_group
+            .ensure_assignment_strategy(AssignmentStrategy::Range)
+            .is_ok());
+        assert!(consumer_group
+            .ensure_assignment_strategy(AssignmentStrategy::Sticky)
+            .is_err());
+        assert_eq!(
+            consumer_group.assignment_strategy,
+            AssignmentStrategy::Range
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_expired_members_only_when_session_timeout_is_exceeded() {
+        let member1_id = 123;
+        let member2_id = 456;
+        let clock = ManualClock::new(IggyTimestamp::from(1_000_000));
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 3, AssignmentStrategy::default());
+        consumer_group.add_member(member1_id, clock.now()).await;
+        consumer_group.add_member(member2_id, clock.now()).await;
+        let session_timeout = IggyDuration::ONE_SECOND;
+
+        assert!(consumer_group
+            .get_expired_members(clock.now(), session_timeout)
+            .await
+            .is_empty());
+
+        clock.advance(IggyDuration::from(500_000));
+        consumer_group
+            .heartbeat(member2_id, clock.now())
+            .await
+            .unwrap();
+        let now = clock.advance(IggyDuration::from(600_000));
+        assert_eq!(
+            consumer_group
+                .get_expired_members(now, session_timeout)
+                .await,
+            vec![member1_id]
+        );
+        assert!(consumer_group
+            .get_expired_members(now, IggyDuration::default())
+            .await
+            .is_empty());
+
+        consumer_group.heartbeat(member1_id, now).await.unwrap();
+        assert!(consumer_group
+            .get_expired_members(now, session_timeout)
+            .await
+            .is_empty());
+        assert!(consumer_group.heartbeat(789, now).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_delay_rebalance_when_rebalance_delay_is_set() {
+        let member_id = 123;
+        let clock = ManualClock::new(IggyTimestamp::from(1_000_000));
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 3, AssignmentStrategy::default())
+            .with_rebalance_delay(IggyDuration::ONE_SECOND);
+
+        consumer_group.add_member(member_id, clock.now()).await;
+        assert_eq!(consumer_group.generation(), 0);
+        assert!(consumer_group.members[&member_id]
+            .read()
+            .await
+            .partitions
+            .is_empty());
+        assert!(!consumer_group.rebalance_if_due(clock.now()).await);
+
+        let now = clock.advance(IggyDuration::from(999_999));
+        assert!(!consumer_group.rebalance_if_due(now).await);
+
+        let now = clock.advance(IggyDuration::from(1));
+        assert!(consumer_group.rebalance_if_due(now).await);
+        assert_eq!(consumer_group.generation(), 1);
+        assert_eq!(
+            consumer_group.members[&member_id]
+                .read()
+                .await
+                .partitions
+                .len(),
+            3
+        );
+        assert!(!consumer_group.rebalance_if_due(now).await);
+    }
 }
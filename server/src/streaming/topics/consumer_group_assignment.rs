@@ -0,0 +1,227 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use ahash::{AHashMap, AHashSet};
+use iggy::consumer_groups::assignment_strategy::AssignmentStrategy;
+
+/// Assigns the topic partitions to the consumer group members.
+pub trait PartitionAssignor: Send + Sync {
+    /// Returns the new assignment (member ID -> partition IDs) for the given members and partitions.
+    /// The current assignment can be used to minimize the partitions movement between the members.
+    fn assign(
+        &self,
+        members: &[u32],
+        partitions: &[u32],
+        current: &AHashMap<u32, Vec<u32>>,
+    ) -> AHashMap<u32, Vec<u32>>;
+}
+
+/// Each member gets a contiguous range of partitions, the first members get one extra partition if the partitions can't be divided evenly.
+#[derive(Debug)]
+pub struct RangeAssignor;
+
+/// The partitions are distributed one by one across the members.
+#[derive(Debug)]
+pub struct RoundRobinAssignor;
+
+/// The partitions are distributed evenly, while the members keep as many of their currently owned partitions as possible.
+#[derive(Debug)]
+pub struct StickyAssignor;
+
+pub fn get_assignor(strategy: AssignmentStrategy) -> &'static dyn PartitionAssignor {
+    match strategy {
+        AssignmentStrategy::Range => &RangeAssignor,
+        AssignmentStrategy::RoundRobin => &RoundRobinAssignor,
+        // The cooperative variant computes the same target assignment, the consumer group is responsible for revoking the moved partitions first.
+        AssignmentStrategy::Sticky | AssignmentStrategy::CooperativeSticky => &StickyAssignor,
+    }
+}
+
+impl PartitionAssignor for RangeAssignor {
+    fn assign(
+        &self,
+        members: &[u32],
+        partitions: &[u32],
+        _current: &AHashMap<u32, Vec<u32>>,
+    ) -> AHashMap<u32, Vec<u32>> {
+        let mut assignment = empty_assignment(members);
+        if members.is_empty() {
+            return assignment;
+        }
+
+        let partitions_per_member = partitions.len() / members.len();
+        let extra_partitions = partitions.len() % members.len();
+        let mut start = 0;
+        for (index, member_id) in members.iter().enumerate() {
+            let count = partitions_per_member + usize::from(index < extra_partitions);
+            assignment
+                .get_mut(member_id)
+                .unwrap()
+                .extend_from_slice(&partitions[start..start + count]);
+            start += count;
+        }
+        assignment
+    }
+}
+
+impl PartitionAssignor for RoundRobinAssignor {
+    fn assign(
+        &self,
+        members: &[u32],
+        partitions: &[u32],
+        _current: &AHashMap<u32, Vec<u32>>,
+    ) -> AHashMap<u32, Vec<u32>> {
+        let mut assignment = empty_assignment(members);
+        if members.is_empty() {
+            return assignment;
+        }
+
+        for (index, partition_id) in partitions.iter().enumerate() {
+            let member_id = members[index % members.len()];
+            assignment.get_mut(&member_id).unwrap().push(*partition_id);
+        }
+        assignment
+    }
+}
+
+impl PartitionAssignor for StickyAssignor {
+    fn assign(
+        &self,
+        members: &[u32],
+        partitions: &[u32],
+        current: &AHashMap<u32, Vec<u32>>,
+    ) -> AHashMap<u32, Vec<u32>> {
+        let mut assignment = empty_assignment(members);
+        if members.is_empty() {
+            return assignment;
+        }
+
+        let min_quota = partitions.len() / members.len();
+        let mut extra_slots = partitions.len() % members.len();
+        let available_partitions = partitions.iter().copied().collect::<AHashSet<_>>();
+        let mut assigned_partitions = AHashSet::with_capacity(partitions.len());
+
+        // The members owning the most partitions are the first ones to keep an extra partition, which results in fewer movements.
+        let mut sorted_members = members.to_vec();
+        sorted_members.sort_by_key(|member_id| {
+            std::cmp::Reverse(current.get(member_id).map_or(0, |owned| owned.len()))
+        });
+        for member_id in sorted_members {
+            let Some(owned_partitions) = current.get(&member_id) else {
+                continue;
+            };
+
+            let mut owned_partitions = owned_partitions
+                .iter()
+                .copied()
+                .filter(|partition_id| {
+                    available_partitions.contains(partition_id)
+                        && !assigned_partitions.contains(partition_id)
+                })
+                .collect::<Vec<_>>();
+            owned_partitions.sort_unstable();
+            let mut quota = min_quota;
+            if extra_slots > 0 && owned_partitions.len() > min_quota {
+                quota += 1;
+                extra_slots -= 1;
+            }
+            owned_partitions.truncate(quota);
+            assigned_partitions.extend(owned_partitions.iter().copied());
+            assignment.insert(member_id, owned_partitions);
+        }
+
+        for partition_id in partitions {
+            if assigned_partitions.contains(partition_id) {
+                continue;
+            }
+
+            let member_id = members
+                .iter()
+                .min_by_key(|member_id| (assignment[*member_id].len(), **member_id))
+                .unwrap();
+            assignment.get_mut(member_id).unwrap().push(*partition_id);
+        }
+        assignment
+    }
+}
+
+fn empty_assignment(members: &[u32]) -> AHashMap<u32, Vec<u32>> {
+    members
+        .iter()
+        .map(|member_id| (*member_id, Vec::new()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_assignor_should_assign_contiguous_partitions() {
+        let assignment = RangeAssignor.assign(&[1, 2], &[1, 2, 3, 4, 5], &AHashMap::new());
+        assert_eq!(assignment[&1], vec![1, 2, 3]);
+        assert_eq!(assignment[&2], vec![4, 5]);
+    }
+
+    #[test]
+    fn round_robin_assignor_should_distribute_partitions_one_by_one() {
+        let assignment = RoundRobinAssignor.assign(&[1, 2], &[1, 2, 3, 4, 5], &AHashMap::new());
+        assert_eq!(assignment[&1], vec![1, 3, 5]);
+        assert_eq!(assignment[&2], vec![2, 4]);
+    }
+
+    #[test]
+    fn sticky_assignor_should_keep_owned_partitions_when_member_joins() {
+        let current = AHashMap::from([(1, vec![1, 3, 5]), (2, vec![2, 4, 6])]);
+        let assignment = StickyAssignor.assign(&[1, 2, 3], &[1, 2, 3, 4, 5, 6], &current);
+        assert_eq!(assignment[&1].len(), 2);
+        assert_eq!(assignment[&2].len(), 2);
+        assert_eq!(assignment[&3].len(), 2);
+        assert!(assignment[&1].iter().all(|id| current[&1].contains(id)));
+        assert!(assignment[&2].iter().all(|id| current[&2].contains(id)));
+    }
+
+    #[test]
+    fn sticky_assignor_should_only_move_partitions_of_the_member_which_left() {
+        let current = AHashMap::from([(1, vec![1, 4]), (2, vec![2, 5]), (3, vec![3, 6])]);
+        let assignment = StickyAssignor.assign(&[1, 2], &[1, 2, 3, 4, 5, 6], &current);
+        assert_eq!(assignment[&1].len(), 3);
+        assert_eq!(assignment[&2].len(), 3);
+        assert!(current[&1].iter().all(|id| assignment[&1].contains(id)));
+        assert!(current[&2].iter().all(|id| assignment[&2].contains(id)));
+    }
+
+    #[test]
+    fn all_assignors_should_assign_every_partition_exactly_once() {
+        let members = [1, 2, 3];
+        let partitions = (1..=10).collect::<Vec<_>>();
+        let current = AHashMap::from([(1, vec![1, 2, 3, 4, 5, 6, 7])]);
+        for strategy in [
+            AssignmentStrategy::Range,
+            AssignmentStrategy::RoundRobin,
+            AssignmentStrategy::Sticky,
+        ] {
+            let assignment = get_assignor(strategy).assign(&members, &partitions, &current);
+            let mut assigned = assignment.values().flatten().copied().collect::<Vec<_>>();
+            assigned.sort_unstable();
+            assert_eq!(assigned, partitions);
+            let counts = assignment.values().map(Vec::len).collect::<Vec<_>>();
+            assert!(counts.iter().max().unwrap() - counts.iter().min().unwrap() <= 1);
+        }
+    }
+}
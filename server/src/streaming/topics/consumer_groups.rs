@@ -20,9 +20,11 @@ use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::topics::COMPONENT;
 use error_set::ErrContext;
+use iggy::consumer_groups::assignment_strategy::AssignmentStrategy;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::atomic::Ordering;
 use tokio::sync::RwLock;
 use tracing::info;
@@ -104,7 +106,9 @@ impl Topic {
         &mut self,
         group_id: Option<u32>,
         name: &str,
+        assignment_strategy: AssignmentStrategy,
     ) -> Result<&RwLock<ConsumerGroup>, IggyError> {
+        self.ensure_not_deleting()?;
         if self.consumer_groups_ids.contains_key(name) {
             return Err(IggyError::ConsumerGroupNameAlreadyExists(
                 name.to_owned(),
@@ -137,13 +141,19 @@ impl Topic {
             return Err(IggyError::ConsumerGroupIdAlreadyExists(id, self.topic_id));
         }
 
-        let consumer_group =
-            ConsumerGroup::new(self.topic_id, id, name, self.partitions.len() as u32);
+        let consumer_group = ConsumerGroup::new(
+            self.topic_id,
+            id,
+            name,
+            self.partitions.len() as u32,
+            assignment_strategy,
+        )
+        .with_rebalance_delay(self.config.consumer_group.rebalance_delay);
         self.consumer_groups.insert(id, RwLock::new(consumer_group));
         self.consumer_groups_ids.insert(name.to_owned(), id);
         info!(
-            "Created consumer group with ID: {} for topic with ID: {} and stream with ID: {}.",
-            id, self.topic_id, self.stream_id
+            "Created consumer group with ID: {} for topic with ID: {} and stream with ID: {}, assignment strategy: {}.",
+            id, self.topic_id, self.stream_id, assignment_strategy
         );
         self.get_consumer_group_by_id(id)
     }
@@ -199,12 +209,21 @@ impl Topic {
         &self,
         group_id: &Identifier,
         member_id: u32,
+        assignment_strategy: Option<AssignmentStrategy>,
+        now: IggyTimestamp,
     ) -> Result<(), IggyError> {
         let consumer_group = self.get_consumer_group(group_id).with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to get consumer group with id: {group_id}")
         })?;
         let mut consumer_group = consumer_group.write().await;
-        consumer_group.add_member(member_id).await;
+        if let Some(assignment_strategy) = assignment_strategy {
+            consumer_group
+                .ensure_assignment_strategy(assignment_strategy)
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - member with ID: {member_id} requested assignment strategy: {assignment_strategy} for consumer group with id: {group_id}")
+                })?;
+        }
+        consumer_group.add_member(member_id, now).await;
         info!(
             "Member with ID: {} has joined consumer group with ID: {} for topic with ID: {} and stream with ID: {}.",
             member_id, group_id, self.topic_id, self.stream_id
@@ -216,18 +235,32 @@ impl Topic {
         &self,
         group_id: &Identifier,
         member_id: u32,
+        now: IggyTimestamp,
     ) -> Result<(), IggyError> {
         let consumer_group = self.get_consumer_group(group_id).with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to get consumer group with id: {group_id}")
         })?;
         let mut consumer_group = consumer_group.write().await;
-        consumer_group.delete_member(member_id).await;
+        consumer_group.delete_member(member_id, now).await;
         info!(
             "Member with ID: {} has left consumer group with ID: {} for topic with ID: {} and stream with ID: {}.",
             member_id, group_id, self.topic_id, self.stream_id
         );
         Ok(())
     }
+
+    pub async fn heartbeat_consumer_group(
+        &self,
+        group_id: &Identifier,
+        member_id: u32,
+        now: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        let consumer_group = self.get_consumer_group(group_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get consumer group with id: {group_id}")
+        })?;
+        let consumer_group = consumer_group.read().await;
+        consumer_group.heartbeat(member_id, now).await
+    }
 }
 
 #[cfg(test)]
@@ -248,7 +281,9 @@ mod tests {
         let name = "test";
         let mut topic = get_topic().await;
         let topic_id = topic.topic_id;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, AssignmentStrategy::default())
+            .await;
         assert!(result.is_ok());
         {
             let created_consumer_group = result.unwrap().read().await;
@@ -276,10 +311,14 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic().await;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, AssignmentStrategy::default())
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
-        let result = topic.create_consumer_group(Some(group_id), "test2").await;
+        let result = topic
+            .create_consumer_group(Some(group_id), "test2", AssignmentStrategy::default())
+            .await;
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(err, IggyError::ConsumerGroupIdAlreadyExists(_, _)));
@@ -291,11 +330,15 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic().await;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, AssignmentStrategy::default())
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let group_id = group_id + 1;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, AssignmentStrategy::default())
+            .await;
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(
@@ -310,7 +353,9 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic().await;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, AssignmentStrategy::default())
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let result = topic
@@ -325,7 +370,9 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic().await;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, AssignmentStrategy::default())
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let group_id = group_id + 1;
@@ -343,11 +390,16 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic().await;
         topic
-            .create_consumer_group(Some(group_id), name)
+            .create_consumer_group(Some(group_id), name, AssignmentStrategy::default())
             .await
             .unwrap();
         let result = topic
-            .join_consumer_group(&Identifier::numeric(group_id).unwrap(), member_id)
+            .join_consumer_group(
+                &Identifier::numeric(group_id).unwrap(),
+                member_id,
+                None,
+                IggyTimestamp::now(),
+            )
             .await;
         assert!(result.is_ok());
         let consumer_group = topic
@@ -366,15 +418,24 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic().await;
         topic
-            .create_consumer_group(Some(group_id), name)
+            .create_consumer_group(Some(group_id), name, AssignmentStrategy::default())
             .await
             .unwrap();
         topic
-            .join_consumer_group(&Identifier::numeric(group_id).unwrap(), member_id)
+            .join_consumer_group(
+                &Identifier::numeric(group_id).unwrap(),
+                member_id,
+                None,
+                IggyTimestamp::now(),
+            )
             .await
             .unwrap();
         let result = topic
-            .leave_consumer_group(&Identifier::numeric(group_id).unwrap(), member_id)
+            .leave_consumer_group(
+                &Identifier::numeric(group_id).unwrap(),
+                member_id,
+                IggyTimestamp::now(),
+            )
             .await;
         assert!(result.is_ok());
         let consumer_group = topic
@@ -23,7 +23,9 @@ use error_set::ErrContext;
 use iggy::consumer::Consumer;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
+use iggy::messages::AutoCommitMode;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::utils::timestamp::IggyTimestamp;
 
 impl Topic {
     pub async fn store_consumer_offset(
@@ -34,7 +36,7 @@ impl Topic {
         client_id: u32,
     ) -> Result<(), IggyError> {
         let Some((polling_consumer, partition_id)) = self
-            .resolve_consumer_with_partition_id(&consumer, client_id, partition_id, false)
+            .resolve_consumer_with_partition_id(&consumer, client_id, partition_id, None)
             .await
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer with partition id, consumer ID: {}, client ID: {}, partition ID: {:?}", consumer.id, client_id, partition_id))? else {
             return Err(IggyError::ConsumerOffsetNotFound(client_id));
@@ -63,6 +65,34 @@ impl Topic {
         partition.store_consumer_offset(consumer, offset).await.with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to store consumer offset, consumer: {consumer}, offset: {offset}"))
     }
 
+    pub async fn auto_commit_consumer_offset(
+        &self,
+        consumer: PollingConsumer,
+        partition_id: u32,
+        mode: AutoCommitMode,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        let partition = self.get_partition(partition_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}")
+        })?;
+        let partition = partition.read().await;
+        partition.auto_commit_offset(consumer, mode, offset).await.with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to auto-commit consumer offset, consumer: {consumer}, mode: {mode}, offset: {offset}"))
+    }
+
+    pub async fn commit_delivered_consumer_offset(
+        &self,
+        consumer: PollingConsumer,
+        partition_id: u32,
+        mode: AutoCommitMode,
+        now: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        let partition = self.get_partition(partition_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}")
+        })?;
+        let partition = partition.read().await;
+        partition.commit_delivered_offset(consumer, mode, now).await.with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to commit delivered consumer offset, consumer: {consumer}, mode: {mode}"))
+    }
+
     pub async fn get_consumer_offset(
         &self,
         consumer: &Consumer,
@@ -70,7 +100,7 @@ impl Topic {
         client_id: u32,
     ) -> Result<Option<ConsumerOffsetInfo>, IggyError> {
         let Some((polling_consumer, partition_id)) = self
-            .resolve_consumer_with_partition_id(consumer, client_id, partition_id, false)
+            .resolve_consumer_with_partition_id(consumer, client_id, partition_id, None)
             .await
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer offset for consumer: {consumer}, client ID: {client_id}, partition ID: {:#?}", partition_id))? else {
             return Ok(None);
@@ -106,7 +136,7 @@ impl Topic {
         client_id: u32,
     ) -> Result<(), IggyError> {
         let Some((polling_consumer, partition_id)) = self
-            .resolve_consumer_with_partition_id(&consumer, client_id, partition_id, false)
+            .resolve_consumer_with_partition_id(&consumer, client_id, partition_id, None)
             .await
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer with partition id, consumer ID: {}, client ID: {}, partition ID: {:?}", consumer.id, client_id, partition_id))? else {
             return Err(IggyError::ConsumerOffsetNotFound(client_id));
@@ -0,0 +1,114 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::topics::COMPONENT;
+use error_set::ErrContext;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::messages::PolledMessage;
+use tracing::trace;
+
+/// The rejected message which exceeded the delivery attempts configured in the dead letter policy of the topic.
+#[derive(Debug)]
+pub struct DeadLetter {
+    pub partition_id: u32,
+    pub delivery_attempts: u32,
+    pub message: PolledMessage,
+}
+
+impl Topic {
+    pub async fn reject_messages(
+        &self,
+        consumer: &Consumer,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        client_id: u32,
+    ) -> Result<(PollingConsumer, Vec<DeadLetter>), IggyError> {
+        let Some(dead_letter_policy) = &self.dead_letter_policy else {
+            return Err(IggyError::InvalidDeadLetterPolicy(format!(
+                "dead letter policy is not configured for topic with ID: {} in stream with ID: {}",
+                self.topic_id, self.stream_id
+            )));
+        };
+
+        let Some((polling_consumer, partition_id)) = self
+            .resolve_consumer_with_partition_id(consumer, client_id, partition_id, None)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer with partition id, consumer ID: {}, client ID: {}, partition ID: {:?}", consumer.id, client_id, partition_id))? else {
+            return Err(IggyError::ConsumerOffsetNotFound(client_id));
+        };
+
+        let partition = self.get_partition(partition_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}")
+        })?;
+        let partition = partition.read().await;
+        let mut dead_letters = Vec::new();
+        for offset in offsets {
+            let delivery_attempts = partition
+                .register_delivery_failure(polling_consumer, *offset)
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to register delivery failure, consumer: {polling_consumer}, offset: {offset}"))?;
+            if delivery_attempts < dead_letter_policy.max_delivery_attempts {
+                continue;
+            }
+
+            let message = partition
+                .get_messages_by_offset(*offset, 1)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to get message by offset: {offset}"
+                    )
+                })?
+                .into_iter()
+                .find(|message| message.offset == *offset);
+            let Some(message) = message else {
+                // The message might have been already removed e.g. due to the expiry.
+                trace!("Rejected message with offset: {offset} was not found in partition with ID: {partition_id}, skipping.");
+                partition.clear_delivery_attempts(polling_consumer, *offset);
+                continue;
+            };
+
+            dead_letters.push(DeadLetter {
+                partition_id,
+                delivery_attempts,
+                message: message.to_polled_message()?,
+            });
+        }
+
+        Ok((polling_consumer, dead_letters))
+    }
+
+    pub async fn clear_delivery_attempts(
+        &self,
+        consumer: PollingConsumer,
+        dead_letters: &[DeadLetter],
+    ) -> Result<(), IggyError> {
+        for dead_letter in dead_letters {
+            let partition_id = dead_letter.partition_id;
+            let partition = self.get_partition(partition_id).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}")
+            })?;
+            let partition = partition.read().await;
+            partition.clear_delivery_attempts(consumer, dead_letter.message.offset);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::topics::topic::Topic;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::info;
+
+/// The pending deletion of the topic, which rejects the new messages while its consumer groups consume the remaining ones.
+#[derive(Debug, Clone, Copy)]
+pub struct TopicDeletion {
+    pub requested_at: IggyTimestamp,
+    /// The time after which the topic is removed, even if its consumer groups haven't consumed all of its messages.
+    pub deadline: IggyTimestamp,
+}
+
+impl TopicDeletion {
+    pub fn new(now: IggyTimestamp, grace_period: IggyDuration) -> Self {
+        Self {
+            requested_at: now,
+            deadline: (now.as_micros() + grace_period.as_micros()).into(),
+        }
+    }
+}
+
+impl Topic {
+    /// Marks the topic as being deleted and notifies its consumer groups, so that they can consume the remaining messages until the grace period elapses.
+    pub fn mark_as_deleting(&mut self, now: IggyTimestamp, grace_period: IggyDuration) {
+        if self.deletion.is_some() {
+            return;
+        }
+
+        self.deletion = Some(TopicDeletion::new(now, grace_period));
+        for consumer_group in self.consumer_groups.values_mut() {
+            let consumer_group = consumer_group.get_mut();
+            consumer_group.topic_deleting = true;
+            info!(
+                "Consumer group: {} was notified that topic with ID: {} in stream with ID: {} is being deleted.",
+                consumer_group.group_id, self.topic_id, self.stream_id
+            );
+        }
+        info!(
+            "Topic with ID: {} in stream with ID: {} is being deleted, grace period: {grace_period}.",
+            self.topic_id, self.stream_id
+        );
+    }
+
+    pub fn is_deleting(&self) -> bool {
+        self.deletion.is_some()
+    }
+
+    /// Fails if the topic is being deleted, so that no new messages or consumer groups are added to it.
+    pub fn ensure_not_deleting(&self) -> Result<(), IggyError> {
+        if self.is_deleting() {
+            return Err(IggyError::TopicBeingDeleted(self.topic_id, self.stream_id));
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the pending deletion can be completed, either because the grace period has elapsed
+    /// or the consumer groups have consumed all the messages of the topic.
+    pub async fn is_deletion_due(&self, now: IggyTimestamp) -> bool {
+        let Some(deletion) = self.deletion else {
+            return false;
+        };
+
+        now.as_micros() >= deletion.deadline.as_micros() || self.are_consumer_groups_drained().await
+    }
+
+    async fn are_consumer_groups_drained(&self) -> bool {
+        for partition in self.partitions.values() {
+            let partition = partition.read().await;
+            if partition.get_messages_count() == 0 {
+                continue;
+            }
+
+            let all_consumed = self.consumer_groups.keys().all(|group_id| {
+                partition
+                    .consumer_group_offsets
+                    .get(group_id)
+                    .is_some_and(|offset| offset.offset >= partition.current_offset)
+            });
+            if !all_consumed {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
+    use crate::streaming::storage::SystemStorage;
+    use iggy::consumer_groups::assignment_strategy::AssignmentStrategy;
+    use std::sync::atomic::{AtomicU32, AtomicU64};
+    use std::sync::Arc;
+
+    async fn create_topic() -> Topic {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let config = Arc::new(SystemConfig {
+            path: tempdir.path().to_str().unwrap().to_string(),
+            ..Default::default()
+        });
+        let storage = Arc::new(SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        ));
+        Topic::empty(
+            1,
+            1,
+            "test",
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            config,
+            storage,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn should_mark_topic_and_its_consumer_groups_as_deleting() {
+        let mut topic = create_topic().await;
+        topic
+            .create_consumer_group(Some(1), "group", AssignmentStrategy::default())
+            .await
+            .unwrap();
+
+        topic.mark_as_deleting(IggyTimestamp::from(1_000), IggyDuration::from(1_000));
+
+        assert!(topic.is_deleting());
+        assert!(topic.ensure_not_deleting().is_err());
+        assert!(
+            topic
+                .get_consumer_group_by_id(1)
+                .unwrap()
+                .read()
+                .await
+                .topic_deleting
+        );
+    }
+
+    #[tokio::test]
+    async fn should_complete_deletion_of_topic_without_messages_or_after_grace_period() {
+        let mut topic = create_topic().await;
+        let now = IggyTimestamp::from(1_000);
+        assert!(!topic.is_deletion_due(now).await);
+
+        topic.mark_as_deleting(now, IggyDuration::from(1_000));
+
+        assert!(topic.is_deletion_due(now).await);
+        assert!(topic.is_deletion_due(IggyTimestamp::from(2_000)).await);
+    }
+}
@@ -0,0 +1,203 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::topics::COMPONENT;
+use crate::streaming::utils::hash;
+use bytes::{BufMut, BytesMut};
+use dashmap::mapref::entry::Entry;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::partitions::key_routing_policy::KeyRoutingPolicy;
+use std::path::Path;
+use tokio::fs;
+use tracing::{info, trace};
+
+const KEY_ROUTE_SIZE: usize = 8;
+
+impl Topic {
+    /// Returns the partition ID for the messages key. The key which has been already routed stays in its partition,
+    /// while the new key is routed by its hash, and the route is persisted so that it survives adding the partitions.
+    pub(crate) async fn route_messages_key(&self, messages_key: &[u8]) -> Result<u32, IggyError> {
+        let messages_key_hash = hash::calculate_32(messages_key);
+        if let Some(partition_id) = self.key_routes.get(&messages_key_hash) {
+            return Ok(*partition_id);
+        }
+
+        let partition_id = self.calculate_partition_id_by_messages_key_hash(messages_key);
+        match self.key_routes.entry(messages_key_hash) {
+            Entry::Occupied(entry) => return Ok(*entry.get()),
+            Entry::Vacant(entry) => {
+                entry.insert(partition_id);
+            }
+        }
+
+        self.storage
+            .persister
+            .append(
+                &self.key_routes_path,
+                &map_key_routes(&[(messages_key_hash, partition_id)]),
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to persist key route to partition with ID: {partition_id} for topic with ID: {} and stream with ID: {}",
+                    self.topic_id, self.stream_id
+                )
+            })?;
+        trace!(
+            "Routed new messages key with hash: {messages_key_hash} to partition with ID: {partition_id} for topic with ID: {} and stream with ID: {}",
+            self.topic_id,
+            self.stream_id
+        );
+        Ok(partition_id)
+    }
+
+    /// Applies the key routing policy after the partitions have been added to the topic.
+    pub(crate) async fn apply_key_routing_policy(
+        &self,
+        policy: KeyRoutingPolicy,
+    ) -> Result<(), IggyError> {
+        match policy {
+            KeyRoutingPolicy::PreserveExisting => {
+                info!(
+                    "Preserved {} key routes for topic with ID: {} and stream with ID: {}.",
+                    self.key_routes.len(),
+                    self.topic_id,
+                    self.stream_id
+                );
+                Ok(())
+            }
+            KeyRoutingPolicy::Rehash => {
+                self.key_routes.clear();
+                self.persist_key_routes().await?;
+                info!(
+                    "Cleared key routes, all the keys will be re-hashed for topic with ID: {} and stream with ID: {}.",
+                    self.topic_id, self.stream_id
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes the routes to the partitions which no longer exist, so that their keys are routed again by the hash.
+    pub(crate) async fn remove_stale_key_routes(&self) -> Result<(), IggyError> {
+        let partitions_count = self.get_partitions_count();
+        let routes_count = self.key_routes.len();
+        self.key_routes
+            .retain(|_, partition_id| *partition_id <= partitions_count);
+        if self.key_routes.len() == routes_count {
+            return Ok(());
+        }
+
+        self.persist_key_routes().await
+    }
+
+    pub(crate) async fn load_key_routes(&self) -> Result<(), IggyError> {
+        if !Path::new(&self.key_routes_path).exists() {
+            return Ok(());
+        }
+
+        let bytes = fs::read(&self.key_routes_path)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to read key routes from path: {}",
+                    self.key_routes_path
+                )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+        let partitions_count = self.get_partitions_count();
+        for (messages_key_hash, partition_id) in read_key_routes(&bytes) {
+            if partition_id <= partitions_count {
+                self.key_routes.insert(messages_key_hash, partition_id);
+            }
+        }
+
+        info!(
+            "Loaded {} key routes for topic with ID: {} and stream with ID: {}.",
+            self.key_routes.len(),
+            self.topic_id,
+            self.stream_id
+        );
+        Ok(())
+    }
+
+    async fn persist_key_routes(&self) -> Result<(), IggyError> {
+        let key_routes = self
+            .key_routes
+            .iter()
+            .map(|route| (*route.key(), *route.value()))
+            .collect::<Vec<_>>();
+        self.storage
+            .persister
+            .overwrite(&self.key_routes_path, &map_key_routes(&key_routes))
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to persist key routes for topic with ID: {} and stream with ID: {}",
+                    self.topic_id, self.stream_id
+                )
+            })
+    }
+}
+
+fn map_key_routes(key_routes: &[(u32, u32)]) -> BytesMut {
+    let mut bytes = BytesMut::with_capacity(key_routes.len() * KEY_ROUTE_SIZE);
+    for (messages_key_hash, partition_id) in key_routes {
+        bytes.put_u32_le(*messages_key_hash);
+        bytes.put_u32_le(*partition_id);
+    }
+    bytes
+}
+
+/// Reads the key routes, the incomplete route (e.g. due to the crash while appending) is skipped.
+fn read_key_routes(bytes: &[u8]) -> impl Iterator<Item = (u32, u32)> + '_ {
+    bytes.chunks_exact(KEY_ROUTE_SIZE).map(|route| {
+        (
+            u32::from_le_bytes(route[..4].try_into().unwrap()),
+            u32::from_le_bytes(route[4..].try_into().unwrap()),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_routes_should_be_written_and_read() {
+        let key_routes = vec![(1, 1), (u32::MAX, 3), (42, 2)];
+
+        let bytes = map_key_routes(&key_routes);
+        let read_routes = read_key_routes(&bytes).collect::<Vec<_>>();
+
+        assert_eq!(bytes.len(), key_routes.len() * KEY_ROUTE_SIZE);
+        assert_eq!(read_routes, key_routes);
+    }
+
+    #[test]
+    fn incomplete_key_route_should_be_skipped() {
+        let mut bytes = map_key_routes(&[(7, 1)]);
+        bytes.put_u32_le(8);
+
+        let read_routes = read_key_routes(&bytes).collect::<Vec<_>>();
+
+        assert_eq!(read_routes, vec![(7, 1)]);
+    }
+}
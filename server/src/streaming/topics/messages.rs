@@ -17,28 +17,41 @@
  */
 
 use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
+use crate::streaming::diagnostics::batch_timings::BatchTimings;
 use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::partitions::linger;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::segments::IggyMessagesMut;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::topics::COMPONENT;
+use crate::streaming::transactions::transaction_coordinator::TransactionCoordinator;
 use crate::streaming::utils::file::folder_size;
 use crate::streaming::utils::hash;
 use ahash::AHashMap;
+use bytes::Bytes;
 use error_set::ErrContext;
-use iggy::confirmation::Confirmation;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::confirmation::{self, Confirmation, ConfirmationLevel};
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
 use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning, PartitioningKind};
+use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::models::messages::PolledMessages;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
 use iggy::utils::timestamp::IggyTimestamp;
+use lending_iterator::prelude::*;
+use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Instant;
+use tokio::time;
 use tracing::{info, trace, warn};
 
+const POLL_INITIAL_CHUNK_COUNT: u32 = 16;
+
 impl Topic {
     pub fn get_messages_count(&self) -> u64 {
         self.messages_count.load(Ordering::SeqCst)
@@ -50,6 +63,8 @@ impl Topic {
         partition_id: u32,
         strategy: PollingStrategy,
         count: u32,
+        now: IggyTimestamp,
+        transactions: &TransactionCoordinator,
     ) -> Result<PolledMessages, IggyError> {
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
@@ -64,46 +79,126 @@ impl Topic {
             ));
         }
 
-        let partition = partition.unwrap();
-        let partition = partition.read().await;
+        let shared_partition = partition.unwrap().clone();
+        let partition = shared_partition.read().await;
         let value = strategy.value;
-        let messages = match strategy.kind {
-            PollingKind::Offset => partition.get_messages_by_offset(value, count).await,
+        // The messages are read in chunks, so that polling the large messages doesn't load all of them into memory,
+        // only to find out that they don't fit in the response.
+        let mut chunk_count = count.min(POLL_INITIAL_CHUNK_COUNT);
+        let mut messages = match strategy.kind {
+            PollingKind::Offset => partition.get_messages_by_offset(value, chunk_count).await,
             PollingKind::Timestamp => {
                 partition
-                    .get_messages_by_timestamp(value.into(), count)
+                    .get_messages_by_timestamp(value.into(), chunk_count)
                     .await
-                    .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get messages by timestamp: {value}, count: {count}"))
+                    .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to get messages by timestamp: {value}, count: {chunk_count}"))
             }
-            PollingKind::First => partition.get_first_messages(count).await,
-            PollingKind::Last => partition.get_last_messages(count).await,
-            PollingKind::Next => partition.get_next_messages(consumer, count).await,
+            PollingKind::First => partition.get_first_messages(chunk_count).await,
+            PollingKind::Last => {
+                let last_count = (count as u64).min(partition.current_offset + 1);
+                let start_offset = 1 + partition.current_offset - last_count;
+                partition.get_messages_by_offset(start_offset, chunk_count).await
+            }
+            PollingKind::Next => partition.get_next_messages(consumer, chunk_count).await,
         }?;
 
-        let messages = messages
-            .into_iter()
-            .map(|msg| msg.to_polled_message())
-            .collect::<Result<Vec<_>, IggyError>>()?;
+        // Skip the messages whose TTL has elapsed and the ones which weren't committed, fetching the following ones
+        // if the whole batch has been skipped, so that the consumer isn't stuck on the messages which are never returned.
+        // Once the response reaches the maximum size, it's truncated and the offset to continue from is returned.
+        // At least one message is always returned, otherwise the consumer would never get past the large one.
+        let max_response_size = self.config.polling.max_response_size.as_bytes_u64();
+        let mut response_size = 0;
+        let mut next_offset = None;
+        let mut polled_messages = Vec::new();
+        while let Some(last_message) = messages.last() {
+            let following_offset = last_message.offset + 1;
+            let fetched_count = messages.len() as u64;
+            let fetched_size = messages
+                .iter()
+                .map(|message| message.get_size_bytes().as_bytes_u64())
+                .sum::<u64>();
+            let (committed_messages, held_back) = self
+                .filter_committed_messages(&partition, messages, now, transactions)
+                .await?;
+            for message in committed_messages {
+                if polled_messages.len() as u32 == count {
+                    break;
+                }
+
+                let message_size = message.get_size_bytes().as_bytes_u64();
+                if !polled_messages.is_empty() && response_size + message_size > max_response_size {
+                    trace!("Poll response for partition: {partition_id} reached the maximum size: {max_response_size} bytes, truncating it at offset: {}.", message.offset);
+                    next_offset = Some(message.offset);
+                    break;
+                }
+
+                response_size += message_size;
+                polled_messages.push(message.to_polled_message()?);
+            }
+
+            let remaining_count = count - polled_messages.len() as u32;
+            if held_back || next_offset.is_some() || remaining_count == 0 {
+                break;
+            }
+
+            // Size the next chunk by the average size of the messages read so far, to fill the rest of the response.
+            let average_message_size = (fetched_size / fetched_count).max(1);
+            let remaining_size = max_response_size.saturating_sub(response_size);
+            chunk_count =
+                (remaining_size / average_message_size).clamp(1, remaining_count as u64) as u32;
+            messages = partition
+                .get_messages_by_offset(following_offset, chunk_count)
+                .await?;
+        }
+
+        // Prefetch the following messages of the sequential consumer in the background, so its next poll is served from memory.
+        if let (Some(first_message), Some(last_message)) =
+            (polled_messages.first(), polled_messages.last())
+        {
+            let polled_next_offset = next_offset.unwrap_or(last_message.offset + 1);
+            if let Some(prefetch_offset) =
+                partition.record_consumer_read(consumer, first_message.offset, polled_next_offset)
+            {
+                let shared_partition = shared_partition.clone();
+                tokio::spawn(async move {
+                    let partition = shared_partition.read().await;
+                    partition.prefetch_messages(consumer, prefetch_offset).await;
+                });
+            }
+        }
+
         Ok(PolledMessages {
             partition_id,
             current_offset: partition.current_offset,
-            messages,
+            messages: polled_messages,
+            next_offset,
         })
     }
 
     pub async fn append_messages(
         &self,
         partitioning: &Partitioning,
-        messages: IggyMessagesMut,
-        confirmation: Option<Confirmation>,
-    ) -> Result<(), IggyError> {
+        mut messages: IggyMessagesMut,
+        confirmation: ConfirmationLevel,
+        now: IggyTimestamp,
+    ) -> Result<BatchTimings, IggyError> {
+        let validation_started_at = Instant::now();
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
         }
 
+        self.ensure_not_deleting()?;
+        if let Some(schema) = &self.schema {
+            let mut messages = messages.iter_mut();
+            while let Some(message) = messages.next() {
+                schema.validate(message.payload())?;
+            }
+        }
+
         // Don't return an error if the topic is full and delete_oldest_segments is true.
         // Oldest segment will be removed eventually by MaintainMessages background job.
-        if self.is_full() && self.config.topic.delete_oldest_segments {
+        // The same applies to the topics with the size-based retention policy.
+        if self.is_full() && self.config.topic.delete_oldest_segments && !self.retains_by_size() {
             return Err(IggyError::TopicFull(self.topic_id, self.stream_id));
         }
 
@@ -113,26 +208,59 @@ impl Topic {
         }
         */
 
-        let partition_id = match partitioning.kind {
-            PartitioningKind::Balanced => self.get_next_partition_id(),
-            PartitioningKind::PartitionId => u32::from_le_bytes(
+        let confirmation = confirmation.max(Self::get_confirmation_level(&mut messages)?);
+        let validation_time = validation_started_at.elapsed();
+        let partitioning_started_at = Instant::now();
+        let partition_id = self.get_partition_id(partitioning).await?;
+        let partitioning_time = partitioning_started_at.elapsed();
+
+        let mut timings = self
+            .append_messages_to_partition(messages, partition_id, confirmation, now)
+            .await?;
+        timings.validation += validation_time;
+        timings.partitioning += partitioning_time;
+        Ok(timings)
+    }
+
+    /// Returns the ID of the partition the messages will be appended to, for the given partitioning.
+    pub async fn get_partition_id(&self, partitioning: &Partitioning) -> Result<u32, IggyError> {
+        match partitioning.kind {
+            PartitioningKind::Balanced => Ok(self.get_next_partition_id()),
+            PartitioningKind::PartitionId => Ok(u32::from_le_bytes(
                 partitioning.value[..partitioning.length as usize]
                     .try_into()
                     .map_err(|_| IggyError::InvalidNumberEncoding)?,
-            ),
-            PartitioningKind::MessagesKey => {
-                self.calculate_partition_id_by_messages_key_hash(&partitioning.value)
-            }
-        };
+            )),
+            PartitioningKind::MessagesKey => self.route_messages_key(&partitioning.value).await,
+        }
+    }
 
-        self.append_messages_to_partition(messages, partition_id, confirmation)
-            .await
+    /// Returns the strongest confirmation level requested by the headers of the messages about to be appended.
+    fn get_confirmation_level(
+        messages: &mut IggyMessagesMut,
+    ) -> Result<ConfirmationLevel, IggyError> {
+        let mut confirmation_level = ConfirmationLevel::default();
+        let mut messages = messages.iter_mut();
+        while let Some(message) = messages.next() {
+            let Some(user_headers) = message.user_headers() else {
+                continue;
+            };
+
+            let headers = HashMap::<HeaderKey, HeaderValue>::from_bytes(Bytes::copy_from_slice(
+                user_headers,
+            ))?;
+            if let Some(level) = confirmation::get_confirmation_level(&headers) {
+                confirmation_level = confirmation_level.max(level);
+            }
+        }
+        Ok(confirmation_level)
     }
 
     pub async fn flush_unsaved_buffer(
         &self,
         partition_id: u32,
         fsync: bool,
+        now: IggyTimestamp,
     ) -> Result<(), IggyError> {
         let partition = self.partitions.get(&partition_id);
         partition
@@ -143,34 +271,78 @@ impl Topic {
             ))?
             .write()
             .await
-            .flush_unsaved_buffer(fsync)
+            .flush_unsaved_buffer(fsync, now)
             .await
     }
 
-    async fn append_messages_to_partition(
+    /// Writes the buffered messages of the partitions to disk, once they've lingered for long enough.
+    pub async fn flush_lingering_messages(&self, now: IggyTimestamp) -> Result<u32, IggyError> {
+        let mut flushed_messages_count = 0;
+        for partition in self.get_partitions() {
+            if !partition.read().await.is_linger_due(now) {
+                continue;
+            }
+
+            flushed_messages_count += partition
+                .write()
+                .await
+                .flush_lingering_messages(now)
+                .await?;
+        }
+        Ok(flushed_messages_count)
+    }
+
+    pub(crate) async fn append_messages_to_partition(
         &self,
         appendable_batch_info: AppendableBatchInfo,
         messages: Vec<Message>,
-        confirmation: Option<Confirmation>,
-    ) -> Result<(), IggyError> {
-        let partition = self.partitions.get(&appendable_batch_info.partition_id);
-        partition
+        confirmation: ConfirmationLevel,
+        now: IggyTimestamp,
+    ) -> Result<BatchTimings, IggyError> {
+        let partition = self
+            .partitions
+            .get(&appendable_batch_info.partition_id)
             .ok_or({
                 IggyError::PartitionNotFound(
                     appendable_batch_info.partition_id,
                     self.stream_id,
                     self.stream_id,
                 )
-            })?
-            .write()
-            .await
-            .append_messages(appendable_batch_info, messages, confirmation)
+            })?;
+        let write_confirmation = match confirmation {
+            ConfirmationLevel::NoWait => Some(Confirmation::NoWait),
+            ConfirmationLevel::Received | ConfirmationLevel::Persisted => None,
+        };
+        let mut partition_guard = partition.write().await;
+        let mut timings = partition_guard
+            .append_messages(appendable_batch_info, messages, write_confirmation, now)
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to append messages")
             })?;
 
-        Ok(())
+        if confirmation != ConfirmationLevel::Persisted {
+            return Ok(timings);
+        }
+
+        let persistence_started_at = Instant::now();
+        // The lingering messages are written along with the others, thus the partition lock is released while waiting
+        // for them to be written by another append or the background flusher, at most for the linger time.
+        if let Some(flush_receiver) = partition_guard.subscribe_to_flush() {
+            drop(partition_guard);
+            let linger_time = self.config.partition.linger_time.get_duration();
+            let _ = time::timeout(linger_time, linger::wait_for_flush(flush_receiver)).await;
+            partition_guard = partition.write().await;
+        }
+
+        partition_guard
+            .flush_unsaved_buffer(true, now)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to persist appended messages, partition: {partition_guard}")
+            })?;
+        timings.persistence += persistence_started_at.elapsed();
+        Ok(timings)
     }
 
     fn get_next_partition_id(&self) -> u32 {
@@ -185,7 +357,7 @@ impl Topic {
         partition_id
     }
 
-    fn calculate_partition_id_by_messages_key_hash(&self, messages_key: &[u8]) -> u32 {
+    pub(crate) fn calculate_partition_id_by_messages_key_hash(&self, messages_key: &[u8]) -> u32 {
         let messages_key_hash = hash::calculate_32(messages_key);
         let partitions_count = self.get_partitions_count();
         let mut partition_id = messages_key_hash % partitions_count;
@@ -315,6 +487,10 @@ impl Topic {
         now: IggyTimestamp,
     ) -> AHashMap<u32, Vec<u64>> {
         let mut expired_segments = AHashMap::new();
+        if !self.retains_by_time() {
+            return expired_segments;
+        }
+
         if let IggyExpiry::ExpireDuration(_) = self.message_expiry {
             for (_, partition) in self.partitions.iter() {
                 let partition = partition.read().await;
@@ -359,7 +535,7 @@ mod tests {
                 .map(|msg| msg.get_size_bytes())
                 .sum::<IggyByteSize>();
             topic
-                .append_messages(batch_size, partitioning.clone(), messages, None)
+                .append_messages(batch_size, partitioning.clone(), messages, None, IggyTimestamp::now())
                 .await
                 .unwrap();
         }
@@ -391,7 +567,7 @@ mod tests {
                 .map(|msg| msg.get_size_bytes())
                 .sum::<IggyByteSize>();
             topic
-                .append_messages(batch_size, partitioning, messages, None)
+                .append_messages(batch_size, partitioning, messages, None, IggyTimestamp::now())
                 .await
                 .unwrap();
         }
@@ -16,14 +16,25 @@
  * under the License.
  */
 
+pub mod bookmarks;
 pub mod consumer_group;
+pub mod consumer_group_assignment;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod dead_letters;
+pub mod deletion;
+pub mod key_routing;
 pub mod messages;
 pub mod partitions;
 pub mod persistence;
+pub mod queue;
+pub mod redeliveries;
+pub mod sampling;
+pub mod schema;
 pub mod segments;
 pub mod storage;
+pub mod throttling;
 pub mod topic;
+pub mod transactions;
 
 pub const COMPONENT: &str = "STREAMING_TOPICS";
@@ -16,14 +16,18 @@
  * under the License.
  */
 
+use crate::streaming::partitions::migration;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::topics::COMPONENT;
+use crate::streaming::utils::clock::SharedClock;
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
 use iggy::utils::timestamp::IggyTimestamp;
+use tracing::{error, info};
 
 const MAX_PARTITIONS_COUNT: u32 = 100_000;
 
@@ -36,6 +40,123 @@ impl Topic {
         self.partitions.len() as u32
     }
 
+    pub fn get_partition_by_identifier(
+        &self,
+        identifier: &Identifier,
+    ) -> Result<IggySharedMut<Partition>, IggyError> {
+        match identifier.kind {
+            IdKind::Numeric => self.get_partition(identifier.get_u32_value()?),
+            IdKind::String => self.get_partition_by_name(&identifier.get_cow_str_value()?),
+        }
+    }
+
+    pub fn try_get_partition(
+        &self,
+        identifier: &Identifier,
+    ) -> Result<Option<IggySharedMut<Partition>>, IggyError> {
+        match identifier.kind {
+            IdKind::Numeric => Ok(self.partitions.get(&identifier.get_u32_value()?).cloned()),
+            IdKind::String => Ok(self
+                .partitions_ids
+                .get(identifier.get_cow_str_value()?.as_ref())
+                .and_then(|partition_id| self.partitions.get(partition_id))
+                .cloned()),
+        }
+    }
+
+    fn get_partition_by_name(&self, name: &str) -> Result<IggySharedMut<Partition>, IggyError> {
+        self.partitions_ids
+            .get(name)
+            .map(|partition_id| self.get_partition(*partition_id))
+            .ok_or_else(|| {
+                IggyError::PartitionNameNotFound(name.to_owned(), self.topic_id, self.stream_id)
+            })?
+    }
+
+    pub async fn update_partition(
+        &mut self,
+        identifier: &Identifier,
+        name: Option<&str>,
+    ) -> Result<u32, IggyError> {
+        let partition = self.get_partition_by_identifier(identifier)?;
+        let mut partition = partition.write().await;
+        if let Some(name) = name {
+            if let Some(partition_id) = self.partitions_ids.get(name) {
+                if *partition_id != partition.partition_id {
+                    return Err(IggyError::PartitionNameAlreadyExists(
+                        name.to_owned(),
+                        self.topic_id,
+                        self.stream_id,
+                    ));
+                }
+            }
+        }
+
+        if let Some(old_name) = partition.name.take() {
+            self.partitions_ids.remove(&old_name);
+        }
+
+        if let Some(name) = name {
+            self.partitions_ids
+                .insert(name.to_owned(), partition.partition_id);
+            partition.name = Some(name.to_owned());
+        }
+
+        info!(
+            "Updated partition with ID: {} for topic with ID: {} and stream with ID: {}, name: {}.",
+            partition.partition_id,
+            self.topic_id,
+            self.stream_id,
+            name.unwrap_or("none")
+        );
+        Ok(partition.partition_id)
+    }
+
+    /// Starts moving the data of the partition to the provided data directory in the background.
+    pub async fn move_partition(
+        &self,
+        identifier: &Identifier,
+        directory: &str,
+        clock: SharedClock,
+    ) -> Result<(), IggyError> {
+        if !self.config.is_data_directory(directory) {
+            return Err(IggyError::InvalidDataDirectory(directory.to_owned()));
+        }
+
+        let partition = self.get_partition_by_identifier(identifier)?;
+        let mut partition_guard = partition.write().await;
+        if partition_guard.is_moving {
+            return Err(IggyError::PartitionAlreadyMoving(
+                partition_guard.partition_id,
+                self.topic_id,
+                self.stream_id,
+            ));
+        }
+
+        let target_path = self.config.get_data_directory_partition_path(
+            directory,
+            self.stream_id,
+            self.topic_id,
+            partition_guard.partition_id,
+        );
+        if partition_guard.get_data_path().await == target_path {
+            info!(
+                "Partition with ID: {} for topic with ID: {} and stream with ID: {} is already stored in directory: {directory}.",
+                partition_guard.partition_id, self.topic_id, self.stream_id
+            );
+            return Ok(());
+        }
+
+        partition_guard.is_moving = true;
+        drop(partition_guard);
+        tokio::spawn(async move {
+            if let Err(error) = migration::move_partition(partition, target_path, clock).await {
+                error!("{COMPONENT} (error: {error}) - failed to move partition");
+            }
+        });
+        Ok(())
+    }
+
     pub async fn add_partitions(&mut self, count: u32) -> Result<Vec<u32>, IggyError> {
         if count == 0 {
             return Ok(vec![]);
@@ -48,7 +169,7 @@ impl Topic {
 
         let mut partition_ids = Vec::with_capacity(count as usize);
         for partition_id in current_partitions_count + 1..=current_partitions_count + count {
-            let partition = Partition::create(
+            let mut partition = Partition::create(
                 self.stream_id,
                 self.topic_id,
                 partition_id,
@@ -64,6 +185,14 @@ impl Topic {
                 IggyTimestamp::now(),
             )
             .await;
+            partition.set_fsync_policy(self.fsync_policy.clone());
+            partition.set_segment_rollover_policy(self.segment_rollover_policy.clone());
+            partition
+                .set_queue_policy(self.queue_policy.clone())
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to set queue policy for partition with ID: {partition_id}")
+                })?;
             self.partitions
                 .insert(partition_id, IggySharedMut::new(partition));
             partition_ids.push(partition_id)
@@ -110,6 +239,9 @@ impl Topic {
         for partition_id in current_partitions_count - count + 1..=current_partitions_count {
             let partition = self.partitions.remove(&partition_id).unwrap();
             let mut partition = partition.write().await;
+            if let Some(name) = &partition.name {
+                self.partitions_ids.remove(name);
+            }
             let partition_messages_count = partition.get_messages_count();
             segments_count += partition.get_segments_count();
             messages_count += partition_messages_count;
@@ -120,6 +252,12 @@ impl Topic {
                 )
             })?;
         }
+        self.remove_stale_key_routes().await.with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to remove key routes to deleted partitions in topic with ID: {}",
+                self.topic_id
+            )
+        })?;
         Ok(Some(DeletedPartitions {
             segments_count,
             messages_count,
@@ -131,3 +269,112 @@ pub struct DeletedPartitions {
     pub segments_count: u32,
     pub messages_count: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
+    use crate::streaming::storage::SystemStorage;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
+    use iggy::utils::expiry::IggyExpiry;
+    use iggy::utils::topic_size::MaxTopicSize;
+    use std::sync::atomic::{AtomicU32, AtomicU64};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn partition_should_be_found_by_name_after_update() {
+        let mut topic = init_topic(2).await;
+
+        let partition_id = topic
+            .update_partition(&Identifier::numeric(2).unwrap(), Some("orders-eu"))
+            .await
+            .unwrap();
+
+        assert_eq!(partition_id, 2);
+        let partition = topic
+            .get_partition_by_identifier(&Identifier::named("orders-eu").unwrap())
+            .unwrap();
+        let partition = partition.read().await;
+        assert_eq!(partition.partition_id, 2);
+        assert_eq!(partition.name.as_deref(), Some("orders-eu"));
+    }
+
+    #[tokio::test]
+    async fn partition_name_should_be_unique_within_topic() {
+        let mut topic = init_topic(2).await;
+        topic
+            .update_partition(&Identifier::numeric(1).unwrap(), Some("orders-eu"))
+            .await
+            .unwrap();
+
+        let result = topic
+            .update_partition(&Identifier::numeric(2).unwrap(), Some("orders-eu"))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(IggyError::PartitionNameAlreadyExists(_, _, _))
+        ));
+    }
+
+    #[tokio::test]
+    async fn previous_partition_name_should_be_released_when_renamed_or_removed() {
+        let mut topic = init_topic(1).await;
+        topic
+            .update_partition(&Identifier::numeric(1).unwrap(), Some("orders-eu"))
+            .await
+            .unwrap();
+        topic
+            .update_partition(&Identifier::named("orders-eu").unwrap(), Some("orders-us"))
+            .await
+            .unwrap();
+
+        assert!(topic
+            .try_get_partition(&Identifier::named("orders-eu").unwrap())
+            .unwrap()
+            .is_none());
+        assert!(topic
+            .try_get_partition(&Identifier::named("orders-us").unwrap())
+            .unwrap()
+            .is_some());
+
+        topic
+            .update_partition(&Identifier::numeric(1).unwrap(), None)
+            .await
+            .unwrap();
+
+        assert!(topic.partitions_ids.is_empty());
+        let partition = topic.get_partition(1).unwrap();
+        assert!(partition.read().await.name.is_none());
+    }
+
+    async fn init_topic(partitions_count: u32) -> Topic {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let config = Arc::new(SystemConfig {
+            path: tempdir.path().to_str().unwrap().to_string(),
+            ..Default::default()
+        });
+        let storage = Arc::new(SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        ));
+        Topic::create(
+            1,
+            1,
+            "test",
+            partitions_count,
+            config,
+            storage,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
+            MaxTopicSize::ServerDefault,
+            1,
+        )
+        .await
+        .unwrap()
+    }
+}
@@ -22,6 +22,7 @@ use crate::streaming::topics::COMPONENT;
 use error_set::ErrContext;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
+use iggy::utils::timestamp::IggyTimestamp;
 
 impl Topic {
     pub async fn load(&mut self, state: TopicState) -> Result<(), IggyError> {
@@ -61,6 +62,38 @@ impl Topic {
         Ok(saved_messages_number)
     }
 
+    pub async fn fsync_unsynced_messages(&self, now: IggyTimestamp) -> Result<(), IggyError> {
+        for partition in self.get_partitions() {
+            let mut partition = partition.write().await;
+            let partition_id = partition.partition_id;
+            partition.fsync_unsynced_messages(now).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to fsync messages, partition ID: {partition_id}")
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Syncs the partitions, for which the fsync policy is due, and returns the count of the synced messages.
+    pub async fn fsync_due_partitions(&self, now: IggyTimestamp) -> Result<u32, IggyError> {
+        if self.fsync_policy.is_none() {
+            return Ok(0);
+        }
+
+        let mut synced_messages_count = 0;
+        for partition in self.get_partitions() {
+            if !partition.read().await.should_fsync(now) {
+                continue;
+            }
+
+            let mut partition = partition.write().await;
+            let partition_id = partition.partition_id;
+            synced_messages_count += partition.fsync_if_due(now).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to fsync messages, partition ID: {partition_id}")
+            })?;
+        }
+        Ok(synced_messages_count)
+    }
+
     pub async fn purge(&self) -> Result<(), IggyError> {
         for partition in self.get_partitions() {
             let mut partition = partition.write().await;
@@ -0,0 +1,188 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::topics::COMPONENT;
+use error_set::ErrContext;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::messages::PolledMessages;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::trace;
+
+impl Topic {
+    /// Leases up to `count` messages to the client polling the topic in the queue mode, so that they aren't delivered
+    /// to any other client until their visibility timeout elapses. Unless the partition ID is specified, the partitions
+    /// are visited starting from the one based on the client ID, to spread the clients sharing the work across them.
+    pub async fn lease_queue_messages(
+        &self,
+        partition_id: Option<u32>,
+        client_id: u32,
+        count: u32,
+        now: IggyTimestamp,
+    ) -> Result<PolledMessages, IggyError> {
+        self.ensure_queue_mode()?;
+        let partition_ids = match partition_id {
+            Some(partition_id) => vec![partition_id],
+            None => {
+                let mut partition_ids = self.partitions.keys().copied().collect::<Vec<_>>();
+                partition_ids.sort_unstable();
+                let start = client_id as usize % partition_ids.len();
+                partition_ids.rotate_left(start);
+                partition_ids
+            }
+        };
+
+        let mut current_offset = 0;
+        for partition_id in &partition_ids {
+            let partition_id = *partition_id;
+            let partition = self.get_partition(partition_id).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}")
+            })?;
+            let partition = partition.read().await;
+            current_offset = partition.current_offset;
+            let offsets = partition.lease_queue_messages(count, now);
+            if offsets.is_empty() {
+                continue;
+            }
+
+            let mut messages = Vec::with_capacity(offsets.len());
+            let mut missing_offsets = Vec::new();
+            for (first_offset, last_offset) in get_offset_ranges(&offsets) {
+                let retained_messages = partition
+                    .get_messages_by_offset(first_offset, (last_offset - first_offset + 1) as u32)
+                    .await
+                    .with_error_context(|error| {
+                        format!("{COMPONENT} (error: {error}) - failed to get messages by offset: {first_offset}, partition ID: {partition_id}")
+                    })?;
+                let mut retained_messages = retained_messages.into_iter().peekable();
+                for offset in first_offset..=last_offset {
+                    while retained_messages
+                        .next_if(|message| message.offset < offset)
+                        .is_some()
+                    {}
+                    match retained_messages.next_if(|message| message.offset == offset) {
+                        Some(message) if !message.is_expired(now) => {
+                            messages.push(message.to_polled_message()?)
+                        }
+                        // The message might have been already removed e.g. due to the retention, or its TTL might have elapsed.
+                        _ => missing_offsets.push(offset),
+                    }
+                }
+            }
+
+            if !missing_offsets.is_empty() {
+                trace!(
+                    "{} leased message(s) were not found in partition with ID: {partition_id}, acknowledging them.",
+                    missing_offsets.len()
+                );
+                if let Some(acknowledged_offset) =
+                    partition.acknowledge_queue_messages(&missing_offsets)
+                {
+                    partition
+                        .store_queue_offset(acknowledged_offset)
+                        .await
+                        .with_error_context(|error| {
+                            format!("{COMPONENT} (error: {error}) - failed to store queue offset: {acknowledged_offset}, partition ID: {partition_id}")
+                        })?;
+                }
+            }
+
+            if messages.is_empty() {
+                continue;
+            }
+
+            return Ok(PolledMessages {
+                partition_id,
+                current_offset,
+                messages,
+                next_offset: None,
+            });
+        }
+
+        Ok(PolledMessages {
+            partition_id: partition_ids.first().copied().unwrap_or_default(),
+            current_offset,
+            messages: Vec::new(),
+            next_offset: None,
+        })
+    }
+
+    /// Acknowledges the messages leased from the partition, so that they're never redelivered.
+    pub async fn ack_queue_messages(
+        &self,
+        partition_id: u32,
+        offsets: &[u64],
+    ) -> Result<(), IggyError> {
+        self.ensure_queue_mode()?;
+        let partition = self.get_partition(partition_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}")
+        })?;
+        let partition = partition.read().await;
+        let Some(acknowledged_offset) = partition.acknowledge_queue_messages(offsets) else {
+            return Ok(());
+        };
+
+        partition
+            .store_queue_offset(acknowledged_offset)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to store queue offset: {acknowledged_offset}, partition ID: {partition_id}")
+            })
+    }
+
+    /// Makes the messages leased from the partition visible again at the given time.
+    pub async fn release_queue_messages(
+        &self,
+        partition_id: u32,
+        offsets: &[u64],
+        visible_at: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        self.ensure_queue_mode()?;
+        let partition = self.get_partition(partition_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}")
+        })?;
+        partition
+            .read()
+            .await
+            .release_queue_messages(offsets, visible_at);
+        Ok(())
+    }
+
+    fn ensure_queue_mode(&self) -> Result<(), IggyError> {
+        if self.queue_policy.is_none() {
+            return Err(IggyError::TopicNotInQueueMode(
+                self.topic_id,
+                self.stream_id,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Groups the sorted offsets into the ranges of the consecutive ones, so that each range can be read at once.
+fn get_offset_ranges(offsets: &[u64]) -> Vec<(u64, u64)> {
+    let mut ranges: Vec<(u64, u64)> = Vec::new();
+    for offset in offsets {
+        match ranges.last_mut() {
+            Some((_, last_offset)) if *last_offset + 1 == *offset => *last_offset = *offset,
+            _ => ranges.push((*offset, *offset)),
+        }
+    }
+    ranges
+}
@@ -0,0 +1,112 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::topics::COMPONENT;
+use error_set::ErrContext;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::messages::PolledMessages;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::trace;
+
+impl Topic {
+    pub async fn nack_messages(
+        &self,
+        consumer: &Consumer,
+        partition_id: Option<u32>,
+        offsets: &[u64],
+        client_id: u32,
+        redeliver_at: IggyTimestamp,
+    ) -> Result<PollingConsumer, IggyError> {
+        let Some((polling_consumer, partition_id)) = self
+            .resolve_consumer_with_partition_id(consumer, client_id, partition_id, None)
+            .await
+            .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to resolve consumer with partition id, consumer ID: {}, client ID: {}, partition ID: {:?}", consumer.id, client_id, partition_id))? else {
+            return Err(IggyError::ConsumerOffsetNotFound(client_id));
+        };
+
+        let partition = self.get_partition(partition_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}")
+        })?;
+        let partition = partition.read().await;
+        for offset in offsets {
+            partition
+                .register_delivery_failure(polling_consumer, *offset)
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to register delivery failure, consumer: {polling_consumer}, offset: {offset}"))?;
+            partition
+                .schedule_redelivery(polling_consumer, *offset, redeliver_at)
+                .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to schedule redelivery, consumer: {polling_consumer}, offset: {offset}"))?;
+        }
+
+        Ok(polling_consumer)
+    }
+
+    /// Returns the negatively acknowledged messages which are due for the redelivery to the consumer, if any.
+    pub async fn get_redelivered_messages(
+        &self,
+        consumer: PollingConsumer,
+        partition_id: u32,
+        now: IggyTimestamp,
+        count: u32,
+    ) -> Result<Option<PolledMessages>, IggyError> {
+        let partition = self.get_partition(partition_id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get partition with id: {partition_id}")
+        })?;
+        let partition = partition.read().await;
+        let offsets = partition.take_due_redeliveries(consumer, now, count);
+        if offsets.is_empty() {
+            return Ok(None);
+        }
+
+        let mut messages = Vec::with_capacity(offsets.len());
+        for offset in offsets {
+            let message = partition
+                .get_messages_by_offset(offset, 1)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to get message by offset: {offset}"
+                    )
+                })?
+                .into_iter()
+                .find(|message| message.offset == offset);
+            let Some(message) = message.filter(|message| !message.is_expired(now)) else {
+                // The message might have been already removed e.g. due to the expiry, or its TTL might have elapsed.
+                trace!("Redelivered message with offset: {offset} was not found in partition with ID: {partition_id}, skipping.");
+                partition.clear_delivery_attempts(consumer, offset);
+                continue;
+            };
+
+            messages.push(message.to_polled_message()?);
+        }
+
+        if messages.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(PolledMessages {
+            partition_id,
+            current_offset: partition.current_offset,
+            messages,
+            next_offset: None,
+        }))
+    }
+}
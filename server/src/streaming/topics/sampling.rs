@@ -0,0 +1,221 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::segments::IggyMessagesMut;
+use crate::streaming::session::Session;
+use crate::streaming::topics::topic::Topic;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::models::message_sampling_policy::{
+    SAMPLED_AT_HEADER, SAMPLED_MESSAGE_ID_HEADER, SAMPLED_ORIGIN_TIMESTAMP_HEADER,
+    SAMPLED_PAYLOAD_LENGTH_HEADER, SAMPLED_PRODUCER_ADDRESS_HEADER,
+    SAMPLED_PRODUCER_CLIENT_ID_HEADER, SAMPLED_PRODUCER_USER_ID_HEADER,
+    SAMPLED_SOURCE_STREAM_ID_HEADER, SAMPLED_SOURCE_TOPIC_ID_HEADER,
+};
+use iggy::prelude::*;
+use lending_iterator::prelude::*;
+use rand::Rng;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+impl Topic {
+    /// Creates the diagnostics messages for the randomly sampled fraction of the messages about to be appended,
+    /// according to the sampling policy of the topic. Returns no messages if the policy is not configured.
+    pub fn sample_messages(
+        &self,
+        messages: &mut IggyMessagesMut,
+        session: &Session,
+        sampled_at: IggyTimestamp,
+    ) -> Result<Vec<IggyMessage>, IggyError> {
+        let Some(sampling_policy) = &self.sampling_policy else {
+            return Ok(Vec::new());
+        };
+
+        let mut rng = rand::rng();
+        let mut sampled_messages = Vec::new();
+        let mut messages = messages.iter_mut();
+        while let Some(message) = messages.next() {
+            if !rng.random_bool(sampling_policy.sampling_rate) {
+                continue;
+            }
+
+            let header = message.msg_header();
+            let payload = message.payload();
+            let truncated_length = payload.len().min(sampling_policy.max_payload_size as usize);
+            let mut headers = HashMap::new();
+            headers.insert(
+                HeaderKey::new(SAMPLED_SOURCE_STREAM_ID_HEADER)?,
+                HeaderValue::from_uint32(self.stream_id)?,
+            );
+            headers.insert(
+                HeaderKey::new(SAMPLED_SOURCE_TOPIC_ID_HEADER)?,
+                HeaderValue::from_uint32(self.topic_id)?,
+            );
+            headers.insert(
+                HeaderKey::new(SAMPLED_MESSAGE_ID_HEADER)?,
+                HeaderValue::from_uint128(header.id())?,
+            );
+            headers.insert(
+                HeaderKey::new(SAMPLED_ORIGIN_TIMESTAMP_HEADER)?,
+                HeaderValue::from_uint64(header.origin_timestamp())?,
+            );
+            headers.insert(
+                HeaderKey::new(SAMPLED_PAYLOAD_LENGTH_HEADER)?,
+                HeaderValue::from_uint32(payload.len() as u32)?,
+            );
+            headers.insert(
+                HeaderKey::new(SAMPLED_PRODUCER_USER_ID_HEADER)?,
+                HeaderValue::from_uint32(session.get_user_id())?,
+            );
+            headers.insert(
+                HeaderKey::new(SAMPLED_PRODUCER_CLIENT_ID_HEADER)?,
+                HeaderValue::from_uint32(session.client_id)?,
+            );
+            headers.insert(
+                HeaderKey::new(SAMPLED_PRODUCER_ADDRESS_HEADER)?,
+                HeaderValue::from_str(&session.ip_address.to_string())?,
+            );
+            headers.insert(
+                HeaderKey::new(SAMPLED_AT_HEADER)?,
+                HeaderValue::from_uint64(sampled_at.as_micros())?,
+            );
+            sampled_messages.push(
+                IggyMessage::builder()
+                    .payload(Bytes::copy_from_slice(&payload[..truncated_length]))
+                    .headers(headers)
+                    .build(),
+            );
+        }
+
+        Ok(sampled_messages)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::persistence::persister::{FileWithSyncPersister, PersisterKind};
+    use crate::streaming::storage::SystemStorage;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
+    use iggy::models::message_sampling_policy::MessageSamplingPolicy;
+    use iggy::utils::expiry::IggyExpiry;
+    use iggy::utils::topic_size::MaxTopicSize;
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::sync::atomic::{AtomicU32, AtomicU64};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn should_sample_all_messages_with_truncated_payload_and_producer_headers() {
+        let mut topic = get_topic().await;
+        topic.sampling_policy = Some(MessageSamplingPolicy::new(
+            Identifier::numeric(1).unwrap(),
+            Identifier::named("diagnostics").unwrap(),
+            1.0,
+            4,
+        ));
+        let session = Session::new(3, 5, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+        let mut messages = IggyMessagesMut::from(
+            [
+                IggyMessage::with_id(1, Bytes::from("payload-1")),
+                IggyMessage::with_id(2, Bytes::from("ab")),
+            ]
+            .as_slice(),
+        );
+
+        let sampled_messages = topic
+            .sample_messages(&mut messages, &session, IggyTimestamp::from(100))
+            .unwrap();
+
+        assert_eq!(sampled_messages.len(), 2);
+        assert_eq!(sampled_messages[0].payload, Bytes::from("payl"));
+        assert_eq!(sampled_messages[1].payload, Bytes::from("ab"));
+        let headers = sampled_messages[0].headers.as_ref().unwrap();
+        let get_header = |key: &str| headers.get(&HeaderKey::new(key).unwrap()).unwrap();
+        assert_eq!(
+            get_header(SAMPLED_PAYLOAD_LENGTH_HEADER)
+                .as_uint32()
+                .unwrap(),
+            9
+        );
+        assert_eq!(
+            get_header(SAMPLED_PRODUCER_USER_ID_HEADER)
+                .as_uint32()
+                .unwrap(),
+            5
+        );
+        assert_eq!(
+            get_header(SAMPLED_PRODUCER_CLIENT_ID_HEADER)
+                .as_uint32()
+                .unwrap(),
+            3
+        );
+        assert_eq!(
+            get_header(SAMPLED_SOURCE_TOPIC_ID_HEADER)
+                .as_uint32()
+                .unwrap(),
+            topic.topic_id
+        );
+        assert_eq!(get_header(SAMPLED_AT_HEADER).as_uint64().unwrap(), 100);
+        assert_eq!(messages.count(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_not_sample_messages_without_sampling_policy() {
+        let topic = get_topic().await;
+        let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+        let mut messages =
+            IggyMessagesMut::from([IggyMessage::new(Bytes::from("payload"))].as_slice());
+
+        let sampled_messages = topic
+            .sample_messages(&mut messages, &session, IggyTimestamp::now())
+            .unwrap();
+
+        assert!(sampled_messages.is_empty());
+    }
+
+    async fn get_topic() -> Topic {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let config = Arc::new(SystemConfig {
+            path: tempdir.path().to_str().unwrap().to_string(),
+            ..Default::default()
+        });
+        let storage = Arc::new(SystemStorage::new(
+            config.clone(),
+            Arc::new(PersisterKind::FileWithSync(FileWithSyncPersister {})),
+        ));
+
+        Topic::create(
+            1,
+            2,
+            "test",
+            1,
+            config,
+            storage,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            IggyExpiry::NeverExpire,
+            CompressionAlgorithm::None,
+            MaxTopicSize::ServerDefault,
+            1,
+        )
+        .await
+        .unwrap()
+    }
+}
@@ -0,0 +1,142 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::error::IggyError;
+use iggy::models::topic_schema::{SchemaKind, TopicSchema};
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor};
+use std::fmt::{Debug, Formatter};
+
+/// The topic schema along with its compiled validator, used to validate the payloads of the appended messages.
+pub struct MessageSchema {
+    pub schema: TopicSchema,
+    validator: SchemaValidator,
+}
+
+enum SchemaValidator {
+    Json(jsonschema::Validator),
+    Protobuf(MessageDescriptor),
+}
+
+impl MessageSchema {
+    pub fn new(schema: TopicSchema) -> Result<Self, IggyError> {
+        let validator = match schema.kind {
+            SchemaKind::Json => {
+                let definition = serde_json::from_slice(&schema.definition).map_err(|error| {
+                    IggyError::InvalidTopicSchema(format!("invalid JSON document: {error}"))
+                })?;
+                let validator = jsonschema::validator_for(&definition).map_err(|error| {
+                    IggyError::InvalidTopicSchema(format!("invalid JSON Schema: {error}"))
+                })?;
+                SchemaValidator::Json(validator)
+            }
+            SchemaKind::Protobuf => {
+                let message_type = schema.message_type.as_deref().ok_or_else(|| {
+                    IggyError::InvalidTopicSchema("missing protobuf message type".to_string())
+                })?;
+                let pool = DescriptorPool::decode(schema.definition.clone()).map_err(|error| {
+                    IggyError::InvalidTopicSchema(format!(
+                        "invalid protobuf file descriptor set: {error}"
+                    ))
+                })?;
+                let descriptor = pool.get_message_by_name(message_type).ok_or_else(|| {
+                    IggyError::InvalidTopicSchema(format!(
+                        "protobuf message type: {message_type} was not found in the descriptor set"
+                    ))
+                })?;
+                SchemaValidator::Protobuf(descriptor)
+            }
+        };
+
+        Ok(Self { schema, validator })
+    }
+
+    /// Validates the message payload against the schema.
+    /// The protobuf payload is considered valid if it can be decoded as the configured message type.
+    pub fn validate(&self, payload: &[u8]) -> Result<(), IggyError> {
+        match &self.validator {
+            SchemaValidator::Json(validator) => {
+                let instance = serde_json::from_slice(payload).map_err(|error| {
+                    IggyError::MessageSchemaViolation(format!("invalid JSON payload: {error}"))
+                })?;
+                validator
+                    .validate(&instance)
+                    .map_err(|error| IggyError::MessageSchemaViolation(error.to_string()))
+            }
+            SchemaValidator::Protobuf(descriptor) => {
+                DynamicMessage::decode(descriptor.clone(), payload)
+                    .map(|_| ())
+                    .map_err(|error| IggyError::MessageSchemaViolation(error.to_string()))
+            }
+        }
+    }
+}
+
+impl Debug for MessageSchema {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MessageSchema({})", self.schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER_SCHEMA: &str = r#"{
+        "type": "object",
+        "properties": {
+            "id": { "type": "integer" },
+            "product": { "type": "string" }
+        },
+        "required": ["id", "product"]
+    }"#;
+
+    #[test]
+    fn json_payload_conforming_to_schema_should_be_valid() {
+        let schema = MessageSchema::new(TopicSchema::json(ORDER_SCHEMA)).unwrap();
+        assert!(schema.validate(br#"{"id": 1, "product": "book"}"#).is_ok());
+    }
+
+    #[test]
+    fn json_payload_not_conforming_to_schema_should_be_rejected() {
+        let schema = MessageSchema::new(TopicSchema::json(ORDER_SCHEMA)).unwrap();
+        let result = schema.validate(br#"{"id": "1"}"#);
+        assert!(matches!(result, Err(IggyError::MessageSchemaViolation(_))));
+    }
+
+    #[test]
+    fn non_json_payload_should_be_rejected() {
+        let schema = MessageSchema::new(TopicSchema::json(ORDER_SCHEMA)).unwrap();
+        let result = schema.validate(b"not a json");
+        assert!(matches!(result, Err(IggyError::MessageSchemaViolation(_))));
+    }
+
+    #[test]
+    fn invalid_json_schema_should_not_be_compiled() {
+        let result = MessageSchema::new(TopicSchema::json(r#"{"type": 1}"#));
+        assert!(matches!(result, Err(IggyError::InvalidTopicSchema(_))));
+    }
+
+    #[test]
+    fn invalid_protobuf_descriptor_should_not_be_compiled() {
+        let result = MessageSchema::new(TopicSchema::protobuf(
+            bytes::Bytes::from_static(&[0xff, 0xff, 0xff]),
+            "orders.OrderCreated",
+        ));
+        assert!(matches!(result, Err(IggyError::InvalidTopicSchema(_))));
+    }
+}
@@ -17,7 +17,15 @@
  */
 
 use crate::streaming::topics::topic::Topic;
+use crate::streaming::topics::COMPONENT;
+use ahash::AHashMap;
+use error_set::ErrContext;
+use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
+use iggy::utils::sizeable::Sizeable;
+use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::collections::VecDeque;
 
 impl Topic {
     pub async fn get_segments_count(&self) -> u32 {
@@ -28,4 +36,315 @@ impl Topic {
 
         segments_count
     }
+
+    /// Returns the start offsets of the oldest closed segments (per partition) that have to be deleted
+    /// for each partition to fit within the max partition size and for the topic to fit within the max topic size.
+    /// Only applicable to the topics with the size-based retention policy.
+    pub async fn get_oversized_segments_start_offsets_per_partition(
+        &self,
+    ) -> AHashMap<u32, Vec<u64>> {
+        if !self.retains_by_size() {
+            return AHashMap::new();
+        }
+
+        let max_topic_size = match self.max_topic_size {
+            MaxTopicSize::Custom(size) => Some(size.as_bytes_u64()),
+            MaxTopicSize::Unlimited | MaxTopicSize::ServerDefault => None,
+        };
+        let max_partition_size = self
+            .get_max_partition_size()
+            .map(|size| size.as_bytes_u64());
+        if max_topic_size.is_none() && max_partition_size.is_none() {
+            return AHashMap::new();
+        }
+
+        let mut partitions = Vec::with_capacity(self.partitions.len());
+        for partition in self.partitions.values() {
+            let partition = partition.read().await;
+            partitions.push(PartitionSegments {
+                partition_id: partition.partition_id,
+                size_bytes: partition.get_size_bytes().as_bytes_u64(),
+                closed_segments: partition
+                    .get_segments()
+                    .iter()
+                    .filter(|segment| segment.is_closed)
+                    .map(|segment| (segment.start_offset, segment.size_bytes.as_bytes_u64()))
+                    .collect(),
+            });
+        }
+
+        select_oversized_segments(partitions, max_topic_size, max_partition_size)
+    }
+
+    /// Returns the start offsets of the oldest closed segments (per partition) which are still stored locally
+    /// and have to be offloaded to the tiered storage, as they're older than the min segment age
+    /// or the partition exceeds the max local size. Only applicable to the topics with the tiering policy.
+    pub async fn get_segments_to_offload_per_partition(
+        &self,
+        now: IggyTimestamp,
+    ) -> AHashMap<u32, Vec<u64>> {
+        let Some(tiering_policy) = &self.tiering_policy else {
+            return AHashMap::new();
+        };
+
+        let min_segment_age = tiering_policy.min_segment_age.map(|age| age.as_micros());
+        let max_local_size = tiering_policy
+            .max_local_partition_size
+            .map(|size| size.as_bytes_u64());
+        let mut segments_to_offload = AHashMap::new();
+        for partition in self.partitions.values() {
+            let partition = partition.read().await;
+            let mut local_size_bytes = 0;
+            let mut local_segments = Vec::new();
+            for segment in partition
+                .get_segments()
+                .iter()
+                .filter(|segment| !segment.is_tiered())
+            {
+                local_size_bytes += segment.size_bytes.as_bytes_u64();
+                if !segment.is_closed {
+                    continue;
+                }
+
+                let end_timestamp = match min_segment_age {
+                    Some(_) => segment.get_last_message_timestamp().await,
+                    None => None,
+                };
+                local_segments.push(LocalSegment {
+                    start_offset: segment.start_offset,
+                    size_bytes: segment.size_bytes.as_bytes_u64(),
+                    end_timestamp,
+                });
+            }
+
+            let start_offsets = select_segments_to_offload(
+                local_segments,
+                local_size_bytes,
+                min_segment_age,
+                max_local_size,
+                now.as_micros(),
+            );
+            if !start_offsets.is_empty() {
+                segments_to_offload.insert(partition.partition_id, start_offsets);
+            }
+        }
+
+        segments_to_offload
+    }
+
+    /// Closes the last segments of the partitions which are older than the max age of the segment rollover policy,
+    /// so that they can be tiered, deleted by the retention or backed up. Returns the number of closed segments.
+    pub async fn roll_over_segments(&self, now: IggyTimestamp) -> Result<u32, IggyError> {
+        if self.segment_rollover_policy.is_none() {
+            return Ok(0);
+        }
+
+        let mut rolled_over_segments_count = 0;
+        for partition in self.partitions.values() {
+            if !partition.read().await.is_segment_rollover_due(now) {
+                continue;
+            }
+
+            let mut partition = partition.write().await;
+            if partition.roll_over_segment(now).await.with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to roll over segment, partition ID: {}", partition.partition_id)
+            })? {
+                rolled_over_segments_count += 1;
+            }
+        }
+
+        Ok(rolled_over_segments_count)
+    }
+}
+
+struct PartitionSegments {
+    partition_id: u32,
+    size_bytes: u64,
+    closed_segments: VecDeque<(u64, u64)>,
+}
+
+/// Picks the oldest closed segments of each partition exceeding the max partition size,
+/// and then, as long as the topic exceeds the max topic size, the oldest closed segment of the largest partition.
+fn select_oversized_segments(
+    mut partitions: Vec<PartitionSegments>,
+    max_topic_size: Option<u64>,
+    max_partition_size: Option<u64>,
+) -> AHashMap<u32, Vec<u64>> {
+    let mut oversized_segments: AHashMap<u32, Vec<u64>> = AHashMap::new();
+    if let Some(max_partition_size) = max_partition_size {
+        for partition in partitions.iter_mut() {
+            while partition.size_bytes > max_partition_size {
+                let Some((start_offset, size_bytes)) = partition.closed_segments.pop_front() else {
+                    break;
+                };
+                partition.size_bytes = partition.size_bytes.saturating_sub(size_bytes);
+                oversized_segments
+                    .entry(partition.partition_id)
+                    .or_default()
+                    .push(start_offset);
+            }
+        }
+    }
+
+    if let Some(max_topic_size) = max_topic_size {
+        let mut topic_size: u64 = partitions
+            .iter()
+            .map(|partition| partition.size_bytes)
+            .sum();
+        while topic_size > max_topic_size {
+            let Some(partition) = partitions
+                .iter_mut()
+                .filter(|partition| !partition.closed_segments.is_empty())
+                .max_by_key(|partition| partition.size_bytes)
+            else {
+                break;
+            };
+            let (start_offset, size_bytes) = partition.closed_segments.pop_front().unwrap();
+            partition.size_bytes = partition.size_bytes.saturating_sub(size_bytes);
+            topic_size = topic_size.saturating_sub(size_bytes);
+            oversized_segments
+                .entry(partition.partition_id)
+                .or_default()
+                .push(start_offset);
+        }
+    }
+
+    oversized_segments
+}
+
+struct LocalSegment {
+    start_offset: u64,
+    size_bytes: u64,
+    end_timestamp: Option<u64>,
+}
+
+/// Picks the oldest closed local segments, as long as the segment is older than the min segment age,
+/// or the partition still exceeds the max local size.
+fn select_segments_to_offload(
+    segments: Vec<LocalSegment>,
+    mut local_size_bytes: u64,
+    min_segment_age: Option<u64>,
+    max_local_size: Option<u64>,
+    now: u64,
+) -> Vec<u64> {
+    let mut start_offsets = Vec::new();
+    for segment in segments {
+        let is_old = matches!(
+            (min_segment_age, segment.end_timestamp),
+            (Some(min_segment_age), Some(end_timestamp)) if end_timestamp + min_segment_age <= now
+        );
+        let is_oversized = max_local_size.is_some_and(|max_size| local_size_bytes > max_size);
+        if !is_old && !is_oversized {
+            break;
+        }
+
+        local_size_bytes = local_size_bytes.saturating_sub(segment.size_bytes);
+        start_offsets.push(segment.start_offset);
+    }
+
+    start_offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partition(
+        partition_id: u32,
+        segments: &[(u64, u64)],
+        active_size: u64,
+    ) -> PartitionSegments {
+        PartitionSegments {
+            partition_id,
+            size_bytes: segments.iter().map(|(_, size)| size).sum::<u64>() + active_size,
+            closed_segments: segments.iter().copied().collect(),
+        }
+    }
+
+    #[test]
+    fn should_select_oldest_segments_exceeding_max_partition_size() {
+        let partitions = vec![
+            partition(1, &[(0, 100), (10, 100), (20, 100)], 50),
+            partition(2, &[(0, 100)], 50),
+        ];
+
+        let segments = select_oversized_segments(partitions, None, Some(200));
+
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[&1], vec![0, 10]);
+    }
+
+    #[test]
+    fn should_select_oldest_segments_of_largest_partitions_exceeding_max_topic_size() {
+        let partitions = vec![
+            partition(1, &[(0, 100), (10, 100), (20, 100)], 0),
+            partition(2, &[(0, 100), (10, 100)], 0),
+        ];
+
+        let segments = select_oversized_segments(partitions, Some(300), None);
+
+        assert_eq!(segments[&1], vec![0]);
+        assert_eq!(segments[&2], vec![0]);
+    }
+
+    #[test]
+    fn should_never_select_active_segments() {
+        let partitions = vec![partition(1, &[(0, 100)], 500)];
+
+        let segments = select_oversized_segments(partitions, Some(100), Some(100));
+
+        assert_eq!(segments[&1], vec![0]);
+    }
+
+    #[test]
+    fn should_not_select_segments_within_limits() {
+        let partitions = vec![partition(1, &[(0, 100)], 50), partition(2, &[(0, 100)], 50)];
+
+        let segments = select_oversized_segments(partitions, Some(300), Some(150));
+
+        assert!(segments.is_empty());
+    }
+
+    fn local_segment(start_offset: u64, size_bytes: u64, end_timestamp: u64) -> LocalSegment {
+        LocalSegment {
+            start_offset,
+            size_bytes,
+            end_timestamp: Some(end_timestamp),
+        }
+    }
+
+    #[test]
+    fn should_select_segments_older_than_min_segment_age_to_offload() {
+        let segments = vec![
+            local_segment(0, 100, 10),
+            local_segment(10, 100, 20),
+            local_segment(20, 100, 90),
+        ];
+
+        let start_offsets = select_segments_to_offload(segments, 350, Some(50), None, 100);
+
+        assert_eq!(start_offsets, vec![0, 10]);
+    }
+
+    #[test]
+    fn should_select_oldest_segments_exceeding_max_local_size_to_offload() {
+        let segments = vec![
+            local_segment(0, 100, 10),
+            local_segment(10, 100, 20),
+            local_segment(20, 100, 30),
+        ];
+
+        let start_offsets = select_segments_to_offload(segments, 350, None, Some(200), 100);
+
+        assert_eq!(start_offsets, vec![0, 10]);
+    }
+
+    #[test]
+    fn should_not_select_segments_within_tiering_policy_to_offload() {
+        let segments = vec![local_segment(0, 100, 80), local_segment(10, 100, 90)];
+
+        let start_offsets = select_segments_to_offload(segments, 250, Some(50), Some(300), 100);
+
+        assert!(start_offsets.is_empty());
+    }
 }
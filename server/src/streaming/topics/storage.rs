@@ -203,18 +203,39 @@ impl TopicStorage for FileTopicStorage {
 
         join_all(load_partitions).await;
         for partition in loaded_partitions.lock().await.drain(..) {
+            if let Some(name) = &partition.name {
+                topic
+                    .partitions_ids
+                    .insert(name.to_owned(), partition.partition_id);
+            }
             topic
                 .partitions
                 .insert(partition.partition_id, IggySharedMut::new(partition));
         }
 
+        topic
+            .set_options(std::mem::take(&mut state.options))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to set options, topic: {topic}")
+            })?;
+
+        topic.load_key_routes().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to load key routes, topic: {topic}")
+        })?;
+        topic.load_bookmarks().await.with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to load bookmarks, topic: {topic}")
+        })?;
+
         for consumer_group in state.consumer_groups.into_values() {
             let consumer_group = ConsumerGroup::new(
                 topic.topic_id,
                 consumer_group.id,
                 &consumer_group.name,
                 topic.get_partitions_count(),
-            );
+                consumer_group.assignment_strategy,
+            )
+            .with_rebalance_delay(topic.config.consumer_group.rebalance_delay);
             topic
                 .consumer_groups_ids
                 .insert(consumer_group.name.to_owned(), consumer_group.group_id);
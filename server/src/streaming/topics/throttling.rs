@@ -0,0 +1,208 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::topics::topic::Topic;
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::info;
+
+const MICROS_PER_SECOND: u128 = 1_000_000;
+
+/// The temporary throughput cap of the topic, set at runtime (e.g. to stop a runaway producer) and kept only in memory.
+///
+/// The appended bytes are consumed from the bucket refilled at the max throughput rate, holding up to one second of burst.
+/// The batch is accepted as long as any bytes are available, so the batches larger than the max throughput
+/// are not rejected forever, and the resulting debt is paid off by the following ones.
+#[derive(Debug)]
+pub struct TopicThrottle {
+    pub max_throughput: IggyByteSize,
+    pub expires_at: Option<IggyTimestamp>,
+    available_bytes: i64,
+    refilled_at: IggyTimestamp,
+}
+
+impl TopicThrottle {
+    pub fn new(
+        max_throughput: IggyByteSize,
+        duration: Option<IggyDuration>,
+        now: IggyTimestamp,
+    ) -> Self {
+        Self {
+            max_throughput,
+            expires_at: duration.map(|duration| (now.as_micros() + duration.as_micros()).into()),
+            available_bytes: max_throughput.as_bytes_u64() as i64,
+            refilled_at: now,
+        }
+    }
+
+    pub fn is_expired(&self, now: IggyTimestamp) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| now.as_micros() >= expires_at.as_micros())
+    }
+
+    pub(crate) fn try_consume(&mut self, size_bytes: u64, now: IggyTimestamp) -> bool {
+        if self.delay(now) > 0 {
+            return false;
+        }
+
+        self.record(size_bytes, now);
+        true
+    }
+
+    /// Returns the time in microseconds until the debt is paid off, or `0` if any bytes are available right away.
+    pub(crate) fn delay(&mut self, now: IggyTimestamp) -> u64 {
+        self.refill(now);
+        if self.available_bytes > 0 {
+            return 0;
+        }
+
+        let missing_bytes = (1 - self.available_bytes) as u128;
+        missing_bytes
+            .saturating_mul(MICROS_PER_SECOND)
+            .div_ceil(self.max_throughput.as_bytes_u64().max(1) as u128) as u64
+    }
+
+    /// Consumes the bytes even if they're not available, e.g. once the size of the polled messages is known.
+    pub(crate) fn record(&mut self, size_bytes: u64, now: IggyTimestamp) {
+        self.refill(now);
+        self.available_bytes = self.available_bytes.saturating_sub(size_bytes as i64);
+    }
+
+    fn refill(&mut self, now: IggyTimestamp) {
+        let max_bytes = self.max_throughput.as_bytes_u64() as i64;
+        let elapsed = now.as_micros().saturating_sub(self.refilled_at.as_micros()) as u128;
+        let refill =
+            (elapsed * max_bytes as u128 / MICROS_PER_SECOND).min(max_bytes as u128) as i64;
+        // The time is not moved forward until at least a single byte is refilled, so that the low rates are not starved.
+        if refill > 0 {
+            self.available_bytes = self.available_bytes.saturating_add(refill).min(max_bytes);
+            self.refilled_at = now;
+        }
+    }
+}
+
+impl Topic {
+    /// Sets the throughput cap of the topic lifted after the optional duration, or clears it if the max throughput is not provided.
+    pub fn set_throttle(
+        &self,
+        max_throughput: Option<IggyByteSize>,
+        duration: Option<IggyDuration>,
+        now: IggyTimestamp,
+    ) {
+        let throttle =
+            max_throughput.map(|max_throughput| TopicThrottle::new(max_throughput, duration, now));
+        *self.throttle.lock().unwrap() = throttle;
+    }
+
+    /// Returns the max throughput of the topic, if it's currently throttled.
+    pub fn get_max_throughput(&self, now: IggyTimestamp) -> Option<IggyByteSize> {
+        self.throttle
+            .lock()
+            .unwrap()
+            .as_ref()
+            .filter(|throttle| !throttle.is_expired(now))
+            .map(|throttle| throttle.max_throughput)
+    }
+
+    /// Checks whether the messages of the given size can be appended without exceeding the throughput cap of the topic.
+    pub fn throttle_messages(&self, size_bytes: u64, now: IggyTimestamp) -> Result<(), IggyError> {
+        let mut throttle = self.throttle.lock().unwrap();
+        let Some(current_throttle) = throttle.as_mut() else {
+            return Ok(());
+        };
+
+        if current_throttle.is_expired(now) {
+            info!(
+                "Throttle of topic with ID: {} in stream with ID: {} has expired.",
+                self.topic_id, self.stream_id
+            );
+            *throttle = None;
+            return Ok(());
+        }
+
+        if current_throttle.try_consume(size_bytes, now) {
+            return Ok(());
+        }
+
+        Err(IggyError::TopicThrottled(self.topic_id, self.stream_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_allow_burst_up_to_max_throughput_and_refill_over_time() {
+        let now = IggyTimestamp::from(1_000_000);
+        let mut throttle = TopicThrottle::new(IggyByteSize::from(1000), None, now);
+
+        assert!(throttle.try_consume(600, now));
+        assert!(throttle.try_consume(600, now));
+        assert!(!throttle.try_consume(1, now));
+
+        let now = IggyTimestamp::from(now.as_micros() + 100_000);
+        assert!(!throttle.try_consume(1, now));
+
+        let now = IggyTimestamp::from(now.as_micros() + 200_000);
+        assert!(throttle.try_consume(100, now));
+        assert!(!throttle.try_consume(1, now));
+    }
+
+    #[test]
+    fn should_not_refill_above_max_throughput() {
+        let now = IggyTimestamp::from(1_000_000);
+        let mut throttle = TopicThrottle::new(IggyByteSize::from(1000), None, now);
+
+        let now = IggyTimestamp::from(now.as_micros() + 60_000_000);
+        assert!(throttle.try_consume(1000, now));
+        assert!(!throttle.try_consume(1, now));
+    }
+
+    #[test]
+    fn should_delay_until_debt_is_paid_off() {
+        let now = IggyTimestamp::from(1_000_000);
+        let mut throttle = TopicThrottle::new(IggyByteSize::from(1000), None, now);
+
+        assert_eq!(throttle.delay(now), 0);
+        throttle.record(1499, now);
+        assert_eq!(throttle.delay(now), 500_000);
+
+        let now = IggyTimestamp::from(now.as_micros() + 250_000);
+        assert_eq!(throttle.delay(now), 250_000);
+
+        let now = IggyTimestamp::from(now.as_micros() + 250_000);
+        assert_eq!(throttle.delay(now), 0);
+    }
+
+    #[test]
+    fn should_expire_after_duration() {
+        let now = IggyTimestamp::from(1_000_000);
+        let throttle = TopicThrottle::new(
+            IggyByteSize::from(1000),
+            Some(IggyDuration::new_from_secs(10)),
+            now,
+        );
+
+        assert!(!throttle.is_expired(now));
+        assert!(!throttle.is_expired(IggyTimestamp::from(10_999_999)));
+        assert!(throttle.is_expired(IggyTimestamp::from(11_000_000)));
+    }
+}
@@ -21,19 +21,36 @@ use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use crate::streaming::topics::deletion::TopicDeletion;
+use crate::streaming::topics::schema::MessageSchema;
+use crate::streaming::topics::throttling::TopicThrottle;
+use crate::streaming::topics::COMPONENT;
 use ahash::AHashMap;
 use core::fmt;
+use dashmap::DashMap;
+use error_set::ErrContext;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::error::IggyError;
-use iggy::locking::IggySharedMut;
+use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::models::bookmark::Bookmark;
+use iggy::models::compaction_policy::CompactionPolicy;
+use iggy::models::dead_letter_policy::DeadLetterPolicy;
+use iggy::models::fsync_policy::FsyncPolicy;
+use iggy::models::message_sampling_policy::MessageSamplingPolicy;
+use iggy::models::queue_policy::QueuePolicy;
+use iggy::models::retention_policy::RetentionPolicy;
+use iggy::models::segment_rollover_policy::SegmentRolloverPolicy;
+use iggy::models::tiering_policy::TieringPolicy;
+use iggy::models::topic_options::TopicOptions;
+use iggy::models::topic_schema::TopicSchema;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
 use iggy::utils::timestamp::IggyTimestamp;
 use iggy::utils::topic_size::MaxTopicSize;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
 use tracing::info;
 
@@ -46,6 +63,8 @@ pub struct Topic {
     pub name: String,
     pub path: String,
     pub partitions_path: String,
+    pub key_routes_path: String,
+    pub bookmarks_path: String,
     pub(crate) size_bytes: Arc<AtomicU64>,
     pub(crate) size_of_parent_stream: Arc<AtomicU64>,
     pub(crate) messages_count_of_parent_stream: Arc<AtomicU64>,
@@ -53,6 +72,9 @@ pub struct Topic {
     pub(crate) segments_count_of_parent_stream: Arc<AtomicU32>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) partitions: AHashMap<u32, IggySharedMut<Partition>>,
+    pub(crate) partitions_ids: AHashMap<String, u32>,
+    pub(crate) key_routes: DashMap<u32, u32>,
+    pub(crate) bookmarks: DashMap<String, Bookmark>,
     pub(crate) storage: Arc<SystemStorage>,
     pub(crate) consumer_groups: AHashMap<u32, RwLock<ConsumerGroup>>,
     pub(crate) consumer_groups_ids: AHashMap<String, u32>,
@@ -62,6 +84,17 @@ pub struct Topic {
     pub compression_algorithm: CompressionAlgorithm,
     pub max_topic_size: MaxTopicSize,
     pub replication_factor: u8,
+    pub schema: Option<MessageSchema>,
+    pub dead_letter_policy: Option<DeadLetterPolicy>,
+    pub sampling_policy: Option<MessageSamplingPolicy>,
+    pub compaction_policy: Option<CompactionPolicy>,
+    pub retention_policy: Option<RetentionPolicy>,
+    pub tiering_policy: Option<TieringPolicy>,
+    pub fsync_policy: Option<FsyncPolicy>,
+    pub segment_rollover_policy: Option<SegmentRolloverPolicy>,
+    pub queue_policy: Option<QueuePolicy>,
+    pub(crate) throttle: Mutex<Option<TopicThrottle>>,
+    pub(crate) deletion: Option<TopicDeletion>,
     pub created_at: IggyTimestamp,
 }
 
@@ -114,13 +147,20 @@ impl Topic {
     ) -> Result<Topic, IggyError> {
         let path = config.get_topic_path(stream_id, topic_id);
         let partitions_path = config.get_partitions_path(stream_id, topic_id);
+        let key_routes_path = config.get_key_routes_path(stream_id, topic_id);
+        let bookmarks_path = config.get_bookmarks_path(stream_id, topic_id);
         let mut topic = Topic {
             stream_id,
             topic_id,
             name: name.to_string(),
             partitions: AHashMap::new(),
+            partitions_ids: AHashMap::new(),
+            key_routes: DashMap::new(),
+            bookmarks: DashMap::new(),
             path,
             partitions_path,
+            key_routes_path,
+            bookmarks_path,
             storage,
             size_bytes: Arc::new(AtomicU64::new(0)),
             size_of_parent_stream,
@@ -135,6 +175,17 @@ impl Topic {
             max_topic_size: Topic::get_max_topic_size(max_topic_size, &config)?,
             compression_algorithm,
             replication_factor,
+            schema: None,
+            dead_letter_policy: None,
+            sampling_policy: None,
+            compaction_policy: None,
+            retention_policy: None,
+            tiering_policy: None,
+            fsync_policy: None,
+            segment_rollover_policy: None,
+            queue_policy: None,
+            throttle: Mutex::new(None),
+            deletion: None,
             config,
             created_at: IggyTimestamp::now(),
         };
@@ -169,10 +220,125 @@ impl Topic {
         }
     }
 
+    pub fn get_schema(&self) -> Option<&TopicSchema> {
+        self.schema.as_ref().map(|schema| &schema.schema)
+    }
+
+    pub fn set_schema(&mut self, schema: Option<TopicSchema>) -> Result<(), IggyError> {
+        self.schema = schema.map(MessageSchema::new).transpose()?;
+        Ok(())
+    }
+
     pub fn is_unlimited(&self) -> bool {
         matches!(self.max_topic_size, MaxTopicSize::Unlimited)
     }
 
+    pub fn retains_by_time(&self) -> bool {
+        match &self.retention_policy {
+            Some(policy) => policy.retains_by_time(),
+            None => true,
+        }
+    }
+
+    pub fn retains_by_size(&self) -> bool {
+        self.retention_policy
+            .as_ref()
+            .is_some_and(|policy| policy.retains_by_size())
+    }
+
+    pub fn get_max_partition_size(&self) -> Option<IggyByteSize> {
+        self.retention_policy
+            .as_ref()
+            .and_then(|policy| policy.max_partition_size)
+    }
+
+    /// Sets the fsync policy of the topic and applies it to all of its partitions.
+    pub async fn set_fsync_policy(&mut self, fsync_policy: Option<FsyncPolicy>) {
+        for partition in self.partitions.values() {
+            partition
+                .write()
+                .await
+                .set_fsync_policy(fsync_policy.clone());
+        }
+        self.fsync_policy = fsync_policy;
+    }
+
+    /// Sets the segment rollover policy of the topic and applies it to all of its partitions.
+    pub async fn set_segment_rollover_policy(
+        &mut self,
+        segment_rollover_policy: Option<SegmentRolloverPolicy>,
+    ) {
+        for partition in self.partitions.values() {
+            partition
+                .write()
+                .await
+                .set_segment_rollover_policy(segment_rollover_policy.clone());
+        }
+        self.segment_rollover_policy = segment_rollover_policy;
+    }
+
+    /// Sets the queue policy of the topic and applies it to all of its partitions.
+    /// Once the policy is removed, the messages are consumed by the offsets again.
+    pub async fn set_queue_policy(
+        &mut self,
+        queue_policy: Option<QueuePolicy>,
+    ) -> Result<(), IggyError> {
+        for partition in self.partitions.values() {
+            let mut partition = partition.write().await;
+            partition
+                .set_queue_policy(queue_policy.clone())
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to set queue policy for partition with ID: {}",
+                        partition.partition_id
+                    )
+                })?;
+        }
+        self.queue_policy = queue_policy;
+        Ok(())
+    }
+
+    /// Returns the options of the topic, including its effective fsync policy.
+    pub fn get_options(&self) -> TopicOptions {
+        TopicOptions {
+            schema: self.get_schema().cloned(),
+            dead_letter_policy: self.dead_letter_policy.clone(),
+            sampling_policy: self.sampling_policy.clone(),
+            compaction_policy: self.compaction_policy.clone(),
+            retention_policy: self.retention_policy.clone(),
+            tiering_policy: self.tiering_policy.clone(),
+            fsync_policy: Some(self.get_fsync_policy()),
+            segment_rollover_policy: self.segment_rollover_policy.clone(),
+            queue_policy: self.queue_policy.clone(),
+        }
+    }
+
+    /// Replaces the options of the topic (the unset ones are removed) and applies them to all of its partitions.
+    /// The schema is compiled first, so the invalid one leaves the topic unchanged.
+    pub async fn set_options(&mut self, options: TopicOptions) -> Result<(), IggyError> {
+        self.set_schema(options.schema)?;
+        self.dead_letter_policy = options.dead_letter_policy;
+        self.sampling_policy = options.sampling_policy;
+        self.compaction_policy = options.compaction_policy;
+        self.retention_policy = options.retention_policy;
+        self.tiering_policy = options.tiering_policy;
+        self.set_fsync_policy(options.fsync_policy).await;
+        self.set_segment_rollover_policy(options.segment_rollover_policy)
+            .await;
+        self.set_queue_policy(options.queue_policy).await
+    }
+
+    /// Returns the effective fsync policy, if the topic has none, the `enforce_fsync` server configuration
+    /// translates to either syncing every persisted message or relying on the OS flush.
+    pub fn get_fsync_policy(&self) -> FsyncPolicy {
+        match &self.fsync_policy {
+            Some(policy) => policy.clone(),
+            None if self.config.partition.enforce_fsync => FsyncPolicy::every_n_messages(1),
+            None => FsyncPolicy::never(),
+        }
+    }
+
     pub fn get_partitions(&self) -> Vec<IggySharedMut<Partition>> {
         self.partitions.values().cloned().collect()
     }
@@ -188,12 +354,13 @@ impl Topic {
         }
     }
 
+    /// When `calculate_partition_at` is set, the next partition is calculated for the consumer group member, which also counts as its heartbeat at the given time.
     pub async fn resolve_consumer_with_partition_id(
         &self,
         consumer: &Consumer,
         client_id: u32,
         partition_id: Option<u32>,
-        calculate_partition_id: bool,
+        calculate_partition_at: Option<IggyTimestamp>,
     ) -> Result<Option<(PollingConsumer, u32)>, IggyError> {
         match consumer.kind {
             ConsumerKind::Consumer => {
@@ -212,10 +379,13 @@ impl Topic {
                     )));
                 }
 
-                let partition_id = if calculate_partition_id {
-                    consumer_group.calculate_partition_id(client_id).await?
-                } else {
-                    consumer_group.get_current_partition_id(client_id).await?
+                let partition_id = match calculate_partition_at {
+                    Some(now) => {
+                        consumer_group
+                            .calculate_partition_id(client_id, now)
+                            .await?
+                    }
+                    None => consumer_group.get_current_partition_id(client_id).await?,
                 };
                 let Some(partition_id) = partition_id else {
                     return Ok(None);
@@ -0,0 +1,195 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::segments::IggyMessagesMut;
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::transactions::transaction_coordinator::{
+    TransactionCoordinator, TransactionState,
+};
+use ahash::AHashSet;
+use bytes::Bytes;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::confirmation::ConfirmationLevel;
+use iggy::error::IggyError;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messaging::{self, IggyMessage};
+use iggy::models::transaction::{
+    self, TransactionMarker, TRANSACTION_ID_HEADER, TRANSACTION_MARKER_HEADER,
+};
+use iggy::utils::timestamp::IggyTimestamp;
+use lending_iterator::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::trace;
+
+/// The number of messages read at once, when looking for the transaction marker in the partition.
+const TRANSACTION_MARKER_SCAN_COUNT: u32 = 1000;
+
+impl Topic {
+    /// Returns the IDs of the transactions within which the messages about to be appended are sent.
+    /// The transaction markers can be appended only by the server, so the messages having the marker header are rejected.
+    pub fn get_transaction_ids(
+        &self,
+        messages: &mut IggyMessagesMut,
+    ) -> Result<AHashSet<u64>, IggyError> {
+        let mut transaction_ids = AHashSet::new();
+        let mut messages = messages.iter_mut();
+        while let Some(message) = messages.next() {
+            let Some(user_headers) = message.user_headers() else {
+                continue;
+            };
+
+            let headers = HashMap::<HeaderKey, HeaderValue>::from_bytes(Bytes::copy_from_slice(
+                user_headers,
+            ))?;
+            if transaction::get_transaction_marker(&headers).is_some() {
+                return Err(IggyError::TransactionMarkerNotAllowed);
+            }
+
+            if let Some(transaction_id) = transaction::get_transaction_id(&headers) {
+                transaction_ids.insert(transaction_id);
+            }
+        }
+        Ok(transaction_ids)
+    }
+
+    /// Appends the transaction marker to every partition of the topic, so that the outcome of the transaction
+    /// can be resolved from the partitions once it's no longer tracked by the transaction coordinator (e.g. after the server restart).
+    pub async fn append_transaction_marker(
+        &self,
+        transaction_id: u64,
+        marker: TransactionMarker,
+        now: IggyTimestamp,
+    ) -> Result<(), IggyError> {
+        let message = IggyMessage::builder()
+            .payload(Bytes::from(marker.to_string()))
+            .header(
+                messaging::HeaderKey::new(TRANSACTION_ID_HEADER)?,
+                messaging::HeaderValue::from_uint64(transaction_id)?,
+            )
+            .header(
+                messaging::HeaderKey::new(TRANSACTION_MARKER_HEADER)?,
+                messaging::HeaderValue::from_uint8(marker.as_code())?,
+            )
+            .build();
+        for partition_id in self.partitions.keys() {
+            self.append_messages_to_partition(
+                IggyMessagesMut::from(std::slice::from_ref(&message)),
+                *partition_id,
+                ConfirmationLevel::Received,
+                now,
+            )
+            .await?;
+        }
+        trace!(
+            "Appended transaction: {transaction_id} {marker} marker to stream ID: {}, topic ID: {}.",
+            self.stream_id,
+            self.topic_id
+        );
+        Ok(())
+    }
+
+    /// Filters the polled messages according to the read committed isolation:
+    /// - the transaction markers and the messages of the aborted transactions are skipped,
+    /// - the messages of the committed transactions are returned,
+    /// - the messages of the open transaction and all the following ones are held back, until the transaction is completed.
+    ///
+    /// Returns the filtered messages and the flag indicating whether the polled messages were held back by the open transaction.
+    pub(crate) async fn filter_committed_messages(
+        &self,
+        partition: &Partition,
+        messages: Vec<Arc<RetainedMessage>>,
+        now: IggyTimestamp,
+        transactions: &TransactionCoordinator,
+    ) -> Result<(Vec<Arc<RetainedMessage>>, bool), IggyError> {
+        let mut committed_messages = Vec::with_capacity(messages.len());
+        for message in messages {
+            if message.is_expired(now) {
+                continue;
+            }
+
+            let Some((transaction_id, marker)) = message.transaction() else {
+                committed_messages.push(message);
+                continue;
+            };
+
+            if marker.is_some() {
+                continue;
+            }
+
+            let state = match transactions.get_state(transaction_id) {
+                Some(state) => state,
+                None => {
+                    Self::resolve_transaction_state(
+                        partition,
+                        transaction_id,
+                        message.offset,
+                        transactions,
+                    )
+                    .await?
+                }
+            };
+            match state {
+                TransactionState::Committed => committed_messages.push(message),
+                TransactionState::Aborted => continue,
+                TransactionState::Open => return Ok((committed_messages, true)),
+            }
+        }
+        Ok((committed_messages, false))
+    }
+
+    /// Resolves the outcome of the transaction which is no longer tracked by the transaction coordinator,
+    /// by looking for its marker in the partition following the message sent within it.
+    /// The transaction without the marker was left open when the server was stopped, so it's considered aborted.
+    async fn resolve_transaction_state(
+        partition: &Partition,
+        transaction_id: u64,
+        offset: u64,
+        transactions: &TransactionCoordinator,
+    ) -> Result<TransactionState, IggyError> {
+        let mut offset = offset + 1;
+        let mut resolved_marker = TransactionMarker::Abort;
+        'scan: while offset <= partition.current_offset {
+            let messages = partition
+                .get_messages_by_offset(offset, TRANSACTION_MARKER_SCAN_COUNT)
+                .await?;
+            let Some(last_message) = messages.last() else {
+                break;
+            };
+
+            offset = last_message.offset + 1;
+            for message in messages.iter() {
+                if let Some((id, Some(marker))) = message.transaction() {
+                    if id == transaction_id {
+                        resolved_marker = marker;
+                        break 'scan;
+                    }
+                }
+            }
+        }
+
+        trace!(
+            "Resolved transaction: {transaction_id} as {resolved_marker} for partition: {}.",
+            partition.partition_id
+        );
+        transactions.record(transaction_id, resolved_marker);
+        Ok(TransactionState::from(resolved_marker))
+    }
+}
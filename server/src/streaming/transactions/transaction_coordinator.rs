@@ -0,0 +1,315 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::polling_consumer::PollingConsumer;
+use ahash::AHashSet;
+use dashmap::DashMap;
+use iggy::error::IggyError;
+use iggy::models::transaction::TransactionMarker;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// The maximum number of the completed transactions whose outcome is kept in memory.
+/// The outcome of the older ones is resolved from the transaction markers stored in the partitions.
+const MAX_COMPLETED_TRANSACTIONS: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionState {
+    Open,
+    Committed,
+    Aborted,
+}
+
+impl From<TransactionMarker> for TransactionState {
+    fn from(marker: TransactionMarker) -> Self {
+        match marker {
+            TransactionMarker::Commit => TransactionState::Committed,
+            TransactionMarker::Abort => TransactionState::Aborted,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Transaction {
+    pub id: u64,
+    pub client_id: u32,
+    pub began_at: IggyTimestamp,
+    /// The (stream ID, topic ID) pairs of the topics written within the transaction.
+    pub topics: AHashSet<(u32, u32)>,
+    /// The consumer offsets stored only once the transaction is committed.
+    pub offsets: Vec<TransactionOffset>,
+}
+
+/// The consumer offset sent to the transaction, so that it's stored atomically with the messages sent within it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransactionOffset {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub consumer: PollingConsumer,
+    pub offset: u64,
+}
+
+/// `TransactionCoordinator` keeps track of the open transactions and the outcome of the recently completed ones.
+/// The transaction IDs are seeded with the current timestamp, so that they remain unique across the server restarts.
+#[derive(Debug)]
+pub struct TransactionCoordinator {
+    next_id: AtomicU64,
+    open: DashMap<u64, Transaction>,
+    completed: DashMap<u64, TransactionMarker>,
+    completed_order: Mutex<VecDeque<u64>>,
+}
+
+impl TransactionCoordinator {
+    pub fn new(now: IggyTimestamp) -> Self {
+        Self {
+            next_id: AtomicU64::new(now.as_micros().max(1)),
+            open: DashMap::new(),
+            completed: DashMap::new(),
+            completed_order: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Begins the new transaction for the given client and returns its unique ID.
+    pub fn begin(&self, client_id: u32, now: IggyTimestamp) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        self.open.insert(
+            id,
+            Transaction {
+                id,
+                client_id,
+                began_at: now,
+                topics: AHashSet::new(),
+                offsets: Vec::new(),
+            },
+        );
+        id
+    }
+
+    /// Registers the topic written within the open transaction owned by the given client.
+    pub fn register_topic(
+        &self,
+        id: u64,
+        client_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        let mut transaction = self.get_open_mut(id, client_id)?;
+        transaction.topics.insert((stream_id, topic_id));
+        Ok(())
+    }
+
+    /// Returns the topics written within the open transaction owned by the given client.
+    pub fn get_topics(&self, id: u64, client_id: u32) -> Result<Vec<(u32, u32)>, IggyError> {
+        let transaction = self.get_open_mut(id, client_id)?;
+        Ok(transaction.topics.iter().copied().collect())
+    }
+
+    /// Registers the consumer offset sent to the open transaction owned by the given client.
+    /// The offset sent again for the same consumer and partition replaces the previous one.
+    pub fn register_offset(
+        &self,
+        id: u64,
+        client_id: u32,
+        offset: TransactionOffset,
+    ) -> Result<(), IggyError> {
+        let mut transaction = self.get_open_mut(id, client_id)?;
+        transaction.offsets.retain(|existing| {
+            existing.stream_id != offset.stream_id
+                || existing.topic_id != offset.topic_id
+                || existing.partition_id != offset.partition_id
+                || existing.consumer != offset.consumer
+        });
+        transaction.offsets.push(offset);
+        Ok(())
+    }
+
+    /// Returns the consumer offsets sent to the open transaction owned by the given client.
+    pub fn get_offsets(
+        &self,
+        id: u64,
+        client_id: u32,
+    ) -> Result<Vec<TransactionOffset>, IggyError> {
+        let transaction = self.get_open_mut(id, client_id)?;
+        Ok(transaction.offsets.clone())
+    }
+
+    /// Completes the open transaction owned by the given client, making its outcome visible to the consumers.
+    pub fn complete(
+        &self,
+        id: u64,
+        client_id: u32,
+        marker: TransactionMarker,
+    ) -> Result<(), IggyError> {
+        // Ensure the ownership before removing the transaction.
+        drop(self.get_open_mut(id, client_id)?);
+        self.open.remove(&id);
+        self.record(id, marker);
+        Ok(())
+    }
+
+    /// Records the outcome of the transaction, resolved from the transaction marker stored in the partition.
+    pub fn record(&self, id: u64, marker: TransactionMarker) {
+        if self.completed.insert(id, marker).is_some() {
+            return;
+        }
+
+        let mut completed_order = self.completed_order.lock().unwrap();
+        completed_order.push_back(id);
+        while completed_order.len() > MAX_COMPLETED_TRANSACTIONS {
+            if let Some(oldest_id) = completed_order.pop_front() {
+                self.completed.remove(&oldest_id);
+            }
+        }
+    }
+
+    /// Returns the IDs of the open transactions owned by the given client.
+    pub fn get_client_transactions(&self, client_id: u32) -> Vec<u64> {
+        self.open
+            .iter()
+            .filter(|transaction| transaction.client_id == client_id)
+            .map(|transaction| transaction.id)
+            .collect()
+    }
+
+    /// Returns the state of the transaction, or `None` if it's neither open nor recently completed.
+    pub fn get_state(&self, id: u64) -> Option<TransactionState> {
+        if self.open.contains_key(&id) {
+            return Some(TransactionState::Open);
+        }
+
+        self.completed
+            .get(&id)
+            .map(|marker| TransactionState::from(*marker))
+    }
+
+    fn get_open_mut(
+        &self,
+        id: u64,
+        client_id: u32,
+    ) -> Result<dashmap::mapref::one::RefMut<'_, u64, Transaction>, IggyError> {
+        let Some(transaction) = self.open.get_mut(&id) else {
+            if self.completed.contains_key(&id) {
+                return Err(IggyError::TransactionNotOpen(id));
+            }
+            return Err(IggyError::TransactionNotFound(id));
+        };
+
+        // The transaction of another client is reported as not found, to not disclose its existence.
+        if transaction.client_id != client_id {
+            return Err(IggyError::TransactionNotFound(id));
+        }
+
+        Ok(transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_should_be_open_until_completed() {
+        let coordinator = TransactionCoordinator::new(IggyTimestamp::from(1000));
+        let id = coordinator.begin(1, IggyTimestamp::now());
+        assert_eq!(id, 1000);
+        assert_eq!(coordinator.get_state(id), Some(TransactionState::Open));
+        coordinator.register_topic(id, 1, 1, 2).unwrap();
+        coordinator.register_topic(id, 1, 1, 2).unwrap();
+        assert_eq!(coordinator.get_topics(id, 1).unwrap(), vec![(1, 2)]);
+
+        coordinator
+            .complete(id, 1, TransactionMarker::Commit)
+            .unwrap();
+        assert_eq!(coordinator.get_state(id), Some(TransactionState::Committed));
+        assert_eq!(
+            coordinator.complete(id, 1, TransactionMarker::Abort),
+            Err(IggyError::TransactionNotOpen(id))
+        );
+    }
+
+    #[test]
+    fn transaction_should_not_be_available_to_another_client() {
+        let coordinator = TransactionCoordinator::new(IggyTimestamp::from(1000));
+        let id = coordinator.begin(1, IggyTimestamp::now());
+        assert_eq!(
+            coordinator.register_topic(id, 2, 1, 1),
+            Err(IggyError::TransactionNotFound(id))
+        );
+        assert_eq!(
+            coordinator.complete(id, 2, TransactionMarker::Commit),
+            Err(IggyError::TransactionNotFound(id))
+        );
+        assert_eq!(coordinator.get_client_transactions(1), vec![id]);
+        assert!(coordinator.get_client_transactions(2).is_empty());
+    }
+
+    #[test]
+    fn offset_sent_again_should_replace_the_previous_one() {
+        let coordinator = TransactionCoordinator::new(IggyTimestamp::from(1000));
+        let id = coordinator.begin(1, IggyTimestamp::now());
+        let offset = TransactionOffset {
+            stream_id: 1,
+            topic_id: 2,
+            partition_id: 3,
+            consumer: PollingConsumer::Consumer(4, 3),
+            offset: 10,
+        };
+        coordinator.register_offset(id, 1, offset).unwrap();
+        coordinator
+            .register_offset(
+                id,
+                1,
+                TransactionOffset {
+                    offset: 20,
+                    ..offset
+                },
+            )
+            .unwrap();
+        coordinator
+            .register_offset(
+                id,
+                1,
+                TransactionOffset {
+                    partition_id: 4,
+                    consumer: PollingConsumer::Consumer(4, 4),
+                    ..offset
+                },
+            )
+            .unwrap();
+
+        let offsets = coordinator.get_offsets(id, 1).unwrap();
+        assert_eq!(offsets.len(), 2);
+        assert_eq!(offsets[0].offset, 20);
+        assert_eq!(offsets[1].partition_id, 4);
+        assert_eq!(
+            coordinator.get_offsets(id, 2),
+            Err(IggyError::TransactionNotFound(id))
+        );
+    }
+
+    #[test]
+    fn unknown_transaction_should_have_no_state() {
+        let coordinator = TransactionCoordinator::new(IggyTimestamp::from(1000));
+        assert_eq!(coordinator.get_state(1), None);
+        coordinator.record(1, TransactionMarker::Abort);
+        assert_eq!(coordinator.get_state(1), Some(TransactionState::Aborted));
+    }
+}
@@ -0,0 +1,527 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::users::authorizers::{
+    AuthorizationRequest, Authorizer, MANAGE_SCHEMAS, PROMOTE_STANDBY, SHUTDOWN_SERVER,
+    UPDATE_CONFIG,
+};
+use crate::streaming::users::user::User;
+use ahash::{AHashMap, AHashSet};
+use iggy::command::*;
+use iggy::error::IggyError;
+use iggy::models::permissions::{
+    GlobalPermissions, PatternPermissions, Permissions, StreamPermissions,
+};
+use iggy::models::user_info::UserId;
+
+/// The built-in permission model, based on the global, the stream and the pattern permissions assigned to the users.
+/// The rules for the specific actions are defined in the `permissioner_rules` module.
+/// The pattern permissions are evaluated against the names of the streams and topics, which are kept in sync by the system.
+#[derive(Debug, Default)]
+pub struct BuiltinAuthorizer {
+    pub(crate) users_permissions: AHashMap<UserId, GlobalPermissions>,
+    pub(crate) users_streams_permissions: AHashMap<(UserId, u32), StreamPermissions>,
+    pub(crate) users_that_can_poll_messages_from_all_streams: AHashSet<UserId>,
+    pub(crate) users_that_can_send_messages_to_all_streams: AHashSet<UserId>,
+    pub(crate) users_that_can_poll_messages_from_specific_streams: AHashSet<(UserId, u32)>,
+    pub(crate) users_that_can_send_messages_to_specific_streams: AHashSet<(UserId, u32)>,
+    pub(crate) users_patterns_permissions: AHashMap<UserId, Vec<PatternPermissions>>,
+    pub(crate) streams_names: AHashMap<u32, String>,
+    pub(crate) topics_names: AHashMap<(u32, u32), String>,
+}
+
+impl BuiltinAuthorizer {
+    pub fn init(&mut self, users: &[&User]) {
+        for user in users {
+            self.init_permissions_for_user(user.id, user.permissions.clone());
+        }
+    }
+
+    pub fn init_permissions_for_user(&mut self, user_id: UserId, permissions: Option<Permissions>) {
+        if permissions.is_none() {
+            return;
+        }
+
+        let permissions = permissions.unwrap();
+        if permissions.global.poll_messages {
+            self.users_that_can_poll_messages_from_all_streams
+                .insert(user_id);
+        }
+
+        if permissions.global.send_messages {
+            self.users_that_can_send_messages_to_all_streams
+                .insert(user_id);
+        }
+
+        self.users_permissions.insert(user_id, permissions.global);
+        if let Some(patterns) = permissions.patterns {
+            self.users_patterns_permissions.insert(user_id, patterns);
+        }
+
+        if permissions.streams.is_none() {
+            return;
+        }
+
+        let streams = permissions.streams.unwrap();
+        for (stream_id, stream) in streams {
+            if stream.poll_messages {
+                self.users_that_can_poll_messages_from_specific_streams
+                    .insert((user_id, stream_id));
+            }
+
+            if stream.send_messages {
+                self.users_that_can_send_messages_to_specific_streams
+                    .insert((user_id, stream_id));
+            }
+
+            self.users_streams_permissions
+                .insert((user_id, stream_id), stream);
+        }
+    }
+
+    pub fn update_permissions_for_user(
+        &mut self,
+        user_id: UserId,
+        permissions: Option<Permissions>,
+    ) {
+        self.delete_permissions_for_user(user_id);
+        self.init_permissions_for_user(user_id, permissions);
+    }
+
+    pub fn delete_permissions_for_user(&mut self, user_id: UserId) {
+        self.users_permissions.remove(&user_id);
+        self.users_that_can_poll_messages_from_all_streams
+            .remove(&user_id);
+        self.users_that_can_send_messages_to_all_streams
+            .remove(&user_id);
+        self.users_streams_permissions
+            .retain(|(id, _), _| *id != user_id);
+        self.users_that_can_poll_messages_from_specific_streams
+            .retain(|(id, _)| *id != user_id);
+        self.users_that_can_send_messages_to_specific_streams
+            .retain(|(id, _)| *id != user_id);
+        self.users_patterns_permissions.remove(&user_id);
+    }
+
+    pub fn register_stream(&mut self, stream_id: u32, name: &str) {
+        self.streams_names.insert(stream_id, name.to_owned());
+    }
+
+    pub fn unregister_stream(&mut self, stream_id: u32) {
+        self.streams_names.remove(&stream_id);
+        self.topics_names.retain(|(id, _), _| *id != stream_id);
+    }
+
+    pub fn register_topic(&mut self, stream_id: u32, topic_id: u32, name: &str) {
+        self.topics_names
+            .insert((stream_id, topic_id), name.to_owned());
+    }
+
+    pub fn unregister_topic(&mut self, stream_id: u32, topic_id: u32) {
+        self.topics_names.remove(&(stream_id, topic_id));
+    }
+
+    fn authorize_by_permissions(&self, request: &AuthorizationRequest) -> Result<(), IggyError> {
+        let user_id = request.user_id;
+        match (request.action, request.stream_id, request.topic_id) {
+            (GET_STATS, _, _) => self.get_stats(user_id),
+            (GET_CLIENTS, _, _) => self.get_clients(user_id),
+            (GET_CLIENT, _, _) => self.get_client(user_id),
+            (GET_CONFIG, _, _) => self.get_config(user_id),
+            (UPDATE_CONFIG, _, _) => self.update_config(user_id),
+            (GET_AUDIT_LOG, _, _) => self.get_audit_log(user_id),
+            (CREATE_BACKUP, _, _) => self.create_backup(user_id),
+            (RESTORE_BACKUP, _, _) => self.restore_backup(user_id),
+            (SHUTDOWN_SERVER, _, _) => self.shutdown_server(user_id),
+            (PROMOTE_STANDBY, _, _) => self.promote_standby(user_id),
+            (GET_QUOTAS, _, _) => self.get_quotas(user_id),
+            (SET_QUOTA, _, _) => self.set_quota(user_id),
+            (GET_USER, _, _) => self.get_user(user_id),
+            (GET_USERS, _, _) => self.get_users(user_id),
+            (CREATE_USER, _, _) => self.create_user(user_id),
+            (DELETE_USER, _, _) => self.delete_user(user_id),
+            (UPDATE_USER, _, _) => self.update_user(user_id),
+            (UPDATE_PERMISSIONS, _, _) => self.update_permissions(user_id),
+            (CHANGE_PASSWORD, _, _) => self.change_password(user_id),
+            (GET_STREAMS, _, _) => self.get_streams(user_id),
+            (CREATE_STREAM, _, _) => self.create_stream(user_id),
+            (GET_STREAM, Some(stream_id), _) => self.get_stream(user_id, stream_id),
+            (UPDATE_STREAM, Some(stream_id), _) => self.update_stream(user_id, stream_id),
+            (DELETE_STREAM, Some(stream_id), _) => self.delete_stream(user_id, stream_id),
+            (PURGE_STREAM, Some(stream_id), _) => self.purge_stream(user_id, stream_id),
+            (GET_TOPICS, Some(stream_id), _) => self.get_topics(user_id, stream_id),
+            (CREATE_TOPIC, Some(stream_id), _) => self.create_topic(user_id, stream_id),
+            (GET_TOPIC, Some(stream_id), Some(topic_id)) => {
+                self.get_topic(user_id, stream_id, topic_id)
+            }
+            (UPDATE_TOPIC, Some(stream_id), Some(topic_id)) => {
+                self.update_topic(user_id, stream_id, topic_id)
+            }
+            (DELETE_TOPIC, Some(stream_id), Some(topic_id)) => {
+                self.delete_topic(user_id, stream_id, topic_id)
+            }
+            (PURGE_TOPIC, Some(stream_id), Some(topic_id)) => {
+                self.purge_topic(user_id, stream_id, topic_id)
+            }
+            (SET_TOPIC_THROTTLE, _, _) => self.set_topic_throttle(user_id),
+            (CREATE_PARTITIONS, Some(stream_id), Some(topic_id)) => {
+                self.create_partitions(user_id, stream_id, topic_id)
+            }
+            (DELETE_PARTITIONS, Some(stream_id), Some(topic_id)) => {
+                self.delete_partitions(user_id, stream_id, topic_id)
+            }
+            (UPDATE_PARTITION, Some(stream_id), Some(topic_id)) => {
+                self.update_partition(user_id, stream_id, topic_id)
+            }
+            (MOVE_PARTITION, _, _) => self.move_partition(user_id),
+            (DELETE_SEGMENTS, Some(stream_id), Some(topic_id)) => {
+                self.delete_segments(user_id, stream_id, topic_id)
+            }
+            (VERIFY_SEGMENTS, Some(stream_id), Some(topic_id)) => {
+                self.verify_segments(user_id, stream_id, topic_id)
+            }
+            (GET_CONSUMER_GROUP, Some(stream_id), Some(topic_id)) => {
+                self.get_consumer_group(user_id, stream_id, topic_id)
+            }
+            (GET_CONSUMER_GROUPS, Some(stream_id), Some(topic_id)) => {
+                self.get_consumer_groups(user_id, stream_id, topic_id)
+            }
+            (CREATE_CONSUMER_GROUP, Some(stream_id), Some(topic_id)) => {
+                self.create_consumer_group(user_id, stream_id, topic_id)
+            }
+            (DELETE_CONSUMER_GROUP, Some(stream_id), Some(topic_id)) => {
+                self.delete_consumer_group(user_id, stream_id, topic_id)
+            }
+            (JOIN_CONSUMER_GROUP, Some(stream_id), Some(topic_id)) => {
+                self.join_consumer_group(user_id, stream_id, topic_id)
+            }
+            (LEAVE_CONSUMER_GROUP, Some(stream_id), Some(topic_id)) => {
+                self.leave_consumer_group(user_id, stream_id, topic_id)
+            }
+            (HEARTBEAT_CONSUMER_GROUP, Some(stream_id), Some(topic_id)) => {
+                self.heartbeat_consumer_group(user_id, stream_id, topic_id)
+            }
+            (GET_CONSUMER_OFFSET, Some(stream_id), Some(topic_id)) => {
+                self.get_consumer_offset(user_id, stream_id, topic_id)
+            }
+            (STORE_CONSUMER_OFFSET, Some(stream_id), Some(topic_id)) => {
+                self.store_consumer_offset(user_id, stream_id, topic_id)
+            }
+            (DELETE_CONSUMER_OFFSET, Some(stream_id), Some(topic_id)) => {
+                self.delete_consumer_offset(user_id, stream_id, topic_id)
+            }
+            (POLL_MESSAGES, Some(stream_id), Some(topic_id)) => {
+                self.poll_messages(user_id, stream_id, topic_id)
+            }
+            (SEND_MESSAGES, Some(stream_id), Some(topic_id)) => {
+                self.append_messages(user_id, stream_id, topic_id)
+            }
+            (GET_SCHEMAS, _, _) => self.get_schemas(user_id),
+            (MANAGE_SCHEMAS, _, _) => self.manage_schemas(user_id),
+            _ => Err(IggyError::Unauthorized),
+        }
+    }
+}
+
+impl Authorizer for BuiltinAuthorizer {
+    fn authorize(
+        &self,
+        _session: &Session,
+        request: &AuthorizationRequest,
+    ) -> Result<(), IggyError> {
+        self.authorize_by_permissions(request)
+            .or_else(|_| self.authorize_by_pattern(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session() -> Session {
+        Session::stateless(1, "127.0.0.1:1234".parse().unwrap())
+    }
+
+    #[test]
+    fn should_authorize_actions_using_the_user_permissions() {
+        let mut authorizer = BuiltinAuthorizer::default();
+        authorizer.init_permissions_for_user(
+            1,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    manage_streams: true,
+                    ..Default::default()
+                },
+                streams: None,
+                patterns: None,
+            }),
+        );
+
+        assert!(authorizer
+            .authorize(&session(), &AuthorizationRequest::new(1, CREATE_STREAM))
+            .is_ok());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::stream(1, DELETE_STREAM, 1)
+            )
+            .is_ok());
+        assert!(authorizer
+            .authorize(&session(), &AuthorizationRequest::new(1, CREATE_USER))
+            .is_err());
+        assert!(authorizer
+            .authorize(&session(), &AuthorizationRequest::new(2, CREATE_STREAM))
+            .is_err());
+    }
+
+    #[test]
+    fn should_deny_the_action_without_the_required_resource() {
+        let mut authorizer = BuiltinAuthorizer::default();
+        authorizer.init_permissions_for_user(
+            1,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    poll_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+                patterns: None,
+            }),
+        );
+
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, POLL_MESSAGES, 1, 1)
+            )
+            .is_ok());
+        assert!(authorizer
+            .authorize(&session(), &AuthorizationRequest::new(1, POLL_MESSAGES))
+            .is_err());
+        assert!(authorizer
+            .authorize(&session(), &AuthorizationRequest::new(1, "unknown.action"))
+            .is_err());
+    }
+
+    fn authorizer_with_patterns(patterns: Vec<PatternPermissions>) -> BuiltinAuthorizer {
+        let mut authorizer = BuiltinAuthorizer::default();
+        authorizer.register_stream(1, "orders");
+        authorizer.register_topic(1, 1, "payments");
+        authorizer.register_topic(1, 2, "refunds");
+        authorizer.register_stream(2, "invoices");
+        authorizer.register_topic(2, 1, "payments");
+        authorizer.init_permissions_for_user(
+            1,
+            Some(Permissions {
+                patterns: Some(patterns),
+                ..Default::default()
+            }),
+        );
+        authorizer
+    }
+
+    #[test]
+    fn should_authorize_actions_on_topics_matching_the_pattern() {
+        let authorizer = authorizer_with_patterns(vec![PatternPermissions::new(
+            "orders.*", false, true, false,
+        )]);
+
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, POLL_MESSAGES, 1, 1)
+            )
+            .is_ok());
+        assert!(authorizer
+            .authorize(&session(), &AuthorizationRequest::topic(1, GET_TOPIC, 1, 2))
+            .is_ok());
+        assert!(authorizer
+            .authorize(&session(), &AuthorizationRequest::stream(1, GET_STREAM, 1))
+            .is_ok());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, SEND_MESSAGES, 1, 1)
+            )
+            .is_err());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, POLL_MESSAGES, 2, 1)
+            )
+            .is_err());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(2, POLL_MESSAGES, 1, 1)
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn should_apply_the_admin_pattern_to_the_stream_only_when_matching_all_its_topics() {
+        let authorizer = authorizer_with_patterns(vec![
+            PatternPermissions::new("orders.pay*", true, false, false),
+            PatternPermissions::new("invoices", true, false, false),
+        ]);
+
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, DELETE_TOPIC, 1, 1)
+            )
+            .is_ok());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, SEND_MESSAGES, 1, 1)
+            )
+            .is_ok());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::stream(1, CREATE_TOPIC, 1)
+            )
+            .is_err());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::stream(1, DELETE_STREAM, 1)
+            )
+            .is_err());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::stream(1, CREATE_TOPIC, 2)
+            )
+            .is_ok());
+        assert!(authorizer
+            .authorize(&session(), &AuthorizationRequest::new(1, CREATE_STREAM))
+            .is_err());
+    }
+
+    #[test]
+    fn should_give_precedence_to_the_most_specific_pattern() {
+        let authorizer = authorizer_with_patterns(vec![
+            PatternPermissions::new("orders.*", true, false, false),
+            PatternPermissions::new("*.payments", false, false, true),
+            PatternPermissions::new("orders.payments", false, true, false),
+        ]);
+
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, POLL_MESSAGES, 1, 1)
+            )
+            .is_ok());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, SEND_MESSAGES, 1, 1)
+            )
+            .is_err());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, DELETE_TOPIC, 1, 1)
+            )
+            .is_err());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, DELETE_TOPIC, 1, 2)
+            )
+            .is_ok());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, SEND_MESSAGES, 2, 1)
+            )
+            .is_ok());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, POLL_MESSAGES, 2, 1)
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn should_not_let_the_pattern_revoke_the_stream_permissions() {
+        let mut authorizer = authorizer_with_patterns(vec![]);
+        authorizer.update_permissions_for_user(
+            1,
+            Some(Permissions {
+                streams: Some(AHashMap::from([(
+                    1,
+                    StreamPermissions {
+                        send_messages: true,
+                        ..Default::default()
+                    },
+                )])),
+                patterns: Some(vec![PatternPermissions::new(
+                    "orders.*", false, false, false,
+                )]),
+                ..Default::default()
+            }),
+        );
+
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, SEND_MESSAGES, 1, 1)
+            )
+            .is_ok());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, POLL_MESSAGES, 1, 1)
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn should_deny_the_pattern_access_to_the_unregistered_topic() {
+        let mut authorizer = authorizer_with_patterns(vec![PatternPermissions::new(
+            "orders.*", false, true, false,
+        )]);
+        authorizer.unregister_topic(1, 1);
+
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, POLL_MESSAGES, 1, 1)
+            )
+            .is_err());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, POLL_MESSAGES, 1, 2)
+            )
+            .is_ok());
+
+        authorizer.unregister_stream(1);
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, POLL_MESSAGES, 1, 2)
+            )
+            .is_err());
+    }
+}
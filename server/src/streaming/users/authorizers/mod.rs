@@ -0,0 +1,163 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+pub mod builtin;
+pub mod opa;
+pub mod policy;
+
+use crate::configs::system::AuthorizationConfig;
+use crate::server_error::AuthorizationError;
+use crate::streaming::session::Session;
+use crate::streaming::users::authorizers::builtin::BuiltinAuthorizer;
+use crate::streaming::users::authorizers::opa::OpaAuthorizer;
+use crate::streaming::users::authorizers::policy::PolicyAuthorizer;
+use derive_more::Display;
+use iggy::error::IggyError;
+use iggy::models::user_info::UserId;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub const COMPONENT: &str = "AUTHORIZATION";
+
+/// The actions which don't have the corresponding command, while the other ones use the command names, e.g. `stream.create`.
+pub const UPDATE_CONFIG: &str = "config.update";
+pub const MANAGE_SCHEMAS: &str = "schema.manage";
+pub const SHUTDOWN_SERVER: &str = "server.shutdown";
+pub const PROMOTE_STANDBY: &str = "standby.promote";
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthorizerKindType {
+    #[default]
+    #[display("builtin")]
+    Builtin,
+    #[display("opa")]
+    Opa,
+    #[display("policy")]
+    Policy,
+}
+
+impl FromStr for AuthorizerKindType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "builtin" => Ok(AuthorizerKindType::Builtin),
+            "opa" => Ok(AuthorizerKindType::Opa),
+            "policy" => Ok(AuthorizerKindType::Policy),
+            _ => Err(format!("Unknown authorizer kind: {}", s)),
+        }
+    }
+}
+
+/// The action performed by the user, optionally on the specific stream or topic.
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct AuthorizationRequest {
+    pub user_id: UserId,
+    pub action: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic_id: Option<u32>,
+}
+
+impl AuthorizationRequest {
+    pub fn new(user_id: UserId, action: &'static str) -> Self {
+        Self {
+            user_id,
+            action,
+            stream_id: None,
+            topic_id: None,
+        }
+    }
+
+    pub fn stream(user_id: UserId, action: &'static str, stream_id: u32) -> Self {
+        Self {
+            stream_id: Some(stream_id),
+            ..Self::new(user_id, action)
+        }
+    }
+
+    pub fn topic(user_id: UserId, action: &'static str, stream_id: u32, topic_id: u32) -> Self {
+        Self {
+            stream_id: Some(stream_id),
+            topic_id: Some(topic_id),
+            ..Self::new(user_id, action)
+        }
+    }
+}
+
+/// Decides whether the action can be performed on the session, returning `IggyError::Unauthorized` otherwise.
+/// The decision is made synchronously, as it's required by the commands holding the system lock,
+/// so the external authorizers only check the decisions resolved (and stored on the session) before the lock was taken.
+pub trait Authorizer: Send + Sync {
+    fn authorize(&self, session: &Session, request: &AuthorizationRequest)
+        -> Result<(), IggyError>;
+}
+
+#[derive(Debug)]
+pub enum AuthorizerKind {
+    Builtin(BuiltinAuthorizer),
+    Opa(Arc<OpaAuthorizer>),
+    Policy(PolicyAuthorizer),
+}
+
+impl Default for AuthorizerKind {
+    fn default() -> Self {
+        Self::Builtin(BuiltinAuthorizer::default())
+    }
+}
+
+impl AuthorizerKind {
+    pub fn from_config(config: &AuthorizationConfig) -> Result<Self, AuthorizationError> {
+        let authorizer = match config.backend {
+            AuthorizerKindType::Builtin => Self::Builtin(BuiltinAuthorizer::default()),
+            AuthorizerKindType::Opa => Self::Opa(Arc::new(OpaAuthorizer::new(&config.opa)?)),
+            AuthorizerKindType::Policy => Self::Policy(PolicyAuthorizer::load(&config.policy)?),
+        };
+        Ok(authorizer)
+    }
+
+    pub fn kind(&self) -> AuthorizerKindType {
+        match self {
+            Self::Builtin(_) => AuthorizerKindType::Builtin,
+            Self::Opa(_) => AuthorizerKindType::Opa,
+            Self::Policy(_) => AuthorizerKindType::Policy,
+        }
+    }
+
+    /// Returns the authorizer making the decisions asynchronously, which have to be resolved before taking the system lock.
+    pub fn external(&self) -> Option<Arc<OpaAuthorizer>> {
+        match self {
+            Self::Opa(a) => Some(a.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn authorize(
+        &self,
+        session: &Session,
+        request: &AuthorizationRequest,
+    ) -> Result<(), IggyError> {
+        let authorizer: &dyn Authorizer = match self {
+            Self::Builtin(a) => a,
+            Self::Opa(a) => a.as_ref(),
+            Self::Policy(a) => a,
+        };
+        authorizer.authorize(session, request)
+    }
+}
@@ -0,0 +1,336 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::system::OpaAuthorizerConfig;
+use crate::server_error::AuthorizationError;
+use crate::streaming::session::Session;
+use crate::streaming::users::authorizers::{AuthorizationRequest, Authorizer, COMPONENT};
+use futures::future::join_all;
+use iggy::error::IggyError;
+use moka::sync::Cache;
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+const MAX_CACHED_DECISIONS: u64 = 100_000;
+/// How long the decisions resolved without the cache (`cache_ttl` is zero) remain valid on the session,
+/// as they're only meant for the command about to be handled.
+const UNCACHED_DECISION_TTL: Duration = Duration::from_secs(5);
+
+/// Queries the Open Policy Agent decision endpoint with the `input` document containing the request,
+/// the action is allowed only if the `result` of the decision is `true`.
+///
+/// The endpoint is queried asynchronously by `resolve`, before the command takes the system lock, and the decisions
+/// are stored on the session of the command, so the synchronous authorization only checks them - the unresolved ones are denied.
+/// The decisions are additionally cached across the sessions for `cache_ttl`, unless it's zero, and expire on the session
+/// along with the cached ones, so that they're never used for longer than `cache_ttl` since being resolved.
+#[derive(Debug)]
+pub struct OpaAuthorizer {
+    url: String,
+    client: reqwest::Client,
+    cache_ttl: Duration,
+    decisions: Option<Cache<AuthorizationRequest, (bool, Instant)>>,
+}
+
+#[derive(Debug, Serialize)]
+struct OpaQuery<'a> {
+    input: &'a AuthorizationRequest,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpaDecision {
+    #[serde(default)]
+    result: Option<Value>,
+}
+
+impl OpaAuthorizer {
+    pub fn new(config: &OpaAuthorizerConfig) -> Result<Self, AuthorizationError> {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout.get_duration())
+            .build()
+            .map_err(|_| AuthorizationError::CannotCreateOpaClient {
+                url: config.url.clone(),
+            })?;
+        let decisions = (!config.cache_ttl.is_zero()).then(|| {
+            Cache::builder()
+                .max_capacity(MAX_CACHED_DECISIONS)
+                .time_to_live(config.cache_ttl.get_duration())
+                .build()
+        });
+        Ok(Self {
+            url: config.url.clone(),
+            client,
+            cache_ttl: config.cache_ttl.get_duration(),
+            decisions,
+        })
+    }
+
+    /// Resolves the decisions for the requests of the command about to be handled on the session, without holding any lock,
+    /// querying the endpoint only for the ones which aren't cached, and stores them on the session.
+    /// The decisions which couldn't be made (e.g. the endpoint is unavailable or has timed out) are removed from the session,
+    /// so that the actions are denied and the decisions are queried again by the next command.
+    pub async fn resolve(&self, session: &Session, requests: &[AuthorizationRequest]) {
+        let mut pending = Vec::with_capacity(requests.len());
+        for request in requests {
+            if pending.contains(request) {
+                continue;
+            }
+            match self.get_cached(request) {
+                Some((allowed, resolved_at)) => session
+                    .set_resolved_decision(*request, Some((allowed, self.expiry(resolved_at)))),
+                None => pending.push(*request),
+            }
+        }
+
+        let decisions = join_all(pending.iter().map(|request| self.query(request))).await;
+        for (request, decision) in pending.into_iter().zip(decisions) {
+            match decision {
+                Ok(allowed) => {
+                    let resolved_at = Instant::now();
+                    if let Some(decisions) = &self.decisions {
+                        decisions.insert(request, (allowed, resolved_at));
+                    }
+                    session
+                        .set_resolved_decision(request, Some((allowed, self.expiry(resolved_at))));
+                }
+                Err(error) => {
+                    warn!(
+                        "{COMPONENT} - failed to query OPA: {} for action: {} of user with ID: {}, it will be denied. {error}",
+                        self.url, request.action, request.user_id
+                    );
+                    session.set_resolved_decision(request, None);
+                }
+            }
+        }
+    }
+
+    fn expiry(&self, resolved_at: Instant) -> Instant {
+        match self.decisions {
+            Some(_) => resolved_at + self.cache_ttl,
+            None => resolved_at + UNCACHED_DECISION_TTL,
+        }
+    }
+
+    fn get_cached(&self, request: &AuthorizationRequest) -> Option<(bool, Instant)> {
+        self.decisions
+            .as_ref()
+            .and_then(|decisions| decisions.get(request))
+    }
+
+    async fn query(&self, request: &AuthorizationRequest) -> Result<bool, String> {
+        let body =
+            serde_json::to_vec(&OpaQuery { input: request }).map_err(|error| error.to_string())?;
+        let response = self
+            .client
+            .post(&self.url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await
+            .map_err(|error| error.to_string())?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(format!("OPA responded with status: {status}"));
+        }
+
+        let body = response.bytes().await.map_err(|error| error.to_string())?;
+        let decision: OpaDecision =
+            serde_json::from_slice(&body).map_err(|error| error.to_string())?;
+        Ok(matches!(decision.result, Some(Value::Bool(true))))
+    }
+}
+
+impl Authorizer for OpaAuthorizer {
+    fn authorize(
+        &self,
+        session: &Session,
+        request: &AuthorizationRequest,
+    ) -> Result<(), IggyError> {
+        match session.get_resolved_decision(request) {
+            Some(true) => Ok(()),
+            Some(false) => Err(IggyError::Unauthorized),
+            None => {
+                // The decision hasn't been resolved before taking the system lock, or it couldn't be made,
+                // so the action is denied rather than blocking the lock while querying the endpoint.
+                warn!(
+                    "{COMPONENT} - unresolved OPA decision for action: {} of user with ID: {}, denying it.",
+                    request.action, request.user_id
+                );
+                Err(IggyError::Unauthorized)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use axum::routing::post;
+    use axum::{Json, Router};
+    use iggy::utils::duration::IggyDuration;
+    use serde_json::json;
+    use tokio::net::TcpListener;
+
+    pub(crate) const ALLOWED_USER_ID: u32 = 1;
+    const DENIED_USER_ID: u32 = 2;
+
+    /// Starts the OPA endpoint allowing only the actions of the user with `ALLOWED_USER_ID`, responding after the delay.
+    pub(crate) async fn start_opa(delay: Duration) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let router = Router::new().route(
+            "/v1/data/iggy/authz/allow",
+            post(move |Json(query): Json<Value>| async move {
+                tokio::time::sleep(delay).await;
+                Json(json!({ "result": query["input"]["user_id"] == ALLOWED_USER_ID }))
+            }),
+        );
+        tokio::spawn(async move { axum::serve(listener, router).await });
+        format!("http://{address}/v1/data/iggy/authz/allow")
+    }
+
+    fn authorizer(url: String, timeout: Duration, cache_ttl: Duration) -> OpaAuthorizer {
+        OpaAuthorizer::new(&OpaAuthorizerConfig {
+            url,
+            timeout: IggyDuration::new(timeout),
+            cache_ttl: IggyDuration::new(cache_ttl),
+        })
+        .unwrap()
+    }
+
+    fn session() -> Session {
+        Session::stateless(ALLOWED_USER_ID, "127.0.0.1:1234".parse().unwrap())
+    }
+
+    #[tokio::test]
+    async fn resolved_decision_should_allow_action() {
+        let url = start_opa(Duration::ZERO).await;
+        let authorizer = authorizer(url, Duration::from_secs(1), Duration::from_secs(5));
+        let session = session();
+        let request = AuthorizationRequest::stream(ALLOWED_USER_ID, "stream.get", 1);
+
+        authorizer.resolve(&session, &[request]).await;
+
+        assert!(authorizer.authorize(&session, &request).is_ok());
+    }
+
+    #[tokio::test]
+    async fn resolved_decision_should_deny_action() {
+        let url = start_opa(Duration::ZERO).await;
+        let authorizer = authorizer(url, Duration::from_secs(1), Duration::from_secs(5));
+        let session = session();
+        let request = AuthorizationRequest::stream(DENIED_USER_ID, "stream.get", 1);
+
+        authorizer.resolve(&session, &[request]).await;
+
+        assert_eq!(
+            authorizer
+                .authorize(&session, &request)
+                .unwrap_err()
+                .as_code(),
+            IggyError::Unauthorized.as_code()
+        );
+    }
+
+    #[tokio::test]
+    async fn decision_should_be_resolved_without_cache() {
+        let url = start_opa(Duration::ZERO).await;
+        let authorizer = authorizer(url, Duration::from_secs(1), Duration::ZERO);
+        let session = session();
+        let allowed = AuthorizationRequest::new(ALLOWED_USER_ID, "user.create");
+        let denied = AuthorizationRequest::new(DENIED_USER_ID, "user.create");
+
+        authorizer.resolve(&session, &[allowed, denied]).await;
+
+        assert!(authorizer.authorize(&session, &allowed).is_ok());
+        assert!(authorizer.authorize(&session, &denied).is_err());
+    }
+
+    #[tokio::test]
+    async fn decision_resolved_without_cache_should_not_be_shared_with_other_session() {
+        let url = start_opa(Duration::ZERO).await;
+        let authorizer = authorizer(url, Duration::from_secs(1), Duration::ZERO);
+        let other_session = session();
+        let session = session();
+        let request = AuthorizationRequest::new(ALLOWED_USER_ID, "user.create");
+
+        authorizer.resolve(&session, &[request]).await;
+
+        assert!(authorizer.authorize(&session, &request).is_ok());
+        assert!(authorizer.authorize(&other_session, &request).is_err());
+    }
+
+    #[tokio::test]
+    async fn resolved_decision_should_expire_after_cache_ttl() {
+        let url = start_opa(Duration::ZERO).await;
+        let authorizer = authorizer(url, Duration::from_secs(1), Duration::from_millis(200));
+        let session = session();
+        let request = AuthorizationRequest::stream(ALLOWED_USER_ID, "stream.get", 1);
+
+        authorizer.resolve(&session, &[request]).await;
+        assert!(authorizer.authorize(&session, &request).is_ok());
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        assert!(authorizer.authorize(&session, &request).is_err());
+    }
+
+    #[tokio::test]
+    async fn timed_out_decision_should_deny_action() {
+        let url = start_opa(Duration::from_secs(2)).await;
+        let authorizer = authorizer(url, Duration::from_millis(100), Duration::from_secs(5));
+        let session = session();
+        let request = AuthorizationRequest::stream(ALLOWED_USER_ID, "stream.get", 1);
+
+        authorizer.resolve(&session, &[request]).await;
+
+        assert!(authorizer.authorize(&session, &request).is_err());
+    }
+
+    #[tokio::test]
+    async fn unavailable_endpoint_should_deny_action() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!(
+            "http://{}/v1/data/iggy/authz/allow",
+            listener.local_addr().unwrap()
+        );
+        drop(listener);
+        let authorizer = authorizer(url, Duration::from_secs(1), Duration::from_secs(5));
+        let session = session();
+        let request = AuthorizationRequest::stream(ALLOWED_USER_ID, "stream.get", 1);
+
+        authorizer.resolve(&session, &[request]).await;
+
+        assert!(authorizer.authorize(&session, &request).is_err());
+    }
+
+    #[tokio::test]
+    async fn unresolved_decision_should_deny_action() {
+        let url = start_opa(Duration::ZERO).await;
+        let authorizer = authorizer(url, Duration::from_secs(1), Duration::from_secs(5));
+        let session = session();
+        let resolved = AuthorizationRequest::stream(ALLOWED_USER_ID, "stream.get", 1);
+        let unresolved = AuthorizationRequest::stream(ALLOWED_USER_ID, "stream.get", 2);
+
+        authorizer.resolve(&session, &[resolved]).await;
+
+        assert!(authorizer.authorize(&session, &resolved).is_ok());
+        assert!(authorizer.authorize(&session, &unresolved).is_err());
+    }
+}
@@ -0,0 +1,232 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::system::PolicyAuthorizerConfig;
+use crate::server_error::AuthorizationError;
+use crate::streaming::session::Session;
+use crate::streaming::users::authorizers::{AuthorizationRequest, Authorizer};
+use iggy::error::IggyError;
+use iggy::models::user_info::UserId;
+use serde::Deserialize;
+
+const ANY_ACTION: &str = "*";
+const ACTIONS_WILDCARD_SUFFIX: &str = ".*";
+
+/// Evaluates the rules of the static policy file, loaded once the server starts.
+/// The deny rules take precedence over the allow ones, and the actions not matching any rule are denied.
+#[derive(Debug, Default, Deserialize)]
+pub struct PolicyAuthorizer {
+    #[serde(default)]
+    rules: Vec<PolicyRule>,
+}
+
+/// The rule matching the actions, e.g. `stream.*` or `message.poll`, performed by the users on the streams and topics.
+/// The empty list of the users, streams or topics matches all of them.
+#[derive(Debug, Deserialize)]
+struct PolicyRule {
+    effect: PolicyEffect,
+    actions: Vec<String>,
+    #[serde(default)]
+    users: Vec<UserId>,
+    #[serde(default)]
+    streams: Vec<u32>,
+    #[serde(default)]
+    topics: Vec<u32>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+enum PolicyEffect {
+    Allow,
+    Deny,
+}
+
+impl PolicyAuthorizer {
+    pub fn load(config: &PolicyAuthorizerConfig) -> Result<Self, AuthorizationError> {
+        let policy = std::fs::read_to_string(&config.path).map_err(|error| {
+            AuthorizationError::InvalidPolicyFile {
+                path: config.path.clone(),
+                reason: error.to_string(),
+            }
+        })?;
+        Self::parse(&policy).map_err(|reason| AuthorizationError::InvalidPolicyFile {
+            path: config.path.clone(),
+            reason,
+        })
+    }
+
+    fn parse(policy: &str) -> Result<Self, String> {
+        let authorizer: Self = toml::from_str(policy).map_err(|error| error.to_string())?;
+        if let Some(index) = authorizer
+            .rules
+            .iter()
+            .position(|rule| rule.actions.is_empty())
+        {
+            return Err(format!("rule at index: {index} has no actions"));
+        }
+
+        Ok(authorizer)
+    }
+
+    fn has_matching_rule(&self, effect: PolicyEffect, request: &AuthorizationRequest) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| rule.effect == effect && rule.matches(request))
+    }
+}
+
+impl PolicyRule {
+    fn matches(&self, request: &AuthorizationRequest) -> bool {
+        self.actions
+            .iter()
+            .any(|action| matches_action(action, request.action))
+            && (self.users.is_empty() || self.users.contains(&request.user_id))
+            && matches_resource(&self.streams, request.stream_id)
+            && matches_resource(&self.topics, request.topic_id)
+    }
+}
+
+fn matches_action(pattern: &str, action: &str) -> bool {
+    if pattern == ANY_ACTION {
+        return true;
+    }
+
+    match pattern.strip_suffix(ACTIONS_WILDCARD_SUFFIX) {
+        Some(prefix) => action
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with('.')),
+        None => pattern == action,
+    }
+}
+
+fn matches_resource(ids: &[u32], id: Option<u32>) -> bool {
+    ids.is_empty() || id.is_some_and(|id| ids.contains(&id))
+}
+
+impl Authorizer for PolicyAuthorizer {
+    fn authorize(
+        &self,
+        _session: &Session,
+        request: &AuthorizationRequest,
+    ) -> Result<(), IggyError> {
+        if self.has_matching_rule(PolicyEffect::Deny, request) {
+            return Err(IggyError::Unauthorized);
+        }
+
+        if self.has_matching_rule(PolicyEffect::Allow, request) {
+            return Ok(());
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session() -> Session {
+        Session::stateless(1, "127.0.0.1:1234".parse().unwrap())
+    }
+    use iggy::command::{CREATE_STREAM, DELETE_STREAM, POLL_MESSAGES, SEND_MESSAGES};
+
+    const POLICY: &str = r#"
+        [[rules]]
+        effect = "allow"
+        actions = ["stream.*"]
+        users = [1]
+
+        [[rules]]
+        effect = "deny"
+        actions = ["stream.delete"]
+        streams = [2]
+
+        [[rules]]
+        effect = "allow"
+        actions = ["message.poll"]
+        streams = [1]
+        topics = [1]
+    "#;
+
+    #[test]
+    fn should_allow_only_the_actions_matching_the_allow_rules() {
+        let authorizer = PolicyAuthorizer::parse(POLICY).unwrap();
+
+        assert!(authorizer
+            .authorize(&session(), &AuthorizationRequest::new(1, CREATE_STREAM))
+            .is_ok());
+        assert!(authorizer
+            .authorize(&session(), &AuthorizationRequest::new(2, CREATE_STREAM))
+            .is_err());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(2, POLL_MESSAGES, 1, 1)
+            )
+            .is_ok());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(2, POLL_MESSAGES, 1, 2)
+            )
+            .is_err());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::topic(1, SEND_MESSAGES, 1, 1)
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn deny_rules_should_take_precedence_over_allow_rules() {
+        let authorizer = PolicyAuthorizer::parse(POLICY).unwrap();
+
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::stream(1, DELETE_STREAM, 1)
+            )
+            .is_ok());
+        assert!(authorizer
+            .authorize(
+                &session(),
+                &AuthorizationRequest::stream(1, DELETE_STREAM, 2)
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn should_match_actions_by_wildcard() {
+        assert!(matches_action("*", "stream.create"));
+        assert!(matches_action("stream.*", "stream.create"));
+        assert!(matches_action("stream.create", "stream.create"));
+        assert!(!matches_action("stream.*", "streams.create"));
+        assert!(!matches_action("stream.*", "topic.create"));
+    }
+
+    #[test]
+    fn should_reject_rule_without_actions() {
+        let policy = r#"
+            [[rules]]
+            effect = "allow"
+            actions = []
+        "#;
+
+        assert!(PolicyAuthorizer::parse(policy).is_err());
+    }
+}
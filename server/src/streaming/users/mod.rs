@@ -16,6 +16,7 @@
  * under the License.
  */
 
+pub mod authorizers;
 pub mod permissioner;
 pub mod permissioner_rules;
 pub mod user;
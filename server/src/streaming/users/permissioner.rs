@@ -15,64 +15,149 @@
  * specific language governing permissions and limitations
  * under the License.
  */
-
+use crate::streaming::personal_access_tokens::token_scope;
+use crate::streaming::session::Session;
+use crate::streaming::tenants::namespaces::TenantNamespaces;
+use crate::streaming::users::authorizers::opa::OpaAuthorizer;
+use crate::streaming::users::authorizers::{
+    AuthorizationRequest, AuthorizerKind, AuthorizerKindType, MANAGE_SCHEMAS, PROMOTE_STANDBY,
+    SHUTDOWN_SERVER, UPDATE_CONFIG,
+};
 use crate::streaming::users::user::User;
-use ahash::{AHashMap, AHashSet};
-use iggy::models::permissions::{GlobalPermissions, Permissions, StreamPermissions};
+use iggy::command::*;
+use iggy::error::IggyError;
+use iggy::models::permissions::Permissions;
 use iggy::models::user_info::UserId;
+use std::sync::Arc;
 
+/// Authorizes the actions performed by the users, by passing them to the configured authorizer.
+/// The actions of the clients logged in with the scoped personal access tokens are limited to the token scope first.
+/// The users permissions and the names of the streams and topics (used by the pattern permissions) are kept only by the built-in authorizer, the external ones ignore them.
+/// Regardless of the authorizer, the users of the tenants are confined to their namespaces.
 #[derive(Debug, Default)]
 pub struct Permissioner {
-    pub(super) users_permissions: AHashMap<UserId, GlobalPermissions>,
-    pub(super) users_streams_permissions: AHashMap<(UserId, u32), StreamPermissions>,
-    pub(super) users_that_can_poll_messages_from_all_streams: AHashSet<UserId>,
-    pub(super) users_that_can_send_messages_to_all_streams: AHashSet<UserId>,
-    pub(super) users_that_can_poll_messages_from_specific_streams: AHashSet<(UserId, u32)>,
-    pub(super) users_that_can_send_messages_to_specific_streams: AHashSet<(UserId, u32)>,
+    authorizer: AuthorizerKind,
+    namespaces: TenantNamespaces,
 }
 
 impl Permissioner {
-    pub fn init(&mut self, users: &[&User]) {
-        for user in users {
-            self.init_permissions_for_user(user.id, user.permissions.clone());
+    pub fn new(authorizer: AuthorizerKind) -> Self {
+        Self {
+            authorizer,
+            namespaces: TenantNamespaces::default(),
         }
     }
 
-    pub fn init_permissions_for_user(&mut self, user_id: UserId, permissions: Option<Permissions>) {
-        if permissions.is_none() {
-            return;
-        }
+    pub fn kind(&self) -> AuthorizerKindType {
+        self.authorizer.kind()
+    }
 
-        let permissions = permissions.unwrap();
-        if permissions.global.poll_messages {
-            self.users_that_can_poll_messages_from_all_streams
-                .insert(user_id);
-        }
+    /// Returns the external authorizer, whose decisions have to be resolved before the command takes the system lock.
+    pub fn external_authorizer(&self) -> Option<Arc<OpaAuthorizer>> {
+        self.authorizer.external()
+    }
 
-        if permissions.global.send_messages {
-            self.users_that_can_send_messages_to_all_streams
-                .insert(user_id);
+    /// Returns the action authorized while handling the command, named after the command unless it's authorized as another one
+    /// (e.g. acknowledging the messages is authorized as polling them), or `None` if the command isn't authorized by the permissioner.
+    pub fn command_action(command: &'static str) -> Option<&'static str> {
+        match command {
+            PING
+            | ENABLE_FRAME_CHECKSUMS
+            | ENABLE_ZERO_COPY_POLLING
+            | ENABLE_CONSUMER_GROUP_EVENTS
+            | GET_CLUSTER_METADATA
+            | GET_SNAPSHOT_FILE
+            | LOGIN_USER
+            | LOGOUT_USER
+            | LOGIN_WITH_PERSONAL_ACCESS_TOKEN
+            | GET_PERSONAL_ACCESS_TOKENS
+            | CREATE_PERSONAL_ACCESS_TOKEN
+            | DELETE_PERSONAL_ACCESS_TOKEN
+            | BEGIN_TRANSACTION => None,
+            GET_ME => Some(GET_CLIENT),
+            GET_SCHEMA | GET_SUBJECT_SCHEMA => Some(GET_SCHEMAS),
+            REGISTER_SCHEMA | UPDATE_SCHEMA_COMPATIBILITY => Some(MANAGE_SCHEMAS),
+            GET_PARTITION => Some(GET_TOPIC),
+            GET_BOOKMARK | GET_BOOKMARKS => Some(GET_CONSUMER_OFFSET),
+            STORE_BOOKMARK | SEND_OFFSETS_TO_TRANSACTION => Some(STORE_CONSUMER_OFFSET),
+            DELETE_BOOKMARK => Some(DELETE_CONSUMER_OFFSET),
+            ACK_MESSAGES | NACK_MESSAGES | REJECT_MESSAGES => Some(POLL_MESSAGES),
+            // Completing the transaction appends its markers to the topics written within it.
+            FLUSH_UNSAVED_BUFFER | COMMIT_TRANSACTION | ABORT_TRANSACTION => Some(SEND_MESSAGES),
+            _ => Some(command),
         }
+    }
 
-        self.users_permissions.insert(user_id, permissions.global);
-        if permissions.streams.is_none() {
-            return;
-        }
+    /// Returns the requests checked while performing the action (or handling the command authorized as the action)
+    /// on the (already found) stream and topic, including the lookup of the stream and topic themselves, to resolve their decisions up front.
+    pub fn authorization_requests(
+        &self,
+        user_id: UserId,
+        action: &'static str,
+        stream_id: Option<u32>,
+        topic_id: Option<u32>,
+    ) -> Vec<AuthorizationRequest> {
+        let Some(action) = Self::command_action(action) else {
+            return Vec::new();
+        };
 
-        let streams = permissions.streams.unwrap();
-        for (stream_id, stream) in streams {
-            if stream.poll_messages {
-                self.users_that_can_poll_messages_from_specific_streams
-                    .insert((user_id, stream_id));
+        let mut requests = Vec::with_capacity(3);
+        if let Some(stream_id) = stream_id {
+            requests.push(AuthorizationRequest::stream(user_id, GET_STREAM, stream_id));
+            if let Some(topic_id) = topic_id {
+                requests.push(AuthorizationRequest::topic(
+                    user_id, GET_TOPIC, stream_id, topic_id,
+                ));
             }
+        }
 
-            if stream.send_messages {
-                self.users_that_can_send_messages_to_specific_streams
-                    .insert((user_id, stream_id));
+        let request = match action {
+            GET_STREAM | UPDATE_STREAM | DELETE_STREAM | PURGE_STREAM | GET_TOPICS
+            | CREATE_TOPIC => {
+                stream_id.map(|stream_id| AuthorizationRequest::stream(user_id, action, stream_id))
             }
+            GET_TOPIC
+            | UPDATE_TOPIC
+            | DELETE_TOPIC
+            | PURGE_TOPIC
+            | CREATE_PARTITIONS
+            | DELETE_PARTITIONS
+            | UPDATE_PARTITION
+            | DELETE_SEGMENTS
+            | VERIFY_SEGMENTS
+            | CREATE_CONSUMER_GROUP
+            | DELETE_CONSUMER_GROUP
+            | GET_CONSUMER_GROUP
+            | GET_CONSUMER_GROUPS
+            | JOIN_CONSUMER_GROUP
+            | LEAVE_CONSUMER_GROUP
+            | HEARTBEAT_CONSUMER_GROUP
+            | GET_CONSUMER_OFFSET
+            | STORE_CONSUMER_OFFSET
+            | DELETE_CONSUMER_OFFSET
+            | POLL_MESSAGES
+            | SEND_MESSAGES => stream_id.zip(topic_id).map(|(stream_id, topic_id)| {
+                AuthorizationRequest::topic(user_id, action, stream_id, topic_id)
+            }),
+            _ => Some(AuthorizationRequest::new(user_id, action)),
+        };
+        if let Some(request) = request {
+            if !requests.contains(&request) {
+                requests.push(request);
+            }
+        }
+        requests
+    }
 
-            self.users_streams_permissions
-                .insert((user_id, stream_id), stream);
+    pub fn init(&mut self, users: &[&User]) {
+        if let AuthorizerKind::Builtin(authorizer) = &mut self.authorizer {
+            authorizer.init(users);
+        }
+    }
+
+    pub fn init_permissions_for_user(&mut self, user_id: UserId, permissions: Option<Permissions>) {
+        if let AuthorizerKind::Builtin(authorizer) = &mut self.authorizer {
+            authorizer.init_permissions_for_user(user_id, permissions);
         }
     }
 
@@ -81,21 +166,770 @@ impl Permissioner {
         user_id: UserId,
         permissions: Option<Permissions>,
     ) {
-        self.delete_permissions_for_user(user_id);
-        self.init_permissions_for_user(user_id, permissions);
+        if let AuthorizerKind::Builtin(authorizer) = &mut self.authorizer {
+            authorizer.update_permissions_for_user(user_id, permissions);
+        }
     }
 
     pub fn delete_permissions_for_user(&mut self, user_id: UserId) {
-        self.users_permissions.remove(&user_id);
-        self.users_that_can_poll_messages_from_all_streams
-            .remove(&user_id);
-        self.users_that_can_send_messages_to_all_streams
-            .remove(&user_id);
-        self.users_streams_permissions
-            .retain(|(id, _), _| *id != user_id);
-        self.users_that_can_poll_messages_from_specific_streams
-            .retain(|(id, _)| *id != user_id);
-        self.users_that_can_send_messages_to_specific_streams
-            .retain(|(id, _)| *id != user_id);
+        self.namespaces.set_user_prefix(user_id, None);
+        if let AuthorizerKind::Builtin(authorizer) = &mut self.authorizer {
+            authorizer.delete_permissions_for_user(user_id);
+        }
+    }
+
+    pub fn register_stream(&mut self, stream_id: u32, name: &str) {
+        self.namespaces.register_stream(stream_id, name);
+        if let AuthorizerKind::Builtin(authorizer) = &mut self.authorizer {
+            authorizer.register_stream(stream_id, name);
+        }
+    }
+
+    pub fn unregister_stream(&mut self, stream_id: u32) {
+        self.namespaces.unregister_stream(stream_id);
+        if let AuthorizerKind::Builtin(authorizer) = &mut self.authorizer {
+            authorizer.unregister_stream(stream_id);
+        }
+    }
+
+    pub fn register_topic(&mut self, stream_id: u32, topic_id: u32, name: &str) {
+        if let AuthorizerKind::Builtin(authorizer) = &mut self.authorizer {
+            authorizer.register_topic(stream_id, topic_id, name);
+        }
+    }
+
+    pub fn unregister_topic(&mut self, stream_id: u32, topic_id: u32) {
+        if let AuthorizerKind::Builtin(authorizer) = &mut self.authorizer {
+            authorizer.unregister_topic(stream_id, topic_id);
+        }
+    }
+
+    /// Confines the user to the namespace of its tenant, or lifts the confinement if the user doesn't belong to any.
+    pub fn set_user_namespace(&mut self, user_id: UserId, prefix: Option<&str>) {
+        self.namespaces.set_user_prefix(user_id, prefix);
+    }
+
+    pub fn is_stream_visible(&self, user_id: UserId, stream_id: u32) -> bool {
+        self.namespaces.is_stream_visible(user_id, stream_id)
+    }
+
+    pub fn authorize_stream_name(&self, user_id: UserId, name: &str) -> Result<(), IggyError> {
+        self.namespaces.authorize_stream_name(user_id, name)
+    }
+
+    /// Authorizes the action of the user logged in with the session, limited to the scope of its personal access token (if any).
+    pub fn authorize(
+        &self,
+        session: &Session,
+        request: AuthorizationRequest,
+    ) -> Result<(), IggyError> {
+        token_scope::authorize(session.get_token_scope().as_deref(), &request)?;
+        self.namespaces.authorize(&request)?;
+        self.authorizer.authorize(session, &request)
+    }
+
+    pub fn get_stats(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), GET_STATS),
+        )
+    }
+
+    pub fn get_clients(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), GET_CLIENTS),
+        )
+    }
+
+    pub fn get_client(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), GET_CLIENT),
+        )
+    }
+
+    pub fn get_config(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), GET_CONFIG),
+        )
+    }
+
+    pub fn update_config(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), UPDATE_CONFIG),
+        )
+    }
+
+    pub fn get_audit_log(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), GET_AUDIT_LOG),
+        )
+    }
+
+    pub fn create_backup(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), CREATE_BACKUP),
+        )
+    }
+
+    pub fn restore_backup(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), RESTORE_BACKUP),
+        )
+    }
+
+    pub fn shutdown_server(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), SHUTDOWN_SERVER),
+        )
+    }
+
+    pub fn promote_standby(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), PROMOTE_STANDBY),
+        )
+    }
+
+    pub fn get_quotas(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), GET_QUOTAS),
+        )
+    }
+
+    pub fn set_quota(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), SET_QUOTA),
+        )
+    }
+
+    pub fn get_user(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), GET_USER),
+        )
+    }
+
+    pub fn get_users(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), GET_USERS),
+        )
+    }
+
+    pub fn create_user(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), CREATE_USER),
+        )
+    }
+
+    pub fn delete_user(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), DELETE_USER),
+        )
+    }
+
+    pub fn update_user(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), UPDATE_USER),
+        )
+    }
+
+    pub fn update_permissions(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), UPDATE_PERMISSIONS),
+        )
+    }
+
+    pub fn change_password(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), CHANGE_PASSWORD),
+        )
+    }
+
+    pub fn get_stream(&self, session: &Session, stream_id: u32) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::stream(session.get_user_id(), GET_STREAM, stream_id),
+        )
+    }
+
+    pub fn get_streams(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), GET_STREAMS),
+        )
+    }
+
+    pub fn create_stream(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), CREATE_STREAM),
+        )
+    }
+
+    pub fn update_stream(&self, session: &Session, stream_id: u32) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::stream(session.get_user_id(), UPDATE_STREAM, stream_id),
+        )
+    }
+
+    pub fn delete_stream(&self, session: &Session, stream_id: u32) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::stream(session.get_user_id(), DELETE_STREAM, stream_id),
+        )
+    }
+
+    pub fn purge_stream(&self, session: &Session, stream_id: u32) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::stream(session.get_user_id(), PURGE_STREAM, stream_id),
+        )
+    }
+
+    pub fn get_topic(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(session.get_user_id(), GET_TOPIC, stream_id, topic_id),
+        )
+    }
+
+    pub fn get_topics(&self, session: &Session, stream_id: u32) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::stream(session.get_user_id(), GET_TOPICS, stream_id),
+        )
+    }
+
+    pub fn create_topic(&self, session: &Session, stream_id: u32) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::stream(session.get_user_id(), CREATE_TOPIC, stream_id),
+        )
+    }
+
+    pub fn update_topic(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(session.get_user_id(), UPDATE_TOPIC, stream_id, topic_id),
+        )
+    }
+
+    pub fn delete_topic(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(session.get_user_id(), DELETE_TOPIC, stream_id, topic_id),
+        )
+    }
+
+    pub fn purge_topic(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(session.get_user_id(), PURGE_TOPIC, stream_id, topic_id),
+        )
+    }
+
+    pub fn set_topic_throttle(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), SET_TOPIC_THROTTLE),
+        )
+    }
+
+    pub fn create_partitions(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                CREATE_PARTITIONS,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn delete_partitions(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                DELETE_PARTITIONS,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn update_partition(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                UPDATE_PARTITION,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn move_partition(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), MOVE_PARTITION),
+        )
+    }
+
+    pub fn delete_segments(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                DELETE_SEGMENTS,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn verify_segments(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                VERIFY_SEGMENTS,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn create_consumer_group(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                CREATE_CONSUMER_GROUP,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn delete_consumer_group(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                DELETE_CONSUMER_GROUP,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn get_consumer_group(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                GET_CONSUMER_GROUP,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn get_consumer_groups(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                GET_CONSUMER_GROUPS,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn join_consumer_group(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                JOIN_CONSUMER_GROUP,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn leave_consumer_group(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                LEAVE_CONSUMER_GROUP,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn heartbeat_consumer_group(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                HEARTBEAT_CONSUMER_GROUP,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn get_consumer_offset(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                GET_CONSUMER_OFFSET,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn store_consumer_offset(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                STORE_CONSUMER_OFFSET,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn delete_consumer_offset(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(
+                session.get_user_id(),
+                DELETE_CONSUMER_OFFSET,
+                stream_id,
+                topic_id,
+            ),
+        )
+    }
+
+    pub fn poll_messages(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(session.get_user_id(), POLL_MESSAGES, stream_id, topic_id),
+        )
+    }
+
+    pub fn append_messages(
+        &self,
+        session: &Session,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::topic(session.get_user_id(), SEND_MESSAGES, stream_id, topic_id),
+        )
+    }
+
+    pub fn get_schemas(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), GET_SCHEMAS),
+        )
+    }
+
+    pub fn manage_schemas(&self, session: &Session) -> Result<(), IggyError> {
+        self.authorize(
+            session,
+            AuthorizationRequest::new(session.get_user_id(), MANAGE_SCHEMAS),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::OpaAuthorizerConfig;
+    use crate::http::jwt::json_web_token::Identity;
+    use crate::streaming::users::authorizers::opa::tests::{start_opa, ALLOWED_USER_ID};
+    use iggy::models::personal_access_token::PersonalAccessTokenScope;
+    use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+    use iggy::utils::duration::IggyDuration;
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::time::Duration;
+
+    fn root_permissioner() -> (Permissioner, UserId) {
+        let root = User::root(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD);
+        let mut permissioner = Permissioner::default();
+        permissioner.init_permissions_for_user(root.id, root.permissions.clone());
+        (permissioner, root.id)
+    }
+
+    #[test]
+    fn polling_should_be_limited_to_token_scope_of_http_identity_session() {
+        let (permissioner, user_id) = root_permissioner();
+        let identity = Identity::new(
+            "token".to_owned(),
+            0,
+            user_id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+            Some(Arc::new(PersonalAccessTokenScope {
+                read_only: true,
+                streams: Some(vec![1]),
+            })),
+        );
+
+        // The streaming responses (SSE and NDJSON) poll the messages with the session created from the identity,
+        // after the request handler has returned, so the scope must be carried by the session itself.
+        let session = identity.session();
+        assert!(permissioner.poll_messages(&session, 1, 1).is_ok());
+        assert!(permissioner.poll_messages(&session, 2, 1).is_err());
+        assert!(permissioner.append_messages(&session, 1, 1).is_err());
+
+        let unscoped_session = Session::stateless(user_id, identity.ip_address);
+        assert!(permissioner.poll_messages(&unscoped_session, 2, 1).is_ok());
+        assert!(permissioner
+            .append_messages(&unscoped_session, 1, 1)
+            .is_ok());
+    }
+
+    fn opa_permissioner(url: String) -> Permissioner {
+        let authorizer = OpaAuthorizer::new(&OpaAuthorizerConfig {
+            url,
+            timeout: IggyDuration::new(Duration::from_millis(100)),
+            cache_ttl: IggyDuration::new(Duration::from_secs(5)),
+        })
+        .unwrap();
+        Permissioner::new(AuthorizerKind::Opa(Arc::new(authorizer)))
+    }
+
+    fn address() -> SocketAddr {
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234)
+    }
+
+    #[test]
+    fn authorization_requests_should_include_lookups_of_stream_and_topic() {
+        let permissioner = Permissioner::default();
+
+        assert_eq!(
+            permissioner.authorization_requests(1, POLL_MESSAGES, Some(2), Some(3)),
+            vec![
+                AuthorizationRequest::stream(1, GET_STREAM, 2),
+                AuthorizationRequest::topic(1, GET_TOPIC, 2, 3),
+                AuthorizationRequest::topic(1, POLL_MESSAGES, 2, 3),
+            ]
+        );
+        assert_eq!(
+            permissioner.authorization_requests(1, CREATE_TOPIC, Some(2), None),
+            vec![
+                AuthorizationRequest::stream(1, GET_STREAM, 2),
+                AuthorizationRequest::stream(1, CREATE_TOPIC, 2),
+            ]
+        );
+        assert_eq!(
+            permissioner.authorization_requests(1, GET_STREAM, Some(2), None),
+            vec![AuthorizationRequest::stream(1, GET_STREAM, 2)]
+        );
+        assert_eq!(
+            permissioner.authorization_requests(1, SET_TOPIC_THROTTLE, Some(2), Some(3)),
+            vec![
+                AuthorizationRequest::stream(1, GET_STREAM, 2),
+                AuthorizationRequest::topic(1, GET_TOPIC, 2, 3),
+                AuthorizationRequest::new(1, SET_TOPIC_THROTTLE),
+            ]
+        );
+        assert!(permissioner
+            .authorization_requests(1, POLL_MESSAGES, None, None)
+            .is_empty());
+    }
+
+    #[test]
+    fn authorization_requests_should_be_derived_from_command_action() {
+        let permissioner = Permissioner::default();
+
+        assert_eq!(
+            permissioner.authorization_requests(1, ACK_MESSAGES, Some(2), Some(3)),
+            permissioner.authorization_requests(1, POLL_MESSAGES, Some(2), Some(3))
+        );
+        assert_eq!(
+            permissioner.authorization_requests(1, COMMIT_TRANSACTION, Some(2), Some(3)),
+            permissioner.authorization_requests(1, SEND_MESSAGES, Some(2), Some(3))
+        );
+        assert_eq!(
+            permissioner.authorization_requests(1, GET_ME, None, None),
+            vec![AuthorizationRequest::new(1, GET_CLIENT)]
+        );
+        assert!(permissioner
+            .authorization_requests(1, LOGIN_USER, None, None)
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn resolved_external_decisions_should_be_checked_without_querying_authorizer() {
+        let permissioner = opa_permissioner(start_opa(Duration::ZERO).await);
+        let session = Session::stateless(ALLOWED_USER_ID, address());
+        let requests = permissioner.authorization_requests(
+            session.get_user_id(),
+            POLL_MESSAGES,
+            Some(1),
+            Some(1),
+        );
+
+        permissioner
+            .external_authorizer()
+            .unwrap()
+            .resolve(&session, &requests)
+            .await;
+
+        assert!(permissioner.get_stream(&session, 1).is_ok());
+        assert!(permissioner.get_topic(&session, 1, 1).is_ok());
+        assert!(permissioner.poll_messages(&session, 1, 1).is_ok());
+        // The decisions which haven't been resolved before are denied, rather than queried while holding the lock.
+        assert!(permissioner.append_messages(&session, 1, 1).is_err());
+        assert!(permissioner.poll_messages(&session, 1, 2).is_err());
+    }
+
+    #[tokio::test]
+    async fn unavailable_external_authorizer_should_deny_action() {
+        let permissioner =
+            opa_permissioner("http://127.0.0.1:1/v1/data/iggy/authz/allow".to_owned());
+        let session = Session::stateless(ALLOWED_USER_ID, address());
+        let requests =
+            permissioner.authorization_requests(session.get_user_id(), CREATE_STREAM, None, None);
+
+        permissioner
+            .external_authorizer()
+            .unwrap()
+            .resolve(&session, &requests)
+            .await;
+
+        assert!(permissioner.create_stream(&session).is_err());
     }
 }
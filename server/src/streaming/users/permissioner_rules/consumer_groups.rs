@@ -16,10 +16,10 @@
  * under the License.
  */
 
-use crate::streaming::users::permissioner::Permissioner;
+use crate::streaming::users::authorizers::builtin::BuiltinAuthorizer;
 use iggy::error::IggyError;
 
-impl Permissioner {
+impl BuiltinAuthorizer {
     pub fn create_consumer_group(
         &self,
         user_id: u32,
@@ -73,4 +73,13 @@ impl Permissioner {
     ) -> Result<(), IggyError> {
         self.get_topic(user_id, stream_id, topic_id)
     }
+
+    pub fn heartbeat_consumer_group(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.get_topic(user_id, stream_id, topic_id)
+    }
 }
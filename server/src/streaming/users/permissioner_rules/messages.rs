@@ -16,10 +16,10 @@
  * under the License.
  */
 
-use crate::streaming::users::permissioner::Permissioner;
+use crate::streaming::users::authorizers::builtin::BuiltinAuthorizer;
 use iggy::error::IggyError;
 
-impl Permissioner {
+impl BuiltinAuthorizer {
     pub fn poll_messages(
         &self,
         user_id: u32,
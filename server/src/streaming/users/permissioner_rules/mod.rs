@@ -20,6 +20,8 @@ mod consumer_groups;
 pub mod consumer_offsets;
 mod messages;
 mod partitions;
+mod patterns;
+mod schemas;
 mod segments;
 mod streams;
 mod system;
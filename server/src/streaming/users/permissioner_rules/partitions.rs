@@ -16,10 +16,10 @@
  * under the License.
  */
 
-use crate::streaming::users::permissioner::Permissioner;
+use crate::streaming::users::authorizers::builtin::BuiltinAuthorizer;
 use iggy::error::IggyError;
 
-impl Permissioner {
+impl BuiltinAuthorizer {
     pub fn create_partitions(
         &self,
         user_id: u32,
@@ -37,4 +37,23 @@ impl Permissioner {
     ) -> Result<(), IggyError> {
         self.update_topic(user_id, stream_id, topic_id)
     }
+
+    pub fn update_partition(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.update_topic(user_id, stream_id, topic_id)
+    }
+
+    pub fn move_partition(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
 }
@@ -0,0 +1,111 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::streaming::users::authorizers::builtin::BuiltinAuthorizer;
+use crate::streaming::users::authorizers::AuthorizationRequest;
+use iggy::command::*;
+use iggy::error::IggyError;
+use iggy::models::permissions::PatternPermissions;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PatternAccess {
+    Read,
+    Write,
+    Admin,
+}
+
+impl PatternAccess {
+    fn required_for(action: &str) -> Option<Self> {
+        match action {
+            GET_STREAM
+            | GET_TOPICS
+            | GET_TOPIC
+            | GET_CONSUMER_GROUP
+            | GET_CONSUMER_GROUPS
+            | CREATE_CONSUMER_GROUP
+            | DELETE_CONSUMER_GROUP
+            | JOIN_CONSUMER_GROUP
+            | LEAVE_CONSUMER_GROUP
+            | HEARTBEAT_CONSUMER_GROUP
+            | GET_CONSUMER_OFFSET
+            | STORE_CONSUMER_OFFSET
+            | DELETE_CONSUMER_OFFSET
+            | POLL_MESSAGES => Some(PatternAccess::Read),
+            SEND_MESSAGES => Some(PatternAccess::Write),
+            UPDATE_STREAM | DELETE_STREAM | PURGE_STREAM | CREATE_TOPIC | UPDATE_TOPIC
+            | DELETE_TOPIC | PURGE_TOPIC | CREATE_PARTITIONS | DELETE_PARTITIONS
+            | UPDATE_PARTITION | DELETE_SEGMENTS | VERIFY_SEGMENTS => Some(PatternAccess::Admin),
+            _ => None,
+        }
+    }
+
+    fn is_granted_by(self, permissions: &PatternPermissions) -> bool {
+        match self {
+            PatternAccess::Read => permissions.admin || permissions.read,
+            PatternAccess::Write => permissions.admin || permissions.write,
+            PatternAccess::Admin => permissions.admin,
+        }
+    }
+}
+
+impl BuiltinAuthorizer {
+    /// Authorizes the action on the stream or topic using only the most specific of the user's patterns matching its name,
+    /// so that e.g. the `orders.payments` pattern can narrow down the access granted by the `orders.*` one.
+    /// If several patterns are equally specific, the first one takes precedence.
+    pub fn authorize_by_pattern(&self, request: &AuthorizationRequest) -> Result<(), IggyError> {
+        let Some(access) = PatternAccess::required_for(request.action) else {
+            return Err(IggyError::Unauthorized);
+        };
+
+        let Some(patterns) = self.users_patterns_permissions.get(&request.user_id) else {
+            return Err(IggyError::Unauthorized);
+        };
+
+        let Some(stream_name) = request
+            .stream_id
+            .and_then(|stream_id| self.streams_names.get(&stream_id))
+        else {
+            return Err(IggyError::Unauthorized);
+        };
+
+        let topic_name = match (request.stream_id, request.topic_id) {
+            (Some(stream_id), Some(topic_id)) => {
+                match self.topics_names.get(&(stream_id, topic_id)) {
+                    Some(topic_name) => Some(topic_name.as_str()),
+                    None => return Err(IggyError::Unauthorized),
+                }
+            }
+            _ => None,
+        };
+
+        let mut matched_pattern: Option<&PatternPermissions> = None;
+        for pattern in patterns {
+            if !pattern.matches(stream_name, topic_name) {
+                continue;
+            }
+
+            if matched_pattern.is_none_or(|matched| pattern.specificity() > matched.specificity()) {
+                matched_pattern = Some(pattern);
+            }
+        }
+
+        match matched_pattern {
+            Some(pattern) if access.is_granted_by(pattern) => Ok(()),
+            _ => Err(IggyError::Unauthorized),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::users::authorizers::builtin::BuiltinAuthorizer;
+use iggy::error::IggyError;
+
+impl BuiltinAuthorizer {
+    /// The schemas are shared across the streams, so reading any stream is sufficient to resolve the schemas attached to the messages.
+    pub fn get_schemas(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_streams || global_permissions.read_streams {
+                return Ok(());
+            }
+        }
+
+        if self.users_streams_permissions.iter().any(
+            |((permissions_user_id, _), stream_permissions)| {
+                *permissions_user_id == user_id
+                    && (stream_permissions.manage_stream || stream_permissions.read_stream)
+            },
+        ) {
+            return Ok(());
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    pub fn manage_schemas(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_streams {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+}
@@ -15,10 +15,10 @@
  * specific language governing permissions and limitations
  * under the License.
  */
-use crate::streaming::users::permissioner::Permissioner;
+use crate::streaming::users::authorizers::builtin::BuiltinAuthorizer;
 use iggy::error::IggyError;
 
-impl Permissioner {
+impl BuiltinAuthorizer {
     pub fn delete_segments(
         &self,
         user_id: u32,
@@ -27,4 +27,13 @@ impl Permissioner {
     ) -> Result<(), IggyError> {
         self.update_topic(user_id, stream_id, topic_id)
     }
+
+    pub fn verify_segments(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.update_topic(user_id, stream_id, topic_id)
+    }
 }
@@ -16,10 +16,10 @@
  * under the License.
  */
 
-use crate::streaming::users::permissioner::Permissioner;
+use crate::streaming::users::authorizers::builtin::BuiltinAuthorizer;
 use iggy::error::IggyError;
 
-impl Permissioner {
+impl BuiltinAuthorizer {
     pub fn get_stats(&self, user_id: u32) -> Result<(), IggyError> {
         self.get_server_info(user_id)
     }
@@ -32,6 +32,68 @@ impl Permissioner {
         self.get_server_info(user_id)
     }
 
+    pub fn get_config(&self, user_id: u32) -> Result<(), IggyError> {
+        if self.can_manage_config(user_id) {
+            return Ok(());
+        }
+
+        self.get_server_info(user_id)
+    }
+
+    pub fn update_config(&self, user_id: u32) -> Result<(), IggyError> {
+        if self.can_manage_config(user_id) {
+            return Ok(());
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    pub fn get_audit_log(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    pub fn create_backup(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    pub fn restore_backup(&self, user_id: u32) -> Result<(), IggyError> {
+        self.create_backup(user_id)
+    }
+
+    pub fn shutdown_server(&self, user_id: u32) -> Result<(), IggyError> {
+        self.create_backup(user_id)
+    }
+
+    pub fn promote_standby(&self, user_id: u32) -> Result<(), IggyError> {
+        self.create_backup(user_id)
+    }
+
+    pub fn get_quotas(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn set_quota(&self, user_id: u32) -> Result<(), IggyError> {
+        self.create_backup(user_id)
+    }
+
+    fn can_manage_config(&self, user_id: u32) -> bool {
+        self.users_permissions
+            .get(&user_id)
+            .is_some_and(|global_permissions| global_permissions.manage_config)
+    }
+
     fn get_server_info(&self, user_id: u32) -> Result<(), IggyError> {
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_servers || global_permissions.read_servers {
@@ -0,0 +1,170 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::system::BufferPoolConfig;
+use bytes::{Bytes, BytesMut};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// A pool of the buffers used for building the message batches on the append path, shared by all the segments of the system.
+///
+/// Once a batch has been written to the log file, its frozen buffer is reclaimed (if nothing else
+/// holds it anymore) and handed out to the next batch, so the hot partitions don't allocate
+/// (and grow) a fresh buffer for each persisted batch. The pool keeps at most `max_buffers` buffers
+/// and drops the ones larger than `max_buffer_size`, so a single burst doesn't pin the memory forever.
+#[derive(Debug)]
+pub struct BufferPool {
+    enabled: bool,
+    max_buffers: usize,
+    max_buffer_size: usize,
+    buffers: Mutex<Vec<BytesMut>>,
+    allocations: AtomicU64,
+    reuses: AtomicU64,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BufferPoolMetrics {
+    pub pooled_buffers: u64,
+    pub allocations: u64,
+    pub reuses: u64,
+}
+
+impl BufferPool {
+    pub fn new(config: &BufferPoolConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            max_buffers: config.max_buffers as usize,
+            max_buffer_size: config.max_buffer_size.as_bytes_u64() as usize,
+            buffers: Mutex::new(Vec::with_capacity(config.max_buffers as usize)),
+            allocations: AtomicU64::new(0),
+            reuses: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns an empty buffer able to hold at least `capacity` bytes, reusing the pooled one if available.
+    pub fn acquire(&self, capacity: usize) -> BytesMut {
+        if self.enabled {
+            let buffer = self.buffers.lock().unwrap().pop();
+            if let Some(mut buffer) = buffer {
+                self.reuses.fetch_add(1, Ordering::Relaxed);
+                buffer.reserve(capacity);
+                return buffer;
+            }
+        }
+
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        BytesMut::with_capacity(capacity)
+    }
+
+    /// Returns the buffer to the pool, unless it's full or the buffer is too large to be kept.
+    pub fn release(&self, mut buffer: BytesMut) {
+        if !self.enabled || buffer.capacity() == 0 || buffer.capacity() > self.max_buffer_size {
+            return;
+        }
+
+        buffer.clear();
+        let mut buffers = self.buffers.lock().unwrap();
+        if buffers.len() < self.max_buffers {
+            buffers.push(buffer);
+        }
+    }
+
+    /// Reclaims the frozen buffer of the written batch, which is possible only if it's the last reference to it.
+    pub fn reclaim(&self, bytes: Bytes) {
+        if !self.enabled {
+            return;
+        }
+
+        if let Ok(buffer) = bytes.try_into_mut() {
+            self.release(buffer);
+        }
+    }
+
+    pub fn get_metrics(&self) -> BufferPoolMetrics {
+        BufferPoolMetrics {
+            pooled_buffers: self.buffers.lock().unwrap().len() as u64,
+            allocations: self.allocations.load(Ordering::Relaxed),
+            reuses: self.reuses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::BufMut;
+    use iggy::utils::byte_size::IggyByteSize;
+
+    fn pool(enabled: bool, max_buffers: u32, max_buffer_size: u64) -> BufferPool {
+        BufferPool::new(&BufferPoolConfig {
+            enabled,
+            max_buffers,
+            max_buffer_size: IggyByteSize::from(max_buffer_size),
+        })
+    }
+
+    #[test]
+    fn should_reuse_reclaimed_buffer() {
+        let pool = pool(true, 4, 1024);
+        let mut buffer = pool.acquire(128);
+        buffer.put_slice(&[1u8; 64]);
+        pool.reclaim(buffer.freeze());
+
+        let buffer = pool.acquire(128);
+        assert!(buffer.is_empty());
+        assert!(buffer.capacity() >= 128);
+        let metrics = pool.get_metrics();
+        assert_eq!(metrics.allocations, 1);
+        assert_eq!(metrics.reuses, 1);
+        assert_eq!(metrics.pooled_buffers, 0);
+    }
+
+    #[test]
+    fn should_not_reclaim_shared_or_oversized_buffers() {
+        let pool = pool(true, 4, 100);
+        let mut buffer = pool.acquire(64);
+        buffer.put_slice(&[1u8; 64]);
+        let bytes = buffer.freeze();
+        let shared = bytes.clone();
+        pool.reclaim(bytes);
+        assert_eq!(pool.get_metrics().pooled_buffers, 0);
+        drop(shared);
+
+        pool.release(BytesMut::with_capacity(1024));
+        assert_eq!(pool.get_metrics().pooled_buffers, 0);
+    }
+
+    #[test]
+    fn should_keep_at_most_max_buffers() {
+        let pool = pool(true, 2, 1024);
+        for _ in 0..3 {
+            pool.release(BytesMut::with_capacity(64));
+        }
+        assert_eq!(pool.get_metrics().pooled_buffers, 2);
+    }
+
+    #[test]
+    fn should_always_allocate_when_disabled() {
+        let pool = pool(false, 4, 1024);
+        pool.release(BytesMut::with_capacity(64));
+        let _ = pool.acquire(64);
+        let metrics = pool.get_metrics();
+        assert_eq!(metrics.allocations, 1);
+        assert_eq!(metrics.reuses, 0);
+        assert_eq!(metrics.pooled_buffers, 0);
+    }
+}
@@ -0,0 +1,152 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::utils::duration::IggyDuration;
+use std::future::Future;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use tokio::time;
+use tracing::{error, info};
+
+/// Watches the TLS certificate and key files, so that the listener can reload its TLS context after they are rotated
+/// (e.g. renewed by the ACME client), without restarting the server. The files are polled for their modification times,
+/// and the reload is retried on the next check if it fails, e.g. when only one of the files has been replaced so far.
+/// The already established connections keep using the previous TLS context until they are closed.
+#[derive(Debug)]
+pub struct CertificateWatcher {
+    paths: Vec<PathBuf>,
+    interval: IggyDuration,
+    modified_at: Vec<Option<SystemTime>>,
+}
+
+impl CertificateWatcher {
+    pub fn new(paths: &[&str], interval: IggyDuration) -> Self {
+        let paths = paths.iter().map(PathBuf::from).collect::<Vec<_>>();
+        let modified_at = read_modified_at(&paths);
+        Self {
+            paths,
+            interval,
+            modified_at,
+        }
+    }
+
+    /// Spawns the task calling the reload function whenever any of the watched files has changed.
+    pub fn start<F, Fut>(mut self, listener: &'static str, mut reload: F)
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send,
+    {
+        if self.interval.is_zero() {
+            info!("TLS certificate reloading for {listener} is disabled.");
+            return;
+        }
+
+        info!(
+            "TLS certificate reloading for {listener} is enabled, the files will be checked every: {}.",
+            self.interval
+        );
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(self.interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                let Some(modified_at) = self.check() else {
+                    continue;
+                };
+
+                match reload().await {
+                    Ok(()) => {
+                        self.modified_at = modified_at;
+                        info!(
+                            "Reloaded TLS certificate for {listener}, new connections will use it."
+                        );
+                    }
+                    Err(error) => {
+                        error!("Failed to reload TLS certificate for {listener}, keeping the previous one. {error:#}")
+                    }
+                }
+            }
+        });
+    }
+
+    /// Returns the current modification times of the files if any of them has changed since the last reload.
+    fn check(&self) -> Option<Vec<Option<SystemTime>>> {
+        let modified_at = read_modified_at(&self.paths);
+        (modified_at != self.modified_at).then_some(modified_at)
+    }
+}
+
+fn read_modified_at(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| {
+            std::fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::str::FromStr;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    #[test]
+    fn should_detect_modified_files() {
+        let directory = TempDir::new().unwrap();
+        let cert_file = directory.path().join("cert.pem");
+        let key_file = directory.path().join("key.pem");
+        std::fs::write(&cert_file, "cert").unwrap();
+        std::fs::write(&key_file, "key").unwrap();
+        let mut watcher = CertificateWatcher::new(
+            &[cert_file.to_str().unwrap(), key_file.to_str().unwrap()],
+            IggyDuration::from_str("1 s").unwrap(),
+        );
+        assert!(watcher.check().is_none());
+
+        let modified_at = SystemTime::now() + Duration::from_secs(60);
+        File::options()
+            .write(true)
+            .open(&key_file)
+            .unwrap()
+            .set_modified(modified_at)
+            .unwrap();
+        let changed = watcher.check().unwrap();
+        assert_eq!(changed[1], Some(modified_at));
+
+        watcher.modified_at = changed;
+        assert!(watcher.check().is_none());
+    }
+
+    #[test]
+    fn should_detect_removed_files() {
+        let directory = TempDir::new().unwrap();
+        let cert_file = directory.path().join("cert.pem");
+        std::fs::write(&cert_file, "cert").unwrap();
+        let watcher = CertificateWatcher::new(
+            &[cert_file.to_str().unwrap()],
+            IggyDuration::from_str("1 s").unwrap(),
+        );
+
+        std::fs::remove_file(&cert_file).unwrap();
+        assert_eq!(watcher.check(), Some(vec![None]));
+    }
+}
@@ -0,0 +1,104 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+pub type SharedClock = Arc<dyn Clock>;
+
+/// The source of the current time for the time-driven behaviors, such as the retention, heartbeats and tokens expiry.
+pub trait Clock: Debug + Send + Sync {
+    fn now(&self) -> IggyTimestamp;
+}
+
+/// The clock backed by the system time, used by the server.
+#[derive(Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> IggyTimestamp {
+        IggyTimestamp::now()
+    }
+}
+
+/// The clock which only moves when advanced explicitly, so that the tests can control the time.
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    now: AtomicU64,
+}
+
+impl ManualClock {
+    pub fn new(now: IggyTimestamp) -> Self {
+        Self {
+            now: AtomicU64::new(now.as_micros()),
+        }
+    }
+
+    pub fn set(&self, now: IggyTimestamp) {
+        self.now.store(now.as_micros(), Ordering::SeqCst);
+    }
+
+    pub fn advance(&self, duration: IggyDuration) -> IggyTimestamp {
+        let now = self
+            .now
+            .fetch_add(duration.as_micros(), Ordering::SeqCst)
+            .wrapping_add(duration.as_micros());
+        now.into()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> IggyTimestamp {
+        self.now.load(Ordering::SeqCst).into()
+    }
+}
+
+pub fn system_clock() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manual_clock_should_only_move_when_advanced_or_set() {
+        let clock = ManualClock::new(IggyTimestamp::from(1_000));
+        assert_eq!(clock.now().as_micros(), 1_000);
+        assert_eq!(clock.now().as_micros(), 1_000);
+
+        let now = clock.advance(IggyDuration::ONE_SECOND);
+        assert_eq!(now.as_micros(), 1_001_000);
+        assert_eq!(clock.now().as_micros(), 1_001_000);
+
+        clock.set(IggyTimestamp::from(5));
+        assert_eq!(clock.now().as_micros(), 5);
+    }
+
+    #[test]
+    fn system_clock_should_return_current_time() {
+        let before = IggyTimestamp::now();
+        let now = SystemClock.now();
+        let after = IggyTimestamp::now();
+        assert!(before.as_micros() <= now.as_micros());
+        assert!(now.as_micros() <= after.as_micros());
+    }
+}
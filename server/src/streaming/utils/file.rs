@@ -17,9 +17,13 @@
  */
 
 use atone::Vc;
+use bytes::Bytes;
 use iggy::utils::byte_size::IggyByteSize;
+use std::io::{IoSlice, Write};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tokio::fs::{read_dir, remove_file, File, OpenOptions};
+use tokio::task::spawn_blocking;
 
 pub async fn open(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().read(true).open(path).await
@@ -49,6 +53,54 @@ pub async fn exists(path: &str) -> Result<bool, std::io::Error> {
     tokio::fs::try_exists(path).await
 }
 
+/// Writes all the buffers with as few `writev` calls as possible, without concatenating them first.
+///
+/// The tokio file submits only the first buffer of the vectored write, so the standard one is used on the blocking thread pool instead.
+/// The standard handle is shared by the caller, so that the file isn't duplicated and converted for every write.
+/// The single `write_vectored` call can write only a part of the buffers, so the remaining ones are resubmitted.
+/// The `written` bytes are skipped, and then advanced by the newly written ones even if the write fails,
+/// so the write can be retried without appending the same bytes twice.
+pub async fn write_all_vectored(
+    file: Arc<std::fs::File>,
+    buffers: Vec<Bytes>,
+    written: &mut usize,
+) -> std::io::Result<()> {
+    let skipped = *written;
+    let (total_written, result) = spawn_blocking(move || {
+        let mut slices = buffers
+            .iter()
+            .map(|buffer| IoSlice::new(buffer))
+            .collect::<Vec<_>>();
+        let mut slices = slices.as_mut_slice();
+        // Also skips the leading empty buffers, which would be reported as the zero-length write.
+        IoSlice::advance_slices(&mut slices, skipped);
+        let mut written = skipped;
+        while !slices.is_empty() {
+            match file.as_ref().write_vectored(slices) {
+                Ok(0) => return (written, Err(std::io::ErrorKind::WriteZero.into())),
+                Ok(bytes) => {
+                    written += bytes;
+                    IoSlice::advance_slices(&mut slices, bytes);
+                }
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(error) => return (written, Err(error)),
+            }
+        }
+        (written, Ok(()))
+    })
+    .await
+    .map_err(std::io::Error::other)?;
+    *written = total_written;
+    result
+}
+
+/// Syncs the file written by [`write_all_vectored`] on the blocking thread pool.
+pub async fn sync_all(file: Arc<std::fs::File>) -> std::io::Result<()> {
+    spawn_blocking(move || file.sync_all())
+        .await
+        .map_err(std::io::Error::other)?
+}
+
 pub async fn folder_size<P>(path: P) -> std::io::Result<IggyByteSize>
 where
     P: Into<PathBuf> + AsRef<Path>,
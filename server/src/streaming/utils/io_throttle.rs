@@ -0,0 +1,117 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::server::BackgroundIoConfig;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::trace;
+
+const MICROS_PER_SECOND: u128 = 1_000_000;
+
+/// The disk bandwidth budget shared by the background tasks, i.e. the archiving and compaction of the segments
+/// and their uploads to the tiered storage, so that they don't compete with the foreground appends and polls.
+///
+/// Each file processed by the background task reserves the time slot proportional to its size, starting at the end
+/// of the previously reserved one, and the task waits until its slot begins, so the concurrent tasks are queued
+/// one after another rather than exceeding the budget together.
+#[derive(Debug)]
+pub struct BackgroundIoThrottle {
+    max_throughput: Option<IggyByteSize>,
+    next_slot_at: Mutex<u64>,
+}
+
+impl BackgroundIoThrottle {
+    pub fn new(config: &BackgroundIoConfig) -> Self {
+        Self {
+            max_throughput: config.throttling_enabled.then_some(config.max_throughput),
+            next_slot_at: Mutex::new(0),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.max_throughput.is_some()
+    }
+
+    /// Waits until the budget consumed by the previous background I/O is paid off, and reserves it for the given size.
+    pub async fn throttle(&self, size_bytes: u64) {
+        let Some(max_throughput) = self.max_throughput else {
+            return;
+        };
+
+        let now = IggyTimestamp::now().as_micros();
+        let delay = {
+            let mut next_slot_at = self.next_slot_at.lock().unwrap();
+            let (delay, next) = reserve_slot(
+                *next_slot_at,
+                now,
+                size_bytes,
+                max_throughput.as_bytes_u64(),
+            );
+            *next_slot_at = next;
+            delay
+        };
+
+        if delay > 0 {
+            trace!("Throttling background I/O of {size_bytes} bytes for {delay} us.");
+            tokio::time::sleep(Duration::from_micros(delay)).await;
+        }
+    }
+}
+
+/// Returns the delay before the I/O of the given size can start and the time at which the next slot begins.
+fn reserve_slot(
+    next_slot_at: u64,
+    now: u64,
+    size_bytes: u64,
+    max_bytes_per_second: u64,
+) -> (u64, u64) {
+    let starts_at = next_slot_at.max(now);
+    let duration = (size_bytes as u128 * MICROS_PER_SECOND / max_bytes_per_second.max(1) as u128)
+        .min(u64::MAX as u128) as u64;
+    (starts_at - now, starts_at.saturating_add(duration))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MB: u64 = 1_000_000;
+
+    #[test]
+    fn should_not_delay_io_when_no_slot_is_reserved() {
+        let (delay, next_slot_at) = reserve_slot(0, 1_000, 10 * MB, 10 * MB);
+        assert_eq!(delay, 0);
+        assert_eq!(next_slot_at, 1_000 + MICROS_PER_SECOND as u64);
+    }
+
+    #[test]
+    fn should_delay_io_until_previous_slot_ends() {
+        let (delay, next_slot_at) = reserve_slot(2_000_000, 500_000, 5 * MB, 10 * MB);
+        assert_eq!(delay, 1_500_000);
+        assert_eq!(next_slot_at, 2_500_000);
+    }
+
+    #[test]
+    fn should_not_accumulate_budget_while_idle() {
+        let (delay, next_slot_at) = reserve_slot(1_000_000, 5_000_000, MB, 10 * MB);
+        assert_eq!(delay, 0);
+        assert_eq!(next_slot_at, 5_100_000);
+    }
+}
@@ -16,8 +16,15 @@
  * under the License.
  */
 
+pub mod buffer_pool;
+pub mod certificate_watcher;
+pub mod clock;
 pub mod crypto;
 pub mod file;
 pub mod hash;
 pub mod head_tail_buf;
+pub mod io_throttle;
 pub mod random_id;
+pub mod rate_limiter;
+pub mod request_lanes;
+pub mod shutdown;
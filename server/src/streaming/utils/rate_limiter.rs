@@ -0,0 +1,249 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use dashmap::DashMap;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::Serialize;
+use std::hash::Hash;
+
+/// The single request is split into the units refilled every microsecond, so the slow rates don't lose the fractions of the request.
+const UNITS_PER_REQUEST: u64 = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateQuota {
+    pub requests_per_second: u32,
+    pub burst: u32,
+}
+
+/// The current consumption of the quota by the given key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RateQuotaUsage<K> {
+    pub key: K,
+    pub consumed_requests: u32,
+    pub available_requests: u32,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    available_units: u64,
+    refilled_at: u64,
+}
+
+impl TokenBucket {
+    fn full(quota: RateQuota, now: u64) -> Self {
+        Self {
+            available_units: quota.burst as u64 * UNITS_PER_REQUEST,
+            refilled_at: now,
+        }
+    }
+
+    fn available_units(&self, quota: RateQuota, now: u64) -> u64 {
+        let elapsed = now.saturating_sub(self.refilled_at);
+        let refill = elapsed.saturating_mul(quota.requests_per_second as u64);
+        self.available_units
+            .saturating_add(refill)
+            .min(quota.burst as u64 * UNITS_PER_REQUEST)
+    }
+
+    fn refill(&mut self, quota: RateQuota, now: u64) {
+        self.available_units = self.available_units(quota, now);
+        self.refilled_at = self.refilled_at.max(now);
+    }
+}
+
+/// Limits the rate of the requests of each key (e.g. the IP address or the user ID) using the token bucket algorithm.
+///
+/// Every key has its own bucket holding up to the burst of the requests, refilled at the requests per second rate.
+/// The buckets are created on the first request, and should be periodically removed once refilled to keep the memory bounded.
+#[derive(Debug)]
+pub struct RateLimiter<K: Hash + Eq> {
+    quota: RateQuota,
+    buckets: DashMap<K, TokenBucket>,
+}
+
+impl<K: Hash + Eq + Clone> RateLimiter<K> {
+    pub fn new(quota: RateQuota) -> Self {
+        Self {
+            quota,
+            buckets: DashMap::new(),
+        }
+    }
+
+    pub fn quota(&self) -> RateQuota {
+        self.quota
+    }
+
+    /// Consumes a single request from the quota of the key, or returns the time after which the next request will be accepted.
+    pub fn try_acquire(&self, key: K, now: IggyTimestamp) -> Result<(), IggyDuration> {
+        let now = now.as_micros();
+        let mut bucket = self
+            .buckets
+            .entry(key)
+            .or_insert_with(|| TokenBucket::full(self.quota, now));
+        bucket.refill(self.quota, now);
+        if bucket.available_units >= UNITS_PER_REQUEST {
+            bucket.available_units -= UNITS_PER_REQUEST;
+            return Ok(());
+        }
+
+        Err(self.retry_after(bucket.available_units))
+    }
+
+    /// Checks whether the quota of the key allows a single request, without consuming it.
+    pub fn check(&self, key: &K, now: IggyTimestamp) -> Result<(), IggyDuration> {
+        let Some(bucket) = self.buckets.get(key) else {
+            return Ok(());
+        };
+
+        let available_units = bucket.available_units(self.quota, now.as_micros());
+        if available_units >= UNITS_PER_REQUEST {
+            return Ok(());
+        }
+
+        Err(self.retry_after(available_units))
+    }
+
+    /// Returns the single request consumed by [`RateLimiter::try_acquire`] to the quota of the key.
+    pub fn release(&self, key: &K) {
+        if let Some(mut bucket) = self.buckets.get_mut(key) {
+            let capacity = self.quota.burst as u64 * UNITS_PER_REQUEST;
+            bucket.available_units = (bucket.available_units + UNITS_PER_REQUEST).min(capacity);
+        }
+    }
+
+    fn retry_after(&self, available_units: u64) -> IggyDuration {
+        let missing_units = UNITS_PER_REQUEST - available_units;
+        IggyDuration::from(missing_units.div_ceil(self.quota.requests_per_second.max(1) as u64))
+    }
+
+    /// Returns the current consumption of the quota by all the keys, which have sent the requests recently.
+    pub fn get_usage(&self, now: IggyTimestamp) -> Vec<RateQuotaUsage<K>> {
+        let now = now.as_micros();
+        self.buckets
+            .iter()
+            .map(|bucket| {
+                let available_requests =
+                    (bucket.available_units(self.quota, now) / UNITS_PER_REQUEST) as u32;
+                RateQuotaUsage {
+                    key: bucket.key().clone(),
+                    consumed_requests: self.quota.burst - available_requests,
+                    available_requests,
+                }
+            })
+            .collect()
+    }
+
+    /// Removes the buckets refilled to the full quota, as they're no different from the ones created on the next request.
+    /// Returns the number of the removed buckets.
+    pub fn remove_idle(&self, now: IggyTimestamp) -> usize {
+        let now = now.as_micros();
+        let capacity = self.quota.burst as u64 * UNITS_PER_REQUEST;
+        let count = self.buckets.len();
+        self.buckets
+            .retain(|_, bucket| bucket.available_units(self.quota, now) < capacity);
+        count - self.buckets.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUOTA: RateQuota = RateQuota {
+        requests_per_second: 10,
+        burst: 2,
+    };
+
+    #[test]
+    fn should_allow_burst_and_then_reject_with_retry_after() {
+        let limiter = RateLimiter::new(QUOTA);
+        let now = IggyTimestamp::from(1_000_000);
+
+        assert!(limiter.try_acquire(1, now).is_ok());
+        assert!(limiter.try_acquire(1, now).is_ok());
+        let retry_after = limiter.try_acquire(1, now).unwrap_err();
+        assert_eq!(retry_after.as_micros(), 100_000);
+
+        let now = IggyTimestamp::from(now.as_micros() + 40_000);
+        let retry_after = limiter.try_acquire(1, now).unwrap_err();
+        assert_eq!(retry_after.as_micros(), 60_000);
+
+        let now = IggyTimestamp::from(now.as_micros() + 60_000);
+        assert!(limiter.try_acquire(1, now).is_ok());
+        assert!(limiter.try_acquire(1, now).is_err());
+    }
+
+    #[test]
+    fn should_limit_each_key_separately() {
+        let limiter = RateLimiter::new(QUOTA);
+        let now = IggyTimestamp::from(1_000_000);
+
+        assert!(limiter.try_acquire(1, now).is_ok());
+        assert!(limiter.try_acquire(1, now).is_ok());
+        assert!(limiter.try_acquire(1, now).is_err());
+        assert!(limiter.try_acquire(2, now).is_ok());
+    }
+
+    #[test]
+    fn should_return_usage_and_remove_idle_buckets() {
+        let limiter = RateLimiter::new(QUOTA);
+        let now = IggyTimestamp::from(1_000_000);
+        limiter.try_acquire(1, now).unwrap();
+        limiter.try_acquire(1, now).unwrap();
+        limiter.try_acquire(2, now).unwrap();
+
+        let now = IggyTimestamp::from(now.as_micros() + 100_000);
+        let mut usage = limiter.get_usage(now);
+        usage.sort_by_key(|usage| usage.key);
+        assert_eq!(
+            usage,
+            vec![
+                RateQuotaUsage {
+                    key: 1,
+                    consumed_requests: 1,
+                    available_requests: 1,
+                },
+                RateQuotaUsage {
+                    key: 2,
+                    consumed_requests: 0,
+                    available_requests: 2,
+                },
+            ]
+        );
+
+        assert_eq!(limiter.remove_idle(now), 1);
+        assert_eq!(limiter.get_usage(now).len(), 1);
+    }
+
+    #[test]
+    fn should_check_quota_without_consuming_it_and_release_consumed_request() {
+        let limiter = RateLimiter::new(QUOTA);
+        let now = IggyTimestamp::from(1_000_000);
+
+        assert!(limiter.check(&1, now).is_ok());
+        limiter.try_acquire(1, now).unwrap();
+        assert!(limiter.check(&1, now).is_ok());
+        limiter.try_acquire(1, now).unwrap();
+        assert_eq!(limiter.check(&1, now).unwrap_err().as_micros(), 100_000);
+
+        limiter.release(&1);
+        assert!(limiter.check(&1, now).is_ok());
+        assert!(limiter.try_acquire(1, now).is_ok());
+        assert!(limiter.try_acquire(1, now).is_err());
+    }
+}
@@ -0,0 +1,206 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::system::RequestLanesConfig;
+use prometheus_client::encoding::EncodeLabelValue;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// The lane in which the command received via the binary protocol is processed.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, EncodeLabelValue)]
+pub enum RequestLane {
+    /// Managing the resources, users and the server itself, including fetching the stats.
+    Admin,
+    /// Producing and consuming the messages.
+    Data,
+}
+
+impl Display for RequestLane {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestLane::Admin => write!(f, "admin"),
+            RequestLane::Data => write!(f, "data"),
+        }
+    }
+}
+
+/// Separates the administrative commands from the data-plane traffic - each lane has its own limit of the commands
+/// processed concurrently, so that the bursts of the produced and polled messages don't starve the administrative commands.
+///
+/// The lanes only cap the concurrency, they don't prioritize the administrative commands once they're processed.
+/// The command modifying the resources still acquires the exclusive lock of the system, so it waits until the data-plane
+/// commands holding the shared one are completed - their number is bounded by the data lane, but not their duration.
+#[derive(Debug)]
+pub struct RequestLanes {
+    admin: Lane,
+    data: Lane,
+}
+
+#[derive(Debug)]
+struct Lane {
+    slots: Arc<Semaphore>,
+    queued: AtomicUsize,
+    active: Arc<AtomicUsize>,
+}
+
+/// Occupies the slot of the lane, until it's dropped.
+#[derive(Debug)]
+pub struct LanePermit {
+    _slot: OwnedSemaphorePermit,
+    active: Arc<AtomicUsize>,
+}
+
+/// Tracks the command waiting in the lane's queue, also when the waiting is cancelled e.g. by the closed connection.
+struct QueuedRequest<'a> {
+    queued: &'a AtomicUsize,
+}
+
+impl RequestLanes {
+    pub fn new(config: &RequestLanesConfig) -> Self {
+        Self {
+            admin: Lane::new(config.admin_max_concurrency),
+            data: Lane::new(config.data_max_concurrency),
+        }
+    }
+
+    /// Waits for the free slot of the lane, which is released once the returned permit is dropped.
+    pub async fn acquire(&self, lane: RequestLane) -> LanePermit {
+        let lane = self.get_lane(lane);
+        let slot = {
+            let _queued = QueuedRequest::new(&lane.queued);
+            // The semaphore is never closed, so acquiring the slot can't fail.
+            lane.slots.clone().acquire_owned().await.unwrap()
+        };
+        lane.active.fetch_add(1, Ordering::SeqCst);
+        LanePermit {
+            _slot: slot,
+            active: lane.active.clone(),
+        }
+    }
+
+    /// Returns the number of the commands waiting for the free slot of the lane.
+    pub fn queue_depth(&self, lane: RequestLane) -> usize {
+        self.get_lane(lane).queued.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of the commands being processed in the lane.
+    pub fn active_count(&self, lane: RequestLane) -> usize {
+        self.get_lane(lane).active.load(Ordering::SeqCst)
+    }
+
+    fn get_lane(&self, lane: RequestLane) -> &Lane {
+        match lane {
+            RequestLane::Admin => &self.admin,
+            RequestLane::Data => &self.data,
+        }
+    }
+}
+
+impl Default for RequestLanes {
+    fn default() -> Self {
+        Self::new(&RequestLanesConfig::default())
+    }
+}
+
+impl Lane {
+    fn new(max_concurrency: u32) -> Self {
+        Self {
+            slots: Arc::new(Semaphore::new(max_concurrency as usize)),
+            queued: AtomicUsize::new(0),
+            active: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+}
+
+impl<'a> QueuedRequest<'a> {
+    fn new(queued: &'a AtomicUsize) -> Self {
+        queued.fetch_add(1, Ordering::SeqCst);
+        Self { queued }
+    }
+}
+
+impl Drop for QueuedRequest<'_> {
+    fn drop(&mut self) {
+        self.queued.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl Drop for LanePermit {
+    fn drop(&mut self) {
+        self.active.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    fn lanes(admin_max_concurrency: u32, data_max_concurrency: u32) -> Arc<RequestLanes> {
+        Arc::new(RequestLanes::new(&RequestLanesConfig {
+            admin_max_concurrency,
+            data_max_concurrency,
+        }))
+    }
+
+    #[tokio::test]
+    async fn admin_commands_should_not_wait_for_busy_data_lane() {
+        let lanes = lanes(1, 1);
+        let _data = lanes.acquire(RequestLane::Data).await;
+        assert_eq!(lanes.active_count(RequestLane::Data), 1);
+
+        let admin = timeout(Duration::from_secs(5), lanes.acquire(RequestLane::Admin)).await;
+        assert!(admin.is_ok());
+        assert_eq!(lanes.active_count(RequestLane::Admin), 1);
+    }
+
+    #[tokio::test]
+    async fn commands_should_be_queued_until_slot_is_released() {
+        let lanes = lanes(1, 1);
+        let data = lanes.acquire(RequestLane::Data).await;
+
+        let waiter = lanes.clone();
+        let handle = tokio::spawn(async move {
+            let _permit = waiter.acquire(RequestLane::Data).await;
+        });
+        while lanes.queue_depth(RequestLane::Data) == 0 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(lanes.queue_depth(RequestLane::Admin), 0);
+
+        drop(data);
+        timeout(Duration::from_secs(5), handle)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(lanes.queue_depth(RequestLane::Data), 0);
+        assert_eq!(lanes.active_count(RequestLane::Data), 0);
+    }
+
+    #[tokio::test]
+    async fn cancelled_command_should_leave_queue() {
+        let lanes = lanes(1, 1);
+        let _data = lanes.acquire(RequestLane::Data).await;
+
+        let waiting = timeout(Duration::from_millis(10), lanes.acquire(RequestLane::Data)).await;
+        assert!(waiting.is_err());
+        assert_eq!(lanes.queue_depth(RequestLane::Data), 0);
+    }
+}
@@ -0,0 +1,157 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use iggy::error::IggyError;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{watch, Notify};
+use tokio::time::timeout;
+
+/// Coordinates the graceful shutdown of the server - once requested (on SIGTERM or via the HTTP API),
+/// the listeners stop accepting the new connections, the new requests are rejected, and the in-flight ones
+/// are given the time to finish before the unsaved messages are flushed and the server exits.
+#[derive(Debug)]
+pub struct ShutdownCoordinator {
+    requested: watch::Sender<bool>,
+    in_flight: AtomicUsize,
+    drained: Notify,
+}
+
+/// Tracks the request being handled, until it's dropped.
+#[derive(Debug)]
+pub struct InFlightRequest {
+    coordinator: Arc<ShutdownCoordinator>,
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self {
+            requested: watch::Sender::new(false),
+            in_flight: AtomicUsize::new(0),
+            drained: Notify::new(),
+        }
+    }
+}
+
+impl ShutdownCoordinator {
+    /// Requests the shutdown, returns false if it was already requested.
+    pub fn request(&self) -> bool {
+        !self.requested.send_replace(true)
+    }
+
+    pub fn is_requested(&self) -> bool {
+        *self.requested.borrow()
+    }
+
+    /// Waits until the shutdown is requested.
+    pub async fn requested(&self) {
+        let mut requested = self.requested.subscribe();
+        let _ = requested.wait_for(|requested| *requested).await;
+    }
+
+    pub fn in_flight_count(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Starts tracking the request, unless the shutdown has been requested already.
+    /// The counter is incremented before checking the flag, so the request is either rejected or awaited by the drain.
+    pub fn begin_request(self: &Arc<Self>) -> Result<InFlightRequest, IggyError> {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        let request = InFlightRequest {
+            coordinator: self.clone(),
+        };
+        if self.is_requested() {
+            return Err(IggyError::ServerShuttingDown);
+        }
+
+        Ok(request)
+    }
+
+    /// Waits until all the in-flight requests are finished, returns false if the timeout expired before.
+    pub async fn drain(&self, drain_timeout: Duration) -> bool {
+        timeout(drain_timeout, async {
+            loop {
+                let drained = self.drained.notified();
+                if self.in_flight_count() == 0 {
+                    return;
+                }
+                drained.await;
+            }
+        })
+        .await
+        .is_ok()
+    }
+}
+
+impl Drop for InFlightRequest {
+    fn drop(&mut self) {
+        if self.coordinator.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.coordinator.drained.notify_waiters();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn requests_should_be_rejected_once_shutdown_is_requested() {
+        let coordinator = Arc::new(ShutdownCoordinator::default());
+        let request = coordinator.begin_request().unwrap();
+        assert_eq!(coordinator.in_flight_count(), 1);
+
+        assert!(coordinator.request());
+        assert!(!coordinator.request());
+        assert!(matches!(
+            coordinator.begin_request(),
+            Err(IggyError::ServerShuttingDown)
+        ));
+        assert_eq!(coordinator.in_flight_count(), 1);
+
+        drop(request);
+        assert_eq!(coordinator.in_flight_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn drain_should_wait_for_in_flight_requests() {
+        let coordinator = Arc::new(ShutdownCoordinator::default());
+        let request = coordinator.begin_request().unwrap();
+        coordinator.request();
+        assert!(!coordinator.drain(Duration::from_millis(10)).await);
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            drop(request);
+        });
+        assert!(coordinator.drain(Duration::from_secs(5)).await);
+    }
+
+    #[tokio::test]
+    async fn requested_should_complete_once_shutdown_is_requested() {
+        let coordinator = Arc::new(ShutdownCoordinator::default());
+        let waiter = coordinator.clone();
+        let handle = tokio::spawn(async move { waiter.requested().await });
+        coordinator.request();
+        timeout(Duration::from_secs(5), handle)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(coordinator.is_requested());
+    }
+}
@@ -17,16 +17,20 @@
  */
 
 use crate::binary::command::ServerCommandHandler;
+use crate::binary::mapper;
 use crate::binary::{command, sender::SenderKind};
 use crate::server_error::ConnectionError;
+use crate::streaming::diagnostics::metrics::TransportLabel;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::command::ServerCommand;
+use ahash::AHashMap;
 use iggy::error::IggyError;
 use iggy::models::batch::{IggyHeader, IggyMutableBatch, IGGY_BATCH_OVERHEAD};
 use std::io::ErrorKind;
 use std::sync::Arc;
-use tracing::{debug, error, info};
+use std::time::Instant;
+use tracing::{debug, error, info, warn};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
 
@@ -37,7 +41,14 @@ pub(crate) async fn handle_connection(
 ) -> Result<(), ConnectionError> {
     let mut length_buffer = [0u8; INITIAL_BYTES_LENGTH];
     let mut code_buffer = [0u8; INITIAL_BYTES_LENGTH];
+    let shutdown = system.read().await.shutdown_coordinator();
+    let request_lanes = system.read().await.request_lanes();
+    let mut pushed_generations = AHashMap::new();
     loop {
+        if sender.is_consumer_group_events_enabled() {
+            push_consumer_group_events(&session, sender, &system, &mut pushed_generations).await?;
+        }
+
         let read_length = match sender.read(&mut length_buffer).await {
             Ok(read_length) => read_length,
             Err(error) => {
@@ -62,8 +73,106 @@ pub(crate) async fn handle_connection(
         let code = u32::from_le_bytes(code_buffer);
         debug!("Received a TCP request, length: {length}, code: {code}");
         let command = ServerCommand::from_code_and_reader(code, sender, length - 4).await?;
+        if let Err(error) = sender.verify_frame_checksum().await {
+            // The request might have been corrupted on its way, so it mustn't be handled.
+            warn!("Received a TCP request with code: {code} and an invalid frame checksum, session: {session}. {error}");
+            system
+                .read()
+                .await
+                .metrics
+                .increment_frame_checksum_errors();
+            sender.send_error_response(error).await?;
+            continue;
+        }
+
         debug!("Received a TCP command: {command}, payload size: {length}");
-        command.handle(sender, length, &session, &system).await?;
+        let _in_flight = match shutdown.begin_request() {
+            Ok(in_flight) => in_flight,
+            Err(error) => {
+                sender.send_error_response(error).await?;
+                continue;
+            }
+        };
+        if command.requires_cluster_leader() {
+            if let Err(error) = system.read().await.ensure_cluster_leader().await {
+                warn!("Received a TCP command: {command} on the cluster follower, session: {session}.");
+                sender.send_error_response(error).await?;
+                continue;
+            }
+        }
+
+        let (name, stream_id, topic_id) = command.authorization();
+        system
+            .resolve_authorization(&session, name, stream_id, topic_id)
+            .await;
+
+        let _lane_permit = request_lanes.acquire(command.lane()).await;
+        let command_name = command.name();
+        let audit_details = command.is_administrative().then(|| command.details());
+        let started_at = Instant::now();
+        let result = command.handle(sender, length, &session, &system).await;
+        {
+            let system = system.read().await;
+            system
+                .metrics
+                .record_command(command_name, TransportLabel::Tcp, started_at.elapsed());
+            if let Some(details) = audit_details {
+                system.record_audit_entry(
+                    &session,
+                    TransportLabel::Tcp,
+                    command_name,
+                    details,
+                    &result,
+                );
+            }
+        }
+        result?;
+    }
+}
+
+/// Pushes the rebalances of the consumer groups the client is a member of, until the next request has arrived.
+/// The last pushed generation of each group is tracked, so that every assignment is sent only once.
+async fn push_consumer_group_events(
+    session: &Session,
+    sender: &mut SenderKind,
+    system: &SharedSystem,
+    pushed_generations: &mut AHashMap<(u32, u32, u32), u32>,
+) -> Result<(), ConnectionError> {
+    loop {
+        let rebalances = system
+            .read()
+            .await
+            .get_consumer_group_rebalances(session.client_id)
+            .await;
+        let mut generations = AHashMap::with_capacity(rebalances.len());
+        let mut receivers = Vec::with_capacity(rebalances.len());
+        for (rebalance, receiver) in rebalances {
+            let key = (rebalance.stream_id, rebalance.topic_id, rebalance.group_id);
+            if pushed_generations.get(&key) != Some(&rebalance.generation) {
+                debug!("Pushing the rebalance of consumer group with ID: {}, topic with ID: {}, stream with ID: {}, generation: {}, session: {session}",
+                    rebalance.group_id, rebalance.topic_id, rebalance.stream_id, rebalance.generation);
+                sender
+                    .send_consumer_group_event(&mapper::map_consumer_group_rebalance(&rebalance))
+                    .await?;
+            }
+            generations.insert(key, rebalance.generation);
+            receivers.push(receiver);
+        }
+        *pushed_generations = generations;
+
+        if receivers.is_empty() {
+            return Ok(());
+        }
+
+        let rebalanced = futures::future::select_all(
+            receivers
+                .iter_mut()
+                .map(|receiver| Box::pin(receiver.changed())),
+        );
+        tokio::select! {
+            result = sender.wait_for_request() => return result.map_err(ConnectionError::from),
+            _ = rebalanced => {}
+        }
     }
 }
 
@@ -17,12 +17,41 @@
  */
 
 use iggy::error::IggyError;
-use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use iggy::utils::checksum;
+use iggy::utils::checksum::ChecksumHasher;
+#[cfg(target_os = "linux")]
+use nix::sys::sendfile::sendfile64;
+use std::fs::File;
+use std::io::SeekFrom;
+#[cfg(target_os = "linux")]
+use std::os::fd::AsFd;
+#[cfg(target_os = "linux")]
+use tokio::io::Interest;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWrite, AsyncWriteExt};
+#[cfg(target_os = "linux")]
+use tokio::net::TcpStream;
 use tracing::debug;
 
 const STATUS_OK: &[u8] = &[0; 4];
 
-pub(crate) async fn read<T>(stream: &mut T, buffer: &mut [u8]) -> Result<usize, IggyError>
+const FRAME_CHECKSUM_LENGTH: usize = 4;
+
+pub(crate) async fn read<T>(
+    stream: &mut T,
+    frame_checksum: &mut Option<ChecksumHasher>,
+    buffer: &mut [u8],
+) -> Result<usize, IggyError>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    let read_bytes = read_exact(stream, buffer).await?;
+    if let Some(frame_checksum) = frame_checksum {
+        frame_checksum.update(&buffer[..read_bytes]);
+    }
+    Ok(read_bytes)
+}
+
+async fn read_exact<T>(stream: &mut T, buffer: &mut [u8]) -> Result<usize, IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
@@ -39,32 +68,144 @@ where
     }
 }
 
-pub(crate) async fn send_empty_ok_response<T>(stream: &mut T) -> Result<(), IggyError>
+/// Reads the checksum following the request frame and compares it with the one calculated over the bytes read so far.
+/// Does nothing if the frame checksums haven't been negotiated with the client.
+pub(crate) async fn verify_frame_checksum<T>(
+    stream: &mut T,
+    frame_checksum: &mut Option<ChecksumHasher>,
+) -> Result<(), IggyError>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    let Some(frame_checksum) = frame_checksum else {
+        return Ok(());
+    };
+
+    let mut checksum_buffer = [0u8; FRAME_CHECKSUM_LENGTH];
+    read_exact(stream, &mut checksum_buffer).await?;
+    let received_checksum = u32::from_le_bytes(checksum_buffer);
+    let expected_checksum = frame_checksum.checksum();
+    frame_checksum.reset();
+    if received_checksum != expected_checksum {
+        return Err(IggyError::InvalidFrameChecksum(
+            received_checksum,
+            expected_checksum,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Confirms the negotiation of the frame checksums, which are used for all the following requests and responses.
+pub(crate) async fn enable_frame_checksums<T>(
+    stream: &mut T,
+    frame_checksum: &mut Option<ChecksumHasher>,
+) -> Result<(), IggyError>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    // The negotiating request has been read already, so only the following frames are checksummed.
+    send_empty_ok_response(stream, frame_checksum).await?;
+    frame_checksum.replace(ChecksumHasher::new());
+    Ok(())
+}
+
+pub(crate) async fn send_empty_ok_response<T>(
+    stream: &mut T,
+    frame_checksum: &Option<ChecksumHasher>,
+) -> Result<(), IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
-    send_ok_response(stream, &[]).await
+    send_ok_response(stream, frame_checksum, &[]).await
 }
 
-pub(crate) async fn send_ok_response<T>(stream: &mut T, payload: &[u8]) -> Result<(), IggyError>
+pub(crate) async fn send_ok_response<T>(
+    stream: &mut T,
+    frame_checksum: &Option<ChecksumHasher>,
+    payload: &[u8],
+) -> Result<(), IggyError>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    send_response(stream, frame_checksum, STATUS_OK, payload).await
+}
+
+/// Reads the range of the file into memory and sends it following the header, used when it can't be sent straight to the socket.
+pub(crate) async fn send_ok_response_from_file<T>(
+    stream: &mut T,
+    frame_checksum: &Option<ChecksumHasher>,
+    header: &[u8],
+    file: File,
+    position: u64,
+    length: u64,
+) -> Result<(), IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
-    send_response(stream, STATUS_OK, payload).await
+    let mut payload = vec![0u8; header.len() + length as usize];
+    payload[..header.len()].copy_from_slice(header);
+    let mut file = tokio::fs::File::from_std(file);
+    file.seek(SeekFrom::Start(position))
+        .await
+        .map_err(|_| IggyError::CannotReadFile)?;
+    file.read_exact(&mut payload[header.len()..])
+        .await
+        .map_err(|_| IggyError::CannotReadFile)?;
+    send_ok_response(stream, frame_checksum, &payload).await
+}
+
+/// Sends the header followed by the range of the file, which is copied by the kernel straight to the socket using `sendfile`.
+#[cfg(target_os = "linux")]
+pub(crate) async fn send_ok_response_with_sendfile(
+    stream: &mut TcpStream,
+    header: &[u8],
+    file: File,
+    position: u64,
+    length: u64,
+) -> Result<(), IggyError> {
+    debug!("Sending response with status: {:?} from file...", STATUS_OK);
+    let payload_length = ((header.len() as u64 + length) as u32).to_le_bytes();
+    stream
+        .write_all(&[STATUS_OK, &payload_length, header].as_slice().concat())
+        .await
+        .map_err(|_| IggyError::TcpError)?;
+
+    let mut offset = position as i64;
+    let end_offset = (position + length) as i64;
+    while offset < end_offset {
+        let remaining = (end_offset - offset) as usize;
+        let sent = stream
+            .async_io(Interest::WRITABLE, || {
+                sendfile64(stream.as_fd(), &file, Some(&mut offset), remaining)
+                    .map_err(std::io::Error::from)
+            })
+            .await
+            .map_err(|_| IggyError::TcpError)?;
+        if sent == 0 {
+            // The file is shorter than expected, so the response can't be completed anymore.
+            return Err(IggyError::CannotReadFile);
+        }
+    }
+
+    debug!("Sent response with status: {:?} from file", STATUS_OK);
+    Ok(())
 }
 
 pub(crate) async fn send_error_response<T>(
     stream: &mut T,
+    frame_checksum: &Option<ChecksumHasher>,
     error: IggyError,
 ) -> Result<(), IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
-    send_response(stream, &error.as_code().to_le_bytes(), &[]).await
+    send_response(stream, frame_checksum, &error.as_code().to_le_bytes(), &[]).await
 }
 
 pub(crate) async fn send_response<T>(
     stream: &mut T,
+    frame_checksum: &Option<ChecksumHasher>,
     status: &[u8],
     payload: &[u8],
 ) -> Result<(), IggyError>
@@ -73,8 +214,13 @@ where
 {
     debug!("Sending response with status: {:?}...", status);
     let length = (payload.len() as u32).to_le_bytes();
+    let mut frame = [status, &length, payload].as_slice().concat();
+    if frame_checksum.is_some() {
+        let checksum = checksum::calculate(&frame);
+        frame.extend_from_slice(&checksum.to_le_bytes());
+    }
     stream
-        .write_all(&[status, &length, payload].as_slice().concat())
+        .write_all(&frame)
         .await
         .map_err(|_| IggyError::TcpError)?;
     debug!("Sent response with status: {:?}", status);
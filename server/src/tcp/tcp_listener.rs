@@ -51,10 +51,22 @@ pub async fn start(address: &str, socket: TcpSocket, system: SharedSystem) -> So
             )
         });
 
+        let shutdown = system.read().await.shutdown_coordinator();
         loop {
-            match listener.accept().await {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = shutdown.requested() => {
+                    info!("TCP listener has stopped accepting new connections.");
+                    break;
+                }
+            };
+            match accepted {
                 Ok((stream, address)) => {
                     info!("Accepted new TCP connection: {address}");
+                    if !system.read().await.is_client_address_allowed(&address) {
+                        continue;
+                    }
+
                     let session = system
                         .read()
                         .await
@@ -21,28 +21,109 @@ use crate::tcp::COMPONENT;
 use crate::{server_error::ServerError, tcp::sender};
 use error_set::ErrContext;
 use iggy::error::IggyError;
+use iggy::system::enable_consumer_group_events::CONSUMER_GROUP_EVENT_STATUS;
+use iggy::utils::checksum::ChecksumHasher;
+use std::fs::File;
 use tokio::{io::AsyncWriteExt, net::TcpStream};
 
 #[derive(Debug)]
 pub struct TcpSender {
     pub(crate) stream: TcpStream,
+    pub(crate) frame_checksum: Option<ChecksumHasher>,
+    pub(crate) zero_copy_polling: bool,
+    pub(crate) consumer_group_events: bool,
+}
+
+impl TcpSender {
+    pub(crate) async fn wait_for_request(&mut self) -> Result<(), IggyError> {
+        let mut buffer = [0u8; 1];
+        match self.stream.peek(&mut buffer).await {
+            Ok(0) => Err(IggyError::ConnectionClosed),
+            Ok(_) => Ok(()),
+            Err(_) => Err(IggyError::TcpError),
+        }
+    }
+
+    pub(crate) async fn send_consumer_group_event(
+        &mut self,
+        payload: &[u8],
+    ) -> Result<(), IggyError> {
+        sender::send_response(
+            &mut self.stream,
+            &self.frame_checksum,
+            &CONSUMER_GROUP_EVENT_STATUS.to_le_bytes(),
+            payload,
+        )
+        .await
+    }
 }
 
 impl Sender for TcpSender {
     async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
-        sender::read(&mut self.stream, buffer).await
+        sender::read(&mut self.stream, &mut self.frame_checksum, buffer).await
+    }
+
+    async fn verify_frame_checksum(&mut self) -> Result<(), IggyError> {
+        sender::verify_frame_checksum(&mut self.stream, &mut self.frame_checksum).await
+    }
+
+    async fn enable_frame_checksums(&mut self) -> Result<(), IggyError> {
+        sender::enable_frame_checksums(&mut self.stream, &mut self.frame_checksum).await
+    }
+
+    async fn enable_zero_copy_polling(&mut self) -> Result<(), IggyError> {
+        sender::send_empty_ok_response(&mut self.stream, &self.frame_checksum).await?;
+        self.zero_copy_polling = true;
+        Ok(())
+    }
+
+    async fn enable_consumer_group_events(&mut self) -> Result<(), IggyError> {
+        sender::send_empty_ok_response(&mut self.stream, &self.frame_checksum).await?;
+        self.consumer_group_events = true;
+        Ok(())
     }
 
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
-        sender::send_empty_ok_response(&mut self.stream).await
+        sender::send_empty_ok_response(&mut self.stream, &self.frame_checksum).await
     }
 
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
-        sender::send_ok_response(&mut self.stream, payload).await
+        sender::send_ok_response(&mut self.stream, &self.frame_checksum, payload).await
+    }
+
+    async fn send_ok_response_from_file(
+        &mut self,
+        header: &[u8],
+        file: File,
+        position: u64,
+        length: u64,
+    ) -> Result<(), IggyError> {
+        // The checksum of the frame has to be calculated over the stored batches, so they're read into memory then.
+        #[cfg(target_os = "linux")]
+        if self.frame_checksum.is_none() {
+            return sender::send_ok_response_with_sendfile(
+                &mut self.stream,
+                header,
+                file,
+                position,
+                length,
+            )
+            .await;
+        }
+
+        sender::send_ok_response_from_file(
+            &mut self.stream,
+            &self.frame_checksum,
+            header,
+            file,
+            position,
+            length,
+        )
+        .await
     }
 
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        sender::send_error_response(&mut self.stream, error).await
+        sender::send_error_response(&mut self.stream, &self.frame_checksum, error).await
     }
 
     async fn shutdown(&mut self) -> Result<(), ServerError> {
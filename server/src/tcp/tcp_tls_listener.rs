@@ -18,15 +18,29 @@
 
 use crate::binary::sender::SenderKind;
 use crate::configs::tcp::TcpTlsConfig;
+use crate::streaming::clients::certificate_auth::crypto_provider;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::certificate_watcher::CertificateWatcher;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
+use anyhow::{anyhow, Context};
+use openssl::pkcs12::Pkcs12;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+use rustls::server::danger::ClientCertVerifier;
 use std::net::SocketAddr;
-use tokio::net::TcpSocket;
+use std::sync::{Arc, RwLock};
+use tokio::net::{TcpSocket, TcpStream};
 use tokio::sync::oneshot;
 use tokio_native_tls::native_tls;
 use tokio_native_tls::native_tls::Identity;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+
+/// The native TLS doesn't support verifying the client certificates, so rustls is used instead when they are required.
+#[derive(Clone)]
+enum TlsAcceptor {
+    Native(tokio_native_tls::TlsAcceptor),
+    Rustls(tokio_rustls::TlsAcceptor),
+}
 
 pub(crate) async fn start(
     address: &str,
@@ -35,24 +49,31 @@ pub(crate) async fn start(
     system: SharedSystem,
 ) -> SocketAddr {
     let address = address.to_string();
+    let verifier = match config.client_certificates {
+        true => Some(
+            system
+                .read()
+                .await
+                .certificate_auth
+                .client_verifier()
+                .expect("Client certificate authentication is not enabled."),
+        ),
+        false => None,
+    };
+    let acceptor =
+        TlsAcceptor::create(&config, verifier.clone()).expect("Unable to create TLS acceptor.");
+    let acceptor = Arc::new(RwLock::new(acceptor));
+    let reloaded_acceptor = acceptor.clone();
+    CertificateWatcher::new(&[&config.certificate], config.reload_interval).start(
+        "TCP TLS",
+        move || {
+            let result = TlsAcceptor::create(&config, verifier.clone())
+                .map(|acceptor| *reloaded_acceptor.write().unwrap() = acceptor);
+            async move { result }
+        },
+    );
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
-        let certificate = std::fs::read(config.certificate.clone());
-        if certificate.is_err() {
-            panic!("Unable to read certificate file.");
-        }
-
-        let identity = Identity::from_pkcs12(&certificate.unwrap(), &config.password);
-        if identity.is_err() {
-            panic!("Unable to create identity from certificate.");
-        }
-
-        let acceptor = tokio_native_tls::TlsAcceptor::from(
-            native_tls::TlsAcceptor::builder(identity.unwrap())
-                .build()
-                .unwrap(),
-        );
-
         let addr = address.parse();
         if addr.is_err() {
             panic!("Unable to parse address {:?}", address);
@@ -77,10 +98,22 @@ pub(crate) async fn start(
             )
         });
 
+        let shutdown = system.read().await.shutdown_coordinator();
         loop {
-            match listener.accept().await {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = shutdown.requested() => {
+                    info!("TCP TLS listener has stopped accepting new connections.");
+                    break;
+                }
+            };
+            match accepted {
                 Ok((stream, address)) => {
                     info!("Accepted new TCP TLS connection: {}", address);
+                    if !system.read().await.is_client_address_allowed(&address) {
+                        continue;
+                    }
+
                     let session = system
                         .read()
                         .await
@@ -88,11 +121,33 @@ pub(crate) async fn start(
                         .await;
 
                     let client_id = session.client_id;
-                    let acceptor = acceptor.clone();
-                    let stream = acceptor.accept(stream).await.unwrap();
+                    let acceptor = acceptor.read().unwrap().clone();
                     let system = system.clone();
-                    let mut sender = SenderKind::get_tcp_tls_sender(stream);
                     tokio::spawn(async move {
+                        let (mut sender, certificate) = match acceptor.accept(stream).await {
+                            Ok(accepted) => accepted,
+                            Err(error) => {
+                                error!("Failed TLS handshake with client: {client_id}, address: {address}. {error}");
+                                system.read().await.delete_client(client_id).await;
+                                return;
+                            }
+                        };
+
+                        if let Some(certificate) = certificate {
+                            let authenticated = system
+                                .read()
+                                .await
+                                .login_user_with_certificate(&certificate, &session)
+                                .await
+                                .map(|_| ());
+                            if let Err(error) = authenticated {
+                                warn!("Failed to authenticate client: {client_id}, address: {address} by certificate. {error}");
+                                system.read().await.delete_client(client_id).await;
+                                let _ = sender.shutdown().await;
+                                return;
+                            }
+                        }
+
                         if let Err(error) =
                             handle_connection(session, &mut sender, system.clone()).await
                         {
@@ -115,3 +170,79 @@ pub(crate) async fn start(
         Err(_) => panic!("Failed to get the local address for TCP TLS listener."),
     }
 }
+
+impl TlsAcceptor {
+    fn create(
+        config: &TcpTlsConfig,
+        verifier: Option<Arc<dyn ClientCertVerifier>>,
+    ) -> anyhow::Result<Self> {
+        match verifier {
+            Some(verifier) => Ok(Self::Rustls(create_rustls_acceptor(config, verifier)?)),
+            None => Ok(Self::Native(create_native_acceptor(config)?)),
+        }
+    }
+
+    /// Performs the TLS handshake, returning the sender and the client certificate, if it was required.
+    async fn accept(&self, stream: TcpStream) -> anyhow::Result<(SenderKind, Option<Vec<u8>>)> {
+        match self {
+            Self::Native(acceptor) => {
+                let stream = acceptor.accept(stream).await?;
+                Ok((SenderKind::get_tcp_tls_sender(stream), None))
+            }
+            Self::Rustls(acceptor) => {
+                let stream = acceptor.accept(stream).await?;
+                let certificate = stream
+                    .get_ref()
+                    .1
+                    .peer_certificates()
+                    .and_then(|certificates| certificates.first())
+                    .map(|certificate| certificate.to_vec())
+                    .ok_or_else(|| anyhow!("missing client certificate"))?;
+                Ok((SenderKind::get_tcp_tls_sender(stream), Some(certificate)))
+            }
+        }
+    }
+}
+
+fn create_native_acceptor(config: &TcpTlsConfig) -> anyhow::Result<tokio_native_tls::TlsAcceptor> {
+    let certificate =
+        std::fs::read(&config.certificate).context("Unable to read certificate file.")?;
+    let identity = Identity::from_pkcs12(&certificate, &config.password)
+        .context("Unable to create identity from certificate.")?;
+    Ok(tokio_native_tls::TlsAcceptor::from(
+        native_tls::TlsAcceptor::builder(identity).build()?,
+    ))
+}
+
+fn create_rustls_acceptor(
+    config: &TcpTlsConfig,
+    verifier: Arc<dyn ClientCertVerifier>,
+) -> anyhow::Result<tokio_rustls::TlsAcceptor> {
+    let certificate =
+        std::fs::read(&config.certificate).context("Unable to read certificate file.")?;
+    let identity = Pkcs12::from_der(&certificate)
+        .and_then(|identity| identity.parse2(&config.password))
+        .context("Unable to create identity from certificate.")?;
+    let (Some(certificate), Some(key)) = (identity.cert, identity.pkey) else {
+        return Err(anyhow!(
+            "Certificate file does not contain the certificate and the private key."
+        ));
+    };
+    let mut certificates = vec![CertificateDer::from(certificate.to_der()?)];
+    if let Some(authorities) = identity.ca {
+        for authority in authorities {
+            certificates.push(CertificateDer::from(authority.to_der()?));
+        }
+    }
+    let key = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key.private_key_to_pkcs8()?));
+
+    let server_config = rustls::ServerConfig::builder_with_provider(crypto_provider())
+        .with_safe_default_protocol_versions()
+        .and_then(|builder| {
+            builder
+                .with_client_cert_verifier(verifier)
+                .with_single_cert(certificates, key)
+        })
+        .context("Unable to create TLS config with client certificate verification.")?;
+    Ok(tokio_rustls::TlsAcceptor::from(Arc::new(server_config)))
+}
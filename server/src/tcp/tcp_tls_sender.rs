@@ -21,30 +21,76 @@ use crate::tcp::COMPONENT;
 use crate::{server_error::ServerError, tcp::sender};
 use error_set::ErrContext;
 use iggy::error::IggyError;
-use tokio::io::AsyncWriteExt;
-use tokio::net::TcpStream;
-use tokio_native_tls::TlsStream;
+use iggy::utils::checksum::ChecksumHasher;
+use std::fmt::Debug;
+use std::fs::File;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+
+/// The TLS stream accepted either by the native TLS acceptor or, when the clients
+/// are authenticated by their certificates, by the rustls one.
+pub trait TlsConnection: AsyncRead + AsyncWrite + Unpin + Send + Debug {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send + Debug> TlsConnection for T {}
 
 #[derive(Debug)]
 pub struct TcpTlsSender {
-    pub(crate) stream: TlsStream<TcpStream>,
+    pub(crate) stream: Box<dyn TlsConnection>,
+    pub(crate) frame_checksum: Option<ChecksumHasher>,
 }
 
 impl Sender for TcpTlsSender {
     async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
-        sender::read(&mut self.stream, buffer).await
+        sender::read(&mut self.stream, &mut self.frame_checksum, buffer).await
+    }
+
+    async fn verify_frame_checksum(&mut self) -> Result<(), IggyError> {
+        sender::verify_frame_checksum(&mut self.stream, &mut self.frame_checksum).await
+    }
+
+    async fn enable_frame_checksums(&mut self) -> Result<(), IggyError> {
+        sender::enable_frame_checksums(&mut self.stream, &mut self.frame_checksum).await
+    }
+
+    async fn enable_zero_copy_polling(&mut self) -> Result<(), IggyError> {
+        // The stored batches would have to be encrypted, so they can't be sent straight from the file.
+        sender::send_error_response(
+            &mut self.stream,
+            &self.frame_checksum,
+            IggyError::FeatureUnavailable,
+        )
+        .await
+    }
+
+    async fn enable_consumer_group_events(&mut self) -> Result<(), IggyError> {
+        // The buffered TLS stream can't tell whether the next request has arrived without reading it.
+        sender::send_error_response(
+            &mut self.stream,
+            &self.frame_checksum,
+            IggyError::FeatureUnavailable,
+        )
+        .await
     }
 
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
-        sender::send_empty_ok_response(&mut self.stream).await
+        sender::send_empty_ok_response(&mut self.stream, &self.frame_checksum).await
     }
 
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
-        sender::send_ok_response(&mut self.stream, payload).await
+        sender::send_ok_response(&mut self.stream, &self.frame_checksum, payload).await
+    }
+
+    async fn send_ok_response_from_file(
+        &mut self,
+        _header: &[u8],
+        _file: File,
+        _position: u64,
+        _length: u64,
+    ) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
     }
 
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        sender::send_error_response(&mut self.stream, error).await
+        sender::send_error_response(&mut self.stream, &self.frame_checksum, error).await
     }
 
     async fn shutdown(&mut self) -> Result<(), ServerError> {
@@ -0,0 +1,265 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::messages::{OrderConfirmed, OrderCreated, OrderRejected, SerializableMessage};
+use iggy::error::IggyError;
+use iggy::messages::send_messages::Message;
+use iggy::utils::timestamp::IggyTimestamp;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::Instant;
+
+const CURRENCY_PAIRS: &[&str] = &["EUR/USD", "EUR/GBP", "USD/GBP", "EUR/PLN", "USD/PLN"];
+
+/// The shape of the payloads produced by the generator.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadSchema {
+    /// Order messages serialized as plain JSON.
+    #[default]
+    Orders,
+    /// Order messages wrapped in the JSON envelope carrying the message type.
+    OrdersEnvelope,
+    /// Fixed size payload made of the repeated lowercase alphabet.
+    Alphabet { size: u32 },
+}
+
+/// Configuration of the messages generator.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MessagesGeneratorConfig {
+    /// The schema of the generated payloads.
+    pub schema: PayloadSchema,
+    /// The optional seed, the same seed always yields the same sequence of messages.
+    pub seed: Option<u64>,
+    /// The optional limit of messages generated per second by `next_batch`.
+    pub messages_per_second: Option<u32>,
+}
+
+impl MessagesGeneratorConfig {
+    pub fn with_schema(self, schema: PayloadSchema) -> Self {
+        Self { schema, ..self }
+    }
+
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self {
+            seed: Some(seed),
+            ..self
+        }
+    }
+
+    pub fn with_rate(self, messages_per_second: u32) -> Self {
+        Self {
+            messages_per_second: Some(messages_per_second),
+            ..self
+        }
+    }
+}
+
+/// Generates the test data shared by the examples, benchmarks and tests.
+#[derive(Debug)]
+pub struct MessagesGenerator {
+    config: MessagesGeneratorConfig,
+    order_id: u64,
+    rng: StdRng,
+    next_batch_at: Option<Instant>,
+}
+
+impl Default for MessagesGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessagesGenerator {
+    pub fn new() -> Self {
+        Self::with_config(MessagesGeneratorConfig::default())
+    }
+
+    pub fn with_config(config: MessagesGeneratorConfig) -> Self {
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+        Self {
+            config,
+            order_id: 0,
+            rng,
+            next_batch_at: None,
+        }
+    }
+
+    pub fn config(&self) -> &MessagesGeneratorConfig {
+        &self.config
+    }
+
+    /// Generates the next order message, regardless of the configured payload schema.
+    pub fn generate(&mut self) -> Box<dyn SerializableMessage> {
+        match self.rng.random_range(0..=2) {
+            0 => self.generate_order_created(),
+            1 => self.generate_order_confirmed(),
+            2 => self.generate_order_rejected(),
+            _ => panic!("Unexpected message type"),
+        }
+    }
+
+    /// Generates the next payload using the configured schema.
+    pub fn generate_payload(&mut self) -> String {
+        match self.config.schema {
+            PayloadSchema::Orders => self.generate().to_json(),
+            PayloadSchema::OrdersEnvelope => self.generate().to_json_envelope(),
+            PayloadSchema::Alphabet { size } => alphabet_payload(size),
+        }
+    }
+
+    /// Generates the batch of messages using the configured schema.
+    pub fn generate_messages(&mut self, count: u32) -> Result<Vec<Message>, IggyError> {
+        let mut messages = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            messages.push(Message::from_str(&self.generate_payload())?);
+        }
+        Ok(messages)
+    }
+
+    /// Generates the batch of messages, waiting first if the configured rate would be exceeded.
+    pub async fn next_batch(&mut self, count: u32) -> Result<Vec<Message>, IggyError> {
+        if let Some(next_batch_at) = self.next_batch_at {
+            tokio::time::sleep_until(next_batch_at).await;
+        }
+        if let Some(messages_per_second) = self.config.messages_per_second {
+            let delay = Duration::from_secs_f64(count as f64 / messages_per_second.max(1) as f64);
+            self.next_batch_at = Some(Instant::now() + delay);
+        }
+        self.generate_messages(count)
+    }
+
+    fn generate_order_created(&mut self) -> Box<dyn SerializableMessage> {
+        self.order_id += 1;
+        Box::new(OrderCreated {
+            order_id: self.order_id,
+            timestamp: IggyTimestamp::now(),
+            currency_pair: CURRENCY_PAIRS[self.rng.random_range(0..CURRENCY_PAIRS.len())]
+                .to_string(),
+            price: self.rng.random_range(10.0..=1000.0),
+            quantity: self.rng.random_range(0.1..=1.0),
+            side: match self.rng.random_range(0..=1) {
+                0 => "buy",
+                _ => "sell",
+            }
+            .to_string(),
+        })
+    }
+
+    fn generate_order_confirmed(&mut self) -> Box<dyn SerializableMessage> {
+        Box::new(OrderConfirmed {
+            order_id: self.order_id,
+            timestamp: IggyTimestamp::now(),
+            price: self.rng.random_range(10.0..=1000.0),
+        })
+    }
+
+    fn generate_order_rejected(&mut self) -> Box<dyn SerializableMessage> {
+        Box::new(OrderRejected {
+            order_id: self.order_id,
+            timestamp: IggyTimestamp::now(),
+            reason: match self.rng.random_range(0..=1) {
+                0 => "cancelled_by_user",
+                _ => "other",
+            }
+            .to_string(),
+        })
+    }
+}
+
+/// Creates the payload of the given size made of the repeated lowercase alphabet.
+pub fn alphabet_payload(size: u32) -> String {
+    let mut payload = String::with_capacity(size as usize);
+    for i in 0..size {
+        let char = (i % 26 + 97) as u8 as char;
+        payload.push(char);
+    }
+
+    payload
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_message_types(generator: &mut MessagesGenerator, count: usize) -> Vec<String> {
+        (0..count)
+            .map(|_| generator.generate().get_message_type().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_should_generate_the_same_messages() {
+        let config = MessagesGeneratorConfig::default().with_seed(42);
+        let mut first_generator = MessagesGenerator::with_config(config);
+        let mut second_generator = MessagesGenerator::with_config(config);
+
+        assert_eq!(
+            get_message_types(&mut first_generator, 100),
+            get_message_types(&mut second_generator, 100)
+        );
+    }
+
+    #[test]
+    fn alphabet_schema_should_generate_payloads_of_the_given_size() {
+        let config =
+            MessagesGeneratorConfig::default().with_schema(PayloadSchema::Alphabet { size: 30 });
+        let mut generator = MessagesGenerator::with_config(config);
+
+        let messages = generator.generate_messages(5).unwrap();
+
+        assert_eq!(messages.len(), 5);
+        for message in messages {
+            assert_eq!(message.length, 30);
+            assert_eq!(&message.payload[..], b"abcdefghijklmnopqrstuvwxyzabcd");
+        }
+    }
+
+    #[test]
+    fn orders_envelope_schema_should_generate_envelopes() {
+        let config = MessagesGeneratorConfig::default()
+            .with_schema(PayloadSchema::OrdersEnvelope)
+            .with_seed(1);
+        let mut generator = MessagesGenerator::with_config(config);
+
+        let payload = generator.generate_payload();
+        let envelope: crate::messages::Envelope = serde_json::from_str(&payload).unwrap();
+
+        assert!(!envelope.message_type.is_empty());
+        assert!(!envelope.payload.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn next_batch_should_respect_the_configured_rate() {
+        let config = MessagesGeneratorConfig::default()
+            .with_schema(PayloadSchema::Alphabet { size: 10 })
+            .with_rate(100);
+        let mut generator = MessagesGenerator::with_config(config);
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            generator.next_batch(50).await.unwrap();
+        }
+
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+}